@@ -0,0 +1,245 @@
+//! Access policy
+//!
+//! Decides which hosts the HTTP clients may contact and which folders the
+//! filesystem helpers may write to, based on the allowlists in
+//! [`AccessPolicySettings`]. Loopback hosts and the app data dir are always
+//! allowed. Violations are logged, kept for the settings page and emitted as
+//! `access-policy-violation`; they are only blocked in enforce mode.
+//!
+//! The policy is global so clients built without an `AppHandle` (LLM and
+//! Ollama clients) can check their URLs.
+
+use crate::events::{AccessPolicyViolation, SettingsConfigApplied};
+use crate::settings::{AccessPolicyMode, AccessPolicySettings};
+use log::warn;
+use serde::Serialize;
+use specta::Type;
+use std::collections::VecDeque;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, OnceLock, RwLock};
+use tauri::{AppHandle, Listener};
+use tauri_specta::Event;
+
+/// Violations kept before the oldest is dropped
+const VIOLATION_CAPACITY: usize = 100;
+
+const LOOPBACK_HOSTS: &[&str] = &["localhost", "127.0.0.1", "::1"];
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessKind {
+    Host,
+    Path,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Type)]
+pub struct AccessViolation {
+    /// Unix timestamp in milliseconds
+    pub timestamp_ms: i64,
+    pub kind: AccessKind,
+    /// The host or path that was not allowed
+    pub target: String,
+    /// Whether the access was blocked, or only reported
+    pub blocked: bool,
+}
+
+#[derive(Debug)]
+struct Policy {
+    mode: AccessPolicyMode,
+    hosts: Vec<String>,
+    paths: Vec<PathBuf>,
+}
+
+static POLICY: RwLock<Policy> = RwLock::new(Policy {
+    mode: AccessPolicyMode::Audit,
+    hosts: Vec::new(),
+    paths: Vec::new(),
+});
+static VIOLATIONS: Mutex<VecDeque<AccessViolation>> = Mutex::new(VecDeque::new());
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+/// Resolve `.` and `..` without touching the filesystem, so paths that
+/// don't exist yet can be checked
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Whether `host` is an allowed host or a subdomain of one
+fn host_allowed(allowed: &[String], host: &str) -> bool {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let host = host.trim_end_matches('.').to_lowercase();
+    if LOOPBACK_HOSTS.contains(&host.as_str()) {
+        return true;
+    }
+    allowed.iter().any(|entry| {
+        let entry = entry.trim().trim_start_matches("*.").to_lowercase();
+        !entry.is_empty()
+            && (host == entry
+                || host
+                    .strip_suffix(entry.as_str())
+                    .is_some_and(|prefix| prefix.ends_with('.')))
+    })
+}
+
+fn path_allowed(allowed: &[PathBuf], path: &Path) -> bool {
+    let path = normalize(path);
+    path.is_absolute() && allowed.iter().any(|root| path.starts_with(root))
+}
+
+impl Policy {
+    fn new(settings: &AccessPolicySettings, app_data_dir: Option<PathBuf>) -> Self {
+        let paths = settings
+            .allowed_paths
+            .iter()
+            .map(|path| path.trim())
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .chain(app_data_dir)
+            .map(|path| normalize(&path))
+            .filter(|path| path.is_absolute())
+            .collect();
+        Self {
+            mode: settings.mode,
+            hosts: settings.allowed_hosts.clone(),
+            paths,
+        }
+    }
+}
+
+/// Apply the access policy settings
+pub fn configure(settings: &AccessPolicySettings, app_data_dir: Option<PathBuf>) {
+    if let Ok(mut policy) = POLICY.write() {
+        *policy = Policy::new(settings, app_data_dir);
+    }
+}
+
+/// Configure the policy from the current settings and keep the handle used
+/// to report violations. The policy is re-read when the config file changes.
+pub fn init(app: &AppHandle) {
+    let _ = APP_HANDLE.set(app.clone());
+    reload(app);
+
+    let app_handle = app.clone();
+    app.listen_any(SettingsConfigApplied::NAME, move |_| reload(&app_handle));
+}
+
+/// Re-read the policy from the settings
+pub fn reload(app: &AppHandle) {
+    let settings = crate::settings::get_settings(app);
+    configure(
+        &settings.access_policy,
+        crate::portable::app_data_dir(app).ok(),
+    );
+}
+
+/// Record a violation and decide whether it's blocked
+fn report(mode: AccessPolicyMode, kind: AccessKind, target: String) -> Result<(), String> {
+    let blocked = mode == AccessPolicyMode::Enforce;
+    let violation = AccessViolation {
+        timestamp_ms: chrono::Utc::now().timestamp_millis(),
+        kind,
+        target,
+        blocked,
+    };
+
+    let message = match kind {
+        AccessKind::Host => format!("Host {} is not in the access policy", violation.target),
+        AccessKind::Path => format!("Path {} is not in the access policy", violation.target),
+    };
+    warn!("{}{}", message, if blocked { "" } else { " (audit only)" });
+
+    if let Ok(mut violations) = VIOLATIONS.lock() {
+        if violations.len() == VIOLATION_CAPACITY {
+            violations.pop_front();
+        }
+        violations.push_back(violation.clone());
+    }
+    if let Some(app) = APP_HANDLE.get() {
+        let _ = AccessPolicyViolation(violation).emit(app);
+    }
+
+    if blocked {
+        Err(message)
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that a URL may be requested
+pub fn check_url(url: &str) -> Result<(), String> {
+    // URLs without a host can't reach anything; the client reports them
+    let Some(host) = reqwest::Url::parse(url.trim())
+        .ok()
+        .and_then(|url| url.host_str().map(|host| host.to_string()))
+    else {
+        return Ok(());
+    };
+    let mode = {
+        let policy = POLICY.read().map_err(|e| e.to_string())?;
+        if policy.mode == AccessPolicyMode::Off || host_allowed(&policy.hosts, &host) {
+            return Ok(());
+        }
+        policy.mode
+    };
+    report(mode, AccessKind::Host, host)
+}
+
+/// Check that a file or folder may be written
+pub fn check_path(path: &Path) -> Result<(), String> {
+    let mode = {
+        let policy = POLICY.read().map_err(|e| e.to_string())?;
+        if policy.mode == AccessPolicyMode::Off || path_allowed(&policy.paths, path) {
+            return Ok(());
+        }
+        policy.mode
+    };
+    report(mode, AccessKind::Path, path.display().to_string())
+}
+
+/// Recent violations, oldest first
+pub fn violations() -> Vec<AccessViolation> {
+    VIOLATIONS
+        .lock()
+        .map(|violations| violations.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hosts_match_subdomains_and_loopback() {
+        let allowed = vec!["example.com".to_string(), "*.hooks.dev".to_string()];
+        assert!(host_allowed(&allowed, "example.com"));
+        assert!(host_allowed(&allowed, "API.Example.com"));
+        assert!(host_allowed(&allowed, "a.hooks.dev"));
+        assert!(host_allowed(&allowed, "hooks.dev"));
+        assert!(!host_allowed(&allowed, "badexample.com"));
+        assert!(!host_allowed(&allowed, "example.com.evil.net"));
+        assert!(host_allowed(&[], "localhost"));
+        assert!(host_allowed(&[], "[::1]"));
+        assert!(!host_allowed(&[], "10.0.0.1"));
+    }
+
+    #[test]
+    fn test_paths_must_stay_inside_allowed_folders() {
+        let root = std::env::temp_dir().join("dictum-policy");
+        let allowed = vec![root.join("notes")];
+        assert!(path_allowed(&allowed, &root.join("notes/2024/a.md")));
+        assert!(path_allowed(&allowed, &root.join("notes")));
+        assert!(!path_allowed(&allowed, &root.join("notes/../secrets")));
+        assert!(!path_allowed(&allowed, &root.join("notes-old/a.md")));
+        assert!(!path_allowed(&allowed, Path::new("notes/a.md")));
+    }
+}
@@ -1,18 +1,35 @@
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 use crate::apple_intelligence;
 use crate::audio_feedback::{play_feedback_sound, play_feedback_sound_blocking, SoundType};
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::{format_for_code, VoiceCommandEngine};
+use crate::automation_hooks;
+use crate::events::TranscriptionFinalized;
+use crate::formatting;
+use crate::helpers::focused_app::focused_app_name;
 use crate::managers::active_listening::ActiveListeningManager;
 use crate::managers::ask_ai::AskAiManager;
 use crate::managers::audio::AudioRecordingManager;
+use crate::managers::browser_bridge::BrowserBridge;
 use crate::managers::history::HistoryManager;
-use crate::managers::transcription::TranscriptionManager;
-use crate::settings::{get_settings, AppSettings, APPLE_INTELLIGENCE_PROVIDER_ID};
+use crate::managers::plugins::{is_plugin_binding, PluginManager};
+use crate::managers::transcription::{TimedTranscript, TranscriptionManager};
+use crate::paragraphing;
+use crate::prompt_routing;
+use crate::settings::{
+    get_settings, is_profile_binding, AppSettings, HookStage, LLMPrompt, PasteMethod, SiteDelivery,
+    APPLE_INTELLIGENCE_PROVIDER_ID,
+};
 use crate::shortcut;
+use crate::speaker_markers;
+use crate::transcription_output;
+use crate::translation;
 use crate::tray::{change_tray_icon, TrayIconState};
 use crate::utils::{self, hide_recording_overlay, show_active_listening_overlay, show_recording_overlay, show_transcribing_overlay};
+use crate::voice_profile;
 use crate::ManagedToggleState;
 use ferrous_opencc::{config::BuiltinConfig, OpenCC};
-use log::{debug, error};
+use log::{debug, error, warn};
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -29,59 +46,79 @@ pub trait ShortcutAction: Send + Sync {
 // Transcribe Action
 struct TranscribeAction;
 
-async fn maybe_post_process_transcription(
-    settings: &AppSettings,
-    transcription: &str,
-) -> Option<String> {
-    if !settings.post_process_enabled {
-        return None;
-    }
-
-    let provider = match settings.active_post_process_provider().cloned() {
-        Some(provider) => provider,
-        None => {
-            debug!("Post-processing enabled but no provider is selected");
-            return None;
-        }
+/// Have the browser extension insert a dictation into the focused field,
+/// returning whether it did
+fn insert_via_browser_bridge(app: &AppHandle, text: &str) -> bool {
+    let Some(bridge) = app.try_state::<Arc<BrowserBridge>>() else {
+        return false;
     };
+    match bridge.insert(text) {
+        Ok(()) => true,
+        Err(e) => {
+            warn!("Pasting instead of using the browser bridge: {}", e);
+            false
+        }
+    }
+}
 
-    let model = settings
-        .post_process_models
-        .get(&provider.id)
-        .cloned()
-        .unwrap_or_default();
+/// The prompt a dictation is post-processed with: the formatting mode's when
+/// one is selected, otherwise the selected post-process prompt if
+/// post-processing is on
+fn selected_post_process_prompt(settings: &AppSettings) -> Option<LLMPrompt> {
+    if let Some(mode) = settings.formatting.active_mode() {
+        return Some(mode.to_prompt());
+    }
 
-    if model.trim().is_empty() {
-        debug!(
-            "Post-processing skipped because provider '{}' has no model configured",
-            provider.id
-        );
+    if !settings.post_process_enabled {
         return None;
     }
 
     let selected_prompt_id = match &settings.post_process_selected_prompt_id {
-        Some(id) => id.clone(),
+        Some(id) => id,
         None => {
             debug!("Post-processing skipped because no prompt is selected");
             return None;
         }
     };
 
-    let prompt = match settings
+    let prompt = settings
         .post_process_prompts
         .iter()
-        .find(|prompt| prompt.id == selected_prompt_id)
-    {
-        Some(prompt) => prompt.prompt.clone(),
+        .find(|prompt| &prompt.id == selected_prompt_id)
+        .cloned();
+    if prompt.is_none() {
+        debug!(
+            "Post-processing skipped because prompt '{}' was not found",
+            selected_prompt_id
+        );
+    }
+    prompt
+}
+
+async fn maybe_post_process_transcription(
+    settings: &AppSettings,
+    transcription: &str,
+) -> Option<String> {
+    let selected_prompt = selected_post_process_prompt(settings)?;
+
+    // The prompt's own provider and model win over the selected provider
+    let (provider, model) = match settings.post_process_target(&selected_prompt) {
+        Some((provider, model)) => (provider.clone(), model),
         None => {
-            debug!(
-                "Post-processing skipped because prompt '{}' was not found",
-                selected_prompt_id
-            );
+            debug!("Post-processing enabled but no provider is selected");
             return None;
         }
     };
 
+    if model.trim().is_empty() {
+        debug!(
+            "Post-processing skipped because provider '{}' has no model configured",
+            provider.id
+        );
+        return None;
+    }
+
+    let prompt = selected_prompt.prompt.clone();
     if prompt.trim().is_empty() {
         debug!("Post-processing skipped because the selected prompt is empty");
         return None;
@@ -165,6 +202,20 @@ async fn maybe_post_process_transcription(
     }
 }
 
+/// Post-process `transcription` with a specific prompt, whatever prompt and
+/// formatting mode are selected
+pub(crate) async fn post_process_with_prompt(
+    settings: &AppSettings,
+    prompt_id: &str,
+    transcription: &str,
+) -> Option<String> {
+    let mut settings = settings.clone();
+    settings.post_process_enabled = true;
+    settings.post_process_selected_prompt_id = Some(prompt_id.to_string());
+    settings.formatting.selected_mode = None;
+    maybe_post_process_transcription(&settings, transcription).await
+}
+
 async fn maybe_convert_chinese_variant(
     settings: &AppSettings,
     transcription: &str,
@@ -224,53 +275,38 @@ impl ShortcutAction for TranscribeAction {
 
         let rm = app.state::<Arc<AudioRecordingManager>>();
 
-        // Get the microphone mode to determine audio feedback timing
-        let settings = get_settings(app);
-        let is_always_on = settings.always_on_microphone;
-        debug!("Microphone mode - always_on: {}", is_always_on);
+        debug!(
+            "Microphone mode - always_on: {}",
+            get_settings(app).always_on_microphone
+        );
+
+        // Play the start sound while the recording starts. The sound is
+        // pre-decoded and its output stream kept open, so it starts right away
+        // in either mode. The blocking helper returns immediately when audio
+        // feedback is disabled.
+        let app_clone = app.clone();
+        let feedback = std::thread::spawn(move || {
+            play_feedback_sound_blocking(&app_clone, SoundType::Start);
+        });
 
-        let mut recording_started = false;
-        if is_always_on {
-            // Always-on mode: Play audio feedback immediately, then apply mute after sound finishes
-            debug!("Always-on mode: Playing audio feedback immediately");
+        let recording_start_time = Instant::now();
+        let recording_started = rm.try_start_recording(&binding_id);
+        if recording_started {
+            debug!("Recording started in {:?}", recording_start_time.elapsed());
+            // Mute only once the sound has finished so it stays audible
             let rm_clone = Arc::clone(&rm);
-            let app_clone = app.clone();
-            // The blocking helper exits immediately if audio feedback is disabled,
-            // so we can always reuse this thread to ensure mute happens right after playback.
             std::thread::spawn(move || {
-                play_feedback_sound_blocking(&app_clone, SoundType::Start);
+                let _ = feedback.join();
                 rm_clone.apply_mute();
             });
-
-            recording_started = rm.try_start_recording(&binding_id);
-            debug!("Recording started: {}", recording_started);
         } else {
-            // On-demand mode: Start recording first, then play audio feedback, then apply mute
-            // This allows the microphone to be activated before playing the sound
-            debug!("On-demand mode: Starting recording first, then audio feedback");
-            let recording_start_time = Instant::now();
-            if rm.try_start_recording(&binding_id) {
-                recording_started = true;
-                debug!("Recording started in {:?}", recording_start_time.elapsed());
-                // Small delay to ensure microphone stream is active
-                let app_clone = app.clone();
-                let rm_clone = Arc::clone(&rm);
-                std::thread::spawn(move || {
-                    std::thread::sleep(std::time::Duration::from_millis(100));
-                    debug!("Handling delayed audio feedback/mute sequence");
-                    // Helper handles disabled audio feedback by returning early, so we reuse it
-                    // to keep mute sequencing consistent in every mode.
-                    play_feedback_sound_blocking(&app_clone, SoundType::Start);
-                    rm_clone.apply_mute();
-                });
-            } else {
-                debug!("Failed to start recording");
-            }
+            debug!("Failed to start recording");
         }
 
         if recording_started {
             // Dynamically register the cancel shortcut in a separate task to avoid deadlock
             shortcut::register_cancel_shortcut(app);
+            tm.start_streaming_preview();
         }
 
         debug!(
@@ -291,6 +327,9 @@ impl ShortcutAction for TranscribeAction {
         let tm = Arc::clone(&app.state::<Arc<TranscriptionManager>>());
         let hm = Arc::clone(&app.state::<Arc<HistoryManager>>());
 
+        // The final transcript replaces the live preview
+        tm.stop_streaming_preview();
+
         change_tray_icon(app, TrayIconState::Transcribing);
         show_transcribing_overlay(app);
 
@@ -310,27 +349,119 @@ impl ShortcutAction for TranscribeAction {
             );
 
             let stop_recording_time = Instant::now();
-            if let Some(samples) = rm.stop_recording(&binding_id) {
+            if let Some((samples, mut pauses)) = rm.stop_recording_with_pauses(&binding_id) {
                 debug!(
                     "Recording stopped and samples retrieved in {:?}, sample count: {}",
                     stop_recording_time.elapsed(),
                     samples.len()
                 );
 
+                // A profile shortcut handles its dictations its own way
+                let settings = get_settings(&ah).for_binding(&binding_id);
+                let recorded_len = samples.len();
+                let samples = voice_profile::filter_to_primary_speaker(&settings, samples);
+                // Pauses point into the recording, not into filtered audio
+                if samples.len() != recorded_len {
+                    pauses.clear();
+                }
+                let duration_seconds = samples.len() as f64 / WHISPER_SAMPLE_RATE as f64;
+                // A page focused in a browser with the companion extension
+                // is matched by its host, like an app by its name
+                let browser_focus = ah
+                    .try_state::<Arc<BrowserBridge>>()
+                    .and_then(|bridge| bridge.focus());
+                let site_rule = browser_focus
+                    .as_ref()
+                    .and_then(|focus| settings.browser_bridge.rule_for(&focus.host))
+                    .cloned();
+                // Text going into a code editor or terminal keeps its fillers
+                // and gets code formatting instead of punctuation
+                let code_target = focused_app_name()
+                    .is_some_and(|app| settings.code_dictation.applies_to(&app))
+                    || browser_focus
+                        .as_ref()
+                        .is_some_and(|focus| settings.code_dictation.applies_to(&focus.host));
+                let bridge_delivery = browser_focus.is_some_and(|focus| focus.editable)
+                    && site_rule
+                        .as_ref()
+                        .map_or(true, |rule| rule.delivery == SiteDelivery::Bridge);
                 let transcription_time = Instant::now();
                 let samples_clone = samples.clone(); // Clone for history saving
-                match tm.transcribe(samples) {
-                    Ok(transcription) => {
+                let result = paragraphing::transcribe(
+                    &tm,
+                    &settings.paragraphing,
+                    samples,
+                    &pauses,
+                    &binding_id,
+                    code_target,
+                )
+                .await;
+                match result {
+                    Ok(TimedTranscript {
+                        text: transcription,
+                        segments,
+                        language: detected_language,
+                        confidence,
+                    }) => {
                         debug!(
                             "Transcription completed in {:?}: '{}'",
                             transcription_time.elapsed(),
                             transcription
                         );
+                        let transcription = if settings.paragraphing.speaker_markers {
+                            speaker_markers::mark_speakers(&samples_clone, &segments)
+                                .unwrap_or(transcription)
+                        } else {
+                            transcription
+                        };
                         if !transcription.is_empty() {
-                            let settings = get_settings(&ah);
+                            let mut settings = get_settings(&ah).for_binding(&binding_id);
+                            // The site's formatting mode, unless a route phrase
+                            // picks another prompt
+                            if let Some(mode) = site_rule
+                                .as_ref()
+                                .and_then(|rule| rule.formatting_mode.clone())
+                            {
+                                settings.formatting.selected_mode = Some(mode);
+                            }
+                            // A leading route phrase picks the post-process prompt
+                            let transcription =
+                                prompt_routing::route_dictation(&ah, &mut settings, &transcription)
+                                    .unwrap_or(transcription);
+                            let transcription = if code_target {
+                                format_for_code(
+                                    &transcription,
+                                    settings.code_dictation.identifier_case,
+                                )
+                            } else {
+                                transcription
+                            };
+                            let transcription = if settings.voice_commands.enabled {
+                                VoiceCommandEngine::new(&settings.voice_commands.commands)
+                                    .apply(&transcription)
+                            } else {
+                                transcription
+                            };
+                            let transcription = automation_hooks::transform(
+                                &settings.automation_hooks,
+                                HookStage::PostTranscribe,
+                                transcription,
+                            )
+                            .await;
+                            let transcription = match ah.try_state::<Arc<PluginManager>>() {
+                                Some(plugins) => plugins.process_text(transcription),
+                                None => transcription,
+                            };
+                            let paste_method =
+                                if code_target && settings.code_dictation.prefer_direct_paste {
+                                    PasteMethod::Direct
+                                } else {
+                                    settings.paste_method
+                                };
                             let mut final_text = transcription.clone();
                             let mut post_processed_text: Option<String> = None;
                             let mut post_process_prompt: Option<String> = None;
+                            let mut formatting_mode: Option<String> = None;
 
                             // First, check if Chinese variant conversion is needed
                             if let Some(converted_text) =
@@ -347,17 +478,37 @@ impl ShortcutAction for TranscribeAction {
                                 post_processed_text = Some(processed_text);
 
                                 // Get the prompt that was used
-                                if let Some(prompt_id) = &settings.post_process_selected_prompt_id {
-                                    if let Some(prompt) = settings
-                                        .post_process_prompts
-                                        .iter()
-                                        .find(|p| &p.id == prompt_id)
-                                    {
-                                        post_process_prompt = Some(prompt.prompt.clone());
+                                post_process_prompt = selected_post_process_prompt(&settings)
+                                    .map(|prompt| prompt.prompt);
+                                formatting_mode = settings
+                                    .formatting
+                                    .active_mode()
+                                    .map(|mode| mode.name.clone());
+                            }
+
+                            // Translate last, so the original stays in history
+                            if let Some(language) = settings.translation.language_for(&binding_id) {
+                                match translation::translate(&settings, &final_text, language).await
+                                {
+                                    Ok(translated) => {
+                                        final_text = translated.clone();
+                                        post_processed_text = Some(translated);
                                     }
+                                    Err(e) => error!("Failed to translate transcription: {}", e),
                                 }
                             }
 
+                            transcription_output::publish(
+                                &ah,
+                                TranscriptionFinalized {
+                                    text: transcription.clone(),
+                                    post_processed_text: post_processed_text.clone(),
+                                    language: detected_language,
+                                    duration_seconds,
+                                    confidence: confidence.map(f64::from),
+                                },
+                            );
+
                             // Save to history with post-processed text and prompt
                             let hm_clone = Arc::clone(&hm);
                             let transcription_for_history = transcription.clone();
@@ -368,6 +519,8 @@ impl ShortcutAction for TranscribeAction {
                                         transcription_for_history,
                                         post_processed_text,
                                         post_process_prompt,
+                                        formatting_mode,
+                                        segments,
                                     )
                                     .await
                                 {
@@ -375,15 +528,43 @@ impl ShortcutAction for TranscribeAction {
                                 }
                             });
 
-                            // Paste the final text (either processed or original)
+                            let final_text = automation_hooks::transform(
+                                &settings.automation_hooks,
+                                HookStage::PrePaste,
+                                final_text,
+                            )
+                            .await;
+
+                            // Insert the final text (either processed or original)
+                            // through the browser extension, or paste it
+                            let inserted_by_browser =
+                                bridge_delivery && insert_via_browser_bridge(&ah, &final_text);
+                            let pasted_text = final_text.clone();
+                            let hooks = settings.automation_hooks.clone();
                             let ah_clone = ah.clone();
                             let paste_time = Instant::now();
                             ah.run_on_main_thread(move || {
-                                match utils::paste(final_text, ah_clone.clone()) {
-                                    Ok(()) => debug!(
-                                        "Text pasted successfully in {:?}",
-                                        paste_time.elapsed()
-                                    ),
+                                let result = if inserted_by_browser {
+                                    Ok(())
+                                } else {
+                                    utils::paste_with_method(
+                                        final_text,
+                                        ah_clone.clone(),
+                                        paste_method,
+                                    )
+                                };
+                                match result {
+                                    Ok(()) => {
+                                        debug!(
+                                            "Text pasted successfully in {:?}",
+                                            paste_time.elapsed()
+                                        );
+                                        automation_hooks::notify(
+                                            &hooks,
+                                            HookStage::PostPaste,
+                                            pasted_text,
+                                        );
+                                    }
                                     Err(e) => error!("Failed to paste transcription: {}", e),
                                 }
                                 // Hide the overlay after transcription is complete
@@ -551,6 +732,32 @@ impl ShortcutAction for ActiveListeningAction {
     }
 }
 
+// Mark Highlight Action - timestamp the current moment of an active listening session
+struct MarkHighlightAction;
+
+impl ShortcutAction for MarkHighlightAction {
+    fn start(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
+        debug!(
+            "MarkHighlightAction::start called for binding: {}",
+            binding_id
+        );
+
+        let alm = app.state::<Arc<ActiveListeningManager>>();
+        if !alm.is_session_active() {
+            debug!("No active listening session, ignoring highlight shortcut");
+            return;
+        }
+
+        if let Err(e) = alm.mark_highlight() {
+            error!("Failed to mark highlight: {}", e);
+        }
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Nothing to do on stop, the highlight is marked on press
+    }
+}
+
 // Toggle Overlay Action - temporarily hide/show the overlay
 struct ToggleOverlayAction;
 
@@ -586,6 +793,36 @@ impl ShortcutAction for ToggleOverlayAction {
     }
 }
 
+// Toggle Microphone Mute Action - mute/unmute the system microphone
+struct ToggleMicMuteAction;
+
+impl ShortcutAction for ToggleMicMuteAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        if let Err(e) = utils::toggle_microphone_mute(app) {
+            error!("Failed to toggle microphone mute: {}", e);
+        }
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Nothing to do on stop for toggle
+    }
+}
+
+// Cycle Formatting Mode Action - switch to the next formatting mode
+struct CycleFormattingModeAction;
+
+impl ShortcutAction for CycleFormattingModeAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        if let Err(e) = formatting::cycle_mode(app) {
+            error!("Failed to switch formatting mode: {}", e);
+        }
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Nothing to do on stop, the mode switches on press
+    }
+}
+
 // Ask AI Action - hold to record, release to process
 struct AskAiAction;
 
@@ -654,6 +891,25 @@ impl ShortcutAction for AskAiAction {
     }
 }
 
+// Plugin Action - runs an action a plugin script registered
+struct PluginAction;
+
+impl ShortcutAction for PluginAction {
+    fn start(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
+        let plugins = app.state::<Arc<PluginManager>>().inner().clone();
+        let binding_id = binding_id.to_string();
+        tauri::async_runtime::spawn_blocking(move || {
+            if let Err(e) = plugins.run_action(&binding_id) {
+                error!("{}", e);
+            }
+        });
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Plugin actions run once per press
+    }
+}
+
 // Static Action Map
 pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::new(|| {
     let mut map = HashMap::new();
@@ -673,6 +929,10 @@ pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::ne
         "active_listening".to_string(),
         Arc::new(ActiveListeningAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        "mark_highlight".to_string(),
+        Arc::new(MarkHighlightAction) as Arc<dyn ShortcutAction>,
+    );
     map.insert(
         "ask_ai".to_string(),
         Arc::new(AskAiAction) as Arc<dyn ShortcutAction>,
@@ -681,5 +941,25 @@ pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::ne
         "toggle_overlay".to_string(),
         Arc::new(ToggleOverlayAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        "toggle_mic_mute".to_string(),
+        Arc::new(ToggleMicMuteAction) as Arc<dyn ShortcutAction>,
+    );
+    map.insert(
+        "cycle_formatting_mode".to_string(),
+        Arc::new(CycleFormattingModeAction) as Arc<dyn ShortcutAction>,
+    );
     map
 });
+
+/// The action a binding runs, including plugin actions and the transcribe
+/// shortcuts of profiles
+pub fn action_for(binding_id: &str) -> Option<Arc<dyn ShortcutAction>> {
+    if is_plugin_binding(binding_id) {
+        return Some(Arc::new(PluginAction));
+    }
+    if is_profile_binding(binding_id) {
+        return ACTION_MAP.get("transcribe").cloned();
+    }
+    ACTION_MAP.get(binding_id).cloned()
+}
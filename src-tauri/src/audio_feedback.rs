@@ -1,12 +1,20 @@
+use crate::audio_toolkit::audio::{
+    FrameResampler, OutputChannel, OutputDevice, OutputEngine, OutputSound, PlaybackReference,
+};
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::managers::docking;
+use crate::managers::scheduler;
 use crate::settings::SoundTheme;
 use crate::settings::{self, AppSettings};
-use cpal::traits::{DeviceTrait, HostTrait};
 use log::{debug, error, warn};
-use rodio::OutputStreamBuilder;
+use rodio::{ChannelCount, Decoder, SampleRate, Source};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::thread;
+use std::time::{Duration, SystemTime};
 use tauri::{AppHandle, Manager};
 
 pub enum SoundType {
@@ -19,119 +27,223 @@ fn resolve_sound_path(
     settings: &AppSettings,
     sound_type: SoundType,
 ) -> Option<PathBuf> {
-    let sound_file = get_sound_path(settings, sound_type);
-    let base_dir = get_sound_base_dir(settings);
-    app.path().resolve(&sound_file, base_dir).ok()
+    let theme = sound_theme(app, settings);
+    let sound_file = get_sound_path(theme, sound_type);
+    match theme {
+        SoundTheme::Custom => crate::portable::app_data_dir(app)
+            .ok()
+            .map(|dir| dir.join(sound_file)),
+        _ => app
+            .path()
+            .resolve(&sound_file, tauri::path::BaseDirectory::Resource)
+            .ok(),
+    }
 }
 
-fn get_sound_path(settings: &AppSettings, sound_type: SoundType) -> String {
-    match (settings.sound_theme, sound_type) {
+fn get_sound_path(theme: SoundTheme, sound_type: SoundType) -> String {
+    match (theme, sound_type) {
         (SoundTheme::Custom, SoundType::Start) => "custom_start.wav".to_string(),
         (SoundTheme::Custom, SoundType::Stop) => "custom_stop.wav".to_string(),
-        (_, SoundType::Start) => settings.sound_theme.to_start_path(),
-        (_, SoundType::Stop) => settings.sound_theme.to_stop_path(),
+        (_, SoundType::Start) => theme.to_start_path(),
+        (_, SoundType::Stop) => theme.to_stop_path(),
+    }
+}
+
+/// Sound theme to play, honoring the active schedule rules
+fn sound_theme(app: &AppHandle, settings: &AppSettings) -> SoundTheme {
+    scheduler::active_overrides(app)
+        .sound_theme
+        .unwrap_or(settings.sound_theme)
+}
+
+/// Whether feedback sounds are on, honoring the active dock rule
+fn feedback_enabled(app: &AppHandle, settings: &AppSettings) -> bool {
+    docking::active_overrides(app, settings)
+        .and_then(|overrides| overrides.audio_feedback)
+        .unwrap_or(settings.audio_feedback)
+}
+
+/// Output device for sounds, honoring the active dock rule
+fn output_device(app: &AppHandle, settings: &AppSettings) -> Option<OutputDevice> {
+    if let Some(name) =
+        docking::active_overrides(app, settings).and_then(|overrides| overrides.output_device)
+    {
+        return Some(OutputDevice { id: None, name });
     }
+    settings
+        .selected_output_device
+        .clone()
+        .map(|name| OutputDevice {
+            id: settings.selected_output_device_id.clone(),
+            name,
+        })
+}
+
+/// A feedback sound decoded into memory
+struct DecodedSound {
+    channels: ChannelCount,
+    sample_rate: SampleRate,
+    samples: Vec<f32>,
+    /// The sound as 16 kHz mono, for cancelling its echo from the microphone
+    reference: Vec<f32>,
+    /// Modification time of the file it was decoded from
+    modified: Option<SystemTime>,
+}
+
+static SOUND_CACHE: OnceLock<Mutex<HashMap<PathBuf, Arc<DecodedSound>>>> = OnceLock::new();
+static OUTPUT_ENGINE: OnceLock<OutputEngine> = OnceLock::new();
+static PLAYBACK_REFERENCE: OnceLock<PlaybackReference> = OnceLock::new();
+
+/// The engine all playback goes through. Its output stream stays open
+/// between sounds, so they start without opening the device first.
+pub fn output_engine() -> &'static OutputEngine {
+    OUTPUT_ENGINE.get_or_init(OutputEngine::new)
+}
+
+/// Feedback sounds as they play, for recorders to cancel their echo
+pub fn playback_reference() -> PlaybackReference {
+    PLAYBACK_REFERENCE
+        .get_or_init(PlaybackReference::new)
+        .clone()
 }
 
-fn get_sound_base_dir(settings: &AppSettings) -> tauri::path::BaseDirectory {
-    match settings.sound_theme {
-        SoundTheme::Custom => tauri::path::BaseDirectory::AppData,
-        _ => tauri::path::BaseDirectory::Resource,
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// The decoded sound at `path`, decoded on first use and again after the
+/// file changed (custom sounds can be replaced)
+fn decoded_sound(path: &Path) -> Result<Arc<DecodedSound>, Box<dyn std::error::Error>> {
+    let modified = modified_time(path);
+    let cache = SOUND_CACHE.get_or_init(Default::default);
+    if let Some(sound) = cache.lock().ok().and_then(|c| c.get(path).cloned()) {
+        if sound.modified == modified {
+            return Ok(sound);
+        }
+    }
+
+    let decoder = Decoder::new(BufReader::new(File::open(path)?))?;
+    let (channels, sample_rate) = (decoder.channels(), decoder.sample_rate());
+    let samples: Vec<f32> = decoder.collect();
+    let sound = Arc::new(DecodedSound {
+        channels,
+        sample_rate,
+        reference: reference_samples(channels, sample_rate, &samples),
+        samples,
+        modified,
+    });
+    if let Ok(mut cache) = cache.lock() {
+        cache.insert(path.to_path_buf(), sound.clone());
+    }
+    debug!("Decoded feedback sound '{}'", path.display());
+    Ok(sound)
+}
+
+fn reference_samples(channels: ChannelCount, sample_rate: SampleRate, samples: &[f32]) -> Vec<f32> {
+    let mono: Vec<f32> = samples
+        .chunks((channels as usize).max(1))
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect();
+    let mut resampler = FrameResampler::new(
+        sample_rate as usize,
+        WHISPER_SAMPLE_RATE as usize,
+        Duration::from_millis(30),
+    );
+    let mut reference = Vec::new();
+    resampler.push(&mono, |frame| reference.extend_from_slice(frame));
+    resampler.finish(|frame| reference.extend_from_slice(frame));
+    reference
+}
+
+/// Decode the feedback sounds and open their output stream ahead of the
+/// first recording, so starting one isn't delayed by either
+pub fn preload(app: &AppHandle) {
+    let app = app.clone();
+    thread::spawn(move || {
+        let settings = settings::get_settings(&app);
+        if !feedback_enabled(&app, &settings) {
+            return;
+        }
+        for sound_type in [SoundType::Start, SoundType::Stop] {
+            if let Some(path) = resolve_sound_path(&app, &settings, sound_type) {
+                if let Err(e) = decoded_sound(&path) {
+                    warn!("Failed to decode sound '{}': {}", path.display(), e);
+                }
+            }
+        }
+        output_engine().warm(output_device(&app, &settings));
+    });
+}
+
+/// Play a feedback sound, optionally waiting until it has finished
+fn play_sound(app: &AppHandle, path: &Path, wait: bool) {
+    let sound = match decoded_sound(path) {
+        Ok(sound) => sound,
+        Err(e) => {
+            error!("Failed to play sound '{}': {}", path.display(), e);
+            return;
+        }
+    };
+    let settings = settings::get_settings(app);
+    let volume = settings.audio_feedback_volume;
+    let playback = output_engine().play(
+        OutputChannel::Feedback,
+        OutputSound::Samples {
+            channels: sound.channels,
+            sample_rate: sound.sample_rate,
+            samples: sound.samples.clone(),
+        },
+        output_device(app, &settings),
+        volume,
+    );
+    playback_reference().push(&sound.reference, volume);
+    if wait {
+        playback.wait();
     }
 }
 
 pub fn play_feedback_sound(app: &AppHandle, sound_type: SoundType) {
     let settings = settings::get_settings(app);
-    if !settings.audio_feedback {
+    if !feedback_enabled(app, &settings) {
         return;
     }
     if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
-        play_sound_async(app, path);
+        play_sound(app, &path, false);
     }
 }
 
 pub fn play_feedback_sound_blocking(app: &AppHandle, sound_type: SoundType) {
     let settings = settings::get_settings(app);
-    if !settings.audio_feedback {
+    if !feedback_enabled(app, &settings) {
         return;
     }
     if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
-        play_sound_blocking(app, &path);
+        play_sound(app, &path, true);
     }
 }
 
 pub fn play_test_sound(app: &AppHandle, sound_type: SoundType) {
     let settings = settings::get_settings(app);
     if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
-        play_sound_blocking(app, &path);
+        play_sound(app, &path, true);
     }
 }
 
-fn play_sound_async(app: &AppHandle, path: PathBuf) {
-    let app_handle = app.clone();
-    thread::spawn(move || {
-        if let Err(e) = play_sound_at_path(&app_handle, path.as_path()) {
-            error!("Failed to play sound '{}': {}", path.display(), e);
-        }
-    });
-}
-
-fn play_sound_blocking(app: &AppHandle, path: &Path) {
-    if let Err(e) = play_sound_at_path(app, path) {
-        error!("Failed to play sound '{}': {}", path.display(), e);
+/// Play an alert for a routed warning, regardless of the audio feedback toggle
+pub fn play_alert_sound(app: &AppHandle) {
+    let settings = settings::get_settings(app);
+    if let Some(path) = resolve_sound_path(app, &settings, SoundType::Start) {
+        play_sound(app, &path, false);
     }
 }
 
-fn play_sound_at_path(app: &AppHandle, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
-    let settings = settings::get_settings(app);
-    let volume = settings.audio_feedback_volume;
-    let selected_device = settings.selected_output_device.clone();
-    play_audio_file(path, selected_device, volume)
-}
-
-fn play_audio_file(
-    path: &std::path::Path,
-    selected_device: Option<String>,
-    volume: f32,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let stream_builder = if let Some(device_name) = selected_device {
-        if device_name == "Default" {
-            debug!("Using default device");
-            OutputStreamBuilder::from_default_device()?
-        } else {
-            let host = crate::audio_toolkit::get_cpal_host();
-            let devices = host.output_devices()?;
-
-            let mut found_device = None;
-            for device in devices {
-                if device.name()? == device_name {
-                    found_device = Some(device);
-                    break;
-                }
-            }
-
-            match found_device {
-                Some(device) => OutputStreamBuilder::from_device(device)?,
-                None => {
-                    warn!("Device '{}' not found, using default device", device_name);
-                    OutputStreamBuilder::from_default_device()?
-                }
-            }
-        }
-    } else {
-        debug!("Using default device");
-        OutputStreamBuilder::from_default_device()?
-    };
-
-    let stream_handle = stream_builder.open_stream()?;
-    let mixer = stream_handle.mixer();
-
-    let file = File::open(path)?;
-    let buf_reader = BufReader::new(file);
-
-    let sink = rodio::play(mixer, buf_reader)?;
-    sink.set_volume(volume);
-    sink.sleep_until_end();
-
-    Ok(())
+/// Play a saved recording on the selected output device at full volume
+pub fn play_recording(app: &AppHandle, path: PathBuf) {
+    let selected_device = output_device(app, &settings::get_settings(app));
+    output_engine().play(
+        OutputChannel::Playback,
+        OutputSound::File(path),
+        selected_device,
+        1.0,
+    );
 }
@@ -1,7 +1,12 @@
 use cpal::traits::{DeviceTrait, HostTrait};
+use std::collections::HashMap;
 
 pub struct CpalDeviceInfo {
     pub index: String,
+    /// Stable identifier that survives renames and reconnects: the device
+    /// UID on macOS, the endpoint ID on Windows, the name elsewhere
+    pub id: String,
+    /// Display name, numbered when several devices share a name
     pub name: String,
     pub is_default: bool,
     pub device: cpal::Device,
@@ -10,43 +15,321 @@ pub struct CpalDeviceInfo {
 pub fn list_input_devices() -> Result<Vec<CpalDeviceInfo>, Box<dyn std::error::Error>> {
     let host = crate::audio_toolkit::get_cpal_host();
     let default_name = host.default_input_device().and_then(|d| d.name().ok());
+    let devices: Vec<cpal::Device> = host.input_devices()?.collect();
+    Ok(describe_devices(
+        devices,
+        default_name,
+        &platform_device_ids(true),
+    ))
+}
 
-    let mut out = Vec::<CpalDeviceInfo>::new();
+pub fn list_output_devices() -> Result<Vec<CpalDeviceInfo>, Box<dyn std::error::Error>> {
+    let host = crate::audio_toolkit::get_cpal_host();
+    let default_name = host.default_output_device().and_then(|d| d.name().ok());
+    let devices: Vec<cpal::Device> = host.output_devices()?.collect();
+    Ok(describe_devices(
+        devices,
+        default_name,
+        &platform_device_ids(false),
+    ))
+}
 
-    for (index, device) in host.input_devices()?.enumerate() {
-        let name = device.name().unwrap_or_else(|_| "Unknown".into());
+/// Find a device by its stable id, falling back to the name for selections
+/// saved before ids existed
+pub fn find_device(
+    mut devices: Vec<CpalDeviceInfo>,
+    id: Option<&str>,
+    name: Option<&str>,
+) -> Option<CpalDeviceInfo> {
+    let index = find_device_index(&devices, id, name)?;
+    Some(devices.swap_remove(index))
+}
 
-        let is_default = Some(name.clone()) == default_name;
+/// Position of a device in the list, matched like [`find_device`]
+pub fn find_device_index(
+    devices: &[CpalDeviceInfo],
+    id: Option<&str>,
+    name: Option<&str>,
+) -> Option<usize> {
+    device_position(
+        devices.iter().map(|d| (d.id.as_str(), d.name.as_str())),
+        id,
+        name,
+    )
+}
 
-        out.push(CpalDeviceInfo {
+fn device_position<'a>(
+    devices: impl Iterator<Item = (&'a str, &'a str)> + Clone,
+    id: Option<&str>,
+    name: Option<&str>,
+) -> Option<usize> {
+    id.and_then(|id| devices.clone().position(|(device_id, _)| device_id == id))
+        .or_else(|| name.and_then(|name| devices.clone().position(|(_, n)| n == name)))
+}
+
+fn describe_devices(
+    devices: Vec<cpal::Device>,
+    default_name: Option<String>,
+    platform_ids: &[(String, String)],
+) -> Vec<CpalDeviceInfo> {
+    let names: Vec<String> = devices
+        .iter()
+        .map(|d| d.name().unwrap_or_else(|_| "Unknown".into()))
+        .collect();
+    let identities = identify_devices(&names, platform_ids);
+
+    devices
+        .into_iter()
+        .zip(names)
+        .zip(identities)
+        .enumerate()
+        .map(|(index, ((device, raw_name), (id, name)))| CpalDeviceInfo {
             index: index.to_string(),
+            is_default: Some(&raw_name) == default_name.as_ref() && name == raw_name,
+            id,
             name,
-            is_default,
             device,
-        });
+        })
+        .collect()
+}
+
+/// Give each device an id and a unique display name. Devices that share a
+/// name are numbered in enumeration order ("USB Mic", "USB Mic (2)"), and
+/// take the platform id of the next unused platform device with that name.
+fn identify_devices(names: &[String], platform_ids: &[(String, String)]) -> Vec<(String, String)> {
+    let mut used = vec![false; platform_ids.len()];
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+
+    names
+        .iter()
+        .map(|name| {
+            let count = seen.entry(name.as_str()).or_insert(0);
+            *count += 1;
+            let label = if *count == 1 {
+                name.clone()
+            } else {
+                format!("{} ({})", name, count)
+            };
+
+            let platform_id = (0..platform_ids.len())
+                .find(|&i| !used[i] && platform_ids[i].0 == *name)
+                .map(|i| {
+                    used[i] = true;
+                    platform_ids[i].1.clone()
+                });
+
+            (platform_id.unwrap_or_else(|| label.clone()), label)
+        })
+        .collect()
+}
+
+/// `(name, id)` of every active endpoint, in enumeration order
+#[cfg(target_os = "windows")]
+fn platform_device_ids(input: bool) -> Vec<(String, String)> {
+    windows_endpoint_ids(input).unwrap_or_else(|e| {
+        log::debug!("Failed to read audio endpoint ids: {}", e);
+        Vec::new()
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn windows_endpoint_ids(input: bool) -> windows::core::Result<Vec<(String, String)>> {
+    use windows::Win32::{
+        Devices::FunctionDiscovery::PKEY_Device_FriendlyName,
+        Media::Audio::{
+            eCapture, eRender, IMMDeviceEnumerator, MMDeviceEnumerator, DEVICE_STATE_ACTIVE,
+        },
+        System::Com::{
+            CoCreateInstance, CoInitializeEx, CoTaskMemFree, StructuredStorage::STGM_READ,
+            CLSCTX_ALL, COINIT_MULTITHREADED,
+        },
+    };
+
+    let flow = if input { eCapture } else { eRender };
+    let mut out = Vec::new();
+
+    unsafe {
+        // Does nothing if COM is already initialized on this thread
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+
+        let enumerator: IMMDeviceEnumerator =
+            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
+        let collection = enumerator.EnumAudioEndpoints(flow, DEVICE_STATE_ACTIVE)?;
+
+        for i in 0..collection.GetCount()? {
+            let device = collection.Item(i)?;
+
+            let id_ptr = device.GetId()?;
+            let id = id_ptr.to_string();
+            CoTaskMemFree(Some(id_ptr.0 as *const _));
+            let Ok(id) = id else { continue };
+
+            // cpal names WASAPI devices by their friendly name
+            let store = device.OpenPropertyStore(STGM_READ)?;
+            let name = store.GetValue(&PKEY_Device_FriendlyName)?.to_string();
+            out.push((name, id));
+        }
     }
 
     Ok(out)
 }
 
-pub fn list_output_devices() -> Result<Vec<CpalDeviceInfo>, Box<dyn std::error::Error>> {
-    let host = crate::audio_toolkit::get_cpal_host();
-    let default_name = host.default_output_device().and_then(|d| d.name().ok());
+/// `(name, UID)` of every CoreAudio device with streams in the requested
+/// direction, in enumeration order
+#[cfg(target_os = "macos")]
+fn platform_device_ids(input: bool) -> Vec<(String, String)> {
+    use core_foundation::base::TCFType;
+    use core_foundation::string::CFString;
+    use coreaudio_sys::*;
+    use std::{mem, ptr};
 
-    let mut out = Vec::<CpalDeviceInfo>::new();
+    fn address(selector: u32, scope: u32) -> AudioObjectPropertyAddress {
+        AudioObjectPropertyAddress {
+            mSelector: selector,
+            mScope: scope,
+            mElement: kAudioObjectPropertyElementMaster,
+        }
+    }
 
-    for (index, device) in host.output_devices()?.enumerate() {
-        let name = device.name().unwrap_or_else(|_| "Unknown".into());
+    unsafe fn string_property(device: AudioObjectID, selector: u32) -> Option<String> {
+        let address = address(selector, kAudioObjectPropertyScopeGlobal);
+        let mut value: CFStringRef = ptr::null();
+        let mut size = mem::size_of::<CFStringRef>() as u32;
+        let status = AudioObjectGetPropertyData(
+            device,
+            &address,
+            0,
+            ptr::null(),
+            &mut size,
+            &mut value as *mut CFStringRef as *mut _,
+        );
+        if status != 0 || value.is_null() {
+            return None;
+        }
+        Some(CFString::wrap_under_create_rule(value as _).to_string())
+    }
 
-        let is_default = Some(name.clone()) == default_name;
+    let scope = if input {
+        kAudioDevicePropertyScopeInput
+    } else {
+        kAudioDevicePropertyScopeOutput
+    };
 
-        out.push(CpalDeviceInfo {
-            index: index.to_string(),
-            name,
-            is_default,
-            device,
-        });
+    unsafe {
+        let devices_address = address(
+            kAudioHardwarePropertyDevices,
+            kAudioObjectPropertyScopeGlobal,
+        );
+        let mut size = 0u32;
+        if AudioObjectGetPropertyDataSize(
+            kAudioObjectSystemObject,
+            &devices_address,
+            0,
+            ptr::null(),
+            &mut size,
+        ) != 0
+        {
+            return Vec::new();
+        }
+
+        let mut devices = vec![0 as AudioObjectID; size as usize / mem::size_of::<AudioObjectID>()];
+        if AudioObjectGetPropertyData(
+            kAudioObjectSystemObject,
+            &devices_address,
+            0,
+            ptr::null(),
+            &mut size,
+            devices.as_mut_ptr() as *mut _,
+        ) != 0
+        {
+            return Vec::new();
+        }
+
+        devices
+            .into_iter()
+            .filter(|&device| {
+                let streams = address(kAudioDevicePropertyStreams, scope);
+                let mut size = 0u32;
+                AudioObjectGetPropertyDataSize(device, &streams, 0, ptr::null(), &mut size) == 0
+                    && size > 0
+            })
+            .filter_map(|device| {
+                Some((
+                    string_property(device, kAudioObjectPropertyName)?,
+                    string_property(device, kAudioDevicePropertyDeviceUID)?,
+                ))
+            })
+            .collect()
     }
+}
 
-    Ok(out)
+/// ALSA device names are already stable, so the name doubles as the id
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn platform_device_ids(_input: bool) -> Vec<(String, String)> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_identical_devices_get_distinct_names_and_ids() {
+        let platform_ids = vec![
+            ("USB Mic".to_string(), "uid-a".to_string()),
+            ("Built-in".to_string(), "uid-b".to_string()),
+            ("USB Mic".to_string(), "uid-c".to_string()),
+        ];
+        let identities =
+            identify_devices(&names(&["USB Mic", "Built-in", "USB Mic"]), &platform_ids);
+
+        assert_eq!(
+            identities,
+            vec![
+                ("uid-a".to_string(), "USB Mic".to_string()),
+                ("uid-b".to_string(), "Built-in".to_string()),
+                ("uid-c".to_string(), "USB Mic (2)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ids_fall_back_to_display_names() {
+        let identities = identify_devices(&names(&["hw:0", "hw:0"]), &[]);
+        assert_eq!(
+            identities,
+            vec![
+                ("hw:0".to_string(), "hw:0".to_string()),
+                ("hw:0 (2)".to_string(), "hw:0 (2)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_device_lookup_prefers_id_over_name() {
+        let devices = [("uid-a", "USB Mic"), ("uid-b", "Micrófono")];
+
+        // The id still matches after the name was localized
+        assert_eq!(
+            device_position(devices.iter().copied(), Some("uid-b"), Some("Microphone")),
+            Some(1)
+        );
+        // Selections saved before ids existed match by name
+        assert_eq!(
+            device_position(devices.iter().copied(), None, Some("USB Mic")),
+            Some(0)
+        );
+        assert_eq!(
+            device_position(devices.iter().copied(), Some("uid-x"), Some("USB Mic")),
+            Some(0)
+        );
+        assert_eq!(
+            device_position(devices.iter().copied(), Some("uid-x"), None),
+            None
+        );
+    }
 }
@@ -0,0 +1,240 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+
+/// Length of the echo path the canceller models (output latency, room
+/// reflections and input latency together)
+const TAIL: Duration = Duration::from_millis(300);
+
+/// Furthest the reference is kept ahead of the capture. Older reference
+/// audio was played while nothing was captured, e.g. before the stream
+/// opened, and is dropped.
+const MAX_LEAD: Duration = Duration::from_millis(150);
+
+/// NLMS step size
+const STEP: f32 = 0.3;
+
+const SAMPLE_RATE: f32 = WHISPER_SAMPLE_RATE as f32;
+
+fn samples_in(duration: Duration) -> usize {
+    (duration.as_secs_f32() * SAMPLE_RATE) as usize
+}
+
+#[derive(Default)]
+struct ReferenceQueue {
+    samples: VecDeque<f32>,
+    /// When the first queued sample was played
+    front_at: Option<Instant>,
+}
+
+/// What the app is playing, shared with the capture path as the echo
+/// reference.
+///
+/// Sounds are pushed as 16 kHz mono when they start playing and taken by the
+/// recorder as it captures, so the reference runs alongside the microphone.
+/// Overlapping sounds are mixed.
+#[derive(Clone, Default)]
+pub struct PlaybackReference {
+    queue: Arc<Mutex<ReferenceQueue>>,
+}
+
+impl PlaybackReference {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a sound that starts playing now
+    pub fn push(&self, samples: &[f32], gain: f32) {
+        self.push_at(samples, gain, Instant::now());
+    }
+
+    fn push_at(&self, samples: &[f32], gain: f32, now: Instant) {
+        let Ok(mut queue) = self.queue.lock() else {
+            return;
+        };
+        let offset = match queue.front_at {
+            Some(front_at) if !queue.samples.is_empty() => {
+                samples_in(now.saturating_duration_since(front_at))
+            }
+            _ => {
+                queue.samples.clear();
+                queue.front_at = Some(now);
+                0
+            }
+        };
+        if queue.samples.len() < offset {
+            queue.samples.resize(offset, 0.0);
+        }
+        for (i, sample) in samples.iter().enumerate() {
+            match queue.samples.get_mut(offset + i) {
+                Some(queued) => *queued += sample * gain,
+                None => queue.samples.push_back(sample * gain),
+            }
+        }
+    }
+
+    /// Reference for `len` newly captured samples, or `None` when nothing
+    /// is playing
+    pub fn take(&self, len: usize) -> Option<Vec<f32>> {
+        self.take_at(len, Instant::now())
+    }
+
+    fn take_at(&self, len: usize, now: Instant) -> Option<Vec<f32>> {
+        let mut queue = self.queue.lock().ok()?;
+        let front_at = queue.front_at?;
+        if queue.samples.is_empty() {
+            return None;
+        }
+
+        let stale = samples_in(now.saturating_duration_since(front_at))
+            .saturating_sub(samples_in(MAX_LEAD) + len);
+        let stale = stale.min(queue.samples.len());
+        queue.samples.drain(..stale);
+        if queue.samples.is_empty() {
+            return None;
+        }
+
+        let taken = len.min(queue.samples.len());
+        let mut reference: Vec<f32> = queue.samples.drain(..taken).collect();
+        reference.resize(len, 0.0);
+        queue.front_at =
+            Some(front_at + Duration::from_secs_f32((stale + taken) as f32 / SAMPLE_RATE));
+        Some(reference)
+    }
+}
+
+/// Adaptive (NLMS) echo canceller for 16 kHz capture.
+///
+/// Learns how the app's own playback reaches the microphone and subtracts
+/// its estimate of that echo from the capture. It only runs while there is
+/// a reference, plus one tail length for the last echoes to die out.
+pub struct EchoCanceller {
+    weights: Vec<f32>,
+    /// Recent reference samples, stored twice so the newest `taps` are
+    /// always one contiguous slice
+    history: Vec<f32>,
+    position: usize,
+    power: f32,
+    /// Samples left to process after the reference ended
+    remaining: usize,
+}
+
+impl EchoCanceller {
+    pub fn new() -> Self {
+        let taps = samples_in(TAIL);
+        Self {
+            weights: vec![0.0; taps],
+            history: vec![0.0; taps * 2],
+            position: 0,
+            power: 0.0,
+            remaining: 0,
+        }
+    }
+
+    /// Remove the echo of `reference` from `capture`. Both cover the same
+    /// samples; `None` means nothing is playing.
+    pub fn process(&mut self, capture: &mut [f32], reference: Option<&[f32]>) {
+        let taps = self.weights.len();
+        if reference.is_some() {
+            self.remaining = taps;
+        } else if self.remaining == 0 {
+            return;
+        } else {
+            self.remaining = self.remaining.saturating_sub(capture.len());
+        }
+
+        for (i, sample) in capture.iter_mut().enumerate() {
+            let played = reference.and_then(|r| r.get(i)).copied().unwrap_or(0.0);
+
+            // Newest reference sample first
+            let oldest = self.history[self.position + taps - 1];
+            self.position = (self.position + taps - 1) % taps;
+            self.history[self.position] = played;
+            self.history[self.position + taps] = played;
+            self.power = (self.power + played * played - oldest * oldest).max(0.0);
+
+            let recent = &self.history[self.position..self.position + taps];
+            let echo: f32 = self.weights.iter().zip(recent).map(|(w, x)| w * x).sum();
+            let error = *sample - echo;
+            *sample = error;
+
+            let step = STEP * error / (self.power + 1e-3);
+            for (weight, x) in self.weights.iter_mut().zip(recent) {
+                *weight += step * x;
+            }
+        }
+    }
+}
+
+impl Default for EchoCanceller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise(len: usize) -> Vec<f32> {
+        let mut state = 12345u32;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (state >> 16) as f32 / 32_768.0 - 1.0
+            })
+            .map(|s| s * 0.3)
+            .collect()
+    }
+
+    fn energy(samples: &[f32]) -> f32 {
+        samples.iter().map(|s| s * s).sum()
+    }
+
+    #[test]
+    fn test_removes_delayed_echo() {
+        let played = noise(48_000);
+        let delay = 800;
+        let mut capture: Vec<f32> = (0..played.len())
+            .map(|i| i.checked_sub(delay).map_or(0.0, |j| played[j] * 0.5))
+            .collect();
+        let echo_energy = energy(&capture[40_000..]);
+
+        let mut canceller = EchoCanceller::new();
+        for (frame, reference) in capture.chunks_mut(480).zip(played.chunks(480)) {
+            canceller.process(frame, Some(reference));
+        }
+        assert!(energy(&capture[40_000..]) < echo_energy * 0.05);
+
+        // Idle once the tail has passed
+        let mut silence = vec![0.1; 8_000];
+        canceller.process(&mut silence, None);
+        let mut quiet = vec![0.1; 480];
+        canceller.process(&mut quiet, None);
+        assert_eq!(quiet, vec![0.1; 480]);
+    }
+
+    #[test]
+    fn test_reference_mixes_overlaps_and_drops_stale_audio() {
+        let reference = PlaybackReference::new();
+        let start = Instant::now();
+        assert!(reference.take_at(4, start).is_none());
+
+        reference.push_at(&[1.0; 8], 1.0, start);
+        reference.push_at(&[1.0; 4], 0.5, start);
+        assert_eq!(
+            reference.take_at(6, start),
+            Some(vec![1.5, 1.5, 1.5, 1.5, 1.0, 1.0])
+        );
+        assert_eq!(reference.take_at(4, start), Some(vec![1.0, 1.0, 0.0, 0.0]));
+        assert!(reference.take_at(4, start).is_none());
+
+        // Nothing captured for a second: the sound is long over
+        reference.push_at(&[1.0; 8], 1.0, start);
+        assert!(reference
+            .take_at(4, start + Duration::from_secs(1))
+            .is_none());
+    }
+}
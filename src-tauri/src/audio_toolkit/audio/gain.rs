@@ -0,0 +1,146 @@
+/// Samples per block the level is measured over (30 ms at 16 kHz)
+const BLOCK: usize = 480;
+
+/// Blocks more than this far below the loudest one (20 dB) are treated as
+/// pauses and left out of the level, so silence doesn't drag it down
+const ACTIVE_BLOCK_RATIO: f32 = 0.1;
+
+/// Below this level the recording is silence, which is left alone rather
+/// than amplified into noise
+const SILENCE_RMS: f32 = 1e-4;
+
+/// Highest peak the gain may produce, just under full scale
+const PEAK_CEILING: f32 = 0.99;
+
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
+/// Convert a level in dBFS to linear RMS
+pub fn dbfs_to_rms(dbfs: f32) -> f32 {
+    10f32.powf(dbfs / 20.0)
+}
+
+/// Scales a recording so its speech sits at a target RMS level.
+///
+/// The level is measured over the active parts only, and the gain is capped
+/// so the loudest peak stays below full scale.
+#[derive(Debug, Clone, Copy)]
+pub struct GainNormalizer {
+    target_rms: f32,
+    max_gain: f32,
+}
+
+impl GainNormalizer {
+    /// Normalize to `target_dbfs`, boosting by at most 30 dB
+    pub fn new(target_dbfs: f32) -> Self {
+        Self {
+            target_rms: dbfs_to_rms(target_dbfs),
+            max_gain: dbfs_to_rms(30.0),
+        }
+    }
+
+    /// Limit how much quiet audio is boosted, in dB
+    pub fn with_max_gain_db(mut self, max_gain_db: f32) -> Self {
+        self.max_gain = dbfs_to_rms(max_gain_db);
+        self
+    }
+
+    /// Level of the active parts of `samples`
+    fn active_rms(samples: &[f32]) -> f32 {
+        let blocks: Vec<f32> = samples.chunks(BLOCK).map(rms).collect();
+        let loudest = blocks.iter().copied().fold(0.0, f32::max);
+        let active: Vec<f32> = blocks
+            .into_iter()
+            .filter(|&block| block >= loudest * ACTIVE_BLOCK_RATIO)
+            .collect();
+        if active.is_empty() {
+            return 0.0;
+        }
+        (active.iter().map(|block| block * block).sum::<f32>() / active.len() as f32).sqrt()
+    }
+
+    /// The gain `apply` would use on `samples`
+    pub fn gain_for(&self, samples: &[f32]) -> f32 {
+        let level = Self::active_rms(samples);
+        if level < SILENCE_RMS {
+            return 1.0;
+        }
+        let peak = samples.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+        let headroom = if peak > 0.0 {
+            PEAK_CEILING / peak
+        } else {
+            f32::MAX
+        };
+        (self.target_rms / level).min(self.max_gain).min(headroom)
+    }
+
+    /// Scale `samples` in place and return the gain that was applied
+    pub fn apply(&self, samples: &mut [f32]) -> f32 {
+        let gain = self.gain_for(samples);
+        if gain != 1.0 {
+            for sample in samples.iter_mut() {
+                *sample *= gain;
+            }
+        }
+        gain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(len: usize, amplitude: f32) -> Vec<f32> {
+        (0..len)
+            .map(|i| (i as f32 * 0.05).sin() * amplitude)
+            .collect()
+    }
+
+    #[test]
+    fn test_quiet_speech_is_raised_to_the_target() {
+        let mut samples = tone(16_000, 0.01);
+        GainNormalizer::new(-20.0).apply(&mut samples);
+
+        let level = rms(&samples);
+        assert!(
+            (level - dbfs_to_rms(-20.0)).abs() < 0.005,
+            "level {}",
+            level
+        );
+    }
+
+    #[test]
+    fn test_pauses_do_not_count_towards_the_level() {
+        let mut samples = tone(8_000, 0.01);
+        samples.extend(vec![0.0; 40_000]);
+        GainNormalizer::new(-20.0).apply(&mut samples);
+
+        assert!((rms(&samples[..8_000]) - dbfs_to_rms(-20.0)).abs() < 0.005);
+    }
+
+    #[test]
+    fn test_peaks_are_kept_below_full_scale() {
+        let mut samples = tone(16_000, 0.01);
+        samples[100] = 0.5;
+        let gain = GainNormalizer::new(-10.0).apply(&mut samples);
+
+        assert!(gain <= PEAK_CEILING / 0.5 + f32::EPSILON);
+        assert!(samples.iter().all(|s| s.abs() <= PEAK_CEILING + 1e-6));
+    }
+
+    #[test]
+    fn test_silence_and_boost_limit() {
+        let mut silence = vec![0.0; 16_000];
+        assert_eq!(GainNormalizer::new(-20.0).apply(&mut silence), 1.0);
+
+        let mut faint = tone(16_000, 0.001);
+        let gain = GainNormalizer::new(-20.0)
+            .with_max_gain_db(12.0)
+            .apply(&mut faint);
+        assert!((gain - dbfs_to_rms(12.0)).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,78 @@
+/// How long the input must stay quiet before the gate closes again
+const HOLD_SECS: f32 = 1.0;
+
+/// Cheap energy gate for an open stream that isn't recording.
+///
+/// With the microphone always on, resampling and spectrum analysis would
+/// otherwise run on every buffer of room noise. The gate opens when a
+/// buffer's RMS reaches the wake threshold and closes after `HOLD_SECS` of
+/// quiet, so the full pipeline only runs while there's sound to process.
+pub struct IdleGate {
+    threshold: f32,
+    hold_samples: usize,
+    quiet_samples: usize,
+    open: bool,
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
+impl IdleGate {
+    pub fn new(sample_rate: usize, threshold: f32) -> Self {
+        Self {
+            threshold,
+            hold_samples: (sample_rate as f32 * HOLD_SECS) as usize,
+            quiet_samples: 0,
+            open: false,
+        }
+    }
+
+    /// Feed a buffer; returns whether it should go through the full pipeline
+    pub fn push(&mut self, samples: &[f32]) -> bool {
+        if rms(samples) >= self.threshold {
+            self.open = true;
+            self.quiet_samples = 0;
+        } else if self.open {
+            self.quiet_samples += samples.len();
+            if self.quiet_samples >= self.hold_samples {
+                self.open = false;
+            }
+        }
+        self.open
+    }
+
+    pub fn reset(&mut self) {
+        self.quiet_samples = 0;
+        self.open = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opens_on_sound_and_closes_after_hold() {
+        let mut gate = IdleGate::new(100, 0.01);
+        let quiet = [0.001f32; 40];
+        let loud = [0.1f32; 40];
+
+        assert!(!gate.push(&quiet));
+        assert!(gate.push(&loud));
+        // Held open through short pauses
+        assert!(gate.push(&quiet));
+        assert!(gate.push(&quiet));
+        assert!(gate.push(&loud));
+        assert!(gate.push(&quiet));
+        assert!(gate.push(&quiet));
+        assert!(!gate.push(&quiet));
+
+        gate.push(&loud);
+        gate.reset();
+        assert!(!gate.push(&quiet));
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::VecDeque;
+
+/// Rolling buffer of the most recent frames heard while not recording.
+///
+/// With retroactive capture on, the open stream keeps its last few seconds
+/// of audio here, and a recording starts with them so words spoken just
+/// before the shortcut aren't lost. Whole frames are kept so they can go
+/// through the VAD like any other frame once the recording starts.
+pub struct LookbackBuffer {
+    frames: VecDeque<Vec<f32>>,
+    len: usize,
+    capacity: usize,
+}
+
+impl LookbackBuffer {
+    /// A buffer holding up to `capacity` samples
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            frames: VecDeque::new(),
+            len: 0,
+            capacity,
+        }
+    }
+
+    pub fn push(&mut self, frame: &[f32]) {
+        self.frames.push_back(frame.to_vec());
+        self.len += frame.len();
+        while self.len > self.capacity {
+            match self.frames.pop_front() {
+                Some(oldest) => self.len -= oldest.len(),
+                None => break,
+            }
+        }
+    }
+
+    /// Take the buffered frames, oldest first, leaving the buffer empty
+    pub fn take(&mut self) -> Vec<Vec<f32>> {
+        self.len = 0;
+        self.frames.drain(..).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_only_the_most_recent_frames() {
+        let mut buffer = LookbackBuffer::new(8);
+        for value in 0..5 {
+            buffer.push(&[value as f32; 3]);
+        }
+
+        let frames = buffer.take();
+        assert_eq!(frames, vec![vec![3.0; 3], vec![4.0; 3]]);
+        assert!(buffer.take().is_empty());
+    }
+}
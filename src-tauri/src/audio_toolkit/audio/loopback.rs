@@ -31,7 +31,7 @@ use std::sync::{
 
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 
-use crate::audio_toolkit::constants;
+use crate::audio_toolkit::{audio::NoiseSuppressor, constants};
 
 /// Information about a loopback capture device
 #[derive(Clone)]
@@ -113,6 +113,8 @@ pub struct LoopbackCapture {
     stream: Mutex<Option<cpal::Stream>>,
     is_running: AtomicBool,
     callback: Mutex<Option<LoopbackCallback>>,
+    /// Denoise the captured audio before it reaches the callback
+    noise_suppression: bool,
 }
 
 impl LoopbackCapture {
@@ -249,6 +251,7 @@ impl LoopbackCapture {
             stream: Mutex::new(None),
             is_running: AtomicBool::new(false),
             callback: Mutex::new(None),
+            noise_suppression: false,
         })
     }
 
@@ -265,9 +268,16 @@ impl LoopbackCapture {
             stream: Mutex::new(None),
             is_running: AtomicBool::new(false),
             callback: Mutex::new(None),
+            noise_suppression: false,
         })
     }
 
+    /// Suppress background noise in the captured audio
+    pub fn with_noise_suppression(mut self) -> Self {
+        self.noise_suppression = true;
+        self
+    }
+
     /// Start capturing audio with the given callback
     pub fn start<F>(&self, callback: F) -> Result<(), LoopbackError>
     where
@@ -339,6 +349,9 @@ impl LoopbackCapture {
         );
 
         let callback_clone = callback.clone();
+        let mut suppressor = self
+            .noise_suppression
+            .then(|| NoiseSuppressor::new(std::time::Duration::from_millis(30)));
 
         let stream_callback = move |data: &[f32], _: &cpal::InputCallbackInfo| {
             // Convert to mono if needed
@@ -351,8 +364,9 @@ impl LoopbackCapture {
             };
 
             // Resample and forward to callback
-            resampler.push(&mono_samples, &mut |frame: &[f32]| {
-                callback_clone(frame);
+            resampler.push(&mono_samples, &mut |frame: &[f32]| match &mut suppressor {
+                Some(suppressor) => suppressor.process(frame, &mut |frame| callback_clone(frame)),
+                None => callback_clone(frame),
             });
         };
 
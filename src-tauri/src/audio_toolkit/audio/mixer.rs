@@ -8,6 +8,19 @@
 //! - Mix ratio control (0.0 = source A only, 1.0 = source B only)
 //! - Automatic level normalization to prevent clipping
 //! - Thread-safe buffer management
+//! - Timestamp alignment and drift compensation between the two sources
+//!
+//! ## Drift compensation
+//!
+//! Microphone and loopback devices run on separate clocks, so over a long
+//! call one stream delivers slightly more samples than the other and the
+//! two drift apart. When samples are pushed with capture timestamps
+//! (`push_mic_at` / `push_system_at`), the microphone is treated as the
+//! reference and the system stream is resampled by a ratio that is
+//! continuously nudged to keep both on the same timeline. Offsets larger
+//! than [`HARD_SYNC_THRESHOLD_SECS`], such as the gap before the second
+//! stream starts or after a dropout, are closed at once with silence or by
+//! skipping samples.
 //!
 //! ## Usage
 //!
@@ -26,6 +39,7 @@
 
 use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 /// Default buffer size in samples (roughly 100ms at 16kHz)
 const DEFAULT_BUFFER_SIZE: usize = 1600;
@@ -33,6 +47,97 @@ const DEFAULT_BUFFER_SIZE: usize = 1600;
 /// Maximum buffer size to prevent memory issues (roughly 5 seconds at 16kHz)
 const MAX_BUFFER_SIZE: usize = 80000;
 
+/// Sample rate both sources are expected to be delivered at
+const DEFAULT_SAMPLE_RATE: u32 = 16000;
+
+/// Offsets above this are corrected at once instead of by resampling
+pub const HARD_SYNC_THRESHOLD_SECS: f64 = 0.05;
+
+/// Largest resampling adjustment, as a fraction of the nominal rate.
+/// Real clock mismatches are well under 0.1%.
+const MAX_RATIO_ADJUSTMENT: f64 = 0.005;
+
+/// Ratio adjustment per second of offset
+const DRIFT_GAIN: f64 = 0.5;
+
+/// Ratio adjustment per second of offset accumulated over a second. This
+/// is what settles on the actual clock mismatch, so no offset remains.
+const DRIFT_INTEGRAL_GAIN: f64 = 0.05;
+
+/// Weight of each new timestamp in the smoothed clock offsets, to average
+/// out callback jitter
+const OFFSET_SMOOTHING: f64 = 0.05;
+
+/// How far a source's timestamps have run ahead of its sample count, i.e.
+/// capture time minus the time its samples cover
+#[derive(Debug, Clone, Copy)]
+struct ClockOffset {
+    smoothed_secs: f64,
+}
+
+impl ClockOffset {
+    fn update(current: Option<Self>, offset_secs: f64) -> Self {
+        let smoothed_secs = match current {
+            Some(current) => {
+                current.smoothed_secs + OFFSET_SMOOTHING * (offset_secs - current.smoothed_secs)
+            }
+            None => offset_secs,
+        };
+        Self { smoothed_secs }
+    }
+}
+
+/// Linear-interpolation resampler whose ratio can change between chunks
+#[derive(Debug, Clone)]
+struct DriftResampler {
+    /// Output samples per input sample
+    ratio: f64,
+    /// Position of the next output sample, relative to `last`
+    phase: f64,
+    /// Final sample of the previous chunk
+    last: f32,
+}
+
+impl DriftResampler {
+    fn new() -> Self {
+        Self {
+            ratio: 1.0,
+            phase: 0.0,
+            last: 0.0,
+        }
+    }
+
+    fn process(&mut self, input: &[f32], output: &mut VecDeque<f32>) {
+        if input.is_empty() {
+            return;
+        }
+
+        let step = 1.0 / self.ratio;
+        let end = (input.len() - 1) as f64;
+        // Input index -1 is the last sample of the previous chunk
+        let sample_at = |i: isize| -> f32 {
+            if i < 0 {
+                self.last
+            } else {
+                input[i as usize]
+            }
+        };
+
+        let mut position = self.phase - 1.0;
+        while position < end {
+            let index = position.floor();
+            let frac = (position - index) as f32;
+            let a = sample_at(index as isize);
+            let b = sample_at(index as isize + 1);
+            output.push_back(a + (b - a) * frac);
+            position += step;
+        }
+
+        self.phase = position - end;
+        self.last = input[input.len() - 1];
+    }
+}
+
 /// Audio mixer for combining two audio sources
 pub struct AudioMixer {
     /// Buffer for microphone audio samples
@@ -43,6 +148,18 @@ pub struct AudioMixer {
     mix_ratio: f32,
     /// Whether to normalize output to prevent clipping
     normalize: bool,
+    /// Sample rate of both sources
+    sample_rate: u32,
+    /// Microphone samples received since timestamps started arriving
+    mic_received: u64,
+    /// System samples written to the buffer, after resampling and syncing
+    system_written: u64,
+    mic_offset: Option<ClockOffset>,
+    system_offset: Option<ClockOffset>,
+    /// Resampler that keeps the system stream on the microphone's clock
+    system_resampler: DriftResampler,
+    /// Offset between the streams integrated over time, in seconds squared
+    drift_integral: f64,
 }
 
 impl AudioMixer {
@@ -57,9 +174,21 @@ impl AudioMixer {
             system_buffer: VecDeque::with_capacity(DEFAULT_BUFFER_SIZE),
             mix_ratio: mix_ratio.clamp(0.0, 1.0),
             normalize: true,
+            sample_rate: DEFAULT_SAMPLE_RATE,
+            mic_received: 0,
+            system_written: 0,
+            mic_offset: None,
+            system_offset: None,
+            system_resampler: DriftResampler::new(),
+            drift_integral: 0.0,
         }
     }
 
+    /// Set the sample rate used to turn timestamps into sample counts
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.sample_rate = sample_rate.max(1);
+    }
+
     /// Set the mix ratio
     ///
     /// # Arguments
@@ -103,6 +232,85 @@ impl AudioMixer {
         }
     }
 
+    /// Push microphone samples along with the capture time of the first one.
+    /// Timestamps from both sources must come from the same clock, e.g. the
+    /// time since recording started.
+    pub fn push_mic_at(&mut self, samples: &[f32], captured_at: Duration) {
+        let offset = captured_at.as_secs_f64() - self.samples_to_secs(self.mic_received);
+        self.mic_offset = Some(ClockOffset::update(self.mic_offset, offset));
+        self.mic_received += samples.len() as u64;
+        self.push_mic(samples);
+    }
+
+    /// Push system audio samples along with the capture time of the first
+    /// one. The samples are aligned to the microphone's timeline.
+    pub fn push_system_at(&mut self, samples: &[f32], captured_at: Duration) {
+        let offset = captured_at.as_secs_f64() - self.samples_to_secs(self.system_written);
+        let system_offset = ClockOffset::update(self.system_offset, offset);
+        self.system_offset = Some(system_offset);
+
+        let Some(mic_offset) = self.mic_offset else {
+            // Nothing to align to yet
+            self.write_system(samples);
+            return;
+        };
+
+        // Positive when the system stream is behind the microphone
+        let lag = system_offset.smoothed_secs - mic_offset.smoothed_secs;
+        let mut skip = 0;
+        if lag.abs() > HARD_SYNC_THRESHOLD_SECS {
+            skip = self.hard_sync_system(offset - mic_offset.smoothed_secs);
+            self.system_offset = Some(ClockOffset {
+                smoothed_secs: mic_offset.smoothed_secs,
+            });
+        } else {
+            let max_integral = MAX_RATIO_ADJUSTMENT / DRIFT_INTEGRAL_GAIN;
+            self.drift_integral = (self.drift_integral
+                + lag * self.samples_to_secs(samples.len() as u64))
+            .clamp(-max_integral, max_integral);
+            let adjustment = (lag * DRIFT_GAIN + self.drift_integral * DRIFT_INTEGRAL_GAIN)
+                .clamp(-MAX_RATIO_ADJUSTMENT, MAX_RATIO_ADJUSTMENT);
+            self.system_resampler.ratio = 1.0 + adjustment;
+        }
+
+        let samples = &samples[skip.min(samples.len())..];
+        let mut resampled = VecDeque::with_capacity(samples.len() + 8);
+        self.system_resampler.process(samples, &mut resampled);
+        let resampled: Vec<f32> = resampled.into();
+        self.write_system(&resampled);
+    }
+
+    /// Current resampling ratio applied to the system stream
+    pub fn drift_ratio(&self) -> f64 {
+        self.system_resampler.ratio
+    }
+
+    fn samples_to_secs(&self, samples: u64) -> f64 {
+        samples as f64 / self.sample_rate as f64
+    }
+
+    fn write_system(&mut self, samples: &[f32]) {
+        self.system_written += samples.len() as u64;
+        self.push_system(samples);
+    }
+
+    /// Close a large gap between the streams: pad the system stream with
+    /// silence when it is behind, or drop its oldest samples when ahead.
+    /// Returns how many incoming samples to skip when the buffer alone
+    /// can't make up the difference.
+    fn hard_sync_system(&mut self, lag_secs: f64) -> usize {
+        let samples = (lag_secs.abs() * self.sample_rate as f64).round() as usize;
+        if lag_secs > 0.0 {
+            self.write_system(&vec![0.0; samples]);
+            0
+        } else {
+            let dropped = samples.min(self.system_buffer.len());
+            self.system_buffer.drain(..dropped);
+            self.system_written = self.system_written.saturating_sub(dropped as u64);
+            samples - dropped
+        }
+    }
+
     /// Get the number of samples available for mixing
     ///
     /// Returns the minimum of both buffer sizes, since we need samples from both
@@ -164,10 +372,16 @@ impl AudioMixer {
         output
     }
 
-    /// Clear all buffers
+    /// Clear all buffers and forget the stream timelines
     pub fn clear(&mut self) {
         self.mic_buffer.clear();
         self.system_buffer.clear();
+        self.mic_received = 0;
+        self.system_written = 0;
+        self.mic_offset = None;
+        self.system_offset = None;
+        self.system_resampler = DriftResampler::new();
+        self.drift_integral = 0.0;
     }
 
     /// Normalize samples to prevent clipping
@@ -218,6 +432,20 @@ impl SharedAudioMixer {
         }
     }
 
+    /// Push timestamped microphone samples (thread-safe)
+    pub fn push_mic_at(&self, samples: &[f32], captured_at: Duration) {
+        if let Ok(mut mixer) = self.inner.lock() {
+            mixer.push_mic_at(samples, captured_at);
+        }
+    }
+
+    /// Push timestamped system audio samples (thread-safe)
+    pub fn push_system_at(&self, samples: &[f32], captured_at: Duration) {
+        if let Ok(mut mixer) = self.inner.lock() {
+            mixer.push_system_at(samples, captured_at);
+        }
+    }
+
     /// Set mix ratio (thread-safe)
     pub fn set_mix_ratio(&self, ratio: f32) {
         if let Ok(mut mixer) = self.inner.lock() {
@@ -395,6 +623,84 @@ mod tests {
         assert_eq!(mixer.system_buffer.len(), 0);
     }
 
+    /// Push `secs` of audio from both sources in 10ms chunks, with the
+    /// system device's clock running `system_rate_error` fast
+    fn push_with_drift(mixer: &mut AudioMixer, secs: u64, system_rate_error: f64) {
+        let chunk = 160;
+        let mut system_due = 0.0;
+        let mut system_pushed = 0;
+        for i in 0..secs * 100 {
+            let t = Duration::from_millis(i * 10);
+            mixer.push_mic_at(&vec![0.1; chunk], t);
+
+            // The system device delivers samples in whole chunks at its own rate
+            system_due += chunk as f64 * (1.0 + system_rate_error);
+            let system_len = system_due.round() as usize - system_pushed;
+            system_pushed += system_len;
+            mixer.push_system_at(&vec![0.1; system_len], t);
+            mixer.mix();
+        }
+    }
+
+    #[test]
+    fn test_resampler_ratio() {
+        let input = vec![0.5; 10_000];
+
+        let mut unity = DriftResampler::new();
+        let mut output = VecDeque::new();
+        unity.process(&input, &mut output);
+        assert_eq!(output.len(), 10_000);
+
+        let mut faster = DriftResampler::new();
+        faster.ratio = 1.01;
+        let mut output = VecDeque::new();
+        faster.process(&input[..5_000], &mut output);
+        faster.process(&input[5_000..], &mut output);
+        assert!((output.len() as i64 - 10_100).abs() <= 1);
+    }
+
+    #[test]
+    fn test_late_system_stream_is_padded_with_silence() {
+        let mut mixer = AudioMixer::new(1.0);
+        mixer.push_mic_at(&[0.5; 1600], Duration::ZERO);
+        // System audio starts 100ms (1600 samples) after the microphone
+        mixer.push_system_at(&[0.3; 1600], Duration::from_millis(100));
+
+        assert_eq!(mixer.system_buffer.len(), 3200);
+        assert!(mixer.system_buffer.iter().take(1600).all(|s| *s == 0.0));
+    }
+
+    #[test]
+    fn test_early_system_samples_are_dropped() {
+        let mut mixer = AudioMixer::new(0.5);
+        mixer.push_system_at(&[0.3; 1600], Duration::ZERO);
+        mixer.push_mic_at(&[0.5; 1600], Duration::from_millis(100));
+        mixer.push_system_at(&[0.3; 1600], Duration::from_millis(100));
+
+        // The first 100ms of system audio predates the microphone
+        assert_eq!(mixer.system_buffer.len(), 1600);
+    }
+
+    #[test]
+    fn test_drift_is_compensated() {
+        // A 0.05% clock mismatch adds up to about 1s of drift per half hour
+        let mut mixer = AudioMixer::new(0.5);
+        push_with_drift(&mut mixer, 120, -0.0005);
+
+        assert!(mixer.drift_ratio() > 1.0);
+        let offset_samples = mixer.mic_received as i64 - mixer.system_written as i64;
+        // Uncompensated, the streams would be 96 samples (6ms) apart
+        assert!(offset_samples.abs() < 8, "offset {}", offset_samples);
+    }
+
+    #[test]
+    fn test_untimestamped_pushes_are_not_resampled() {
+        let mut mixer = AudioMixer::new(0.5);
+        mixer.push_system(&[0.3; 100]);
+        assert_eq!(mixer.system_buffer.len(), 100);
+        assert_eq!(mixer.drift_ratio(), 1.0);
+    }
+
     #[test]
     fn test_shared_mixer() {
         let mixer = SharedAudioMixer::new(0.5);
@@ -1,16 +1,34 @@
 // Re-export all audio components
 mod device;
+mod echo_canceller;
+mod gain;
+mod idle_gate;
+mod lookback;
 pub mod loopback;
 pub mod mixer;
+mod noise_suppressor;
+mod output;
+mod pauses;
 mod recorder;
 mod resampler;
+mod silence_monitor;
 mod utils;
 mod visualizer;
 
-pub use device::{list_input_devices, list_output_devices, CpalDeviceInfo};
+pub use device::{
+    find_device, find_device_index, list_input_devices, list_output_devices, CpalDeviceInfo,
+};
+pub use echo_canceller::{EchoCanceller, PlaybackReference};
+pub use gain::{dbfs_to_rms, GainNormalizer};
+pub use idle_gate::IdleGate;
+pub use lookback::LookbackBuffer;
 pub use loopback::{LoopbackCapture, LoopbackDeviceInfo, LoopbackError, LoopbackSupport};
 pub use mixer::{AudioMixer, SharedAudioMixer};
+pub use noise_suppressor::NoiseSuppressor;
+pub use output::{OutputChannel, OutputDevice, OutputEngine, OutputSound, Playback};
+pub use pauses::{Pause, PauseTracker};
 pub use recorder::AudioRecorder;
 pub use resampler::FrameResampler;
+pub use silence_monitor::DigitalSilenceMonitor;
 pub use utils::save_wav_file;
 pub use visualizer::AudioVisualiser;
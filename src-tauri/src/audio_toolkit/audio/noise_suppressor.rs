@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+use nnnoiseless::DenoiseState;
+
+use crate::audio_toolkit::{audio::FrameResampler, constants::WHISPER_SAMPLE_RATE};
+
+/// RNNoise only runs at 48 kHz
+const RNNOISE_SAMPLE_RATE: usize = 48_000;
+
+/// RNNoise expects samples scaled like 16-bit PCM
+const PCM_SCALE: f32 = i16::MAX as f32;
+
+/// RNNoise based noise suppressor for 16 kHz capture.
+///
+/// Frames are taken up to 48 kHz, denoised 10 ms at a time and brought back
+/// down in frames of the same length they came in, so the stage can sit in
+/// front of the VAD. The resamplers delay the audio by a little under
+/// 100 ms.
+pub struct NoiseSuppressor {
+    upsampler: FrameResampler,
+    downsampler: FrameResampler,
+    state: Box<DenoiseState<'static>>,
+    denoised: Vec<f32>,
+    /// The first frame RNNoise returns fades in from silence
+    warmed_up: bool,
+}
+
+impl NoiseSuppressor {
+    pub fn new(frame_duration: Duration) -> Self {
+        let sample_rate = WHISPER_SAMPLE_RATE as usize;
+        Self {
+            upsampler: FrameResampler::new(
+                sample_rate,
+                RNNOISE_SAMPLE_RATE,
+                Duration::from_millis(10),
+            ),
+            downsampler: FrameResampler::new(RNNOISE_SAMPLE_RATE, sample_rate, frame_duration),
+            state: DenoiseState::new(),
+            denoised: vec![0.0; DenoiseState::FRAME_SIZE],
+            warmed_up: false,
+        }
+    }
+
+    /// Denoise `frame`, emitting whatever denoised frames are ready
+    pub fn process(&mut self, frame: &[f32], emit: &mut impl FnMut(&[f32])) {
+        let Self {
+            upsampler,
+            downsampler,
+            state,
+            denoised,
+            warmed_up,
+        } = self;
+        upsampler.push(frame, |chunk| {
+            Self::denoise(chunk, state, denoised, warmed_up);
+            downsampler.push(denoised, &mut *emit);
+        });
+    }
+
+    /// Flush the audio still held by the resamplers, padded with silence
+    pub fn finish(&mut self, emit: &mut impl FnMut(&[f32])) {
+        let Self {
+            upsampler,
+            downsampler,
+            state,
+            denoised,
+            warmed_up,
+        } = self;
+        upsampler.finish(|chunk| {
+            Self::denoise(chunk, state, denoised, warmed_up);
+            downsampler.push(denoised, &mut *emit);
+        });
+        downsampler.finish(&mut *emit);
+    }
+
+    fn denoise(
+        chunk: &[f32],
+        state: &mut DenoiseState<'static>,
+        denoised: &mut [f32],
+        warmed_up: &mut bool,
+    ) {
+        let scaled: Vec<f32> = chunk.iter().map(|sample| sample * PCM_SCALE).collect();
+        state.process_frame(denoised, &scaled);
+        if *warmed_up {
+            for sample in denoised.iter_mut() {
+                *sample /= PCM_SCALE;
+            }
+        } else {
+            denoised.fill(0.0);
+            *warmed_up = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(30);
+
+    /// Deterministic white noise at the given amplitude
+    fn noise(len: usize, amplitude: f32) -> Vec<f32> {
+        let mut seed = 0x2545_f491_u32;
+        (0..len)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                (seed as f32 / u32::MAX as f32 * 2.0 - 1.0) * amplitude
+            })
+            .collect()
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    fn run(input: &[f32]) -> Vec<Vec<f32>> {
+        let mut suppressor = NoiseSuppressor::new(FRAME);
+        let mut frames = Vec::new();
+        for frame in input.chunks(480) {
+            suppressor.process(frame, &mut |out: &[f32]| frames.push(out.to_vec()));
+        }
+        suppressor.finish(&mut |out: &[f32]| frames.push(out.to_vec()));
+        frames
+    }
+
+    #[test]
+    fn keeps_the_frame_length_and_all_the_audio() {
+        let input = noise(16_000 * 2, 0.05);
+        let frames = run(&input);
+
+        assert!(frames.iter().all(|frame| frame.len() == 480));
+        assert!(frames.len() * 480 >= input.len());
+    }
+
+    #[test]
+    fn attenuates_steady_noise() {
+        let input = noise(16_000 * 4, 0.05);
+        let output: Vec<f32> = run(&input).concat();
+
+        // Give RNNoise a second to settle on the noise floor
+        let settled = 16_000..input.len();
+        assert!(rms(&output[settled.clone()]) < rms(&input[settled]) * 0.5);
+    }
+}
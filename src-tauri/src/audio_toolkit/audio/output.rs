@@ -0,0 +1,424 @@
+//! Output engine shared by everything the app plays.
+//!
+//! Feedback sounds, recordings played back from history and spoken output
+//! all go through one engine thread. It keeps a single output stream open
+//! and mixes the sounds on it, so several can play at once without each
+//! opening the device.
+//!
+//! ## Features
+//!
+//! - One persistent stream, closed after a while without sounds
+//! - Per-channel volume on top of each sound's own volume
+//! - Ducking: while speech plays, the other channels are turned down
+//! - Follows the system default device when it changes, and lets sounds
+//!   still playing on a previous device finish instead of cutting them off
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use crate::audio_toolkit::audio::{OutputChannel, OutputEngine, OutputSound};
+//!
+//! let engine = OutputEngine::new();
+//! engine
+//!     .play(OutputChannel::Feedback, OutputSound::File(path), None, 0.8)
+//!     .wait();
+//! ```
+
+use cpal::traits::{DeviceTrait, HostTrait};
+use log::{debug, error, warn};
+use rodio::buffer::SamplesBuffer;
+use rodio::{ChannelCount, Decoder, OutputStream, OutputStreamBuilder, SampleRate, Sink};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use crate::audio_toolkit::audio::{find_device, list_output_devices};
+use crate::audio_toolkit::get_cpal_host;
+
+/// The stream is closed after this long without sounds to let the device
+/// idle
+const STREAM_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// How often finished sounds are collected and ducking is updated while
+/// something plays
+const MIX_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Gain applied to the other channels while a ducking channel plays
+const DUCK_GAIN: f32 = 0.3;
+
+/// What a sound is, for per-channel volume and ducking
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OutputChannel {
+    /// Start/stop sounds, alerts and test sounds
+    Feedback,
+    /// Recordings played back
+    Playback,
+    /// Spoken output; ducks the other channels
+    Speech,
+}
+
+impl OutputChannel {
+    fn ducks_others(self) -> bool {
+        matches!(self, OutputChannel::Speech)
+    }
+}
+
+/// An output device picked by name, with its stable id when known
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutputDevice {
+    pub id: Option<String>,
+    pub name: String,
+}
+
+fn is_default_device(device: &Option<OutputDevice>) -> bool {
+    device
+        .as_ref()
+        .map_or(true, |device| device.name == "Default")
+}
+
+/// Audio to play
+pub enum OutputSound {
+    /// Interleaved samples already in memory
+    Samples {
+        channels: ChannelCount,
+        sample_rate: SampleRate,
+        samples: Vec<f32>,
+    },
+    /// An audio file, decoded as it plays
+    File(PathBuf),
+}
+
+enum Command {
+    /// Open the output stream for a device ahead of the first sound
+    Warm(Option<OutputDevice>),
+    Play {
+        channel: OutputChannel,
+        sound: OutputSound,
+        device: Option<OutputDevice>,
+        volume: f32,
+        done: mpsc::Sender<()>,
+    },
+    SetChannelVolume(OutputChannel, f32),
+    StopChannel(OutputChannel),
+}
+
+/// A sound handed to the engine
+pub struct Playback {
+    finished: mpsc::Receiver<()>,
+}
+
+impl Playback {
+    /// Block until the sound has finished, or was dropped because it
+    /// couldn't be played
+    pub fn wait(self) {
+        let _ = self.finished.recv();
+    }
+}
+
+/// Handle to the engine thread. Cloning shares the same engine.
+#[derive(Clone)]
+pub struct OutputEngine {
+    commands: mpsc::Sender<Command>,
+}
+
+impl Default for OutputEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutputEngine {
+    pub fn new() -> Self {
+        let (tx, rx) = mpsc::channel();
+        if let Err(e) = thread::Builder::new()
+            .name("audio-output".to_string())
+            .spawn(move || run_engine(rx))
+        {
+            error!("Failed to start the audio output engine: {}", e);
+        }
+        Self { commands: tx }
+    }
+
+    /// Open the stream for `device` so the next sound starts right away
+    pub fn warm(&self, device: Option<OutputDevice>) {
+        let _ = self.commands.send(Command::Warm(device));
+    }
+
+    /// Play `sound` on `device` (the default device when `None`) at
+    /// `volume`, mixed with whatever else is playing
+    pub fn play(
+        &self,
+        channel: OutputChannel,
+        sound: OutputSound,
+        device: Option<OutputDevice>,
+        volume: f32,
+    ) -> Playback {
+        let (done, finished) = mpsc::channel();
+        let command = Command::Play {
+            channel,
+            sound,
+            device,
+            volume,
+            done,
+        };
+        if self.commands.send(command).is_err() {
+            error!("Audio output engine is not running");
+        }
+        Playback { finished }
+    }
+
+    /// Volume applied to every sound on `channel`, on top of its own
+    pub fn set_channel_volume(&self, channel: OutputChannel, volume: f32) {
+        let _ = self
+            .commands
+            .send(Command::SetChannelVolume(channel, volume.clamp(0.0, 1.0)));
+    }
+
+    /// Stop everything playing on `channel`
+    pub fn stop_channel(&self, channel: OutputChannel) {
+        let _ = self.commands.send(Command::StopChannel(channel));
+    }
+}
+
+/// A sound playing on a stream
+struct Voice {
+    channel: OutputChannel,
+    volume: f32,
+    sink: Sink,
+    done: mpsc::Sender<()>,
+}
+
+struct OpenStream {
+    device: Option<OutputDevice>,
+    /// Name of the system default device when the stream was opened on it
+    default_name: Option<String>,
+    stream: OutputStream,
+    voices: Vec<Voice>,
+}
+
+struct Engine {
+    current: Option<OpenStream>,
+    /// Streams on a previous device, kept until their sounds finish
+    retiring: Vec<OpenStream>,
+    channel_volumes: HashMap<OutputChannel, f32>,
+}
+
+fn run_engine(commands: mpsc::Receiver<Command>) {
+    let mut engine = Engine {
+        current: None,
+        retiring: Vec::new(),
+        channel_volumes: HashMap::new(),
+    };
+
+    loop {
+        let timeout = if engine.is_playing() {
+            MIX_INTERVAL
+        } else {
+            STREAM_IDLE_TIMEOUT
+        };
+        match commands.recv_timeout(timeout) {
+            Ok(command) => engine.handle(command),
+            Err(RecvTimeoutError::Timeout) => {
+                if !engine.is_playing() && engine.current.take().is_some() {
+                    debug!("Closed idle audio output stream");
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+        engine.mix();
+    }
+}
+
+impl Engine {
+    fn streams(&mut self) -> impl Iterator<Item = &mut OpenStream> + '_ {
+        self.current.iter_mut().chain(self.retiring.iter_mut())
+    }
+
+    fn is_playing(&self) -> bool {
+        self.current
+            .iter()
+            .chain(&self.retiring)
+            .any(|s| !s.voices.is_empty())
+    }
+
+    fn handle(&mut self, command: Command) {
+        match command {
+            Command::Warm(device) => {
+                self.stream_for(device);
+            }
+            Command::Play {
+                channel,
+                sound,
+                device,
+                volume,
+                done,
+            } => {
+                let Some(stream) = self.stream_for(device) else {
+                    return;
+                };
+                let sink = Sink::connect_new(stream.stream.mixer());
+                match sound {
+                    OutputSound::Samples {
+                        channels,
+                        sample_rate,
+                        samples,
+                    } => sink.append(SamplesBuffer::new(channels, sample_rate, samples)),
+                    OutputSound::File(path) => {
+                        match File::open(&path).map_err(|e| e.to_string()).and_then(|f| {
+                            Decoder::new(BufReader::new(f)).map_err(|e| e.to_string())
+                        }) {
+                            Ok(decoder) => sink.append(decoder),
+                            Err(e) => {
+                                error!("Failed to play '{}': {}", path.display(), e);
+                                return;
+                            }
+                        }
+                    }
+                }
+                stream.voices.push(Voice {
+                    channel,
+                    volume,
+                    sink,
+                    done,
+                });
+            }
+            Command::SetChannelVolume(channel, volume) => {
+                self.channel_volumes.insert(channel, volume);
+            }
+            Command::StopChannel(channel) => {
+                for stream in self.streams() {
+                    for voice in stream.voices.iter().filter(|v| v.channel == channel) {
+                        voice.sink.stop();
+                    }
+                }
+            }
+        }
+    }
+
+    /// The stream for `device`, opening it if needed
+    fn stream_for(&mut self, device: Option<OutputDevice>) -> Option<&mut OpenStream> {
+        let default_name = is_default_device(&device)
+            .then(default_output_name)
+            .flatten();
+        let reusable = self
+            .current
+            .as_ref()
+            .is_some_and(|open| open.device == device && open.default_name == default_name);
+
+        if !reusable {
+            if let Some(old) = self.current.take() {
+                if old.voices.is_empty() {
+                    // Release the old device before opening the new one
+                    drop(old);
+                } else {
+                    self.retiring.push(old);
+                }
+            }
+            match open_stream(device.clone()) {
+                Ok(stream) => {
+                    self.current = Some(OpenStream {
+                        device,
+                        default_name,
+                        stream,
+                        voices: Vec::new(),
+                    });
+                }
+                Err(e) => error!("Failed to open audio output stream: {}", e),
+            }
+        }
+        self.current.as_mut()
+    }
+
+    /// Collect finished sounds and apply channel volumes and ducking
+    fn mix(&mut self) {
+        for stream in self.streams() {
+            stream.voices.retain(|voice| {
+                if voice.sink.empty() {
+                    let _ = voice.done.send(());
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+        self.retiring.retain(|stream| !stream.voices.is_empty());
+
+        let playing: Vec<OutputChannel> = self
+            .current
+            .iter()
+            .chain(&self.retiring)
+            .flat_map(|stream| stream.voices.iter().map(|voice| voice.channel))
+            .collect();
+        let channel_volumes = self.channel_volumes.clone();
+        for stream in self.streams() {
+            for voice in &stream.voices {
+                let channel_volume = channel_volumes.get(&voice.channel).copied().unwrap_or(1.0);
+                let gain = channel_gain(voice.channel, channel_volume, &playing);
+                voice.sink.set_volume(voice.volume * gain);
+            }
+        }
+    }
+}
+
+/// Gain for a sound on `channel`, given every channel currently playing
+fn channel_gain(channel: OutputChannel, channel_volume: f32, playing: &[OutputChannel]) -> f32 {
+    let ducked = !channel.ducks_others() && playing.iter().any(|c| c.ducks_others());
+    if ducked {
+        channel_volume * DUCK_GAIN
+    } else {
+        channel_volume
+    }
+}
+
+fn default_output_name() -> Option<String> {
+    get_cpal_host()
+        .default_output_device()
+        .and_then(|device| device.name().ok())
+}
+
+fn open_stream(device: Option<OutputDevice>) -> Result<OutputStream, Box<dyn std::error::Error>> {
+    let builder = match device {
+        Some(selected) if selected.name != "Default" => {
+            let found_device = find_device(
+                list_output_devices()?,
+                selected.id.as_deref(),
+                Some(&selected.name),
+            );
+            match found_device {
+                Some(info) => OutputStreamBuilder::from_device(info.device)?,
+                None => {
+                    warn!("Device '{}' not found, using default device", selected.name);
+                    OutputStreamBuilder::from_default_device()?
+                }
+            }
+        }
+        _ => {
+            debug!("Using default device");
+            OutputStreamBuilder::from_default_device()?
+        }
+    };
+    Ok(builder.open_stream()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speech_ducks_the_other_channels() {
+        let playing = [OutputChannel::Feedback, OutputChannel::Speech];
+        assert_eq!(
+            channel_gain(OutputChannel::Feedback, 1.0, &playing),
+            DUCK_GAIN
+        );
+        assert_eq!(channel_gain(OutputChannel::Speech, 1.0, &playing), 1.0);
+        assert_eq!(
+            channel_gain(OutputChannel::Playback, 0.5, &[OutputChannel::Playback]),
+            0.5
+        );
+    }
+}
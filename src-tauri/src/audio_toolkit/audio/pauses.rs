@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+/// Silence the VAD cut out between two stretches of speech
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pause {
+    /// Sample offset in the recorded audio where the silence was cut out
+    pub offset: usize,
+    pub duration: Duration,
+}
+
+/// Collects the pauses of a recording from the VAD's keep/drop decisions.
+///
+/// Silence before the first and after the last speech isn't a pause. The
+/// VAD keeps a few frames around speech, so a pause is a little shorter
+/// than the silence the speaker left.
+pub struct PauseTracker {
+    sample_rate: u32,
+    silent_samples: usize,
+    pauses: Vec<Pause>,
+}
+
+impl PauseTracker {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            silent_samples: 0,
+            pauses: Vec::new(),
+        }
+    }
+
+    /// A frame of `len` samples was dropped as silence
+    pub fn silence(&mut self, len: usize) {
+        self.silent_samples += len;
+    }
+
+    /// Speech is about to be kept at `offset` of the recorded audio
+    pub fn speech(&mut self, offset: usize) {
+        if self.silent_samples > 0 && offset > 0 {
+            self.pauses.push(Pause {
+                offset,
+                duration: Duration::from_secs_f64(
+                    self.silent_samples as f64 / self.sample_rate as f64,
+                ),
+            });
+        }
+        self.silent_samples = 0;
+    }
+
+    /// Take the pauses so far, oldest first, and start over
+    pub fn take(&mut self) -> Vec<Pause> {
+        self.silent_samples = 0;
+        std::mem::take(&mut self.pauses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_silence_between_speech_is_a_pause() {
+        let mut tracker = PauseTracker::new(16000);
+        tracker.silence(480);
+        tracker.speech(0);
+        tracker.silence(8000);
+        tracker.silence(8000);
+        tracker.speech(4800);
+        tracker.speech(5280);
+        tracker.silence(480);
+
+        assert_eq!(
+            tracker.take(),
+            vec![Pause {
+                offset: 4800,
+                duration: Duration::from_secs(1),
+            }]
+        );
+        tracker.speech(5760);
+        assert!(tracker.take().is_empty());
+    }
+}
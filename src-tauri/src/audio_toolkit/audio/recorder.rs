@@ -10,7 +10,10 @@ use cpal::{
 };
 
 use crate::audio_toolkit::{
-    audio::{AudioVisualiser, FrameResampler},
+    audio::{
+        AudioVisualiser, EchoCanceller, FrameResampler, IdleGate, LookbackBuffer, NoiseSuppressor,
+        Pause, PauseTracker, PlaybackReference,
+    },
     constants,
     vad::{self, VadFrame},
     VoiceActivityDetector,
@@ -18,18 +21,37 @@ use crate::audio_toolkit::{
 
 enum Cmd {
     Start,
-    Stop(mpsc::Sender<Vec<f32>>),
+    Stop(mpsc::Sender<(Vec<f32>, Vec<Pause>)>),
     Shutdown,
 }
 
-pub struct AudioRecorder {
-    device: Option<Device>,
-    cmd_tx: Option<mpsc::Sender<Cmd>>,
-    worker_handle: Option<std::thread::JoinHandle<()>>,
+/// Per-stream options the consumer thread runs with
+#[derive(Clone, Default)]
+struct ConsumerConfig {
     vad: Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
     /// Callback for forwarding resampled audio samples (used by Active Listening)
     sample_cb: Option<Arc<dyn Fn(&[f32]) + Send + Sync + 'static>>,
+    /// Callback for resampled audio while not recording (used by the wake word)
+    idle_cb: Option<Arc<dyn Fn(&[f32]) + Send + Sync + 'static>>,
+    /// Callback with the VAD's decision on each recorded frame
+    speech_cb: Option<Arc<dyn Fn(&[f32], bool) + Send + Sync + 'static>>,
+    /// RMS at which the idle gate opens; `None` runs the full pipeline constantly
+    idle_wake_threshold: Option<f32>,
+    /// Audio kept from before a recording starts; `None` disables retroactive
+    /// capture
+    lookback: Option<Duration>,
+    /// What the app is playing, removed from the capture as echo
+    echo_reference: Option<PlaybackReference>,
+    /// Denoise the capture before the VAD sees it
+    noise_suppression: bool,
+}
+
+pub struct AudioRecorder {
+    device: Option<Device>,
+    cmd_tx: Option<mpsc::Sender<Cmd>>,
+    worker_handle: Option<std::thread::JoinHandle<()>>,
+    consumer: ConsumerConfig,
 }
 
 impl AudioRecorder {
@@ -38,14 +60,12 @@ impl AudioRecorder {
             device: None,
             cmd_tx: None,
             worker_handle: None,
-            vad: None,
-            level_cb: None,
-            sample_cb: None,
+            consumer: ConsumerConfig::default(),
         })
     }
 
     pub fn with_vad(mut self, vad: Box<dyn VoiceActivityDetector>) -> Self {
-        self.vad = Some(Arc::new(Mutex::new(vad)));
+        self.consumer.vad = Some(Arc::new(Mutex::new(vad)));
         self
     }
 
@@ -53,7 +73,7 @@ impl AudioRecorder {
     where
         F: Fn(Vec<f32>) + Send + Sync + 'static,
     {
-        self.level_cb = Some(Arc::new(cb));
+        self.consumer.level_cb = Some(Arc::new(cb));
         self
     }
 
@@ -64,7 +84,54 @@ impl AudioRecorder {
     where
         F: Fn(&[f32]) + Send + Sync + 'static,
     {
-        self.sample_cb = Some(Arc::new(cb));
+        self.consumer.sample_cb = Some(Arc::new(cb));
+        self
+    }
+
+    /// Set a callback for resampled audio samples while not recording.
+    /// Meant for an always-open stream, e.g. to listen for a wake word.
+    pub fn with_idle_sample_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(&[f32]) + Send + Sync + 'static,
+    {
+        self.consumer.idle_cb = Some(Arc::new(cb));
+        self
+    }
+
+    /// Set a callback told, for each frame recorded live, whether the VAD
+    /// heard speech in it. Needs a VAD.
+    pub fn with_speech_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(&[f32], bool) + Send + Sync + 'static,
+    {
+        self.consumer.speech_cb = Some(Arc::new(cb));
+        self
+    }
+
+    /// Skip resampling and spectrum analysis while not recording and the
+    /// input stays below `threshold` RMS. Meant for an always-open stream.
+    pub fn with_idle_gate(mut self, threshold: f32) -> Self {
+        self.consumer.idle_wake_threshold = Some(threshold);
+        self
+    }
+
+    /// Keep the last `duration` of audio while not recording, and start each
+    /// recording with it. Meant for an always-open stream.
+    pub fn with_lookback(mut self, duration: Duration) -> Self {
+        self.consumer.lookback = Some(duration);
+        self
+    }
+
+    /// Cancel the echo of audio the app plays while capturing. Whatever plays
+    /// it pushes to `reference` as it starts.
+    pub fn with_echo_cancellation(mut self, reference: PlaybackReference) -> Self {
+        self.consumer.echo_reference = Some(reference);
+        self
+    }
+
+    /// Suppress background noise before voice activity detection
+    pub fn with_noise_suppression(mut self) -> Self {
+        self.consumer.noise_suppression = true;
         self
     }
 
@@ -85,11 +152,8 @@ impl AudioRecorder {
         };
 
         let thread_device = device.clone();
-        let vad = self.vad.clone();
-        // Move the optional level callback into the worker thread
-        let level_cb = self.level_cb.clone();
-        // Move the optional sample callback into the worker thread
-        let sample_cb = self.sample_cb.clone();
+        // Move the VAD, callbacks and options into the worker thread
+        let consumer = self.consumer.clone();
 
         let worker = std::thread::spawn(move || {
             let config = AudioRecorder::get_preferred_config(&thread_device)
@@ -139,7 +203,7 @@ impl AudioRecorder {
             stream.play().expect("failed to start stream");
 
             // keep the stream alive while we process samples
-            run_consumer(sample_rate, vad, sample_rx, cmd_rx, level_cb, sample_cb);
+            run_consumer(sample_rate, sample_rx, cmd_rx, consumer);
             // stream is dropped here, after run_consumer returns
         });
 
@@ -158,6 +222,12 @@ impl AudioRecorder {
     }
 
     pub fn stop(&self) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
+        Ok(self.stop_with_pauses()?.0)
+    }
+
+    /// Stop recording and return the samples with the pauses the VAD cut out
+    /// of them. Without a VAD there are no pauses.
+    pub fn stop_with_pauses(&self) -> Result<(Vec<f32>, Vec<Pause>), Box<dyn std::error::Error>> {
         let (resp_tx, resp_rx) = mpsc::channel();
         if let Some(tx) = &self.cmd_tx {
             tx.send(Cmd::Stop(resp_tx))?;
@@ -261,21 +331,71 @@ impl AudioRecorder {
     }
 }
 
+/// Remove the app's own playback from a resampled frame
+fn cancel_echo<'a>(
+    frame: &'a [f32],
+    echo: &mut Option<(EchoCanceller, PlaybackReference)>,
+    cancelled: &'a mut Vec<f32>,
+) -> &'a [f32] {
+    let Some((canceller, reference)) = echo else {
+        return frame;
+    };
+    cancelled.clear();
+    cancelled.extend_from_slice(frame);
+    canceller.process(cancelled, reference.take(frame.len()).as_deref());
+    cancelled
+}
+
+/// Denoise a frame on its way to `emit`, or pass it straight through
+fn suppress_noise(
+    frame: &[f32],
+    suppressor: &mut Option<NoiseSuppressor>,
+    emit: &mut impl FnMut(&[f32]),
+) {
+    match suppressor {
+        Some(suppressor) => suppressor.process(frame, emit),
+        None => emit(frame),
+    }
+}
+
 fn run_consumer(
     in_sample_rate: u32,
-    vad: Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
     sample_rx: mpsc::Receiver<Vec<f32>>,
     cmd_rx: mpsc::Receiver<Cmd>,
-    level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
-    sample_cb: Option<Arc<dyn Fn(&[f32]) + Send + Sync + 'static>>,
+    config: ConsumerConfig,
 ) {
+    let ConsumerConfig {
+        vad,
+        level_cb,
+        sample_cb,
+        idle_cb,
+        speech_cb,
+        idle_wake_threshold,
+        lookback,
+        echo_reference,
+        noise_suppression,
+    } = config;
+    let mut idle_gate =
+        idle_wake_threshold.map(|threshold| IdleGate::new(in_sample_rate as usize, threshold));
+    let mut lookback = lookback.map(|duration| {
+        LookbackBuffer::new(
+            (duration.as_secs_f32() * constants::WHISPER_SAMPLE_RATE as f32) as usize,
+        )
+    });
+
+    let frame_duration = Duration::from_millis(30);
     let mut frame_resampler = FrameResampler::new(
         in_sample_rate as usize,
         constants::WHISPER_SAMPLE_RATE as usize,
-        Duration::from_millis(30),
+        frame_duration,
     );
 
+    let mut echo = echo_reference.map(|reference| (EchoCanceller::new(), reference));
+    let mut cancelled = Vec::<f32>::new();
+    let mut suppressor = noise_suppression.then(|| NoiseSuppressor::new(frame_duration));
+
     let mut processed_samples = Vec::<f32>::new();
+    let mut pauses = PauseTracker::new(constants::WHISPER_SAMPLE_RATE);
     let mut recording = false;
 
     // ---------- spectrum visualisation setup ---------------------------- //
@@ -289,15 +409,21 @@ fn run_consumer(
         4000.0, // vocal_max_hz
     );
 
+    /// Returns whether the VAD heard speech in the frame, if recording with one
     fn handle_frame(
         samples: &[f32],
         recording: bool,
         vad: &Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
         out_buf: &mut Vec<f32>,
+        pauses: &mut PauseTracker,
         sample_cb: &Option<Arc<dyn Fn(&[f32]) + Send + Sync + 'static>>,
-    ) {
+        lookback: &mut Option<LookbackBuffer>,
+    ) -> Option<bool> {
         if !recording {
-            return;
+            if let Some(buffer) = lookback {
+                buffer.push(samples);
+            }
+            return None;
         }
 
         // Forward resampled samples via callback (for Active Listening)
@@ -309,18 +435,27 @@ fn run_consumer(
             match vad_arc.lock() {
                 Ok(mut det) => {
                     match det.push_frame(samples).unwrap_or(VadFrame::Speech(samples)) {
-                        VadFrame::Speech(buf) => out_buf.extend_from_slice(buf),
-                        VadFrame::Noise => {}
+                        VadFrame::Speech(buf) => {
+                            pauses.speech(out_buf.len());
+                            out_buf.extend_from_slice(buf);
+                            Some(true)
+                        }
+                        VadFrame::Noise => {
+                            pauses.silence(samples.len());
+                            Some(false)
+                        }
                     }
                 }
                 Err(e) => {
                     log::error!("Failed to lock VAD: {}", e);
                     // Fall back to treating as speech when VAD lock fails
                     out_buf.extend_from_slice(samples);
+                    None
                 }
             }
         } else {
             out_buf.extend_from_slice(samples);
+            None
         }
     }
 
@@ -330,23 +465,50 @@ fn run_consumer(
             Err(_) => break, // stream closed
         };
 
-        // ---------- spectrum processing ---------------------------------- //
-        if let Some(buckets) = visualizer.feed(&raw) {
-            if let Some(cb) = &level_cb {
-                cb(buckets);
+        // ---------- idle gate --------------------------------------------- //
+        // Quiet input is dropped before any real work while not recording
+        let idle = !recording && idle_gate.as_mut().is_some_and(|gate| !gate.push(&raw));
+
+        if !idle {
+            // ---------- spectrum processing ------------------------------ //
+            if let Some(buckets) = visualizer.feed(&raw) {
+                if let Some(cb) = &level_cb {
+                    cb(buckets);
+                }
             }
-        }
 
-        // ---------- existing pipeline ------------------------------------ //
-        frame_resampler.push(&raw, &mut |frame: &[f32]| {
-            handle_frame(frame, recording, &vad, &mut processed_samples, &sample_cb)
-        });
+            // ---------- existing pipeline -------------------------------- //
+            frame_resampler.push(&raw, &mut |frame: &[f32]| {
+                suppress_noise(
+                    cancel_echo(frame, &mut echo, &mut cancelled),
+                    &mut suppressor,
+                    &mut |frame: &[f32]| {
+                        if let Some(cb) = idle_cb.as_ref().filter(|_| !recording) {
+                            cb(frame);
+                        }
+                        let speech = handle_frame(
+                            frame,
+                            recording,
+                            &vad,
+                            &mut processed_samples,
+                            &mut pauses,
+                            &sample_cb,
+                            &mut lookback,
+                        );
+                        if let (Some(cb), Some(speech)) = (&speech_cb, speech) {
+                            cb(frame, speech);
+                        }
+                    },
+                )
+            });
+        }
 
         // non-blocking check for a command
         while let Ok(cmd) = cmd_rx.try_recv() {
             match cmd {
                 Cmd::Start => {
                     processed_samples.clear();
+                    pauses.take();
                     recording = true;
                     visualizer.reset(); // Reset visualization buffer
                     if let Some(v) = &vad {
@@ -355,16 +517,51 @@ fn run_consumer(
                             Err(e) => log::error!("Failed to lock VAD for reset: {}", e),
                         }
                     }
+
+                    // Start with what was said just before the shortcut
+                    let earlier = lookback.as_mut().map(|buffer| buffer.take());
+                    for frame in earlier.unwrap_or_default() {
+                        handle_frame(
+                            &frame,
+                            true,
+                            &vad,
+                            &mut processed_samples,
+                            &mut pauses,
+                            &sample_cb,
+                            &mut None,
+                        );
+                    }
                 }
                 Cmd::Stop(reply_tx) => {
                     recording = false;
+                    if let Some(gate) = idle_gate.as_mut() {
+                        gate.reset();
+                    }
 
+                    // we still want to process the last few frames
+                    let mut last_frame = |frame: &[f32]| {
+                        handle_frame(
+                            frame,
+                            true,
+                            &vad,
+                            &mut processed_samples,
+                            &mut pauses,
+                            &sample_cb,
+                            &mut None,
+                        );
+                    };
                     frame_resampler.finish(&mut |frame: &[f32]| {
-                        // we still want to process the last few frames
-                        handle_frame(frame, true, &vad, &mut processed_samples, &sample_cb)
+                        suppress_noise(
+                            cancel_echo(frame, &mut echo, &mut cancelled),
+                            &mut suppressor,
+                            &mut last_frame,
+                        )
                     });
+                    if let Some(suppressor) = suppressor.as_mut() {
+                        suppressor.finish(&mut last_frame);
+                    }
 
-                    let _ = reply_tx.send(std::mem::take(&mut processed_samples));
+                    let _ = reply_tx.send((std::mem::take(&mut processed_samples), pauses.take()));
                 }
                 Cmd::Shutdown => return,
             }
@@ -0,0 +1,78 @@
+/// Amplitude below which a sample counts as digital silence.
+///
+/// A live microphone always carries some noise floor; a stream of exact
+/// zeros means the OS is feeding us nothing, e.g. after macOS revokes the
+/// microphone permission.
+const DIGITAL_SILENCE_THRESHOLD: f32 = 1e-7;
+
+/// Detects a stream that has produced nothing but digital silence for too long
+pub struct DigitalSilenceMonitor {
+    limit_samples: usize,
+    silent_samples: usize,
+    tripped: bool,
+}
+
+impl DigitalSilenceMonitor {
+    pub fn new(sample_rate: usize, limit_secs: f32) -> Self {
+        Self {
+            limit_samples: (sample_rate as f32 * limit_secs) as usize,
+            silent_samples: 0,
+            tripped: false,
+        }
+    }
+
+    /// Feed a frame; returns true exactly once, when the silence limit is reached
+    pub fn push(&mut self, samples: &[f32]) -> bool {
+        if self.tripped {
+            return false;
+        }
+
+        if samples.iter().all(|s| s.abs() < DIGITAL_SILENCE_THRESHOLD) {
+            self.silent_samples += samples.len();
+        } else {
+            self.silent_samples = 0;
+        }
+
+        if self.silent_samples >= self.limit_samples {
+            self.tripped = true;
+            return true;
+        }
+        false
+    }
+
+    pub fn reset(&mut self) {
+        self.silent_samples = 0;
+        self.tripped = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trips_once_after_limit() {
+        let mut monitor = DigitalSilenceMonitor::new(100, 1.0);
+        let zeros = [0.0f32; 50];
+
+        assert!(!monitor.push(&zeros));
+        assert!(monitor.push(&zeros));
+        assert!(!monitor.push(&zeros));
+
+        monitor.reset();
+        assert!(!monitor.push(&zeros));
+    }
+
+    #[test]
+    fn test_noise_floor_resets_counter() {
+        let mut monitor = DigitalSilenceMonitor::new(100, 1.0);
+        let zeros = [0.0f32; 60];
+        let mut quiet = [0.0f32; 60];
+        quiet[30] = 0.0005;
+
+        assert!(!monitor.push(&zeros));
+        assert!(!monitor.push(&quiet));
+        assert!(!monitor.push(&zeros));
+        assert!(monitor.push(&zeros));
+    }
+}
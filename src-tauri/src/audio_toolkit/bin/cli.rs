@@ -1,10 +1,9 @@
 use hound::WavWriter;
 use std::io::{self, Write};
 
-use handy_app_lib::audio_toolkit::{
+use dictum_app_lib::audio_toolkit::{
     audio::{list_input_devices, CpalDeviceInfo},
-    vad::SmoothedVad,
-    AudioRecorder, SileroVad,
+    AudioRecorder, VadConfig,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -175,8 +174,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("=========================");
     print_help();
 
-    let silero = SileroVad::new("./resources/models/silero_vad_v4.onnx", 0.5)?;
-    let smoothed_vad = SmoothedVad::new(Box::new(silero), 15, 15);
+    let smoothed_vad = VadConfig::default()
+        .with_threshold(0.5)
+        .build("./resources/models/silero_vad_v4.onnx")?;
     let recorder = AudioRecorder::new()?.with_vad(Box::new(smoothed_vad));
     let mut state = RecorderState::new(recorder);
 
@@ -189,7 +189,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
-        let parts: Vec<&str> = input.trim().split_whitespace().collect();
+        let parts: Vec<&str> = input.split_whitespace().collect();
 
         if parts.is_empty() {
             continue;
@@ -1,10 +1,13 @@
 //! Universal audio file decoder using symphonia.
 //!
 //! Decodes various audio formats (WAV, MP3, M4A, AAC, FLAC, OGG, MP4) to
-//! f32 samples at 16kHz mono, ready for transcription.
+//! f32 samples at 16kHz mono, ready for transcription. Anything symphonia
+//! can't read, such as video containers or Opus, is handed to `ffmpeg` when
+//! it's installed.
 
 use std::fs::File;
 use std::path::Path;
+use std::process::{Command, Stdio};
 
 use log::{debug, info};
 use serde::{Deserialize, Serialize};
@@ -17,7 +20,7 @@ use symphonia::core::meta::MetadataOptions;
 use symphonia::core::probe::Hint;
 
 /// Target sample rate for transcription models
-const TARGET_SAMPLE_RATE: u32 = 16_000;
+pub const TARGET_SAMPLE_RATE: u32 = 16_000;
 
 /// Result of decoding an audio file
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -56,10 +59,29 @@ impl DecodedAudio {
     }
 }
 
-/// Decode an audio file to f32 samples at 16kHz mono.
+/// Audio formats symphonia decodes itself
+const NATIVE_EXTENSIONS: [&str; 7] = ["wav", "mp3", "m4a", "aac", "flac", "ogg", "mp4"];
+
+/// Audio and video formats decoded through `ffmpeg`
+const FFMPEG_EXTENSIONS: [&str; 10] = [
+    "opus", "webm", "mkv", "mov", "m4v", "avi", "wma", "aiff", "aif", "amr",
+];
+
+/// Decode an audio or video file to f32 samples at 16kHz mono.
 ///
-/// Supports WAV, MP3, M4A, AAC, FLAC, OGG, and MP4 formats.
+/// WAV, MP3, M4A, AAC, FLAC, OGG and MP4 are decoded natively. Other
+/// formats, and native ones symphonia fails on, go through `ffmpeg`.
 pub fn decode_audio_file(path: &Path) -> Result<DecodedAudio, String> {
+    if has_extension(path, &NATIVE_EXTENSIONS) {
+        match decode_with_symphonia(path) {
+            Ok(decoded) => return Ok(decoded),
+            Err(e) => debug!("Native decoding of {} failed: {}", path.display(), e),
+        }
+    }
+    decode_with_ffmpeg(path)
+}
+
+fn decode_with_symphonia(path: &Path) -> Result<DecodedAudio, String> {
     let file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
 
     let mss = MediaSourceStream::new(Box::new(file), Default::default());
@@ -208,6 +230,66 @@ pub fn decode_audio_file(path: &Path) -> Result<DecodedAudio, String> {
     })
 }
 
+/// Decode with the `ffmpeg` binary, which downmixes and resamples to 16kHz
+/// mono itself and writes raw little-endian f32 samples to stdout
+fn decode_with_ffmpeg(path: &Path) -> Result<DecodedAudio, String> {
+    let output = Command::new("ffmpeg")
+        .arg("-nostdin")
+        .args(["-v", "error", "-i"])
+        .arg(path)
+        .args(["-vn", "-ac", "1", "-ar", &TARGET_SAMPLE_RATE.to_string()])
+        .args(["-f", "f32le", "-"])
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                "Unsupported format: install ffmpeg to transcribe this file".to_string()
+            } else {
+                format!("Failed to run ffmpeg: {}", e)
+            }
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("ffmpeg failed to decode file: {}", stderr.trim()));
+    }
+
+    let samples = samples_from_f32le(&output.stdout);
+    if samples.is_empty() {
+        return Err("No audio samples were decoded from the file".to_string());
+    }
+
+    let duration_seconds = samples.len() as f64 / TARGET_SAMPLE_RATE as f64;
+    let original_format = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("unknown")
+        .to_lowercase();
+
+    info!(
+        "Decoded {} with ffmpeg: {:.1}s, {} samples at {}Hz",
+        original_format,
+        duration_seconds,
+        samples.len(),
+        TARGET_SAMPLE_RATE,
+    );
+
+    Ok(DecodedAudio {
+        samples,
+        duration_seconds,
+        original_format,
+        sample_rate: TARGET_SAMPLE_RATE,
+    })
+}
+
+/// Raw little-endian f32 samples, ignoring a trailing partial sample
+fn samples_from_f32le(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
 /// Simple linear interpolation resampler for converting between sample rates.
 ///
 /// For transcription purposes, this provides sufficient quality while being
@@ -239,17 +321,25 @@ fn resample_linear(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
     output
 }
 
-/// Get list of supported audio file extensions
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|ext| extensions.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+/// Get list of supported audio and video file extensions
 pub fn get_supported_extensions() -> Vec<&'static str> {
-    vec!["wav", "mp3", "m4a", "aac", "flac", "ogg", "mp4"]
+    NATIVE_EXTENSIONS
+        .iter()
+        .chain(FFMPEG_EXTENSIONS.iter())
+        .copied()
+        .collect()
 }
 
-/// Check if a file extension is a supported audio format
+/// Check if a file extension is a supported audio or video format
 pub fn is_supported_format(path: &Path) -> bool {
-    path.extension()
-        .and_then(|e| e.to_str())
-        .map(|ext| get_supported_extensions().contains(&ext.to_lowercase().as_str()))
-        .unwrap_or(false)
+    has_extension(path, &get_supported_extensions())
 }
 
 #[cfg(test)]
@@ -304,5 +394,16 @@ mod tests {
         assert!(is_supported_format(Path::new("/path/to/file.flac")));
         assert!(!is_supported_format(Path::new("test.txt")));
         assert!(!is_supported_format(Path::new("noext")));
+        assert!(is_supported_format(Path::new("meeting.MKV")));
+        assert!(is_supported_format(Path::new("voice.opus")));
+    }
+
+    #[test]
+    fn test_samples_from_f32le() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0.5f32.to_le_bytes());
+        bytes.extend_from_slice(&(-1.0f32).to_le_bytes());
+        bytes.push(0);
+        assert_eq!(samples_from_f32le(&bytes), vec![0.5, -1.0]);
     }
 }
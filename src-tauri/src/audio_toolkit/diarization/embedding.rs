@@ -0,0 +1,323 @@
+//! Embedding-based diarization
+//!
+//! Speech is collected into windows of a second or two, each window is
+//! turned into a speaker embedding, and the embeddings are clustered online:
+//! a window joins the most similar speaker heard so far if it's close
+//! enough, and starts a new speaker otherwise. Unlike the energy-based
+//! diarizer this keeps any number of speakers apart, as long as the embedder
+//! separates their voices.
+
+use super::{SpeakerChange, SpeakerDiarizer, SpeakerId, Voiceprint};
+
+/// Turns speech into a fixed-length speaker embedding
+pub trait SpeakerEmbedder: Send + Sync {
+    /// Embedding of mono speech at the diarizer's sample rate, or `None` if
+    /// there's too little voice in it to tell
+    fn embed(&mut self, samples: &[f32]) -> Option<Vec<f32>>;
+}
+
+/// Embeds speech as its average spectral shape ([`Voiceprint`]). Needs no
+/// model, but tells similar voices apart less reliably than a trained
+/// embedding model.
+pub struct SpectralEmbedder {
+    sample_rate: u32,
+}
+
+impl SpectralEmbedder {
+    pub fn new(sample_rate: u32) -> Self {
+        Self { sample_rate }
+    }
+}
+
+impl SpeakerEmbedder for SpectralEmbedder {
+    fn embed(&mut self, samples: &[f32]) -> Option<Vec<f32>> {
+        Voiceprint::from_samples(samples, self.sample_rate).map(|print| print.bands().to_vec())
+    }
+}
+
+/// Configuration for the embedding-based diarizer
+#[derive(Clone, Debug)]
+pub struct EmbeddingDiarizerConfig {
+    /// RMS threshold below which audio is considered silence (0.0-1.0)
+    pub silence_threshold: f32,
+    /// Milliseconds of speech embedded at a time
+    pub window_ms: u32,
+    /// Silence after which a partly filled window is embedded on its own, so
+    /// it isn't mixed with whoever speaks next
+    pub min_silence_duration_ms: u32,
+    /// Cosine similarity above which a window belongs to a known speaker
+    pub similarity_threshold: f32,
+    /// Most speakers told apart; further voices join the closest one
+    pub max_speakers: usize,
+    /// Sample rate of the audio (for time calculations)
+    pub sample_rate: u32,
+}
+
+impl Default for EmbeddingDiarizerConfig {
+    fn default() -> Self {
+        Self {
+            silence_threshold: 0.02,
+            window_ms: 1500,
+            min_silence_duration_ms: 500,
+            similarity_threshold: 0.7,
+            max_speakers: 8,
+            sample_rate: 16000,
+        }
+    }
+}
+
+impl EmbeddingDiarizerConfig {
+    pub fn with_silence_threshold(mut self, threshold: f32) -> Self {
+        self.silence_threshold = threshold;
+        self
+    }
+
+    pub fn with_window_ms(mut self, window_ms: u32) -> Self {
+        self.window_ms = window_ms;
+        self
+    }
+
+    pub fn with_similarity_threshold(mut self, threshold: f32) -> Self {
+        self.similarity_threshold = threshold;
+        self
+    }
+
+    pub fn with_max_speakers(mut self, max_speakers: usize) -> Self {
+        self.max_speakers = max_speakers;
+        self
+    }
+
+    pub fn with_sample_rate(mut self, sample_rate: u32) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    fn samples_in(&self, ms: u32) -> usize {
+        (self.sample_rate as u64 * ms as u64 / 1000) as usize
+    }
+}
+
+/// A speaker heard in the session, as the mean of their embeddings
+struct Cluster {
+    centroid: Vec<f32>,
+    windows: usize,
+}
+
+fn normalized(mut embedding: Vec<f32>) -> Vec<f32> {
+    let norm = embedding.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        embedding.iter_mut().for_each(|v| *v /= norm);
+    }
+    embedding
+}
+
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norms = norm(a) * norm(b);
+    if norms == 0.0 {
+        0.0
+    } else {
+        dot / norms
+    }
+}
+
+/// Speaker diarizer that clusters speaker embeddings online
+pub struct EmbeddingDiarizer {
+    config: EmbeddingDiarizerConfig,
+    embedder: Box<dyn SpeakerEmbedder>,
+    clusters: Vec<Cluster>,
+    current_speaker: SpeakerId,
+    /// Speech collected for the next embedding
+    window: Vec<f32>,
+    /// Samples of silence since the last speech
+    silence_samples: usize,
+    /// Total samples processed
+    total_samples: usize,
+}
+
+impl EmbeddingDiarizer {
+    pub fn new(embedder: Box<dyn SpeakerEmbedder>) -> Self {
+        Self::with_config(embedder, EmbeddingDiarizerConfig::default())
+    }
+
+    pub fn with_config(
+        embedder: Box<dyn SpeakerEmbedder>,
+        config: EmbeddingDiarizerConfig,
+    ) -> Self {
+        Self {
+            config,
+            embedder,
+            clusters: Vec::new(),
+            current_speaker: 0,
+            window: Vec::new(),
+            silence_samples: 0,
+            total_samples: 0,
+        }
+    }
+
+    /// Speaker whose voice the embedding matches, adding a new one if it
+    /// matches nobody
+    fn assign(&mut self, embedding: Vec<f32>) -> SpeakerId {
+        let embedding = normalized(embedding);
+        let closest = self
+            .clusters
+            .iter()
+            .enumerate()
+            .map(|(id, cluster)| (id, cosine(&cluster.centroid, &embedding)))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        let id = match closest {
+            Some((id, similarity))
+                if similarity >= self.config.similarity_threshold
+                    || self.clusters.len() >= self.config.max_speakers.max(1) =>
+            {
+                id
+            }
+            _ => {
+                self.clusters.push(Cluster {
+                    centroid: vec![0.0; embedding.len()],
+                    windows: 0,
+                });
+                self.clusters.len() - 1
+            }
+        };
+
+        let cluster = &mut self.clusters[id];
+        cluster.windows += 1;
+        let weight = 1.0 / cluster.windows as f32;
+        for (mean, value) in cluster.centroid.iter_mut().zip(&embedding) {
+            *mean += (value - *mean) * weight;
+        }
+        id as SpeakerId
+    }
+
+    /// Embed the collected speech and switch speaker if it's someone else
+    fn flush_window(&mut self) -> Option<SpeakerChange> {
+        let window = std::mem::take(&mut self.window);
+        let embedding = self.embedder.embed(&window)?;
+        let speaker = self.assign(embedding);
+        if speaker == self.current_speaker {
+            return None;
+        }
+
+        let previous_speaker = self.current_speaker;
+        self.current_speaker = speaker;
+        Some(SpeakerChange {
+            new_speaker: speaker,
+            previous_speaker,
+            sample_offset: self.total_samples,
+        })
+    }
+}
+
+impl SpeakerDiarizer for EmbeddingDiarizer {
+    fn process_frame(&mut self, samples: &[f32]) -> Option<SpeakerChange> {
+        self.total_samples += samples.len();
+        let rms = if samples.is_empty() {
+            0.0
+        } else {
+            (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+        };
+
+        if rms < self.config.silence_threshold {
+            self.silence_samples += samples.len();
+            let pause = self.config.samples_in(self.config.min_silence_duration_ms);
+            if !self.window.is_empty() && self.silence_samples >= pause {
+                return self.flush_window();
+            }
+            return None;
+        }
+
+        self.silence_samples = 0;
+        self.window.extend_from_slice(samples);
+        if self.window.len() >= self.config.samples_in(self.config.window_ms) {
+            return self.flush_window();
+        }
+        None
+    }
+
+    fn get_current_speaker(&self) -> SpeakerId {
+        self.current_speaker
+    }
+
+    fn reset(&mut self) {
+        self.clusters.clear();
+        self.current_speaker = 0;
+        self.window.clear();
+        self.silence_samples = 0;
+        self.total_samples = 0;
+    }
+
+    fn get_speaker_count(&self) -> usize {
+        self.clusters.len().max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: u32 = 16_000;
+
+    /// A buzzy harmonic "voice" with a resonance around `formant_hz`
+    fn voice(f0: f32, formant_hz: f32, seconds: f32) -> Vec<f32> {
+        let len = (SAMPLE_RATE as f32 * seconds) as usize;
+        (0..len)
+            .map(|i| {
+                let t = i as f32 / SAMPLE_RATE as f32;
+                (1..40)
+                    .map(|h| {
+                        let hz = f0 * h as f32;
+                        let gain = 1.0 / (1.0 + ((hz - formant_hz) / 300.0).powi(2));
+                        gain * (2.0 * std::f32::consts::PI * hz * t).sin()
+                    })
+                    .sum::<f32>()
+                    * 0.05
+            })
+            .collect()
+    }
+
+    fn speakers_of(diarizer: &mut EmbeddingDiarizer, audio: &[f32]) -> Vec<SpeakerId> {
+        audio
+            .chunks(480)
+            .filter_map(|frame| diarizer.process_frame(frame))
+            .map(|change| change.new_speaker)
+            .collect()
+    }
+
+    #[test]
+    fn test_tells_three_speakers_apart() {
+        let config = EmbeddingDiarizerConfig::default().with_similarity_threshold(0.9);
+        let mut diarizer =
+            EmbeddingDiarizer::with_config(Box::new(SpectralEmbedder::new(SAMPLE_RATE)), config);
+
+        let mut meeting = voice(120.0, 700.0, 3.0);
+        meeting.extend(voice(210.0, 2200.0, 3.0));
+        meeting.extend(voice(160.0, 1400.0, 3.0));
+        meeting.extend(voice(120.0, 700.0, 3.0));
+
+        assert_eq!(speakers_of(&mut diarizer, &meeting), vec![1, 2, 0]);
+        assert_eq!(diarizer.get_speaker_count(), 3);
+
+        diarizer.reset();
+        assert_eq!(diarizer.get_speaker_count(), 1);
+        assert_eq!(diarizer.get_current_speaker(), 0);
+    }
+
+    #[test]
+    fn test_max_speakers_caps_new_voices() {
+        let config = EmbeddingDiarizerConfig::default()
+            .with_similarity_threshold(0.9)
+            .with_max_speakers(2);
+        let mut diarizer =
+            EmbeddingDiarizer::with_config(Box::new(SpectralEmbedder::new(SAMPLE_RATE)), config);
+
+        let mut meeting = voice(120.0, 700.0, 3.0);
+        meeting.extend(voice(210.0, 2200.0, 3.0));
+        meeting.extend(voice(160.0, 1400.0, 3.0));
+        speakers_of(&mut diarizer, &meeting);
+
+        assert_eq!(diarizer.get_speaker_count(), 2);
+    }
+}
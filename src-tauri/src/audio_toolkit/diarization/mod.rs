@@ -2,10 +2,21 @@
 //!
 //! Provides energy-based speaker diarization for identifying "who is speaking".
 //! This implementation uses RMS energy analysis and silence detection to track
-//! speaker changes, which works well for 2-person conversations.
+//! speaker changes, which works well for 2-person conversations. For meetings
+//! with more people, [`EmbeddingDiarizer`] clusters speaker embeddings instead.
 
 use std::collections::VecDeque;
 
+mod embedding;
+mod onnx_embedder;
+mod voiceprint;
+
+pub use embedding::{
+    EmbeddingDiarizer, EmbeddingDiarizerConfig, SpeakerEmbedder, SpectralEmbedder,
+};
+pub use onnx_embedder::OnnxSpeakerEmbedder;
+pub use voiceprint::{filter_to_speaker, Voiceprint};
+
 /// Unique identifier for a speaker
 pub type SpeakerId = u32;
 
@@ -47,6 +58,33 @@ impl Default for DiarizationConfig {
     }
 }
 
+impl DiarizationConfig {
+    pub fn with_silence_threshold(mut self, threshold: f32) -> Self {
+        self.silence_threshold = threshold;
+        self
+    }
+
+    pub fn with_min_silence_duration_ms(mut self, duration_ms: u32) -> Self {
+        self.min_silence_duration_ms = duration_ms;
+        self
+    }
+
+    pub fn with_energy_change_threshold(mut self, threshold: f32) -> Self {
+        self.energy_change_threshold = threshold;
+        self
+    }
+
+    pub fn with_sample_rate(mut self, sample_rate: u32) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    pub fn with_history_window_size(mut self, frames: usize) -> Self {
+        self.history_window_size = frames;
+        self
+    }
+}
+
 /// Trait for speaker diarization implementations
 pub trait SpeakerDiarizer: Send + Sync {
     /// Process a frame of audio samples and detect any speaker changes
@@ -0,0 +1,192 @@
+//! ONNX speaker embedding models
+//!
+//! Runs WeSpeaker-style speaker embedding models, such as the ResNet and
+//! CAM++ exports from WeSpeaker or 3D-Speaker. These take Kaldi-style
+//! 80-bin log mel filterbank features shaped `[1, frames, 80]` and return
+//! one embedding for the whole input.
+
+use anyhow::Result;
+use ort::session::Session;
+use ort::value::Tensor;
+use rustfft::{num_complex::Complex32, Fft, FftPlanner};
+use std::path::Path;
+use std::sync::Arc;
+
+use super::embedding::SpeakerEmbedder;
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+
+/// 25 ms frames every 10 ms at 16 kHz
+const FRAME_LENGTH: usize = 400;
+const FRAME_SHIFT: usize = 160;
+const FFT_SIZE: usize = 512;
+const MEL_BINS: usize = 80;
+const LOW_HZ: f32 = 20.0;
+const PREEMPHASIS: f32 = 0.97;
+
+/// Fewest frames worth embedding (half a second)
+const MIN_FRAMES: usize = 50;
+
+fn kaldi_mel(hz: f32) -> f32 {
+    1127.0 * (1.0 + hz / 700.0).ln()
+}
+
+/// Log mel filterbank features as computed by Kaldi's `compute-fbank-feats`
+/// with its defaults (Povey window, no dither), mean normalized per bin
+struct Fbank {
+    fft: Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    /// First FFT bin and weights of each mel filter
+    filters: Vec<(usize, Vec<f32>)>,
+}
+
+impl Fbank {
+    fn new(sample_rate: u32) -> Self {
+        let window = (0..FRAME_LENGTH)
+            .map(|i| {
+                let phase = 2.0 * std::f32::consts::PI * i as f32 / (FRAME_LENGTH - 1) as f32;
+                (0.5 - 0.5 * phase.cos()).powf(0.85)
+            })
+            .collect();
+
+        let bin_hz = sample_rate as f32 / FFT_SIZE as f32;
+        let (low, high) = (kaldi_mel(LOW_HZ), kaldi_mel(sample_rate as f32 / 2.0));
+        let step = (high - low) / (MEL_BINS + 1) as f32;
+        let filters = (0..MEL_BINS)
+            .map(|m| {
+                let (left, center, right) = (
+                    low + step * m as f32,
+                    low + step * (m + 1) as f32,
+                    low + step * (m + 2) as f32,
+                );
+                let weights: Vec<(usize, f32)> = (0..FFT_SIZE / 2)
+                    .filter_map(|bin| {
+                        let mel = kaldi_mel(bin as f32 * bin_hz);
+                        let weight = if mel > left && mel <= center {
+                            (mel - left) / (center - left)
+                        } else if mel > center && mel < right {
+                            (right - mel) / (right - center)
+                        } else {
+                            return None;
+                        };
+                        Some((bin, weight))
+                    })
+                    .collect();
+                let start = weights.first().map_or(0, |&(bin, _)| bin);
+                (start, weights.into_iter().map(|(_, w)| w).collect())
+            })
+            .collect();
+
+        Self {
+            fft: FftPlanner::<f32>::new().plan_fft_forward(FFT_SIZE),
+            window,
+            filters,
+        }
+    }
+
+    /// Features of every full frame, flattened frame by frame
+    fn compute(&self, samples: &[f32]) -> Vec<f32> {
+        let mut features = Vec::new();
+        let mut buffer = vec![Complex32::new(0.0, 0.0); FFT_SIZE];
+        let mut frame = [0.0f32; FRAME_LENGTH];
+        for start in (0..samples.len().saturating_sub(FRAME_LENGTH - 1)).step_by(FRAME_SHIFT) {
+            // The models were trained on 16-bit sample values
+            for (value, sample) in frame.iter_mut().zip(&samples[start..]) {
+                *value = sample * 32768.0;
+            }
+            let mean = frame.iter().sum::<f32>() / FRAME_LENGTH as f32;
+            frame.iter_mut().for_each(|v| *v -= mean);
+            for i in (1..FRAME_LENGTH).rev() {
+                frame[i] -= PREEMPHASIS * frame[i - 1];
+            }
+            frame[0] -= PREEMPHASIS * frame[0];
+
+            buffer.fill(Complex32::new(0.0, 0.0));
+            for ((slot, value), w) in buffer.iter_mut().zip(&frame).zip(&self.window) {
+                *slot = Complex32::new(value * w, 0.0);
+            }
+            self.fft.process(&mut buffer);
+
+            for (first_bin, weights) in &self.filters {
+                let energy: f32 = buffer[*first_bin..]
+                    .iter()
+                    .zip(weights)
+                    .map(|(c, w)| c.norm_sqr() * w)
+                    .sum();
+                features.push(energy.max(f32::EPSILON).ln());
+            }
+        }
+
+        let frames = features.len() / MEL_BINS;
+        if frames > 0 {
+            for bin in 0..MEL_BINS {
+                let mean = features.iter().skip(bin).step_by(MEL_BINS).sum::<f32>() / frames as f32;
+                features
+                    .iter_mut()
+                    .skip(bin)
+                    .step_by(MEL_BINS)
+                    .for_each(|v| *v -= mean);
+            }
+        }
+        features
+    }
+}
+
+/// Speaker embeddings from an ONNX model
+pub struct OnnxSpeakerEmbedder {
+    session: Session,
+    fbank: Fbank,
+}
+
+impl OnnxSpeakerEmbedder {
+    /// Load a model taking 16 kHz audio
+    pub fn new<P: AsRef<Path>>(model_path: P) -> Result<Self> {
+        let session = Session::builder()
+            .and_then(|builder| builder.with_intra_threads(1))
+            .and_then(|builder| builder.commit_from_file(model_path))
+            .map_err(|e| anyhow::anyhow!("Failed to load speaker embedding model: {e}"))?;
+        Ok(Self {
+            session,
+            fbank: Fbank::new(WHISPER_SAMPLE_RATE),
+        })
+    }
+
+    fn run(&mut self, features: Vec<f32>) -> Result<Vec<f32>> {
+        let frames = features.len() / MEL_BINS;
+        let input = Tensor::from_array(([1usize, frames, MEL_BINS], features))?;
+        let outputs = self.session.run(ort::inputs![input])?;
+        let (_, embedding) = outputs[0].try_extract_tensor::<f32>()?;
+        Ok(embedding.to_vec())
+    }
+}
+
+impl SpeakerEmbedder for OnnxSpeakerEmbedder {
+    fn embed(&mut self, samples: &[f32]) -> Option<Vec<f32>> {
+        let features = self.fbank.compute(samples);
+        if features.len() < MIN_FRAMES * MEL_BINS {
+            return None;
+        }
+        self.run(features)
+            .map_err(|e| log::warn!("Speaker embedding failed: {e}"))
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fbank_frames_and_normalization() {
+        let fbank = Fbank::new(16000);
+        let tone: Vec<f32> = (0..16000)
+            .map(|i| (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 16000.0).sin() * 0.1)
+            .collect();
+
+        let features = fbank.compute(&tone);
+        // (16000 - 400) / 160 + 1 frames
+        assert_eq!(features.len(), 98 * MEL_BINS);
+        let first_bin_mean = features.iter().step_by(MEL_BINS).sum::<f32>() / 98.0;
+        assert!(first_bin_mean.abs() < 1e-3);
+        assert!(fbank.compute(&tone[..399]).is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+//! Voiceprints
+//!
+//! A voiceprint is the long-term average spectral shape of someone's voice:
+//! the log energy in mel-spaced bands, averaged over voiced frames and with
+//! each frame's overall level removed, so it doesn't depend on how loud or
+//! close to the microphone they were. It's far from a neural speaker
+//! embedding, but it separates a user's own voice from a colleague across
+//! the room well enough to filter them out.
+
+use rustfft::{num_complex::Complex32, Fft, FftPlanner};
+
+const FRAME_SIZE: usize = 512;
+const HOP_SIZE: usize = 256;
+const BANDS: usize = 24;
+const MIN_HZ: f32 = 80.0;
+const MAX_HZ: f32 = 7600.0;
+
+/// Frames quieter than this RMS aren't voice
+const VOICED_RMS: f32 = 0.01;
+
+/// Fewest voiced frames a voiceprint is built from (about a third of a
+/// second at 16 kHz)
+const MIN_VOICED_FRAMES: usize = 20;
+
+/// Length of the windows audio is compared in when filtering by speaker
+const FILTER_WINDOW_SECS: f32 = 1.5;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Voiceprint {
+    bands: Vec<f32>,
+}
+
+fn hz_to_mel(hz: f32) -> f32 {
+    2595.0 * (1.0 + hz / 700.0).log10()
+}
+
+fn mel_to_hz(mel: f32) -> f32 {
+    700.0 * (10f32.powf(mel / 2595.0) - 1.0)
+}
+
+/// FFT bin ranges of the mel-spaced bands
+fn band_bins(sample_rate: u32) -> Vec<(usize, usize)> {
+    let max_hz = MAX_HZ.min(sample_rate as f32 / 2.0);
+    let (min_mel, max_mel) = (hz_to_mel(MIN_HZ), hz_to_mel(max_hz));
+    let bin_hz = sample_rate as f32 / FRAME_SIZE as f32;
+    (0..BANDS)
+        .map(|band| {
+            let edge = |i: usize| {
+                let mel = min_mel + (max_mel - min_mel) * i as f32 / BANDS as f32;
+                (mel_to_hz(mel) / bin_hz).round() as usize
+            };
+            let start = edge(band);
+            (start, edge(band + 1).max(start + 1))
+        })
+        .collect()
+}
+
+impl Voiceprint {
+    /// Build a voiceprint from mono audio, or `None` if there's too little
+    /// voice in it
+    pub fn from_samples(samples: &[f32], sample_rate: u32) -> Option<Self> {
+        let fft = FftPlanner::<f32>::new().plan_fft_forward(FRAME_SIZE);
+        let window: Vec<f32> = (0..FRAME_SIZE)
+            .map(|i| {
+                0.5 * (1.0 - (2.0 * std::f32::consts::PI * i as f32 / FRAME_SIZE as f32).cos())
+            })
+            .collect();
+        let bins = band_bins(sample_rate);
+
+        let mut sum = vec![0.0; BANDS];
+        let mut voiced = 0;
+        let mut buffer = vec![Complex32::new(0.0, 0.0); FRAME_SIZE];
+        for frame in samples.windows(FRAME_SIZE).step_by(HOP_SIZE) {
+            let rms = (frame.iter().map(|s| s * s).sum::<f32>() / FRAME_SIZE as f32).sqrt();
+            if rms < VOICED_RMS {
+                continue;
+            }
+            for ((slot, sample), w) in buffer.iter_mut().zip(frame).zip(&window) {
+                *slot = Complex32::new(sample * w, 0.0);
+            }
+            fft.process(&mut buffer);
+
+            let energies: Vec<f32> = bins
+                .iter()
+                .map(|&(start, end)| {
+                    let energy: f32 = buffer[start..end.min(FRAME_SIZE / 2)]
+                        .iter()
+                        .map(|c| c.norm_sqr())
+                        .sum();
+                    (energy + 1e-10).ln()
+                })
+                .collect();
+            // Remove the frame's level so only the shape remains
+            let level = energies.iter().sum::<f32>() / BANDS as f32;
+            for (total, energy) in sum.iter_mut().zip(&energies) {
+                *total += energy - level;
+            }
+            voiced += 1;
+        }
+
+        if voiced < MIN_VOICED_FRAMES {
+            return None;
+        }
+        Some(Self {
+            bands: sum.into_iter().map(|total| total / voiced as f32).collect(),
+        })
+    }
+
+    /// Restore a voiceprint stored with [`Voiceprint::bands`]
+    pub fn from_bands(bands: Vec<f32>) -> Option<Self> {
+        (bands.len() == BANDS).then_some(Self { bands })
+    }
+
+    pub fn bands(&self) -> &[f32] {
+        &self.bands
+    }
+
+    /// Similarity to another voiceprint, from -1 to 1 (same voice)
+    pub fn similarity(&self, other: &Voiceprint) -> f32 {
+        let dot: f32 = self
+            .bands
+            .iter()
+            .zip(&other.bands)
+            .map(|(a, b)| a * b)
+            .sum();
+        let norm = |bands: &[f32]| bands.iter().map(|b| b * b).sum::<f32>().sqrt();
+        let norms = norm(&self.bands) * norm(&other.bands);
+        if norms == 0.0 {
+            0.0
+        } else {
+            dot / norms
+        }
+    }
+}
+
+/// Keep only the parts of `samples` spoken by the voiceprint's owner.
+///
+/// The audio is compared in short windows; windows with too little voice to
+/// judge are kept, so pauses and short words aren't cut out.
+pub fn filter_to_speaker(
+    samples: &[f32],
+    sample_rate: u32,
+    speaker: &Voiceprint,
+    threshold: f32,
+) -> Vec<f32> {
+    let window = (sample_rate as f32 * FILTER_WINDOW_SECS) as usize;
+    samples
+        .chunks(window.max(FRAME_SIZE))
+        .filter(|chunk| {
+            Voiceprint::from_samples(chunk, sample_rate)
+                .is_none_or(|print| print.similarity(speaker) >= threshold)
+        })
+        .flatten()
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: u32 = 16_000;
+
+    /// A buzzy harmonic "voice" with a resonance around `formant_hz`
+    fn voice(f0: f32, formant_hz: f32, seconds: f32) -> Vec<f32> {
+        let len = (SAMPLE_RATE as f32 * seconds) as usize;
+        (0..len)
+            .map(|i| {
+                let t = i as f32 / SAMPLE_RATE as f32;
+                (1..40)
+                    .map(|h| {
+                        let hz = f0 * h as f32;
+                        let gain = 1.0 / (1.0 + ((hz - formant_hz) / 300.0).powi(2));
+                        gain * (2.0 * std::f32::consts::PI * hz * t).sin()
+                    })
+                    .sum::<f32>()
+                    * 0.05
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_voiceprint_matches_its_own_voice() {
+        let user = Voiceprint::from_samples(&voice(120.0, 700.0, 3.0), SAMPLE_RATE).unwrap();
+        let again = Voiceprint::from_samples(&voice(120.0, 700.0, 1.5), SAMPLE_RATE).unwrap();
+        let colleague = Voiceprint::from_samples(&voice(210.0, 2200.0, 1.5), SAMPLE_RATE).unwrap();
+        assert!(user.similarity(&again) > 0.95);
+        assert!(user.similarity(&colleague) < user.similarity(&again));
+
+        assert!(Voiceprint::from_samples(&[0.0; 16_000], SAMPLE_RATE).is_none());
+        assert_eq!(Voiceprint::from_bands(user.bands().to_vec()), Some(user));
+        assert!(Voiceprint::from_bands(vec![0.0; 3]).is_none());
+    }
+
+    #[test]
+    fn test_filter_drops_other_speakers() {
+        let user = Voiceprint::from_samples(&voice(120.0, 700.0, 3.0), SAMPLE_RATE).unwrap();
+        let mut audio = voice(120.0, 700.0, 1.5);
+        audio.extend(voice(210.0, 2200.0, 1.5));
+        audio.extend(vec![0.0; 24_000]);
+
+        let filtered = filter_to_speaker(&audio, SAMPLE_RATE, &user, 0.9);
+        assert_eq!(filtered.len(), 48_000);
+        assert_eq!(filtered[..24_000], audio[..24_000]);
+    }
+}
@@ -1,3 +1,24 @@
+//! Audio toolkit
+//!
+//! Capture, VAD, diarization and decoding, usable without the app: nothing
+//! in here takes an `AppHandle` or reads app settings. Callers configure
+//! each piece directly:
+//!
+//! ```ignore
+//! use dictum_app_lib::audio_toolkit::{AudioRecorder, DiarizationConfig, VadConfig};
+//!
+//! let vad = VadConfig::default().with_threshold(0.4).build("silero_vad_v4.onnx")?;
+//! let mut recorder = AudioRecorder::new()?.with_vad(Box::new(vad));
+//! recorder.open(None)?;
+//! recorder.start()?;
+//! let samples = recorder.stop()?;
+//!
+//! let config = DiarizationConfig::default().with_min_silence_duration_ms(300);
+//! ```
+//!
+//! `src/audio_toolkit/bin/cli.rs` is a small recorder built on this API
+//! (`cargo run --bin cli --features cli`).
+
 pub mod audio;
 pub mod constants;
 pub mod decoder;
@@ -5,16 +26,67 @@ pub mod diarization;
 pub mod text;
 pub mod utils;
 pub mod sound_detector;
+pub mod tuning;
 pub mod vad;
+pub mod voice_commands;
+pub mod wake_word;
 
 pub use audio::{
-    list_input_devices, list_output_devices, save_wav_file, AudioRecorder, CpalDeviceInfo,
+    find_device, find_device_index, list_input_devices, list_output_devices, save_wav_file,
+    AudioRecorder, CpalDeviceInfo, DigitalSilenceMonitor,
 };
 pub use diarization::{
-    create_shared_diarizer, DiarizationConfig, EnergyBasedDiarizer, SharedDiarizer,
-    SpeakerChange, SpeakerDiarizer, SpeakerId,
+    create_shared_diarizer, filter_to_speaker, DiarizationConfig, EmbeddingDiarizer,
+    EmbeddingDiarizerConfig, EnergyBasedDiarizer, OnnxSpeakerEmbedder, SharedDiarizer,
+    SpeakerChange, SpeakerDiarizer, SpeakerEmbedder, SpeakerId, SpectralEmbedder, Voiceprint,
 };
-pub use text::{apply_custom_words, filter_transcription_output};
+pub use text::{apply_custom_words, filter_transcription_output, format_for_code, IdentifierCase};
 pub use utils::get_cpal_host;
-pub use sound_detector::{SoundDetector, SoundEvent};
-pub use vad::{SileroVad, VoiceActivityDetector};
+pub use sound_detector::{SoundCategory, SoundDetector, SoundEvent};
+pub use vad::{SileroVad, SmoothedVad, VadConfig, VoiceActivityDetector};
+pub use voice_commands::{VoiceCommand, VoiceCommandAction, VoiceCommandEngine};
+pub use wake_word::{OnnxWakeWordModel, WakeWordDetector, WakeWordModel};
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    fn check_sources(dir: &Path, violations: &mut Vec<String>) {
+        for entry in fs::read_dir(dir).unwrap().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                check_sources(&path, violations);
+                continue;
+            }
+            if path.extension().is_none_or(|ext| ext != "rs") {
+                continue;
+            }
+            let source = fs::read_to_string(&path).unwrap();
+            let code = source
+                .lines()
+                .enumerate()
+                .take_while(|(_, line)| !line.contains("#[cfg(test)]"))
+                .filter(|(_, line)| !line.trim_start().starts_with("//"));
+            for (number, line) in code {
+                let app_path = line.contains("crate::") && !line.contains("crate::audio_toolkit");
+                if app_path || line.contains("tauri::") || line.contains("AppHandle") {
+                    violations.push(format!(
+                        "{}:{}: {}",
+                        path.display(),
+                        number + 1,
+                        line.trim()
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_toolkit_does_not_depend_on_the_app() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/audio_toolkit");
+        let mut violations = Vec::new();
+        check_sources(&dir, &mut violations);
+        assert!(violations.is_empty(), "{}", violations.join("\n"));
+    }
+}
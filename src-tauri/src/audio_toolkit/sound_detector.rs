@@ -4,10 +4,23 @@
 //! The actual ONNX inference is a placeholder that returns an empty Vec.
 //! Dropping in a real model later requires ~20 lines of change in `detect_sounds`.
 
-use crate::settings::sound_detection::{SoundCategory, SoundDetectionSettings};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+/// Categories of environmental sounds that can be detected
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SoundCategory {
+    Doorbell,
+    Alarm,
+    PhoneRing,
+    DogBark,
+    BabyCry,
+    Knocking,
+    Siren,
+    Applause,
+}
+
 /// A detected sound event
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct SoundEvent {
@@ -33,11 +46,12 @@ impl SoundDetector {
         }
     }
 
-    /// Sync detector state from application settings
-    pub fn update_settings(&mut self, settings: &SoundDetectionSettings) {
-        self.enabled = settings.enabled;
-        self.threshold = settings.threshold;
-        self.categories = settings.categories.clone();
+    /// Set whether detection runs, its confidence threshold (0.0-1.0) and
+    /// which categories are reported
+    pub fn configure(&mut self, enabled: bool, threshold: f32, categories: Vec<SoundCategory>) {
+        self.enabled = enabled;
+        self.threshold = threshold;
+        self.categories = categories;
     }
 
     /// Detect environmental sounds in audio samples.
@@ -89,15 +103,9 @@ mod tests {
     }
 
     #[test]
-    fn test_update_settings() {
+    fn test_configure() {
         let mut detector = SoundDetector::new();
-        let settings = SoundDetectionSettings {
-            enabled: true,
-            categories: vec![SoundCategory::Alarm, SoundCategory::Siren],
-            threshold: 0.7,
-            notification_enabled: true,
-        };
-        detector.update_settings(&settings);
+        detector.configure(true, 0.7, vec![SoundCategory::Alarm, SoundCategory::Siren]);
         assert!(detector.enabled);
         assert_eq!(detector.threshold, 0.7);
         assert_eq!(detector.categories.len(), 2);
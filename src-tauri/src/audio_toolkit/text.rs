@@ -1,4 +1,6 @@
 use natural::phonetics::soundex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
 use strsim::levenshtein;
 
 /// Applies custom word corrections to transcribed text using fuzzy matching
@@ -175,6 +177,109 @@ pub fn filter_transcription_output(text: &str) -> String {
     trimmed.to_string()
 }
 
+/// How spoken identifiers are written when dictating code
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentifierCase {
+    Snake,
+    Camel,
+}
+
+/// Punctuation the model adds at the end of words and clauses
+const SENTENCE_PUNCTUATION: &[char] = &['.', ',', '!', '?'];
+
+/// Formats a transcription for pasting into a code editor or terminal
+///
+/// Sentence punctuation the model added at the end of words is dropped.
+/// "variable name", "snake case" and "camel case" turn the words that follow
+/// them, up to the next punctuation mark or the end of the text, into one
+/// identifier. "variable name" uses `case`.
+///
+/// # Arguments
+/// * `text` - The transcribed text to format
+/// * `case` - Case used for identifiers introduced by "variable name"
+///
+/// # Returns
+/// The formatted text
+pub fn format_for_code(text: &str, case: IdentifierCase) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut formatted: Vec<String> = Vec::new();
+    let mut i = 0;
+
+    while i < words.len() {
+        if let Some(identifier_case) = identifier_trigger(&words[i..], case) {
+            let mut parts = Vec::new();
+            let mut end = i + 2;
+            while end < words.len() {
+                let word = words[end];
+                end += 1;
+                let part: String = word
+                    .chars()
+                    .filter(|c| c.is_alphanumeric())
+                    .collect::<String>()
+                    .to_lowercase();
+                if !part.is_empty() {
+                    parts.push(part);
+                }
+                if word.ends_with(SENTENCE_PUNCTUATION) {
+                    break;
+                }
+            }
+            if !parts.is_empty() {
+                formatted.push(join_identifier(&parts, identifier_case));
+                i = end;
+                continue;
+            }
+        }
+
+        let word = words[i].trim_end_matches(SENTENCE_PUNCTUATION);
+        if !word.is_empty() {
+            formatted.push(word.to_string());
+        }
+        i += 1;
+    }
+
+    formatted.join(" ")
+}
+
+/// The case an identifier phrase at the start of `words` asks for
+fn identifier_trigger(words: &[&str], default_case: IdentifierCase) -> Option<IdentifierCase> {
+    let [first, second, ..] = words else {
+        return None;
+    };
+    let phrase = format!(
+        "{} {}",
+        first.to_lowercase(),
+        second.trim_end_matches(SENTENCE_PUNCTUATION).to_lowercase()
+    );
+    match phrase.as_str() {
+        "variable name" => Some(default_case),
+        "snake case" => Some(IdentifierCase::Snake),
+        "camel case" => Some(IdentifierCase::Camel),
+        _ => None,
+    }
+}
+
+fn join_identifier(parts: &[String], case: IdentifierCase) -> String {
+    match case {
+        IdentifierCase::Snake => parts.join("_"),
+        IdentifierCase::Camel => parts
+            .iter()
+            .enumerate()
+            .map(|(i, part)| {
+                if i == 0 {
+                    return part.clone();
+                }
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -316,4 +421,27 @@ mod tests {
         let result = apply_custom_words(text, &custom_words, 0.5);
         assert_eq!(result, "Hello 123 World");
     }
+
+    #[test]
+    fn test_format_for_code() {
+        assert_eq!(
+            format_for_code(
+                "Set variable name max retries, to three.",
+                IdentifierCase::Snake
+            ),
+            "Set max_retries to three"
+        );
+        assert_eq!(
+            format_for_code("Rename it to camel case user name.", IdentifierCase::Snake),
+            "Rename it to userName"
+        );
+        assert_eq!(
+            format_for_code("git status.", IdentifierCase::Camel),
+            "git status"
+        );
+        assert_eq!(
+            format_for_code("Call foo.bar now!", IdentifierCase::Snake),
+            "Call foo.bar now"
+        );
+    }
 }
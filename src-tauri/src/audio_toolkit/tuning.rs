@@ -0,0 +1,526 @@
+//! VAD and diarization tuning
+//!
+//! Runs labelled audio through a grid of VAD thresholds and diarization
+//! configs, scores every combination against the labels and recommends the
+//! best one. VAD needs real speech, so it is only scored on samples the
+//! user records; diarization is energy based and is also scored on
+//! synthetic two-speaker fixtures.
+//!
+//! Silero's speech probability doesn't depend on the threshold, so it is
+//! computed once per frame and each threshold is replayed through the same
+//! smoothing the recorder uses.
+
+use crate::audio_toolkit::diarization::{DiarizationConfig, EnergyBasedDiarizer, SpeakerDiarizer};
+use crate::audio_toolkit::vad::{SileroVad, VadConfig, VadFrame, VoiceActivityDetector};
+use serde::Serialize;
+use specta::Type;
+use std::ops::Range;
+
+/// VAD frame length at 16 kHz (30 ms)
+pub const FRAME_SAMPLES: usize = 480;
+
+const SAMPLE_RATE: usize = 16_000;
+
+/// VAD thresholds tried
+pub const VAD_THRESHOLDS: [f32; 7] = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7];
+
+/// Diarization silence gaps tried, in milliseconds
+pub const MIN_SILENCE_DURATIONS_MS: [u32; 3] = [300, 500, 800];
+
+/// Diarization energy change ratios tried
+pub const ENERGY_CHANGE_THRESHOLDS: [f32; 3] = [1.5, 2.0, 3.0];
+
+/// How close a detected speaker change must be to a real one
+const TURN_TOLERANCE_SAMPLES: usize = SAMPLE_RATE;
+
+/// F1 scores this close count as a tie
+const F1_TIE: f64 = 0.01;
+
+/// Audio with known speech and speaker turns
+#[derive(Clone, Debug, Default)]
+pub struct LabelledAudio {
+    pub name: String,
+    /// 16 kHz mono samples
+    pub samples: Vec<f32>,
+    /// Sample ranges containing speech; `None` skips VAD scoring
+    pub speech: Option<Vec<Range<usize>>>,
+    /// Sample offsets where the speaker changes; `None` skips diarization
+    pub speaker_changes: Option<Vec<usize>>,
+}
+
+/// Detection quality for one VAD threshold
+#[derive(Serialize, Clone, Debug, PartialEq, Type)]
+pub struct VadScore {
+    pub threshold: f32,
+    /// Share of kept audio that is speech
+    pub precision: f64,
+    /// Share of speech that was kept (the rest was cut off)
+    pub recall: f64,
+    pub f1: f64,
+    /// Share of non-speech that was kept
+    pub false_positive_rate: f64,
+}
+
+/// Detection quality for one diarization config
+#[derive(Serialize, Clone, Debug, PartialEq, Type)]
+pub struct DiarizationScore {
+    pub min_silence_duration_ms: u32,
+    pub energy_change_threshold: f32,
+    /// Share of detected speaker changes that were real
+    pub precision: f64,
+    /// Share of real speaker changes that were detected
+    pub recall: f64,
+    pub f1: f64,
+}
+
+/// Scores for every parameter combination plus the recommendation
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct TuningReport {
+    /// Names of the audio that was scored
+    pub samples: Vec<String>,
+    pub vad: Vec<VadScore>,
+    pub diarization: Vec<DiarizationScore>,
+    pub recommended_vad: Option<VadScore>,
+    pub recommended_diarization: Option<DiarizationScore>,
+}
+
+#[derive(Default, Clone, Copy)]
+struct Counts {
+    true_positives: usize,
+    false_positives: usize,
+    false_negatives: usize,
+    true_negatives: usize,
+}
+
+impl Counts {
+    fn add(&mut self, other: Counts) {
+        self.true_positives += other.true_positives;
+        self.false_positives += other.false_positives;
+        self.false_negatives += other.false_negatives;
+        self.true_negatives += other.true_negatives;
+    }
+
+    fn precision(&self) -> f64 {
+        ratio(
+            self.true_positives,
+            self.true_positives + self.false_positives,
+        )
+    }
+
+    fn recall(&self) -> f64 {
+        ratio(
+            self.true_positives,
+            self.true_positives + self.false_negatives,
+        )
+    }
+
+    fn f1(&self) -> f64 {
+        let (p, r) = (self.precision(), self.recall());
+        if p + r == 0.0 {
+            0.0
+        } else {
+            2.0 * p * r / (p + r)
+        }
+    }
+
+    fn false_positive_rate(&self) -> f64 {
+        ratio(
+            self.false_positives,
+            self.false_positives + self.true_negatives,
+        )
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+/// Speech probability of every full frame
+pub fn vad_probabilities(vad: &mut SileroVad, samples: &[f32]) -> anyhow::Result<Vec<f32>> {
+    samples
+        .chunks_exact(FRAME_SAMPLES)
+        .map(|frame| vad.speech_probability(frame))
+        .collect()
+}
+
+/// Replays precomputed decisions so they can be smoothed
+struct ReplayVad {
+    decisions: std::vec::IntoIter<bool>,
+}
+
+impl VoiceActivityDetector for ReplayVad {
+    fn push_frame<'a>(&'a mut self, frame: &'a [f32]) -> anyhow::Result<VadFrame<'a>> {
+        if self.decisions.next().unwrap_or(false) {
+            Ok(VadFrame::Speech(frame))
+        } else {
+            Ok(VadFrame::Noise)
+        }
+    }
+}
+
+/// Which frames the recorder would keep at this threshold, including the
+/// pre-roll it adds when speech starts
+fn kept_frames(probabilities: &[f32], threshold: f32) -> Vec<bool> {
+    let decisions: Vec<bool> = probabilities.iter().map(|p| *p > threshold).collect();
+    let replay = ReplayVad {
+        decisions: decisions.into_iter(),
+    };
+    // Same smoothing as the recorder
+    let mut vad = VadConfig::default().smooth(Box::new(replay));
+
+    let frame = [0.0; FRAME_SAMPLES];
+    let mut kept = vec![false; probabilities.len()];
+    for i in 0..probabilities.len() {
+        if let Ok(VadFrame::Speech(buf)) = vad.push_frame(&frame) {
+            let frames = (buf.len() / FRAME_SAMPLES).max(1);
+            for k in kept
+                .iter_mut()
+                .take(i + 1)
+                .skip((i + 1).saturating_sub(frames))
+            {
+                *k = true;
+            }
+        }
+    }
+    kept
+}
+
+/// Whether each frame's midpoint falls inside a speech range
+fn frame_labels(frame_count: usize, speech: &[Range<usize>]) -> Vec<bool> {
+    (0..frame_count)
+        .map(|i| {
+            let midpoint = i * FRAME_SAMPLES + FRAME_SAMPLES / 2;
+            speech.iter().any(|range| range.contains(&midpoint))
+        })
+        .collect()
+}
+
+fn vad_counts(kept: &[bool], labels: &[bool]) -> Counts {
+    let mut counts = Counts::default();
+    for (&kept, &speech) in kept.iter().zip(labels) {
+        match (kept, speech) {
+            (true, true) => counts.true_positives += 1,
+            (true, false) => counts.false_positives += 1,
+            (false, true) => counts.false_negatives += 1,
+            (false, false) => counts.true_negatives += 1,
+        }
+    }
+    counts
+}
+
+/// Score every VAD threshold over audio whose frame probabilities are known
+pub fn score_vad(audio: &[(&LabelledAudio, Vec<f32>)]) -> Vec<VadScore> {
+    VAD_THRESHOLDS
+        .iter()
+        .map(|&threshold| {
+            let mut counts = Counts::default();
+            for (sample, probabilities) in audio {
+                let Some(speech) = &sample.speech else {
+                    continue;
+                };
+                let labels = frame_labels(probabilities.len(), speech);
+                counts.add(vad_counts(&kept_frames(probabilities, threshold), &labels));
+            }
+            VadScore {
+                threshold,
+                precision: counts.precision(),
+                recall: counts.recall(),
+                f1: counts.f1(),
+                false_positive_rate: counts.false_positive_rate(),
+            }
+        })
+        .collect()
+}
+
+fn detected_changes(samples: &[f32], config: DiarizationConfig) -> Vec<usize> {
+    let mut diarizer = EnergyBasedDiarizer::with_config(config);
+    samples
+        .chunks(FRAME_SAMPLES)
+        .filter_map(|frame| diarizer.process_frame(frame))
+        .map(|change| change.sample_offset)
+        .collect()
+}
+
+/// Match detections to real changes within the tolerance, each at most once
+fn change_counts(detected: &[usize], actual: &[usize]) -> Counts {
+    let mut matched = vec![false; detected.len()];
+    let mut counts = Counts::default();
+    for &change in actual {
+        let nearest = detected
+            .iter()
+            .enumerate()
+            .filter(|(i, d)| !matched[*i] && d.abs_diff(change) <= TURN_TOLERANCE_SAMPLES)
+            .min_by_key(|(_, d)| d.abs_diff(change))
+            .map(|(i, _)| i);
+        match nearest {
+            Some(i) => {
+                matched[i] = true;
+                counts.true_positives += 1;
+            }
+            None => counts.false_negatives += 1,
+        }
+    }
+    counts.false_positives = matched.iter().filter(|m| !**m).count();
+    counts
+}
+
+/// Score every diarization config over audio with known speaker changes
+pub fn score_diarization(audio: &[LabelledAudio]) -> Vec<DiarizationScore> {
+    let mut scores = Vec::new();
+    for &min_silence_duration_ms in &MIN_SILENCE_DURATIONS_MS {
+        for &energy_change_threshold in &ENERGY_CHANGE_THRESHOLDS {
+            let config = DiarizationConfig::default()
+                .with_min_silence_duration_ms(min_silence_duration_ms)
+                .with_energy_change_threshold(energy_change_threshold);
+            let mut counts = Counts::default();
+            for sample in audio {
+                let Some(actual) = &sample.speaker_changes else {
+                    continue;
+                };
+                let detected = detected_changes(&sample.samples, config.clone());
+                counts.add(change_counts(&detected, actual));
+            }
+            scores.push(DiarizationScore {
+                min_silence_duration_ms,
+                energy_change_threshold,
+                precision: counts.precision(),
+                recall: counts.recall(),
+                f1: counts.f1(),
+            });
+        }
+    }
+    scores
+}
+
+/// Best F1; among near-ties prefer the threshold that cuts off less speech
+pub fn recommend_vad(scores: &[VadScore]) -> Option<VadScore> {
+    let best_f1 = scores.iter().map(|s| s.f1).fold(0.0, f64::max);
+    if best_f1 == 0.0 {
+        return None;
+    }
+    scores
+        .iter()
+        .filter(|s| s.f1 >= best_f1 - F1_TIE)
+        .max_by(|a, b| a.recall.total_cmp(&b.recall))
+        .cloned()
+}
+
+/// Best F1; the first config wins a tie
+pub fn recommend_diarization(scores: &[DiarizationScore]) -> Option<DiarizationScore> {
+    scores
+        .iter()
+        .filter(|s| s.f1 > 0.0)
+        .fold(None, |best: Option<&DiarizationScore>, s| match best {
+            Some(b) if b.f1 >= s.f1 => Some(b),
+            _ => Some(s),
+        })
+        .cloned()
+}
+
+/// Deterministic noise so fixtures are the same on every run
+struct Noise(u32);
+
+impl Noise {
+    fn next(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (self.0 >> 8) as f32 / (1u32 << 24) as f32 * 2.0 - 1.0
+    }
+}
+
+/// A voiced-sounding segment: a harmonic tone with syllable-rate
+/// amplitude modulation
+fn voice(samples: &mut Vec<f32>, noise: &mut Noise, secs: f32, pitch_hz: f32, level: f32) {
+    let count = (secs * SAMPLE_RATE as f32) as usize;
+    for n in 0..count {
+        let t = n as f32 / SAMPLE_RATE as f32;
+        let tone: f32 = (1..=3)
+            .map(|h| (2.0 * std::f32::consts::PI * pitch_hz * h as f32 * t).sin() / h as f32)
+            .sum();
+        let syllables = 0.6 + 0.4 * (2.0 * std::f32::consts::PI * 4.0 * t).sin().abs();
+        samples.push(level * syllables * tone * 0.6 + noise.next() * 0.003);
+    }
+}
+
+fn pause(samples: &mut Vec<f32>, noise: &mut Noise, secs: f32) {
+    let count = (secs * SAMPLE_RATE as f32) as usize;
+    samples.extend((0..count).map(|_| noise.next() * 0.003));
+}
+
+/// (seconds, speaker) turns of a fixture; `None` is a pause
+type Turns = &'static [(f32, Option<usize>)];
+
+/// Synthetic conversations between a quiet and a loud speaker, labelled
+/// with their speaker changes
+pub fn diarization_fixtures() -> Vec<LabelledAudio> {
+    let conversations: [(&str, Turns); 2] = [
+        (
+            "fixture: turns with pauses",
+            &[
+                (3.0, Some(0)),
+                (0.8, None),
+                (2.5, Some(1)),
+                (0.7, None),
+                (3.0, Some(0)),
+                (1.0, None),
+                (2.0, Some(1)),
+            ],
+        ),
+        (
+            "fixture: interruptions",
+            &[
+                (3.5, Some(0)),
+                (3.0, Some(1)),
+                (0.2, None),
+                (3.0, Some(0)),
+                (2.5, Some(1)),
+            ],
+        ),
+    ];
+    let speakers = [(140.0, 0.08), (210.0, 0.3)];
+
+    conversations
+        .iter()
+        .map(|(name, turns)| {
+            let mut noise = Noise(7);
+            let mut samples = Vec::new();
+            let mut changes = Vec::new();
+            let mut last_speaker = None;
+            for &(secs, speaker) in turns.iter() {
+                match speaker {
+                    Some(speaker) => {
+                        if last_speaker.is_some_and(|last| last != speaker) {
+                            changes.push(samples.len());
+                        }
+                        last_speaker = Some(speaker);
+                        let (pitch, level) = speakers[speaker];
+                        voice(&mut samples, &mut noise, secs, pitch, level);
+                    }
+                    None => pause(&mut samples, &mut noise, secs),
+                }
+            }
+            LabelledAudio {
+                name: name.to_string(),
+                samples,
+                speech: None,
+                speaker_changes: Some(changes),
+            }
+        })
+        .collect()
+}
+
+/// Score VAD and diarization over the given audio. `probabilities` holds
+/// the Silero frame probabilities for each entry of `audio` that has
+/// speech labels, in order.
+pub fn run_benchmark(audio: &[LabelledAudio], probabilities: Vec<Vec<f32>>) -> TuningReport {
+    let vad_inputs: Vec<(&LabelledAudio, Vec<f32>)> = audio
+        .iter()
+        .filter(|a| a.speech.is_some())
+        .zip(probabilities)
+        .collect();
+    let vad = if vad_inputs.is_empty() {
+        Vec::new()
+    } else {
+        score_vad(&vad_inputs)
+    };
+
+    let diarization = if audio.iter().any(|a| a.speaker_changes.is_some()) {
+        score_diarization(audio)
+    } else {
+        Vec::new()
+    };
+
+    TuningReport {
+        samples: audio.iter().map(|a| a.name.clone()).collect(),
+        recommended_vad: recommend_vad(&vad),
+        recommended_diarization: recommend_diarization(&diarization),
+        vad,
+        diarization,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kept_frames_include_pre_roll_and_hangover() {
+        // 20 silent frames, 10 speech frames, 30 silent frames
+        let mut probabilities = vec![0.0; 20];
+        probabilities.extend(vec![0.9; 10]);
+        probabilities.extend(vec![0.0; 30]);
+
+        let kept = kept_frames(&probabilities, 0.5);
+        // Speech starts at frame 20 and is confirmed at 21; 15 frames of
+        // pre-roll reach back to frame 6
+        assert!(!kept[5]);
+        assert!(kept[6..30].iter().all(|k| *k));
+        // 15 frames of hangover after the last speech frame
+        assert!(kept[44]);
+        assert!(!kept[45]);
+    }
+
+    #[test]
+    fn test_vad_scores_reward_matching_threshold() {
+        let speech_frames = [40..60, 80..100];
+        let probabilities: Vec<f32> = (0..140)
+            .map(|i| {
+                if speech_frames.iter().any(|r| r.contains(&i)) {
+                    0.6
+                } else {
+                    0.15
+                }
+            })
+            .collect();
+        let audio = LabelledAudio {
+            name: "sample".to_string(),
+            samples: Vec::new(),
+            speech: Some(
+                speech_frames
+                    .iter()
+                    .map(|r| r.start * FRAME_SAMPLES..r.end * FRAME_SAMPLES)
+                    .collect(),
+            ),
+            speaker_changes: None,
+        };
+
+        let scores = score_vad(&[(&audio, probabilities)]);
+        let at = |t: f32| scores.iter().find(|s| s.threshold == t).unwrap();
+
+        // Too low keeps the noise, too high drops everything
+        assert!(at(0.1).false_positive_rate > 0.9);
+        assert_eq!(at(0.7).recall, 0.0);
+        assert_eq!(at(0.3).recall, 1.0);
+
+        let best = recommend_vad(&scores).unwrap();
+        assert!((0.2..=0.5).contains(&best.threshold));
+    }
+
+    #[test]
+    fn test_change_matching_uses_tolerance() {
+        let counts = change_counts(&[16_000, 50_000, 90_000], &[20_000, 100_000, 200_000]);
+        assert_eq!(counts.true_positives, 2);
+        assert_eq!(counts.false_positives, 1);
+        assert_eq!(counts.false_negatives, 1);
+    }
+
+    #[test]
+    fn test_fixtures_produce_a_diarization_recommendation() {
+        let fixtures = diarization_fixtures();
+        assert!(fixtures
+            .iter()
+            .all(|f| f.speaker_changes.as_ref().is_some_and(|c| !c.is_empty())));
+
+        let report = run_benchmark(&fixtures, Vec::new());
+        assert!(report.vad.is_empty());
+        assert!(report.recommended_vad.is_none());
+        assert_eq!(
+            report.diarization.len(),
+            MIN_SILENCE_DURATIONS_MS.len() * ENERGY_CHANGE_THRESHOLDS.len()
+        );
+        assert!(report.recommended_diarization.is_some());
+    }
+}
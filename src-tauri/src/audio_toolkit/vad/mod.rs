@@ -1,4 +1,5 @@
 use anyhow::Result;
+use std::path::Path;
 
 pub enum VadFrame<'a> {
     /// Speech – may aggregate several frames (prefill + current + hangover)
@@ -30,3 +31,70 @@ mod smoothed;
 
 pub use silero::SileroVad;
 pub use smoothed::SmoothedVad;
+
+/// Configuration of the recording VAD: Silero followed by smoothing.
+///
+/// ```ignore
+/// let vad = VadConfig::default().with_threshold(0.4).build(model_path)?;
+/// let recorder = AudioRecorder::new()?.with_vad(Box::new(vad));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct VadConfig {
+    /// Speech probability above which a frame counts as speech (0.0-1.0)
+    pub threshold: f32,
+    /// Frames kept from before speech starts
+    pub prefill_frames: usize,
+    /// Frames kept after speech stops
+    pub hangover_frames: usize,
+    /// Consecutive speech frames needed to start speech
+    pub onset_frames: usize,
+}
+
+impl Default for VadConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.3,
+            prefill_frames: 15,
+            hangover_frames: 15,
+            onset_frames: 2,
+        }
+    }
+}
+
+impl VadConfig {
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn with_prefill_frames(mut self, frames: usize) -> Self {
+        self.prefill_frames = frames;
+        self
+    }
+
+    pub fn with_hangover_frames(mut self, frames: usize) -> Self {
+        self.hangover_frames = frames;
+        self
+    }
+
+    pub fn with_onset_frames(mut self, frames: usize) -> Self {
+        self.onset_frames = frames;
+        self
+    }
+
+    /// Smooth the decisions of any detector with this configuration
+    pub fn smooth(&self, inner: Box<dyn VoiceActivityDetector>) -> SmoothedVad {
+        SmoothedVad::new(
+            inner,
+            self.prefill_frames,
+            self.hangover_frames,
+            self.onset_frames,
+        )
+    }
+
+    /// Load the Silero model from `model_path` and smooth it
+    pub fn build<P: AsRef<Path>>(&self, model_path: P) -> Result<SmoothedVad> {
+        let silero = SileroVad::new(model_path, self.threshold)?;
+        Ok(self.smooth(Box::new(silero)))
+    }
+}
@@ -0,0 +1,228 @@
+//! Voice commands
+//!
+//! Spoken commands inside a transcription, like "new paragraph", "comma" or
+//! "delete that", turned into the formatting or edit they ask for. Commands
+//! are matched on whole words, ignoring case and the punctuation the model
+//! added around them; the longest phrase wins where several match.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// What a voice command does to the text dictated so far
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum VoiceCommandAction {
+    NewLine,
+    NewParagraph,
+    /// Insert text, e.g. a punctuation mark. Closing punctuation attaches
+    /// to the word before it.
+    Insert {
+        text: String,
+    },
+    /// Remove the sentence dictated last
+    DeleteLastSentence,
+    DeleteLastWord,
+    /// Capitalize the next word
+    CapitalizeNext,
+    /// Write the next word in capitals
+    UppercaseNext,
+}
+
+/// A spoken phrase and the action it triggers
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct VoiceCommand {
+    pub phrase: String,
+    pub action: VoiceCommandAction,
+}
+
+/// Characters that end a sentence
+const SENTENCE_ENDS: &[char] = &['.', '!', '?'];
+/// Punctuation the model adds after a word that an inserted mark replaces
+const MODEL_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?'];
+/// Inserted text starting with one of these goes right after the last word
+const CLOSING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', ')'];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Case {
+    Capitalize,
+    Uppercase,
+}
+
+/// Lowercased word without surrounding punctuation, for matching
+fn normalize(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+fn apply_case(word: &str, case: Option<Case>) -> String {
+    match case {
+        Some(Case::Uppercase) => word.to_uppercase(),
+        Some(Case::Capitalize) => {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+        None => word.to_string(),
+    }
+}
+
+/// Recognizes a set of voice commands in transcriptions
+pub struct VoiceCommandEngine {
+    /// Normalized phrase words and their action, longest phrase first
+    commands: Vec<(Vec<String>, VoiceCommandAction)>,
+}
+
+impl VoiceCommandEngine {
+    pub fn new(commands: &[VoiceCommand]) -> Self {
+        let mut commands: Vec<(Vec<String>, VoiceCommandAction)> = commands
+            .iter()
+            .map(|command| {
+                let words: Vec<String> = command
+                    .phrase
+                    .split_whitespace()
+                    .map(normalize)
+                    .filter(|word| !word.is_empty())
+                    .collect();
+                (words, command.action.clone())
+            })
+            .filter(|(words, _)| !words.is_empty())
+            .collect();
+        commands.sort_by_key(|(words, _)| std::cmp::Reverse(words.len()));
+        Self { commands }
+    }
+
+    /// The command spoken at the start of `words`, and how many words it
+    /// takes up
+    fn command_at(&self, words: &[&str]) -> Option<(usize, &VoiceCommandAction)> {
+        self.commands.iter().find_map(|(phrase, action)| {
+            let matches = phrase.len() <= words.len()
+                && phrase
+                    .iter()
+                    .zip(words)
+                    .all(|(expected, word)| *expected == normalize(word));
+            matches.then_some((phrase.len(), action))
+        })
+    }
+
+    /// Carry out the commands in a transcription
+    pub fn apply(&self, text: &str) -> String {
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let mut out = String::new();
+        let mut case = None;
+        let mut i = 0;
+
+        while i < words.len() {
+            let Some((len, action)) = self.command_at(&words[i..]) else {
+                if !out.is_empty() && !out.ends_with('\n') {
+                    out.push(' ');
+                }
+                out.push_str(&apply_case(words[i], case.take()));
+                i += 1;
+                continue;
+            };
+            i += len;
+
+            match action {
+                VoiceCommandAction::NewLine | VoiceCommandAction::NewParagraph => {
+                    out.truncate(out.trim_end_matches(' ').len());
+                    out.push('\n');
+                    if *action == VoiceCommandAction::NewParagraph {
+                        out.push('\n');
+                    }
+                    case = Some(Case::Capitalize);
+                }
+                VoiceCommandAction::Insert { text } => {
+                    if text.starts_with(CLOSING_PUNCTUATION) {
+                        let end = out.trim_end().trim_end_matches(MODEL_PUNCTUATION).len();
+                        out.truncate(end);
+                    } else if !out.is_empty() && !out.ends_with('\n') {
+                        out.push(' ');
+                    }
+                    out.push_str(text);
+                    if text.ends_with(SENTENCE_ENDS) {
+                        case = Some(Case::Capitalize);
+                    }
+                }
+                VoiceCommandAction::DeleteLastSentence => {
+                    let text = out.trim_end().trim_end_matches(MODEL_PUNCTUATION);
+                    let end = text
+                        .rfind(|c: char| SENTENCE_ENDS.contains(&c) || c == '\n')
+                        .map_or(0, |index| index + 1);
+                    out.truncate(end);
+                }
+                VoiceCommandAction::DeleteLastWord => {
+                    let text = out.trim_end();
+                    let end = text.rfind(char::is_whitespace).map_or(0, |index| index + 1);
+                    out.truncate(out[..end].trim_end_matches(' ').len());
+                }
+                VoiceCommandAction::CapitalizeNext => case = Some(Case::Capitalize),
+                VoiceCommandAction::UppercaseNext => case = Some(Case::Uppercase),
+            }
+        }
+
+        out.trim().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn engine() -> VoiceCommandEngine {
+        let command = |phrase: &str, action| VoiceCommand {
+            phrase: phrase.to_string(),
+            action,
+        };
+        let insert = |text: &str| VoiceCommandAction::Insert {
+            text: text.to_string(),
+        };
+        VoiceCommandEngine::new(&[
+            command("new line", VoiceCommandAction::NewLine),
+            command("new paragraph", VoiceCommandAction::NewParagraph),
+            command("comma", insert(",")),
+            command("period", insert(".")),
+            command("delete that", VoiceCommandAction::DeleteLastSentence),
+            command("undo last sentence", VoiceCommandAction::DeleteLastSentence),
+            command("scratch that word", VoiceCommandAction::DeleteLastWord),
+            command("capitalize", VoiceCommandAction::CapitalizeNext),
+            command("all caps", VoiceCommandAction::UppercaseNext),
+        ])
+    }
+
+    #[test]
+    fn test_formatting_commands() {
+        assert_eq!(
+            engine().apply("Dear team comma thanks period New paragraph. see you soon"),
+            "Dear team, thanks.\n\nSee you soon"
+        );
+        assert_eq!(engine().apply("first, New line. second"), "first,\nSecond");
+        assert_eq!(
+            engine().apply("this is all caps urgent and capitalize rust"),
+            "this is URGENT and Rust"
+        );
+    }
+
+    #[test]
+    fn test_edit_commands() {
+        assert_eq!(
+            engine().apply("Keep this. Drop this one. Delete that. And go on"),
+            "Keep this. And go on"
+        );
+        assert_eq!(engine().apply("Only sentence undo last sentence"), "");
+        assert_eq!(
+            engine().apply("one two three scratch that word four"),
+            "one two four"
+        );
+    }
+
+    #[test]
+    fn test_plain_text_is_left_alone() {
+        assert_eq!(
+            engine().apply("  Nothing to see here, really.  "),
+            "Nothing to see here, really."
+        );
+        assert_eq!(VoiceCommandEngine::new(&[]).apply("new line"), "new line");
+    }
+}
@@ -0,0 +1,235 @@
+//! Wake word detection
+//!
+//! Listens for a spoken keyword on a 16 kHz stream. [`OnnxWakeWordModel`]
+//! runs openWakeWord-style models: a shared mel spectrogram model and
+//! speech embedding model turn the audio into features, and a small keyword
+//! model (e.g. one trained for "Hey Handy") scores the last 16 embeddings.
+//! Every 80 ms chunk of audio adds one embedding.
+
+use anyhow::Result;
+use ort::session::Session;
+use ort::value::Tensor;
+use std::collections::VecDeque;
+use std::path::Path;
+
+/// Samples per scored chunk: 80 ms at 16 kHz
+pub const CHUNK_SAMPLES: usize = 1280;
+/// Samples before each chunk the mel model needs for the chunk's frames
+const MEL_CONTEXT: usize = 480;
+const MEL_BINS: usize = 32;
+/// Mel frames the embedding model looks at
+const EMBEDDING_FRAMES: usize = 76;
+/// Embeddings the keyword model scores at once
+const KEYWORD_EMBEDDINGS: usize = 16;
+
+/// Chunks ignored after a detection, so one utterance fires once (2 s)
+const REFRACTORY_CHUNKS: usize = 25;
+
+/// The shared feature models openWakeWord ships next to keyword models
+pub const MELSPECTROGRAM_MODEL: &str = "melspectrogram.onnx";
+pub const EMBEDDING_MODEL: &str = "embedding_model.onnx";
+
+/// Scores chunks of [`CHUNK_SAMPLES`] samples for a wake word
+pub trait WakeWordModel: Send {
+    /// Score of the wake word having just been said (0.0-1.0)
+    fn score(&mut self, chunk: &[f32]) -> Result<f32>;
+
+    /// Forget all audio heard so far
+    fn reset(&mut self);
+}
+
+fn load_session(path: &Path) -> Result<Session> {
+    Session::builder()
+        .and_then(|builder| builder.with_intra_threads(1))
+        .and_then(|builder| builder.commit_from_file(path))
+        .map_err(|e| anyhow::anyhow!("Failed to load {}: {e}", path.display()))
+}
+
+/// An openWakeWord keyword model with its feature models
+pub struct OnnxWakeWordModel {
+    melspectrogram: Session,
+    embedding: Session,
+    keyword: Session,
+    /// The previous chunk's last samples, in 16-bit sample values
+    context: Vec<f32>,
+    mels: VecDeque<[f32; MEL_BINS]>,
+    embeddings: VecDeque<Vec<f32>>,
+}
+
+impl OnnxWakeWordModel {
+    /// Load a keyword model. The mel spectrogram and embedding models are
+    /// expected in the same folder.
+    pub fn new<P: AsRef<Path>>(keyword_model_path: P) -> Result<Self> {
+        let keyword_model_path = keyword_model_path.as_ref();
+        let dir = keyword_model_path
+            .parent()
+            .ok_or_else(|| anyhow::anyhow!("Invalid wake word model path"))?;
+        let mut model = Self {
+            melspectrogram: load_session(&dir.join(MELSPECTROGRAM_MODEL))?,
+            embedding: load_session(&dir.join(EMBEDDING_MODEL))?,
+            keyword: load_session(keyword_model_path)?,
+            context: Vec::new(),
+            mels: VecDeque::new(),
+            embeddings: VecDeque::new(),
+        };
+        model.reset();
+        Ok(model)
+    }
+
+    fn run_melspectrogram(&mut self, audio: Vec<f32>) -> Result<()> {
+        let input = Tensor::from_array(([1usize, audio.len()], audio))?;
+        let outputs = self.melspectrogram.run(ort::inputs![input])?;
+        let (_, values) = outputs[0].try_extract_tensor::<f32>()?;
+        for frame in values.chunks_exact(MEL_BINS) {
+            let mut mel = [0.0; MEL_BINS];
+            // The embedding model was trained on values scaled like this
+            for (slot, value) in mel.iter_mut().zip(frame) {
+                *slot = value / 10.0 + 2.0;
+            }
+            self.mels.push_back(mel);
+        }
+        while self.mels.len() > EMBEDDING_FRAMES {
+            self.mels.pop_front();
+        }
+        Ok(())
+    }
+
+    fn run_embedding(&mut self) -> Result<()> {
+        let features: Vec<f32> = self.mels.iter().flatten().copied().collect();
+        let input = Tensor::from_array(([1usize, EMBEDDING_FRAMES, MEL_BINS, 1], features))?;
+        let outputs = self.embedding.run(ort::inputs![input])?;
+        let (_, embedding) = outputs[0].try_extract_tensor::<f32>()?;
+        self.embeddings.push_back(embedding.to_vec());
+        if self.embeddings.len() > KEYWORD_EMBEDDINGS {
+            self.embeddings.pop_front();
+        }
+        Ok(())
+    }
+
+    fn run_keyword(&mut self) -> Result<f32> {
+        let size = self.embeddings[0].len();
+        let features: Vec<f32> = self.embeddings.iter().flatten().copied().collect();
+        let input = Tensor::from_array(([1usize, KEYWORD_EMBEDDINGS, size], features))?;
+        let outputs = self.keyword.run(ort::inputs![input])?;
+        let (_, scores) = outputs[0].try_extract_tensor::<f32>()?;
+        Ok(scores.first().copied().unwrap_or(0.0))
+    }
+}
+
+impl WakeWordModel for OnnxWakeWordModel {
+    fn score(&mut self, chunk: &[f32]) -> Result<f32> {
+        // The models were trained on 16-bit sample values
+        let mut audio = std::mem::take(&mut self.context);
+        audio.extend(chunk.iter().map(|sample| sample * 32768.0));
+        self.context = audio[audio.len().saturating_sub(MEL_CONTEXT)..].to_vec();
+
+        self.run_melspectrogram(audio)?;
+        self.run_embedding()?;
+        if self.embeddings.len() < KEYWORD_EMBEDDINGS {
+            return Ok(0.0);
+        }
+        self.run_keyword()
+    }
+
+    fn reset(&mut self) {
+        self.context.clear();
+        // openWakeWord starts from a spectrogram of ones
+        self.mels = VecDeque::from(vec![[1.0; MEL_BINS]; EMBEDDING_FRAMES]);
+        self.embeddings.clear();
+    }
+}
+
+/// Feeds a stream of frames to a wake word model in whole chunks and
+/// reports when the score crosses the threshold
+pub struct WakeWordDetector {
+    model: Box<dyn WakeWordModel>,
+    threshold: f32,
+    pending: Vec<f32>,
+    /// Chunks left before the detector can fire again
+    refractory: usize,
+}
+
+impl WakeWordDetector {
+    pub fn new(model: Box<dyn WakeWordModel>, threshold: f32) -> Self {
+        Self {
+            model,
+            threshold,
+            pending: Vec::with_capacity(CHUNK_SAMPLES * 2),
+            refractory: 0,
+        }
+    }
+
+    /// Feed 16 kHz samples; returns the score when the wake word was heard
+    pub fn push(&mut self, samples: &[f32]) -> Option<f32> {
+        self.pending.extend_from_slice(samples);
+        let mut detected = None;
+        while self.pending.len() >= CHUNK_SAMPLES {
+            let chunk: Vec<f32> = self.pending.drain(..CHUNK_SAMPLES).collect();
+            let score = match self.model.score(&chunk) {
+                Ok(score) => score,
+                Err(e) => {
+                    log::debug!("Wake word scoring failed: {e}");
+                    continue;
+                }
+            };
+            if self.refractory > 0 {
+                self.refractory -= 1;
+            } else if score >= self.threshold {
+                detected = Some(score);
+                self.refractory = REFRACTORY_CHUNKS;
+            }
+        }
+        detected
+    }
+
+    /// Forget all audio heard so far, e.g. after a recording the detector
+    /// didn't hear
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.model.reset();
+        self.refractory = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scores each chunk with its loudest sample
+    struct PeakModel;
+
+    impl WakeWordModel for PeakModel {
+        fn score(&mut self, chunk: &[f32]) -> Result<f32> {
+            assert_eq!(chunk.len(), CHUNK_SAMPLES);
+            Ok(chunk.iter().fold(0.0, |peak, s| s.abs().max(peak)))
+        }
+
+        fn reset(&mut self) {}
+    }
+
+    #[test]
+    fn test_detector_scores_whole_chunks() {
+        let mut detector = WakeWordDetector::new(Box::new(PeakModel), 0.5);
+        // 30 ms frames don't line up with 80 ms chunks
+        let quiet = vec![0.1; 480];
+        let loud = vec![0.9; 480];
+        assert_eq!(detector.push(&quiet), None);
+        assert_eq!(detector.push(&loud), None);
+        assert_eq!(detector.push(&quiet), Some(0.9));
+        assert_eq!(detector.pending.len(), 3 * 480 - CHUNK_SAMPLES);
+    }
+
+    #[test]
+    fn test_detector_fires_once_per_utterance() {
+        let mut detector = WakeWordDetector::new(Box::new(PeakModel), 0.5);
+        let loud = vec![0.9; CHUNK_SAMPLES];
+        assert!(detector.push(&loud).is_some());
+        for _ in 0..REFRACTORY_CHUNKS {
+            assert!(detector.push(&loud).is_none());
+        }
+        assert!(detector.push(&loud).is_some());
+
+        detector.reset();
+        assert!(detector.push(&loud).is_some());
+    }
+}
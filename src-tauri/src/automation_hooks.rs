@@ -0,0 +1,259 @@
+//! Automation hooks
+//!
+//! Runs the user's programs at set points of the dictation pipeline, with
+//! the text on stdin. At the `post_transcribe` and `pre_paste` stages what a
+//! program prints replaces the text; `post_paste` hooks are only told what
+//! was pasted. A hook that fails, prints nothing or runs past its timeout
+//! leaves the text as it was.
+//!
+//! Programs are started directly, not through a shell, and see only a few
+//! basic environment variables unless they inherit Dictum's, plus
+//! `DICTUM_HOOK_STAGE` naming the stage.
+
+use crate::settings::{AutomationHook, AutomationHookSettings, HookStage};
+use log::{debug, error, warn};
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// How often a running hook is checked on
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long the pipe threads get to finish once the hook has exited. A
+/// program it left running in the background can hold the pipes open.
+const PIPE_GRACE: Duration = Duration::from_millis(500);
+
+/// Variables passed on to hooks that don't inherit the environment
+const BASE_ENV: &[&str] = &[
+    "PATH",
+    "HOME",
+    "USER",
+    "LANG",
+    "TMPDIR",
+    "TEMP",
+    "TMP",
+    "USERPROFILE",
+    "SYSTEMROOT",
+];
+
+fn read_all(mut pipe: impl Read) -> String {
+    let mut output = Vec::new();
+    let _ = pipe.read_to_end(&mut output);
+    String::from_utf8_lossy(&output).into_owned()
+}
+
+/// Join a thread unless it's still running at `until`, in which case it's
+/// left to finish on its own
+fn join_until<T>(handle: JoinHandle<T>, until: Instant) -> Option<T> {
+    while !handle.is_finished() {
+        if Instant::now() >= until {
+            return None;
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+    handle.join().ok()
+}
+
+/// Run a hook with `input` on stdin and return what it printed
+pub fn run(hook: &AutomationHook, input: &str) -> Result<String, String> {
+    let mut command = Command::new(hook.command.trim());
+    command
+        .args(&hook.args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if !hook.inherit_env {
+        command.env_clear();
+        for name in BASE_ENV {
+            if let Some(value) = std::env::var_os(name) {
+                command.env(name, value);
+            }
+        }
+    }
+    command
+        .envs(&hook.env)
+        .env("DICTUM_HOOK_STAGE", hook.stage.as_str());
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to start {}: {}", hook.command, e))?;
+
+    // Feed stdin and drain the output on their own threads, so a program
+    // that writes a lot before reading can't block on a full pipe
+    let stdin = child.stdin.take();
+    let input = input.to_string();
+    let writer = thread::spawn(move || {
+        if let Some(mut stdin) = stdin {
+            let _ = stdin.write_all(input.as_bytes());
+        }
+    });
+    let stdout = child
+        .stdout
+        .take()
+        .map(|pipe| thread::spawn(|| read_all(pipe)));
+    let stderr = child
+        .stderr
+        .take()
+        .map(|pipe| thread::spawn(|| read_all(pipe)));
+
+    let deadline = Instant::now() + Duration::from_millis(hook.timeout_ms as u64);
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                let until = Instant::now() + PIPE_GRACE;
+                join_until(writer, until);
+                for reader in [stdout, stderr].into_iter().flatten() {
+                    join_until(reader, until);
+                }
+                return Err(format!("Timed out after {} ms", hook.timeout_ms));
+            }
+            Ok(None) => thread::sleep(POLL_INTERVAL),
+            Err(e) => return Err(format!("Failed to wait for {}: {}", hook.command, e)),
+        }
+    };
+
+    let until = Instant::now() + PIPE_GRACE;
+    join_until(writer, until);
+    let stdout = stdout
+        .and_then(|t| join_until(t, until))
+        .unwrap_or_default();
+    let stderr = stderr
+        .and_then(|t| join_until(t, until))
+        .unwrap_or_default();
+    if !status.success() {
+        return Err(format!("Exited with {}: {}", status, stderr.trim()));
+    }
+    Ok(stdout)
+}
+
+/// Pass `text` through each hook in turn
+fn run_chain<'a>(hooks: impl IntoIterator<Item = &'a AutomationHook>, text: String) -> String {
+    hooks
+        .into_iter()
+        .fold(text, |text, hook| match run(hook, &text) {
+            Ok(output) if hook.stage.rewrites_text() && !output.trim().is_empty() => {
+                debug!("Hook '{}' rewrote the text", hook.name);
+                output.trim_end_matches(['\r', '\n']).to_string()
+            }
+            Ok(_) => text,
+            Err(e) => {
+                warn!("Hook '{}' failed: {}", hook.name, e);
+                text
+            }
+        })
+}
+
+/// Run the hooks for a stage that rewrites the text and return the result
+pub async fn transform(
+    settings: &AutomationHookSettings,
+    stage: HookStage,
+    text: String,
+) -> String {
+    let hooks: Vec<AutomationHook> = settings.hooks_for(stage).cloned().collect();
+    if hooks.is_empty() {
+        return text;
+    }
+
+    let original = text.clone();
+    tauri::async_runtime::spawn_blocking(move || run_chain(&hooks, text))
+        .await
+        .unwrap_or_else(|e| {
+            error!("Hooks for {} panicked: {}", stage.as_str(), e);
+            original
+        })
+}
+
+/// Run the hooks for a stage in the background, ignoring their output
+pub fn notify(settings: &AutomationHookSettings, stage: HookStage, text: String) {
+    let hooks: Vec<AutomationHook> = settings.hooks_for(stage).cloned().collect();
+    if hooks.is_empty() {
+        return;
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        for hook in &hooks {
+            if let Err(e) = run(hook, &text) {
+                warn!("Hook '{}' failed: {}", hook.name, e);
+            }
+        }
+    });
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn hook(stage: HookStage, command: &str, args: &[&str]) -> AutomationHook {
+        AutomationHook {
+            id: "test".to_string(),
+            name: "test".to_string(),
+            stage,
+            command: command.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            enabled: true,
+            timeout_ms: 2000,
+            inherit_env: false,
+            env: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_chain_rewrites_text_and_skips_failures() {
+        let hooks = [
+            hook(HookStage::PrePaste, "tr", &["a-z", "A-Z"]),
+            hook(HookStage::PrePaste, "false", &[]),
+            hook(HookStage::PrePaste, "sed", &["s/$/!/"]),
+        ];
+        assert_eq!(run_chain(&hooks, "hello".to_string()), "HELLO!");
+    }
+
+    #[test]
+    fn test_post_paste_output_is_ignored() {
+        let hooks = [hook(HookStage::PostPaste, "echo", &["replaced"])];
+        assert_eq!(run_chain(&hooks, "hello".to_string()), "hello");
+    }
+
+    #[test]
+    fn test_slow_hook_is_killed() {
+        let mut slow = hook(HookStage::PrePaste, "sleep", &["5"]);
+        slow.timeout_ms = 100;
+
+        let started = Instant::now();
+        assert!(run(&slow, "").is_err());
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_background_child_holding_the_pipes_doesnt_block() {
+        let mut slow = hook(HookStage::PrePaste, "sh", &["-c", "sleep 5 & sleep 5"]);
+        slow.timeout_ms = 100;
+
+        let started = Instant::now();
+        assert!(run(&slow, "").is_err());
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_environment_is_limited_to_the_basics() {
+        let mut env_hook = hook(HookStage::PrePaste, "env", &[]);
+        env_hook
+            .env
+            .insert("GREETING".to_string(), "hello".to_string());
+
+        let output = run(&env_hook, "").unwrap();
+        let names: Vec<&str> = output
+            .lines()
+            .filter_map(|line| line.split('=').next())
+            .collect();
+        assert!(names.contains(&"GREETING"));
+        assert!(names.contains(&"DICTUM_HOOK_STAGE"));
+        assert!(names.iter().all(
+            |name| BASE_ENV.contains(name) || ["GREETING", "DICTUM_HOOK_STAGE"].contains(name)
+        ));
+    }
+}
@@ -0,0 +1,387 @@
+//! Browser extension bridge
+//!
+//! Browsers only let extensions talk to programs they launch themselves
+//! through native messaging. Started with `--native-messaging-host <file>`,
+//! the app therefore doesn't open a window but becomes that program: it
+//! connects to the bridge the running app listens on (see
+//! [`crate::managers::browser_bridge`]), authenticates with the token from
+//! `<file>` and relays messages in both directions until the browser closes
+//! its end.
+//!
+//! Messages use native messaging's framing on both sides: a 32-bit length in
+//! native byte order followed by that many bytes of JSON.
+
+use crate::settings::BrowserBridgeSettings;
+use log::info;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+/// Command line flag that starts the app as the native messaging host
+pub const NATIVE_HOST_FLAG: &str = "--native-messaging-host";
+
+/// Name browsers know the native messaging host by
+pub const NATIVE_HOST_NAME: &str = "com.dictum.bridge";
+
+/// Largest message accepted, the limit browsers put on messages to hosts
+const MAX_MESSAGE_BYTES: usize = 1024 * 1024;
+
+/// Where the running bridge listens, written for the native messaging host
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BridgeEndpoint {
+    pub port: u16,
+    pub token: String,
+}
+
+/// Read one framed message, or `None` once the other side closed
+pub fn read_frame(reader: &mut impl Read) -> io::Result<Option<Vec<u8>>> {
+    let mut len = [0u8; 4];
+    match reader.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let len = u32::from_ne_bytes(len) as usize;
+    if len > MAX_MESSAGE_BYTES {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Message of {} bytes is too large", len),
+        ));
+    }
+    let mut message = vec![0u8; len];
+    reader.read_exact(&mut message)?;
+    Ok(Some(message))
+}
+
+/// Write one framed message
+pub fn write_frame(writer: &mut impl Write, message: &[u8]) -> io::Result<()> {
+    if message.len() > MAX_MESSAGE_BYTES {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Message of {} bytes is too large", message.len()),
+        ));
+    }
+    writer.write_all(&(message.len() as u32).to_ne_bytes())?;
+    writer.write_all(message)?;
+    writer.flush()
+}
+
+/// Write one JSON message
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    write_frame(writer, message.to_string().as_bytes())
+}
+
+/// The bridge file path when the app was launched as the native messaging
+/// host
+pub fn native_host_bridge_file() -> Option<PathBuf> {
+    let mut args = std::env::args().skip(1);
+    args.find(|arg| arg == NATIVE_HOST_FLAG)?;
+    args.next().map(PathBuf::from)
+}
+
+/// Copy messages from `reader` to `writer` until `reader` is closed
+fn relay(mut reader: impl Read, mut writer: impl Write) -> io::Result<()> {
+    while let Some(message) = read_frame(&mut reader)? {
+        write_frame(&mut writer, &message)?;
+    }
+    Ok(())
+}
+
+/// Run as the native messaging host, returning the process exit code
+pub fn run_native_host(bridge_file: &Path) -> i32 {
+    match relay_to_bridge(bridge_file) {
+        Ok(()) => 0,
+        Err(e) => {
+            // Stdout belongs to the browser, so report on stderr, which
+            // browsers write to their own log
+            eprintln!("Dictum native messaging host: {}", e);
+            1
+        }
+    }
+}
+
+fn relay_to_bridge(bridge_file: &Path) -> Result<(), String> {
+    let endpoint: BridgeEndpoint = std::fs::read_to_string(bridge_file)
+        .map_err(|e| format!("Failed to read {}: {}", bridge_file.display(), e))
+        .and_then(|contents| {
+            serde_json::from_str(&contents).map_err(|e| format!("Invalid bridge file: {}", e))
+        })?;
+    let mut stream = TcpStream::connect(("127.0.0.1", endpoint.port))
+        .map_err(|e| format!("Dictum isn't running or the bridge is off: {}", e))?;
+    write_message(
+        &mut stream,
+        &json!({ "type": "hello", "token": endpoint.token }),
+    )
+    .map_err(|e| format!("Failed to reach the bridge: {}", e))?;
+
+    let from_app = stream
+        .try_clone()
+        .map_err(|e| format!("Failed to share bridge connection: {}", e))?;
+    std::thread::spawn(move || {
+        let _ = relay(from_app, io::stdout().lock());
+        // The app went away, so there's nothing left to relay
+        std::process::exit(0);
+    });
+    relay(io::stdin().lock(), stream).map_err(|e| format!("Relay to the bridge failed: {}", e))
+}
+
+/// Path of the file telling the native messaging host where the bridge is
+pub fn bridge_file_path(app: &AppHandle) -> Result<PathBuf, String> {
+    crate::portable::app_data_dir(app)
+        .map(|dir| dir.join("browser-bridge.json"))
+        .map_err(|e| format!("Failed to get app data directory: {}", e))
+}
+
+/// Write the bridge file for the bridge now listening
+pub fn write_bridge_file(app: &AppHandle, endpoint: &BridgeEndpoint) -> Result<(), String> {
+    let path = bridge_file_path(app)?;
+    let contents = serde_json::to_string(endpoint).map_err(|e| e.to_string())?;
+    std::fs::write(&path, contents)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Chrome extension IDs are 32 letters from a to p; anything else is taken
+/// to be a Firefox add-on ID
+fn is_chrome_extension_id(id: &str) -> bool {
+    id.len() == 32 && id.bytes().all(|b| (b'a'..=b'p').contains(&b))
+}
+
+/// The host manifests for Chromium-based browsers and for Firefox
+fn host_manifests(host_path: &Path, extension_ids: &[String]) -> (Value, Value) {
+    let (chrome_ids, firefox_ids): (Vec<&String>, Vec<&String>) = extension_ids
+        .iter()
+        .partition(|id| is_chrome_extension_id(id));
+    let manifest = |allowed_key: &str, allowed: Value| {
+        let mut manifest = json!({
+            "name": NATIVE_HOST_NAME,
+            "description": "Dictum browser bridge",
+            "path": host_path,
+            "type": "stdio",
+        });
+        manifest[allowed_key] = allowed;
+        manifest
+    };
+    let chrome_origins: Vec<String> = chrome_ids
+        .iter()
+        .map(|id| format!("chrome-extension://{}/", id))
+        .collect();
+    (
+        manifest("allowed_origins", json!(chrome_origins)),
+        manifest("allowed_extensions", json!(firefox_ids)),
+    )
+}
+
+/// Write the script browsers launch, which starts the app as the native
+/// messaging host
+fn write_host_script(dir: &Path, bridge_file: &Path) -> Result<PathBuf, String> {
+    let exe =
+        std::env::current_exe().map_err(|e| format!("Failed to find the app executable: {}", e))?;
+
+    #[cfg(target_os = "windows")]
+    let (path, script) = (
+        dir.join("dictum-bridge.bat"),
+        format!(
+            "@echo off\r\n\"{}\" {} \"{}\"\r\n",
+            exe.display(),
+            NATIVE_HOST_FLAG,
+            bridge_file.display()
+        ),
+    );
+    #[cfg(not(target_os = "windows"))]
+    let (path, script) = (
+        dir.join("dictum-bridge.sh"),
+        format!(
+            "#!/bin/sh\nexec \"{}\" {} \"{}\"\n",
+            exe.display(),
+            NATIVE_HOST_FLAG,
+            bridge_file.display()
+        ),
+    );
+
+    std::fs::write(&path, script)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .map_err(|e| format!("Failed to make {} executable: {}", path.display(), e))?;
+    }
+    Ok(path)
+}
+
+/// Directories browsers look for host manifests in, for the Chromium-based
+/// browsers and for Firefox
+#[cfg(not(target_os = "windows"))]
+fn manifest_dirs(home: &Path) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    #[cfg(target_os = "macos")]
+    let (chromium, firefox_root, firefox_dir) = {
+        let support = home.join("Library/Application Support");
+        (
+            [
+                "Google/Chrome",
+                "Chromium",
+                "Microsoft Edge",
+                "BraveSoftware/Brave-Browser",
+            ]
+            .map(|browser| support.join(browser)),
+            support.join("Mozilla"),
+            support.join("Mozilla/NativeMessagingHosts"),
+        )
+    };
+    #[cfg(not(target_os = "macos"))]
+    let (chromium, firefox_root, firefox_dir) = {
+        let config = home.join(".config");
+        (
+            [
+                "google-chrome",
+                "chromium",
+                "microsoft-edge",
+                "BraveSoftware/Brave-Browser",
+            ]
+            .map(|browser| config.join(browser)),
+            home.join(".mozilla"),
+            home.join(".mozilla/native-messaging-hosts"),
+        )
+    };
+
+    // Only browsers that are installed, so no empty profiles get created
+    let chromium_dirs = chromium
+        .into_iter()
+        .filter(|dir| dir.is_dir())
+        .map(|dir| dir.join("NativeMessagingHosts"))
+        .collect();
+    let firefox_dirs = if firefox_root.is_dir() {
+        vec![firefox_dir]
+    } else {
+        Vec::new()
+    };
+    (chromium_dirs, firefox_dirs)
+}
+
+/// Register a host manifest in the Windows registry under `browser_key`
+#[cfg(target_os = "windows")]
+fn register_manifest(browser_key: &str, manifest: &Path) -> Result<(), String> {
+    let key = format!(
+        r"HKCU\Software\{}\NativeMessagingHosts\{}",
+        browser_key, NATIVE_HOST_NAME
+    );
+    let status = std::process::Command::new("reg")
+        .args(["add", &key, "/ve", "/t", "REG_SZ", "/d"])
+        .arg(manifest)
+        .arg("/f")
+        .status()
+        .map_err(|e| format!("Failed to run reg: {}", e))?;
+    if !status.success() {
+        return Err(format!("Failed to register {}", key));
+    }
+    Ok(())
+}
+
+/// Install the native messaging host for every installed browser, so the
+/// companion extension can reach the bridge. Returns where it was
+/// installed.
+pub fn install_native_host(
+    app: &AppHandle,
+    settings: &BrowserBridgeSettings,
+) -> Result<Vec<String>, String> {
+    if settings.extension_ids.is_empty() {
+        return Err("Add the companion extension's ID first".to_string());
+    }
+    let dir = crate::portable::app_data_dir(app)
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?
+        .join("native-messaging");
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let host_path = write_host_script(&dir, &bridge_file_path(app)?)?;
+    let (chromium, firefox) = host_manifests(&host_path, &settings.extension_ids);
+    let file_name = format!("{}.json", NATIVE_HOST_NAME);
+    let write_manifest = |dir: &Path, manifest: &Value| -> Result<PathBuf, String> {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        let path = dir.join(&file_name);
+        let contents = serde_json::to_string_pretty(manifest).map_err(|e| e.to_string())?;
+        std::fs::write(&path, contents)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        Ok(path)
+    };
+
+    let mut installed = Vec::new();
+    #[cfg(target_os = "windows")]
+    {
+        let chromium_path = write_manifest(&dir.join("chromium"), &chromium)?;
+        let firefox_path = write_manifest(&dir.join("firefox"), &firefox)?;
+        for key in [
+            r"Google\Chrome",
+            r"Microsoft\Edge",
+            r"BraveSoftware\Brave-Browser",
+        ] {
+            register_manifest(key, &chromium_path)?;
+            installed.push(key.to_string());
+        }
+        register_manifest("Mozilla", &firefox_path)?;
+        installed.push("Mozilla".to_string());
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        use tauri::Manager;
+
+        let home = app
+            .path()
+            .home_dir()
+            .map_err(|e| format!("Failed to find the home directory: {}", e))?;
+        let (chromium_dirs, firefox_dirs) = manifest_dirs(&home);
+        for dir in chromium_dirs {
+            installed.push(write_manifest(&dir, &chromium)?.display().to_string());
+        }
+        for dir in firefox_dirs {
+            installed.push(write_manifest(&dir, &firefox)?.display().to_string());
+        }
+    }
+    if installed.is_empty() {
+        return Err("No supported browser found".to_string());
+    }
+    info!("Installed the native messaging host for {:?}", installed);
+    Ok(installed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_round_trip_and_oversized_frames_are_rejected() {
+        let mut buffer = Vec::new();
+        write_message(&mut buffer, &json!({ "type": "blur" })).unwrap();
+        write_frame(&mut buffer, b"{}").unwrap();
+
+        let mut reader = buffer.as_slice();
+        let first = read_frame(&mut reader).unwrap().unwrap();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&first).unwrap(),
+            json!({ "type": "blur" })
+        );
+        assert_eq!(read_frame(&mut reader).unwrap().unwrap(), b"{}");
+        assert!(read_frame(&mut reader).unwrap().is_none());
+
+        let oversized = ((MAX_MESSAGE_BYTES + 1) as u32).to_ne_bytes();
+        assert!(read_frame(&mut oversized.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_manifests_split_chrome_and_firefox_ids() {
+        let ids = [
+            "abcdefghijklmnopabcdefghijklmnop".to_string(),
+            "bridge@dictum.app".to_string(),
+        ];
+        let (chromium, firefox) = host_manifests(Path::new("/opt/dictum-bridge.sh"), &ids);
+        assert_eq!(
+            chromium["allowed_origins"],
+            json!(["chrome-extension://abcdefghijklmnopabcdefghijklmnop/"])
+        );
+        assert_eq!(firefox["allowed_extensions"], json!(["bridge@dictum.app"]));
+        assert_eq!(firefox["name"], NATIVE_HOST_NAME);
+    }
+}
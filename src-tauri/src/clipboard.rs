@@ -1,10 +1,19 @@
+use crate::events::ClipboardAudioCopied;
 use crate::input::{self, EnigoState};
-use crate::settings::{get_settings, ClipboardHandling, PasteMethod};
+use crate::notifications::{notify, Notification};
+use crate::settings::{
+    get_settings, ClipboardHandling, ClipboardWatchSettings, NotificationFeature,
+    NotificationSeverity, PasteMethod,
+};
 use enigo::Enigo;
 use log::info;
-use std::time::Duration;
+use serde::Serialize;
+use specta::Type;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Manager};
 use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_specta::Event;
 
 #[cfg(target_os = "linux")]
 use crate::utils::{is_kde_wayland, is_wayland};
@@ -447,8 +456,17 @@ fn paste_direct(enigo: &mut Enigo, text: &str) -> Result<(), String> {
 }
 
 pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
+    let paste_method = get_settings(&app_handle).paste_method;
+    paste_with_method(text, app_handle, paste_method)
+}
+
+/// Pastes with `paste_method` instead of the configured method
+pub fn paste_with_method(
+    text: String,
+    app_handle: AppHandle,
+    paste_method: PasteMethod,
+) -> Result<(), String> {
     let settings = get_settings(&app_handle);
-    let paste_method = settings.paste_method;
     let paste_delay_ms = settings.paste_delay_ms;
 
     // Append trailing space if setting is enabled
@@ -501,3 +519,170 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
 
     Ok(())
 }
+
+/// How often the clipboard is read while it's watched
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// An audio file copied to the clipboard, offered for transcription
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct ClipboardAudioEvent {
+    pub path: String,
+    pub file_name: String,
+}
+
+/// The file a copied path or `file://` URL points to, if its extension is
+/// allowed. Text with several lines is never a single path.
+fn audio_file_from_clipboard(text: &str, settings: &ClipboardWatchSettings) -> Option<PathBuf> {
+    let text = text.trim().trim_matches(['"', '\'']);
+    if text.is_empty() || text.contains('\n') {
+        return None;
+    }
+
+    let path = if text.starts_with("file://") {
+        reqwest::Url::parse(text).ok()?.to_file_path().ok()?
+    } else {
+        PathBuf::from(text)
+    };
+    let extension = path.extension()?.to_str()?;
+    settings.allows_extension(extension).then_some(path)
+}
+
+/// Waits for the clipboard to settle: text is let through once it has
+/// stayed on the clipboard for the debounce time, and only once
+#[derive(Default)]
+struct ClipboardDebounce {
+    /// Latest text and when it was copied
+    pending: Option<(String, Instant)>,
+    /// Last text let through, or on the clipboard when watching started
+    settled: Option<String>,
+}
+
+impl ClipboardDebounce {
+    fn settled_on(text: String) -> Self {
+        Self {
+            pending: None,
+            settled: Some(text),
+        }
+    }
+
+    fn observe(&mut self, text: &str, now: Instant, debounce: Duration) -> Option<String> {
+        if self.settled.as_deref() == Some(text) {
+            self.pending = None;
+            return None;
+        }
+        match &self.pending {
+            Some((pending, since)) if pending == text => {
+                if now.duration_since(*since) < debounce {
+                    return None;
+                }
+                self.pending = None;
+                self.settled = Some(text.to_string());
+                Some(text.to_string())
+            }
+            _ => {
+                self.pending = Some((text.to_string(), now));
+                None
+            }
+        }
+    }
+}
+
+/// Tell the user an audio file was copied. The frontend offers to
+/// transcribe it when it gets the event.
+fn offer_transcription(app: &AppHandle, path: PathBuf) {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    info!("Audio file copied to the clipboard: {}", path.display());
+
+    let _ = ClipboardAudioCopied(ClipboardAudioEvent {
+        path: path.to_string_lossy().into_owned(),
+        file_name: file_name.clone(),
+    })
+    .emit(app);
+    notify(
+        app,
+        Notification {
+            feature: NotificationFeature::Clipboard,
+            severity: NotificationSeverity::Info,
+            title: "Transcribe copied audio?".to_string(),
+            body: format!("Open Dictum to transcribe {}", file_name),
+        },
+    );
+}
+
+/// Watch the clipboard for copied audio files while the setting is on.
+/// Whatever was copied before watching started is not offered.
+pub fn start_clipboard_watcher(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut debounce: Option<ClipboardDebounce> = None;
+        loop {
+            tokio::time::sleep(WATCH_INTERVAL).await;
+            let settings = get_settings(&app).clipboard_watch;
+            if !settings.enabled {
+                debounce = None;
+                continue;
+            }
+
+            let text = app.clipboard().read_text().unwrap_or_default();
+            let Some(watch) = debounce.as_mut() else {
+                debounce = Some(ClipboardDebounce::settled_on(text));
+                continue;
+            };
+            let wait = Duration::from_millis(settings.debounce_ms as u64);
+            let Some(text) = watch.observe(&text, Instant::now(), wait) else {
+                continue;
+            };
+            if let Some(path) = audio_file_from_clipboard(&text, &settings) {
+                if path.is_file() {
+                    offer_transcription(&app, path);
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audio_file_from_clipboard() {
+        let settings = ClipboardWatchSettings::default();
+        assert_eq!(
+            audio_file_from_clipboard("  \"/tmp/call.MP3\"\n", &settings),
+            Some(PathBuf::from("/tmp/call.MP3"))
+        );
+        assert_eq!(
+            audio_file_from_clipboard("file:///tmp/team%20sync.m4a", &settings),
+            Some(PathBuf::from("/tmp/team sync.m4a"))
+        );
+        assert_eq!(audio_file_from_clipboard("/tmp/notes.txt", &settings), None);
+        assert_eq!(
+            audio_file_from_clipboard("/tmp/a.wav\n/tmp/b.wav", &settings),
+            None
+        );
+        assert_eq!(audio_file_from_clipboard("Hello there", &settings), None);
+    }
+
+    #[test]
+    fn test_debounce_lets_settled_text_through_once() {
+        let wait = Duration::from_millis(1000);
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let mut debounce = ClipboardDebounce::settled_on("old".to_string());
+
+        assert_eq!(debounce.observe("old", at(0), wait), None);
+        assert_eq!(debounce.observe("a.wav", at(0), wait), None);
+        // Copying something else restarts the wait
+        assert_eq!(debounce.observe("b.wav", at(500), wait), None);
+        assert_eq!(debounce.observe("b.wav", at(1000), wait), None);
+        assert_eq!(
+            debounce.observe("b.wav", at(1500), wait),
+            Some("b.wav".to_string())
+        );
+        assert_eq!(debounce.observe("b.wav", at(3000), wait), None);
+    }
+}
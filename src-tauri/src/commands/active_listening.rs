@@ -1,17 +1,35 @@
 //! Tauri commands for Active Listening feature
 
+use crate::access_policy;
 use crate::audio_toolkit::audio::loopback::{LoopbackCapture, LoopbackSupport};
 use crate::managers::active_listening::{
     ActiveListeningManager, ActiveListeningSession, ActiveListeningState, MeetingSummary,
 };
+use crate::managers::active_listening_history::{
+    ActiveListeningHistoryManager, StoredSession, StoredSessionInfo,
+};
+use crate::managers::anonymization::{self, NameMapping};
 use crate::managers::audio::AudioRecordingManager;
+use crate::managers::chapters::{self, Chapter};
+use crate::managers::highlights::{highlight_excerpts, HighlightExcerpt, SessionHighlight};
+use crate::managers::live_summary::LiveSummary;
+use crate::managers::meeting_export::{self, SummaryExportFormat};
+use crate::managers::meeting_memory::{self, MemorySearch};
+use crate::managers::meeting_qa::{self, MeetingAnswer};
+use crate::managers::model::ModelManager;
+use crate::managers::session_replay::{self, ReplayOptions, ReplaySource, SessionReplay};
+use crate::managers::transcription::TranscriptionManager;
+use crate::managers::webhook::WebhookManager;
 use crate::ollama_client::OllamaClient;
 use crate::settings::{
-    get_settings, write_settings, ActiveListeningPrompt, AudioSourceType, PromptCategory,
+    get_settings, write_settings, ActiveListeningPrompt, AudioSourceType, DiarizationSettings,
+    DualLanguageSettings, InsightFilters, ModelUnloadTimeout, PromptCategory, SessionBudget,
+    SpeakerProfile, TranslationMethod, WebhookEvent,
 };
-use log::{debug, info};
+use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
 use specta::Type;
+use std::path::Path;
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
 
@@ -116,6 +134,32 @@ pub fn get_active_listening_session(app: AppHandle) -> Option<ActiveListeningSes
     al_manager.get_current_session()
 }
 
+/// Mark the current moment of the active session as a highlight
+#[tauri::command]
+#[specta::specta]
+pub fn mark_session_highlight(app: AppHandle) -> Result<SessionHighlight, String> {
+    let al_manager = app.state::<Arc<ActiveListeningManager>>();
+    al_manager.mark_highlight()
+}
+
+/// Get the highlights of a session, or of the active session when none is
+/// given, with the transcript around each one
+#[tauri::command]
+#[specta::specta]
+pub fn get_session_highlights(
+    app: AppHandle,
+    session: Option<ActiveListeningSession>,
+) -> Result<Vec<HighlightExcerpt>, String> {
+    let session = match session {
+        Some(session) => session,
+        None => app
+            .state::<Arc<ActiveListeningManager>>()
+            .get_current_session()
+            .ok_or_else(|| "No active listening session".to_string())?,
+    };
+    Ok(highlight_excerpts(&session))
+}
+
 /// Check if Ollama server is available
 #[tauri::command]
 #[specta::specta]
@@ -194,6 +238,20 @@ pub fn change_ollama_model_setting(app: AppHandle, model: String) -> Result<(),
     Ok(())
 }
 
+/// Change how long Ollama keeps the model loaded after a request
+#[tauri::command]
+#[specta::specta]
+pub fn change_ollama_keep_alive_setting(
+    app: AppHandle,
+    keep_alive: ModelUnloadTimeout,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.active_listening.ollama_keep_alive = keep_alive;
+    write_settings(&app, settings);
+    debug!("Ollama keep alive: {:?}", keep_alive);
+    Ok(())
+}
+
 /// Change the context window size
 #[tauri::command]
 #[specta::specta]
@@ -208,6 +266,132 @@ pub fn change_active_listening_context_window_setting(
     Ok(())
 }
 
+/// Set the draft model for low-latency live captions (`None` to disable)
+#[tauri::command]
+#[specta::specta]
+pub fn change_active_listening_draft_model_setting(
+    app: AppHandle,
+    model_id: Option<String>,
+) -> Result<(), String> {
+    if let Some(ref id) = model_id {
+        let model_manager = app.state::<Arc<ModelManager>>();
+        let model = model_manager
+            .get_model_info(id)
+            .ok_or_else(|| format!("Model not found: {}", id))?;
+        if !model.is_downloaded {
+            return Err(format!("Model not downloaded: {}", id));
+        }
+    }
+
+    let mut settings = get_settings(&app);
+    settings.active_listening.draft_model_id = model_id.clone();
+    write_settings(&app, settings);
+
+    if model_id.is_none() {
+        app.state::<Arc<TranscriptionManager>>()
+            .unload_draft_model();
+    }
+    debug!("Active listening draft model: {:?}", model_id);
+    Ok(())
+}
+
+/// Set the limits on LLM calls, tokens and latency per session
+#[tauri::command]
+#[specta::specta]
+pub fn change_active_listening_budget_setting(
+    app: AppHandle,
+    budget: SessionBudget,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.active_listening.budget = budget;
+    write_settings(&app, settings);
+    debug!("Active listening budget: {:?}", budget);
+    Ok(())
+}
+
+/// Set the checks that drop low-value insights
+#[tauri::command]
+#[specta::specta]
+pub fn change_active_listening_insight_filters_setting(
+    app: AppHandle,
+    filters: InsightFilters,
+) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&filters.max_transcript_similarity) {
+        return Err("Similarity threshold must be between 0 and 1".to_string());
+    }
+    if filters.max_transcript_similarity > 0.0 && filters.embedding_model.trim().is_empty() {
+        return Err("An embedding model is required for the similarity check".to_string());
+    }
+
+    let mut settings = get_settings(&app);
+    settings.active_listening.insight_filters = filters;
+    write_settings(&app, settings);
+    debug!("Active listening insight filters updated");
+    Ok(())
+}
+
+/// Set whether segments are stored with a translation, and how it's made
+#[tauri::command]
+#[specta::specta]
+pub fn change_active_listening_dual_language_setting(
+    app: AppHandle,
+    dual_language: DualLanguageSettings,
+) -> Result<(), String> {
+    if dual_language.target_language.trim().is_empty() {
+        return Err("A target language is required".to_string());
+    }
+    if dual_language.method == TranslationMethod::Whisper
+        && !dual_language
+            .target_language
+            .trim()
+            .eq_ignore_ascii_case("english")
+    {
+        return Err("Whisper can only translate into English".to_string());
+    }
+
+    let mut settings = get_settings(&app);
+    settings.active_listening.dual_language = dual_language;
+    write_settings(&app, settings);
+    debug!("Active listening dual language output updated");
+    Ok(())
+}
+
+/// Set whether sessions are stored with people's names replaced
+#[tauri::command]
+#[specta::specta]
+pub fn change_active_listening_anonymize_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.active_listening.anonymize_sessions = enabled;
+    write_settings(&app, settings);
+    debug!("Active listening session anonymization: {}", enabled);
+    Ok(())
+}
+
+/// Set the names replaced in anonymized sessions besides the speakers'
+#[tauri::command]
+#[specta::specta]
+pub fn change_active_listening_anonymized_names_setting(
+    app: AppHandle,
+    names: Vec<String>,
+) -> Result<(), String> {
+    let mut anonymized_names: Vec<String> = Vec::new();
+    for name in names {
+        let name = name.trim().to_string();
+        if !name.is_empty() && !anonymized_names.contains(&name) {
+            anonymized_names.push(name);
+        }
+    }
+
+    let mut settings = get_settings(&app);
+    settings.active_listening.anonymized_names = anonymized_names;
+    write_settings(&app, settings);
+    debug!("Active listening anonymized names updated");
+    Ok(())
+}
+
 // ---- Audio Source Settings commands ----
 
 /// Change the audio source type for active listening
@@ -359,6 +543,127 @@ pub fn set_active_listening_selected_prompt(app: AppHandle, id: String) -> Resul
     Ok(())
 }
 
+/// Create a speaker profile
+#[tauri::command]
+#[specta::specta]
+pub fn add_speaker_profile(
+    app: AppHandle,
+    name: String,
+    custom_words: Vec<String>,
+    default_speaker_id: Option<u32>,
+) -> Result<SpeakerProfile, String> {
+    let mut settings = get_settings(&app);
+
+    let profile = SpeakerProfile {
+        id: format!("speaker_{}", chrono::Utc::now().timestamp_millis()),
+        name,
+        custom_words,
+        default_speaker_id,
+    };
+
+    settings
+        .active_listening
+        .speaker_profiles
+        .push(profile.clone());
+    write_settings(&app, settings);
+
+    debug!("Created speaker profile: {}", profile.id);
+    Ok(profile)
+}
+
+/// Update an existing speaker profile
+#[tauri::command]
+#[specta::specta]
+pub fn update_speaker_profile(app: AppHandle, profile: SpeakerProfile) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+
+    let existing = settings
+        .active_listening
+        .speaker_profiles
+        .iter_mut()
+        .find(|p| p.id == profile.id)
+        .ok_or_else(|| format!("Speaker profile not found: {}", profile.id))?;
+    *existing = profile;
+
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Delete a speaker profile
+#[tauri::command]
+#[specta::specta]
+pub fn delete_speaker_profile(app: AppHandle, id: String) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings
+        .active_listening
+        .speaker_profiles
+        .retain(|p| p.id != id);
+    write_settings(&app, settings);
+
+    // Stop correcting the current session's segments with it
+    let al_manager = app.state::<Arc<ActiveListeningManager>>();
+    al_manager.unassign_speaker_profile(&id);
+
+    debug!("Deleted speaker profile: {}", id);
+    Ok(())
+}
+
+/// Assign a speaker profile to a diarized speaker of the active session, so
+/// their segments are labeled with the profile's name and corrected with its
+/// vocabulary. `None` clears the assignment.
+#[tauri::command]
+#[specta::specta]
+pub fn assign_speaker_profile(
+    app: AppHandle,
+    speaker_id: u32,
+    profile_id: Option<String>,
+) -> Result<(), String> {
+    let al_manager = app.state::<Arc<ActiveListeningManager>>();
+    al_manager.assign_speaker_profile(speaker_id, profile_id)
+}
+
+/// Name a speaker of the active session. Their segments so far and their
+/// history entries are relabeled; `None` goes back to the default label.
+#[tauri::command]
+#[specta::specta]
+pub fn rename_speaker(app: AppHandle, speaker_id: u32, name: Option<String>) -> Result<(), String> {
+    let al_manager = app.state::<Arc<ActiveListeningManager>>();
+    al_manager.rename_speaker(speaker_id, name)
+}
+
+// ---- Diarization commands ----
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_diarization_settings(app: AppHandle) -> DiarizationSettings {
+    get_settings(&app).diarization
+}
+
+/// Replace the diarization settings. They apply from the next session.
+#[tauri::command]
+#[specta::specta]
+pub fn set_diarization_settings(
+    app: AppHandle,
+    diarization: DiarizationSettings,
+) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&diarization.similarity_threshold) {
+        return Err("Similarity threshold must be between 0.0 and 1.0".to_string());
+    }
+    if diarization.max_speakers < 2 {
+        return Err("At least two speakers must be told apart".to_string());
+    }
+    if let Some(path) = &diarization.model_path {
+        if !std::path::Path::new(path).is_file() {
+            return Err(format!("Speaker embedding model not found: {}", path));
+        }
+    }
+
+    let mut settings = get_settings(&app);
+    settings.diarization = diarization;
+    write_settings(&app, settings);
+    Ok(())
+}
+
 // ---- Loopback/System Audio commands ----
 
 /// Get the loopback support level for the current platform
@@ -413,141 +718,175 @@ pub async fn generate_meeting_summary(
     session: ActiveListeningSession,
 ) -> Result<MeetingSummary, String> {
     let al_manager = app.state::<Arc<ActiveListeningManager>>();
-    al_manager.generate_session_summary(&session).await
+    let summary = al_manager.generate_session_summary(&session).await?;
+
+    // Keep the summary with the stored session for the session browser,
+    // with the names replaced if the session was stored anonymized
+    if !crate::settings::is_guest_mode(&app) {
+        let history = app.state::<Arc<ActiveListeningHistoryManager>>();
+        let session_id = &summary.session_id;
+        let saved = anonymization::anonymize_summary(&app, &summary)
+            .and_then(|stored| history.save_summary(&stored).map_err(|e| e.to_string()));
+        match saved {
+            Ok(true) => debug!("Saved summary of session {}", session_id),
+            Ok(false) => debug!("Session {} is not stored, summary not saved", session_id),
+            Err(e) => warn!("Failed to save summary of session {}: {}", session_id, e),
+        }
+    }
+
+    app.state::<Arc<WebhookManager>>()
+        .notify(WebhookEvent::SummaryGenerated, &summary);
+
+    Ok(summary)
 }
 
-/// Export meeting summary to different formats
+/// Summary of what has happened so far in the running session. It's cached
+/// and only updated with the segments transcribed since the last request.
 #[tauri::command]
 #[specta::specta]
-pub fn export_meeting_summary(
-    summary: MeetingSummary,
-    format: String,
-) -> Result<String, String> {
-    match format.as_str() {
-        "markdown" => Ok(export_summary_to_markdown(&summary)),
-        "text" => Ok(export_summary_to_text(&summary)),
-        "json" => serde_json::to_string_pretty(&summary)
-            .map_err(|e| format!("Failed to serialize summary: {}", e)),
-        _ => Err(format!("Unsupported export format: {}", format)),
-    }
+pub async fn get_live_summary(app: AppHandle, session_id: String) -> Result<LiveSummary, String> {
+    let al_manager = app.state::<Arc<ActiveListeningManager>>();
+    al_manager.live_summary(&session_id).await
 }
 
-/// Format summary as Markdown
-fn export_summary_to_markdown(summary: &MeetingSummary) -> String {
-    let mut md = String::new();
-
-    md.push_str("# Meeting Summary\n\n");
-    md.push_str(&format!("**Duration:** {} minutes\n\n", summary.duration_minutes));
+/// Split a session into titled chapters by topic
+#[tauri::command]
+#[specta::specta]
+pub async fn detect_session_chapters(
+    app: AppHandle,
+    session: ActiveListeningSession,
+) -> Result<Vec<Chapter>, String> {
+    let model = get_settings(&app).active_listening.ollama_model;
+    if model.is_empty() {
+        return Err("No Ollama model configured".to_string());
+    }
+    chapters::detect_chapters(&app, &session, &model).await
+}
 
-    md.push_str("## Executive Summary\n\n");
-    md.push_str(&summary.executive_summary);
-    md.push_str("\n\n");
+/// Answer a question about one session from its transcript, with the
+/// supporting segments and their timestamps
+#[tauri::command]
+#[specta::specta]
+pub async fn ask_meeting(
+    app: AppHandle,
+    session: ActiveListeningSession,
+    question: String,
+) -> Result<MeetingAnswer, String> {
+    meeting_qa::ask_meeting(&app, &session, &question).await
+}
 
-    if !summary.decisions.is_empty() {
-        md.push_str("## Key Decisions\n\n");
-        for decision in &summary.decisions {
-            md.push_str(&format!("- {}\n", decision));
-        }
-        md.push('\n');
-    }
+/// Search all past sessions started between `since` and `until`
+/// (milliseconds) for what was said about something, returning the matching
+/// segments and, with `compose_answer`, an answer written from them
+#[tauri::command]
+#[specta::specta]
+pub async fn search_meeting_memory(
+    app: AppHandle,
+    query: String,
+    since: Option<i64>,
+    until: Option<i64>,
+    compose_answer: bool,
+) -> Result<MemorySearch, String> {
+    meeting_memory::search(&app, &query, since, until, compose_answer).await
+}
 
-    if !summary.action_items.is_empty() {
-        md.push_str("## Action Items\n\n");
-        for item in &summary.action_items {
-            let mut line = format!("- [ ] {}", item.description);
-            if let Some(assignee) = &item.assignee {
-                line.push_str(&format!(" (@{})", assignee));
-            }
-            if let Some(deadline) = &item.deadline {
-                line.push_str(&format!(" [Due: {}]", deadline));
-            }
-            md.push_str(&line);
-            md.push('\n');
-        }
-        md.push('\n');
-    }
+/// Re-run a finished session, or a recording of a meeting, with a different
+/// prompt or model and summarize the result
+#[tauri::command]
+#[specta::specta]
+pub async fn replay_active_listening_session(
+    app: AppHandle,
+    source: ReplaySource,
+    options: ReplayOptions,
+) -> Result<SessionReplay, String> {
+    session_replay::replay_session(&app, source, options).await
+}
 
-    if !summary.topics.is_empty() {
-        md.push_str("## Topics Discussed\n\n");
-        for topic in &summary.topics {
-            md.push_str(&format!("- {}\n", topic));
+/// Export a meeting summary as Markdown, plain text, HTML, JSON or PDF,
+/// with the session's transcript appended when the session is given.
+///
+/// With a path the export is written there and the path is returned;
+/// otherwise the export itself is returned, which PDF doesn't allow.
+#[tauri::command]
+#[specta::specta]
+pub fn export_meeting_summary(
+    summary: MeetingSummary,
+    format: SummaryExportFormat,
+    session: Option<ActiveListeningSession>,
+    path: Option<String>,
+) -> Result<String, String> {
+    let content = meeting_export::render(&summary, session.as_ref(), format)?;
+    match path {
+        Some(path) => {
+            access_policy::check_path(Path::new(&path))?;
+            std::fs::write(&path, content)
+                .map_err(|e| format!("Failed to write summary to {}: {}", path, e))?;
+            info!("Exported meeting summary to {}", path);
+            Ok(path)
         }
-        md.push('\n');
-    }
-
-    if !summary.follow_ups.is_empty() {
-        md.push_str("## Follow-up Questions\n\n");
-        for question in &summary.follow_ups {
-            md.push_str(&format!("- {}\n", question));
+        None if format == SummaryExportFormat::Pdf => {
+            Err("PDF summaries can only be exported to a file".to_string())
         }
-        md.push('\n');
+        None => String::from_utf8(content).map_err(|e| e.to_string()),
     }
-
-    md
 }
 
-/// Format summary as plain text
-fn export_summary_to_text(summary: &MeetingSummary) -> String {
-    let mut text = String::new();
+// ---- Session history commands ----
 
-    text.push_str("MEETING SUMMARY\n");
-    text.push_str(&"=".repeat(50));
-    text.push('\n');
-    text.push_str(&format!("Duration: {} minutes\n\n", summary.duration_minutes));
-
-    text.push_str("EXECUTIVE SUMMARY\n");
-    text.push_str(&"-".repeat(30));
-    text.push('\n');
-    text.push_str(&summary.executive_summary);
-    text.push_str("\n\n");
-
-    if !summary.decisions.is_empty() {
-        text.push_str("KEY DECISIONS\n");
-        text.push_str(&"-".repeat(30));
-        text.push('\n');
-        for (i, decision) in summary.decisions.iter().enumerate() {
-            text.push_str(&format!("{}. {}\n", i + 1, decision));
-        }
-        text.push('\n');
-    }
+/// List past Active Listening sessions, most recent first
+#[tauri::command]
+#[specta::specta]
+pub fn list_active_listening_sessions(
+    app: AppHandle,
+    limit: usize,
+) -> Result<Vec<StoredSessionInfo>, String> {
+    let manager = app.state::<Arc<ActiveListeningHistoryManager>>();
+    manager
+        .list_sessions(limit)
+        .map_err(|e| format!("Failed to list sessions: {}", e))
+}
 
-    if !summary.action_items.is_empty() {
-        text.push_str("ACTION ITEMS\n");
-        text.push_str(&"-".repeat(30));
-        text.push('\n');
-        for (i, item) in summary.action_items.iter().enumerate() {
-            let mut line = format!("{}. {}", i + 1, item.description);
-            if let Some(assignee) = &item.assignee {
-                line.push_str(&format!(" (Assignee: {})", assignee));
-            }
-            if let Some(deadline) = &item.deadline {
-                line.push_str(&format!(" [Due: {}]", deadline));
-            }
-            text.push_str(&line);
-            text.push('\n');
-        }
-        text.push('\n');
-    }
+/// Find past sessions whose topic, transcript, insights or summary contain the query
+#[tauri::command]
+#[specta::specta]
+pub fn search_active_listening_sessions(
+    app: AppHandle,
+    query: String,
+    limit: usize,
+) -> Result<Vec<StoredSessionInfo>, String> {
+    let manager = app.state::<Arc<ActiveListeningHistoryManager>>();
+    manager
+        .search_sessions(&query, limit)
+        .map_err(|e| format!("Failed to search sessions: {}", e))
+}
 
-    if !summary.topics.is_empty() {
-        text.push_str("TOPICS DISCUSSED\n");
-        text.push_str(&"-".repeat(30));
-        text.push('\n');
-        for (i, topic) in summary.topics.iter().enumerate() {
-            text.push_str(&format!("{}. {}\n", i + 1, topic));
-        }
-        text.push('\n');
-    }
+/// Load a past session with its insights and summary
+#[tauri::command]
+#[specta::specta]
+pub fn get_stored_active_listening_session(
+    app: AppHandle,
+    id: String,
+) -> Result<Option<StoredSession>, String> {
+    let manager = app.state::<Arc<ActiveListeningHistoryManager>>();
+    manager
+        .get_session(&id)
+        .map_err(|e| format!("Failed to load session: {}", e))
+}
 
-    if !summary.follow_ups.is_empty() {
-        text.push_str("FOLLOW-UP QUESTIONS\n");
-        text.push_str(&"-".repeat(30));
-        text.push('\n');
-        for (i, question) in summary.follow_ups.iter().enumerate() {
-            text.push_str(&format!("{}. {}\n", i + 1, question));
-        }
-        text.push('\n');
-    }
+/// Delete a past session
+#[tauri::command]
+#[specta::specta]
+pub fn delete_stored_active_listening_session(app: AppHandle, id: String) -> Result<(), String> {
+    let manager = app.state::<Arc<ActiveListeningHistoryManager>>();
+    manager
+        .delete_session(&id)
+        .map_err(|e| format!("Failed to delete session: {}", e))
+}
 
-    text
+/// The names behind the placeholders of a past session that was stored
+/// anonymized, empty for other sessions
+#[tauri::command]
+#[specta::specta]
+pub fn get_session_name_map(app: AppHandle, id: String) -> Result<Vec<NameMapping>, String> {
+    anonymization::load_name_map(&app, &id)
 }
@@ -0,0 +1,55 @@
+//! Tauri commands for the local HTTP API
+
+use crate::managers::api_server::ApiServer;
+use crate::settings::{generate_api_token, get_settings, write_settings};
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+/// Turn the API server on or off. The first time it's turned on a token is
+/// generated.
+#[tauri::command]
+#[specta::specta]
+pub fn change_api_server_enabled_setting(
+    app: AppHandle,
+    api_server: State<'_, Arc<ApiServer>>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.api_server.enabled = enabled;
+    if enabled && settings.api_server.token.is_none() {
+        settings.api_server.token = Some(generate_api_token());
+    }
+    write_settings(&app, settings);
+    api_server.apply_settings()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_api_server_port_setting(
+    app: AppHandle,
+    api_server: State<'_, Arc<ApiServer>>,
+    port: u16,
+) -> Result<(), String> {
+    if port < 1024 {
+        return Err("Port must be between 1024 and 65535".to_string());
+    }
+    let mut settings = get_settings(&app);
+    settings.api_server.port = port;
+    write_settings(&app, settings);
+    api_server.apply_settings()
+}
+
+/// Replace the API token, so clients using the old one are locked out
+#[tauri::command]
+#[specta::specta]
+pub fn regenerate_api_server_token(
+    app: AppHandle,
+    api_server: State<'_, Arc<ApiServer>>,
+) -> Result<String, String> {
+    let token = generate_api_token();
+    let mut settings = get_settings(&app);
+    settings.api_server.token = Some(token.clone());
+    write_settings(&app, settings);
+    api_server.apply_settings()?;
+    Ok(token)
+}
@@ -2,9 +2,11 @@
 
 use crate::managers::ask_ai::{AskAiConversation, AskAiManager, AskAiState};
 use crate::managers::ask_ai_history::AskAiHistoryManager;
+use crate::managers::ask_ai_tools::{AskAiTool, AskAiToolInfo};
+use crate::managers::rag::{DocMetadata, RagManager};
 use crate::overlay::{hide_recording_overlay, reset_overlay_size};
 use crate::settings::{get_settings, write_settings};
-use log::debug;
+use log::{debug, info};
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
 
@@ -102,6 +104,31 @@ pub fn start_new_ask_ai_conversation(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Regenerate the last response in the active conversation
+#[tauri::command]
+#[specta::specta]
+pub fn regenerate_ask_ai_response(app: AppHandle) -> Result<(), String> {
+    let manager = app.state::<Arc<AskAiManager>>();
+    manager.regenerate_last_response()?;
+    debug!("Regenerating last Ask AI response via command");
+    Ok(())
+}
+
+/// Edit an earlier question, forking the conversation at that turn.
+/// Returns the ID of the new branch conversation.
+#[tauri::command]
+#[specta::specta]
+pub fn edit_ask_ai_question_and_branch(
+    app: AppHandle,
+    turn_id: String,
+    question: String,
+) -> Result<String, String> {
+    let manager = app.state::<Arc<AskAiManager>>();
+    let branch_id = manager.edit_question_and_branch(&turn_id, question)?;
+    debug!("Branched Ask AI conversation {} via command", branch_id);
+    Ok(branch_id)
+}
+
 /// Enable or disable Ask AI feature
 #[tauri::command]
 #[specta::specta]
@@ -146,6 +173,44 @@ pub fn change_ask_ai_system_prompt_setting(app: AppHandle, prompt: String) -> Re
     Ok(())
 }
 
+/// Enable or disable tool calling for Ask AI
+#[tauri::command]
+#[specta::specta]
+pub fn change_ask_ai_tools_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.ask_ai.tools_enabled = enabled;
+    write_settings(&app, settings);
+    debug!("Ask AI tools enabled: {}", enabled);
+    Ok(())
+}
+
+/// Change which tools the Ask AI model is allowed to call
+#[tauri::command]
+#[specta::specta]
+pub fn change_ask_ai_allowed_tools_setting(
+    app: AppHandle,
+    tools: Vec<String>,
+) -> Result<(), String> {
+    if let Some(unknown) = tools
+        .iter()
+        .find(|name| AskAiTool::from_name(name).is_none())
+    {
+        return Err(format!("Unknown Ask AI tool: {}", unknown));
+    }
+    let mut settings = get_settings(&app);
+    settings.ask_ai.allowed_tools = tools.clone();
+    write_settings(&app, settings);
+    debug!("Ask AI allowed tools changed to: {:?}", tools);
+    Ok(())
+}
+
+/// List all tools Ask AI can be allowed to call
+#[tauri::command]
+#[specta::specta]
+pub fn get_ask_ai_available_tools() -> Vec<AskAiToolInfo> {
+    AskAiTool::ALL.iter().map(|tool| tool.info()).collect()
+}
+
 /// Get Ask AI settings (for display in UI)
 #[tauri::command]
 #[specta::specta]
@@ -202,6 +267,14 @@ pub fn save_ask_ai_conversation_to_history(
     app: AppHandle,
     conversation: AskAiConversation,
 ) -> Result<(), String> {
+    if crate::settings::is_guest_mode(&app) {
+        debug!(
+            "Guest mode: not saving Ask AI conversation {}",
+            conversation.id
+        );
+        return Ok(());
+    }
+
     let manager = app.state::<Arc<AskAiHistoryManager>>();
     manager
         .save_conversation(&conversation)
@@ -233,6 +306,47 @@ pub fn get_ask_ai_conversation_from_history(
         .map_err(|e| format!("Failed to get conversation: {}", e))
 }
 
+/// List conversations branched from the given conversation
+#[tauri::command]
+#[specta::specta]
+pub fn list_ask_ai_conversation_branches(
+    app: AppHandle,
+    id: String,
+) -> Result<Vec<AskAiConversation>, String> {
+    let manager = app.state::<Arc<AskAiHistoryManager>>();
+    manager
+        .list_branches(&id)
+        .map_err(|e| format!("Failed to list conversation branches: {}", e))
+}
+
+/// Get the absolute path of a saved Ask AI question recording
+#[tauri::command]
+#[specta::specta]
+pub fn get_ask_ai_audio_file_path(app: AppHandle, file_name: String) -> Result<String, String> {
+    let manager = app.state::<Arc<AskAiHistoryManager>>();
+    let path = manager
+        .get_audio_file_path(&file_name)
+        .map_err(|e| e.to_string())?;
+    path.to_str()
+        .ok_or_else(|| "Invalid file path".to_string())
+        .map(|s| s.to_string())
+}
+
+/// Play back a saved Ask AI question recording on the selected output device
+#[tauri::command]
+#[specta::specta]
+pub fn play_ask_ai_question_audio(app: AppHandle, file_name: String) -> Result<(), String> {
+    let manager = app.state::<Arc<AskAiHistoryManager>>();
+    let path = manager
+        .get_audio_file_path(&file_name)
+        .map_err(|e| e.to_string())?;
+    if !path.exists() {
+        return Err(format!("Audio file {} not found", file_name));
+    }
+    crate::audio_feedback::play_recording(&app, path);
+    Ok(())
+}
+
 /// Delete an Ask AI conversation from history
 #[tauri::command]
 #[specta::specta]
@@ -244,3 +358,67 @@ pub fn delete_ask_ai_conversation_from_history(app: AppHandle, id: String) -> Re
     debug!("Deleted Ask AI conversation {} from history", id);
     Ok(())
 }
+
+/// Source type used for Ask AI answers pinned to the knowledge base
+const PINNED_ANSWER_SOURCE_TYPE: &str = "ask_ai";
+
+/// Pin a question/answer turn to the knowledge base so it can be retrieved later.
+///
+/// The turn is looked up in the active conversation first, then in history.
+/// Pinning the same turn twice returns the existing document ID.
+#[tauri::command]
+#[specta::specta]
+pub async fn pin_ask_ai_turn_to_knowledge_base(
+    app: AppHandle,
+    conversation_id: String,
+    turn_id: String,
+) -> Result<i64, String> {
+    let source_id = format!("{}:{}", conversation_id, turn_id);
+    let rag_manager = app.state::<Arc<RagManager>>().inner().clone();
+
+    if let Some(document_id) =
+        rag_manager.find_document_by_source(PINNED_ANSWER_SOURCE_TYPE, &source_id)?
+    {
+        debug!(
+            "Ask AI turn {} already pinned as document {}",
+            turn_id, document_id
+        );
+        return Ok(document_id);
+    }
+
+    let active = app
+        .state::<Arc<AskAiManager>>()
+        .get_conversation()
+        .filter(|conv| conv.id == conversation_id);
+    let conversation = match active {
+        Some(conv) => conv,
+        None => app
+            .state::<Arc<AskAiHistoryManager>>()
+            .get_conversation(&conversation_id)
+            .map_err(|e| format!("Failed to get conversation: {}", e))?
+            .ok_or_else(|| format!("Conversation {} not found", conversation_id))?,
+    };
+
+    let turn = conversation
+        .turns
+        .iter()
+        .find(|turn| turn.id == turn_id)
+        .ok_or_else(|| format!("Turn {} not found", turn_id))?;
+
+    let metadata = DocMetadata {
+        source_type: PINNED_ANSWER_SOURCE_TYPE.to_string(),
+        source_id: Some(source_id),
+        title: Some(turn.question.trim().to_string()),
+        extra: Some(serde_json::json!({
+            "conversation_id": conversation.id,
+            "turn_id": turn.id,
+            "timestamp": turn.timestamp,
+        })),
+    };
+
+    let document_id = rag_manager
+        .add_document(&turn.to_knowledge_text(), metadata)
+        .await?;
+    info!("Pinned Ask AI turn {} as document {}", turn_id, document_id);
+    Ok(document_id)
+}
@@ -1,12 +1,33 @@
 use crate::audio_feedback;
-use crate::audio_toolkit::audio::{list_input_devices, list_output_devices};
+use crate::audio_toolkit::audio::{
+    find_device, list_input_devices, list_output_devices, CpalDeviceInfo,
+};
+use crate::audio_toolkit::decoder::decode_audio_file;
+use crate::audio_toolkit::tuning::{self, LabelledAudio, TuningReport};
+use crate::audio_toolkit::SileroVad;
 use crate::managers::audio::{AudioRecordingManager, MicrophoneMode};
-use crate::settings::{get_settings, write_settings};
+use crate::managers::docking::DockingManager;
+use crate::managers::scheduler::{CronExpression, SchedulerManager};
+use crate::settings::{
+    get_settings, write_settings, DockState, DockingSettings, ScheduleOverrides, ScheduleSettings,
+};
 use log::warn;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::sync::Arc;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Manager, State};
+
+/// Range the gain normalization target can be set in, in dBFS
+const MIN_GAIN_TARGET_DBFS: f32 = -40.0;
+const MAX_GAIN_TARGET_DBFS: f32 = -6.0;
+
+/// Range the silence auto-stop can be set in, in seconds
+const MIN_SILENCE_AUTO_STOP_SECONDS: f32 = 2.0;
+const MAX_SILENCE_AUTO_STOP_SECONDS: f32 = 60.0;
+
+/// Range the recording limit can be set in, in minutes
+const MIN_RECORDING_LIMIT_MINUTES: u32 = 1;
+const MAX_RECORDING_LIMIT_MINUTES: u32 = 120;
 
 #[derive(Serialize, Type)]
 pub struct CustomSounds {
@@ -15,12 +36,9 @@ pub struct CustomSounds {
 }
 
 fn custom_sound_exists(app: &AppHandle, sound_type: &str) -> bool {
-    app.path()
-        .resolve(
-            format!("custom_{}.wav", sound_type),
-            tauri::path::BaseDirectory::AppData,
-        )
-        .map_or(false, |path| path.exists())
+    crate::portable::app_data_dir(app).map_or(false, |dir| {
+        dir.join(format!("custom_{}.wav", sound_type)).exists()
+    })
 }
 
 #[tauri::command]
@@ -35,6 +53,8 @@ pub fn check_custom_sounds(app: AppHandle) -> CustomSounds {
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
 pub struct AudioDevice {
     pub index: String,
+    /// Stable identifier, see `CpalDeviceInfo::id`
+    pub id: String,
     pub name: String,
     pub is_default: bool,
 }
@@ -74,12 +94,14 @@ pub fn get_available_microphones() -> Result<Vec<AudioDevice>, String> {
 
     let mut result = vec![AudioDevice {
         index: "default".to_string(),
+        id: "default".to_string(),
         name: "Default".to_string(),
         is_default: true,
     }];
 
     result.extend(devices.into_iter().map(|d| AudioDevice {
         index: d.index,
+        id: d.id,
         name: d.name,
         is_default: false, // The explicit default is handled separately
     }));
@@ -87,15 +109,25 @@ pub fn get_available_microphones() -> Result<Vec<AudioDevice>, String> {
     Ok(result)
 }
 
+/// Stable id of the device shown under this name, if it is connected
+fn device_id_for(
+    devices: Result<Vec<CpalDeviceInfo>, Box<dyn std::error::Error>>,
+    name: &str,
+) -> Option<String> {
+    find_device(devices.ok()?, None, Some(name)).map(|d| d.id)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn set_selected_microphone(app: AppHandle, device_name: String) -> Result<(), String> {
     let mut settings = get_settings(&app);
-    settings.selected_microphone = if device_name == "default" {
-        None
+    if device_name == "default" {
+        settings.selected_microphone = None;
+        settings.selected_microphone_id = None;
     } else {
-        Some(device_name)
-    };
+        settings.selected_microphone_id = device_id_for(list_input_devices(), &device_name);
+        settings.selected_microphone = Some(device_name);
+    }
     write_settings(&app, settings);
 
     // Update the audio manager to use the new device
@@ -123,12 +155,14 @@ pub fn get_available_output_devices() -> Result<Vec<AudioDevice>, String> {
 
     let mut result = vec![AudioDevice {
         index: "default".to_string(),
+        id: "default".to_string(),
         name: "Default".to_string(),
         is_default: true,
     }];
 
     result.extend(devices.into_iter().map(|d| AudioDevice {
         index: d.index,
+        id: d.id,
         name: d.name,
         is_default: false, // The explicit default is handled separately
     }));
@@ -140,11 +174,13 @@ pub fn get_available_output_devices() -> Result<Vec<AudioDevice>, String> {
 #[specta::specta]
 pub fn set_selected_output_device(app: AppHandle, device_name: String) -> Result<(), String> {
     let mut settings = get_settings(&app);
-    settings.selected_output_device = if device_name == "default" {
-        None
+    if device_name == "default" {
+        settings.selected_output_device = None;
+        settings.selected_output_device_id = None;
     } else {
-        Some(device_name)
-    };
+        settings.selected_output_device_id = device_id_for(list_output_devices(), &device_name);
+        settings.selected_output_device = Some(device_name);
+    }
     write_settings(&app, settings);
     Ok(())
 }
@@ -176,11 +212,13 @@ pub async fn play_test_sound(app: AppHandle, sound_type: String) {
 #[specta::specta]
 pub fn set_clamshell_microphone(app: AppHandle, device_name: String) -> Result<(), String> {
     let mut settings = get_settings(&app);
-    settings.clamshell_microphone = if device_name == "default" {
-        None
+    if device_name == "default" {
+        settings.clamshell_microphone = None;
+        settings.clamshell_microphone_id = None;
     } else {
-        Some(device_name)
-    };
+        settings.clamshell_microphone_id = device_id_for(list_input_devices(), &device_name);
+        settings.clamshell_microphone = Some(device_name);
+    }
     write_settings(&app, settings);
     Ok(())
 }
@@ -194,9 +232,249 @@ pub fn get_clamshell_microphone(app: AppHandle) -> Result<String, String> {
         .unwrap_or_else(|| "default".to_string()))
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn get_dock_state(docking_manager: State<Arc<DockingManager>>) -> DockState {
+    docking_manager.state()
+}
+
+/// Replace the dock rules, switching the microphone if the active rule changed it
+#[tauri::command]
+#[specta::specta]
+pub fn set_docking_settings(
+    app: AppHandle,
+    docking_manager: State<Arc<DockingManager>>,
+    docking: DockingSettings,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.docking = docking;
+    write_settings(&app, settings);
+    docking_manager.rules_changed();
+    Ok(())
+}
+
+/// Overrides of the schedule rules active right now
+#[tauri::command]
+#[specta::specta]
+pub fn get_schedule_overrides(scheduler: State<Arc<SchedulerManager>>) -> ScheduleOverrides {
+    scheduler.overrides()
+}
+
+/// Replace the schedule rules and apply the ones active right now
+#[tauri::command]
+#[specta::specta]
+pub fn set_schedule_settings(
+    app: AppHandle,
+    scheduler: State<Arc<SchedulerManager>>,
+    schedule: ScheduleSettings,
+) -> Result<(), String> {
+    for rule in &schedule.rules {
+        CronExpression::parse(&rule.when)
+            .map_err(|e| format!("Invalid schedule for '{}': {}", rule.name, e))?;
+    }
+    let mut settings = get_settings(&app);
+    settings.schedule = schedule;
+    write_settings(&app, settings);
+    scheduler.rules_changed();
+    Ok(())
+}
+
+/// Stream input levels and VAD decisions as `vad-test-point` events until
+/// stopped or the time limit is reached
+#[tauri::command]
+#[specta::specta]
+pub fn start_vad_test(app: AppHandle) -> Result<(), String> {
+    crate::vad_test::start(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn stop_vad_test(app: AppHandle) {
+    crate::vad_test::stop(&app);
+}
+
+/// Record the user reading aloud and store their voiceprint, so other
+/// speakers can be filtered out
+#[tauri::command]
+#[specta::specta]
+pub async fn enroll_voice_profile(app: AppHandle) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || crate::voice_profile::enroll(&app))
+        .await
+        .map_err(|e| format!("Voice enrollment task failed: {}", e))?
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn clear_voice_profile(app: AppHandle) {
+    crate::voice_profile::clear(&app);
+}
+
+/// Turn gain normalization on or off and set the level, in dBFS, that
+/// recordings are brought to before transcription
+#[tauri::command]
+#[specta::specta]
+pub fn change_gain_normalization_setting(
+    app: AppHandle,
+    enabled: bool,
+    target_dbfs: f32,
+) -> Result<(), String> {
+    if !(MIN_GAIN_TARGET_DBFS..=MAX_GAIN_TARGET_DBFS).contains(&target_dbfs) {
+        return Err(format!(
+            "Target level must be between {} and {} dBFS",
+            MIN_GAIN_TARGET_DBFS, MAX_GAIN_TARGET_DBFS
+        ));
+    }
+    let mut settings = get_settings(&app);
+    settings.general.gain_normalization = enabled;
+    settings.general.gain_target_dbfs = target_dbfs;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Turn the silence auto-stop of toggle-mode recordings on or off and set
+/// how many seconds without speech end a recording
+#[tauri::command]
+#[specta::specta]
+pub fn change_silence_auto_stop_setting(
+    app: AppHandle,
+    enabled: bool,
+    seconds: f32,
+) -> Result<(), String> {
+    if !(MIN_SILENCE_AUTO_STOP_SECONDS..=MAX_SILENCE_AUTO_STOP_SECONDS).contains(&seconds) {
+        return Err(format!(
+            "Silence before stopping must be between {} and {} seconds",
+            MIN_SILENCE_AUTO_STOP_SECONDS, MAX_SILENCE_AUTO_STOP_SECONDS
+        ));
+    }
+    let mut settings = get_settings(&app);
+    settings.general.silence_auto_stop = enabled;
+    settings.general.silence_auto_stop_seconds = seconds;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Turn the recording limit on or off and set how many minutes a recording
+/// can run before it's stopped and transcribed. Applies from the next
+/// recording.
+#[tauri::command]
+#[specta::specta]
+pub fn change_recording_limit_setting(
+    app: AppHandle,
+    enabled: bool,
+    minutes: u32,
+) -> Result<(), String> {
+    if !(MIN_RECORDING_LIMIT_MINUTES..=MAX_RECORDING_LIMIT_MINUTES).contains(&minutes) {
+        return Err(format!(
+            "Recording limit must be between {} and {} minutes",
+            MIN_RECORDING_LIMIT_MINUTES, MAX_RECORDING_LIMIT_MINUTES
+        ));
+    }
+    let mut settings = get_settings(&app);
+    settings.general.recording_limit = enabled;
+    settings.general.recording_limit_minutes = minutes;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Mute or unmute the system microphone, returning the new state
+#[tauri::command]
+#[specta::specta]
+pub fn toggle_microphone_mute(app: AppHandle) -> Result<bool, String> {
+    crate::utils::toggle_microphone_mute(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn is_microphone_muted() -> bool {
+    crate::helpers::system_mute::is_microphone_muted()
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn is_recording(app: AppHandle) -> bool {
     let audio_manager = app.state::<Arc<AudioRecordingManager>>();
     audio_manager.is_recording()
 }
+
+/// A recording to tune the VAD with. Without speech labels it is assumed to
+/// follow the guided protocol: [`GUIDED_SILENCE_MS`] of silence, then speech
+/// until the end.
+#[derive(Deserialize, Debug, Clone, Type)]
+pub struct TuningSample {
+    pub path: String,
+    /// `(start, end)` of each speech segment, in milliseconds
+    pub speech_segments_ms: Option<Vec<(u64, u64)>>,
+    /// Offsets where the speaker changes, in milliseconds
+    pub speaker_changes_ms: Option<Vec<u64>>,
+}
+
+/// Silence at the start of a guided tuning recording
+pub const GUIDED_SILENCE_MS: u64 = 3000;
+
+fn ms_to_samples(ms: u64) -> usize {
+    (ms * 16) as usize
+}
+
+fn load_tuning_sample(sample: &TuningSample) -> Result<LabelledAudio, String> {
+    let path = std::path::Path::new(&sample.path);
+    let decoded = decode_audio_file(path)?;
+    let len = decoded.samples.len();
+
+    let speech = match &sample.speech_segments_ms {
+        Some(segments) => segments
+            .iter()
+            .map(|&(start, end)| ms_to_samples(start).min(len)..ms_to_samples(end).min(len))
+            .collect(),
+        None => vec![ms_to_samples(GUIDED_SILENCE_MS).min(len)..len],
+    };
+
+    Ok(LabelledAudio {
+        name: path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| sample.path.clone()),
+        samples: decoded.samples,
+        speech: Some(speech),
+        speaker_changes: sample
+            .speaker_changes_ms
+            .as_ref()
+            .map(|changes| changes.iter().map(|&ms| ms_to_samples(ms)).collect()),
+    })
+}
+
+/// Score every VAD threshold and diarization config and recommend the best.
+/// VAD is scored on the given recording; diarization is scored on built-in
+/// fixtures plus the recording if it has speaker labels.
+#[tauri::command]
+#[specta::specta]
+pub async fn run_tuning_benchmark(
+    app: AppHandle,
+    sample: Option<TuningSample>,
+) -> Result<TuningReport, String> {
+    let vad_path = app
+        .path()
+        .resolve(
+            "resources/models/silero_vad_v4.onnx",
+            tauri::path::BaseDirectory::Resource,
+        )
+        .map_err(|e| format!("Failed to resolve VAD path: {}", e))?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut audio = Vec::new();
+        let mut probabilities = Vec::new();
+        if let Some(sample) = sample {
+            let recording = load_tuning_sample(&sample)?;
+            let mut vad = SileroVad::new(&vad_path, 0.5)
+                .map_err(|e| format!("Failed to create SileroVad: {}", e))?;
+            probabilities.push(
+                tuning::vad_probabilities(&mut vad, &recording.samples)
+                    .map_err(|e| format!("Failed to run VAD: {}", e))?,
+            );
+            audio.push(recording);
+        }
+        audio.extend(tuning::diarization_fixtures());
+        Ok(tuning::run_benchmark(&audio, probabilities))
+    })
+    .await
+    .map_err(|e| format!("Tuning benchmark failed: {}", e))?
+}
@@ -0,0 +1,52 @@
+//! Tauri commands for automation hooks
+
+use crate::automation_hooks;
+use crate::settings::{get_settings, write_settings, AutomationHook, AutomationHookSettings};
+use tauri::AppHandle;
+use uuid::Uuid;
+
+/// Bounds for a hook's timeout, in milliseconds
+const MIN_HOOK_TIMEOUT_MS: u32 = 100;
+const MAX_HOOK_TIMEOUT_MS: u32 = 60_000;
+
+fn validate_hook(hook: &AutomationHook) -> Result<(), String> {
+    if hook.command.trim().is_empty() {
+        return Err(format!("Hook '{}' needs a command", hook.name));
+    }
+    if !(MIN_HOOK_TIMEOUT_MS..=MAX_HOOK_TIMEOUT_MS).contains(&hook.timeout_ms) {
+        return Err(format!(
+            "Hook timeouts must be between {} and {} ms",
+            MIN_HOOK_TIMEOUT_MS, MAX_HOOK_TIMEOUT_MS
+        ));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_automation_hooks_setting(
+    app: AppHandle,
+    mut automation_hooks: AutomationHookSettings,
+) -> Result<(), String> {
+    for hook in &mut automation_hooks.hooks {
+        validate_hook(hook)?;
+        if hook.id.trim().is_empty() {
+            hook.id = Uuid::new_v4().to_string();
+        }
+    }
+
+    let mut settings = get_settings(&app);
+    settings.automation_hooks = automation_hooks;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Run a hook once with `input` and return what it printed
+#[tauri::command]
+#[specta::specta]
+pub async fn test_automation_hook(hook: AutomationHook, input: String) -> Result<String, String> {
+    validate_hook(&hook)?;
+    tauri::async_runtime::spawn_blocking(move || automation_hooks::run(&hook, &input))
+        .await
+        .map_err(|e| format!("Hook test failed: {}", e))?
+}
@@ -0,0 +1,99 @@
+//! Tauri commands for backups of app data
+
+use crate::managers::backup::{BackupInfo, BackupManager};
+use crate::settings::{get_settings, write_settings, BackupSettings};
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_backup_settings(app: AppHandle) -> Result<BackupSettings, String> {
+    Ok(get_settings(&app).backup)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_backup_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.backup.enabled = enabled;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_backup_interval_setting(app: AppHandle, hours: u32) -> Result<(), String> {
+    if !(1..=24 * 30).contains(&hours) {
+        return Err("Backup interval must be between 1 hour and 30 days".to_string());
+    }
+    let mut settings = get_settings(&app);
+    settings.backup.interval_hours = hours;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_backup_folder_setting(app: AppHandle, folder: Option<String>) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.backup.folder = folder.filter(|f| !f.trim().is_empty());
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_backup_keep_count_setting(app: AppHandle, count: usize) -> Result<(), String> {
+    if !(1..=100).contains(&count) {
+        return Err("Number of backups to keep must be between 1 and 100".to_string());
+    }
+    let mut settings = get_settings(&app);
+    settings.backup.keep_count = count;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_backup_include_audio_setting(app: AppHandle, include: bool) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.backup.include_audio = include;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Create a backup now
+#[tauri::command]
+#[specta::specta]
+pub async fn create_backup(
+    backup_manager: State<'_, Arc<BackupManager>>,
+) -> Result<BackupInfo, String> {
+    let manager = backup_manager.inner().clone();
+    tokio::task::spawn_blocking(move || manager.create_backup())
+        .await
+        .map_err(|e| format!("Backup task failed: {}", e))?
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn list_backups(
+    backup_manager: State<'_, Arc<BackupManager>>,
+) -> Result<Vec<BackupInfo>, String> {
+    Ok(backup_manager.list_backups())
+}
+
+/// Restore a backup and restart the app so every manager reloads its data
+#[tauri::command]
+#[specta::specta]
+pub async fn restore_from_backup(
+    app: AppHandle,
+    backup_manager: State<'_, Arc<BackupManager>>,
+    id: String,
+) -> Result<(), String> {
+    let manager = backup_manager.inner().clone();
+    tokio::task::spawn_blocking(move || manager.restore_backup(&id))
+        .await
+        .map_err(|e| format!("Restore task failed: {}", e))??;
+
+    app.restart()
+}
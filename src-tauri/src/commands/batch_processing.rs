@@ -1,8 +1,19 @@
-use crate::managers::batch_processor::{BatchProcessor, BatchQueueStatus};
-use std::path::PathBuf;
+use crate::access_policy;
+use crate::export::ExportFormat;
+use crate::managers::batch_processor::{
+    validate_naming_template, BatchOutputConfig, BatchProcessor, BatchQueueStatus,
+};
+use crate::settings::{
+    get_settings, write_settings, BatchOutputDestination, BatchOutputSettings,
+    ClipboardWatchSettings,
+};
+use std::path::{Path, PathBuf};
 use tauri::AppHandle;
 use tokio::sync::Mutex;
 
+/// Longest the clipboard watcher may wait for the clipboard to settle
+const MAX_CLIPBOARD_DEBOUNCE_MS: u32 = 10_000;
+
 #[tauri::command]
 #[specta::specta]
 pub async fn add_to_batch_queue(
@@ -14,6 +25,24 @@ pub async fn add_to_batch_queue(
     processor.add_files(file_paths).await
 }
 
+/// Queue a file offered by the clipboard watcher and start the batch, or
+/// let the running batch pick it up
+#[tauri::command]
+#[specta::specta]
+pub async fn transcribe_clipboard_file(
+    path: String,
+    batch_processor: tauri::State<'_, Mutex<BatchProcessor>>,
+) -> Result<BatchQueueStatus, String> {
+    let path = PathBuf::from(path);
+    access_policy::check_path(&path)?;
+    let processor = batch_processor.lock().await;
+    let status = processor.add_files(vec![path]).await?;
+    if !status.is_processing {
+        processor.process_queue().await?;
+    }
+    Ok(status)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn start_batch_processing(
@@ -61,3 +90,123 @@ pub async fn clear_completed_batch_items(
     processor.clear_completed().await;
     Ok(())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_batch_item_format(
+    id: String,
+    format: Option<ExportFormat>,
+    batch_processor: tauri::State<'_, Mutex<BatchProcessor>>,
+) -> Result<(), String> {
+    let processor = batch_processor.lock().await;
+    processor.set_item_format(&id, format).await
+}
+
+/// Output of the current batch: the formats each item is written in and
+/// where, overriding the batch output settings
+#[tauri::command]
+#[specta::specta]
+pub async fn get_batch_output_config(
+    batch_processor: tauri::State<'_, Mutex<BatchProcessor>>,
+) -> Result<BatchOutputConfig, String> {
+    let processor = batch_processor.lock().await;
+    Ok(processor.output_config())
+}
+
+/// Set the output of the current batch. Items still waiting pick it up too.
+#[tauri::command]
+#[specta::specta]
+pub async fn configure_batch_output(
+    config: BatchOutputConfig,
+    batch_processor: tauri::State<'_, Mutex<BatchProcessor>>,
+) -> Result<(), String> {
+    if let Some(dir) = config
+        .output_dir
+        .as_deref()
+        .filter(|dir| !dir.trim().is_empty())
+    {
+        access_policy::check_path(Path::new(dir))?;
+    }
+    let mut processor = batch_processor.lock().await;
+    processor.set_output_config(config).await;
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_batch_output_settings(app: AppHandle) -> Result<BatchOutputSettings, String> {
+    Ok(get_settings(&app).batch_output)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_batch_naming_template(app: AppHandle, template: String) -> Result<(), String> {
+    validate_naming_template(&template)?;
+    let mut settings = get_settings(&app);
+    settings.batch_output.naming_template = template.trim().to_string();
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_batch_output_destination(
+    app: AppHandle,
+    destination: BatchOutputDestination,
+    output_root: Option<String>,
+    mirror_source_root: Option<String>,
+) -> Result<(), String> {
+    let output_root = output_root.filter(|root| !root.trim().is_empty());
+    if destination != BatchOutputDestination::SameDirectory && output_root.is_none() {
+        return Err("An output folder is required for this destination".to_string());
+    }
+
+    let mut settings = get_settings(&app);
+    settings.batch_output.destination = destination;
+    settings.batch_output.output_root = output_root;
+    settings.batch_output.mirror_source_root =
+        mirror_source_root.filter(|root| !root.trim().is_empty());
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_batch_default_format(app: AppHandle, format: ExportFormat) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.batch_output.default_format = format;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set whether copied audio files are offered for transcription
+#[tauri::command]
+#[specta::specta]
+pub fn change_clipboard_watch_setting(
+    app: AppHandle,
+    mut clipboard_watch: ClipboardWatchSettings,
+) -> Result<(), String> {
+    if clipboard_watch.debounce_ms > MAX_CLIPBOARD_DEBOUNCE_MS {
+        return Err(format!(
+            "Debounce must be at most {} ms",
+            MAX_CLIPBOARD_DEBOUNCE_MS
+        ));
+    }
+
+    let mut extensions: Vec<String> = Vec::new();
+    for extension in &clipboard_watch.extensions {
+        let extension = extension.trim().trim_start_matches('.').to_lowercase();
+        if !extension.is_empty() && !extensions.contains(&extension) {
+            extensions.push(extension);
+        }
+    }
+    if clipboard_watch.enabled && extensions.is_empty() {
+        return Err("At least one file extension is required".to_string());
+    }
+    clipboard_watch.extensions = extensions;
+
+    let mut settings = get_settings(&app);
+    settings.clipboard_watch = clipboard_watch;
+    write_settings(&app, settings);
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! Tauri commands for the browser extension bridge
+
+use crate::browser_bridge;
+use crate::managers::browser_bridge::{BrowserBridge, BrowserBridgeStatus};
+use crate::settings::{generate_api_token, get_settings, write_settings, SiteRule};
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+/// Turn the browser bridge on or off. The first time it's turned on a token
+/// is generated.
+#[tauri::command]
+#[specta::specta]
+pub fn change_browser_bridge_enabled_setting(
+    app: AppHandle,
+    bridge: State<'_, Arc<BrowserBridge>>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.browser_bridge.enabled = enabled;
+    if enabled && settings.browser_bridge.token.is_none() {
+        settings.browser_bridge.token = Some(generate_api_token());
+    }
+    write_settings(&app, settings);
+    bridge.apply_settings()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_browser_bridge_port_setting(
+    app: AppHandle,
+    bridge: State<'_, Arc<BrowserBridge>>,
+    port: u16,
+) -> Result<(), String> {
+    if port < 1024 {
+        return Err("Port must be between 1024 and 65535".to_string());
+    }
+    let mut settings = get_settings(&app);
+    settings.browser_bridge.port = port;
+    write_settings(&app, settings);
+    bridge.apply_settings()
+}
+
+/// Set the companion extension IDs the native messaging host accepts. The
+/// host has to be installed again for a change to reach the browsers.
+#[tauri::command]
+#[specta::specta]
+pub fn set_browser_extension_ids(app: AppHandle, ids: Vec<String>) -> Result<(), String> {
+    let ids: Vec<String> = ids
+        .iter()
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .collect();
+    let mut settings = get_settings(&app);
+    settings.browser_bridge.extension_ids = ids;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_browser_site_rules(app: AppHandle, rules: Vec<SiteRule>) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    for rule in &rules {
+        if rule.host.trim().is_empty() {
+            return Err("Site rules need a host".to_string());
+        }
+        if let Some(mode) = &rule.formatting_mode {
+            if !settings.formatting.modes.iter().any(|m| &m.id == mode) {
+                return Err(format!("Formatting mode '{}' not found", mode));
+            }
+        }
+    }
+    settings.browser_bridge.site_rules = rules;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Install the native messaging host for the installed browsers, returning
+/// where it was installed
+#[tauri::command]
+#[specta::specta]
+pub fn install_browser_native_host(app: AppHandle) -> Result<Vec<String>, String> {
+    let settings = get_settings(&app).browser_bridge;
+    browser_bridge::install_native_host(&app, &settings)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_browser_bridge_status(bridge: State<'_, Arc<BrowserBridge>>) -> BrowserBridgeStatus {
+    bridge.status()
+}
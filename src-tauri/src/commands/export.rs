@@ -0,0 +1,188 @@
+use crate::export::templates::{self, ExportData};
+use crate::export::writers::{self, ExportDestination, RenderedExport};
+use crate::managers::active_listening::MeetingSummary;
+use crate::managers::ask_ai_history::AskAiHistoryManager;
+use crate::managers::history::HistoryManager;
+use crate::settings::{get_settings, write_settings, ExportKind, ExportTemplate};
+use serde::Deserialize;
+use specta::Type;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// An object to export
+#[derive(Clone, Debug, Deserialize, Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExportSource {
+    Transcript {
+        entry_id: i64,
+    },
+    /// Summaries aren't always stored, so the one on screen is passed in
+    MeetingSummary {
+        summary: MeetingSummary,
+    },
+    /// Action items of one history entry, or all of them
+    ActionItems {
+        entry_id: Option<i64>,
+    },
+    AskAiConversation {
+        conversation_id: String,
+    },
+}
+
+async fn load_source(app: &AppHandle, source: ExportSource) -> Result<ExportData, String> {
+    match source {
+        ExportSource::Transcript { entry_id } => {
+            let history = app.state::<Arc<HistoryManager>>();
+            history
+                .get_entry_by_id(entry_id)
+                .await
+                .map_err(|e| format!("Failed to get history entry: {}", e))?
+                .map(ExportData::Transcript)
+                .ok_or_else(|| format!("History entry {} not found", entry_id))
+        }
+        ExportSource::MeetingSummary { summary } => Ok(ExportData::MeetingSummary(summary)),
+        ExportSource::ActionItems { entry_id } => {
+            let history = app.state::<Arc<HistoryManager>>();
+            history
+                .get_action_items(entry_id)
+                .map(ExportData::ActionItems)
+                .map_err(|e| format!("Failed to get action items: {}", e))
+        }
+        ExportSource::AskAiConversation { conversation_id } => {
+            let manager = app.state::<Arc<AskAiHistoryManager>>();
+            manager
+                .get_conversation(&conversation_id)
+                .map_err(|e| format!("Failed to get conversation: {}", e))?
+                .map(ExportData::AskAiConversation)
+                .ok_or_else(|| format!("Conversation {} not found", conversation_id))
+        }
+    }
+}
+
+async fn render_source(
+    app: &AppHandle,
+    source: ExportSource,
+    template_id: &str,
+) -> Result<RenderedExport, String> {
+    let template = templates::find_template(&get_settings(app).export, template_id)
+        .ok_or_else(|| format!("Export template '{}' not found", template_id))?;
+    let data = load_source(app, source).await?;
+    Ok(RenderedExport {
+        kind: template.kind,
+        content: templates::render(&template, &data)?,
+        template_id: template.id,
+        file_extension: template.file_extension,
+    })
+}
+
+/// List the built-in and user export templates, optionally for one kind
+#[tauri::command]
+#[specta::specta]
+pub fn get_export_templates(app: AppHandle, kind: Option<ExportKind>) -> Vec<ExportTemplate> {
+    templates::list_templates(&get_settings(&app).export, kind)
+}
+
+/// Create a user template, or update it if its id exists. Templates without
+/// an id get a new one.
+#[tauri::command]
+#[specta::specta]
+pub fn save_export_template(
+    app: AppHandle,
+    template: ExportTemplate,
+) -> Result<ExportTemplate, String> {
+    if templates::is_builtin(&template.id) {
+        return Err("Built-in templates can't be changed, save a copy instead".to_string());
+    }
+    templates::validate(&template.body)?;
+
+    let mut template = template;
+    if template.id.trim().is_empty() {
+        template.id = format!("template_{}", chrono::Utc::now().timestamp_millis());
+    }
+    template.file_extension = template
+        .file_extension
+        .trim()
+        .trim_start_matches('.')
+        .to_string();
+    if template.file_extension.is_empty() {
+        template.file_extension = "txt".to_string();
+    }
+
+    let mut settings = get_settings(&app);
+    match settings
+        .export
+        .templates
+        .iter_mut()
+        .find(|existing| existing.id == template.id)
+    {
+        Some(existing) => *existing = template.clone(),
+        None => settings.export.templates.push(template.clone()),
+    }
+    write_settings(&app, settings);
+
+    Ok(template)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn delete_export_template(app: AppHandle, id: String) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    let original_len = settings.export.templates.len();
+    settings
+        .export
+        .templates
+        .retain(|template| template.id != id);
+    if settings.export.templates.len() == original_len {
+        return Err(format!("Export template '{}' not found", id));
+    }
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_export_webhook_url_setting(
+    app: AppHandle,
+    url: Option<String>,
+) -> Result<(), String> {
+    let url = url
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty());
+    if let Some(url) = &url {
+        if !crate::warning_notifications::is_valid_webhook_url(url) {
+            return Err("Only http(s) webhook URLs are supported".to_string());
+        }
+    }
+
+    let mut settings = get_settings(&app);
+    settings.export.webhook_url = url;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Render an object with a template without writing it anywhere, for previews
+#[tauri::command]
+#[specta::specta]
+pub async fn render_export(
+    app: AppHandle,
+    source: ExportSource,
+    template_id: String,
+) -> Result<String, String> {
+    render_source(&app, source, &template_id)
+        .await
+        .map(|export| export.content)
+}
+
+/// Render an object with a template and write it to a file, the clipboard
+/// or a webhook. Returns where the export went.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_with_template(
+    app: AppHandle,
+    source: ExportSource,
+    template_id: String,
+    destination: ExportDestination,
+) -> Result<String, String> {
+    let export = render_source(&app, source, &template_id).await?;
+    writers::write_export(&app, &export, &destination).await
+}
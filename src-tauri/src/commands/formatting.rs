@@ -0,0 +1,53 @@
+use crate::formatting;
+use crate::settings::formatting::default_formatting_modes;
+use crate::settings::{get_settings, write_settings, FormattingMode};
+use crate::tray;
+use std::collections::HashSet;
+use tauri::AppHandle;
+
+/// Format dictations with a mode, or stop formatting them with `None`
+#[tauri::command]
+#[specta::specta]
+pub fn set_formatting_mode(app: AppHandle, mode_id: Option<String>) -> Result<(), String> {
+    formatting::select_mode(&app, mode_id)
+}
+
+/// Replace the formatting modes. A selected mode that was removed is
+/// unselected.
+#[tauri::command]
+#[specta::specta]
+pub fn set_formatting_modes(app: AppHandle, modes: Vec<FormattingMode>) -> Result<(), String> {
+    let mut ids = HashSet::new();
+    for mode in &modes {
+        if mode.id.trim().is_empty() || mode.name.trim().is_empty() {
+            return Err("Formatting modes need an id and a name".to_string());
+        }
+        if mode.prompt.trim().is_empty() {
+            return Err(format!("Formatting mode '{}' has no prompt", mode.name));
+        }
+        if !ids.insert(mode.id.as_str()) {
+            return Err(format!("Formatting mode id '{}' is used twice", mode.id));
+        }
+    }
+
+    let mut settings = get_settings(&app);
+    settings.formatting.modes = modes;
+    if settings.formatting.active_mode().is_none() {
+        settings.formatting.selected_mode = None;
+    }
+    write_settings(&app, settings);
+    tray::refresh_tray_menu(&app);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn reset_formatting_modes(app: AppHandle) {
+    let mut settings = get_settings(&app);
+    settings.formatting.modes = default_formatting_modes();
+    if settings.formatting.active_mode().is_none() {
+        settings.formatting.selected_mode = None;
+    }
+    write_settings(&app, settings);
+    tray::refresh_tray_menu(&app);
+}
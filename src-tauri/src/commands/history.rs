@@ -1,5 +1,10 @@
-use crate::managers::history::{HistoryEntry, HistoryManager};
-use std::sync::Arc;
+use crate::managers::history::{
+    DuplicateResolution, HistoryEntry, HistoryManager, HistoryStorageStats, TrashedHistoryEntry,
+};
+use crate::managers::transcription::TranscriptSegment;
+use crate::managers::vocabulary::VocabularyManager;
+use crate::settings::HistoryDedupSettings;
+use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, State};
 
 #[tauri::command]
@@ -40,6 +45,19 @@ pub async fn get_audio_file_path(
         .map(|s| s.to_string())
 }
 
+/// Word or segment timings of an entry, for a transcript that follows and
+/// seeks the recording's playback. Empty when the engine didn't report any.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_entry_segments(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+) -> Result<Vec<TranscriptSegment>, String> {
+    history_manager
+        .get_entry_segments(id)
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn delete_history_entry(
@@ -53,6 +71,125 @@ pub async fn delete_history_entry(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn restore_history_entry(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+) -> Result<(), String> {
+    history_manager
+        .restore_entry(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Keep one or both of an entry flagged as a repeat and the one before it
+#[tauri::command]
+#[specta::specta]
+pub async fn resolve_duplicate_history_entry(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    resolution: DuplicateResolution,
+) -> Result<(), String> {
+    history_manager
+        .resolve_duplicate(id, resolution)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Rate how well an entry was transcribed, from 1 to 5, optionally with
+/// what was actually said. Ratings feed the usage report's model stats and
+/// new corrections teach the vocabulary the terms the model missed, which
+/// are returned.
+#[tauri::command]
+#[specta::specta]
+pub async fn rate_transcription(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    vocabulary_manager: State<'_, Mutex<VocabularyManager>>,
+    history_id: i64,
+    rating: u8,
+    correction: Option<String>,
+) -> Result<Vec<String>, String> {
+    if !(1..=5).contains(&rating) {
+        return Err("Rating must be between 1 and 5".to_string());
+    }
+    let correction = correction
+        .map(|correction| correction.trim().to_string())
+        .filter(|correction| !correction.is_empty());
+
+    let (transcription, previous_correction) = history_manager
+        .rate_entry(history_id, rating, correction.as_deref())
+        .map_err(|e| e.to_string())?;
+
+    // Only learn from a correction once, not every time the entry is rated
+    let Some(correction) = correction.filter(|c| previous_correction.as_ref() != Some(c)) else {
+        return Ok(Vec::new());
+    };
+    let manager = vocabulary_manager
+        .lock()
+        .map_err(|e| format!("Failed to lock vocabulary manager: {}", e))?;
+    manager.learn_from_correction(&transcription, &correction)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_trash(
+    history_manager: State<'_, Arc<HistoryManager>>,
+) -> Result<Vec<TrashedHistoryEntry>, String> {
+    history_manager
+        .get_trash_entries()
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn empty_trash(history_manager: State<'_, Arc<HistoryManager>>) -> Result<usize, String> {
+    history_manager.empty_trash().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn update_history_trash_retention_days(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    days: u32,
+) -> Result<(), String> {
+    if days > 365 {
+        return Err("Trash retention must be 365 days or less".to_string());
+    }
+
+    let mut settings = crate::settings::get_settings(&app);
+    settings.history_trash_retention_days = days;
+    crate::settings::write_settings(&app, settings);
+
+    history_manager
+        .cleanup_old_entries()
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Set how accidental double dictations are detected
+#[tauri::command]
+#[specta::specta]
+pub async fn update_history_dedup(
+    app: AppHandle,
+    dedup: HistoryDedupSettings,
+) -> Result<(), String> {
+    if !(0.5..=1.0).contains(&dedup.similarity_threshold) {
+        return Err("Similarity threshold must be between 0.5 and 1".to_string());
+    }
+    if dedup.window_seconds == 0 || dedup.window_seconds > 300 {
+        return Err("Duplicate window must be between 1 and 300 seconds".to_string());
+    }
+
+    let mut settings = crate::settings::get_settings(&app);
+    settings.history_dedup = dedup;
+    crate::settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn update_history_limit(
@@ -71,6 +208,38 @@ pub async fn update_history_limit(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn update_history_max_size(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    max_size_mb: Option<u64>,
+) -> Result<(), String> {
+    if max_size_mb == Some(0) {
+        return Err("History size cap must be greater than 0 MB".to_string());
+    }
+
+    let mut settings = crate::settings::get_settings(&app);
+    settings.history_max_size_mb = max_size_mb;
+    crate::settings::write_settings(&app, settings);
+
+    history_manager
+        .cleanup_old_entries()
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_storage_stats(
+    history_manager: State<'_, Arc<HistoryManager>>,
+) -> Result<HistoryStorageStats, String> {
+    history_manager
+        .get_storage_stats()
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn update_recording_retention_period(
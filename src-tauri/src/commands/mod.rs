@@ -1,17 +1,39 @@
 pub mod active_listening;
+pub mod api_server;
 pub mod ask_ai;
 pub mod audio;
+pub mod automation_hooks;
+pub mod backup;
 pub mod batch_processing;
+pub mod browser_bridge;
+pub mod export;
+pub mod formatting;
 pub mod history;
 pub mod models;
+pub mod notifications;
+pub mod onboarding;
+pub mod palette;
+pub mod plugins;
 pub mod rag;
+pub mod remote_control;
+pub mod report;
+pub mod signals;
 pub mod suggestions;
+pub mod sync;
 pub mod tasks;
+pub mod transcribe_profiles;
 pub mod transcription;
+pub mod translation;
+pub mod updates;
 pub mod sound_detection;
 pub mod vocabulary;
+pub mod wake_word;
+pub mod webhooks;
 
-use crate::settings::{get_settings, write_settings, AppSettings, LogLevel};
+use crate::access_policy::AccessViolation;
+use crate::event_trail::{EventTrail, TrailEntry};
+use crate::log_viewer::{LogFilter, LogPage};
+use crate::settings::{get_settings, write_settings, AccessPolicySettings, AppSettings, LogLevel};
 use crate::utils::cancel_current_operation;
 use tauri::{AppHandle, Manager};
 use tauri_plugin_opener::OpenerExt;
@@ -25,14 +47,19 @@ pub fn cancel_operation(app: AppHandle) {
 #[tauri::command]
 #[specta::specta]
 pub fn get_app_dir_path(app: AppHandle) -> Result<String, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::portable::app_data_dir(&app)
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
     Ok(app_data_dir.to_string_lossy().to_string())
 }
 
+/// Whether app data lives next to the executable instead of the user profile
+#[tauri::command]
+#[specta::specta]
+pub fn is_portable_mode() -> bool {
+    crate::portable::is_portable()
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn get_app_settings(app: AppHandle) -> Result<AppSettings, String> {
@@ -48,9 +75,7 @@ pub fn get_default_settings() -> Result<AppSettings, String> {
 #[tauri::command]
 #[specta::specta]
 pub fn get_log_dir_path(app: AppHandle) -> Result<String, String> {
-    let log_dir = app
-        .path()
-        .app_log_dir()
+    let log_dir = crate::portable::app_log_dir(&app)
         .map_err(|e| format!("Failed to get log directory: {}", e))?;
 
     Ok(log_dir.to_string_lossy().to_string())
@@ -74,12 +99,58 @@ pub fn set_log_level(app: AppHandle, level: LogLevel) -> Result<(), String> {
     Ok(())
 }
 
+/// Override the file log level for one module, or remove its override when
+/// `level` is `None`
+#[specta::specta]
+#[tauri::command]
+pub fn set_log_module_level(
+    app: AppHandle,
+    module: String,
+    level: Option<LogLevel>,
+) -> Result<(), String> {
+    let module = module.trim().to_string();
+    if module.is_empty() {
+        return Err("Module name cannot be empty".to_string());
+    }
+
+    let mut settings = get_settings(&app);
+    match level {
+        Some(level) => {
+            settings.log_module_levels.insert(module, level);
+        }
+        None => {
+            settings.log_module_levels.remove(&module);
+        }
+    }
+    crate::log_levels::set_module_levels(&settings.log_module_levels);
+    write_settings(&app, settings);
+
+    Ok(())
+}
+
+/// Replace the hosts and folders integrations may access
+#[specta::specta]
+#[tauri::command]
+pub fn set_access_policy(app: AppHandle, policy: AccessPolicySettings) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.access_policy = policy;
+    write_settings(&app, settings);
+    crate::access_policy::reload(&app);
+
+    Ok(())
+}
+
+/// Hosts and paths recently reported by the access policy, oldest first
+#[specta::specta]
+#[tauri::command]
+pub fn get_access_policy_violations() -> Vec<AccessViolation> {
+    crate::access_policy::violations()
+}
+
 #[specta::specta]
 #[tauri::command]
 pub fn open_recordings_folder(app: AppHandle) -> Result<(), String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::portable::app_data_dir(&app)
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
     let recordings_dir = app_data_dir.join("recordings");
@@ -95,9 +166,7 @@ pub fn open_recordings_folder(app: AppHandle) -> Result<(), String> {
 #[specta::specta]
 #[tauri::command]
 pub fn open_log_dir(app: AppHandle) -> Result<(), String> {
-    let log_dir = app
-        .path()
-        .app_log_dir()
+    let log_dir = crate::portable::app_log_dir(&app)
         .map_err(|e| format!("Failed to get log directory: {}", e))?;
 
     let path = log_dir.to_string_lossy().as_ref().to_string();
@@ -108,12 +177,44 @@ pub fn open_log_dir(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Log entries from the app's log files, newest first
+#[specta::specta]
+#[tauri::command]
+pub async fn get_recent_logs(
+    app: AppHandle,
+    filter: Option<LogFilter>,
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> Result<LogPage, String> {
+    crate::log_viewer::query(&app, &filter.unwrap_or_default(), offset, limit)
+}
+
+/// Emit new log entries matching the filter as `log-entries` events until
+/// `stop_log_follow` is called
+#[specta::specta]
+#[tauri::command]
+pub fn start_log_follow(app: AppHandle, filter: Option<LogFilter>) -> Result<(), String> {
+    crate::log_viewer::start_follow(&app, filter.unwrap_or_default())
+}
+
+#[specta::specta]
+#[tauri::command]
+pub fn stop_log_follow(app: AppHandle) {
+    crate::log_viewer::stop_follow(&app);
+}
+
+/// Recent app events and manager state changes, oldest first. Empty unless
+/// debug mode is on.
+#[specta::specta]
+#[tauri::command]
+pub fn get_event_trail(app: AppHandle) -> Vec<TrailEntry> {
+    app.state::<EventTrail>().entries()
+}
+
 #[specta::specta]
 #[tauri::command]
 pub fn open_app_data_dir(app: AppHandle) -> Result<(), String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::portable::app_data_dir(&app)
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
     let path = app_data_dir.to_string_lossy().as_ref().to_string();
@@ -0,0 +1,39 @@
+//! Tauri commands for notification settings
+
+use crate::settings::{get_settings, write_settings, NotificationSettings};
+use tauri::AppHandle;
+
+/// Longest batch window, in seconds
+const MAX_BATCH_WINDOW_SECONDS: u32 = 3600;
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_notification_settings(app: AppHandle) -> NotificationSettings {
+    get_settings(&app).notifications
+}
+
+/// Replace the notification settings
+#[tauri::command]
+#[specta::specta]
+pub fn set_notification_settings(
+    app: AppHandle,
+    notifications: NotificationSettings,
+) -> Result<(), String> {
+    let quiet_hours = &notifications.quiet_hours;
+    if quiet_hours.start_minute >= MINUTES_PER_DAY || quiet_hours.end_minute >= MINUTES_PER_DAY {
+        return Err("Quiet hours must be times of day".to_string());
+    }
+    if notifications.batch_window_seconds > MAX_BATCH_WINDOW_SECONDS {
+        return Err(format!(
+            "Batch window must be at most {} seconds",
+            MAX_BATCH_WINDOW_SECONDS
+        ));
+    }
+
+    let mut settings = get_settings(&app);
+    settings.notifications = notifications;
+    write_settings(&app, settings);
+    Ok(())
+}
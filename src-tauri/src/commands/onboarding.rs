@@ -0,0 +1,291 @@
+//! Hardware and permission checks for the onboarding flow
+//!
+//! Each check is exposed as its own command, and `get_capability_report`
+//! bundles them so onboarding can adapt its steps to the current platform.
+
+use crate::audio_toolkit::audio::{list_input_devices, LoopbackCapture, LoopbackSupport};
+use crate::commands::active_listening::LoopbackSupportLevel;
+use crate::helpers::disk_space::available_space;
+use crate::managers::model::ModelManager;
+use crate::ollama_client::OllamaClient;
+use crate::permission_monitor::MICROPHONE_SETTINGS_URL;
+use crate::settings::get_settings;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+use tauri_plugin_notification::{NotificationExt, PermissionState};
+use tauri_plugin_opener::OpenerExt;
+
+/// Status of an OS permission the app relies on
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionStatus {
+    Granted,
+    Denied,
+    /// The user has not been asked yet
+    NotDetermined,
+    /// This platform does not gate the capability behind a permission
+    NotRequired,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct MicrophoneCheck {
+    pub permission: PermissionStatus,
+    pub input_device_count: usize,
+    pub has_default_device: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct LoopbackCheck {
+    pub support_level: LoopbackSupportLevel,
+    pub device_count: usize,
+}
+
+/// Whether there is room to download transcription models
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum DiskSpaceStatus {
+    /// Every available model fits
+    Sufficient,
+    /// Only some of the smaller models fit
+    LimitedToSmallModels,
+    /// Not even the smallest model fits
+    Insufficient,
+    /// Free space could not be determined
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct DiskSpaceCheck {
+    pub status: DiskSpaceStatus,
+    pub free_mb: Option<u64>,
+    pub smallest_model_mb: u64,
+    pub largest_model_mb: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct OllamaCheck {
+    pub base_url: String,
+    pub reachable: bool,
+    pub model_count: usize,
+}
+
+/// Everything onboarding needs to know about this machine
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct CapabilityReport {
+    pub platform: String,
+    pub microphone: MicrophoneCheck,
+    pub accessibility: PermissionStatus,
+    pub notifications: PermissionStatus,
+    pub loopback: LoopbackCheck,
+    pub disk_space: DiskSpaceCheck,
+    pub ollama: OllamaCheck,
+}
+
+fn permission_from_bool(granted: bool) -> PermissionStatus {
+    if granted {
+        PermissionStatus::Granted
+    } else {
+        PermissionStatus::Denied
+    }
+}
+
+fn disk_space_status(free_mb: Option<u64>, smallest_mb: u64, largest_mb: u64) -> DiskSpaceStatus {
+    match free_mb {
+        None => DiskSpaceStatus::Unknown,
+        Some(free) if free >= largest_mb => DiskSpaceStatus::Sufficient,
+        Some(free) if free >= smallest_mb => DiskSpaceStatus::LimitedToSmallModels,
+        Some(_) => DiskSpaceStatus::Insufficient,
+    }
+}
+
+/// Check microphone permission and whether any input device is present
+#[tauri::command]
+#[specta::specta]
+pub async fn check_microphone_capability() -> MicrophoneCheck {
+    #[cfg(target_os = "macos")]
+    let permission =
+        permission_from_bool(tauri_plugin_macos_permissions::check_microphone_permission().await);
+    #[cfg(not(target_os = "macos"))]
+    let permission = PermissionStatus::NotRequired;
+
+    let devices = list_input_devices().unwrap_or_else(|e| {
+        debug!("Failed to list input devices: {}", e);
+        Vec::new()
+    });
+
+    MicrophoneCheck {
+        permission,
+        input_device_count: devices.len(),
+        has_default_device: devices.iter().any(|d| d.is_default),
+    }
+}
+
+/// Check accessibility permission, needed to paste transcriptions on macOS
+#[tauri::command]
+#[specta::specta]
+pub async fn check_accessibility_capability() -> PermissionStatus {
+    #[cfg(target_os = "macos")]
+    {
+        permission_from_bool(tauri_plugin_macos_permissions::check_accessibility_permission().await)
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        PermissionStatus::NotRequired
+    }
+}
+
+/// Check whether system notifications may be shown
+#[tauri::command]
+#[specta::specta]
+pub fn check_notification_capability(app: AppHandle) -> Result<PermissionStatus, String> {
+    let state = app
+        .notification()
+        .permission_state()
+        .map_err(|e| format!("Failed to query notification permission: {}", e))?;
+
+    Ok(match state {
+        PermissionState::Granted => PermissionStatus::Granted,
+        PermissionState::Denied => PermissionStatus::Denied,
+        _ => PermissionStatus::NotDetermined,
+    })
+}
+
+/// Check whether system audio can be captured for active listening
+#[tauri::command]
+#[specta::specta]
+pub fn check_loopback_capability() -> LoopbackCheck {
+    let support_level = match LoopbackCapture::support_level() {
+        LoopbackSupport::Native => LoopbackSupportLevel::Native,
+        LoopbackSupport::RequiresVirtualDevice => LoopbackSupportLevel::RequiresVirtualDevice,
+        LoopbackSupport::NotSupported => LoopbackSupportLevel::NotSupported,
+    };
+    let device_count = if support_level == LoopbackSupportLevel::NotSupported {
+        0
+    } else {
+        LoopbackCapture::list_devices()
+            .map(|devices| devices.len())
+            .unwrap_or(0)
+    };
+
+    LoopbackCheck {
+        support_level,
+        device_count,
+    }
+}
+
+/// Check free space in the models directory against the model catalogue
+#[tauri::command]
+#[specta::specta]
+pub fn check_model_disk_space(
+    app: AppHandle,
+    model_manager: State<'_, Arc<ModelManager>>,
+) -> Result<DiskSpaceCheck, String> {
+    let models_dir = crate::portable::app_data_dir(&app)
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?
+        .join("models");
+
+    let sizes: Vec<u64> = model_manager
+        .get_available_models()
+        .iter()
+        .filter(|m| !m.is_downloaded)
+        .map(|m| m.size_mb)
+        .collect();
+    let smallest_model_mb = sizes.iter().copied().min().unwrap_or(0);
+    let largest_model_mb = sizes.iter().copied().max().unwrap_or(0);
+
+    let free_mb = match available_space(&models_dir) {
+        Ok(bytes) => Some(bytes / (1024 * 1024)),
+        Err(e) => {
+            debug!("Could not determine free disk space: {}", e);
+            None
+        }
+    };
+
+    Ok(DiskSpaceCheck {
+        status: disk_space_status(free_mb, smallest_model_mb, largest_model_mb),
+        free_mb,
+        smallest_model_mb,
+        largest_model_mb,
+    })
+}
+
+/// Check whether the configured Ollama server is running
+#[tauri::command]
+#[specta::specta]
+pub async fn check_ollama_presence(app: AppHandle) -> Result<OllamaCheck, String> {
+    let base_url = get_settings(&app).ask_ai.ollama_base_url;
+    let client = OllamaClient::new(&base_url)?;
+
+    let reachable = client.health_check().await.unwrap_or(false);
+    let model_count = if reachable {
+        client.list_models().await.map(|m| m.len()).unwrap_or(0)
+    } else {
+        0
+    };
+
+    Ok(OllamaCheck {
+        base_url,
+        reachable,
+        model_count,
+    })
+}
+
+/// Open the system settings page where microphone access is granted
+#[tauri::command]
+#[specta::specta]
+pub fn open_microphone_settings(app: AppHandle) -> Result<(), String> {
+    if !cfg!(target_os = "macos") {
+        return Err("Microphone settings can only be opened on macOS".to_string());
+    }
+    app.opener()
+        .open_url(MICROPHONE_SETTINGS_URL, None::<String>)
+        .map_err(|e| format!("Failed to open microphone settings: {}", e))
+}
+
+/// Run every onboarding check and return a combined report
+#[tauri::command]
+#[specta::specta]
+pub async fn get_capability_report(
+    app: AppHandle,
+    model_manager: State<'_, Arc<ModelManager>>,
+) -> Result<CapabilityReport, String> {
+    Ok(CapabilityReport {
+        platform: std::env::consts::OS.to_string(),
+        microphone: check_microphone_capability().await,
+        accessibility: check_accessibility_capability().await,
+        notifications: check_notification_capability(app.clone())?,
+        loopback: check_loopback_capability(),
+        disk_space: check_model_disk_space(app.clone(), model_manager)?,
+        ollama: check_ollama_presence(app).await?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disk_space_status() {
+        assert_eq!(disk_space_status(None, 100, 1000), DiskSpaceStatus::Unknown);
+        assert_eq!(
+            disk_space_status(Some(5000), 100, 1000),
+            DiskSpaceStatus::Sufficient
+        );
+        assert_eq!(
+            disk_space_status(Some(500), 100, 1000),
+            DiskSpaceStatus::LimitedToSmallModels
+        );
+        assert_eq!(
+            disk_space_status(Some(50), 100, 1000),
+            DiskSpaceStatus::Insufficient
+        );
+        // Nothing left to download
+        assert_eq!(
+            disk_space_status(Some(0), 0, 0),
+            DiskSpaceStatus::Sufficient
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! Tauri commands for the command palette
+
+use crate::palette::{self, PaletteAction, PaletteItem, PaletteOutcome};
+use tauri::AppHandle;
+
+/// Results shown when `limit` isn't given
+const DEFAULT_LIMIT: usize = 20;
+
+/// Palette items matching `query`, best first. An empty query lists the
+/// actions, models and prompts.
+#[tauri::command]
+#[specta::specta]
+pub async fn query_palette(
+    app: AppHandle,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<PaletteItem>, String> {
+    Ok(palette::query(&app, &query, limit.unwrap_or(DEFAULT_LIMIT)).await)
+}
+
+/// Execute the action of a palette item returned by `query_palette`
+#[tauri::command]
+#[specta::specta]
+pub async fn execute_palette_item(
+    app: AppHandle,
+    action: PaletteAction,
+) -> Result<PaletteOutcome, String> {
+    palette::execute(&app, action).await
+}
@@ -0,0 +1,67 @@
+//! Tauri commands for plugins
+
+use crate::managers::plugins::{PluginInfo, PluginManager};
+use crate::settings::{get_settings, write_settings, PluginSettings};
+use crate::shortcut;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+use tauri_plugin_opener::OpenerExt;
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_plugins(plugins: State<'_, Arc<PluginManager>>) -> Vec<PluginInfo> {
+    plugins.list()
+}
+
+/// Load the plugins folder again, picking up new and edited scripts
+#[tauri::command]
+#[specta::specta]
+pub async fn reload_plugins(
+    app: AppHandle,
+    plugins: State<'_, Arc<PluginManager>>,
+) -> Result<Vec<PluginInfo>, String> {
+    let plugins = plugins.inner().clone();
+    let infos = tauri::async_runtime::spawn_blocking(move || plugins.reload())
+        .await
+        .map_err(|e| format!("Failed to reload plugins: {}", e))??;
+    shortcut::refresh_plugin_shortcuts(&app);
+    Ok(infos)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn change_plugin_settings(
+    app: AppHandle,
+    plugins: State<'_, Arc<PluginManager>>,
+    plugin_settings: PluginSettings,
+) -> Result<Vec<PluginInfo>, String> {
+    let mut settings = get_settings(&app);
+    settings.plugins = plugin_settings;
+    write_settings(&app, settings);
+    reload_plugins(app, plugins).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_plugin_action(
+    plugins: State<'_, Arc<PluginManager>>,
+    binding_id: String,
+) -> Result<(), String> {
+    let plugins = plugins.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || plugins.run_action(&binding_id))
+        .await
+        .map_err(|e| format!("Plugin action failed: {}", e))?
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn open_plugins_folder(
+    app: AppHandle,
+    plugins: State<'_, Arc<PluginManager>>,
+) -> Result<(), String> {
+    let dir = plugins.plugins_dir()?;
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create plugins folder: {}", e))?;
+    app.opener()
+        .open_path(dir.to_string_lossy().to_string(), None::<String>)
+        .map_err(|e| format!("Failed to open plugins folder: {}", e))
+}
@@ -1,6 +1,9 @@
 //! Tauri commands for RAG (Knowledge Base) functionality
 
-use crate::managers::rag::{DocMetadata, RagManager, SearchResult, StoredDocument};
+use crate::managers::indexing_queue::{IndexingQueue, IndexingStatus};
+use crate::managers::rag::{
+    DocMetadata, EmbeddingOptions, RagManager, SearchResult, StoredDocument,
+};
 use crate::settings::{get_settings, write_settings, KnowledgeBaseSettings};
 use std::sync::Arc;
 use tauri::{AppHandle, State};
@@ -9,12 +12,17 @@ use tauri::{AppHandle, State};
 #[tauri::command]
 #[specta::specta]
 pub async fn rag_add_document(
+    app: AppHandle,
     rag_manager: State<'_, Arc<RagManager>>,
     content: String,
     source_type: String,
     source_id: Option<String>,
     title: Option<String>,
 ) -> Result<i64, String> {
+    if crate::settings::is_guest_mode(&app) {
+        return Err("Documents can't be added to the knowledge base in guest mode".to_string());
+    }
+
     let metadata = DocMetadata {
         source_type,
         source_id,
@@ -192,3 +200,120 @@ pub fn change_kb_use_in_active_listening_setting(
     write_settings(&app, settings);
     Ok(())
 }
+
+/// Update the number of chunks sent per embed request
+#[tauri::command]
+#[specta::specta]
+pub fn change_kb_embedding_batch_size_setting(
+    app: AppHandle,
+    rag_manager: State<'_, Arc<RagManager>>,
+    batch_size: usize,
+) -> Result<(), String> {
+    if !(1..=256).contains(&batch_size) {
+        return Err("Embedding batch size must be between 1 and 256".to_string());
+    }
+    let mut settings = get_settings(&app);
+    settings.knowledge_base.embedding_batch_size = batch_size;
+    rag_manager.set_embedding_options(EmbeddingOptions::from(&settings.knowledge_base));
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Update the number of concurrent embed requests while indexing
+#[tauri::command]
+#[specta::specta]
+pub fn change_kb_embedding_concurrency_setting(
+    app: AppHandle,
+    rag_manager: State<'_, Arc<RagManager>>,
+    concurrency: usize,
+) -> Result<(), String> {
+    if !(1..=8).contains(&concurrency) {
+        return Err("Embedding concurrency must be between 1 and 8".to_string());
+    }
+    let mut settings = get_settings(&app);
+    settings.knowledge_base.embedding_concurrency = concurrency;
+    rag_manager.set_embedding_options(EmbeddingOptions::from(&settings.knowledge_base));
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Update the retry count for transient embed request failures
+#[tauri::command]
+#[specta::specta]
+pub fn change_kb_embedding_max_retries_setting(
+    app: AppHandle,
+    rag_manager: State<'_, Arc<RagManager>>,
+    max_retries: u32,
+) -> Result<(), String> {
+    if max_retries > 10 {
+        return Err("Embedding retries must be at most 10".to_string());
+    }
+    let mut settings = get_settings(&app);
+    settings.knowledge_base.embedding_max_retries = max_retries;
+    rag_manager.set_embedding_options(EmbeddingOptions::from(&settings.knowledge_base));
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Update the background indexing rate limit
+#[tauri::command]
+#[specta::specta]
+pub fn change_kb_indexing_max_per_minute_setting(
+    app: AppHandle,
+    max_per_minute: u32,
+) -> Result<(), String> {
+    if !(1..=60).contains(&max_per_minute) {
+        return Err("Indexing rate must be between 1 and 60 documents per minute".to_string());
+    }
+    let mut settings = get_settings(&app);
+    settings.knowledge_base.indexing_max_per_minute = max_per_minute;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Update whether background indexing waits for Active Listening sessions to end
+#[tauri::command]
+#[specta::specta]
+pub fn change_kb_pause_indexing_during_sessions_setting(
+    app: AppHandle,
+    pause: bool,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.knowledge_base.pause_indexing_during_sessions = pause;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Update the number of attempts before a queued document is marked as failed
+#[tauri::command]
+#[specta::specta]
+pub fn change_kb_indexing_max_attempts_setting(
+    app: AppHandle,
+    max_attempts: u32,
+) -> Result<(), String> {
+    if !(1..=20).contains(&max_attempts) {
+        return Err("Indexing attempts must be between 1 and 20".to_string());
+    }
+    let mut settings = get_settings(&app);
+    settings.knowledge_base.indexing_max_attempts = max_attempts;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Get the status of the background indexing queue
+#[tauri::command]
+#[specta::specta]
+pub fn get_indexing_status(
+    indexing_queue: State<'_, Arc<IndexingQueue>>,
+) -> Result<IndexingStatus, String> {
+    indexing_queue.status()
+}
+
+/// Re-queue documents that failed to index, returning how many were re-queued
+#[tauri::command]
+#[specta::specta]
+pub fn retry_failed_indexing(
+    indexing_queue: State<'_, Arc<IndexingQueue>>,
+) -> Result<usize, String> {
+    indexing_queue.retry_failed()
+}
@@ -0,0 +1,84 @@
+//! Tauri commands for the remote control companion
+
+use crate::managers::remote_control::{RemoteControl, RemotePairing};
+use crate::settings::{get_settings, write_settings};
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_control_enabled_setting(
+    app: AppHandle,
+    remote_control: State<'_, Arc<RemoteControl>>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.remote_control.enabled = enabled;
+    write_settings(&app, settings);
+    remote_control.apply_settings()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_control_port_setting(
+    app: AppHandle,
+    remote_control: State<'_, Arc<RemoteControl>>,
+    port: u16,
+) -> Result<(), String> {
+    if port < 1024 {
+        return Err("Port must be between 1024 and 65535".to_string());
+    }
+    let mut settings = get_settings(&app);
+    if settings.api_server.port == port {
+        return Err("The local HTTP API already uses this port".to_string());
+    }
+    settings.remote_control.port = port;
+    write_settings(&app, settings);
+    remote_control.apply_settings()
+}
+
+/// Turn the mDNS announcement on or off
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_control_advertise_setting(
+    app: AppHandle,
+    remote_control: State<'_, Arc<RemoteControl>>,
+    advertise: bool,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.remote_control.advertise = advertise;
+    write_settings(&app, settings);
+    remote_control.apply_settings()
+}
+
+/// Show a new pairing code for a device to enter
+#[tauri::command]
+#[specta::specta]
+pub fn start_remote_pairing(
+    remote_control: State<'_, Arc<RemoteControl>>,
+) -> Result<RemotePairing, String> {
+    remote_control.start_pairing()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn cancel_remote_pairing(remote_control: State<'_, Arc<RemoteControl>>) {
+    remote_control.cancel_pairing();
+}
+
+/// Forget a paired device, so its token stops working
+#[tauri::command]
+#[specta::specta]
+pub fn revoke_remote_device(app: AppHandle, id: String) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    let before = settings.remote_control.devices.len();
+    settings
+        .remote_control
+        .devices
+        .retain(|device| device.id != id);
+    if settings.remote_control.devices.len() == before {
+        return Err(format!("No paired device with id {}", id));
+    }
+    write_settings(&app, settings);
+    Ok(())
+}
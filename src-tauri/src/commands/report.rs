@@ -0,0 +1,127 @@
+//! Tauri commands for the local monthly usage report
+
+use crate::managers::history::HistoryManager;
+use crate::managers::rag::RagManager;
+use crate::monthly_report::{
+    render, summarize_dictation, summarize_llm_usage, summarize_models, MonthlyReport,
+    ReportFormat, ReportPeriod, StorageGrowth,
+};
+use crate::settings::{get_settings, APPLE_INTELLIGENCE_PROVIDER_ID};
+use chrono::Local;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+fn file_size(path: &Path) -> u64 {
+    fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
+
+/// Providers running on this machine don't incur per-token costs
+fn is_local_base_url(base_url: &str) -> bool {
+    let lower = base_url.to_lowercase();
+    ["://localhost", "://127.0.0.1", "://[::1]", "://0.0.0.0"]
+        .iter()
+        .any(|host| lower.contains(host))
+}
+
+/// Generate a usage report for a month and write it under the app data
+/// directory. Defaults to the current month. Returns the report's path.
+#[tauri::command]
+#[specta::specta]
+pub async fn generate_monthly_report(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    rag_manager: State<'_, Arc<RagManager>>,
+    year: Option<i32>,
+    month: Option<u32>,
+    format: ReportFormat,
+    price_per_million_tokens: Option<f64>,
+) -> Result<String, String> {
+    let period = match (year, month) {
+        (Some(year), Some(month)) => ReportPeriod::new(year, month)?,
+        (None, None) => ReportPeriod::current(),
+        _ => return Err("Both year and month must be given".to_string()),
+    };
+    if price_per_million_tokens.is_some_and(|p| !p.is_finite() || p < 0.0) {
+        return Err("Price per million tokens must be a non-negative number".to_string());
+    }
+
+    let (start, end) = period.bounds()?;
+    let (prev_start, prev_end) = period.previous().bounds()?;
+
+    let entries = history_manager
+        .get_usage_entries(start, end)
+        .map_err(|e| e.to_string())?;
+    let previous_entries = history_manager
+        .get_usage_entries(prev_start, prev_end)
+        .map_err(|e| e.to_string())?;
+    let ask_ai = history_manager
+        .get_ask_ai_usage(start, end)
+        .map_err(|e| e.to_string())?;
+    let storage_stats = history_manager
+        .get_storage_stats()
+        .map_err(|e| e.to_string())?;
+
+    let recording_bytes: Vec<u64> = entries
+        .iter()
+        .map(|e| history_manager.recording_bytes(&e.file_name))
+        .collect();
+    let previous_bytes: u64 = previous_entries
+        .iter()
+        .map(|e| history_manager.recording_bytes(&e.file_name))
+        .sum();
+
+    let settings = get_settings(&app);
+    let (provider_label, provider_is_local) = match settings.active_post_process_provider() {
+        Some(provider) => (
+            provider.label.clone(),
+            provider.id == APPLE_INTELLIGENCE_PROVIDER_ID || is_local_base_url(&provider.base_url),
+        ),
+        None => (settings.post_process_provider_id.clone(), false),
+    };
+
+    let documents_added = rag_manager
+        .list_documents()?
+        .iter()
+        .filter(|d| d.created_at >= start && d.created_at < end)
+        .count();
+
+    let app_data_dir = crate::portable::app_data_dir(&app)
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    let report = MonthlyReport {
+        period,
+        generated_at: Local::now().format("%Y-%m-%d %H:%M").to_string(),
+        dictation: summarize_dictation(&entries, &recording_bytes),
+        models: summarize_models(&entries),
+        llm: summarize_llm_usage(
+            &entries,
+            &ask_ai,
+            &provider_label,
+            provider_is_local,
+            price_per_million_tokens,
+        ),
+        storage: StorageGrowth {
+            recordings_added_bytes: recording_bytes.iter().sum(),
+            previous_month_added_bytes: previous_bytes,
+            total_recordings_bytes: storage_stats.total_bytes,
+            history_db_bytes: file_size(&app_data_dir.join("history.db")),
+            rag_db_bytes: file_size(&app_data_dir.join("rag.db")),
+            documents_added,
+        },
+    };
+
+    let reports_dir = app_data_dir.join("reports");
+    fs::create_dir_all(&reports_dir)
+        .map_err(|e| format!("Failed to create reports directory: {}", e))?;
+    let path = reports_dir.join(format!(
+        "dictum-report-{}.{}",
+        period.slug(),
+        format.file_extension()
+    ));
+    fs::write(&path, render(&report, format))
+        .map_err(|e| format!("Failed to write report: {}", e))?;
+
+    Ok(path.to_string_lossy().to_string())
+}
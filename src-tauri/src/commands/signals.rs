@@ -0,0 +1,33 @@
+//! Tauri commands for mapping Unix signals to shortcut actions
+
+use crate::actions::ACTION_MAP;
+use crate::settings::{get_settings, write_settings, UnixSignal};
+use tauri::AppHandle;
+
+/// Binding ids a signal can be mapped to
+#[tauri::command]
+#[specta::specta]
+pub fn get_signal_binding_options() -> Vec<String> {
+    let mut options: Vec<String> = ACTION_MAP.keys().cloned().collect();
+    options.sort();
+    options
+}
+
+/// Map `signal` to a binding id, or unmap it with `None`
+#[tauri::command]
+#[specta::specta]
+pub fn change_signal_binding_setting(
+    app: AppHandle,
+    signal: UnixSignal,
+    binding_id: Option<String>,
+) -> Result<(), String> {
+    if let Some(binding_id) = &binding_id {
+        if !ACTION_MAP.contains_key(binding_id) {
+            return Err(format!("Unknown action '{}'", binding_id));
+        }
+    }
+    let mut settings = get_settings(&app);
+    settings.signals.set_binding(signal, binding_id);
+    write_settings(&app, settings);
+    Ok(())
+}
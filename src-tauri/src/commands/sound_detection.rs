@@ -25,7 +25,7 @@ pub fn change_sound_detection_enabled(
     let mut settings = get_settings(&app);
     settings.sound_detection.enabled = enabled;
     if let Ok(mut det) = detector.lock() {
-        det.update_settings(&settings.sound_detection);
+        settings.sound_detection.apply_to(&mut det);
     }
     write_settings(&app, settings);
     Ok(())
@@ -45,7 +45,7 @@ pub fn change_sound_detection_threshold(
     let mut settings = get_settings(&app);
     settings.sound_detection.threshold = threshold;
     if let Ok(mut det) = detector.lock() {
-        det.update_settings(&settings.sound_detection);
+        settings.sound_detection.apply_to(&mut det);
     }
     write_settings(&app, settings);
     Ok(())
@@ -62,7 +62,7 @@ pub fn change_sound_detection_categories(
     let mut settings = get_settings(&app);
     settings.sound_detection.categories = categories;
     if let Ok(mut det) = detector.lock() {
-        det.update_settings(&settings.sound_detection);
+        settings.sound_detection.apply_to(&mut det);
     }
     write_settings(&app, settings);
     Ok(())
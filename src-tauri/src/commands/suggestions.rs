@@ -2,8 +2,10 @@
 //!
 //! Provides Tauri commands for managing quick responses and suggestions settings.
 
-use crate::managers::suggestion_engine::SuggestionEngine;
-use crate::settings::{get_settings, write_settings, QuickResponse, SuggestionsSettings};
+use crate::managers::suggestion_engine::{Suggestion, SuggestionEngine, SuggestionStats};
+use crate::settings::{
+    get_settings, write_settings, QuickResponse, SuggestionsSettings, WarningRoute, WarningSeverity,
+};
 use tauri::{AppHandle, Manager};
 
 /// Get the current suggestions settings
@@ -296,3 +298,183 @@ pub async fn change_display_duration(
 
     Ok(())
 }
+
+/// Update the similarity threshold for treating suggestions as duplicates
+#[tauri::command]
+#[specta::specta]
+pub async fn change_suggestion_dedup_threshold(
+    app: AppHandle,
+    threshold: f32,
+) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err("Similarity threshold must be between 0.0 and 1.0".to_string());
+    }
+
+    let mut settings = get_settings(&app);
+    settings.suggestions.dedup_similarity_threshold = threshold;
+    write_settings(&app, settings.clone());
+
+    // Update the suggestion engine if it exists
+    if let Some(engine) = app.try_state::<SuggestionEngine>() {
+        engine.update_settings(settings.suggestions).await;
+    }
+
+    Ok(())
+}
+
+/// Update how long shown suggestions are remembered for deduplication
+#[tauri::command]
+#[specta::specta]
+pub async fn change_suggestion_dedup_window(
+    app: AppHandle,
+    window_seconds: u32,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.suggestions.dedup_window_seconds = window_seconds;
+    write_settings(&app, settings.clone());
+
+    // Update the suggestion engine if it exists
+    if let Some(engine) = app.try_state::<SuggestionEngine>() {
+        engine.update_settings(settings.suggestions).await;
+    }
+
+    Ok(())
+}
+
+/// Update the cooldown between suggestions of the same category
+#[tauri::command]
+#[specta::specta]
+pub async fn change_suggestion_category_cooldown(
+    app: AppHandle,
+    cooldown_seconds: u32,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.suggestions.category_cooldown_seconds = cooldown_seconds;
+    write_settings(&app, settings.clone());
+
+    // Update the suggestion engine if it exists
+    if let Some(engine) = app.try_state::<SuggestionEngine>() {
+        engine.update_settings(settings.suggestions).await;
+    }
+
+    Ok(())
+}
+
+/// Update the maximum number of suggestions shown per minute
+#[tauri::command]
+#[specta::specta]
+pub async fn change_max_suggestions_per_minute(
+    app: AppHandle,
+    max_per_minute: u32,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.suggestions.max_suggestions_per_minute = max_per_minute;
+    write_settings(&app, settings.clone());
+
+    // Update the suggestion engine if it exists
+    if let Some(engine) = app.try_state::<SuggestionEngine>() {
+        engine.update_settings(settings.suggestions).await;
+    }
+
+    Ok(())
+}
+
+/// Enable or disable feedback-based suggestion ranking
+#[tauri::command]
+#[specta::specta]
+pub async fn change_suggestion_feedback_learning(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.suggestions.feedback_learning_enabled = enabled;
+    write_settings(&app, settings.clone());
+
+    // Update the suggestion engine if it exists
+    if let Some(engine) = app.try_state::<SuggestionEngine>() {
+        engine.update_settings(settings.suggestions).await;
+    }
+
+    Ok(())
+}
+
+/// Record that a suggestion was accepted (copied/used) or dismissed
+#[tauri::command]
+#[specta::specta]
+pub async fn record_suggestion_feedback(
+    app: AppHandle,
+    session_id: Option<String>,
+    suggestion: Suggestion,
+    accepted: bool,
+) -> Result<(), String> {
+    let engine = app
+        .try_state::<SuggestionEngine>()
+        .ok_or("Suggestion engine not available")?;
+    engine
+        .record_feedback(session_id.as_deref(), &suggestion, accepted)
+        .await
+}
+
+/// Get accept/reject statistics for suggestions
+#[tauri::command]
+#[specta::specta]
+pub async fn get_suggestion_stats(app: AppHandle) -> Result<SuggestionStats, String> {
+    let engine = app
+        .try_state::<SuggestionEngine>()
+        .ok_or("Suggestion engine not available")?;
+    engine.get_stats().await
+}
+
+/// Clear all suggestion feedback and reset ranking adjustments
+#[tauri::command]
+#[specta::specta]
+pub async fn reset_suggestion_feedback(app: AppHandle) -> Result<(), String> {
+    let engine = app
+        .try_state::<SuggestionEngine>()
+        .ok_or("Suggestion engine not available")?;
+    engine.reset_feedback().await
+}
+
+/// Update how warnings of a given severity are routed
+#[tauri::command]
+#[specta::specta]
+pub async fn change_warning_route(
+    app: AppHandle,
+    severity: WarningSeverity,
+    route: WarningRoute,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    *settings.suggestions.warning_routing.route_mut(severity) = route;
+    write_settings(&app, settings.clone());
+
+    // Update the suggestion engine if it exists
+    if let Some(engine) = app.try_state::<SuggestionEngine>() {
+        engine.update_settings(settings.suggestions).await;
+    }
+
+    Ok(())
+}
+
+/// Update the webhook URL that receives routed warnings
+#[tauri::command]
+#[specta::specta]
+pub async fn change_warning_webhook_url(app: AppHandle, url: Option<String>) -> Result<(), String> {
+    let url = url.map(|u| u.trim().to_string()).filter(|u| !u.is_empty());
+    if let Some(ref u) = url {
+        let lower = u.to_lowercase();
+        if !lower.starts_with("https://") && !lower.starts_with("http://") {
+            return Err("Webhook URL must start with http:// or https://".to_string());
+        }
+    }
+
+    let mut settings = get_settings(&app);
+    settings.suggestions.warning_webhook_url = url;
+    write_settings(&app, settings.clone());
+
+    // Update the suggestion engine if it exists
+    if let Some(engine) = app.try_state::<SuggestionEngine>() {
+        engine.update_settings(settings.suggestions).await;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+//! Tauri commands for folder-based sync
+
+use crate::managers::sync::{SyncManager, SyncReport, SyncStatus};
+use crate::settings::{get_settings, write_settings, SyncConflictPolicy, SyncSettings};
+use std::path::Path;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_sync_settings(app: AppHandle) -> Result<SyncSettings, String> {
+    Ok(get_settings(&app).sync)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_sync_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    if enabled && settings.sync.folder.is_none() {
+        return Err("Choose a sync folder before enabling sync".to_string());
+    }
+    settings.sync.enabled = enabled;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_sync_folder_setting(app: AppHandle, folder: Option<String>) -> Result<(), String> {
+    let folder = folder.filter(|f| !f.trim().is_empty());
+    if let Some(ref folder) = folder {
+        if !Path::new(folder).is_dir() {
+            return Err(format!("Sync folder does not exist: {}", folder));
+        }
+    }
+
+    let mut settings = get_settings(&app);
+    if folder.is_none() {
+        settings.sync.enabled = false;
+    }
+    settings.sync.folder = folder;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_sync_conflict_policy_setting(
+    app: AppHandle,
+    policy: SyncConflictPolicy,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.sync.conflict_policy = policy;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Sync immediately instead of waiting for the next change check
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_now(sync_manager: State<'_, Arc<SyncManager>>) -> Result<SyncReport, String> {
+    sync_manager.sync_now().await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_sync_status(sync_manager: State<'_, Arc<SyncManager>>) -> Result<SyncStatus, String> {
+    Ok(sync_manager.status())
+}
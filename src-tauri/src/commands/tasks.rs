@@ -1,6 +1,10 @@
+use crate::managers::active_listening::ActiveListeningSession;
 use crate::managers::history::HistoryManager;
-use crate::managers::task_extractor::{ActionItem, TaskExtractor};
+use crate::managers::task_extractor::{ActionItem, ActionItemMention, TaskExtractor};
+use crate::notifications::{notify, Notification};
+use crate::settings::{get_settings, NotificationFeature, NotificationSeverity};
 use std::sync::Arc;
+use tauri::AppHandle;
 
 #[tauri::command]
 #[specta::specta]
@@ -35,6 +39,23 @@ pub async fn extract_action_items(
         .insert_action_items(entry_id, &items)
         .map_err(|e| format!("Failed to store action items: {}", e))?;
 
+    for item in &stored_items {
+        let severity = if item.priority == "high" {
+            NotificationSeverity::Warning
+        } else {
+            NotificationSeverity::Info
+        };
+        notify(
+            &app,
+            Notification {
+                feature: NotificationFeature::ActionItems,
+                severity,
+                title: "New action item".to_string(),
+                body: item.task.clone(),
+            },
+        );
+    }
+
     Ok(stored_items)
 }
 
@@ -49,6 +70,23 @@ pub async fn get_action_items(
         .map_err(|e| format!("Failed to get action items: {}", e))
 }
 
+/// Link open action items to the parts of a session that discussed them.
+/// `mark_progress` defaults to the Active Listening setting.
+#[tauri::command]
+#[specta::specta]
+pub async fn link_action_item_mentions(
+    app: AppHandle,
+    session: ActiveListeningSession,
+    mark_progress: Option<bool>,
+) -> Result<Vec<ActionItemMention>, String> {
+    let mark_progress = mark_progress.unwrap_or_else(|| {
+        get_settings(&app)
+            .active_listening
+            .mark_action_item_progress
+    });
+    crate::managers::task_extractor::link_session_mentions(&app, &session, mark_progress).await
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn toggle_action_item(
@@ -97,6 +135,12 @@ pub async fn export_action_items(
                 if let Some(ref deadline) = item.deadline {
                     md.push_str(&format!(" [{}]", deadline));
                 }
+                if !item.mentions.is_empty() {
+                    md.push_str(&format!(" (discussed {}x)", item.mentions.len()));
+                }
+                if item.stale {
+                    md.push_str(" (stale)");
+                }
                 md.push_str(&format!(" ({})\n", item.priority));
             }
             Ok(md)
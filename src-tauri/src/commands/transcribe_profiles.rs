@@ -0,0 +1,56 @@
+//! Tauri commands for transcribe profiles
+
+use crate::settings::{get_settings, profile_binding_id, write_settings, TranscribeProfile};
+use crate::shortcut::unregister_shortcut;
+use std::collections::HashSet;
+use tauri::AppHandle;
+
+/// Replace the transcribe profiles. The shortcuts of removed profiles are
+/// unbound, and renamed profiles' shortcuts take the new name.
+#[tauri::command]
+#[specta::specta]
+pub fn set_transcribe_profiles(
+    app: AppHandle,
+    profiles: Vec<TranscribeProfile>,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    let mut ids = HashSet::new();
+    for profile in &profiles {
+        if profile.id.trim().is_empty() || profile.name.trim().is_empty() {
+            return Err("Transcribe profiles need an id and a name".to_string());
+        }
+        if !ids.insert(profile.id.as_str()) {
+            return Err(format!(
+                "Transcribe profile id '{}' is used twice",
+                profile.id
+            ));
+        }
+        if let Some(prompt_id) = &profile.post_process_prompt_id {
+            if !settings
+                .post_process_prompts
+                .iter()
+                .any(|p| &p.id == prompt_id)
+            {
+                return Err(format!("Prompt '{}' not found", prompt_id));
+            }
+        }
+    }
+
+    for removed in settings
+        .transcribe_profiles
+        .iter()
+        .filter(|old| !ids.contains(old.id.as_str()))
+    {
+        if let Some(binding) = settings.bindings.remove(&profile_binding_id(&removed.id)) {
+            let _ = unregister_shortcut(&app, binding);
+        }
+    }
+    for profile in &profiles {
+        if let Some(binding) = settings.bindings.get_mut(&profile_binding_id(&profile.id)) {
+            binding.name = profile.name.clone();
+        }
+    }
+    settings.transcribe_profiles = profiles;
+    write_settings(&app, settings);
+    Ok(())
+}
@@ -1,7 +1,13 @@
 use crate::managers::transcription::TranscriptionManager;
-use crate::settings::{get_settings, write_settings, ModelUnloadTimeout};
+use crate::settings::transcription::remote_stt_providers;
+use crate::settings::voice_commands::default_voice_commands;
+use crate::settings::{
+    get_settings, write_settings, IdentifierCase, ModelUnloadTimeout, RemoteSttProvider,
+    VoiceCommand, VoiceCommandAction, LOCAL_BACKEND,
+};
 use serde::Serialize;
 use specta::Type;
+use std::collections::HashSet;
 use tauri::{AppHandle, State};
 
 #[derive(Serialize, Type)]
@@ -38,3 +44,244 @@ pub fn unload_model_manually(
         .unload_model()
         .map_err(|e| format!("Failed to unload model: {}", e))
 }
+
+/// Remote speech-to-text services dictations can be sent to
+#[tauri::command]
+#[specta::specta]
+pub fn get_remote_stt_providers() -> Vec<RemoteSttProvider> {
+    remote_stt_providers()
+}
+
+fn validate_remote_stt_provider(provider_id: &str) -> Result<RemoteSttProvider, String> {
+    remote_stt_providers()
+        .into_iter()
+        .find(|provider| provider.id == provider_id)
+        .ok_or_else(|| format!("Transcription provider '{}' not found", provider_id))
+}
+
+/// Pick the transcription backend, `local` or a provider id, for one
+/// shortcut binding or, without a binding, for every shortcut. A binding
+/// without a backend uses the default again.
+#[tauri::command]
+#[specta::specta]
+pub fn set_transcription_backend(
+    app: AppHandle,
+    binding_id: Option<String>,
+    backend: Option<String>,
+) -> Result<(), String> {
+    if let Some(backend) = backend.as_deref().filter(|id| *id != LOCAL_BACKEND) {
+        validate_remote_stt_provider(backend)?;
+    }
+
+    let mut settings = get_settings(&app);
+    let backends = &mut settings.transcription_backend;
+    match (binding_id, backend) {
+        (Some(binding_id), Some(backend)) => {
+            if !settings.bindings.contains_key(&binding_id) {
+                return Err(format!("Shortcut binding '{}' not found", binding_id));
+            }
+            backends.binding_backends.insert(binding_id, backend);
+        }
+        (Some(binding_id), None) => {
+            backends.binding_backends.remove(&binding_id);
+        }
+        (None, backend) => {
+            backends.default_backend = backend.unwrap_or_else(|| LOCAL_BACKEND.to_string());
+        }
+    }
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_stt_api_key_setting(
+    app: AppHandle,
+    provider_id: String,
+    api_key: String,
+) -> Result<(), String> {
+    validate_remote_stt_provider(&provider_id)?;
+    let mut settings = get_settings(&app);
+    settings
+        .transcription_backend
+        .api_keys
+        .insert(provider_id, api_key.trim().to_string());
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_stt_model_setting(
+    app: AppHandle,
+    provider_id: String,
+    model: String,
+) -> Result<(), String> {
+    validate_remote_stt_provider(&provider_id)?;
+    let mut settings = get_settings(&app);
+    settings
+        .transcription_backend
+        .models
+        .insert(provider_id, model.trim().to_string());
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_stt_base_url_setting(
+    app: AppHandle,
+    provider_id: String,
+    base_url: String,
+) -> Result<(), String> {
+    let provider = validate_remote_stt_provider(&provider_id)?;
+    if !provider.allow_base_url_edit {
+        return Err(format!("{} doesn't allow changing its URL", provider.label));
+    }
+    let base_url = base_url.trim();
+    if !base_url.is_empty() && reqwest::Url::parse(base_url).is_err() {
+        return Err(format!("'{}' isn't a valid URL", base_url));
+    }
+    let mut settings = get_settings(&app);
+    settings
+        .transcription_backend
+        .base_urls
+        .insert(provider_id, base_url.to_string());
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_stt_fallback_setting(app: AppHandle, enabled: bool) {
+    let mut settings = get_settings(&app);
+    settings.transcription_backend.fallback_to_local = enabled;
+    write_settings(&app, settings);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_paragraphing_enabled_setting(app: AppHandle, enabled: bool) {
+    let mut settings = get_settings(&app);
+    settings.paragraphing.enabled = enabled;
+    write_settings(&app, settings);
+}
+
+/// Set the pauses, in milliseconds, that end a sentence and start a
+/// paragraph
+#[tauri::command]
+#[specta::specta]
+pub fn change_paragraphing_pauses_setting(
+    app: AppHandle,
+    sentence_pause_ms: u32,
+    paragraph_pause_ms: u32,
+) -> Result<(), String> {
+    if !(200..=10_000).contains(&sentence_pause_ms) || !(200..=10_000).contains(&paragraph_pause_ms)
+    {
+        return Err("Pauses must be between 200 ms and 10 seconds".to_string());
+    }
+    if paragraph_pause_ms <= sentence_pause_ms {
+        return Err("The paragraph pause must be longer than the sentence pause".to_string());
+    }
+    let mut settings = get_settings(&app);
+    settings.paragraphing.sentence_pause_ms = sentence_pause_ms;
+    settings.paragraphing.paragraph_pause_ms = paragraph_pause_ms;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_speaker_markers_setting(app: AppHandle, enabled: bool) {
+    let mut settings = get_settings(&app);
+    settings.paragraphing.speaker_markers = enabled;
+    write_settings(&app, settings);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_code_dictation_enabled_setting(app: AppHandle, enabled: bool) {
+    let mut settings = get_settings(&app);
+    settings.code_dictation.enabled = enabled;
+    write_settings(&app, settings);
+}
+
+/// Set the apps, matched by the start of their name, that count as code
+/// editors or terminals
+#[tauri::command]
+#[specta::specta]
+pub fn change_code_dictation_apps_setting(app: AppHandle, apps: Vec<String>) {
+    let mut settings = get_settings(&app);
+    settings.code_dictation.apps = apps
+        .into_iter()
+        .map(|name| name.trim().to_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect();
+    write_settings(&app, settings);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_code_dictation_identifier_case_setting(app: AppHandle, case: IdentifierCase) {
+    let mut settings = get_settings(&app);
+    settings.code_dictation.identifier_case = case;
+    write_settings(&app, settings);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_code_dictation_direct_paste_setting(app: AppHandle, enabled: bool) {
+    let mut settings = get_settings(&app);
+    settings.code_dictation.prefer_direct_paste = enabled;
+    write_settings(&app, settings);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_voice_commands_enabled_setting(app: AppHandle, enabled: bool) {
+    let mut settings = get_settings(&app);
+    settings.voice_commands.enabled = enabled;
+    write_settings(&app, settings);
+}
+
+/// Replace the voice command grammar. Phrases are compared ignoring case,
+/// so each may appear once.
+#[tauri::command]
+#[specta::specta]
+pub fn set_voice_commands(app: AppHandle, commands: Vec<VoiceCommand>) -> Result<(), String> {
+    let mut phrases = HashSet::new();
+    let mut cleaned = Vec::with_capacity(commands.len());
+    for command in commands {
+        let phrase = command
+            .phrase
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if phrase.is_empty() {
+            return Err("Voice commands need a phrase".to_string());
+        }
+        if !phrases.insert(phrase.to_lowercase()) {
+            return Err(format!("'{}' is used by more than one command", phrase));
+        }
+        if matches!(&command.action, VoiceCommandAction::Insert { text } if text.is_empty()) {
+            return Err(format!("'{}' has no text to insert", phrase));
+        }
+        cleaned.push(VoiceCommand {
+            phrase,
+            action: command.action,
+        });
+    }
+    let mut settings = get_settings(&app);
+    settings.voice_commands.commands = cleaned;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Restore the built-in voice commands
+#[tauri::command]
+#[specta::specta]
+pub fn reset_voice_commands(app: AppHandle) {
+    let mut settings = get_settings(&app);
+    settings.voice_commands.commands = default_voice_commands();
+    write_settings(&app, settings);
+}
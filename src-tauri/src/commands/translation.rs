@@ -0,0 +1,67 @@
+use crate::settings::{get_settings, write_settings, OLLAMA_TRANSLATION_PROVIDER};
+use tauri::AppHandle;
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_translation_enabled_setting(app: AppHandle, enabled: bool) {
+    let mut settings = get_settings(&app);
+    settings.translation.enabled = enabled;
+    write_settings(&app, settings);
+}
+
+/// Pick the language dictations are translated into for one shortcut
+/// binding or, without a binding, for every shortcut. A binding without a
+/// language uses the default again; without a default, only shortcuts with
+/// their own language are translated.
+#[tauri::command]
+#[specta::specta]
+pub fn set_translation_language(
+    app: AppHandle,
+    binding_id: Option<String>,
+    language: String,
+) -> Result<(), String> {
+    let language = language.trim().to_string();
+    let mut settings = get_settings(&app);
+    let translation = &mut settings.translation;
+    match binding_id {
+        Some(binding_id) => {
+            if !settings.bindings.contains_key(&binding_id) {
+                return Err(format!("Shortcut binding '{}' not found", binding_id));
+            }
+            if language.is_empty() {
+                translation.binding_languages.remove(&binding_id);
+            } else {
+                translation.binding_languages.insert(binding_id, language);
+            }
+        }
+        None => translation.target_language = language,
+    }
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Pick the post-process provider, or `ollama`, and the model dictations
+/// are translated with. Without a provider the selected post-process
+/// provider is used.
+#[tauri::command]
+#[specta::specta]
+pub fn change_translation_provider_setting(
+    app: AppHandle,
+    provider_id: Option<String>,
+    model: Option<String>,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    if let Some(provider_id) = provider_id.as_deref() {
+        if provider_id != OLLAMA_TRANSLATION_PROVIDER
+            && settings.post_process_provider(provider_id).is_none()
+        {
+            return Err(format!("Provider '{}' not found", provider_id));
+        }
+    }
+    settings.translation.provider_id = provider_id;
+    settings.translation.model = model
+        .map(|model| model.trim().to_string())
+        .filter(|model| !model.is_empty());
+    write_settings(&app, settings);
+    Ok(())
+}
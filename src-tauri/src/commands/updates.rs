@@ -0,0 +1,444 @@
+//! Tauri commands for the update flow
+//!
+//! Update checks run in the backend so the configured channel, skipped
+//! version, deferral and staged rollout bucket are all applied before the UI
+//! is told about an update. The last update found is kept so its changelog
+//! can be shown and it can be installed without checking again.
+
+use crate::settings::general::{GeneralSettings, UpdateChannel};
+use crate::settings::{get_settings, write_settings};
+use chrono::Utc;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::{AppHandle, State, Url};
+use tauri_plugin_updater::{Update, UpdaterExt};
+use tauri_specta::Event;
+
+const GITHUB_API: &str = "https://api.github.com";
+/// Manifest asset the release workflow attaches to every release
+const MANIFEST_ASSET: &str = "latest.json";
+
+/// Longest an update prompt can be deferred
+const MAX_DEFER_HOURS: u32 = 24 * 30;
+
+/// The update found by the last check, ready to install
+#[derive(Default)]
+pub struct PendingUpdate(pub tokio::sync::Mutex<Option<Update>>);
+
+/// An available update as shown in the UI
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub current_version: String,
+    pub channel: UpdateChannel,
+    /// Release date, if the manifest provides one
+    pub date: Option<String>,
+    /// Release notes from the update manifest
+    pub changelog: Option<String>,
+}
+
+/// Download progress emitted as `update-download-progress`
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct UpdateDownloadProgress {
+    pub downloaded: u64,
+    pub content_length: Option<u64>,
+}
+
+/// Why an available update is not being offered
+#[derive(Debug, PartialEq, Eq)]
+enum Withheld {
+    Skipped,
+    Deferred,
+    NotInRollout,
+}
+
+/// Stable endpoints configured for the updater plugin in `tauri.conf.json`
+fn configured_endpoints(app: &AppHandle) -> Result<Vec<Url>, String> {
+    app.config()
+        .plugins
+        .0
+        .get("updater")
+        .and_then(|updater| updater.get("endpoints"))
+        .and_then(|endpoints| endpoints.as_array())
+        .map(|endpoints| {
+            endpoints
+                .iter()
+                .filter_map(|e| e.as_str()?.parse().ok())
+                .collect::<Vec<Url>>()
+        })
+        .filter(|endpoints| !endpoints.is_empty())
+        .ok_or_else(|| "No update endpoints are configured".to_string())
+}
+
+/// `owner/repo` of a GitHub release download endpoint
+fn github_repo(endpoint: &Url) -> Option<String> {
+    if endpoint.host_str() != Some("github.com") {
+        return None;
+    }
+    let mut segments = endpoint.path_segments()?;
+    let (owner, repo) = (segments.next()?, segments.next()?);
+    (segments.next() == Some("releases")).then(|| format!("{}/{}", owner, repo))
+}
+
+/// Manifest of the newest release if it is a prerelease. Once a stable
+/// release is newer, beta installs follow the stable channel again.
+fn prerelease_manifest(releases: &serde_json::Value) -> Option<Url> {
+    let newest = releases
+        .as_array()?
+        .iter()
+        .find(|release| release["draft"].as_bool() != Some(true))?;
+    if newest["prerelease"].as_bool() != Some(true) {
+        return None;
+    }
+    newest["assets"]
+        .as_array()?
+        .iter()
+        .find(|asset| asset["name"] == MANIFEST_ASSET)?["browser_download_url"]
+        .as_str()?
+        .parse()
+        .ok()
+}
+
+async fn beta_endpoint(stable: &Url) -> Option<Url> {
+    let repo = github_repo(stable)?;
+    let releases: serde_json::Value = reqwest::Client::new()
+        .get(format!(
+            "{}/repos/{}/releases?per_page=10",
+            GITHUB_API, repo
+        ))
+        .header(
+            reqwest::header::USER_AGENT,
+            "Dictum/1.0 (+https://github.com/cjpais/Handy)",
+        )
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .ok()?
+        .json()
+        .await
+        .ok()?;
+    prerelease_manifest(&releases)
+}
+
+async fn channel_endpoints(app: &AppHandle, channel: UpdateChannel) -> Result<Vec<Url>, String> {
+    let stable = configured_endpoints(app)?;
+    if channel == UpdateChannel::Beta {
+        if let Some(beta) = beta_endpoint(&stable[0]).await {
+            return Ok(vec![beta]);
+        }
+        debug!("No prerelease is newer than the stable release, checking stable");
+    }
+    Ok(stable)
+}
+
+/// Percentage of installs a release is rolled out to, from the manifest's
+/// optional `rollout_percentage` field. Releases without one go to everyone.
+fn rollout_percentage(manifest: &serde_json::Value) -> u8 {
+    manifest
+        .get("rollout_percentage")
+        .and_then(|v| v.as_u64())
+        .map(|p| p.min(100) as u8)
+        .unwrap_or(100)
+}
+
+fn new_rollout_bucket() -> u8 {
+    (uuid::Uuid::new_v4().as_u128() % 100) as u8
+}
+
+/// Decide whether an update should be offered. Manual checks ignore the
+/// deferral and the rollout so users can always opt in early.
+fn withheld_reason(
+    version: &str,
+    rollout: u8,
+    general: &GeneralSettings,
+    bucket: u8,
+    manual: bool,
+    now: i64,
+) -> Option<Withheld> {
+    if general.skipped_update_version.as_deref() == Some(version) {
+        return Some(Withheld::Skipped);
+    }
+    if manual {
+        return None;
+    }
+    if general
+        .update_deferred_until
+        .is_some_and(|until| now < until)
+    {
+        return Some(Withheld::Deferred);
+    }
+    if bucket >= rollout {
+        return Some(Withheld::NotInRollout);
+    }
+    None
+}
+
+fn update_info(update: &Update, channel: UpdateChannel) -> UpdateInfo {
+    UpdateInfo {
+        version: update.version.clone(),
+        current_version: update.current_version.clone(),
+        channel,
+        date: update.date.map(|d| d.to_string()),
+        changelog: update.body.clone(),
+    }
+}
+
+/// Check the configured channel for an update the user should be offered
+#[tauri::command]
+#[specta::specta]
+pub async fn check_for_update(
+    app: AppHandle,
+    pending: State<'_, PendingUpdate>,
+    manual: bool,
+) -> Result<Option<UpdateInfo>, String> {
+    let mut settings = get_settings(&app);
+    if !settings.general.update_checks_enabled {
+        return Ok(None);
+    }
+
+    let bucket = match settings.general.update_rollout_bucket {
+        Some(bucket) => bucket,
+        None => {
+            let bucket = new_rollout_bucket();
+            settings.general.update_rollout_bucket = Some(bucket);
+            write_settings(&app, settings.clone());
+            bucket
+        }
+    };
+
+    let channel = settings.general.update_channel;
+    let endpoints = channel_endpoints(&app, channel).await?;
+    let update = app
+        .updater_builder()
+        .endpoints(endpoints)
+        .map_err(|e| e.to_string())?
+        .build()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| format!("Failed to check for updates: {}", e))?;
+
+    let mut pending = pending.0.lock().await;
+    *pending = None;
+
+    let Some(update) = update else {
+        return Ok(None);
+    };
+
+    let rollout = rollout_percentage(&update.raw_json);
+    if let Some(reason) = withheld_reason(
+        &update.version,
+        rollout,
+        &settings.general,
+        bucket,
+        manual,
+        Utc::now().timestamp(),
+    ) {
+        debug!(
+            "Update {} available but withheld: {:?}",
+            update.version, reason
+        );
+        return Ok(None);
+    }
+
+    let info = update_info(&update, channel);
+    *pending = Some(update);
+    Ok(Some(info))
+}
+
+/// Changelog of the update found by the last check, if any
+#[tauri::command]
+#[specta::specta]
+pub async fn get_pending_update_changelog(
+    app: AppHandle,
+    pending: State<'_, PendingUpdate>,
+) -> Result<Option<UpdateInfo>, String> {
+    let channel = get_settings(&app).general.update_channel;
+    Ok(pending
+        .0
+        .lock()
+        .await
+        .as_ref()
+        .map(|update| update_info(update, channel)))
+}
+
+/// Never offer this version again; newer versions are still offered
+#[tauri::command]
+#[specta::specta]
+pub async fn skip_update_version(
+    app: AppHandle,
+    pending: State<'_, PendingUpdate>,
+    version: String,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.general.skipped_update_version = Some(version.clone());
+    write_settings(&app, settings);
+
+    let mut pending = pending.0.lock().await;
+    if pending.as_ref().is_some_and(|u| u.version == version) {
+        *pending = None;
+    }
+    info!("Skipping update {}", version);
+    Ok(())
+}
+
+/// Stop prompting for updates for the given number of hours
+#[tauri::command]
+#[specta::specta]
+pub fn defer_update(app: AppHandle, hours: u32) -> Result<(), String> {
+    if !(1..=MAX_DEFER_HOURS).contains(&hours) {
+        return Err(format!(
+            "Updates can be deferred for 1 to {} hours",
+            MAX_DEFER_HOURS
+        ));
+    }
+
+    let mut settings = get_settings(&app);
+    settings.general.update_deferred_until = Some(Utc::now().timestamp() + hours as i64 * 3600);
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Download and install the pending update, emitting progress as it downloads
+#[tauri::command]
+#[specta::specta]
+pub async fn install_pending_update(
+    app: AppHandle,
+    pending: State<'_, PendingUpdate>,
+) -> Result<(), String> {
+    let update = pending
+        .0
+        .lock()
+        .await
+        .take()
+        .ok_or_else(|| "No update is pending".to_string())?;
+
+    let mut downloaded: u64 = 0;
+    let progress_app = app.clone();
+    update
+        .download_and_install(
+            move |chunk_length, content_length| {
+                downloaded += chunk_length as u64;
+                let _ = UpdateDownloadProgress {
+                    downloaded,
+                    content_length,
+                }
+                .emit(&progress_app);
+            },
+            || debug!("Update download finished"),
+        )
+        .await
+        .map_err(|e| format!("Failed to install update: {}", e))?;
+
+    // A fresh install clears any earlier skip or deferral
+    let mut settings = get_settings(&app);
+    settings.general.skipped_update_version = None;
+    settings.general.update_deferred_until = None;
+    write_settings(&app, settings);
+
+    info!("Installed update {}", update.version);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_rollout_percentage_defaults_to_everyone() {
+        assert_eq!(rollout_percentage(&json!({ "version": "1.0.0" })), 100);
+        assert_eq!(rollout_percentage(&json!({ "rollout_percentage": 25 })), 25);
+        assert_eq!(
+            rollout_percentage(&json!({ "rollout_percentage": 400 })),
+            100
+        );
+        assert_eq!(
+            rollout_percentage(&json!({ "rollout_percentage": "x" })),
+            100
+        );
+    }
+
+    #[test]
+    fn test_skipped_version_is_withheld_even_when_manual() {
+        let general = GeneralSettings {
+            skipped_update_version: Some("1.2.0".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            withheld_reason("1.2.0", 100, &general, 0, true, 0),
+            Some(Withheld::Skipped)
+        );
+        assert_eq!(withheld_reason("1.3.0", 100, &general, 0, false, 0), None);
+    }
+
+    #[test]
+    fn test_deferral_only_applies_to_automatic_checks() {
+        let general = GeneralSettings {
+            update_deferred_until: Some(1_000),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            withheld_reason("1.2.0", 100, &general, 0, false, 500),
+            Some(Withheld::Deferred)
+        );
+        assert_eq!(withheld_reason("1.2.0", 100, &general, 0, true, 500), None);
+        assert_eq!(
+            withheld_reason("1.2.0", 100, &general, 0, false, 1_000),
+            None
+        );
+    }
+
+    #[test]
+    fn test_staged_rollout_uses_bucket() {
+        let general = GeneralSettings::default();
+
+        assert_eq!(withheld_reason("1.2.0", 25, &general, 24, false, 0), None);
+        assert_eq!(
+            withheld_reason("1.2.0", 25, &general, 25, false, 0),
+            Some(Withheld::NotInRollout)
+        );
+        assert_eq!(withheld_reason("1.2.0", 25, &general, 99, true, 0), None);
+        assert_eq!(
+            withheld_reason("1.2.0", 0, &general, 0, false, 0),
+            Some(Withheld::NotInRollout)
+        );
+    }
+
+    #[test]
+    fn test_github_repo_from_release_endpoint() {
+        let endpoint =
+            Url::parse("https://github.com/cjpais/Handy/releases/latest/download/latest.json")
+                .unwrap();
+        assert_eq!(github_repo(&endpoint).as_deref(), Some("cjpais/Handy"));
+
+        let elsewhere = Url::parse("https://example.com/cjpais/Handy/releases/latest.json");
+        assert_eq!(github_repo(&elsewhere.unwrap()), None);
+    }
+
+    #[test]
+    fn test_prerelease_manifest_only_when_newest() {
+        let asset = json!({
+            "name": "latest.json",
+            "browser_download_url": "https://github.com/o/r/releases/download/v2.0.0-beta.1/latest.json"
+        });
+        let beta = json!({ "draft": false, "prerelease": true, "assets": [asset] });
+        let stable = json!({ "draft": false, "prerelease": false, "assets": [] });
+        let draft = json!({ "draft": true, "prerelease": true, "assets": [] });
+
+        let manifest = prerelease_manifest(&json!([draft, beta.clone(), stable.clone()])).unwrap();
+        assert!(manifest.path().ends_with("v2.0.0-beta.1/latest.json"));
+        assert_eq!(prerelease_manifest(&json!([stable, beta])), None);
+        assert_eq!(prerelease_manifest(&json!([])), None);
+    }
+
+    #[test]
+    fn test_rollout_bucket_range() {
+        for _ in 0..100 {
+            assert!(new_rollout_bucket() < 100);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Tauri commands for the wake word
+
+use crate::audio_toolkit::wake_word::{EMBEDDING_MODEL, MELSPECTROGRAM_MODEL};
+use crate::managers::wake_word::WakeWordManager;
+use crate::settings::{get_settings, write_settings, WakeWordSettings};
+use std::path::Path;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+/// Replace the wake word settings and load or unload its model. A model
+/// that fails to load leaves the wake word off.
+#[tauri::command]
+#[specta::specta]
+pub fn set_wake_word_settings(
+    app: AppHandle,
+    manager: State<'_, Arc<WakeWordManager>>,
+    wake_word: WakeWordSettings,
+) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&wake_word.sensitivity) {
+        return Err("Sensitivity must be between 0.0 and 1.0".to_string());
+    }
+    if !(300..=10_000).contains(&wake_word.silence_timeout_ms) {
+        return Err("Silence timeout must be between 300 and 10000 ms".to_string());
+    }
+    if let Some(path) = &wake_word.model_path {
+        let path = Path::new(path);
+        if !path.is_file() {
+            return Err(format!("Wake word model not found: {}", path.display()));
+        }
+        let dir = path.parent().unwrap_or(Path::new("."));
+        for feature_model in [MELSPECTROGRAM_MODEL, EMBEDDING_MODEL] {
+            if !dir.join(feature_model).is_file() {
+                return Err(format!(
+                    "{} must be in the same folder as the wake word model",
+                    feature_model
+                ));
+            }
+        }
+    }
+
+    let mut settings = get_settings(&app);
+    if wake_word.enabled && !settings.always_on_microphone {
+        return Err("The wake word needs the always-on microphone".to_string());
+    }
+    settings.wake_word = wake_word;
+    write_settings(&app, settings);
+
+    if let Err(e) = manager.apply_settings() {
+        let mut settings = get_settings(&app);
+        settings.wake_word.enabled = false;
+        write_settings(&app, settings);
+        return Err(e);
+    }
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! Tauri commands for webhook deliveries
+
+use crate::managers::webhook::WebhookManager;
+use crate::settings::{get_settings, write_settings, WebhookEvent};
+use crate::warning_notifications::is_valid_webhook_url;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_webhook_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.webhooks.enabled = enabled;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_webhook_url_setting(app: AppHandle, url: Option<String>) -> Result<(), String> {
+    let url = url
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty());
+    if let Some(url) = &url {
+        if !is_valid_webhook_url(url) {
+            return Err("Only http(s) webhook URLs are supported".to_string());
+        }
+    }
+    let mut settings = get_settings(&app);
+    settings.webhooks.url = url;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_webhook_auth_header_setting(
+    app: AppHandle,
+    auth_header: Option<String>,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.webhooks.auth_header = auth_header
+        .map(|auth| auth.trim().to_string())
+        .filter(|auth| !auth.is_empty());
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_webhook_events_setting(
+    app: AppHandle,
+    events: Vec<WebhookEvent>,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.webhooks.events = events
+        .into_iter()
+        .filter(|event| *event != WebhookEvent::Test)
+        .collect();
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_webhook_max_retries_setting(app: AppHandle, retries: u32) -> Result<(), String> {
+    if retries > 10 {
+        return Err("Webhook retries must be between 0 and 10".to_string());
+    }
+    let mut settings = get_settings(&app);
+    settings.webhooks.max_retries = retries;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Send a test payload to the configured URL and return the response status
+#[tauri::command]
+#[specta::specta]
+pub async fn test_webhook(webhook_manager: State<'_, Arc<WebhookManager>>) -> Result<u16, String> {
+    webhook_manager.send_test().await
+}
@@ -0,0 +1,277 @@
+//! Event trail
+//!
+//! While debug mode is on, keeps the most recent app events and manager
+//! state transitions in a ring buffer. Reports like "the overlay got stuck"
+//! can then be traced back to the event that was missing or arrived out of
+//! order, without asking the user to reproduce with trace logging.
+//!
+//! Events are picked up through listeners, so emitters don't need to know
+//! about the trail. High-frequency events (levels, progress) are left out
+//! so they don't push everything else out of the buffer.
+
+use crate::events::*;
+use crate::settings::get_settings;
+use serde::Serialize;
+use specta::Type;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Listener, Manager};
+use tauri_specta::Event;
+
+/// Entries kept before the oldest is dropped
+pub const TRAIL_CAPACITY: usize = 500;
+
+/// Longest payload summary kept per event, in characters
+const PAYLOAD_SUMMARY_CHARS: usize = 200;
+
+/// Events recorded in the trail
+const AUDITED_EVENTS: &[&str] = &[
+    AccessPolicyViolation::NAME,
+    ActiveListeningBudgetExceeded::NAME,
+    ActiveListeningHighlight::NAME,
+    ActiveListeningInsight::NAME,
+    ActiveListeningSegment::NAME,
+    ActiveListeningStateChanged::NAME,
+    AskAiResponse::NAME,
+    AskAiStateChanged::NAME,
+    AskAiToolCall::NAME,
+    BackupCompleted::NAME,
+    BatchComplete::NAME,
+    BatchItemStatus::NAME,
+    CheckForUpdates::NAME,
+    ClipboardAudioCopied::NAME,
+    DockStateChanged::NAME,
+    FormattingModeChanged::NAME,
+    HideOverlay::NAME,
+    HistoryUpdated::NAME,
+    IndexingStatusChanged::NAME,
+    MicrophoneMuteChanged::NAME,
+    ModelDownloadComplete::NAME,
+    ModelExtractionCompleted::NAME,
+    ModelExtractionFailed::NAME,
+    ModelExtractionStarted::NAME,
+    ModelStateChanged::NAME,
+    PermissionLost::NAME,
+    PluginEmitted::NAME,
+    PromptRouted::NAME,
+    RecordingLimitWarning::NAME,
+    RemoteDevicePaired::NAME,
+    SettingsChanged::NAME,
+    SettingsConfigApplied::NAME,
+    SettingsSynced::NAME,
+    ShowOverlay::NAME,
+    Suggestions::NAME,
+    TranscriptionFinalized::NAME,
+    VadTestStopped::NAME,
+];
+
+/// High-frequency events left out of the trail
+const UNAUDITED_EVENTS: &[&str] = &[
+    HandyKeysEvent::NAME,
+    LogEntries::NAME,
+    MicLevel::NAME,
+    ModelDownloadProgress::NAME,
+    RagIndexingProgress::NAME,
+    RecordingProgress::NAME,
+    SessionReplayProgress::NAME,
+    SuggestionPartial::NAME,
+    TranscriptionPartial::NAME,
+    TranscriptionProgress::NAME,
+    UpdateDownloadProgress::NAME,
+    VadTestPoint::NAME,
+];
+
+/// Events after which debug mode may have been switched
+const SETTINGS_EVENTS: &[&str] = &[
+    SettingsChanged::NAME,
+    SettingsConfigApplied::NAME,
+    SettingsSynced::NAME,
+];
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum TrailKind {
+    Event,
+    Transition,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Type)]
+pub struct TrailEntry {
+    /// Unix timestamp in milliseconds
+    pub timestamp_ms: i64,
+    pub kind: TrailKind,
+    /// Event name, or the manager whose state changed
+    pub name: String,
+    /// Payload summary, or `from -> to` for transitions
+    pub detail: String,
+}
+
+pub struct EventTrail {
+    enabled: AtomicBool,
+    entries: Mutex<VecDeque<TrailEntry>>,
+}
+
+/// Shorten a payload to [`PAYLOAD_SUMMARY_CHARS`]
+fn summarize(payload: &str) -> String {
+    match payload.char_indices().nth(PAYLOAD_SUMMARY_CHARS) {
+        Some((end, _)) => format!("{}…", &payload[..end]),
+        None => payload.to_string(),
+    }
+}
+
+impl EventTrail {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled: AtomicBool::new(enabled),
+            entries: Mutex::new(VecDeque::with_capacity(TRAIL_CAPACITY)),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Turn recording on or off. The trail is cleared when turned off.
+    pub fn set_enabled(&self, enabled: bool) {
+        if !self.enabled.swap(enabled, Ordering::Relaxed) || enabled {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+
+    fn push(&self, kind: TrailKind, name: &str, detail: String) {
+        if !self.is_enabled() {
+            return;
+        }
+        let entry = TrailEntry {
+            timestamp_ms: chrono::Utc::now().timestamp_millis(),
+            kind,
+            name: name.to_string(),
+            detail,
+        };
+        if let Ok(mut entries) = self.entries.lock() {
+            if entries.len() == TRAIL_CAPACITY {
+                entries.pop_front();
+            }
+            entries.push_back(entry);
+        }
+    }
+
+    pub fn record_event(&self, name: &str, payload: &str) {
+        self.push(TrailKind::Event, name, summarize(payload));
+    }
+
+    pub fn record_transition(&self, manager: &str, from: &dyn Debug, to: &dyn Debug) {
+        self.push(
+            TrailKind::Transition,
+            manager,
+            format!("{:?} -> {:?}", from, to),
+        );
+    }
+
+    /// Recorded entries, oldest first
+    pub fn entries(&self) -> Vec<TrailEntry> {
+        self.entries
+            .lock()
+            .map(|entries| entries.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Manage the trail and start listening for events
+pub fn init(app: &AppHandle) {
+    app.manage(EventTrail::new(get_settings(app).debug_mode));
+
+    for &name in AUDITED_EVENTS {
+        let app_handle = app.clone();
+        app.listen_any(name, move |event| {
+            let trail = app_handle.state::<EventTrail>();
+            trail.record_event(name, event.payload());
+            if SETTINGS_EVENTS.contains(&name) {
+                trail.set_enabled(get_settings(&app_handle).debug_mode);
+            }
+        });
+    }
+}
+
+/// Record a manager state change, if debug mode is on
+pub fn record_transition(app: &AppHandle, manager: &str, from: &dyn Debug, to: &dyn Debug) {
+    if let Some(trail) = app.try_state::<EventTrail>() {
+        trail.record_transition(manager, from, to);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trail_keeps_latest_entries_while_enabled() {
+        let trail = EventTrail::new(false);
+        trail.record_event("show-overlay", "\"recording\"");
+        assert!(trail.entries().is_empty());
+
+        trail.set_enabled(true);
+        for i in 0..TRAIL_CAPACITY + 5 {
+            trail.record_event("history-updated", &i.to_string());
+        }
+        trail.record_transition("audio", &"Idle", &"Recording");
+
+        let entries = trail.entries();
+        assert_eq!(entries.len(), TRAIL_CAPACITY);
+        assert_eq!(entries[0].detail, "6");
+        let last = entries.last().unwrap();
+        assert_eq!(last.kind, TrailKind::Transition);
+        assert_eq!(last.detail, "\"Idle\" -> \"Recording\"");
+
+        trail.set_enabled(false);
+        assert!(trail.entries().is_empty());
+    }
+
+    /// Kebab-case event name of an event type, as tauri-specta names it
+    fn event_name(type_name: &str) -> String {
+        let mut name = String::new();
+        for c in type_name.chars() {
+            if c.is_uppercase() && !name.is_empty() {
+                name.push('-');
+            }
+            name.extend(c.to_lowercase());
+        }
+        name
+    }
+
+    #[test]
+    fn test_every_registered_event_is_audited_or_left_out() {
+        let lib = include_str!("lib.rs");
+        let start = lib.find("collect_events![").unwrap() + "collect_events![".len();
+        let end = start + lib[start..].find(']').unwrap();
+        let registered: Vec<String> = lib[start..end]
+            .split(',')
+            .filter_map(|path| path.trim().strip_prefix("events::"))
+            .map(event_name)
+            .collect();
+
+        for name in &registered {
+            let audited = AUDITED_EVENTS.contains(&name.as_str());
+            let unaudited = UNAUDITED_EVENTS.contains(&name.as_str());
+            assert!(audited != unaudited, "{} must be in exactly one list", name);
+        }
+        assert_eq!(
+            registered.len(),
+            AUDITED_EVENTS.len() + UNAUDITED_EVENTS.len()
+        );
+    }
+
+    #[test]
+    fn test_payloads_are_summarized() {
+        assert_eq!(summarize("{\"a\":1}"), "{\"a\":1}");
+        let long = "é".repeat(PAYLOAD_SUMMARY_CHARS + 10);
+        let summary = summarize(&long);
+        assert_eq!(summary.chars().count(), PAYLOAD_SUMMARY_CHARS + 1);
+        assert!(summary.ends_with('…'));
+    }
+}
@@ -0,0 +1,273 @@
+//! Events
+//!
+//! Every event the backend emits, declared once so the names and payload
+//! types are exported to `bindings.ts` and can't drift from the frontend.
+//! Event names are the kebab-case struct names (`HistoryUpdated` is
+//! `history-updated`). Emit with `HistoryUpdated.emit(&app)`, which needs
+//! [`tauri_specta::Event`] in scope.
+
+use crate::access_policy::AccessViolation;
+use crate::clipboard::ClipboardAudioEvent;
+use crate::log_viewer::LogEntry;
+use crate::managers::active_listening::{
+    ActiveListeningHighlightEvent, ActiveListeningInsightEvent, ActiveListeningSegmentEvent,
+    ActiveListeningStateEvent,
+};
+use crate::managers::ask_ai::{AskAiResponseEvent, AskAiStateEvent, AskAiToolCallEvent};
+use crate::managers::backup::BackupInfo;
+use crate::managers::batch_processor::{BatchProgressEvent, BatchQueueStatus};
+use crate::managers::indexing_queue::IndexingStatus;
+use crate::managers::model::DownloadProgress;
+use crate::managers::plugins::PluginMessage;
+use crate::managers::session_budget::ActiveListeningBudgetEvent;
+use crate::managers::session_replay::SessionReplayProgressEvent;
+use crate::managers::suggestion_engine::{SuggestionPartialEvent, SuggestionsEvent};
+use crate::managers::transcription::ModelStateEvent;
+use crate::permission_monitor::PermissionLostEvent;
+use crate::prompt_routing::PromptRoutedEvent;
+use crate::settings::general::UpdateChannel;
+use crate::settings::DockState;
+use serde::Serialize;
+use specta::Type;
+use tauri_specta::Event;
+
+pub use crate::commands::updates::UpdateDownloadProgress;
+pub use crate::managers::rag::RagIndexingProgress;
+pub use crate::overlay::{RecordingLimitWarning, RecordingProgress};
+pub use crate::vad_test::VadTestPoint;
+
+/// What the recording overlay shows
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, Type)]
+#[serde(rename_all = "kebab-case")]
+pub enum OverlayState {
+    Recording,
+    Transcribing,
+    AskAiRecording,
+    AskAiTranscribing,
+    AskAiGenerating,
+    ActiveListening,
+}
+
+/// New value of a setting changed from the backend
+#[derive(Serialize, Clone, Debug, PartialEq, Type)]
+#[serde(untagged)]
+pub enum SettingValue {
+    Bool(bool),
+    Number(f64),
+    UpdateChannel(UpdateChannel),
+}
+
+/* ---------- overlay and recording ------------------------------------- */
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ShowOverlay(pub OverlayState);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct HideOverlay;
+
+/// Spectrum levels of the microphone input
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct MicLevel(pub Vec<f32>);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct MicrophoneMuteChanged(pub bool);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct PermissionLost(pub PermissionLostEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct DockStateChanged(pub DockState);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct VadTestStopped;
+
+/// Keys pressed while a shortcut is being recorded with HandyKeys
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct HandyKeysEvent {
+    /// Currently pressed modifier keys
+    pub modifiers: Vec<String>,
+    /// The key that was pressed (if any)
+    pub key: Option<String>,
+    /// Whether this is a key down event
+    pub is_key_down: bool,
+    /// The full hotkey string (e.g., "option+space")
+    pub hotkey_string: String,
+}
+
+/* ---------- settings -------------------------------------------------- */
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct SettingsChanged {
+    pub setting: String,
+    pub value: SettingValue,
+}
+
+impl SettingsChanged {
+    pub fn new(setting: &str, value: SettingValue) -> Self {
+        Self {
+            setting: setting.to_string(),
+            value,
+        }
+    }
+}
+
+/// Keys changed by an edit to the config file
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct SettingsConfigApplied(pub Vec<String>);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct SettingsSynced;
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct CheckForUpdates;
+
+/* ---------- models ---------------------------------------------------- */
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ModelStateChanged(pub ModelStateEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ModelDownloadProgress(pub DownloadProgress);
+
+/// Id of the downloaded model
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ModelDownloadComplete(pub String);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ModelExtractionStarted(pub String);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ModelExtractionCompleted(pub String);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ModelExtractionFailed {
+    pub model_id: String,
+    pub error: String,
+}
+
+/* ---------- transcription --------------------------------------------- */
+
+/// A dictation finished transcribing. Part of the app's public interface
+/// for other tools (see [`crate::transcription_output`]): fields are only
+/// ever added, never renamed or removed.
+#[derive(Serialize, Clone, Debug, PartialEq, Type, Event)]
+pub struct TranscriptionFinalized {
+    /// Text as transcribed, before any post-processing
+    pub text: String,
+    /// Text after Chinese variant conversion or LLM post-processing, if
+    /// either ran. This is what was pasted.
+    pub post_processed_text: Option<String>,
+    /// Language that was spoken, as detected by the engine or service or as
+    /// set in the settings. `None` when auto-detected by an engine that
+    /// doesn't report it.
+    pub language: Option<String>,
+    /// Length of the recording in seconds
+    pub duration_seconds: f64,
+    /// Model confidence from 0 to 1, `None` when the engine doesn't report it
+    pub confidence: Option<f64>,
+}
+
+/// Text transcribed so far from the recording in progress. Each event
+/// replaces the previous one.
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct TranscriptionPartial(pub String);
+
+/// How far the transcription of a long recording, split into chunks, has
+/// got. Emitted after each chunk.
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct TranscriptionProgress {
+    /// Chunks transcribed so far
+    pub done: u32,
+    pub total: u32,
+    /// Share of the recording's audio transcribed, from 0 to 1
+    pub progress: f32,
+}
+
+/// A dictation started with a route phrase and is post-processed with the
+/// route's prompt. Emitted before post-processing starts.
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct PromptRouted(pub PromptRoutedEvent);
+
+/// A different formatting mode was picked, with its id, or `None` when
+/// formatting was turned off
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct FormattingModeChanged(pub Option<String>);
+
+/* ---------- history, backups and batches ------------------------------ */
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct HistoryUpdated;
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct BackupCompleted(pub BackupInfo);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct BatchItemStatus(pub BatchProgressEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct BatchComplete(pub BatchQueueStatus);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct IndexingStatusChanged(pub IndexingStatus);
+
+/// An audio file was copied while the clipboard is watched
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ClipboardAudioCopied(pub ClipboardAudioEvent);
+
+/* ---------- active listening, suggestions and Ask AI ------------------ */
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ActiveListeningStateChanged(pub ActiveListeningStateEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ActiveListeningSegment(pub ActiveListeningSegmentEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ActiveListeningInsight(pub ActiveListeningInsightEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ActiveListeningHighlight(pub ActiveListeningHighlightEvent);
+
+/// A limit of the session's LLM budget was reached
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct ActiveListeningBudgetExceeded(pub ActiveListeningBudgetEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct SessionReplayProgress(pub SessionReplayProgressEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct SuggestionPartial(pub SuggestionPartialEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct Suggestions(pub SuggestionsEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct AskAiStateChanged(pub AskAiStateEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct AskAiResponse(pub AskAiResponseEvent);
+
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct AskAiToolCall(pub AskAiToolCallEvent);
+
+/* ---------- plugins --------------------------------------------------- */
+
+/// A plugin script called `emit(name, payload)`
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct PluginEmitted(pub PluginMessage);
+
+/* ---------- remote control -------------------------------------------- */
+
+/// Name of a device that was just paired for remote control
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct RemoteDevicePaired(pub String);
+
+/* ---------- diagnostics ----------------------------------------------- */
+
+/// New lines of the log file while following it
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct LogEntries(pub Vec<LogEntry>);
+
+/// A host or path outside the access policy was accessed
+#[derive(Serialize, Clone, Debug, Type, Event)]
+pub struct AccessPolicyViolation(pub AccessViolation);
@@ -4,9 +4,13 @@ use specta::Type;
 use std::fmt;
 
 use crate::managers::history::HistoryEntry;
+use crate::managers::transcription::TranscriptSegment;
+
+pub mod templates;
+pub mod writers;
 
 /// Supported export formats for transcriptions
-#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
 pub enum ExportFormat {
     #[serde(rename = "txt")]
     Txt,
@@ -55,7 +59,7 @@ impl ExportFormat {
 }
 
 /// Format a unix timestamp (seconds) to a human-readable local datetime string
-fn format_timestamp(timestamp: i64) -> String {
+pub fn format_timestamp(timestamp: i64) -> String {
     DateTime::from_timestamp(timestamp, 0)
         .map(|dt| dt.with_timezone(&Local).format("%B %e, %Y %l:%M %p").to_string())
         .unwrap_or_else(|| format!("Unknown ({})", timestamp))
@@ -73,12 +77,26 @@ fn format_subtitle_time(seconds: f64, use_comma: bool) -> String {
     format!("{:02}:{:02}:{:02}{}{:03}", hours, minutes, secs, separator, ms)
 }
 
+/// "Name: " for entries heard from a known speaker
+fn speaker_prefix(entry: &HistoryEntry) -> String {
+    entry
+        .speaker_label
+        .as_deref()
+        .map(|label| format!("{}: ", label))
+        .unwrap_or_default()
+}
+
 /// Export a single history entry as plain text
 pub fn export_as_txt(entry: &HistoryEntry) -> String {
     let date = format_timestamp(entry.timestamp);
     let text = entry.post_processed_text.as_deref().unwrap_or(&entry.transcription_text);
 
-    format!("Transcription - {}\n\n{}\n", date, text)
+    format!(
+        "Transcription - {}\n\n{}{}\n",
+        date,
+        speaker_prefix(entry),
+        text
+    )
 }
 
 /// Export a single history entry as SRT subtitle format.
@@ -149,6 +167,7 @@ pub fn export_as_json(entry: &HistoryEntry) -> Result<String, String> {
         id: i64,
         timestamp: i64,
         date: String,
+        speaker: Option<String>,
         transcription: String,
         post_processed: Option<String>,
     }
@@ -157,6 +176,7 @@ pub fn export_as_json(entry: &HistoryEntry) -> Result<String, String> {
         id: entry.id,
         timestamp: entry.timestamp,
         date: format_timestamp(entry.timestamp),
+        speaker: entry.speaker_label.clone(),
         transcription: entry.transcription_text.clone(),
         post_processed: entry.post_processed_text.clone(),
     };
@@ -170,6 +190,9 @@ pub fn export_as_markdown(entry: &HistoryEntry) -> String {
     let text = entry.post_processed_text.as_deref().unwrap_or(&entry.transcription_text);
 
     let mut md = format!("# Transcription\n\n**Date:** {}\n\n", date);
+    if let Some(label) = &entry.speaker_label {
+        md.push_str(&format!("**Speaker:** {}\n\n", label));
+    }
 
     md.push_str("## Text\n\n");
     md.push_str(text);
@@ -195,6 +218,72 @@ pub fn export_entry(entry: &HistoryEntry, format: &ExportFormat) -> Result<Strin
     }
 }
 
+/// Words per cue when timed words are grouped into subtitles
+const WORDS_PER_CUE: usize = 10;
+
+/// Group timed words or segments into subtitle cues of about
+/// `WORDS_PER_CUE` words
+fn segment_cues(segments: &[TranscriptSegment]) -> Vec<(f64, f64, String)> {
+    let mut cues = Vec::new();
+    let mut current: Vec<&TranscriptSegment> = Vec::new();
+    let mut words = 0;
+
+    for segment in segments {
+        current.push(segment);
+        words += segment.text.split_whitespace().count();
+        if words >= WORDS_PER_CUE {
+            cues.push(cue_from(&current));
+            current.clear();
+            words = 0;
+        }
+    }
+    if !current.is_empty() {
+        cues.push(cue_from(&current));
+    }
+    cues
+}
+
+fn cue_from(segments: &[&TranscriptSegment]) -> (f64, f64, String) {
+    let start = segments.first().map_or(0.0, |s| s.start as f64);
+    let end = segments.last().map_or(0.0, |s| s.end as f64);
+    let text = segments
+        .iter()
+        .map(|s| s.text.trim())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (start, end, text)
+}
+
+/// Export timed segments as SRT subtitles with their real timings
+pub fn export_segments_as_srt(segments: &[TranscriptSegment]) -> String {
+    let mut srt = String::new();
+    for (i, (start, end, text)) in segment_cues(segments).into_iter().enumerate() {
+        srt.push_str(&format!(
+            "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            format_subtitle_time(start, true),
+            format_subtitle_time(end, true),
+            text
+        ));
+    }
+    srt
+}
+
+/// Export timed segments as WebVTT subtitles with their real timings
+pub fn export_segments_as_vtt(segments: &[TranscriptSegment]) -> String {
+    let mut vtt = String::from("WEBVTT\n\n");
+    for (start, end, text) in segment_cues(segments) {
+        vtt.push_str(&format!(
+            "{} --> {}\n{}\n\n",
+            format_subtitle_time(start, false),
+            format_subtitle_time(end, false),
+            text
+        ));
+    }
+    vtt
+}
+
 /// Export multiple history entries as a single text file
 pub fn export_entries_as_txt(entries: &[HistoryEntry]) -> String {
     entries
@@ -211,6 +300,7 @@ pub fn export_entries_as_json(entries: &[HistoryEntry]) -> Result<String, String
         id: i64,
         timestamp: i64,
         date: String,
+        speaker: Option<String>,
         transcription: String,
         post_processed: Option<String>,
     }
@@ -221,6 +311,7 @@ pub fn export_entries_as_json(entries: &[HistoryEntry]) -> Result<String, String
             id: e.id,
             timestamp: e.timestamp,
             date: format_timestamp(e.timestamp),
+            speaker: e.speaker_label.clone(),
             transcription: e.transcription_text.clone(),
             post_processed: e.post_processed_text.clone(),
         })
@@ -247,7 +338,13 @@ pub fn export_entries_as_markdown(entries: &[HistoryEntry]) -> String {
             .as_deref()
             .unwrap_or(&entry.transcription_text);
 
-        md.push_str(&format!("## {}\n\n{}\n\n---\n\n", date, text));
+        let speaker = entry
+            .speaker_label
+            .as_deref()
+            .map(|label| format!("**{}:** ", label))
+            .unwrap_or_default();
+
+        md.push_str(&format!("## {}\n\n{}{}\n\n---\n\n", date, speaker, text));
     }
 
     md
@@ -333,6 +430,11 @@ mod tests {
             transcription_text: "Hello world this is a test transcription with enough words to make multiple subtitle segments for testing purposes".to_string(),
             post_processed_text: None,
             post_process_prompt: None,
+            speaker_label: None,
+            rating: None,
+            correction: None,
+            duplicate_of: None,
+            formatting_mode: None,
         }
     }
 
@@ -346,6 +448,11 @@ mod tests {
             transcription_text: "hello world".to_string(),
             post_processed_text: Some("Hello, World!".to_string()),
             post_process_prompt: Some("Fix grammar".to_string()),
+            speaker_label: Some("Dana".to_string()),
+            rating: None,
+            correction: None,
+            duplicate_of: None,
+            formatting_mode: None,
         }
     }
 
@@ -361,7 +468,7 @@ mod tests {
     fn test_export_as_txt_uses_post_processed() {
         let entry = sample_entry_with_post_processed();
         let result = export_as_txt(&entry);
-        assert!(result.contains("Hello, World!"));
+        assert!(result.contains("Dana: Hello, World!"));
         assert!(!result.contains("hello world"));
     }
 
@@ -389,6 +496,20 @@ mod tests {
         assert!(result.contains("."));
     }
 
+    #[test]
+    fn test_export_segments_as_srt_groups_words() {
+        let segments: Vec<TranscriptSegment> = (0..12)
+            .map(|i| TranscriptSegment {
+                start: i as f32,
+                end: i as f32 + 0.5,
+                text: format!("w{}", i),
+            })
+            .collect();
+        let result = export_segments_as_srt(&segments);
+        assert!(result.starts_with("1\n00:00:00,000 --> 00:00:09,500\nw0 w1"));
+        assert!(result.contains("2\n00:00:10,000 --> 00:00:11,500\nw10 w11\n"));
+    }
+
     #[test]
     fn test_export_as_json() {
         let entry = sample_entry();
@@ -414,6 +535,7 @@ mod tests {
         assert!(result.contains("Hello, World!"));
         assert!(result.contains("## Original Transcription"));
         assert!(result.contains("hello world"));
+        assert!(result.contains("**Speaker:** Dana"));
     }
 
     #[test]
@@ -443,6 +565,8 @@ mod tests {
         let result = export_entries_as_json(&entries).unwrap();
         let parsed: Vec<serde_json::Value> = serde_json::from_str(&result).unwrap();
         assert_eq!(parsed.len(), 2);
+        assert!(parsed[0]["speaker"].is_null());
+        assert_eq!(parsed[1]["speaker"], "Dana");
     }
 
     #[test]
@@ -0,0 +1,308 @@
+//! Export templates
+//!
+//! Renders exportable objects with minijinja templates. Each kind of object
+//! has built-in templates; users can add their own from the settings, which
+//! are rendered the same way. Templates see the object under a name that
+//! depends on its kind (`entry` and `text`, `summary`, `items` or
+//! `conversation`) and `exported_at`, and can use the `datetime` (Unix
+//! seconds) and `offset` (milliseconds into a session) filters.
+
+use minijinja::{context, Environment};
+
+use super::format_timestamp;
+use crate::managers::active_listening::MeetingSummary;
+use crate::managers::ask_ai::AskAiConversation;
+use crate::managers::chapters::format_offset;
+use crate::managers::history::HistoryEntry;
+use crate::managers::task_extractor::ActionItem;
+use crate::settings::{ExportKind, ExportSettings, ExportTemplate};
+
+/// Prefix of the ids of built-in templates
+const BUILTIN_PREFIX: &str = "builtin_";
+
+const TRANSCRIPT_MARKDOWN: &str = "# {{ entry.title }}
+
+**Date:** {{ entry.timestamp | datetime }}
+
+{% if entry.speaker_label %}
+**Speaker:** {{ entry.speaker_label }}
+
+{% endif %}
+{{ text }}
+";
+
+const TRANSCRIPT_TEXT: &str = "{{ entry.timestamp | datetime }}
+{% if entry.speaker_label %}{{ entry.speaker_label }}: {% endif %}{{ text }}
+";
+
+const MEETING_SUMMARY_MARKDOWN: &str = "# Meeting Summary
+
+**Date:** {{ (summary.generated_at // 1000) | datetime }}
+
+**Duration:** {{ summary.duration_minutes }} minutes
+
+## Executive Summary
+
+{{ summary.executive_summary }}
+{% if summary.decisions %}
+
+## Key Decisions
+
+{% for decision in summary.decisions %}
+- {{ decision }}
+{% endfor %}
+{% endif %}
+{% if summary.action_items %}
+
+## Action Items
+
+{% for item in summary.action_items %}
+- [ ] {{ item.description }}{% if item.assignee %} (@{{ item.assignee }}){% endif %}{% if item.deadline %} [Due: {{ item.deadline }}]{% endif %}
+
+{% endfor %}
+{% endif %}
+{% if summary.highlights %}
+
+## Highlights
+
+{% for highlight in summary.highlights %}
+- **{{ highlight.offset_ms | offset }}** {{ highlight.transcript }}
+{% endfor %}
+{% endif %}
+";
+
+const ACTION_ITEMS_MARKDOWN: &str = "# Action Items
+
+{% for item in items %}
+- [{% if item.completed %}x{% else %} {% endif %}] {{ item.task }}{% if item.assignee %} (@{{ item.assignee }}){% endif %}{% if item.deadline %} [{{ item.deadline }}]{% endif %} ({{ item.priority }})
+{% endfor %}
+";
+
+const ASK_AI_MARKDOWN: &str = "# {{ conversation.title if conversation.title else \"Ask AI\" }}
+
+{% for turn in conversation.turns %}
+**Q:** {{ turn.question }}
+
+{{ turn.response }}
+
+{% endfor %}
+";
+
+/// Templates that ship with the app
+pub fn builtin_templates() -> Vec<ExportTemplate> {
+    let builtin = |id: &str, name: &str, kind, body: &str, file_extension: &str| ExportTemplate {
+        id: format!("{}{}", BUILTIN_PREFIX, id),
+        name: name.to_string(),
+        kind,
+        body: body.to_string(),
+        file_extension: file_extension.to_string(),
+    };
+    vec![
+        builtin(
+            "transcript_markdown",
+            "Markdown",
+            ExportKind::Transcript,
+            TRANSCRIPT_MARKDOWN,
+            "md",
+        ),
+        builtin(
+            "transcript_text",
+            "Plain text",
+            ExportKind::Transcript,
+            TRANSCRIPT_TEXT,
+            "txt",
+        ),
+        builtin(
+            "meeting_summary_markdown",
+            "Markdown",
+            ExportKind::MeetingSummary,
+            MEETING_SUMMARY_MARKDOWN,
+            "md",
+        ),
+        builtin(
+            "action_items_markdown",
+            "Markdown",
+            ExportKind::ActionItems,
+            ACTION_ITEMS_MARKDOWN,
+            "md",
+        ),
+        builtin(
+            "ask_ai_markdown",
+            "Markdown",
+            ExportKind::AskAiConversation,
+            ASK_AI_MARKDOWN,
+            "md",
+        ),
+    ]
+}
+
+pub fn is_builtin(id: &str) -> bool {
+    id.starts_with(BUILTIN_PREFIX)
+}
+
+/// Built-in templates followed by the user's, optionally only those for one
+/// kind of object
+pub fn list_templates(settings: &ExportSettings, kind: Option<ExportKind>) -> Vec<ExportTemplate> {
+    builtin_templates()
+        .into_iter()
+        .chain(settings.templates.iter().cloned())
+        .filter(|template| kind.is_none_or(|kind| template.kind == kind))
+        .collect()
+}
+
+pub fn find_template(settings: &ExportSettings, id: &str) -> Option<ExportTemplate> {
+    list_templates(settings, None)
+        .into_iter()
+        .find(|template| template.id == id)
+}
+
+/// An object loaded for export
+pub enum ExportData {
+    Transcript(HistoryEntry),
+    MeetingSummary(MeetingSummary),
+    ActionItems(Vec<ActionItem>),
+    AskAiConversation(AskAiConversation),
+}
+
+impl ExportData {
+    pub fn kind(&self) -> ExportKind {
+        match self {
+            ExportData::Transcript(_) => ExportKind::Transcript,
+            ExportData::MeetingSummary(_) => ExportKind::MeetingSummary,
+            ExportData::ActionItems(_) => ExportKind::ActionItems,
+            ExportData::AskAiConversation(_) => ExportKind::AskAiConversation,
+        }
+    }
+}
+
+fn environment() -> Environment<'static> {
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.set_keep_trailing_newline(true);
+    env.add_filter("datetime", format_timestamp);
+    env.add_filter("offset", format_offset);
+    env
+}
+
+/// Check that a template parses
+pub fn validate(body: &str) -> Result<(), String> {
+    environment()
+        .template_from_str(body)
+        .map(|_| ())
+        .map_err(|e| format!("Invalid template: {}", e))
+}
+
+/// Render an object with a template made for its kind
+pub fn render(template: &ExportTemplate, data: &ExportData) -> Result<String, String> {
+    if template.kind != data.kind() {
+        return Err(format!(
+            "Template '{}' can't export {:?}",
+            template.name,
+            data.kind()
+        ));
+    }
+
+    let exported_at = chrono::Utc::now().timestamp();
+    let ctx = match data {
+        ExportData::Transcript(entry) => context! {
+            entry => entry,
+            text => entry.post_processed_text.as_deref().unwrap_or(&entry.transcription_text),
+            exported_at => exported_at,
+        },
+        ExportData::MeetingSummary(summary) => context! {
+            summary => summary,
+            exported_at => exported_at,
+        },
+        ExportData::ActionItems(items) => context! {
+            items => items,
+            exported_at => exported_at,
+        },
+        ExportData::AskAiConversation(conversation) => context! {
+            conversation => conversation,
+            exported_at => exported_at,
+        },
+    };
+
+    environment()
+        .render_str(&template.body, ctx)
+        .map_err(|e| format!("Failed to render template '{}': {}", template.name, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry() -> HistoryEntry {
+        HistoryEntry {
+            id: 1,
+            file_name: "handy-1700000000.wav".to_string(),
+            timestamp: 1700000000,
+            saved: false,
+            title: "Standup".to_string(),
+            transcription_text: "hello world".to_string(),
+            post_processed_text: Some("Hello, world!".to_string()),
+            post_process_prompt: None,
+            speaker_label: Some("Dana".to_string()),
+            rating: None,
+            correction: None,
+            duplicate_of: None,
+            formatting_mode: None,
+        }
+    }
+
+    fn template(kind: ExportKind, body: &str) -> ExportTemplate {
+        ExportTemplate {
+            id: "custom".to_string(),
+            name: "Custom".to_string(),
+            kind,
+            body: body.to_string(),
+            file_extension: "txt".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_builtin_templates_parse_and_render() {
+        for template in builtin_templates() {
+            assert!(is_builtin(&template.id));
+            assert!(validate(&template.body).is_ok(), "{}", template.id);
+        }
+
+        let markdown =
+            find_template(&ExportSettings::default(), "builtin_transcript_markdown").unwrap();
+        let rendered = render(&markdown, &ExportData::Transcript(entry())).unwrap();
+        assert!(rendered.starts_with("# Standup\n"));
+        assert!(rendered.contains("**Speaker:** Dana\n"));
+        assert!(rendered.ends_with("Hello, world!\n"));
+    }
+
+    #[test]
+    fn test_user_templates_are_listed_by_kind() {
+        let settings = ExportSettings {
+            templates: vec![template(ExportKind::ActionItems, "{{ items | length }}")],
+            webhook_url: None,
+        };
+        let action_items = list_templates(&settings, Some(ExportKind::ActionItems));
+        assert_eq!(action_items.len(), 2);
+        assert_eq!(action_items[1].id, "custom");
+        assert_eq!(
+            render(&action_items[1], &ExportData::ActionItems(Vec::new())).unwrap(),
+            "0"
+        );
+    }
+
+    #[test]
+    fn test_render_rejects_mismatched_kind_and_bad_syntax() {
+        let data = ExportData::Transcript(entry());
+        assert!(render(&template(ExportKind::ActionItems, "{{ items }}"), &data).is_err());
+        assert!(validate("{% for item in items %}").is_err());
+        assert_eq!(
+            render(
+                &template(ExportKind::Transcript, "{{ text | upper }}"),
+                &data
+            )
+            .unwrap(),
+            "HELLO, WORLD!"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Export writers
+//!
+//! Deliver a rendered export to where the user asked for it: a file, the
+//! clipboard or a webhook.
+
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+use std::time::Duration;
+use tauri::AppHandle;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use crate::access_policy;
+use crate::settings::{get_settings, ExportKind};
+use crate::warning_notifications::is_valid_webhook_url;
+
+/// Timeout for webhook deliveries
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Where a rendered export goes
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExportDestination {
+    /// A file, usually picked in a save dialog
+    File {
+        path: String,
+    },
+    Clipboard,
+    /// POSTed as JSON; the webhook from the export settings when no URL is given
+    Webhook {
+        url: Option<String>,
+    },
+}
+
+/// An object rendered with a template, ready to be written
+#[derive(Clone, Debug, Serialize)]
+pub struct RenderedExport {
+    pub kind: ExportKind,
+    pub template_id: String,
+    pub file_extension: String,
+    pub content: String,
+}
+
+/// Write an export to its destination, returning where it went
+pub async fn write_export(
+    app: &AppHandle,
+    export: &RenderedExport,
+    destination: &ExportDestination,
+) -> Result<String, String> {
+    match destination {
+        ExportDestination::File { path } => write_file(path, export),
+        ExportDestination::Clipboard => {
+            app.clipboard()
+                .write_text(&export.content)
+                .map_err(|e| format!("Failed to write to clipboard: {}", e))?;
+            debug!("Copied {:?} export to the clipboard", export.kind);
+            Ok("clipboard".to_string())
+        }
+        ExportDestination::Webhook { url } => {
+            let url = match url {
+                Some(url) => url.trim().to_string(),
+                None => get_settings(app)
+                    .export
+                    .webhook_url
+                    .map(|url| url.trim().to_string())
+                    .ok_or("No export webhook URL is configured")?,
+            };
+            send_webhook(&url, export).await?;
+            Ok(url)
+        }
+    }
+}
+
+fn write_file(path: &str, export: &RenderedExport) -> Result<String, String> {
+    if path.trim().is_empty() {
+        return Err("No file path given for the export".to_string());
+    }
+    access_policy::check_path(Path::new(path))?;
+    std::fs::write(path, &export.content)
+        .map_err(|e| format!("Failed to write export to {}: {}", path, e))?;
+    info!("Exported {:?} to {}", export.kind, path);
+    Ok(path.to_string())
+}
+
+async fn send_webhook(url: &str, export: &RenderedExport) -> Result<(), String> {
+    if !is_valid_webhook_url(url) {
+        return Err("Only http(s) webhook URLs are supported".to_string());
+    }
+    access_policy::check_url(url)?;
+
+    let response = reqwest::Client::new()
+        .post(url)
+        .timeout(WEBHOOK_TIMEOUT)
+        .json(export)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to deliver export to webhook: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Export webhook returned {}", response.status()));
+    }
+    debug!("Delivered {:?} export to webhook", export.kind);
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Formatting modes
+//!
+//! Switching the mode dictations are formatted with, from the settings, the
+//! tray menu or the cycle shortcut. The mode itself is applied during
+//! post-processing, where its prompt replaces the selected post-process
+//! prompt.
+
+use crate::events::FormattingModeChanged;
+use crate::settings::{get_settings, write_settings};
+use crate::tray;
+use log::info;
+use tauri::AppHandle;
+use tauri_specta::Event;
+
+/// Tray menu items picking a mode have this id followed by the mode's id
+pub const TRAY_ITEM_PREFIX: &str = "formatting_mode:";
+
+/// Tray menu item turning formatting off
+pub const TRAY_ITEM_NONE: &str = "formatting_mode_none";
+
+/// Format dictations with the mode `mode_id`, or not at all for `None`
+pub fn select_mode(app: &AppHandle, mode_id: Option<String>) -> Result<(), String> {
+    let mut settings = get_settings(app);
+    if let Some(id) = &mode_id {
+        if !settings.formatting.modes.iter().any(|mode| &mode.id == id) {
+            return Err(format!("Formatting mode '{}' not found", id));
+        }
+    }
+    if settings.formatting.selected_mode == mode_id {
+        return Ok(());
+    }
+
+    info!("Formatting mode changed to {:?}", mode_id);
+    settings.formatting.selected_mode = mode_id.clone();
+    write_settings(app, settings);
+    tray::refresh_tray_menu(app);
+    let _ = FormattingModeChanged(mode_id).emit(app);
+    Ok(())
+}
+
+/// Switch to the next mode, or back to no formatting after the last one
+pub fn cycle_mode(app: &AppHandle) -> Result<(), String> {
+    let next = get_settings(app).formatting.next_mode();
+    select_mode(app, next)
+}
@@ -0,0 +1,101 @@
+//! Headless mode
+//!
+//! Started with `--headless` (or `DICTUM_HEADLESS=1`), the app runs only its
+//! backend: shortcuts, the tray icon, transcription and the SIGUSR2 toggle.
+//! No webview windows are created, so neither the settings window nor the
+//! recording overlay exists and everything is configured through the
+//! settings store.
+//!
+//! With `--json` (or `DICTUM_JSON_OUTPUT=1`) as well, every finished
+//! transcription is also written to stdout as one line of JSON, and logs go
+//! to stderr instead so stdout can be piped straight into another program.
+
+use std::sync::OnceLock;
+
+/// Command line flag that enables headless mode
+pub const HEADLESS_FLAG: &str = "--headless";
+
+/// Environment variable that enables headless mode
+pub const HEADLESS_ENV: &str = "DICTUM_HEADLESS";
+
+/// Command line flag that prints transcriptions as JSON lines
+pub const JSON_OUTPUT_FLAG: &str = "--json";
+
+/// Environment variable that prints transcriptions as JSON lines
+pub const JSON_OUTPUT_ENV: &str = "DICTUM_JSON_OUTPUT";
+
+static HEADLESS: OnceLock<bool> = OnceLock::new();
+static JSON_OUTPUT: OnceLock<bool> = OnceLock::new();
+
+fn option_requested<I, S>(flag: &str, args: I, env_value: Option<&str>) -> bool
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let env_enabled = env_value.is_some_and(|v| {
+        matches!(
+            v.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes" | "on"
+        )
+    });
+    env_enabled || args.into_iter().any(|arg| arg.as_ref() == flag)
+}
+
+fn headless_requested<I, S>(args: I, env_value: Option<&str>) -> bool
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    option_requested(HEADLESS_FLAG, args, env_value)
+}
+
+/// Whether the app was started without webview windows
+pub fn is_headless() -> bool {
+    *HEADLESS.get_or_init(|| {
+        let env_value = std::env::var(HEADLESS_ENV).ok();
+        headless_requested(std::env::args().skip(1), env_value.as_deref())
+    })
+}
+
+/// Whether finished transcriptions are printed to stdout. Only applies
+/// when headless.
+pub fn is_json_output() -> bool {
+    is_headless()
+        && *JSON_OUTPUT.get_or_init(|| {
+            let env_value = std::env::var(JSON_OUTPUT_ENV).ok();
+            option_requested(
+                JSON_OUTPUT_FLAG,
+                std::env::args().skip(1),
+                env_value.as_deref(),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag_enables_headless() {
+        assert!(headless_requested(["--headless"], None));
+        assert!(headless_requested(["--verbose", "--headless"], None));
+        assert!(!headless_requested(["--headless=false"], None));
+        assert!(!headless_requested(Vec::<String>::new(), None));
+    }
+
+    #[test]
+    fn test_env_enables_headless() {
+        assert!(headless_requested(Vec::<String>::new(), Some("1")));
+        assert!(headless_requested(Vec::<String>::new(), Some(" TRUE ")));
+        assert!(!headless_requested(Vec::<String>::new(), Some("0")));
+        assert!(!headless_requested(Vec::<String>::new(), Some("")));
+    }
+
+    #[test]
+    fn test_json_output_has_its_own_flag() {
+        let flag = JSON_OUTPUT_FLAG;
+        assert!(option_requested(flag, ["--headless", "--json"], None));
+        assert!(!option_requested(flag, ["--headless"], None));
+        assert!(option_requested(flag, ["--headless"], Some("on")));
+    }
+}
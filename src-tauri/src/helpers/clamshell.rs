@@ -45,16 +45,67 @@ pub fn is_laptop() -> Result<bool, String> {
     Ok(stdout.contains("InternalBattery"))
 }
 
-/// Stub implementation for non-macOS platforms
-/// Always returns false since clamshell mode is macOS-specific
-#[cfg(not(target_os = "macos"))]
+/// Checks if the laptop lid is closed, using the ACPI lid switch
+///
+/// Reads `/proc/acpi/button/lid/*/state`, which contains e.g.
+/// `state:      closed`. While the app is running with the lid closed, an
+/// external display is in use.
+#[cfg(target_os = "linux")]
+pub fn is_clamshell() -> Result<bool, String> {
+    let entries = match std::fs::read_dir("/proc/acpi/button/lid") {
+        Ok(entries) => entries,
+        // No lid switch, e.g. a desktop
+        Err(_) => return Ok(false),
+    };
+
+    Ok(entries.flatten().any(|entry| {
+        std::fs::read_to_string(entry.path().join("state"))
+            .map(|state| lid_is_closed(&state))
+            .unwrap_or(false)
+    }))
+}
+
+#[cfg(any(target_os = "linux", test))]
+fn lid_is_closed(state: &str) -> bool {
+    state
+        .split_whitespace()
+        .last()
+        .is_some_and(|value| value.eq_ignore_ascii_case("closed"))
+}
+
+/// Checks if the computer is a laptop by looking for a system battery
+#[cfg(target_os = "linux")]
+#[tauri::command]
+#[specta::specta]
+pub fn is_laptop() -> Result<bool, String> {
+    let entries = match std::fs::read_dir("/sys/class/power_supply") {
+        Ok(entries) => entries,
+        Err(_) => return Ok(false),
+    };
+
+    // Peripherals such as wireless mice report a battery with scope "Device"
+    Ok(entries.flatten().any(|entry| {
+        let path = entry.path();
+        let is_battery = std::fs::read_to_string(path.join("type"))
+            .map(|t| t.trim() == "Battery")
+            .unwrap_or(false);
+        let is_device = std::fs::read_to_string(path.join("scope"))
+            .map(|s| s.trim() == "Device")
+            .unwrap_or(false);
+        is_battery && !is_device
+    }))
+}
+
+/// Stub implementation for other platforms
+/// Always returns false since the lid state can't be read there
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
 pub fn is_clamshell() -> Result<bool, String> {
     Ok(false)
 }
 
-/// Stub implementation for non-macOS platforms
-/// Always returns false since laptop detection is macOS-specific
-#[cfg(not(target_os = "macos"))]
+/// Stub implementation for other platforms
+/// Always returns false since laptop detection isn't implemented there
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
 #[tauri::command]
 #[specta::specta]
 pub fn is_laptop() -> Result<bool, String> {
@@ -83,4 +134,11 @@ mod tests {
             println!("Is laptop: {}", is_laptop);
         }
     }
+
+    #[test]
+    fn test_lid_state_parsing() {
+        assert!(lid_is_closed("state:      closed\n"));
+        assert!(!lid_is_closed("state:      open\n"));
+        assert!(!lid_is_closed(""));
+    }
 }
@@ -0,0 +1,99 @@
+use std::path::Path;
+#[cfg(unix)]
+use std::process::Command;
+
+/// Returns the free space in bytes on the volume containing `path`
+///
+/// On Unix this asks `df` in POSIX output mode, which reports 1024-byte blocks.
+#[cfg(unix)]
+pub fn available_space(path: &Path) -> Result<u64, String> {
+    let output = Command::new("df")
+        .arg("-Pk")
+        .arg(path)
+        .output()
+        .map_err(|e| format!("Failed to execute df: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!("df command failed with status: {}", output.status));
+    }
+
+    parse_df_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Returns the free space in bytes on the volume containing `path`
+#[cfg(windows)]
+pub fn available_space(path: &Path) -> Result<u64, String> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows::core::PCWSTR;
+    use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let wide: Vec<u16> = path
+        .as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+    let mut available: u64 = 0;
+
+    unsafe {
+        GetDiskFreeSpaceExW(
+            PCWSTR(wide.as_ptr()),
+            Some(&mut available as *mut u64),
+            None,
+            None,
+        )
+        .map_err(|e| format!("Failed to query free disk space: {}", e))?;
+    }
+
+    Ok(available)
+}
+
+/// Parse the "Available" column of `df -P` output in 1024-byte blocks
+#[cfg(any(unix, test))]
+fn parse_df_output(stdout: &str) -> Result<u64, String> {
+    // Both the filesystem name and mount point may contain spaces, so anchor
+    // on the capacity column: ... Available Capacity% Mounted-on
+    let line = stdout
+        .lines()
+        .skip(1)
+        .find(|l| !l.trim().is_empty())
+        .ok_or_else(|| "Unexpected df output".to_string())?;
+    let columns: Vec<&str> = line.split_whitespace().collect();
+    let capacity_index = columns
+        .iter()
+        .rposition(|c| c.ends_with('%'))
+        .filter(|&i| i > 0)
+        .ok_or_else(|| format!("Unexpected df output: {}", line))?;
+
+    let available_kb: u64 = columns[capacity_index - 1]
+        .parse()
+        .map_err(|_| format!("Unexpected df output: {}", line))?;
+    Ok(available_kb * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_df_output() {
+        let output = "Filesystem     1024-blocks      Used Available Capacity Mounted on\n\
+                      /dev/disk3s5     482797652 301234567 150000000      67% /System/Volumes/Data\n";
+        assert_eq!(parse_df_output(output).unwrap(), 150_000_000 * 1024);
+
+        let spaced = "Filesystem 1024-blocks Used Available Capacity Mounted on\n\
+                      //nas/My Share 1000 400 600 40% /Volumes/My Share\n";
+        assert_eq!(parse_df_output(spaced).unwrap(), 600 * 1024);
+    }
+
+    #[test]
+    fn test_parse_df_output_rejects_garbage() {
+        assert!(parse_df_output("").is_err());
+        assert!(parse_df_output("Filesystem\nnonsense\n").is_err());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_available_space_for_temp_dir() {
+        assert!(available_space(&std::env::temp_dir()).is_ok());
+    }
+}
@@ -0,0 +1,89 @@
+//! Name of the application that has keyboard focus
+//!
+//! Used to tell where dictated text is about to be pasted. Names are
+//! lowercased, with any `.exe` suffix dropped.
+//!
+//! - macOS: the frontmost process, via AppleScript.
+//! - Windows: the image name of the foreground window's process.
+//! - Linux: the foreground window's process through `xdotool` (X11 only,
+//!   Wayland doesn't expose the focused window).
+
+/// The focused application's name, or `None` when it can't be determined
+pub fn focused_app_name() -> Option<String> {
+    let name = platform_focused_app()?;
+    let name = name.trim().to_lowercase();
+    let name = name.strip_suffix(".exe").unwrap_or(&name).to_string();
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(target_os = "macos")]
+fn platform_focused_app() -> Option<String> {
+    let output = std::process::Command::new("osascript")
+        .args([
+            "-e",
+            "tell application \"System Events\" to get name of first process whose frontmost is true",
+        ])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(target_os = "windows")]
+fn platform_focused_app() -> Option<String> {
+    use windows::core::PWSTR;
+    use windows::Win32::{
+        Foundation::CloseHandle,
+        System::Threading::{
+            OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+            PROCESS_QUERY_LIMITED_INFORMATION,
+        },
+        UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId},
+    };
+
+    unsafe {
+        let window = GetForegroundWindow();
+        let mut process_id = 0u32;
+        GetWindowThreadProcessId(window, Some(&mut process_id as *mut u32));
+        if process_id == 0 {
+            return None;
+        }
+
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id).ok()?;
+        let mut buffer = [0u16; 1024];
+        let mut len = buffer.len() as u32;
+        let result = QueryFullProcessImageNameW(
+            process,
+            PROCESS_NAME_WIN32,
+            PWSTR(buffer.as_mut_ptr()),
+            &mut len,
+        );
+        let _ = CloseHandle(process);
+        result.ok()?;
+
+        let path = String::from_utf16_lossy(&buffer[..len as usize]);
+        std::path::Path::new(&path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn platform_focused_app() -> Option<String> {
+    let output = std::process::Command::new("xdotool")
+        .args(["getactivewindow", "getwindowpid"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let pid = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    std::fs::read_to_string(format!("/proc/{}/comm", pid)).ok()
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn platform_focused_app() -> Option<String> {
+    None
+}
@@ -1 +1,4 @@
 pub mod clamshell;
+pub mod disk_space;
+pub mod focused_app;
+pub mod system_mute;
@@ -0,0 +1,219 @@
+//! System-wide mute for the default output and input devices
+//!
+//! Output muting backs the mute-while-recording option. Input muting backs
+//! the microphone mute toggle, which is useful during calls even when
+//! nothing is being transcribed.
+//!
+//! - Windows: the default endpoint's `IAudioEndpointVolume`.
+//! - Linux: PipeWire (`wpctl`), PulseAudio (`pactl`), then ALSA (`amixer`).
+//! - macOS: AppleScript. There is no input mute there, so the input volume
+//!   is set to zero and restored on unmute.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the microphone was last muted by us
+static MICROPHONE_MUTED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(target_os = "macos")]
+static SAVED_INPUT_VOLUME: std::sync::Mutex<Option<u32>> = std::sync::Mutex::new(None);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MuteTarget {
+    Output,
+    Input,
+}
+
+/// Mute or unmute the default output device. Fails silently when the
+/// platform tooling is unavailable.
+pub fn set_output_mute(mute: bool) {
+    let _ = set_mute(MuteTarget::Output, mute);
+}
+
+/// Mute or unmute the default microphone
+pub fn set_microphone_mute(mute: bool) -> Result<(), String> {
+    set_mute(MuteTarget::Input, mute)?;
+    MICROPHONE_MUTED.store(mute, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Whether the default microphone is muted. Uses the system state where it
+/// can be read, so mutes made elsewhere are picked up too.
+pub fn is_microphone_muted() -> bool {
+    is_muted(MuteTarget::Input).unwrap_or_else(|| MICROPHONE_MUTED.load(Ordering::SeqCst))
+}
+
+/// Whether the microphone was muted through this app
+pub fn is_microphone_muted_by_app() -> bool {
+    MICROPHONE_MUTED.load(Ordering::SeqCst)
+}
+
+/// Flip the microphone mute, returning the new state
+pub fn toggle_microphone_mute() -> Result<bool, String> {
+    let mute = !is_microphone_muted();
+    set_microphone_mute(mute)?;
+    Ok(mute)
+}
+
+#[cfg(target_os = "windows")]
+fn endpoint_volume(
+    target: MuteTarget,
+) -> windows::core::Result<windows::Win32::Media::Audio::Endpoints::IAudioEndpointVolume> {
+    use windows::Win32::{
+        Media::Audio::{
+            eCapture, eMultimedia, eRender, Endpoints::IAudioEndpointVolume, IMMDeviceEnumerator,
+            MMDeviceEnumerator,
+        },
+        System::Com::{CoCreateInstance, CoInitializeEx, CLSCTX_ALL, COINIT_MULTITHREADED},
+    };
+
+    let flow = match target {
+        MuteTarget::Output => eRender,
+        MuteTarget::Input => eCapture,
+    };
+
+    unsafe {
+        // Initialize the COM library for this thread.
+        // If already initialized (e.g., by another library like Tauri), this does nothing.
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+
+        let all_devices: IMMDeviceEnumerator =
+            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
+        let default_device = all_devices.GetDefaultAudioEndpoint(flow, eMultimedia)?;
+        default_device.Activate::<IAudioEndpointVolume>(CLSCTX_ALL, None)
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn set_mute(target: MuteTarget, mute: bool) -> Result<(), String> {
+    let volume = endpoint_volume(target).map_err(|e| e.to_string())?;
+    unsafe { volume.SetMute(mute, std::ptr::null()) }.map_err(|e| e.to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn is_muted(target: MuteTarget) -> Option<bool> {
+    let volume = endpoint_volume(target).ok()?;
+    unsafe { volume.GetMute() }
+        .ok()
+        .map(|muted| muted.as_bool())
+}
+
+#[cfg(target_os = "linux")]
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    std::process::Command::new(program)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+}
+
+#[cfg(target_os = "linux")]
+fn set_mute(target: MuteTarget, mute: bool) -> Result<(), String> {
+    let mute_val = if mute { "1" } else { "0" };
+    let (wpctl_node, pactl_cmd, pactl_node, amixer_control, amixer_state) = match target {
+        MuteTarget::Output => (
+            "@DEFAULT_AUDIO_SINK@",
+            "set-sink-mute",
+            "@DEFAULT_SINK@",
+            "Master",
+            if mute { "mute" } else { "unmute" },
+        ),
+        MuteTarget::Input => (
+            "@DEFAULT_AUDIO_SOURCE@",
+            "set-source-mute",
+            "@DEFAULT_SOURCE@",
+            "Capture",
+            if mute { "nocap" } else { "cap" },
+        ),
+    };
+
+    // Try multiple backends to increase compatibility
+    if run("wpctl", &["set-mute", wpctl_node, mute_val]).is_some()
+        || run("pactl", &[pactl_cmd, pactl_node, mute_val]).is_some()
+        || run("amixer", &["set", amixer_control, amixer_state]).is_some()
+    {
+        Ok(())
+    } else {
+        Err("No supported audio mixer (wpctl, pactl or amixer) found".to_string())
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn is_muted(target: MuteTarget) -> Option<bool> {
+    let (wpctl_node, pactl_cmd, pactl_node) = match target {
+        MuteTarget::Output => ("@DEFAULT_AUDIO_SINK@", "get-sink-mute", "@DEFAULT_SINK@"),
+        MuteTarget::Input => (
+            "@DEFAULT_AUDIO_SOURCE@",
+            "get-source-mute",
+            "@DEFAULT_SOURCE@",
+        ),
+    };
+
+    // "Volume: 0.40 [MUTED]"
+    if let Some(out) = run("wpctl", &["get-volume", wpctl_node]) {
+        return Some(out.contains("[MUTED]"));
+    }
+    // "Mute: yes"
+    run("pactl", &[pactl_cmd, pactl_node]).map(|out| out.contains("yes"))
+}
+
+#[cfg(target_os = "macos")]
+fn osascript(script: &str) -> Result<String, String> {
+    let output = std::process::Command::new("osascript")
+        .args(["-e", script])
+        .output()
+        .map_err(|e| format!("Failed to run osascript: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn input_volume() -> Option<u32> {
+    osascript("input volume of (get volume settings)")
+        .ok()?
+        .parse()
+        .ok()
+}
+
+#[cfg(target_os = "macos")]
+fn set_mute(target: MuteTarget, mute: bool) -> Result<(), String> {
+    match target {
+        MuteTarget::Output => osascript(&format!("set volume output muted {}", mute)).map(|_| ()),
+        MuteTarget::Input => {
+            let mut saved = SAVED_INPUT_VOLUME
+                .lock()
+                .map_err(|e| format!("Failed to lock saved input volume: {}", e))?;
+            let volume = if mute {
+                if let Some(current) = input_volume().filter(|v| *v > 0) {
+                    *saved = Some(current);
+                }
+                0
+            } else {
+                saved.take().unwrap_or(75)
+            };
+            osascript(&format!("set volume input volume {}", volume)).map(|_| ())
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn is_muted(target: MuteTarget) -> Option<bool> {
+    match target {
+        MuteTarget::Output => osascript("output muted of (get volume settings)")
+            .ok()
+            .map(|out| out == "true"),
+        MuteTarget::Input => input_volume().map(|volume| volume == 0),
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
+fn set_mute(_target: MuteTarget, _mute: bool) -> Result<(), String> {
+    Err("Muting is not supported on this platform".to_string())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
+fn is_muted(_target: MuteTarget) -> Option<bool> {
+    None
+}
@@ -1,41 +1,76 @@
+mod access_policy;
 mod actions;
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 mod apple_intelligence;
 mod audio_feedback;
 pub mod audio_toolkit;
+mod automation_hooks;
+mod browser_bridge;
 mod clipboard;
 mod commands;
 pub mod error;
+mod event_trail;
+mod events;
+pub mod export;
+mod formatting;
+mod headless;
 mod helpers;
 mod input;
 mod llm_client;
+mod log_levels;
+mod log_viewer;
 mod managers;
+mod monthly_report;
+mod notifications;
 mod ollama_client;
 mod overlay;
+mod palette;
+mod paragraphing;
+mod permission_monitor;
+mod portable;
+mod prompt_routing;
 mod settings;
 mod shortcut;
 mod signal_handle;
+mod speaker_markers;
+mod transcription_output;
+mod translation;
 mod tray;
 mod tray_i18n;
 mod utils;
+mod vad_test;
+mod voice_profile;
+mod warning_notifications;
 use specta_typescript::{BigIntExportBehavior, Typescript};
-use tauri_specta::{collect_commands, Builder};
+use tauri_specta::{collect_commands, collect_events, Builder, Event};
 
 use env_filter::Builder as EnvFilterBuilder;
 use managers::active_listening::ActiveListeningManager;
+use managers::active_listening_history::ActiveListeningHistoryManager;
+use managers::api_server::ApiServer;
 use managers::ask_ai::AskAiManager;
 use managers::ask_ai_history::AskAiHistoryManager;
 use managers::audio::AudioRecordingManager;
+use managers::backup::BackupManager;
 use managers::batch_processor::BatchProcessor;
+use managers::browser_bridge::BrowserBridge;
+use managers::docking::DockingManager;
 use managers::history::HistoryManager;
+use managers::indexing_queue::IndexingQueue;
 use managers::model::ModelManager;
-use managers::rag::RagManager;
+use managers::plugins::PluginManager;
+use managers::rag::{EmbeddingOptions, RagManager};
+use managers::remote_control::RemoteControl;
+use managers::scheduler::SchedulerManager;
 use managers::suggestion_engine::SuggestionEngine;
+use managers::sync::SyncManager;
 use managers::task_extractor::TaskExtractor;
 use managers::transcription::TranscriptionManager;
 use managers::vocabulary::VocabularyManager;
+use managers::wake_word::WakeWordManager;
+use managers::webhook::WebhookManager;
 #[cfg(unix)]
-use signal_hook::consts::SIGUSR2;
+use signal_hook::consts::{SIGUSR1, SIGUSR2};
 #[cfg(unix)]
 use signal_hook::iterator::Signals;
 use std::collections::HashMap;
@@ -44,11 +79,11 @@ use std::sync::{Arc, Mutex};
 use tauri::image::Image;
 
 use tauri::tray::TrayIconBuilder;
-use tauri::Emitter;
 use tauri::{AppHandle, Manager};
 use tauri_plugin_autostart::{MacosLauncher, ManagerExt};
 use tauri_plugin_log::{Builder as LogBuilder, RotationStrategy, Target, TargetKind};
 
+use crate::events::CheckForUpdates;
 use crate::settings::get_settings;
 
 // Global atomic to store the file log level filter
@@ -98,6 +133,10 @@ struct ShortcutToggleStates {
 type ManagedToggleState = Mutex<ShortcutToggleStates>;
 
 fn show_main_window(app: &AppHandle) {
+    if headless::is_headless() {
+        log::info!("Running headless, there is no main window to show");
+        return;
+    }
     if let Some(main_window) = app.get_webview_window("main") {
         // First, ensure the window is visible
         if let Err(e) = main_window.show() {
@@ -125,6 +164,11 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     // after onboarding completes. This avoids triggering permission dialogs
     // on macOS before the user is ready.
 
+    // Record events from the start so the trail covers manager startup
+    event_trail::init(app_handle);
+    access_policy::init(app_handle);
+    audio_feedback::preload(app_handle);
+
     // Initialize the managers
     let recording_manager = Arc::new(
         AudioRecordingManager::new(app_handle).expect("Failed to initialize recording manager"),
@@ -148,21 +192,31 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     let ask_ai_history_manager = Arc::new(
         AskAiHistoryManager::new(app_handle).expect("Failed to initialize ask ai history manager"),
     );
+    if let Err(e) = ask_ai_history_manager.delete_orphaned_audio() {
+        log::warn!("Failed to delete unsaved Ask AI audio: {}", e);
+    }
+    let active_listening_history_manager = Arc::new(
+        ActiveListeningHistoryManager::new(app_handle)
+            .expect("Failed to initialize active listening history manager"),
+    );
 
     // Initialize RAG manager with Ollama client
     let settings = settings::get_settings(app_handle);
     let ollama_base_url = settings.active_listening.ollama_base_url.clone();
-    let rag_db_path = app_handle
-        .path()
-        .app_data_dir()
+    let rag_db_path = crate::portable::app_data_dir(app_handle)
         .expect("Failed to get app data dir")
         .join("rag.db");
     let ollama_client = Arc::new(
         ollama_client::OllamaClient::new(&ollama_base_url)
             .expect("Failed to initialize Ollama client for RAG"),
     );
-    let rag_manager = Arc::new(
-        RagManager::new(rag_db_path, ollama_client.clone()).expect("Failed to initialize RAG manager"),
+    let mut rag_manager = RagManager::new(rag_db_path.clone(), ollama_client.clone())
+        .expect("Failed to initialize RAG manager");
+    rag_manager.set_app_handle(app_handle.clone());
+    rag_manager.set_embedding_options(EmbeddingOptions::from(&settings.knowledge_base));
+    let rag_manager = Arc::new(rag_manager);
+    let indexing_queue = Arc::new(
+        IndexingQueue::new(app_handle, rag_db_path).expect("Failed to initialize indexing queue"),
     );
 
     // Initialize the Suggestion Engine
@@ -176,19 +230,42 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     // Initialize Batch Processor
     let mut batch_processor = BatchProcessor::new();
     batch_processor.set_app_handle(app_handle.clone());
+    batch_processor.set_storage_path(
+        crate::portable::app_data_dir(app_handle)
+            .expect("Failed to get app data dir")
+            .join("batch_queue.json"),
+    );
+
+    // Initialize Backup Manager
+    let backup_manager =
+        Arc::new(BackupManager::new(app_handle).expect("Failed to initialize backup manager"));
+
+    // Initialize folder sync
+    let sync_manager =
+        Arc::new(SyncManager::new(app_handle).expect("Failed to initialize sync manager"));
+
+    // Initialize dock state detection
+    let docking_manager = Arc::new(DockingManager::new(app_handle));
+
+    // Initialize webhook deliveries
+    let webhook_manager = Arc::new(WebhookManager::new(app_handle));
 
     // Initialize Task Extractor
     let mut task_extractor = TaskExtractor::new();
     task_extractor.set_app_handle(app_handle.clone());
 
     // Initialize Vocabulary Manager
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
-        .expect("Failed to get app data dir");
+    let app_data_dir =
+        crate::portable::app_data_dir(app_handle).expect("Failed to get app data dir");
     let vocabulary_manager =
         VocabularyManager::new(&app_data_dir).expect("Failed to initialize vocabulary manager");
 
+    // Load plugin scripts
+    let plugin_manager = Arc::new(PluginManager::new(app_handle));
+    if let Err(e) = plugin_manager.reload() {
+        log::error!("Failed to load plugins: {}", e);
+    }
+
     // Add managers to Tauri's managed state
     app_handle.manage(recording_manager.clone());
     app_handle.manage(model_manager.clone());
@@ -197,32 +274,84 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     app_handle.manage(active_listening_manager.clone());
     app_handle.manage(ask_ai_manager.clone());
     app_handle.manage(ask_ai_history_manager.clone());
+    app_handle.manage(active_listening_history_manager.clone());
     app_handle.manage(rag_manager.clone());
+    app_handle.manage(indexing_queue.clone());
     app_handle.manage(suggestion_engine);
     app_handle.manage(tokio::sync::Mutex::new(batch_processor));
+    app_handle.manage(backup_manager.clone());
+    app_handle.manage(sync_manager.clone());
+    app_handle.manage(docking_manager.clone());
+    app_handle.manage(webhook_manager.clone());
     app_handle.manage(Mutex::new(task_extractor));
     app_handle.manage(Mutex::new(vocabulary_manager));
+    app_handle.manage(plugin_manager);
+
+    // Start background knowledge base indexing once all managers are available
+    indexing_queue.start();
+    backup_manager.start();
+    sync_manager.start();
+    docking_manager.start();
+    clipboard::start_clipboard_watcher(app_handle);
 
     // Initialize Sound Detector
     let mut sound_detector = audio_toolkit::SoundDetector::new();
     let sd_settings = settings::get_settings(app_handle);
-    sound_detector.update_settings(&sd_settings.sound_detection);
+    sd_settings.sound_detection.apply_to(&mut sound_detector);
     app_handle.manage(Mutex::new(sound_detector));
 
+    // Apply schedule rules once the sound detector they reconfigure exists
+    let scheduler = Arc::new(SchedulerManager::new(app_handle));
+    app_handle.manage(scheduler.clone());
+    scheduler.start();
+    app_handle.manage(notifications::NotificationCenter::default());
+
+    app_handle.manage(vad_test::VadTestState::default());
+    app_handle.manage(log_viewer::LogFollowState::default());
+
     // Initialize the shortcuts
     shortcut::init_shortcuts(app_handle);
+    shortcut::refresh_plugin_shortcuts(app_handle);
 
     #[cfg(unix)]
-    let signals = Signals::new(&[SIGUSR2]).unwrap();
-    // Set up SIGUSR2 signal handler for toggling transcription
+    let signals = Signals::new(&[SIGUSR1, SIGUSR2]).unwrap();
+    // Set up the SIGUSR1/SIGUSR2 handler for the actions mapped in settings
     #[cfg(unix)]
     signal_handle::setup_signal_handler(app_handle.clone(), signals);
 
-    // Apply macOS Accessory policy if starting hidden
+    // Start the local HTTP API if it's enabled
+    let api_server = Arc::new(ApiServer::new(app_handle));
+    if let Err(e) = api_server.apply_settings() {
+        log::error!("{}", e);
+    }
+    app_handle.manage(api_server);
+
+    // Start the remote control server if it's enabled
+    let remote_control = Arc::new(RemoteControl::new(app_handle));
+    if let Err(e) = remote_control.apply_settings() {
+        log::error!("{}", e);
+    }
+    app_handle.manage(remote_control);
+
+    // Start the browser extension bridge if it's enabled
+    let browser_bridge = Arc::new(BrowserBridge::new(app_handle));
+    if let Err(e) = browser_bridge.apply_settings() {
+        log::error!("{}", e);
+    }
+    app_handle.manage(browser_bridge);
+
+    // Load the wake word model if it's enabled
+    let wake_word = Arc::new(WakeWordManager::new(app_handle));
+    if let Err(e) = wake_word.apply_settings() {
+        log::error!("{}", e);
+    }
+    app_handle.manage(wake_word);
+
+    // Apply macOS Accessory policy if starting hidden or headless
     #[cfg(target_os = "macos")]
     {
         let settings = settings::get_settings(app_handle);
-        if settings.general.start_hidden {
+        if settings.general.start_hidden || headless::is_headless() {
             let _ = app_handle.set_activation_policy(tauri::ActivationPolicy::Accessory);
         }
     }
@@ -252,7 +381,23 @@ fn initialize_core_logic(app_handle: &AppHandle) {
                 let settings = settings::get_settings(app);
                 if settings.general.update_checks_enabled {
                     show_main_window(app);
-                    let _ = app.emit("check-for-updates", ());
+                    let _ = CheckForUpdates.emit(app);
+                }
+            }
+            "toggle_mic_mute" => {
+                if let Err(e) = utils::toggle_microphone_mute(app) {
+                    log::error!("Failed to toggle microphone mute: {}", e);
+                }
+            }
+            formatting::TRAY_ITEM_NONE => {
+                if let Err(e) = formatting::select_mode(app, None) {
+                    log::error!("Failed to turn formatting off: {}", e);
+                }
+            }
+            id if id.starts_with(formatting::TRAY_ITEM_PREFIX) => {
+                let mode_id = id[formatting::TRAY_ITEM_PREFIX.len()..].to_string();
+                if let Err(e) = formatting::select_mode(app, Some(mode_id)) {
+                    log::error!("Failed to select formatting mode: {}", e);
                 }
             }
             "cancel" => {
@@ -361,7 +506,9 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     }
 
     // Create the recording overlay window (hidden by default)
-    utils::create_recording_overlay(app_handle);
+    if !headless::is_headless() {
+        utils::create_recording_overlay(app_handle);
+    }
 }
 
 #[tauri::command]
@@ -371,195 +518,465 @@ fn trigger_update_check(app: AppHandle) -> Result<(), String> {
     if !settings.general.update_checks_enabled {
         return Ok(());
     }
-    app.emit("check-for-updates", ())
-        .map_err(|e| e.to_string())?;
+    CheckForUpdates.emit(&app).map_err(|e| e.to_string())?;
     Ok(())
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Launched by a browser as the companion extension's native messaging
+    // host, so relay messages instead of starting the app
+    if let Some(bridge_file) = browser_bridge::native_host_bridge_file() {
+        std::process::exit(browser_bridge::run_native_host(&bridge_file));
+    }
+
     // Parse console logging directives from RUST_LOG, falling back to info-level logging
     // when the variable is unset
     let console_filter = build_console_filter();
 
-    let specta_builder = Builder::<tauri::Wry>::new().commands(collect_commands![
-        shortcut::change_binding,
-        shortcut::reset_binding,
-        shortcut::change_ptt_setting,
-        shortcut::change_audio_feedback_setting,
-        shortcut::change_audio_feedback_volume_setting,
-        shortcut::change_sound_theme_setting,
-        shortcut::change_start_hidden_setting,
-        shortcut::change_autostart_setting,
-        shortcut::change_translate_to_english_setting,
-        shortcut::change_selected_language_setting,
-        shortcut::change_overlay_position_setting,
-        shortcut::change_debug_mode_setting,
-        shortcut::change_word_correction_threshold_setting,
-        shortcut::change_paste_method_setting,
-        shortcut::change_clipboard_handling_setting,
-        shortcut::change_post_process_enabled_setting,
-        shortcut::change_post_process_base_url_setting,
-        shortcut::change_post_process_api_key_setting,
-        shortcut::change_post_process_model_setting,
-        shortcut::set_post_process_provider,
-        shortcut::fetch_post_process_models,
-        shortcut::add_post_process_prompt,
-        shortcut::update_post_process_prompt,
-        shortcut::delete_post_process_prompt,
-        shortcut::set_post_process_selected_prompt,
-        shortcut::update_custom_words,
-        shortcut::suspend_binding,
-        shortcut::resume_binding,
-        shortcut::change_mute_while_recording_setting,
-        shortcut::change_append_trailing_space_setting,
-        shortcut::change_app_language_setting,
-        shortcut::change_update_checks_setting,
-        shortcut::change_private_overlay_setting,
-        trigger_update_check,
-        commands::cancel_operation,
-        commands::get_app_dir_path,
-        commands::get_app_settings,
-        commands::get_default_settings,
-        commands::get_log_dir_path,
-        commands::set_log_level,
-        commands::open_recordings_folder,
-        commands::open_log_dir,
-        commands::open_app_data_dir,
-        commands::check_apple_intelligence_available,
-        commands::initialize_enigo,
-        commands::models::get_available_models,
-        commands::models::get_model_info,
-        commands::models::download_model,
-        commands::models::delete_model,
-        commands::models::cancel_download,
-        commands::models::set_active_model,
-        commands::models::get_current_model,
-        commands::models::get_transcription_model_status,
-        commands::models::is_model_loading,
-        commands::models::has_any_models_available,
-        commands::models::has_any_models_or_downloads,
-        commands::models::get_recommended_first_model,
-        commands::audio::update_microphone_mode,
-        commands::audio::get_microphone_mode,
-        commands::audio::get_available_microphones,
-        commands::audio::set_selected_microphone,
-        commands::audio::get_selected_microphone,
-        commands::audio::get_available_output_devices,
-        commands::audio::set_selected_output_device,
-        commands::audio::get_selected_output_device,
-        commands::audio::play_test_sound,
-        commands::audio::check_custom_sounds,
-        commands::audio::set_clamshell_microphone,
-        commands::audio::get_clamshell_microphone,
-        commands::audio::is_recording,
-        commands::transcription::set_model_unload_timeout,
-        commands::transcription::get_model_load_status,
-        commands::transcription::unload_model_manually,
-        commands::history::get_history_entries,
-        commands::history::toggle_history_entry_saved,
-        commands::history::get_audio_file_path,
-        commands::history::delete_history_entry,
-        commands::history::update_history_limit,
-        commands::history::update_recording_retention_period,
-        commands::active_listening::start_active_listening_session,
-        commands::active_listening::stop_active_listening_session,
-        commands::active_listening::get_active_listening_state,
-        commands::active_listening::get_active_listening_session,
-        commands::active_listening::check_ollama_connection,
-        commands::active_listening::fetch_ollama_models,
-        commands::active_listening::change_active_listening_enabled_setting,
-        commands::active_listening::change_active_listening_segment_duration_setting,
-        commands::active_listening::change_ollama_base_url_setting,
-        commands::active_listening::change_ollama_model_setting,
-        commands::active_listening::change_active_listening_context_window_setting,
-        commands::active_listening::change_audio_source_type_setting,
-        commands::active_listening::change_audio_mix_ratio_setting,
-        commands::active_listening::get_audio_source_type,
-        commands::active_listening::get_audio_mix_ratio,
-        commands::active_listening::get_loopback_support_level,
-        commands::active_listening::is_loopback_supported,
-        commands::active_listening::list_loopback_devices,
-        commands::active_listening::add_active_listening_prompt,
-        commands::active_listening::update_active_listening_prompt,
-        commands::active_listening::delete_active_listening_prompt,
-        commands::active_listening::set_active_listening_selected_prompt,
-        commands::active_listening::generate_meeting_summary,
-        commands::active_listening::export_meeting_summary,
-        commands::ask_ai::get_ask_ai_state,
-        commands::ask_ai::is_ask_ai_active,
-        commands::ask_ai::get_ask_ai_question,
-        commands::ask_ai::get_ask_ai_response,
-        commands::ask_ai::get_ask_ai_conversation,
-        commands::ask_ai::can_start_ask_ai_recording,
-        commands::ask_ai::cancel_ask_ai_session,
-        commands::ask_ai::reset_ask_ai_session,
-        commands::ask_ai::dismiss_ask_ai_session,
-        commands::ask_ai::start_new_ask_ai_conversation,
-        commands::ask_ai::change_ask_ai_enabled_setting,
-        commands::ask_ai::change_ask_ai_ollama_base_url_setting,
-        commands::ask_ai::change_ask_ai_ollama_model_setting,
-        commands::ask_ai::change_ask_ai_system_prompt_setting,
-        commands::ask_ai::get_ask_ai_settings,
-        commands::ask_ai::save_ask_ai_window_bounds,
-        commands::ask_ai::get_ask_ai_window_bounds,
-        commands::ask_ai::save_ask_ai_conversation_to_history,
-        commands::ask_ai::list_ask_ai_conversations,
-        commands::ask_ai::get_ask_ai_conversation_from_history,
-        commands::ask_ai::delete_ask_ai_conversation_from_history,
-        commands::rag::rag_add_document,
-        commands::rag::rag_search,
-        commands::rag::rag_delete_document,
-        commands::rag::rag_list_documents,
-        commands::rag::rag_get_stats,
-        commands::rag::rag_get_embedding_model,
-        commands::rag::rag_set_embedding_model,
-        commands::rag::rag_clear_all,
-        commands::rag::get_knowledge_base_settings,
-        commands::rag::change_knowledge_base_enabled_setting,
-        commands::rag::change_auto_index_transcriptions_setting,
-        commands::rag::change_kb_embedding_model_setting,
-        commands::rag::change_kb_top_k_setting,
-        commands::rag::change_kb_similarity_threshold_setting,
-        commands::rag::change_kb_use_in_active_listening_setting,
-        commands::suggestions::get_suggestions_settings,
-        commands::suggestions::update_suggestions_settings,
-        commands::suggestions::change_suggestions_enabled_setting,
-        commands::suggestions::get_quick_responses,
-        commands::suggestions::get_quick_responses_by_category,
-        commands::suggestions::add_quick_response,
-        commands::suggestions::update_quick_response,
-        commands::suggestions::delete_quick_response,
-        commands::suggestions::toggle_quick_response,
-        commands::suggestions::change_rag_suggestions_enabled,
-        commands::suggestions::change_llm_suggestions_enabled,
-        commands::suggestions::change_max_suggestions,
-        commands::suggestions::change_min_confidence,
-        commands::suggestions::change_auto_dismiss_on_copy,
-        commands::suggestions::change_display_duration,
-        commands::batch_processing::add_to_batch_queue,
-        commands::batch_processing::start_batch_processing,
-        commands::batch_processing::cancel_batch_processing,
-        commands::batch_processing::get_batch_status,
-        commands::batch_processing::remove_batch_item,
-        commands::batch_processing::clear_completed_batch_items,
-        commands::tasks::extract_action_items,
-        commands::tasks::get_action_items,
-        commands::tasks::toggle_action_item,
-        commands::tasks::delete_action_item,
-        commands::tasks::export_action_items,
-        commands::vocabulary::get_vocabulary,
-        commands::vocabulary::add_vocabulary_term,
-        commands::vocabulary::remove_vocabulary_term,
-        commands::vocabulary::import_vocabulary,
-        commands::vocabulary::export_vocabulary,
-        commands::sound_detection::get_sound_detection_settings,
-        commands::sound_detection::change_sound_detection_enabled,
-        commands::sound_detection::change_sound_detection_threshold,
-        commands::sound_detection::change_sound_detection_categories,
-        commands::sound_detection::change_sound_detection_notification,
-        helpers::clamshell::is_laptop,
-    ]);
+    // Windows declared in tauri.conf.json are created at startup, so drop
+    // them before the app is built when running headless
+    let mut context = tauri::generate_context!();
+    if headless::is_headless() {
+        context.config_mut().app.windows.clear();
+    }
+
+    let specta_builder = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![
+            shortcut::change_binding,
+            shortcut::reset_binding,
+            shortcut::change_ptt_setting,
+            shortcut::change_audio_feedback_setting,
+            shortcut::change_audio_feedback_volume_setting,
+            shortcut::change_sound_theme_setting,
+            shortcut::change_start_hidden_setting,
+            shortcut::change_autostart_setting,
+            shortcut::change_translate_to_english_setting,
+            shortcut::change_selected_language_setting,
+            shortcut::change_overlay_position_setting,
+            shortcut::change_debug_mode_setting,
+            shortcut::change_guest_mode_setting,
+            shortcut::change_word_correction_threshold_setting,
+            shortcut::change_paste_method_setting,
+            shortcut::change_clipboard_handling_setting,
+            shortcut::change_post_process_enabled_setting,
+            shortcut::change_post_process_base_url_setting,
+            shortcut::change_post_process_api_key_setting,
+            shortcut::change_post_process_model_setting,
+            shortcut::set_post_process_provider,
+            shortcut::fetch_post_process_models,
+            shortcut::set_post_process_prompt_model,
+            shortcut::add_post_process_prompt,
+            shortcut::update_post_process_prompt,
+            shortcut::delete_post_process_prompt,
+            shortcut::set_post_process_selected_prompt,
+            shortcut::set_post_process_routes,
+            shortcut::update_custom_words,
+            shortcut::suspend_binding,
+            shortcut::resume_binding,
+            shortcut::change_mute_while_recording_setting,
+            shortcut::change_append_trailing_space_setting,
+            shortcut::change_app_language_setting,
+            shortcut::change_update_checks_setting,
+            shortcut::change_update_channel_setting,
+            shortcut::change_private_overlay_setting,
+            shortcut::change_tray_level_indicator_setting,
+            shortcut::change_vad_threshold_setting,
+            shortcut::change_low_power_idle_setting,
+            shortcut::change_idle_wake_threshold_setting,
+            shortcut::change_prearm_microphone_setting,
+            shortcut::change_retroactive_capture_setting,
+            shortcut::change_retroactive_capture_seconds_setting,
+            shortcut::change_primary_speaker_only_setting,
+            shortcut::change_primary_speaker_threshold_setting,
+            shortcut::change_echo_cancellation_setting,
+            shortcut::change_noise_suppression_setting,
+            shortcut::change_streaming_preview_setting,
+            trigger_update_check,
+            commands::cancel_operation,
+            commands::get_app_dir_path,
+            commands::is_portable_mode,
+            commands::get_app_settings,
+            commands::get_default_settings,
+            commands::get_log_dir_path,
+            commands::set_log_level,
+            commands::set_log_module_level,
+            commands::set_access_policy,
+            commands::get_access_policy_violations,
+            commands::open_recordings_folder,
+            commands::open_log_dir,
+            commands::get_recent_logs,
+            commands::start_log_follow,
+            commands::stop_log_follow,
+            commands::get_event_trail,
+            commands::open_app_data_dir,
+            commands::check_apple_intelligence_available,
+            commands::initialize_enigo,
+            commands::models::get_available_models,
+            commands::models::get_model_info,
+            commands::models::download_model,
+            commands::models::delete_model,
+            commands::models::cancel_download,
+            commands::models::set_active_model,
+            commands::models::get_current_model,
+            commands::models::get_transcription_model_status,
+            commands::models::is_model_loading,
+            commands::models::has_any_models_available,
+            commands::models::has_any_models_or_downloads,
+            commands::models::get_recommended_first_model,
+            commands::audio::update_microphone_mode,
+            commands::audio::get_microphone_mode,
+            commands::audio::get_available_microphones,
+            commands::audio::set_selected_microphone,
+            commands::audio::get_selected_microphone,
+            commands::audio::get_available_output_devices,
+            commands::audio::set_selected_output_device,
+            commands::audio::get_selected_output_device,
+            commands::audio::play_test_sound,
+            commands::audio::check_custom_sounds,
+            commands::audio::set_clamshell_microphone,
+            commands::audio::get_clamshell_microphone,
+            commands::audio::get_dock_state,
+            commands::audio::set_docking_settings,
+            commands::audio::set_schedule_settings,
+            commands::audio::get_schedule_overrides,
+            commands::audio::toggle_microphone_mute,
+            commands::audio::is_microphone_muted,
+            commands::audio::is_recording,
+            commands::audio::start_vad_test,
+            commands::audio::stop_vad_test,
+            commands::audio::enroll_voice_profile,
+            commands::audio::clear_voice_profile,
+            commands::audio::change_gain_normalization_setting,
+            commands::audio::change_silence_auto_stop_setting,
+            commands::audio::change_recording_limit_setting,
+            commands::audio::run_tuning_benchmark,
+            commands::transcription::set_model_unload_timeout,
+            commands::transcription::get_model_load_status,
+            commands::transcription::unload_model_manually,
+            commands::transcription::get_remote_stt_providers,
+            commands::transcription::set_transcription_backend,
+            commands::transcription::change_remote_stt_api_key_setting,
+            commands::transcription::change_remote_stt_model_setting,
+            commands::transcription::change_remote_stt_base_url_setting,
+            commands::transcription::change_remote_stt_fallback_setting,
+            commands::transcription::change_paragraphing_enabled_setting,
+            commands::transcription::change_paragraphing_pauses_setting,
+            commands::transcription::change_speaker_markers_setting,
+            commands::translation::change_translation_enabled_setting,
+            commands::translation::set_translation_language,
+            commands::translation::change_translation_provider_setting,
+            commands::formatting::set_formatting_mode,
+            commands::formatting::set_formatting_modes,
+            commands::formatting::reset_formatting_modes,
+            commands::transcribe_profiles::set_transcribe_profiles,
+            shortcut::change_transcribe_profile_binding,
+            commands::transcription::change_code_dictation_enabled_setting,
+            commands::transcription::change_code_dictation_apps_setting,
+            commands::transcription::change_code_dictation_identifier_case_setting,
+            commands::transcription::change_code_dictation_direct_paste_setting,
+            commands::transcription::change_voice_commands_enabled_setting,
+            commands::transcription::set_voice_commands,
+            commands::transcription::reset_voice_commands,
+            commands::history::get_history_entries,
+            commands::history::toggle_history_entry_saved,
+            commands::history::get_audio_file_path,
+            commands::history::get_history_entry_segments,
+            commands::history::delete_history_entry,
+            commands::history::restore_history_entry,
+            commands::history::resolve_duplicate_history_entry,
+            commands::history::rate_transcription,
+            commands::history::get_history_trash,
+            commands::history::empty_trash,
+            commands::history::update_history_trash_retention_days,
+            commands::history::update_history_dedup,
+            commands::history::update_history_limit,
+            commands::history::update_history_max_size,
+            commands::history::get_history_storage_stats,
+            commands::history::update_recording_retention_period,
+            commands::active_listening::start_active_listening_session,
+            commands::active_listening::stop_active_listening_session,
+            commands::active_listening::get_active_listening_state,
+            commands::active_listening::get_active_listening_session,
+            commands::active_listening::mark_session_highlight,
+            commands::active_listening::get_session_highlights,
+            commands::active_listening::check_ollama_connection,
+            commands::active_listening::fetch_ollama_models,
+            commands::active_listening::change_active_listening_enabled_setting,
+            commands::active_listening::change_active_listening_segment_duration_setting,
+            commands::active_listening::change_ollama_base_url_setting,
+            commands::active_listening::change_ollama_model_setting,
+            commands::active_listening::change_ollama_keep_alive_setting,
+            commands::active_listening::change_active_listening_context_window_setting,
+            commands::active_listening::change_active_listening_draft_model_setting,
+            commands::active_listening::change_active_listening_budget_setting,
+            commands::active_listening::change_active_listening_insight_filters_setting,
+            commands::active_listening::change_active_listening_dual_language_setting,
+            commands::active_listening::change_active_listening_anonymize_setting,
+            commands::active_listening::change_active_listening_anonymized_names_setting,
+            commands::active_listening::change_audio_source_type_setting,
+            commands::active_listening::change_audio_mix_ratio_setting,
+            commands::active_listening::get_audio_source_type,
+            commands::active_listening::get_audio_mix_ratio,
+            commands::active_listening::get_loopback_support_level,
+            commands::active_listening::is_loopback_supported,
+            commands::active_listening::list_loopback_devices,
+            commands::active_listening::add_active_listening_prompt,
+            commands::active_listening::update_active_listening_prompt,
+            commands::active_listening::delete_active_listening_prompt,
+            commands::active_listening::set_active_listening_selected_prompt,
+            commands::active_listening::add_speaker_profile,
+            commands::active_listening::update_speaker_profile,
+            commands::active_listening::delete_speaker_profile,
+            commands::active_listening::assign_speaker_profile,
+            commands::active_listening::rename_speaker,
+            commands::active_listening::get_diarization_settings,
+            commands::active_listening::set_diarization_settings,
+            commands::active_listening::generate_meeting_summary,
+            commands::active_listening::get_live_summary,
+            commands::active_listening::detect_session_chapters,
+            commands::active_listening::ask_meeting,
+            commands::active_listening::search_meeting_memory,
+            commands::active_listening::replay_active_listening_session,
+            commands::active_listening::export_meeting_summary,
+            commands::active_listening::list_active_listening_sessions,
+            commands::active_listening::search_active_listening_sessions,
+            commands::active_listening::get_stored_active_listening_session,
+            commands::active_listening::delete_stored_active_listening_session,
+            commands::active_listening::get_session_name_map,
+            commands::ask_ai::get_ask_ai_state,
+            commands::ask_ai::is_ask_ai_active,
+            commands::ask_ai::get_ask_ai_question,
+            commands::ask_ai::get_ask_ai_response,
+            commands::ask_ai::get_ask_ai_conversation,
+            commands::ask_ai::can_start_ask_ai_recording,
+            commands::ask_ai::cancel_ask_ai_session,
+            commands::ask_ai::reset_ask_ai_session,
+            commands::ask_ai::dismiss_ask_ai_session,
+            commands::ask_ai::start_new_ask_ai_conversation,
+            commands::ask_ai::regenerate_ask_ai_response,
+            commands::ask_ai::edit_ask_ai_question_and_branch,
+            commands::ask_ai::change_ask_ai_enabled_setting,
+            commands::ask_ai::change_ask_ai_ollama_base_url_setting,
+            commands::ask_ai::change_ask_ai_ollama_model_setting,
+            commands::ask_ai::change_ask_ai_system_prompt_setting,
+            commands::ask_ai::change_ask_ai_tools_enabled_setting,
+            commands::ask_ai::change_ask_ai_allowed_tools_setting,
+            commands::ask_ai::get_ask_ai_available_tools,
+            commands::ask_ai::get_ask_ai_settings,
+            commands::ask_ai::save_ask_ai_window_bounds,
+            commands::ask_ai::get_ask_ai_window_bounds,
+            commands::ask_ai::save_ask_ai_conversation_to_history,
+            commands::ask_ai::list_ask_ai_conversations,
+            commands::ask_ai::get_ask_ai_conversation_from_history,
+            commands::ask_ai::list_ask_ai_conversation_branches,
+            commands::ask_ai::get_ask_ai_audio_file_path,
+            commands::ask_ai::play_ask_ai_question_audio,
+            commands::ask_ai::delete_ask_ai_conversation_from_history,
+            commands::ask_ai::pin_ask_ai_turn_to_knowledge_base,
+            commands::rag::rag_add_document,
+            commands::rag::rag_search,
+            commands::rag::rag_delete_document,
+            commands::rag::rag_list_documents,
+            commands::rag::rag_get_stats,
+            commands::rag::rag_get_embedding_model,
+            commands::rag::rag_set_embedding_model,
+            commands::rag::rag_clear_all,
+            commands::rag::get_knowledge_base_settings,
+            commands::rag::change_knowledge_base_enabled_setting,
+            commands::rag::change_auto_index_transcriptions_setting,
+            commands::rag::change_kb_embedding_model_setting,
+            commands::rag::change_kb_top_k_setting,
+            commands::rag::change_kb_similarity_threshold_setting,
+            commands::rag::change_kb_use_in_active_listening_setting,
+            commands::rag::change_kb_embedding_batch_size_setting,
+            commands::rag::change_kb_embedding_concurrency_setting,
+            commands::rag::change_kb_embedding_max_retries_setting,
+            commands::rag::change_kb_indexing_max_per_minute_setting,
+            commands::rag::change_kb_pause_indexing_during_sessions_setting,
+            commands::rag::change_kb_indexing_max_attempts_setting,
+            commands::rag::get_indexing_status,
+            commands::rag::retry_failed_indexing,
+            commands::suggestions::get_suggestions_settings,
+            commands::suggestions::update_suggestions_settings,
+            commands::suggestions::change_suggestions_enabled_setting,
+            commands::suggestions::get_quick_responses,
+            commands::suggestions::get_quick_responses_by_category,
+            commands::suggestions::add_quick_response,
+            commands::suggestions::update_quick_response,
+            commands::suggestions::delete_quick_response,
+            commands::suggestions::toggle_quick_response,
+            commands::suggestions::change_rag_suggestions_enabled,
+            commands::suggestions::change_llm_suggestions_enabled,
+            commands::suggestions::change_max_suggestions,
+            commands::suggestions::change_min_confidence,
+            commands::suggestions::change_auto_dismiss_on_copy,
+            commands::suggestions::change_display_duration,
+            commands::suggestions::change_suggestion_dedup_threshold,
+            commands::suggestions::change_suggestion_dedup_window,
+            commands::suggestions::change_suggestion_category_cooldown,
+            commands::suggestions::change_max_suggestions_per_minute,
+            commands::suggestions::change_suggestion_feedback_learning,
+            commands::suggestions::record_suggestion_feedback,
+            commands::suggestions::get_suggestion_stats,
+            commands::suggestions::reset_suggestion_feedback,
+            commands::suggestions::change_warning_route,
+            commands::suggestions::change_warning_webhook_url,
+            commands::backup::get_backup_settings,
+            commands::backup::change_backup_enabled_setting,
+            commands::backup::change_backup_interval_setting,
+            commands::backup::change_backup_folder_setting,
+            commands::backup::change_backup_keep_count_setting,
+            commands::backup::change_backup_include_audio_setting,
+            commands::backup::create_backup,
+            commands::backup::list_backups,
+            commands::backup::restore_from_backup,
+            commands::batch_processing::add_to_batch_queue,
+            commands::batch_processing::transcribe_clipboard_file,
+            commands::batch_processing::start_batch_processing,
+            commands::batch_processing::cancel_batch_processing,
+            commands::batch_processing::get_batch_status,
+            commands::batch_processing::remove_batch_item,
+            commands::batch_processing::clear_completed_batch_items,
+            commands::batch_processing::set_batch_item_format,
+            commands::batch_processing::get_batch_output_config,
+            commands::batch_processing::configure_batch_output,
+            commands::batch_processing::get_batch_output_settings,
+            commands::batch_processing::change_batch_naming_template,
+            commands::batch_processing::change_batch_output_destination,
+            commands::batch_processing::change_batch_default_format,
+            commands::batch_processing::change_clipboard_watch_setting,
+            commands::sync::get_sync_settings,
+            commands::sync::change_sync_enabled_setting,
+            commands::sync::change_sync_folder_setting,
+            commands::sync::change_sync_conflict_policy_setting,
+            commands::sync::sync_now,
+            commands::sync::get_sync_status,
+            commands::report::generate_monthly_report,
+            commands::onboarding::check_microphone_capability,
+            commands::onboarding::check_accessibility_capability,
+            commands::onboarding::check_notification_capability,
+            commands::onboarding::check_loopback_capability,
+            commands::onboarding::check_model_disk_space,
+            commands::onboarding::check_ollama_presence,
+            commands::onboarding::get_capability_report,
+            commands::onboarding::open_microphone_settings,
+            commands::updates::check_for_update,
+            commands::updates::get_pending_update_changelog,
+            commands::updates::skip_update_version,
+            commands::updates::defer_update,
+            commands::updates::install_pending_update,
+            commands::tasks::extract_action_items,
+            commands::tasks::get_action_items,
+            commands::tasks::link_action_item_mentions,
+            commands::tasks::toggle_action_item,
+            commands::tasks::delete_action_item,
+            commands::tasks::export_action_items,
+            commands::vocabulary::get_vocabulary,
+            commands::vocabulary::add_vocabulary_term,
+            commands::vocabulary::remove_vocabulary_term,
+            commands::vocabulary::import_vocabulary,
+            commands::vocabulary::export_vocabulary,
+            commands::webhooks::change_webhook_enabled_setting,
+            commands::webhooks::change_webhook_url_setting,
+            commands::webhooks::change_webhook_auth_header_setting,
+            commands::webhooks::change_webhook_events_setting,
+            commands::webhooks::change_webhook_max_retries_setting,
+            commands::webhooks::test_webhook,
+            commands::automation_hooks::change_automation_hooks_setting,
+            commands::automation_hooks::test_automation_hook,
+            commands::plugins::get_plugins,
+            commands::plugins::reload_plugins,
+            commands::plugins::change_plugin_settings,
+            commands::plugins::run_plugin_action,
+            commands::plugins::open_plugins_folder,
+            shortcut::change_plugin_action_binding,
+            commands::api_server::change_api_server_enabled_setting,
+            commands::api_server::change_api_server_port_setting,
+            commands::api_server::regenerate_api_server_token,
+            commands::remote_control::change_remote_control_enabled_setting,
+            commands::remote_control::change_remote_control_port_setting,
+            commands::remote_control::change_remote_control_advertise_setting,
+            commands::remote_control::start_remote_pairing,
+            commands::remote_control::cancel_remote_pairing,
+            commands::remote_control::revoke_remote_device,
+            commands::browser_bridge::change_browser_bridge_enabled_setting,
+            commands::browser_bridge::change_browser_bridge_port_setting,
+            commands::browser_bridge::set_browser_extension_ids,
+            commands::browser_bridge::set_browser_site_rules,
+            commands::browser_bridge::install_browser_native_host,
+            commands::browser_bridge::get_browser_bridge_status,
+            commands::palette::query_palette,
+            commands::palette::execute_palette_item,
+            commands::wake_word::set_wake_word_settings,
+            commands::signals::get_signal_binding_options,
+            commands::signals::change_signal_binding_setting,
+            commands::export::get_export_templates,
+            commands::export::save_export_template,
+            commands::export::delete_export_template,
+            commands::export::change_export_webhook_url_setting,
+            commands::export::render_export,
+            commands::export::export_with_template,
+            commands::sound_detection::get_sound_detection_settings,
+            commands::sound_detection::change_sound_detection_enabled,
+            commands::sound_detection::change_sound_detection_threshold,
+            commands::sound_detection::change_sound_detection_categories,
+            commands::sound_detection::change_sound_detection_notification,
+            commands::notifications::get_notification_settings,
+            commands::notifications::set_notification_settings,
+            helpers::clamshell::is_laptop,
+        ])
+        .events(collect_events![
+            events::ShowOverlay,
+            events::HideOverlay,
+            events::MicLevel,
+            events::RecordingProgress,
+            events::RecordingLimitWarning,
+            events::MicrophoneMuteChanged,
+            events::PermissionLost,
+            events::DockStateChanged,
+            events::VadTestPoint,
+            events::VadTestStopped,
+            events::HandyKeysEvent,
+            events::SettingsChanged,
+            events::SettingsConfigApplied,
+            events::SettingsSynced,
+            events::CheckForUpdates,
+            events::UpdateDownloadProgress,
+            events::ModelStateChanged,
+            events::ModelDownloadProgress,
+            events::ModelDownloadComplete,
+            events::ModelExtractionStarted,
+            events::ModelExtractionCompleted,
+            events::ModelExtractionFailed,
+            events::TranscriptionFinalized,
+            events::TranscriptionPartial,
+            events::TranscriptionProgress,
+            events::PromptRouted,
+            events::FormattingModeChanged,
+            events::HistoryUpdated,
+            events::BackupCompleted,
+            events::BatchItemStatus,
+            events::BatchComplete,
+            events::ClipboardAudioCopied,
+            events::IndexingStatusChanged,
+            events::RagIndexingProgress,
+            events::ActiveListeningStateChanged,
+            events::ActiveListeningSegment,
+            events::ActiveListeningInsight,
+            events::ActiveListeningHighlight,
+            events::ActiveListeningBudgetExceeded,
+            events::SessionReplayProgress,
+            events::SuggestionPartial,
+            events::Suggestions,
+            events::AskAiStateChanged,
+            events::AskAiResponse,
+            events::AskAiToolCall,
+            events::PluginEmitted,
+            events::RemoteDevicePaired,
+            events::LogEntries,
+            events::AccessPolicyViolation,
+        ]);
 
     #[cfg(debug_assertions)] // <- Only export on non-release builds
     specta_builder
@@ -569,6 +986,8 @@ pub fn run() {
         )
         .expect("Failed to export typescript bindings");
 
+    let invoke_handler = specta_builder.invoke_handler();
+
     let mut builder = tauri::Builder::default().plugin(
         LogBuilder::new()
             .level(log::LevelFilter::Trace) // Set to most verbose level globally
@@ -576,18 +995,33 @@ pub fn run() {
             .rotation_strategy(RotationStrategy::KeepOne)
             .clear_targets()
             .targets([
-                // Console output respects RUST_LOG environment variable
-                Target::new(TargetKind::Stdout).filter({
+                // Console output respects RUST_LOG environment variable. It goes
+                // to stderr when stdout carries JSON transcriptions.
+                Target::new(if headless::is_json_output() {
+                    TargetKind::Stderr
+                } else {
+                    TargetKind::Stdout
+                })
+                .filter({
                     let console_filter = console_filter.clone();
                     move |metadata| console_filter.enabled(metadata)
                 }),
-                // File logs respect the user's settings (stored in FILE_LOG_LEVEL atomic)
-                Target::new(TargetKind::LogDir {
-                    file_name: Some("dictum".into()),
+                // File logs respect the user's settings (stored in FILE_LOG_LEVEL atomic),
+                // with per-module overrides taking precedence
+                Target::new(match portable::portable_data_dir() {
+                    Some(dir) => TargetKind::Folder {
+                        path: dir.join("logs"),
+                        file_name: Some("dictum".into()),
+                    },
+                    None => TargetKind::LogDir {
+                        file_name: Some("dictum".into()),
+                    },
                 })
                 .filter(|metadata| {
-                    let file_level = FILE_LOG_LEVEL.load(Ordering::Relaxed);
-                    metadata.level() <= level_filter_from_u8(file_level)
+                    let level = log_levels::module_level(metadata.target()).unwrap_or_else(|| {
+                        level_filter_from_u8(FILE_LOG_LEVEL.load(Ordering::Relaxed))
+                    });
+                    metadata.level() <= level
                 }),
             ])
             .build(),
@@ -609,6 +1043,7 @@ pub fn run() {
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_macos_permissions::init())
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .plugin(tauri_plugin_autostart::init(
@@ -616,18 +1051,34 @@ pub fn run() {
             Some(vec![]),
         ))
         .manage(Mutex::new(ShortcutToggleStates::default()))
+        .manage(commands::updates::PendingUpdate::default())
         .setup(move |app| {
+            specta_builder.mount_events(app);
+            // Config file overrides apply before anything reads the settings
+            if let Err(e) = settings::config_file::apply_config_file(app.handle()) {
+                log::warn!("{}", e);
+            }
             let settings = get_settings(&app.handle());
             let tauri_log_level: tauri_plugin_log::LogLevel = settings.log_level.into();
             let file_log_level: log::Level = tauri_log_level.into();
             // Store the file log level in the atomic for the filter to use
             FILE_LOG_LEVEL.store(file_log_level.to_level_filter() as u8, Ordering::Relaxed);
+            log_levels::set_module_levels(&settings.log_module_levels);
             let app_handle = app.handle().clone();
 
+            if let Some(dir) = portable::portable_data_dir() {
+                log::info!("Running in portable mode with data in {:?}", dir);
+            }
+
+            if headless::is_headless() {
+                log::info!("Running headless, no windows will be created");
+            }
+
             initialize_core_logic(&app_handle);
+            settings::config_file::watch_config_file(&app_handle);
 
             // Show main window only if not starting hidden
-            if !settings.general.start_hidden {
+            if !settings.general.start_hidden && !headless::is_headless() {
                 if let Some(main_window) = app_handle.get_webview_window("main") {
                     main_window.show().unwrap();
                     main_window.set_focus().unwrap();
@@ -657,7 +1108,7 @@ pub fn run() {
             }
             _ => {}
         })
-        .invoke_handler(specta_builder.invoke_handler())
-        .run(tauri::generate_context!())
+        .invoke_handler(invoke_handler)
+        .run(context)
         .expect("error while running tauri application");
 }
@@ -1,3 +1,4 @@
+use crate::access_policy;
 use crate::settings::PostProcessProvider;
 use log::debug;
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, REFERER, USER_AGENT};
@@ -95,6 +96,7 @@ pub async fn send_chat_completion(
 ) -> Result<Option<String>, String> {
     let base_url = provider.base_url.trim_end_matches('/');
     let url = format!("{}/chat/completions", base_url);
+    access_policy::check_url(&url)?;
 
     debug!("Sending chat completion request to: {}", url);
 
@@ -146,6 +148,7 @@ pub async fn fetch_models(
 ) -> Result<Vec<String>, String> {
     let base_url = provider.base_url.trim_end_matches('/');
     let url = format!("{}/models", base_url);
+    access_policy::check_url(&url)?;
 
     debug!("Fetching models from: {}", url);
 
@@ -0,0 +1,101 @@
+//! Per-module log levels
+//!
+//! Overrides the file log level for individual modules, so e.g.
+//! `transcription = trace` and `audio = warn` can be combined. A module is
+//! named by one or more path segments ("transcription",
+//! "managers::transcription", "whisper_rs") and matches any log target
+//! containing those segments in a row. When several overrides match, the
+//! one with the most segments wins.
+
+use crate::settings::LogLevel;
+use log::LevelFilter;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Active overrides, most specific first
+static MODULE_LEVELS: RwLock<Vec<(String, LevelFilter)>> = RwLock::new(Vec::new());
+
+fn level_filter(level: LogLevel) -> LevelFilter {
+    let tauri_log_level: tauri_plugin_log::LogLevel = level.into();
+    let log_level: log::Level = tauri_log_level.into();
+    log_level.to_level_filter()
+}
+
+/// Whether `module` names `target` or one of its parents or children
+fn module_matches(module: &str, target: &str) -> bool {
+    let module: Vec<&str> = module.split("::").collect();
+    let target: Vec<&str> = target.split("::").collect();
+    target.windows(module.len()).any(|window| window == module)
+}
+
+fn sorted_levels(levels: &HashMap<String, LogLevel>) -> Vec<(String, LevelFilter)> {
+    let mut sorted: Vec<(String, LevelFilter)> = levels
+        .iter()
+        .map(|(module, level)| (module.trim().to_string(), level_filter(*level)))
+        .filter(|(module, _)| !module.is_empty())
+        .collect();
+    sorted.sort_by(|(a, _), (b, _)| {
+        b.split("::")
+            .count()
+            .cmp(&a.split("::").count())
+            .then_with(|| a.cmp(b))
+    });
+    sorted
+}
+
+fn find_level(levels: &[(String, LevelFilter)], target: &str) -> Option<LevelFilter> {
+    levels
+        .iter()
+        .find(|(module, _)| module_matches(module, target))
+        .map(|(_, level)| *level)
+}
+
+/// Replace the active overrides
+pub fn set_module_levels(levels: &HashMap<String, LogLevel>) {
+    let sorted = sorted_levels(levels);
+    if let Ok(mut active) = MODULE_LEVELS.write() {
+        *active = sorted;
+    }
+}
+
+/// The overridden level for a log target, if any
+pub fn module_level(target: &str) -> Option<LevelFilter> {
+    let levels = MODULE_LEVELS.read().ok()?;
+    find_level(&levels, target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modules_match_whole_segments() {
+        let target = "dictum_app_lib::managers::transcription";
+        assert!(module_matches("transcription", target));
+        assert!(module_matches("managers::transcription", target));
+        assert!(module_matches("dictum_app_lib", target));
+        assert!(!module_matches("transcript", target));
+        assert!(!module_matches("audio::transcription", target));
+        assert!(module_matches("ort", "ort::session"));
+    }
+
+    #[test]
+    fn test_most_specific_override_wins() {
+        let levels = sorted_levels(&HashMap::from([
+            ("managers".to_string(), LogLevel::Warn),
+            ("managers::transcription".to_string(), LogLevel::Trace),
+            (" ".to_string(), LogLevel::Error),
+        ]));
+        assert_eq!(levels.len(), 2);
+
+        assert_eq!(
+            find_level(&levels, "dictum_app_lib::managers::transcription"),
+            Some(LevelFilter::Trace)
+        );
+        assert_eq!(
+            find_level(&levels, "dictum_app_lib::managers::audio"),
+            Some(LevelFilter::Warn)
+        );
+        assert_eq!(find_level(&levels, "dictum_app_lib::overlay"), None);
+    }
+}
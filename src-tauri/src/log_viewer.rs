@@ -0,0 +1,453 @@
+//! Log viewer
+//!
+//! Reads the app's log files so the settings window can show them without
+//! users hunting for the log folder. Lines are parsed from the
+//! tauri-plugin-log format:
+//!
+//! ```text
+//! [2025-01-31][14:02:11][INFO][dictum_app_lib::managers::audio] message
+//! ```
+//!
+//! Continuation lines of multi-line messages are folded into the entry
+//! above them. Follow mode tails the current log file and emits new entries
+//! as `log-entries` events.
+
+use crate::events::LogEntries;
+use crate::settings::LogLevel;
+use chrono::NaiveDateTime;
+use log::Level;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+/// File name of the current log, matching the log target in lib.rs
+const LOG_FILE_STEM: &str = "dictum";
+
+const DEFAULT_PAGE_SIZE: usize = 200;
+const MAX_PAGE_SIZE: usize = 1000;
+
+/// How often follow mode checks the log file for new lines
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(500);
+
+/// One parsed log record
+#[derive(Serialize, Clone, Debug, PartialEq, Type)]
+pub struct LogEntry {
+    /// Unix timestamp in milliseconds (log timestamps are UTC)
+    pub timestamp_ms: i64,
+    pub level: LogLevel,
+    /// Module path the record was logged from
+    pub module: String,
+    pub message: String,
+}
+
+/// Filters for [`query`]. Unset fields match everything.
+#[derive(Deserialize, Clone, Debug, Default, Type)]
+pub struct LogFilter {
+    /// Least severe level to include
+    pub min_level: Option<LogLevel>,
+    /// Case-insensitive substring of the module path
+    pub module: Option<String>,
+    /// Case-insensitive substring of the message
+    pub search: Option<String>,
+    /// Earliest timestamp to include, in Unix milliseconds
+    pub since_ms: Option<i64>,
+    /// Latest timestamp to include, in Unix milliseconds
+    pub until_ms: Option<i64>,
+}
+
+/// A page of log entries, newest first
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct LogPage {
+    pub entries: Vec<LogEntry>,
+    /// Number of entries matching the filter across all pages
+    pub total: usize,
+    /// Offset of the next page, if there is one
+    pub next_offset: Option<usize>,
+}
+
+fn severity(level: LogLevel) -> Level {
+    match level {
+        LogLevel::Trace => Level::Trace,
+        LogLevel::Debug => Level::Debug,
+        LogLevel::Info => Level::Info,
+        LogLevel::Warn => Level::Warn,
+        LogLevel::Error => Level::Error,
+    }
+}
+
+fn log_level(level: Level) -> LogLevel {
+    match level {
+        Level::Trace => LogLevel::Trace,
+        Level::Debug => LogLevel::Debug,
+        Level::Info => LogLevel::Info,
+        Level::Warn => LogLevel::Warn,
+        Level::Error => LogLevel::Error,
+    }
+}
+
+/// Take the next `[...]` field off the front of a line
+fn bracketed(line: &str) -> Option<(&str, &str)> {
+    let rest = line.strip_prefix('[')?;
+    let end = rest.find(']')?;
+    Some((&rest[..end], &rest[end + 1..]))
+}
+
+/// Parse the first line of a log record
+fn parse_header(line: &str) -> Option<LogEntry> {
+    let (date, rest) = bracketed(line)?;
+    let (time, rest) = bracketed(rest)?;
+    let (level, rest) = bracketed(rest)?;
+    let (module, rest) = bracketed(rest)?;
+
+    let timestamp =
+        NaiveDateTime::parse_from_str(&format!("{} {}", date, time), "%Y-%m-%d %H:%M:%S").ok()?;
+    let level = Level::from_str(level).ok()?;
+
+    Some(LogEntry {
+        timestamp_ms: timestamp.and_utc().timestamp_millis(),
+        level: log_level(level),
+        module: module.to_string(),
+        message: rest.strip_prefix(' ').unwrap_or(rest).to_string(),
+    })
+}
+
+/// Turns log lines into entries, folding continuation lines into the
+/// entry they belong to
+#[derive(Default)]
+struct LogParser {
+    pending: Option<LogEntry>,
+}
+
+impl LogParser {
+    /// Feed one line. Returns the previous entry once the next one starts.
+    fn push_line(&mut self, line: &str) -> Option<LogEntry> {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if let Some(entry) = parse_header(line) {
+            return self.pending.replace(entry);
+        }
+        // Lines before the first header belong to an entry we can't see
+        if let Some(entry) = &mut self.pending {
+            entry.message.push('\n');
+            entry.message.push_str(line);
+        }
+        None
+    }
+
+    fn finish(&mut self) -> Option<LogEntry> {
+        self.pending.take()
+    }
+}
+
+fn parse_log(content: &str) -> Vec<LogEntry> {
+    let mut parser = LogParser::default();
+    let mut entries: Vec<LogEntry> = content
+        .lines()
+        .filter_map(|line| parser.push_line(line))
+        .collect();
+    entries.extend(parser.finish());
+    entries
+}
+
+impl LogFilter {
+    fn matches(&self, entry: &LogEntry) -> bool {
+        let contains = |haystack: &str, needle: &Option<String>| {
+            needle
+                .as_ref()
+                .is_none_or(|n| haystack.to_lowercase().contains(&n.to_lowercase()))
+        };
+
+        self.min_level
+            .is_none_or(|min| severity(entry.level) <= severity(min))
+            && self
+                .since_ms
+                .is_none_or(|since| entry.timestamp_ms >= since)
+            && self
+                .until_ms
+                .is_none_or(|until| entry.timestamp_ms <= until)
+            && contains(&entry.module, &self.module)
+            && contains(&entry.message, &self.search)
+    }
+}
+
+/// Filter entries (oldest first) and return one page, newest first
+fn paginate(entries: Vec<LogEntry>, filter: &LogFilter, offset: usize, limit: usize) -> LogPage {
+    let matching: Vec<LogEntry> = entries
+        .into_iter()
+        .rev()
+        .filter(|e| filter.matches(e))
+        .collect();
+    let total = matching.len();
+    let limit = limit.clamp(1, MAX_PAGE_SIZE);
+    let entries: Vec<LogEntry> = matching.into_iter().skip(offset).take(limit).collect();
+    let end = offset + entries.len();
+
+    LogPage {
+        entries,
+        total,
+        next_offset: (end < total).then_some(end),
+    }
+}
+
+/// Log files in the directory, oldest first. Rotated files sort before the
+/// current one.
+fn log_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let read_dir = match fs::read_dir(dir) {
+        Ok(read_dir) => read_dir,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read log directory: {}", e)),
+    };
+
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = read_dir
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "log")
+                && path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .is_some_and(|s| s.starts_with(LOG_FILE_STEM))
+        })
+        .map(|path| {
+            let modified = fs::metadata(&path)
+                .and_then(|m| m.modified())
+                .unwrap_or(std::time::UNIX_EPOCH);
+            (modified, path)
+        })
+        .collect();
+    files.sort();
+    Ok(files.into_iter().map(|(_, path)| path).collect())
+}
+
+fn current_log_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = crate::portable::app_log_dir(app)
+        .map_err(|e| format!("Failed to get log directory: {}", e))?;
+    Ok(dir.join(format!("{}.log", LOG_FILE_STEM)))
+}
+
+/// Matching entries from all log files, newest first
+pub fn query(
+    app: &AppHandle,
+    filter: &LogFilter,
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> Result<LogPage, String> {
+    let dir = crate::portable::app_log_dir(app)
+        .map_err(|e| format!("Failed to get log directory: {}", e))?;
+
+    let mut entries = Vec::new();
+    for path in log_files(&dir)? {
+        // The file may be rotated away between listing and reading
+        let Ok(bytes) = fs::read(&path) else {
+            continue;
+        };
+        entries.extend(parse_log(&String::from_utf8_lossy(&bytes)));
+    }
+
+    Ok(paginate(
+        entries,
+        filter,
+        offset.unwrap_or(0),
+        limit.unwrap_or(DEFAULT_PAGE_SIZE),
+    ))
+}
+
+/// The running follow session, if any
+#[derive(Default)]
+pub struct LogFollowState {
+    active: AtomicU64,
+    next_id: AtomicU64,
+}
+
+/// Reads whatever was appended to a log file since the last call
+struct LogTail {
+    path: PathBuf,
+    position: u64,
+    partial: Vec<u8>,
+    parser: LogParser,
+}
+
+impl LogTail {
+    /// Start at the current end of the file
+    fn new(path: PathBuf) -> Self {
+        let position = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        Self {
+            path,
+            position,
+            partial: Vec::new(),
+            parser: LogParser::default(),
+        }
+    }
+
+    fn read_new(&mut self) -> Vec<LogEntry> {
+        let Ok(mut file) = File::open(&self.path) else {
+            return Vec::new();
+        };
+        let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+        if len < self.position {
+            // The file was rotated, start over from the top
+            self.position = 0;
+            self.partial.clear();
+        }
+        if len == self.position || file.seek(SeekFrom::Start(self.position)).is_err() {
+            return Vec::new();
+        }
+
+        let mut bytes = Vec::new();
+        let Ok(read) = file.take(len - self.position).read_to_end(&mut bytes) else {
+            return Vec::new();
+        };
+        self.position += read as u64;
+        self.partial.extend_from_slice(&bytes);
+
+        // Only parse complete lines; keep the rest for the next read
+        let Some(last_newline) = self.partial.iter().rposition(|&b| b == b'\n') else {
+            return Vec::new();
+        };
+        let complete: Vec<u8> = self.partial.drain(..=last_newline).collect();
+        let mut entries: Vec<LogEntry> = String::from_utf8_lossy(&complete)
+            .lines()
+            .filter_map(|line| self.parser.push_line(line))
+            .collect();
+        // Records are written whole, so a chunk ending in a newline ends one
+        if self.partial.is_empty() {
+            entries.extend(self.parser.finish());
+        }
+        entries
+    }
+}
+
+/// Emit new entries matching the filter as `log-entries` events until
+/// [`stop_follow`] is called, replacing any running follow session
+pub fn start_follow(app: &AppHandle, filter: LogFilter) -> Result<(), String> {
+    let mut tail = LogTail::new(current_log_file(app)?);
+    let state = app.state::<LogFollowState>();
+    let id = state.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+    state.active.store(id, Ordering::SeqCst);
+
+    let app = app.clone();
+    thread::spawn(move || {
+        // No logging in here, it would feed itself
+        while app.state::<LogFollowState>().active.load(Ordering::SeqCst) == id {
+            let entries: Vec<LogEntry> = tail
+                .read_new()
+                .into_iter()
+                .filter(|e| filter.matches(e))
+                .collect();
+            if !entries.is_empty() {
+                let _ = LogEntries(entries).emit(&app);
+            }
+            thread::sleep(FOLLOW_INTERVAL);
+        }
+    });
+    Ok(())
+}
+
+pub fn stop_follow(app: &AppHandle) {
+    app.state::<LogFollowState>()
+        .active
+        .store(0, Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+[2025-01-31][14:02:11][INFO][dictum_app_lib::managers::audio] Opened microphone
+[2025-01-31][14:02:12][DEBUG][dictum_app_lib::managers::transcription] Loaded model
+[2025-01-31][14:02:13][ERROR][dictum_app_lib::ollama_client] Request failed:
+connection refused
+  at localhost:11434
+[2025-01-31][14:02:14][WARN][dictum_app_lib::managers::audio] Device lost [USB Mic]
+";
+
+    #[test]
+    fn test_parse_plugin_log_format() {
+        let entries = parse_log(SAMPLE);
+        assert_eq!(entries.len(), 4);
+
+        assert_eq!(entries[0].level, LogLevel::Info);
+        assert_eq!(entries[0].module, "dictum_app_lib::managers::audio");
+        assert_eq!(entries[0].message, "Opened microphone");
+        assert_eq!(entries[0].timestamp_ms, 1_738_332_131_000);
+
+        // Continuation lines stay with their record
+        assert_eq!(
+            entries[2].message,
+            "Request failed:\nconnection refused\n  at localhost:11434"
+        );
+        // Brackets in the message are left alone
+        assert_eq!(entries[3].message, "Device lost [USB Mic]");
+    }
+
+    #[test]
+    fn test_filters_and_pagination() {
+        let entries = parse_log(SAMPLE);
+
+        let warnings = LogFilter {
+            min_level: Some(LogLevel::Warn),
+            ..Default::default()
+        };
+        let page = paginate(entries.clone(), &warnings, 0, 10);
+        assert_eq!(page.total, 2);
+        // Newest first
+        assert_eq!(page.entries[0].level, LogLevel::Warn);
+        assert_eq!(page.entries[1].level, LogLevel::Error);
+
+        let audio = LogFilter {
+            module: Some("AUDIO".to_string()),
+            since_ms: Some(1_738_332_132_000),
+            ..Default::default()
+        };
+        let page = paginate(entries.clone(), &audio, 0, 10);
+        assert_eq!(page.total, 1);
+        assert_eq!(page.entries[0].message, "Device lost [USB Mic]");
+
+        let first = paginate(entries.clone(), &LogFilter::default(), 0, 3);
+        assert_eq!(first.entries.len(), 3);
+        assert_eq!(first.next_offset, Some(3));
+        let second = paginate(entries, &LogFilter::default(), 3, 3);
+        assert_eq!(second.entries.len(), 1);
+        assert_eq!(second.entries[0].message, "Opened microphone");
+        assert_eq!(second.next_offset, None);
+    }
+
+    #[test]
+    fn test_tail_reads_only_appended_lines() {
+        use std::io::Write;
+
+        let path = std::env::temp_dir().join(format!("dictum-tail-{}.log", std::process::id()));
+        fs::write(&path, "[2025-01-31][14:02:11][INFO][app] old\n").unwrap();
+        let mut tail = LogTail::new(path.clone());
+        assert!(tail.read_new().is_empty());
+
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        write!(
+            file,
+            "[2025-01-31][14:02:12][INFO][app] new\nsecond line\n[2025-01-31]"
+        )
+        .unwrap();
+        // The record may still have continuation lines coming
+        assert!(tail.read_new().is_empty());
+
+        writeln!(file, "[14:02:13][WARN][app] last").unwrap();
+        let entries = tail.read_new();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].message, "new\nsecond line");
+        assert_eq!(entries[1].message, "last");
+
+        // Rotation truncates the file
+        fs::write(&path, "[2025-01-31][14:02:14][INFO][app] rotated\n").unwrap();
+        assert_eq!(tail.read_new()[0].message, "rotated");
+
+        let _ = fs::remove_file(path);
+    }
+}
@@ -4,21 +4,44 @@
 //! Handles the state machine for active listening sessions and coordinates
 //! between audio input, transcription, and insight generation.
 
+use crate::audio_toolkit::apply_custom_words;
 use crate::audio_toolkit::diarization::{create_shared_diarizer, SharedDiarizer};
-use crate::managers::history::HistoryManager;
-use crate::managers::rag::{DocMetadata, RagManager};
+use crate::events::{
+    ActiveListeningBudgetExceeded, ActiveListeningHighlight, ActiveListeningInsight,
+    ActiveListeningSegment, ActiveListeningStateChanged,
+};
+use crate::managers::active_listening_history::ActiveListeningHistoryManager;
+use crate::managers::anonymization;
+use crate::managers::chapters::{
+    chaptered_transcript, detect_chapters, format_offset, Chapter, CHAPTERED_SUMMARY_MINUTES,
+};
+use crate::managers::highlights::{highlight_excerpts, HighlightExcerpt, SessionHighlight};
+use crate::managers::history::{HistoryManager, SessionSpeaker};
+use crate::managers::indexing_queue::IndexingQueue;
+use crate::managers::insight_filter;
+use crate::managers::live_summary::{self, LiveSummary, LiveSummaryCache};
+use crate::managers::rag::DocMetadata;
+use crate::managers::scheduler;
+use crate::managers::session_budget::{Admission, BudgetLimit, BudgetTracker};
 use crate::managers::suggestion_engine::{Suggestion, SuggestionContext, SuggestionEngine};
+use crate::managers::task_extractor;
 use crate::managers::transcription::TranscriptionManager;
+use crate::managers::webhook::WebhookManager;
 use crate::ollama_client::{apply_prompt_template, OllamaClient};
-use crate::settings::get_settings;
+use crate::settings::{
+    get_settings, AppSettings, ModelUnloadTimeout, SpeakerProfile, TranslationMethod, WebhookEvent,
+};
+use crate::translation::translation_prompt;
+use crate::voice_profile;
 use log::{debug, error, info, warn};
 use serde::{Deserialize, Serialize};
 use specta::Type;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
 use tokio::sync::mpsc;
 
 /// State of the active listening session
@@ -54,6 +77,12 @@ pub struct ActiveListeningSession {
     pub topic: Option<String>,
     /// All insights generated during this session
     pub insights: Vec<SessionInsight>,
+    /// Moments marked with the highlight shortcut
+    #[serde(default)]
+    pub highlights: Vec<SessionHighlight>,
+    /// Names given to speakers during the session, by speaker ID
+    #[serde(default)]
+    pub speaker_names: HashMap<u32, String>,
 }
 
 /// A single insight generated from a segment
@@ -71,6 +100,20 @@ pub struct SessionInsight {
     pub speaker_id: Option<u32>,
     /// Human-readable speaker label (e.g., "You", "Speaker 2", or custom name)
     pub speaker_label: Option<String>,
+    /// The transcription in the session's second language, when dual
+    /// language output is on
+    #[serde(default)]
+    pub translation: Option<String>,
+}
+
+impl SessionInsight {
+    /// The transcription prefixed with who said it, if known
+    pub fn attributed_transcription(&self) -> String {
+        match &self.speaker_label {
+            Some(label) => format!("{}: {}", label, self.transcription),
+            None => self.transcription.clone(),
+        }
+    }
 }
 
 /// An action item extracted from a meeting
@@ -103,6 +146,12 @@ pub struct MeetingSummary {
     pub duration_minutes: u32,
     /// When this summary was generated
     pub generated_at: i64,
+    /// Chapters of sessions long enough to be split by topic
+    #[serde(default)]
+    pub chapters: Vec<Chapter>,
+    /// Highlighted moments with the transcript around them
+    #[serde(default)]
+    pub highlights: Vec<HighlightExcerpt>,
 }
 
 /// Event payload for active listening segment
@@ -115,6 +164,16 @@ pub struct ActiveListeningSegmentEvent {
     pub speaker_id: Option<u32>,
     /// Human-readable speaker label
     pub speaker_label: Option<String>,
+    /// Whether this is a draft from the draft model. The main model's
+    /// transcription follows with the same timestamp and replaces it.
+    pub draft: bool,
+}
+
+/// Event payload for a highlight marked during a session
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct ActiveListeningHighlightEvent {
+    pub session_id: String,
+    pub highlight: SessionHighlight,
 }
 
 /// Event payload for active listening insight (streaming)
@@ -133,6 +192,20 @@ pub struct ActiveListeningStateEvent {
     pub error: Option<String>,
 }
 
+/// Number the previous insights for the `{{previous_context}}` prompt variable
+pub fn format_previous_context(context: &VecDeque<String>) -> String {
+    if context.is_empty() {
+        "No previous context.".to_string()
+    } else {
+        context
+            .iter()
+            .enumerate()
+            .map(|(i, c)| format!("{}. {}", i + 1, c))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
 /// Active Listening Manager
 ///
 /// Coordinates the active listening feature, managing:
@@ -169,6 +242,15 @@ pub struct ActiveListeningManager {
 
     /// Current detected speaker ID for the segment being accumulated
     current_segment_speaker: Arc<Mutex<Option<u32>>>,
+
+    /// Speaker profile ID assigned to each diarized speaker of the session
+    speaker_profiles: Arc<Mutex<HashMap<u32, String>>>,
+
+    /// LLM usage of the session against its budget
+    budget: Arc<Mutex<BudgetTracker>>,
+
+    /// Running summary of the session, updated when it's asked for
+    live_summary: LiveSummaryCache,
 }
 
 impl ActiveListeningManager {
@@ -188,6 +270,9 @@ impl ActiveListeningManager {
             shutdown_signal: Arc::new(AtomicBool::new(false)),
             diarizer: create_shared_diarizer(),
             current_segment_speaker: Arc::new(Mutex::new(None)),
+            speaker_profiles: Arc::new(Mutex::new(HashMap::new())),
+            budget: Arc::new(Mutex::new(BudgetTracker::default())),
+            live_summary: LiveSummaryCache::default(),
         })
     }
 
@@ -208,6 +293,10 @@ impl ActiveListeningManager {
 
     /// Start a new active listening session
     pub fn start_session(&self, topic: Option<String>) -> Result<String, String> {
+        if scheduler::active_overrides(&self.app_handle).active_listening == Some(false) {
+            return Err("Active Listening is paused by the schedule".to_string());
+        }
+
         let mut state = self.state.lock().unwrap();
 
         if *state != ActiveListeningState::Idle {
@@ -225,6 +314,8 @@ impl ActiveListeningManager {
             ended_at: None,
             topic: topic.clone(),
             insights: Vec::new(),
+            highlights: Vec::new(),
+            speaker_names: HashMap::new(),
         };
 
         // Update state
@@ -251,34 +342,68 @@ impl ActiveListeningManager {
             context.clear();
         }
 
-        // Reset diarizer for new session
+        // Fresh diarizer for the new session, built from the current settings
         {
-            let mut diarizer = self.diarizer.lock().unwrap();
-            diarizer.reset();
+            let diarizer = get_settings(&self.app_handle).diarization.create_diarizer();
+            *self.diarizer.lock().unwrap() = diarizer;
         }
         {
             let mut speaker = self.current_segment_speaker.lock().unwrap();
             *speaker = None;
         }
+        {
+            let mut assignments = self.speaker_profiles.lock().unwrap();
+            *assignments = get_settings(&self.app_handle)
+                .active_listening
+                .speaker_profiles
+                .iter()
+                .filter_map(|p| p.default_speaker_id.map(|id| (id, p.id.clone())))
+                .collect();
+        }
+        {
+            let budget = get_settings(&self.app_handle).active_listening.budget;
+            *self.budget.lock().unwrap() = BudgetTracker::new(budget);
+        }
 
         // Emit session started event
-        let _ = self.app_handle.emit(
-            "active-listening-state-changed",
-            ActiveListeningStateEvent {
-                state: ActiveListeningState::Listening,
-                session_id: Some(session_id.clone()),
-                error: None,
-            },
-        );
+        let _ = ActiveListeningStateChanged(ActiveListeningStateEvent {
+            state: ActiveListeningState::Listening,
+            session_id: Some(session_id.clone()),
+            error: None,
+        })
+        .emit(&self.app_handle);
 
         info!(
             "Started active listening session: {} with topic: {:?}",
             session_id, topic
         );
+        self.preload_insight_model();
 
         Ok(session_id)
     }
 
+    /// Load the insight model in the background, so the first insight
+    /// doesn't wait for Ollama to load it
+    fn preload_insight_model(&self) {
+        let settings = get_settings(&self.app_handle).active_listening;
+        if settings.ollama_model.is_empty()
+            || settings.ollama_keep_alive == ModelUnloadTimeout::Immediately
+        {
+            return;
+        }
+        tauri::async_runtime::spawn(async move {
+            let model = &settings.ollama_model;
+            let result = match OllamaClient::for_active_listening(&settings) {
+                Ok(client) => client.preload(model).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(()) => debug!("Loaded Ollama model {} for the session", model),
+                Err(e) => warn!("Failed to load Ollama model {} ahead: {}", model, e),
+            }
+        });
+    }
+
     /// Stop the current active listening session
     pub fn stop_session(&self) -> Result<Option<ActiveListeningSession>, String> {
         let mut state = self.state.lock().unwrap();
@@ -311,16 +436,15 @@ impl ActiveListeningManager {
             let mut start_time = self.segment_start_time.lock().unwrap();
             *start_time = None;
         }
+        self.transcription_manager.unload_draft_model();
 
         // Emit session ended event
-        let _ = self.app_handle.emit(
-            "active-listening-state-changed",
-            ActiveListeningStateEvent {
-                state: ActiveListeningState::Idle,
-                session_id: session.as_ref().map(|s| s.id.clone()),
-                error: None,
-            },
-        );
+        let _ = ActiveListeningStateChanged(ActiveListeningStateEvent {
+            state: ActiveListeningState::Idle,
+            session_id: session.as_ref().map(|s| s.id.clone()),
+            error: None,
+        })
+        .emit(&self.app_handle);
 
         if let Some(ref s) = session {
             info!(
@@ -328,11 +452,167 @@ impl ActiveListeningManager {
                 s.id,
                 s.insights.len()
             );
+
+            if !crate::settings::is_guest_mode(&self.app_handle) {
+                let saved = if get_settings(&self.app_handle)
+                    .active_listening
+                    .anonymize_sessions
+                {
+                    anonymization::store_anonymized(&self.app_handle, s)
+                } else {
+                    let history = self
+                        .app_handle
+                        .state::<Arc<ActiveListeningHistoryManager>>();
+                    history.save_session(s).map_err(|e| e.to_string())
+                };
+                if let Err(e) = saved {
+                    error!("Failed to save active listening session {}: {}", s.id, e);
+                }
+            }
+
+            self.app_handle
+                .state::<Arc<WebhookManager>>()
+                .notify(WebhookEvent::SessionEnded, s);
+
+            // Follow up on open action items the session discussed
+            let app_handle = self.app_handle.clone();
+            let finished = s.clone();
+            tauri::async_runtime::spawn(async move {
+                let mark_progress = get_settings(&app_handle)
+                    .active_listening
+                    .mark_action_item_progress;
+                if let Err(e) =
+                    task_extractor::link_session_mentions(&app_handle, &finished, mark_progress)
+                        .await
+                {
+                    warn!(
+                        "Failed to link action items to session {}: {}",
+                        finished.id, e
+                    );
+                }
+            });
         }
 
         Ok(session)
     }
 
+    /// Assign a speaker profile to a diarized speaker of the current session,
+    /// or clear the assignment with `None`
+    pub fn assign_speaker_profile(
+        &self,
+        speaker_id: u32,
+        profile_id: Option<String>,
+    ) -> Result<(), String> {
+        if !self.is_session_active() {
+            return Err("No active listening session".to_string());
+        }
+        let mut assignments = self.speaker_profiles.lock().unwrap();
+        match profile_id {
+            Some(profile_id) => {
+                let settings = get_settings(&self.app_handle);
+                if settings
+                    .active_listening
+                    .get_speaker_profile(&profile_id)
+                    .is_none()
+                {
+                    return Err(format!("Speaker profile not found: {}", profile_id));
+                }
+                info!(
+                    "Assigned speaker profile {} to speaker {}",
+                    profile_id, speaker_id
+                );
+                assignments.insert(speaker_id, profile_id);
+            }
+            None => {
+                assignments.remove(&speaker_id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Name a speaker of the current session, or go back to the default
+    /// label with `None`. Segments already heard from them, in the session
+    /// and in history, are relabeled.
+    pub fn rename_speaker(&self, speaker_id: u32, name: Option<String>) -> Result<(), String> {
+        let name = name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty());
+        let profile = self
+            .speaker_profiles
+            .lock()
+            .unwrap()
+            .get(&speaker_id)
+            .cloned()
+            .and_then(|profile_id| {
+                get_settings(&self.app_handle)
+                    .active_listening
+                    .get_speaker_profile(&profile_id)
+                    .cloned()
+            });
+        let label = speaker_label(Some(speaker_id), name.as_deref(), profile.as_ref());
+
+        let session_id = {
+            let mut current = self.current_session.lock().unwrap();
+            let session = current
+                .as_mut()
+                .ok_or_else(|| "No active listening session".to_string())?;
+            match name {
+                Some(name) => session.speaker_names.insert(speaker_id, name),
+                None => session.speaker_names.remove(&speaker_id),
+            };
+            for insight in &mut session.insights {
+                if insight.speaker_id == Some(speaker_id) {
+                    insight.speaker_label = label.clone();
+                }
+            }
+            session.id.clone()
+        };
+        info!("Speaker {} is now {:?}", speaker_id, label);
+
+        let history_manager = self.app_handle.state::<Arc<HistoryManager>>();
+        history_manager
+            .relabel_session_speaker(&session_id, speaker_id, label.as_deref())
+            .map_err(|e| format!("Failed to relabel history: {}", e))?;
+        Ok(())
+    }
+
+    /// Drop every assignment of a deleted speaker profile
+    pub fn unassign_speaker_profile(&self, profile_id: &str) {
+        self.speaker_profiles
+            .lock()
+            .unwrap()
+            .retain(|_, assigned| assigned != profile_id);
+    }
+
+    /// Mark the current moment of the active session as a highlight
+    pub fn mark_highlight(&self) -> Result<SessionHighlight, String> {
+        let mut current = self.current_session.lock().unwrap();
+        let session = current
+            .as_mut()
+            .ok_or_else(|| "No active listening session".to_string())?;
+
+        let timestamp = chrono::Utc::now().timestamp_millis();
+        let highlight = SessionHighlight {
+            timestamp,
+            offset_ms: (timestamp - session.started_at).max(0),
+        };
+        session.highlights.push(highlight.clone());
+        let session_id = session.id.clone();
+        drop(current);
+
+        let _ = ActiveListeningHighlight(ActiveListeningHighlightEvent {
+            session_id: session_id.clone(),
+            highlight: highlight.clone(),
+        })
+        .emit(&self.app_handle);
+
+        info!(
+            "Marked highlight in session {} at {}ms",
+            session_id, highlight.offset_ms
+        );
+        Ok(highlight)
+    }
+
     /// Push audio samples to the segment buffer
     ///
     /// This is called by the audio pipeline when in active listening mode.
@@ -452,14 +732,12 @@ impl ActiveListeningManager {
         }
 
         // Emit state change
-        let _ = self.app_handle.emit(
-            "active-listening-state-changed",
-            ActiveListeningStateEvent {
-                state: ActiveListeningState::Processing,
-                session_id: Some(session_id.clone()),
-                error: None,
-            },
-        );
+        let _ = ActiveListeningStateChanged(ActiveListeningStateEvent {
+            state: ActiveListeningState::Processing,
+            session_id: Some(session_id.clone()),
+            error: None,
+        })
+        .emit(&self.app_handle);
 
         // Process in background with captured session info
         let self_clone = ActiveListeningManagerHandle {
@@ -469,6 +747,8 @@ impl ActiveListeningManager {
             current_session: self.current_session.clone(),
             context_buffer: self.context_buffer.clone(),
             shutdown_signal: self.shutdown_signal.clone(),
+            speaker_profiles: self.speaker_profiles.clone(),
+            budget: self.budget.clone(),
         };
 
         let segment_start_instant = Instant::now();
@@ -522,6 +802,23 @@ impl ActiveListeningManager {
         }
     }
 
+    /// Summary of the running session so far, updated with the segments
+    /// transcribed since it was last asked for
+    pub async fn live_summary(&self, session_id: &str) -> Result<LiveSummary, String> {
+        let session = self
+            .get_current_session()
+            .filter(|session| session.id == session_id)
+            .ok_or_else(|| format!("Session {} is not running", session_id))?;
+        let rolling_context = self.context_buffer.lock().unwrap().clone();
+        live_summary::update(
+            &self.app_handle,
+            &self.live_summary,
+            &session,
+            &rolling_context,
+        )
+        .await
+    }
+
     /// Generate a comprehensive meeting summary from the session
     ///
     /// This method creates a structured summary including:
@@ -533,11 +830,22 @@ impl ActiveListeningManager {
     pub async fn generate_session_summary(
         &self,
         session: &ActiveListeningSession,
+    ) -> Result<MeetingSummary, String> {
+        let model = get_settings(&self.app_handle).active_listening.ollama_model;
+        self.generate_session_summary_with_model(session, &model)
+            .await
+    }
+
+    /// Generate a meeting summary with a specific Ollama model
+    pub async fn generate_session_summary_with_model(
+        &self,
+        session: &ActiveListeningSession,
+        model: &str,
     ) -> Result<MeetingSummary, String> {
         let settings = get_settings(&self.app_handle);
         let ollama_settings = &settings.active_listening;
 
-        if ollama_settings.ollama_model.is_empty() {
+        if model.is_empty() {
             return Err("No Ollama model configured".to_string());
         }
 
@@ -545,14 +853,6 @@ impl ActiveListeningManager {
             return Err("No insights to summarize".to_string());
         }
 
-        // Combine all transcriptions
-        let full_transcript = session
-            .insights
-            .iter()
-            .map(|i| i.transcription.as_str())
-            .collect::<Vec<_>>()
-            .join("\n\n");
-
         // Calculate duration
         let duration_minutes = if let Some(ended) = session.ended_at {
             ((ended - session.started_at) / 60000) as u32
@@ -561,6 +861,59 @@ impl ActiveListeningManager {
             ((now - session.started_at) / 60000) as u32
         };
 
+        // Long sessions are split into chapters so the summary follows them
+        let chapters = if duration_minutes >= CHAPTERED_SUMMARY_MINUTES {
+            match detect_chapters(&self.app_handle, session, model).await {
+                Ok(chapters) if chapters.len() > 1 => chapters,
+                Ok(_) => Vec::new(),
+                Err(e) => {
+                    warn!(
+                        "Chapter detection failed, summarizing without chapters: {}",
+                        e
+                    );
+                    Vec::new()
+                }
+            }
+        } else {
+            Vec::new()
+        };
+
+        // Combine all transcriptions
+        let full_transcript = if chapters.is_empty() {
+            session
+                .insights
+                .iter()
+                .map(SessionInsight::attributed_transcription)
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        } else {
+            chaptered_transcript(session, &chapters)
+        };
+        let chapter_note = if chapters.is_empty() {
+            ""
+        } else {
+            "\n- The transcript is split into chapters; cover each chapter in the topics, in order"
+        };
+
+        // Highlighted moments are repeated after the transcript so they weigh more
+        let highlights = highlight_excerpts(session);
+        let highlight_section = if highlights.is_empty() {
+            String::new()
+        } else {
+            let moments = highlights
+                .iter()
+                .filter(|h| !h.transcript.is_empty())
+                .map(|h| format!("- [{}] {}", format_offset(h.offset_ms), h.transcript))
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("\nHighlighted moments (marked as important during the meeting):\n{moments}\n")
+        };
+        let highlight_note = if highlights.is_empty() {
+            ""
+        } else {
+            "\n- Give the highlighted moments extra weight; make sure each is reflected in the summary"
+        };
+
         let topic = session.topic.clone().unwrap_or_else(|| "Meeting".to_string());
 
         let prompt = format!(
@@ -570,7 +923,7 @@ Meeting Topic: {topic}
 Duration: {duration_minutes} minutes
 Transcript:
 {full_transcript}
-
+{highlight_section}
 Provide a comprehensive summary in the following JSON format:
 {{
   "executive_summary": "2-3 sentence overview of the meeting",
@@ -585,22 +938,24 @@ Provide a comprehensive summary in the following JSON format:
 Important:
 - Be concise and factual
 - Only include items that were actually discussed
-- Use null for unknown assignees/deadlines
+- Use null for unknown assignees/deadlines{chapter_note}{highlight_note}
 - Return valid JSON only"#,
         );
 
         info!("Generating meeting summary for session {}", session.id);
 
-        let client = OllamaClient::new(&ollama_settings.ollama_base_url)
+        let client = OllamaClient::for_active_listening(ollama_settings)
             .map_err(|e| format!("Failed to create Ollama client: {}", e))?;
 
         let response = client
-            .generate(&ollama_settings.ollama_model, prompt)
+            .generate(model, prompt)
             .await
             .map_err(|e| format!("Ollama request failed: {}", e))?;
 
         // Parse the JSON response
-        let summary = Self::parse_summary_response(&response, session, duration_minutes)?;
+        let mut summary = Self::parse_summary_response(&response, session, duration_minutes)?;
+        summary.chapters = chapters;
+        summary.highlights = highlights;
 
         info!(
             "Generated summary with {} decisions, {} action items, {} topics",
@@ -701,6 +1056,8 @@ Important:
             follow_ups,
             duration_minutes,
             generated_at: chrono::Utc::now().timestamp_millis(),
+            chapters: Vec::new(),
+            highlights: Vec::new(),
         })
     }
 }
@@ -716,9 +1073,138 @@ struct ActiveListeningManagerHandle {
     /// Currently set on Drop but can be extended to support request cancellation.
     #[allow(dead_code)]
     shutdown_signal: Arc<AtomicBool>,
+    speaker_profiles: Arc<Mutex<HashMap<u32, String>>>,
+    budget: Arc<Mutex<BudgetTracker>>,
+}
+
+/// Label for a segment's speaker, preferring the name they were given in
+/// the session, then the assigned profile's name
+fn speaker_label(
+    speaker_id: Option<u32>,
+    name: Option<&str>,
+    profile: Option<&SpeakerProfile>,
+) -> Option<String> {
+    if let Some(name) = name {
+        return Some(name.to_string());
+    }
+    if let Some(profile) = profile {
+        return Some(profile.name.clone());
+    }
+    speaker_id.map(|id| {
+        if id == 0 {
+            "You".to_string()
+        } else {
+            format!("Speaker {}", id + 1)
+        }
+    })
+}
+
+/// Apply a speaker's own vocabulary to their segment
+fn apply_speaker_words(text: &str, profile: Option<&SpeakerProfile>, threshold: f64) -> String {
+    match profile {
+        Some(profile) if !profile.custom_words.is_empty() => {
+            apply_custom_words(text, &profile.custom_words, threshold)
+        }
+        _ => text.to_string(),
+    }
+}
+
+/// The transcription of a segment and its translation, if one was made
+struct SegmentText {
+    transcription: String,
+    translation: Option<String>,
+}
+
+/// The draft model to caption segments with, if one is set and differs
+/// from the main model
+fn draft_model<'a>(
+    settings: &'a AppSettings,
+    transcription_manager: &TranscriptionManager,
+) -> Option<&'a str> {
+    settings
+        .active_listening
+        .draft_model_id
+        .as_deref()
+        .filter(|id| !id.is_empty())
+        .filter(|id| transcription_manager.get_current_model().as_deref() != Some(*id))
 }
 
 impl ActiveListeningManagerHandle {
+    /// Transcribe a segment with the draft model. Failures only cost the
+    /// draft, so they are logged and yield an empty transcription.
+    async fn transcribe_draft(&self, samples: Vec<f32>, model_id: &str) -> String {
+        let transcription_manager = self.transcription_manager.clone();
+        let model = model_id.to_string();
+        match tauri::async_runtime::spawn_blocking(move || {
+            transcription_manager.transcribe_draft(samples, &model)
+        })
+        .await
+        {
+            Ok(Ok(text)) => text,
+            Ok(Err(e)) => {
+                warn!("Draft transcription with {} failed: {}", model_id, e);
+                String::new()
+            }
+            Err(e) => {
+                warn!("Draft transcription task failed: {}", e);
+                String::new()
+            }
+        }
+    }
+
+    /// Translate a segment for dual language output. Failures only cost the
+    /// translation, so they are logged and yield `None`.
+    async fn translate_segment(
+        &self,
+        session_id: &str,
+        samples: Vec<f32>,
+        transcription: &str,
+        settings: &AppSettings,
+    ) -> Option<String> {
+        let dual_language = &settings.active_listening.dual_language;
+        let result = match dual_language.method {
+            TranslationMethod::Whisper => {
+                let transcription_manager = self.transcription_manager.clone();
+                match tauri::async_runtime::spawn_blocking(move || {
+                    transcription_manager.translate_to_english(samples)
+                })
+                .await
+                {
+                    Ok(result) => result.map_err(|e| e.to_string()),
+                    Err(e) => Err(e.to_string()),
+                }
+            }
+            TranslationMethod::Llm => {
+                let ollama_settings = &settings.active_listening;
+                if ollama_settings.ollama_model.is_empty() {
+                    warn!("No Ollama model configured, skipping translation");
+                    return None;
+                }
+                let prompt = translation_prompt(transcription, &dual_language.target_language);
+                if !self.admit_llm_call(session_id, &prompt) {
+                    return None;
+                }
+                let result = match OllamaClient::for_active_listening(ollama_settings) {
+                    Ok(client) => client.generate(&ollama_settings.ollama_model, prompt).await,
+                    Err(e) => Err(e),
+                };
+                if let Ok(text) = &result {
+                    self.budget.lock().unwrap().record_response(text);
+                }
+                result
+            }
+        };
+
+        match result {
+            Ok(text) if !text.trim().is_empty() => Some(text.trim().to_string()),
+            Ok(_) => None,
+            Err(e) => {
+                warn!("Translating segment failed: {}", e);
+                None
+            }
+        }
+    }
+
     /// Process a segment with pre-captured session info.
     /// This version is used by trigger_segment_processing to ensure session info
     /// is captured before the async task starts, preventing race conditions with stop_session.
@@ -731,13 +1217,22 @@ impl ActiveListeningManagerHandle {
         speaker_id: Option<u32>,
     ) {
         let segment_duration_ms = segment_start.elapsed().as_millis() as u64;
-        let speaker_label = speaker_id.map(|id| {
-            if id == 0 {
-                "You".to_string()
-            } else {
-                format!("Speaker {}", id + 1)
-            }
+        let settings = get_settings(&self.app_handle);
+        let profile = speaker_id
+            .and_then(|id| self.speaker_profiles.lock().unwrap().get(&id).cloned())
+            .and_then(|profile_id| {
+                settings
+                    .active_listening
+                    .get_speaker_profile(&profile_id)
+                    .cloned()
+            });
+        let name = speaker_id.and_then(|id| {
+            let session = self.current_session.lock().unwrap();
+            session
+                .as_ref()
+                .and_then(|s| s.speaker_names.get(&id).cloned())
         });
+        let speaker_label = speaker_label(speaker_id, name.as_deref(), profile.as_ref());
         info!(
             "process_segment_with_session: session={}, {} samples, duration {}ms, speaker={:?}",
             session_id,
@@ -746,46 +1241,126 @@ impl ActiveListeningManagerHandle {
             speaker_label
         );
 
+        let samples = voice_profile::filter_to_primary_speaker(&settings, samples);
+
         // Keep a copy of samples for saving to history
         let samples_for_history = samples.clone();
 
-        // Step 1: Transcribe the segment
+        // Step 1: Transcribe the segment. The main model runs in the
+        // background so a draft model can caption the segment meanwhile.
         info!("Transcribing segment with {} samples", samples.len());
-        let transcription = match self.transcription_manager.transcribe(samples) {
-            Ok(text) => text,
-            Err(e) => {
+        let transcription_manager = self.transcription_manager.clone();
+        let main_transcription =
+            tauri::async_runtime::spawn_blocking(move || transcription_manager.transcribe(samples));
+
+        let draft_timestamp = match draft_model(&settings, &self.transcription_manager) {
+            Some(model_id) => {
+                let draft = self
+                    .transcribe_draft(samples_for_history.clone(), model_id)
+                    .await;
+                let draft = apply_speaker_words(
+                    &draft,
+                    profile.as_ref(),
+                    settings.word_correction_threshold,
+                );
+                if draft.trim().is_empty() {
+                    None
+                } else {
+                    let timestamp = chrono::Utc::now().timestamp_millis();
+                    let _ = ActiveListeningSegment(ActiveListeningSegmentEvent {
+                        session_id: session_id.clone(),
+                        transcription: draft.clone(),
+                        timestamp,
+                        speaker_id,
+                        speaker_label: speaker_label.clone(),
+                        draft: true,
+                    })
+                    .emit(&self.app_handle);
+
+                    // Suggestions follow the draft rather than wait for the main model
+                    if settings.suggestions.enabled {
+                        self.generate_suggestions(session_id.clone(), draft, topic.clone())
+                            .await;
+                    }
+                    Some(timestamp)
+                }
+            }
+            None => None,
+        };
+
+        let transcription = match main_transcription.await {
+            Ok(Ok(text)) => text,
+            Ok(Err(e)) => {
                 error!("Transcription failed: {}", e);
                 self.emit_error(&session_id, format!("Transcription failed: {}", e));
                 self.transition_to_listening();
                 return;
             }
+            Err(e) => {
+                error!("Transcription task failed: {}", e);
+                self.emit_error(&session_id, format!("Transcription failed: {}", e));
+                self.transition_to_listening();
+                return;
+            }
         };
 
+        let transcription = apply_speaker_words(
+            &transcription,
+            profile.as_ref(),
+            settings.word_correction_threshold,
+        );
         info!("Transcription result: '{}'", transcription.trim());
 
+        self.transcription_manager
+            .record_streamed_transcript(&transcription);
+
         if transcription.trim().is_empty() {
             info!("Empty transcription, skipping Ollama");
+            if let Some(timestamp) = draft_timestamp {
+                // Withdraw the draft
+                let _ = ActiveListeningSegment(ActiveListeningSegmentEvent {
+                    session_id: session_id.clone(),
+                    transcription,
+                    timestamp,
+                    speaker_id,
+                    speaker_label,
+                    draft: false,
+                })
+                .emit(&self.app_handle);
+            }
             self.transition_to_listening();
             return;
         }
 
-        let timestamp = chrono::Utc::now().timestamp_millis();
+        let timestamp = draft_timestamp.unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
+
+        // Emit segment transcription event with speaker info, replacing the
+        // draft if there was one
+        let _ = ActiveListeningSegment(ActiveListeningSegmentEvent {
+            session_id: session_id.clone(),
+            transcription: transcription.clone(),
+            timestamp,
+            speaker_id,
+            speaker_label: speaker_label.clone(),
+            draft: false,
+        })
+        .emit(&self.app_handle);
 
-        // Emit segment transcription event with speaker info
-        let _ = self.app_handle.emit(
-            "active-listening-segment",
-            ActiveListeningSegmentEvent {
-                session_id: session_id.clone(),
-                transcription: transcription.clone(),
-                timestamp,
-                speaker_id,
-                speaker_label: speaker_label.clone(),
-            },
-        );
+        // Second pass for bilingual sessions, stored next to the original
+        let translation = if settings.active_listening.dual_language.enabled {
+            self.translate_segment(
+                &session_id,
+                samples_for_history.clone(),
+                &transcription,
+                &settings,
+            )
+            .await
+        } else {
+            None
+        };
 
         // Step 2: Generate real-time suggestions (runs in parallel with insights)
-        let settings = get_settings(&self.app_handle);
-        if settings.suggestions.enabled {
+        if settings.suggestions.enabled && draft_timestamp.is_none() {
             self.generate_suggestions(
                 session_id.clone(),
                 transcription.clone(),
@@ -795,21 +1370,28 @@ impl ActiveListeningManagerHandle {
         }
 
         // Step 3: Generate insight with Ollama
-        let settings = get_settings(&self.app_handle);
         let ollama_settings = &settings.active_listening;
 
         if ollama_settings.ollama_model.is_empty() {
             warn!("No Ollama model configured, skipping insight generation");
-            self.add_insight_to_session(
+            let label = self.add_insight_to_session(
                 &session_id,
-                transcription.clone(),
+                SegmentText {
+                    transcription: transcription.clone(),
+                    translation: translation.clone(),
+                },
                 String::new(),
                 segment_duration_ms,
                 speaker_id,
                 speaker_label.clone(),
             );
             // Save to history without LLM insight
-            self.save_to_history(samples_for_history, transcription, None, None)
+            let speaker = SessionSpeaker {
+                session_id,
+                speaker_id,
+                label,
+            };
+            self.save_to_history(samples_for_history, transcription, None, None, speaker)
                 .await;
             self.transition_to_listening();
             return;
@@ -827,19 +1409,7 @@ impl ActiveListeningManagerHandle {
             .unwrap_or_else(|| "Summarize: {{transcription}}".to_string());
 
         // Get context from previous insights
-        let previous_context = {
-            let context = self.context_buffer.lock().unwrap();
-            if context.is_empty() {
-                "No previous context.".to_string()
-            } else {
-                context
-                    .iter()
-                    .enumerate()
-                    .map(|(i, c)| format!("{}. {}", i + 1, c))
-                    .collect::<Vec<_>>()
-                    .join("\n")
-            }
-        };
+        let previous_context = format_previous_context(&self.context_buffer.lock().unwrap());
 
         // Apply template
         let prompt = apply_prompt_template(
@@ -851,8 +1421,32 @@ impl ActiveListeningManagerHandle {
 
         info!("Ollama prompt: {}", prompt);
 
+        // Over budget: keep the transcription, skip the insight
+        if !self.admit_llm_call(&session_id, &prompt) {
+            let label = self.add_insight_to_session(
+                &session_id,
+                SegmentText {
+                    transcription: transcription.clone(),
+                    translation: translation.clone(),
+                },
+                String::new(),
+                segment_duration_ms,
+                speaker_id,
+                speaker_label,
+            );
+            let speaker = SessionSpeaker {
+                session_id,
+                speaker_id,
+                label,
+            };
+            self.save_to_history(samples_for_history, transcription, None, None, speaker)
+                .await;
+            self.transition_to_listening();
+            return;
+        }
+
         // Call Ollama with streaming
-        let client = match OllamaClient::new(&ollama_settings.ollama_base_url) {
+        let client = match OllamaClient::for_active_listening(ollama_settings) {
             Ok(c) => c,
             Err(e) => {
                 error!("Failed to create Ollama client: {}", e);
@@ -860,39 +1454,46 @@ impl ActiveListeningManagerHandle {
                 return;
             }
         };
-        let (tx, mut rx) = mpsc::channel::<String>(100);
-
-        let session_id_clone = session_id.clone();
-        let app_handle_clone = self.app_handle.clone();
-
-        // Spawn task to forward stream chunks to frontend
-        let stream_forward_handle = tauri::async_runtime::spawn(async move {
-            let mut full_response = String::new();
-            while let Some(chunk) = rx.recv().await {
-                full_response.push_str(&chunk);
-                let _ = app_handle_clone.emit(
-                    "active-listening-insight",
-                    ActiveListeningInsightEvent {
-                        session_id: session_id_clone.clone(),
-                        chunk,
-                        done: false,
-                    },
-                );
+        // With filters on, each insight is held back until it passes them, so
+        // rejected ones are never shown
+        let filters = &ollama_settings.insight_filters;
+        let mut attempt_prompt = prompt.clone();
+        let mut attempt = 0;
+        let (ollama_result, insight) = loop {
+            let (result, insight) = self
+                .generate_insight(
+                    &client,
+                    &ollama_settings.ollama_model,
+                    attempt_prompt.clone(),
+                    &session_id,
+                    !filters.enabled,
+                )
+                .await;
+            if !filters.enabled || result.is_err() || insight.is_empty() {
+                break (result, insight);
             }
-            full_response
-        });
 
-        // Call Ollama
-        let ollama_result = client
-            .generate_stream(&ollama_settings.ollama_model, prompt, tx)
-            .await;
-
-        // Wait for stream forwarding to complete
-        let insight = match stream_forward_handle.await {
-            Ok(text) => text,
-            Err(e) => {
-                error!("Stream forward task failed: {}", e);
-                String::new()
+            match insight_filter::check(&client, &insight, &transcription, filters).await {
+                Ok(()) => {
+                    let _ = ActiveListeningInsight(ActiveListeningInsightEvent {
+                        session_id: session_id.clone(),
+                        chunk: insight.clone(),
+                        done: false,
+                    })
+                    .emit(&self.app_handle);
+                    break (result, insight);
+                }
+                Err(rejection) => {
+                    info!("Dropped insight ({}), attempt {}", rejection, attempt + 1);
+                    self.budget.lock().unwrap().record_response(&insight);
+                    attempt_prompt = insight_filter::retry_prompt(&prompt);
+                    if attempt >= filters.max_retries
+                        || !self.admit_llm_call(&session_id, &attempt_prompt)
+                    {
+                        break (result, String::new());
+                    }
+                    attempt += 1;
+                }
             }
         };
 
@@ -903,15 +1504,15 @@ impl ActiveListeningManagerHandle {
         );
         match ollama_result {
             Ok(_) => {
+                self.budget.lock().unwrap().record_response(&insight);
+
                 // Emit done signal
-                let _ = self.app_handle.emit(
-                    "active-listening-insight",
-                    ActiveListeningInsightEvent {
-                        session_id: session_id.clone(),
-                        chunk: String::new(),
-                        done: true,
-                    },
-                );
+                let _ = ActiveListeningInsight(ActiveListeningInsightEvent {
+                    session_id: session_id.clone(),
+                    chunk: String::new(),
+                    done: true,
+                })
+                .emit(&self.app_handle);
 
                 // Add to context buffer
                 if !insight.is_empty() {
@@ -926,9 +1527,12 @@ impl ActiveListeningManagerHandle {
                 }
 
                 // Add insight to session (session might be stopped, but that's okay)
-                self.add_insight_to_session(
+                let label = self.add_insight_to_session(
                     &session_id,
-                    transcription.clone(),
+                    SegmentText {
+                        transcription: transcription.clone(),
+                        translation: translation.clone(),
+                    },
                     insight.clone(),
                     segment_duration_ms,
                     speaker_id,
@@ -942,27 +1546,41 @@ impl ActiveListeningManagerHandle {
                     Some(insight)
                 };
                 info!("Saving to history with insight: {:?}", post_processed);
+                let speaker = SessionSpeaker {
+                    session_id,
+                    speaker_id,
+                    label,
+                };
                 self.save_to_history(
                     samples_for_history,
                     transcription,
                     post_processed,
                     Some(prompt_template),
+                    speaker,
                 )
                 .await;
             }
             Err(e) => {
                 error!("Ollama generation failed: {}", e);
                 // Still save the transcription without insight
-                self.add_insight_to_session(
+                let label = self.add_insight_to_session(
                     &session_id,
-                    transcription.clone(),
+                    SegmentText {
+                        transcription: transcription.clone(),
+                        translation: translation.clone(),
+                    },
                     String::new(),
                     segment_duration_ms,
                     speaker_id,
                     speaker_label,
                 );
                 // Save to history without LLM insight
-                self.save_to_history(samples_for_history, transcription, None, None)
+                let speaker = SessionSpeaker {
+                    session_id,
+                    speaker_id,
+                    label,
+                };
+                self.save_to_history(samples_for_history, transcription, None, None, speaker)
                     .await;
             }
         }
@@ -970,6 +1588,87 @@ impl ActiveListeningManagerHandle {
         self.transition_to_listening();
     }
 
+    /// Generate an insight for `prompt`, streaming its chunks to the frontend
+    /// when `stream` is set. Returns the generation result and the full text.
+    async fn generate_insight(
+        &self,
+        client: &OllamaClient,
+        model: &str,
+        prompt: String,
+        session_id: &str,
+        stream: bool,
+    ) -> (Result<String, String>, String) {
+        let (tx, mut rx) = mpsc::channel::<String>(100);
+
+        let session_id_clone = session_id.to_string();
+        let app_handle_clone = self.app_handle.clone();
+
+        // Spawn task to forward stream chunks to frontend
+        let stream_forward_handle = tauri::async_runtime::spawn(async move {
+            let mut full_response = String::new();
+            while let Some(chunk) = rx.recv().await {
+                full_response.push_str(&chunk);
+                if stream {
+                    let _ = ActiveListeningInsight(ActiveListeningInsightEvent {
+                        session_id: session_id_clone.clone(),
+                        chunk,
+                        done: false,
+                    })
+                    .emit(&app_handle_clone);
+                }
+            }
+            full_response
+        });
+
+        // Call Ollama, giving up on the insight if it takes longer than
+        // the budget allows for a segment
+        let generation = client.generate_stream(model, prompt, tx);
+        let segment_timeout = self.budget.lock().unwrap().segment_timeout();
+        let ollama_result = match segment_timeout {
+            Some(limit) => match tokio::time::timeout(limit, generation).await {
+                Ok(result) => result,
+                Err(_) => {
+                    self.report_budget_exceeded(session_id, BudgetLimit::SegmentLatency);
+                    Err(format!("Insight took longer than {}s", limit.as_secs()))
+                }
+            },
+            None => generation.await,
+        };
+
+        // Wait for stream forwarding to complete
+        let insight = match stream_forward_handle.await {
+            Ok(text) => text,
+            Err(e) => {
+                error!("Stream forward task failed: {}", e);
+                String::new()
+            }
+        };
+        (ollama_result, insight)
+    }
+
+    /// Count an LLM call against the session budget. Returns false, reporting
+    /// the budget running out the first time, if the call must be skipped.
+    fn admit_llm_call(&self, session_id: &str, prompt: &str) -> bool {
+        let admission = self.budget.lock().unwrap().admit(prompt);
+        match admission {
+            Admission::Allowed => true,
+            Admission::Exceeded(limit) => {
+                self.report_budget_exceeded(session_id, limit);
+                false
+            }
+            Admission::Exhausted => false,
+        }
+    }
+
+    fn report_budget_exceeded(&self, session_id: &str, limit: BudgetLimit) {
+        let event = self.budget.lock().unwrap().event(session_id, limit);
+        warn!(
+            "Session {} reached its {:?} budget after {} LLM calls and ~{} tokens",
+            session_id, limit, event.llm_calls, event.tokens
+        );
+        let _ = ActiveListeningBudgetExceeded(event).emit(&self.app_handle);
+    }
+
     /// Save transcription and audio to history
     async fn save_to_history(
         &self,
@@ -977,14 +1676,16 @@ impl ActiveListeningManagerHandle {
         transcription: String,
         post_processed_text: Option<String>,
         post_process_prompt: Option<String>,
+        speaker: SessionSpeaker,
     ) {
         let history_manager = self.app_handle.state::<Arc<HistoryManager>>();
         if let Err(e) = history_manager
-            .save_transcription(
+            .save_session_segment(
                 audio_samples,
                 transcription,
                 post_processed_text,
                 post_process_prompt,
+                speaker,
             )
             .await
         {
@@ -1006,26 +1707,22 @@ impl ActiveListeningManagerHandle {
             *state = ActiveListeningState::Listening;
             drop(state);
 
-            let _ = self.app_handle.emit(
-                "active-listening-state-changed",
-                ActiveListeningStateEvent {
-                    state: ActiveListeningState::Listening,
-                    session_id,
-                    error: None,
-                },
-            );
+            let _ = ActiveListeningStateChanged(ActiveListeningStateEvent {
+                state: ActiveListeningState::Listening,
+                session_id,
+                error: None,
+            })
+            .emit(&self.app_handle);
         }
     }
 
     fn emit_error(&self, session_id: &str, error: String) {
-        let _ = self.app_handle.emit(
-            "active-listening-state-changed",
-            ActiveListeningStateEvent {
-                state: ActiveListeningState::Error,
-                session_id: Some(session_id.to_string()),
-                error: Some(error),
-            },
-        );
+        let _ = ActiveListeningStateChanged(ActiveListeningStateEvent {
+            state: ActiveListeningState::Error,
+            session_id: Some(session_id.to_string()),
+            error: Some(error),
+        })
+        .emit(&self.app_handle);
     }
 
     /// Generate and emit suggestions based on the transcribed segment
@@ -1052,13 +1749,46 @@ impl ActiveListeningManagerHandle {
                 }
             };
 
+            // Transcripts of earlier segments for quick response matching
+            let recent_transcriptions = {
+                let window = get_settings(&self.app_handle)
+                    .suggestions
+                    .quick_response_context_segments
+                    .saturating_sub(1);
+                let session = self.current_session.lock().unwrap();
+                session
+                    .as_ref()
+                    .map(|s| {
+                        let skip = s.insights.len().saturating_sub(window);
+                        s.insights
+                            .iter()
+                            .skip(skip)
+                            .map(|insight| insight.transcription.clone())
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default()
+            };
+
             let context = SuggestionContext {
                 transcription,
                 previous_context,
+                recent_transcriptions,
                 session_topic: topic,
                 session_id: session_id.clone(),
             };
 
+            // LLM suggestions count against the session budget
+            let llm_suggestions = get_settings(&self.app_handle)
+                .suggestions
+                .llm_suggestions_enabled;
+            if llm_suggestions {
+                let prompt = format!("{}\n{}", context.transcription, context.previous_context);
+                if !self.admit_llm_call(&session_id, &prompt) {
+                    debug!("Session {} is over budget", session_id);
+                    return;
+                }
+            }
+
             // Generate suggestions
             let suggestions = engine.get_suggestions(&context).await;
 
@@ -1075,46 +1805,45 @@ impl ActiveListeningManagerHandle {
     fn add_insight_to_session(
         &self,
         session_id: &str,
-        transcription: String,
+        text: SegmentText,
         insight: String,
         duration_ms: u64,
         speaker_id: Option<u32>,
         speaker_label: Option<String>,
-    ) {
+    ) -> Option<String> {
         // Store transcription for later indexing
-        let transcription_for_rag = transcription.clone();
+        let transcription_for_rag = text.transcription.clone();
         let session_id_for_rag = session_id.to_string();
         let app_handle = self.app_handle.clone();
 
+        let mut speaker_label = speaker_label;
         let mut session_guard = self.current_session.lock().unwrap();
         if let Some(ref mut session) = *session_guard {
             if session.id == session_id {
+                // The speaker may have been renamed while the segment was processed
+                if let Some(name) = speaker_id.and_then(|id| session.speaker_names.get(&id)) {
+                    speaker_label = Some(name.clone());
+                }
                 session.insights.push(SessionInsight {
                     timestamp: chrono::Utc::now().timestamp_millis(),
-                    transcription,
+                    transcription: text.transcription,
                     insight,
                     duration_ms,
                     speaker_id,
-                    speaker_label,
+                    speaker_label: speaker_label.clone(),
+                    translation: text.translation,
                 });
             }
         }
         drop(session_guard);
 
-        // Index transcription in knowledge base if enabled
-        // Do this asynchronously to not block the main flow
-        tokio::spawn(async move {
-            Self::maybe_index_transcription(&app_handle, &transcription_for_rag, &session_id_for_rag)
-                .await;
-        });
+        // Queue transcription for background indexing in the knowledge base
+        Self::maybe_index_transcription(&app_handle, &transcription_for_rag, &session_id_for_rag);
+        speaker_label
     }
 
-    /// Index a transcription in the knowledge base if auto-indexing is enabled
-    async fn maybe_index_transcription(
-        app_handle: &AppHandle,
-        transcription: &str,
-        session_id: &str,
-    ) {
+    /// Queue a transcription for knowledge base indexing if auto-indexing is enabled
+    fn maybe_index_transcription(app_handle: &AppHandle, transcription: &str, session_id: &str) {
         // Skip empty transcriptions
         if transcription.trim().is_empty() {
             return;
@@ -1126,16 +1855,15 @@ impl ActiveListeningManagerHandle {
             return;
         }
 
-        // Get the RAG manager from app state
-        let rag_manager = match app_handle.try_state::<Arc<RagManager>>() {
-            Some(manager) => manager,
+        // Get the indexing queue from app state
+        let indexing_queue = match app_handle.try_state::<Arc<IndexingQueue>>() {
+            Some(queue) => queue,
             None => {
-                debug!("RAG manager not available, skipping transcription indexing");
+                debug!("Indexing queue not available, skipping transcription indexing");
                 return;
             }
         };
 
-        // Index the transcription
         let metadata = DocMetadata {
             source_type: "transcription".to_string(),
             source_id: Some(session_id.to_string()),
@@ -1146,15 +1874,21 @@ impl ActiveListeningManagerHandle {
             extra: None,
         };
 
-        match rag_manager.add_document(transcription, metadata).await {
-            Ok(doc_id) => {
+        match indexing_queue.enqueue(transcription, metadata) {
+            Ok(true) => {
                 debug!(
-                    "Indexed transcription as document {} for session {}",
-                    doc_id, session_id
+                    "Queued transcription for indexing for session {}",
+                    session_id
                 );
             }
+            Ok(false) => {
+                debug!("Skipped duplicate transcription for session {}", session_id);
+            }
             Err(e) => {
-                warn!("Failed to index transcription in knowledge base: {}", e);
+                warn!(
+                    "Failed to queue transcription for knowledge base indexing: {}",
+                    e
+                );
             }
         }
     }
@@ -1186,6 +1920,7 @@ mod tests {
             duration_ms: 5000,
             speaker_id: Some(0),
             speaker_label: Some("You".to_string()),
+            translation: None,
         };
 
         assert_eq!(insight.timestamp, 1234567890);
@@ -1204,6 +1939,8 @@ mod tests {
             ended_at: Some(2000000),
             topic: Some("Test Topic".to_string()),
             insights: vec![],
+            highlights: Vec::new(),
+            speaker_names: HashMap::new(),
         };
 
         assert_eq!(session.id, "test_session_123");
@@ -1221,6 +1958,7 @@ mod tests {
             timestamp: 123456789,
             speaker_id: Some(1),
             speaker_label: Some("Speaker 2".to_string()),
+            draft: false,
         };
 
         assert_eq!(event.session_id, "session_1");
@@ -1313,6 +2051,7 @@ mod tests {
                 duration_ms: 5000,
                 speaker_id: Some(0),
                 speaker_label: Some("You".to_string()),
+                translation: None,
             },
             SessionInsight {
                 timestamp: 2000,
@@ -1321,6 +2060,7 @@ mod tests {
                 duration_ms: 3000,
                 speaker_id: Some(1),
                 speaker_label: Some("Speaker 2".to_string()),
+                translation: None,
             },
         ];
 
@@ -1330,6 +2070,8 @@ mod tests {
             ended_at: None,
             topic: Some("Test Topic".to_string()),
             insights,
+            highlights: Vec::new(),
+            speaker_names: HashMap::new(),
         };
 
         assert_eq!(session.insights.len(), 2);
@@ -1353,7 +2095,10 @@ mod tests {
                 duration_ms: 1000,
                 speaker_id: None,
                 speaker_label: None,
+                translation: None,
             }],
+            highlights: Vec::new(),
+            speaker_names: HashMap::new(),
         };
 
         let cloned = session.clone();
@@ -1364,4 +2109,65 @@ mod tests {
         assert_eq!(session.topic, cloned.topic);
         assert_eq!(session.insights.len(), cloned.insights.len());
     }
+
+    #[test]
+    fn test_format_previous_context() {
+        assert_eq!(
+            format_previous_context(&VecDeque::new()),
+            "No previous context."
+        );
+        let context = VecDeque::from(vec!["First".to_string(), "Second".to_string()]);
+        assert_eq!(format_previous_context(&context), "1. First\n2. Second");
+    }
+
+    #[test]
+    fn test_speaker_profile_labels_and_corrects_segments() {
+        let profile = SpeakerProfile {
+            id: "sp_1".to_string(),
+            name: "Dana".to_string(),
+            custom_words: vec!["Kubernetes".to_string()],
+            default_speaker_id: Some(2),
+        };
+
+        assert_eq!(speaker_label(Some(0), None, None), Some("You".to_string()));
+        assert_eq!(
+            speaker_label(Some(2), None, None),
+            Some("Speaker 3".to_string())
+        );
+        assert_eq!(
+            speaker_label(Some(2), None, Some(&profile)),
+            Some("Dana".to_string())
+        );
+        assert_eq!(
+            speaker_label(Some(2), Some("Dana K."), Some(&profile)),
+            Some("Dana K.".to_string())
+        );
+        assert_eq!(speaker_label(None, None, None), None);
+
+        assert_eq!(
+            apply_speaker_words("we deploy on kubernetis", Some(&profile), 0.18),
+            "we deploy on Kubernetes"
+        );
+        assert_eq!(
+            apply_speaker_words("we deploy on kubernetis", None, 0.18),
+            "we deploy on kubernetis"
+        );
+    }
+
+    #[test]
+    fn test_attributed_transcription_names_the_speaker() {
+        let mut insight = SessionInsight {
+            timestamp: 0,
+            transcription: "Ship it Friday".to_string(),
+            insight: String::new(),
+            duration_ms: 1000,
+            speaker_id: Some(1),
+            speaker_label: Some("Dana".to_string()),
+            translation: None,
+        };
+        assert_eq!(insight.attributed_transcription(), "Dana: Ship it Friday");
+
+        insight.speaker_label = None;
+        assert_eq!(insight.attributed_transcription(), "Ship it Friday");
+    }
 }
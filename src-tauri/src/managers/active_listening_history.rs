@@ -0,0 +1,653 @@
+//! Active Listening History Manager
+//!
+//! Persists finished Active Listening sessions, with their insights and
+//! generated summaries, so they can be browsed after the app restarts.
+
+use anyhow::Result;
+use log::{debug, info};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::PathBuf;
+use tauri::AppHandle;
+
+use super::active_listening::{ActiveListeningSession, MeetingSummary, SessionInsight};
+
+/// Characters of transcript shown on either side of a search match
+const EXCERPT_RADIUS: usize = 60;
+
+/// A past session as listed in the session browser
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct StoredSessionInfo {
+    pub id: String,
+    pub started_at: i64,
+    pub ended_at: Option<i64>,
+    pub topic: Option<String>,
+    pub insight_count: u32,
+    pub has_summary: bool,
+    /// Transcript around the first match, for sessions found by a search
+    pub excerpt: Option<String>,
+}
+
+/// A past session with the summary generated for it, if any
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct StoredSession {
+    pub session: ActiveListeningSession,
+    pub summary: Option<MeetingSummary>,
+}
+
+/// A stored segment containing some of the terms searched for
+#[derive(Clone, Debug, PartialEq)]
+pub struct SegmentMatch {
+    pub session_id: String,
+    /// Index of the segment's insight in its session
+    pub segment_index: usize,
+    /// How many of the terms the transcription contains
+    pub matched_terms: usize,
+}
+
+/// Manages Active Listening session persistence
+pub struct ActiveListeningHistoryManager {
+    db_path: PathBuf,
+}
+
+impl ActiveListeningHistoryManager {
+    /// Create a new ActiveListeningHistoryManager
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let app_data_dir = crate::portable::app_data_dir(app_handle)?;
+        Ok(Self {
+            db_path: app_data_dir.join("history.db"),
+        })
+    }
+
+    fn get_connection(&self) -> Result<Connection> {
+        Ok(Connection::open(&self.db_path)?)
+    }
+
+    /// Save a session and all its insights, replacing any earlier copy
+    pub fn save_session(&self, session: &ActiveListeningSession) -> Result<()> {
+        let mut conn = self.get_connection()?;
+        save_session_with_conn(&mut conn, session)?;
+        debug!(
+            "Saved Active Listening session {} with {} insights",
+            session.id,
+            session.insights.len()
+        );
+        Ok(())
+    }
+
+    /// Attach a generated summary to its stored session. Returns false if
+    /// the session isn't stored.
+    pub fn save_summary(&self, summary: &MeetingSummary) -> Result<bool> {
+        let conn = self.get_connection()?;
+        save_summary_with_conn(&conn, summary)
+    }
+
+    /// Get a stored session by ID
+    pub fn get_session(&self, id: &str) -> Result<Option<StoredSession>> {
+        let conn = self.get_connection()?;
+        get_session_with_conn(&conn, id)
+    }
+
+    /// List stored sessions, most recent first
+    pub fn list_sessions(&self, limit: usize) -> Result<Vec<StoredSessionInfo>> {
+        let conn = self.get_connection()?;
+        list_sessions_with_conn(&conn, limit)
+    }
+
+    /// Find sessions whose topic, transcript, insights or summary contain
+    /// the query, most recent first
+    pub fn search_sessions(&self, query: &str, limit: usize) -> Result<Vec<StoredSessionInfo>> {
+        let conn = self.get_connection()?;
+        search_sessions_with_conn(&conn, query, limit)
+    }
+
+    /// Find segments whose transcription contains any of `terms`, in
+    /// sessions started between `since` and `until` (milliseconds). Segments
+    /// matching more terms come first, then more recent ones.
+    pub fn search_segments(
+        &self,
+        terms: &[String],
+        since: Option<i64>,
+        until: Option<i64>,
+        limit: usize,
+    ) -> Result<Vec<SegmentMatch>> {
+        let conn = self.get_connection()?;
+        search_segments_with_conn(&conn, terms, since, until, limit)
+    }
+
+    /// Keep the encrypted names behind an anonymized session's placeholders
+    pub fn save_name_map(&self, session_id: &str, nonce: &[u8], names: &[u8]) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "INSERT OR REPLACE INTO active_listening_name_maps (session_id, nonce, names)
+             VALUES (?1, ?2, ?3)",
+            params![session_id, nonce, names],
+        )?;
+        Ok(())
+    }
+
+    /// The nonce and encrypted names of an anonymized session, if it was
+    /// anonymized
+    pub fn get_name_map(&self, session_id: &str) -> Result<Option<(Vec<u8>, Vec<u8>)>> {
+        let conn = self.get_connection()?;
+        Ok(conn
+            .query_row(
+                "SELECT nonce, names FROM active_listening_name_maps WHERE session_id = ?1",
+                [session_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?)
+    }
+
+    /// Delete a stored session, its insights and its name map
+    pub fn delete_session(&self, id: &str) -> Result<()> {
+        let mut conn = self.get_connection()?;
+        if delete_session_with_conn(&mut conn, id)? {
+            info!("Deleted Active Listening session {}", id);
+        } else {
+            debug!("Active Listening session {} not found for deletion", id);
+        }
+        Ok(())
+    }
+}
+
+fn save_session_with_conn(conn: &mut Connection, session: &ActiveListeningSession) -> Result<()> {
+    let tx = conn.transaction()?;
+
+    // Keep a summary saved by an earlier copy of the session
+    tx.execute(
+        "INSERT INTO active_listening_sessions (id, started_at, ended_at, topic, highlights, speaker_names)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+         ON CONFLICT(id) DO UPDATE SET
+            started_at = excluded.started_at,
+            ended_at = excluded.ended_at,
+            topic = excluded.topic,
+            highlights = excluded.highlights,
+            speaker_names = excluded.speaker_names",
+        params![
+            session.id,
+            session.started_at,
+            session.ended_at,
+            session.topic,
+            serde_json::to_string(&session.highlights)?,
+            serde_json::to_string(&session.speaker_names)?
+        ],
+    )?;
+
+    tx.execute(
+        "DELETE FROM active_listening_insights WHERE session_id = ?1",
+        params![session.id],
+    )?;
+    for (order, insight) in session.insights.iter().enumerate() {
+        tx.execute(
+            "INSERT INTO active_listening_insights (session_id, insight_order, timestamp, transcription, insight, duration_ms, speaker_id, speaker_label, translation)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                session.id,
+                order as i64,
+                insight.timestamp,
+                insight.transcription,
+                insight.insight,
+                insight.duration_ms as i64,
+                insight.speaker_id,
+                insight.speaker_label,
+                insight.translation
+            ],
+        )?;
+    }
+
+    tx.commit()?;
+    Ok(())
+}
+
+fn save_summary_with_conn(conn: &Connection, summary: &MeetingSummary) -> Result<bool> {
+    let updated = conn.execute(
+        "UPDATE active_listening_sessions SET summary = ?1 WHERE id = ?2",
+        params![serde_json::to_string(summary)?, summary.session_id],
+    )?;
+    Ok(updated > 0)
+}
+
+fn get_session_with_conn(conn: &Connection, id: &str) -> Result<Option<StoredSession>> {
+    let row = conn
+        .query_row(
+            "SELECT id, started_at, ended_at, topic, highlights, speaker_names, summary
+             FROM active_listening_sessions WHERE id = ?1",
+            [id],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, Option<i64>>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, String>(5)?,
+                    row.get::<_, Option<String>>(6)?,
+                ))
+            },
+        )
+        .optional()?;
+
+    let Some((id, started_at, ended_at, topic, highlights, speaker_names, summary)) = row else {
+        return Ok(None);
+    };
+
+    let mut stmt = conn.prepare(
+        "SELECT timestamp, transcription, insight, duration_ms, speaker_id, speaker_label, translation
+         FROM active_listening_insights
+         WHERE session_id = ?1
+         ORDER BY insight_order ASC",
+    )?;
+    let insights = stmt
+        .query_map([&id], |row| {
+            Ok(SessionInsight {
+                timestamp: row.get(0)?,
+                transcription: row.get(1)?,
+                insight: row.get(2)?,
+                duration_ms: row.get::<_, i64>(3)? as u64,
+                speaker_id: row.get(4)?,
+                speaker_label: row.get(5)?,
+                translation: row.get(6)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(Some(StoredSession {
+        session: ActiveListeningSession {
+            id,
+            started_at,
+            ended_at,
+            topic,
+            insights,
+            highlights: serde_json::from_str(&highlights).unwrap_or_default(),
+            speaker_names: serde_json::from_str(&speaker_names).unwrap_or_default(),
+        },
+        summary: summary.and_then(|json| serde_json::from_str(&json).ok()),
+    }))
+}
+
+const SESSION_INFO_COLUMNS: &str = "s.id, s.started_at, s.ended_at, s.topic,
+    (SELECT COUNT(*) FROM active_listening_insights i WHERE i.session_id = s.id),
+    s.summary IS NOT NULL";
+
+fn row_to_info(row: &rusqlite::Row) -> rusqlite::Result<StoredSessionInfo> {
+    Ok(StoredSessionInfo {
+        id: row.get(0)?,
+        started_at: row.get(1)?,
+        ended_at: row.get(2)?,
+        topic: row.get(3)?,
+        insight_count: row.get(4)?,
+        has_summary: row.get(5)?,
+        excerpt: None,
+    })
+}
+
+fn list_sessions_with_conn(conn: &Connection, limit: usize) -> Result<Vec<StoredSessionInfo>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM active_listening_sessions s
+         ORDER BY s.started_at DESC
+         LIMIT ?1",
+        SESSION_INFO_COLUMNS
+    ))?;
+    let sessions = stmt
+        .query_map([limit as i64], row_to_info)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(sessions)
+}
+
+fn search_sessions_with_conn(
+    conn: &Connection,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<StoredSessionInfo>> {
+    let query = query.trim();
+    if query.is_empty() {
+        return list_sessions_with_conn(conn, limit);
+    }
+    let pattern = like_pattern(query);
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM active_listening_sessions s
+         WHERE s.topic LIKE ?1 ESCAPE '\\'
+            OR s.summary LIKE ?1 ESCAPE '\\'
+            OR EXISTS (
+                SELECT 1 FROM active_listening_insights i
+                WHERE i.session_id = s.id
+                  AND (i.transcription LIKE ?1 ESCAPE '\\' OR i.insight LIKE ?1 ESCAPE '\\')
+            )
+         ORDER BY s.started_at DESC
+         LIMIT ?2",
+        SESSION_INFO_COLUMNS
+    ))?;
+    let mut sessions = stmt
+        .query_map(params![pattern, limit as i64], row_to_info)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut excerpt_stmt = conn.prepare(
+        "SELECT transcription FROM active_listening_insights
+         WHERE session_id = ?1 AND transcription LIKE ?2 ESCAPE '\\'
+         ORDER BY insight_order ASC
+         LIMIT 1",
+    )?;
+    for session in &mut sessions {
+        let transcription: Option<String> = excerpt_stmt
+            .query_row(params![session.id, pattern], |row| row.get(0))
+            .optional()?;
+        session.excerpt = transcription.and_then(|text| excerpt(&text, query));
+    }
+
+    Ok(sessions)
+}
+
+fn search_segments_with_conn(
+    conn: &Connection,
+    terms: &[String],
+    since: Option<i64>,
+    until: Option<i64>,
+    limit: usize,
+) -> Result<Vec<SegmentMatch>> {
+    if terms.is_empty() {
+        return Ok(Vec::new());
+    }
+    let any_term = (0..terms.len())
+        .map(|i| format!("i.transcription LIKE ?{} ESCAPE '\\'", i + 3))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    let mut stmt = conn.prepare(&format!(
+        "SELECT i.session_id, i.insight_order, i.transcription
+         FROM active_listening_insights i
+         JOIN active_listening_sessions s ON s.id = i.session_id
+         WHERE (?1 IS NULL OR s.started_at >= ?1)
+           AND (?2 IS NULL OR s.started_at < ?2)
+           AND ({})
+         ORDER BY i.timestamp DESC",
+        any_term
+    ))?;
+
+    let mut values: Vec<Box<dyn rusqlite::types::ToSql>> = vec![Box::new(since), Box::new(until)];
+    values.extend(
+        terms
+            .iter()
+            .map(|term| Box::new(like_pattern(term)) as Box<dyn rusqlite::types::ToSql>),
+    );
+    let lower_terms: Vec<String> = terms.iter().map(|term| term.to_lowercase()).collect();
+    let mut matches = stmt
+        .query_map(rusqlite::params_from_iter(values.iter()), |row| {
+            let transcription = row.get::<_, String>(2)?.to_lowercase();
+            Ok(SegmentMatch {
+                session_id: row.get(0)?,
+                segment_index: row.get::<_, i64>(1)? as usize,
+                matched_terms: lower_terms
+                    .iter()
+                    .filter(|term| transcription.contains(term.as_str()))
+                    .count(),
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    // Stable, so equally good matches stay most recent first
+    matches.sort_by(|a, b| b.matched_terms.cmp(&a.matched_terms));
+    matches.truncate(limit);
+    Ok(matches)
+}
+
+fn delete_session_with_conn(conn: &mut Connection, id: &str) -> Result<bool> {
+    let tx = conn.transaction()?;
+    tx.execute(
+        "DELETE FROM active_listening_insights WHERE session_id = ?1",
+        params![id],
+    )?;
+    tx.execute(
+        "DELETE FROM active_listening_name_maps WHERE session_id = ?1",
+        params![id],
+    )?;
+    let deleted = tx.execute(
+        "DELETE FROM active_listening_sessions WHERE id = ?1",
+        params![id],
+    )?;
+    tx.commit()?;
+    Ok(deleted > 0)
+}
+
+/// A LIKE pattern matching the query anywhere, with wildcards in it escaped
+pub(crate) fn like_pattern(query: &str) -> String {
+    let mut pattern = String::from("%");
+    for c in query.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
+
+/// The text around the first case-insensitive match of the query
+fn excerpt(text: &str, query: &str) -> Option<String> {
+    let lower = text.to_lowercase();
+    // Lowercasing can change byte lengths, so only use the match position
+    // when it maps back onto the original text
+    let start = lower.find(&query.to_lowercase())?;
+    if lower.len() != text.len() || !text.is_char_boundary(start) {
+        return Some(text.chars().take(EXCERPT_RADIUS * 2).collect());
+    }
+
+    let from = text[..start]
+        .char_indices()
+        .rev()
+        .nth(EXCERPT_RADIUS - 1)
+        .map_or(0, |(i, _)| i);
+    let to = text[start..]
+        .char_indices()
+        .nth(query.chars().count() + EXCERPT_RADIUS)
+        .map_or(text.len(), |(i, _)| start + i);
+
+    let mut excerpt = text[from..to].trim().to_string();
+    if from > 0 {
+        excerpt.insert(0, '…');
+    }
+    if to < text.len() {
+        excerpt.push('…');
+    }
+    Some(excerpt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn setup_conn() -> Connection {
+        let conn = Connection::open_in_memory().expect("open in-memory db");
+        conn.execute_batch(
+            "CREATE TABLE active_listening_sessions (
+                id TEXT PRIMARY KEY,
+                started_at INTEGER NOT NULL,
+                ended_at INTEGER,
+                topic TEXT,
+                highlights TEXT NOT NULL DEFAULT '[]',
+                speaker_names TEXT NOT NULL DEFAULT '{}',
+                summary TEXT
+            );
+            CREATE TABLE active_listening_insights (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                session_id TEXT NOT NULL,
+                insight_order INTEGER NOT NULL,
+                timestamp INTEGER NOT NULL,
+                transcription TEXT NOT NULL,
+                insight TEXT NOT NULL,
+                duration_ms INTEGER NOT NULL,
+                speaker_id INTEGER,
+                speaker_label TEXT,
+                translation TEXT
+            );
+            CREATE TABLE active_listening_name_maps (
+                session_id TEXT PRIMARY KEY,
+                nonce BLOB NOT NULL,
+                names BLOB NOT NULL
+            );",
+        )
+        .expect("create tables");
+        conn
+    }
+
+    fn session(id: &str, started_at: i64, lines: &[&str]) -> ActiveListeningSession {
+        ActiveListeningSession {
+            id: id.to_string(),
+            started_at,
+            ended_at: Some(started_at + 60_000),
+            topic: Some(format!("Topic {}", id)),
+            insights: lines
+                .iter()
+                .enumerate()
+                .map(|(i, line)| SessionInsight {
+                    timestamp: started_at + i as i64 * 1000,
+                    transcription: line.to_string(),
+                    insight: String::new(),
+                    duration_ms: 1000,
+                    speaker_id: Some(1),
+                    speaker_label: Some("Alex".to_string()),
+                    translation: None,
+                })
+                .collect(),
+            highlights: Vec::new(),
+            speaker_names: HashMap::from([(1, "Alex".to_string())]),
+        }
+    }
+
+    fn summary(session_id: &str) -> MeetingSummary {
+        MeetingSummary {
+            session_id: session_id.to_string(),
+            executive_summary: "Agreed on the launch date".to_string(),
+            decisions: Vec::new(),
+            action_items: Vec::new(),
+            topics: Vec::new(),
+            follow_ups: Vec::new(),
+            duration_minutes: 1,
+            generated_at: 0,
+            chapters: Vec::new(),
+            highlights: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn sessions_round_trip_with_summary() {
+        let mut conn = setup_conn();
+        let mut original = session("al_1", 1_000, &["Hello there", "Let's start"]);
+        original.insights[0].translation = Some("Hallo zusammen".to_string());
+        save_session_with_conn(&mut conn, &original).unwrap();
+        assert!(!save_summary_with_conn(&conn, &summary("al_missing")).unwrap());
+        assert!(save_summary_with_conn(&conn, &summary("al_1")).unwrap());
+
+        // Saving the session again keeps its summary
+        save_session_with_conn(&mut conn, &original).unwrap();
+
+        let stored = get_session_with_conn(&conn, "al_1").unwrap().unwrap();
+        assert_eq!(stored.session.insights.len(), 2);
+        assert_eq!(stored.session.insights[1].transcription, "Let's start");
+        assert_eq!(
+            stored.session.insights[0].translation.as_deref(),
+            Some("Hallo zusammen")
+        );
+        assert_eq!(stored.session.insights[1].translation, None);
+        assert_eq!(stored.session.speaker_names.get(&1).unwrap(), "Alex");
+        assert_eq!(
+            stored.summary.unwrap().executive_summary,
+            "Agreed on the launch date"
+        );
+
+        let listed = list_sessions_with_conn(&conn, 10).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].insight_count, 2);
+        assert!(listed[0].has_summary);
+
+        assert!(delete_session_with_conn(&mut conn, "al_1").unwrap());
+        assert!(get_session_with_conn(&conn, "al_1").unwrap().is_none());
+        let orphans: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM active_listening_insights",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(orphans, 0);
+    }
+
+    #[test]
+    fn search_matches_transcripts_and_summaries() {
+        let mut conn = setup_conn();
+        save_session_with_conn(&mut conn, &session("al_1", 1_000, &["Budget review"])).unwrap();
+        save_session_with_conn(
+            &mut conn,
+            &session("al_2", 2_000, &["We need 100% of the budget"]),
+        )
+        .unwrap();
+        save_session_with_conn(&mut conn, &session("al_3", 3_000, &["Nothing here"])).unwrap();
+        save_summary_with_conn(&conn, &summary("al_3")).unwrap();
+
+        let ids = |query| -> Vec<String> {
+            search_sessions_with_conn(&conn, query, 10)
+                .unwrap()
+                .into_iter()
+                .map(|s| s.id)
+                .collect()
+        };
+        assert_eq!(ids("BUDGET"), ["al_2", "al_1"]);
+        assert_eq!(ids("100%"), ["al_2"]);
+        assert_eq!(ids("launch date"), ["al_3"]);
+        assert_eq!(ids("Topic al_1"), ["al_1"]);
+        assert_eq!(ids(""), ["al_3", "al_2", "al_1"]);
+
+        let found = search_sessions_with_conn(&conn, "budget", 1).unwrap();
+        assert_eq!(
+            found[0].excerpt.as_deref(),
+            Some("We need 100% of the budget")
+        );
+    }
+
+    #[test]
+    fn segment_search_ranks_by_matched_terms_within_the_time_range() {
+        let mut conn = setup_conn();
+        save_session_with_conn(
+            &mut conn,
+            &session(
+                "al_1",
+                1_000,
+                &["The pricing is final", "Pricing and the launch"],
+            ),
+        )
+        .unwrap();
+        save_session_with_conn(&mut conn, &session("al_2", 5_000, &["More on pricing"])).unwrap();
+
+        let terms = vec!["PRICING".to_string(), "launch".to_string()];
+        let found = search_segments_with_conn(&conn, &terms, None, None, 10).unwrap();
+        let found: Vec<(&str, usize, usize)> = found
+            .iter()
+            .map(|m| (m.session_id.as_str(), m.segment_index, m.matched_terms))
+            .collect();
+        assert_eq!(found, [("al_1", 1, 2), ("al_2", 0, 1), ("al_1", 0, 1)]);
+
+        let recent = search_segments_with_conn(&conn, &terms, Some(2_000), None, 10).unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].session_id, "al_2");
+        let older = search_segments_with_conn(&conn, &terms, None, Some(2_000), 1).unwrap();
+        assert_eq!(older[0].segment_index, 1);
+        assert!(search_segments_with_conn(&conn, &[], None, None, 10)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn excerpt_trims_long_transcripts_around_the_match() {
+        let text = format!("{} needle {}", "a".repeat(100), "b".repeat(100));
+        let excerpt = excerpt(&text, "NEEDLE").unwrap();
+        assert!(excerpt.starts_with('…') && excerpt.ends_with('…'));
+        assert!(excerpt.contains("needle"));
+        assert_eq!(
+            excerpt.chars().count(),
+            EXCERPT_RADIUS * 2 + "needle".len() + 2
+        );
+        assert!(super::excerpt("short", "missing").is_none());
+    }
+}
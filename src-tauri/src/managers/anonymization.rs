@@ -0,0 +1,336 @@
+//! Session anonymization
+//!
+//! Replaces the names of the people in a finished Active Listening session
+//! with placeholders ("Person 1") before it's stored, for workplaces whose
+//! policies don't allow keeping colleagues' names. Only names the user has
+//! given are replaced: the session's speaker names, and the speaker profiles
+//! and confirmed names mentioned in it. This is a heuristic, not a
+//! guarantee: a name on none of those lists, or transcribed with another
+//! spelling, is kept. Which placeholder stands for which name is stored with
+//! the session, encrypted with a key that stays in the app data directory,
+//! so the names can still be looked up on this device.
+
+use crate::managers::active_listening::{ActiveListeningSession, MeetingSummary};
+use crate::managers::active_listening_history::ActiveListeningHistoryManager;
+use crate::managers::history::HistoryManager;
+use crate::settings::{get_settings, SpeakerProfile};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use log::info;
+use regex::Regex;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// File in the app data directory with the key name maps are encrypted with
+const KEY_FILE: &str = "anonymization.key";
+/// A name in an anonymized session and the placeholder that replaced it
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct NameMapping {
+    pub name: String,
+    pub placeholder: String,
+}
+
+/// Labels the session gives speakers without a name
+fn is_generic_label(label: &str) -> bool {
+    label == "You"
+        || label
+            .strip_prefix("Speaker ")
+            .is_some_and(|n| n.parse::<u32>().is_ok())
+}
+
+/// Names in `session` and the placeholders they're replaced with: its
+/// speakers first, then the profiles and `confirmed` names it mentions. The
+/// first name of a full name gets the same placeholder.
+pub fn name_map(
+    session: &ActiveListeningSession,
+    profiles: &[SpeakerProfile],
+    confirmed: &[String],
+) -> Vec<NameMapping> {
+    let texts: Vec<&str> = session
+        .insights
+        .iter()
+        .map(|insight| insight.transcription.as_str())
+        .collect();
+
+    let mut speaker_ids: Vec<&u32> = session.speaker_names.keys().collect();
+    speaker_ids.sort();
+    let speakers = speaker_ids
+        .into_iter()
+        .map(|id| session.speaker_names[id].clone())
+        .chain(
+            session
+                .insights
+                .iter()
+                .filter_map(|insight| insight.speaker_label.clone()),
+        )
+        .filter(|label| !is_generic_label(label));
+    let mentioned = profiles
+        .iter()
+        .map(|profile| profile.name.clone())
+        .chain(confirmed.iter().cloned())
+        .filter(|name| {
+            let pattern = Regex::new(&format!(r"\b{}\b", regex::escape(name)));
+            pattern.is_ok_and(|pattern| texts.iter().any(|text| pattern.is_match(text)))
+        });
+
+    let mut map: Vec<NameMapping> = Vec::new();
+    let mut people = 0;
+    for name in speakers.chain(mentioned) {
+        let name = name.trim().to_string();
+        if name.is_empty() || map.iter().any(|m| m.name == name) {
+            continue;
+        }
+        people += 1;
+        let placeholder = format!("Person {}", people);
+        let first_name = name
+            .split_whitespace()
+            .next()
+            .filter(|first| first.chars().count() > 1 && *first != name)
+            .map(str::to_string);
+        map.push(NameMapping {
+            name,
+            placeholder: placeholder.clone(),
+        });
+        if let Some(first_name) = first_name {
+            if !map.iter().any(|m| m.name == first_name) {
+                map.push(NameMapping {
+                    name: first_name,
+                    placeholder,
+                });
+            }
+        }
+    }
+    map
+}
+
+/// Replaces the names of a name map with their placeholders
+struct Anonymizer {
+    pattern: Regex,
+    placeholders: HashMap<String, String>,
+}
+
+impl Anonymizer {
+    /// `None` when there are no names to replace
+    fn new(map: &[NameMapping]) -> Option<Self> {
+        if map.is_empty() {
+            return None;
+        }
+        // Longer names first, so a full name wins over its first name
+        let mut names: Vec<&str> = map.iter().map(|m| m.name.as_str()).collect();
+        names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+        let alternatives: Vec<String> = names.iter().map(|name| regex::escape(name)).collect();
+        let pattern = Regex::new(&format!(r"\b(?:{})\b", alternatives.join("|"))).ok()?;
+        let placeholders = map
+            .iter()
+            .map(|m| (m.name.clone(), m.placeholder.clone()))
+            .collect();
+        Some(Self {
+            pattern,
+            placeholders,
+        })
+    }
+
+    fn apply(&self, text: &str) -> String {
+        self.pattern
+            .replace_all(text, |caps: &regex::Captures| {
+                self.placeholders[&caps[0]].clone()
+            })
+            .into_owned()
+    }
+
+    /// Replace the names in every string of `value`
+    fn apply_to<T: Serialize + DeserializeOwned>(&self, value: &T) -> Result<T, String> {
+        let mut json = serde_json::to_value(value)
+            .map_err(|e| format!("Failed to serialize for anonymization: {}", e))?;
+        self.apply_json(&mut json);
+        serde_json::from_value(json)
+            .map_err(|e| format!("Failed to deserialize anonymized value: {}", e))
+    }
+
+    fn apply_json(&self, value: &mut Value) {
+        match value {
+            Value::String(text) => *text = self.apply(text),
+            Value::Array(items) => items.iter_mut().for_each(|item| self.apply_json(item)),
+            Value::Object(fields) => fields.values_mut().for_each(|field| self.apply_json(field)),
+            _ => {}
+        }
+    }
+}
+
+/// The key name maps are encrypted with, created on first use
+fn cipher(app: &AppHandle) -> Result<ChaCha20Poly1305, String> {
+    let dir = crate::portable::app_data_dir(app)
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+    let path = dir.join(KEY_FILE);
+    let key = match std::fs::read(&path) {
+        Ok(bytes) if bytes.len() == 32 => *Key::from_slice(&bytes),
+        Ok(_) => return Err(format!("{} is not a valid key", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+            write_key(&path, &key)?;
+            info!("Created anonymization key at {}", path.display());
+            key
+        }
+        Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+    };
+    Ok(ChaCha20Poly1305::new(&key))
+}
+
+/// Write a new key readable only by the user
+fn write_key(path: &Path, key: &Key) -> Result<(), String> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options
+        .open(path)
+        .and_then(|mut file| file.write_all(key))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Store `session` with its names replaced by placeholders, along with its
+/// entries in the transcription history, and keep the names encrypted
+pub fn store_anonymized(app: &AppHandle, session: &ActiveListeningSession) -> Result<(), String> {
+    let settings = get_settings(app).active_listening;
+    let map = name_map(
+        session,
+        &settings.speaker_profiles,
+        &settings.anonymized_names,
+    );
+    let history = app.state::<Arc<ActiveListeningHistoryManager>>();
+    let Some(anonymizer) = Anonymizer::new(&map) else {
+        return history.save_session(session).map_err(|e| e.to_string());
+    };
+
+    history
+        .save_session(&anonymizer.apply_to(session)?)
+        .map_err(|e| e.to_string())?;
+    app.state::<Arc<HistoryManager>>()
+        .anonymize_session_entries(&session.id, &|text| anonymizer.apply(text))
+        .map_err(|e| format!("Failed to anonymize history entries: {}", e))?;
+
+    let names = serde_json::to_vec(&map).map_err(|e| e.to_string())?;
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let encrypted = cipher(app)?
+        .encrypt(&nonce, names.as_slice())
+        .map_err(|e| format!("Failed to encrypt names: {}", e))?;
+    history
+        .save_name_map(&session.id, nonce.as_slice(), &encrypted)
+        .map_err(|e| e.to_string())?;
+    info!(
+        "Stored session {} with {} names replaced",
+        session.id,
+        map.len()
+    );
+    Ok(())
+}
+
+/// The names behind the placeholders of a stored session, empty when it
+/// wasn't anonymized
+pub fn load_name_map(app: &AppHandle, session_id: &str) -> Result<Vec<NameMapping>, String> {
+    let history = app.state::<Arc<ActiveListeningHistoryManager>>();
+    let Some((nonce, encrypted)) = history
+        .get_name_map(session_id)
+        .map_err(|e| e.to_string())?
+    else {
+        return Ok(Vec::new());
+    };
+    if nonce.len() != 12 {
+        return Err(format!("Name map of session {} is corrupt", session_id));
+    }
+    let names = cipher(app)?
+        .decrypt(Nonce::from_slice(&nonce), encrypted.as_slice())
+        .map_err(|_| format!("Failed to decrypt names of session {}", session_id))?;
+    serde_json::from_slice(&names).map_err(|e| e.to_string())
+}
+
+/// `summary` with the names of its session replaced, when the session was
+/// stored anonymized
+pub fn anonymize_summary(
+    app: &AppHandle,
+    summary: &MeetingSummary,
+) -> Result<MeetingSummary, String> {
+    let map = load_name_map(app, &summary.session_id)?;
+    match Anonymizer::new(&map) {
+        Some(anonymizer) => anonymizer.apply_to(summary),
+        None => Ok(summary.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::active_listening::SessionInsight;
+
+    fn session(lines: &[(&str, &str)]) -> ActiveListeningSession {
+        ActiveListeningSession {
+            id: "al_1".to_string(),
+            started_at: 0,
+            ended_at: None,
+            topic: Some("Launch with Priya".to_string()),
+            insights: lines
+                .iter()
+                .map(|(label, text)| SessionInsight {
+                    timestamp: 0,
+                    transcription: text.to_string(),
+                    insight: String::new(),
+                    duration_ms: 1000,
+                    speaker_id: None,
+                    speaker_label: Some(label.to_string()),
+                    translation: None,
+                })
+                .collect(),
+            highlights: Vec::new(),
+            speaker_names: HashMap::from([(1, "Alex Kim".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_name_map_finds_speakers_and_confirmed_names() {
+        let session = session(&[
+            ("You", "Monday works. Did Priya send the deck?"),
+            ("Alex Kim", "Yes, and Priya's notes. We can mark it done."),
+            ("Speaker 3", "Then Mark can review it"),
+        ]);
+        let confirmed = ["Priya".to_string(), "Sam".to_string()];
+        let placeholders: Vec<(&str, &str)> = name_map(&session, &[], &confirmed)
+            .iter()
+            .map(|m| (m.name.as_str(), m.placeholder.as_str()))
+            .collect();
+        // "Mark" was never confirmed and "Sam" isn't mentioned
+        assert_eq!(
+            placeholders,
+            vec![
+                ("Alex Kim", "Person 1"),
+                ("Alex", "Person 1"),
+                ("Priya", "Person 2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_anonymizer_replaces_names_in_every_field() {
+        let session = session(&[("Alex Kim", "Alex said Priya's team is ready")]);
+        let map = name_map(&session, &[], &["Priya".to_string()]);
+        let anonymized = Anonymizer::new(&map).unwrap().apply_to(&session).unwrap();
+        let insight = &anonymized.insights[0];
+        assert_eq!(
+            insight.transcription,
+            "Person 1 said Person 2's team is ready"
+        );
+        assert_eq!(insight.speaker_label.as_deref(), Some("Person 1"));
+        assert_eq!(anonymized.speaker_names[&1], "Person 1");
+        assert_eq!(anonymized.topic.as_deref(), Some("Launch with Person 2"));
+        assert_eq!(anonymized.id, "al_1");
+    }
+}
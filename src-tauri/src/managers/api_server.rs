@@ -0,0 +1,461 @@
+//! Local HTTP API
+//!
+//! An optional server on 127.0.0.1 that lets window managers, Stream Deck
+//! buttons and scripts control the app without relying on `SIGUSR2`. Every
+//! request needs the token from the settings as `Authorization: Bearer
+//! <token>`, and every response is JSON:
+//!
+//! | Endpoint                         | Does                                  |
+//! |----------------------------------|---------------------------------------|
+//! | `GET /status`                    | Whether a dictation or session runs   |
+//! | `POST /transcription/start`      | Start a dictation                     |
+//! | `POST /transcription/stop`       | Stop it and transcribe                |
+//! | `POST /transcription/toggle`     | Start or stop, like `SIGUSR2`         |
+//! | `GET /transcription/last`        | The latest history entry              |
+//! | `GET /history?limit=&offset=&q=` | History entries, newest first         |
+//! | `POST /active-listening/start`   | Start a session, `{"topic": "..."}`   |
+//! | `POST /active-listening/stop`    | Stop the session and return it        |
+//!
+//! ```sh
+//! curl -X POST -H "Authorization: Bearer $TOKEN" http://127.0.0.1:48213/transcription/toggle
+//! ```
+
+use crate::actions::ACTION_MAP;
+use crate::commands::active_listening::{
+    start_active_listening_session, stop_active_listening_session,
+};
+use crate::managers::active_listening::ActiveListeningManager;
+use crate::managers::audio::AudioRecordingManager;
+use crate::managers::history::HistoryManager;
+use crate::settings::get_settings;
+use crate::ManagedToggleState;
+use log::{debug, error, info, warn};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::io::{self, Read};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tiny_http::{Header, Request, Response, Server};
+
+/// Binding the transcription endpoints drive
+const TRANSCRIBE_BINDING: &str = "transcribe";
+/// History entries returned when no limit is given
+const DEFAULT_HISTORY_LIMIT: usize = 20;
+/// Most history entries one request returns
+const MAX_HISTORY_LIMIT: usize = 200;
+/// Largest history offset accepted
+const MAX_HISTORY_OFFSET: usize = 100_000;
+/// Largest request body read
+const MAX_BODY_BYTES: u64 = 16 * 1024;
+/// How long a restart waits for the stopped server to release the port
+const REBIND_TIMEOUT: Duration = Duration::from_secs(2);
+const REBIND_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Route {
+    Status,
+    StartTranscription,
+    StopTranscription,
+    ToggleTranscription,
+    LastTranscription,
+    History,
+    StartActiveListening,
+    StopActiveListening,
+}
+
+pub(crate) fn route(method: &str, path: &str) -> Option<Route> {
+    let route = match (method, path.trim_end_matches('/')) {
+        ("GET", "/status") => Route::Status,
+        ("POST", "/transcription/start") => Route::StartTranscription,
+        ("POST", "/transcription/stop") => Route::StopTranscription,
+        ("POST", "/transcription/toggle") => Route::ToggleTranscription,
+        ("GET", "/transcription/last") => Route::LastTranscription,
+        ("GET", "/history") => Route::History,
+        ("POST", "/active-listening/start") => Route::StartActiveListening,
+        ("POST", "/active-listening/stop") => Route::StopActiveListening,
+        _ => return None,
+    };
+    Some(route)
+}
+
+/// Compare secrets in constant time
+pub(crate) fn constant_time_eq(given: &[u8], expected: &[u8]) -> bool {
+    given.len() == expected.len()
+        && given
+            .iter()
+            .zip(expected)
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Whether an `Authorization` header carries the token, compared in
+/// constant time
+pub(crate) fn is_authorized(header: Option<&str>, token: &str) -> bool {
+    let Some(given) = header.and_then(|value| value.trim().strip_prefix("Bearer ")) else {
+        return false;
+    };
+    constant_time_eq(given.trim().as_bytes(), token.as_bytes())
+}
+
+/// The `Authorization` header of a request
+pub(crate) fn authorization(request: &Request) -> Option<String> {
+    request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv("Authorization"))
+        .map(|header| header.value.as_str().to_string())
+}
+
+/// Path of a request without the query string
+pub(crate) fn path(request: &Request) -> String {
+    request
+        .url()
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Read a JSON request body, using the default when it's empty
+pub(crate) fn read_body<T: DeserializeOwned + Default>(
+    request: &mut Request,
+) -> Result<T, (u16, String)> {
+    let mut body = String::new();
+    request
+        .as_reader()
+        .take(MAX_BODY_BYTES)
+        .read_to_string(&mut body)
+        .map_err(|e| (400, format!("Failed to read request body: {}", e)))?;
+    if body.trim().is_empty() {
+        return Ok(T::default());
+    }
+    serde_json::from_str(&body).map_err(|e| (400, format!("Invalid body: {}", e)))
+}
+
+/// Page of history asked for in a query string
+#[derive(Debug, PartialEq)]
+struct HistoryQuery {
+    limit: usize,
+    offset: usize,
+    search: Option<String>,
+}
+
+fn history_query(url: &str) -> HistoryQuery {
+    let mut query = HistoryQuery {
+        limit: DEFAULT_HISTORY_LIMIT,
+        offset: 0,
+        search: None,
+    };
+    let Ok(url) = reqwest::Url::parse(&format!("http://localhost{}", url)) else {
+        return query;
+    };
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "limit" => {
+                if let Ok(limit) = value.parse::<usize>() {
+                    query.limit = limit.clamp(1, MAX_HISTORY_LIMIT);
+                }
+            }
+            "offset" => {
+                query.offset = value
+                    .parse::<usize>()
+                    .map_or(0, |offset| offset.min(MAX_HISTORY_OFFSET))
+            }
+            "q" => query.search = Some(value.trim().to_string()).filter(|q| !q.is_empty()),
+            _ => {}
+        }
+    }
+    query
+}
+
+#[derive(Deserialize, Default)]
+struct StartSessionBody {
+    #[serde(default)]
+    topic: Option<String>,
+}
+
+/// Start or stop the transcribe binding the way a toggle shortcut does.
+/// `start` of `None` toggles. Returns whether a dictation is now running.
+pub(crate) fn set_transcribing(
+    app: &AppHandle,
+    start: Option<bool>,
+) -> Result<bool, (u16, String)> {
+    let action = ACTION_MAP
+        .get(TRANSCRIBE_BINDING)
+        .ok_or((500, "No transcribe action".to_string()))?;
+
+    // Update the toggle state under the lock, but call the action after
+    // releasing it, as actions may need the lock themselves
+    let should_start = {
+        let toggle_state_manager = app.state::<ManagedToggleState>();
+        let mut states = toggle_state_manager
+            .lock()
+            .map_err(|e| (500, format!("Failed to lock toggle state: {}", e)))?;
+        let is_active = states
+            .active_toggles
+            .entry(TRANSCRIBE_BINDING.to_string())
+            .or_insert(false);
+        let should_start = start.unwrap_or(!*is_active);
+        if should_start == *is_active {
+            let message = if should_start {
+                "A dictation is already running"
+            } else {
+                "No dictation is running"
+            };
+            return Err((409, message.to_string()));
+        }
+        *is_active = should_start;
+        should_start
+    };
+
+    if should_start {
+        action.start(app, TRANSCRIBE_BINDING, "API");
+    } else {
+        action.stop(app, TRANSCRIBE_BINDING, "API");
+    }
+    Ok(should_start)
+}
+
+pub(crate) fn handle(
+    app: &AppHandle,
+    route: Route,
+    request: &mut Request,
+) -> Result<Value, (u16, String)> {
+    let internal = |e: String| (500, e);
+    match route {
+        Route::Status => {
+            let recording = app.state::<Arc<AudioRecordingManager>>().is_recording();
+            let active_listening = app.state::<Arc<ActiveListeningManager>>().get_state();
+            Ok(json!({ "recording": recording, "active_listening": active_listening }))
+        }
+        Route::StartTranscription => {
+            set_transcribing(app, Some(true)).map(|recording| json!({ "recording": recording }))
+        }
+        Route::StopTranscription => {
+            set_transcribing(app, Some(false)).map(|recording| json!({ "recording": recording }))
+        }
+        Route::ToggleTranscription => {
+            set_transcribing(app, None).map(|recording| json!({ "recording": recording }))
+        }
+        Route::LastTranscription => {
+            let entry = app
+                .state::<Arc<HistoryManager>>()
+                .get_latest_entry()
+                .map_err(|e| internal(e.to_string()))?
+                .ok_or((404, "No transcriptions yet".to_string()))?;
+            Ok(json!(entry))
+        }
+        Route::History => {
+            let query = history_query(request.url());
+            let history = app.state::<Arc<HistoryManager>>();
+            let entries = match &query.search {
+                Some(search) => {
+                    history.search_entries(search, query.offset.saturating_add(query.limit))
+                }
+                None => tauri::async_runtime::block_on(history.get_history_entries()),
+            }
+            .map_err(|e| internal(e.to_string()))?;
+            let page: Vec<_> = entries
+                .into_iter()
+                .skip(query.offset)
+                .take(query.limit)
+                .collect();
+            Ok(json!(page))
+        }
+        Route::StartActiveListening => {
+            let body: StartSessionBody = read_body(request)?;
+            let session_id =
+                start_active_listening_session(app.clone(), body.topic).map_err(|e| (409, e))?;
+            Ok(json!({ "session_id": session_id }))
+        }
+        Route::StopActiveListening => {
+            let session = stop_active_listening_session(app.clone()).map_err(internal)?;
+            Ok(json!(session))
+        }
+    }
+}
+
+pub(crate) fn respond(request: Request, status: u16, body: &Value) {
+    let content_type = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+        .expect("static header is valid");
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(content_type);
+    if let Err(e) = request.respond(response) {
+        debug!("Failed to answer API request: {}", e);
+    }
+}
+
+fn serve(app: &AppHandle, server: &Server, token: &str) {
+    for mut request in server.incoming_requests() {
+        if !is_authorized(authorization(&request).as_deref(), token) {
+            respond(
+                request,
+                401,
+                &json!({ "error": "Missing or wrong API token" }),
+            );
+            continue;
+        }
+
+        let path = path(&request);
+        let Some(route) = route(request.method().as_str(), &path) else {
+            respond(request, 404, &json!({ "error": "Unknown endpoint" }));
+            continue;
+        };
+        debug!("API request: {:?}", route);
+        match handle(app, route, &mut request) {
+            Ok(body) => respond(request, 200, &body),
+            Err((status, message)) => respond(request, status, &json!({ "error": message })),
+        }
+    }
+}
+
+/// A running server and the thread answering its requests
+struct Running {
+    server: Arc<Server>,
+    worker: JoinHandle<()>,
+}
+
+pub struct ApiServer {
+    app_handle: AppHandle,
+    running: Mutex<Option<Running>>,
+}
+
+impl ApiServer {
+    pub fn new(app: &AppHandle) -> Self {
+        Self {
+            app_handle: app.clone(),
+            running: Mutex::new(None),
+        }
+    }
+
+    /// Start, restart or stop the server to match the settings
+    pub fn apply_settings(&self) -> Result<(), String> {
+        // Held throughout, so concurrent calls can't both bind the port
+        let mut running = self
+            .running
+            .lock()
+            .map_err(|e| format!("Failed to lock API server: {}", e))?;
+        let restarting = running.is_some();
+        Self::shut_down(running.take());
+
+        let settings = get_settings(&self.app_handle).api_server;
+        if !settings.enabled {
+            return Ok(());
+        }
+        let token = settings
+            .token
+            .filter(|token| !token.is_empty())
+            .ok_or("Generate an API token before starting the API server")?;
+
+        let server = Arc::new(bind(settings.port, restarting)?);
+        let app = self.app_handle.clone();
+        let worker = server.clone();
+        let worker = thread::spawn(move || serve(&app, &worker, &token));
+        *running = Some(Running { server, worker });
+        info!("API server listening on 127.0.0.1:{}", settings.port);
+        Ok(())
+    }
+
+    /// Stop the server if it runs
+    pub fn stop(&self) {
+        match self.running.lock() {
+            Ok(mut running) => Self::shut_down(running.take()),
+            Err(e) => warn!("Failed to lock API server: {}", e),
+        }
+    }
+
+    /// Stop a server and wait for its thread to finish the requests it has,
+    /// then drop it, which closes the listening socket
+    fn shut_down(running: Option<Running>) {
+        let Some(Running { server, worker }) = running else {
+            return;
+        };
+        server.unblock();
+        if worker.join().is_err() {
+            error!("API server thread panicked");
+        }
+        drop(server);
+        info!("API server stopped");
+    }
+}
+
+/// Listen on `port`. When `restarting`, an address in use is retried for a
+/// moment, as the stopped server releases the port in the background.
+fn bind(port: u16, restarting: bool) -> Result<Server, String> {
+    let deadline = Instant::now() + REBIND_TIMEOUT;
+    loop {
+        match Server::http(("127.0.0.1", port)) {
+            Ok(server) => return Ok(server),
+            Err(e)
+                if restarting
+                    && Instant::now() < deadline
+                    && e.downcast_ref::<io::Error>()
+                        .is_some_and(|e| e.kind() == io::ErrorKind::AddrInUse) =>
+            {
+                thread::sleep(REBIND_INTERVAL);
+            }
+            Err(e) => {
+                return Err(format!(
+                    "Failed to start API server on port {}: {}",
+                    port, e
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_matches_method_and_path() {
+        assert_eq!(route("GET", "/status"), Some(Route::Status));
+        assert_eq!(
+            route("POST", "/transcription/toggle/"),
+            Some(Route::ToggleTranscription)
+        );
+        assert_eq!(route("GET", "/transcription/toggle"), None);
+        assert_eq!(route("POST", "/status"), None);
+        assert_eq!(route("GET", "/settings"), None);
+    }
+
+    #[test]
+    fn test_is_authorized_needs_the_exact_bearer_token() {
+        let token = "0123456789abcdef";
+        assert!(is_authorized(Some("Bearer 0123456789abcdef"), token));
+        assert!(!is_authorized(Some("Bearer 0123456789abcdee"), token));
+        assert!(!is_authorized(Some("Bearer 0123"), token));
+        assert!(!is_authorized(Some("0123456789abcdef"), token));
+        assert!(!is_authorized(None, token));
+    }
+
+    #[test]
+    fn test_history_query_clamps_the_page() {
+        assert_eq!(
+            history_query("/history"),
+            HistoryQuery {
+                limit: DEFAULT_HISTORY_LIMIT,
+                offset: 0,
+                search: None,
+            }
+        );
+        assert_eq!(
+            history_query("/history?limit=5000&offset=10&q=stand%20up"),
+            HistoryQuery {
+                limit: MAX_HISTORY_LIMIT,
+                offset: 10,
+                search: Some("stand up".to_string()),
+            }
+        );
+        assert_eq!(history_query("/history?limit=0&q=%20").limit, 1);
+        assert_eq!(
+            history_query("/history?offset=18446744073709551615").offset,
+            MAX_HISTORY_OFFSET
+        );
+    }
+}
@@ -6,8 +6,11 @@
 //! Supports multi-turn conversations where users can ask follow-up questions
 //! by triggering the shortcut again while the modal is open.
 
+use crate::events::{AskAiResponse, AskAiStateChanged, AskAiToolCall};
+use crate::managers::ask_ai_history::AskAiHistoryManager;
+use crate::managers::ask_ai_tools::{allowed_tools, AskAiToolExecutor};
 use crate::managers::transcription::TranscriptionManager;
-use crate::ollama_client::OllamaClient;
+use crate::ollama_client::{OllamaChatMessage, OllamaClient};
 use crate::overlay::{hide_recording_overlay, reset_overlay_size, show_ask_ai_response_overlay};
 use crate::settings::get_settings;
 use crate::tray::{change_tray_icon, TrayIconState};
@@ -17,13 +20,17 @@ use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
 /// Maximum number of conversation turns to include in context
 const MAX_CONTEXT_TURNS: usize = 10;
 
+/// Maximum number of tool-calling rounds before forcing a final answer
+const MAX_TOOL_ROUNDS: usize = 4;
+
 /// State of the Ask AI session
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
 #[serde(rename_all = "snake_case")]
@@ -63,6 +70,20 @@ pub struct ConversationTurn {
     pub timestamp: i64,
     /// Optional reference to the audio file for this turn
     pub audio_file_name: Option<String>,
+    /// Earlier responses to this question that were replaced by regeneration
+    #[serde(default)]
+    pub alternate_responses: Vec<String>,
+}
+
+impl ConversationTurn {
+    /// Format the turn as a knowledge base document
+    pub fn to_knowledge_text(&self) -> String {
+        format!(
+            "Question: {}\n\nAnswer: {}",
+            self.question.trim(),
+            self.response.trim()
+        )
+    }
 }
 
 /// An Ask AI conversation consisting of multiple turns
@@ -78,6 +99,12 @@ pub struct AskAiConversation {
     pub updated_at: i64,
     /// Auto-generated title from first question
     pub title: Option<String>,
+    /// Conversation this one was branched from, if any
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// Number of parent turns carried over when branching
+    #[serde(default)]
+    pub branch_turn_index: Option<usize>,
 }
 
 impl AskAiConversation {
@@ -90,6 +117,8 @@ impl AskAiConversation {
             created_at: now,
             updated_at: now,
             title: None,
+            parent_id: None,
+            branch_turn_index: None,
         }
     }
 
@@ -101,6 +130,7 @@ impl AskAiConversation {
             response,
             timestamp: Utc::now().timestamp(),
             audio_file_name,
+            alternate_responses: Vec::new(),
         };
 
         // Set title from first question if not set
@@ -112,6 +142,53 @@ impl AskAiConversation {
         self.updated_at = Utc::now().timestamp();
     }
 
+    /// Replace a turn's response with a regenerated one, keeping the old
+    /// response in `alternate_responses`. Returns false if the turn is gone.
+    pub fn replace_response(&mut self, turn_id: &str, response: String) -> bool {
+        let Some(turn) = self.turns.iter_mut().find(|turn| turn.id == turn_id) else {
+            return false;
+        };
+        let previous = std::mem::replace(&mut turn.response, response);
+        turn.alternate_responses.push(previous);
+        self.updated_at = Utc::now().timestamp();
+        true
+    }
+
+    /// The conversation up to, but not including, `turn_id`; the context a
+    /// regenerated response is asked in
+    pub fn before_turn(&self, turn_id: &str) -> AskAiConversation {
+        let mut context = self.clone();
+        if let Some(index) = self.turn_index(turn_id) {
+            context.turns.truncate(index);
+        }
+        context
+    }
+
+    /// Fork a new conversation that keeps the first `turn_index` turns.
+    ///
+    /// The copied turns get fresh IDs so both branches can be stored side by side.
+    pub fn branch_at(&self, turn_index: usize) -> AskAiConversation {
+        let mut branch = AskAiConversation::new();
+        branch.parent_id = Some(self.id.clone());
+        branch.branch_turn_index = Some(turn_index.min(self.turns.len()));
+        branch.title = self.title.clone();
+        branch.turns = self
+            .turns
+            .iter()
+            .take(turn_index)
+            .map(|turn| ConversationTurn {
+                id: Uuid::new_v4().to_string(),
+                ..turn.clone()
+            })
+            .collect();
+        branch
+    }
+
+    /// Find the index of a turn by its ID
+    pub fn turn_index(&self, turn_id: &str) -> Option<usize> {
+        self.turns.iter().position(|turn| turn.id == turn_id)
+    }
+
     /// Generate a title from the first question (truncated)
     fn generate_title(question: &str) -> String {
         let trimmed = question.trim();
@@ -140,6 +217,23 @@ impl AskAiConversation {
 
         context
     }
+
+    /// Build chat messages for Ollama's /api/chat endpoint from conversation history
+    pub fn build_chat_messages(&self, system_prompt: &str) -> Vec<OllamaChatMessage> {
+        let mut messages = Vec::new();
+
+        if !system_prompt.is_empty() {
+            messages.push(OllamaChatMessage::new("system", system_prompt));
+        }
+
+        let start_idx = self.turns.len().saturating_sub(MAX_CONTEXT_TURNS);
+        for turn in &self.turns[start_idx..] {
+            messages.push(OllamaChatMessage::new("user", turn.question.clone()));
+            messages.push(OllamaChatMessage::new("assistant", turn.response.clone()));
+        }
+
+        messages
+    }
 }
 
 impl Default for AskAiConversation {
@@ -165,6 +259,14 @@ pub struct AskAiResponseEvent {
     pub done: bool,
 }
 
+/// Event payload emitted when the model invokes a tool
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct AskAiToolCallEvent {
+    pub name: String,
+    pub arguments: String,
+    pub result: String,
+}
+
 /// Ask AI Manager
 ///
 /// Coordinates the Ask AI feature:
@@ -287,8 +389,12 @@ impl AskAiManager {
 
         // If this is a new conversation (not a follow-up), create a new conversation
         if !is_follow_up {
-            let mut conversation = self.active_conversation.lock().unwrap();
-            *conversation = Some(AskAiConversation::new());
+            let discarded = self
+                .active_conversation
+                .lock()
+                .unwrap()
+                .replace(AskAiConversation::new());
+            self.discard_audio(discarded, None);
         }
 
         // Reset cancel signal
@@ -320,6 +426,123 @@ impl AskAiManager {
         Ok(())
     }
 
+    /// Re-run the last turn with the same question and context.
+    ///
+    /// The turn stays in the conversation until the new response arrives, which
+    /// then replaces it and keeps the previous answer in `alternate_responses`.
+    /// If generation fails or is cancelled, the turn is left as it was.
+    pub fn regenerate_last_response(&self) -> Result<(), String> {
+        let last_turn = {
+            // Hold the state lock until the state is Generating, so concurrent
+            // calls can't both start regenerating the same turn
+            let mut state = self.state.lock().unwrap();
+            if !matches!(
+                *state,
+                AskAiState::Complete | AskAiState::ConversationActive | AskAiState::Error
+            ) {
+                return Err("Ask AI session busy".to_string());
+            }
+            let last_turn = self
+                .active_conversation
+                .lock()
+                .unwrap()
+                .as_ref()
+                .and_then(|conv| conv.turns.last().cloned())
+                .ok_or_else(|| "No response to regenerate".to_string())?;
+            *state = AskAiState::Generating;
+            last_turn
+        };
+
+        info!("Ask AI: Regenerating response for turn {}", last_turn.id);
+        self.spawn_answer(last_turn.question, None, Some(last_turn.id));
+        Ok(())
+    }
+
+    /// Fork the active conversation at `turn_id` and ask `new_question` in its place.
+    ///
+    /// Turns before the edited one are carried over into a new conversation, which
+    /// becomes the active conversation. Returns the new conversation ID.
+    pub fn edit_question_and_branch(
+        &self,
+        turn_id: &str,
+        new_question: String,
+    ) -> Result<String, String> {
+        if new_question.trim().is_empty() {
+            return Err("Question cannot be empty".to_string());
+        }
+
+        {
+            let state = self.state.lock().unwrap();
+            if matches!(
+                *state,
+                AskAiState::Recording | AskAiState::Transcribing | AskAiState::Generating
+            ) {
+                return Err("Ask AI session busy".to_string());
+            }
+        }
+
+        let (branch, discarded) = {
+            let mut conversation = self.active_conversation.lock().unwrap();
+            let current = conversation
+                .as_ref()
+                .ok_or_else(|| "No active conversation".to_string())?;
+            let index = current
+                .turn_index(turn_id)
+                .ok_or_else(|| format!("Turn {} not found", turn_id))?;
+            let branch = current.branch_at(index);
+            let discarded = conversation.replace(branch.clone());
+            (branch, discarded)
+        };
+        // The branch still plays the audio of the turns it carried over
+        self.discard_audio(discarded, Some(&branch));
+        let branch_id = branch.id;
+
+        info!(
+            "Ask AI: Branched conversation {} at turn {}",
+            branch_id, turn_id
+        );
+        self.spawn_answer(new_question, None, None);
+        Ok(branch_id)
+    }
+
+    /// Answer a question in the background without recording
+    fn spawn_answer(
+        &self,
+        question: String,
+        audio_file_name: Option<String>,
+        regenerated_turn_id: Option<String>,
+    ) {
+        {
+            let mut current_question = self.current_question.lock().unwrap();
+            *current_question = Some(question.clone());
+        }
+        {
+            let mut response = self.current_response.lock().unwrap();
+            response.clear();
+        }
+        self.cancel_signal.store(false, Ordering::SeqCst);
+
+        let handle = self.handle();
+        tauri::async_runtime::spawn(async move {
+            handle
+                .answer(question, audio_file_name, regenerated_turn_id)
+                .await;
+        });
+    }
+
+    fn handle(&self) -> AskAiManagerHandle {
+        AskAiManagerHandle {
+            app_handle: self.app_handle.clone(),
+            transcription_manager: self.transcription_manager.clone(),
+            state: self.state.clone(),
+            current_question: self.current_question.clone(),
+            current_response: self.current_response.clone(),
+            current_audio_samples: self.current_audio_samples.clone(),
+            active_conversation: self.active_conversation.clone(),
+            cancel_signal: self.cancel_signal.clone(),
+        }
+    }
+
     /// Process the recorded audio - called when shortcut is released
     pub fn process_question(&self, samples: Vec<f32>) {
         if samples.is_empty() {
@@ -363,16 +586,7 @@ impl AskAiManager {
         self.emit_state_change_with_conversation(AskAiState::Transcribing, None, None, conversation);
 
         // Process in background
-        let handle = AskAiManagerHandle {
-            app_handle: self.app_handle.clone(),
-            transcription_manager: self.transcription_manager.clone(),
-            state: self.state.clone(),
-            current_question: self.current_question.clone(),
-            current_response: self.current_response.clone(),
-            current_audio_samples: self.current_audio_samples.clone(),
-            active_conversation: self.active_conversation.clone(),
-            cancel_signal: self.cancel_signal.clone(),
-        };
+        let handle = self.handle();
 
         tauri::async_runtime::spawn(async move {
             handle.process(samples).await;
@@ -392,10 +606,8 @@ impl AskAiManager {
             let mut state = self.state.lock().unwrap();
             *state = AskAiState::Idle;
         }
-        {
-            let mut conversation = self.active_conversation.lock().unwrap();
-            *conversation = None;
-        }
+        let discarded = self.active_conversation.lock().unwrap().take();
+        self.discard_audio(discarded, None);
         {
             let mut question = self.current_question.lock().unwrap();
             *question = None;
@@ -407,6 +619,22 @@ impl AskAiManager {
         self.emit_state_change_with_conversation(AskAiState::Idle, None, None, None);
     }
 
+    /// Delete the question audio of a discarded conversation, unless it was
+    /// saved to history or `keep` still uses it
+    fn discard_audio(
+        &self,
+        conversation: Option<AskAiConversation>,
+        keep: Option<&AskAiConversation>,
+    ) {
+        let Some(conversation) = conversation else {
+            return;
+        };
+        let history_manager = self.app_handle.state::<Arc<AskAiHistoryManager>>();
+        if let Err(e) = history_manager.delete_unsaved_audio(&conversation, keep) {
+            warn!("Ask AI: Failed to delete unsaved question audio: {}", e);
+        }
+    }
+
     /// Dismiss the overlay but keep conversation for potential resume
     pub fn dismiss(&self) {
         let has_turns = self
@@ -439,15 +667,13 @@ impl AskAiManager {
         error: Option<String>,
         conversation: Option<AskAiConversation>,
     ) {
-        let _ = self.app_handle.emit(
-            "ask-ai-state-changed",
-            AskAiStateEvent {
-                state,
-                question,
-                error,
-                conversation,
-            },
-        );
+        let _ = AskAiStateChanged(AskAiStateEvent {
+            state,
+            question,
+            error,
+            conversation,
+        })
+        .emit(&self.app_handle);
     }
 }
 
@@ -458,7 +684,6 @@ struct AskAiManagerHandle {
     state: Arc<Mutex<AskAiState>>,
     current_question: Arc<Mutex<Option<String>>>,
     current_response: Arc<Mutex<String>>,
-    #[allow(dead_code)]
     current_audio_samples: Arc<Mutex<Vec<f32>>>,
     active_conversation: Arc<Mutex<Option<AskAiConversation>>>,
     cancel_signal: Arc<AtomicBool>,
@@ -474,7 +699,11 @@ impl AskAiManagerHandle {
 
         // Step 1: Transcribe the audio
         debug!("Ask AI: Transcribing {} samples", samples.len());
-        let transcription = match self.transcription_manager.transcribe(samples) {
+        let transcription = match self
+            .transcription_manager
+            .transcribe_for_binding(samples, "ask_ai")
+            .await
+        {
             Ok(text) => text,
             Err(e) => {
                 error!("Ask AI: Transcription failed: {}", e);
@@ -503,6 +732,48 @@ impl AskAiManagerHandle {
             return;
         }
 
+        // Save the question audio so it can be played back later
+        let audio_file_name = self.save_question_audio().await;
+
+        self.answer(transcription, audio_file_name, None).await;
+    }
+
+    /// Persist the current turn's audio, returning its file name on success
+    async fn save_question_audio(&self) -> Option<String> {
+        if crate::settings::is_guest_mode(&self.app_handle) {
+            return None;
+        }
+
+        let samples = self.current_audio_samples.lock().unwrap().clone();
+        if samples.is_empty() {
+            return None;
+        }
+
+        let history_manager = self
+            .app_handle
+            .state::<Arc<AskAiHistoryManager>>()
+            .inner()
+            .clone();
+        match history_manager.save_question_audio(&samples).await {
+            Ok(file_name) => Some(file_name),
+            Err(e) => {
+                warn!("Ask AI: Failed to save question audio: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Generate and record an answer for `transcription` in the active conversation.
+    ///
+    /// With `regenerated_turn_id`, the answer replaces that turn's response and is
+    /// generated with only the turns before it as context; otherwise it's added as
+    /// a new turn. Nothing is recorded if generation fails.
+    async fn answer(
+        &self,
+        transcription: String,
+        audio_file_name: Option<String>,
+        regenerated_turn_id: Option<String>,
+    ) {
         // Update state to generating
         {
             let mut state = self.state.lock().unwrap();
@@ -534,7 +805,15 @@ impl AskAiManagerHandle {
         }
 
         // Build the prompt with conversation context and system prompt
-        let prompt = self.build_prompt(&transcription, &ask_ai_settings.system_prompt);
+        let context = {
+            let conversation = self.active_conversation.lock().unwrap();
+            let conversation = conversation.clone().unwrap_or_default();
+            match &regenerated_turn_id {
+                Some(turn_id) => conversation.before_turn(turn_id),
+                None => conversation,
+            }
+        };
+        let prompt = Self::build_prompt(&context, &transcription, &ask_ai_settings.system_prompt);
 
         let client = match OllamaClient::new(&ask_ai_settings.ollama_base_url) {
             Ok(c) => c,
@@ -544,49 +823,22 @@ impl AskAiManagerHandle {
                 return;
             }
         };
-        let (tx, mut rx) = mpsc::channel::<String>(100);
-
-        let app_handle_clone = self.app_handle.clone();
-        let current_response = self.current_response.clone();
-        let cancel_signal = self.cancel_signal.clone();
-
-        // Spawn task to forward stream chunks to frontend
-        let stream_forward_handle = tauri::async_runtime::spawn(async move {
-            let mut full_response = String::new();
-            while let Some(chunk) = rx.recv().await {
-                // Check for cancellation
-                if cancel_signal.load(Ordering::SeqCst) {
-                    debug!("Ask AI: Stream forwarding cancelled");
-                    break;
-                }
 
-                full_response.push_str(&chunk);
-
-                // Update stored response
-                {
-                    let mut response = current_response.lock().unwrap();
-                    response.push_str(&chunk);
-                }
-
-                // Emit chunk to frontend
-                let _ = app_handle_clone.emit(
-                    "ask-ai-response",
-                    AskAiResponseEvent {
-                        chunk,
-                        done: false,
-                    },
-                );
-            }
-            full_response
-        });
-
-        // Call Ollama
-        let ollama_result = client
-            .generate_stream(&ask_ai_settings.ollama_model, prompt, tx)
-            .await;
-
-        // Wait for stream forwarding to complete
-        let full_response = stream_forward_handle.await.unwrap_or_default();
+        let (ollama_result, full_response) = if ask_ai_settings.tools_enabled {
+            let result = self
+                .generate_with_tools(
+                    &client,
+                    &ask_ai_settings.ollama_model,
+                    &context,
+                    &transcription,
+                )
+                .await;
+            let full_response = result.clone().unwrap_or_default();
+            (result, full_response)
+        } else {
+            self.generate_streaming(&client, &ask_ai_settings.ollama_model, prompt)
+                .await
+        };
 
         // Check for cancellation
         if self.cancel_signal.load(Ordering::SeqCst) {
@@ -603,18 +855,27 @@ impl AskAiManagerHandle {
                 {
                     let mut conversation = self.active_conversation.lock().unwrap();
                     if let Some(ref mut conv) = *conversation {
-                        conv.add_turn(transcription.clone(), full_response.clone(), None);
+                        match &regenerated_turn_id {
+                            Some(turn_id) => {
+                                if !conv.replace_response(turn_id, full_response.clone()) {
+                                    warn!("Ask AI: Regenerated turn {} no longer exists", turn_id);
+                                }
+                            }
+                            None => conv.add_turn(
+                                transcription.clone(),
+                                full_response.clone(),
+                                audio_file_name,
+                            ),
+                        }
                     }
                 }
 
                 // Emit done signal
-                let _ = self.app_handle.emit(
-                    "ask-ai-response",
-                    AskAiResponseEvent {
-                        chunk: String::new(),
-                        done: true,
-                    },
-                );
+                let _ = AskAiResponse(AskAiResponseEvent {
+                    chunk: String::new(),
+                    done: true,
+                })
+                .emit(&self.app_handle);
 
                 // Update state to complete (conversation active)
                 {
@@ -638,10 +899,125 @@ impl AskAiManagerHandle {
         }
     }
 
-    /// Build the prompt with conversation context and system prompt
-    fn build_prompt(&self, new_question: &str, system_prompt: &str) -> String {
-        let conversation = self.active_conversation.lock().unwrap();
+    /// Generate a response via the streaming /api/generate endpoint,
+    /// forwarding chunks to the frontend as they arrive
+    async fn generate_streaming(
+        &self,
+        client: &OllamaClient,
+        model: &str,
+        prompt: String,
+    ) -> (Result<String, String>, String) {
+        let (tx, mut rx) = mpsc::channel::<String>(100);
+
+        let app_handle_clone = self.app_handle.clone();
+        let current_response = self.current_response.clone();
+        let cancel_signal = self.cancel_signal.clone();
+
+        // Spawn task to forward stream chunks to frontend
+        let stream_forward_handle = tauri::async_runtime::spawn(async move {
+            let mut full_response = String::new();
+            while let Some(chunk) = rx.recv().await {
+                // Check for cancellation
+                if cancel_signal.load(Ordering::SeqCst) {
+                    debug!("Ask AI: Stream forwarding cancelled");
+                    break;
+                }
+
+                full_response.push_str(&chunk);
+
+                // Update stored response
+                {
+                    let mut response = current_response.lock().unwrap();
+                    response.push_str(&chunk);
+                }
+
+                // Emit chunk to frontend
+                let _ = AskAiResponse(AskAiResponseEvent { chunk, done: false })
+                    .emit(&app_handle_clone);
+            }
+            full_response
+        });
+
+        // Call Ollama
+        let ollama_result = client.generate_stream(model, prompt, tx).await;
+
+        // Wait for stream forwarding to complete
+        let full_response = stream_forward_handle.await.unwrap_or_default();
+
+        (ollama_result, full_response)
+    }
+
+    /// Generate a response via /api/chat, letting the model call whitelisted tools.
+    ///
+    /// Tool results are appended as "tool" messages until the model produces a
+    /// final answer or `MAX_TOOL_ROUNDS` is reached. The final answer is emitted
+    /// to the frontend as a single chunk.
+    async fn generate_with_tools(
+        &self,
+        client: &OllamaClient,
+        model: &str,
+        context: &AskAiConversation,
+        question: &str,
+    ) -> Result<String, String> {
+        let settings = get_settings(&self.app_handle);
+        let executor = AskAiToolExecutor::new(
+            self.app_handle.clone(),
+            allowed_tools(&settings.ask_ai.allowed_tools),
+        );
+        let tools = executor.definitions();
+
+        let mut messages = context.build_chat_messages(&settings.ask_ai.system_prompt);
+        messages.push(OllamaChatMessage::new("user", question));
+
+        let mut answer = None;
+        for round in 0..=MAX_TOOL_ROUNDS {
+            if self.cancel_signal.load(Ordering::SeqCst) {
+                return Err("Cancelled".to_string());
+            }
+
+            // On the last round, withhold tools so the model has to answer
+            let round_tools = if round < MAX_TOOL_ROUNDS {
+                &tools[..]
+            } else {
+                &tools[..0]
+            };
+            let reply = client.chat(model, &messages, round_tools).await?;
+
+            if reply.tool_calls.is_empty() {
+                answer = Some(reply.content);
+                break;
+            }
+
+            let calls = reply.tool_calls.clone();
+            messages.push(reply);
+            for call in &calls {
+                let result = executor.execute(call).await;
+                let _ = AskAiToolCall(AskAiToolCallEvent {
+                    name: call.function.name.clone(),
+                    arguments: call.function.arguments.to_string(),
+                    result: result.clone(),
+                })
+                .emit(&self.app_handle);
+                messages.push(OllamaChatMessage::new("tool", result));
+            }
+        }
 
+        let answer = answer.unwrap_or_default();
+        {
+            let mut response = self.current_response.lock().unwrap();
+            response.push_str(&answer);
+        }
+        let _ = AskAiResponse(AskAiResponseEvent {
+            chunk: answer.clone(),
+            done: false,
+        })
+        .emit(&self.app_handle);
+
+        Ok(answer)
+    }
+
+    /// Build the prompt with conversation context and system prompt
+    fn build_prompt(conv: &AskAiConversation, new_question: &str, system_prompt: &str) -> String {
         // Start with system prompt if provided
         let system_section = if system_prompt.is_empty() {
             String::new()
@@ -649,18 +1025,13 @@ impl AskAiManagerHandle {
             format!("System: {}\n\n", system_prompt)
         };
 
-        if let Some(ref conv) = *conversation {
-            if conv.turns.is_empty() {
-                // First question
-                format!("{}User: {}", system_section, new_question)
-            } else {
-                // Multi-turn - include context
-                let context = conv.build_context();
-                format!("{}{}User: {}", system_section, context, new_question)
-            }
-        } else {
-            // No conversation
+        if conv.turns.is_empty() {
+            // First question
             format!("{}User: {}", system_section, new_question)
+        } else {
+            // Multi-turn - include context
+            let context = conv.build_context();
+            format!("{}{}User: {}", system_section, context, new_question)
         }
     }
 
@@ -677,15 +1048,13 @@ impl AskAiManagerHandle {
         error: Option<String>,
         conversation: Option<AskAiConversation>,
     ) {
-        let _ = self.app_handle.emit(
-            "ask-ai-state-changed",
-            AskAiStateEvent {
-                state,
-                question,
-                error,
-                conversation,
-            },
-        );
+        let _ = AskAiStateChanged(AskAiStateEvent {
+            state,
+            question,
+            error,
+            conversation,
+        })
+        .emit(&self.app_handle);
     }
 
     fn emit_error(&self, error: String) {
@@ -856,6 +1225,103 @@ mod tests {
         assert!(context.contains(&format!("Question {}", last_turn_index)));
     }
 
+    #[test]
+    fn test_build_chat_messages_includes_system_and_turns() {
+        let mut conv = AskAiConversation::new();
+        conv.add_turn("Question 1".to_string(), "Answer 1".to_string(), None);
+
+        let messages = conv.build_chat_messages("Be brief.");
+
+        let roles: Vec<&str> = messages.iter().map(|m| m.role.as_str()).collect();
+        assert_eq!(roles, vec!["system", "user", "assistant"]);
+        assert_eq!(messages[1].content, "Question 1");
+        assert_eq!(messages[2].content, "Answer 1");
+    }
+
+    #[test]
+    fn test_build_chat_messages_skips_empty_system_prompt() {
+        let conv = AskAiConversation::new();
+        assert!(conv.build_chat_messages("").is_empty());
+    }
+
+    #[test]
+    fn test_turn_to_knowledge_text() {
+        let mut conv = AskAiConversation::new();
+        conv.add_turn(
+            " What is Rust? ".to_string(),
+            "A language.\n".to_string(),
+            None,
+        );
+
+        assert_eq!(
+            conv.turns[0].to_knowledge_text(),
+            "Question: What is Rust?\n\nAnswer: A language."
+        );
+    }
+
+    #[test]
+    fn test_replace_response_keeps_previous_as_alternate() {
+        let mut conv = AskAiConversation::new();
+        conv.add_turn("Q1".to_string(), "A1".to_string(), None);
+        conv.add_turn("Q2".to_string(), "A2".to_string(), None);
+        let turn_id = conv.turns[1].id.clone();
+
+        assert!(conv.replace_response(&turn_id, "A2 again".to_string()));
+
+        assert_eq!(conv.turns.len(), 2);
+        assert_eq!(conv.turns[1].id, turn_id);
+        assert_eq!(conv.turns[1].response, "A2 again");
+        assert_eq!(conv.turns[1].alternate_responses, vec!["A2".to_string()]);
+        assert!(!conv.replace_response("missing", "A3".to_string()));
+    }
+
+    #[test]
+    fn test_failed_regeneration_keeps_last_turn() {
+        let mut conv = AskAiConversation::new();
+        conv.add_turn("Q1".to_string(), "A1".to_string(), None);
+        conv.add_turn("Q2".to_string(), "A2".to_string(), None);
+        let turn_id = conv.turns[1].id.clone();
+
+        // The context is built without the turn, which stays in the
+        // conversation until a new response replaces it
+        let context = conv.before_turn(&turn_id);
+        assert_eq!(context.turns.len(), 1);
+        assert_eq!(context.turns[0].question, "Q1");
+
+        assert_eq!(conv.turns.len(), 2);
+        assert_eq!(conv.turns[1].response, "A2");
+        assert!(conv.turns[1].alternate_responses.is_empty());
+    }
+
+    #[test]
+    fn test_branch_at_keeps_earlier_turns_with_new_ids() {
+        let mut conv = AskAiConversation::new();
+        conv.add_turn("Q1".to_string(), "A1".to_string(), None);
+        conv.add_turn("Q2".to_string(), "A2".to_string(), None);
+        conv.add_turn("Q3".to_string(), "A3".to_string(), None);
+
+        let branch = conv.branch_at(2);
+
+        assert_ne!(branch.id, conv.id);
+        assert_eq!(branch.parent_id.as_deref(), Some(conv.id.as_str()));
+        assert_eq!(branch.branch_turn_index, Some(2));
+        assert_eq!(branch.turns.len(), 2);
+        assert_eq!(branch.turns[1].question, "Q2");
+        assert_ne!(branch.turns[0].id, conv.turns[0].id);
+        assert_eq!(branch.title, conv.title);
+    }
+
+    #[test]
+    fn test_turn_index() {
+        let mut conv = AskAiConversation::new();
+        conv.add_turn("Q1".to_string(), "A1".to_string(), None);
+        conv.add_turn("Q2".to_string(), "A2".to_string(), None);
+
+        let second_id = conv.turns[1].id.clone();
+        assert_eq!(conv.turn_index(&second_id), Some(1));
+        assert_eq!(conv.turn_index("missing"), None);
+    }
+
     #[test]
     fn test_generate_title_short_question() {
         let title = AskAiConversation::generate_title("Short question");
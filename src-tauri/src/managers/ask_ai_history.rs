@@ -3,27 +3,34 @@
 //! Manages persistence of Ask AI conversations to the database.
 
 use anyhow::Result;
-use log::{debug, info};
+use log::{debug, error, info};
 use rusqlite::{params, Connection, OptionalExtension};
+use std::fs;
 use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use tauri::AppHandle;
+use uuid::Uuid;
+
+use crate::audio_toolkit::save_wav_file;
 
 use super::ask_ai::{AskAiConversation, ConversationTurn};
 
 /// Manages Ask AI conversation persistence
 pub struct AskAiHistoryManager {
     db_path: PathBuf,
-    #[allow(dead_code)]
     recordings_dir: PathBuf,
 }
 
 impl AskAiHistoryManager {
     /// Create a new AskAiHistoryManager
     pub fn new(app_handle: &AppHandle) -> Result<Self> {
-        let app_data_dir = app_handle.path().app_data_dir()?;
+        let app_data_dir = crate::portable::app_data_dir(app_handle)?;
         let recordings_dir = app_data_dir.join("recordings");
         let db_path = app_data_dir.join("history.db");
 
+        if !recordings_dir.exists() {
+            fs::create_dir_all(&recordings_dir)?;
+        }
+
         Ok(Self {
             db_path,
             recordings_dir,
@@ -34,19 +41,134 @@ impl AskAiHistoryManager {
         Ok(Connection::open(&self.db_path)?)
     }
 
+    /// Save the audio of a spoken question, returning the file name to store on the turn
+    pub async fn save_question_audio(&self, samples: &[f32]) -> Result<String> {
+        let file_name = format!("ask-ai-{}.wav", Uuid::new_v4());
+        save_wav_file(self.recordings_dir.join(&file_name), samples).await?;
+        debug!("Saved Ask AI question audio {}", file_name);
+        Ok(file_name)
+    }
+
+    /// Resolve the path of a question audio file, rejecting names outside the recordings dir
+    pub fn get_audio_file_path(&self, file_name: &str) -> Result<PathBuf> {
+        if file_name.is_empty() || file_name.contains(['/', '\\']) || file_name.contains("..") {
+            anyhow::bail!("Invalid audio file name: {}", file_name);
+        }
+        Ok(self.recordings_dir.join(file_name))
+    }
+
+    /// Delete the question audio files referenced by a conversation's turns.
+    ///
+    /// Files still referenced by another conversation (e.g. a branch) are kept.
+    fn delete_audio_files(&self, conn: &Connection, conversation: &AskAiConversation) {
+        for file_name in conversation
+            .turns
+            .iter()
+            .filter_map(|turn| turn.audio_file_name.as_deref())
+        {
+            let shared: i64 = conn
+                .query_row(
+                    "SELECT COUNT(*) FROM ask_ai_turns WHERE audio_file_name = ?1 AND conversation_id != ?2",
+                    params![file_name, conversation.id],
+                    |row| row.get(0),
+                )
+                .unwrap_or(0);
+            if shared > 0 {
+                continue;
+            }
+            self.remove_audio_file(file_name);
+        }
+    }
+
+    fn remove_audio_file(&self, file_name: &str) {
+        let Ok(path) = self.get_audio_file_path(file_name) else {
+            return;
+        };
+        if path.exists() {
+            if let Err(e) = fs::remove_file(&path) {
+                error!("Failed to delete Ask AI audio file {}: {}", file_name, e);
+            }
+        }
+    }
+
+    /// Whether a saved turn refers to a question audio file. Errors count as
+    /// saved, so a file is never deleted on a failed lookup.
+    fn is_audio_saved(conn: &Connection, file_name: &str) -> bool {
+        conn.query_row(
+            "SELECT COUNT(*) FROM ask_ai_turns WHERE audio_file_name = ?1",
+            params![file_name],
+            |row| row.get::<_, i64>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(true)
+    }
+
+    /// Delete the question audio of a conversation discarded without being saved.
+    ///
+    /// Files a saved turn or a turn of `keep` (e.g. a branch of the discarded
+    /// conversation) refers to are kept.
+    pub fn delete_unsaved_audio(
+        &self,
+        conversation: &AskAiConversation,
+        keep: Option<&AskAiConversation>,
+    ) -> Result<()> {
+        let conn = self.get_connection()?;
+        let kept = |file_name: &str| {
+            keep.is_some_and(|keep| {
+                keep.turns
+                    .iter()
+                    .any(|turn| turn.audio_file_name.as_deref() == Some(file_name))
+            })
+        };
+        for file_name in conversation
+            .turns
+            .iter()
+            .filter_map(|turn| turn.audio_file_name.as_deref())
+        {
+            if !kept(file_name) && !Self::is_audio_saved(&conn, file_name) {
+                self.remove_audio_file(file_name);
+            }
+        }
+        Ok(())
+    }
+
+    /// Delete question audio no saved turn refers to, left behind when the app
+    /// quit during a conversation that was never saved. Returns the number of
+    /// files deleted.
+    pub fn delete_orphaned_audio(&self) -> Result<usize> {
+        let conn = self.get_connection()?;
+        let mut deleted = 0;
+        for entry in fs::read_dir(&self.recordings_dir)?.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if !file_name.starts_with("ask-ai-") || !file_name.ends_with(".wav") {
+                continue;
+            }
+            if !Self::is_audio_saved(&conn, &file_name) {
+                self.remove_audio_file(&file_name);
+                deleted += 1;
+            }
+        }
+        if deleted > 0 {
+            info!("Deleted {} unsaved Ask AI audio files", deleted);
+        }
+        Ok(deleted)
+    }
+
     /// Save a conversation to the database
     pub fn save_conversation(&self, conversation: &AskAiConversation) -> Result<()> {
         let conn = self.get_connection()?;
 
         // Insert or update the conversation
         conn.execute(
-            "INSERT OR REPLACE INTO ask_ai_conversations (id, title, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4)",
+            "INSERT OR REPLACE INTO ask_ai_conversations (id, title, created_at, updated_at, parent_id, branch_turn_index)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
             params![
                 conversation.id,
                 conversation.title,
                 conversation.created_at,
-                conversation.updated_at
+                conversation.updated_at,
+                conversation.parent_id,
+                conversation.branch_turn_index.map(|i| i as i64)
             ],
         )?;
 
@@ -58,9 +180,14 @@ impl AskAiHistoryManager {
 
         // Insert all turns
         for (order, turn) in conversation.turns.iter().enumerate() {
+            let alternate_responses = if turn.alternate_responses.is_empty() {
+                None
+            } else {
+                Some(serde_json::to_string(&turn.alternate_responses)?)
+            };
             conn.execute(
-                "INSERT INTO ask_ai_turns (id, conversation_id, question, response, audio_file_name, timestamp, turn_order)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                "INSERT INTO ask_ai_turns (id, conversation_id, question, response, audio_file_name, timestamp, turn_order, alternate_responses)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
                 params![
                     turn.id,
                     conversation.id,
@@ -68,7 +195,8 @@ impl AskAiHistoryManager {
                     turn.response,
                     turn.audio_file_name,
                     turn.timestamp,
-                    order as i64
+                    order as i64,
+                    alternate_responses
                 ],
             )?;
         }
@@ -83,20 +211,11 @@ impl AskAiHistoryManager {
 
         // Get conversation metadata
         let mut stmt = conn.prepare(
-            "SELECT id, title, created_at, updated_at FROM ask_ai_conversations WHERE id = ?1",
+            "SELECT id, title, created_at, updated_at, parent_id, branch_turn_index
+             FROM ask_ai_conversations WHERE id = ?1",
         )?;
 
-        let conversation_opt = stmt
-            .query_row([id], |row| {
-                Ok(AskAiConversation {
-                    id: row.get(0)?,
-                    title: row.get(1)?,
-                    created_at: row.get(2)?,
-                    updated_at: row.get(3)?,
-                    turns: Vec::new(),
-                })
-            })
-            .optional()?;
+        let conversation_opt = stmt.query_row([id], Self::row_to_conversation).optional()?;
 
         let mut conversation = match conversation_opt {
             Some(c) => c,
@@ -105,19 +224,23 @@ impl AskAiHistoryManager {
 
         // Get turns for this conversation
         let mut stmt = conn.prepare(
-            "SELECT id, question, response, audio_file_name, timestamp
+            "SELECT id, question, response, audio_file_name, timestamp, alternate_responses
              FROM ask_ai_turns
              WHERE conversation_id = ?1
              ORDER BY turn_order ASC",
         )?;
 
         let turns = stmt.query_map([id], |row| {
+            let alternate_responses: Option<String> = row.get(5)?;
             Ok(ConversationTurn {
                 id: row.get(0)?,
                 question: row.get(1)?,
                 response: row.get(2)?,
                 audio_file_name: row.get(3)?,
                 timestamp: row.get(4)?,
+                alternate_responses: alternate_responses
+                    .and_then(|json| serde_json::from_str(&json).ok())
+                    .unwrap_or_default(),
             })
         })?;
 
@@ -133,21 +256,13 @@ impl AskAiHistoryManager {
         let conn = self.get_connection()?;
 
         let mut stmt = conn.prepare(
-            "SELECT id, title, created_at, updated_at
+            "SELECT id, title, created_at, updated_at, parent_id, branch_turn_index
              FROM ask_ai_conversations
              ORDER BY updated_at DESC
              LIMIT ?1",
         )?;
 
-        let conversations = stmt.query_map([limit as i64], |row| {
-            Ok(AskAiConversation {
-                id: row.get(0)?,
-                title: row.get(1)?,
-                created_at: row.get(2)?,
-                updated_at: row.get(3)?,
-                turns: Vec::new(),
-            })
-        })?;
+        let conversations = stmt.query_map([limit as i64], Self::row_to_conversation)?;
 
         let mut result = Vec::new();
         for conv in conversations {
@@ -164,10 +279,55 @@ impl AskAiHistoryManager {
         Ok(result)
     }
 
+    /// List conversations that were branched from `parent_id`
+    pub fn list_branches(&self, parent_id: &str) -> Result<Vec<AskAiConversation>> {
+        let conn = self.get_connection()?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, title, created_at, updated_at, parent_id, branch_turn_index
+             FROM ask_ai_conversations
+             WHERE parent_id = ?1
+             ORDER BY created_at ASC",
+        )?;
+
+        let ids: Vec<String> = stmt
+            .query_map([parent_id], Self::row_to_conversation)?
+            .filter_map(|r| r.ok())
+            .map(|c| c.id)
+            .collect();
+
+        let mut result = Vec::new();
+        for id in ids {
+            if let Some(conversation) = self.get_conversation(&id)? {
+                result.push(conversation);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Map a conversation metadata row (without turns)
+    fn row_to_conversation(row: &rusqlite::Row) -> rusqlite::Result<AskAiConversation> {
+        let branch_turn_index: Option<i64> = row.get(5)?;
+        Ok(AskAiConversation {
+            id: row.get(0)?,
+            title: row.get(1)?,
+            created_at: row.get(2)?,
+            updated_at: row.get(3)?,
+            turns: Vec::new(),
+            parent_id: row.get(4)?,
+            branch_turn_index: branch_turn_index.map(|i| i as usize),
+        })
+    }
+
     /// Delete a conversation and all its turns
     pub fn delete_conversation(&self, id: &str) -> Result<()> {
         let conn = self.get_connection()?;
 
+        if let Some(conversation) = self.get_conversation(id)? {
+            self.delete_audio_files(&conn, &conversation);
+        }
+
         // Due to ON DELETE CASCADE, deleting the conversation will also delete turns
         let deleted = conn.execute(
             "DELETE FROM ask_ai_conversations WHERE id = ?1",
@@ -225,3 +385,53 @@ impl AskAiHistoryManager {
         Ok(count)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager() -> AskAiHistoryManager {
+        AskAiHistoryManager {
+            db_path: PathBuf::from("history.db"),
+            recordings_dir: PathBuf::from("recordings"),
+        }
+    }
+
+    #[test]
+    fn get_audio_file_path_accepts_plain_file_names() {
+        let path = manager().get_audio_file_path("ask-ai-123.wav").unwrap();
+        assert_eq!(path, PathBuf::from("recordings").join("ask-ai-123.wav"));
+    }
+
+    #[test]
+    fn delete_orphaned_audio_keeps_saved_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let manager = AskAiHistoryManager {
+            db_path: dir.path().join("history.db"),
+            recordings_dir: dir.path().to_path_buf(),
+        };
+        let conn = manager.get_connection().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE ask_ai_turns (id TEXT PRIMARY KEY, audio_file_name TEXT);
+             INSERT INTO ask_ai_turns VALUES ('t1', 'ask-ai-saved.wav');",
+        )
+        .unwrap();
+        for name in ["ask-ai-saved.wav", "ask-ai-unsaved.wav", "recording.wav"] {
+            fs::write(dir.path().join(name), b"").unwrap();
+        }
+
+        assert_eq!(manager.delete_orphaned_audio().unwrap(), 1);
+        assert!(dir.path().join("ask-ai-saved.wav").exists());
+        assert!(!dir.path().join("ask-ai-unsaved.wav").exists());
+        assert!(dir.path().join("recording.wav").exists());
+    }
+
+    #[test]
+    fn get_audio_file_path_rejects_traversal() {
+        let manager = manager();
+        assert!(manager.get_audio_file_path("").is_err());
+        assert!(manager.get_audio_file_path("../settings.json").is_err());
+        assert!(manager.get_audio_file_path("nested/file.wav").is_err());
+        assert!(manager.get_audio_file_path("nested\\file.wav").is_err());
+    }
+}
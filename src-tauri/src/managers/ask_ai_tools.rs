@@ -0,0 +1,323 @@
+//! Ask AI Tools
+//!
+//! A constrained tool-calling layer for Ask AI. The model may only invoke
+//! tools from a fixed set, and only those the user has whitelisted in
+//! settings. Tool results are returned as plain text and fed back into the
+//! conversation as "tool" messages.
+
+use crate::managers::history::HistoryManager;
+use crate::managers::rag::RagManager;
+use crate::ollama_client::{OllamaTool, OllamaToolCall, OllamaToolFunction};
+use crate::settings::get_settings;
+use chrono::Local;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use specta::Type;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_opener::OpenerExt;
+
+/// Maximum number of history entries returned by `search_history`
+const MAX_HISTORY_RESULTS: usize = 5;
+
+/// Maximum characters of tool output fed back to the model
+const MAX_TOOL_OUTPUT_CHARS: usize = 4000;
+
+/// Tools the Ask AI model can call
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AskAiTool {
+    SearchHistory,
+    QueryKnowledgeBase,
+    CurrentDateTime,
+    OpenUrl,
+}
+
+/// Tool description for display in the settings UI
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct AskAiToolInfo {
+    pub name: String,
+    pub description: String,
+}
+
+impl AskAiTool {
+    pub const ALL: [AskAiTool; 4] = [
+        AskAiTool::SearchHistory,
+        AskAiTool::QueryKnowledgeBase,
+        AskAiTool::CurrentDateTime,
+        AskAiTool::OpenUrl,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AskAiTool::SearchHistory => "search_history",
+            AskAiTool::QueryKnowledgeBase => "query_knowledge_base",
+            AskAiTool::CurrentDateTime => "current_datetime",
+            AskAiTool::OpenUrl => "open_url",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|tool| tool.name() == name)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            AskAiTool::SearchHistory => {
+                "Search the user's past transcriptions for text containing a query."
+            }
+            AskAiTool::QueryKnowledgeBase => {
+                "Retrieve passages from the user's local knowledge base relevant to a query."
+            }
+            AskAiTool::CurrentDateTime => "Get the current local date and time.",
+            AskAiTool::OpenUrl => "Open an http(s) URL in the user's default browser.",
+        }
+    }
+
+    fn parameters(&self) -> serde_json::Value {
+        match self {
+            AskAiTool::SearchHistory | AskAiTool::QueryKnowledgeBase => json!({
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "Text to search for" }
+                },
+                "required": ["query"]
+            }),
+            AskAiTool::CurrentDateTime => json!({ "type": "object", "properties": {} }),
+            AskAiTool::OpenUrl => json!({
+                "type": "object",
+                "properties": {
+                    "url": { "type": "string", "description": "The http or https URL to open" }
+                },
+                "required": ["url"]
+            }),
+        }
+    }
+
+    /// Build the function-calling definition advertised to the model
+    pub fn definition(&self) -> OllamaTool {
+        OllamaTool {
+            tool_type: "function".to_string(),
+            function: OllamaToolFunction {
+                name: self.name().to_string(),
+                description: self.description().to_string(),
+                parameters: self.parameters(),
+            },
+        }
+    }
+
+    pub fn info(&self) -> AskAiToolInfo {
+        AskAiToolInfo {
+            name: self.name().to_string(),
+            description: self.description().to_string(),
+        }
+    }
+}
+
+/// Resolve the whitelisted tool names from settings into known tools
+pub fn allowed_tools(allowed: &[String]) -> Vec<AskAiTool> {
+    AskAiTool::ALL
+        .iter()
+        .copied()
+        .filter(|tool| allowed.iter().any(|name| name == tool.name()))
+        .collect()
+}
+
+/// Read a required string argument from a tool call
+fn string_argument(arguments: &serde_json::Value, key: &str) -> Result<String, String> {
+    // Some models send arguments as a JSON-encoded string rather than an object
+    let parsed;
+    let arguments = match arguments {
+        serde_json::Value::String(raw) => {
+            parsed = serde_json::from_str::<serde_json::Value>(raw).unwrap_or_default();
+            &parsed
+        }
+        other => other,
+    };
+
+    arguments
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| format!("Missing required argument '{}'", key))
+}
+
+/// Only allow web URLs to be opened
+fn validate_url(url: &str) -> Result<(), String> {
+    let lower = url.to_lowercase();
+    if lower.starts_with("https://") || lower.starts_with("http://") {
+        Ok(())
+    } else {
+        Err("Only http and https URLs can be opened".to_string())
+    }
+}
+
+fn truncate_output(output: String) -> String {
+    if output.chars().count() <= MAX_TOOL_OUTPUT_CHARS {
+        output
+    } else {
+        let truncated: String = output.chars().take(MAX_TOOL_OUTPUT_CHARS).collect();
+        format!("{}\n[truncated]", truncated)
+    }
+}
+
+/// Executes tool calls requested by the model
+pub struct AskAiToolExecutor {
+    app_handle: AppHandle,
+    allowed: Vec<AskAiTool>,
+}
+
+impl AskAiToolExecutor {
+    pub fn new(app_handle: AppHandle, allowed: Vec<AskAiTool>) -> Self {
+        Self {
+            app_handle,
+            allowed,
+        }
+    }
+
+    /// Tool definitions for the whitelisted tools
+    pub fn definitions(&self) -> Vec<OllamaTool> {
+        self.allowed.iter().map(|tool| tool.definition()).collect()
+    }
+
+    /// Execute a tool call, returning the text fed back to the model.
+    ///
+    /// Errors are reported to the model as text rather than aborting the answer.
+    pub async fn execute(&self, call: &OllamaToolCall) -> String {
+        let name = call.function.name.as_str();
+        let tool = match AskAiTool::from_name(name) {
+            Some(tool) if self.allowed.contains(&tool) => tool,
+            _ => {
+                warn!("Ask AI: Model requested non-whitelisted tool '{}'", name);
+                return format!("Error: tool '{}' is not available", name);
+            }
+        };
+
+        debug!("Ask AI: Executing tool '{}'", name);
+        let result = match tool {
+            AskAiTool::SearchHistory => self.search_history(&call.function.arguments),
+            AskAiTool::QueryKnowledgeBase => {
+                self.query_knowledge_base(&call.function.arguments).await
+            }
+            AskAiTool::CurrentDateTime => {
+                Ok(Local::now().format("%A, %B %e, %Y %H:%M %Z").to_string())
+            }
+            AskAiTool::OpenUrl => self.open_url(&call.function.arguments),
+        };
+
+        match result {
+            Ok(output) => truncate_output(output),
+            Err(e) => format!("Error: {}", e),
+        }
+    }
+
+    fn search_history(&self, arguments: &serde_json::Value) -> Result<String, String> {
+        let query = string_argument(arguments, "query")?;
+        let history_manager = self.app_handle.state::<Arc<HistoryManager>>();
+        let entries = history_manager
+            .search_entries(&query, MAX_HISTORY_RESULTS)
+            .map_err(|e| format!("History search failed: {}", e))?;
+
+        if entries.is_empty() {
+            return Ok(format!("No transcriptions found containing '{}'", query));
+        }
+
+        Ok(entries
+            .iter()
+            .map(|entry| {
+                let text = entry
+                    .post_processed_text
+                    .as_deref()
+                    .unwrap_or(&entry.transcription_text);
+                format!("[{}] {}", entry.title, text)
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    async fn query_knowledge_base(&self, arguments: &serde_json::Value) -> Result<String, String> {
+        let query = string_argument(arguments, "query")?;
+        let kb_settings = get_settings(&self.app_handle).knowledge_base;
+        if !kb_settings.enabled {
+            return Err("The knowledge base is disabled".to_string());
+        }
+
+        let rag_manager = self.app_handle.state::<Arc<RagManager>>().inner().clone();
+        let results = rag_manager.search(&query, kb_settings.top_k).await?;
+        let relevant: Vec<String> = results
+            .into_iter()
+            .filter(|r| r.similarity >= kb_settings.similarity_threshold)
+            .map(|r| match r.title {
+                Some(title) => format!("[{}] {}", title, r.chunk_text),
+                None => r.chunk_text,
+            })
+            .collect();
+
+        if relevant.is_empty() {
+            Ok(format!("No knowledge base entries relevant to '{}'", query))
+        } else {
+            Ok(relevant.join("\n---\n"))
+        }
+    }
+
+    fn open_url(&self, arguments: &serde_json::Value) -> Result<String, String> {
+        let url = string_argument(arguments, "url")?;
+        validate_url(&url)?;
+        self.app_handle
+            .opener()
+            .open_url(&url, None::<String>)
+            .map_err(|e| format!("Failed to open URL: {}", e))?;
+        Ok(format!("Opened {}", url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tool_name_roundtrip() {
+        for tool in AskAiTool::ALL {
+            assert_eq!(AskAiTool::from_name(tool.name()), Some(tool));
+        }
+        assert_eq!(AskAiTool::from_name("rm_rf"), None);
+    }
+
+    #[test]
+    fn test_allowed_tools_ignores_unknown_names() {
+        let allowed = allowed_tools(&[
+            "current_datetime".to_string(),
+            "delete_everything".to_string(),
+        ]);
+        assert_eq!(allowed, vec![AskAiTool::CurrentDateTime]);
+    }
+
+    #[test]
+    fn test_string_argument_accepts_object_and_encoded_string() {
+        let object = json!({ "query": "budget" });
+        assert_eq!(string_argument(&object, "query").unwrap(), "budget");
+
+        let encoded = json!("{\"query\": \"roadmap\"}");
+        assert_eq!(string_argument(&encoded, "query").unwrap(), "roadmap");
+
+        assert!(string_argument(&json!({}), "query").is_err());
+        assert!(string_argument(&json!({ "query": "  " }), "query").is_err());
+    }
+
+    #[test]
+    fn test_validate_url_rejects_non_web_schemes() {
+        assert!(validate_url("https://example.com").is_ok());
+        assert!(validate_url("HTTP://example.com").is_ok());
+        assert!(validate_url("file:///etc/passwd").is_err());
+        assert!(validate_url("javascript:alert(1)").is_err());
+    }
+
+    #[test]
+    fn test_truncate_output() {
+        let long = "a".repeat(MAX_TOOL_OUTPUT_CHARS + 10);
+        let truncated = truncate_output(long);
+        assert!(truncated.ends_with("[truncated]"));
+        assert_eq!(truncate_output("short".to_string()), "short");
+    }
+}
@@ -1,12 +1,80 @@
-use crate::audio_toolkit::{list_input_devices, vad::SmoothedVad, AudioRecorder, SileroVad};
-use crate::helpers::clamshell;
-use crate::settings::{get_settings, AppSettings};
+use crate::actions;
+use crate::audio_feedback;
+use crate::audio_toolkit::{
+    audio::Pause, find_device, find_device_index, list_input_devices, list_output_devices,
+    vad::VadConfig, AudioRecorder, CpalDeviceInfo, DigitalSilenceMonitor,
+};
+use crate::event_trail;
+use crate::helpers::{clamshell, system_mute};
+use crate::managers::docking;
+use crate::managers::transcription::TranscriptionManager;
+use crate::managers::wake_word::WakeWordManager;
+use crate::permission_monitor;
+use crate::settings::{get_settings, write_settings, AppSettings};
+use crate::tray::TrayLevelIndicator;
 use crate::utils;
 use log::{debug, error, info, warn};
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::thread;
+use std::time::{Duration, Instant};
 use tauri::Manager;
 
+/// Point a saved device selection at the device it resolves to, recording
+/// its stable id and current name. Returns whether anything changed.
+fn refresh_device_selection(
+    devices: &[CpalDeviceInfo],
+    id: &mut Option<String>,
+    name: &mut Option<String>,
+) -> bool {
+    if name.is_none() {
+        return false;
+    }
+    let Some(index) = find_device_index(devices, id.as_deref(), name.as_deref()) else {
+        return false;
+    };
+    let device = &devices[index];
+    if id.as_deref() == Some(device.id.as_str()) && name.as_deref() == Some(device.name.as_str()) {
+        return false;
+    }
+    *id = Some(device.id.clone());
+    *name = Some(device.name.clone());
+    true
+}
+
+/// Add stable ids to device selections saved by name, and follow devices
+/// whose name changed since they were selected
+fn migrate_device_ids(app: &tauri::AppHandle) {
+    let inputs = list_input_devices().unwrap_or_default();
+    let outputs = list_output_devices().unwrap_or_default();
+
+    let mut settings = get_settings(app);
+    let mut changed = refresh_device_selection(
+        &inputs,
+        &mut settings.selected_microphone_id,
+        &mut settings.selected_microphone,
+    );
+    changed |= refresh_device_selection(
+        &inputs,
+        &mut settings.clamshell_microphone_id,
+        &mut settings.clamshell_microphone,
+    );
+    changed |= refresh_device_selection(
+        &outputs,
+        &mut settings.selected_output_device_id,
+        &mut settings.selected_output_device,
+    );
+
+    if changed {
+        info!("Updated saved audio device selections to stable device ids");
+        write_settings(app, settings);
+    }
+}
+
+/// How often recording progress is sent to the overlay
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+/// Share of the recording limit after which the overlay warns about it
+const RECORDING_LIMIT_WARNING: f32 = 0.8;
+
 /// Helper macro to safely acquire a mutex lock and return early on failure
 macro_rules! safe_lock {
     ($mutex:expr) => {
@@ -38,95 +106,6 @@ macro_rules! safe_lock_err {
     };
 }
 
-fn set_mute(mute: bool) {
-    // Expected behavior:
-    // - Windows: works on most systems using standard audio drivers.
-    // - Linux: works on many systems (PipeWire, PulseAudio, ALSA),
-    //   but some distros may lack the tools used.
-    // - macOS: works on most standard setups via AppleScript.
-    // If unsupported, fails silently.
-
-    #[cfg(target_os = "windows")]
-    {
-        unsafe {
-            use windows::Win32::{
-                Media::Audio::{
-                    eMultimedia, eRender, Endpoints::IAudioEndpointVolume, IMMDeviceEnumerator,
-                    MMDeviceEnumerator,
-                },
-                System::Com::{CoCreateInstance, CoInitializeEx, CLSCTX_ALL, COINIT_MULTITHREADED},
-            };
-
-            macro_rules! unwrap_or_return {
-                ($expr:expr) => {
-                    match $expr {
-                        Ok(val) => val,
-                        Err(_) => return,
-                    }
-                };
-            }
-
-            // Initialize the COM library for this thread.
-            // If already initialized (e.g., by another library like Tauri), this does nothing.
-            let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
-
-            let all_devices: IMMDeviceEnumerator =
-                unwrap_or_return!(CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL));
-            let default_device =
-                unwrap_or_return!(all_devices.GetDefaultAudioEndpoint(eRender, eMultimedia));
-            let volume_interface = unwrap_or_return!(
-                default_device.Activate::<IAudioEndpointVolume>(CLSCTX_ALL, None)
-            );
-
-            let _ = volume_interface.SetMute(mute, std::ptr::null());
-        }
-    }
-
-    #[cfg(target_os = "linux")]
-    {
-        use std::process::Command;
-
-        let mute_val = if mute { "1" } else { "0" };
-        let amixer_state = if mute { "mute" } else { "unmute" };
-
-        // Try multiple backends to increase compatibility
-        // 1. PipeWire (wpctl)
-        if Command::new("wpctl")
-            .args(["set-mute", "@DEFAULT_AUDIO_SINK@", mute_val])
-            .output()
-            .map(|o| o.status.success())
-            .unwrap_or(false)
-        {
-            return;
-        }
-
-        // 2. PulseAudio (pactl)
-        if Command::new("pactl")
-            .args(["set-sink-mute", "@DEFAULT_SINK@", mute_val])
-            .output()
-            .map(|o| o.status.success())
-            .unwrap_or(false)
-        {
-            return;
-        }
-
-        // 3. ALSA (amixer)
-        let _ = Command::new("amixer")
-            .args(["set", "Master", amixer_state])
-            .output();
-    }
-
-    #[cfg(target_os = "macos")]
-    {
-        use std::process::Command;
-        let script = format!(
-            "set volume output muted {}",
-            if mute { "true" } else { "false" }
-        );
-        let _ = Command::new("osascript").args(["-e", &script]).output();
-    }
-}
-
 const WHISPER_SAMPLE_RATE: usize = 16000;
 
 /* ──────────────────────────────────────────────────────────────── */
@@ -144,16 +123,49 @@ pub enum MicrophoneMode {
     ActiveListening,
 }
 
+/// Ends a toggle-mode recording once the VAD has heard no speech for a while
+struct SilenceAutoStop {
+    binding_id: String,
+    limit: Duration,
+    silence: Duration,
+}
+
+impl SilenceAutoStop {
+    fn new(binding_id: &str, limit: Duration) -> Self {
+        Self {
+            binding_id: binding_id.to_string(),
+            limit,
+            silence: Duration::ZERO,
+        }
+    }
+
+    /// Feed the VAD's decision on a frame of `len` samples; returns whether
+    /// the recording has been silent for too long
+    fn push(&mut self, len: usize, speech: bool) -> bool {
+        if speech {
+            self.silence = Duration::ZERO;
+        } else {
+            self.silence += Duration::from_secs_f64(len as f64 / WHISPER_SAMPLE_RATE as f64);
+        }
+        self.silence >= self.limit
+    }
+}
+
 /* ──────────────────────────────────────────────────────────────── */
 
 fn create_audio_recorder(
     vad_path: &str,
     app_handle: &tauri::AppHandle,
     sample_callback: Option<ActiveListeningCallback>,
+    silence_monitor: Arc<Mutex<DigitalSilenceMonitor>>,
 ) -> Result<AudioRecorder, anyhow::Error> {
-    let silero = SileroVad::new(vad_path, 0.3)
+    let settings = get_settings(app_handle);
+    let general = settings.general;
+    let threshold = general.vad_threshold.clamp(0.0, 1.0);
+    let smoothed_vad = VadConfig::default()
+        .with_threshold(threshold)
+        .build(vad_path)
         .map_err(|e| anyhow::anyhow!("Failed to create SileroVad: {}", e))?;
-    let smoothed_vad = SmoothedVad::new(Box::new(silero), 15, 15, 2);
 
     // Recorder with VAD plus a spectrum-level callback that forwards updates to
     // the frontend.
@@ -162,21 +174,99 @@ fn create_audio_recorder(
         .with_vad(Box::new(smoothed_vad))
         .with_level_callback({
             let app_handle = app_handle.clone();
+            let tray_level = TrayLevelIndicator::default();
             move |levels| {
                 utils::emit_levels(&app_handle, &levels);
+                tray_level.update(&app_handle, &levels);
             }
         });
 
-    // If a sample callback is provided, wire it up for Active Listening
-    if let Some(cb) = sample_callback {
-        recorder = recorder.with_sample_callback(move |samples| {
-            cb(samples);
-        });
+    if general.low_power_idle {
+        recorder = recorder.with_idle_gate(general.idle_wake_threshold.max(0.0));
+    }
+
+    if general.echo_cancellation {
+        recorder = recorder.with_echo_cancellation(audio_feedback::playback_reference());
+    }
+
+    if settings.noise_suppression.microphone {
+        recorder = recorder.with_noise_suppression();
+    }
+
+    // Active Listening streams continuously, so there's nothing to look back on
+    if general.retroactive_capture && sample_callback.is_none() {
+        recorder = recorder.with_lookback(Duration::from_secs_f32(
+            general.retroactive_capture_seconds.max(0.0),
+        ));
     }
 
+    // End toggle-mode recordings nobody speaks into anymore
+    recorder = recorder.with_speech_callback({
+        let app_handle = app_handle.clone();
+        move |samples, speech| {
+            if let Some(audio) = app_handle.try_state::<Arc<AudioRecordingManager>>() {
+                audio.push_voice_activity(samples, speech);
+            }
+        }
+    });
+
+    // Listen for the wake word while not recording
+    recorder = recorder.with_idle_sample_callback({
+        let app_handle = app_handle.clone();
+        move |samples| {
+            if let Some(wake_word) = app_handle.try_state::<Arc<WakeWordManager>>() {
+                wake_word.push_idle(samples);
+            }
+        }
+    });
+
+    // Watch recorded frames for a dead stream, feed them to the live
+    // transcription preview and the wake word's end of speech detection, and
+    // forward them for Active Listening if a sample callback is provided
+    let app_handle = app_handle.clone();
+    recorder = recorder.with_sample_callback(move |samples| {
+        let stream_died = silence_monitor
+            .lock()
+            .map(|mut monitor| monitor.push(samples))
+            .unwrap_or(false);
+        if stream_died {
+            permission_monitor::handle_silent_stream(&app_handle);
+        }
+
+        if let Some(tm) = app_handle.try_state::<Arc<TranscriptionManager>>() {
+            tm.push_preview_samples(samples);
+        }
+
+        if let Some(wake_word) = app_handle.try_state::<Arc<WakeWordManager>>() {
+            wake_word.push_recording(samples);
+        }
+
+        if let Some(cb) = &sample_callback {
+            cb(samples);
+        }
+    });
+
     Ok(recorder)
 }
 
+/// Stop the recording that ran into the recording limit the way its
+/// shortcut would, which transcribes what was said
+fn stop_at_limit(app: &tauri::AppHandle, state: &Mutex<RecordingState>, limit: Duration) {
+    let binding_id = match &*safe_lock!(state) {
+        RecordingState::Recording { binding_id } => binding_id.clone(),
+        RecordingState::Idle => return,
+    };
+    warn!(
+        "Recording reached the {}-minute limit, stopping '{}'",
+        limit.as_secs() / 60,
+        binding_id
+    );
+    utils::set_toggle(app, &binding_id, false);
+    if let Some(action) = actions::action_for(&binding_id) {
+        action.stop(app, &binding_id, "RecordingLimit");
+    }
+}
+
 /* ──────────────────────────────────────────────────────────────── */
 
 /// Callback type for active listening audio samples
@@ -195,12 +285,20 @@ pub struct AudioRecordingManager {
 
     /// Callback for forwarding audio samples in active listening mode
     active_listening_callback: Arc<Mutex<Option<ActiveListeningCallback>>>,
+    /// Detects a stream that went silent, e.g. after the mic permission was revoked
+    silence_monitor: Arc<Mutex<DigitalSilenceMonitor>>,
+    /// When the current recording started; drives the overlay's timer
+    recording_started_at: Arc<Mutex<Option<Instant>>>,
+    /// Silence in the current recording, if it stops itself after too much
+    silence_auto_stop: Arc<Mutex<Option<SilenceAutoStop>>>,
 }
 
 impl AudioRecordingManager {
     /* ---------- construction ------------------------------------------------ */
 
     pub fn new(app: &tauri::AppHandle) -> Result<Self, anyhow::Error> {
+        migrate_device_ids(app);
+
         let settings = get_settings(app);
         let mode = if settings.always_on_microphone {
             MicrophoneMode::AlwaysOn
@@ -218,6 +316,12 @@ impl AudioRecordingManager {
             is_recording: Arc::new(Mutex::new(false)),
             did_mute: Arc::new(Mutex::new(false)),
             active_listening_callback: Arc::new(Mutex::new(None)),
+            silence_monitor: Arc::new(Mutex::new(DigitalSilenceMonitor::new(
+                WHISPER_SAMPLE_RATE,
+                permission_monitor::SILENT_STREAM_LIMIT_SECS,
+            ))),
+            recording_started_at: Arc::new(Mutex::new(None)),
+            silence_auto_stop: Arc::new(Mutex::new(None)),
         };
 
         // Always-on?  Open immediately.
@@ -230,7 +334,25 @@ impl AudioRecordingManager {
 
     /* ---------- helper methods --------------------------------------------- */
 
-    fn get_effective_microphone_device(&self, settings: &AppSettings) -> Option<cpal::Device> {
+    pub fn get_effective_microphone_device(&self, settings: &AppSettings) -> Option<cpal::Device> {
+        let mut devices = match list_input_devices() {
+            Ok(devices) => devices,
+            Err(e) => {
+                debug!("Failed to list devices, using default: {}", e);
+                return None;
+            }
+        };
+
+        // A dock rule's microphone wins while its device is connected
+        if let Some(dock_mic) = docking::active_overrides(&self.app_handle, settings)
+            .and_then(|overrides| overrides.microphone)
+        {
+            if let Some(index) = find_device_index(&devices, None, Some(dock_mic.as_str())) {
+                return Some(devices.swap_remove(index).device);
+            }
+            debug!("Dock microphone '{}' is not connected", dock_mic);
+        }
+
         // Check if we're in clamshell mode and have a clamshell microphone configured
         let use_clamshell_mic = if let Ok(is_clamshell) = clamshell::is_clamshell() {
             is_clamshell && settings.clamshell_microphone.is_some()
@@ -238,23 +360,92 @@ impl AudioRecordingManager {
             false
         };
 
-        let device_name = if use_clamshell_mic {
-            settings.clamshell_microphone.as_ref().unwrap()
+        let (device_id, device_name) = if use_clamshell_mic {
+            (
+                settings.clamshell_microphone_id.as_deref(),
+                settings.clamshell_microphone.as_deref().unwrap(),
+            )
         } else {
-            settings.selected_microphone.as_ref()?
+            (
+                settings.selected_microphone_id.as_deref(),
+                settings.selected_microphone.as_deref()?,
+            )
         };
 
-        // Find the device by name
-        match list_input_devices() {
-            Ok(devices) => devices
-                .into_iter()
-                .find(|d| d.name == *device_name)
-                .map(|d| d.device),
-            Err(e) => {
-                debug!("Failed to list devices, using default: {}", e);
-                None
+        find_device(devices, device_id, Some(device_name)).map(|d| d.device)
+    }
+
+    /* ---------- recording progress ------------------------------------------ */
+
+    /// Start the clock for a recording and emit its progress until it ends.
+    /// With `count_words` it also reports how many words were transcribed so
+    /// far. Only `limited` recordings stop at the recording limit.
+    fn start_progress_timer(&self, count_words: bool, limited: bool) {
+        let started = Instant::now();
+        *safe_lock!(self.recording_started_at) = Some(started);
+
+        let transcription_manager = self
+            .app_handle
+            .try_state::<Arc<TranscriptionManager>>()
+            .map(|tm| tm.inner().clone());
+        if count_words {
+            if let Some(tm) = &transcription_manager {
+                tm.reset_streamed_word_count();
             }
         }
+
+        let general = get_settings(&self.app_handle).general;
+        let limit = (general.recording_limit && limited)
+            .then(|| Duration::from_secs(general.recording_limit_minutes as u64 * 60));
+
+        let started_at = self.recording_started_at.clone();
+        let state = self.state.clone();
+        let app_handle = self.app_handle.clone();
+        thread::spawn(move || {
+            let mut warned = false;
+            loop {
+                // Stop once the recording ends or a newer one replaces it
+                let current = started_at.lock().map(|s| *s).unwrap_or(None);
+                if current != Some(started) {
+                    break;
+                }
+
+                let elapsed = started.elapsed();
+                if let Some(limit) = limit {
+                    if elapsed >= limit {
+                        stop_at_limit(&app_handle, &state, limit);
+                        break;
+                    }
+                    if !warned && elapsed >= limit.mul_f32(RECORDING_LIMIT_WARNING) {
+                        warned = true;
+                        utils::emit_recording_limit_warning(
+                            &app_handle,
+                            &utils::RecordingLimitWarning {
+                                elapsed_ms: elapsed.as_millis() as u64,
+                                limit_ms: limit.as_millis() as u64,
+                            },
+                        );
+                    }
+                }
+
+                let word_count = transcription_manager
+                    .as_ref()
+                    .filter(|_| count_words)
+                    .map(|tm| tm.streamed_word_count());
+                utils::emit_recording_progress(
+                    &app_handle,
+                    &utils::RecordingProgress {
+                        elapsed_ms: elapsed.as_millis() as u64,
+                        word_count,
+                    },
+                );
+                thread::sleep(PROGRESS_INTERVAL);
+            }
+        });
+    }
+
+    fn stop_progress_timer(&self) {
+        *safe_lock!(self.recording_started_at) = None;
     }
 
     /* ---------- microphone life-cycle -------------------------------------- */
@@ -273,7 +464,7 @@ impl AudioRecordingManager {
         };
 
         if settings.general.mute_while_recording && is_open {
-            set_mute(true);
+            system_mute::set_output_mute(true);
             *did_mute_guard = true;
             debug!("Mute applied");
         }
@@ -283,7 +474,7 @@ impl AudioRecordingManager {
     pub fn remove_mute(&self) {
         let mut did_mute_guard = safe_lock!(self.did_mute);
         if *did_mute_guard {
-            set_mute(false);
+            system_mute::set_output_mute(false);
             *did_mute_guard = false;
             debug!("Mute removed");
         }
@@ -330,6 +521,7 @@ impl AudioRecordingManager {
                 vad_path_str,
                 &self.app_handle,
                 sample_callback,
+                self.silence_monitor.clone(),
             )?);
         }
 
@@ -358,7 +550,7 @@ impl AudioRecordingManager {
 
         let mut did_mute_guard = safe_lock!(self.did_mute);
         if *did_mute_guard {
-            set_mute(false);
+            system_mute::set_output_mute(false);
         }
         *did_mute_guard = false;
 
@@ -379,6 +571,38 @@ impl AudioRecordingManager {
         debug!("Microphone stream stopped");
     }
 
+    /* ---------- pre-arming -------------------------------------------------- */
+
+    /// Open the stream ahead of a recording, while the shortcut is still being
+    /// pressed. Only applies in on-demand mode.
+    pub fn prearm_microphone_stream(&self) {
+        let state = safe_lock!(self.state);
+        if !matches!(*state, RecordingState::Idle) || !self.is_on_demand() {
+            return;
+        }
+        if let Err(e) = self.start_microphone_stream() {
+            warn!("Failed to pre-arm microphone stream: {e}");
+        } else {
+            debug!("Microphone stream pre-armed");
+        }
+    }
+
+    /// Close a pre-armed stream the shortcut didn't go on to record with
+    pub fn disarm_microphone_stream(&self) {
+        // Hold the state lock so a recording can't start while closing
+        let state = safe_lock!(self.state);
+        if matches!(*state, RecordingState::Idle) && self.is_on_demand() {
+            self.stop_microphone_stream();
+        }
+    }
+
+    fn is_on_demand(&self) -> bool {
+        match self.mode.lock() {
+            Ok(mode) => matches!(*mode, MicrophoneMode::OnDemand),
+            Err(_) => false,
+        }
+    }
+
     /* ---------- mode switching --------------------------------------------- */
 
     pub fn update_mode(&self, new_mode: MicrophoneMode) -> Result<(), anyhow::Error> {
@@ -427,14 +651,29 @@ impl AudioRecordingManager {
 
             if let Ok(recorder_guard) = self.recorder.lock() {
                 if let Some(rec) = recorder_guard.as_ref() {
+                    if let Ok(mut monitor) = self.silence_monitor.lock() {
+                        monitor.reset();
+                    }
+                    self.arm_silence_auto_stop(binding_id);
                     if rec.start().is_ok() {
                         if let Ok(mut is_rec) = self.is_recording.lock() {
                             *is_rec = true;
                         }
-                        *state = RecordingState::Recording {
+                        let recording = RecordingState::Recording {
                             binding_id: binding_id.to_string(),
                         };
+                        event_trail::record_transition(
+                            &self.app_handle,
+                            "audio",
+                            &*state,
+                            &recording,
+                        );
+                        *state = recording;
                         debug!("Recording started for binding {binding_id}");
+                        let streaming_preview =
+                            get_settings(&self.app_handle).general.streaming_preview;
+                        self.start_progress_timer(streaming_preview, true);
+                        permission_monitor::watch_microphone_authorization(&self.app_handle);
                         return true;
                     }
                 }
@@ -446,6 +685,57 @@ impl AudioRecordingManager {
         }
     }
 
+    /// Have the recording about to start for `binding_id` stop itself after
+    /// the configured silence, when it's toggled rather than held
+    fn arm_silence_auto_stop(&self, binding_id: &str) {
+        let general = get_settings(&self.app_handle).general;
+        let auto_stop = (general.silence_auto_stop && !general.push_to_talk).then(|| {
+            SilenceAutoStop::new(
+                binding_id,
+                Duration::from_secs_f32(general.silence_auto_stop_seconds.max(0.0)),
+            )
+        });
+        *safe_lock!(self.silence_auto_stop) = auto_stop;
+    }
+
+    fn disarm_silence_auto_stop(&self) {
+        *safe_lock!(self.silence_auto_stop) = None;
+    }
+
+    /// Feed the VAD's decision on a recorded frame. Once a recording armed
+    /// for it has been silent long enough, its binding is stopped as if
+    /// toggled off, which transcribes what was said.
+    pub fn push_voice_activity(&self, samples: &[f32], speech: bool) {
+        let mut auto_stop = safe_lock!(self.silence_auto_stop);
+        let Some(current) = auto_stop.as_mut() else {
+            return;
+        };
+        if !current.push(samples.len(), speech) {
+            return;
+        }
+        let binding_id = std::mem::take(&mut current.binding_id);
+        let limit = current.limit;
+        *auto_stop = None;
+        drop(auto_stop);
+
+        info!(
+            "No speech for {:.0}s, stopping '{}'",
+            limit.as_secs_f32(),
+            binding_id
+        );
+        // Stopping waits for the audio thread this is called on
+        let app = self.app_handle.clone();
+        thread::spawn(move || {
+            // Already stopped some other way
+            if !utils::set_toggle(&app, &binding_id, false) {
+                return;
+            }
+            if let Some(action) = actions::action_for(&binding_id) {
+                action.stop(&app, &binding_id, "SilenceAutoStop");
+            }
+        });
+    }
+
     pub fn update_selected_device(&self) -> Result<(), anyhow::Error> {
         // If currently open, restart the microphone stream to use the new device
         let is_open = match self.is_open.lock() {
@@ -459,32 +749,63 @@ impl AudioRecordingManager {
         Ok(())
     }
 
+    /// Rebuild the recorder so a changed VAD threshold takes effect
+    pub fn reload_vad(&self) -> Result<(), anyhow::Error> {
+        if self.is_recording() {
+            return Err(anyhow::anyhow!("Cannot change the VAD while recording"));
+        }
+        let is_open = *safe_lock_err!(self.is_open);
+        if is_open {
+            self.stop_microphone_stream();
+        }
+        *safe_lock_err!(self.recorder) = None;
+        if is_open {
+            self.start_microphone_stream()?;
+        }
+        Ok(())
+    }
+
     pub fn stop_recording(&self, binding_id: &str) -> Option<Vec<f32>> {
+        self.stop_recording_with_pauses(binding_id)
+            .map(|(samples, _)| samples)
+    }
+
+    /// Stop recording and return the samples with the pauses the VAD cut out
+    /// of them
+    pub fn stop_recording_with_pauses(&self, binding_id: &str) -> Option<(Vec<f32>, Vec<Pause>)> {
         let mut state = safe_lock!(self.state, None);
 
         match *state {
             RecordingState::Recording {
                 binding_id: ref active,
             } if active == binding_id => {
+                event_trail::record_transition(
+                    &self.app_handle,
+                    "audio",
+                    &*state,
+                    &RecordingState::Idle,
+                );
                 *state = RecordingState::Idle;
                 drop(state);
+                self.stop_progress_timer();
+                self.disarm_silence_auto_stop();
 
-                let samples = if let Ok(recorder_guard) = self.recorder.lock() {
+                let (samples, pauses) = if let Ok(recorder_guard) = self.recorder.lock() {
                     if let Some(rec) = recorder_guard.as_ref() {
-                        match rec.stop() {
-                            Ok(buf) => buf,
+                        match rec.stop_with_pauses() {
+                            Ok(recording) => recording,
                             Err(e) => {
                                 error!("stop() failed: {e}");
-                                Vec::new()
+                                Default::default()
                             }
                         }
                     } else {
                         error!("Recorder not available");
-                        Vec::new()
+                        Default::default()
                     }
                 } else {
                     error!("Failed to lock recorder");
-                    Vec::new()
+                    Default::default()
                 };
 
                 if let Ok(mut is_rec) = self.is_recording.lock() {
@@ -506,9 +827,9 @@ impl AudioRecordingManager {
                 if s_len < WHISPER_SAMPLE_RATE && s_len > 0 {
                     let mut padded = samples;
                     padded.resize(WHISPER_SAMPLE_RATE * 5 / 4, 0.0);
-                    Some(padded)
+                    Some((padded, pauses))
                 } else {
-                    Some(samples)
+                    Some((samples, pauses))
                 }
             }
             _ => None,
@@ -526,8 +847,16 @@ impl AudioRecordingManager {
         let mut state = safe_lock!(self.state);
 
         if let RecordingState::Recording { .. } = *state {
+            event_trail::record_transition(
+                &self.app_handle,
+                "audio",
+                &*state,
+                &RecordingState::Idle,
+            );
             *state = RecordingState::Idle;
             drop(state);
+            self.stop_progress_timer();
+            self.disarm_silence_auto_stop();
 
             if let Ok(recorder_guard) = self.recorder.lock() {
                 if let Some(rec) = recorder_guard.as_ref() {
@@ -594,12 +923,15 @@ impl AudioRecordingManager {
         {
             let recorder_guard = safe_lock_err!(self.recorder);
             if let Some(rec) = recorder_guard.as_ref() {
+                safe_lock_err!(self.silence_monitor).reset();
                 rec.start()
                     .map_err(|e| anyhow::anyhow!("Failed to start recording: {}", e))?;
                 let mut is_rec = safe_lock_err!(self.is_recording);
                 *is_rec = true;
             }
         }
+        // Active Listening sessions are meant to run for hours
+        self.start_progress_timer(true, false);
 
         info!("Active listening started");
         Ok(())
@@ -617,6 +949,7 @@ impl AudioRecordingManager {
         }
 
         // Stop recording
+        self.stop_progress_timer();
         if let Ok(recorder_guard) = self.recorder.lock() {
             if let Some(rec) = recorder_guard.as_ref() {
                 let _ = rec.stop();
@@ -689,3 +1022,23 @@ impl AudioRecordingManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_silence_auto_stop_resets_on_speech() {
+        // 30 ms frames at 16 kHz
+        const FRAME: usize = 480;
+        let mut auto_stop = SilenceAutoStop::new("transcribe", Duration::from_millis(300));
+        for _ in 0..9 {
+            assert!(!auto_stop.push(FRAME, false));
+        }
+        assert!(!auto_stop.push(FRAME, true));
+        for _ in 0..9 {
+            assert!(!auto_stop.push(FRAME, false));
+        }
+        assert!(auto_stop.push(FRAME, false));
+    }
+}
@@ -0,0 +1,464 @@
+//! Backup Manager
+//!
+//! Snapshots the app's settings and databases (transcription and Ask AI
+//! history, knowledge base, vocabulary) into timestamped folders on a
+//! schedule, rotates old snapshots, and restores a snapshot on request.
+//! Recordings are only included when the user opts in.
+
+use crate::access_policy;
+use crate::events::BackupCompleted;
+use crate::settings::{get_settings, settings_store_path, BackupSettings};
+use chrono::{Local, Utc};
+use log::{debug, error, info, warn};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+use tauri_specta::Event;
+
+/// How often the scheduler checks whether a backup is due
+const CHECK_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Delay before the first check so backups don't compete with startup
+const STARTUP_DELAY: Duration = Duration::from_secs(60);
+
+const BACKUP_PREFIX: &str = "dictum-backup-";
+const MANIFEST_FILE: &str = "manifest.json";
+const SETTINGS_FILE: &str = "settings.json";
+const RECORDINGS_DIR: &str = "recordings";
+
+/// SQLite databases in the app data dir that are backed up.
+/// Ask AI conversations live in history.db alongside transcriptions.
+const DATABASES: [&str; 3] = ["history.db", "rag.db", "vocabulary.db"];
+
+/// Metadata written into every backup folder
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupManifest {
+    created_at: i64,
+    app_version: String,
+    files: Vec<String>,
+    includes_audio: bool,
+}
+
+/// A backup available for restore
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct BackupInfo {
+    pub id: String,
+    pub created_at: i64,
+    pub app_version: String,
+    pub files: Vec<String>,
+    pub includes_audio: bool,
+    pub size_bytes: u64,
+}
+
+/// Copy a consistent snapshot of a SQLite database to `dest`
+fn snapshot_database(src: &Path, dest: &Path) -> Result<(), String> {
+    let conn =
+        Connection::open(src).map_err(|e| format!("Failed to open {}: {}", src.display(), e))?;
+    conn.execute("VACUUM INTO ?1", params![dest.to_string_lossy()])
+        .map_err(|e| format!("Failed to snapshot {}: {}", src.display(), e))?;
+    Ok(())
+}
+
+/// Total size of the files under `dir`
+fn dir_size(dir: &Path) -> u64 {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| match entry.metadata() {
+                    Ok(meta) if meta.is_dir() => dir_size(&entry.path()),
+                    Ok(meta) => meta.len(),
+                    Err(_) => 0,
+                })
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// Copy the regular files directly inside `src` into `dest`
+fn copy_files(src: &Path, dest: &Path) -> Result<usize, String> {
+    fs::create_dir_all(dest).map_err(|e| e.to_string())?;
+    let mut copied = 0;
+    for entry in fs::read_dir(src).map_err(|e| e.to_string())?.flatten() {
+        let path = entry.path();
+        if path.is_file() {
+            fs::copy(&path, dest.join(entry.file_name())).map_err(|e| e.to_string())?;
+            copied += 1;
+        }
+    }
+    Ok(copied)
+}
+
+/// Read the backups in `dir`, newest first
+fn read_backups(dir: &Path) -> Vec<BackupInfo> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut backups: Vec<BackupInfo> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let id = entry.file_name().to_string_lossy().to_string();
+            if !id.starts_with(BACKUP_PREFIX) || !entry.path().is_dir() {
+                return None;
+            }
+            let manifest: BackupManifest =
+                serde_json::from_slice(&fs::read(entry.path().join(MANIFEST_FILE)).ok()?).ok()?;
+            Some(BackupInfo {
+                id,
+                created_at: manifest.created_at,
+                app_version: manifest.app_version,
+                files: manifest.files,
+                includes_audio: manifest.includes_audio,
+                size_bytes: dir_size(&entry.path()),
+            })
+        })
+        .collect();
+
+    backups.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    backups
+}
+
+/// Delete all but the newest `keep_count` backups, returning how many were removed
+fn rotate_backups(dir: &Path, keep_count: usize) -> usize {
+    let mut removed = 0;
+    for backup in read_backups(dir).into_iter().skip(keep_count.max(1)) {
+        match fs::remove_dir_all(dir.join(&backup.id)) {
+            Ok(()) => removed += 1,
+            Err(e) => warn!("Failed to remove old backup {}: {}", backup.id, e),
+        }
+    }
+    removed
+}
+
+/// Whether a scheduled backup should run given the newest existing backup
+fn is_backup_due(settings: &BackupSettings, newest: Option<i64>, now: i64) -> bool {
+    if !settings.enabled {
+        return false;
+    }
+    let interval = settings.interval_hours.max(1) as i64 * 60 * 60;
+    newest.map_or(true, |created_at| now - created_at >= interval)
+}
+
+/// Backup ids are folder names we created; reject anything else
+fn validate_backup_id(id: &str) -> Result<(), String> {
+    if !id.starts_with(BACKUP_PREFIX) || id.contains(['/', '\\']) || id.contains("..") {
+        return Err(format!("Invalid backup id: {}", id));
+    }
+    Ok(())
+}
+
+pub struct BackupManager {
+    app_handle: AppHandle,
+    app_data_dir: PathBuf,
+    /// Serializes backup and restore operations
+    lock: Mutex<()>,
+}
+
+impl BackupManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self, String> {
+        let app_data_dir = crate::portable::app_data_dir(app_handle)
+            .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+
+        Ok(Self {
+            app_handle: app_handle.clone(),
+            app_data_dir,
+            lock: Mutex::new(()),
+        })
+    }
+
+    /// Folder backups are written to and read from
+    fn backup_dir(&self, settings: &BackupSettings) -> PathBuf {
+        settings
+            .folder
+            .as_deref()
+            .filter(|folder| !folder.trim().is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| self.app_data_dir.join("backups"))
+    }
+
+    /// Start the background scheduler
+    pub fn start(self: &Arc<Self>) {
+        let manager = self.clone();
+        tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(STARTUP_DELAY).await;
+            loop {
+                let settings = get_settings(&manager.app_handle).backup;
+                let newest = read_backups(&manager.backup_dir(&settings))
+                    .first()
+                    .map(|b| b.created_at);
+
+                if is_backup_due(&settings, newest, Utc::now().timestamp()) {
+                    let worker = manager.clone();
+                    match tokio::task::spawn_blocking(move || worker.create_backup()).await {
+                        Ok(Ok(backup)) => info!("Scheduled backup {} completed", backup.id),
+                        Ok(Err(e)) => error!("Scheduled backup failed: {}", e),
+                        Err(e) => error!("Scheduled backup task panicked: {}", e),
+                    }
+                }
+
+                tokio::time::sleep(CHECK_INTERVAL).await;
+            }
+        });
+    }
+
+    /// Create a backup now and rotate old ones
+    pub fn create_backup(&self) -> Result<BackupInfo, String> {
+        let settings = get_settings(&self.app_handle).backup;
+        let backup = self.snapshot(&settings)?;
+
+        let removed = rotate_backups(&self.backup_dir(&settings), settings.keep_count);
+        if removed > 0 {
+            debug!("Rotated out {} old backups", removed);
+        }
+
+        let _ = BackupCompleted(backup.clone()).emit(&self.app_handle);
+        Ok(backup)
+    }
+
+    /// Write a new backup folder without rotating
+    fn snapshot(&self, settings: &BackupSettings) -> Result<BackupInfo, String> {
+        let _guard = self.lock.lock().map_err(|e| e.to_string())?;
+
+        let dir = self.backup_dir(settings);
+        access_policy::check_path(&dir)?;
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create backup folder {}: {}", dir.display(), e))?;
+
+        let base_id = format!("{}{}", BACKUP_PREFIX, Local::now().format("%Y%m%d-%H%M%S"));
+        let mut id = base_id.clone();
+        let mut suffix = 1;
+        while dir.join(&id).exists() {
+            id = format!("{}-{}", base_id, suffix);
+            suffix += 1;
+        }
+
+        // Write into a staging folder so a half-written backup is never listed
+        let staging = dir.join(format!(".{}.partial", id));
+        let result = self.write_snapshot(&staging, settings.include_audio);
+        let manifest = match result {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                let _ = fs::remove_dir_all(&staging);
+                return Err(e);
+            }
+        };
+
+        let target = dir.join(&id);
+        fs::rename(&staging, &target).map_err(|e| format!("Failed to finalize backup: {}", e))?;
+        info!("Created backup {} ({} files)", id, manifest.files.len());
+
+        Ok(BackupInfo {
+            id,
+            created_at: manifest.created_at,
+            app_version: manifest.app_version,
+            files: manifest.files,
+            includes_audio: manifest.includes_audio,
+            size_bytes: dir_size(&target),
+        })
+    }
+
+    fn write_snapshot(
+        &self,
+        staging: &Path,
+        include_audio: bool,
+    ) -> Result<BackupManifest, String> {
+        fs::create_dir_all(staging).map_err(|e| e.to_string())?;
+        let mut files = Vec::new();
+
+        // Settings are read from the store so unsaved in-memory changes are included
+        let store = self
+            .app_handle
+            .store(settings_store_path())
+            .map_err(|e| format!("Failed to open settings store: {}", e))?;
+        if let Some(settings) = store.get("settings") {
+            let json = serde_json::to_vec_pretty(&serde_json::json!({ "settings": settings }))
+                .map_err(|e| e.to_string())?;
+            fs::write(staging.join(SETTINGS_FILE), json).map_err(|e| e.to_string())?;
+            files.push(SETTINGS_FILE.to_string());
+        }
+
+        for db in DATABASES {
+            let src = self.app_data_dir.join(db);
+            if src.exists() {
+                snapshot_database(&src, &staging.join(db))?;
+                files.push(db.to_string());
+            }
+        }
+
+        let recordings = self.app_data_dir.join(RECORDINGS_DIR);
+        let includes_audio = include_audio && recordings.exists();
+        if includes_audio {
+            let copied = copy_files(&recordings, &staging.join(RECORDINGS_DIR))
+                .map_err(|e| format!("Failed to copy recordings: {}", e))?;
+            debug!("Copied {} recordings into backup", copied);
+        }
+
+        let manifest = BackupManifest {
+            created_at: Utc::now().timestamp(),
+            app_version: self.app_handle.package_info().version.to_string(),
+            files,
+            includes_audio,
+        };
+        let json = serde_json::to_vec_pretty(&manifest).map_err(|e| e.to_string())?;
+        fs::write(staging.join(MANIFEST_FILE), json).map_err(|e| e.to_string())?;
+
+        Ok(manifest)
+    }
+
+    /// Backups in the configured folder, newest first
+    pub fn list_backups(&self) -> Vec<BackupInfo> {
+        let settings = get_settings(&self.app_handle).backup;
+        read_backups(&self.backup_dir(&settings))
+    }
+
+    /// Restore settings and databases from a backup. The current state is
+    /// snapshotted first so the restore can itself be undone. The app must be
+    /// restarted afterwards for managers to pick up the restored data.
+    pub fn restore_backup(&self, id: &str) -> Result<(), String> {
+        validate_backup_id(id)?;
+        let settings = get_settings(&self.app_handle).backup;
+        let source = self.backup_dir(&settings).join(id);
+        let manifest: BackupManifest = fs::read(source.join(MANIFEST_FILE))
+            .map_err(|e| format!("Backup {} not found: {}", id, e))
+            .and_then(|bytes| {
+                serde_json::from_slice(&bytes)
+                    .map_err(|e| format!("Invalid backup manifest: {}", e))
+            })?;
+
+        let safety = self.snapshot(&settings)?;
+        info!(
+            "Saved current state as {} before restoring {}",
+            safety.id, id
+        );
+
+        let _guard = self.lock.lock().map_err(|e| e.to_string())?;
+
+        for file in &manifest.files {
+            if file == SETTINGS_FILE {
+                self.restore_settings(&source.join(SETTINGS_FILE))?;
+            } else if DATABASES.contains(&file.as_str()) {
+                let dest = self.app_data_dir.join(file);
+                let tmp = dest.with_extension("db.restore");
+                fs::copy(source.join(file), &tmp)
+                    .and_then(|_| fs::rename(&tmp, &dest))
+                    .map_err(|e| format!("Failed to restore {}: {}", file, e))?;
+            }
+        }
+
+        if manifest.includes_audio {
+            copy_files(
+                &source.join(RECORDINGS_DIR),
+                &self.app_data_dir.join(RECORDINGS_DIR),
+            )
+            .map_err(|e| format!("Failed to restore recordings: {}", e))?;
+        }
+
+        info!("Restored backup {}", id);
+        Ok(())
+    }
+
+    fn restore_settings(&self, path: &Path) -> Result<(), String> {
+        let json: serde_json::Value = fs::read(path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| serde_json::from_slice(&bytes).map_err(|e| e.to_string()))
+            .map_err(|e| format!("Failed to read backed up settings: {}", e))?;
+        let settings = json
+            .get("settings")
+            .cloned()
+            .ok_or_else(|| "Backed up settings are missing".to_string())?;
+
+        let store = self
+            .app_handle
+            .store(settings_store_path())
+            .map_err(|e| format!("Failed to open settings store: {}", e))?;
+        store.set("settings", settings);
+        store
+            .save()
+            .map_err(|e| format!("Failed to save restored settings: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_backup(dir: &Path, id: &str, created_at: i64) {
+        let path = dir.join(id);
+        fs::create_dir_all(&path).unwrap();
+        let manifest = BackupManifest {
+            created_at,
+            app_version: "0.8.0".to_string(),
+            files: vec![SETTINGS_FILE.to_string()],
+            includes_audio: false,
+        };
+        fs::write(
+            path.join(MANIFEST_FILE),
+            serde_json::to_vec(&manifest).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_rotate_keeps_newest_backups() {
+        let dir = TempDir::new().unwrap();
+        for (i, created_at) in [100, 300, 200, 400].iter().enumerate() {
+            write_backup(dir.path(), &format!("{}{}", BACKUP_PREFIX, i), *created_at);
+        }
+        // Folders without a manifest or our prefix are left alone
+        fs::create_dir_all(dir.path().join("unrelated")).unwrap();
+
+        assert_eq!(rotate_backups(dir.path(), 2), 2);
+        let remaining: Vec<i64> = read_backups(dir.path())
+            .iter()
+            .map(|b| b.created_at)
+            .collect();
+        assert_eq!(remaining, vec![400, 300]);
+        assert!(dir.path().join("unrelated").exists());
+    }
+
+    #[test]
+    fn test_is_backup_due() {
+        let settings = BackupSettings::default();
+        let day = 24 * 60 * 60;
+        assert!(is_backup_due(&settings, None, 1000));
+        assert!(!is_backup_due(&settings, Some(1000), 1000 + day - 1));
+        assert!(is_backup_due(&settings, Some(1000), 1000 + day));
+
+        let disabled = BackupSettings {
+            enabled: false,
+            ..BackupSettings::default()
+        };
+        assert!(!is_backup_due(&disabled, None, 1000));
+    }
+
+    #[test]
+    fn test_snapshot_database_copies_rows() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("history.db");
+        let dest = dir.path().join("snapshot.db");
+        let conn = Connection::open(&src).unwrap();
+        conn.execute_batch("CREATE TABLE t (v TEXT); INSERT INTO t VALUES ('kept');")
+            .unwrap();
+
+        snapshot_database(&src, &dest).unwrap();
+
+        let copy = Connection::open(&dest).unwrap();
+        let value: String = copy.query_row("SELECT v FROM t", [], |r| r.get(0)).unwrap();
+        assert_eq!(value, "kept");
+    }
+
+    #[test]
+    fn test_validate_backup_id() {
+        assert!(validate_backup_id("dictum-backup-20240101-120000").is_ok());
+        assert!(validate_backup_id("../settings").is_err());
+        assert!(validate_backup_id("dictum-backup-../../etc").is_err());
+    }
+}
@@ -2,14 +2,21 @@ use log::{debug, error, info, warn};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::collections::VecDeque;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Manager};
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
+use crate::access_policy;
 use crate::audio_toolkit::decoder;
+use crate::events::{BatchComplete, BatchItemStatus};
+use crate::export::{self, ExportFormat};
+use crate::managers::transcription::{TimedTranscript, TranscriptSegment, TranscriptionManager};
+use crate::settings::{get_settings, BatchOutputDestination, BatchOutputSettings};
+use tauri_specta::Event;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
 pub enum JobStatus {
@@ -19,6 +26,8 @@ pub enum JobStatus {
     Completed,
     Failed,
     Cancelled,
+    /// Was in progress when the app exited; picked up again on the next run
+    Interrupted,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -30,6 +39,31 @@ pub struct BatchItem {
     pub progress: f32,
     pub error: Option<String>,
     pub duration_seconds: Option<f64>,
+    /// Per-item format override; falls back to the batch output default
+    #[serde(default)]
+    pub output_format: Option<ExportFormat>,
+    /// Resolved output file, set once the item completes
+    #[serde(default)]
+    pub output_path: Option<String>,
+    /// Every file written for the item, one per format
+    #[serde(default)]
+    pub output_paths: Vec<String>,
+    /// Output chosen for the batch the item belongs to
+    #[serde(default)]
+    pub batch_output: BatchOutputConfig,
+}
+
+/// Output chosen for one batch, overriding the batch output settings for its
+/// items
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct BatchOutputConfig {
+    /// Formats each item is written in, one file per format. Empty uses the
+    /// default format.
+    #[serde(default)]
+    pub formats: Vec<ExportFormat>,
+    /// Folder the files are written to instead of the configured destination
+    #[serde(default)]
+    pub output_dir: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -48,6 +82,262 @@ pub struct BatchProgressEvent {
     pub progress: f32,
     pub total_items: usize,
     pub completed_items: usize,
+    pub error: Option<String>,
+    pub duration_seconds: Option<f64>,
+}
+
+/// Shortcut whose transcription backend batch files are transcribed with
+const BATCH_BINDING_ID: &str = "transcribe";
+
+/// Length of the pieces a file is transcribed in, so progress can be reported
+const TRANSCRIBE_CHUNK_SAMPLES: usize = 30 * decoder::TARGET_SAMPLE_RATE as usize;
+
+/// Progress once decoding is done and when the last chunk is transcribed
+const TRANSCRIBE_START_PROGRESS: f32 = 0.2;
+const TRANSCRIBE_END_PROGRESS: f32 = 0.95;
+
+/// Placeholders accepted in the output naming template
+const TEMPLATE_PLACEHOLDERS: [&str; 4] = ["basename", "model", "date", "ext"];
+
+/// Check that a naming template only uses known placeholders
+pub fn validate_naming_template(template: &str) -> Result<(), String> {
+    if template.trim().is_empty() {
+        return Err("Naming template cannot be empty".to_string());
+    }
+
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let end = after
+            .find("}}")
+            .ok_or_else(|| "Unclosed placeholder in naming template".to_string())?;
+        let name = after[..end].trim();
+        if !TEMPLATE_PLACEHOLDERS.contains(&name) {
+            return Err(format!("Unknown placeholder '{{{{{}}}}}'", name));
+        }
+        rest = &after[end + 2..];
+    }
+
+    Ok(())
+}
+
+/// Replace characters that are not allowed in file names
+fn sanitize_file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect()
+}
+
+/// Render the output file name for a source file
+fn render_file_name(template: &str, source: &Path, model: &str, format: &ExportFormat) -> String {
+    let basename = source
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "transcription".to_string());
+    let model = if model.is_empty() { "unknown" } else { model };
+    let date = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let ext = format.file_extension();
+
+    let mut name = template.to_string();
+    for (placeholder, value) in [
+        ("basename", basename.as_str()),
+        ("model", model),
+        ("date", date.as_str()),
+        ("ext", ext),
+    ] {
+        name = name.replace(&format!("{{{{{}}}}}", placeholder), value);
+    }
+
+    if !template.contains("{{ext}}") {
+        name = format!("{}.{}", name, ext);
+    }
+
+    sanitize_file_name(&name)
+}
+
+/// Directory components of `dir` below `root`, or all of its normal components
+/// when it lies outside the root
+fn mirrored_components(dir: &Path, root: Option<&Path>) -> PathBuf {
+    let relative = root
+        .and_then(|root| dir.strip_prefix(root).ok())
+        .unwrap_or(dir);
+    relative
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(part) => Some(part),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Formats `item` is written in: its own override, else those chosen for its
+/// batch, else the default format
+pub fn item_formats(item: &BatchItem, settings: &BatchOutputSettings) -> Vec<ExportFormat> {
+    if let Some(format) = &item.output_format {
+        vec![format.clone()]
+    } else if !item.batch_output.formats.is_empty() {
+        item.batch_output.formats.clone()
+    } else {
+        vec![settings.default_format.clone()]
+    }
+}
+
+/// Resolve where the output for `item` should be written in its first format
+pub fn resolve_output_path(
+    item: &BatchItem,
+    settings: &BatchOutputSettings,
+    model: &str,
+) -> Result<PathBuf, String> {
+    let formats = item_formats(item, settings);
+    resolve_output_path_for(item, settings, model, &formats[0])
+}
+
+/// Resolve where the output for `item` in `format` should be written
+pub fn resolve_output_path_for(
+    item: &BatchItem,
+    settings: &BatchOutputSettings,
+    model: &str,
+    format: &ExportFormat,
+) -> Result<PathBuf, String> {
+    let source = Path::new(&item.file_path);
+    let file_name = render_file_name(&settings.naming_template, source, model, format);
+    let source_dir = source.parent().unwrap_or_else(|| Path::new(""));
+
+    let output_root = || {
+        settings
+            .output_root
+            .as_deref()
+            .filter(|root| !root.trim().is_empty())
+            .map(PathBuf::from)
+            .ok_or_else(|| "No output folder configured for batch output".to_string())
+    };
+
+    let batch_dir = item
+        .batch_output
+        .output_dir
+        .as_deref()
+        .filter(|dir| !dir.trim().is_empty());
+    let dir = match (batch_dir, &settings.destination) {
+        (Some(dir), _) => PathBuf::from(dir),
+        (None, BatchOutputDestination::SameDirectory) => source_dir.to_path_buf(),
+        (None, BatchOutputDestination::OutputFolder) => output_root()?,
+        (None, BatchOutputDestination::MirrorTree) => {
+            let mirror_root = settings.mirror_source_root.as_deref().map(Path::new);
+            output_root()?.join(mirrored_components(source_dir, mirror_root))
+        }
+    };
+
+    Ok(dir.join(file_name))
+}
+
+/// Render a file's transcript in the output format
+fn render_transcript(
+    file_name: &str,
+    duration_seconds: f64,
+    transcript: &TimedTranscript,
+    format: &ExportFormat,
+) -> Result<String, String> {
+    // Without timings, subtitles get a single cue spanning the whole file
+    let segments = if transcript.segments.is_empty() {
+        vec![TranscriptSegment {
+            start: 0.0,
+            end: duration_seconds as f32,
+            text: transcript.text.clone(),
+        }]
+    } else {
+        transcript.segments.clone()
+    };
+
+    match format {
+        ExportFormat::Txt => Ok(format!("{}\n", transcript.text)),
+        ExportFormat::Srt => Ok(export::export_segments_as_srt(&segments)),
+        ExportFormat::Vtt => Ok(export::export_segments_as_vtt(&segments)),
+        ExportFormat::Json => {
+            #[derive(Serialize)]
+            struct ExportedFile<'a> {
+                file: &'a str,
+                duration_seconds: f64,
+                transcription: &'a str,
+                segments: &'a [TranscriptSegment],
+            }
+
+            serde_json::to_string_pretty(&ExportedFile {
+                file: file_name,
+                duration_seconds,
+                transcription: &transcript.text,
+                segments: &transcript.segments,
+            })
+            .map_err(|e| format!("Failed to serialize: {}", e))
+        }
+        ExportFormat::Markdown => Ok(format!("# {}\n\n{}\n", file_name, transcript.text)),
+    }
+}
+
+/// Write an output file, creating its folder if needed
+fn write_output(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// On-disk format of the persisted queue
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedQueue {
+    version: u32,
+    items: Vec<BatchItem>,
+}
+
+const PERSISTED_QUEUE_VERSION: u32 = 1;
+
+/// Write the queue to disk, replacing the previous file atomically
+fn save_queue(path: &Path, queue: &VecDeque<BatchItem>) {
+    let persisted = PersistedQueue {
+        version: PERSISTED_QUEUE_VERSION,
+        items: queue.iter().cloned().collect(),
+    };
+    let result = serde_json::to_vec_pretty(&persisted)
+        .map_err(|e| e.to_string())
+        .and_then(|json| {
+            let tmp_path = path.with_extension("json.tmp");
+            fs::write(&tmp_path, json).map_err(|e| e.to_string())?;
+            fs::rename(&tmp_path, path).map_err(|e| e.to_string())
+        });
+
+    if let Err(e) = result {
+        error!("Failed to persist batch queue to {}: {}", path.display(), e);
+    }
+}
+
+/// Read a persisted queue, marking items that were mid-flight as interrupted
+fn load_queue(path: &Path) -> VecDeque<BatchItem> {
+    let persisted = match fs::read(path) {
+        Ok(bytes) => match serde_json::from_slice::<PersistedQueue>(&bytes) {
+            Ok(persisted) => persisted,
+            Err(e) => {
+                warn!("Ignoring unreadable batch queue {}: {}", path.display(), e);
+                return VecDeque::new();
+            }
+        },
+        Err(_) => return VecDeque::new(),
+    };
+
+    persisted
+        .items
+        .into_iter()
+        .map(|mut item| {
+            if matches!(item.status, JobStatus::Decoding | JobStatus::Transcribing) {
+                item.status = JobStatus::Interrupted;
+                item.progress = 0.0;
+            }
+            item
+        })
+        .collect()
 }
 
 pub struct BatchProcessor {
@@ -55,6 +345,10 @@ pub struct BatchProcessor {
     cancel_signal: Arc<AtomicBool>,
     is_processing: Arc<AtomicBool>,
     app_handle: Option<AppHandle>,
+    /// Where the queue is persisted; `None` keeps it in memory only
+    storage_path: Option<PathBuf>,
+    /// Output of the current batch, given to items as they're added
+    output_config: BatchOutputConfig,
 }
 
 impl BatchProcessor {
@@ -64,6 +358,8 @@ impl BatchProcessor {
             cancel_signal: Arc::new(AtomicBool::new(false)),
             is_processing: Arc::new(AtomicBool::new(false)),
             app_handle: None,
+            storage_path: None,
+            output_config: BatchOutputConfig::default(),
         }
     }
 
@@ -71,6 +367,28 @@ impl BatchProcessor {
         self.app_handle = Some(handle);
     }
 
+    /// Persist the queue at `path`, restoring any items saved by a previous run.
+    /// Must be called before processing starts.
+    pub fn set_storage_path(&mut self, path: PathBuf) {
+        let restored = load_queue(&path);
+        let resumable = restored
+            .iter()
+            .filter(|i| matches!(i.status, JobStatus::Queued | JobStatus::Interrupted))
+            .count();
+        if resumable > 0 {
+            info!("Restored {} pending batch items", resumable);
+        }
+
+        self.queue = Arc::new(Mutex::new(restored));
+        self.storage_path = Some(path);
+    }
+
+    fn persist(storage_path: &Option<PathBuf>, queue: &VecDeque<BatchItem>) {
+        if let Some(path) = storage_path {
+            save_queue(path, queue);
+        }
+    }
+
     pub async fn add_files(&self, paths: Vec<PathBuf>) -> Result<BatchQueueStatus, String> {
         let mut queue = self.queue.lock().await;
 
@@ -94,11 +412,16 @@ impl BatchProcessor {
                 progress: 0.0,
                 error: None,
                 duration_seconds: None,
+                output_format: None,
+                output_path: None,
+                output_paths: Vec::new(),
+                batch_output: self.output_config.clone(),
             };
 
             queue.push_back(item);
         }
 
+        Self::persist(&self.storage_path, &queue);
         Ok(self.build_status(&queue))
     }
 
@@ -114,6 +437,7 @@ impl BatchProcessor {
         let cancel = self.cancel_signal.clone();
         let is_processing = self.is_processing.clone();
         let app = self.app_handle.clone();
+        let storage_path = self.storage_path.clone();
 
         tokio::spawn(async move {
             loop {
@@ -122,11 +446,13 @@ impl BatchProcessor {
                     break;
                 }
 
-                // Find next queued item
+                // Find next queued or interrupted item
                 let next_id = {
                     let q = queue.lock().await;
                     q.iter()
-                        .find(|item| item.status == JobStatus::Queued)
+                        .find(|item| {
+                            matches!(item.status, JobStatus::Queued | JobStatus::Interrupted)
+                        })
                         .map(|item| item.id.clone())
                 };
 
@@ -136,23 +462,15 @@ impl BatchProcessor {
                 };
 
                 // Update status to Decoding
-                {
-                    let mut q = queue.lock().await;
-                    if let Some(item) = q.iter_mut().find(|i| i.id == item_id) {
-                        item.status = JobStatus::Decoding;
-                        item.progress = 0.1;
-                    }
-                    if let Some(ref app) = app {
-                        let status = Self::build_status_static(&q);
-                        let _ = app.emit("batch-item-status", &BatchProgressEvent {
-                            item_id: item_id.clone(),
-                            status: JobStatus::Decoding,
-                            progress: 0.1,
-                            total_items: status.total,
-                            completed_items: status.completed,
-                        });
-                    }
-                }
+                Self::update_item(
+                    &queue,
+                    &storage_path,
+                    &app,
+                    &item_id,
+                    JobStatus::Decoding,
+                    0.1,
+                )
+                .await;
 
                 // Get file path
                 let file_path = {
@@ -163,65 +481,174 @@ impl BatchProcessor {
                         .unwrap_or_default()
                 };
 
-                // Decode audio file
+                // Decode off the async runtime, ffmpeg can take a while on long videos
                 let path = PathBuf::from(&file_path);
-                match decoder::decode_audio_file(&path) {
-                    Ok(decoded) => {
-                        debug!(
-                            "Decoded {}: {:.1}s, {} samples",
-                            file_path,
-                            decoded.duration_seconds,
-                            decoded.samples.len()
-                        );
+                let decoded =
+                    tokio::task::spawn_blocking(move || decoder::decode_audio_file(&path))
+                        .await
+                        .map_err(|e| format!("Decoding task failed: {}", e))
+                        .and_then(|result| result);
+
+                let decoded = match decoded {
+                    Ok(decoded) => decoded,
+                    Err(e) => {
+                        error!("Failed to decode {}: {}", file_path, e);
+                        Self::fail_item(&queue, &storage_path, &app, &item_id, e).await;
+                        continue;
+                    }
+                };
+                debug!(
+                    "Decoded {}: {:.1}s, {} samples",
+                    file_path,
+                    decoded.duration_seconds,
+                    decoded.samples.len()
+                );
+
+                // Update with duration and mark as Transcribing
+                {
+                    let mut q = queue.lock().await;
+                    if let Some(item) = q.iter_mut().find(|i| i.id == item_id) {
+                        item.duration_seconds = Some(decoded.duration_seconds);
+                    }
+                }
+                Self::update_item(
+                    &queue,
+                    &storage_path,
+                    &app,
+                    &item_id,
+                    JobStatus::Transcribing,
+                    TRANSCRIBE_START_PROGRESS,
+                )
+                .await;
+
+                let Some(ref app_handle) = app else {
+                    let e = "Transcription is not available".to_string();
+                    Self::fail_item(&queue, &storage_path, &app, &item_id, e).await;
+                    continue;
+                };
+                let Some(tm) = app_handle.try_state::<Arc<TranscriptionManager>>() else {
+                    let e = "Transcription is not available".to_string();
+                    Self::fail_item(&queue, &storage_path, &app, &item_id, e).await;
+                    continue;
+                };
+                let tm = Arc::clone(&tm);
+                tm.initiate_model_load();
+
+                // Transcribe in chunks so progress can be reported along the way
+                let chunks: Vec<&[f32]> =
+                    decoded.samples.chunks(TRANSCRIBE_CHUNK_SAMPLES).collect();
+                let mut transcript = TimedTranscript::default();
+                let mut transcribed = 0;
+                let mut failure = None;
+                for (index, chunk) in chunks.iter().enumerate() {
+                    if cancel.load(Ordering::SeqCst) {
+                        break;
+                    }
 
-                        // Update with duration and mark as Transcribing
-                        {
-                            let mut q = queue.lock().await;
-                            if let Some(item) = q.iter_mut().find(|i| i.id == item_id) {
-                                item.duration_seconds = Some(decoded.duration_seconds);
-                                item.status = JobStatus::Transcribing;
-                                item.progress = 0.5;
-                            }
-                            if let Some(ref app) = app {
-                                let status = Self::build_status_static(&q);
-                                let _ = app.emit("batch-item-status", &BatchProgressEvent {
-                                    item_id: item_id.clone(),
-                                    status: JobStatus::Transcribing,
-                                    progress: 0.5,
-                                    total_items: status.total,
-                                    completed_items: status.completed,
-                                });
-                            }
+                    let last_part = index + 1 == chunks.len();
+                    match tm
+                        .transcribe_part_for_binding(chunk.to_vec(), BATCH_BINDING_ID, last_part)
+                        .await
+                    {
+                        Ok(part) => {
+                            let offset = (index * TRANSCRIBE_CHUNK_SAMPLES) as f32
+                                / decoder::TARGET_SAMPLE_RATE as f32;
+                            transcript.append(part, offset);
+                            transcribed += 1;
+                        }
+                        Err(e) => {
+                            failure = Some(format!("Transcription failed: {}", e));
+                            break;
                         }
+                    }
+
+                    let done = (index + 1) as f32 / chunks.len() as f32;
+                    let progress = TRANSCRIBE_START_PROGRESS
+                        + done * (TRANSCRIBE_END_PROGRESS - TRANSCRIBE_START_PROGRESS);
+                    Self::update_item(
+                        &queue,
+                        &storage_path,
+                        &app,
+                        &item_id,
+                        JobStatus::Transcribing,
+                        progress,
+                    )
+                    .await;
+                }
+
+                if let Some(e) = failure {
+                    error!("Failed to transcribe {}: {}", file_path, e);
+                    tm.maybe_unload_immediately("batch transcription");
+                    Self::fail_item(&queue, &storage_path, &app, &item_id, e).await;
+                    continue;
+                }
+                if transcribed < chunks.len() {
+                    info!("Batch processing cancelled during {}", file_path);
+                    tm.maybe_unload_immediately("batch transcription");
+                    Self::update_item(
+                        &queue,
+                        &storage_path,
+                        &app,
+                        &item_id,
+                        JobStatus::Cancelled,
+                        0.0,
+                    )
+                    .await;
+                    break;
+                }
 
-                        // TODO: Send decoded.samples to TranscriptionManager for transcription
-                        // For now, mark as completed after decoding
+                // Write the transcript next to the source or into the output folder
+                let settings = get_settings(app_handle);
+                let output_settings = settings.batch_output;
+                let model = settings.selected_model;
+                let item = {
+                    let q = queue.lock().await;
+                    q.iter().find(|i| i.id == item_id).cloned()
+                };
+                let Some(item) = item else {
+                    // Removed from the queue while it was being transcribed
+                    continue;
+                };
+                let written: Result<Vec<String>, String> = item_formats(&item, &output_settings)
+                    .iter()
+                    .map(|format| {
+                        let path =
+                            resolve_output_path_for(&item, &output_settings, &model, format)?;
+                        access_policy::check_path(&path)?;
+                        let contents = render_transcript(
+                            &item.file_name,
+                            decoded.duration_seconds,
+                            &transcript,
+                            format,
+                        )?;
+                        write_output(&path, &contents)?;
+                        info!("Wrote batch transcript to {}", path.display());
+                        Ok(path.to_string_lossy().to_string())
+                    })
+                    .collect();
+
+                match written {
+                    Ok(paths) => {
                         {
                             let mut q = queue.lock().await;
                             if let Some(item) = q.iter_mut().find(|i| i.id == item_id) {
-                                item.status = JobStatus::Completed;
-                                item.progress = 1.0;
-                            }
-                            if let Some(ref app) = app {
-                                let status = Self::build_status_static(&q);
-                                let _ = app.emit("batch-item-status", &BatchProgressEvent {
-                                    item_id: item_id.clone(),
-                                    status: JobStatus::Completed,
-                                    progress: 1.0,
-                                    total_items: status.total,
-                                    completed_items: status.completed,
-                                });
+                                item.output_path = paths.first().cloned();
+                                item.output_paths = paths;
                             }
                         }
+                        Self::update_item(
+                            &queue,
+                            &storage_path,
+                            &app,
+                            &item_id,
+                            JobStatus::Completed,
+                            1.0,
+                        )
+                        .await;
                     }
                     Err(e) => {
-                        error!("Failed to decode {}: {}", file_path, e);
-                        let mut q = queue.lock().await;
-                        if let Some(item) = q.iter_mut().find(|i| i.id == item_id) {
-                            item.status = JobStatus::Failed;
-                            item.error = Some(e);
-                            item.progress = 0.0;
-                        }
+                        error!("Failed to write output for {}: {}", item.file_name, e);
+                        Self::fail_item(&queue, &storage_path, &app, &item_id, e).await;
                     }
                 }
             }
@@ -232,13 +659,64 @@ impl BatchProcessor {
             if let Some(ref app) = app {
                 let q = queue.lock().await;
                 let status = Self::build_status_static(&q);
-                let _ = app.emit("batch-complete", &status);
+                let _ = BatchComplete(status).emit(app);
             }
         });
 
         Ok(())
     }
 
+    /// Set an item's status and progress, persist the queue and tell the UI
+    async fn update_item(
+        queue: &Mutex<VecDeque<BatchItem>>,
+        storage_path: &Option<PathBuf>,
+        app: &Option<AppHandle>,
+        item_id: &str,
+        status: JobStatus,
+        progress: f32,
+    ) {
+        let mut q = queue.lock().await;
+        let Some(item) = q.iter_mut().find(|i| i.id == item_id) else {
+            return;
+        };
+        item.status = status.clone();
+        item.progress = progress;
+        let error = item.error.clone();
+        let duration_seconds = item.duration_seconds;
+
+        Self::persist(storage_path, &q);
+        if let Some(ref app) = app {
+            let status_summary = Self::build_status_static(&q);
+            let _ = BatchItemStatus(BatchProgressEvent {
+                item_id: item_id.to_string(),
+                status,
+                progress,
+                total_items: status_summary.total,
+                completed_items: status_summary.completed,
+                error,
+                duration_seconds,
+            })
+            .emit(app);
+        }
+    }
+
+    /// Mark an item as failed with `error`
+    async fn fail_item(
+        queue: &Mutex<VecDeque<BatchItem>>,
+        storage_path: &Option<PathBuf>,
+        app: &Option<AppHandle>,
+        item_id: &str,
+        error: String,
+    ) {
+        {
+            let mut q = queue.lock().await;
+            if let Some(item) = q.iter_mut().find(|i| i.id == item_id) {
+                item.error = Some(error);
+            }
+        }
+        Self::update_item(queue, storage_path, app, item_id, JobStatus::Failed, 0.0).await;
+    }
+
     pub fn cancel(&self) {
         self.cancel_signal.store(true, Ordering::SeqCst);
     }
@@ -252,17 +730,66 @@ impl BatchProcessor {
         let mut queue = self.queue.lock().await;
         if let Some(pos) = queue.iter().position(|item| item.id == id) {
             queue.remove(pos);
+            Self::persist(&self.storage_path, &queue);
             Ok(())
         } else {
             Err("Item not found".to_string())
         }
     }
 
+    /// Override the output format for a single item, or clear the override
+    pub async fn set_item_format(
+        &self,
+        id: &str,
+        format: Option<ExportFormat>,
+    ) -> Result<(), String> {
+        let mut queue = self.queue.lock().await;
+        let item = queue
+            .iter_mut()
+            .find(|item| item.id == id)
+            .ok_or_else(|| "Item not found".to_string())?;
+
+        if matches!(item.status, JobStatus::Decoding | JobStatus::Transcribing) {
+            return Err("Cannot change the format of an item being processed".to_string());
+        }
+
+        item.output_format = format;
+        Self::persist(&self.storage_path, &queue);
+        Ok(())
+    }
+
+    pub fn output_config(&self) -> BatchOutputConfig {
+        self.output_config.clone()
+    }
+
+    /// Set the output of the current batch. It applies to the items still
+    /// waiting to be processed and to any added later.
+    pub async fn set_output_config(&mut self, mut config: BatchOutputConfig) {
+        let mut formats = Vec::new();
+        for format in config.formats {
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+        }
+        config.formats = formats;
+        config.output_dir = config.output_dir.filter(|dir| !dir.trim().is_empty());
+
+        let mut queue = self.queue.lock().await;
+        for item in queue.iter_mut() {
+            if matches!(item.status, JobStatus::Queued | JobStatus::Interrupted) {
+                item.batch_output = config.clone();
+            }
+        }
+        Self::persist(&self.storage_path, &queue);
+        self.output_config = config;
+    }
+
     pub async fn clear_completed(&self) {
         let mut queue = self.queue.lock().await;
         queue.retain(|item| {
             item.status != JobStatus::Completed && item.status != JobStatus::Failed
         });
+        Self::persist(&self.storage_path, &queue);
     }
 
     fn build_status(&self, queue: &VecDeque<BatchItem>) -> BatchQueueStatus {
@@ -287,3 +814,162 @@ impl BatchProcessor {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn item(id: &str, status: JobStatus) -> BatchItem {
+        BatchItem {
+            id: id.to_string(),
+            file_name: format!("{}.wav", id),
+            file_path: format!("/tmp/{}.wav", id),
+            status,
+            progress: 0.5,
+            error: None,
+            duration_seconds: Some(12.0),
+            output_format: None,
+            output_path: None,
+            output_paths: Vec::new(),
+            batch_output: BatchOutputConfig::default(),
+        }
+    }
+
+    #[test]
+    fn test_queue_roundtrip_marks_in_progress_items_interrupted() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("batch_queue.json");
+
+        let queue: VecDeque<BatchItem> = vec![
+            item("queued", JobStatus::Queued),
+            item("decoding", JobStatus::Decoding),
+            item("transcribing", JobStatus::Transcribing),
+            item("done", JobStatus::Completed),
+        ]
+        .into();
+        save_queue(&path, &queue);
+
+        let restored = load_queue(&path);
+        let statuses: Vec<JobStatus> = restored.iter().map(|i| i.status.clone()).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                JobStatus::Queued,
+                JobStatus::Interrupted,
+                JobStatus::Interrupted,
+                JobStatus::Completed,
+            ]
+        );
+        assert_eq!(restored[1].progress, 0.0);
+        assert_eq!(restored[3].duration_seconds, Some(12.0));
+    }
+
+    #[test]
+    fn test_load_queue_missing_or_corrupt_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("batch_queue.json");
+        assert!(load_queue(&path).is_empty());
+
+        fs::write(&path, "not json").unwrap();
+        assert!(load_queue(&path).is_empty());
+    }
+
+    #[test]
+    fn test_validate_naming_template() {
+        assert!(validate_naming_template("{{basename}}_{{model}}.txt").is_ok());
+        assert!(validate_naming_template("{{ basename }}-{{date}}").is_ok());
+        assert!(validate_naming_template("").is_err());
+        assert!(validate_naming_template("{{basename").is_err());
+        assert!(validate_naming_template("{{speaker}}").is_err());
+    }
+
+    #[test]
+    fn test_resolve_output_path_destinations() {
+        let mut settings = BatchOutputSettings::default();
+        let mut source = item("a", JobStatus::Queued);
+        source.file_path = "/audio/meetings/2024/standup.wav".to_string();
+
+        let path = resolve_output_path(&source, &settings, "whisper-small").unwrap();
+        assert_eq!(
+            path,
+            PathBuf::from("/audio/meetings/2024/standup_whisper-small.txt")
+        );
+
+        settings.destination = BatchOutputDestination::OutputFolder;
+        assert!(resolve_output_path(&source, &settings, "m").is_err());
+
+        settings.output_root = Some("/out".to_string());
+        let path = resolve_output_path(&source, &settings, "m").unwrap();
+        assert_eq!(path, PathBuf::from("/out/standup_m.txt"));
+
+        settings.destination = BatchOutputDestination::MirrorTree;
+        let path = resolve_output_path(&source, &settings, "m").unwrap();
+        assert_eq!(
+            path,
+            PathBuf::from("/out/audio/meetings/2024/standup_m.txt")
+        );
+
+        settings.mirror_source_root = Some("/audio".to_string());
+        let path = resolve_output_path(&source, &settings, "m").unwrap();
+        assert_eq!(path, PathBuf::from("/out/meetings/2024/standup_m.txt"));
+    }
+
+    #[test]
+    fn test_append_part_offsets_segments() {
+        let mut transcript = TimedTranscript::default();
+        let part = |text: &str, start: f32| TimedTranscript {
+            text: text.to_string(),
+            segments: vec![TranscriptSegment {
+                start,
+                end: start + 1.0,
+                text: text.to_string(),
+            }],
+            ..Default::default()
+        };
+        transcript.append(part("hello", 0.5), 0.0);
+        transcript.append(part("", 0.0), 30.0);
+        transcript.append(part("world", 2.0), 60.0);
+
+        assert_eq!(transcript.text, "hello world");
+        let starts: Vec<f32> = transcript.segments.iter().map(|s| s.start).collect();
+        assert_eq!(starts, vec![0.5, 30.0, 62.0]);
+
+        let srt = render_transcript("a.mp3", 63.0, &transcript, &ExportFormat::Srt).unwrap();
+        assert!(srt.starts_with("1\n00:00:00,500 --> 00:01:03,000\n"));
+    }
+
+    #[test]
+    fn test_batch_output_formats_and_folder() {
+        let settings = BatchOutputSettings::default();
+        let mut source = item("a", JobStatus::Queued);
+        source.file_path = "/audio/call.mp3".to_string();
+        assert_eq!(item_formats(&source, &settings), vec![ExportFormat::Txt]);
+
+        source.batch_output = BatchOutputConfig {
+            formats: vec![ExportFormat::Srt, ExportFormat::Json],
+            output_dir: Some("/transcripts".to_string()),
+        };
+        assert_eq!(
+            item_formats(&source, &settings),
+            vec![ExportFormat::Srt, ExportFormat::Json]
+        );
+        let path = resolve_output_path_for(&source, &settings, "m", &ExportFormat::Json).unwrap();
+        assert_eq!(path, PathBuf::from("/transcripts/call_m.json"));
+
+        source.output_format = Some(ExportFormat::Vtt);
+        assert_eq!(item_formats(&source, &settings), vec![ExportFormat::Vtt]);
+    }
+
+    #[test]
+    fn test_resolve_output_path_format_override_and_template() {
+        let mut settings = BatchOutputSettings::default();
+        settings.naming_template = "{{basename}}/{{model}}".to_string();
+        let mut source = item("a", JobStatus::Queued);
+        source.file_path = "/audio/call.mp3".to_string();
+        source.output_format = Some(ExportFormat::Srt);
+
+        let path = resolve_output_path(&source, &settings, "").unwrap();
+        assert_eq!(path, PathBuf::from("/audio/call_unknown.srt"));
+    }
+}
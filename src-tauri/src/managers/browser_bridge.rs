@@ -0,0 +1,366 @@
+//! Browser bridge
+//!
+//! Listens on 127.0.0.1 for the native messaging host the companion browser
+//! extension launches (see [`crate::browser_bridge`]). The extension reports
+//! which page and field has focus, and dictations into an editable field are
+//! sent to it to insert instead of being pasted with simulated keystrokes.
+//!
+//! The extension sends `{"type": "focus", "url": "...", "title": "...",
+//! "editable": true}` whenever a page or field gets focus and
+//! `{"type": "blur"}` when the browser window loses it. The app sends
+//! `{"type": "insert", "text": "..."}` for the extension to insert into the
+//! focused field.
+
+use crate::browser_bridge::{read_frame, write_bridge_file, write_message, BridgeEndpoint};
+use crate::managers::api_server::constant_time_eq;
+use crate::settings::get_settings;
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use specta::Type;
+use std::collections::HashMap;
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::AppHandle;
+
+/// How long a new connection has to authenticate
+const HELLO_TIMEOUT: Duration = Duration::from_secs(5);
+/// How often the listener checks whether it was stopped
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ExtensionMessage {
+    Hello {
+        token: String,
+    },
+    Focus {
+        url: String,
+        #[serde(default)]
+        title: String,
+        #[serde(default)]
+        editable: bool,
+    },
+    Blur,
+}
+
+/// The page focused in a browser with the extension installed
+#[derive(Serialize, Debug, Clone, PartialEq, Type)]
+pub struct BrowserFocus {
+    pub url: String,
+    pub host: String,
+    pub title: String,
+    /// Whether the focused element takes text
+    pub editable: bool,
+}
+
+/// Whether any extension is connected and what it has focused
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct BrowserBridgeStatus {
+    pub listening: bool,
+    pub connections: usize,
+    pub focus: Option<BrowserFocus>,
+}
+
+/// The host of a URL, without credentials or port
+fn host_of(url: &str) -> String {
+    let after_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let authority = after_scheme
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default();
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let host = match host.strip_prefix('[') {
+        // IPv6 addresses keep their colons
+        Some(ipv6) => ipv6.split(']').next().unwrap_or_default(),
+        None => host.split(':').next().unwrap_or_default(),
+    };
+    host.to_lowercase()
+}
+
+#[derive(Default)]
+struct BridgeState {
+    connections: HashMap<u64, TcpStream>,
+    /// The focused page and the connection it was reported on
+    focus: Option<(u64, BrowserFocus)>,
+}
+
+pub struct BrowserBridge {
+    app_handle: AppHandle,
+    state: Arc<Mutex<BridgeState>>,
+    /// Set to stop the listener running now
+    stop_flag: Mutex<Option<Arc<AtomicBool>>>,
+    next_connection: Arc<AtomicU64>,
+}
+
+impl BrowserBridge {
+    pub fn new(app: &AppHandle) -> Self {
+        Self {
+            app_handle: app.clone(),
+            state: Arc::new(Mutex::new(BridgeState::default())),
+            stop_flag: Mutex::new(None),
+            next_connection: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Start, restart or stop the bridge to match the settings
+    pub fn apply_settings(&self) -> Result<(), String> {
+        self.stop();
+
+        let settings = get_settings(&self.app_handle).browser_bridge;
+        if !settings.enabled {
+            return Ok(());
+        }
+        let token = settings
+            .token
+            .filter(|token| !token.is_empty())
+            .ok_or("Generate a bridge token before starting the browser bridge")?;
+
+        let listener = TcpListener::bind(("127.0.0.1", settings.port)).map_err(|e| {
+            format!(
+                "Failed to start browser bridge on port {}: {}",
+                settings.port, e
+            )
+        })?;
+        listener
+            .set_nonblocking(true)
+            .map_err(|e| format!("Failed to configure browser bridge: {}", e))?;
+        write_bridge_file(
+            &self.app_handle,
+            &BridgeEndpoint {
+                port: settings.port,
+                token: token.clone(),
+            },
+        )?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let worker_stop = stop.clone();
+        let state = self.state.clone();
+        let next_connection = self.next_connection.clone();
+        std::thread::spawn(move || {
+            listen(&listener, &worker_stop, &state, &next_connection, &token)
+        });
+
+        match self.stop_flag.lock() {
+            Ok(mut current) => *current = Some(stop),
+            Err(e) => error!("Failed to lock browser bridge: {}", e),
+        }
+        info!("Browser bridge listening on 127.0.0.1:{}", settings.port);
+        Ok(())
+    }
+
+    /// Stop listening and drop every connection
+    pub fn stop(&self) {
+        let stop = match self.stop_flag.lock() {
+            Ok(mut current) => current.take(),
+            Err(e) => {
+                warn!("Failed to lock browser bridge: {}", e);
+                None
+            }
+        };
+        let Some(stop) = stop else {
+            return;
+        };
+        stop.store(true, Ordering::SeqCst);
+        if let Ok(mut state) = self.state.lock() {
+            for stream in state.connections.values() {
+                let _ = stream.shutdown(std::net::Shutdown::Both);
+            }
+            state.connections.clear();
+            state.focus = None;
+        }
+        info!("Browser bridge stopped");
+    }
+
+    /// The page focused in a connected browser, if any
+    pub fn focus(&self) -> Option<BrowserFocus> {
+        let state = self.state.lock().ok()?;
+        state.focus.as_ref().map(|(_, focus)| focus.clone())
+    }
+
+    pub fn status(&self) -> BrowserBridgeStatus {
+        let listening = self.stop_flag.lock().is_ok_and(|flag| flag.is_some());
+        let (connections, focus) = match self.state.lock() {
+            Ok(state) => (
+                state.connections.len(),
+                state.focus.as_ref().map(|(_, focus)| focus.clone()),
+            ),
+            Err(_) => (0, None),
+        };
+        BrowserBridgeStatus {
+            listening,
+            connections,
+            focus,
+        }
+    }
+
+    /// Have the extension insert `text` into the focused field
+    pub fn insert(&self, text: &str) -> Result<(), String> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| format!("Failed to lock browser bridge: {}", e))?;
+        let id = match &state.focus {
+            Some((id, focus)) if focus.editable => *id,
+            _ => return Err("No text field is focused in the browser".to_string()),
+        };
+        let stream = state
+            .connections
+            .get_mut(&id)
+            .ok_or("The browser disconnected")?;
+        write_message(stream, &json!({ "type": "insert", "text": text }))
+            .map_err(|e| format!("Failed to send text to the browser: {}", e))
+    }
+}
+
+fn listen(
+    listener: &TcpListener,
+    stop: &AtomicBool,
+    state: &Arc<Mutex<BridgeState>>,
+    next_connection: &AtomicU64,
+    token: &str,
+) {
+    while !stop.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let id = next_connection.fetch_add(1, Ordering::SeqCst);
+                let state = state.clone();
+                let token = token.to_string();
+                std::thread::spawn(move || {
+                    if let Err(e) = serve_connection(id, stream, &state, &token) {
+                        debug!("Browser bridge connection {} ended: {}", id, e);
+                    }
+                    if let Ok(mut state) = state.lock() {
+                        state.connections.remove(&id);
+                        if state
+                            .focus
+                            .as_ref()
+                            .is_some_and(|(focus_id, _)| *focus_id == id)
+                        {
+                            state.focus = None;
+                        }
+                    }
+                });
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+            Err(e) => {
+                error!("Browser bridge failed to accept a connection: {}", e);
+                std::thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+fn parse(message: &[u8]) -> Result<ExtensionMessage, String> {
+    serde_json::from_slice(message).map_err(|e| format!("Invalid message: {}", e))
+}
+
+/// Authenticate a connection, then track the focus it reports until it
+/// closes
+fn serve_connection(
+    id: u64,
+    mut stream: TcpStream,
+    state: &Mutex<BridgeState>,
+    token: &str,
+) -> Result<(), String> {
+    stream.set_nonblocking(false).map_err(|e| e.to_string())?;
+    stream
+        .set_read_timeout(Some(HELLO_TIMEOUT))
+        .map_err(|e| e.to_string())?;
+    let hello = read_frame(&mut stream)
+        .map_err(|e| e.to_string())?
+        .ok_or("Closed before authenticating")?;
+    match parse(&hello)? {
+        ExtensionMessage::Hello { token: given }
+            if constant_time_eq(given.as_bytes(), token.as_bytes()) => {}
+        _ => return Err("Missing or wrong bridge token".to_string()),
+    }
+    stream.set_read_timeout(None).map_err(|e| e.to_string())?;
+
+    let writer = stream.try_clone().map_err(|e| e.to_string())?;
+    state
+        .lock()
+        .map_err(|e| e.to_string())?
+        .connections
+        .insert(id, writer);
+    info!("Browser extension connected to the bridge");
+
+    while let Some(message) = read_frame(&mut stream).map_err(|e| e.to_string())? {
+        let message = match parse(&message) {
+            Ok(message) => message,
+            Err(e) => {
+                warn!("Browser bridge: {}", e);
+                continue;
+            }
+        };
+        let mut state = state.lock().map_err(|e| e.to_string())?;
+        match message {
+            ExtensionMessage::Focus {
+                url,
+                title,
+                editable,
+            } => {
+                let host = host_of(&url);
+                debug!("Browser focus on {} (editable: {})", host, editable);
+                state.focus = Some((
+                    id,
+                    BrowserFocus {
+                        url,
+                        host,
+                        title,
+                        editable,
+                    },
+                ));
+            }
+            ExtensionMessage::Blur => {
+                if state
+                    .focus
+                    .as_ref()
+                    .is_some_and(|(focus_id, _)| *focus_id == id)
+                {
+                    state.focus = None;
+                }
+            }
+            ExtensionMessage::Hello { .. } => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_of_strips_scheme_credentials_and_port() {
+        assert_eq!(
+            host_of("https://Mail.Google.com/mail/u/0/#inbox"),
+            "mail.google.com"
+        );
+        assert_eq!(
+            host_of("http://user:pw@localhost:3000/form?x=1"),
+            "localhost"
+        );
+        assert_eq!(host_of("http://[::1]:8080/"), "::1");
+    }
+
+    #[test]
+    fn test_extension_messages_parse() {
+        let focus = parse(br#"{"type":"focus","url":"https://github.com","editable":true}"#);
+        assert!(matches!(
+            focus,
+            Ok(ExtensionMessage::Focus { editable: true, .. })
+        ));
+        assert!(matches!(
+            parse(br#"{"type":"blur"}"#),
+            Ok(ExtensionMessage::Blur)
+        ));
+        assert!(parse(br#"{"type":"unknown"}"#).is_err());
+    }
+}
@@ -0,0 +1,319 @@
+//! Chapters
+//!
+//! Splits a long Active Listening session into chapters where the topic
+//! shifts. Segment transcriptions are embedded with the knowledge base
+//! embedding model, and a boundary is placed where the segments before a
+//! point stop resembling the segments after it (as in TextTiling). Each
+//! chapter is then given a short title by the LLM.
+
+use crate::managers::active_listening::{ActiveListeningSession, SessionInsight};
+use crate::managers::rag::RagManager;
+use crate::ollama_client::OllamaClient;
+use crate::settings::get_settings;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::AppHandle;
+
+/// Sessions at least this long are summarized chapter by chapter
+pub const CHAPTERED_SUMMARY_MINUTES: u32 = 60;
+
+/// Segments averaged on each side of a candidate boundary
+const COMPARISON_WINDOW: usize = 3;
+
+/// Fewest segments in a chapter
+const MIN_CHAPTER_SEGMENTS: usize = 4;
+
+/// Transcript characters sent to the LLM when titling a chapter
+const TITLE_EXCERPT_CHARS: usize = 4000;
+
+/// A stretch of a session about one topic
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct Chapter {
+    pub title: String,
+    /// Start, in milliseconds from the start of the session
+    pub start_ms: i64,
+    /// End, in milliseconds from the start of the session
+    pub end_ms: i64,
+    /// Index of the chapter's first insight in the session
+    pub first_segment: usize,
+    /// Index of the chapter's last insight in the session
+    pub last_segment: usize,
+}
+
+fn mean(embeddings: &[Vec<f32>]) -> Vec<f32> {
+    let mut sum = vec![0.0; embeddings.first().map_or(0, |e| e.len())];
+    for embedding in embeddings {
+        for (total, value) in sum.iter_mut().zip(embedding) {
+            *total += value;
+        }
+    }
+    sum
+}
+
+/// Indices of the segments that start a new topic
+///
+/// Each gap between segments is scored by the similarity of the segments
+/// around it. Gaps scoring well below the average are boundary candidates,
+/// taken lowest first as long as every chapter keeps `min_segments`.
+fn topic_boundaries(embeddings: &[Vec<f32>], window: usize, min_segments: usize) -> Vec<usize> {
+    let count = embeddings.len();
+    if count < min_segments * 2 {
+        return Vec::new();
+    }
+
+    let scores: Vec<(usize, f32)> = (min_segments..=count - min_segments)
+        .map(|gap| {
+            let before = mean(&embeddings[gap.saturating_sub(window)..gap]);
+            let after = mean(&embeddings[gap..(gap + window).min(count)]);
+            (gap, RagManager::cosine_similarity(&before, &after))
+        })
+        .collect();
+
+    let average = scores.iter().map(|(_, s)| s).sum::<f32>() / scores.len() as f32;
+    let deviation = (scores
+        .iter()
+        .map(|(_, s)| (s - average).powi(2))
+        .sum::<f32>()
+        / scores.len() as f32)
+        .sqrt();
+    let cutoff = average - deviation / 2.0;
+
+    let mut candidates: Vec<(usize, f32)> = scores
+        .into_iter()
+        .filter(|(_, score)| *score < cutoff)
+        .collect();
+    candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let mut boundaries: Vec<usize> = Vec::new();
+    for (gap, _) in candidates {
+        if boundaries
+            .iter()
+            .all(|boundary| boundary.abs_diff(gap) >= min_segments)
+        {
+            boundaries.push(gap);
+        }
+    }
+    boundaries.sort_unstable();
+    boundaries
+}
+
+/// Start of an insight's segment, in milliseconds from the session start
+pub fn segment_start(session: &ActiveListeningSession, insight: &SessionInsight) -> i64 {
+    (insight.timestamp - insight.duration_ms as i64 - session.started_at).max(0)
+}
+
+/// Chapters split at `boundaries`, titled "Chapter N" until named
+fn untitled_chapters(session: &ActiveListeningSession, boundaries: &[usize]) -> Vec<Chapter> {
+    let count = session.insights.len();
+    let mut starts = vec![0];
+    starts.extend(boundaries.iter().copied().filter(|&b| b > 0 && b < count));
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(index, &first)| {
+            let last = starts.get(index + 1).map_or(count, |&next| next) - 1;
+            let end = &session.insights[last];
+            Chapter {
+                title: format!("Chapter {}", index + 1),
+                start_ms: segment_start(session, &session.insights[first]),
+                end_ms: (end.timestamp - session.started_at).max(0),
+                first_segment: first,
+                last_segment: last,
+            }
+        })
+        .collect()
+}
+
+/// Format an offset as `m:ss`, or `h:mm:ss` from an hour on
+pub fn format_offset(ms: i64) -> String {
+    let seconds = ms.max(0) / 1000;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+/// The session transcript with a heading before each chapter
+pub fn chaptered_transcript(session: &ActiveListeningSession, chapters: &[Chapter]) -> String {
+    chapters
+        .iter()
+        .map(|chapter| {
+            let text = session.insights[chapter.first_segment..=chapter.last_segment]
+                .iter()
+                .map(SessionInsight::attributed_transcription)
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            format!(
+                "## {} ({}-{})\n\n{}",
+                chapter.title,
+                format_offset(chapter.start_ms),
+                format_offset(chapter.end_ms),
+                text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+async fn title_chapter(
+    client: &OllamaClient,
+    model: &str,
+    session: &ActiveListeningSession,
+    chapter: &Chapter,
+) -> Result<String, String> {
+    let excerpt: String = session.insights[chapter.first_segment..=chapter.last_segment]
+        .iter()
+        .map(|insight| insight.transcription.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .chars()
+        .take(TITLE_EXCERPT_CHARS)
+        .collect();
+    let prompt = format!(
+        "Give a short title (at most 6 words) for this part of a meeting transcript. \
+         Reply with the title only.\n\nTranscript:\n{}",
+        excerpt
+    );
+
+    let title = client.generate(model, prompt).await?;
+    let title = title.trim().trim_matches(|c| c == '"' || c == '*').trim();
+    if title.is_empty() {
+        Err("Empty chapter title".to_string())
+    } else {
+        Ok(title.to_string())
+    }
+}
+
+/// Detect and title the chapters of a session. Sessions without a clear topic
+/// shift come back as a single chapter.
+pub async fn detect_chapters(
+    app: &AppHandle,
+    session: &ActiveListeningSession,
+    model: &str,
+) -> Result<Vec<Chapter>, String> {
+    if session.insights.is_empty() {
+        return Err("No insights to split into chapters".to_string());
+    }
+    let settings = get_settings(app);
+    let client = OllamaClient::for_active_listening(&settings.active_listening)
+        .map_err(|e| format!("Failed to create Ollama client: {}", e))?;
+
+    let boundaries = if session.insights.len() >= MIN_CHAPTER_SEGMENTS * 2 {
+        let kb = &settings.knowledge_base;
+        let texts: Vec<String> = session
+            .insights
+            .iter()
+            .map(|insight| insight.transcription.clone())
+            .collect();
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(kb.embedding_batch_size.max(1)) {
+            embeddings.extend(
+                client
+                    .generate_embeddings_batch(&kb.embedding_model, batch, kb.embedding_max_retries)
+                    .await?,
+            );
+        }
+        topic_boundaries(&embeddings, COMPARISON_WINDOW, MIN_CHAPTER_SEGMENTS)
+    } else {
+        Vec::new()
+    };
+
+    let mut chapters = untitled_chapters(session, &boundaries);
+    if chapters.len() == 1 {
+        chapters[0].title = session
+            .topic
+            .clone()
+            .unwrap_or_else(|| "Full session".to_string());
+        return Ok(chapters);
+    }
+
+    for (index, chapter) in chapters.iter_mut().enumerate() {
+        match title_chapter(&client, model, session, chapter).await {
+            Ok(title) => chapter.title = title,
+            Err(e) => warn!("Failed to title chapter {}: {}", index + 1, e),
+        }
+    }
+
+    info!(
+        "Split session {} into {} chapters",
+        session.id,
+        chapters.len()
+    );
+    Ok(chapters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(count: usize) -> ActiveListeningSession {
+        ActiveListeningSession {
+            id: "al_1".to_string(),
+            started_at: 1_000_000,
+            ended_at: None,
+            topic: None,
+            insights: (0..count)
+                .map(|i| SessionInsight {
+                    timestamp: 1_000_000 + (i as i64 + 1) * 30_000,
+                    transcription: format!("segment {}", i),
+                    insight: String::new(),
+                    duration_ms: 30_000,
+                    speaker_id: None,
+                    speaker_label: None,
+                    translation: None,
+                })
+                .collect(),
+            highlights: Vec::new(),
+            speaker_names: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_boundaries_follow_topic_shifts() {
+        let topic_a = vec![1.0, 0.1, 0.0];
+        let topic_b = vec![0.0, 0.1, 1.0];
+        let mut embeddings = vec![topic_a.clone(); 6];
+        embeddings.extend(vec![topic_b.clone(); 6]);
+        embeddings.extend(vec![topic_a; 5]);
+        assert_eq!(topic_boundaries(&embeddings, 3, 4), vec![6, 12]);
+
+        // Too short to hold two chapters
+        assert!(topic_boundaries(&embeddings[..7], 3, 4).is_empty());
+        // No shift at all
+        assert!(topic_boundaries(&vec![topic_b; 12], 3, 4).is_empty());
+    }
+
+    #[test]
+    fn test_chapters_cover_the_session() {
+        let session = session(10);
+        let chapters = untitled_chapters(&session, &[4]);
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(
+            (chapters[0].first_segment, chapters[0].last_segment),
+            (0, 3)
+        );
+        assert_eq!(
+            (chapters[1].first_segment, chapters[1].last_segment),
+            (4, 9)
+        );
+        assert_eq!(chapters[0].start_ms, 0);
+        assert_eq!(chapters[0].end_ms, 120_000);
+        assert_eq!(chapters[1].start_ms, 120_000);
+        assert_eq!(chapters[1].end_ms, 300_000);
+
+        let transcript = chaptered_transcript(&session, &chapters);
+        assert!(transcript.starts_with("## Chapter 1 (0:00-2:00)\n\nsegment 0"));
+        assert!(transcript.contains("## Chapter 2 (2:00-5:00)\n\nsegment 4"));
+    }
+
+    #[test]
+    fn test_format_offset() {
+        assert_eq!(format_offset(0), "0:00");
+        assert_eq!(format_offset(754_000), "12:34");
+        assert_eq!(format_offset(3_723_000), "1:02:03");
+    }
+}
@@ -0,0 +1,157 @@
+//! Docking Manager
+//!
+//! Tracks whether the computer is undocked, docked to an external display,
+//! or running in clamshell mode, and exposes the overrides of the matching
+//! dock rule. When the state changes the microphone stream is reopened if
+//! the microphone override differs, and `dock-state-changed` is emitted.
+
+use crate::event_trail;
+use crate::events::DockStateChanged;
+use crate::helpers::clamshell;
+use crate::managers::audio::AudioRecordingManager;
+use crate::settings::{get_settings, AppSettings, DockOverrides, DockState};
+use log::{debug, info, warn};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+/// How often the display and lid state is checked
+const WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Work out the dock state from the number of connected displays and the
+/// lid state
+fn dock_state_from(monitor_count: usize, clamshell: bool) -> DockState {
+    if clamshell {
+        DockState::Clamshell
+    } else if monitor_count > 1 {
+        DockState::Docked
+    } else {
+        DockState::Undocked
+    }
+}
+
+pub struct DockingManager {
+    app_handle: AppHandle,
+    state: Mutex<DockState>,
+    /// A microphone switch that had to wait for a recording to finish
+    pending_reopen: AtomicBool,
+}
+
+impl DockingManager {
+    pub fn new(app: &AppHandle) -> Self {
+        Self {
+            app_handle: app.clone(),
+            state: Mutex::new(DockState::default()),
+            pending_reopen: AtomicBool::new(false),
+        }
+    }
+
+    /// The most recently detected dock state
+    pub fn state(&self) -> DockState {
+        self.state.lock().map(|s| *s).unwrap_or_default()
+    }
+
+    fn detect(app: &AppHandle) -> DockState {
+        let monitor_count = match app.available_monitors() {
+            Ok(monitors) => monitors.len(),
+            Err(e) => {
+                debug!("Failed to list monitors: {}", e);
+                1
+            }
+        };
+        let clamshell = clamshell::is_clamshell().unwrap_or_else(|e| {
+            debug!("Failed to check clamshell mode: {}", e);
+            false
+        });
+        dock_state_from(monitor_count, clamshell)
+    }
+
+    /// Check the dock state now and then every few seconds
+    pub fn start(self: &Arc<Self>) {
+        let manager = self.clone();
+        tauri::async_runtime::spawn(async move {
+            loop {
+                let app = manager.app_handle.clone();
+                match tauri::async_runtime::spawn_blocking(move || Self::detect(&app)).await {
+                    Ok(state) => manager.update_state(state),
+                    Err(e) => warn!("Dock state check failed: {}", e),
+                }
+                tokio::time::sleep(WATCH_INTERVAL).await;
+            }
+        });
+    }
+
+    fn update_state(&self, new_state: DockState) {
+        let previous = match self.state.lock() {
+            Ok(mut state) => std::mem::replace(&mut *state, new_state),
+            Err(e) => {
+                warn!("Failed to lock dock state: {}", e);
+                return;
+            }
+        };
+
+        if previous != new_state {
+            info!("Dock state changed: {:?} -> {:?}", previous, new_state);
+            event_trail::record_transition(&self.app_handle, "docking", &previous, &new_state);
+            let docking = get_settings(&self.app_handle).docking;
+            let microphone = |state| {
+                docking
+                    .overrides_for(state)
+                    .and_then(|o| o.microphone.clone())
+            };
+            if microphone(previous) != microphone(new_state) {
+                self.pending_reopen.store(true, Ordering::SeqCst);
+            }
+            let _ = DockStateChanged(new_state).emit(&self.app_handle);
+        }
+
+        if self.pending_reopen.load(Ordering::SeqCst) {
+            self.reopen_microphone();
+        }
+    }
+
+    /// Reopen the microphone after the dock rules changed
+    pub fn rules_changed(&self) {
+        self.pending_reopen.store(true, Ordering::SeqCst);
+        self.reopen_microphone();
+    }
+
+    /// Switch an open microphone stream to the new device, waiting until
+    /// no recording is in progress
+    fn reopen_microphone(&self) {
+        let Some(audio_manager) = self.app_handle.try_state::<Arc<AudioRecordingManager>>() else {
+            return;
+        };
+        if audio_manager.is_recording() || audio_manager.is_active_listening() {
+            debug!("Deferring dock microphone switch until recording ends");
+            return;
+        }
+
+        self.pending_reopen.store(false, Ordering::SeqCst);
+        if let Err(e) = audio_manager.update_selected_device() {
+            warn!("Failed to switch microphone for dock state: {}", e);
+        }
+    }
+}
+
+/// Overrides of the dock rule for the current dock state, if any
+pub fn active_overrides(app: &AppHandle, settings: &AppSettings) -> Option<DockOverrides> {
+    let state = app.try_state::<Arc<DockingManager>>()?.state();
+    settings.docking.overrides_for(state).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dock_state_from_displays_and_lid() {
+        assert_eq!(dock_state_from(1, false), DockState::Undocked);
+        assert_eq!(dock_state_from(2, false), DockState::Docked);
+        assert_eq!(dock_state_from(1, true), DockState::Clamshell);
+        assert_eq!(dock_state_from(3, true), DockState::Clamshell);
+        assert_eq!(dock_state_from(0, false), DockState::Undocked);
+    }
+}
@@ -0,0 +1,109 @@
+//! Highlights
+//!
+//! Moments marked with the highlight shortcut during an Active Listening
+//! session. A highlight only stores when it was marked; the transcript
+//! around it is collected afterwards, since the segment being recorded at
+//! that moment hasn't been transcribed yet.
+
+use crate::managers::active_listening::ActiveListeningSession;
+use crate::managers::chapters::segment_start;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Transcript before and after a highlight included in its excerpt
+const CONTEXT_MS: i64 = 30_000;
+
+/// A moment marked during a session
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct SessionHighlight {
+    /// Unix timestamp when the highlight was marked (milliseconds)
+    pub timestamp: i64,
+    /// Milliseconds from the start of the session
+    pub offset_ms: i64,
+}
+
+/// A highlight with the transcript around it
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct HighlightExcerpt {
+    /// Milliseconds from the start of the session
+    pub offset_ms: i64,
+    pub transcript: String,
+}
+
+/// Transcript of the segments within `CONTEXT_MS` of a highlight
+fn surrounding_transcript(
+    session: &ActiveListeningSession,
+    highlight: &SessionHighlight,
+) -> String {
+    let (from, to) = (
+        highlight.offset_ms - CONTEXT_MS,
+        highlight.offset_ms + CONTEXT_MS,
+    );
+    session
+        .insights
+        .iter()
+        .filter(|insight| {
+            let start = segment_start(session, insight);
+            let end = insight.timestamp - session.started_at;
+            start <= to && end >= from && !insight.transcription.trim().is_empty()
+        })
+        .map(|insight| insight.transcription.trim())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The session's highlights with their surrounding transcript
+pub fn highlight_excerpts(session: &ActiveListeningSession) -> Vec<HighlightExcerpt> {
+    session
+        .highlights
+        .iter()
+        .map(|highlight| HighlightExcerpt {
+            offset_ms: highlight.offset_ms,
+            transcript: surrounding_transcript(session, highlight),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::active_listening::SessionInsight;
+
+    #[test]
+    fn test_excerpts_cover_segments_around_the_highlight() {
+        let session = ActiveListeningSession {
+            id: "al_1".to_string(),
+            started_at: 0,
+            ended_at: None,
+            topic: None,
+            insights: (0..6)
+                .map(|i| SessionInsight {
+                    timestamp: (i + 1) * 30_000,
+                    transcription: format!("segment {}", i),
+                    insight: String::new(),
+                    duration_ms: 30_000,
+                    speaker_id: None,
+                    speaker_label: None,
+                    translation: None,
+                })
+                .collect(),
+            highlights: vec![
+                SessionHighlight {
+                    timestamp: 75_000,
+                    offset_ms: 75_000,
+                },
+                SessionHighlight {
+                    timestamp: 400_000,
+                    offset_ms: 400_000,
+                },
+            ],
+            speaker_names: Default::default(),
+        };
+
+        let excerpts = highlight_excerpts(&session);
+        assert_eq!(excerpts.len(), 2);
+        assert_eq!(excerpts[0].transcript, "segment 1 segment 2 segment 3");
+        // Marked after the last transcribed segment
+        assert_eq!(excerpts[1].transcript, "");
+    }
+}
@@ -5,11 +5,17 @@ use rusqlite::{params, Connection, OptionalExtension};
 use rusqlite_migration::{Migrations, M};
 use serde::{Deserialize, Serialize};
 use specta::Type;
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::AppHandle;
 
 use crate::audio_toolkit::save_wav_file;
+use crate::events::HistoryUpdated;
+use crate::managers::active_listening_history::like_pattern;
+use crate::managers::transcription::TranscriptSegment;
+use crate::settings::HistoryDedupSettings;
+use tauri_specta::Event;
 
 /// Database migrations for transcription history.
 /// Each migration is applied in order. The library tracks which migrations
@@ -69,6 +75,108 @@ static MIGRATIONS: &[M] = &[
 
         CREATE INDEX IF NOT EXISTS idx_action_items_entry ON action_items(entry_id);",
     ),
+    // Migration 6: Ask AI conversation branching and regenerated responses
+    M::up(
+        "ALTER TABLE ask_ai_conversations ADD COLUMN parent_id TEXT;
+        ALTER TABLE ask_ai_conversations ADD COLUMN branch_turn_index INTEGER;
+        ALTER TABLE ask_ai_turns ADD COLUMN alternate_responses TEXT;",
+    ),
+    // Migration 7: Accept/reject feedback on real-time suggestions
+    M::up(
+        "CREATE TABLE IF NOT EXISTS suggestion_feedback (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            session_id TEXT,
+            suggestion_type TEXT NOT NULL,
+            category TEXT NOT NULL,
+            source TEXT,
+            text TEXT NOT NULL,
+            accepted BOOLEAN NOT NULL,
+            timestamp INTEGER NOT NULL
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_suggestion_feedback_timestamp ON suggestion_feedback(timestamp);",
+    ),
+    // Migration 8: Trash for soft-deleted entries
+    M::up(
+        "ALTER TABLE transcription_history ADD COLUMN deleted_at INTEGER;
+
+        CREATE INDEX IF NOT EXISTS idx_transcription_history_deleted_at ON transcription_history(deleted_at);",
+    ),
+    // Migration 9: Transcription model of each entry, for usage reports
+    M::up("ALTER TABLE transcription_history ADD COLUMN transcription_model TEXT;"),
+    // Migration 10: Action item mentions in later Active Listening sessions
+    M::up(
+        "CREATE TABLE IF NOT EXISTS action_item_mentions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            action_item_id INTEGER NOT NULL,
+            session_id TEXT NOT NULL,
+            excerpt TEXT NOT NULL,
+            similarity REAL NOT NULL,
+            progress TEXT NOT NULL DEFAULT 'mentioned',
+            mentioned_at INTEGER NOT NULL,
+            UNIQUE (action_item_id, session_id),
+            FOREIGN KEY (action_item_id) REFERENCES action_items(id) ON DELETE CASCADE
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_action_item_mentions_item ON action_item_mentions(action_item_id);",
+    ),
+    // Migration 11: Session and speaker of Active Listening segments
+    M::up(
+        "ALTER TABLE transcription_history ADD COLUMN session_id TEXT;
+        ALTER TABLE transcription_history ADD COLUMN speaker_id INTEGER;
+        ALTER TABLE transcription_history ADD COLUMN speaker_label TEXT;
+
+        CREATE INDEX IF NOT EXISTS idx_transcription_history_session ON transcription_history(session_id);",
+    ),
+    // Migration 12: Active Listening session tables
+    M::up(
+        "CREATE TABLE IF NOT EXISTS active_listening_sessions (
+            id TEXT PRIMARY KEY,
+            started_at INTEGER NOT NULL,
+            ended_at INTEGER,
+            topic TEXT,
+            highlights TEXT NOT NULL DEFAULT '[]',
+            speaker_names TEXT NOT NULL DEFAULT '{}',
+            summary TEXT
+        );
+
+        CREATE TABLE IF NOT EXISTS active_listening_insights (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            session_id TEXT NOT NULL,
+            insight_order INTEGER NOT NULL,
+            timestamp INTEGER NOT NULL,
+            transcription TEXT NOT NULL,
+            insight TEXT NOT NULL,
+            duration_ms INTEGER NOT NULL,
+            speaker_id INTEGER,
+            speaker_label TEXT,
+            FOREIGN KEY (session_id) REFERENCES active_listening_sessions(id) ON DELETE CASCADE
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_active_listening_sessions_started ON active_listening_sessions(started_at);
+        CREATE INDEX IF NOT EXISTS idx_active_listening_insights_session ON active_listening_insights(session_id);",
+    ),
+    // Migration 13: Transcription ratings and corrections
+    M::up(
+        "ALTER TABLE transcription_history ADD COLUMN rating INTEGER;
+        ALTER TABLE transcription_history ADD COLUMN correction TEXT;",
+    ),
+    // Migration 14: Word and segment timings
+    M::up("ALTER TABLE transcription_history ADD COLUMN segments TEXT;"),
+    // Migration 15: Active Listening segment translations
+    M::up("ALTER TABLE active_listening_insights ADD COLUMN translation TEXT;"),
+    // Migration 16: Near-duplicate dictation flag
+    M::up("ALTER TABLE transcription_history ADD COLUMN duplicate_of INTEGER;"),
+    // Migration 17: Dictation formatting mode
+    M::up("ALTER TABLE transcription_history ADD COLUMN formatting_mode TEXT;"),
+    // Migration 18: Encrypted name maps of anonymized sessions
+    M::up(
+        "CREATE TABLE IF NOT EXISTS active_listening_name_maps (
+            session_id TEXT PRIMARY KEY,
+            nonce BLOB NOT NULL,
+            names BLOB NOT NULL
+        );",
+    ),
 ];
 
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
@@ -81,6 +189,167 @@ pub struct HistoryEntry {
     pub transcription_text: String,
     pub post_processed_text: Option<String>,
     pub post_process_prompt: Option<String>,
+    /// Who said it, for Active Listening segments
+    pub speaker_label: Option<String>,
+    /// How well it was transcribed, from 1 to 5, if the user rated it
+    pub rating: Option<u8>,
+    /// What was actually said, if the user corrected the transcription
+    pub correction: Option<String>,
+    /// The entry dictated just before that this one nearly repeats
+    #[serde(default)]
+    pub duplicate_of: Option<i64>,
+    /// Name of the formatting mode the dictation was formatted with
+    #[serde(default)]
+    pub formatting_mode: Option<String>,
+}
+
+/// The Active Listening session and speaker a history entry was heard from
+#[derive(Clone, Debug)]
+pub struct SessionSpeaker {
+    pub session_id: String,
+    pub speaker_id: Option<u32>,
+    pub label: Option<String>,
+}
+
+/// What gets written to the database for a new history entry
+#[derive(Clone, Debug, Default)]
+pub struct NewEntry {
+    pub transcription_text: String,
+    pub post_processed_text: Option<String>,
+    pub post_process_prompt: Option<String>,
+    pub formatting_mode: Option<String>,
+    pub speaker: Option<SessionSpeaker>,
+    pub segments: Vec<TranscriptSegment>,
+}
+
+/// A history entry waiting in the trash
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct TrashedHistoryEntry {
+    pub entry: HistoryEntry,
+    pub deleted_at: i64,
+    /// When the entry will be purged permanently
+    pub purge_at: i64,
+}
+
+/// Disk usage of stored recordings
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct HistoryStorageStats {
+    pub entry_count: usize,
+    pub saved_count: usize,
+    pub total_bytes: u64,
+    /// Bytes held by saved entries, which are never evicted by the size cap
+    pub saved_bytes: u64,
+    pub max_bytes: Option<u64>,
+}
+
+fn trash_grace_seconds(retention_days: u32) -> i64 {
+    retention_days as i64 * 24 * 60 * 60
+}
+
+/// On-disk size of a single recording
+struct RecordingSize {
+    id: i64,
+    file_name: String,
+    saved: bool,
+    bytes: u64,
+}
+
+/// Pick the oldest unsaved recordings to delete until the total fits under
+/// `max_bytes`. `sizes` must be ordered oldest first.
+fn select_entries_over_size_cap(sizes: &[RecordingSize], max_bytes: u64) -> Vec<(i64, String)> {
+    let mut total: u64 = sizes.iter().map(|s| s.bytes).sum();
+    let mut evicted = Vec::new();
+
+    for size in sizes.iter().filter(|s| !s.saved) {
+        if total <= max_bytes {
+            break;
+        }
+        total = total.saturating_sub(size.bytes);
+        evicted.push((size.id, size.file_name.clone()));
+    }
+
+    evicted
+}
+
+/// What to do with an entry flagged as a duplicate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateResolution {
+    /// Move the duplicate to the trash
+    KeepOriginal,
+    /// Move the original to the trash and keep the duplicate
+    KeepDuplicate,
+    /// Keep both entries and drop the flag
+    KeepBoth,
+}
+
+/// How a dictation repeats the one before it
+#[derive(Debug, PartialEq)]
+enum Repeat {
+    /// Word for word, ignoring case and punctuation
+    Exact,
+    /// At least as similar as the threshold
+    Near,
+}
+
+/// Lowercase words without surrounding punctuation, for comparing
+/// transcriptions
+fn comparable_text(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether `text` repeats `previous` closely enough to be a duplicate
+fn detect_repeat(previous: &str, text: &str, threshold: f64) -> Option<Repeat> {
+    let previous = comparable_text(previous);
+    let text = comparable_text(text);
+    if text.is_empty() {
+        return None;
+    }
+    if previous == text {
+        return Some(Repeat::Exact);
+    }
+    (strsim::normalized_levenshtein(&previous, &text) >= threshold).then_some(Repeat::Near)
+}
+
+/// A transcription as counted by the monthly usage report.
+///
+/// Trashed entries aren't counted.
+#[derive(Clone, Debug)]
+pub struct UsageEntry {
+    pub timestamp: i64,
+    pub file_name: String,
+    pub word_count: usize,
+    pub transcription_chars: usize,
+    pub post_processed_chars: Option<usize>,
+    pub transcription_model: Option<String>,
+    /// The user's rating, from 1 to 5
+    pub rating: Option<u8>,
+    /// Whether the user corrected the transcription
+    pub corrected: bool,
+}
+
+/// Size of a single Ask AI exchange, for LLM usage estimates
+#[derive(Clone, Debug)]
+pub struct AskAiUsage {
+    pub timestamp: i64,
+    pub question_chars: usize,
+    pub response_chars: usize,
+}
+
+/// A recorded accept/reject reaction to a suggestion
+#[derive(Clone, Debug)]
+pub struct SuggestionFeedback {
+    pub category: String,
+    pub source: Option<String>,
+    pub accepted: bool,
+    pub timestamp: i64,
 }
 
 pub struct HistoryManager {
@@ -92,7 +361,7 @@ pub struct HistoryManager {
 impl HistoryManager {
     pub fn new(app_handle: &AppHandle) -> Result<Self> {
         // Create recordings directory in app data dir
-        let app_data_dir = app_handle.path().app_data_dir()?;
+        let app_data_dir = crate::portable::app_data_dir(app_handle)?;
         let recordings_dir = app_data_dir.join("recordings");
         let db_path = app_data_dir.join("history.db");
 
@@ -214,80 +483,227 @@ impl HistoryManager {
         Ok(Connection::open(&self.db_path)?)
     }
 
-    /// Save a transcription to history (both database and WAV file)
+    /// Save a transcription to history (both database and WAV file), with
+    /// the timings of its words or segments if the engine reported them
     pub async fn save_transcription(
         &self,
         audio_samples: Vec<f32>,
         transcription_text: String,
         post_processed_text: Option<String>,
         post_process_prompt: Option<String>,
+        formatting_mode: Option<String>,
+        segments: Vec<TranscriptSegment>,
     ) -> Result<()> {
+        let entry = NewEntry {
+            transcription_text,
+            post_processed_text,
+            post_process_prompt,
+            formatting_mode,
+            speaker: None,
+            segments,
+        };
+        self.save_entry(audio_samples, entry).await
+    }
+
+    /// Save an Active Listening segment to history along with who said it
+    pub async fn save_session_segment(
+        &self,
+        audio_samples: Vec<f32>,
+        transcription_text: String,
+        post_processed_text: Option<String>,
+        post_process_prompt: Option<String>,
+        speaker: SessionSpeaker,
+    ) -> Result<()> {
+        let entry = NewEntry {
+            transcription_text,
+            post_processed_text,
+            post_process_prompt,
+            speaker: Some(speaker),
+            ..Default::default()
+        };
+        self.save_entry(audio_samples, entry).await
+    }
+
+    async fn save_entry(&self, audio_samples: Vec<f32>, entry: NewEntry) -> Result<()> {
+        if crate::settings::is_guest_mode(&self.app_handle) {
+            debug!("Guest mode: not saving transcription to history");
+            return Ok(());
+        }
+
         let timestamp = Utc::now().timestamp();
+
+        // Catch accidental double dictations. Active Listening segments
+        // aren't dictations.
+        let dedup = crate::settings::get_settings(&self.app_handle).history_dedup;
+        let repeat = match entry.speaker {
+            None => self.find_repeat(&entry.transcription_text, timestamp, &dedup)?,
+            Some(_) => None,
+        };
+        let duplicate_of = match repeat {
+            Some((previous_id, Repeat::Exact))
+                if dedup.enabled && dedup.discard_exact_duplicates =>
+            {
+                info!("Discarded dictation repeating entry {}", previous_id);
+                return Ok(());
+            }
+            Some((previous_id, _)) if dedup.enabled => Some(previous_id),
+            _ => None,
+        };
+
         let file_name = format!("handy-{}.wav", timestamp);
-        let title = self.format_timestamp_title(timestamp);
 
         // Save WAV file
         let file_path = self.recordings_dir.join(&file_name);
         save_wav_file(file_path, &audio_samples).await?;
 
         // Save to database
-        self.save_to_database(
-            file_name,
-            timestamp,
-            title,
-            transcription_text,
-            post_processed_text,
-            post_process_prompt,
-        )?;
+        let id = self.save_to_database(file_name, timestamp, entry)?;
+        if let Some(original) = duplicate_of {
+            debug!("History entry {} repeats entry {}", id, original);
+            Self::set_duplicate_of_with_conn(&self.get_connection()?, id, Some(original))?;
+        }
 
         // Clean up old entries
         self.cleanup_old_entries()?;
 
         // Emit history updated event
-        if let Err(e) = self.app_handle.emit("history-updated", ()) {
+        if let Err(e) = HistoryUpdated.emit(&self.app_handle) {
             error!("Failed to emit history-updated event: {}", e);
         }
 
         Ok(())
     }
 
-    fn save_to_database(
-        &self,
-        file_name: String,
-        timestamp: i64,
-        title: String,
-        transcription_text: String,
-        post_processed_text: Option<String>,
-        post_process_prompt: Option<String>,
-    ) -> Result<()> {
+    fn save_to_database(&self, file_name: String, timestamp: i64, entry: NewEntry) -> Result<i64> {
+        let NewEntry {
+            transcription_text,
+            post_processed_text,
+            post_process_prompt,
+            formatting_mode,
+            speaker,
+            segments,
+        } = entry;
+        let title = self.format_timestamp_title(timestamp);
+        let model = crate::settings::get_settings(&self.app_handle).selected_model;
+        let transcription_model = Some(model).filter(|m| !m.is_empty());
+        let (session_id, speaker_id, speaker_label) = match speaker {
+            Some(speaker) => (Some(speaker.session_id), speaker.speaker_id, speaker.label),
+            None => (None, None, None),
+        };
+        let segments = if segments.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(&segments)?)
+        };
+
         let conn = self.get_connection()?;
         conn.execute(
-            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
-            params![file_name, timestamp, false, title, transcription_text, post_processed_text, post_process_prompt],
+            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, transcription_model, session_id, speaker_id, speaker_label, segments, formatting_mode) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+            params![file_name, timestamp, false, title, transcription_text, post_processed_text, post_process_prompt, transcription_model, session_id, speaker_id, speaker_label, segments, formatting_mode],
         )?;
 
         debug!("Saved transcription to database");
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// The recent dictation `text` repeats, and how
+    fn find_repeat(
+        &self,
+        text: &str,
+        timestamp: i64,
+        dedup: &HistoryDedupSettings,
+    ) -> Result<Option<(i64, Repeat)>> {
+        let conn = self.get_connection()?;
+        let recent = Self::recent_dictation_with_conn(&conn, timestamp, dedup.window_seconds)?;
+        Ok(recent.and_then(|(id, previous)| {
+            detect_repeat(&previous, text, dedup.similarity_threshold).map(|repeat| (id, repeat))
+        }))
+    }
+
+    /// The latest dictation, if it was saved at most `window_seconds`
+    /// before `timestamp`
+    fn recent_dictation_with_conn(
+        conn: &Connection,
+        timestamp: i64,
+        window_seconds: u32,
+    ) -> Result<Option<(i64, String)>> {
+        let recent = conn
+            .query_row(
+                "SELECT id, transcription_text FROM transcription_history
+                 WHERE deleted_at IS NULL AND session_id IS NULL AND timestamp >= ?1
+                 ORDER BY timestamp DESC, id DESC
+                 LIMIT 1",
+                params![timestamp - window_seconds as i64],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        Ok(recent)
+    }
+
+    fn set_duplicate_of_with_conn(
+        conn: &Connection,
+        id: i64,
+        duplicate_of: Option<i64>,
+    ) -> Result<()> {
+        conn.execute(
+            "UPDATE transcription_history SET duplicate_of = ?1 WHERE id = ?2",
+            params![duplicate_of, id],
+        )?;
         Ok(())
     }
 
+    /// Settle an entry flagged as a duplicate of the one before it
+    pub async fn resolve_duplicate(&self, id: i64, resolution: DuplicateResolution) -> Result<()> {
+        let entry = self
+            .get_entry_by_id(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("History entry {} not found", id))?;
+        let original = entry
+            .duplicate_of
+            .ok_or_else(|| anyhow::anyhow!("History entry {} is not a duplicate", id))?;
+
+        match resolution {
+            DuplicateResolution::KeepOriginal => self.delete_entry(id).await,
+            DuplicateResolution::KeepDuplicate => {
+                Self::set_duplicate_of_with_conn(&self.get_connection()?, id, None)?;
+                self.delete_entry(original).await
+            }
+            DuplicateResolution::KeepBoth => {
+                Self::set_duplicate_of_with_conn(&self.get_connection()?, id, None)?;
+                if let Err(e) = HistoryUpdated.emit(&self.app_handle) {
+                    error!("Failed to emit history-updated event: {}", e);
+                }
+                Ok(())
+            }
+        }
+    }
+
     pub fn cleanup_old_entries(&self) -> Result<()> {
         let retention_period = crate::settings::get_recording_retention_period(&self.app_handle);
 
         match retention_period {
             crate::settings::RecordingRetentionPeriod::Never => {
-                // Don't delete anything
-                return Ok(());
+                // Don't delete anything by age or count
             }
             crate::settings::RecordingRetentionPeriod::PreserveLimit => {
                 // Use the old count-based logic with history_limit
                 let limit = crate::settings::get_history_limit(&self.app_handle);
-                return self.cleanup_by_count(limit);
+                self.cleanup_by_count(limit)?;
             }
             _ => {
                 // Use time-based logic
-                return self.cleanup_by_time(retention_period);
+                self.cleanup_by_time(retention_period)?;
             }
         }
+
+        self.purge_expired_trash()?;
+
+        // The size cap applies on top of whichever policy is selected
+        if let Some(max_bytes) = crate::settings::get_history_max_size_bytes(&self.app_handle) {
+            self.cleanup_by_size(max_bytes)?;
+        }
+
+        Ok(())
     }
 
     fn delete_entries_and_files(&self, entries: &[(i64, String)]) -> Result<usize> {
@@ -325,7 +741,7 @@ impl HistoryManager {
 
         // Get all entries that are not saved, ordered by timestamp desc
         let mut stmt = conn.prepare(
-            "SELECT id, file_name FROM transcription_history WHERE saved = 0 ORDER BY timestamp DESC"
+            "SELECT id, file_name FROM transcription_history WHERE saved = 0 AND deleted_at IS NULL ORDER BY timestamp DESC"
         )?;
 
         let rows = stmt.query_map([], |row| {
@@ -366,7 +782,7 @@ impl HistoryManager {
 
         // Get all unsaved entries older than the cutoff timestamp
         let mut stmt = conn.prepare(
-            "SELECT id, file_name FROM transcription_history WHERE saved = 0 AND timestamp < ?1",
+            "SELECT id, file_name FROM transcription_history WHERE saved = 0 AND deleted_at IS NULL AND timestamp < ?1",
         )?;
 
         let rows = stmt.query_map(params![cutoff_timestamp], |row| {
@@ -390,10 +806,144 @@ impl HistoryManager {
         Ok(())
     }
 
+    /// Size of each recording on disk, trashed entries first and then oldest first
+    fn recording_sizes(&self) -> Result<Vec<RecordingSize>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name, (saved AND deleted_at IS NULL) AS saved FROM transcription_history
+             ORDER BY deleted_at IS NULL, timestamp ASC",
+        )?;
+
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, i64>("id")?,
+                row.get::<_, String>("file_name")?,
+                row.get::<_, bool>("saved")?,
+            ))
+        })?;
+
+        let mut sizes = Vec::new();
+        for row in rows {
+            let (id, file_name, saved) = row?;
+            let bytes = fs::metadata(self.recordings_dir.join(&file_name))
+                .map(|m| m.len())
+                .unwrap_or(0);
+            sizes.push(RecordingSize {
+                id,
+                file_name,
+                saved,
+                bytes,
+            });
+        }
+
+        Ok(sizes)
+    }
+
+    fn cleanup_by_size(&self, max_bytes: u64) -> Result<()> {
+        let sizes = self.recording_sizes()?;
+        let entries_to_delete = select_entries_over_size_cap(&sizes, max_bytes);
+        let deleted_count = self.delete_entries_and_files(&entries_to_delete)?;
+
+        if deleted_count > 0 {
+            debug!(
+                "Cleaned up {} old history entries to stay under the {} byte cap",
+                deleted_count, max_bytes
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Disk usage of stored recordings for the storage dashboard
+    pub fn get_storage_stats(&self) -> Result<HistoryStorageStats> {
+        let sizes = self.recording_sizes()?;
+        let saved: Vec<&RecordingSize> = sizes.iter().filter(|s| s.saved).collect();
+
+        Ok(HistoryStorageStats {
+            entry_count: sizes.len(),
+            saved_count: saved.len(),
+            total_bytes: sizes.iter().map(|s| s.bytes).sum(),
+            saved_bytes: saved.iter().map(|s| s.bytes).sum(),
+            max_bytes: crate::settings::get_history_max_size_bytes(&self.app_handle),
+        })
+    }
+
+    /// Transcriptions made in `[start, end)` (unix seconds) for the usage report
+    pub fn get_usage_entries(&self, start: i64, end: i64) -> Result<Vec<UsageEntry>> {
+        let conn = self.get_connection()?;
+        Self::get_usage_entries_with_conn(&conn, start, end)
+    }
+
+    fn get_usage_entries_with_conn(
+        conn: &Connection,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<UsageEntry>> {
+        let mut stmt = conn.prepare(
+            "SELECT timestamp, file_name, transcription_text, post_processed_text, transcription_model, rating, correction
+             FROM transcription_history
+             WHERE deleted_at IS NULL AND timestamp >= ?1 AND timestamp < ?2
+             ORDER BY timestamp ASC",
+        )?;
+
+        let rows = stmt.query_map(params![start, end], |row| {
+            let text: String = row.get("transcription_text")?;
+            let post_processed: Option<String> = row.get("post_processed_text")?;
+            Ok(UsageEntry {
+                timestamp: row.get("timestamp")?,
+                file_name: row.get("file_name")?,
+                word_count: text.split_whitespace().count(),
+                transcription_chars: text.chars().count(),
+                post_processed_chars: post_processed.map(|p| p.chars().count()),
+                transcription_model: row.get("transcription_model")?,
+                rating: row.get("rating")?,
+                corrected: row.get::<_, Option<String>>("correction")?.is_some(),
+            })
+        })?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            entries.push(row?);
+        }
+
+        Ok(entries)
+    }
+
+    /// Ask AI exchanges in `[start, end)` (unix seconds) for the usage report
+    pub fn get_ask_ai_usage(&self, start: i64, end: i64) -> Result<Vec<AskAiUsage>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT timestamp, question, response FROM ask_ai_turns
+             WHERE timestamp >= ?1 AND timestamp < ?2",
+        )?;
+
+        let rows = stmt.query_map(params![start, end], |row| {
+            Ok(AskAiUsage {
+                timestamp: row.get("timestamp")?,
+                question_chars: row.get::<_, String>("question")?.chars().count(),
+                response_chars: row.get::<_, String>("response")?.chars().count(),
+            })
+        })?;
+
+        let mut usage = Vec::new();
+        for row in rows {
+            usage.push(row?);
+        }
+
+        Ok(usage)
+    }
+
+    /// On-disk size of a recording, zero if it has been removed
+    pub fn recording_bytes(&self, file_name: &str) -> u64 {
+        fs::metadata(self.recordings_dir.join(file_name))
+            .map(|m| m.len())
+            .unwrap_or(0)
+    }
+
     pub async fn get_history_entries(&self) -> Result<Vec<HistoryEntry>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt FROM transcription_history ORDER BY timestamp DESC"
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, speaker_label, rating, correction, duplicate_of, formatting_mode FROM transcription_history WHERE deleted_at IS NULL ORDER BY timestamp DESC"
         )?;
 
         let rows = stmt.query_map([], |row| {
@@ -406,6 +956,11 @@ impl HistoryManager {
                 transcription_text: row.get("transcription_text")?,
                 post_processed_text: row.get("post_processed_text")?,
                 post_process_prompt: row.get("post_process_prompt")?,
+                speaker_label: row.get("speaker_label")?,
+                rating: row.get("rating")?,
+                correction: row.get("correction")?,
+                duplicate_of: row.get("duplicate_of")?,
+                formatting_mode: row.get("formatting_mode")?,
             })
         })?;
 
@@ -424,8 +979,9 @@ impl HistoryManager {
 
     fn get_latest_entry_with_conn(conn: &Connection) -> Result<Option<HistoryEntry>> {
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, speaker_label, rating, correction, duplicate_of, formatting_mode
              FROM transcription_history
+             WHERE deleted_at IS NULL
              ORDER BY timestamp DESC
              LIMIT 1",
         )?;
@@ -441,6 +997,11 @@ impl HistoryManager {
                     transcription_text: row.get("transcription_text")?,
                     post_processed_text: row.get("post_processed_text")?,
                     post_process_prompt: row.get("post_process_prompt")?,
+                    speaker_label: row.get("speaker_label")?,
+                    rating: row.get("rating")?,
+                    correction: row.get("correction")?,
+                    duplicate_of: row.get("duplicate_of")?,
+                    formatting_mode: row.get("formatting_mode")?,
                 })
             })
             .optional()?;
@@ -468,7 +1029,7 @@ impl HistoryManager {
         debug!("Toggled saved status for entry {}: {}", id, new_saved);
 
         // Emit history updated event
-        if let Err(e) = self.app_handle.emit("history-updated", ()) {
+        if let Err(e) = HistoryUpdated.emit(&self.app_handle) {
             error!("Failed to emit history-updated event: {}", e);
         }
 
@@ -482,7 +1043,7 @@ impl HistoryManager {
     pub async fn get_entry_by_id(&self, id: i64) -> Result<Option<HistoryEntry>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, speaker_label, rating, correction, duplicate_of, formatting_mode
              FROM transcription_history WHERE id = ?1",
         )?;
 
@@ -497,6 +1058,11 @@ impl HistoryManager {
                     transcription_text: row.get("transcription_text")?,
                     post_processed_text: row.get("post_processed_text")?,
                     post_process_prompt: row.get("post_process_prompt")?,
+                    speaker_label: row.get("speaker_label")?,
+                    rating: row.get("rating")?,
+                    correction: row.get("correction")?,
+                    duplicate_of: row.get("duplicate_of")?,
+                    formatting_mode: row.get("formatting_mode")?,
                 })
             })
             .optional()?;
@@ -504,7 +1070,31 @@ impl HistoryManager {
         Ok(entry)
     }
 
+    /// Move an entry to the trash. With a zero-day grace period the entry is
+    /// deleted permanently right away.
     pub async fn delete_entry(&self, id: i64) -> Result<()> {
+        if crate::settings::get_settings(&self.app_handle).history_trash_retention_days == 0 {
+            return self.permanently_delete_entry(id).await;
+        }
+
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET deleted_at = ?1 WHERE id = ?2 AND deleted_at IS NULL",
+            params![Utc::now().timestamp(), id],
+        )?;
+
+        debug!("Moved history entry {} to trash", id);
+
+        // Emit history updated event
+        if let Err(e) = HistoryUpdated.emit(&self.app_handle) {
+            error!("Failed to emit history-updated event: {}", e);
+        }
+
+        Ok(())
+    }
+
+    /// Delete an entry and its audio file permanently
+    async fn permanently_delete_entry(&self, id: i64) -> Result<()> {
         let conn = self.get_connection()?;
 
         // Get the entry to find the file name
@@ -528,13 +1118,177 @@ impl HistoryManager {
         debug!("Deleted history entry with id: {}", id);
 
         // Emit history updated event
-        if let Err(e) = self.app_handle.emit("history-updated", ()) {
+        if let Err(e) = HistoryUpdated.emit(&self.app_handle) {
             error!("Failed to emit history-updated event: {}", e);
         }
 
         Ok(())
     }
 
+    /// Take an entry back out of the trash
+    pub async fn restore_entry(&self, id: i64) -> Result<()> {
+        let conn = self.get_connection()?;
+        let restored = conn.execute(
+            "UPDATE transcription_history SET deleted_at = NULL WHERE id = ?1 AND deleted_at IS NOT NULL",
+            params![id],
+        )?;
+
+        if restored == 0 {
+            return Err(anyhow::anyhow!("Entry {} is not in the trash", id));
+        }
+
+        debug!("Restored history entry {} from trash", id);
+
+        if let Err(e) = HistoryUpdated.emit(&self.app_handle) {
+            error!("Failed to emit history-updated event: {}", e);
+        }
+
+        Ok(())
+    }
+
+    /// Entries currently in the trash, most recently deleted first
+    pub fn get_trash_entries(&self) -> Result<Vec<TrashedHistoryEntry>> {
+        let grace_seconds = trash_grace_seconds(
+            crate::settings::get_settings(&self.app_handle).history_trash_retention_days,
+        );
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, speaker_label, rating, correction, duplicate_of, formatting_mode, deleted_at
+             FROM transcription_history
+             WHERE deleted_at IS NOT NULL
+             ORDER BY deleted_at DESC",
+        )?;
+
+        let rows = stmt.query_map([], |row| {
+            let deleted_at: i64 = row.get("deleted_at")?;
+            Ok(TrashedHistoryEntry {
+                entry: HistoryEntry {
+                    id: row.get("id")?,
+                    file_name: row.get("file_name")?,
+                    timestamp: row.get("timestamp")?,
+                    saved: row.get("saved")?,
+                    title: row.get("title")?,
+                    transcription_text: row.get("transcription_text")?,
+                    post_processed_text: row.get("post_processed_text")?,
+                    post_process_prompt: row.get("post_process_prompt")?,
+                    speaker_label: row.get("speaker_label")?,
+                    rating: row.get("rating")?,
+                    correction: row.get("correction")?,
+                    duplicate_of: row.get("duplicate_of")?,
+                    formatting_mode: row.get("formatting_mode")?,
+                },
+                deleted_at,
+                purge_at: deleted_at + grace_seconds,
+            })
+        })?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            entries.push(row?);
+        }
+
+        Ok(entries)
+    }
+
+    /// Permanently delete everything in the trash
+    pub fn empty_trash(&self) -> Result<usize> {
+        let entries = self.trashed_entries_before(i64::MAX)?;
+        let count = entries.len();
+        self.delete_entries_and_files(&entries)?;
+
+        if count > 0 {
+            info!("Emptied {} entries from history trash", count);
+            if let Err(e) = HistoryUpdated.emit(&self.app_handle) {
+                error!("Failed to emit history-updated event: {}", e);
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Permanently delete trashed entries whose grace period has passed
+    fn purge_expired_trash(&self) -> Result<()> {
+        let retention_days =
+            crate::settings::get_settings(&self.app_handle).history_trash_retention_days;
+        let cutoff = Utc::now().timestamp() - trash_grace_seconds(retention_days);
+        let entries = self.trashed_entries_before(cutoff)?;
+        let deleted_count = self.delete_entries_and_files(&entries)?;
+
+        if deleted_count > 0 {
+            debug!(
+                "Purged {} expired entries from history trash",
+                deleted_count
+            );
+        }
+
+        Ok(())
+    }
+
+    fn trashed_entries_before(&self, cutoff: i64) -> Result<Vec<(i64, String)>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name FROM transcription_history WHERE deleted_at IS NOT NULL AND deleted_at <= ?1",
+        )?;
+
+        let rows = stmt.query_map(params![cutoff], |row| {
+            Ok((row.get::<_, i64>("id")?, row.get::<_, String>("file_name")?))
+        })?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            entries.push(row?);
+        }
+
+        Ok(entries)
+    }
+
+    /// Search history entries whose original or post-processed text contains `query`
+    pub fn search_entries(&self, query: &str, limit: usize) -> Result<Vec<HistoryEntry>> {
+        let conn = self.get_connection()?;
+        Self::search_entries_with_conn(&conn, query, limit)
+    }
+
+    fn search_entries_with_conn(
+        conn: &Connection,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<HistoryEntry>> {
+        let pattern = like_pattern(query);
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, speaker_label, rating, correction, duplicate_of, formatting_mode
+             FROM transcription_history
+             WHERE deleted_at IS NULL
+               AND (transcription_text LIKE ?1 ESCAPE '\\' OR post_processed_text LIKE ?1 ESCAPE '\\')
+             ORDER BY timestamp DESC
+             LIMIT ?2",
+        )?;
+
+        let rows = stmt.query_map(params![pattern, limit as i64], |row| {
+            Ok(HistoryEntry {
+                id: row.get("id")?,
+                file_name: row.get("file_name")?,
+                timestamp: row.get("timestamp")?,
+                saved: row.get("saved")?,
+                title: row.get("title")?,
+                transcription_text: row.get("transcription_text")?,
+                post_processed_text: row.get("post_processed_text")?,
+                post_process_prompt: row.get("post_process_prompt")?,
+                speaker_label: row.get("speaker_label")?,
+                rating: row.get("rating")?,
+                correction: row.get("correction")?,
+                duplicate_of: row.get("duplicate_of")?,
+                formatting_mode: row.get("formatting_mode")?,
+            })
+        })?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            entries.push(row?);
+        }
+
+        Ok(entries)
+    }
+
     /// Get the transcription text for a history entry by ID
     pub fn get_entry_text(&self, id: i64) -> Result<Option<String>> {
         let conn = self.get_connection()?;
@@ -548,6 +1302,156 @@ impl HistoryManager {
         Ok(text)
     }
 
+    /// Word or segment timings of an entry, empty when none were stored
+    pub fn get_entry_segments(&self, id: i64) -> Result<Vec<TranscriptSegment>> {
+        let conn = self.get_connection()?;
+        Self::get_entry_segments_with_conn(&conn, id)
+    }
+
+    fn get_entry_segments_with_conn(conn: &Connection, id: i64) -> Result<Vec<TranscriptSegment>> {
+        let segments: Option<String> = conn
+            .query_row(
+                "SELECT segments FROM transcription_history WHERE id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("History entry {} not found", id))?;
+        match segments {
+            Some(json) => Ok(serde_json::from_str(&json)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Relabel the history entries of one speaker of an Active Listening
+    /// session, returning how many were changed
+    pub fn relabel_session_speaker(
+        &self,
+        session_id: &str,
+        speaker_id: u32,
+        label: Option<&str>,
+    ) -> Result<usize> {
+        let conn = self.get_connection()?;
+        let changed =
+            Self::relabel_session_speaker_with_conn(&conn, session_id, speaker_id, label)?;
+        if changed > 0 {
+            if let Err(e) = HistoryUpdated.emit(&self.app_handle) {
+                error!("Failed to emit history-updated event: {}", e);
+            }
+        }
+        Ok(changed)
+    }
+
+    fn relabel_session_speaker_with_conn(
+        conn: &Connection,
+        session_id: &str,
+        speaker_id: u32,
+        label: Option<&str>,
+    ) -> Result<usize> {
+        Ok(conn.execute(
+            "UPDATE transcription_history SET speaker_label = ?1 WHERE session_id = ?2 AND speaker_id = ?3",
+            params![label, session_id, speaker_id],
+        )?)
+    }
+
+    /// Rewrite the texts and speaker labels of an Active Listening session's
+    /// history entries with `anonymize`, returning how many were changed
+    pub fn anonymize_session_entries(
+        &self,
+        session_id: &str,
+        anonymize: &dyn Fn(&str) -> String,
+    ) -> Result<usize> {
+        let mut conn = self.get_connection()?;
+        let changed = Self::anonymize_session_entries_with_conn(&mut conn, session_id, anonymize)?;
+        if changed > 0 {
+            if let Err(e) = HistoryUpdated.emit(&self.app_handle) {
+                error!("Failed to emit history-updated event: {}", e);
+            }
+        }
+        Ok(changed)
+    }
+
+    fn anonymize_session_entries_with_conn(
+        conn: &mut Connection,
+        session_id: &str,
+        anonymize: &dyn Fn(&str) -> String,
+    ) -> Result<usize> {
+        let tx = conn.transaction()?;
+        let entries = {
+            let mut stmt = tx.prepare(
+                "SELECT id, transcription_text, post_processed_text, correction, speaker_label, segments
+                 FROM transcription_history WHERE session_id = ?1",
+            )?;
+            let rows = stmt.query_map([session_id], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    [
+                        Some(row.get::<_, String>(1)?),
+                        row.get::<_, Option<String>>(2)?,
+                        row.get::<_, Option<String>>(3)?,
+                        row.get::<_, Option<String>>(4)?,
+                        row.get::<_, Option<String>>(5)?,
+                    ],
+                ))
+            })?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()?
+        };
+
+        let mut changed = 0;
+        for (id, texts) in entries {
+            let anonymized = texts.clone().map(|text| text.map(|text| anonymize(&text)));
+            if anonymized == texts {
+                continue;
+            }
+            let [text, post_processed, correction, label, segments] = anonymized;
+            tx.execute(
+                "UPDATE transcription_history SET transcription_text = ?1, post_processed_text = ?2, correction = ?3, speaker_label = ?4, segments = ?5 WHERE id = ?6",
+                params![text, post_processed, correction, label, segments, id],
+            )?;
+            changed += 1;
+        }
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// Store the user's rating of an entry and what was actually said, if
+    /// they corrected it. Returns the transcription and the correction it
+    /// had before.
+    pub fn rate_entry(
+        &self,
+        id: i64,
+        rating: u8,
+        correction: Option<&str>,
+    ) -> Result<(String, Option<String>)> {
+        let conn = self.get_connection()?;
+        let rated = Self::rate_entry_with_conn(&conn, id, rating, correction)?;
+        if let Err(e) = HistoryUpdated.emit(&self.app_handle) {
+            error!("Failed to emit history-updated event: {}", e);
+        }
+        Ok(rated)
+    }
+
+    fn rate_entry_with_conn(
+        conn: &Connection,
+        id: i64,
+        rating: u8,
+        correction: Option<&str>,
+    ) -> Result<(String, Option<String>)> {
+        let rated = conn
+            .query_row(
+                "SELECT transcription_text, correction FROM transcription_history WHERE id = ?1",
+                params![id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("History entry {} not found", id))?;
+        conn.execute(
+            "UPDATE transcription_history SET rating = ?1, correction = ?2 WHERE id = ?3",
+            params![rating, correction, id],
+        )?;
+        Ok(rated)
+    }
+
     /// Insert action items extracted from a transcript
     pub fn insert_action_items(
         &self,
@@ -602,6 +1506,8 @@ impl HistoryManager {
                 priority: row.get(5)?,
                 completed: row.get(6)?,
                 created_at: row.get(7)?,
+                mentions: Vec::new(),
+                stale: false,
             })
         })?;
 
@@ -609,9 +1515,79 @@ impl HistoryManager {
         for row in rows {
             items.push(row?);
         }
+
+        // Attach follow-through from later sessions
+        let mut mentions = Self::action_item_mentions(&conn)?;
+        let now = Utc::now().timestamp_millis();
+        for item in &mut items {
+            item.mentions = mentions.remove(&item.id).unwrap_or_default();
+            item.stale = crate::managers::task_extractor::is_stale(item, now);
+        }
         Ok(items)
     }
 
+    /// All action item mentions by item id, oldest first
+    fn action_item_mentions(
+        conn: &Connection,
+    ) -> Result<HashMap<i64, Vec<crate::managers::task_extractor::ActionItemMention>>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, action_item_id, session_id, excerpt, similarity, progress, mentioned_at FROM action_item_mentions ORDER BY mentioned_at ASC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(crate::managers::task_extractor::ActionItemMention {
+                id: row.get(0)?,
+                action_item_id: row.get(1)?,
+                session_id: row.get(2)?,
+                excerpt: row.get(3)?,
+                similarity: row.get::<_, f64>(4)? as f32,
+                progress: crate::managers::task_extractor::MentionProgress::parse(
+                    &row.get::<_, String>(5)?,
+                ),
+                mentioned_at: row.get(6)?,
+            })
+        })?;
+
+        let mut mentions: HashMap<i64, Vec<_>> = HashMap::new();
+        for row in rows {
+            let mention = row?;
+            mentions
+                .entry(mention.action_item_id)
+                .or_default()
+                .push(mention);
+        }
+        Ok(mentions)
+    }
+
+    /// Store that a session discussed an action item, replacing an earlier
+    /// link from the same session
+    pub fn upsert_action_item_mention(
+        &self,
+        mention: &crate::managers::task_extractor::ActionItemMention,
+    ) -> Result<crate::managers::task_extractor::ActionItemMention> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "INSERT INTO action_item_mentions (action_item_id, session_id, excerpt, similarity, progress, mentioned_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT (action_item_id, session_id) DO UPDATE SET excerpt = excluded.excerpt, similarity = excluded.similarity, progress = excluded.progress, mentioned_at = excluded.mentioned_at",
+            params![
+                mention.action_item_id,
+                mention.session_id,
+                mention.excerpt,
+                mention.similarity as f64,
+                mention.progress.as_str(),
+                mention.mentioned_at
+            ],
+        )?;
+        let id = conn.query_row(
+            "SELECT id FROM action_item_mentions WHERE action_item_id = ?1 AND session_id = ?2",
+            params![mention.action_item_id, mention.session_id],
+            |row| row.get(0),
+        )?;
+        Ok(crate::managers::task_extractor::ActionItemMention {
+            id,
+            ..mention.clone()
+        })
+    }
+
     /// Toggle an action item's completed status
     pub fn toggle_action_item(&self, id: i64, completed: bool) -> Result<()> {
         let conn = self.get_connection()?;
@@ -625,10 +1601,61 @@ impl HistoryManager {
     /// Delete an action item
     pub fn delete_action_item(&self, id: i64) -> Result<()> {
         let conn = self.get_connection()?;
+        conn.execute(
+            "DELETE FROM action_item_mentions WHERE action_item_id = ?1",
+            params![id],
+        )?;
         conn.execute("DELETE FROM action_items WHERE id = ?1", params![id])?;
         Ok(())
     }
 
+    /// Record whether a suggestion was accepted (copied/used) or dismissed
+    pub fn insert_suggestion_feedback(
+        &self,
+        session_id: Option<&str>,
+        suggestion_type: &str,
+        category: &str,
+        source: Option<&str>,
+        text: &str,
+        accepted: bool,
+    ) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "INSERT INTO suggestion_feedback (session_id, suggestion_type, category, source, text, accepted, timestamp) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![session_id, suggestion_type, category, source, text, accepted, Utc::now().timestamp()],
+        )?;
+        Ok(())
+    }
+
+    /// Get suggestion feedback recorded at or after `since` (Unix seconds)
+    pub fn get_suggestion_feedback(&self, since: i64) -> Result<Vec<SuggestionFeedback>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT category, source, accepted, timestamp FROM suggestion_feedback WHERE timestamp >= ?1 ORDER BY timestamp ASC",
+        )?;
+        let rows = stmt.query_map(params![since], |row| {
+            Ok(SuggestionFeedback {
+                category: row.get(0)?,
+                source: row.get(1)?,
+                accepted: row.get(2)?,
+                timestamp: row.get(3)?,
+            })
+        })?;
+
+        let mut feedback = Vec::new();
+        for row in rows {
+            feedback.push(row?);
+        }
+        Ok(feedback)
+    }
+
+    /// Delete all recorded suggestion feedback
+    pub fn clear_suggestion_feedback(&self) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute("DELETE FROM suggestion_feedback", [])?;
+        Ok(())
+    }
+
     fn format_timestamp_title(&self, timestamp: i64) -> String {
         if let Some(utc_datetime) = DateTime::from_timestamp(timestamp, 0) {
             // Convert UTC to local timezone
@@ -656,7 +1683,17 @@ mod tests {
                 title TEXT NOT NULL,
                 transcription_text TEXT NOT NULL,
                 post_processed_text TEXT,
-                post_process_prompt TEXT
+                post_process_prompt TEXT,
+                deleted_at INTEGER,
+                transcription_model TEXT,
+                session_id TEXT,
+                speaker_id INTEGER,
+                speaker_label TEXT,
+                rating INTEGER,
+                correction TEXT,
+                segments TEXT,
+                duplicate_of INTEGER,
+                formatting_mode TEXT
             );",
         )
         .expect("create transcription_history table");
@@ -701,4 +1738,287 @@ mod tests {
         assert_eq!(entry.transcription_text, "second");
         assert_eq!(entry.post_processed_text.as_deref(), Some("processed"));
     }
+
+    #[test]
+    fn get_latest_entry_skips_trashed_entries() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "kept", None);
+        insert_entry(&conn, 200, "trashed", None);
+        conn.execute(
+            "UPDATE transcription_history SET deleted_at = 300 WHERE timestamp = 200",
+            [],
+        )
+        .expect("trash entry");
+
+        let entry = HistoryManager::get_latest_entry_with_conn(&conn)
+            .expect("fetch latest entry")
+            .expect("entry exists");
+
+        assert_eq!(entry.transcription_text, "kept");
+    }
+
+    #[test]
+    fn relabel_session_speaker_only_touches_that_speaker() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "mine", None);
+        insert_entry(&conn, 200, "theirs", None);
+        insert_entry(&conn, 300, "other session", None);
+        conn.execute_batch(
+            "UPDATE transcription_history SET session_id = 'a', speaker_id = 0, speaker_label = 'You' WHERE timestamp = 100;
+             UPDATE transcription_history SET session_id = 'a', speaker_id = 1, speaker_label = 'Speaker 2' WHERE timestamp = 200;
+             UPDATE transcription_history SET session_id = 'b', speaker_id = 1, speaker_label = 'Speaker 2' WHERE timestamp = 300;",
+        )
+        .expect("tag entries");
+
+        let changed =
+            HistoryManager::relabel_session_speaker_with_conn(&conn, "a", 1, Some("Dana"))
+                .expect("relabel speaker");
+        assert_eq!(changed, 1);
+
+        let labels: Vec<Option<String>> = conn
+            .prepare("SELECT speaker_label FROM transcription_history ORDER BY timestamp")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(
+            labels,
+            vec![
+                Some("You".to_string()),
+                Some("Dana".to_string()),
+                Some("Speaker 2".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn anonymize_session_entries_only_touches_that_session() {
+        let mut conn = setup_conn();
+        insert_entry(&conn, 100, "ask Dana about it", Some("Ask Dana about it."));
+        insert_entry(&conn, 200, "no names here", None);
+        insert_entry(&conn, 300, "Dana again", None);
+        conn.execute_batch(
+            "UPDATE transcription_history SET session_id = 'a', speaker_label = 'Dana' WHERE timestamp IN (100, 200);
+             UPDATE transcription_history SET session_id = 'b' WHERE timestamp = 300;",
+        )
+        .expect("tag entries");
+
+        let anonymize = |text: &str| text.replace("Dana", "Person 1");
+        let changed =
+            HistoryManager::anonymize_session_entries_with_conn(&mut conn, "a", &anonymize)
+                .expect("anonymize entries");
+        assert_eq!(changed, 2);
+
+        let entries: Vec<(String, Option<String>, Option<String>)> = conn
+            .prepare(
+                "SELECT transcription_text, post_processed_text, speaker_label
+                 FROM transcription_history ORDER BY timestamp",
+            )
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(entries[0].0, "ask Person 1 about it");
+        assert_eq!(entries[0].1.as_deref(), Some("Ask Person 1 about it."));
+        assert_eq!(entries[1].2.as_deref(), Some("Person 1"));
+        assert_eq!(entries[2].0, "Dana again");
+    }
+
+    #[test]
+    fn rate_entry_stores_feedback_and_returns_previous_correction() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "deploy to cooper netties", None);
+        let id = conn.last_insert_rowid();
+
+        let (text, previous) =
+            HistoryManager::rate_entry_with_conn(&conn, id, 2, Some("deploy to Kubernetes"))
+                .expect("rate entry");
+        assert_eq!(text, "deploy to cooper netties");
+        assert_eq!(previous, None);
+
+        let (_, previous) =
+            HistoryManager::rate_entry_with_conn(&conn, id, 3, None).expect("rate entry again");
+        assert_eq!(previous.as_deref(), Some("deploy to Kubernetes"));
+
+        let usage = HistoryManager::get_usage_entries_with_conn(&conn, 0, 200)
+            .expect("fetch usage entries");
+        assert_eq!(usage[0].rating, Some(3));
+        assert!(!usage[0].corrected);
+
+        assert!(HistoryManager::rate_entry_with_conn(&conn, id + 1, 5, None).is_err());
+    }
+
+    #[test]
+    fn search_treats_wildcards_literally() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "grew 50% this year", None);
+        insert_entry(&conn, 200, "grew 50 percent", None);
+        insert_entry(&conn, 300, "snake_case names", None);
+        insert_entry(&conn, 400, "snake case names", None);
+
+        let percent =
+            HistoryManager::search_entries_with_conn(&conn, "50%", 10).expect("search percent");
+        assert_eq!(percent.len(), 1);
+        assert_eq!(percent[0].timestamp, 100);
+
+        let underscore = HistoryManager::search_entries_with_conn(&conn, "snake_c", 10)
+            .expect("search underscore");
+        assert_eq!(underscore.len(), 1);
+        assert_eq!(underscore[0].timestamp, 300);
+    }
+
+    #[test]
+    fn entry_segments_round_trip() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "no timings", None);
+        let id = conn.last_insert_rowid();
+        assert!(HistoryManager::get_entry_segments_with_conn(&conn, id)
+            .expect("fetch segments")
+            .is_empty());
+
+        conn.execute(
+            "UPDATE transcription_history SET segments = ?1 WHERE id = ?2",
+            params![
+                r#"[{"start":0.0,"end":0.4,"text":"no"},{"start":0.5,"end":1.2,"text":"timings"}]"#,
+                id
+            ],
+        )
+        .expect("store segments");
+        let segments =
+            HistoryManager::get_entry_segments_with_conn(&conn, id).expect("fetch segments");
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1].text, "timings");
+        assert_eq!(segments[1].start, 0.5);
+
+        assert!(HistoryManager::get_entry_segments_with_conn(&conn, id + 1).is_err());
+    }
+
+    #[test]
+    fn usage_entries_cover_range_excluding_trashed() {
+        let conn = setup_conn();
+        insert_entry(&conn, 50, "before the month", None);
+        insert_entry(
+            &conn,
+            100,
+            "three little words",
+            Some("Three little words."),
+        );
+        insert_entry(&conn, 120, "counted", None);
+        insert_entry(&conn, 150, "trashed", None);
+        insert_entry(&conn, 200, "after the month", None);
+        conn.execute(
+            "UPDATE transcription_history SET transcription_model = 'small' WHERE timestamp = 120",
+            [],
+        )
+        .expect("set model");
+        conn.execute(
+            "UPDATE transcription_history SET deleted_at = 300 WHERE timestamp = 150",
+            [],
+        )
+        .expect("trash entry");
+
+        let usage = HistoryManager::get_usage_entries_with_conn(&conn, 100, 200)
+            .expect("fetch usage entries");
+
+        assert_eq!(usage.len(), 2);
+        assert_eq!(usage[0].word_count, 3);
+        assert_eq!(usage[0].post_processed_chars, Some(19));
+        assert_eq!(usage[0].transcription_model, None);
+        assert_eq!(usage[1].timestamp, 120);
+        assert_eq!(usage[1].transcription_model.as_deref(), Some("small"));
+    }
+
+    #[test]
+    fn detect_repeat_ignores_case_and_punctuation() {
+        assert_eq!(
+            detect_repeat("Send the report.", "send the report", 0.85),
+            Some(Repeat::Exact)
+        );
+        assert_eq!(
+            detect_repeat("Send the report to Dana", "Send the reports to Dana", 0.85),
+            Some(Repeat::Near)
+        );
+        assert_eq!(
+            detect_repeat("Send the report", "Book a room for Friday", 0.85),
+            None
+        );
+        assert_eq!(detect_repeat("", "...", 0.85), None);
+    }
+
+    #[test]
+    fn recent_dictation_skips_old_trashed_and_session_entries() {
+        let conn = setup_conn();
+        insert_entry(&conn, 1000, "Old dictation", None);
+        assert_eq!(
+            HistoryManager::recent_dictation_with_conn(&conn, 1100, 30).unwrap(),
+            None
+        );
+
+        insert_entry(&conn, 1090, "Recent dictation", None);
+        insert_entry(&conn, 1095, "Trashed dictation", None);
+        conn.execute(
+            "UPDATE transcription_history SET deleted_at = 1096 WHERE timestamp = 1095",
+            [],
+        )
+        .unwrap();
+        insert_entry(&conn, 1098, "Meeting segment", None);
+        conn.execute(
+            "UPDATE transcription_history SET session_id = 'al_1' WHERE timestamp = 1098",
+            [],
+        )
+        .unwrap();
+
+        let (id, text) = HistoryManager::recent_dictation_with_conn(&conn, 1100, 30)
+            .unwrap()
+            .unwrap();
+        assert_eq!(text, "Recent dictation");
+
+        HistoryManager::set_duplicate_of_with_conn(&conn, id, Some(1)).unwrap();
+        let latest = HistoryManager::get_latest_entry_with_conn(&conn)
+            .unwrap()
+            .unwrap();
+        assert_eq!(latest.duplicate_of, None);
+        let duplicate_of: Option<i64> = conn
+            .query_row(
+                "SELECT duplicate_of FROM transcription_history WHERE id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(duplicate_of, Some(1));
+    }
+
+    fn recording(id: i64, saved: bool, bytes: u64) -> RecordingSize {
+        RecordingSize {
+            id,
+            file_name: format!("handy-{}.wav", id),
+            saved,
+            bytes,
+        }
+    }
+
+    #[test]
+    fn size_cap_evicts_oldest_unsaved_first() {
+        let sizes = vec![
+            recording(1, true, 400),
+            recording(2, false, 300),
+            recording(3, false, 200),
+            recording(4, false, 100),
+        ];
+
+        let evicted = select_entries_over_size_cap(&sizes, 600);
+        let ids: Vec<i64> = evicted.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![2, 3]);
+
+        assert!(select_entries_over_size_cap(&sizes, 1000).is_empty());
+    }
+
+    #[test]
+    fn size_cap_never_evicts_saved_entries() {
+        let sizes = vec![recording(1, true, 500), recording(2, false, 100)];
+        let evicted = select_entries_over_size_cap(&sizes, 100);
+        assert_eq!(evicted, vec![(2, "handy-2.wav".to_string())]);
+    }
 }
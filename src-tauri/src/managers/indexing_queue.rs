@@ -0,0 +1,497 @@
+//! Background Indexing Queue
+//!
+//! Persists documents waiting to be added to the knowledge base and indexes
+//! them one at a time in the background. The queue is rate limited, skips
+//! duplicates, retries failures with backoff, and holds off while
+//! interactive LLM work (Active Listening, Ask AI) is in progress.
+
+use crate::events::IndexingStatusChanged;
+use crate::managers::active_listening::{ActiveListeningManager, ActiveListeningState};
+use crate::managers::ask_ai::{AskAiManager, AskAiState};
+use crate::managers::rag::{DocMetadata, RagManager};
+use crate::settings::{get_settings, KnowledgeBaseSettings};
+use chrono::Utc;
+use log::{debug, info, warn};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+use tokio::sync::Notify;
+
+/// How often the worker re-checks the queue when idle or deferred
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Base retry delay for a failed document (doubled per attempt)
+const RETRY_BASE_DELAY_SECS: i64 = 30;
+
+/// Upper bound on the retry delay
+const RETRY_MAX_DELAY_SECS: i64 = 3600;
+
+const STATUS_PENDING: &str = "pending";
+const STATUS_IN_PROGRESS: &str = "in_progress";
+const STATUS_FAILED: &str = "failed";
+
+/// Snapshot of the background indexing queue
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct IndexingStatus {
+    /// Documents waiting to be indexed (including ones scheduled for retry)
+    pub pending: i64,
+    /// Documents that exhausted their retry attempts
+    pub failed: i64,
+    /// Whether a document is being indexed right now
+    pub in_progress: bool,
+    /// Whether indexing is held back by interactive LLM work
+    pub paused: bool,
+    /// Error from the most recent failed attempt, cleared on success
+    pub last_error: Option<String>,
+}
+
+/// A document waiting in the queue
+#[derive(Clone, Debug)]
+struct QueuedDocument {
+    id: i64,
+    content: String,
+    source_type: String,
+    source_id: Option<String>,
+    title: Option<String>,
+    attempts: u32,
+}
+
+impl QueuedDocument {
+    fn metadata(&self) -> DocMetadata {
+        DocMetadata {
+            source_type: self.source_type.clone(),
+            source_id: self.source_id.clone(),
+            title: self.title.clone(),
+            extra: None,
+        }
+    }
+}
+
+/// Delay before retrying a document that has failed `attempts` times
+fn retry_delay_secs(attempts: u32) -> i64 {
+    let exponent = attempts.saturating_sub(1).min(16);
+    (RETRY_BASE_DELAY_SECS << exponent).min(RETRY_MAX_DELAY_SECS)
+}
+
+/// SQLite persistence for the queue (stored in the RAG database)
+struct QueueStore {
+    db_path: PathBuf,
+}
+
+impl QueueStore {
+    fn get_connection(&self) -> Result<Connection, String> {
+        Connection::open(&self.db_path).map_err(|e| format!("Failed to open RAG database: {}", e))
+    }
+
+    /// Add a document to the queue. Returns false if it is a duplicate of a
+    /// queued or already indexed document.
+    fn enqueue(&self, content: &str, metadata: &DocMetadata) -> Result<bool, String> {
+        let conn = self.get_connection()?;
+
+        let queued: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM indexing_queue WHERE source_type = ?1 AND source_id IS ?2 AND content = ?3)",
+                params![metadata.source_type, metadata.source_id, content],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Failed to check indexing queue: {}", e))?;
+        let indexed: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM documents WHERE source_type = ?1 AND source_id IS ?2 AND content = ?3)",
+                params![metadata.source_type, metadata.source_id, content],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Failed to check documents: {}", e))?;
+        if queued || indexed {
+            return Ok(false);
+        }
+
+        conn.execute(
+            "INSERT INTO indexing_queue (content, source_type, source_id, title) VALUES (?1, ?2, ?3, ?4)",
+            params![content, metadata.source_type, metadata.source_id, metadata.title],
+        )
+        .map_err(|e| format!("Failed to enqueue document: {}", e))?;
+        Ok(true)
+    }
+
+    /// Oldest pending document whose retry time has passed
+    fn next_due(&self, now: i64) -> Result<Option<QueuedDocument>, String> {
+        let conn = self.get_connection()?;
+        conn.query_row(
+            "SELECT id, content, source_type, source_id, title, attempts FROM indexing_queue
+             WHERE status = ?1 AND next_attempt_at <= ?2 ORDER BY id LIMIT 1",
+            params![STATUS_PENDING, now],
+            |row| {
+                Ok(QueuedDocument {
+                    id: row.get(0)?,
+                    content: row.get(1)?,
+                    source_type: row.get(2)?,
+                    source_id: row.get(3)?,
+                    title: row.get(4)?,
+                    attempts: row.get(5)?,
+                })
+            },
+        )
+        .optional()
+        .map_err(|e| format!("Failed to read indexing queue: {}", e))
+    }
+
+    fn set_status(&self, id: i64, status: &str) -> Result<(), String> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE indexing_queue SET status = ?1 WHERE id = ?2",
+            params![status, id],
+        )
+        .map_err(|e| format!("Failed to update indexing queue: {}", e))?;
+        Ok(())
+    }
+
+    fn remove(&self, id: i64) -> Result<(), String> {
+        let conn = self.get_connection()?;
+        conn.execute("DELETE FROM indexing_queue WHERE id = ?1", params![id])
+            .map_err(|e| format!("Failed to remove queued document: {}", e))?;
+        Ok(())
+    }
+
+    /// Record a failed attempt, scheduling a retry or marking the document
+    /// as failed once `max_attempts` is reached. Returns true if it failed permanently.
+    fn record_failure(
+        &self,
+        item: &QueuedDocument,
+        error: &str,
+        max_attempts: u32,
+        now: i64,
+    ) -> Result<bool, String> {
+        let attempts = item.attempts + 1;
+        let exhausted = attempts >= max_attempts.max(1);
+        let status = if exhausted {
+            STATUS_FAILED
+        } else {
+            STATUS_PENDING
+        };
+
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE indexing_queue SET status = ?1, attempts = ?2, last_error = ?3, next_attempt_at = ?4 WHERE id = ?5",
+            params![status, attempts, error, now + retry_delay_secs(attempts), item.id],
+        )
+        .map_err(|e| format!("Failed to update indexing queue: {}", e))?;
+        Ok(exhausted)
+    }
+
+    /// Return documents interrupted mid-indexing (e.g. by a crash) to the queue
+    fn reset_in_progress(&self) -> Result<usize, String> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE indexing_queue SET status = ?1 WHERE status = ?2",
+            params![STATUS_PENDING, STATUS_IN_PROGRESS],
+        )
+        .map_err(|e| format!("Failed to reset indexing queue: {}", e))
+    }
+
+    /// Re-queue every failed document with a fresh attempt count
+    fn retry_failed(&self) -> Result<usize, String> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE indexing_queue SET status = ?1, attempts = 0, next_attempt_at = 0 WHERE status = ?2",
+            params![STATUS_PENDING, STATUS_FAILED],
+        )
+        .map_err(|e| format!("Failed to retry failed documents: {}", e))
+    }
+
+    fn count(&self, status: &str) -> Result<i64, String> {
+        let conn = self.get_connection()?;
+        conn.query_row(
+            "SELECT COUNT(*) FROM indexing_queue WHERE status = ?1",
+            params![status],
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Failed to count queued documents: {}", e))
+    }
+}
+
+/// Background queue feeding documents into the knowledge base
+pub struct IndexingQueue {
+    app_handle: AppHandle,
+    store: QueueStore,
+    wake: Notify,
+    in_progress: AtomicBool,
+    paused: AtomicBool,
+    last_error: Mutex<Option<String>>,
+}
+
+impl IndexingQueue {
+    /// Create the queue on top of the RAG database at `db_path`
+    pub fn new(app_handle: &AppHandle, db_path: PathBuf) -> Result<Self, String> {
+        let store = QueueStore { db_path };
+        let restored = store.reset_in_progress()?;
+        if restored > 0 {
+            info!(
+                "Restored {} interrupted documents to the indexing queue",
+                restored
+            );
+        }
+
+        Ok(Self {
+            app_handle: app_handle.clone(),
+            store,
+            wake: Notify::new(),
+            in_progress: AtomicBool::new(false),
+            paused: AtomicBool::new(false),
+            last_error: Mutex::new(None),
+        })
+    }
+
+    /// Spawn the background worker
+    pub fn start(self: &Arc<Self>) {
+        let queue = self.clone();
+        tauri::async_runtime::spawn(async move {
+            queue.run().await;
+        });
+    }
+
+    /// Queue a document for indexing. Returns false if it was a duplicate.
+    pub fn enqueue(&self, content: &str, metadata: DocMetadata) -> Result<bool, String> {
+        if content.trim().is_empty() {
+            return Ok(false);
+        }
+        if crate::settings::is_guest_mode(&self.app_handle) {
+            debug!(
+                "Guest mode: not queueing {} document for indexing",
+                metadata.source_type
+            );
+            return Ok(false);
+        }
+
+        let added = self.store.enqueue(content, &metadata)?;
+        if added {
+            debug!("Queued {} document for indexing", metadata.source_type);
+            self.wake.notify_one();
+            self.emit_status();
+        }
+        Ok(added)
+    }
+
+    /// Re-queue documents that exhausted their retries
+    pub fn retry_failed(&self) -> Result<usize, String> {
+        let count = self.store.retry_failed()?;
+        if count > 0 {
+            self.wake.notify_one();
+            self.emit_status();
+        }
+        Ok(count)
+    }
+
+    /// Current queue status
+    pub fn status(&self) -> Result<IndexingStatus, String> {
+        Ok(IndexingStatus {
+            pending: self.store.count(STATUS_PENDING)? + self.store.count(STATUS_IN_PROGRESS)?,
+            failed: self.store.count(STATUS_FAILED)?,
+            in_progress: self.in_progress.load(Ordering::Relaxed),
+            paused: self.paused.load(Ordering::Relaxed),
+            last_error: self.last_error.lock().unwrap().clone(),
+        })
+    }
+
+    async fn run(&self) {
+        let mut wait = false;
+        loop {
+            if wait {
+                let _ = tokio::time::timeout(POLL_INTERVAL, self.wake.notified()).await;
+            }
+            wait = true;
+
+            let settings = get_settings(&self.app_handle).knowledge_base;
+            if !settings.enabled {
+                continue;
+            }
+
+            let defer = self.interactive_work_running(&settings);
+            if self.paused.swap(defer, Ordering::Relaxed) != defer {
+                self.emit_status();
+            }
+            if defer {
+                continue;
+            }
+
+            let item = match self.store.next_due(Utc::now().timestamp()) {
+                Ok(Some(item)) => item,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("Indexing queue: {}", e);
+                    continue;
+                }
+            };
+
+            self.process(item, &settings).await;
+
+            // Rate limit, then immediately look for the next document
+            let per_minute = settings.indexing_max_per_minute.max(1);
+            tokio::time::sleep(Duration::from_secs(60) / per_minute).await;
+            wait = false;
+        }
+    }
+
+    /// Whether an interactive LLM call is (or may soon be) using Ollama
+    fn interactive_work_running(&self, settings: &KnowledgeBaseSettings) -> bool {
+        if let Some(active_listening) = self.app_handle.try_state::<Arc<ActiveListeningManager>>() {
+            match active_listening.get_state() {
+                ActiveListeningState::Processing => return true,
+                ActiveListeningState::Idle => {}
+                _ if settings.pause_indexing_during_sessions => return true,
+                _ => {}
+            }
+        }
+
+        if let Some(ask_ai) = self.app_handle.try_state::<Arc<AskAiManager>>() {
+            if matches!(
+                ask_ai.get_state(),
+                AskAiState::Transcribing | AskAiState::Generating
+            ) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    async fn process(&self, item: QueuedDocument, settings: &KnowledgeBaseSettings) {
+        let rag_manager = match self.app_handle.try_state::<Arc<RagManager>>() {
+            Some(manager) => manager.inner().clone(),
+            None => return,
+        };
+
+        if let Err(e) = self.store.set_status(item.id, STATUS_IN_PROGRESS) {
+            warn!("Indexing queue: {}", e);
+            return;
+        }
+        self.in_progress.store(true, Ordering::Relaxed);
+        self.emit_status();
+
+        match rag_manager
+            .add_document(&item.content, item.metadata())
+            .await
+        {
+            Ok(document_id) => {
+                debug!(
+                    "Indexed queued {} as document {}",
+                    item.source_type, document_id
+                );
+                if let Err(e) = self.store.remove(item.id) {
+                    warn!("Indexing queue: {}", e);
+                }
+                *self.last_error.lock().unwrap() = None;
+            }
+            Err(e) => {
+                match self.store.record_failure(
+                    &item,
+                    &e,
+                    settings.indexing_max_attempts,
+                    Utc::now().timestamp(),
+                ) {
+                    Ok(true) => warn!(
+                        "Giving up indexing queued document {} after {} attempts: {}",
+                        item.id,
+                        item.attempts + 1,
+                        e
+                    ),
+                    Ok(false) => debug!("Will retry queued document {}: {}", item.id, e),
+                    Err(store_err) => warn!("Indexing queue: {}", store_err),
+                }
+                *self.last_error.lock().unwrap() = Some(e);
+            }
+        }
+
+        self.in_progress.store(false, Ordering::Relaxed);
+        self.emit_status();
+    }
+
+    fn emit_status(&self) {
+        match self.status() {
+            Ok(status) => {
+                let _ = IndexingStatusChanged(status).emit(&self.app_handle);
+            }
+            Err(e) => warn!("Indexing queue: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ollama_client::OllamaClient;
+    use tempfile::TempDir;
+
+    fn test_store() -> (TempDir, QueueStore) {
+        let dir = TempDir::new().unwrap();
+        let db_path = dir.path().join("rag.db");
+        let client = Arc::new(OllamaClient::new("http://localhost:11434").unwrap());
+        // Runs the RAG migrations, which create the queue table
+        RagManager::new(db_path.clone(), client).unwrap();
+        (dir, QueueStore { db_path })
+    }
+
+    fn metadata(source_id: &str) -> DocMetadata {
+        DocMetadata {
+            source_type: "transcription".to_string(),
+            source_id: Some(source_id.to_string()),
+            title: None,
+            extra: None,
+        }
+    }
+
+    #[test]
+    fn test_retry_delay_backs_off_and_caps() {
+        assert_eq!(retry_delay_secs(1), 30);
+        assert_eq!(retry_delay_secs(2), 60);
+        assert_eq!(retry_delay_secs(3), 120);
+        assert_eq!(retry_delay_secs(40), RETRY_MAX_DELAY_SECS);
+    }
+
+    #[test]
+    fn test_enqueue_skips_duplicates() {
+        let (_dir, store) = test_store();
+        assert!(store.enqueue("hello", &metadata("s1")).unwrap());
+        assert!(!store.enqueue("hello", &metadata("s1")).unwrap());
+        // Same text from another session is a different document
+        assert!(store.enqueue("hello", &metadata("s2")).unwrap());
+        assert_eq!(store.count(STATUS_PENDING).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_failure_schedules_retry_then_fails() {
+        let (_dir, store) = test_store();
+        store.enqueue("hello", &metadata("s1")).unwrap();
+        let item = store.next_due(0).unwrap().unwrap();
+
+        assert!(!store.record_failure(&item, "timeout", 2, 0).unwrap());
+        // Not due until the backoff has elapsed
+        assert!(store.next_due(0).unwrap().is_none());
+        let item = store.next_due(retry_delay_secs(1)).unwrap().unwrap();
+        assert_eq!(item.attempts, 1);
+
+        assert!(store.record_failure(&item, "timeout", 2, 0).unwrap());
+        assert_eq!(store.count(STATUS_FAILED).unwrap(), 1);
+
+        assert_eq!(store.retry_failed().unwrap(), 1);
+        assert!(store.next_due(0).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_reset_in_progress() {
+        let (_dir, store) = test_store();
+        store.enqueue("hello", &metadata("s1")).unwrap();
+        let item = store.next_due(0).unwrap().unwrap();
+        store.set_status(item.id, STATUS_IN_PROGRESS).unwrap();
+        assert!(store.next_due(0).unwrap().is_none());
+
+        assert_eq!(store.reset_in_progress().unwrap(), 1);
+        assert!(store.next_due(0).unwrap().is_some());
+    }
+}
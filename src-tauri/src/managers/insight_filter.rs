@@ -0,0 +1,119 @@
+//! Insight Filters
+//!
+//! Checks run on an Active Listening insight before it's shown or stored,
+//! configured by [`InsightFilters`]. Models often answer a segment with
+//! something that says nothing ("The speaker discussed various topics.") or
+//! just repeats what was said. Insights that are too short, contain a banned
+//! phrase or whose embedding is nearly the same as the transcript's are
+//! rejected, and the manager asks again or drops them.
+
+use crate::managers::rag::RagManager;
+use crate::ollama_client::OllamaClient;
+use crate::settings::InsightFilters;
+use log::warn;
+use std::fmt;
+
+/// Added to the prompt when an insight is asked for again
+const RETRY_INSTRUCTION: &str = "Your previous answer was too vague. Be specific: \
+name the concrete points, decisions or questions raised, and don't restate the transcript.";
+
+/// Why an insight was rejected
+#[derive(Debug, PartialEq)]
+pub enum InsightRejection {
+    TooShort(usize),
+    BannedPhrase(String),
+    /// Cosine similarity of the insight to the transcript
+    RestatesTranscript(f32),
+}
+
+impl fmt::Display for InsightRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort(len) => write!(f, "only {} characters", len),
+            Self::BannedPhrase(phrase) => write!(f, "contains \"{}\"", phrase),
+            Self::RestatesTranscript(similarity) => {
+                write!(f, "restates the transcript (similarity {:.2})", similarity)
+            }
+        }
+    }
+}
+
+/// Length and banned phrase checks
+pub fn check_text(insight: &str, filters: &InsightFilters) -> Result<(), InsightRejection> {
+    let insight = insight.trim();
+    let len = insight.chars().count();
+    if len < filters.min_length as usize {
+        return Err(InsightRejection::TooShort(len));
+    }
+
+    let lowercase = insight.to_lowercase();
+    if let Some(phrase) = filters
+        .banned_phrases
+        .iter()
+        .map(|phrase| phrase.trim())
+        .find(|phrase| !phrase.is_empty() && lowercase.contains(&phrase.to_lowercase()))
+    {
+        return Err(InsightRejection::BannedPhrase(phrase.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Run every enabled check. If the embeddings can't be generated the
+/// similarity check is skipped rather than losing the insight.
+pub async fn check(
+    client: &OllamaClient,
+    insight: &str,
+    transcript: &str,
+    filters: &InsightFilters,
+) -> Result<(), InsightRejection> {
+    check_text(insight, filters)?;
+
+    if filters.max_transcript_similarity <= 0.0 {
+        return Ok(());
+    }
+    let inputs = [insight.to_string(), transcript.to_string()];
+    match client
+        .generate_embeddings_batch(&filters.embedding_model, &inputs, 1)
+        .await
+    {
+        Ok(embeddings) if embeddings.len() == 2 => {
+            let similarity = RagManager::cosine_similarity(&embeddings[0], &embeddings[1]);
+            if similarity >= filters.max_transcript_similarity {
+                return Err(InsightRejection::RestatesTranscript(similarity));
+            }
+        }
+        Ok(_) => warn!("Unexpected embeddings for the insight similarity check"),
+        Err(e) => warn!("Skipping insight similarity check: {}", e),
+    }
+    Ok(())
+}
+
+/// The prompt to ask for an insight again after one was rejected
+pub fn retry_prompt(prompt: &str) -> String {
+    format!("{}\n\n{}", prompt, RETRY_INSTRUCTION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_text() {
+        let filters = InsightFilters {
+            min_length: 20,
+            banned_phrases: vec!["Various Topics".to_string(), " ".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            check_text("  Too short.  ", &filters),
+            Err(InsightRejection::TooShort(10))
+        );
+        assert_eq!(
+            check_text("The speaker discussed various topics.", &filters),
+            Err(InsightRejection::BannedPhrase("Various Topics".to_string()))
+        );
+        assert!(check_text("Dana will send the Q3 budget by Friday.", &filters).is_ok());
+    }
+}
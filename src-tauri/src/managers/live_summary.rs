@@ -0,0 +1,218 @@
+//! Live summary
+//!
+//! A running summary of the Active Listening session in progress, for
+//! catching up after joining late or zoning out. The first summary is written
+//! from the session's rolling context and its most recent segments. After
+//! that, each request folds only the segments transcribed since into the
+//! previous summary, which is cached until new segments come in.
+
+use crate::managers::active_listening::{
+    format_previous_context, ActiveListeningSession, SessionInsight,
+};
+use crate::ollama_client::OllamaClient;
+use crate::settings::get_settings;
+use log::info;
+use serde::Serialize;
+use specta::Type;
+use std::collections::VecDeque;
+use tauri::AppHandle;
+
+/// Most recent segments the first summary is written from
+const RECENT_SEGMENTS: usize = 12;
+/// Most transcript characters sent per update; the oldest are dropped first
+const MAX_TRANSCRIPT_CHARS: usize = 12_000;
+
+/// What has happened so far in a running session
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct LiveSummary {
+    pub session_id: String,
+    pub summary: String,
+    /// Segments of the session the summary covers
+    pub segment_count: u32,
+    /// When the summary was last updated (milliseconds)
+    pub generated_at: i64,
+}
+
+/// The latest live summary. Held across generation so concurrent requests
+/// wait for one update instead of each making their own.
+pub type LiveSummaryCache = tokio::sync::Mutex<Option<LiveSummary>>;
+
+/// Segments the next update has to fold in
+fn pending_segments<'a>(
+    session: &'a ActiveListeningSession,
+    previous: Option<&LiveSummary>,
+) -> &'a [SessionInsight] {
+    let start = match previous {
+        Some(previous) => previous.segment_count as usize,
+        None => session.insights.len().saturating_sub(RECENT_SEGMENTS),
+    };
+    session.insights.get(start..).unwrap_or_default()
+}
+
+/// The segments' attributed transcriptions, keeping the most recent ones
+/// when they're too long
+fn transcript(segments: &[SessionInsight]) -> String {
+    let mut lines = VecDeque::new();
+    let mut length = 0;
+    for segment in segments.iter().rev() {
+        let line = segment.attributed_transcription();
+        length += line.len() + 1;
+        if length > MAX_TRANSCRIPT_CHARS && !lines.is_empty() {
+            break;
+        }
+        lines.push_front(line);
+    }
+    Vec::from(lines).join("\n")
+}
+
+fn build_prompt(
+    session: &ActiveListeningSession,
+    previous: Option<&LiveSummary>,
+    rolling_context: &VecDeque<String>,
+    segments: &[SessionInsight],
+) -> String {
+    let topic = session.topic.as_deref().unwrap_or("Meeting");
+    let elapsed_minutes = (chrono::Utc::now().timestamp_millis() - session.started_at) / 60_000;
+    let so_far = match previous {
+        Some(previous) => format!("Summary so far:\n{}", previous.summary),
+        None => format!(
+            "Notes taken during the meeting so far:\n{}",
+            format_previous_context(rolling_context)
+        ),
+    };
+    let transcript = transcript(segments);
+
+    format!(
+        r#"Keep a running summary of a meeting that is still going on, for someone who joined late or lost focus.
+
+Meeting Topic: {topic}
+Elapsed: {elapsed_minutes} minutes
+
+{so_far}
+
+Latest transcript:
+{transcript}
+
+Write the updated summary of everything that has happened so far: one short paragraph on where the discussion stands, then bullet points for the main points, decisions and open questions.
+
+Important:
+- Keep what still matters from before and add what's new
+- Be concise and factual; only include what was actually said
+- Reply with the summary only"#,
+    )
+}
+
+/// Bring the cached summary of `session` up to date and return it
+pub async fn update(
+    app: &AppHandle,
+    cache: &LiveSummaryCache,
+    session: &ActiveListeningSession,
+    rolling_context: &VecDeque<String>,
+) -> Result<LiveSummary, String> {
+    let mut cached = cache.lock().await;
+    let previous = cached.as_ref().filter(|s| s.session_id == session.id);
+    let segments = pending_segments(session, previous);
+    if segments.is_empty() {
+        return previous
+            .cloned()
+            .ok_or_else(|| "Nothing has been transcribed yet".to_string());
+    }
+
+    let settings = get_settings(app).active_listening;
+    if settings.ollama_model.is_empty() {
+        return Err("No Ollama model configured".to_string());
+    }
+    let prompt = build_prompt(session, previous, rolling_context, segments);
+    let client = OllamaClient::for_active_listening(&settings)
+        .map_err(|e| format!("Failed to create Ollama client: {}", e))?;
+    let response = client
+        .generate(&settings.ollama_model, prompt)
+        .await
+        .map_err(|e| format!("Ollama request failed: {}", e))?;
+
+    let summary = LiveSummary {
+        session_id: session.id.clone(),
+        summary: response.trim().to_string(),
+        segment_count: session.insights.len() as u32,
+        generated_at: chrono::Utc::now().timestamp_millis(),
+    };
+    info!(
+        "Updated live summary of session {} with {} segments",
+        session.id,
+        segments.len()
+    );
+    *cached = Some(summary.clone());
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(count: usize) -> ActiveListeningSession {
+        ActiveListeningSession {
+            id: "al_1".to_string(),
+            started_at: 1_000_000,
+            ended_at: None,
+            topic: None,
+            insights: (0..count)
+                .map(|i| SessionInsight {
+                    timestamp: 1_000_000 + (i as i64 + 1) * 30_000,
+                    transcription: format!("segment {}", i),
+                    insight: String::new(),
+                    duration_ms: 30_000,
+                    speaker_id: None,
+                    speaker_label: None,
+                    translation: None,
+                })
+                .collect(),
+            highlights: Vec::new(),
+            speaker_names: Default::default(),
+        }
+    }
+
+    fn summary(segment_count: u32) -> LiveSummary {
+        LiveSummary {
+            session_id: "al_1".to_string(),
+            summary: "They agreed on the launch date.".to_string(),
+            segment_count,
+            generated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_first_summary_uses_recent_segments_then_only_new_ones() {
+        let session = session(20);
+        let first = pending_segments(&session, None);
+        assert_eq!(first.len(), RECENT_SEGMENTS);
+        assert_eq!(first[0].transcription, "segment 8");
+
+        let update = pending_segments(&session, Some(&summary(18)));
+        assert_eq!(update.len(), 2);
+        assert_eq!(update[0].transcription, "segment 18");
+        assert!(pending_segments(&session, Some(&summary(20))).is_empty());
+    }
+
+    #[test]
+    fn test_update_prompt_builds_on_the_previous_summary() {
+        let session = session(3);
+        let previous = summary(2);
+        let prompt = build_prompt(
+            &session,
+            Some(&previous),
+            &VecDeque::from(["An earlier note".to_string()]),
+            pending_segments(&session, Some(&previous)),
+        );
+        assert!(prompt.contains("They agreed on the launch date."));
+        assert!(prompt.contains("segment 2"));
+        assert!(!prompt.contains("segment 1"));
+        assert!(!prompt.contains("An earlier note"));
+    }
+
+    #[test]
+    fn test_transcript_keeps_the_latest_segments() {
+        let mut session = session(3);
+        session.insights[0].transcription = "x".repeat(MAX_TRANSCRIPT_CHARS);
+        assert_eq!(transcript(&session.insights), "segment 1\nsegment 2");
+    }
+}
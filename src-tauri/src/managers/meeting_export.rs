@@ -0,0 +1,675 @@
+//! Meeting summary export
+//!
+//! Lays a meeting summary out once as a document of headings, paragraphs,
+//! lists and tables, then renders that document as Markdown, HTML, plain
+//! text or PDF. When the session is known, its transcript is appended with
+//! every segment attributed to its speaker.
+
+use crate::export::format_timestamp;
+use crate::managers::active_listening::{ActiveListeningSession, MeetingSummary};
+use crate::managers::chapters::format_offset;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Format a meeting summary is exported in
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryExportFormat {
+    Markdown,
+    Text,
+    Html,
+    Json,
+    Pdf,
+}
+
+impl SummaryExportFormat {
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            SummaryExportFormat::Markdown => "md",
+            SummaryExportFormat::Text => "txt",
+            SummaryExportFormat::Html => "html",
+            SummaryExportFormat::Json => "json",
+            SummaryExportFormat::Pdf => "pdf",
+        }
+    }
+}
+
+/// A transcript segment in the appendix
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct TranscriptLine {
+    /// Milliseconds into the session
+    pub offset_ms: i64,
+    pub speaker: Option<String>,
+    pub text: String,
+    /// The segment in the session's second language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation: Option<String>,
+}
+
+/// Part of a summary document
+#[derive(Debug, Clone, PartialEq)]
+enum Block {
+    Title(String),
+    Heading(String),
+    Paragraph(String),
+    /// A paragraph starting with a bold label, e.g. a timestamp and speaker
+    Labeled {
+        label: String,
+        text: String,
+    },
+    List(Vec<String>),
+    Table {
+        header: Vec<&'static str>,
+        rows: Vec<Vec<String>>,
+    },
+}
+
+/// The session's segments with their offsets and speakers
+pub fn transcript_lines(session: &ActiveListeningSession) -> Vec<TranscriptLine> {
+    session
+        .insights
+        .iter()
+        .filter(|insight| !insight.transcription.trim().is_empty())
+        .map(|insight| TranscriptLine {
+            offset_ms: insight.timestamp - session.started_at,
+            // Speakers renamed after the segment was stored get their new name
+            speaker: insight
+                .speaker_id
+                .and_then(|id| session.speaker_names.get(&id).cloned())
+                .or_else(|| insight.speaker_label.clone()),
+            text: insight.transcription.trim().to_string(),
+            translation: insight
+                .translation
+                .as_ref()
+                .map(|translation| translation.trim().to_string())
+                .filter(|translation| !translation.is_empty()),
+        })
+        .collect()
+}
+
+fn document(summary: &MeetingSummary, session: Option<&ActiveListeningSession>) -> Vec<Block> {
+    let title = session
+        .and_then(|session| session.topic.clone())
+        .filter(|topic| !topic.trim().is_empty())
+        .unwrap_or_else(|| "Meeting Summary".to_string());
+    let started_at = session.map_or(summary.generated_at, |session| session.started_at);
+    let mut blocks = vec![
+        Block::Title(title),
+        Block::Paragraph(format!(
+            "{} · {} minutes",
+            format_timestamp(started_at / 1000),
+            summary.duration_minutes
+        )),
+        Block::Heading("Executive Summary".to_string()),
+        Block::Paragraph(summary.executive_summary.clone()),
+    ];
+
+    if !summary.chapters.is_empty() {
+        blocks.push(Block::Heading("Chapters".to_string()));
+        blocks.push(Block::Table {
+            header: vec!["Chapter", "Start", "End"],
+            rows: summary
+                .chapters
+                .iter()
+                .map(|chapter| {
+                    vec![
+                        chapter.title.clone(),
+                        format_offset(chapter.start_ms),
+                        format_offset(chapter.end_ms),
+                    ]
+                })
+                .collect(),
+        });
+    }
+
+    if !summary.highlights.is_empty() {
+        blocks.push(Block::Heading("Highlights".to_string()));
+        blocks.extend(summary.highlights.iter().map(|highlight| Block::Labeled {
+            label: format_offset(highlight.offset_ms),
+            text: highlight.transcript.clone(),
+        }));
+    }
+
+    if !summary.decisions.is_empty() {
+        blocks.push(Block::Heading("Key Decisions".to_string()));
+        blocks.push(Block::List(summary.decisions.clone()));
+    }
+
+    if !summary.action_items.is_empty() {
+        blocks.push(Block::Heading("Action Items".to_string()));
+        blocks.push(Block::Table {
+            header: vec!["Task", "Assignee", "Due"],
+            rows: summary
+                .action_items
+                .iter()
+                .map(|item| {
+                    vec![
+                        item.description.clone(),
+                        item.assignee.clone().unwrap_or_else(|| "—".to_string()),
+                        item.deadline.clone().unwrap_or_else(|| "—".to_string()),
+                    ]
+                })
+                .collect(),
+        });
+    }
+
+    if !summary.topics.is_empty() {
+        blocks.push(Block::Heading("Topics Discussed".to_string()));
+        blocks.push(Block::List(summary.topics.clone()));
+    }
+
+    if !summary.follow_ups.is_empty() {
+        blocks.push(Block::Heading("Follow-up Questions".to_string()));
+        blocks.push(Block::List(summary.follow_ups.clone()));
+    }
+
+    let transcript = session.map(transcript_lines).unwrap_or_default();
+    let bilingual = transcript.iter().any(|line| line.translation.is_some());
+    if bilingual {
+        // Original and translation side by side
+        blocks.push(Block::Heading("Appendix: Transcript".to_string()));
+        blocks.push(Block::Table {
+            header: vec!["Time", "Speaker", "Original", "Translation"],
+            rows: transcript
+                .into_iter()
+                .map(|line| {
+                    vec![
+                        format_offset(line.offset_ms),
+                        line.speaker.unwrap_or_else(|| "—".to_string()),
+                        line.text,
+                        line.translation.unwrap_or_else(|| "—".to_string()),
+                    ]
+                })
+                .collect(),
+        });
+    } else if !transcript.is_empty() {
+        blocks.push(Block::Heading("Appendix: Transcript".to_string()));
+        blocks.extend(transcript.into_iter().map(|line| Block::Labeled {
+            label: match line.speaker {
+                Some(speaker) => format!("[{}] {}:", format_offset(line.offset_ms), speaker),
+                None => format!("[{}]", format_offset(line.offset_ms)),
+            },
+            text: line.text,
+        }));
+    }
+
+    blocks
+}
+
+/// Render a summary, with the session's transcript when it's given
+pub fn render(
+    summary: &MeetingSummary,
+    session: Option<&ActiveListeningSession>,
+    format: SummaryExportFormat,
+) -> Result<Vec<u8>, String> {
+    let blocks = document(summary, session);
+    Ok(match format {
+        SummaryExportFormat::Markdown => render_markdown(&blocks).into_bytes(),
+        SummaryExportFormat::Text => render_text(&blocks).into_bytes(),
+        SummaryExportFormat::Html => render_html(&blocks).into_bytes(),
+        SummaryExportFormat::Pdf => render_pdf(&blocks),
+        SummaryExportFormat::Json => {
+            #[derive(Serialize)]
+            struct SummaryExport<'a> {
+                #[serde(flatten)]
+                summary: &'a MeetingSummary,
+                transcript: Vec<TranscriptLine>,
+            }
+            let export = SummaryExport {
+                summary,
+                transcript: session.map(transcript_lines).unwrap_or_default(),
+            };
+            serde_json::to_vec_pretty(&export)
+                .map_err(|e| format!("Failed to serialize summary: {}", e))?
+        }
+    })
+}
+
+/* ---------- Markdown ---------------------------------------------------- */
+
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+fn render_markdown(blocks: &[Block]) -> String {
+    let mut md = String::new();
+    for block in blocks {
+        match block {
+            Block::Title(text) => md.push_str(&format!("# {}\n\n", text)),
+            Block::Heading(text) => md.push_str(&format!("## {}\n\n", text)),
+            Block::Paragraph(text) => md.push_str(&format!("{}\n\n", text)),
+            Block::Labeled { label, text } => md.push_str(&format!("**{}** {}\n\n", label, text)),
+            Block::List(items) => {
+                for item in items {
+                    md.push_str(&format!("- {}\n", item));
+                }
+                md.push('\n');
+            }
+            Block::Table { header, rows } => {
+                md.push_str(&format!("| {} |\n", header.join(" | ")));
+                md.push_str(&format!("|{}\n", " --- |".repeat(header.len())));
+                for row in rows {
+                    let cells: Vec<String> = row.iter().map(|cell| markdown_cell(cell)).collect();
+                    md.push_str(&format!("| {} |\n", cells.join(" | ")));
+                }
+                md.push('\n');
+            }
+        }
+    }
+    md.truncate(md.trim_end().len());
+    md.push('\n');
+    md
+}
+
+/* ---------- Plain text -------------------------------------------------- */
+
+fn render_text(blocks: &[Block]) -> String {
+    let mut text = String::new();
+    for block in blocks {
+        match block {
+            Block::Title(title) => {
+                text.push_str(&format!("{}\n{}\n\n", title.to_uppercase(), "=".repeat(50)))
+            }
+            Block::Heading(heading) => {
+                text.push_str(&format!("{}\n{}\n", heading.to_uppercase(), "-".repeat(30)))
+            }
+            Block::Paragraph(paragraph) => text.push_str(&format!("{}\n\n", paragraph)),
+            Block::Labeled { label, text: body } => text.push_str(&format!("{} {}\n", label, body)),
+            Block::List(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    text.push_str(&format!("{}. {}\n", i + 1, item));
+                }
+                text.push('\n');
+            }
+            Block::Table { header, rows } => {
+                for row in rows {
+                    let cells: Vec<String> = header
+                        .iter()
+                        .zip(row)
+                        .skip(1)
+                        .filter(|(_, cell)| cell.as_str() != "—")
+                        .map(|(name, cell)| format!("{}: {}", name, cell))
+                        .collect();
+                    match cells.is_empty() {
+                        true => text.push_str(&format!("- {}\n", row[0])),
+                        false => text.push_str(&format!("- {} ({})\n", row[0], cells.join(", "))),
+                    }
+                }
+                text.push('\n');
+            }
+        }
+    }
+    text.truncate(text.trim_end().len());
+    text.push('\n');
+    text
+}
+
+/* ---------- HTML -------------------------------------------------------- */
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const HTML_STYLE: &str = "body { font-family: -apple-system, BlinkMacSystemFont, \"Segoe UI\", Roboto, sans-serif; max-width: 48rem; margin: 2rem auto; padding: 0 1rem; line-height: 1.5; color: #222; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #ddd; padding: 0.4rem 0.6rem; text-align: left; vertical-align: top; }
+th { background: #f4f4f4; }";
+
+fn render_html(blocks: &[Block]) -> String {
+    let title = blocks
+        .iter()
+        .find_map(|block| match block {
+            Block::Title(title) => Some(title.as_str()),
+            _ => None,
+        })
+        .unwrap_or("Meeting Summary");
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n",
+        escape_html(title),
+        HTML_STYLE
+    );
+    for block in blocks {
+        match block {
+            Block::Title(text) => html.push_str(&format!("<h1>{}</h1>\n", escape_html(text))),
+            Block::Heading(text) => html.push_str(&format!("<h2>{}</h2>\n", escape_html(text))),
+            Block::Paragraph(text) => html.push_str(&format!("<p>{}</p>\n", escape_html(text))),
+            Block::Labeled { label, text } => html.push_str(&format!(
+                "<p><strong>{}</strong> {}</p>\n",
+                escape_html(label),
+                escape_html(text)
+            )),
+            Block::List(items) => {
+                html.push_str("<ul>\n");
+                for item in items {
+                    html.push_str(&format!("<li>{}</li>\n", escape_html(item)));
+                }
+                html.push_str("</ul>\n");
+            }
+            Block::Table { header, rows } => {
+                html.push_str("<table>\n<tr>");
+                for name in header {
+                    html.push_str(&format!("<th>{}</th>", escape_html(name)));
+                }
+                html.push_str("</tr>\n");
+                for row in rows {
+                    html.push_str("<tr>");
+                    for cell in row {
+                        html.push_str(&format!("<td>{}</td>", escape_html(cell)));
+                    }
+                    html.push_str("</tr>\n");
+                }
+                html.push_str("</table>\n");
+            }
+        }
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/* ---------- PDF --------------------------------------------------------- */
+
+/// A4 in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const PAGE_MARGIN: f32 = 56.0;
+const BODY_SIZE: f32 = 10.5;
+/// Average Helvetica glyph width as a share of the font size, for wrapping
+const AVERAGE_GLYPH_WIDTH: f32 = 0.5;
+
+/// A line of text laid out on a PDF page
+struct PdfLine {
+    bold: bool,
+    size: f32,
+    indent: f32,
+    space_before: f32,
+    text: String,
+}
+
+/// Break text into lines of at most `width` characters, splitting words
+/// only when they don't fit on a line of their own
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > width {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(word.drain(..width).collect());
+        }
+        let word: String = word.into_iter().collect();
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+fn pdf_lines(blocks: &[Block]) -> Vec<PdfLine> {
+    let mut lines = Vec::new();
+    let mut push = |text: &str, bold: bool, size: f32, indent: f32, space_before: f32| {
+        let width = (PAGE_WIDTH - 2.0 * PAGE_MARGIN - indent) / (size * AVERAGE_GLYPH_WIDTH);
+        for (i, wrapped) in wrap(text, width as usize).into_iter().enumerate() {
+            lines.push(PdfLine {
+                bold,
+                size,
+                indent,
+                space_before: if i == 0 { space_before } else { 0.0 },
+                text: wrapped,
+            });
+        }
+    };
+    for block in blocks {
+        match block {
+            Block::Title(text) => push(text, true, 18.0, 0.0, 0.0),
+            Block::Heading(text) => push(text, true, 13.0, 0.0, 14.0),
+            Block::Paragraph(text) => push(text, false, BODY_SIZE, 0.0, 6.0),
+            Block::Labeled { label, text } => {
+                push(&format!("{} {}", label, text), false, BODY_SIZE, 0.0, 4.0)
+            }
+            Block::List(items) => {
+                for item in items {
+                    push(&format!("- {}", item), false, BODY_SIZE, 10.0, 3.0);
+                }
+            }
+            Block::Table { header, rows } => {
+                push(&header.join("  |  "), true, BODY_SIZE, 0.0, 6.0);
+                for row in rows {
+                    push(&row.join("  |  "), false, BODY_SIZE, 0.0, 3.0);
+                }
+            }
+        }
+    }
+    lines
+}
+
+/// Text as a PDF string literal in WinAnsi encoding. Characters the
+/// standard fonts can't show become `?`.
+fn pdf_string(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ' '..='~' => escaped.push(c),
+            '‘' | '’' => escaped.push('\''),
+            '“' | '”' => escaped.push('"'),
+            '–' | '—' => escaped.push('-'),
+            '…' => escaped.push_str("..."),
+            '\u{a0}'..='\u{ff}' => escaped.push_str(&format!("\\{:03o}", c as u32)),
+            _ => escaped.push('?'),
+        }
+    }
+    escaped
+}
+
+fn render_pdf(blocks: &[Block]) -> Vec<u8> {
+    // Lay the lines out top to bottom, starting a page when one is full
+    let mut pages = vec![String::new()];
+    let mut y = PAGE_HEIGHT - PAGE_MARGIN;
+    for line in pdf_lines(blocks) {
+        let height = line.size * 1.35;
+        let top_of_page = y == PAGE_HEIGHT - PAGE_MARGIN;
+        if !top_of_page {
+            y -= line.space_before;
+        }
+        if y - height < PAGE_MARGIN {
+            pages.push(String::new());
+            y = PAGE_HEIGHT - PAGE_MARGIN;
+        }
+        y -= height;
+        let content = pages.last_mut().expect("there is always a page");
+        content.push_str(&format!(
+            "BT /{} {:.1} Tf {:.1} {:.1} Td ({}) Tj ET\n",
+            if line.bold { "F2" } else { "F1" },
+            line.size,
+            PAGE_MARGIN + line.indent,
+            y,
+            pdf_string(&line.text)
+        ));
+    }
+
+    // Catalog, page tree and fonts, then a page and its content per page
+    let kids: Vec<String> = (0..pages.len())
+        .map(|i| format!("{} 0 R", 5 + 2 * i))
+        .collect();
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.join(" "),
+            pages.len()
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+    ];
+    for (i, content) in pages.iter().enumerate() {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+            PAGE_WIDTH,
+            PAGE_HEIGHT,
+            6 + 2 * i
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ));
+    }
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend(format!("{} 0 obj\n{}\nendobj\n", i + 1, object).into_bytes());
+    }
+    let xref = pdf.len();
+    pdf.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).into_bytes());
+    for offset in offsets {
+        pdf.extend(format!("{:010} 00000 n \n", offset).into_bytes());
+    }
+    pdf.extend(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        )
+        .into_bytes(),
+    );
+    pdf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::active_listening::{ActionItem, SessionInsight};
+    use std::collections::HashMap;
+
+    fn summary() -> MeetingSummary {
+        MeetingSummary {
+            session_id: "session".to_string(),
+            executive_summary: "We agreed to ship on Friday.".to_string(),
+            decisions: vec!["Ship on Friday".to_string()],
+            action_items: vec![ActionItem {
+                description: "Write the | release notes".to_string(),
+                assignee: Some("Dana".to_string()),
+                deadline: None,
+            }],
+            topics: Vec::new(),
+            follow_ups: Vec::new(),
+            duration_minutes: 30,
+            generated_at: 1_700_000_000_000,
+            chapters: Vec::new(),
+            highlights: Vec::new(),
+        }
+    }
+
+    fn session() -> ActiveListeningSession {
+        let insight = |seconds: i64, speaker: Option<(u32, &str)>, text: &str| SessionInsight {
+            timestamp: 1_700_000_000_000 + seconds * 1000,
+            transcription: text.to_string(),
+            insight: String::new(),
+            duration_ms: 5000,
+            speaker_id: speaker.map(|(id, _)| id),
+            speaker_label: speaker.map(|(_, label)| label.to_string()),
+            translation: None,
+        };
+        ActiveListeningSession {
+            id: "session".to_string(),
+            started_at: 1_700_000_000_000,
+            ended_at: None,
+            topic: Some("Release planning".to_string()),
+            insights: vec![
+                insight(5, Some((1, "Speaker 1")), "Can we ship Friday?"),
+                insight(12, None, "Yes, if the notes are done."),
+                insight(20, Some((2, "Alex")), "  "),
+            ],
+            highlights: Vec::new(),
+            // Speaker 1 was named after their segment was stored
+            speaker_names: HashMap::from([(1, "Dana".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_markdown_has_action_item_table_and_transcript_appendix() {
+        let md = render_markdown(&document(&summary(), Some(&session())));
+        assert!(md.starts_with("# Release planning\n"));
+        assert!(md.contains("| Task | Assignee | Due |\n| --- | --- | --- |\n"));
+        assert!(md.contains("| Write the \\| release notes | Dana | — |\n"));
+        assert!(md.contains("## Appendix: Transcript\n\n**[0:05] Dana:** Can we ship Friday?\n"));
+        assert!(md.ends_with("**[0:12]** Yes, if the notes are done.\n"));
+
+        let without_session = render_markdown(&document(&summary(), None));
+        assert!(without_session.starts_with("# Meeting Summary\n"));
+        assert!(!without_session.contains("Appendix"));
+    }
+
+    #[test]
+    fn test_translations_are_rendered_side_by_side() {
+        let mut session = session();
+        session.insights[0].translation = Some("Vendredi ?".to_string());
+        let md = render_markdown(&document(&summary(), Some(&session)));
+        assert!(md.contains("| Time | Speaker | Original | Translation |\n"));
+        assert!(md.contains("| 0:05 | Dana | Can we ship Friday? | Vendredi ? |\n"));
+        assert!(md.ends_with("| 0:12 | — | Yes, if the notes are done. | — |\n"));
+
+        let json = render(&summary(), Some(&session), SummaryExportFormat::Json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert_eq!(json.matches("\"translation\"").count(), 1);
+    }
+
+    #[test]
+    fn test_html_is_escaped() {
+        let mut summary = summary();
+        summary.executive_summary = "<b>Ship</b> & celebrate".to_string();
+        let html = render_html(&document(&summary, None));
+        assert!(html.contains("<p>&lt;b&gt;Ship&lt;/b&gt; &amp; celebrate</p>"));
+        assert!(html.contains("<th>Assignee</th>"));
+    }
+
+    #[test]
+    fn test_pdf_is_well_formed() {
+        let pdf = render_pdf(&document(&summary(), Some(&session())));
+        let text = String::from_utf8(pdf.clone()).unwrap();
+        assert!(text.starts_with("%PDF-1.4\n"));
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains("(Release planning) Tj"));
+
+        // The xref table points at the objects it lists
+        let xref: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .unwrap()
+            .lines()
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(text[xref..].starts_with("xref\n"));
+        let first_offset: usize = text[xref..].lines().nth(3).unwrap()[..10].parse().unwrap();
+        assert!(text[first_offset..].starts_with("1 0 obj\n"));
+    }
+
+    #[test]
+    fn test_wrap_and_pdf_string() {
+        assert_eq!(wrap("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap("", 10), vec![""]);
+        assert_eq!(pdf_string("a (b) \\ é — 日"), "a \\(b\\) \\\\ \\351 - ?");
+    }
+}
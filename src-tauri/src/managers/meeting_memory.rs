@@ -0,0 +1,412 @@
+//! Meeting memory
+//!
+//! Searches all past Active Listening sessions at once ("what did we say
+//! about pricing last week?"). Segments are found two ways: by the
+//! question's keywords in the stored transcripts, and, with the knowledge
+//! base on, by similarity to the transcriptions it indexed for the sessions.
+//! Both are merged per segment, and the best matches can be turned into an
+//! answer by the Active Listening model.
+
+use crate::managers::active_listening::{ActiveListeningSession, SessionInsight};
+use crate::managers::active_listening_history::ActiveListeningHistoryManager;
+use crate::managers::chapters::segment_start;
+use crate::managers::meeting_qa::segment_for_chunk;
+use crate::managers::rag::RagManager;
+use crate::ollama_client::OllamaClient;
+use crate::settings::get_settings;
+use chrono::{DateTime, Local};
+use log::{info, warn};
+use serde::Serialize;
+use specta::Type;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// Source type the knowledge base indexes session transcriptions under
+const TRANSCRIPTION_SOURCE: &str = "transcription";
+
+/// Matches returned for a search
+const MAX_MATCHES: usize = 10;
+
+/// Words of a question that say nothing about what to look for
+const STOPWORDS: &[&str] = &[
+    "the",
+    "and",
+    "for",
+    "with",
+    "that",
+    "this",
+    "from",
+    "about",
+    "what",
+    "when",
+    "where",
+    "who",
+    "how",
+    "why",
+    "which",
+    "did",
+    "does",
+    "was",
+    "were",
+    "are",
+    "have",
+    "has",
+    "had",
+    "say",
+    "said",
+    "talk",
+    "talked",
+    "mention",
+    "mentioned",
+    "discuss",
+    "discussed",
+    "decide",
+    "decided",
+    "our",
+    "you",
+    "they",
+    "any",
+    "all",
+    "there",
+    "last",
+    "week",
+    "month",
+    "yesterday",
+    "today",
+    "meeting",
+    "meetings",
+];
+
+/// A segment of a past session that matches the search
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct MemoryMatch {
+    pub session_id: String,
+    pub session_topic: Option<String>,
+    /// When the session started (milliseconds)
+    pub session_started_at: i64,
+    /// Index of the segment's insight in the session
+    pub segment_index: usize,
+    /// Start, in milliseconds from the start of the session
+    pub start_ms: i64,
+    pub speaker: Option<String>,
+    pub text: String,
+    /// Share of the search's keywords the segment contains (0-1)
+    pub keyword_score: f32,
+    /// Similarity to the search (0-1), when the knowledge base found it
+    pub similarity: Option<f32>,
+}
+
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct MemorySearch {
+    pub query: String,
+    /// Best matches first
+    pub matches: Vec<MemoryMatch>,
+    /// Answer composed from the matches, when asked for
+    pub answer: Option<String>,
+}
+
+/// How a segment matched
+#[derive(Clone, Copy, Debug, Default)]
+struct Evidence {
+    keyword_score: f32,
+    similarity: Option<f32>,
+}
+
+impl Evidence {
+    /// Keywords and similarity count equally, so a segment found both ways
+    /// ranks above one found either way alone
+    fn score(&self) -> f32 {
+        (self.keyword_score + self.similarity.unwrap_or(0.0)) / 2.0
+    }
+}
+
+/// The words of the query worth looking for in transcripts
+fn search_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in query
+        .split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+    {
+        if word.chars().count() >= 3
+            && !STOPWORDS.contains(&word.as_str())
+            && !terms.contains(&word)
+        {
+            terms.push(word);
+        }
+    }
+    terms
+}
+
+/// Whether a session started in `since..until` (milliseconds)
+fn in_range(session: &ActiveListeningSession, since: Option<i64>, until: Option<i64>) -> bool {
+    since.is_none_or(|since| session.started_at >= since)
+        && until.is_none_or(|until| session.started_at < until)
+}
+
+/// The name of whoever said a segment, as the speaker was last named
+fn speaker(session: &ActiveListeningSession, insight: &SessionInsight) -> Option<String> {
+    insight
+        .speaker_id
+        .and_then(|id| session.speaker_names.get(&id).cloned())
+        .or_else(|| insight.speaker_label.clone())
+}
+
+/// A stored session, loaded from the history once per search
+fn load_session<'a>(
+    history: &ActiveListeningHistoryManager,
+    sessions: &'a mut HashMap<String, Option<ActiveListeningSession>>,
+    id: &str,
+) -> Option<&'a ActiveListeningSession> {
+    sessions
+        .entry(id.to_string())
+        .or_insert_with(|| match history.get_session(id) {
+            Ok(stored) => stored.map(|stored| stored.session),
+            Err(e) => {
+                warn!("Failed to load session {}: {}", id, e);
+                None
+            }
+        })
+        .as_ref()
+}
+
+/// Rank the segments found and look up what they said
+fn build_matches(
+    found: HashMap<(String, usize), Evidence>,
+    sessions: &HashMap<String, Option<ActiveListeningSession>>,
+) -> Vec<MemoryMatch> {
+    let mut ranked: Vec<((String, usize), Evidence)> = found.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.score().total_cmp(&a.1.score()));
+
+    ranked
+        .into_iter()
+        .filter_map(|((session_id, index), evidence)| {
+            let session = sessions.get(&session_id)?.as_ref()?;
+            let insight = session.insights.get(index)?;
+            Some(MemoryMatch {
+                session_id,
+                session_topic: session.topic.clone(),
+                session_started_at: session.started_at,
+                segment_index: index,
+                start_ms: segment_start(session, insight),
+                speaker: speaker(session, insight),
+                text: insight.transcription.clone(),
+                keyword_score: evidence.keyword_score,
+                similarity: evidence.similarity,
+            })
+        })
+        .take(MAX_MATCHES)
+        .collect()
+}
+
+fn build_prompt(query: &str, matches: &[MemoryMatch]) -> String {
+    let mut by_time: Vec<&MemoryMatch> = matches.iter().collect();
+    by_time.sort_by_key(|m| m.session_started_at + m.start_ms);
+    let excerpts = by_time
+        .iter()
+        .map(|m| {
+            let said_at = DateTime::from_timestamp_millis(m.session_started_at + m.start_ms)
+                .map(|time| {
+                    time.with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string()
+                })
+                .unwrap_or_default();
+            let topic = m.session_topic.as_deref().unwrap_or("Meeting");
+            let speaker = m
+                .speaker
+                .as_deref()
+                .map(|speaker| format!(" {}:", speaker))
+                .unwrap_or_default();
+            format!("[{}, {}]{} {}", topic, said_at, speaker, m.text)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"Answer a question about past meetings using only the transcript excerpts below.
+
+Transcript excerpts (with the meeting and when it was said):
+{excerpts}
+
+Question: {query}
+
+Important:
+- Be concise and factual
+- Mention the meeting and date of the excerpts you rely on
+- If the excerpts don't answer the question, say so"#,
+    )
+}
+
+/// Search past sessions started between `since` and `until` (milliseconds)
+/// for the segments matching `query`, optionally answering it from them
+pub async fn search(
+    app: &AppHandle,
+    query: &str,
+    since: Option<i64>,
+    until: Option<i64>,
+    compose_answer: bool,
+) -> Result<MemorySearch, String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Err("Query is empty".to_string());
+    }
+    let settings = get_settings(app);
+    let history = app
+        .state::<Arc<ActiveListeningHistoryManager>>()
+        .inner()
+        .clone();
+    let mut found: HashMap<(String, usize), Evidence> = HashMap::new();
+    let mut sessions: HashMap<String, Option<ActiveListeningSession>> = HashMap::new();
+
+    let terms = search_terms(query);
+    let keyword_matches = history
+        .search_segments(&terms, since, until, MAX_MATCHES * 2)
+        .map_err(|e| format!("Failed to search sessions: {}", e))?;
+    for segment in keyword_matches {
+        found
+            .entry((segment.session_id, segment.segment_index))
+            .or_default()
+            .keyword_score = segment.matched_terms as f32 / terms.len() as f32;
+    }
+
+    let rag_manager = app.try_state::<Arc<RagManager>>();
+    if let Some(rag_manager) = rag_manager.filter(|_| settings.knowledge_base.enabled) {
+        // Chunks overlap, so fetch extra to end up with enough segments
+        let results = rag_manager
+            .search_source_type(query, TRANSCRIPTION_SOURCE, MAX_MATCHES * 2)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Knowledge base search across sessions failed: {}", e);
+                Vec::new()
+            });
+        let threshold = settings.knowledge_base.similarity_threshold;
+        for result in results.iter().filter(|r| r.similarity >= threshold) {
+            let Some(id) = result.metadata.source_id.as_deref() else {
+                continue;
+            };
+            let Some(session) = load_session(&history, &mut sessions, id) else {
+                continue;
+            };
+            if !in_range(session, since, until) {
+                continue;
+            }
+            if let Some(index) = segment_for_chunk(session, &result.chunk_text) {
+                let evidence = found.entry((session.id.clone(), index)).or_default();
+                evidence.similarity = Some(
+                    evidence
+                        .similarity
+                        .map_or(result.similarity, |s| s.max(result.similarity)),
+                );
+            }
+        }
+    }
+
+    for (session_id, _) in found.keys() {
+        load_session(&history, &mut sessions, session_id);
+    }
+    let matches = build_matches(found, &sessions);
+    info!("Meeting memory search matched {} segments", matches.len());
+
+    let model = &settings.active_listening.ollama_model;
+    let answer = if compose_answer && !matches.is_empty() {
+        if model.is_empty() {
+            return Err("No Ollama model configured".to_string());
+        }
+        let client = OllamaClient::for_active_listening(&settings.active_listening)
+            .map_err(|e| format!("Failed to create Ollama client: {}", e))?;
+        let answer = client
+            .generate(model, build_prompt(query, &matches))
+            .await
+            .map_err(|e| format!("Ollama request failed: {}", e))?;
+        Some(answer.trim().to_string())
+    } else {
+        None
+    };
+
+    Ok(MemorySearch {
+        query: query.to_string(),
+        matches,
+        answer,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(id: &str, started_at: i64, transcriptions: &[&str]) -> ActiveListeningSession {
+        ActiveListeningSession {
+            id: id.to_string(),
+            started_at,
+            ended_at: None,
+            topic: Some(format!("Topic {}", id)),
+            insights: transcriptions
+                .iter()
+                .enumerate()
+                .map(|(i, text)| SessionInsight {
+                    timestamp: started_at + (i as i64 + 1) * 30_000,
+                    transcription: text.to_string(),
+                    insight: String::new(),
+                    duration_ms: 30_000,
+                    speaker_id: Some(1),
+                    speaker_label: Some("Speaker 1".to_string()),
+                    translation: None,
+                })
+                .collect(),
+            highlights: Vec::new(),
+            speaker_names: HashMap::from([(1, "Alex".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_search_terms_drop_question_words() {
+        assert_eq!(
+            search_terms("What did we say about Pricing last week? pricing, EU launch"),
+            vec!["pricing", "launch"]
+        );
+        assert!(search_terms("what did we say?").is_empty());
+    }
+
+    #[test]
+    fn test_matches_found_both_ways_rank_first() {
+        let sessions = HashMap::from([
+            (
+                "al_1".to_string(),
+                Some(session(
+                    "al_1",
+                    0,
+                    &["Pricing stays", "The EU launch moves"],
+                )),
+            ),
+            ("al_gone".to_string(), None),
+        ]);
+        let found = HashMap::from([
+            (
+                ("al_1".to_string(), 0),
+                Evidence {
+                    keyword_score: 1.0,
+                    similarity: None,
+                },
+            ),
+            (
+                ("al_1".to_string(), 1),
+                Evidence {
+                    keyword_score: 0.5,
+                    similarity: Some(0.8),
+                },
+            ),
+            (("al_gone".to_string(), 0), Evidence::default()),
+            (("al_1".to_string(), 7), Evidence::default()),
+        ]);
+
+        let matches = build_matches(found, &sessions);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].segment_index, 1);
+        assert_eq!(matches[0].start_ms, 30_000);
+        assert_eq!(matches[0].speaker.as_deref(), Some("Alex"));
+        assert_eq!(matches[1].text, "Pricing stays");
+        assert!(in_range(&sessions["al_1"].clone().unwrap(), Some(0), None));
+        assert!(!in_range(&sessions["al_1"].clone().unwrap(), None, Some(0)));
+    }
+}
@@ -0,0 +1,319 @@
+//! Meeting Q&A
+//!
+//! Answers questions about a single Active Listening session ("what did we
+//! decide about pricing?"). Retrieval is scoped to the session: the knowledge
+//! base is searched for the transcriptions indexed under the session id, and
+//! when the session wasn't indexed its segments are embedded on the spot. The
+//! best matching segments are passed to the Active Listening model, and
+//! returned with their timestamps so the answer can be checked.
+
+use crate::managers::active_listening::ActiveListeningSession;
+use crate::managers::chapters::{format_offset, segment_start};
+use crate::managers::rag::{RagManager, SearchResult};
+use crate::ollama_client::OllamaClient;
+use crate::settings::get_settings;
+use log::{debug, info, warn};
+use serde::Serialize;
+use specta::Type;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// A transcript segment the answer is based on
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct MeetingAnswerSource {
+    /// Index of the segment's insight in the session
+    pub segment_index: usize,
+    /// Start, in milliseconds from the start of the session
+    pub start_ms: i64,
+    /// End, in milliseconds from the start of the session
+    pub end_ms: i64,
+    pub speaker_label: Option<String>,
+    pub text: String,
+    /// Similarity to the question (0-1, higher is better)
+    pub similarity: f32,
+}
+
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct MeetingAnswer {
+    pub session_id: String,
+    pub question: String,
+    pub answer: String,
+    /// Supporting segments, in the order they were said
+    pub sources: Vec<MeetingAnswerSource>,
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The segment a knowledge base chunk was cut from. Each segment is indexed
+/// as its own document, so a chunk is always part of one transcription.
+pub fn segment_for_chunk(session: &ActiveListeningSession, chunk: &str) -> Option<usize> {
+    let chunk = normalize_whitespace(chunk);
+    if chunk.is_empty() {
+        return None;
+    }
+    session
+        .insights
+        .iter()
+        .position(|insight| normalize_whitespace(&insight.transcription).contains(&chunk))
+}
+
+/// Keep the best score per segment and return the `top_k` best segments
+fn rank_segments(scored: Vec<(usize, f32)>, top_k: usize) -> Vec<(usize, f32)> {
+    let mut best: Vec<(usize, f32)> = Vec::new();
+    for (index, similarity) in scored {
+        match best.iter_mut().find(|(existing, _)| *existing == index) {
+            Some(entry) => entry.1 = entry.1.max(similarity),
+            None => best.push((index, similarity)),
+        }
+    }
+    best.sort_by(|a, b| b.1.total_cmp(&a.1));
+    best.truncate(top_k);
+    best
+}
+
+fn build_source(
+    session: &ActiveListeningSession,
+    index: usize,
+    similarity: f32,
+) -> MeetingAnswerSource {
+    let insight = &session.insights[index];
+    MeetingAnswerSource {
+        segment_index: index,
+        start_ms: segment_start(session, insight),
+        end_ms: (insight.timestamp - session.started_at).max(0),
+        speaker_label: insight.speaker_label.clone(),
+        text: insight.transcription.clone(),
+        similarity,
+    }
+}
+
+fn build_prompt(
+    session: &ActiveListeningSession,
+    question: &str,
+    sources: &[MeetingAnswerSource],
+) -> String {
+    let excerpts = sources
+        .iter()
+        .map(|source| {
+            let speaker = source
+                .speaker_label
+                .as_deref()
+                .map(|label| format!(" {}:", label))
+                .unwrap_or_default();
+            format!(
+                "[{}]{} {}",
+                format_offset(source.start_ms),
+                speaker,
+                source.text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let topic = session.topic.as_deref().unwrap_or("Meeting");
+
+    format!(
+        r#"Answer a question about a meeting using only the transcript excerpts below.
+
+Meeting Topic: {topic}
+Transcript excerpts (with time from the start of the meeting):
+{excerpts}
+
+Question: {question}
+
+Important:
+- Be concise and factual
+- Mention the time of the excerpts you rely on, like [12:34]
+- If the excerpts don't answer the question, say so"#,
+    )
+}
+
+/// Segments matching the question among the session's indexed transcriptions
+async fn search_index(
+    app: &AppHandle,
+    session: &ActiveListeningSession,
+    question: &str,
+    top_k: usize,
+) -> Result<Vec<(usize, f32)>, String> {
+    let Some(rag_manager) = app.try_state::<Arc<RagManager>>() else {
+        return Ok(Vec::new());
+    };
+    // Chunks overlap, so fetch extra to end up with `top_k` segments
+    let results: Vec<SearchResult> = rag_manager
+        .search_source(question, "transcription", &session.id, top_k * 2)
+        .await?;
+    Ok(results
+        .iter()
+        .filter_map(|result| {
+            segment_for_chunk(session, &result.chunk_text).map(|index| (index, result.similarity))
+        })
+        .collect())
+}
+
+/// Segments matching the question, embedding the session's segments directly
+async fn search_segments(
+    app: &AppHandle,
+    session: &ActiveListeningSession,
+    question: &str,
+) -> Result<Vec<(usize, f32)>, String> {
+    let settings = get_settings(app);
+    let kb = &settings.knowledge_base;
+    let client = OllamaClient::new(&settings.active_listening.ollama_base_url)
+        .map_err(|e| format!("Failed to create Ollama client: {}", e))?;
+
+    let query = client
+        .generate_embeddings(&kb.embedding_model, question)
+        .await?;
+    let texts: Vec<String> = session
+        .insights
+        .iter()
+        .map(|insight| insight.transcription.clone())
+        .collect();
+    let mut scored = Vec::with_capacity(texts.len());
+    for (batch_index, batch) in texts.chunks(kb.embedding_batch_size.max(1)).enumerate() {
+        let embeddings = client
+            .generate_embeddings_batch(&kb.embedding_model, batch, kb.embedding_max_retries)
+            .await?;
+        let offset = batch_index * kb.embedding_batch_size.max(1);
+        scored.extend(
+            embeddings.iter().enumerate().map(|(i, embedding)| {
+                (offset + i, RagManager::cosine_similarity(&query, embedding))
+            }),
+        );
+    }
+    Ok(scored)
+}
+
+/// Answer a question about one session, with the segments the answer is based on
+pub async fn ask_meeting(
+    app: &AppHandle,
+    session: &ActiveListeningSession,
+    question: &str,
+) -> Result<MeetingAnswer, String> {
+    let question = question.trim();
+    if question.is_empty() {
+        return Err("Question is empty".to_string());
+    }
+    if session.insights.is_empty() {
+        return Err("No transcript to answer from".to_string());
+    }
+    let settings = get_settings(app);
+    let model = settings.active_listening.ollama_model.clone();
+    if model.is_empty() {
+        return Err("No Ollama model configured".to_string());
+    }
+    let top_k = settings.knowledge_base.top_k.max(1);
+
+    let mut scored = if settings.knowledge_base.enabled {
+        search_index(app, session, question, top_k)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(
+                    "Knowledge base search failed for session {}: {}",
+                    session.id, e
+                );
+                Vec::new()
+            })
+    } else {
+        Vec::new()
+    };
+    if scored.is_empty() {
+        debug!("Session {} not indexed, embedding its segments", session.id);
+        scored = search_segments(app, session, question).await?;
+    }
+
+    let threshold = settings.knowledge_base.similarity_threshold;
+    scored.retain(|(index, similarity)| {
+        *similarity >= threshold && !session.insights[*index].transcription.trim().is_empty()
+    });
+    let mut sources: Vec<MeetingAnswerSource> = rank_segments(scored, top_k)
+        .into_iter()
+        .map(|(index, similarity)| build_source(session, index, similarity))
+        .collect();
+    sources.sort_by_key(|source| source.segment_index);
+
+    if sources.is_empty() {
+        return Ok(MeetingAnswer {
+            session_id: session.id.clone(),
+            question: question.to_string(),
+            answer: "Nothing in this meeting's transcript matches the question.".to_string(),
+            sources,
+        });
+    }
+
+    info!(
+        "Answering question about session {} from {} segments",
+        session.id,
+        sources.len()
+    );
+
+    let client = OllamaClient::for_active_listening(&settings.active_listening)
+        .map_err(|e| format!("Failed to create Ollama client: {}", e))?;
+    let answer = client
+        .generate(&model, build_prompt(session, question, &sources))
+        .await
+        .map_err(|e| format!("Ollama request failed: {}", e))?;
+
+    Ok(MeetingAnswer {
+        session_id: session.id.clone(),
+        question: question.to_string(),
+        answer: answer.trim().to_string(),
+        sources,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::active_listening::SessionInsight;
+
+    fn session(transcriptions: &[&str]) -> ActiveListeningSession {
+        ActiveListeningSession {
+            id: "al_1".to_string(),
+            started_at: 0,
+            ended_at: None,
+            topic: None,
+            insights: transcriptions
+                .iter()
+                .enumerate()
+                .map(|(i, text)| SessionInsight {
+                    timestamp: (i as i64 + 1) * 30_000,
+                    transcription: text.to_string(),
+                    insight: String::new(),
+                    duration_ms: 30_000,
+                    speaker_id: None,
+                    speaker_label: None,
+                    translation: None,
+                })
+                .collect(),
+            highlights: Vec::new(),
+            speaker_names: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_chunks_map_back_to_their_segment() {
+        let session = session(&[
+            "Let's go over the roadmap.",
+            "We decided to keep pricing at  ten dollars.\nEveryone agreed.",
+        ]);
+        assert_eq!(
+            segment_for_chunk(&session, "pricing at ten dollars. Everyone"),
+            Some(1)
+        );
+        assert_eq!(segment_for_chunk(&session, "the roadmap"), Some(0));
+        assert_eq!(segment_for_chunk(&session, "hiring plan"), None);
+        assert_eq!(segment_for_chunk(&session, "  "), None);
+    }
+
+    #[test]
+    fn test_rank_segments_keeps_best_chunk_per_segment() {
+        let ranked = rank_segments(vec![(2, 0.5), (0, 0.7), (2, 0.9), (1, 0.6)], 2);
+        assert_eq!(ranked, vec![(2, 0.9), (0, 0.7)]);
+
+        let session = session(&["a", "b", "c"]);
+        let source = build_source(&session, 2, 0.9);
+        assert_eq!((source.start_ms, source.end_ms), (60_000, 90_000));
+    }
+}
@@ -1,12 +1,35 @@
 pub mod active_listening;
+pub mod active_listening_history;
+pub mod anonymization;
+pub mod api_server;
 pub mod ask_ai;
 pub mod ask_ai_history;
+pub mod ask_ai_tools;
 pub mod audio;
+pub mod backup;
 pub mod batch_processor;
+pub mod browser_bridge;
+pub mod chapters;
+pub mod docking;
+pub mod highlights;
 pub mod history;
+pub mod indexing_queue;
+pub mod insight_filter;
+pub mod live_summary;
+pub mod meeting_export;
+pub mod meeting_memory;
+pub mod meeting_qa;
 pub mod model;
+pub mod plugins;
 pub mod rag;
+pub mod remote_control;
+pub mod scheduler;
+pub mod session_budget;
+pub mod session_replay;
 pub mod suggestion_engine;
+pub mod sync;
 pub mod task_extractor;
 pub mod transcription;
 pub mod vocabulary;
+pub mod wake_word;
+pub mod webhook;
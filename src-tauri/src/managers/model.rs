@@ -1,3 +1,8 @@
+use crate::access_policy;
+use crate::events::{
+    ModelDownloadComplete, ModelDownloadProgress, ModelExtractionCompleted, ModelExtractionFailed,
+    ModelExtractionStarted,
+};
 use crate::settings::{get_settings, write_settings};
 use anyhow::Result;
 use flate2::read::GzDecoder;
@@ -12,7 +17,9 @@ use std::io::Write;
 use std::path::PathBuf;
 use std::sync::Mutex;
 use tar::Archive;
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+use zip::ZipArchive;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub enum EngineType {
@@ -20,6 +27,19 @@ pub enum EngineType {
     Parakeet,
     Moonshine,
     SenseVoice,
+    /// Kaldi models run through Vosk, only available in builds with the
+    /// `vosk` feature
+    Vosk,
+}
+
+impl EngineType {
+    /// Whether this build can run models of the engine
+    pub fn is_available(&self) -> bool {
+        match self {
+            EngineType::Vosk => cfg!(feature = "vosk"),
+            _ => true,
+        }
+    }
 }
 
 /// Model info as stored in the JSON configuration file
@@ -129,9 +149,7 @@ impl ModelManager {
 
     pub fn new(app_handle: &AppHandle) -> Result<Self> {
         // Create models directory in app data
-        let models_dir = app_handle
-            .path()
-            .app_data_dir()
+        let models_dir = crate::portable::app_data_dir(app_handle)
             .map_err(|e| anyhow::anyhow!("Failed to get app data dir: {}", e))?
             .join("models");
 
@@ -150,6 +168,10 @@ impl ModelManager {
 
         let mut available_models = HashMap::new();
         for entry in config.models {
+            if !entry.engine_type.is_available() {
+                debug!("Skipping model {}: engine not in this build", entry.id);
+                continue;
+            }
             let id = entry.id.clone();
             available_models.insert(id, ModelInfo::from(entry));
         }
@@ -294,6 +316,8 @@ impl ModelManager {
         let url = model_info
             .url
             .ok_or_else(|| anyhow::anyhow!("No download URL for model"))?;
+        access_policy::check_url(&url).map_err(|e| anyhow::anyhow!(e))?;
+        let is_zip = url.ends_with(".zip");
         let model_path = self.models_dir.join(&model_info.filename);
         let partial_path = self
             .models_dir
@@ -403,9 +427,7 @@ impl ModelManager {
                 0.0
             },
         };
-        let _ = self
-            .app_handle
-            .emit("model-download-progress", &initial_progress);
+        let _ = ModelDownloadProgress(initial_progress.clone()).emit(&self.app_handle);
 
         // Download with progress
         while let Some(chunk) = stream.next().await {
@@ -437,7 +459,7 @@ impl ModelManager {
                 percentage,
             };
 
-            let _ = self.app_handle.emit("model-download-progress", &progress);
+            let _ = ModelDownloadProgress(progress.clone()).emit(&self.app_handle);
         }
 
         file.flush()?;
@@ -463,10 +485,10 @@ impl ModelManager {
             }
         }
 
-        // Handle directory-based models (extract tar.gz) vs file-based models
+        // Handle directory-based models (extract the archive) vs file-based models
         if model_info.is_directory {
             // Emit extraction started event
-            let _ = self.app_handle.emit("model-extraction-started", model_id);
+            let _ = ModelExtractionStarted(model_id.to_string()).emit(&self.app_handle);
             info!("Extracting archive for directory-based model: {}", model_id);
 
             // Use a temporary extraction directory to ensure atomic operations
@@ -483,23 +505,27 @@ impl ModelManager {
             // Create temporary extraction directory
             fs::create_dir_all(&temp_extract_dir)?;
 
-            // Open the downloaded tar.gz file
-            let tar_gz = File::open(&partial_path)?;
-            let tar = GzDecoder::new(tar_gz);
-            let mut archive = Archive::new(tar);
-
-            // Extract to the temporary directory first
-            archive.unpack(&temp_extract_dir).map_err(|e| {
+            // Extract to the temporary directory first. Vosk models come as
+            // zip files, the others as tar.gz files.
+            let archive = File::open(&partial_path)?;
+            let extracted = if is_zip {
+                ZipArchive::new(archive)
+                    .and_then(|mut zip| zip.extract(&temp_extract_dir))
+                    .map_err(|e| e.to_string())
+            } else {
+                Archive::new(GzDecoder::new(archive))
+                    .unpack(&temp_extract_dir)
+                    .map_err(|e| e.to_string())
+            };
+            extracted.map_err(|e| {
                 let error_msg = format!("Failed to extract archive: {}", e);
                 // Clean up failed extraction
                 let _ = fs::remove_dir_all(&temp_extract_dir);
-                let _ = self.app_handle.emit(
-                    "model-extraction-failed",
-                    &serde_json::json!({
-                        "model_id": model_id,
-                        "error": error_msg
-                    }),
-                );
+                let _ = ModelExtractionFailed {
+                    model_id: model_id.to_string(),
+                    error: error_msg.clone(),
+                }
+                .emit(&self.app_handle);
                 anyhow::anyhow!(error_msg)
             })?;
 
@@ -528,9 +554,9 @@ impl ModelManager {
 
             info!("Successfully extracted archive for model: {}", model_id);
             // Emit extraction completed event
-            let _ = self.app_handle.emit("model-extraction-completed", model_id);
+            let _ = ModelExtractionCompleted(model_id.to_string()).emit(&self.app_handle);
 
-            // Remove the downloaded tar.gz file
+            // Remove the downloaded archive
             let _ = fs::remove_file(&partial_path);
         } else {
             // Move partial file to final location for file-based models
@@ -548,7 +574,7 @@ impl ModelManager {
         }
 
         // Emit completion event
-        let _ = self.app_handle.emit("model-download-complete", model_id);
+        let _ = ModelDownloadComplete(model_id.to_string()).emit(&self.app_handle);
 
         info!(
             "Successfully downloaded model {} to {:?}",
@@ -0,0 +1,575 @@
+//! Plugin Manager
+//!
+//! Loads Rhai scripts from the `plugins` folder in the app data directory.
+//! Running a script's top level registers what it adds:
+//!
+//! ```rhai
+//! register_text_stage("Product names", |text| text.replace("dictum", "Dictum"));
+//! register_suggestion_source("Glossary", |text| {
+//!     if text.contains("SLA") { [#{ text: "Our SLA is 99.9%", confidence: 0.9 }] } else { [] }
+//! });
+//! register_action("standup", "Post standup", || emit("standup", #{ at: timestamp() }));
+//! ```
+//!
+//! Text stages run on every dictation after the automation hooks, in file
+//! name order. Suggestion sources are asked alongside the built-in sources
+//! during Active Listening. Actions can be bound to shortcuts.
+//!
+//! Scripts are sandboxed: they can't import modules, `eval` or touch files
+//! and the network, each call is cut off after a fixed number of
+//! operations, and besides the text they're given they can only read
+//! non-secret settings with `setting(key)`, send events to the frontend with
+//! `emit(name, payload)` and `log(message)`.
+
+use crate::events::PluginEmitted;
+use crate::settings::{get_settings, PluginSettings, PRIVATE_SETTINGS};
+use log::{debug, info, warn};
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{Array, Dynamic, Engine, FnPtr, Map, AST};
+use serde::Serialize;
+use specta::Type;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tauri::AppHandle;
+use tauri_specta::Event;
+
+/// Binding ids of plugin actions start with this, followed by
+/// `<plugin>:<action id>`
+pub const PLUGIN_BINDING_PREFIX: &str = "plugin:";
+
+/// Operations one call into a script may run before it's stopped
+const MAX_OPERATIONS: u64 = 1_000_000;
+const MAX_CALL_LEVELS: usize = 32;
+const MAX_STRING_SIZE: usize = 1 << 20;
+const MAX_COLLECTION_SIZE: usize = 10_000;
+
+/// Confidence of suggestions a source returns as plain strings
+const DEFAULT_SUGGESTION_CONFIDENCE: f32 = 0.7;
+
+/// Whether a binding runs a plugin action
+pub fn is_plugin_binding(binding_id: &str) -> bool {
+    binding_id.starts_with(PLUGIN_BINDING_PREFIX)
+}
+
+fn binding_id(plugin: &str, action: &str) -> String {
+    format!("{}{}:{}", PLUGIN_BINDING_PREFIX, plugin, action)
+}
+
+/// An event a plugin sent to the frontend
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct PluginMessage {
+    pub plugin: String,
+    pub name: String,
+    /// The payload as JSON
+    pub payload: String,
+}
+
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct PluginActionInfo {
+    /// Id of the shortcut binding that runs the action
+    pub binding_id: String,
+    pub label: String,
+}
+
+/// A plugin file and what it registered
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct PluginInfo {
+    /// File name without `.rhai`
+    pub name: String,
+    pub enabled: bool,
+    pub text_stages: Vec<String>,
+    pub suggestion_sources: Vec<String>,
+    pub actions: Vec<PluginActionInfo>,
+    /// Why the plugin failed to load
+    pub error: Option<String>,
+}
+
+/// A suggestion from a plugin source
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginSuggestion {
+    /// Name of the source it came from
+    pub source: String,
+    pub text: String,
+    pub confidence: f32,
+}
+
+struct RegisteredAction {
+    id: String,
+    label: String,
+    function: FnPtr,
+}
+
+/// What a script registered while its top level ran
+#[derive(Default)]
+struct Registry {
+    text_stages: Vec<(String, FnPtr)>,
+    suggestion_sources: Vec<(String, FnPtr)>,
+    actions: Vec<RegisteredAction>,
+}
+
+struct Plugin {
+    engine: Engine,
+    ast: AST,
+    registry: Registry,
+}
+
+impl Plugin {
+    /// Compile `source` and run its top level, collecting what it registers
+    fn load(source: &str, engine: Engine, registry: &Mutex<Registry>) -> Result<Self, String> {
+        let ast = engine
+            .compile(source)
+            .map_err(|e| format!("Failed to parse: {}", e))?;
+        engine
+            .run_ast(&ast)
+            .map_err(|e| format!("Failed to run: {}", e))?;
+        let registry = std::mem::take(&mut *registry.lock().unwrap());
+        Ok(Self {
+            engine,
+            ast,
+            registry,
+        })
+    }
+
+    fn call(&self, function: &FnPtr, args: impl rhai::FuncArgs) -> Result<Dynamic, String> {
+        function
+            .call::<Dynamic>(&self.engine, &self.ast, args)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Pass `text` through the plugin's stages in the order they were
+    /// registered
+    fn process_text(&self, name: &str, text: String) -> String {
+        self.registry
+            .text_stages
+            .iter()
+            .fold(text, |text, (stage, function)| {
+                match self.call(function, (text.clone(),)) {
+                    Ok(output) if output.is_string() => output.into_string().unwrap_or(text),
+                    Ok(_) => {
+                        warn!("Plugin {} stage '{}' didn't return text", name, stage);
+                        text
+                    }
+                    Err(e) => {
+                        warn!("Plugin {} stage '{}' failed: {}", name, stage, e);
+                        text
+                    }
+                }
+            })
+    }
+
+    fn suggestions(&self, name: &str, text: &str) -> Vec<PluginSuggestion> {
+        let mut suggestions = Vec::new();
+        for (source, function) in &self.registry.suggestion_sources {
+            match self.call(function, (text.to_string(),)) {
+                Ok(output) => suggestions.extend(parse_suggestions(output).into_iter().map(
+                    |(text, confidence)| PluginSuggestion {
+                        source: source.clone(),
+                        text,
+                        confidence,
+                    },
+                )),
+                Err(e) => warn!("Plugin {} source '{}' failed: {}", name, source, e),
+            }
+        }
+        suggestions
+    }
+}
+
+/// Read what a suggestion source returned: a string, a `#{ text,
+/// confidence }` map or an array of either
+fn parse_suggestions(output: Dynamic) -> Vec<(String, f32)> {
+    let items = if output.is_array() {
+        output.cast::<Array>()
+    } else if output.is_unit() {
+        Vec::new()
+    } else {
+        vec![output]
+    };
+
+    items
+        .into_iter()
+        .filter_map(|item| {
+            if item.is_string() {
+                return Some((item.into_string().ok()?, DEFAULT_SUGGESTION_CONFIDENCE));
+            }
+            let map = item.try_cast::<Map>()?;
+            let text = map.get("text")?.clone().into_string().ok()?;
+            let confidence = map
+                .get("confidence")
+                .and_then(|c| {
+                    c.as_float()
+                        .ok()
+                        .or_else(|| c.as_int().ok().map(|i| i as f64))
+                })
+                .map(|c| (c as f32).clamp(0.0, 1.0))
+                .unwrap_or(DEFAULT_SUGGESTION_CONFIDENCE);
+            Some((text, confidence))
+        })
+        .filter(|(text, _)| !text.trim().is_empty())
+        .collect()
+}
+
+/// Look up a dotted settings path like `suggestions.enabled`, refusing
+/// private settings
+fn read_setting(settings: &serde_json::Value, key: &str) -> Option<serde_json::Value> {
+    let mut path = key.split('.');
+    let top = path.next()?;
+    if PRIVATE_SETTINGS.contains(&top) {
+        return None;
+    }
+    path.try_fold(settings.get(top)?, |value, part| value.get(part))
+        .cloned()
+}
+
+/// An engine with the sandbox limits and the registration API, whose
+/// registrations are collected in the returned registry
+fn sandboxed_engine(plugin: &str) -> (Engine, Arc<Mutex<Registry>>) {
+    let mut engine = Engine::new();
+    engine
+        .set_max_operations(MAX_OPERATIONS)
+        .set_max_call_levels(MAX_CALL_LEVELS)
+        .set_max_expr_depths(64, 32)
+        .set_max_string_size(MAX_STRING_SIZE)
+        .set_max_array_size(MAX_COLLECTION_SIZE)
+        .set_max_map_size(MAX_COLLECTION_SIZE)
+        .set_module_resolver(DummyModuleResolver::new())
+        .disable_symbol("eval");
+
+    let name = plugin.to_string();
+    engine.on_print(move |message| info!("[plugin {}] {}", name, message));
+    let name = plugin.to_string();
+    engine.on_debug(move |message, _, _| debug!("[plugin {}] {}", name, message));
+    let name = plugin.to_string();
+    engine.register_fn("log", move |message: &str| {
+        info!("[plugin {}] {}", name, message)
+    });
+
+    let registry = Arc::new(Mutex::new(Registry::default()));
+    let stages = registry.clone();
+    engine.register_fn("register_text_stage", move |name: &str, function: FnPtr| {
+        stages
+            .lock()
+            .unwrap()
+            .text_stages
+            .push((name.to_string(), function));
+    });
+    let sources = registry.clone();
+    engine.register_fn(
+        "register_suggestion_source",
+        move |name: &str, function: FnPtr| {
+            sources
+                .lock()
+                .unwrap()
+                .suggestion_sources
+                .push((name.to_string(), function));
+        },
+    );
+    let actions = registry.clone();
+    engine.register_fn(
+        "register_action",
+        move |id: &str, label: &str, function: FnPtr| {
+            actions.lock().unwrap().actions.push(RegisteredAction {
+                id: id.to_string(),
+                label: label.to_string(),
+                function,
+            });
+        },
+    );
+
+    (engine, registry)
+}
+
+struct LoadedPlugin {
+    info: PluginInfo,
+    plugin: Option<Plugin>,
+}
+
+pub struct PluginManager {
+    app_handle: AppHandle,
+    plugins: Mutex<Vec<LoadedPlugin>>,
+}
+
+impl PluginManager {
+    pub fn new(app_handle: &AppHandle) -> Self {
+        Self {
+            app_handle: app_handle.clone(),
+            plugins: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The folder plugins are loaded from
+    pub fn plugins_dir(&self) -> Result<PathBuf, String> {
+        crate::portable::app_data_dir(&self.app_handle)
+            .map(|dir| dir.join("plugins"))
+            .map_err(|e| format!("Failed to get app data dir: {}", e))
+    }
+
+    /// Adds the calls that need the app to a sandboxed engine
+    fn engine_for(&self, plugin: &str) -> (Engine, Arc<Mutex<Registry>>) {
+        let (mut engine, registry) = sandboxed_engine(plugin);
+
+        let app = self.app_handle.clone();
+        engine.register_fn("setting", move |key: &str| -> Dynamic {
+            let settings = serde_json::to_value(get_settings(&app)).unwrap_or_default();
+            read_setting(&settings, key)
+                .and_then(|value| rhai::serde::to_dynamic(value).ok())
+                .unwrap_or(Dynamic::UNIT)
+        });
+
+        let app = self.app_handle.clone();
+        let name = plugin.to_string();
+        engine.register_fn("emit", move |event: &str, payload: Dynamic| {
+            let payload = rhai::serde::from_dynamic::<serde_json::Value>(&payload)
+                .unwrap_or_default()
+                .to_string();
+            let message = PluginMessage {
+                plugin: name.clone(),
+                name: event.to_string(),
+                payload,
+            };
+            if let Err(e) = PluginEmitted(message).emit(&app) {
+                warn!("Failed to emit plugin event: {}", e);
+            }
+        });
+        let app = self.app_handle.clone();
+        let name = plugin.to_string();
+        engine.register_fn("emit", move |event: &str| {
+            let message = PluginMessage {
+                plugin: name.clone(),
+                name: event.to_string(),
+                payload: "null".to_string(),
+            };
+            if let Err(e) = PluginEmitted(message).emit(&app) {
+                warn!("Failed to emit plugin event: {}", e);
+            }
+        });
+
+        (engine, registry)
+    }
+
+    fn load_one(&self, name: &str, path: &Path, settings: &PluginSettings) -> LoadedPlugin {
+        let mut info = PluginInfo {
+            name: name.to_string(),
+            enabled: settings.is_enabled(name),
+            text_stages: Vec::new(),
+            suggestion_sources: Vec::new(),
+            actions: Vec::new(),
+            error: None,
+        };
+        if !info.enabled {
+            return LoadedPlugin { info, plugin: None };
+        }
+
+        let loaded = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read: {}", e))
+            .and_then(|source| {
+                let (engine, registry) = self.engine_for(name);
+                Plugin::load(&source, engine, &registry)
+            });
+        match loaded {
+            Ok(plugin) => {
+                let registry = &plugin.registry;
+                info.text_stages = registry
+                    .text_stages
+                    .iter()
+                    .map(|(n, _)| n.clone())
+                    .collect();
+                info.suggestion_sources = registry
+                    .suggestion_sources
+                    .iter()
+                    .map(|(n, _)| n.clone())
+                    .collect();
+                info.actions = registry
+                    .actions
+                    .iter()
+                    .map(|action| PluginActionInfo {
+                        binding_id: binding_id(name, &action.id),
+                        label: action.label.clone(),
+                    })
+                    .collect();
+                LoadedPlugin {
+                    info,
+                    plugin: Some(plugin),
+                }
+            }
+            Err(e) => {
+                warn!("Plugin {} failed to load: {}", name, e);
+                info.error = Some(e);
+                LoadedPlugin { info, plugin: None }
+            }
+        }
+    }
+
+    /// Load every `.rhai` file in the plugins folder again
+    pub fn reload(&self) -> Result<Vec<PluginInfo>, String> {
+        let dir = self.plugins_dir()?;
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create plugins folder: {}", e))?;
+        let mut paths: Vec<PathBuf> = std::fs::read_dir(&dir)
+            .map_err(|e| format!("Failed to read plugins folder: {}", e))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rhai"))
+            .collect();
+        paths.sort();
+
+        let settings = get_settings(&self.app_handle).plugins;
+        let loaded: Vec<LoadedPlugin> = paths
+            .iter()
+            .filter_map(|path| {
+                let name = path.file_stem()?.to_string_lossy().into_owned();
+                Some(self.load_one(&name, path, &settings))
+            })
+            .collect();
+        let infos = loaded.iter().map(|plugin| plugin.info.clone()).collect();
+        info!(
+            "Loaded {} of {} plugins",
+            loaded.iter().filter(|p| p.plugin.is_some()).count(),
+            loaded.len()
+        );
+        *self.plugins.lock().unwrap() = loaded;
+        Ok(infos)
+    }
+
+    pub fn list(&self) -> Vec<PluginInfo> {
+        self.plugins
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|plugin| plugin.info.clone())
+            .collect()
+    }
+
+    /// Run a dictation through every plugin's text stages
+    pub fn process_text(&self, text: String) -> String {
+        self.plugins
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|loaded| Some((&loaded.info.name, loaded.plugin.as_ref()?)))
+            .fold(text, |text, (name, plugin)| plugin.process_text(name, text))
+    }
+
+    /// Ask every plugin's suggestion sources about a segment
+    pub fn suggestions(&self, text: &str) -> Vec<PluginSuggestion> {
+        self.plugins
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|loaded| Some((&loaded.info.name, loaded.plugin.as_ref()?)))
+            .flat_map(|(name, plugin)| plugin.suggestions(name, text))
+            .collect()
+    }
+
+    /// Label of the action a plugin binding runs, if it's loaded
+    pub fn action_label(&self, binding: &str) -> Option<String> {
+        self.plugins
+            .lock()
+            .unwrap()
+            .iter()
+            .flat_map(|loaded| loaded.info.actions.iter())
+            .find(|action| action.binding_id == binding)
+            .map(|action| action.label.clone())
+    }
+
+    /// Run the action a plugin binding is for
+    pub fn run_action(&self, binding: &str) -> Result<(), String> {
+        let plugins = self.plugins.lock().unwrap();
+        let (plugin, action) = plugins
+            .iter()
+            .filter_map(|loaded| {
+                loaded
+                    .plugin
+                    .as_ref()
+                    .map(|plugin| (&loaded.info.name, plugin))
+            })
+            .find_map(|(name, plugin)| {
+                plugin
+                    .registry
+                    .actions
+                    .iter()
+                    .find(|action| binding_id(name, &action.id) == binding)
+                    .map(|action| (plugin, action))
+            })
+            .ok_or_else(|| format!("No plugin action for {}", binding))?;
+        plugin
+            .call(&action.function, ())
+            .map(|_| ())
+            .map_err(|e| format!("Plugin action '{}' failed: {}", action.label, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(source: &str) -> Result<Plugin, String> {
+        let (engine, registry) = sandboxed_engine("test");
+        Plugin::load(source, engine, &registry)
+    }
+
+    #[test]
+    fn test_text_stages_run_in_order_and_skip_failures() {
+        let plugin = load(
+            r#"
+            register_text_stage("upper", |text| text.to_upper());
+            register_text_stage("broken", |text| text.no_such_method());
+            register_text_stage("bang", |text| text + "!");
+            "#,
+        )
+        .unwrap();
+        assert_eq!(plugin.process_text("test", "hello".to_string()), "HELLO!");
+    }
+
+    #[test]
+    fn test_suggestion_sources_accept_strings_and_maps() {
+        let plugin = load(
+            r#"
+            register_suggestion_source("glossary", |text| {
+                if text.contains("SLA") {
+                    ["Our SLA is 99.9%", #{ text: "Ask about credits", confidence: 2 }]
+                } else {
+                    []
+                }
+            });
+            "#,
+        )
+        .unwrap();
+        let suggestions = plugin.suggestions("test", "what is the SLA");
+        assert_eq!(suggestions.len(), 2);
+        assert_eq!(suggestions[0].confidence, DEFAULT_SUGGESTION_CONFIDENCE);
+        assert_eq!(suggestions[1].text, "Ask about credits");
+        assert_eq!(suggestions[1].confidence, 1.0);
+        assert!(plugin.suggestions("test", "hello").is_empty());
+    }
+
+    #[test]
+    fn test_sandbox_stops_runaway_and_unsafe_scripts() {
+        assert!(load("loop {}").is_err());
+        assert!(load(r#"import "other" as other;"#).is_err());
+        assert!(load(r#"eval("1 + 1")"#).is_err());
+
+        let plugin = load(r#"register_text_stage("spin", |text| { loop {} });"#).unwrap();
+        assert_eq!(plugin.process_text("test", "kept".to_string()), "kept");
+    }
+
+    #[test]
+    fn test_private_settings_are_not_readable() {
+        let settings = serde_json::json!({
+            "push_to_talk": true,
+            "suggestions": { "enabled": false },
+            "api_server": { "token": "secret" },
+            "browser_bridge": { "token": "secret" },
+        });
+        assert_eq!(
+            read_setting(&settings, "push_to_talk"),
+            Some(serde_json::json!(true))
+        );
+        assert_eq!(
+            read_setting(&settings, "suggestions.enabled"),
+            Some(serde_json::json!(false))
+        );
+        assert_eq!(read_setting(&settings, "api_server.token"), None);
+        assert_eq!(read_setting(&settings, "browser_bridge.token"), None);
+        assert_eq!(read_setting(&settings, "missing"), None);
+    }
+}
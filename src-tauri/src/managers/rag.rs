@@ -4,12 +4,16 @@
 //! Uses SQLite for vector storage and Ollama for embedding generation.
 
 use crate::ollama_client::OllamaClient;
+use crate::settings::KnowledgeBaseSettings;
+use futures_util::{stream, StreamExt};
 use log::{debug, info, warn};
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
 use rusqlite_migration::{Migrations, M};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_specta::Event;
 use tokio::sync::Mutex;
 
 /// Database migrations for RAG tables
@@ -56,6 +60,24 @@ static MIGRATIONS: &[M] = &[
             ('top_k', '3');
         "#,
     ),
+    M::up(
+        r#"
+        CREATE TABLE IF NOT EXISTS indexing_queue (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            content TEXT NOT NULL,
+            source_type TEXT NOT NULL,
+            source_id TEXT,
+            title TEXT,
+            status TEXT NOT NULL DEFAULT 'pending',
+            attempts INTEGER NOT NULL DEFAULT 0,
+            last_error TEXT,
+            next_attempt_at INTEGER NOT NULL DEFAULT 0,
+            created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_indexing_queue_status ON indexing_queue(status, next_attempt_at);
+        "#,
+    ),
 ];
 
 /// Document metadata for RAG storage
@@ -99,11 +121,49 @@ pub struct StoredDocument {
     pub created_at: i64,
 }
 
+/// Progress of embedding generation for a single document
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+pub struct RagIndexingProgress {
+    pub document_id: i64,
+    /// Chunks embedded and stored so far
+    pub completed_chunks: usize,
+    pub total_chunks: usize,
+}
+
+/// Tuning for the embed requests made while indexing a document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmbeddingOptions {
+    /// Chunks sent per /api/embed request
+    pub batch_size: usize,
+    /// Maximum embed requests in flight at once
+    pub concurrency: usize,
+    /// Retries for transient request failures
+    pub max_retries: u32,
+}
+
+impl Default for EmbeddingOptions {
+    fn default() -> Self {
+        Self::from(&KnowledgeBaseSettings::default())
+    }
+}
+
+impl From<&KnowledgeBaseSettings> for EmbeddingOptions {
+    fn from(settings: &KnowledgeBaseSettings) -> Self {
+        Self {
+            batch_size: settings.embedding_batch_size.max(1),
+            concurrency: settings.embedding_concurrency.max(1),
+            max_retries: settings.embedding_max_retries,
+        }
+    }
+}
+
 /// RAG Manager for knowledge base operations
 pub struct RagManager {
     db_path: PathBuf,
     ollama_client: Arc<OllamaClient>,
     embedding_model: Mutex<String>,
+    embedding_options: std::sync::Mutex<EmbeddingOptions>,
+    app_handle: Option<AppHandle>,
 }
 
 impl RagManager {
@@ -117,6 +177,8 @@ impl RagManager {
             db_path,
             ollama_client,
             embedding_model: Mutex::new("nomic-embed-text".to_string()),
+            embedding_options: std::sync::Mutex::new(EmbeddingOptions::default()),
+            app_handle: None,
         };
 
         // Initialize database
@@ -136,6 +198,25 @@ impl RagManager {
         Ok(manager)
     }
 
+    /// Set the app handle used to emit indexing progress events
+    pub fn set_app_handle(&mut self, app_handle: AppHandle) {
+        self.app_handle = Some(app_handle);
+    }
+
+    /// Update the batching, concurrency and retry options for indexing
+    pub fn set_embedding_options(&self, options: EmbeddingOptions) {
+        if let Ok(mut current) = self.embedding_options.lock() {
+            *current = options;
+        }
+    }
+
+    fn embedding_options(&self) -> EmbeddingOptions {
+        self.embedding_options
+            .lock()
+            .map(|options| *options)
+            .unwrap_or_default()
+    }
+
     /// Get a database connection
     fn get_connection(&self) -> Result<Connection, String> {
         Connection::open(&self.db_path)
@@ -197,46 +278,90 @@ impl RagManager {
         let document_id = conn.last_insert_rowid();
         debug!("Added document {} to knowledge base", document_id);
 
-        // Generate and store embeddings for the document
-        self.index_document(document_id, content).await?;
+        // Generate and store embeddings for the document. On failure, remove
+        // the partially indexed document so a retry does not duplicate it.
+        if let Err(e) = self.index_document(document_id, content).await {
+            let _ = conn.execute(
+                "DELETE FROM embeddings WHERE document_id = ?1",
+                params![document_id],
+            );
+            let _ = conn.execute("DELETE FROM documents WHERE id = ?1", params![document_id]);
+            return Err(e);
+        }
 
         Ok(document_id)
     }
 
     /// Index a document by generating embeddings for its chunks
+    ///
+    /// Chunks are embedded in batches via /api/embed, with up to
+    /// `concurrency` requests in flight. A `rag-indexing-progress` event is
+    /// emitted after each batch is stored.
     async fn index_document(&self, document_id: i64, content: &str) -> Result<(), String> {
-        let chunks = self.chunk_text(content);
+        let chunks: Vec<(usize, String)> = self
+            .chunk_text(content)
+            .into_iter()
+            .enumerate()
+            .filter(|(_, chunk)| !chunk.trim().is_empty())
+            .collect();
         let model = self.embedding_model.lock().await.clone();
+        let options = self.embedding_options();
+        let total_chunks = chunks.len();
 
         debug!(
-            "Indexing document {} with {} chunks using model {}",
-            document_id,
-            chunks.len(),
-            model
+            "Indexing document {} with {} chunks using model {} (batch size {}, concurrency {})",
+            document_id, total_chunks, model, options.batch_size, options.concurrency
         );
+        self.emit_progress(document_id, 0, total_chunks);
+
+        let model_ref = model.as_str();
+        let mut batches = stream::iter(chunks.chunks(options.batch_size.max(1)))
+            .map(|batch| async move {
+                let inputs: Vec<String> = batch.iter().map(|(_, chunk)| chunk.clone()).collect();
+                let embeddings = self
+                    .ollama_client
+                    .generate_embeddings_batch(model_ref, &inputs, options.max_retries)
+                    .await?;
+                Ok::<_, String>((batch, embeddings))
+            })
+            .buffer_unordered(options.concurrency.max(1));
 
-        for (index, chunk) in chunks.iter().enumerate() {
-            if chunk.trim().is_empty() {
-                continue;
-            }
-
-            // Generate embedding
-            let embedding = self
-                .ollama_client
-                .generate_embeddings(&model, chunk)
-                .await?;
+        let mut conn = self.get_connection()?;
+        let mut completed_chunks = 0;
+        while let Some(result) = batches.next().await {
+            let (batch, embeddings) = result?;
+            Self::store_embeddings(&mut conn, document_id, &model, batch, &embeddings)?;
+            completed_chunks += batch.len();
+            self.emit_progress(document_id, completed_chunks, total_chunks);
+        }
 
-            // Store embedding
-            let conn = self.get_connection()?;
-            let embedding_blob = Self::vec_to_blob(&embedding);
+        info!(
+            "Indexed document {} with {} embedding chunks",
+            document_id, total_chunks
+        );
+        Ok(())
+    }
 
-            conn.execute(
+    /// Store a batch of chunk embeddings in a single transaction
+    fn store_embeddings(
+        conn: &mut Connection,
+        document_id: i64,
+        model: &str,
+        batch: &[(usize, String)],
+        embeddings: &[Vec<f32>],
+    ) -> Result<(), String> {
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        for ((index, chunk), embedding) in batch.iter().zip(embeddings) {
+            tx.execute(
                 "INSERT INTO embeddings (document_id, chunk_index, chunk_text, embedding, dimensions, model) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
                 params![
                     document_id,
-                    index as i64,
+                    *index as i64,
                     chunk,
-                    embedding_blob,
+                    Self::vec_to_blob(embedding),
                     embedding.len() as i64,
                     model
                 ],
@@ -244,12 +369,19 @@ impl RagManager {
             .map_err(|e| format!("Failed to store embedding: {}", e))?;
         }
 
-        info!(
-            "Indexed document {} with {} embedding chunks",
-            document_id,
-            chunks.len()
-        );
-        Ok(())
+        tx.commit()
+            .map_err(|e| format!("Failed to commit embeddings: {}", e))
+    }
+
+    fn emit_progress(&self, document_id: i64, completed_chunks: usize, total_chunks: usize) {
+        if let Some(app_handle) = &self.app_handle {
+            let _ = RagIndexingProgress {
+                document_id,
+                completed_chunks,
+                total_chunks,
+            }
+            .emit(app_handle);
+        }
     }
 
     /// Chunk text into smaller pieces for embedding
@@ -305,7 +437,43 @@ impl RagManager {
     /// # Returns
     /// Vector of search results sorted by similarity
     pub async fn search(&self, query: &str, top_k: usize) -> Result<Vec<SearchResult>, String> {
+        self.search_in(query, top_k, None).await
+    }
+
+    /// Search only the documents from one source, e.g. the transcriptions
+    /// indexed for one Active Listening session
+    pub async fn search_source(
+        &self,
+        query: &str,
+        source_type: &str,
+        source_id: &str,
+        top_k: usize,
+    ) -> Result<Vec<SearchResult>, String> {
+        self.search_in(query, top_k, Some((source_type, Some(source_id))))
+            .await
+    }
+
+    /// Search only the documents of one source type, e.g. the transcriptions
+    /// of all Active Listening sessions
+    pub async fn search_source_type(
+        &self,
+        query: &str,
+        source_type: &str,
+        top_k: usize,
+    ) -> Result<Vec<SearchResult>, String> {
+        self.search_in(query, top_k, Some((source_type, None)))
+            .await
+    }
+
+    async fn search_in(
+        &self,
+        query: &str,
+        top_k: usize,
+        source: Option<(&str, Option<&str>)>,
+    ) -> Result<Vec<SearchResult>, String> {
         let model = self.embedding_model.lock().await.clone();
+        let (source_type, source_id) = source.unzip();
+        let source_id = source_id.flatten();
 
         // Generate query embedding
         let query_embedding = self
@@ -330,12 +498,13 @@ impl RagManager {
                 FROM embeddings e
                 JOIN documents d ON e.document_id = d.id
                 WHERE e.model = ?1
+                  AND (?2 IS NULL OR (d.source_type = ?2 AND (?3 IS NULL OR d.source_id = ?3)))
                 "#,
             )
             .map_err(|e| format!("Failed to prepare search query: {}", e))?;
 
         let mut results: Vec<SearchResult> = stmt
-            .query_map(params![model], |row| {
+            .query_map(params![model, source_type, source_id], |row| {
                 let document_id: i64 = row.get(0)?;
                 let chunk_text: String = row.get(1)?;
                 let embedding_blob: Vec<u8> = row.get(2)?;
@@ -389,6 +558,22 @@ impl RagManager {
         self.ollama_client.generate_embeddings(&model, text).await
     }
 
+    /// Find a document by its source type and source ID
+    pub fn find_document_by_source(
+        &self,
+        source_type: &str,
+        source_id: &str,
+    ) -> Result<Option<i64>, String> {
+        let conn = self.get_connection()?;
+        conn.query_row(
+            "SELECT id FROM documents WHERE source_type = ?1 AND source_id = ?2 LIMIT 1",
+            params![source_type, source_id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| format!("Failed to look up document: {}", e))
+    }
+
     /// Delete a document and its embeddings
     pub fn delete_document(&self, document_id: i64) -> Result<(), String> {
         let conn = self.get_connection()?;
@@ -492,7 +677,7 @@ impl RagManager {
     }
 
     /// Calculate cosine similarity between two vectors
-    fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
         if a.len() != b.len() || a.is_empty() {
             return 0.0;
         }
@@ -538,6 +723,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_embedding_options_clamp_to_at_least_one() {
+        let settings = KnowledgeBaseSettings {
+            embedding_batch_size: 0,
+            embedding_concurrency: 0,
+            ..Default::default()
+        };
+        let options = EmbeddingOptions::from(&settings);
+        assert_eq!(options.batch_size, 1);
+        assert_eq!(options.concurrency, 1);
+    }
+
     #[test]
     fn test_chunk_text_short() {
         // Create a mock manager for testing (we just need the method)
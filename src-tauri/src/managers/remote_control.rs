@@ -0,0 +1,544 @@
+//! Remote control companion
+//!
+//! An optional server on the local network that lets a phone or browser act
+//! as a push-to-talk button, follow live captions and read Ask AI answers.
+//! Opening `http://<this machine>:<port>/` in a browser serves a small page
+//! that does all of this; companion apps find the server over mDNS as
+//! `_dictum._tcp`.
+//!
+//! Devices pair with a six-digit code shown in the app for two minutes and
+//! get their own token back, which every other request needs as
+//! `Authorization: Bearer <token>`. Traffic is plain HTTP, so the tokens are
+//! what keeps other devices on the network out; revoking a device in the
+//! settings locks it out at once.
+//!
+//! Besides the endpoints of the local HTTP API, paired devices can use:
+//!
+//! | Endpoint             | Does                                             |
+//! |----------------------|--------------------------------------------------|
+//! | `POST /pair`         | `{"code": "123456", "name": "Phone"}`, no token  |
+//! | `POST /ptt/press`    | Start a dictation                                |
+//! | `POST /ptt/release`  | Stop it and transcribe                           |
+//! | `GET /events?after=` | App events after a sequence number               |
+
+use crate::events::{
+    ActiveListeningSegment, AskAiResponse, HideOverlay, RemoteDevicePaired, ShowOverlay,
+    TranscriptionFinalized, TranscriptionPartial,
+};
+use crate::managers::api_server;
+use crate::settings::{generate_api_token, get_settings, write_settings, PairedDevice};
+use log::{debug, error, info, warn};
+use mdns_sd::{ServiceDaemon, ServiceInfo};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use specta::Type;
+use std::collections::VecDeque;
+use std::net::{IpAddr, UdpSocket};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Listener};
+use tauri_specta::Event;
+use tiny_http::{Header, Request, Response, Server};
+
+/// mDNS service type companion apps browse for
+const SERVICE_TYPE: &str = "_dictum._tcp.local.";
+/// How long a pairing code can be used
+const PAIRING_CODE_LIFETIME: Duration = Duration::from_secs(120);
+/// Wrong codes accepted before the pairing is called off
+const MAX_PAIRING_ATTEMPTS: u32 = 5;
+/// Longest device name kept
+const MAX_DEVICE_NAME_CHARS: usize = 64;
+/// Events kept for devices to catch up on
+const FEED_CAPACITY: usize = 200;
+
+/// The page served at `/`
+const REMOTE_PAGE: &str = include_str!("remote_control.html");
+
+/// App events forwarded to paired devices
+const FORWARDED_EVENTS: &[&str] = &[
+    ShowOverlay::NAME,
+    HideOverlay::NAME,
+    TranscriptionPartial::NAME,
+    TranscriptionFinalized::NAME,
+    ActiveListeningSegment::NAME,
+    AskAiResponse::NAME,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RemoteRoute {
+    Page,
+    Pair,
+    PressToTalk,
+    ReleaseToTalk,
+    Events,
+    Api(api_server::Route),
+}
+
+fn remote_route(method: &str, path: &str) -> Option<RemoteRoute> {
+    let route = match (method, path.trim_end_matches('/')) {
+        ("GET", "") => RemoteRoute::Page,
+        ("POST", "/pair") => RemoteRoute::Pair,
+        ("POST", "/ptt/press") => RemoteRoute::PressToTalk,
+        ("POST", "/ptt/release") => RemoteRoute::ReleaseToTalk,
+        ("GET", "/events") => RemoteRoute::Events,
+        _ => return api_server::route(method, path).map(RemoteRoute::Api),
+    };
+    Some(route)
+}
+
+/// An app event as paired devices see it
+#[derive(Serialize, Clone, Debug, PartialEq)]
+struct RemoteEvent {
+    seq: u64,
+    event: String,
+    payload: Value,
+}
+
+/// The latest forwarded events, numbered so devices can ask for what they
+/// haven't seen
+#[derive(Default)]
+struct Feed {
+    events: VecDeque<RemoteEvent>,
+    next_seq: u64,
+}
+
+impl Feed {
+    fn push(&mut self, event: &str, payload: Value) {
+        if self.events.len() == FEED_CAPACITY {
+            self.events.pop_front();
+        }
+        self.next_seq += 1;
+        self.events.push_back(RemoteEvent {
+            seq: self.next_seq,
+            event: event.to_string(),
+            payload,
+        });
+    }
+
+    /// Events after `seq`, oldest first
+    fn after(&self, seq: u64) -> Vec<RemoteEvent> {
+        self.events
+            .iter()
+            .filter(|event| event.seq > seq)
+            .cloned()
+            .collect()
+    }
+}
+
+/// A pairing code waiting to be entered on a device
+struct Pairing {
+    code: String,
+    expires_at: Instant,
+    attempts: u32,
+}
+
+impl Pairing {
+    fn new(now: Instant) -> Self {
+        let code = uuid::Uuid::new_v4().as_u128() % 1_000_000;
+        Self {
+            code: format!("{:06}", code),
+            expires_at: now + PAIRING_CODE_LIFETIME,
+            attempts: 0,
+        }
+    }
+
+    /// Whether the code can't be used any more
+    fn is_spent(&self, now: Instant) -> bool {
+        now >= self.expires_at || self.attempts >= MAX_PAIRING_ATTEMPTS
+    }
+
+    /// Check an entered code; wrong ones use up an attempt
+    fn accepts(&mut self, code: &str, now: Instant) -> bool {
+        if self.is_spent(now) {
+            return false;
+        }
+        let matches = api_server::constant_time_eq(code.trim().as_bytes(), self.code.as_bytes());
+        if !matches {
+            self.attempts += 1;
+        }
+        matches
+    }
+}
+
+/// A pairing started in the app, as shown to the user
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct RemotePairing {
+    pub code: String,
+    pub expires_in_seconds: u32,
+    /// Addresses to open on the device
+    pub urls: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct PairBody {
+    #[serde(default)]
+    code: String,
+    #[serde(default)]
+    name: String,
+}
+
+/// The `after` sequence number of an events request
+fn events_after(url: &str) -> u64 {
+    reqwest::Url::parse(&format!("http://localhost{}", url))
+        .ok()
+        .and_then(|url| {
+            url.query_pairs()
+                .find(|(key, _)| key == "after")
+                .and_then(|(_, value)| value.parse().ok())
+        })
+        .unwrap_or(0)
+}
+
+/// The paired device a request's `Authorization` header belongs to
+fn authorized_device<'a>(
+    devices: &'a [PairedDevice],
+    header: Option<&str>,
+) -> Option<&'a PairedDevice> {
+    devices
+        .iter()
+        .find(|device| api_server::is_authorized(header, &device.token))
+}
+
+/// This machine's address on the local network, found by asking which
+/// interface would route to an outside address (nothing is sent)
+fn local_ip() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("192.0.2.1:80").ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}
+
+/// State shared with the server thread
+#[derive(Default)]
+struct Shared {
+    pairing: Mutex<Option<Pairing>>,
+    feed: Mutex<Feed>,
+}
+
+impl Shared {
+    fn pair(&self, app: &AppHandle, request: &mut Request) -> Result<Value, (u16, String)> {
+        let body: PairBody = api_server::read_body(request)?;
+        {
+            let mut pairing = self
+                .pairing
+                .lock()
+                .map_err(|e| (500, format!("Failed to lock pairing: {}", e)))?;
+            let now = Instant::now();
+            let Some(active) = pairing.as_mut() else {
+                return Err((403, "Start pairing in Dictum first".to_string()));
+            };
+            if !active.accepts(&body.code, now) {
+                if active.is_spent(now) {
+                    *pairing = None;
+                }
+                return Err((403, "Wrong or expired pairing code".to_string()));
+            }
+            *pairing = None;
+        }
+
+        let name: String = body
+            .name
+            .trim()
+            .chars()
+            .take(MAX_DEVICE_NAME_CHARS)
+            .collect();
+        let device = PairedDevice {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: if name.is_empty() {
+                "Remote".to_string()
+            } else {
+                name
+            },
+            token: generate_api_token(),
+            paired_at: chrono::Utc::now().timestamp(),
+        };
+        let mut settings = get_settings(app);
+        settings.remote_control.devices.push(device.clone());
+        write_settings(app, settings);
+        info!("Paired remote device '{}'", device.name);
+        let _ = RemoteDevicePaired(device.name.clone()).emit(app);
+
+        Ok(json!({ "device_id": device.id, "token": device.token }))
+    }
+
+    fn handle(
+        &self,
+        app: &AppHandle,
+        route: RemoteRoute,
+        request: &mut Request,
+    ) -> Result<Value, (u16, String)> {
+        match route {
+            RemoteRoute::Page => Err((404, "Not an API endpoint".to_string())),
+            RemoteRoute::Pair => self.pair(app, request),
+            RemoteRoute::PressToTalk => api_server::set_transcribing(app, Some(true))
+                .map(|recording| json!({ "recording": recording })),
+            RemoteRoute::ReleaseToTalk => api_server::set_transcribing(app, Some(false))
+                .map(|recording| json!({ "recording": recording })),
+            RemoteRoute::Events => {
+                let after = events_after(request.url());
+                let feed = self
+                    .feed
+                    .lock()
+                    .map_err(|e| (500, format!("Failed to lock event feed: {}", e)))?;
+                Ok(json!({ "events": feed.after(after), "last": feed.next_seq }))
+            }
+            RemoteRoute::Api(route) => api_server::handle(app, route, request),
+        }
+    }
+
+    fn serve(&self, app: &AppHandle, server: &Server) {
+        for mut request in server.incoming_requests() {
+            let path = api_server::path(&request);
+            let Some(route) = remote_route(request.method().as_str(), &path) else {
+                api_server::respond(request, 404, &json!({ "error": "Unknown endpoint" }));
+                continue;
+            };
+            debug!("Remote request: {:?}", route);
+
+            if route == RemoteRoute::Page {
+                let content_type =
+                    Header::from_bytes(&b"Content-Type"[..], &b"text/html; charset=utf-8"[..])
+                        .expect("static header is valid");
+                let response = Response::from_string(REMOTE_PAGE).with_header(content_type);
+                if let Err(e) = request.respond(response) {
+                    debug!("Failed to serve remote page: {}", e);
+                }
+                continue;
+            }
+
+            // Pairing is the only request that doesn't need a device token
+            if route != RemoteRoute::Pair {
+                let devices = get_settings(app).remote_control.devices;
+                let header = api_server::authorization(&request);
+                if authorized_device(&devices, header.as_deref()).is_none() {
+                    api_server::respond(
+                        request,
+                        401,
+                        &json!({ "error": "Pair this device first" }),
+                    );
+                    continue;
+                }
+            }
+
+            let result = self.handle(app, route, &mut request);
+            match result {
+                Ok(body) => api_server::respond(request, 200, &body),
+                Err((status, message)) => {
+                    api_server::respond(request, status, &json!({ "error": message }))
+                }
+            }
+        }
+    }
+}
+
+pub struct RemoteControl {
+    app_handle: AppHandle,
+    shared: Arc<Shared>,
+    server: Mutex<Option<Arc<Server>>>,
+    mdns: Mutex<Option<ServiceDaemon>>,
+}
+
+impl RemoteControl {
+    pub fn new(app: &AppHandle) -> Self {
+        let shared = Arc::new(Shared::default());
+        for &name in FORWARDED_EVENTS {
+            let shared = shared.clone();
+            app.listen_any(name, move |event| {
+                let payload = serde_json::from_str(event.payload()).unwrap_or(Value::Null);
+                if let Ok(mut feed) = shared.feed.lock() {
+                    feed.push(name, payload);
+                }
+            });
+        }
+        Self {
+            app_handle: app.clone(),
+            shared,
+            server: Mutex::new(None),
+            mdns: Mutex::new(None),
+        }
+    }
+
+    /// Start, restart or stop the server to match the settings
+    pub fn apply_settings(&self) -> Result<(), String> {
+        self.stop();
+
+        let settings = get_settings(&self.app_handle).remote_control;
+        if !settings.enabled {
+            return Ok(());
+        }
+
+        let server = Server::http(("0.0.0.0", settings.port)).map_err(|e| {
+            format!(
+                "Failed to start remote control on port {}: {}",
+                settings.port, e
+            )
+        })?;
+        let server = Arc::new(server);
+        let app = self.app_handle.clone();
+        let shared = self.shared.clone();
+        let worker = server.clone();
+        std::thread::spawn(move || shared.serve(&app, &worker));
+
+        match self.server.lock() {
+            Ok(mut current) => *current = Some(server),
+            Err(e) => error!("Failed to lock remote control server: {}", e),
+        }
+        info!("Remote control listening on port {}", settings.port);
+
+        if settings.advertise {
+            match advertise(settings.port) {
+                Ok(daemon) => {
+                    if let Ok(mut mdns) = self.mdns.lock() {
+                        *mdns = Some(daemon);
+                    }
+                }
+                Err(e) => warn!("Failed to advertise remote control: {}", e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Stop the server and the mDNS announcement if they run
+    pub fn stop(&self) {
+        if let Some(daemon) = self.mdns.lock().ok().and_then(|mut mdns| mdns.take()) {
+            if let Err(e) = daemon.shutdown() {
+                debug!("Failed to stop mDNS: {}", e);
+            }
+        }
+        let server = match self.server.lock() {
+            Ok(mut current) => current.take(),
+            Err(e) => {
+                warn!("Failed to lock remote control server: {}", e);
+                None
+            }
+        };
+        if let Some(server) = server {
+            server.unblock();
+            info!("Remote control stopped");
+        }
+    }
+
+    fn is_running(&self) -> bool {
+        self.server
+            .lock()
+            .map(|server| server.is_some())
+            .unwrap_or(false)
+    }
+
+    /// Start pairing a new device, replacing any code not used yet
+    pub fn start_pairing(&self) -> Result<RemotePairing, String> {
+        if !self.is_running() {
+            return Err("Turn on remote control before pairing a device".to_string());
+        }
+        let pairing = Pairing::new(Instant::now());
+        let code = pairing.code.clone();
+        *self
+            .shared
+            .pairing
+            .lock()
+            .map_err(|e| format!("Failed to lock pairing: {}", e))? = Some(pairing);
+
+        let port = get_settings(&self.app_handle).remote_control.port;
+        let urls = local_ip()
+            .map(|ip| format!("http://{}:{}/", ip, port))
+            .into_iter()
+            .collect();
+        Ok(RemotePairing {
+            code,
+            expires_in_seconds: PAIRING_CODE_LIFETIME.as_secs() as u32,
+            urls,
+        })
+    }
+
+    pub fn cancel_pairing(&self) {
+        if let Ok(mut pairing) = self.shared.pairing.lock() {
+            *pairing = None;
+        }
+    }
+}
+
+/// Announce the server over mDNS
+fn advertise(port: u16) -> Result<ServiceDaemon, String> {
+    let daemon = ServiceDaemon::new().map_err(|e| e.to_string())?;
+    let suffix = &generate_api_token()[..6];
+    let host = format!("dictum-{}.local.", suffix);
+    let instance = format!("Dictum {}", suffix);
+    let properties = [("path", "/"), ("pairing", "code")];
+    let service = ServiceInfo::new(SERVICE_TYPE, &instance, &host, "", port, &properties[..])
+        .map_err(|e| e.to_string())?
+        .enable_addr_auto();
+    daemon.register(service).map_err(|e| e.to_string())?;
+    Ok(daemon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(token: &str) -> PairedDevice {
+        PairedDevice {
+            id: token.to_string(),
+            name: token.to_string(),
+            token: token.to_string(),
+            paired_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_remote_routes_fall_back_to_the_api() {
+        assert_eq!(remote_route("GET", "/"), Some(RemoteRoute::Page));
+        assert_eq!(remote_route("POST", "/pair"), Some(RemoteRoute::Pair));
+        assert_eq!(
+            remote_route("POST", "/ptt/press"),
+            Some(RemoteRoute::PressToTalk)
+        );
+        assert_eq!(
+            remote_route("GET", "/status"),
+            Some(RemoteRoute::Api(api_server::Route::Status))
+        );
+        assert_eq!(remote_route("GET", "/ptt/press"), None);
+    }
+
+    #[test]
+    fn test_pairing_code_expires_and_limits_guesses() {
+        let now = Instant::now();
+        let mut pairing = Pairing::new(now);
+        let code = pairing.code.clone();
+        assert_eq!(code.len(), 6);
+        assert!(pairing.accepts(&code, now));
+        assert!(!pairing.accepts(&code, now + PAIRING_CODE_LIFETIME));
+
+        let mut pairing = Pairing::new(now);
+        let code = pairing.code.clone();
+        for _ in 0..MAX_PAIRING_ATTEMPTS {
+            assert!(!pairing.accepts("wrong", now));
+        }
+        assert!(pairing.is_spent(now));
+        assert!(!pairing.accepts(&code, now));
+    }
+
+    #[test]
+    fn test_feed_returns_events_after_a_sequence_number() {
+        let mut feed = Feed::default();
+        for i in 0..FEED_CAPACITY + 5 {
+            feed.push("transcription-partial", json!(i));
+        }
+        assert_eq!(feed.events.len(), FEED_CAPACITY);
+        let latest = feed.after(feed.next_seq - 2);
+        assert_eq!(latest.len(), 2);
+        assert_eq!(latest[1].payload, json!(FEED_CAPACITY + 4));
+        assert_eq!(feed.after(0).len(), FEED_CAPACITY);
+    }
+
+    #[test]
+    fn test_only_paired_tokens_are_authorized() {
+        let devices = [device("phone-token"), device("tablet-token")];
+        assert_eq!(
+            authorized_device(&devices, Some("Bearer tablet-token")).map(|d| d.id.as_str()),
+            Some("tablet-token")
+        );
+        assert!(authorized_device(&devices, Some("Bearer laptop-token")).is_none());
+        assert!(authorized_device(&devices, None).is_none());
+        assert_eq!(events_after("/events?after=42"), 42);
+        assert_eq!(events_after("/events"), 0);
+    }
+}
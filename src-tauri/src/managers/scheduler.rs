@@ -0,0 +1,333 @@
+//! Scheduler
+//!
+//! Applies the schedule rules: every half minute the rules whose cron
+//! expression matches the current local time are merged into the active
+//! overrides. Sound detection is reconfigured to match them, while the
+//! feedback sounds and Active Listening read them when they're used.
+
+use crate::audio_toolkit::SoundDetector;
+use crate::event_trail;
+use crate::settings::{get_settings, AppSettings, ScheduleOverrides, ScheduleSettings};
+use chrono::{Datelike, Local, NaiveDateTime, Timelike};
+use log::{info, warn};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// How often the rules are checked against the clock
+const TICK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// One field of a cron expression: the values it matches, as bits
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Field {
+    bits: u64,
+    /// Whether the field is `*`, which matters for the day fields
+    any: bool,
+}
+
+impl Field {
+    fn parse(text: &str, min: u32, max: u32) -> Result<Field, String> {
+        let mut bits = 0u64;
+        for part in text.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => {
+                    let step: u32 = step
+                        .parse()
+                        .ok()
+                        .filter(|step| *step > 0)
+                        .ok_or_else(|| format!("Invalid step in '{}'", part))?;
+                    (range, step)
+                }
+                None => (part, 1),
+            };
+            let value = |text: &str| {
+                text.parse::<u32>()
+                    .ok()
+                    .filter(|value| (min..=max).contains(value))
+                    .ok_or_else(|| format!("'{}' is not between {} and {}", text, min, max))
+            };
+            let (start, end) = match range {
+                "*" => (min, max),
+                _ => match range.split_once('-') {
+                    Some((start, end)) => (value(start)?, value(end)?),
+                    None => (value(range)?, value(range)?),
+                },
+            };
+            // A range like 22-6 wraps around to the start
+            let len = if end >= start {
+                end - start + 1
+            } else {
+                max - start + 1 + end - min + 1
+            };
+            for offset in (0..len).step_by(step as usize) {
+                let value = min + (start - min + offset) % (max - min + 1);
+                bits |= 1 << value;
+            }
+        }
+        Ok(Field {
+            bits,
+            any: text == "*",
+        })
+    }
+
+    fn matches(&self, value: u32) -> bool {
+        self.bits & (1 << value) != 0
+    }
+}
+
+/// A parsed `minute hour day-of-month month day-of-week` expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronExpression {
+    minute: Field,
+    hour: Field,
+    day: Field,
+    month: Field,
+    weekday: Field,
+}
+
+impl CronExpression {
+    pub fn parse(text: &str) -> Result<CronExpression, String> {
+        let fields: Vec<&str> = text.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(format!(
+                "'{}' needs five fields: minute, hour, day, month and weekday",
+                text
+            ));
+        };
+        let mut weekday = Field::parse(weekday, 0, 7)?;
+        // Both 0 and 7 are Sunday
+        if weekday.matches(7) {
+            weekday.bits |= 1;
+        }
+        Ok(CronExpression {
+            minute: Field::parse(minute, 0, 59)?,
+            hour: Field::parse(hour, 0, 23)?,
+            day: Field::parse(day, 1, 31)?,
+            month: Field::parse(month, 1, 12)?,
+            weekday,
+        })
+    }
+
+    /// Whether the expression matches the minute `time` falls in
+    pub fn matches(&self, time: &NaiveDateTime) -> bool {
+        let day = self.day.matches(time.day());
+        let weekday = self.weekday.matches(time.weekday().num_days_from_sunday());
+        // Like cron, two restricted day fields match if either does
+        let day_matches = match (self.day.any, self.weekday.any) {
+            (false, false) => day || weekday,
+            _ => day && weekday,
+        };
+        self.minute.matches(time.minute())
+            && self.hour.matches(time.hour())
+            && self.month.matches(time.month())
+            && day_matches
+    }
+}
+
+/// Overrides of the rules active at `time`. Rules with an invalid
+/// expression never apply.
+pub fn overrides_at(schedule: &ScheduleSettings, time: &NaiveDateTime) -> ScheduleOverrides {
+    if !schedule.enabled {
+        return ScheduleOverrides::default();
+    }
+    schedule
+        .rules
+        .iter()
+        .filter(|rule| {
+            CronExpression::parse(&rule.when)
+                .map(|expression| expression.matches(time))
+                .unwrap_or(false)
+        })
+        .fold(ScheduleOverrides::default(), |merged, rule| {
+            merged.or(&rule.overrides)
+        })
+}
+
+pub struct SchedulerManager {
+    app_handle: AppHandle,
+    overrides: Mutex<ScheduleOverrides>,
+}
+
+impl SchedulerManager {
+    pub fn new(app: &AppHandle) -> Self {
+        Self {
+            app_handle: app.clone(),
+            overrides: Mutex::new(ScheduleOverrides::default()),
+        }
+    }
+
+    /// Overrides of the rules active right now
+    pub fn overrides(&self) -> ScheduleOverrides {
+        self.overrides
+            .lock()
+            .map(|overrides| overrides.clone())
+            .unwrap_or_default()
+    }
+
+    /// Check the rules now and then every half minute
+    pub fn start(self: &Arc<Self>) {
+        let manager = self.clone();
+        tauri::async_runtime::spawn(async move {
+            loop {
+                manager.tick();
+                tokio::time::sleep(TICK_INTERVAL).await;
+            }
+        });
+    }
+
+    /// Check the rules again after they were edited
+    pub fn rules_changed(&self) {
+        self.tick();
+    }
+
+    fn tick(&self) {
+        let settings = get_settings(&self.app_handle);
+        let now = Local::now().naive_local();
+        let new_overrides = overrides_at(&settings.schedule, &now);
+        let previous = match self.overrides.lock() {
+            Ok(mut overrides) => std::mem::replace(&mut *overrides, new_overrides.clone()),
+            Err(e) => {
+                warn!("Failed to lock schedule overrides: {}", e);
+                return;
+            }
+        };
+        if previous != new_overrides {
+            info!(
+                "Schedule overrides changed: {:?} -> {:?}",
+                previous, new_overrides
+            );
+            event_trail::record_transition(
+                &self.app_handle,
+                "scheduler",
+                &previous,
+                &new_overrides,
+            );
+        }
+        // Reapplied every tick, so a settings change can't outlast a rule
+        self.apply_sound_detection(&settings, &new_overrides);
+    }
+
+    fn apply_sound_detection(&self, settings: &AppSettings, overrides: &ScheduleOverrides) {
+        let Some(detector) = self.app_handle.try_state::<Mutex<SoundDetector>>() else {
+            return;
+        };
+        let sound_detection = &settings.sound_detection;
+        if let Ok(mut detector) = detector.lock() {
+            detector.configure(
+                overrides.sound_detection.unwrap_or(sound_detection.enabled),
+                sound_detection.threshold,
+                sound_detection.categories.clone(),
+            );
+        }
+    }
+}
+
+/// Overrides of the schedule rules active right now, if any
+pub fn active_overrides(app: &AppHandle) -> ScheduleOverrides {
+    app.try_state::<Arc<SchedulerManager>>()
+        .map(|scheduler| scheduler.overrides())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{ScheduleRule, SoundTheme};
+    use chrono::NaiveDate;
+
+    /// A time on Monday, 2026-03-02
+    fn monday(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, 2)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn matches(expression: &str, time: NaiveDateTime) -> bool {
+        CronExpression::parse(expression).unwrap().matches(&time)
+    }
+
+    #[test]
+    fn test_cron_fields() {
+        assert!(matches("* * * * *", monday(12, 34)));
+        assert!(matches("*/15 9-17 * * 1-5", monday(9, 30)));
+        assert!(!matches("*/15 9-17 * * 1-5", monday(9, 31)));
+        assert!(!matches("* 9-17 * * 0,6", monday(10, 0)));
+        assert!(matches("0 12 * 3 *", monday(12, 0)));
+        assert!(matches(
+            "* * * * 7",
+            monday(0, 0) + chrono::Duration::days(6)
+        ));
+
+        // Ranges wrap around midnight
+        assert!(matches("* 22-6 * * *", monday(23, 0)));
+        assert!(matches("* 22-6 * * *", monday(3, 0)));
+        assert!(!matches("* 22-6 * * *", monday(12, 0)));
+
+        // Restricted days match if either day field does
+        assert!(matches("* * 15 * 1", monday(8, 0)));
+        assert!(!matches("* * 15 * 2", monday(8, 0)));
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        assert!(CronExpression::parse("* * * *").is_err());
+        assert!(CronExpression::parse("60 * * * *").is_err());
+        assert!(CronExpression::parse("* * 0 * *").is_err());
+        assert!(CronExpression::parse("*/0 * * * *").is_err());
+        assert!(CronExpression::parse("a * * * *").is_err());
+    }
+
+    #[test]
+    fn test_first_active_rule_wins_per_setting() {
+        let rule = |when: &str, overrides: ScheduleOverrides| ScheduleRule {
+            name: when.to_string(),
+            when: when.to_string(),
+            overrides,
+        };
+        let mut schedule = ScheduleSettings {
+            enabled: true,
+            rules: vec![
+                rule(
+                    "* 22-6 * * *",
+                    ScheduleOverrides {
+                        sound_detection: Some(true),
+                        sound_theme: Some(SoundTheme::Pop),
+                        ..Default::default()
+                    },
+                ),
+                rule(
+                    "* 9-11 * * 1-5",
+                    ScheduleOverrides {
+                        active_listening: Some(false),
+                        ..Default::default()
+                    },
+                ),
+                rule("bad", ScheduleOverrides::default()),
+                rule(
+                    "* * * * *",
+                    ScheduleOverrides {
+                        sound_detection: Some(false),
+                        sound_theme: Some(SoundTheme::Marimba),
+                        ..Default::default()
+                    },
+                ),
+            ],
+        };
+
+        let night = overrides_at(&schedule, &monday(23, 0));
+        assert_eq!(night.sound_detection, Some(true));
+        assert_eq!(night.sound_theme, Some(SoundTheme::Pop));
+        assert_eq!(night.active_listening, None);
+
+        let focus = overrides_at(&schedule, &monday(10, 0));
+        assert_eq!(focus.active_listening, Some(false));
+        assert_eq!(focus.sound_detection, Some(false));
+
+        schedule.enabled = false;
+        assert_eq!(
+            overrides_at(&schedule, &monday(23, 0)),
+            ScheduleOverrides::default()
+        );
+    }
+}
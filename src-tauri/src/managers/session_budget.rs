@@ -0,0 +1,161 @@
+//! Session Budget
+//!
+//! Keeps the LLM work of an Active Listening session within the limits set in
+//! [`SessionBudget`]. Once the session's calls or tokens are used up, insights
+//! and LLM suggestions are skipped for the rest of the session while
+//! transcription carries on. Tokens are estimated from text length, as in the
+//! monthly report.
+
+use crate::monthly_report::estimate_tokens;
+use crate::settings::SessionBudget;
+use serde::Serialize;
+use specta::Type;
+use std::time::Duration;
+
+/// The limit of a session budget that was reached
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetLimit {
+    LlmCalls,
+    Tokens,
+    /// An insight took longer than allowed for one segment and was dropped.
+    /// Later segments still get insights.
+    SegmentLatency,
+}
+
+/// Event payload for a session budget limit being reached
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct ActiveListeningBudgetEvent {
+    pub session_id: String,
+    pub limit: BudgetLimit,
+    /// LLM calls made in the session so far
+    pub llm_calls: u32,
+    /// Estimated tokens used in the session so far
+    pub tokens: u64,
+}
+
+/// Whether an LLM call may be made
+#[derive(Debug, PartialEq, Eq)]
+pub enum Admission {
+    Allowed,
+    /// The budget ran out with this call; report it
+    Exceeded(BudgetLimit),
+    /// The budget ran out earlier and was already reported
+    Exhausted,
+}
+
+/// LLM usage of the current session against its budget
+#[derive(Debug, Default)]
+pub struct BudgetTracker {
+    budget: SessionBudget,
+    llm_calls: u32,
+    tokens: u64,
+    exhausted: bool,
+}
+
+impl BudgetTracker {
+    pub fn new(budget: SessionBudget) -> Self {
+        Self {
+            budget,
+            ..Default::default()
+        }
+    }
+
+    /// Count an LLM call with the given prompt, unless it would go over the
+    /// session's calls or tokens
+    pub fn admit(&mut self, prompt: &str) -> Admission {
+        if self.exhausted {
+            return Admission::Exhausted;
+        }
+
+        let prompt_tokens = estimate_tokens(prompt.chars().count()) as u64;
+        let limit = if self.budget.max_llm_calls > 0 && self.llm_calls >= self.budget.max_llm_calls
+        {
+            Some(BudgetLimit::LlmCalls)
+        } else if self.budget.max_tokens > 0
+            && self.tokens + prompt_tokens > self.budget.max_tokens as u64
+        {
+            Some(BudgetLimit::Tokens)
+        } else {
+            None
+        };
+
+        match limit {
+            Some(limit) => {
+                self.exhausted = true;
+                Admission::Exceeded(limit)
+            }
+            None => {
+                self.llm_calls += 1;
+                self.tokens += prompt_tokens;
+                Admission::Allowed
+            }
+        }
+    }
+
+    /// Count the tokens of a response
+    pub fn record_response(&mut self, response: &str) {
+        self.tokens += estimate_tokens(response.chars().count()) as u64;
+    }
+
+    /// How long an insight may take for one segment
+    pub fn segment_timeout(&self) -> Option<Duration> {
+        let seconds = self.budget.max_segment_latency_seconds;
+        (seconds > 0).then_some(Duration::from_secs(seconds as u64))
+    }
+
+    pub fn event(&self, session_id: &str, limit: BudgetLimit) -> ActiveListeningBudgetEvent {
+        ActiveListeningBudgetEvent {
+            session_id: session_id.to_string(),
+            limit,
+            llm_calls: self.llm_calls,
+            tokens: self.tokens,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calls_run_out_and_are_reported_once() {
+        let mut tracker = BudgetTracker::new(SessionBudget {
+            max_llm_calls: 2,
+            ..Default::default()
+        });
+        assert_eq!(tracker.admit("first"), Admission::Allowed);
+        assert_eq!(tracker.admit("second"), Admission::Allowed);
+        assert_eq!(
+            tracker.admit("third"),
+            Admission::Exceeded(BudgetLimit::LlmCalls)
+        );
+        assert_eq!(tracker.admit("fourth"), Admission::Exhausted);
+        assert_eq!(tracker.event("al_1", BudgetLimit::LlmCalls).llm_calls, 2);
+    }
+
+    #[test]
+    fn test_tokens_include_responses() {
+        let mut tracker = BudgetTracker::new(SessionBudget {
+            max_tokens: 10,
+            ..Default::default()
+        });
+        // 16 characters is about 4 tokens
+        assert_eq!(tracker.admit(&"a".repeat(16)), Admission::Allowed);
+        tracker.record_response(&"b".repeat(16));
+        assert_eq!(
+            tracker.admit(&"c".repeat(16)),
+            Admission::Exceeded(BudgetLimit::Tokens)
+        );
+        assert_eq!(tracker.event("al_1", BudgetLimit::Tokens).tokens, 8);
+    }
+
+    #[test]
+    fn test_zero_means_unlimited() {
+        let mut tracker = BudgetTracker::new(SessionBudget::default());
+        for _ in 0..100 {
+            assert_eq!(tracker.admit(&"a".repeat(1000)), Admission::Allowed);
+        }
+        assert_eq!(tracker.segment_timeout(), None);
+    }
+}
@@ -0,0 +1,269 @@
+//! Session replay
+//!
+//! Re-processes a finished Active Listening session offline with a different
+//! prompt or model, producing an alternate set of insights and a new summary
+//! without re-attending the meeting. A replay starts either from the
+//! session's stored transcriptions or from a recording of the whole meeting,
+//! which is cut into segments and transcribed first.
+//!
+//! Replays don't touch the live session, history or the knowledge base.
+
+use crate::audio_toolkit::decoder;
+use crate::events::SessionReplayProgress;
+use crate::managers::active_listening::{
+    format_previous_context, ActiveListeningManager, ActiveListeningSession, MeetingSummary,
+    SessionInsight,
+};
+use crate::managers::transcription::TranscriptionManager;
+use crate::ollama_client::{apply_prompt_template, OllamaClient};
+use crate::settings::get_settings;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+/// Sample rate of decoded audio
+const SAMPLE_RATE: usize = 16_000;
+
+/// What a replay starts from
+#[derive(Clone, Debug, Deserialize, Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReplaySource {
+    /// A session returned by `stop_active_listening_session`
+    Session { session: ActiveListeningSession },
+    /// A recording of the whole meeting
+    Audio { path: String, topic: Option<String> },
+}
+
+/// What to change for the replay; unset fields use the current settings
+#[derive(Clone, Debug, Default, Deserialize, Type)]
+pub struct ReplayOptions {
+    /// Active Listening prompt to generate insights with
+    pub prompt_id: Option<String>,
+    /// Ollama model for insights and the summary
+    pub model: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct SessionReplay {
+    /// Id of the replayed session, when replaying a session
+    pub replayed_from: Option<String>,
+    /// The replayed session with the new insights
+    pub session: ActiveListeningSession,
+    pub summary: MeetingSummary,
+}
+
+/// Progress of a running replay, emitted after each segment
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct SessionReplayProgressEvent {
+    pub replay_id: String,
+    pub completed: usize,
+    pub total: usize,
+}
+
+/// Segments of a session that have something to replay
+fn session_segments(session: &ActiveListeningSession) -> Vec<SessionInsight> {
+    session
+        .insights
+        .iter()
+        .filter(|insight| !insight.transcription.trim().is_empty())
+        .map(|insight| SessionInsight {
+            insight: String::new(),
+            ..insight.clone()
+        })
+        .collect()
+}
+
+/// Cut a recording into segments of the live session length and transcribe them
+async fn audio_segments(
+    app: &AppHandle,
+    path: PathBuf,
+    started_at: i64,
+) -> Result<Vec<SessionInsight>, String> {
+    let segment_seconds = get_settings(app)
+        .active_listening
+        .segment_duration_seconds
+        .max(1) as usize;
+    let transcription_manager = app.state::<Arc<TranscriptionManager>>().inner().clone();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let decoded = decoder::decode_audio_file(&path)?;
+        let segment_len = segment_seconds * SAMPLE_RATE;
+
+        let mut segments = Vec::new();
+        for (index, chunk) in decoded.samples.chunks(segment_len).enumerate() {
+            let transcription = transcription_manager
+                .transcribe(chunk.to_vec())
+                .map_err(|e| format!("Transcription failed: {}", e))?;
+            if transcription.trim().is_empty() {
+                continue;
+            }
+            let offset_ms = (index * segment_len * 1000 / SAMPLE_RATE) as i64;
+            segments.push(SessionInsight {
+                timestamp: started_at + offset_ms,
+                transcription,
+                insight: String::new(),
+                duration_ms: (chunk.len() * 1000 / SAMPLE_RATE) as u64,
+                speaker_id: None,
+                speaker_label: None,
+                translation: None,
+            });
+        }
+        Ok(segments)
+    })
+    .await
+    .map_err(|e| format!("Replay transcription task failed: {}", e))?
+}
+
+/// Replay a session with a different prompt or model
+pub async fn replay_session(
+    app: &AppHandle,
+    source: ReplaySource,
+    options: ReplayOptions,
+) -> Result<SessionReplay, String> {
+    let settings = get_settings(app).active_listening;
+    let model = options
+        .model
+        .filter(|model| !model.trim().is_empty())
+        .unwrap_or_else(|| settings.ollama_model.clone());
+    if model.is_empty() {
+        return Err("No Ollama model configured".to_string());
+    }
+    let prompt_template = match options.prompt_id.as_deref() {
+        Some(id) => settings
+            .get_prompt(id)
+            .ok_or_else(|| format!("Prompt not found: {}", id))?,
+        None => settings
+            .get_selected_prompt()
+            .ok_or_else(|| "No Active Listening prompt selected".to_string())?,
+    }
+    .prompt_template
+    .clone();
+
+    let al_manager = app.state::<Arc<ActiveListeningManager>>();
+    let now = chrono::Utc::now().timestamp_millis();
+    let replay_id = format!("al_replay_{}", now);
+
+    let (mut session, segments, replayed_from) = match source {
+        ReplaySource::Session { session } => {
+            let segments = session_segments(&session);
+            let replayed_from = Some(session.id.clone());
+            (session, segments, replayed_from)
+        }
+        ReplaySource::Audio { path, topic } => {
+            if al_manager.is_session_active() {
+                return Err("Stop the active session before replaying a recording".to_string());
+            }
+            let segments = audio_segments(app, PathBuf::from(path), now).await?;
+            let ended_at = segments
+                .last()
+                .map(|segment| segment.timestamp + segment.duration_ms as i64);
+            let session = ActiveListeningSession {
+                id: replay_id.clone(),
+                started_at: now,
+                ended_at,
+                topic,
+                insights: Vec::new(),
+                highlights: Vec::new(),
+                speaker_names: Default::default(),
+            };
+            (session, segments, None)
+        }
+    };
+    if segments.is_empty() {
+        return Err("Nothing to replay: the session has no transcribed segments".to_string());
+    }
+
+    info!(
+        "Replaying session {} ({} segments) with model {}",
+        session.id,
+        segments.len(),
+        model
+    );
+
+    let client = OllamaClient::for_active_listening(&settings)
+        .map_err(|e| format!("Failed to create Ollama client: {}", e))?;
+    let total = segments.len();
+    let mut context = VecDeque::new();
+    let mut insights = Vec::with_capacity(total);
+
+    for (completed, mut segment) in segments.into_iter().enumerate() {
+        let prompt = apply_prompt_template(
+            &prompt_template,
+            &segment.transcription,
+            &format_previous_context(&context),
+            session.topic.as_deref(),
+        );
+        match client.generate(&model, prompt).await {
+            Ok(insight) => segment.insight = insight,
+            Err(e) => warn!("Replay insight failed for segment {}: {}", completed, e),
+        }
+
+        if !segment.insight.is_empty() {
+            context.push_back(segment.insight.clone());
+            while context.len() > settings.context_window_size {
+                context.pop_front();
+            }
+        }
+        insights.push(segment);
+
+        let _ = SessionReplayProgress(SessionReplayProgressEvent {
+            replay_id: replay_id.clone(),
+            completed: completed + 1,
+            total,
+        })
+        .emit(app);
+    }
+
+    session.id = replay_id;
+    session.insights = insights;
+    let summary = al_manager
+        .generate_session_summary_with_model(&session, &model)
+        .await?;
+
+    Ok(SessionReplay {
+        replayed_from,
+        session,
+        summary,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insight(transcription: &str, speaker_id: Option<u32>) -> SessionInsight {
+        SessionInsight {
+            timestamp: 1_000,
+            transcription: transcription.to_string(),
+            insight: "old insight".to_string(),
+            duration_ms: 30_000,
+            speaker_id,
+            speaker_label: speaker_id.map(|id| format!("Speaker {}", id + 1)),
+            translation: None,
+        }
+    }
+
+    #[test]
+    fn test_session_segments_drop_old_insights_and_empty_segments() {
+        let session = ActiveListeningSession {
+            id: "al_1".to_string(),
+            started_at: 0,
+            ended_at: Some(60_000),
+            topic: None,
+            insights: vec![insight("Pricing stays as is", Some(1)), insight("  ", None)],
+            highlights: Vec::new(),
+            speaker_names: Default::default(),
+        };
+
+        let segments = session_segments(&session);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].transcription, "Pricing stays as is");
+        assert!(segments[0].insight.is_empty());
+        assert_eq!(segments[0].speaker_label.as_deref(), Some("Speaker 2"));
+    }
+}
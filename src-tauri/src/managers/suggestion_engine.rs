@@ -2,18 +2,42 @@
 //!
 //! Provides real-time response suggestions during conversations.
 //! Integrates keyword-based quick responses, RAG-powered suggestions,
-//! and LLM-generated talking points.
+//! LLM-generated talking points and the sources plugins register.
 
+use crate::events::{SuggestionPartial, Suggestions};
+use crate::managers::history::{HistoryManager, SuggestionFeedback};
+use crate::managers::plugins::PluginManager;
 use crate::managers::rag::RagManager;
+use crate::notifications::{notify, Notification};
 use crate::ollama_client::OllamaClient;
-use crate::settings::{QuickResponse, SuggestionsSettings, WarningSeverity};
+use crate::settings::{
+    NotificationFeature, NotificationSeverity, QuickResponse, SuggestionsSettings, WarningSeverity,
+};
+use crate::warning_notifications::route_warning;
 use log::{debug, error, info, warn};
 use serde::{Deserialize, Serialize};
 use specta::Type;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
-use std::time::Instant;
-use tauri::{AppHandle, Emitter};
-use tokio::sync::RwLock;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+use tokio::sync::{mpsc, RwLock};
+
+/// Largest ranking adjustment feedback can apply to a suggestion's confidence
+const MAX_FEEDBACK_ADJUSTMENT: f32 = 0.2;
+
+/// Feedback loses half its influence after this many days
+const FEEDBACK_HALF_LIFE_DAYS: f32 = 14.0;
+
+/// Feedback older than this is ignored
+const FEEDBACK_LOOKBACK_DAYS: i64 = 90;
+
+/// Confidence penalty for triggers that only matched an earlier segment
+const EARLIER_SEGMENT_PENALTY: f32 = 0.15;
+
+/// Base confidence for a quick response triggered by a single keyword
+const KEYWORD_MATCH_CONFIDENCE: f32 = 0.6;
 
 /// A suggestion generated by the engine
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
@@ -54,6 +78,92 @@ pub struct SuggestionsEvent {
     pub timestamp: i64,
 }
 
+/// Event payload for a single suggestion parsed while the LLM is still generating
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct SuggestionPartialEvent {
+    pub session_id: String,
+    pub suggestion: Suggestion,
+    pub timestamp: i64,
+}
+
+/// Parse the remainder of a `WARNING [SEVERITY]: message` line
+fn parse_warning(rest: &str) -> Option<Suggestion> {
+    let (label, message) = rest.split_once(':')?;
+    let message = message.trim();
+    if message.is_empty() {
+        return None;
+    }
+
+    let label = label.to_lowercase();
+    let severity = if label.contains("high") {
+        WarningSeverity::High
+    } else if label.contains("medium") {
+        WarningSeverity::Medium
+    } else {
+        WarningSeverity::Low
+    };
+
+    Some(Suggestion::Warning {
+        message: message.to_string(),
+        severity,
+    })
+}
+
+/// Incrementally parses `POINT:` / `RATIONALE:` talking points (and
+/// `WARNING` lines) from streamed LLM output, yielding each as soon as it
+/// is complete
+#[derive(Debug, Default)]
+struct TalkingPointParser {
+    /// Text after the last complete line
+    buffer: String,
+    point: Option<String>,
+}
+
+impl TalkingPointParser {
+    /// Feed a chunk of streamed text, returning any newly completed points
+    fn push(&mut self, chunk: &str) -> Vec<Suggestion> {
+        self.buffer.push_str(chunk);
+        let mut completed = Vec::new();
+        while let Some(newline) = self.buffer.find('\n') {
+            let line: String = self.buffer.drain(..=newline).collect();
+            completed.extend(self.parse_line(&line));
+        }
+        completed
+    }
+
+    /// Flush any remaining text once the stream has ended
+    fn finish(mut self) -> Vec<Suggestion> {
+        let rest = std::mem::take(&mut self.buffer);
+        let mut completed: Vec<Suggestion> = self.parse_line(&rest).into_iter().collect();
+        completed.extend(self.take_point(String::new()));
+        completed
+    }
+
+    fn parse_line(&mut self, line: &str) -> Option<Suggestion> {
+        let line = line.trim();
+        if let Some(point) = line.strip_prefix("POINT:") {
+            // A new point completes the previous one, even without a rationale
+            let previous = self.take_point(String::new());
+            self.point = Some(point.trim().to_string());
+            previous
+        } else if let Some(rationale) = line.strip_prefix("RATIONALE:") {
+            self.take_point(rationale.trim().to_string())
+        } else if let Some(warning) = line.strip_prefix("WARNING") {
+            parse_warning(warning)
+        } else {
+            None
+        }
+    }
+
+    fn take_point(&mut self, rationale: String) -> Option<Suggestion> {
+        self.point.take().map(|point| Suggestion::TalkingPoint {
+            point,
+            rationale,
+            confidence: 0.7,
+        })
+    }
+}
+
 /// Context for generating suggestions
 #[derive(Clone, Debug)]
 pub struct SuggestionContext {
@@ -61,12 +171,345 @@ pub struct SuggestionContext {
     pub transcription: String,
     /// Previous context (recent transcriptions)
     pub previous_context: String,
+    /// Transcripts of the segments before the current one, oldest first
+    pub recent_transcriptions: Vec<String>,
     /// Session topic if set
     pub session_topic: Option<String>,
     /// Current session ID
     pub session_id: String,
 }
 
+impl Suggestion {
+    /// The main text shown to the user
+    pub fn text(&self) -> &str {
+        match self {
+            Suggestion::QuickResponse { text, .. } => text,
+            Suggestion::DataPoint { fact, .. } => fact,
+            Suggestion::TalkingPoint { point, .. } => point,
+            Suggestion::Warning { message, .. } => message,
+        }
+    }
+
+    /// The suggestion type, matching the serialized `type` tag
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Suggestion::QuickResponse { .. } => "quick_response",
+            Suggestion::DataPoint { .. } => "data_point",
+            Suggestion::TalkingPoint { .. } => "talking_point",
+            Suggestion::Warning { .. } => "warning",
+        }
+    }
+
+    /// Where the suggestion came from: the quick response ID or the
+    /// knowledge base document title
+    pub fn source(&self) -> Option<&str> {
+        match self {
+            Suggestion::QuickResponse { id, .. } => Some(id),
+            Suggestion::DataPoint { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+
+    /// Category used for cooldowns: the template category for quick
+    /// responses, otherwise the suggestion type
+    pub fn category(&self) -> &str {
+        match self {
+            Suggestion::QuickResponse { category, .. } => category,
+            Suggestion::DataPoint { .. } => "data_point",
+            Suggestion::TalkingPoint { .. } => "talking_point",
+            Suggestion::Warning { .. } => "warning",
+        }
+    }
+}
+
+/// Match quick response templates against the recent transcript.
+///
+/// Only the current segment and the `context_segments - 1` segments before
+/// it are considered, so responses stop surfacing once the conversation has
+/// moved on. Matches that only occur in an earlier segment are ranked lower.
+fn match_quick_responses(
+    quick_responses: &[QuickResponse],
+    context: &SuggestionContext,
+    context_segments: usize,
+) -> Vec<Suggestion> {
+    let current = context.transcription.to_lowercase();
+    let earlier_count = context_segments.saturating_sub(1);
+    let earlier: Vec<String> = context
+        .recent_transcriptions
+        .iter()
+        .rev()
+        .take(earlier_count)
+        .map(|t| t.to_lowercase())
+        .collect();
+
+    quick_responses
+        .iter()
+        .filter(|qr| qr.enabled)
+        .filter_map(|qr| {
+            let (confidence, trigger) = std::iter::once((&current, 0.0))
+                .chain(earlier.iter().map(|t| (t, EARLIER_SEGMENT_PENALTY)))
+                .find_map(|(segment, penalty)| {
+                    match_triggers(qr, segment)
+                        .map(|(confidence, trigger)| (confidence - penalty, trigger))
+                })?;
+
+            Some(Suggestion::QuickResponse {
+                id: qr.id.clone(),
+                text: qr.response_template.clone(),
+                confidence,
+                category: qr.category.clone(),
+                trigger_phrase: trigger,
+            })
+        })
+        .collect()
+}
+
+/// Match a quick response's trigger phrases, then keywords, against one
+/// lowercased segment. Returns the confidence and the matched trigger.
+fn match_triggers(qr: &QuickResponse, segment: &str) -> Option<(f32, String)> {
+    for trigger in &qr.trigger_phrases {
+        let trigger_lower = trigger.to_lowercase();
+        if !trigger_lower.is_empty() && segment.contains(&trigger_lower) {
+            // Calculate confidence based on how well the trigger matches
+            let confidence = calculate_trigger_confidence(segment, &trigger_lower);
+            return Some((confidence, trigger.clone()));
+        }
+    }
+
+    let words = word_set(segment);
+    qr.trigger_keywords
+        .iter()
+        .find(|keyword| words.contains(&keyword.trim().to_lowercase()))
+        .map(|keyword| (KEYWORD_MATCH_CONFIDENCE, keyword.clone()))
+}
+
+/// Calculate confidence for a trigger match
+fn calculate_trigger_confidence(text: &str, trigger: &str) -> f32 {
+    // Base confidence for any match
+    let mut confidence = 0.7;
+
+    // Boost if trigger is a significant portion of the text
+    let trigger_words: Vec<&str> = trigger.split_whitespace().collect();
+    let text_words: Vec<&str> = text.split_whitespace().collect();
+
+    if !text_words.is_empty() {
+        let ratio = trigger_words.len() as f32 / text_words.len() as f32;
+        confidence += ratio * 0.2;
+    }
+
+    // Boost for exact phrase match (not just contains)
+    if text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .windows(trigger_words.len())
+        .any(|window| window == trigger_words.as_slice())
+    {
+        confidence += 0.1;
+    }
+
+    confidence.min(1.0)
+}
+
+/// Accept/reject statistics for one suggestion category
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct SuggestionCategoryStats {
+    pub category: String,
+    pub accepted: u32,
+    pub rejected: u32,
+    /// Current ranking adjustment applied to this category's suggestions
+    pub ranking_adjustment: f32,
+}
+
+/// Summary of recorded suggestion feedback
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct SuggestionStats {
+    pub total_accepted: u32,
+    pub total_rejected: u32,
+    pub categories: Vec<SuggestionCategoryStats>,
+}
+
+/// Time-decayed accept/reject weights
+#[derive(Clone, Copy, Debug, Default)]
+struct FeedbackTally {
+    accepted: f32,
+    rejected: f32,
+}
+
+impl FeedbackTally {
+    /// Ranking adjustment in [-MAX_FEEDBACK_ADJUSTMENT, MAX_FEEDBACK_ADJUSTMENT].
+    /// The +2 prior keeps a single reaction from swinging the ranking.
+    fn adjustment(&self) -> f32 {
+        MAX_FEEDBACK_ADJUSTMENT * (self.accepted - self.rejected)
+            / (self.accepted + self.rejected + 2.0)
+    }
+}
+
+/// Ranking adjustments learned from suggestion feedback
+#[derive(Clone, Debug, Default)]
+struct FeedbackWeights {
+    categories: HashMap<String, FeedbackTally>,
+    sources: HashMap<String, FeedbackTally>,
+}
+
+impl FeedbackWeights {
+    fn from_feedback(feedback: &[SuggestionFeedback], now: i64) -> Self {
+        let mut weights = Self::default();
+        for item in feedback {
+            let age_days = (now - item.timestamp).max(0) as f32 / 86_400.0;
+            let weight = 0.5_f32.powf(age_days / FEEDBACK_HALF_LIFE_DAYS);
+
+            let mut tallies = vec![weights.categories.entry(item.category.clone()).or_default()];
+            if let Some(source) = &item.source {
+                tallies.push(weights.sources.entry(source.clone()).or_default());
+            }
+            for tally in tallies {
+                if item.accepted {
+                    tally.accepted += weight;
+                } else {
+                    tally.rejected += weight;
+                }
+            }
+        }
+        weights
+    }
+
+    fn category_adjustment(&self, category: &str) -> f32 {
+        self.categories
+            .get(category)
+            .map(|t| t.adjustment())
+            .unwrap_or(0.0)
+    }
+
+    /// Combined category and source adjustment for a suggestion
+    fn adjustment(&self, suggestion: &Suggestion) -> f32 {
+        let source = suggestion
+            .source()
+            .and_then(|s| self.sources.get(s))
+            .map(|t| t.adjustment())
+            .unwrap_or(0.0);
+        self.category_adjustment(suggestion.category()) + source
+    }
+}
+
+/// A suggestion that was recently shown
+#[derive(Clone, Debug)]
+struct EmittedSuggestion {
+    words: HashSet<String>,
+    category: String,
+    emitted_at: Instant,
+}
+
+/// Normalized word set used for similarity comparisons
+fn word_set(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+/// Jaccard similarity between two word sets (0.0 - 1.0)
+fn word_similarity(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let intersection = a.intersection(b).count();
+    let union = a.union(b).count();
+    intersection as f32 / union as f32
+}
+
+/// Suppresses near-duplicate suggestions and limits how often they are shown
+#[derive(Debug, Default)]
+struct SuggestionThrottle {
+    recent: VecDeque<EmittedSuggestion>,
+}
+
+impl SuggestionThrottle {
+    /// Whether a suggestion would currently be dropped as a duplicate or
+    /// because its category is cooling down. Does not record anything.
+    fn is_suppressed(
+        &self,
+        suggestion: &Suggestion,
+        settings: &SuggestionsSettings,
+        now: Instant,
+    ) -> bool {
+        let dedup_window = Duration::from_secs(settings.dedup_window_seconds as u64);
+        let cooldown = Duration::from_secs(settings.category_cooldown_seconds as u64);
+        let words = word_set(suggestion.text());
+
+        self.recent.iter().any(|e| {
+            let age = now.saturating_duration_since(e.emitted_at);
+            (age < cooldown && e.category == suggestion.category())
+                || (age < dedup_window
+                    && word_similarity(&e.words, &words) >= settings.dedup_similarity_threshold)
+        })
+    }
+
+    /// Filter ranked suggestions against recently shown ones and record the
+    /// ones that pass. Suggestions are dropped if they are too similar to a
+    /// recent suggestion, if their category is cooling down, or once the
+    /// per-minute limit is reached.
+    fn filter(
+        &mut self,
+        suggestions: Vec<Suggestion>,
+        settings: &SuggestionsSettings,
+        now: Instant,
+    ) -> Vec<Suggestion> {
+        let dedup_window = Duration::from_secs(settings.dedup_window_seconds as u64);
+        let cooldown = Duration::from_secs(settings.category_cooldown_seconds as u64);
+        let minute = Duration::from_secs(60);
+        let retention = dedup_window.max(cooldown).max(minute);
+        self.recent
+            .retain(|e| now.saturating_duration_since(e.emitted_at) < retention);
+
+        let mut budget = if settings.max_suggestions_per_minute == 0 {
+            usize::MAX
+        } else {
+            let shown_last_minute = self
+                .recent
+                .iter()
+                .filter(|e| now.saturating_duration_since(e.emitted_at) < minute)
+                .count();
+            (settings.max_suggestions_per_minute as usize).saturating_sub(shown_last_minute)
+        };
+
+        let cooling: HashSet<String> = self
+            .recent
+            .iter()
+            .filter(|e| now.saturating_duration_since(e.emitted_at) < cooldown)
+            .map(|e| e.category.clone())
+            .collect();
+
+        let mut accepted = Vec::new();
+        for suggestion in suggestions {
+            if budget == 0 || accepted.len() >= settings.max_suggestions {
+                break;
+            }
+            if cooling.contains(suggestion.category()) {
+                continue;
+            }
+
+            let words = word_set(suggestion.text());
+            let duplicate = self.recent.iter().any(|e| {
+                now.saturating_duration_since(e.emitted_at) < dedup_window
+                    && word_similarity(&e.words, &words) >= settings.dedup_similarity_threshold
+            });
+            if duplicate {
+                continue;
+            }
+
+            self.recent.push_back(EmittedSuggestion {
+                words,
+                category: suggestion.category().to_string(),
+                emitted_at: now,
+            });
+            accepted.push(suggestion);
+            budget -= 1;
+        }
+
+        accepted
+    }
+}
+
 /// Suggestion Engine
 ///
 /// Generates real-time suggestions during Active Listening sessions by:
@@ -83,6 +526,10 @@ pub struct SuggestionEngine {
     ollama_client: Arc<OllamaClient>,
     /// Current settings
     settings: Arc<RwLock<SuggestionsSettings>>,
+    /// Recently shown suggestions for dedup and rate limiting
+    throttle: Arc<RwLock<SuggestionThrottle>>,
+    /// Ranking adjustments from feedback (loaded lazily, cleared on change)
+    feedback_weights: Arc<RwLock<Option<FeedbackWeights>>>,
 }
 
 impl SuggestionEngine {
@@ -99,6 +546,8 @@ impl SuggestionEngine {
             rag_manager,
             ollama_client,
             settings: Arc::new(RwLock::new(settings)),
+            throttle: Arc::new(RwLock::new(SuggestionThrottle::default())),
+            feedback_weights: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -126,7 +575,9 @@ impl SuggestionEngine {
         let start = Instant::now();
 
         // 1. Check quick response triggers
-        let quick_suggestions = self.match_quick_responses(&context.transcription).await;
+        let quick_suggestions = self
+            .match_quick_responses(context, settings.quick_response_context_segments)
+            .await;
         suggestions.extend(quick_suggestions);
 
         // 2. Get RAG-based suggestions if enabled
@@ -138,21 +589,36 @@ impl SuggestionEngine {
 
         // 3. Get LLM-generated suggestions if enabled
         if settings.llm_suggestions_enabled && suggestions.len() < settings.max_suggestions {
-            if let Some(llm_suggestions) = self.get_llm_suggestions(context).await {
+            if let Some(llm_suggestions) = self.get_llm_suggestions(context, &settings).await {
                 suggestions.extend(llm_suggestions);
             }
         }
 
+        // 4. Ask plugin suggestion sources
+        suggestions.extend(self.get_plugin_suggestions(context));
+
         // Filter by confidence threshold
         suggestions.retain(|s| self.get_confidence(s) >= settings.min_confidence);
 
-        // Sort by confidence and limit
-        suggestions.sort_by(|a, b| {
-            self.get_confidence(b)
-                .partial_cmp(&self.get_confidence(a))
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        suggestions.truncate(settings.max_suggestions);
+        // Rank by confidence, adjusted by accept/reject feedback if enabled
+        let weights = if settings.feedback_learning_enabled {
+            self.feedback_weights().await
+        } else {
+            FeedbackWeights::default()
+        };
+        let mut ranked: Vec<(f32, Suggestion)> = suggestions
+            .into_iter()
+            .map(|s| (self.get_confidence(&s) + weights.adjustment(&s), s))
+            .collect();
+        ranked.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        let suggestions: Vec<Suggestion> = ranked.into_iter().map(|(_, s)| s).collect();
+
+        // Drop repeats of recent suggestions and apply cooldown / rate limits
+        let suggestions =
+            self.throttle
+                .write()
+                .await
+                .filter(suggestions, &settings, Instant::now());
 
         debug!(
             "Generated {} suggestions in {:?}",
@@ -163,6 +629,110 @@ impl SuggestionEngine {
         suggestions
     }
 
+    fn history_manager(&self) -> Result<Arc<HistoryManager>, String> {
+        self.app_handle
+            .try_state::<Arc<HistoryManager>>()
+            .map(|state| state.inner().clone())
+            .ok_or_else(|| "History manager not available".to_string())
+    }
+
+    /// Recent suggestion feedback within the lookback window
+    fn load_feedback(&self, now: i64) -> Result<Vec<SuggestionFeedback>, String> {
+        let since = now - FEEDBACK_LOOKBACK_DAYS * 86_400;
+        self.history_manager()?
+            .get_suggestion_feedback(since)
+            .map_err(|e| format!("Failed to load suggestion feedback: {}", e))
+    }
+
+    /// Get the cached feedback weights, loading them on first use
+    async fn feedback_weights(&self) -> FeedbackWeights {
+        if let Some(weights) = self.feedback_weights.read().await.as_ref() {
+            return weights.clone();
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let weights = match self.load_feedback(now) {
+            Ok(feedback) => FeedbackWeights::from_feedback(&feedback, now),
+            Err(e) => {
+                warn!("{}", e);
+                FeedbackWeights::default()
+            }
+        };
+        *self.feedback_weights.write().await = Some(weights.clone());
+        weights
+    }
+
+    /// Record that a suggestion was accepted (copied/used) or dismissed
+    pub async fn record_feedback(
+        &self,
+        session_id: Option<&str>,
+        suggestion: &Suggestion,
+        accepted: bool,
+    ) -> Result<(), String> {
+        if crate::settings::is_guest_mode(&self.app_handle) {
+            return Ok(());
+        }
+
+        self.history_manager()?
+            .insert_suggestion_feedback(
+                session_id,
+                suggestion.kind(),
+                suggestion.category(),
+                suggestion.source(),
+                suggestion.text(),
+                accepted,
+            )
+            .map_err(|e| format!("Failed to record suggestion feedback: {}", e))?;
+
+        // Recompute weights on next use
+        *self.feedback_weights.write().await = None;
+        Ok(())
+    }
+
+    /// Summarize recorded feedback per category
+    pub async fn get_stats(&self) -> Result<SuggestionStats, String> {
+        let now = chrono::Utc::now().timestamp();
+        let feedback = self.load_feedback(now)?;
+        let weights = FeedbackWeights::from_feedback(&feedback, now);
+
+        let mut counts: HashMap<&str, (u32, u32)> = HashMap::new();
+        for item in &feedback {
+            let entry = counts.entry(item.category.as_str()).or_default();
+            if item.accepted {
+                entry.0 += 1;
+            } else {
+                entry.1 += 1;
+            }
+        }
+
+        let mut categories: Vec<SuggestionCategoryStats> = counts
+            .into_iter()
+            .map(|(category, (accepted, rejected))| SuggestionCategoryStats {
+                category: category.to_string(),
+                accepted,
+                rejected,
+                ranking_adjustment: weights.category_adjustment(category),
+            })
+            .collect();
+        categories.sort_by(|a, b| a.category.cmp(&b.category));
+
+        Ok(SuggestionStats {
+            total_accepted: categories.iter().map(|c| c.accepted).sum(),
+            total_rejected: categories.iter().map(|c| c.rejected).sum(),
+            categories,
+        })
+    }
+
+    /// Forget all recorded feedback and reset ranking adjustments
+    pub async fn reset_feedback(&self) -> Result<(), String> {
+        self.history_manager()?
+            .clear_suggestion_feedback()
+            .map_err(|e| format!("Failed to reset suggestion feedback: {}", e))?;
+        *self.feedback_weights.write().await = Some(FeedbackWeights::default());
+        info!("Reset suggestion feedback");
+        Ok(())
+    }
+
     /// Get the confidence score for a suggestion
     fn get_confidence(&self, suggestion: &Suggestion) -> f32 {
         match suggestion {
@@ -177,65 +747,33 @@ impl SuggestionEngine {
         }
     }
 
-    /// Match quick response templates against the transcription
-    async fn match_quick_responses(&self, transcription: &str) -> Vec<Suggestion> {
+    /// Match quick response templates against the recent transcript
+    async fn match_quick_responses(
+        &self,
+        context: &SuggestionContext,
+        context_segments: usize,
+    ) -> Vec<Suggestion> {
         let quick_responses = self.quick_responses.read().await;
-        let transcription_lower = transcription.to_lowercase();
-        let mut matches = Vec::new();
-
-        for qr in quick_responses.iter() {
-            if !qr.enabled {
-                continue;
-            }
-
-            for trigger in &qr.trigger_phrases {
-                let trigger_lower = trigger.to_lowercase();
-                if transcription_lower.contains(&trigger_lower) {
-                    // Calculate confidence based on how well the trigger matches
-                    let confidence = self.calculate_trigger_confidence(&transcription_lower, &trigger_lower);
-
-                    matches.push(Suggestion::QuickResponse {
-                        id: qr.id.clone(),
-                        text: qr.response_template.clone(),
-                        confidence,
-                        category: qr.category.clone(),
-                        trigger_phrase: trigger.clone(),
-                    });
-
-                    // Only one match per quick response
-                    break;
-                }
-            }
-        }
-
-        matches
+        match_quick_responses(&quick_responses, context, context_segments)
     }
 
-    /// Calculate confidence for a trigger match
-    fn calculate_trigger_confidence(&self, text: &str, trigger: &str) -> f32 {
-        // Base confidence for any match
-        let mut confidence = 0.7;
-
-        // Boost if trigger is a significant portion of the text
-        let trigger_words: Vec<&str> = trigger.split_whitespace().collect();
-        let text_words: Vec<&str> = text.split_whitespace().collect();
-
-        if !text_words.is_empty() {
-            let ratio = trigger_words.len() as f32 / text_words.len() as f32;
-            confidence += ratio * 0.2;
-        }
-
-        // Boost for exact phrase match (not just contains)
-        if text.split_whitespace().collect::<Vec<_>>().windows(trigger_words.len())
-            .any(|window| window == trigger_words.as_slice())
-        {
-            confidence += 0.1;
-        }
-
-        confidence.min(1.0)
+    /// Get suggestions from the RAG knowledge base
+    /// Suggestions from the sources plugin scripts registered, as talking points
+    fn get_plugin_suggestions(&self, context: &SuggestionContext) -> Vec<Suggestion> {
+        let Some(plugins) = self.app_handle.try_state::<Arc<PluginManager>>() else {
+            return Vec::new();
+        };
+        plugins
+            .suggestions(&context.transcription)
+            .into_iter()
+            .map(|suggestion| Suggestion::TalkingPoint {
+                point: suggestion.text,
+                rationale: suggestion.source,
+                confidence: suggestion.confidence,
+            })
+            .collect()
     }
 
-    /// Get suggestions from the RAG knowledge base
     async fn get_rag_suggestions(&self, context: &SuggestionContext) -> Option<Vec<Suggestion>> {
         let rag = self.rag_manager.as_ref()?;
 
@@ -272,7 +810,14 @@ impl SuggestionEngine {
     }
 
     /// Get LLM-generated suggestions
-    async fn get_llm_suggestions(&self, context: &SuggestionContext) -> Option<Vec<Suggestion>> {
+    ///
+    /// Talking points are emitted as `suggestion-partial` events while the
+    /// response streams in; the complete list is returned for ranking.
+    async fn get_llm_suggestions(
+        &self,
+        context: &SuggestionContext,
+        settings: &SuggestionsSettings,
+    ) -> Option<Vec<Suggestion>> {
         let app_settings = crate::settings::get_settings(&self.app_handle);
         let active_listening_settings = &app_settings.active_listening;
 
@@ -288,83 +833,118 @@ Provide suggestions in this format:
 POINT: [Your suggested talking point]
 RATIONALE: [Brief reason why this is helpful]
 
+If the speaker is about to make a risky commitment (a date, price, guarantee or promise they may not be able to keep), also add:
+WARNING [LOW|MEDIUM|HIGH]: [What to be careful about]
+
 Be concise - each point should be actionable and under 50 words. Only suggest if truly relevant."#,
             context.transcription,
             context.previous_context,
             context.session_topic.as_deref().unwrap_or("General conversation")
         );
 
-        // Use Ollama to generate suggestions
-        match self
-            .ollama_client
-            .generate(
-                &active_listening_settings.ollama_model,
-                prompt,
-            )
-            .await
-        {
-            Ok(response) => {
-                let suggestions = self.parse_llm_suggestions(&response);
-                if suggestions.is_empty() {
-                    None
-                } else {
-                    Some(suggestions)
+        // Stream the response so each talking point can be shown as soon as it parses
+        let (tx, mut rx) = mpsc::channel::<String>(64);
+        let generation =
+            self.ollama_client
+                .generate_stream(&active_listening_settings.ollama_model, prompt, tx);
+
+        let mut parser = TalkingPointParser::default();
+        let mut suggestions = Vec::new();
+        let consume = async {
+            while let Some(chunk) = rx.recv().await {
+                for suggestion in parser.push(&chunk) {
+                    self.emit_partial_suggestion(context, &suggestion, settings)
+                        .await;
+                    suggestions.push(suggestion);
                 }
             }
-            Err(e) => {
-                warn!("Failed to get LLM suggestions: {}", e);
-                None
+        };
+
+        let (result, ()) = futures_util::future::join(generation, consume).await;
+        if let Err(e) = result {
+            warn!("Failed to get LLM suggestions: {}", e);
+            if suggestions.is_empty() {
+                return None;
             }
         }
-    }
 
-    /// Parse LLM response into suggestions
-    fn parse_llm_suggestions(&self, response: &str) -> Vec<Suggestion> {
-        let mut suggestions = Vec::new();
-        let lines: Vec<&str> = response.lines().collect();
-
-        let mut current_point: Option<String> = None;
-        let mut current_rationale: Option<String> = None;
-
-        for line in lines {
-            let line = line.trim();
+        for suggestion in parser.finish() {
+            self.emit_partial_suggestion(context, &suggestion, settings)
+                .await;
+            suggestions.push(suggestion);
+        }
 
-            if line.starts_with("POINT:") {
-                // Save previous point if exists
-                if let Some(point) = current_point.take() {
-                    suggestions.push(Suggestion::TalkingPoint {
-                        point,
-                        rationale: current_rationale.take().unwrap_or_default(),
-                        confidence: 0.7,
-                    });
-                }
-                current_point = Some(line.trim_start_matches("POINT:").trim().to_string());
-            } else if line.starts_with("RATIONALE:") {
-                current_rationale = Some(line.trim_start_matches("RATIONALE:").trim().to_string());
-            }
+        if suggestions.is_empty() {
+            None
+        } else {
+            Some(suggestions)
         }
+    }
 
-        // Don't forget the last point
-        if let Some(point) = current_point {
-            suggestions.push(Suggestion::TalkingPoint {
-                point,
-                rationale: current_rationale.unwrap_or_default(),
-                confidence: 0.7,
-            });
+    /// Emit a single streamed suggestion unless it would be filtered out
+    /// of the final suggestions event
+    async fn emit_partial_suggestion(
+        &self,
+        context: &SuggestionContext,
+        suggestion: &Suggestion,
+        settings: &SuggestionsSettings,
+    ) {
+        if self.get_confidence(suggestion) < settings.min_confidence
+            || self
+                .throttle
+                .read()
+                .await
+                .is_suppressed(suggestion, settings, Instant::now())
+        {
+            return;
         }
 
-        suggestions
+        let event = SuggestionPartialEvent {
+            session_id: context.session_id.clone(),
+            suggestion: suggestion.clone(),
+            timestamp: chrono::Utc::now().timestamp_millis(),
+        };
+        if let Err(e) = SuggestionPartial(event.clone()).emit(&self.app_handle) {
+            error!("Failed to emit partial suggestion event: {}", e);
+        }
     }
 
     /// Emit suggestions event to the frontend
+    ///
+    /// Warnings are additionally routed to notifications, sound or a webhook
+    /// according to the per-severity routing table.
     pub async fn emit_suggestions(&self, session_id: &str, suggestions: Vec<Suggestion>) {
+        {
+            let settings = self.settings.read().await;
+            for suggestion in &suggestions {
+                let text = match suggestion {
+                    Suggestion::Warning { message, severity } => {
+                        route_warning(&self.app_handle, &settings, session_id, message, *severity);
+                        continue;
+                    }
+                    Suggestion::QuickResponse { text, .. } => text,
+                    Suggestion::DataPoint { fact, .. } => fact,
+                    Suggestion::TalkingPoint { point, .. } => point,
+                };
+                notify(
+                    &self.app_handle,
+                    Notification {
+                        feature: NotificationFeature::Suggestions,
+                        severity: NotificationSeverity::Info,
+                        title: "Suggestion".to_string(),
+                        body: text.clone(),
+                    },
+                );
+            }
+        }
+
         let event = SuggestionsEvent {
             session_id: session_id.to_string(),
             suggestions,
             timestamp: chrono::Utc::now().timestamp_millis(),
         };
 
-        if let Err(e) = self.app_handle.emit("suggestions", &event) {
+        if let Err(e) = Suggestions(event.clone()).emit(&self.app_handle) {
             error!("Failed to emit suggestions event: {}", e);
         }
     }
@@ -479,6 +1059,146 @@ RATIONALE: This can help overcome price objections"#;
         assert_eq!(point_count, 2);
     }
 
+    #[test]
+    fn test_talking_point_parser_streams_points() {
+        let mut parser = TalkingPointParser::default();
+
+        // Nothing is complete until the rationale line ends
+        assert!(parser.push("POINT: Offer a pay").is_empty());
+        assert!(parser.push("ment plan\nRATIONALE: Eases budget").is_empty());
+
+        let first = parser.push(" concerns\n\nPOINT: Ask about timing\n");
+        assert_eq!(first.len(), 1);
+        match &first[0] {
+            Suggestion::TalkingPoint {
+                point, rationale, ..
+            } => {
+                assert_eq!(point, "Offer a payment plan");
+                assert_eq!(rationale, "Eases budget concerns");
+            }
+            other => panic!("unexpected suggestion: {:?}", other),
+        }
+
+        // A trailing point without a rationale is flushed at the end
+        let rest = parser.finish();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].text(), "Ask about timing");
+    }
+
+    #[test]
+    fn test_talking_point_parser_point_without_rationale() {
+        let mut parser = TalkingPointParser::default();
+        let points = parser.push("POINT: First\nPOINT: Second\nRATIONALE: Because");
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].text(), "First");
+
+        let rest = parser.finish();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].text(), "Second");
+    }
+
+    #[test]
+    fn test_talking_point_parser_warnings() {
+        let mut parser = TalkingPointParser::default();
+        let parsed = parser.push(
+            "WARNING [HIGH]: You are committing to a Friday delivery\nWARNING: Check the discount\nWARNING [MEDIUM]:\n",
+        );
+        assert_eq!(parsed.len(), 2);
+        match &parsed[0] {
+            Suggestion::Warning { message, severity } => {
+                assert_eq!(message, "You are committing to a Friday delivery");
+                assert_eq!(*severity, WarningSeverity::High);
+            }
+            other => panic!("unexpected suggestion: {:?}", other),
+        }
+        match &parsed[1] {
+            Suggestion::Warning { severity, .. } => assert_eq!(*severity, WarningSeverity::Low),
+            other => panic!("unexpected suggestion: {:?}", other),
+        }
+    }
+
+    fn test_quick_response(phrases: &[&str], keywords: &[&str]) -> QuickResponse {
+        QuickResponse {
+            id: "qr_test".to_string(),
+            name: "Test".to_string(),
+            trigger_phrases: phrases.iter().map(|p| p.to_string()).collect(),
+            trigger_keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            category: "pricing".to_string(),
+            response_template: "Response".to_string(),
+            enabled: true,
+            created_at: 0,
+        }
+    }
+
+    fn context_with_history(current: &str, earlier: &[&str]) -> SuggestionContext {
+        SuggestionContext {
+            transcription: current.to_string(),
+            previous_context: String::new(),
+            recent_transcriptions: earlier.iter().map(|t| t.to_string()).collect(),
+            session_topic: None,
+            session_id: "session".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_keyword_triggers_match_whole_words() {
+        let qr = test_quick_response(&[], &["pricing"]);
+        assert!(match_triggers(&qr, "what does the pricing look like?").is_some());
+        assert!(match_triggers(&qr, "we discussed repricing last week").is_none());
+
+        // Phrases take precedence over keywords
+        let qr = test_quick_response(&["too expensive"], &["budget"]);
+        let (_, trigger) = match_triggers(&qr, "it is too expensive for our budget").unwrap();
+        assert_eq!(trigger, "too expensive");
+    }
+
+    #[test]
+    fn test_quick_responses_match_recent_segments_only() {
+        let responses = vec![test_quick_response(&["too expensive"], &[])];
+
+        let current = match_quick_responses(
+            &responses,
+            &context_with_history("this is too expensive", &[]),
+            2,
+        );
+        let earlier = match_quick_responses(
+            &responses,
+            &context_with_history("let's move on", &["this is too expensive"]),
+            2,
+        );
+        assert_eq!(current.len(), 1);
+        assert_eq!(earlier.len(), 1);
+        match (&current[0], &earlier[0]) {
+            (
+                Suggestion::QuickResponse { confidence: a, .. },
+                Suggestion::QuickResponse { confidence: b, .. },
+            ) => assert!(b < a),
+            _ => panic!("expected quick responses"),
+        }
+
+        // Outside the window the trigger no longer surfaces the response
+        let stale = match_quick_responses(
+            &responses,
+            &context_with_history("let's move on", &["this is too expensive", "next topic"]),
+            2,
+        );
+        assert!(stale.is_empty());
+    }
+
+    #[test]
+    fn test_throttle_is_suppressed_does_not_record() {
+        let mut throttle = SuggestionThrottle::default();
+        let settings = throttle_settings();
+        let now = Instant::now();
+        let suggestion = talking_point("Offer a payment plan");
+
+        assert!(!throttle.is_suppressed(&suggestion, &settings, now));
+        assert!(!throttle.is_suppressed(&suggestion, &settings, now));
+
+        throttle.filter(vec![suggestion.clone()], &settings, now);
+        assert!(throttle.is_suppressed(&suggestion, &settings, now));
+    }
+
     #[test]
     fn test_suggestion_confidence_ordering() {
         let suggestions = vec![
@@ -525,6 +1245,188 @@ RATIONALE: This can help overcome price objections"#;
         }
     }
 
+    fn talking_point(point: &str) -> Suggestion {
+        Suggestion::TalkingPoint {
+            point: point.to_string(),
+            rationale: String::new(),
+            confidence: 0.7,
+        }
+    }
+
+    fn quick_response(category: &str, text: &str) -> Suggestion {
+        Suggestion::QuickResponse {
+            id: text.to_string(),
+            text: text.to_string(),
+            confidence: 0.8,
+            category: category.to_string(),
+            trigger_phrase: "test".to_string(),
+        }
+    }
+
+    fn throttle_settings() -> SuggestionsSettings {
+        SuggestionsSettings {
+            category_cooldown_seconds: 0,
+            max_suggestions_per_minute: 0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_throttle_drops_near_duplicates() {
+        let mut throttle = SuggestionThrottle::default();
+        let settings = throttle_settings();
+        let now = Instant::now();
+
+        let first = throttle.filter(
+            vec![talking_point(
+                "Offer a payment plan to ease budget concerns",
+            )],
+            &settings,
+            now,
+        );
+        assert_eq!(first.len(), 1);
+
+        let repeat = throttle.filter(
+            vec![talking_point(
+                "Offer a payment plan to ease the budget concerns",
+            )],
+            &settings,
+            now + Duration::from_secs(15),
+        );
+        assert!(repeat.is_empty());
+
+        let different = throttle.filter(
+            vec![talking_point("Ask who else is involved in the decision")],
+            &settings,
+            now + Duration::from_secs(15),
+        );
+        assert_eq!(different.len(), 1);
+
+        // Forgotten once the dedup window has passed
+        let later = throttle.filter(
+            vec![talking_point(
+                "Offer a payment plan to ease budget concerns",
+            )],
+            &settings,
+            now + Duration::from_secs(settings.dedup_window_seconds as u64 + 1),
+        );
+        assert_eq!(later.len(), 1);
+    }
+
+    #[test]
+    fn test_throttle_category_cooldown() {
+        let mut throttle = SuggestionThrottle::default();
+        let settings = SuggestionsSettings {
+            category_cooldown_seconds: 30,
+            ..throttle_settings()
+        };
+        let now = Instant::now();
+
+        throttle.filter(
+            vec![quick_response("pricing", "Discuss ROI")],
+            &settings,
+            now,
+        );
+        let cooling = throttle.filter(
+            vec![
+                quick_response("pricing", "Mention the annual discount"),
+                quick_response("timing", "Ask about their timeline"),
+            ],
+            &settings,
+            now + Duration::from_secs(10),
+        );
+        assert_eq!(cooling.len(), 1);
+        assert_eq!(cooling[0].category(), "timing");
+
+        let cooled = throttle.filter(
+            vec![quick_response("pricing", "Mention the annual discount")],
+            &settings,
+            now + Duration::from_secs(31),
+        );
+        assert_eq!(cooled.len(), 1);
+    }
+
+    #[test]
+    fn test_throttle_max_per_minute() {
+        let mut throttle = SuggestionThrottle::default();
+        let settings = SuggestionsSettings {
+            max_suggestions_per_minute: 2,
+            ..throttle_settings()
+        };
+        let now = Instant::now();
+
+        let first = throttle.filter(
+            vec![
+                talking_point("Summarize the agreed next steps"),
+                talking_point("Confirm the delivery date"),
+                talking_point("Ask about security requirements"),
+            ],
+            &settings,
+            now,
+        );
+        assert_eq!(first.len(), 2);
+
+        let limited = throttle.filter(
+            vec![talking_point("Introduce the onboarding team")],
+            &settings,
+            now + Duration::from_secs(30),
+        );
+        assert!(limited.is_empty());
+
+        let next_minute = throttle.filter(
+            vec![talking_point("Introduce the onboarding team")],
+            &settings,
+            now + Duration::from_secs(61),
+        );
+        assert_eq!(next_minute.len(), 1);
+    }
+
+    fn feedback(
+        category: &str,
+        source: Option<&str>,
+        accepted: bool,
+        timestamp: i64,
+    ) -> SuggestionFeedback {
+        SuggestionFeedback {
+            category: category.to_string(),
+            source: source.map(|s| s.to_string()),
+            accepted,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn test_feedback_weights_boost_accepted_and_penalize_rejected() {
+        let now = 1_700_000_000;
+        let weights = FeedbackWeights::from_feedback(
+            &[
+                feedback("pricing", Some("qr_too_expensive"), true, now),
+                feedback("pricing", Some("qr_too_expensive"), true, now),
+                feedback("talking_point", None, false, now),
+                feedback("talking_point", None, false, now),
+            ],
+            now,
+        );
+
+        let pricing = quick_response("pricing", "Discuss ROI");
+        assert!(weights.adjustment(&pricing) > 0.0);
+        assert!(weights.adjustment(&talking_point("Ask a question")) < 0.0);
+        assert_eq!(weights.category_adjustment("timing"), 0.0);
+        assert!(weights.adjustment(&pricing) <= 2.0 * MAX_FEEDBACK_ADJUSTMENT);
+    }
+
+    #[test]
+    fn test_feedback_weights_decay_with_age() {
+        let now = 1_700_000_000;
+        let half_life = (FEEDBACK_HALF_LIFE_DAYS * 86_400.0) as i64;
+        let recent = FeedbackWeights::from_feedback(&[feedback("pricing", None, true, now)], now);
+        let old = FeedbackWeights::from_feedback(
+            &[feedback("pricing", None, true, now - 2 * half_life)],
+            now,
+        );
+        assert!(recent.category_adjustment("pricing") > old.category_adjustment("pricing"));
+    }
+
     #[test]
     fn test_warning_severity_confidence() {
         // High severity warnings should have high confidence
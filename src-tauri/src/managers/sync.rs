@@ -0,0 +1,818 @@
+//! Folder Sync
+//!
+//! Keeps settings, vocabulary, prompts and quick responses in step across
+//! devices by reading and writing a single bundle file in a user-chosen folder
+//! (Dropbox, iCloud Drive, Syncthing, ...). The bundle and the local state are
+//! polled every few seconds, and whichever side changed is synced.
+//!
+//! Merging is three-way against the last synced state: an item changed on only
+//! one side takes that side's value, and an item changed on both sides is
+//! resolved by the configured conflict policy. Only settings on an allowlist
+//! are synced, so secrets and device-specific settings never leave the device.
+
+use crate::access_policy;
+use crate::events::SettingsSynced;
+use crate::managers::suggestion_engine::SuggestionEngine;
+use crate::managers::vocabulary::{VocabularyEntry, VocabularyManager};
+use crate::settings::{
+    get_settings, write_settings, AppSettings, SyncConflictPolicy, PRIVATE_SETTINGS,
+};
+use chrono::Utc;
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use specta::Type;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+/// How often the sync folder and local state are polled for changes
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+const BUNDLE_FILE: &str = "dictum-sync.json";
+const BUNDLE_VERSION: u32 = 1;
+
+/// Top-level settings that are synced. Anything else, including settings
+/// added later, stays on this device until it's listed here.
+const SYNCED_KEYS: &[&str] = &[
+    "general",
+    "audio_feedback",
+    "audio_feedback_volume",
+    "sound_theme",
+    "always_on_microphone",
+    "translate_to_english",
+    "selected_language",
+    "overlay_position",
+    "custom_words",
+    "model_unload_timeout",
+    "word_correction_threshold",
+    "history_limit",
+    "recording_retention_period",
+    "history_max_size_mb",
+    "history_trash_retention_days",
+    "history_dedup",
+    "paste_method",
+    "paste_delay_ms",
+    "clipboard_handling",
+    "post_process_enabled",
+    "post_process_provider_id",
+    "post_process_models",
+    "post_process_selected_prompt_id",
+    "post_process_routes",
+    "active_listening",
+    "diarization",
+    "ask_ai",
+    "knowledge_base",
+    "suggestions",
+    "sound_detection",
+    "noise_suppression",
+    "notifications",
+    "clipboard_watch",
+    "export",
+    "schedule",
+    "paragraphing",
+    "translation",
+    "code_dictation",
+    "voice_commands",
+    "formatting",
+    "transcribe_profiles",
+    "wake_word",
+];
+
+/// Nested settings that are secret, only make sense on this device, or are
+/// synced as their own section
+const EXCLUDED_NESTED_KEYS: &[(&str, &str)] = &[
+    ("general", "start_hidden"),
+    ("general", "autostart_enabled"),
+    ("general", "update_rollout_bucket"),
+    ("general", "voice_profile"),
+    ("active_listening", "prompts"),
+    ("diarization", "model_path"),
+    ("ask_ai", "window_width"),
+    ("ask_ai", "window_height"),
+    ("ask_ai", "window_x"),
+    ("ask_ai", "window_y"),
+    ("suggestions", "quick_responses"),
+    ("suggestions", "warning_webhook_url"),
+    ("export", "webhook_url"),
+    ("wake_word", "model_path"),
+];
+
+type Section = BTreeMap<String, Value>;
+
+/// The synced state, split into keyed sections so each item merges on its own
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+struct SyncSections {
+    #[serde(default)]
+    settings: Section,
+    #[serde(default)]
+    vocabulary: Section,
+    #[serde(default)]
+    post_process_prompts: Section,
+    #[serde(default)]
+    active_listening_prompts: Section,
+    #[serde(default)]
+    quick_responses: Section,
+}
+
+/// File written to the sync folder
+#[derive(Debug, Serialize, Deserialize)]
+struct SyncBundle {
+    version: u32,
+    device_id: String,
+    updated_at: i64,
+    #[serde(flatten)]
+    sections: SyncSections,
+}
+
+/// Sync bookkeeping persisted in the app data dir
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SyncState {
+    device_id: String,
+    /// Sections as of the last successful sync; the merge base
+    base: Option<SyncSections>,
+    last_synced_at: Option<i64>,
+    /// Modification time of the bundle after our last read or write
+    bundle_modified_ms: Option<u128>,
+}
+
+/// Outcome of a sync
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct SyncReport {
+    /// Items updated locally from the remote bundle
+    pub pulled: usize,
+    /// Whether the bundle was rewritten
+    pub pushed: bool,
+    /// Items changed on both sides and resolved by the conflict policy
+    pub conflicts: usize,
+}
+
+/// Current sync state for the settings UI
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct SyncStatus {
+    pub enabled: bool,
+    pub last_synced_at: Option<i64>,
+    pub last_error: Option<String>,
+}
+
+/// Key array items by their `id` field
+fn keyed_by_id(items: Option<&Value>) -> Section {
+    items
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| {
+                    let id = item.get("id")?.as_str()?.to_string();
+                    Some((id, item.clone()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The settings that are synced, without excluded nested keys
+fn synced_settings<'a>(settings: impl IntoIterator<Item = (&'a String, &'a Value)>) -> Section {
+    let mut synced = Section::new();
+    for (key, value) in settings {
+        if !SYNCED_KEYS.contains(&key.as_str()) || PRIVATE_SETTINGS.contains(&key.as_str()) {
+            continue;
+        }
+        let mut value = value.clone();
+        for (parent, child) in EXCLUDED_NESTED_KEYS {
+            if parent == key {
+                if let Some(nested) = value.as_object_mut() {
+                    nested.remove(*child);
+                }
+            }
+        }
+        synced.insert(key.clone(), value);
+    }
+    synced
+}
+
+/// Build the synced sections from local settings and vocabulary
+fn collect_sections(settings: &Value, vocabulary: &[VocabularyEntry]) -> SyncSections {
+    SyncSections {
+        settings: settings
+            .as_object()
+            .map(synced_settings)
+            .unwrap_or_default(),
+        vocabulary: vocabulary
+            .iter()
+            .map(|entry| (entry.term.clone(), json!({ "category": entry.category })))
+            .collect(),
+        post_process_prompts: keyed_by_id(settings.get("post_process_prompts")),
+        active_listening_prompts: keyed_by_id(
+            settings
+                .get("active_listening")
+                .and_then(|a| a.get("prompts")),
+        ),
+        quick_responses: keyed_by_id(
+            settings
+                .get("suggestions")
+                .and_then(|s| s.get("quick_responses")),
+        ),
+    }
+}
+
+/// Drop the settings a bundle shouldn't carry, so one written by an older
+/// version or edited in the sync folder can't set unsynced keys
+fn remote_sections(sections: SyncSections) -> SyncSections {
+    SyncSections {
+        settings: synced_settings(&sections.settings),
+        ..sections
+    }
+}
+
+/// Three-way merge of one section. Returns the merged section and the number of conflicts.
+fn merge_section(
+    local: &Section,
+    remote: &Section,
+    base: &Section,
+    policy: SyncConflictPolicy,
+) -> (Section, usize) {
+    let keys: BTreeSet<&String> = local
+        .keys()
+        .chain(remote.keys())
+        .chain(base.keys())
+        .collect();
+    let mut merged = Section::new();
+    let mut conflicts = 0;
+
+    for key in keys {
+        let (l, r, b) = (local.get(key), remote.get(key), base.get(key));
+        let value = if l == r || r == b {
+            l
+        } else if l == b {
+            r
+        } else {
+            conflicts += 1;
+            match policy {
+                SyncConflictPolicy::PreferLocal => l,
+                SyncConflictPolicy::PreferRemote => r,
+            }
+        };
+
+        if let Some(value) = value {
+            merged.insert(key.clone(), value.clone());
+        }
+    }
+
+    (merged, conflicts)
+}
+
+fn merge_sections(
+    local: &SyncSections,
+    remote: &SyncSections,
+    base: &SyncSections,
+    policy: SyncConflictPolicy,
+) -> (SyncSections, usize) {
+    let (settings, c1) = merge_section(&local.settings, &remote.settings, &base.settings, policy);
+    let (vocabulary, c2) = merge_section(
+        &local.vocabulary,
+        &remote.vocabulary,
+        &base.vocabulary,
+        policy,
+    );
+    let (post_process_prompts, c3) = merge_section(
+        &local.post_process_prompts,
+        &remote.post_process_prompts,
+        &base.post_process_prompts,
+        policy,
+    );
+    let (active_listening_prompts, c4) = merge_section(
+        &local.active_listening_prompts,
+        &remote.active_listening_prompts,
+        &base.active_listening_prompts,
+        policy,
+    );
+    let (quick_responses, c5) = merge_section(
+        &local.quick_responses,
+        &remote.quick_responses,
+        &base.quick_responses,
+        policy,
+    );
+
+    (
+        SyncSections {
+            settings,
+            vocabulary,
+            post_process_prompts,
+            active_listening_prompts,
+            quick_responses,
+        },
+        c1 + c2 + c3 + c4 + c5,
+    )
+}
+
+/// Number of items that differ between two sections
+fn count_changes(from: &Section, to: &Section) -> usize {
+    from.keys()
+        .chain(to.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter(|key| from.get(*key) != to.get(*key))
+        .count()
+}
+
+/// Rebuild an id-keyed array, keeping the existing order and appending new items
+fn ordered_items(existing: Option<&Value>, section: &Section) -> Value {
+    let mut items = Vec::new();
+    let mut seen = BTreeSet::new();
+    if let Some(existing) = existing.and_then(|v| v.as_array()) {
+        for item in existing {
+            if let Some(id) = item.get("id").and_then(|id| id.as_str()) {
+                if let Some(value) = section.get(id) {
+                    items.push(value.clone());
+                    seen.insert(id.to_string());
+                }
+            }
+        }
+    }
+    for (id, value) in section {
+        if !seen.contains(id) {
+            items.push(value.clone());
+        }
+    }
+    Value::Array(items)
+}
+
+/// Write merged sections into the local settings JSON, leaving unsynced keys untouched
+fn apply_to_settings(settings: &mut Value, merged: &SyncSections) {
+    let Some(object) = settings.as_object_mut() else {
+        return;
+    };
+
+    for (key, value) in &merged.settings {
+        let nested_excluded = EXCLUDED_NESTED_KEYS.iter().any(|(parent, _)| parent == key);
+        match (object.get_mut(key), value.as_object()) {
+            (Some(Value::Object(local)), Some(remote)) if nested_excluded => {
+                for (field, field_value) in remote {
+                    local.insert(field.clone(), field_value.clone());
+                }
+            }
+            _ => {
+                object.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    let prompts = ordered_items(
+        object.get("post_process_prompts"),
+        &merged.post_process_prompts,
+    );
+    object.insert("post_process_prompts".to_string(), prompts);
+
+    if let Some(Value::Object(active_listening)) = object.get_mut("active_listening") {
+        let prompts = ordered_items(
+            active_listening.get("prompts"),
+            &merged.active_listening_prompts,
+        );
+        active_listening.insert("prompts".to_string(), prompts);
+    }
+
+    if let Some(Value::Object(suggestions)) = object.get_mut("suggestions") {
+        let quick_responses =
+            ordered_items(suggestions.get("quick_responses"), &merged.quick_responses);
+        suggestions.insert("quick_responses".to_string(), quick_responses);
+    }
+}
+
+fn modified_ms(path: &Path) -> Option<u128> {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_millis())
+}
+
+pub struct SyncManager {
+    app_handle: AppHandle,
+    state_path: PathBuf,
+    state: Mutex<SyncState>,
+    last_error: Mutex<Option<String>>,
+    /// Serializes sync runs between the poller and the sync_now command
+    running: tokio::sync::Mutex<()>,
+}
+
+impl SyncManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self, String> {
+        let state_path = crate::portable::app_data_dir(app_handle)
+            .map_err(|e| format!("Failed to get app data dir: {}", e))?
+            .join("sync_state.json");
+
+        let mut state: SyncState = fs::read(&state_path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+        if state.device_id.is_empty() {
+            state.device_id = uuid::Uuid::new_v4().to_string();
+        }
+
+        Ok(Self {
+            app_handle: app_handle.clone(),
+            state_path,
+            state: Mutex::new(state),
+            last_error: Mutex::new(None),
+            running: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    /// Start polling the sync folder and local state for changes
+    pub fn start(self: &Arc<Self>) {
+        let manager = self.clone();
+        tauri::async_runtime::spawn(async move {
+            loop {
+                tokio::time::sleep(POLL_INTERVAL).await;
+                if !get_settings(&manager.app_handle).sync.enabled {
+                    continue;
+                }
+
+                match manager.has_changes() {
+                    Ok(true) => {
+                        if let Err(e) = manager.sync_now().await {
+                            warn!("Background sync failed: {}", e);
+                        }
+                    }
+                    Ok(false) => {}
+                    Err(e) => debug!("Skipping sync check: {}", e),
+                }
+            }
+        });
+    }
+
+    pub fn status(&self) -> SyncStatus {
+        SyncStatus {
+            enabled: get_settings(&self.app_handle).sync.enabled,
+            last_synced_at: self.state.lock().ok().and_then(|s| s.last_synced_at),
+            last_error: self.last_error.lock().ok().and_then(|e| e.clone()),
+        }
+    }
+
+    fn bundle_path(&self, settings: &AppSettings) -> Result<PathBuf, String> {
+        settings
+            .sync
+            .folder
+            .as_deref()
+            .filter(|folder| !folder.trim().is_empty())
+            .map(|folder| Path::new(folder).join(BUNDLE_FILE))
+            .ok_or_else(|| "No sync folder configured".to_string())
+            .and_then(|path| access_policy::check_path(&path).map(|_| path))
+    }
+
+    fn local_sections(&self, settings: &AppSettings) -> Result<SyncSections, String> {
+        let settings_value = serde_json::to_value(settings).map_err(|e| e.to_string())?;
+        let vocabulary = self
+            .app_handle
+            .state::<Mutex<VocabularyManager>>()
+            .lock()
+            .map_err(|e| format!("Failed to lock vocabulary manager: {}", e))?
+            .get_vocabulary()?;
+        Ok(collect_sections(&settings_value, &vocabulary))
+    }
+
+    /// Whether the remote bundle or the local state changed since the last sync
+    fn has_changes(&self) -> Result<bool, String> {
+        let settings = get_settings(&self.app_handle);
+        let bundle_path = self.bundle_path(&settings)?;
+        let local = self.local_sections(&settings)?;
+
+        let state = self.state.lock().map_err(|e| e.to_string())?;
+        let remote_changed = modified_ms(&bundle_path) != state.bundle_modified_ms;
+        let local_changed = state.base.as_ref() != Some(&local);
+        Ok(remote_changed || local_changed)
+    }
+
+    /// Merge the local state with the sync bundle and write the result to both sides
+    pub async fn sync_now(&self) -> Result<SyncReport, String> {
+        let _running = self.running.lock().await;
+        let result = self.run_sync().await;
+        if let Ok(mut last_error) = self.last_error.lock() {
+            *last_error = result.as_ref().err().cloned();
+        }
+        result
+    }
+
+    async fn run_sync(&self) -> Result<SyncReport, String> {
+        let settings = get_settings(&self.app_handle);
+        if !settings.sync.enabled {
+            return Err("Sync is disabled".to_string());
+        }
+        let bundle_path = self.bundle_path(&settings)?;
+        let local = self.local_sections(&settings)?;
+
+        let remote = match fs::read(&bundle_path) {
+            Ok(bytes) => {
+                let bundle: SyncBundle = serde_json::from_slice(&bytes)
+                    .map_err(|e| format!("Sync bundle is unreadable: {}", e))?;
+                if bundle.version > BUNDLE_VERSION {
+                    return Err("Sync bundle was written by a newer version".to_string());
+                }
+                Some(remote_sections(bundle.sections))
+            }
+            Err(_) => None,
+        };
+
+        let (base, device_id) = {
+            let state = self.state.lock().map_err(|e| e.to_string())?;
+            (
+                state.base.clone().unwrap_or_default(),
+                state.device_id.clone(),
+            )
+        };
+
+        let (merged, conflicts) = match &remote {
+            Some(remote) => merge_sections(&local, remote, &base, settings.sync.conflict_policy),
+            None => (local.clone(), 0),
+        };
+
+        let pulled = count_changes(&local.settings, &merged.settings)
+            + count_changes(&local.vocabulary, &merged.vocabulary)
+            + count_changes(&local.post_process_prompts, &merged.post_process_prompts)
+            + count_changes(
+                &local.active_listening_prompts,
+                &merged.active_listening_prompts,
+            )
+            + count_changes(&local.quick_responses, &merged.quick_responses);
+        if pulled > 0 {
+            self.apply_locally(settings, &local, &merged).await?;
+        }
+
+        let pushed = remote.as_ref() != Some(&merged);
+        if pushed {
+            let bundle = SyncBundle {
+                version: BUNDLE_VERSION,
+                device_id,
+                updated_at: Utc::now().timestamp(),
+                sections: merged.clone(),
+            };
+            let json = serde_json::to_vec_pretty(&bundle).map_err(|e| e.to_string())?;
+            let tmp_path = bundle_path.with_extension("json.tmp");
+            fs::write(&tmp_path, json)
+                .and_then(|_| fs::rename(&tmp_path, &bundle_path))
+                .map_err(|e| format!("Failed to write sync bundle: {}", e))?;
+        }
+
+        {
+            let mut state = self.state.lock().map_err(|e| e.to_string())?;
+            state.base = Some(merged);
+            state.last_synced_at = Some(Utc::now().timestamp());
+            state.bundle_modified_ms = modified_ms(&bundle_path);
+            match serde_json::to_vec(&*state) {
+                Ok(json) => {
+                    if let Err(e) = fs::write(&self.state_path, json) {
+                        error!("Failed to save sync state: {}", e);
+                    }
+                }
+                Err(e) => error!("Failed to serialize sync state: {}", e),
+            }
+        }
+
+        if conflicts > 0 {
+            info!("Sync resolved {} conflicts", conflicts);
+        }
+        debug!("Sync complete: pulled {}, pushed {}", pulled, pushed);
+
+        Ok(SyncReport {
+            pulled,
+            pushed,
+            conflicts,
+        })
+    }
+
+    async fn apply_locally(
+        &self,
+        settings: AppSettings,
+        local: &SyncSections,
+        merged: &SyncSections,
+    ) -> Result<(), String> {
+        let mut settings_value = serde_json::to_value(&settings).map_err(|e| e.to_string())?;
+        apply_to_settings(&mut settings_value, merged);
+        let updated: AppSettings = serde_json::from_value(settings_value)
+            .map_err(|e| format!("Synced settings are invalid: {}", e))?;
+        let suggestions = updated.suggestions.clone();
+        write_settings(&self.app_handle, updated);
+
+        if let Some(engine) = self.app_handle.try_state::<SuggestionEngine>() {
+            engine.update_settings(suggestions).await;
+        }
+
+        if local.vocabulary != merged.vocabulary {
+            let vocabulary = self.app_handle.state::<Mutex<VocabularyManager>>();
+            let manager = vocabulary
+                .lock()
+                .map_err(|e| format!("Failed to lock vocabulary manager: {}", e))?;
+            for term in local.vocabulary.keys() {
+                if !merged.vocabulary.contains_key(term) {
+                    manager.remove_term_by_text(term)?;
+                }
+            }
+            for (term, value) in &merged.vocabulary {
+                if local.vocabulary.get(term) != Some(value) {
+                    let category = value.get("category").and_then(|c| c.as_str());
+                    manager.upsert_synced_term(term, category)?;
+                }
+            }
+        }
+
+        let _ = SettingsSynced.emit(&self.app_handle);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(items: &[(&str, Value)]) -> Section {
+        items
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_takes_one_sided_changes() {
+        let base = section(&[("a", json!(1)), ("b", json!(1)), ("c", json!(1))]);
+        // Local changed a and deleted c; remote changed b and added d
+        let local = section(&[("a", json!(2)), ("b", json!(1))]);
+        let remote = section(&[
+            ("a", json!(1)),
+            ("b", json!(3)),
+            ("c", json!(1)),
+            ("d", json!(4)),
+        ]);
+
+        let (merged, conflicts) =
+            merge_section(&local, &remote, &base, SyncConflictPolicy::PreferLocal);
+        assert_eq!(conflicts, 0);
+        assert_eq!(
+            merged,
+            section(&[("a", json!(2)), ("b", json!(3)), ("d", json!(4))])
+        );
+    }
+
+    #[test]
+    fn test_merge_conflicts_follow_policy() {
+        let base = section(&[("a", json!(1))]);
+        let local = section(&[("a", json!(2))]);
+        let remote = section(&[("a", json!(3))]);
+
+        let (merged, conflicts) =
+            merge_section(&local, &remote, &base, SyncConflictPolicy::PreferLocal);
+        assert_eq!(conflicts, 1);
+        assert_eq!(merged["a"], json!(2));
+
+        let (merged, _) = merge_section(&local, &remote, &base, SyncConflictPolicy::PreferRemote);
+        assert_eq!(merged["a"], json!(3));
+    }
+
+    #[test]
+    fn test_collect_sections_excludes_secrets_and_local_keys() {
+        let settings = json!({
+            "post_process_api_keys": { "openai": "sk-secret" },
+            "selected_microphone": "USB Mic",
+            "custom_words": ["Dictum"],
+            "post_process_prompts": [{ "id": "p1", "name": "Fix", "prompt": "..." }],
+            "suggestions": {
+                "enabled": true,
+                "warning_webhook_url": "https://hooks.example.com/x",
+                "quick_responses": [{ "id": "q1", "name": "Thanks" }]
+            },
+            "transcription_backend": {
+                "default_backend": "openai",
+                "api_keys": { "openai": "sk-secret" }
+            },
+            "webhooks": {
+                "enabled": true,
+                "url": "https://hooks.example.com/y",
+                "auth_header": "Bearer secret"
+            },
+            "api_server": { "enabled": true, "port": 7777, "token": "secret" },
+            "remote_control": {
+                "enabled": true,
+                "devices": [{ "id": "d1", "name": "Phone", "token": "secret" }]
+            },
+            "browser_bridge": { "enabled": true, "token": "secret" }
+        });
+
+        let sections = collect_sections(&settings, &[]);
+        assert!(!sections.settings.contains_key("post_process_api_keys"));
+        assert!(!sections.settings.contains_key("selected_microphone"));
+        assert!(!sections.settings.contains_key("remote_control"));
+        assert_eq!(sections.settings["custom_words"], json!(["Dictum"]));
+        assert_eq!(sections.settings["suggestions"], json!({ "enabled": true }));
+        assert!(!sections.settings.contains_key("transcription_backend"));
+        assert!(!sections.settings.contains_key("webhooks"));
+        assert!(!sections.settings.contains_key("api_server"));
+        assert!(!sections.settings.contains_key("browser_bridge"));
+        assert!(sections.post_process_prompts.contains_key("p1"));
+        assert!(sections.quick_responses.contains_key("q1"));
+    }
+
+    #[test]
+    fn test_synced_keys_are_settings_and_not_private() {
+        let defaults = serde_json::to_value(crate::settings::get_default_settings()).unwrap();
+        for key in SYNCED_KEYS {
+            assert!(defaults.get(key).is_some(), "{} is not a setting", key);
+            assert!(!PRIVATE_SETTINGS.contains(key), "{} is private", key);
+        }
+    }
+
+    #[test]
+    fn test_unlisted_settings_are_not_synced() {
+        let settings = json!({
+            "custom_words": ["Dictum"],
+            "some_new_setting": { "token": "secret" }
+        });
+        let sections = collect_sections(&settings, &[]);
+        assert_eq!(
+            sections.settings,
+            section(&[("custom_words", json!(["Dictum"]))])
+        );
+    }
+
+    #[test]
+    fn test_pulled_automation_hooks_are_ignored() {
+        let mut settings = json!({
+            "custom_words": [],
+            "automation_hooks": { "enabled": false, "hooks": [] }
+        });
+        let remote = remote_sections(SyncSections {
+            settings: section(&[
+                ("custom_words", json!(["Dictum"])),
+                (
+                    "automation_hooks",
+                    json!({
+                        "enabled": true,
+                        "hooks": [{ "id": "h1", "command": "sh", "args": ["-c", "id"] }]
+                    }),
+                ),
+            ]),
+            ..SyncSections::default()
+        });
+
+        let local = collect_sections(&settings, &[]);
+        assert!(!local.settings.contains_key("automation_hooks"));
+        let (merged, _) = merge_sections(
+            &local,
+            &remote,
+            &SyncSections::default(),
+            SyncConflictPolicy::PreferRemote,
+        );
+        apply_to_settings(&mut settings, &merged);
+        assert_eq!(settings["custom_words"], json!(["Dictum"]));
+        assert_eq!(
+            settings["automation_hooks"],
+            json!({ "enabled": false, "hooks": [] })
+        );
+    }
+
+    #[test]
+    fn test_apply_to_settings_keeps_local_only_fields() {
+        let mut settings = json!({
+            "selected_microphone": "USB Mic",
+            "custom_words": [],
+            "post_process_prompts": [{ "id": "p1" }, { "id": "p2" }],
+            "suggestions": {
+                "enabled": false,
+                "warning_webhook_url": "https://hooks.example.com/x",
+                "quick_responses": []
+            }
+        });
+        let merged = SyncSections {
+            settings: section(&[
+                ("custom_words", json!(["Dictum"])),
+                ("suggestions", json!({ "enabled": true })),
+            ]),
+            post_process_prompts: section(&[
+                ("p2", json!({ "id": "p2" })),
+                ("p3", json!({ "id": "p3" })),
+            ]),
+            quick_responses: section(&[("q1", json!({ "id": "q1" }))]),
+            ..SyncSections::default()
+        };
+
+        apply_to_settings(&mut settings, &merged);
+        assert_eq!(settings["selected_microphone"], json!("USB Mic"));
+        assert_eq!(settings["custom_words"], json!(["Dictum"]));
+        assert_eq!(settings["suggestions"]["enabled"], json!(true));
+        assert_eq!(
+            settings["suggestions"]["warning_webhook_url"],
+            json!("https://hooks.example.com/x")
+        );
+        assert_eq!(
+            settings["suggestions"]["quick_responses"],
+            json!([{ "id": "q1" }])
+        );
+        assert_eq!(
+            settings["post_process_prompts"],
+            json!([{ "id": "p2" }, { "id": "p3" }])
+        );
+    }
+}
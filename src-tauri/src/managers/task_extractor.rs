@@ -1,10 +1,44 @@
-use log::{debug, info};
+use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
 use specta::Type;
-use tauri::AppHandle;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
 
+use crate::managers::active_listening::ActiveListeningSession;
+use crate::managers::history::HistoryManager;
+use crate::managers::rag::RagManager;
 use crate::ollama_client::OllamaClient;
 
+/// Open items not discussed for this long are reported as stale
+const STALE_AFTER_DAYS: i64 = 14;
+
+/// Share of an item's keywords a segment must contain to mention it
+const KEYWORD_MATCH_THRESHOLD: f32 = 0.6;
+
+/// Embedding similarity above which a segment mentions an item
+const EMBEDDING_MATCH_THRESHOLD: f32 = 0.8;
+
+/// Phrases that say an item has been finished
+const DONE_PHRASES: &[&str] = &[
+    "is done",
+    "are done",
+    "got done",
+    "finished",
+    "completed",
+    "took care of",
+    "taken care of",
+    "already sent",
+    "shipped",
+    "wrapped up",
+];
+
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "with", "that", "this", "from", "into", "about", "will", "should", "need",
+    "needs", "our", "their", "them", "you", "your", "have", "has", "are", "was", "were", "but",
+    "not", "all", "any", "can", "out", "off", "its", "his", "her", "who", "what", "when",
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct ActionItem {
     pub id: i64,
@@ -15,6 +49,51 @@ pub struct ActionItem {
     pub priority: String,
     pub completed: bool,
     pub created_at: String,
+    /// Later sessions that discussed this item, oldest first
+    #[serde(default)]
+    pub mentions: Vec<ActionItemMention>,
+    /// Open and not discussed for `STALE_AFTER_DAYS`
+    #[serde(default)]
+    pub stale: bool,
+}
+
+/// What a session said about an action item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum MentionProgress {
+    Mentioned,
+    Done,
+}
+
+impl MentionProgress {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MentionProgress::Mentioned => "mentioned",
+            MentionProgress::Done => "done",
+        }
+    }
+
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "done" => MentionProgress::Done,
+            _ => MentionProgress::Mentioned,
+        }
+    }
+}
+
+/// A later session that discussed an action item
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ActionItemMention {
+    pub id: i64,
+    pub action_item_id: i64,
+    pub session_id: String,
+    /// The segment that mentioned the item
+    pub excerpt: String,
+    /// How closely the segment matched the item (0-1)
+    pub similarity: f32,
+    pub progress: MentionProgress,
+    /// Unix timestamp of the segment in milliseconds
+    pub mentioned_at: i64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -50,10 +129,9 @@ pub async fn extract_action_items_standalone(
     entry_id: i64,
 ) -> Result<Vec<ActionItem>, String> {
     let settings = crate::settings::get_settings(app);
-    let ollama_url = &settings.active_listening.ollama_base_url;
     let model = &settings.active_listening.ollama_model;
 
-    let client = OllamaClient::new(ollama_url)
+    let client = OllamaClient::for_active_listening(&settings.active_listening)
         .map_err(|e| format!("Failed to create Ollama client: {}", e))?;
 
     let health = client
@@ -128,6 +206,8 @@ Transcript:
                     .to_lowercase(),
                 completed: false,
                 created_at: chrono::Utc::now().to_rfc3339(),
+                mentions: Vec::new(),
+                stale: false,
             })
         })
         .collect();
@@ -154,3 +234,262 @@ fn extract_json_array(text: &str) -> Option<String> {
     }
     None
 }
+
+/// `created_at` is RFC 3339 for extracted items and SQLite's `datetime('now')`
+/// format for rows written by older versions
+fn created_at_ms(created_at: &str) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc3339(created_at)
+        .map(|date| date.timestamp_millis())
+        .or_else(|_| {
+            chrono::NaiveDateTime::parse_from_str(created_at, "%Y-%m-%d %H:%M:%S")
+                .map(|date| date.and_utc().timestamp_millis())
+        })
+        .ok()
+}
+
+/// Whether an open item has gone `STALE_AFTER_DAYS` without being discussed
+pub fn is_stale(item: &ActionItem, now_ms: i64) -> bool {
+    if item.completed {
+        return false;
+    }
+    let last_activity = item
+        .mentions
+        .iter()
+        .map(|mention| mention.mentioned_at)
+        .chain(created_at_ms(&item.created_at))
+        .max();
+    last_activity.is_some_and(|at| now_ms - at > STALE_AFTER_DAYS * 24 * 60 * 60 * 1000)
+}
+
+fn keywords(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(|word| word.to_lowercase())
+        .filter(|word| word.chars().count() >= 3 && !STOPWORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Share of the item's keywords found in the segment
+fn keyword_overlap(task: &str, segment: &str) -> f32 {
+    let task_words = keywords(task);
+    if task_words.len() < 2 {
+        return 0.0;
+    }
+    let segment_words = keywords(segment);
+    task_words.intersection(&segment_words).count() as f32 / task_words.len() as f32
+}
+
+fn detect_progress(segment: &str) -> MentionProgress {
+    let segment = segment.to_lowercase();
+    if DONE_PHRASES.iter().any(|phrase| segment.contains(phrase)) {
+        MentionProgress::Done
+    } else {
+        MentionProgress::Mentioned
+    }
+}
+
+/// The best matching segment for an item, as (segment index, similarity)
+fn best_match(
+    task: &str,
+    segments: &[&str],
+    embedding_scores: Option<&[f32]>,
+) -> Option<(usize, f32)> {
+    segments
+        .iter()
+        .enumerate()
+        .filter_map(|(index, segment)| {
+            let keyword_score = keyword_overlap(task, segment);
+            let embedding_score = embedding_scores.map_or(0.0, |scores| scores[index]);
+            if keyword_score >= KEYWORD_MATCH_THRESHOLD
+                || embedding_score >= EMBEDDING_MATCH_THRESHOLD
+            {
+                Some((index, keyword_score.max(embedding_score)))
+            } else {
+                None
+            }
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Embedding similarity of every item to every segment, or `None` when the
+/// knowledge base is off or the embeddings can't be generated
+async fn embedding_scores(
+    app: &AppHandle,
+    tasks: &[String],
+    segments: &[String],
+) -> Option<Vec<Vec<f32>>> {
+    let settings = crate::settings::get_settings(app);
+    let kb = &settings.knowledge_base;
+    if !kb.enabled {
+        return None;
+    }
+    let client = OllamaClient::new(&settings.active_listening.ollama_base_url).ok()?;
+
+    let mut inputs = tasks.to_vec();
+    inputs.extend(segments.iter().cloned());
+    let mut embeddings = Vec::with_capacity(inputs.len());
+    for batch in inputs.chunks(kb.embedding_batch_size.max(1)) {
+        match client
+            .generate_embeddings_batch(&kb.embedding_model, batch, kb.embedding_max_retries)
+            .await
+        {
+            Ok(batch) => embeddings.extend(batch),
+            Err(e) => {
+                warn!("Falling back to keyword matching for action items: {}", e);
+                return None;
+            }
+        }
+    }
+
+    let (task_embeddings, segment_embeddings) = embeddings.split_at(tasks.len());
+    Some(
+        task_embeddings
+            .iter()
+            .map(|task| {
+                segment_embeddings
+                    .iter()
+                    .map(|segment| RagManager::cosine_similarity(task, segment))
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+/// Link open action items from earlier sessions to the segments of `session`
+/// that discuss them. With `mark_progress`, items the session says are done
+/// are marked completed.
+pub async fn link_session_mentions(
+    app: &AppHandle,
+    session: &ActiveListeningSession,
+    mark_progress: bool,
+) -> Result<Vec<ActionItemMention>, String> {
+    let history_manager = app.state::<Arc<HistoryManager>>().inner().clone();
+    let items: Vec<ActionItem> = history_manager
+        .get_action_items(None)
+        .map_err(|e| format!("Failed to get action items: {}", e))?
+        .into_iter()
+        .filter(|item| {
+            !item.completed
+                && created_at_ms(&item.created_at).is_none_or(|at| at < session.started_at)
+        })
+        .collect();
+    let insights: Vec<_> = session
+        .insights
+        .iter()
+        .filter(|insight| !insight.transcription.trim().is_empty())
+        .collect();
+    if items.is_empty() || insights.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let tasks: Vec<String> = items.iter().map(|item| item.task.clone()).collect();
+    let segments: Vec<String> = insights
+        .iter()
+        .map(|insight| insight.transcription.clone())
+        .collect();
+    let scores = embedding_scores(app, &tasks, &segments).await;
+    let segment_refs: Vec<&str> = segments.iter().map(|segment| segment.as_str()).collect();
+
+    let mut mentions = Vec::new();
+    for (item_index, item) in items.iter().enumerate() {
+        let item_scores = scores.as_ref().map(|scores| scores[item_index].as_slice());
+        let Some((segment_index, similarity)) = best_match(&item.task, &segment_refs, item_scores)
+        else {
+            continue;
+        };
+
+        let mention = ActionItemMention {
+            id: 0,
+            action_item_id: item.id,
+            session_id: session.id.clone(),
+            excerpt: segments[segment_index].clone(),
+            similarity,
+            progress: detect_progress(&segments[segment_index]),
+            mentioned_at: insights[segment_index].timestamp,
+        };
+        let mention = history_manager
+            .upsert_action_item_mention(&mention)
+            .map_err(|e| format!("Failed to store action item mention: {}", e))?;
+
+        if mark_progress && mention.progress == MentionProgress::Done {
+            history_manager
+                .toggle_action_item(item.id, true)
+                .map_err(|e| format!("Failed to update action item: {}", e))?;
+        }
+        mentions.push(mention);
+    }
+
+    info!(
+        "Session {} discussed {} open action items",
+        session.id,
+        mentions.len()
+    );
+    Ok(mentions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(created_at: &str, completed: bool) -> ActionItem {
+        ActionItem {
+            id: 1,
+            entry_id: 1,
+            task: "Send the pricing proposal to Acme".to_string(),
+            assignee: None,
+            deadline: None,
+            priority: "medium".to_string(),
+            completed,
+            created_at: created_at.to_string(),
+            mentions: Vec::new(),
+            stale: false,
+        }
+    }
+
+    #[test]
+    fn test_segments_match_items_by_keywords() {
+        let segments = [
+            "Let's talk about the hiring plan.",
+            "I finished the Acme pricing proposal and sent it yesterday.",
+        ];
+        let (index, similarity) =
+            best_match("Send the pricing proposal to Acme", &segments, None).unwrap();
+        assert_eq!(index, 1);
+        assert!(similarity >= KEYWORD_MATCH_THRESHOLD);
+        assert_eq!(detect_progress(segments[index]), MentionProgress::Done);
+        assert_eq!(detect_progress(segments[0]), MentionProgress::Mentioned);
+
+        assert!(best_match("Book the offsite venue", &segments, None).is_none());
+        // A close embedding counts even without shared keywords
+        let scores = [0.2, 0.9];
+        assert_eq!(
+            best_match("Book the offsite venue", &segments, Some(&scores)).map(|m| m.0),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_open_items_go_stale_without_mentions() {
+        let day = 24 * 60 * 60 * 1000;
+        let created = created_at_ms("2024-03-01T09:00:00+00:00").unwrap();
+        assert_eq!(created_at_ms("2024-03-01 09:00:00"), Some(created));
+
+        let mut open = item("2024-03-01T09:00:00+00:00", false);
+        assert!(!is_stale(&open, created + 10 * day));
+        assert!(is_stale(&open, created + 20 * day));
+        assert!(!is_stale(
+            &item("2024-03-01 09:00:00", true),
+            created + 20 * day
+        ));
+
+        open.mentions.push(ActionItemMention {
+            id: 1,
+            action_item_id: 1,
+            session_id: "al_1".to_string(),
+            excerpt: String::new(),
+            similarity: 0.7,
+            progress: MentionProgress::Mentioned,
+            mentioned_at: created + 15 * day,
+        });
+        assert!(!is_stale(&open, created + 20 * day));
+    }
+}
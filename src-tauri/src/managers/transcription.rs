@@ -1,15 +1,25 @@
+pub mod backend;
+#[cfg(feature = "vosk")]
+mod vosk;
+
+use crate::audio_toolkit::audio::{GainNormalizer, Pause};
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
 use crate::audio_toolkit::{apply_custom_words, filter_transcription_output};
+use crate::events::{ModelStateChanged, TranscriptionPartial, TranscriptionProgress};
 use crate::managers::model::{EngineType, ModelManager};
-use crate::settings::{get_settings, ModelUnloadTimeout};
+use crate::settings::{get_settings, AppSettings, ModelUnloadTimeout};
 use crate::utils::lock::SafeLock;
 use anyhow::Result;
+use backend::{RemoteError, TranscriptionBackend};
 use log::{debug, error, info, warn};
-use serde::Serialize;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use std::time::{Duration, SystemTime};
-use tauri::{AppHandle, Emitter};
+use tauri::AppHandle;
+use tauri_specta::Event;
 use transcribe_rs::{
     engines::{
         moonshine::{ModelVariant, MoonshineEngine, MoonshineModelParams},
@@ -25,7 +35,20 @@ use transcribe_rs::{
     TranscriptionEngine,
 };
 
-#[derive(Clone, Debug, Serialize)]
+/// How often the live preview re-transcribes the recording
+const PREVIEW_INTERVAL: Duration = Duration::from_millis(1500);
+/// Most recent audio the live preview transcribes, in seconds
+const PREVIEW_WINDOW_SECONDS: usize = 20;
+/// Audio needed before the first preview, in seconds
+const PREVIEW_MIN_SECONDS: f32 = 0.5;
+/// Recordings longer than this are transcribed in chunks, in seconds
+const CHUNKED_MIN_SECONDS: usize = 180;
+/// Longest chunk a long recording is split into, in seconds
+const MAX_CHUNK_SECONDS: usize = 60;
+/// Shortest chunk a long recording is split into at a pause, in seconds
+const MIN_CHUNK_SECONDS: usize = 20;
+
+#[derive(Clone, Debug, Serialize, specta::Type)]
 pub struct ModelStateEvent {
     pub event_type: String,
     pub model_id: Option<String>,
@@ -33,11 +56,231 @@ pub struct ModelStateEvent {
     pub error: Option<String>,
 }
 
+/// A word or segment of a transcript and when it was said, in seconds from
+/// the start of the recording
+#[derive(Clone, Debug, Serialize, Deserialize, specta::Type)]
+pub struct TranscriptSegment {
+    pub start: f32,
+    pub end: f32,
+    pub text: String,
+}
+
+/// A transcription with the timing of its words or segments. Engines and
+/// backends that don't report timing leave `segments` empty.
+#[derive(Clone, Debug, Default)]
+pub struct TimedTranscript {
+    pub text: String,
+    pub segments: Vec<TranscriptSegment>,
+    /// Language that was spoken, as detected or as the model was asked for
+    pub language: Option<String>,
+    /// Confidence from 0 to 1, from engines and services that report one
+    pub confidence: Option<f32>,
+}
+
+impl TimedTranscript {
+    /// Add the transcript of a later part of the recording, shifting its
+    /// timings by `offset` seconds, where the part starts
+    pub fn append(&mut self, part: TimedTranscript, offset: f32) {
+        self.language = self.language.take().or(part.language);
+        self.confidence = match (self.confidence, part.confidence) {
+            (Some(own), Some(other)) => {
+                Some(weighted_confidence((own, &self.text), (other, &part.text)))
+            }
+            (own, other) => own.or(other),
+        };
+        let text = part.text.trim();
+        if !text.is_empty() {
+            if !self.text.is_empty() {
+                self.text.push(' ');
+            }
+            self.text.push_str(text);
+        }
+        self.segments
+            .extend(part.segments.into_iter().map(|segment| TranscriptSegment {
+                start: segment.start + offset,
+                end: segment.end + offset,
+                text: segment.text,
+            }));
+    }
+}
+
 enum LoadedEngine {
     Whisper(WhisperEngine),
     Parakeet(ParakeetEngine),
     Moonshine(MoonshineEngine),
     SenseVoice(SenseVoiceEngine),
+    #[cfg(feature = "vosk")]
+    Vosk(vosk::VoskEngine),
+}
+
+impl LoadedEngine {
+    /// Load a model into the engine for its type
+    fn open(
+        engine_type: &EngineType,
+        model_id: &str,
+        model_path: &Path,
+    ) -> std::result::Result<Self, String> {
+        match engine_type {
+            EngineType::Whisper => {
+                let mut engine = WhisperEngine::new();
+                engine
+                    .load_model(model_path)
+                    .map_err(|e| format!("Failed to load whisper model {}: {}", model_id, e))?;
+                Ok(LoadedEngine::Whisper(engine))
+            }
+            EngineType::Parakeet => {
+                let mut engine = ParakeetEngine::new();
+                engine
+                    .load_model_with_params(model_path, ParakeetModelParams::int8())
+                    .map_err(|e| format!("Failed to load parakeet model {}: {}", model_id, e))?;
+                Ok(LoadedEngine::Parakeet(engine))
+            }
+            EngineType::Moonshine => {
+                let mut engine = MoonshineEngine::new();
+                engine
+                    .load_model_with_params(
+                        model_path,
+                        MoonshineModelParams::variant(ModelVariant::Base),
+                    )
+                    .map_err(|e| format!("Failed to load moonshine model {}: {}", model_id, e))?;
+                Ok(LoadedEngine::Moonshine(engine))
+            }
+            EngineType::SenseVoice => {
+                let mut engine = SenseVoiceEngine::new();
+                engine
+                    .load_model_with_params(model_path, SenseVoiceModelParams::int8())
+                    .map_err(|e| format!("Failed to load SenseVoice model {}: {}", model_id, e))?;
+                Ok(LoadedEngine::SenseVoice(engine))
+            }
+            #[cfg(feature = "vosk")]
+            EngineType::Vosk => {
+                let mut engine = vosk::VoskEngine::new();
+                engine
+                    .load_model(model_path)
+                    .map_err(|e| format!("Failed to load Vosk model {}: {}", model_id, e))?;
+                Ok(LoadedEngine::Vosk(engine))
+            }
+            #[cfg(not(feature = "vosk"))]
+            EngineType::Vosk => Err(format!(
+                "Failed to load Vosk model {}: this build doesn't include the Vosk engine",
+                model_id
+            )),
+        }
+    }
+
+    fn unload(&mut self) {
+        match self {
+            LoadedEngine::Whisper(ref mut e) => e.unload_model(),
+            LoadedEngine::Parakeet(ref mut e) => e.unload_model(),
+            LoadedEngine::Moonshine(ref mut e) => e.unload_model(),
+            LoadedEngine::SenseVoice(ref mut e) => e.unload_model(),
+            #[cfg(feature = "vosk")]
+            LoadedEngine::Vosk(ref mut e) => e.unload_model(),
+        }
+    }
+
+    /// Transcribe samples with the language settings, without any post-processing
+    fn transcribe(&mut self, audio: Vec<f32>, settings: &AppSettings) -> Result<String> {
+        Ok(self.transcribe_timed(audio, settings)?.text)
+    }
+
+    /// Like `transcribe`, keeping the word timings Parakeet and Vosk report
+    /// and the segment timings Whisper reports
+    fn transcribe_timed(
+        &mut self,
+        audio: Vec<f32>,
+        settings: &AppSettings,
+    ) -> Result<TimedTranscript> {
+        let result = match self {
+            LoadedEngine::Whisper(whisper_engine) => {
+                // Normalize language code for Whisper
+                // Convert zh-Hans and zh-Hant to zh since Whisper uses ISO 639-1 codes
+                let whisper_language = if settings.selected_language == "auto" {
+                    None
+                } else {
+                    let normalized = if settings.selected_language == "zh-Hans"
+                        || settings.selected_language == "zh-Hant"
+                    {
+                        "zh".to_string()
+                    } else {
+                        settings.selected_language.clone()
+                    };
+                    Some(normalized)
+                };
+
+                let params = WhisperInferenceParams {
+                    language: whisper_language,
+                    translate: settings.translate_to_english,
+                    ..Default::default()
+                };
+
+                whisper_engine
+                    .transcribe_samples(audio, Some(params))
+                    .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?
+            }
+            LoadedEngine::Parakeet(parakeet_engine) => {
+                let params = ParakeetInferenceParams {
+                    timestamp_granularity: TimestampGranularity::Word,
+                    ..Default::default()
+                };
+                parakeet_engine
+                    .transcribe_samples(audio, Some(params))
+                    .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?
+            }
+            LoadedEngine::Moonshine(moonshine_engine) => moonshine_engine
+                .transcribe_samples(audio, None)
+                .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e))?,
+            LoadedEngine::SenseVoice(sense_voice_engine) => {
+                let language = match settings.selected_language.as_str() {
+                    "zh" | "zh-Hans" | "zh-Hant" => SenseVoiceLanguage::Chinese,
+                    "en" => SenseVoiceLanguage::English,
+                    "ja" => SenseVoiceLanguage::Japanese,
+                    "ko" => SenseVoiceLanguage::Korean,
+                    "yue" => SenseVoiceLanguage::Cantonese,
+                    _ => SenseVoiceLanguage::Auto,
+                };
+                let params = SenseVoiceInferenceParams {
+                    language,
+                    use_itn: true,
+                };
+                sense_voice_engine
+                    .transcribe_samples(audio, Some(params))
+                    .map_err(|e| anyhow::anyhow!("SenseVoice transcription failed: {}", e))?
+            }
+            #[cfg(feature = "vosk")]
+            LoadedEngine::Vosk(vosk_engine) => vosk_engine
+                .transcribe_samples(audio, None)
+                .map_err(|e| anyhow::anyhow!("Vosk transcription failed: {}", e))?,
+        };
+        // transcribe-rs reports neither the detected language nor token
+        // probabilities, so those come from the settings or stay unknown
+        let segments = result
+            .segments
+            .unwrap_or_default()
+            .into_iter()
+            .map(|segment| TranscriptSegment {
+                start: segment.start,
+                end: segment.end,
+                text: segment.text.trim().to_string(),
+            })
+            .filter(|segment| !segment.text.is_empty())
+            .collect();
+        Ok(TimedTranscript {
+            text: result.text,
+            segments,
+            confidence: self.last_confidence(),
+            ..Default::default()
+        })
+    }
+
+    /// Confidence of the last transcription, for the engines that report one
+    fn last_confidence(&self) -> Option<f32> {
+        #[cfg(feature = "vosk")]
+        if let LoadedEngine::Vosk(vosk_engine) = self {
+            return vosk_engine.last_confidence();
+        }
+        None
+    }
 }
 
 #[derive(Clone)]
@@ -51,6 +294,14 @@ pub struct TranscriptionManager {
     watcher_handle: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
     is_loading: Arc<Mutex<bool>>,
     loading_condvar: Arc<Condvar>,
+    /// Words transcribed so far in the current streaming session
+    streamed_words: Arc<AtomicUsize>,
+    /// Small model for draft transcripts, with the id it was loaded from
+    draft_engine: Arc<Mutex<Option<(String, LoadedEngine)>>>,
+    /// Audio of the recording being previewed, `None` when no preview runs
+    preview_audio: Arc<Mutex<Option<Vec<f32>>>>,
+    /// Bumped for every preview so a stale preview thread stops
+    preview_generation: Arc<AtomicU64>,
 }
 
 impl TranscriptionManager {
@@ -70,6 +321,10 @@ impl TranscriptionManager {
             watcher_handle: Arc::new(Mutex::new(None)),
             is_loading: Arc::new(Mutex::new(false)),
             loading_condvar: Arc::new(Condvar::new()),
+            streamed_words: Arc::new(AtomicUsize::new(0)),
+            draft_engine: Arc::new(Mutex::new(None)),
+            preview_audio: Arc::new(Mutex::new(None)),
+            preview_generation: Arc::new(AtomicU64::new(0)),
         };
 
         // Start the idle watcher
@@ -108,15 +363,13 @@ impl TranscriptionManager {
                                 debug!("Starting to unload model due to inactivity");
 
                                 if let Ok(()) = manager_cloned.unload_model() {
-                                    let _ = app_handle_cloned.emit(
-                                        "model-state-changed",
-                                        ModelStateEvent {
-                                            event_type: "unloaded".to_string(),
-                                            model_id: None,
-                                            model_name: None,
-                                            error: None,
-                                        },
-                                    );
+                                    let _ = ModelStateChanged(ModelStateEvent {
+                                        event_type: "unloaded".to_string(),
+                                        model_id: None,
+                                        model_name: None,
+                                        error: None,
+                                    })
+                                    .emit(&app_handle_cloned);
                                     let unload_duration = unload_start.elapsed();
                                     debug!(
                                         "Model unloaded due to inactivity (took {}ms)",
@@ -153,12 +406,7 @@ impl TranscriptionManager {
         {
             let mut engine = self.engine.safe_lock()?;
             if let Some(ref mut loaded_engine) = *engine {
-                match loaded_engine {
-                    LoadedEngine::Whisper(ref mut e) => e.unload_model(),
-                    LoadedEngine::Parakeet(ref mut e) => e.unload_model(),
-                    LoadedEngine::Moonshine(ref mut e) => e.unload_model(),
-                    LoadedEngine::SenseVoice(ref mut e) => e.unload_model(),
-                }
+                loaded_engine.unload();
             }
             *engine = None; // Drop the engine to free memory
         }
@@ -168,15 +416,13 @@ impl TranscriptionManager {
         }
 
         // Emit unloaded event
-        let _ = self.app_handle.emit(
-            "model-state-changed",
-            ModelStateEvent {
-                event_type: "unloaded".to_string(),
-                model_id: None,
-                model_name: None,
-                error: None,
-            },
-        );
+        let _ = ModelStateChanged(ModelStateEvent {
+            event_type: "unloaded".to_string(),
+            model_id: None,
+            model_name: None,
+            error: None,
+        })
+        .emit(&self.app_handle);
 
         let unload_duration = unload_start.elapsed();
         debug!(
@@ -204,15 +450,13 @@ impl TranscriptionManager {
         debug!("Starting to load model: {}", model_id);
 
         // Emit loading started event
-        let _ = self.app_handle.emit(
-            "model-state-changed",
-            ModelStateEvent {
-                event_type: "loading_started".to_string(),
-                model_id: Some(model_id.to_string()),
-                model_name: None,
-                error: None,
-            },
-        );
+        let _ = ModelStateChanged(ModelStateEvent {
+            event_type: "loading_started".to_string(),
+            model_id: Some(model_id.to_string()),
+            model_name: None,
+            error: None,
+        })
+        .emit(&self.app_handle);
 
         let model_info = self
             .model_manager
@@ -221,116 +465,40 @@ impl TranscriptionManager {
 
         if !model_info.is_downloaded {
             let error_msg = "Model not downloaded";
-            let _ = self.app_handle.emit(
-                "model-state-changed",
-                ModelStateEvent {
-                    event_type: "loading_failed".to_string(),
-                    model_id: Some(model_id.to_string()),
-                    model_name: Some(model_info.name.clone()),
-                    error: Some(error_msg.to_string()),
-                },
-            );
+            let _ = ModelStateChanged(ModelStateEvent {
+                event_type: "loading_failed".to_string(),
+                model_id: Some(model_id.to_string()),
+                model_name: Some(model_info.name.clone()),
+                error: Some(error_msg.to_string()),
+            })
+            .emit(&self.app_handle);
             return Err(anyhow::anyhow!(error_msg));
         }
 
         let model_path = self.model_manager.get_model_path(model_id)?;
 
-        // Create appropriate engine based on model type
-        let loaded_engine = match model_info.engine_type {
-            EngineType::Whisper => {
-                let mut engine = WhisperEngine::new();
-                engine.load_model(&model_path).map_err(|e| {
-                    let error_msg = format!("Failed to load whisper model {}: {}", model_id, e);
-                    let _ = self.app_handle.emit(
-                        "model-state-changed",
-                        ModelStateEvent {
-                            event_type: "loading_failed".to_string(),
-                            model_id: Some(model_id.to_string()),
-                            model_name: Some(model_info.name.clone()),
-                            error: Some(error_msg.clone()),
-                        },
-                    );
-                    anyhow::anyhow!(error_msg)
-                })?;
-                LoadedEngine::Whisper(engine)
-            }
-            EngineType::Parakeet => {
-                let mut engine = ParakeetEngine::new();
-                engine
-                    .load_model_with_params(&model_path, ParakeetModelParams::int8())
-                    .map_err(|e| {
-                        let error_msg =
-                            format!("Failed to load parakeet model {}: {}", model_id, e);
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
-                    })?;
-                LoadedEngine::Parakeet(engine)
-            }
-            EngineType::Moonshine => {
-                let mut engine = MoonshineEngine::new();
-                engine
-                    .load_model_with_params(
-                        &model_path,
-                        MoonshineModelParams::variant(ModelVariant::Base),
-                    )
-                    .map_err(|e| {
-                        let error_msg =
-                            format!("Failed to load moonshine model {}: {}", model_id, e);
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
-                    })?;
-                LoadedEngine::Moonshine(engine)
-            }
-            EngineType::SenseVoice => {
-                let mut engine = SenseVoiceEngine::new();
-                engine
-                    .load_model_with_params(&model_path, SenseVoiceModelParams::int8())
-                    .map_err(|e| {
-                        let error_msg =
-                            format!("Failed to load SenseVoice model {}: {}", model_id, e);
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
-                    })?;
-                LoadedEngine::SenseVoice(engine)
-            }
-        };
+        let loaded_engine = LoadedEngine::open(&model_info.engine_type, model_id, &model_path)
+            .map_err(|error_msg| {
+                let _ = ModelStateChanged(ModelStateEvent {
+                    event_type: "loading_failed".to_string(),
+                    model_id: Some(model_id.to_string()),
+                    model_name: Some(model_info.name.clone()),
+                    error: Some(error_msg.clone()),
+                })
+                .emit(&self.app_handle);
+                anyhow::anyhow!(error_msg)
+            })?;
 
         // Update the current engine and model ID
         {
             let mut engine = self.engine.safe_lock().map_err(|e| {
-                let _ = self.app_handle.emit(
-                    "model-state-changed",
-                    ModelStateEvent {
-                        event_type: "loading_failed".to_string(),
-                        model_id: Some(model_id.to_string()),
-                        model_name: Some(model_info.name.clone()),
-                        error: Some(e.to_string()),
-                    },
-                );
+                let _ = ModelStateChanged(ModelStateEvent {
+                    event_type: "loading_failed".to_string(),
+                    model_id: Some(model_id.to_string()),
+                    model_name: Some(model_info.name.clone()),
+                    error: Some(e.to_string()),
+                })
+                .emit(&self.app_handle);
                 anyhow::anyhow!(e)
             })?;
             *engine = Some(loaded_engine);
@@ -341,15 +509,13 @@ impl TranscriptionManager {
         }
 
         // Emit loading completed event
-        let _ = self.app_handle.emit(
-            "model-state-changed",
-            ModelStateEvent {
-                event_type: "loading_completed".to_string(),
-                model_id: Some(model_id.to_string()),
-                model_name: Some(model_info.name.clone()),
-                error: None,
-            },
-        );
+        let _ = ModelStateChanged(ModelStateEvent {
+            event_type: "loading_completed".to_string(),
+            model_id: Some(model_id.to_string()),
+            model_name: Some(model_info.name.clone()),
+            error: None,
+        })
+        .emit(&self.app_handle);
 
         let load_duration = load_start.elapsed();
         debug!(
@@ -394,7 +560,85 @@ impl TranscriptionManager {
         }
     }
 
+    /// Start counting words for a new streaming session
+    pub fn reset_streamed_word_count(&self) {
+        self.streamed_words.store(0, Ordering::Relaxed);
+    }
+
+    /// Add a segment transcribed during a streaming session to the word count
+    pub fn record_streamed_transcript(&self, text: &str) {
+        self.streamed_words
+            .fetch_add(text.split_whitespace().count(), Ordering::Relaxed);
+    }
+
+    pub fn streamed_word_count(&self) -> usize {
+        self.streamed_words.load(Ordering::Relaxed)
+    }
+
+    /// Bring quiet (or loud) recordings to the target level when gain
+    /// normalization is on
+    fn normalize_gain(audio: &mut [f32], settings: &AppSettings) {
+        if !settings.general.gain_normalization {
+            return;
+        }
+        let gain = GainNormalizer::new(settings.general.gain_target_dbfs).apply(audio);
+        debug!("Normalized recording with a gain of {:.2}", gain);
+    }
+
+    /// Apply custom words and filter the output. Filler words are kept when
+    /// `keep_fillers` is set, for dictating into a code editor.
+    fn clean_transcript(&self, text: &str, settings: &AppSettings, keep_fillers: bool) -> String {
+        let corrected = if !settings.custom_words.is_empty() {
+            apply_custom_words(
+                text,
+                &settings.custom_words,
+                settings.word_correction_threshold,
+            )
+        } else {
+            text.to_string()
+        };
+        if keep_fillers {
+            return corrected.trim().to_string();
+        }
+        filter_transcription_output(&corrected)
+    }
+
     pub fn transcribe(&self, audio: Vec<f32>) -> Result<String> {
+        let settings = get_settings(&self.app_handle);
+        Ok(self
+            .transcribe_and_maybe_unload(audio, &settings, true, false)?
+            .text)
+    }
+
+    /// Transcribe speech straight into English with the loaded Whisper
+    /// model. Other engines can't translate.
+    pub fn translate_to_english(&self, audio: Vec<f32>) -> Result<String> {
+        if audio.is_empty() {
+            return Ok(String::new());
+        }
+
+        let mut settings = get_settings(&self.app_handle);
+        settings.translate_to_english = true;
+        let text = {
+            let mut engine_guard = self.engine.safe_lock()?;
+            match engine_guard.as_mut() {
+                Some(engine @ LoadedEngine::Whisper(_)) => engine.transcribe(audio, &settings)?,
+                Some(_) => return Err(anyhow::anyhow!("Only Whisper models can translate")),
+                None => return Err(anyhow::anyhow!("Model is not loaded for translation.")),
+            }
+        };
+        Ok(self.clean_transcript(&text, &settings, false))
+    }
+
+    /// Transcribe with `settings`, leaving the model loaded even when it's
+    /// set to unload right away unless `unload` is true
+    fn transcribe_and_maybe_unload(
+        &self,
+        mut audio: Vec<f32>,
+        settings: &AppSettings,
+        unload: bool,
+        keep_fillers: bool,
+    ) -> Result<TimedTranscript> {
         // Update last activity timestamp
         self.last_activity.store(
             SystemTime::now()
@@ -410,8 +654,10 @@ impl TranscriptionManager {
 
         if audio.is_empty() {
             debug!("Empty audio vector");
-            self.maybe_unload_immediately("empty audio");
-            return Ok(String::new());
+            if unload {
+                self.maybe_unload_immediately("empty audio");
+            }
+            return Ok(TimedTranscript::default());
         }
 
         // Check if model is loaded, if not try to load it
@@ -430,8 +676,7 @@ impl TranscriptionManager {
             }
         }
 
-        // Get current settings for configuration
-        let settings = get_settings(&self.app_handle);
+        Self::normalize_gain(&mut audio, settings);
 
         // Perform transcription with the appropriate engine
         let result = {
@@ -442,78 +687,10 @@ impl TranscriptionManager {
                 )
             })?;
 
-            match engine {
-                LoadedEngine::Whisper(whisper_engine) => {
-                    // Normalize language code for Whisper
-                    // Convert zh-Hans and zh-Hant to zh since Whisper uses ISO 639-1 codes
-                    let whisper_language = if settings.selected_language == "auto" {
-                        None
-                    } else {
-                        let normalized = if settings.selected_language == "zh-Hans"
-                            || settings.selected_language == "zh-Hant"
-                        {
-                            "zh".to_string()
-                        } else {
-                            settings.selected_language.clone()
-                        };
-                        Some(normalized)
-                    };
-
-                    let params = WhisperInferenceParams {
-                        language: whisper_language,
-                        translate: settings.translate_to_english,
-                        ..Default::default()
-                    };
-
-                    whisper_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?
-                }
-                LoadedEngine::Parakeet(parakeet_engine) => {
-                    let params = ParakeetInferenceParams {
-                        timestamp_granularity: TimestampGranularity::Segment,
-                        ..Default::default()
-                    };
-                    parakeet_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?
-                }
-                LoadedEngine::Moonshine(moonshine_engine) => moonshine_engine
-                    .transcribe_samples(audio, None)
-                    .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e))?,
-                LoadedEngine::SenseVoice(sense_voice_engine) => {
-                    let language = match settings.selected_language.as_str() {
-                        "zh" | "zh-Hans" | "zh-Hant" => SenseVoiceLanguage::Chinese,
-                        "en" => SenseVoiceLanguage::English,
-                        "ja" => SenseVoiceLanguage::Japanese,
-                        "ko" => SenseVoiceLanguage::Korean,
-                        "yue" => SenseVoiceLanguage::Cantonese,
-                        _ => SenseVoiceLanguage::Auto,
-                    };
-                    let params = SenseVoiceInferenceParams {
-                        language,
-                        use_itn: true,
-                    };
-                    sense_voice_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("SenseVoice transcription failed: {}", e))?
-                }
-            }
-        };
-
-        // Apply word correction if custom words are configured
-        let corrected_result = if !settings.custom_words.is_empty() {
-            apply_custom_words(
-                &result.text,
-                &settings.custom_words,
-                settings.word_correction_threshold,
-            )
-        } else {
-            result.text
+            engine.transcribe_timed(audio, settings)?
         };
 
-        // Filter out filler words and hallucinations
-        let filtered_result = filter_transcription_output(&corrected_result);
+        let filtered_result = self.clean_transcript(&result.text, settings, keep_fillers);
 
         let et = std::time::Instant::now();
         let translation_note = if settings.translate_to_english {
@@ -535,10 +712,400 @@ impl TranscriptionManager {
             info!("Transcription result: {}", final_result);
         }
 
-        self.maybe_unload_immediately("transcription");
+        if unload {
+            self.maybe_unload_immediately("transcription");
+        }
+
+        // Timings of text that was filtered out would point at nothing
+        let segments = if final_result.is_empty() {
+            Vec::new()
+        } else {
+            result.segments
+        };
+        Ok(TimedTranscript {
+            text: final_result,
+            segments,
+            language: result
+                .language
+                .or_else(|| requested_language(&settings.selected_language)),
+            confidence: result.confidence,
+        })
+    }
+
+    /// Transcribe a dictation with the backend picked for its shortcut. When
+    /// a remote service can't be reached, the local model stands in if the
+    /// settings allow it.
+    pub async fn transcribe_for_binding(
+        &self,
+        audio: Vec<f32>,
+        binding_id: &str,
+    ) -> Result<String> {
+        Ok(self
+            .transcribe_binding_and_maybe_unload(audio, binding_id, true, false)
+            .await?
+            .text)
+    }
+
+    /// Like `transcribe_for_binding`, with the timing of the words or
+    /// segments when the engine reports it. Recordings of more than a few
+    /// minutes are split into chunks at the VAD's `pauses` so no engine or
+    /// service gets one huge buffer. The chunks share the model, so they're
+    /// transcribed one after another, and their texts are joined. Filler
+    /// words are kept when `keep_fillers` is set.
+    pub async fn transcribe_long_for_binding(
+        &self,
+        audio: Vec<f32>,
+        pauses: &[Pause],
+        binding_id: &str,
+        keep_fillers: bool,
+    ) -> Result<TimedTranscript> {
+        let bounds = chunk_bounds(pauses, audio.len());
+        if bounds.is_empty() {
+            return self
+                .transcribe_binding_and_maybe_unload(audio, binding_id, true, keep_fillers)
+                .await;
+        }
+
+        info!(
+            "Transcribing {:.0}s recording in {} chunks",
+            audio.len() as f32 / WHISPER_SAMPLE_RATE as f32,
+            bounds.len() + 1
+        );
+        let mut parts = Vec::with_capacity(bounds.len() + 1);
+        let mut start = 0;
+        for &end in &bounds {
+            parts.push(audio[start..end].to_vec());
+            start = end;
+        }
+        parts.push(audio[start..].to_vec());
+
+        let starts = std::iter::once(0).chain(bounds);
+        let mut transcript = TimedTranscript::default();
+        for (part, start) in self
+            .transcribe_parts_for_binding(parts, binding_id, keep_fillers)
+            .await?
+            .into_iter()
+            .zip(starts)
+        {
+            transcript.append(part, start as f32 / WHISPER_SAMPLE_RATE as f32);
+        }
+        Ok(transcript)
+    }
+
+    /// Transcribe the parts of one dictation one after another with the
+    /// backend picked for its shortcut. The model stays loaded until the
+    /// last part is done. When the parts add up to a long recording, a
+    /// `TranscriptionProgress` event follows each one.
+    pub async fn transcribe_parts_for_binding(
+        &self,
+        parts: Vec<Vec<f32>>,
+        binding_id: &str,
+        keep_fillers: bool,
+    ) -> Result<Vec<TimedTranscript>> {
+        let count = parts.len();
+        let total_len: usize = parts.iter().map(Vec::len).sum();
+        let report = total_len > CHUNKED_MIN_SECONDS * WHISPER_SAMPLE_RATE as usize;
+        let mut done_len = 0;
+        let mut texts = Vec::with_capacity(count);
+        for (index, audio) in parts.into_iter().enumerate() {
+            let unload = index + 1 == count;
+            done_len += audio.len();
+            texts.push(
+                self.transcribe_binding_and_maybe_unload(audio, binding_id, unload, keep_fillers)
+                    .await?,
+            );
+            if report {
+                let _ = TranscriptionProgress {
+                    done: (index + 1) as u32,
+                    total: count as u32,
+                    progress: done_len as f32 / total_len as f32,
+                }
+                .emit(&self.app_handle);
+            }
+        }
+        Ok(texts)
+    }
+
+    /// Transcribe one part of a longer recording with the backend picked
+    /// for its shortcut, leaving the model loaded until the last part
+    pub async fn transcribe_part_for_binding(
+        &self,
+        audio: Vec<f32>,
+        binding_id: &str,
+        last_part: bool,
+    ) -> Result<TimedTranscript> {
+        self.transcribe_binding_and_maybe_unload(audio, binding_id, last_part, false)
+            .await
+    }
+
+    async fn transcribe_binding_and_maybe_unload(
+        &self,
+        mut audio: Vec<f32>,
+        binding_id: &str,
+        unload: bool,
+        keep_fillers: bool,
+    ) -> Result<TimedTranscript> {
+        let settings = get_settings(&self.app_handle).for_binding(binding_id);
+        let TranscriptionBackend::Remote(config) =
+            TranscriptionBackend::for_binding(&settings, binding_id)
+        else {
+            return self.transcribe_and_maybe_unload(audio, &settings, unload, keep_fillers);
+        };
+        if audio.is_empty() {
+            return Ok(TimedTranscript::default());
+        }
 
-        Ok(final_result)
+        let fallback_to_local = settings.transcription_backend.fallback_to_local;
+        let st = std::time::Instant::now();
+        // The local fallback normalizes again, which leaves the level as is
+        Self::normalize_gain(&mut audio, &settings);
+        match backend::transcribe_remote(&config, &audio, &settings).await {
+            Ok(result) => {
+                info!(
+                    "{} transcription completed in {}ms",
+                    config.provider.label,
+                    st.elapsed().as_millis()
+                );
+                Ok(TimedTranscript {
+                    text: self.clean_transcript(&result.text, &settings, keep_fillers),
+                    segments: Vec::new(),
+                    language: result
+                        .language
+                        .or_else(|| requested_language(&settings.selected_language)),
+                    confidence: result.confidence,
+                })
+            }
+            Err(RemoteError::Unreachable(e)) if fallback_to_local => {
+                warn!("{}, transcribing with the local model", e);
+                self.transcribe_and_maybe_unload(audio, &settings, unload, keep_fillers)
+            }
+            Err(e) => Err(anyhow::anyhow!(
+                "{} transcription failed: {}",
+                config.provider.label,
+                e
+            )),
+        }
     }
+
+    /// Transcribe with a small draft model, loading it on first use. The draft
+    /// model has its own engine, so it can run while the main model is busy.
+    pub fn transcribe_draft(&self, audio: Vec<f32>, model_id: &str) -> Result<String> {
+        if audio.is_empty() {
+            return Ok(String::new());
+        }
+
+        let st = std::time::Instant::now();
+        let mut draft = self.draft_engine.safe_lock()?;
+        if draft.as_ref().map(|(id, _)| id.as_str()) != Some(model_id) {
+            let model_info = self
+                .model_manager
+                .get_model_info(model_id)
+                .ok_or_else(|| anyhow::anyhow!("Model not found: {}", model_id))?;
+            if !model_info.is_downloaded {
+                return Err(anyhow::anyhow!("Draft model not downloaded"));
+            }
+            let model_path = self.model_manager.get_model_path(model_id)?;
+            let engine = LoadedEngine::open(&model_info.engine_type, model_id, &model_path)
+                .map_err(|e| anyhow::anyhow!(e))?;
+            if let Some((_, mut previous)) = draft.replace((model_id.to_string(), engine)) {
+                previous.unload();
+            }
+            debug!("Loaded draft model: {}", model_id);
+        }
+
+        let settings = get_settings(&self.app_handle);
+        let (_, engine) = draft
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("Draft model is not loaded"))?;
+        let text = engine.transcribe(audio, &settings)?;
+        let text = if settings.custom_words.is_empty() {
+            text
+        } else {
+            apply_custom_words(
+                &text,
+                &settings.custom_words,
+                settings.word_correction_threshold,
+            )
+        };
+
+        debug!(
+            "Draft transcription completed in {}ms",
+            st.elapsed().as_millis()
+        );
+        Ok(filter_transcription_output(&text))
+    }
+
+    /// Free the draft model's memory
+    pub fn unload_draft_model(&self) {
+        if let Ok(mut draft) = self.draft_engine.lock() {
+            if let Some((model_id, mut engine)) = draft.take() {
+                engine.unload();
+                debug!("Unloaded draft model: {}", model_id);
+            }
+        }
+    }
+
+    /// Start transcribing the recording as it comes in, emitting
+    /// `transcription-partial` with the text so far. Does nothing when the
+    /// streaming preview setting is off.
+    pub fn start_streaming_preview(&self) {
+        if !get_settings(&self.app_handle).general.streaming_preview {
+            return;
+        }
+
+        let generation = self.preview_generation.fetch_add(1, Ordering::SeqCst) + 1;
+        if let Ok(mut preview) = self.preview_audio.lock() {
+            *preview = Some(Vec::new());
+        }
+
+        // The thread only clones the fields it needs: dropping a clone of the
+        // manager would stop the idle watcher
+        let engine = self.engine.clone();
+        let preview_audio = self.preview_audio.clone();
+        let preview_generation = self.preview_generation.clone();
+        let streamed_words = self.streamed_words.clone();
+        let app_handle = self.app_handle.clone();
+        thread::spawn(move || {
+            let is_current = || preview_generation.load(Ordering::SeqCst) == generation;
+            let mut transcribed_len = 0;
+
+            while is_current() {
+                thread::sleep(PREVIEW_INTERVAL);
+                if !is_current() {
+                    break;
+                }
+
+                let window = match preview_audio.lock() {
+                    Ok(preview) => match preview.as_deref() {
+                        Some(samples) => preview_window(samples, transcribed_len).map(|window| {
+                            transcribed_len = samples.len();
+                            window.to_vec()
+                        }),
+                        None => break,
+                    },
+                    Err(_) => break,
+                };
+                let Some(window) = window else {
+                    continue;
+                };
+                let window_len = window.len();
+
+                // Never wait on the engine: if the model is still loading or
+                // busy, skip this round rather than delay the final transcript
+                let settings = get_settings(&app_handle);
+                let text = match engine.try_lock() {
+                    Ok(mut guard) => match guard.as_mut() {
+                        Some(engine) => engine.transcribe(window, &settings),
+                        None => continue,
+                    },
+                    Err(_) => continue,
+                };
+
+                match text {
+                    Ok(text) => {
+                        let text = if settings.custom_words.is_empty() {
+                            text
+                        } else {
+                            apply_custom_words(
+                                &text,
+                                &settings.custom_words,
+                                settings.word_correction_threshold,
+                            )
+                        };
+                        if is_current() {
+                            let text = filter_transcription_output(&text);
+                            streamed_words.fetch_max(
+                                preview_word_count(&text, window_len, transcribed_len),
+                                Ordering::Relaxed,
+                            );
+                            let _ = TranscriptionPartial(text).emit(&app_handle);
+                        }
+                    }
+                    Err(e) => debug!("Streaming preview transcription failed: {}", e),
+                }
+            }
+            debug!("Streaming preview {} stopped", generation);
+        });
+    }
+
+    /// Add recorded samples to the live preview, if one is running
+    pub fn push_preview_samples(&self, samples: &[f32]) {
+        if let Ok(mut preview) = self.preview_audio.lock() {
+            if let Some(audio) = preview.as_mut() {
+                audio.extend_from_slice(samples);
+            }
+        }
+    }
+
+    /// Stop the live preview and drop its audio
+    pub fn stop_streaming_preview(&self) {
+        self.preview_generation.fetch_add(1, Ordering::SeqCst);
+        if let Ok(mut preview) = self.preview_audio.lock() {
+            *preview = None;
+        }
+    }
+}
+
+/// Apply custom word corrections and drop filler words and hallucinations
+
+/// The most recent audio to preview, or `None` if there is too little or
+/// nothing new since the last preview
+fn preview_window(samples: &[f32], transcribed_len: usize) -> Option<&[f32]> {
+    let min_samples = (PREVIEW_MIN_SECONDS * WHISPER_SAMPLE_RATE as f32) as usize;
+    if samples.len() < min_samples || samples.len() == transcribed_len {
+        return None;
+    }
+    let max_samples = PREVIEW_WINDOW_SECONDS * WHISPER_SAMPLE_RATE as usize;
+    Some(&samples[samples.len().saturating_sub(max_samples)..])
+}
+
+/// Language the model is asked to transcribe, `None` when auto-detecting
+fn requested_language(selected_language: &str) -> Option<String> {
+    (selected_language != "auto").then(|| selected_language.to_string())
+}
+
+/// Confidence of two transcripts together, weighted by their word counts
+fn weighted_confidence(first: (f32, &str), second: (f32, &str)) -> f32 {
+    let words = |text: &str| text.split_whitespace().count() as f32;
+    let (first_words, second_words) = (words(first.1), words(second.1));
+    if first_words + second_words == 0.0 {
+        return (first.0 + second.0) / 2.0;
+    }
+    (first.0 * first_words + second.0 * second_words) / (first_words + second_words)
+}
+
+/// Words dictated so far, from the preview of the last `window_len` samples.
+/// Once the recording is longer than the window the count is extrapolated.
+fn preview_word_count(text: &str, window_len: usize, total_len: usize) -> usize {
+    let words = text.split_whitespace().count();
+    if window_len == 0 || total_len <= window_len {
+        return words;
+    }
+    words * total_len / window_len
+}
+
+/// Offsets to split a recording of `len` samples into chunks at. Recordings
+/// that aren't long get none. Each chunk ends at the longest pause that
+/// leaves it between the minimum and maximum chunk length, or is cut at the
+/// maximum when the speaker didn't pause.
+fn chunk_bounds(pauses: &[Pause], len: usize) -> Vec<usize> {
+    let rate = WHISPER_SAMPLE_RATE as usize;
+    if len <= CHUNKED_MIN_SECONDS * rate {
+        return Vec::new();
+    }
+    let (min_len, max_len) = (MIN_CHUNK_SECONDS * rate, MAX_CHUNK_SECONDS * rate);
+    let mut bounds = Vec::new();
+    let mut start = 0;
+    while len - start > max_len {
+        let end = pauses
+            .iter()
+            .filter(|pause| pause.offset >= start + min_len && pause.offset <= start + max_len)
+            .max_by_key(|pause| pause.duration)
+            .map_or(start + max_len, |pause| pause.offset);
+        bounds.push(end);
+        start = end;
+    }
+    bounds
 }
 
 impl Drop for TranscriptionManager {
@@ -562,3 +1129,75 @@ impl Drop for TranscriptionManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview_window_needs_new_audio() {
+        let samples = vec![0.0; WHISPER_SAMPLE_RATE as usize];
+        assert!(preview_window(&samples[..1000], 0).is_none());
+        assert_eq!(preview_window(&samples, 0).unwrap().len(), samples.len());
+        assert!(preview_window(&samples, samples.len()).is_none());
+    }
+
+    #[test]
+    fn test_append_keeps_language_and_weights_confidence() {
+        let part = |text: &str, language: Option<&str>, confidence: Option<f32>| TimedTranscript {
+            text: text.to_string(),
+            language: language.map(str::to_string),
+            confidence,
+            ..Default::default()
+        };
+        let mut transcript = TimedTranscript::default();
+        transcript.append(part("one two three", Some("de"), Some(0.9)), 0.0);
+        transcript.append(part("four", Some("en"), Some(0.5)), 10.0);
+        transcript.append(part("five", None, None), 20.0);
+
+        assert_eq!(transcript.language.as_deref(), Some("de"));
+        assert!((transcript.confidence.unwrap() - 0.8).abs() < 1e-6);
+        assert_eq!(requested_language("auto"), None);
+        assert_eq!(requested_language("fr").as_deref(), Some("fr"));
+    }
+
+    #[test]
+    fn test_preview_word_count_extrapolates_past_the_window() {
+        assert_eq!(preview_word_count("one two three", 100, 100), 3);
+        assert_eq!(preview_word_count("one two three", 100, 300), 9);
+        assert_eq!(preview_word_count("", 100, 300), 0);
+    }
+
+    #[test]
+    fn test_chunk_bounds_prefer_the_longest_pause() {
+        let rate = WHISPER_SAMPLE_RATE as usize;
+        let pause = |seconds: usize, pause_ms: u64| Pause {
+            offset: seconds * rate,
+            duration: Duration::from_millis(pause_ms),
+        };
+        assert!(chunk_bounds(&[], CHUNKED_MIN_SECONDS * rate).is_empty());
+
+        // Too early, the longest in the first window, then none in the
+        // second window so it's cut at the maximum
+        let pauses = [
+            pause(10, 3000),
+            pause(30, 800),
+            pause(45, 1500),
+            pause(50, 900),
+        ];
+        assert_eq!(
+            chunk_bounds(&pauses, 200 * rate),
+            vec![45 * rate, 105 * rate, 165 * rate]
+        );
+    }
+
+    #[test]
+    fn test_preview_window_keeps_the_most_recent_audio() {
+        let rate = WHISPER_SAMPLE_RATE as usize;
+        let mut samples = vec![0.0; (PREVIEW_WINDOW_SECONDS + 5) * rate];
+        *samples.last_mut().unwrap() = 1.0;
+        let window = preview_window(&samples, 0).unwrap();
+        assert_eq!(window.len(), PREVIEW_WINDOW_SECONDS * rate);
+        assert_eq!(window.last(), Some(&1.0));
+    }
+}
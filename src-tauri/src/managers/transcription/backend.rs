@@ -0,0 +1,331 @@
+//! Transcription backends
+//!
+//! Dictations are transcribed by the local model unless their shortcut is
+//! set to a remote speech-to-text service. Remote services get the recording
+//! as a 16 kHz WAV file, and their text is cleaned up like the local model's.
+
+use super::TimedTranscript;
+use crate::access_policy;
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::settings::{AppSettings, RemoteSttApi, RemoteSttConfig};
+use hound::{WavSpec, WavWriter};
+use log::debug;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+use std::fmt;
+use std::io::Cursor;
+use std::time::Duration;
+
+/// Connection timeout, short so an offline machine falls back quickly
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Most of an error response kept for the error message
+const ERROR_BODY_CHARS: usize = 200;
+
+/// What transcribes a dictation
+pub enum TranscriptionBackend {
+    Local,
+    Remote(RemoteSttConfig),
+}
+
+impl TranscriptionBackend {
+    /// The backend picked for a shortcut binding
+    pub fn for_binding(settings: &AppSettings, binding_id: &str) -> Self {
+        match settings.transcription_backend.remote_for(binding_id) {
+            Some(config) => TranscriptionBackend::Remote(config),
+            None => TranscriptionBackend::Local,
+        }
+    }
+}
+
+/// Why a remote transcription failed
+#[derive(Debug)]
+pub enum RemoteError {
+    /// The service couldn't be reached or is down, so the local model can
+    /// stand in
+    Unreachable(String),
+    /// The service rejected the request, e.g. for a wrong API key
+    Failed(String),
+}
+
+impl fmt::Display for RemoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RemoteError::Unreachable(e) | RemoteError::Failed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct OpenAiTranscription {
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct DeepgramResponse {
+    results: DeepgramResults,
+}
+
+#[derive(Deserialize)]
+struct DeepgramResults {
+    channels: Vec<DeepgramChannel>,
+}
+
+#[derive(Deserialize)]
+struct DeepgramChannel {
+    alternatives: Vec<DeepgramAlternative>,
+    /// Only set when the language was detected
+    detected_language: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct DeepgramAlternative {
+    transcript: String,
+    confidence: Option<f32>,
+}
+
+/// Encode samples as a 16-bit mono WAV file
+fn wav_bytes(audio: &[f32]) -> Result<Vec<u8>, String> {
+    let spec = WavSpec {
+        channels: 1,
+        sample_rate: WHISPER_SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut buffer = Cursor::new(Vec::new());
+    let mut writer =
+        WavWriter::new(&mut buffer, spec).map_err(|e| format!("Failed to encode audio: {}", e))?;
+    for sample in audio {
+        writer
+            .write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+            .map_err(|e| format!("Failed to encode audio: {}", e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to encode audio: {}", e))?;
+    Ok(buffer.into_inner())
+}
+
+/// Language to ask the service for, `None` to let it detect the language
+fn language(selected_language: &str) -> Option<String> {
+    match selected_language {
+        "auto" => None,
+        // Services take ISO 639-1 codes, like Whisper
+        "zh-Hans" | "zh-Hant" => Some("zh".to_string()),
+        language => Some(language.to_string()),
+    }
+}
+
+fn send_error(e: reqwest::Error) -> RemoteError {
+    if e.is_connect() || e.is_timeout() {
+        RemoteError::Unreachable(format!("Transcription service unreachable: {}", e))
+    } else {
+        RemoteError::Failed(format!("Transcription request failed: {}", e))
+    }
+}
+
+async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, RemoteError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.text().await.unwrap_or_default();
+    let error = format!(
+        "Transcription service returned {}: {}",
+        status,
+        body.trim()
+            .chars()
+            .take(ERROR_BODY_CHARS)
+            .collect::<String>()
+    );
+    // A service that's down is as good as unreachable
+    if status.is_server_error() {
+        Err(RemoteError::Unreachable(error))
+    } else {
+        Err(RemoteError::Failed(error))
+    }
+}
+
+/// Transcribe samples with a remote service, without any post-processing.
+/// Services report the detected language and a confidence when they can.
+pub async fn transcribe_remote(
+    config: &RemoteSttConfig,
+    audio: &[f32],
+    settings: &AppSettings,
+) -> Result<TimedTranscript, RemoteError> {
+    // Self-hosted servers often don't need a key
+    if config.api_key.is_empty() && !config.provider.allow_base_url_edit {
+        return Err(RemoteError::Failed(format!(
+            "No API key set for {}",
+            config.provider.label
+        )));
+    }
+    access_policy::check_url(&config.base_url).map_err(RemoteError::Failed)?;
+
+    let wav = wav_bytes(audio).map_err(RemoteError::Failed)?;
+    let timeout = settings.transcription_backend.timeout_seconds.max(1) as u64;
+    let client = reqwest::Client::builder()
+        .connect_timeout(CONNECT_TIMEOUT)
+        .timeout(Duration::from_secs(timeout))
+        .build()
+        .map_err(|e| RemoteError::Failed(format!("Failed to create HTTP client: {}", e)))?;
+
+    debug!(
+        "Sending {} bytes of audio to {} ({})",
+        wav.len(),
+        config.provider.label,
+        config.model
+    );
+    let mut transcript = match config.provider.api {
+        RemoteSttApi::OpenAi => TimedTranscript {
+            text: transcribe_openai(&client, config, wav, settings).await?,
+            ..Default::default()
+        },
+        RemoteSttApi::Deepgram => transcribe_deepgram(&client, config, wav, settings).await?,
+    };
+    transcript.text = transcript.text.trim().to_string();
+    Ok(transcript)
+}
+
+async fn transcribe_openai(
+    client: &reqwest::Client,
+    config: &RemoteSttConfig,
+    wav: Vec<u8>,
+    settings: &AppSettings,
+) -> Result<String, RemoteError> {
+    // Translation has its own endpoint, which always answers in English
+    let endpoint = if settings.translate_to_english {
+        "translations"
+    } else {
+        "transcriptions"
+    };
+    let file = Part::bytes(wav)
+        .file_name("audio.wav")
+        .mime_str("audio/wav")
+        .map_err(|e| RemoteError::Failed(format!("Failed to attach audio: {}", e)))?;
+    let mut form = Form::new()
+        .part("file", file)
+        .text("model", config.model.clone())
+        .text("response_format", "json");
+    if !settings.translate_to_english {
+        if let Some(language) = language(&settings.selected_language) {
+            form = form.text("language", language);
+        }
+    }
+
+    let mut request = client
+        .post(format!("{}/audio/{}", config.base_url, endpoint))
+        .multipart(form);
+    if !config.api_key.is_empty() {
+        request = request.bearer_auth(&config.api_key);
+    }
+    let response = check_status(request.send().await.map_err(send_error)?).await?;
+    response
+        .json::<OpenAiTranscription>()
+        .await
+        .map(|transcription| transcription.text)
+        .map_err(|e| RemoteError::Failed(format!("Invalid transcription response: {}", e)))
+}
+
+async fn transcribe_deepgram(
+    client: &reqwest::Client,
+    config: &RemoteSttConfig,
+    wav: Vec<u8>,
+    settings: &AppSettings,
+) -> Result<TimedTranscript, RemoteError> {
+    let mut url = reqwest::Url::parse(&format!("{}/listen", config.base_url))
+        .map_err(|e| RemoteError::Failed(format!("Invalid transcription URL: {}", e)))?;
+    {
+        let mut query = url.query_pairs_mut();
+        query
+            .append_pair("model", &config.model)
+            .append_pair("smart_format", "true");
+        match language(&settings.selected_language) {
+            Some(language) => query.append_pair("language", &language),
+            None => query.append_pair("detect_language", "true"),
+        };
+    }
+
+    let response = client
+        .post(url)
+        .header(AUTHORIZATION, format!("Token {}", config.api_key))
+        .header(CONTENT_TYPE, "audio/wav")
+        .body(wav)
+        .send()
+        .await
+        .map_err(send_error)?;
+    let response = check_status(response).await?;
+    response
+        .json::<DeepgramResponse>()
+        .await
+        .map(deepgram_transcript)
+        .map_err(|e| RemoteError::Failed(format!("Invalid transcription response: {}", e)))
+}
+
+/// The first channel's best alternative, with its confidence and the
+/// channel's detected language
+fn deepgram_transcript(response: DeepgramResponse) -> TimedTranscript {
+    let Some(channel) = response.results.channels.into_iter().next() else {
+        return TimedTranscript::default();
+    };
+    let Some(best) = channel.alternatives.into_iter().next() else {
+        return TimedTranscript::default();
+    };
+    TimedTranscript {
+        text: best.transcript,
+        segments: Vec::new(),
+        language: channel.detected_language,
+        confidence: best.confidence,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wav_bytes_is_16_bit_mono() {
+        let wav = wav_bytes(&[0.0, 1.0, -2.0]).unwrap();
+        assert_eq!(&wav[..4], b"RIFF");
+        assert_eq!(wav.len(), 44 + 3 * 2);
+        // Out-of-range samples are clamped instead of wrapping around
+        assert_eq!(&wav[44..], &[0, 0, 0xff, 0x7f, 0x01, 0x80]);
+    }
+
+    #[test]
+    fn test_language_uses_iso_codes() {
+        assert_eq!(language("auto"), None);
+        assert_eq!(language("zh-Hant").as_deref(), Some("zh"));
+        assert_eq!(language("de").as_deref(), Some("de"));
+    }
+
+    #[test]
+    fn test_deepgram_transcript_takes_the_best_alternative() {
+        let response: DeepgramResponse = serde_json::from_str(
+            r#"{"results": {"channels": [{"alternatives": [
+                {"transcript": "Hello there.", "confidence": 0.98},
+                {"transcript": "Hello their.", "confidence": 0.41}
+            ]}]}}"#,
+        )
+        .unwrap();
+        let transcript = deepgram_transcript(response);
+        assert_eq!(transcript.text, "Hello there.");
+        assert_eq!(transcript.confidence, Some(0.98));
+        assert_eq!(transcript.language, None);
+
+        let detected: DeepgramResponse = serde_json::from_str(
+            r#"{"results": {"channels": [{"detected_language": "de", "alternatives": [
+                {"transcript": "Hallo.", "confidence": 0.9}
+            ]}]}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            deepgram_transcript(detected).language.as_deref(),
+            Some("de")
+        );
+
+        let empty: DeepgramResponse =
+            serde_json::from_str(r#"{"results": {"channels": []}}"#).unwrap();
+        assert_eq!(deepgram_transcript(empty).text, "");
+    }
+}
@@ -0,0 +1,110 @@
+//! Vosk engine
+//!
+//! Kaldi models run through Vosk. They're far less accurate than Whisper or
+//! Parakeet and don't punctuate, but need only a fraction of the CPU and
+//! memory, which keeps old machines usable with Active Listening running.
+//! Only built with the `vosk` feature, as it links against libvosk.
+
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use std::error::Error;
+use std::path::Path;
+use transcribe_rs::{TranscriptionEngine, TranscriptionResult, TranscriptionSegment};
+use vosk::{Model, Recognizer};
+
+#[derive(Default)]
+pub struct VoskEngine {
+    model: Option<Model>,
+    /// Mean word confidence of the last transcription
+    last_confidence: Option<f32>,
+}
+
+/// Samples as the 16-bit integers Vosk expects
+fn to_pcm16(audio: &[f32]) -> Vec<i16> {
+    audio
+        .iter()
+        .map(|sample| (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+        .collect()
+}
+
+impl VoskEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mean word confidence of the last transcription. Vosk models are for
+    /// one language, which it doesn't report.
+    pub fn last_confidence(&self) -> Option<f32> {
+        self.last_confidence
+    }
+}
+
+impl TranscriptionEngine for VoskEngine {
+    type InferenceParams = ();
+    type ModelParams = ();
+
+    /// Load the model directory of a Vosk model
+    fn load_model_with_params(
+        &mut self,
+        model_path: &Path,
+        _params: Self::ModelParams,
+    ) -> Result<(), Box<dyn Error>> {
+        let model = Model::new(model_path.to_string_lossy())
+            .ok_or_else(|| format!("no Vosk model in {}", model_path.display()))?;
+        self.model = Some(model);
+        Ok(())
+    }
+
+    fn unload_model(&mut self) {
+        self.model = None;
+        self.last_confidence = None;
+    }
+
+    /// Transcribe a whole recording, with the timing of each word
+    fn transcribe_samples(
+        &mut self,
+        samples: Vec<f32>,
+        _params: Option<Self::InferenceParams>,
+    ) -> Result<TranscriptionResult, Box<dyn Error>> {
+        let model = self.model.as_ref().ok_or("Vosk model not loaded")?;
+        let mut recognizer = Recognizer::new(model, WHISPER_SAMPLE_RATE as f32)
+            .ok_or("Failed to create Vosk recognizer")?;
+        recognizer.set_words(true);
+        recognizer
+            .accept_waveform(&to_pcm16(&samples))
+            .map_err(|e| format!("{:?}", e))?;
+
+        let result = recognizer
+            .final_result()
+            .single()
+            .ok_or("Vosk returned no transcription")?;
+        let segments = result
+            .result
+            .iter()
+            .map(|word| TranscriptionSegment {
+                start: word.start,
+                end: word.end,
+                text: word.word.to_string(),
+            })
+            .collect();
+        self.last_confidence = (!result.result.is_empty()).then(|| {
+            result.result.iter().map(|word| word.conf).sum::<f32>() / result.result.len() as f32
+        });
+        Ok(TranscriptionResult {
+            text: result.text.to_string(),
+            segments: Some(segments),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples_are_clamped_to_pcm16() {
+        assert_eq!(
+            to_pcm16(&[0.0, 0.5, 1.0, -1.0, 2.0, -2.0]),
+            [0, 16383, i16::MAX, -i16::MAX, i16::MAX, -i16::MAX]
+        );
+    }
+}
@@ -8,7 +8,7 @@ use serde::Serialize;
 use std::sync::Arc;
 use tauri::AppHandle;
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, specta::Type)]
 pub struct ModelStateEvent {
     pub event_type: String,
     pub model_id: Option<String>,
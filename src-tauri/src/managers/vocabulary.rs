@@ -14,6 +14,38 @@ pub struct VocabularyEntry {
     pub created_at: String,
 }
 
+/// Shortest word a correction teaches
+const MIN_LEARNED_TERM_CHARS: usize = 3;
+
+/// Words a correction added that the transcription didn't have, such as
+/// names and jargon the model misheard. Only words with a capital letter or
+/// a digit count, so rephrasing with everyday words teaches nothing.
+pub fn corrected_terms(original: &str, corrected: &str) -> Vec<String> {
+    let words = |text: &str| -> Vec<String> {
+        text.split_whitespace()
+            .map(|word| {
+                word.trim_matches(|c: char| !c.is_alphanumeric())
+                    .to_string()
+            })
+            .filter(|word| !word.is_empty())
+            .collect()
+    };
+    let heard: std::collections::HashSet<String> = words(original)
+        .into_iter()
+        .map(|word| word.to_lowercase())
+        .collect();
+
+    let mut terms: Vec<String> = Vec::new();
+    for word in words(corrected) {
+        let term_like = word.chars().count() >= MIN_LEARNED_TERM_CHARS
+            && word.chars().any(|c| c.is_uppercase() || c.is_ascii_digit());
+        if term_like && !heard.contains(&word.to_lowercase()) && !terms.contains(&word) {
+            terms.push(word);
+        }
+    }
+    terms
+}
+
 pub struct VocabularyManager {
     db_path: PathBuf,
 }
@@ -118,6 +150,25 @@ impl VocabularyManager {
         Ok(())
     }
 
+    pub fn remove_term_by_text(&self, term: &str) -> Result<(), String> {
+        let conn = self.get_connection()?;
+        conn.execute("DELETE FROM vocabulary WHERE term = ?1", params![term])
+            .map_err(|e| format!("Failed to delete term: {}", e))?;
+        Ok(())
+    }
+
+    /// Insert a term received from sync, or update its category if it already exists
+    pub fn upsert_synced_term(&self, term: &str, category: Option<&str>) -> Result<(), String> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "INSERT INTO vocabulary (term, source, category) VALUES (?1, 'sync', ?2)
+             ON CONFLICT(term) DO UPDATE SET category = excluded.category",
+            params![term, category],
+        )
+        .map_err(|e| format!("Failed to sync term: {}", e))?;
+        Ok(())
+    }
+
     pub fn import_vocabulary(&self, json: &str) -> Result<usize, String> {
         let entries: Vec<VocabularyEntry> =
             serde_json::from_str(json).map_err(|e| format!("Invalid JSON: {}", e))?;
@@ -140,9 +191,50 @@ impl VocabularyManager {
         Ok(count)
     }
 
+    /// Add the terms a transcription correction introduced, or count them
+    /// again if they're known. Returns the terms learned.
+    pub fn learn_from_correction(
+        &self,
+        original: &str,
+        corrected: &str,
+    ) -> Result<Vec<String>, String> {
+        let terms = corrected_terms(original, corrected);
+        let conn = self.get_connection()?;
+        for term in &terms {
+            conn.execute(
+                "INSERT INTO vocabulary (term, source) VALUES (?1, 'learned')
+                 ON CONFLICT(term) DO UPDATE SET frequency = frequency + 1",
+                params![term],
+            )
+            .map_err(|e| format!("Failed to learn term: {}", e))?;
+        }
+        if !terms.is_empty() {
+            info!("Learned {} vocabulary terms from a correction", terms.len());
+        }
+        Ok(terms)
+    }
+
     pub fn export_vocabulary(&self) -> Result<String, String> {
         let entries = self.get_vocabulary()?;
         serde_json::to_string_pretty(&entries)
             .map_err(|e| format!("Failed to serialize vocabulary: {}", e))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corrected_terms_are_new_names_and_jargon() {
+        assert_eq!(
+            corrected_terms(
+                "deploy the cooper netties cluster to us east",
+                "Deploy the Kubernetes cluster to us-east-2, ask Siobhan."
+            ),
+            vec!["Kubernetes", "us-east-2", "Siobhan"]
+        );
+        // Recasing a word that was heard, or rewording, teaches nothing
+        assert!(corrected_terms("meet with dana", "Meet Dana at noon").is_empty());
+    }
+}
@@ -0,0 +1,239 @@
+//! Wake word
+//!
+//! Listens for the wake word on the always-on microphone stream while
+//! nothing is being recorded. Hearing it runs the transcribe or Ask AI
+//! action the way a toggle shortcut would, and the dictation is stopped
+//! again once the speaker goes quiet.
+
+use crate::actions::ACTION_MAP;
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::{OnnxWakeWordModel, WakeWordDetector};
+use crate::managers::audio::AudioRecordingManager;
+use crate::settings::{get_settings, WakeWordSettings};
+use crate::utils::set_toggle;
+use log::{debug, info};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+/// What the actions are told started them
+const SHORTCUT_STRING: &str = "WakeWord";
+
+/// RMS above which a frame counts as speech when deciding the speaker is done
+const SPEECH_RMS: f32 = 0.01;
+/// A dictation nobody speaks into is stopped after this long
+const NO_SPEECH_TIMEOUT: Duration = Duration::from_secs(8);
+/// Longest dictation the wake word runs
+const MAX_DICTATION: Duration = Duration::from_secs(120);
+
+/// A dictation started by the wake word
+struct Dictation {
+    binding_id: String,
+    started: Instant,
+    /// Whether any of its recording came through yet
+    recording: bool,
+    heard_speech: bool,
+    quiet: Duration,
+}
+
+impl Dictation {
+    fn new(binding_id: &str) -> Self {
+        Self {
+            binding_id: binding_id.to_string(),
+            started: Instant::now(),
+            recording: false,
+            heard_speech: false,
+            quiet: Duration::ZERO,
+        }
+    }
+
+    /// Feed a recorded frame; returns whether the dictation is over
+    fn push(&mut self, samples: &[f32], silence_timeout: Duration) -> bool {
+        self.recording = true;
+        let rms = (samples.iter().map(|s| s * s).sum::<f32>() / samples.len().max(1) as f32).sqrt();
+        if rms >= SPEECH_RMS {
+            self.heard_speech = true;
+            self.quiet = Duration::ZERO;
+        } else {
+            self.quiet += Duration::from_micros(
+                samples.len() as u64 * 1_000_000 / WHISPER_SAMPLE_RATE as u64,
+            );
+        }
+
+        let elapsed = self.started.elapsed();
+        (self.heard_speech && self.quiet >= silence_timeout)
+            || (!self.heard_speech && elapsed >= NO_SPEECH_TIMEOUT)
+            || elapsed >= MAX_DICTATION
+    }
+}
+
+pub struct WakeWordManager {
+    app_handle: AppHandle,
+    settings: Mutex<WakeWordSettings>,
+    detector: Mutex<Option<WakeWordDetector>>,
+    dictation: Mutex<Option<Dictation>>,
+}
+
+impl WakeWordManager {
+    pub fn new(app_handle: &AppHandle) -> Self {
+        Self {
+            app_handle: app_handle.clone(),
+            settings: Mutex::new(WakeWordSettings::default()),
+            detector: Mutex::new(None),
+            dictation: Mutex::new(None),
+        }
+    }
+
+    /// Load the keyword model when the wake word is enabled, or unload it
+    pub fn apply_settings(&self) -> Result<(), String> {
+        let settings = get_settings(&self.app_handle).wake_word;
+        let mut detector = self.detector.lock().map_err(|e| e.to_string())?;
+        *detector = None;
+        *self.settings.lock().map_err(|e| e.to_string())? = settings.clone();
+        if !settings.enabled {
+            return Ok(());
+        }
+
+        let path = settings
+            .model_path
+            .as_deref()
+            .ok_or("The wake word needs a keyword model")?;
+        let model = OnnxWakeWordModel::new(path).map_err(|e| e.to_string())?;
+        *detector = Some(WakeWordDetector::new(Box::new(model), settings.threshold()));
+        info!("Listening for the wake word with {}", path);
+        Ok(())
+    }
+
+    /// Feed a frame heard while nothing is being recorded
+    pub fn push_idle(&self, samples: &[f32]) {
+        let Ok(mut detector) = self.detector.lock() else {
+            return;
+        };
+        let Some(detector) = detector.as_mut() else {
+            return;
+        };
+
+        if let Ok(mut dictation) = self.dictation.lock() {
+            match dictation.as_ref() {
+                // Still starting; the wake word was just heard
+                Some(d) if !d.recording && d.started.elapsed() < NO_SPEECH_TIMEOUT => return,
+                // A dictation that ended some other way, e.g. by its shortcut,
+                // or never started
+                Some(_) => {
+                    *dictation = None;
+                    detector.reset();
+                }
+                None => {}
+            }
+        }
+
+        if let Some(score) = detector.push(samples) {
+            info!("Wake word heard (score {:.2})", score);
+            // What was heard before the dictation mustn't set it off again
+            detector.reset();
+            self.start();
+        }
+    }
+
+    /// Feed a frame of a recording, to stop a wake word dictation once the
+    /// speaker goes quiet
+    pub fn push_recording(&self, samples: &[f32]) {
+        let Ok(mut dictation) = self.dictation.lock() else {
+            return;
+        };
+        let Some(current) = dictation.as_mut() else {
+            return;
+        };
+        let silence_timeout = self
+            .settings
+            .lock()
+            .map(|settings| Duration::from_millis(settings.silence_timeout_ms as u64))
+            .unwrap_or_default();
+        if current.push(samples, silence_timeout) {
+            let binding_id = current.binding_id.clone();
+            *dictation = None;
+            drop(dictation);
+            self.stop(binding_id);
+        }
+    }
+
+    /// Run the wake word's action. Starting takes the recording manager's
+    /// locks and plays sounds, which mustn't happen on the audio thread this
+    /// is called on.
+    fn start(&self) {
+        let Ok(binding_id) = self.settings.lock().map(|s| s.action.binding_id()) else {
+            return;
+        };
+        if let Ok(mut dictation) = self.dictation.lock() {
+            *dictation = Some(Dictation::new(binding_id));
+        }
+
+        let app = self.app_handle.clone();
+        thread::spawn(move || {
+            let recording = app.state::<Arc<AudioRecordingManager>>().is_recording();
+            if recording || !set_toggle(&app, binding_id, true) {
+                debug!("Ignoring the wake word while '{}' is running", binding_id);
+                if let Some(manager) = app.try_state::<Arc<WakeWordManager>>() {
+                    if let Ok(mut dictation) = manager.dictation.lock() {
+                        *dictation = None;
+                    }
+                }
+                return;
+            }
+            if let Some(action) = ACTION_MAP.get(binding_id) {
+                action.start(&app, binding_id, SHORTCUT_STRING);
+            }
+        });
+    }
+
+    fn stop(&self, binding_id: String) {
+        let app = self.app_handle.clone();
+        thread::spawn(move || {
+            // Already stopped by its shortcut
+            if !set_toggle(&app, &binding_id, false) {
+                return;
+            }
+            debug!("Wake word dictation over, stopping '{}'", binding_id);
+            if let Some(action) = ACTION_MAP.get(binding_id.as_str()) {
+                action.stop(&app, &binding_id, SHORTCUT_STRING);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SILENCE: Duration = Duration::from_millis(300);
+
+    fn frame(level: f32) -> Vec<f32> {
+        // 100 ms at 16 kHz
+        vec![level; 1600]
+    }
+
+    #[test]
+    fn test_dictation_ends_after_quiet_following_speech() {
+        let mut dictation = Dictation::new("transcribe");
+        // Silence before anything is said doesn't end it
+        for _ in 0..5 {
+            assert!(!dictation.push(&frame(0.0), SILENCE));
+        }
+        assert!(!dictation.push(&frame(0.2), SILENCE));
+        assert!(!dictation.push(&frame(0.0), SILENCE));
+        assert!(!dictation.push(&frame(0.0), SILENCE));
+        // Speaking again restarts the quiet
+        assert!(!dictation.push(&frame(0.2), SILENCE));
+        assert!(!dictation.push(&frame(0.0), SILENCE));
+        assert!(!dictation.push(&frame(0.0), SILENCE));
+        assert!(dictation.push(&frame(0.0), SILENCE));
+    }
+
+    #[test]
+    fn test_dictation_without_speech_times_out() {
+        let mut dictation = Dictation::new("transcribe");
+        dictation.started -= NO_SPEECH_TIMEOUT;
+        assert!(dictation.push(&frame(0.0), SILENCE));
+    }
+}
@@ -0,0 +1,212 @@
+//! Webhook Manager
+//!
+//! POSTs finished dictations, ended Active Listening sessions and meeting
+//! summaries as JSON to the URL in the webhook settings:
+//!
+//! ```json
+//! {"event":"transcription_completed","timestamp":1760000000000,"data":{"text":"hello world"}}
+//! ```
+//!
+//! Deliveries run in the background. When the server can't be reached or
+//! answers with a server error they are retried, waiting twice as long
+//! before each retry.
+
+use crate::access_policy;
+use crate::settings::{get_settings, WebhookEvent, WebhookSettings};
+use crate::warning_notifications::is_valid_webhook_url;
+use log::{debug, warn};
+use reqwest::header::AUTHORIZATION;
+use serde::Serialize;
+use std::time::Duration;
+use tauri::AppHandle;
+
+/// Time the server gets to answer one delivery
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+/// Wait before the first retry
+const FIRST_RETRY_DELAY: Duration = Duration::from_secs(2);
+/// Longest wait between retries
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Body of every delivery
+#[derive(Serialize)]
+struct WebhookPayload<T: Serialize> {
+    event: WebhookEvent,
+    /// Milliseconds since the Unix epoch
+    timestamp: i64,
+    data: T,
+}
+
+/// Why a delivery failed
+#[derive(Debug)]
+enum DeliveryError {
+    /// The server couldn't be reached, was busy or failed, so it's worth
+    /// trying again
+    Retryable(String),
+    /// The request was rejected or couldn't be made
+    Failed(String),
+}
+
+/// Wait before retry `attempt`, counting from 0
+fn retry_delay(attempt: u32) -> Duration {
+    FIRST_RETRY_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_RETRY_DELAY)
+}
+
+/// Whether a response status is worth retrying
+fn is_retryable_status(status: u16) -> bool {
+    status == 429 || status >= 500
+}
+
+async fn post(settings: &WebhookSettings, body: &serde_json::Value) -> Result<u16, DeliveryError> {
+    let url = settings
+        .target_url()
+        .ok_or_else(|| DeliveryError::Failed("No webhook URL is configured".to_string()))?;
+    if !is_valid_webhook_url(url) {
+        return Err(DeliveryError::Failed(
+            "Only http(s) webhook URLs are supported".to_string(),
+        ));
+    }
+    access_policy::check_url(url).map_err(DeliveryError::Failed)?;
+
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| DeliveryError::Failed(format!("Failed to create HTTP client: {}", e)))?;
+    let mut request = client.post(url).json(body);
+    if let Some(auth) = settings
+        .auth_header
+        .as_deref()
+        .map(str::trim)
+        .filter(|auth| !auth.is_empty())
+    {
+        request = request.header(AUTHORIZATION, auth);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| DeliveryError::Retryable(format!("Failed to reach webhook: {}", e)))?;
+    let status = response.status();
+    if status.is_success() {
+        Ok(status.as_u16())
+    } else if is_retryable_status(status.as_u16()) {
+        Err(DeliveryError::Retryable(format!(
+            "Webhook returned {}",
+            status
+        )))
+    } else {
+        Err(DeliveryError::Failed(format!(
+            "Webhook returned {}",
+            status
+        )))
+    }
+}
+
+/// Deliver a payload, retrying up to `max_retries` times
+async fn deliver(settings: &WebhookSettings, body: &serde_json::Value) -> Result<u16, String> {
+    let mut attempt = 0;
+    loop {
+        match post(settings, body).await {
+            Ok(status) => return Ok(status),
+            Err(DeliveryError::Retryable(e)) if attempt < settings.max_retries => {
+                let delay = retry_delay(attempt);
+                debug!("{}, retrying webhook delivery in {:?}", e, delay);
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(DeliveryError::Retryable(e)) | Err(DeliveryError::Failed(e)) => return Err(e),
+        }
+    }
+}
+
+fn payload<T: Serialize>(event: WebhookEvent, data: T) -> Result<serde_json::Value, String> {
+    serde_json::to_value(WebhookPayload {
+        event,
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        data,
+    })
+    .map_err(|e| format!("Failed to serialize webhook payload: {}", e))
+}
+
+pub struct WebhookManager {
+    app_handle: AppHandle,
+}
+
+impl WebhookManager {
+    pub fn new(app: &AppHandle) -> Self {
+        Self {
+            app_handle: app.clone(),
+        }
+    }
+
+    /// Deliver an event in the background if the webhook settings ask for it
+    pub fn notify<T: Serialize>(&self, event: WebhookEvent, data: &T) {
+        let settings = get_settings(&self.app_handle).webhooks;
+        if !settings.sends(event) {
+            return;
+        }
+        let body = match payload(event, data) {
+            Ok(body) => body,
+            Err(e) => {
+                warn!("{}", e);
+                return;
+            }
+        };
+        tauri::async_runtime::spawn(async move {
+            match deliver(&settings, &body).await {
+                Ok(status) => debug!("Delivered {:?} webhook ({})", event, status),
+                Err(e) => warn!("Failed to deliver {:?} webhook: {}", event, e),
+            }
+        });
+    }
+
+    /// Send a test payload right away, without retries, and return the
+    /// response status
+    pub async fn send_test(&self) -> Result<u16, String> {
+        let settings = get_settings(&self.app_handle).webhooks;
+        let body = payload(
+            WebhookEvent::Test,
+            serde_json::json!({ "message": "Test delivery from Dictum" }),
+        )?;
+        post(&settings, &body).await.map_err(|e| match e {
+            DeliveryError::Retryable(e) | DeliveryError::Failed(e) => e,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_the_cap() {
+        assert_eq!(retry_delay(0), Duration::from_secs(2));
+        assert_eq!(retry_delay(1), Duration::from_secs(4));
+        assert_eq!(retry_delay(3), Duration::from_secs(16));
+        assert_eq!(retry_delay(10), MAX_RETRY_DELAY);
+        assert_eq!(retry_delay(u32::MAX), MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn test_only_busy_and_server_errors_are_retried() {
+        assert!(is_retryable_status(500));
+        assert!(is_retryable_status(503));
+        assert!(is_retryable_status(429));
+        assert!(!is_retryable_status(400));
+        assert!(!is_retryable_status(401));
+        assert!(!is_retryable_status(404));
+    }
+
+    #[test]
+    fn test_payload_wraps_data_with_event_and_timestamp() {
+        let body = payload(
+            WebhookEvent::TranscriptionCompleted,
+            serde_json::json!({ "text": "hello" }),
+        )
+        .unwrap();
+        assert_eq!(body["event"], "transcription_completed");
+        assert_eq!(body["data"]["text"], "hello");
+        assert!(body["timestamp"].as_i64().unwrap() > 0);
+    }
+}
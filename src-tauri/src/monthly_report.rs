@@ -0,0 +1,683 @@
+//! Local monthly usage report
+//!
+//! Summarizes a calendar month of dictation, model usage, estimated LLM usage
+//! and storage growth into a Markdown or HTML file. Everything is computed
+//! from local databases and files; nothing is sent over the network.
+
+use chrono::{Datelike, Local, NaiveDate, TimeZone};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::BTreeMap;
+
+use crate::managers::history::{AskAiUsage, UsageEntry};
+
+/// Bytes of audio per second in saved recordings (16 kHz mono, 16-bit)
+const WAV_BYTES_PER_SECOND: u64 = 32_000;
+
+/// Size of the WAV header preceding the samples
+const WAV_HEADER_BYTES: u64 = 44;
+
+/// Rough characters-per-token ratio used for LLM usage estimates
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Output format of the monthly report
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum ReportFormat {
+    #[serde(rename = "markdown")]
+    Markdown,
+    #[serde(rename = "html")]
+    Html,
+}
+
+impl ReportFormat {
+    pub fn file_extension(&self) -> &str {
+        match self {
+            ReportFormat::Markdown => "md",
+            ReportFormat::Html => "html",
+        }
+    }
+}
+
+/// A calendar month in local time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReportPeriod {
+    pub year: i32,
+    pub month: u32,
+}
+
+impl ReportPeriod {
+    pub fn new(year: i32, month: u32) -> Result<Self, String> {
+        if !(1..=12).contains(&month) {
+            return Err(format!("Invalid month: {}", month));
+        }
+        Ok(Self { year, month })
+    }
+
+    pub fn current() -> Self {
+        let today = Local::now().date_naive();
+        Self {
+            year: today.year(),
+            month: today.month(),
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        if self.month == 1 {
+            Self {
+                year: self.year - 1,
+                month: 12,
+            }
+        } else {
+            Self {
+                year: self.year,
+                month: self.month - 1,
+            }
+        }
+    }
+
+    fn next(&self) -> Self {
+        if self.month == 12 {
+            Self {
+                year: self.year + 1,
+                month: 1,
+            }
+        } else {
+            Self {
+                year: self.year,
+                month: self.month + 1,
+            }
+        }
+    }
+
+    fn first_day(&self) -> Result<NaiveDate, String> {
+        NaiveDate::from_ymd_opt(self.year, self.month, 1)
+            .ok_or_else(|| format!("Invalid report period {}-{:02}", self.year, self.month))
+    }
+
+    /// Start (inclusive) and end (exclusive) of the month as unix seconds
+    pub fn bounds(&self) -> Result<(i64, i64), String> {
+        let to_timestamp = |period: &ReportPeriod| -> Result<i64, String> {
+            let midnight = period.first_day()?.and_hms_opt(0, 0, 0).unwrap_or_default();
+            Local
+                .from_local_datetime(&midnight)
+                .earliest()
+                .map(|dt| dt.timestamp())
+                .ok_or_else(|| "Could not resolve local midnight".to_string())
+        };
+        Ok((to_timestamp(self)?, to_timestamp(&self.next())?))
+    }
+
+    /// Human-readable label, e.g. "March 2026"
+    pub fn label(&self) -> String {
+        self.first_day()
+            .map(|d| d.format("%B %Y").to_string())
+            .unwrap_or_else(|_| format!("{}-{:02}", self.year, self.month))
+    }
+
+    /// Short identifier used in file names, e.g. "2026-03"
+    pub fn slug(&self) -> String {
+        format!("{}-{:02}", self.year, self.month)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DictationStats {
+    pub entries: usize,
+    pub words: usize,
+    pub audio_seconds: u64,
+    pub active_days: usize,
+    /// Local date and number of dictations on the busiest day
+    pub busiest_day: Option<(String, usize)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModelUsage {
+    pub model: String,
+    pub entries: usize,
+    pub words: usize,
+    /// Mean of the ratings users gave the model's transcriptions, from 1 to 5
+    pub average_rating: Option<f32>,
+    /// Transcriptions users had to correct
+    pub corrections: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LlmUsage {
+    pub post_process_provider: String,
+    pub post_process_is_local: bool,
+    pub post_process_runs: usize,
+    pub post_process_tokens: usize,
+    pub ask_ai_turns: usize,
+    pub ask_ai_tokens: usize,
+    /// Estimated spend on the post-processing provider, if a price was given
+    pub estimated_cost: Option<f64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StorageGrowth {
+    pub recordings_added_bytes: u64,
+    pub previous_month_added_bytes: u64,
+    pub total_recordings_bytes: u64,
+    pub history_db_bytes: u64,
+    pub rag_db_bytes: u64,
+    pub documents_added: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct MonthlyReport {
+    pub period: ReportPeriod,
+    pub generated_at: String,
+    pub dictation: DictationStats,
+    pub models: Vec<ModelUsage>,
+    pub llm: LlmUsage,
+    pub storage: StorageGrowth,
+}
+
+/// Approximate audio length of a saved recording from its file size
+pub fn estimate_audio_seconds(recording_bytes: u64) -> u64 {
+    recording_bytes.saturating_sub(WAV_HEADER_BYTES) / WAV_BYTES_PER_SECOND
+}
+
+pub fn estimate_tokens(chars: usize) -> usize {
+    chars.div_ceil(CHARS_PER_TOKEN)
+}
+
+fn local_date(timestamp: i64) -> String {
+    Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Dictation totals for the month; `recording_bytes` gives the size of each entry's audio
+pub fn summarize_dictation(entries: &[UsageEntry], recording_bytes: &[u64]) -> DictationStats {
+    let mut per_day: BTreeMap<String, usize> = BTreeMap::new();
+    for entry in entries {
+        *per_day.entry(local_date(entry.timestamp)).or_default() += 1;
+    }
+
+    // Earliest date wins ties since BTreeMap iterates in order
+    let busiest_day = per_day
+        .iter()
+        .fold(
+            None,
+            |best: Option<(&String, usize)>, (day, &count)| match best {
+                Some((_, best_count)) if best_count >= count => best,
+                _ => Some((day, count)),
+            },
+        )
+        .map(|(day, count)| (day.clone(), count));
+
+    DictationStats {
+        entries: entries.len(),
+        words: entries.iter().map(|e| e.word_count).sum(),
+        audio_seconds: recording_bytes
+            .iter()
+            .map(|&b| estimate_audio_seconds(b))
+            .sum(),
+        active_days: per_day.len(),
+        busiest_day,
+    }
+}
+
+/// Dictations grouped by transcription model, most used first, with the
+/// feedback users gave on them
+pub fn summarize_models(entries: &[UsageEntry]) -> Vec<ModelUsage> {
+    let mut by_model: BTreeMap<String, ModelUsage> = BTreeMap::new();
+    // Sum and count of each model's ratings
+    let mut ratings: BTreeMap<String, (u32, u32)> = BTreeMap::new();
+    for entry in entries {
+        let model = entry
+            .transcription_model
+            .clone()
+            .unwrap_or_else(|| "unknown".to_string());
+        let usage = by_model.entry(model.clone()).or_insert_with(|| ModelUsage {
+            model: model.clone(),
+            entries: 0,
+            words: 0,
+            average_rating: None,
+            corrections: 0,
+        });
+        usage.entries += 1;
+        usage.words += entry.word_count;
+        usage.corrections += entry.corrected as usize;
+        if let Some(rating) = entry.rating {
+            let (sum, count) = ratings.entry(model).or_default();
+            *sum += rating as u32;
+            *count += 1;
+        }
+    }
+
+    let mut models: Vec<ModelUsage> = by_model
+        .into_values()
+        .map(|mut usage| {
+            usage.average_rating = ratings
+                .get(&usage.model)
+                .map(|&(sum, count)| sum as f32 / count as f32);
+            usage
+        })
+        .collect();
+    models.sort_by(|a, b| b.entries.cmp(&a.entries));
+    models
+}
+
+/// Estimated LLM token usage for post-processing and Ask AI.
+///
+/// Ask AI runs on the local Ollama server, so only post-processing on a
+/// remote provider is priced.
+pub fn summarize_llm_usage(
+    entries: &[UsageEntry],
+    ask_ai: &[AskAiUsage],
+    provider: &str,
+    provider_is_local: bool,
+    price_per_million_tokens: Option<f64>,
+) -> LlmUsage {
+    let post_processed: Vec<&UsageEntry> = entries
+        .iter()
+        .filter(|e| e.post_processed_chars.is_some())
+        .collect();
+    let post_process_tokens = post_processed
+        .iter()
+        .map(|e| {
+            estimate_tokens(e.transcription_chars)
+                + estimate_tokens(e.post_processed_chars.unwrap_or(0))
+        })
+        .sum();
+    let ask_ai_tokens = ask_ai
+        .iter()
+        .map(|t| estimate_tokens(t.question_chars) + estimate_tokens(t.response_chars))
+        .sum();
+
+    let estimated_cost = price_per_million_tokens.map(|price| {
+        if provider_is_local {
+            0.0
+        } else {
+            post_process_tokens as f64 / 1_000_000.0 * price
+        }
+    });
+
+    LlmUsage {
+        post_process_provider: provider.to_string(),
+        post_process_is_local: provider_is_local,
+        post_process_runs: post_processed.len(),
+        post_process_tokens,
+        ask_ai_turns: ask_ai.len(),
+        ask_ai_tokens,
+        estimated_cost,
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+fn format_duration(seconds: u64) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m {}s", minutes, seconds % 60)
+    }
+}
+
+fn format_growth(current: u64, previous: u64) -> String {
+    if previous == 0 {
+        return "n/a".to_string();
+    }
+    let change = (current as f64 - previous as f64) / previous as f64 * 100.0;
+    format!("{:+.0}%", change)
+}
+
+fn format_rating(rating: Option<f32>) -> String {
+    rating
+        .map(|rating| format!("{:.1}/5", rating))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_cost(llm: &LlmUsage) -> String {
+    match llm.estimated_cost {
+        Some(cost) => format!("${:.2}", cost),
+        None => "not estimated (no price given)".to_string(),
+    }
+}
+
+/// Label/value rows shared by both renderers
+fn summary_rows(report: &MonthlyReport) -> Vec<(&'static str, Vec<(String, String)>)> {
+    let d = &report.dictation;
+    let llm = &report.llm;
+    let s = &report.storage;
+
+    let provider = if llm.post_process_is_local {
+        format!("{} (local)", llm.post_process_provider)
+    } else {
+        llm.post_process_provider.clone()
+    };
+
+    vec![
+        (
+            "Dictation",
+            vec![
+                ("Dictations".to_string(), d.entries.to_string()),
+                ("Words".to_string(), d.words.to_string()),
+                (
+                    "Audio recorded".to_string(),
+                    format_duration(d.audio_seconds),
+                ),
+                ("Active days".to_string(), d.active_days.to_string()),
+                (
+                    "Busiest day".to_string(),
+                    d.busiest_day
+                        .as_ref()
+                        .map(|(day, count)| format!("{} ({} dictations)", day, count))
+                        .unwrap_or_else(|| "-".to_string()),
+                ),
+            ],
+        ),
+        (
+            "LLM usage (estimated)",
+            vec![
+                ("Post-processing provider".to_string(), provider),
+                (
+                    "Post-processed dictations".to_string(),
+                    llm.post_process_runs.to_string(),
+                ),
+                (
+                    "Post-processing tokens".to_string(),
+                    format!("~{}", llm.post_process_tokens),
+                ),
+                ("Ask AI questions".to_string(), llm.ask_ai_turns.to_string()),
+                (
+                    "Ask AI tokens (local)".to_string(),
+                    format!("~{}", llm.ask_ai_tokens),
+                ),
+                ("Estimated cost".to_string(), format_cost(llm)),
+            ],
+        ),
+        (
+            "Storage",
+            vec![
+                (
+                    "Recordings added".to_string(),
+                    format!(
+                        "{} ({} vs previous month)",
+                        format_bytes(s.recordings_added_bytes),
+                        format_growth(s.recordings_added_bytes, s.previous_month_added_bytes)
+                    ),
+                ),
+                (
+                    "All recordings".to_string(),
+                    format_bytes(s.total_recordings_bytes),
+                ),
+                (
+                    "History database".to_string(),
+                    format_bytes(s.history_db_bytes),
+                ),
+                (
+                    "Knowledge base database".to_string(),
+                    format_bytes(s.rag_db_bytes),
+                ),
+                (
+                    "Knowledge base documents added".to_string(),
+                    s.documents_added.to_string(),
+                ),
+            ],
+        ),
+    ]
+}
+
+pub fn render_markdown(report: &MonthlyReport) -> String {
+    let mut out = format!(
+        "# Dictum report: {}\n\n_Generated {} on this device. Token counts are estimates._\n",
+        report.period.label(),
+        report.generated_at
+    );
+
+    for (title, rows) in summary_rows(report) {
+        out.push_str(&format!("\n## {}\n\n| | |\n|---|---|\n", title));
+        for (label, value) in rows {
+            out.push_str(&format!("| {} | {} |\n", label, value));
+        }
+    }
+
+    out.push_str("\n## Models\n\n");
+    if report.models.is_empty() {
+        out.push_str("No dictations this month.\n");
+    } else {
+        out.push_str(
+            "| Model | Dictations | Words | Avg. rating | Corrected |\n|---|---|---|---|---|\n",
+        );
+        for model in &report.models {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                model.model.replace('|', "\\|"),
+                model.entries,
+                model.words,
+                format_rating(model.average_rating),
+                model.corrections
+            ));
+        }
+    }
+
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn render_html(report: &MonthlyReport) -> String {
+    let title = format!("Dictum report: {}", report.period.label());
+    let mut body = format!(
+        "<h1>{}</h1>\n<p><em>Generated {} on this device. Token counts are estimates.</em></p>\n",
+        escape_html(&title),
+        escape_html(&report.generated_at)
+    );
+
+    for (section, rows) in summary_rows(report) {
+        body.push_str(&format!("<h2>{}</h2>\n<table>\n", escape_html(section)));
+        for (label, value) in rows {
+            body.push_str(&format!(
+                "<tr><th>{}</th><td>{}</td></tr>\n",
+                escape_html(&label),
+                escape_html(&value)
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+
+    body.push_str("<h2>Models</h2>\n");
+    if report.models.is_empty() {
+        body.push_str("<p>No dictations this month.</p>\n");
+    } else {
+        body.push_str("<table>\n<tr><th>Model</th><th>Dictations</th><th>Words</th><th>Avg. rating</th><th>Corrected</th></tr>\n");
+        for model in &report.models {
+            body.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(&model.model),
+                model.entries,
+                model.words,
+                format_rating(model.average_rating),
+                model.corrections
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>body{{font-family:sans-serif;max-width:720px;margin:2em auto}}th{{text-align:left;padding-right:2em}}table{{border-collapse:collapse;margin-bottom:1em}}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape_html(&title),
+        body
+    )
+}
+
+pub fn render(report: &MonthlyReport, format: ReportFormat) -> String {
+    match format {
+        ReportFormat::Markdown => render_markdown(report),
+        ReportFormat::Html => render_html(report),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: i64, words: usize, model: Option<&str>, post: Option<usize>) -> UsageEntry {
+        UsageEntry {
+            timestamp,
+            file_name: format!("handy-{}.wav", timestamp),
+            word_count: words,
+            transcription_chars: words * 5,
+            post_processed_chars: post,
+            transcription_model: model.map(|m| m.to_string()),
+            rating: None,
+            corrected: false,
+        }
+    }
+
+    fn sample_report() -> MonthlyReport {
+        let entries = vec![
+            entry(1_700_000_000, 10, Some("small"), Some(40)),
+            entry(1_700_000_100, 5, Some("<turbo>"), None),
+        ];
+        MonthlyReport {
+            period: ReportPeriod::new(2026, 3).unwrap(),
+            generated_at: "2026-04-01 09:00".to_string(),
+            dictation: summarize_dictation(&entries, &[32_044, 64_044]),
+            models: summarize_models(&entries),
+            llm: summarize_llm_usage(&entries, &[], "openai", false, Some(2.0)),
+            storage: StorageGrowth::default(),
+        }
+    }
+
+    #[test]
+    fn test_period_validation_and_wraparound() {
+        assert!(ReportPeriod::new(2026, 13).is_err());
+        assert!(ReportPeriod::new(2026, 0).is_err());
+
+        let january = ReportPeriod::new(2026, 1).unwrap();
+        assert_eq!(january.previous(), ReportPeriod::new(2025, 12).unwrap());
+        assert_eq!(january.slug(), "2026-01");
+
+        let (start, end) = ReportPeriod::new(2026, 12).unwrap().bounds().unwrap();
+        assert!(end > start);
+        // December spans 31 days, give or take a DST hour
+        assert!((end - start - 31 * 86_400).abs() <= 3600);
+    }
+
+    #[test]
+    fn test_summarize_dictation() {
+        let entries = vec![
+            entry(1_700_000_000, 10, None, None),
+            entry(1_700_000_060, 4, None, None),
+        ];
+        let stats = summarize_dictation(&entries, &[32_044, 320_044]);
+
+        assert_eq!(stats.entries, 2);
+        assert_eq!(stats.words, 14);
+        assert_eq!(stats.audio_seconds, 11);
+        assert_eq!(stats.active_days, 1);
+        assert_eq!(stats.busiest_day.map(|(_, count)| count), Some(2));
+
+        assert_eq!(summarize_dictation(&[], &[]), DictationStats::default());
+    }
+
+    #[test]
+    fn test_summarize_models_groups_unknown() {
+        let entries = vec![
+            entry(1, 3, Some("small"), None),
+            entry(2, 4, None, None),
+            entry(3, 5, Some("small"), None),
+        ];
+        let models = summarize_models(&entries);
+
+        assert_eq!(models[0].model, "small");
+        assert_eq!(models[0].entries, 2);
+        assert_eq!(models[0].words, 8);
+        assert_eq!(models[1].model, "unknown");
+    }
+
+    #[test]
+    fn test_summarize_models_averages_feedback() {
+        let mut entries = vec![
+            entry(1, 3, Some("small"), None),
+            entry(2, 4, Some("small"), None),
+            entry(3, 5, Some("small"), None),
+            entry(4, 5, Some("turbo"), None),
+        ];
+        entries[0].rating = Some(2);
+        entries[0].corrected = true;
+        entries[1].rating = Some(5);
+        let models = summarize_models(&entries);
+
+        assert_eq!(models[0].average_rating, Some(3.5));
+        assert_eq!(models[0].corrections, 1);
+        assert_eq!(models[1].average_rating, None);
+        assert_eq!(format_rating(models[0].average_rating), "3.5/5");
+        assert_eq!(format_rating(None), "-");
+    }
+
+    #[test]
+    fn test_llm_cost_only_for_remote_providers() {
+        let entries = vec![entry(1, 10, None, Some(400_000)), entry(2, 10, None, None)];
+        let ask_ai = vec![AskAiUsage {
+            timestamp: 1,
+            question_chars: 8,
+            response_chars: 9,
+        }];
+
+        let remote = summarize_llm_usage(&entries, &ask_ai, "openai", false, Some(10.0));
+        assert_eq!(remote.post_process_runs, 1);
+        assert_eq!(remote.post_process_tokens, 13 + 100_000);
+        assert_eq!(remote.ask_ai_tokens, 2 + 3);
+        assert!((remote.estimated_cost.unwrap() - 1.00013).abs() < 1e-9);
+
+        let local = summarize_llm_usage(&entries, &ask_ai, "custom", true, Some(10.0));
+        assert_eq!(local.estimated_cost, Some(0.0));
+
+        let unpriced = summarize_llm_usage(&entries, &ask_ai, "openai", false, None);
+        assert_eq!(unpriced.estimated_cost, None);
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let markdown = render_markdown(&sample_report());
+        assert!(markdown.starts_with("# Dictum report: March 2026"));
+        assert!(markdown.contains("| Dictations | 2 |"));
+        assert!(markdown.contains("| small | 1 | 10 |"));
+        assert!(markdown.contains("| Recordings added | 0 B (n/a vs previous month) |"));
+    }
+
+    #[test]
+    fn test_render_html_escapes_values() {
+        let html = render_html(&sample_report());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("&lt;turbo&gt;"));
+        assert!(!html.contains("<turbo>"));
+    }
+
+    #[test]
+    fn test_format_helpers() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_duration(3725), "1h 2m");
+        assert_eq!(format_duration(65), "1m 5s");
+        assert_eq!(format_growth(150, 100), "+50%");
+        assert_eq!(estimate_audio_seconds(10), 0);
+    }
+}
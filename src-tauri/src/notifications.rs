@@ -0,0 +1,272 @@
+//! Central dispatch of system notifications
+//!
+//! Every feature hands its notifications to [`notify`] rather than calling
+//! the OS directly. Notifications are checked against the per-feature
+//! settings and quiet hours, then rate limited: after a feature shows a
+//! notification, anything else it raises within the batch window is held
+//! and shown as one ("3 new action items") when the window closes.
+
+use crate::settings::{get_settings, NotificationFeature, NotificationSeverity};
+use chrono::Timelike;
+use log::{debug, warn};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+/// Most held notifications listed in a batch's body
+const BATCH_BODY_LINES: usize = 3;
+
+#[derive(Clone, Debug)]
+pub struct Notification {
+    pub feature: NotificationFeature,
+    pub severity: NotificationSeverity,
+    pub title: String,
+    pub body: String,
+}
+
+fn batch_title(feature: NotificationFeature, count: usize) -> String {
+    match feature {
+        NotificationFeature::SoundDetection => format!("{} sounds detected", count),
+        NotificationFeature::Suggestions => format!("{} new suggestions", count),
+        NotificationFeature::Warnings => format!("{} conversation warnings", count),
+        NotificationFeature::ActionItems => format!("{} new action items", count),
+        NotificationFeature::Clipboard => format!("{} audio files copied", count),
+    }
+}
+
+/// One notification standing in for several held ones
+fn batch(feature: NotificationFeature, held: Vec<Notification>) -> Notification {
+    let severity = held.iter().map(|n| n.severity).max().unwrap_or_default();
+    let mut lines: Vec<String> = held
+        .iter()
+        .take(BATCH_BODY_LINES)
+        .map(|n| {
+            if n.body.is_empty() {
+                n.title.clone()
+            } else {
+                n.body.clone()
+            }
+        })
+        .collect();
+    if held.len() > BATCH_BODY_LINES {
+        lines.push(format!("and {} more", held.len() - BATCH_BODY_LINES));
+    }
+    Notification {
+        feature,
+        severity,
+        title: batch_title(feature, held.len()),
+        body: lines.join("\n"),
+    }
+}
+
+/// What to do with a submitted notification
+#[derive(Debug)]
+enum Dispatch {
+    Show(Notification),
+    /// Held as the first of a batch; flush the feature after this long
+    Hold(Duration),
+    /// Added to a batch that is already waiting
+    Held,
+}
+
+/// Rate limiting state of one feature
+struct Window {
+    opened_at: Instant,
+    held: Vec<Notification>,
+}
+
+#[derive(Default)]
+struct Dispatcher {
+    windows: HashMap<NotificationFeature, Window>,
+}
+
+impl Dispatcher {
+    fn submit(&mut self, notification: Notification, now: Instant, window: Duration) -> Dispatch {
+        let feature = notification.feature;
+        match self.windows.get_mut(&feature) {
+            Some(open) if now.duration_since(open.opened_at) < window || !open.held.is_empty() => {
+                open.held.push(notification);
+                if open.held.len() == 1 {
+                    Dispatch::Hold(window.saturating_sub(now.duration_since(open.opened_at)))
+                } else {
+                    Dispatch::Held
+                }
+            }
+            _ => {
+                self.windows.insert(
+                    feature,
+                    Window {
+                        opened_at: now,
+                        held: Vec::new(),
+                    },
+                );
+                Dispatch::Show(notification)
+            }
+        }
+    }
+
+    /// Close a feature's window, returning what was held in it. Showing it
+    /// opens a new window.
+    fn flush(&mut self, feature: NotificationFeature, now: Instant) -> Option<Notification> {
+        let open = self.windows.get_mut(&feature)?;
+        let mut held = std::mem::take(&mut open.held);
+        if held.is_empty() {
+            return None;
+        }
+        open.opened_at = now;
+        Some(if held.len() == 1 {
+            held.remove(0)
+        } else {
+            batch(feature, held)
+        })
+    }
+}
+
+/// Managed state shared by every caller of [`notify`]
+#[derive(Default)]
+pub struct NotificationCenter {
+    dispatcher: Mutex<Dispatcher>,
+}
+
+fn show(app: &AppHandle, notification: &Notification) {
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title(&notification.title)
+        .body(&notification.body)
+        .show()
+    {
+        warn!("Failed to show notification: {}", e);
+    }
+}
+
+fn flush_later(app: &AppHandle, feature: NotificationFeature, delay: Duration) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(delay).await;
+        let center = app.state::<NotificationCenter>();
+        let flushed = center
+            .dispatcher
+            .lock()
+            .unwrap()
+            .flush(feature, Instant::now());
+        if let Some(notification) = flushed {
+            show(&app, &notification);
+        }
+    });
+}
+
+/// Show a notification if the settings allow it, batching bursts
+pub fn notify(app: &AppHandle, notification: Notification) {
+    let settings = get_settings(app).notifications;
+    let minute_of_day = chrono::Local::now().num_seconds_from_midnight() / 60;
+    if !settings.allows(notification.feature, notification.severity, minute_of_day) {
+        debug!(
+            "Notification suppressed by settings: {}",
+            notification.title
+        );
+        return;
+    }
+
+    let window = Duration::from_secs(settings.batch_window_seconds as u64);
+    let center = app.state::<NotificationCenter>();
+    let feature = notification.feature;
+    let dispatch = center
+        .dispatcher
+        .lock()
+        .unwrap()
+        .submit(notification, Instant::now(), window);
+    match dispatch {
+        Dispatch::Show(notification) => show(app, &notification),
+        Dispatch::Hold(delay) => flush_later(app, feature, delay),
+        Dispatch::Held => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action_item(task: &str) -> Notification {
+        Notification {
+            feature: NotificationFeature::ActionItems,
+            severity: NotificationSeverity::Info,
+            title: "New action item".to_string(),
+            body: task.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_bursts_are_batched_per_feature() {
+        let mut dispatcher = Dispatcher::default();
+        let window = Duration::from_secs(30);
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        assert!(matches!(
+            dispatcher.submit(action_item("one"), at(0), window),
+            Dispatch::Show(_)
+        ));
+        match dispatcher.submit(action_item("two"), at(10), window) {
+            Dispatch::Hold(delay) => assert_eq!(delay, Duration::from_secs(20)),
+            other => panic!("expected the first held notification, got {:?}", other),
+        }
+        assert!(matches!(
+            dispatcher.submit(action_item("three"), at(12), window),
+            Dispatch::Held
+        ));
+        assert!(matches!(
+            dispatcher.submit(action_item("four"), at(13), window),
+            Dispatch::Held
+        ));
+        assert!(matches!(
+            dispatcher.submit(action_item("five"), at(14), window),
+            Dispatch::Held
+        ));
+
+        // Other features have their own window
+        let warning = Notification {
+            feature: NotificationFeature::Warnings,
+            severity: NotificationSeverity::Warning,
+            title: "Conversation warning".to_string(),
+            body: String::new(),
+        };
+        assert!(matches!(
+            dispatcher.submit(warning, at(15), window),
+            Dispatch::Show(_)
+        ));
+
+        let flushed = dispatcher
+            .flush(NotificationFeature::ActionItems, at(30))
+            .unwrap();
+        assert_eq!(flushed.title, "4 new action items");
+        assert_eq!(flushed.body, "two\nthree\nfour\nand 1 more");
+        assert!(dispatcher
+            .flush(NotificationFeature::ActionItems, at(31))
+            .is_none());
+
+        // The batch counts as shown, so the window starts over
+        assert!(matches!(
+            dispatcher.submit(action_item("six"), at(40), window),
+            Dispatch::Hold(_)
+        ));
+        assert!(matches!(
+            dispatcher.submit(action_item("seven"), at(90), window),
+            Dispatch::Held
+        ));
+    }
+
+    #[test]
+    fn test_zero_window_shows_everything() {
+        let mut dispatcher = Dispatcher::default();
+        let now = Instant::now();
+        for task in ["one", "two", "three"] {
+            assert!(matches!(
+                dispatcher.submit(action_item(task), now, Duration::ZERO),
+                Dispatch::Show(_)
+            ));
+        }
+    }
+}
@@ -3,6 +3,8 @@
 //! Provides HTTP client for communicating with local Ollama server,
 //! supporting streaming responses for real-time insight generation.
 
+use crate::access_policy;
+use crate::settings::{ActiveListeningSettings, ModelUnloadTimeout};
 use futures_util::StreamExt;
 use log::{debug, error, warn};
 use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
@@ -17,6 +19,8 @@ struct OllamaGenerateRequest {
     stream: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<OllamaOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keep_alive: Option<serde_json::Value>,
 }
 
 /// Ollama embeddings request payload
@@ -32,6 +36,36 @@ struct OllamaEmbeddingsResponse {
     embedding: Vec<f32>,
 }
 
+/// Ollama batch embed request payload (/api/embed)
+#[derive(Debug, Serialize)]
+struct OllamaEmbedRequest<'a> {
+    model: &'a str,
+    input: &'a [String],
+}
+
+/// Ollama batch embed response
+#[derive(Debug, Deserialize)]
+struct OllamaEmbedResponse {
+    embeddings: Vec<Vec<f32>>,
+}
+
+/// Base delay before retrying a failed embed request (doubled per attempt)
+const EMBED_RETRY_BASE_DELAY_MS: u64 = 500;
+
+/// Failure of a single embed request attempt
+struct EmbedAttemptError {
+    message: String,
+    /// Whether the request may succeed if retried (connection errors, 429, 5xx)
+    transient: bool,
+}
+
+/// Whether an HTTP status indicates a failure worth retrying
+fn is_transient_status(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        || status == reqwest::StatusCode::REQUEST_TIMEOUT
+        || status.is_server_error()
+}
+
 /// Ollama model options
 #[derive(Debug, Serialize)]
 struct OllamaOptions {
@@ -58,6 +92,79 @@ struct OllamaStreamResponse {
     eval_count: Option<u64>,
 }
 
+/// A message exchanged with Ollama's /api/chat endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaChatMessage {
+    /// One of "system", "user", "assistant" or "tool"
+    pub role: String,
+    #[serde(default)]
+    pub content: String,
+    /// Tool invocations requested by the model (assistant messages only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_calls: Vec<OllamaToolCall>,
+}
+
+impl OllamaChatMessage {
+    pub fn new(role: &str, content: impl Into<String>) -> Self {
+        Self {
+            role: role.to_string(),
+            content: content.into(),
+            tool_calls: Vec::new(),
+        }
+    }
+}
+
+/// A tool invocation requested by the model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaToolCall {
+    pub function: OllamaToolCallFunction,
+}
+
+/// Name and arguments of a requested tool invocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaToolCallFunction {
+    pub name: String,
+    #[serde(default)]
+    pub arguments: serde_json::Value,
+}
+
+/// Tool definition advertised to the model (OpenAI-compatible function schema)
+#[derive(Debug, Clone, Serialize)]
+pub struct OllamaTool {
+    #[serde(rename = "type")]
+    pub tool_type: String,
+    pub function: OllamaToolFunction,
+}
+
+/// Function signature of an advertised tool
+#[derive(Debug, Clone, Serialize)]
+pub struct OllamaToolFunction {
+    pub name: String,
+    pub description: String,
+    /// JSON schema describing the function arguments
+    pub parameters: serde_json::Value,
+}
+
+/// Ollama chat request payload
+#[derive(Debug, Serialize)]
+struct OllamaChatRequest<'a> {
+    model: String,
+    messages: &'a [OllamaChatMessage],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    tools: &'a [OllamaTool],
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<OllamaOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keep_alive: Option<serde_json::Value>,
+}
+
+/// Ollama (non-streaming) chat response
+#[derive(Debug, Deserialize)]
+struct OllamaChatResponse {
+    message: OllamaChatMessage,
+}
+
 /// Ollama model info from /api/tags
 #[derive(Debug, Deserialize)]
 struct OllamaTagsResponse {
@@ -85,6 +192,9 @@ pub struct OllamaModelInfo {
 pub struct OllamaClient {
     client: reqwest::Client,
     base_url: String,
+    /// How long the model stays loaded after a request, `None` for the
+    /// server's default
+    keep_alive: Option<serde_json::Value>,
 }
 
 /// Default timeout for Ollama API requests (5 minutes for long-running generation)
@@ -95,8 +205,11 @@ const CONNECT_TIMEOUT_SECS: u64 = 10;
 impl OllamaClient {
     /// Create a new Ollama client with the given base URL
     ///
-    /// Returns an error if the HTTP client cannot be built.
+    /// Returns an error if the HTTP client cannot be built or the access
+    /// policy blocks the server.
     pub fn new(base_url: &str) -> Result<Self, String> {
+        access_policy::check_url(base_url)?;
+
         let mut headers = HeaderMap::new();
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
@@ -110,9 +223,52 @@ impl OllamaClient {
         Ok(Self {
             client,
             base_url: base_url.trim_end_matches('/').to_string(),
+            keep_alive: None,
         })
     }
 
+    /// Create a client for the Active Listening server that keeps the model
+    /// loaded for as long as the settings say
+    pub fn for_active_listening(settings: &ActiveListeningSettings) -> Result<Self, String> {
+        let mut client = Self::new(&settings.ollama_base_url)?;
+        client.keep_alive = Some(keep_alive(settings.ollama_keep_alive));
+        Ok(client)
+    }
+
+    /// Load a model ahead of its first request, so that request doesn't wait
+    /// for a cold start
+    pub async fn preload(&self, model: &str) -> Result<(), String> {
+        let url = format!("{}/api/generate", self.base_url);
+        debug!("Loading Ollama model {} at: {}", model, url);
+
+        // A request without a prompt only loads the model
+        let request_body = OllamaGenerateRequest {
+            model: model.to_string(),
+            prompt: String::new(),
+            stream: false,
+            options: None,
+            keep_alive: self.keep_alive.clone(),
+        };
+
+        let response = self
+            .client
+            .post(&url)
+            .json(&request_body)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to send load request: {}", e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(format!("Load request failed ({}): {}", status, error_text));
+        }
+        Ok(())
+    }
+
     /// Check if Ollama server is available
     pub async fn health_check(&self) -> Result<bool, String> {
         let url = format!("{}/api/tags", self.base_url);
@@ -200,6 +356,7 @@ impl OllamaClient {
                 temperature: 0.7,
                 num_ctx: Some(4096),
             }),
+            keep_alive: self.keep_alive.clone(),
         };
 
         let response = self
@@ -283,6 +440,7 @@ impl OllamaClient {
                 temperature: 0.7,
                 num_ctx: Some(4096),
             }),
+            keep_alive: self.keep_alive.clone(),
         };
 
         let response = self
@@ -313,6 +471,63 @@ impl OllamaClient {
         Ok(stream_response.response)
     }
 
+    /// Send a chat request with optional tool definitions (non-streaming)
+    ///
+    /// Uses the /api/chat endpoint. The returned assistant message either carries
+    /// the final answer in `content` or a list of `tool_calls` the caller must
+    /// execute and feed back as "tool" messages.
+    pub async fn chat(
+        &self,
+        model: &str,
+        messages: &[OllamaChatMessage],
+        tools: &[OllamaTool],
+    ) -> Result<OllamaChatMessage, String> {
+        let url = format!("{}/api/chat", self.base_url);
+        debug!(
+            "Starting Ollama chat to: {} with model: {} ({} messages, {} tools)",
+            url,
+            model,
+            messages.len(),
+            tools.len()
+        );
+
+        let request_body = OllamaChatRequest {
+            model: model.to_string(),
+            messages,
+            tools,
+            stream: false,
+            options: Some(OllamaOptions {
+                temperature: 0.7,
+                num_ctx: Some(4096),
+            }),
+            keep_alive: self.keep_alive.clone(),
+        };
+
+        let response = self
+            .client
+            .post(&url)
+            .json(&request_body)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to send chat request: {}", e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(format!("Chat request failed ({}): {}", status, error_text));
+        }
+
+        let chat_response: OllamaChatResponse = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse chat response: {}", e))?;
+
+        Ok(chat_response.message)
+    }
+
     /// Generate embeddings for text using Ollama
     ///
     /// Uses the /api/embeddings endpoint to generate vector embeddings.
@@ -366,6 +581,114 @@ impl OllamaClient {
 
         Ok(embeddings_response.embedding)
     }
+
+    /// Generate embeddings for several texts in a single request
+    ///
+    /// Uses the /api/embed endpoint, which accepts a batch of inputs and
+    /// returns one embedding per input in the same order. Transient failures
+    /// (connection errors, 429 and 5xx responses) are retried up to
+    /// `max_retries` times with exponential backoff.
+    pub async fn generate_embeddings_batch(
+        &self,
+        model: &str,
+        inputs: &[String],
+        max_retries: u32,
+    ) -> Result<Vec<Vec<f32>>, String> {
+        if inputs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut attempt = 0;
+        loop {
+            match self.embed_batch_once(model, inputs).await {
+                Ok(embeddings) => return Ok(embeddings),
+                Err(e) if e.transient && attempt < max_retries => {
+                    let delay = EMBED_RETRY_BASE_DELAY_MS * (1 << attempt.min(6));
+                    warn!(
+                        "Embed request failed (attempt {}/{}), retrying in {}ms: {}",
+                        attempt + 1,
+                        max_retries + 1,
+                        delay,
+                        e.message
+                    );
+                    tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e.message),
+            }
+        }
+    }
+
+    async fn embed_batch_once(
+        &self,
+        model: &str,
+        inputs: &[String],
+    ) -> Result<Vec<Vec<f32>>, EmbedAttemptError> {
+        let url = format!("{}/api/embed", self.base_url);
+        debug!(
+            "Generating {} embeddings with model {}",
+            inputs.len(),
+            model
+        );
+
+        let request_body = OllamaEmbedRequest {
+            model,
+            input: inputs,
+        };
+
+        let response = self
+            .client
+            .post(&url)
+            .json(&request_body)
+            .send()
+            .await
+            .map_err(|e| EmbedAttemptError {
+                message: format!("Failed to send embed request: {}", e),
+                transient: true,
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(EmbedAttemptError {
+                message: format!("Embed request failed ({}): {}", status, error_text),
+                transient: is_transient_status(status),
+            });
+        }
+
+        let embed_response: OllamaEmbedResponse =
+            response.json().await.map_err(|e| EmbedAttemptError {
+                message: format!("Failed to parse embed response: {}", e),
+                transient: false,
+            })?;
+
+        if embed_response.embeddings.len() != inputs.len() {
+            return Err(EmbedAttemptError {
+                message: format!(
+                    "Embed response returned {} embeddings for {} inputs",
+                    embed_response.embeddings.len(),
+                    inputs.len()
+                ),
+                transient: false,
+            });
+        }
+
+        Ok(embed_response.embeddings)
+    }
+}
+
+/// Ollama's `keep_alive` for an unload timeout: a duration, 0 to unload
+/// right away or -1 to keep the model loaded
+fn keep_alive(timeout: ModelUnloadTimeout) -> serde_json::Value {
+    match timeout {
+        ModelUnloadTimeout::Never => (-1).into(),
+        ModelUnloadTimeout::Immediately => 0.into(),
+        ModelUnloadTimeout::Sec5 => "5s".into(),
+        timeout => format!("{}m", timeout.to_minutes().unwrap_or(5)).into(),
+    }
 }
 
 /// Apply template variables to a prompt template
@@ -433,6 +756,33 @@ mod tests {
         assert!(result.contains("AI Meeting"));
     }
 
+    #[test]
+    fn test_keep_alive_follows_the_unload_timeout() {
+        assert_eq!(keep_alive(ModelUnloadTimeout::Never), serde_json::json!(-1));
+        assert_eq!(
+            keep_alive(ModelUnloadTimeout::Immediately),
+            serde_json::json!(0)
+        );
+        assert_eq!(
+            keep_alive(ModelUnloadTimeout::Min15),
+            serde_json::json!("15m")
+        );
+        assert_eq!(
+            keep_alive(ModelUnloadTimeout::Hour1),
+            serde_json::json!("60m")
+        );
+    }
+
+    #[test]
+    fn test_is_transient_status() {
+        assert!(is_transient_status(
+            reqwest::StatusCode::SERVICE_UNAVAILABLE
+        ));
+        assert!(is_transient_status(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert!(!is_transient_status(reqwest::StatusCode::NOT_FOUND));
+        assert!(!is_transient_status(reqwest::StatusCode::BAD_REQUEST));
+    }
+
     #[test]
     fn test_apply_prompt_template_no_topic() {
         let template = "Topic: {{session_topic}}";
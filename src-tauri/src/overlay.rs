@@ -2,7 +2,9 @@ use crate::input;
 use crate::settings;
 use crate::settings::OverlayPosition;
 use log::debug;
-use tauri::{AppHandle, Emitter, Manager, PhysicalPosition, PhysicalSize};
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize};
 
 #[cfg(not(target_os = "macos"))]
 use tauri::WebviewWindowBuilder;
@@ -10,8 +12,10 @@ use tauri::WebviewWindowBuilder;
 #[cfg(target_os = "macos")]
 use tauri::WebviewUrl;
 
+use crate::events::{HideOverlay, MicLevel, OverlayState, ShowOverlay};
 #[cfg(target_os = "macos")]
 use tauri_nspanel::{tauri_panel, CollectionBehavior, PanelBuilder, PanelLevel};
+use tauri_specta::Event;
 
 #[cfg(target_os = "macos")]
 tauri_panel! {
@@ -324,7 +328,7 @@ pub fn show_recording_overlay(app_handle: &AppHandle) {
         force_overlay_topmost(&overlay_window);
 
         // Emit event to trigger fade-in animation with recording state
-        let _ = overlay_window.emit("show-overlay", "recording");
+        let _ = ShowOverlay(OverlayState::Recording).emit(&overlay_window);
     }
 }
 
@@ -346,7 +350,7 @@ pub fn show_transcribing_overlay(app_handle: &AppHandle) {
         force_overlay_topmost(&overlay_window);
 
         // Emit event to switch to transcribing state
-        let _ = overlay_window.emit("show-overlay", "transcribing");
+        let _ = ShowOverlay(OverlayState::Transcribing).emit(&overlay_window);
     }
 }
 
@@ -366,7 +370,7 @@ pub fn hide_recording_overlay(app_handle: &AppHandle) {
     // we still want to hide it properly
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
         // Emit event to trigger fade-out animation
-        let _ = overlay_window.emit("hide-overlay", ());
+        let _ = HideOverlay.emit(&overlay_window);
         // Hide the window after a short delay to allow animation to complete
         let window_clone = overlay_window.clone();
         std::thread::spawn(move || {
@@ -378,11 +382,44 @@ pub fn hide_recording_overlay(app_handle: &AppHandle) {
 
 pub fn emit_levels(app_handle: &AppHandle, levels: &Vec<f32>) {
     // emit levels to main app
-    let _ = app_handle.emit("mic-level", levels);
+    let _ = MicLevel(levels.clone()).emit(app_handle);
 
     // also emit to the recording overlay if it's open
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
-        let _ = overlay_window.emit("mic-level", levels);
+        let _ = MicLevel(levels.clone()).emit(&overlay_window);
+    }
+}
+
+/// Payload of the `recording-progress` event
+#[derive(Clone, Debug, Serialize, Type, Event)]
+pub struct RecordingProgress {
+    pub elapsed_ms: u64,
+    /// Words transcribed so far; only reported while streaming
+    pub word_count: Option<usize>,
+}
+
+pub fn emit_recording_progress(app_handle: &AppHandle, progress: &RecordingProgress) {
+    let _ = progress.emit(app_handle);
+
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let _ = progress.emit(&overlay_window);
+    }
+}
+
+/// Payload of the `recording-limit-warning` event, sent once a recording has
+/// used up most of the recording limit
+#[derive(Clone, Debug, Serialize, Type, Event)]
+pub struct RecordingLimitWarning {
+    pub elapsed_ms: u64,
+    /// When the recording is stopped
+    pub limit_ms: u64,
+}
+
+pub fn emit_recording_limit_warning(app_handle: &AppHandle, warning: &RecordingLimitWarning) {
+    let _ = warning.emit(app_handle);
+
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let _ = warning.emit(&overlay_window);
     }
 }
 
@@ -408,7 +445,7 @@ pub fn show_ask_ai_overlay(app_handle: &AppHandle) {
         force_overlay_topmost(&overlay_window);
 
         // Emit event to trigger fade-in animation with ask-ai recording state
-        let _ = overlay_window.emit("show-overlay", "ask-ai-recording");
+        let _ = ShowOverlay(OverlayState::AskAiRecording).emit(&overlay_window);
     }
 }
 
@@ -430,7 +467,7 @@ pub fn show_ask_ai_transcribing_overlay(app_handle: &AppHandle) {
         force_overlay_topmost(&overlay_window);
 
         // Emit event to switch to ask-ai transcribing state
-        let _ = overlay_window.emit("show-overlay", "ask-ai-transcribing");
+        let _ = ShowOverlay(OverlayState::AskAiTranscribing).emit(&overlay_window);
     }
 }
 
@@ -456,7 +493,7 @@ pub fn show_active_listening_overlay(app_handle: &AppHandle) {
         force_overlay_topmost(&overlay_window);
 
         // Emit event to trigger fade-in animation with active-listening state
-        let _ = overlay_window.emit("show-overlay", "active-listening");
+        let _ = ShowOverlay(OverlayState::ActiveListening).emit(&overlay_window);
     }
 }
 
@@ -533,7 +570,7 @@ pub fn show_ask_ai_response_overlay(app_handle: &AppHandle) {
         force_overlay_topmost(&overlay_window);
 
         // Emit event to show Ask AI generating state
-        let _ = overlay_window.emit("show-overlay", "ask-ai-generating");
+        let _ = ShowOverlay(OverlayState::AskAiGenerating).emit(&overlay_window);
     }
 }
 
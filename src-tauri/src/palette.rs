@@ -0,0 +1,443 @@
+//! Command palette
+//!
+//! Fuzzy search over everything the palette can run: shortcut actions,
+//! switching to a downloaded model, running a post-process prompt on the
+//! latest dictation, history entries and knowledge base documents. Results
+//! come back ranked, each with the action that executes it.
+//!
+//! A query matches an item when every word of it matches the item's title,
+//! or failing that its subtitle, as a fuzzy subsequence. Matches on word
+//! starts and runs of consecutive characters rank higher.
+
+use crate::actions::{action_for, post_process_with_prompt};
+use crate::managers::history::HistoryManager;
+use crate::managers::model::ModelManager;
+use crate::managers::rag::RagManager;
+use crate::managers::transcription::TranscriptionManager;
+use crate::settings::{get_settings, write_settings};
+use crate::ManagedToggleState;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+/// Characters of a history entry or document shown as its title
+const TITLE_CHARS: usize = 80;
+
+/// Points for each matched character, and the bonuses on top
+const MATCH_SCORE: i32 = 16;
+const CONSECUTIVE_BONUS: i32 = 15;
+const WORD_START_BONUS: i32 = 10;
+const FIRST_CHAR_BONUS: i32 = 15;
+
+/// What executing a palette item does
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaletteAction {
+    /// Run a shortcut's action, starting or stopping it like a toggle
+    /// shortcut would
+    RunBinding { binding_id: String },
+    /// Switch to a downloaded model
+    SwitchModel { model_id: String },
+    /// Post-process the latest dictation with a prompt and copy the result
+    RunPrompt { prompt_id: String },
+    /// Copy a history entry's text
+    CopyHistoryEntry { entry_id: i64 },
+    /// Copy a knowledge base document's content
+    CopyDocument { document_id: i64 },
+}
+
+/// Kinds of palette items, in the order they rank on equal scores
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PaletteCategory {
+    Action,
+    Model,
+    Prompt,
+    History,
+    Document,
+}
+
+/// A search result in the palette
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct PaletteItem {
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub category: PaletteCategory,
+    pub action: PaletteAction,
+    pub score: u32,
+    /// Character positions of the title the query matched, for highlighting
+    pub matches: Vec<u32>,
+}
+
+/// What happened when an item was executed, for the palette to report
+#[derive(Serialize, Clone, Debug, PartialEq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PaletteOutcome {
+    Done,
+    Copied,
+}
+
+fn is_word_start(chars: &[char], index: usize) -> bool {
+    match index.checked_sub(1).map(|previous| chars[previous]) {
+        None => true,
+        Some(previous) => {
+            !previous.is_alphanumeric() || (previous.is_lowercase() && chars[index].is_uppercase())
+        }
+    }
+}
+
+/// Match `query` against `text` from the text position `start`, greedily
+fn match_from(query: &[char], text: &[char], start: usize) -> Option<(i32, Vec<usize>)> {
+    let mut score = 0;
+    let mut positions = Vec::with_capacity(query.len());
+    let mut next = start;
+    for &wanted in query {
+        let found =
+            (next..text.len()).find(|&i| text[i].to_lowercase().eq(wanted.to_lowercase()))?;
+        score += MATCH_SCORE;
+        match positions.last() {
+            Some(&last) if last + 1 == found => score += CONSECUTIVE_BONUS,
+            // Gaps cost a point per skipped character
+            Some(&last) => score -= (found - last - 1) as i32,
+            None if found == 0 => score += FIRST_CHAR_BONUS,
+            None => score -= found.min(10) as i32,
+        }
+        if is_word_start(text, found) {
+            score += WORD_START_BONUS;
+        }
+        positions.push(found);
+        next = found + 1;
+    }
+    Some((score, positions))
+}
+
+/// Score `text` for a fuzzy `query`, with the character positions matched.
+/// Every character of the query has to appear in order, ignoring case.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<(u32, Vec<usize>)> {
+    let query: Vec<char> = query.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let first = query.first()?;
+
+    // Try every place the first character occurs, keeping the best
+    text.iter()
+        .enumerate()
+        .filter(|(_, c)| c.to_lowercase().eq(first.to_lowercase()))
+        .filter_map(|(start, _)| match_from(&query, &text, start))
+        .max_by_key(|(score, _)| *score)
+        .map(|(score, positions)| (score.max(1) as u32, positions))
+}
+
+/// Score an item with a title and subtitle for all words of `query`
+fn score_item(query: &str, title: &str, subtitle: Option<&str>) -> Option<(u32, Vec<u32>)> {
+    let mut total = 0;
+    let mut matches = Vec::new();
+    for word in query.split_whitespace() {
+        if let Some((score, positions)) = fuzzy_match(word, title) {
+            total += score;
+            matches.extend(positions.into_iter().map(|p| p as u32));
+        } else {
+            // Subtitle matches count for half
+            let (score, _) = fuzzy_match(word, subtitle?)?;
+            total += score / 2;
+        }
+    }
+    matches.sort_unstable();
+    matches.dedup();
+    Some((total, matches))
+}
+
+/// One line of at most `TITLE_CHARS` characters
+fn one_line(text: &str) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match line.char_indices().nth(TITLE_CHARS) {
+        Some((end, _)) => format!("{}…", &line[..end]),
+        None => line,
+    }
+}
+
+struct Candidate {
+    title: String,
+    subtitle: Option<String>,
+    category: PaletteCategory,
+    action: PaletteAction,
+}
+
+async fn candidates(app: &AppHandle, include_content: bool) -> Vec<Candidate> {
+    let settings = get_settings(app);
+    let mut candidates = Vec::new();
+
+    let mut bindings: Vec<_> = settings
+        .bindings
+        .values()
+        .filter(|binding| binding.id != "cancel" && action_for(&binding.id).is_some())
+        .collect();
+    bindings.sort_by(|a, b| a.name.cmp(&b.name));
+    candidates.extend(bindings.into_iter().map(|binding| Candidate {
+        title: binding.name.clone(),
+        subtitle: Some(binding.description.clone()),
+        category: PaletteCategory::Action,
+        action: PaletteAction::RunBinding {
+            binding_id: binding.id.clone(),
+        },
+    }));
+
+    let models = app.state::<Arc<ModelManager>>().get_available_models();
+    candidates.extend(
+        models
+            .into_iter()
+            .filter(|model| model.is_downloaded && model.id != settings.selected_model)
+            .map(|model| Candidate {
+                title: model.name,
+                subtitle: Some(model.description),
+                category: PaletteCategory::Model,
+                action: PaletteAction::SwitchModel { model_id: model.id },
+            }),
+    );
+
+    candidates.extend(
+        settings
+            .post_process_prompts
+            .iter()
+            .map(|prompt| Candidate {
+                title: prompt.name.clone(),
+                subtitle: None,
+                category: PaletteCategory::Prompt,
+                action: PaletteAction::RunPrompt {
+                    prompt_id: prompt.id.clone(),
+                },
+            }),
+    );
+
+    // History and documents only show up once something is typed
+    if !include_content {
+        return candidates;
+    }
+
+    let history_manager = app.state::<Arc<HistoryManager>>().inner().clone();
+    match history_manager.get_history_entries().await {
+        Ok(entries) => candidates.extend(entries.into_iter().map(|entry| {
+            Candidate {
+                title: one_line(
+                    entry
+                        .post_processed_text
+                        .as_ref()
+                        .unwrap_or(&entry.transcription_text),
+                ),
+                subtitle: Some(entry.title),
+                category: PaletteCategory::History,
+                action: PaletteAction::CopyHistoryEntry { entry_id: entry.id },
+            }
+        })),
+        Err(e) => warn!("Palette couldn't read history: {}", e),
+    }
+
+    match app.state::<Arc<RagManager>>().list_documents() {
+        Ok(documents) => candidates.extend(documents.into_iter().map(|document| {
+            Candidate {
+                title: document
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| one_line(&document.content)),
+                subtitle: Some(document.source_type),
+                category: PaletteCategory::Document,
+                action: PaletteAction::CopyDocument {
+                    document_id: document.id,
+                },
+            }
+        })),
+        Err(e) => warn!("Palette couldn't read the knowledge base: {}", e),
+    }
+
+    candidates
+}
+
+/// Rank candidates for `query`, best first. An empty query lists the
+/// actions, models and prompts in their own order.
+fn rank(query: &str, candidates: Vec<Candidate>, limit: usize) -> Vec<PaletteItem> {
+    let mut items: Vec<PaletteItem> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let (score, matches) = if query.trim().is_empty() {
+                (0, Vec::new())
+            } else {
+                score_item(query, &candidate.title, candidate.subtitle.as_deref())?
+            };
+            Some(PaletteItem {
+                title: candidate.title,
+                subtitle: candidate.subtitle,
+                category: candidate.category,
+                action: candidate.action,
+                score,
+                matches,
+            })
+        })
+        .collect();
+    // The sort is stable, so equal items keep their source order
+    items.sort_by(|a, b| b.score.cmp(&a.score).then(a.category.cmp(&b.category)));
+    items.truncate(limit);
+    items
+}
+
+/// The palette items matching `query`, best first
+pub async fn query(app: &AppHandle, query: &str, limit: usize) -> Vec<PaletteItem> {
+    let query = query.trim();
+    rank(query, candidates(app, !query.is_empty()).await, limit)
+}
+
+/// Run an action the way a toggle shortcut would
+fn run_binding(app: &AppHandle, binding_id: &str) -> Result<(), String> {
+    let action = action_for(binding_id).ok_or_else(|| format!("No action for '{}'", binding_id))?;
+    // Update the toggle state under the lock, but call the action after
+    // releasing it, as actions may need the lock themselves
+    let should_start = {
+        let toggle_state_manager = app.state::<ManagedToggleState>();
+        let mut states = toggle_state_manager
+            .lock()
+            .map_err(|e| format!("Failed to lock toggle state: {}", e))?;
+        let is_active = states
+            .active_toggles
+            .entry(binding_id.to_string())
+            .or_insert(false);
+        *is_active = !*is_active;
+        *is_active
+    };
+    if should_start {
+        action.start(app, binding_id, "Palette");
+    } else {
+        action.stop(app, binding_id, "Palette");
+    }
+    Ok(())
+}
+
+fn copy(app: &AppHandle, text: &str) -> Result<PaletteOutcome, String> {
+    app.clipboard()
+        .write_text(text)
+        .map_err(|e| format!("Failed to copy: {}", e))?;
+    Ok(PaletteOutcome::Copied)
+}
+
+/// Execute a palette item's action
+pub async fn execute(app: &AppHandle, action: PaletteAction) -> Result<PaletteOutcome, String> {
+    debug!("Palette executing {:?}", action);
+    match action {
+        PaletteAction::RunBinding { binding_id } => {
+            run_binding(app, &binding_id)?;
+            Ok(PaletteOutcome::Done)
+        }
+        PaletteAction::SwitchModel { model_id } => {
+            let model = app
+                .state::<Arc<ModelManager>>()
+                .get_model_info(&model_id)
+                .filter(|model| model.is_downloaded)
+                .ok_or_else(|| format!("Model not downloaded: {}", model_id))?;
+            app.state::<Arc<TranscriptionManager>>()
+                .load_model(&model.id)
+                .map_err(|e| e.to_string())?;
+            let mut settings = get_settings(app);
+            settings.selected_model = model.id;
+            write_settings(app, settings);
+            Ok(PaletteOutcome::Done)
+        }
+        PaletteAction::RunPrompt { prompt_id } => {
+            let entry = app
+                .state::<Arc<HistoryManager>>()
+                .get_latest_entry()
+                .map_err(|e| e.to_string())?
+                .ok_or("There's no dictation to run the prompt on")?;
+            let settings = get_settings(app);
+            let text = post_process_with_prompt(&settings, &prompt_id, &entry.transcription_text)
+                .await
+                .ok_or("The prompt didn't return any text")?;
+            copy(app, &text)
+        }
+        PaletteAction::CopyHistoryEntry { entry_id } => {
+            let text = app
+                .state::<Arc<HistoryManager>>()
+                .get_entry_text(entry_id)
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| format!("History entry {} not found", entry_id))?;
+            copy(app, &text)
+        }
+        PaletteAction::CopyDocument { document_id } => {
+            let document = app
+                .state::<Arc<RagManager>>()
+                .list_documents()?
+                .into_iter()
+                .find(|document| document.id == document_id)
+                .ok_or_else(|| format!("Document {} not found", document_id))?;
+            copy(app, &document.content)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(title: &str, category: PaletteCategory) -> Candidate {
+        Candidate {
+            title: title.to_string(),
+            subtitle: None,
+            category,
+            action: PaletteAction::RunBinding {
+                binding_id: title.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_match_needs_characters_in_order() {
+        let (_, positions) = fuzzy_match("tsc", "Transcribe").unwrap();
+        assert_eq!(positions, [0, 4, 5]);
+        assert!(fuzzy_match("TRANS", "transcribe").is_some());
+        assert!(fuzzy_match("bt", "Transcribe").is_none());
+        assert!(fuzzy_match("", "Transcribe").is_none());
+    }
+
+    #[test]
+    fn test_word_starts_and_runs_rank_higher() {
+        let word_starts = fuzzy_match("al", "Active Listening").unwrap().0;
+        let scattered = fuzzy_match("al", "Cancel all").unwrap().0;
+        assert!(word_starts > scattered);
+
+        let items = rank(
+            "ask",
+            vec![
+                candidate("Toggle Mask", PaletteCategory::Action),
+                candidate("Ask AI", PaletteCategory::Action),
+            ],
+            10,
+        );
+        assert_eq!(items[0].title, "Ask AI");
+        assert_eq!(items[0].matches, [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_every_word_must_match_and_ties_keep_category_order() {
+        let items = rank(
+            "ema for",
+            vec![
+                candidate("Email", PaletteCategory::Prompt),
+                candidate("Formal email", PaletteCategory::History),
+                candidate("Email formal", PaletteCategory::Action),
+            ],
+            10,
+        );
+        let titles: Vec<_> = items.iter().map(|item| item.title.as_str()).collect();
+        assert_eq!(titles, ["Email formal", "Formal email"]);
+
+        let items = rank(
+            "",
+            vec![
+                candidate("Formal", PaletteCategory::Prompt),
+                candidate("Transcribe", PaletteCategory::Action),
+            ],
+            1,
+        );
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].title, "Transcribe");
+    }
+}
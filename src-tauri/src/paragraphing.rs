@@ -0,0 +1,224 @@
+//! Paragraphing
+//!
+//! Breaks dictations up where the speaker paused, so a dictated document
+//! isn't one long blob. The VAD's pauses are measured while recording: a
+//! medium pause ends a sentence and a long one starts a new paragraph. The
+//! recording is split at those pauses, each part is transcribed on its own
+//! and the texts are joined with the matching break, so the same pauses
+//! always give the same layout. This runs before post-processing.
+
+use crate::audio_toolkit::audio::Pause;
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::managers::transcription::{TimedTranscript, TranscriptionManager};
+use crate::settings::ParagraphingSettings;
+use log::debug;
+use std::time::Duration;
+
+/// Shortest part a recording is split into; shorter ones transcribe poorly
+const MIN_PART_SAMPLES: usize = WHISPER_SAMPLE_RATE as usize;
+/// Characters that already end a sentence
+const SENTENCE_ENDS: &[char] = &['.', '!', '?', '…', '。', '！', '？'];
+/// Closing characters that may follow a sentence end
+const CLOSERS: &[char] = &['"', '\'', ')', '”', '’', '」'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Break {
+    Sentence,
+    Paragraph,
+}
+
+fn break_for(settings: &ParagraphingSettings, pause: Duration) -> Option<Break> {
+    let millis = pause.as_millis();
+    if millis >= settings.paragraph_pause_ms as u128 {
+        Some(Break::Paragraph)
+    } else if millis >= settings.sentence_pause_ms as u128 {
+        Some(Break::Sentence)
+    } else {
+        None
+    }
+}
+
+/// Offsets to split a recording of `len` samples at, with the break each
+/// one makes. Pauses that would leave a part too short are skipped.
+fn split_points(
+    settings: &ParagraphingSettings,
+    pauses: &[Pause],
+    len: usize,
+) -> Vec<(usize, Break)> {
+    let mut points = Vec::new();
+    let mut start = 0;
+    for pause in pauses {
+        let Some(kind) = break_for(settings, pause.duration) else {
+            continue;
+        };
+        if pause.offset < start + MIN_PART_SAMPLES || len < pause.offset + MIN_PART_SAMPLES {
+            continue;
+        }
+        points.push((pause.offset, kind));
+        start = pause.offset;
+    }
+    points
+}
+
+/// Close the text's last sentence unless it's already closed
+fn end_sentence(text: &mut String) {
+    let closed = text.trim_end_matches(CLOSERS).ends_with(SENTENCE_ENDS);
+    if !closed {
+        let open = text.trim_end_matches([',', ';', ':']).len();
+        text.truncate(open);
+        text.push('.');
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Join the texts of the parts with the breaks between them. An empty part
+/// passes on the stronger of the breaks around it.
+fn join_parts(parts: &[String], breaks: &[Break]) -> String {
+    let mut text = String::new();
+    let mut pending = None;
+    for (index, part) in parts.iter().enumerate() {
+        if index > 0 {
+            pending = pending.max(breaks.get(index - 1).copied());
+        }
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+        if !text.is_empty() {
+            match pending {
+                Some(Break::Paragraph) => {
+                    end_sentence(&mut text);
+                    text.push_str("\n\n");
+                    text.push_str(&capitalize(part));
+                }
+                Some(Break::Sentence) => {
+                    end_sentence(&mut text);
+                    text.push(' ');
+                    text.push_str(&capitalize(part));
+                }
+                None => {
+                    text.push(' ');
+                    text.push_str(part);
+                }
+            }
+        } else {
+            text.push_str(part);
+        }
+        pending = None;
+    }
+    text
+}
+
+/// Transcribe a dictation with the backend picked for its shortcut, broken
+/// into sentences and paragraphs at its pauses when paragraphing is on.
+/// Filler words are kept when `keep_fillers` is set.
+pub async fn transcribe(
+    tm: &TranscriptionManager,
+    settings: &ParagraphingSettings,
+    audio: Vec<f32>,
+    pauses: &[Pause],
+    binding_id: &str,
+    keep_fillers: bool,
+) -> anyhow::Result<TimedTranscript> {
+    let points = if settings.enabled {
+        split_points(settings, pauses, audio.len())
+    } else {
+        Vec::new()
+    };
+    if points.is_empty() {
+        return tm
+            .transcribe_long_for_binding(audio, pauses, binding_id, keep_fillers)
+            .await;
+    }
+
+    debug!("Transcribing {} parts split at pauses", points.len() + 1);
+    let mut parts = Vec::with_capacity(points.len() + 1);
+    let mut start = 0;
+    for &(offset, _) in &points {
+        parts.push(audio[start..offset].to_vec());
+        start = offset;
+    }
+    parts.push(audio[start..].to_vec());
+
+    let transcripts = tm
+        .transcribe_parts_for_binding(parts, binding_id, keep_fillers)
+        .await?;
+    let texts: Vec<String> = transcripts.iter().map(|t| t.text.clone()).collect();
+    let breaks: Vec<Break> = points.iter().map(|&(_, kind)| kind).collect();
+
+    // Part timings start at zero; move them to where the part begins
+    let starts = std::iter::once(0).chain(points.iter().map(|&(offset, _)| offset));
+    let mut transcript = TimedTranscript::default();
+    for (part, start) in transcripts.into_iter().zip(starts) {
+        transcript.append(part, start as f32 / WHISPER_SAMPLE_RATE as f32);
+    }
+    // The parts are joined at their breaks rather than with spaces
+    transcript.text = join_parts(&texts, &breaks);
+    Ok(transcript)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pause(seconds: f32, pause_ms: u64) -> Pause {
+        Pause {
+            offset: (seconds * WHISPER_SAMPLE_RATE as f32) as usize,
+            duration: Duration::from_millis(pause_ms),
+        }
+    }
+
+    #[test]
+    fn test_split_points_follow_the_thresholds() {
+        let settings = ParagraphingSettings {
+            enabled: true,
+            ..Default::default()
+        };
+        let len = 20 * WHISPER_SAMPLE_RATE as usize;
+        let pauses = [
+            pause(3.0, 300),
+            pause(6.0, 900),
+            pause(6.5, 2500),
+            pause(12.0, 2500),
+            pause(19.5, 900),
+        ];
+
+        // Too short, too close to the previous split and too close to the end
+        // are skipped
+        assert_eq!(
+            split_points(&settings, &pauses, len),
+            vec![
+                (6 * WHISPER_SAMPLE_RATE as usize, Break::Sentence),
+                (12 * WHISPER_SAMPLE_RATE as usize, Break::Paragraph),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_join_parts_ends_sentences_and_starts_paragraphs() {
+        let parts = [
+            "so the plan is".to_string(),
+            "we ship on monday,".to_string(),
+            "".to_string(),
+            "\"Next,\" he said.".to_string(),
+            "then we rest".to_string(),
+        ];
+        let breaks = [
+            Break::Sentence,
+            Break::Sentence,
+            Break::Paragraph,
+            Break::Sentence,
+        ];
+        assert_eq!(
+            join_parts(&parts, &breaks),
+            "so the plan is. We ship on monday.\n\n\"Next,\" he said. Then we rest"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Detection of microphone permission loss while recording
+//!
+//! When macOS revokes the microphone permission from a running app, the
+//! input stream keeps delivering frames but they are all zeros. Two signals
+//! are watched: a stream of pure digital silence, and the authorization
+//! status polled while a recording is in progress. Either one stops the
+//! recording and emits a `permission-lost` event the UI can act on.
+
+use crate::events::PermissionLost;
+use crate::managers::audio::AudioRecordingManager;
+use crate::utils::cancel_current_operation;
+use log::warn;
+use serde::Serialize;
+use specta::Type;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+/// Seconds of pure digital silence before a stream is considered dead
+pub const SILENT_STREAM_LIMIT_SECS: f32 = 3.0;
+
+/// Deep link to the microphone page of the macOS privacy settings
+pub const MICROPHONE_SETTINGS_URL: &str =
+    "x-apple.systempreferences:com.apple.preference.security?Privacy_Microphone";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionLossReason {
+    /// The OS reports the microphone permission as no longer granted
+    AuthorizationRevoked,
+    /// The input stream only delivers digital silence
+    SilentStream,
+}
+
+/// Payload of the `permission-lost` event
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct PermissionLostEvent {
+    pub permission: String,
+    pub reason: PermissionLossReason,
+    pub message: String,
+    /// Link that opens the relevant system settings page, where one exists
+    pub settings_url: Option<String>,
+}
+
+fn permission_lost_event(reason: PermissionLossReason) -> PermissionLostEvent {
+    let message = match reason {
+        PermissionLossReason::AuthorizationRevoked => {
+            "Microphone access was revoked. Recording was stopped; re-enable Dictum in System Settings > Privacy & Security > Microphone."
+        }
+        PermissionLossReason::SilentStream => {
+            "The microphone stopped delivering audio. Recording was stopped; check that Dictum still has microphone access."
+        }
+    };
+
+    PermissionLostEvent {
+        permission: "microphone".to_string(),
+        reason,
+        message: message.to_string(),
+        settings_url: cfg!(target_os = "macos").then(|| MICROPHONE_SETTINGS_URL.to_string()),
+    }
+}
+
+/// Stop any dictation in progress and tell the UI why
+fn report_permission_lost(app: &AppHandle, reason: PermissionLossReason) {
+    warn!(
+        "Microphone permission lost ({:?}), stopping recording",
+        reason
+    );
+
+    if app.state::<Arc<AudioRecordingManager>>().is_recording() {
+        cancel_current_operation(app);
+    }
+
+    if let Err(e) = PermissionLost(permission_lost_event(reason)).emit(app) {
+        warn!("Failed to emit permission-lost event: {}", e);
+    }
+}
+
+/// Called from the audio thread when the stream has gone digitally silent
+pub fn handle_silent_stream(app: &AppHandle) {
+    // Muting the microphone ourselves also produces silence
+    if crate::helpers::system_mute::is_microphone_muted_by_app() {
+        return;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let reason = if tauri_plugin_macos_permissions::check_microphone_permission().await {
+                PermissionLossReason::SilentStream
+            } else {
+                PermissionLossReason::AuthorizationRevoked
+            };
+            report_permission_lost(&app, reason);
+        });
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        // Outside macOS this is far more likely a hardware mute than a
+        // permission change, so leave the recording alone
+        let _ = app;
+        warn!("Microphone is delivering only digital silence; it may be muted");
+    }
+}
+
+/// Poll the microphone authorization status for as long as a recording runs
+#[cfg(target_os = "macos")]
+pub fn watch_microphone_authorization(app: &AppHandle) {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    const POLL_INTERVAL: Duration = Duration::from_secs(2);
+    static WATCHING: AtomicBool = AtomicBool::new(false);
+
+    if WATCHING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        log::debug!("Watching microphone authorization");
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            if !app.state::<Arc<AudioRecordingManager>>().is_recording() {
+                break;
+            }
+            if !tauri_plugin_macos_permissions::check_microphone_permission().await {
+                report_permission_lost(&app, PermissionLossReason::AuthorizationRevoked);
+                break;
+            }
+        }
+        WATCHING.store(false, Ordering::SeqCst);
+    });
+}
+
+/// Authorization cannot be revoked at runtime on other platforms
+#[cfg(not(target_os = "macos"))]
+pub fn watch_microphone_authorization(_app: &AppHandle) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_payload() {
+        let event = permission_lost_event(PermissionLossReason::AuthorizationRevoked);
+        assert_eq!(event.permission, "microphone");
+        assert!(event.message.contains("revoked"));
+        assert_eq!(
+            event.settings_url.is_some(),
+            cfg!(target_os = "macos"),
+            "deep link is only offered on macOS"
+        );
+    }
+}
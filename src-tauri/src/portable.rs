@@ -0,0 +1,121 @@
+//! Portable mode
+//!
+//! When a `portable` marker file sits next to the executable, all app data
+//! (settings store, models, history, knowledge base, logs) lives in a `Data`
+//! directory beside it instead of the per-user app data directory. This lets
+//! the app run from a USB stick without an installer.
+//!
+//! On macOS the executable is inside the app bundle, so the marker may also
+//! be placed next to `Dictum.app`.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tauri::{AppHandle, Manager};
+
+/// Marker file that switches the app into portable mode
+const MARKER_FILE: &str = "portable";
+
+/// Name of the data directory created next to the marker
+const DATA_DIR_NAME: &str = "Data";
+
+static PORTABLE_DATA_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+/// Directories that may hold the marker, nearest first
+fn marker_locations(exe_dir: &Path) -> Vec<PathBuf> {
+    let mut locations = vec![exe_dir.to_path_buf()];
+
+    // Dictum.app/Contents/MacOS/dictum -> the folder containing Dictum.app
+    if exe_dir.ends_with("Contents/MacOS") {
+        if let Some(bundle_parent) = exe_dir.ancestors().nth(3) {
+            locations.push(bundle_parent.to_path_buf());
+        }
+    }
+
+    locations
+}
+
+fn detect_portable_data_dir(exe_dir: &Path) -> Option<PathBuf> {
+    marker_locations(exe_dir)
+        .into_iter()
+        .find(|dir| dir.join(MARKER_FILE).is_file())
+        .map(|dir| dir.join(DATA_DIR_NAME))
+}
+
+/// The portable data directory, if the app is running in portable mode
+pub fn portable_data_dir() -> Option<&'static Path> {
+    PORTABLE_DATA_DIR
+        .get_or_init(|| {
+            let exe = std::env::current_exe().ok()?;
+            let dir = detect_portable_data_dir(exe.parent()?)?;
+            if let Err(e) = std::fs::create_dir_all(&dir) {
+                // Fall back to the regular location rather than failing to start
+                eprintln!("Portable mode disabled: cannot create {:?}: {}", dir, e);
+                return None;
+            }
+            Some(dir)
+        })
+        .as_deref()
+}
+
+pub fn is_portable() -> bool {
+    portable_data_dir().is_some()
+}
+
+/// App data directory, honouring portable mode
+pub fn app_data_dir(app: &AppHandle) -> tauri::Result<PathBuf> {
+    match portable_data_dir() {
+        Some(dir) => Ok(dir.to_path_buf()),
+        None => app.path().app_data_dir(),
+    }
+}
+
+/// Log directory, honouring portable mode
+pub fn app_log_dir(app: &AppHandle) -> tauri::Result<PathBuf> {
+    match portable_data_dir() {
+        Some(dir) => Ok(dir.join("logs")),
+        None => app.path().app_log_dir(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_no_marker_means_not_portable() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(detect_portable_data_dir(dir.path()), None);
+    }
+
+    #[test]
+    fn test_marker_next_to_executable() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join(MARKER_FILE), "").unwrap();
+
+        assert_eq!(
+            detect_portable_data_dir(dir.path()),
+            Some(dir.path().join(DATA_DIR_NAME))
+        );
+    }
+
+    #[test]
+    fn test_marker_next_to_macos_bundle() {
+        let dir = TempDir::new().unwrap();
+        let exe_dir = dir.path().join("Dictum.app/Contents/MacOS");
+        std::fs::create_dir_all(&exe_dir).unwrap();
+        std::fs::write(dir.path().join(MARKER_FILE), "").unwrap();
+
+        assert_eq!(
+            detect_portable_data_dir(&exe_dir),
+            Some(dir.path().join(DATA_DIR_NAME))
+        );
+    }
+
+    #[test]
+    fn test_marker_directory_is_ignored() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join(MARKER_FILE)).unwrap();
+        assert_eq!(detect_portable_data_dir(dir.path()), None);
+    }
+}
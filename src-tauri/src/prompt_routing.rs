@@ -0,0 +1,169 @@
+//! Prompt routing
+//!
+//! Lets the first words of a dictation pick its post-process prompt. When a
+//! dictation starts with one of the configured phrases ("formal email, hi
+//! Sam..."), the phrase is removed and the rest is post-processed with the
+//! phrase's prompt. Phrases match whole words, ignoring case and punctuation.
+
+use crate::events::PromptRouted;
+use crate::settings::{AppSettings, PromptRoute};
+use log::{debug, warn};
+use serde::Serialize;
+use specta::Type;
+use tauri::AppHandle;
+use tauri_specta::Event;
+
+/// Event payload for a dictation routed to a prompt
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct PromptRoutedEvent {
+    pub phrase: String,
+    pub prompt_id: String,
+    pub prompt_name: String,
+}
+
+/// A word lowercased without punctuation, so "Email," matches "email"
+fn normalize(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// The route whose phrase the text starts with, and the text after it.
+/// Longer phrases win, so "formal email" is preferred over "formal".
+fn match_route<'a, 't>(
+    text: &'t str,
+    routes: &'a [PromptRoute],
+) -> Option<(&'a PromptRoute, &'t str)> {
+    // Normalized words of the text with the byte offset each ends at
+    let mut words = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| !c.is_whitespace()) {
+        let len = rest[start..]
+            .find(char::is_whitespace)
+            .unwrap_or(rest.len() - start);
+        let word = normalize(&rest[start..start + len]);
+        let end = text.len() - rest.len() + start + len;
+        if !word.is_empty() {
+            words.push((word, end));
+        }
+        rest = &text[end..];
+    }
+
+    routes
+        .iter()
+        .filter_map(|route| {
+            let phrase: Vec<String> = route
+                .phrase
+                .split_whitespace()
+                .map(normalize)
+                .filter(|word| !word.is_empty())
+                .collect();
+            let matches = !phrase.is_empty()
+                && phrase.len() < words.len()
+                && phrase.iter().zip(&words).all(|(p, (w, _))| p == w);
+            matches.then(|| (phrase.len(), route))
+        })
+        .max_by_key(|(len, _)| *len)
+        .map(|(len, route)| {
+            let end = words[len - 1].1;
+            // Only the separator after the phrase goes; the text may start
+            // with quotes, brackets or symbols of its own
+            let after = text[end..]
+                .trim_start_matches(|c: char| c.is_whitespace() || matches!(c, ',' | ':' | '.'));
+            (route, after)
+        })
+}
+
+/// Upper-case the first letter, as the text now starts the dictation
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// If the dictation starts with a route's phrase, switch the settings to
+/// post-process with its prompt and return the dictation without the phrase
+pub fn route_dictation(app: &AppHandle, settings: &mut AppSettings, text: &str) -> Option<String> {
+    let (route, rest) = match_route(text, &settings.post_process_routes)?;
+    let Some(prompt) = settings
+        .post_process_prompts
+        .iter()
+        .find(|prompt| prompt.id == route.prompt_id)
+    else {
+        warn!(
+            "Route \"{}\" points at missing prompt '{}'",
+            route.phrase, route.prompt_id
+        );
+        return None;
+    };
+
+    debug!(
+        "Dictation routed by \"{}\" to prompt '{}'",
+        route.phrase, prompt.name
+    );
+    let _ = PromptRouted(PromptRoutedEvent {
+        phrase: route.phrase.clone(),
+        prompt_id: prompt.id.clone(),
+        prompt_name: prompt.name.clone(),
+    })
+    .emit(app);
+
+    let rest = capitalize(rest);
+    settings.post_process_enabled = true;
+    settings.post_process_selected_prompt_id = Some(route.prompt_id.clone());
+    // A spoken route wins over the selected formatting mode
+    settings.formatting.selected_mode = None;
+    Some(rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(phrase: &str, prompt_id: &str) -> PromptRoute {
+        PromptRoute {
+            phrase: phrase.to_string(),
+            prompt_id: prompt_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_phrase_is_stripped_ignoring_case_and_punctuation() {
+        let routes = [
+            route("formal email", "formal"),
+            route("make a list", "list"),
+        ];
+        let (matched, rest) = match_route(" Formal e-mail, hi Sam.", &routes).unwrap();
+        assert_eq!(matched.prompt_id, "formal");
+        assert_eq!(rest, "hi Sam.");
+        assert_eq!(capitalize(rest), "Hi Sam.");
+
+        let (matched, rest) = match_route("Make a list: eggs, milk", &routes).unwrap();
+        assert_eq!(matched.prompt_id, "list");
+        assert_eq!(rest, "eggs, milk");
+
+        let (_, rest) = match_route("Make a list. \"eggs\" and #milk", &routes).unwrap();
+        assert_eq!(rest, "\"eggs\" and #milk");
+        let (_, rest) = match_route("make a list : $5 (bread)", &routes).unwrap();
+        assert_eq!(rest, "$5 (bread)");
+    }
+
+    #[test]
+    fn test_longest_phrase_wins() {
+        let routes = [route("formal", "short"), route("formal email", "long")];
+        let (matched, _) = match_route("formal email dear team", &routes).unwrap();
+        assert_eq!(matched.prompt_id, "long");
+    }
+
+    #[test]
+    fn test_phrase_must_start_the_dictation_and_leave_text() {
+        let routes = [route("formal email", "formal")];
+        assert!(match_route("please write a formal email", &routes).is_none());
+        assert!(match_route("Formal email.", &routes).is_none());
+        assert!(match_route("formality emails matter", &routes).is_none());
+        assert!(match_route("anything", &[route("  ", "empty")]).is_none());
+    }
+}
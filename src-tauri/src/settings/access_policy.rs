@@ -0,0 +1,61 @@
+//! Access Policy Settings
+//!
+//! Hosts and folders the backend may reach on behalf of integrations
+//! (webhooks, LLM providers, backup and sync folders, batch output).
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// How the access policy treats hosts and paths outside the allowlists
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessPolicyMode {
+    /// No checks
+    Off,
+    /// Log and report violations but let the access through
+    #[default]
+    Audit,
+    /// Block violations
+    Enforce,
+}
+
+/// Settings for the access policy
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct AccessPolicySettings {
+    #[serde(default)]
+    pub mode: AccessPolicyMode,
+
+    /// Hosts that may be contacted. An entry also allows its subdomains;
+    /// loopback addresses are always allowed.
+    #[serde(default = "default_allowed_hosts")]
+    pub allowed_hosts: Vec<String>,
+
+    /// Folders that may be written to, besides the app data dir
+    #[serde(default)]
+    pub allowed_paths: Vec<String>,
+}
+
+/// Hosts used by the built-in providers and the model catalog
+fn default_allowed_hosts() -> Vec<String> {
+    [
+        "api.openai.com",
+        "openrouter.ai",
+        "api.anthropic.com",
+        "api.groq.com",
+        "api.cerebras.ai",
+        "blob.handy.computer",
+    ]
+    .iter()
+    .map(|host| host.to_string())
+    .collect()
+}
+
+impl Default for AccessPolicySettings {
+    fn default() -> Self {
+        Self {
+            mode: AccessPolicyMode::default(),
+            allowed_hosts: default_allowed_hosts(),
+            allowed_paths: Vec::new(),
+        }
+    }
+}
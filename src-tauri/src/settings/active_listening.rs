@@ -1,3 +1,4 @@
+use super::ModelUnloadTimeout;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
@@ -35,6 +36,120 @@ impl Default for AudioMixSettings {
     }
 }
 
+/// Limits on the LLM work of one Active Listening session. 0 means no limit.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+pub struct SessionBudget {
+    /// Most insight and LLM suggestion requests per session
+    #[serde(default)]
+    pub max_llm_calls: u32,
+    /// Most estimated prompt and response tokens per session
+    #[serde(default)]
+    pub max_tokens: u32,
+    /// Longest an insight may take for one segment before it's dropped
+    #[serde(default)]
+    pub max_segment_latency_seconds: u32,
+}
+
+/// Checks that drop low-value insights before they're shown or stored.
+/// A dropped insight is generated again up to `max_retries` times.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct InsightFilters {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Shortest insight kept, in characters. 0 keeps any length.
+    #[serde(default = "default_min_insight_length")]
+    pub min_length: u32,
+    /// Insights containing any of these phrases, ignoring case, are dropped
+    #[serde(default = "default_banned_phrases")]
+    pub banned_phrases: Vec<String>,
+    /// Insights whose embedding is at least this similar to the transcript's
+    /// only restate it and are dropped. 0 disables the check.
+    #[serde(default)]
+    pub max_transcript_similarity: f32,
+    /// Ollama model embedding insights and transcripts for the similarity check
+    #[serde(default = "default_insight_embedding_model")]
+    pub embedding_model: String,
+    #[serde(default = "default_insight_retries")]
+    pub max_retries: u32,
+}
+
+fn default_min_insight_length() -> u32 {
+    40
+}
+
+fn default_banned_phrases() -> Vec<String> {
+    [
+        "the speaker discussed various topics",
+        "various topics were discussed",
+        "no specific information",
+        "nothing of note",
+        "no new information",
+    ]
+    .iter()
+    .map(|phrase| phrase.to_string())
+    .collect()
+}
+
+fn default_insight_embedding_model() -> String {
+    "nomic-embed-text".to_string()
+}
+
+fn default_insight_retries() -> u32 {
+    1
+}
+
+impl Default for InsightFilters {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_length: default_min_insight_length(),
+            banned_phrases: default_banned_phrases(),
+            max_transcript_similarity: 0.0,
+            embedding_model: default_insight_embedding_model(),
+            max_retries: default_insight_retries(),
+        }
+    }
+}
+
+/// How the translation of a bilingual session is produced
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TranslationMethod {
+    /// Ask the insight model to translate the transcription
+    #[default]
+    Llm,
+    /// Run the segment through Whisper again in translate mode. Whisper
+    /// only translates into English.
+    Whisper,
+}
+
+/// Keep a translation next to the original transcription of each segment,
+/// for meetings held in more than one language
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct DualLanguageSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Language the segments are translated into
+    #[serde(default = "default_translation_language")]
+    pub target_language: String,
+    #[serde(default)]
+    pub method: TranslationMethod,
+}
+
+fn default_translation_language() -> String {
+    "English".to_string()
+}
+
+impl Default for DualLanguageSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target_language: default_translation_language(),
+            method: TranslationMethod::default(),
+        }
+    }
+}
+
 /// Settings for the Active Listening feature
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
 pub struct ActiveListeningSettings {
@@ -54,6 +169,11 @@ pub struct ActiveListeningSettings {
     #[serde(default = "default_ollama_model")]
     pub ollama_model: String,
 
+    /// How long Ollama keeps the model loaded after a request. The model is
+    /// loaded ahead when a session starts, unless it's unloaded immediately.
+    #[serde(default = "default_ollama_keep_alive")]
+    pub ollama_keep_alive: ModelUnloadTimeout,
+
     /// Custom prompts for active listening
     #[serde(default = "default_prompts")]
     pub prompts: Vec<ActiveListeningPrompt>,
@@ -73,6 +193,63 @@ pub struct ActiveListeningSettings {
     /// Settings for audio mixing when using Mixed mode
     #[serde(default)]
     pub audio_mix_settings: AudioMixSettings,
+
+    /// Mark open action items completed when a later session says they're done
+    #[serde(default)]
+    pub mark_action_item_progress: bool,
+
+    /// Known speakers with their own vocabulary
+    #[serde(default)]
+    pub speaker_profiles: Vec<SpeakerProfile>,
+
+    /// Small, fast model transcribing each segment first for the live
+    /// captions and suggestions. The selected model still transcribes the
+    /// segment for the record and replaces the draft. `None` disables drafts.
+    #[serde(default)]
+    pub draft_model_id: Option<String>,
+
+    /// Limits on LLM calls, tokens and latency per session, so cloud
+    /// providers can't run up unexpected costs
+    #[serde(default)]
+    pub budget: SessionBudget,
+
+    /// Post-filters dropping vacuous or generic insights
+    #[serde(default)]
+    pub insight_filters: InsightFilters,
+
+    /// Translation stored and exported alongside each segment
+    #[serde(default)]
+    pub dual_language: DualLanguageSettings,
+
+    /// Replace people's names with placeholders in stored sessions, keeping
+    /// the names encrypted on this device
+    #[serde(default)]
+    pub anonymize_sessions: bool,
+
+    /// Names replaced in anonymized sessions besides the speakers' and the
+    /// speaker profiles', as confirmed by the user
+    #[serde(default)]
+    pub anonymized_names: Vec<String>,
+}
+
+/// A known speaker whose segments get their own word corrections
+#[derive(Serialize, Deserialize, Debug, Clone, Type, PartialEq)]
+pub struct SpeakerProfile {
+    /// Unique identifier for the profile
+    pub id: String,
+
+    /// Name shown instead of "Speaker N" for segments of this speaker
+    pub name: String,
+
+    /// Names and terms this speaker uses, corrected in their segments on
+    /// top of the global custom words
+    #[serde(default)]
+    pub custom_words: Vec<String>,
+
+    /// Diarized speaker this profile is assigned to when a session starts
+    /// (0 = you)
+    #[serde(default)]
+    pub default_speaker_id: Option<u32>,
 }
 
 /// Category for grouping prompts
@@ -129,6 +306,11 @@ fn default_ollama_model() -> String {
     String::new()
 }
 
+/// Ollama's own default
+fn default_ollama_keep_alive() -> ModelUnloadTimeout {
+    ModelUnloadTimeout::Min5
+}
+
 fn default_context_window_size() -> usize {
     3
 }
@@ -298,11 +480,20 @@ impl Default for ActiveListeningSettings {
             segment_duration_seconds: default_segment_duration_seconds(),
             ollama_base_url: default_ollama_base_url(),
             ollama_model: default_ollama_model(),
+            ollama_keep_alive: default_ollama_keep_alive(),
             prompts: default_prompts(),
             selected_prompt_id: Some("default_meeting_notes".to_string()),
             context_window_size: default_context_window_size(),
             audio_source_type: AudioSourceType::default(),
             audio_mix_settings: AudioMixSettings::default(),
+            mark_action_item_progress: false,
+            speaker_profiles: Vec::new(),
+            draft_model_id: None,
+            budget: SessionBudget::default(),
+            insight_filters: InsightFilters::default(),
+            dual_language: DualLanguageSettings::default(),
+            anonymize_sessions: false,
+            anonymized_names: Vec::new(),
         }
     }
 }
@@ -324,6 +515,11 @@ impl ActiveListeningSettings {
     pub fn get_prompt_mut(&mut self, id: &str) -> Option<&mut ActiveListeningPrompt> {
         self.prompts.iter_mut().find(|p| p.id == id)
     }
+
+    /// Get a speaker profile by ID
+    pub fn get_speaker_profile(&self, id: &str) -> Option<&SpeakerProfile> {
+        self.speaker_profiles.iter().find(|p| p.id == id)
+    }
 }
 
 /// Ensure default prompts exist in settings (for migrations)
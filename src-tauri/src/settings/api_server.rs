@@ -0,0 +1,43 @@
+//! API Server Settings
+//!
+//! The optional local HTTP server scripts and tools use to control the app.
+//! It only listens on the loopback interface and every request needs the
+//! token generated on this machine.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Settings for the local HTTP API
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct ApiServerSettings {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Port on 127.0.0.1 the server listens on
+    #[serde(default = "default_port")]
+    pub port: u16,
+
+    /// Bearer token requests must carry; generated when the server is first
+    /// enabled
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+fn default_port() -> u16 {
+    48213
+}
+
+impl Default for ApiServerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default_port(),
+            token: None,
+        }
+    }
+}
+
+/// A new random API token
+pub fn generate_api_token() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
@@ -35,6 +35,14 @@ pub struct AskAiSettings {
     /// Saved window Y position for the Ask AI overlay
     #[serde(default)]
     pub window_y: Option<f64>,
+
+    /// Let the model call whitelisted local tools while answering
+    #[serde(default)]
+    pub tools_enabled: bool,
+
+    /// Names of the tools the model is allowed to call
+    #[serde(default = "default_allowed_tools")]
+    pub allowed_tools: Vec<String>,
 }
 
 fn default_enabled() -> bool {
@@ -53,6 +61,15 @@ fn default_system_prompt() -> String {
     "You are a helpful AI assistant. Provide clear, concise, and accurate responses.".to_string()
 }
 
+fn default_allowed_tools() -> Vec<String> {
+    // open_url has side effects, so it must be opted into explicitly
+    vec![
+        "search_history".to_string(),
+        "query_knowledge_base".to_string(),
+        "current_datetime".to_string(),
+    ]
+}
+
 impl Default for AskAiSettings {
     fn default() -> Self {
         Self {
@@ -64,6 +81,8 @@ impl Default for AskAiSettings {
             window_height: None,
             window_x: None,
             window_y: None,
+            tools_enabled: false,
+            allowed_tools: default_allowed_tools(),
         }
     }
 }
@@ -0,0 +1,137 @@
+//! Automation Hook Settings
+//!
+//! User scripts and programs that are handed the dictation on stdin at set
+//! points of the pipeline, so they can rewrite it or act on it.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+
+/// Where in the pipeline a hook runs
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum HookStage {
+    /// Right after transcription, before post-processing; the output
+    /// replaces the transcription
+    PostTranscribe,
+    /// Just before pasting; the output replaces the text that's pasted
+    PrePaste,
+    /// After pasting; the output is ignored
+    PostPaste,
+}
+
+impl HookStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HookStage::PostTranscribe => "post_transcribe",
+            HookStage::PrePaste => "pre_paste",
+            HookStage::PostPaste => "post_paste",
+        }
+    }
+
+    /// Whether what the hook prints replaces the text
+    pub fn rewrites_text(&self) -> bool {
+        !matches!(self, HookStage::PostPaste)
+    }
+}
+
+/// A program run at one stage of the pipeline
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct AutomationHook {
+    pub id: String,
+    pub name: String,
+    pub stage: HookStage,
+
+    /// Program to run, either a path or a name looked up on `PATH`
+    pub command: String,
+
+    /// Arguments passed as they are, without a shell
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// How long the program may run before it's killed
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u32,
+
+    /// Pass Dictum's whole environment on; otherwise the program only sees
+    /// the basics like `PATH` and `HOME`, plus `env`
+    #[serde(default)]
+    pub inherit_env: bool,
+
+    /// Extra environment variables
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_timeout_ms() -> u32 {
+    5000
+}
+
+/// Settings for automation hooks
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Type)]
+pub struct AutomationHookSettings {
+    /// Whether any hooks run
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Hooks run in this order within a stage, each getting the output of
+    /// the one before
+    #[serde(default)]
+    pub hooks: Vec<AutomationHook>,
+}
+
+impl AutomationHookSettings {
+    /// Enabled hooks for a stage, in the order they run
+    pub fn hooks_for(&self, stage: HookStage) -> impl Iterator<Item = &AutomationHook> {
+        self.hooks
+            .iter()
+            .filter(move |hook| self.enabled && hook.enabled && hook.stage == stage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hook(id: &str, stage: HookStage, enabled: bool) -> AutomationHook {
+        AutomationHook {
+            id: id.to_string(),
+            name: id.to_string(),
+            stage,
+            command: "cat".to_string(),
+            args: Vec::new(),
+            enabled,
+            timeout_ms: default_timeout_ms(),
+            inherit_env: false,
+            env: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_hooks_for_keeps_order_and_skips_disabled() {
+        let mut settings = AutomationHookSettings {
+            enabled: true,
+            hooks: vec![
+                hook("a", HookStage::PrePaste, true),
+                hook("b", HookStage::PostPaste, true),
+                hook("c", HookStage::PrePaste, false),
+                hook("d", HookStage::PrePaste, true),
+            ],
+        };
+        let ids: Vec<&str> = settings
+            .hooks_for(HookStage::PrePaste)
+            .map(|hook| hook.id.as_str())
+            .collect();
+        assert_eq!(ids, ["a", "d"]);
+
+        settings.enabled = false;
+        assert_eq!(settings.hooks_for(HookStage::PrePaste).count(), 0);
+    }
+}
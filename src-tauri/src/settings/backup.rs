@@ -0,0 +1,50 @@
+//! Backup Settings
+//!
+//! Settings for scheduled snapshots of the app's settings and databases.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Settings for automatic backups
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct BackupSettings {
+    /// Whether scheduled backups are enabled; off until the user opts in
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Hours between scheduled backups
+    #[serde(default = "default_interval_hours")]
+    pub interval_hours: u32,
+
+    /// Folder backups are written to; defaults to `backups` in the app data dir
+    #[serde(default)]
+    pub folder: Option<String>,
+
+    /// Number of backups kept before the oldest are rotated out
+    #[serde(default = "default_keep_count")]
+    pub keep_count: usize,
+
+    /// Also copy recordings, which can make backups much larger
+    #[serde(default)]
+    pub include_audio: bool,
+}
+
+fn default_interval_hours() -> u32 {
+    24
+}
+
+fn default_keep_count() -> usize {
+    7
+}
+
+impl Default for BackupSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_hours: default_interval_hours(),
+            folder: None,
+            keep_count: default_keep_count(),
+            include_audio: false,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Batch Output Settings
+//!
+//! Settings controlling where batch transcriptions are written and how the
+//! output files are named.
+
+use crate::export::ExportFormat;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Where batch output files are placed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchOutputDestination {
+    /// Next to the source audio file
+    SameDirectory,
+    /// Directly inside the output root
+    OutputFolder,
+    /// Inside the output root, recreating the source file's directory tree
+    MirrorTree,
+}
+
+/// Settings for batch transcription output
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct BatchOutputSettings {
+    /// File name template. Supports {{basename}}, {{model}}, {{date}} and {{ext}};
+    /// the format's extension is appended when {{ext}} is not used.
+    #[serde(default = "default_naming_template")]
+    pub naming_template: String,
+
+    /// Where output files are placed
+    #[serde(default = "default_destination")]
+    pub destination: BatchOutputDestination,
+
+    /// Output root for the OutputFolder and MirrorTree destinations
+    #[serde(default)]
+    pub output_root: Option<String>,
+
+    /// Directory the mirrored tree is taken relative to. When unset, or when a
+    /// source file lies outside it, the file's full directory path is mirrored.
+    #[serde(default)]
+    pub mirror_source_root: Option<String>,
+
+    /// Format used for items without a per-item override
+    #[serde(default = "default_format")]
+    pub default_format: ExportFormat,
+}
+
+fn default_naming_template() -> String {
+    "{{basename}}_{{model}}.{{ext}}".to_string()
+}
+
+fn default_destination() -> BatchOutputDestination {
+    BatchOutputDestination::SameDirectory
+}
+
+fn default_format() -> ExportFormat {
+    ExportFormat::Txt
+}
+
+impl Default for BatchOutputSettings {
+    fn default() -> Self {
+        Self {
+            naming_template: default_naming_template(),
+            destination: default_destination(),
+            output_root: None,
+            mirror_source_root: None,
+            default_format: default_format(),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Browser Bridge Settings
+//!
+//! The bridge a companion browser extension connects to, so dictations go
+//! straight into the focused web form instead of through simulated
+//! keystrokes. Site rules pick, per website, whether the bridge or the
+//! normal paste method is used and which formatting mode applies.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// How a dictation reaches a website's focused field
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SiteDelivery {
+    /// Send the text to the extension, which inserts it into the field
+    #[default]
+    Bridge,
+    /// Paste with the configured paste method, for sites whose editors
+    /// ignore inserted text
+    Paste,
+}
+
+/// How dictations into one website are handled
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct SiteRule {
+    /// Host the rule applies to, including its subdomains, so "google.com"
+    /// also covers "docs.google.com"
+    pub host: String,
+
+    #[serde(default)]
+    pub delivery: SiteDelivery,
+
+    /// Formatting mode dictations into the site are formatted with
+    #[serde(default)]
+    pub formatting_mode: Option<String>,
+}
+
+impl SiteRule {
+    fn matches(&self, host: &str) -> bool {
+        let pattern = self.host.trim().trim_start_matches("*.").to_lowercase();
+        let host = host.to_lowercase();
+        !pattern.is_empty()
+            && (host == pattern
+                || host
+                    .strip_suffix(&pattern)
+                    .is_some_and(|prefix| prefix.ends_with('.')))
+    }
+}
+
+/// Settings for the browser extension bridge
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct BrowserBridgeSettings {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Port on 127.0.0.1 the native messaging host connects to
+    #[serde(default = "default_port")]
+    pub port: u16,
+
+    /// Token the native messaging host authenticates with; generated when
+    /// the bridge is first enabled
+    #[serde(default)]
+    pub token: Option<String>,
+
+    /// IDs of the companion extension installs allowed to use the native
+    /// messaging host. Chrome IDs are 32 letters, Firefox IDs contain an `@`.
+    #[serde(default)]
+    pub extension_ids: Vec<String>,
+
+    #[serde(default)]
+    pub site_rules: Vec<SiteRule>,
+}
+
+fn default_port() -> u16 {
+    48214
+}
+
+impl Default for BrowserBridgeSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default_port(),
+            token: None,
+            extension_ids: Vec::new(),
+            site_rules: Vec::new(),
+        }
+    }
+}
+
+impl BrowserBridgeSettings {
+    /// The rule for `host`, preferring the most specific one
+    pub fn rule_for(&self, host: &str) -> Option<&SiteRule> {
+        self.site_rules
+            .iter()
+            .filter(|rule| rule.matches(host))
+            .max_by_key(|rule| rule.host.trim().len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(host: &str, delivery: SiteDelivery) -> SiteRule {
+        SiteRule {
+            host: host.to_string(),
+            delivery,
+            formatting_mode: None,
+        }
+    }
+
+    #[test]
+    fn test_rules_match_subdomains_and_prefer_the_most_specific() {
+        let settings = BrowserBridgeSettings {
+            site_rules: vec![
+                rule("google.com", SiteDelivery::Bridge),
+                rule("*.docs.google.com", SiteDelivery::Paste),
+            ],
+            ..Default::default()
+        };
+        let delivery = |host| settings.rule_for(host).map(|rule| rule.delivery);
+
+        assert_eq!(delivery("google.com"), Some(SiteDelivery::Bridge));
+        assert_eq!(delivery("Mail.Google.com"), Some(SiteDelivery::Bridge));
+        assert_eq!(delivery("docs.google.com"), Some(SiteDelivery::Paste));
+        assert_eq!(delivery("notgoogle.com"), None);
+        assert_eq!(delivery("example.org"), None);
+    }
+}
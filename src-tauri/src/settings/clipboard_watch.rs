@@ -0,0 +1,59 @@
+//! Clipboard Watch Settings
+//!
+//! Settings for watching the clipboard for copied audio files, which are
+//! then offered for batch transcription.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Settings for the clipboard watcher
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct ClipboardWatchSettings {
+    /// Whether the clipboard is watched
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// File extensions, without the dot, of the files that are offered
+    #[serde(default = "default_extensions")]
+    pub extensions: Vec<String>,
+
+    /// How long the clipboard must hold the same path before it's offered,
+    /// so a burst of copies only offers the last one
+    #[serde(default = "default_debounce_ms")]
+    pub debounce_ms: u32,
+}
+
+fn default_extensions() -> Vec<String> {
+    [
+        "wav", "mp3", "m4a", "flac", "ogg", "opus", "aac", "webm", "mp4", "mov", "mkv",
+    ]
+    .iter()
+    .map(|extension| extension.to_string())
+    .collect()
+}
+
+fn default_debounce_ms() -> u32 {
+    1000
+}
+
+impl Default for ClipboardWatchSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            extensions: default_extensions(),
+            debounce_ms: default_debounce_ms(),
+        }
+    }
+}
+
+impl ClipboardWatchSettings {
+    /// Whether a file with this extension is offered, ignoring case
+    pub fn allows_extension(&self, extension: &str) -> bool {
+        self.extensions.iter().any(|allowed| {
+            allowed
+                .trim()
+                .trim_start_matches('.')
+                .eq_ignore_ascii_case(extension)
+        })
+    }
+}
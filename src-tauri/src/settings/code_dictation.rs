@@ -0,0 +1,123 @@
+//! Code Dictation Settings
+//!
+//! Dictating into a code editor or terminal switches to a pipeline that
+//! leaves fillers and punctuation alone, turns "variable name" phrases into
+//! identifiers and types the text instead of pasting it.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+pub use crate::audio_toolkit::IdentifierCase;
+
+/// Settings for dictating into code editors and terminals
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct CodeDictationSettings {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// Apps treated as code editors or terminals, matched against the start
+    /// of the focused app's lowercased name, or of the focused site's host
+    /// when the browser extension is connected
+    #[serde(default = "default_apps")]
+    pub apps: Vec<String>,
+
+    /// Case used for "variable name" phrases
+    #[serde(default = "default_identifier_case")]
+    pub identifier_case: IdentifierCase,
+
+    /// Type the text directly instead of using the configured paste method
+    #[serde(default = "default_prefer_direct_paste")]
+    pub prefer_direct_paste: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_apps() -> Vec<String> {
+    [
+        "code",
+        "codium",
+        "cursor",
+        "windsurf",
+        "zed",
+        "xcode",
+        "idea",
+        "pycharm",
+        "webstorm",
+        "clion",
+        "goland",
+        "rustrover",
+        "android studio",
+        "studio64",
+        "sublime_text",
+        "vim",
+        "nvim",
+        "emacs",
+        "terminal",
+        "iterm2",
+        "warp",
+        "ghostty",
+        "alacritty",
+        "kitty",
+        "wezterm",
+        "konsole",
+        "gnome-terminal",
+        "windowsterminal",
+        "powershell",
+        "pwsh",
+        "cmd",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect()
+}
+
+fn default_identifier_case() -> IdentifierCase {
+    IdentifierCase::Snake
+}
+
+fn default_prefer_direct_paste() -> bool {
+    true
+}
+
+impl Default for CodeDictationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            apps: default_apps(),
+            identifier_case: default_identifier_case(),
+            prefer_direct_paste: default_prefer_direct_paste(),
+        }
+    }
+}
+
+impl CodeDictationSettings {
+    /// Whether dictating into `app` should use the code pipeline
+    pub fn applies_to(&self, app: &str) -> bool {
+        let app = app.to_lowercase();
+        self.enabled
+            && self
+                .apps
+                .iter()
+                .map(|name| name.trim().to_lowercase())
+                .any(|name| !name.is_empty() && app.starts_with(&name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_to_known_editors_only() {
+        let mut settings = CodeDictationSettings::default();
+        assert!(settings.applies_to("code"));
+        assert!(settings.applies_to("idea64"));
+        assert!(settings.applies_to("wezterm-gui"));
+        assert!(!settings.applies_to("slack"));
+
+        settings.enabled = false;
+        assert!(!settings.applies_to("code"));
+    }
+}
@@ -0,0 +1,273 @@
+//! Config file overrides
+//!
+//! An optional `config.toml` (or `config.json`) in the user config directory,
+//! e.g. `~/.config/dictum/config.toml`, overrides the stored settings. It is
+//! applied at startup and again whenever the file changes, so settings can be
+//! managed declaratively or pushed out to many machines. `DICTUM_CONFIG`
+//! points at a different file; in portable mode the file sits in the data
+//! directory.
+//!
+//! Keys are the same as in the settings store. Tables are merged key by key
+//! and every other value, including arrays, replaces the stored one:
+//!
+//! ```toml
+//! push_to_talk = true
+//! selected_language = "en"
+//!
+//! [backup]
+//! enabled = true
+//! ```
+
+use super::{get_settings, write_settings, AppSettings, ShortcutBinding};
+use crate::events::SettingsConfigApplied;
+use crate::shortcut;
+use log::{debug, info, warn};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+/// Environment variable naming an explicit config file
+pub const CONFIG_ENV: &str = "DICTUM_CONFIG";
+
+/// How often the config file is checked for changes
+const WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+const CONFIG_DIR_NAME: &str = "dictum";
+const CONFIG_FILE_NAMES: &[&str] = &["config.toml", "config.json"];
+
+/// Locate the config file, if one exists
+pub fn config_file_path(app: &AppHandle) -> Option<PathBuf> {
+    if let Ok(path) = std::env::var(CONFIG_ENV) {
+        if !path.is_empty() {
+            return Some(PathBuf::from(path));
+        }
+    }
+
+    let dir = match crate::portable::portable_data_dir() {
+        Some(dir) => dir.to_path_buf(),
+        None => app.path().config_dir().ok()?.join(CONFIG_DIR_NAME),
+    };
+    CONFIG_FILE_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+fn parse_config(path: &Path, text: &str) -> Result<Value, String> {
+    let value: Value = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str(text).map_err(|e| e.to_string())?,
+        _ => toml::from_str(text).map_err(|e| e.to_string())?,
+    };
+    if !value.is_object() {
+        return Err("Config file must contain a table of settings".to_string());
+    }
+    Ok(value)
+}
+
+/// Merge overrides into the settings value, returning the top-level keys
+/// whose value changed
+fn merge_overrides(settings: &mut Value, overrides: &Value) -> Vec<String> {
+    let (Some(settings), Some(overrides)) = (settings.as_object_mut(), overrides.as_object())
+    else {
+        return Vec::new();
+    };
+
+    let mut changed = Vec::new();
+    for (key, value) in overrides {
+        let Some(current) = settings.get_mut(key) else {
+            warn!("Ignoring unknown setting '{}' in config file", key);
+            continue;
+        };
+        let before = current.clone();
+        merge_value(current, value);
+        if *current != before {
+            changed.push(key.clone());
+        }
+    }
+    changed
+}
+
+fn merge_value(target: &mut Value, overrides: &Value) {
+    match (target, overrides) {
+        (Value::Object(target), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                match target.get_mut(key) {
+                    Some(current) => merge_value(current, value),
+                    None => {
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (target, value) => *target = value.clone(),
+    }
+}
+
+fn apply_overrides(
+    settings: &AppSettings,
+    overrides: &Value,
+) -> Result<(AppSettings, Vec<String>), String> {
+    let mut value = serde_json::to_value(settings).map_err(|e| e.to_string())?;
+    let changed = merge_overrides(&mut value, overrides);
+    let updated = serde_json::from_value(value)
+        .map_err(|e| format!("Config file has an invalid value: {}", e))?;
+    Ok((updated, changed))
+}
+
+/// Apply the config file to the stored settings. Returns the settings that
+/// changed; an invalid file leaves the stored settings untouched.
+pub fn apply_config_file(app: &AppHandle) -> Result<Vec<String>, String> {
+    let Some(path) = config_file_path(app) else {
+        return Ok(Vec::new());
+    };
+
+    let text = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read config file {:?}: {}", path, e))?;
+    let overrides =
+        parse_config(&path, &text).map_err(|e| format!("Invalid config file {:?}: {}", path, e))?;
+
+    let (updated, changed) = apply_overrides(&get_settings(app), &overrides)?;
+    if !changed.is_empty() {
+        info!(
+            "Config file {:?} overrides settings: {}",
+            path,
+            changed.join(", ")
+        );
+        write_settings(app, updated);
+    }
+    Ok(changed)
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Shortcuts are registered at startup, so swap any the file changed
+fn rebind_shortcuts(app: &AppHandle, previous: &HashMap<String, ShortcutBinding>) {
+    for (id, binding) in get_settings(app).bindings {
+        // The cancel shortcut is registered dynamically while recording
+        if id == "cancel" {
+            continue;
+        }
+        let Some(old) = previous.get(&id) else {
+            continue;
+        };
+        if old.current_binding == binding.current_binding {
+            continue;
+        }
+        if let Err(e) = shortcut::unregister_shortcut(app, old.clone()) {
+            warn!("Failed to unregister shortcut {}: {}", id, e);
+        }
+        if let Err(e) = shortcut::register_shortcut(app, binding) {
+            warn!("Failed to register shortcut {} from config file: {}", id, e);
+        }
+    }
+}
+
+/// Re-apply the config file whenever it is created or modified, emitting
+/// `settings-config-applied` with the keys that changed
+pub fn watch_config_file(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut last_seen = config_file_path(&app).and_then(|p| modified_time(&p));
+        loop {
+            tokio::time::sleep(WATCH_INTERVAL).await;
+
+            let modified = config_file_path(&app).and_then(|p| modified_time(&p));
+            if modified.is_none() || modified == last_seen {
+                last_seen = modified;
+                continue;
+            }
+            last_seen = modified;
+
+            debug!("Config file changed, reloading");
+            let previous_bindings = get_settings(&app).bindings;
+            match apply_config_file(&app) {
+                Ok(changed) if !changed.is_empty() => {
+                    if changed.iter().any(|key| key == "bindings") {
+                        rebind_shortcuts(&app, &previous_bindings);
+                    }
+                    let _ = SettingsConfigApplied(changed).emit(&app);
+                }
+                Ok(_) => {}
+                Err(e) => warn!("{}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_toml_and_json() {
+        let toml = parse_config(
+            Path::new("config.toml"),
+            "push_to_talk = true\n[backup]\nenabled = true\n",
+        )
+        .unwrap();
+        assert_eq!(
+            toml,
+            json!({ "push_to_talk": true, "backup": { "enabled": true } })
+        );
+
+        let json = parse_config(Path::new("config.json"), r#"{"push_to_talk": true}"#).unwrap();
+        assert_eq!(json, json!({ "push_to_talk": true }));
+
+        assert!(parse_config(Path::new("config.json"), "[1, 2]").is_err());
+        assert!(parse_config(Path::new("config.toml"), "push_to_talk = ").is_err());
+    }
+
+    #[test]
+    fn test_nested_tables_merge_and_unknown_keys_are_ignored() {
+        let mut settings = json!({
+            "push_to_talk": false,
+            "backup": { "enabled": false, "keep": 5 },
+            "custom_words": ["a"],
+        });
+        let changed = merge_overrides(
+            &mut settings,
+            &json!({
+                "backup": { "enabled": true },
+                "custom_words": ["b"],
+                "push_to_talk": false,
+                "no_such_setting": 1,
+            }),
+        );
+
+        assert_eq!(
+            changed,
+            vec!["backup".to_string(), "custom_words".to_string()]
+        );
+        assert_eq!(
+            settings,
+            json!({
+                "push_to_talk": false,
+                "backup": { "enabled": true, "keep": 5 },
+                "custom_words": ["b"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_apply_overrides_rejects_invalid_values() {
+        let settings = get_default_settings();
+
+        let (updated, changed) = apply_overrides(
+            &settings,
+            &json!({ "push_to_talk": !settings.general.push_to_talk }),
+        )
+        .unwrap();
+        assert_eq!(updated.general.push_to_talk, !settings.general.push_to_talk);
+        assert_eq!(changed, vec!["push_to_talk".to_string()]);
+
+        assert!(apply_overrides(&settings, &json!({ "push_to_talk": "yes" })).is_err());
+    }
+}
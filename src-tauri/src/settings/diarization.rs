@@ -0,0 +1,92 @@
+//! Diarization Settings
+//!
+//! Settings for telling Active Listening speakers apart.
+
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::{
+    EmbeddingDiarizer, EmbeddingDiarizerConfig, EnergyBasedDiarizer, OnnxSpeakerEmbedder,
+    SpeakerDiarizer, SpeakerEmbedder, SpectralEmbedder,
+};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// How speakers are told apart
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DiarizationBackend {
+    /// Turn-taking and loudness changes; fine for two people
+    #[default]
+    Energy,
+    /// Clustering of speaker embeddings; for three or more people
+    Embedding,
+}
+
+/// Settings for speaker diarization
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct DiarizationSettings {
+    #[serde(default)]
+    pub backend: DiarizationBackend,
+
+    /// ONNX speaker embedding model for the embedding backend. Without one,
+    /// voices are compared by their spectral shape.
+    #[serde(default)]
+    pub model_path: Option<String>,
+
+    /// How similar two stretches of speech must be to count as the same
+    /// speaker (0.0-1.0)
+    #[serde(default = "default_similarity_threshold")]
+    pub similarity_threshold: f32,
+
+    /// Most speakers told apart in one session
+    #[serde(default = "default_max_speakers")]
+    pub max_speakers: u32,
+}
+
+fn default_similarity_threshold() -> f32 {
+    0.7
+}
+
+fn default_max_speakers() -> u32 {
+    8
+}
+
+impl Default for DiarizationSettings {
+    fn default() -> Self {
+        Self {
+            backend: DiarizationBackend::default(),
+            model_path: None,
+            similarity_threshold: default_similarity_threshold(),
+            max_speakers: default_max_speakers(),
+        }
+    }
+}
+
+impl DiarizationSettings {
+    /// Build the diarizer these settings describe. A model that fails to
+    /// load falls back to spectral embeddings.
+    pub fn create_diarizer(&self) -> Box<dyn SpeakerDiarizer> {
+        if self.backend == DiarizationBackend::Energy {
+            return Box::new(EnergyBasedDiarizer::new());
+        }
+
+        let embedder: Box<dyn SpeakerEmbedder> = match &self.model_path {
+            Some(path) => match OnnxSpeakerEmbedder::new(path) {
+                Ok(embedder) => {
+                    info!("Loaded speaker embedding model from {}", path);
+                    Box::new(embedder)
+                }
+                Err(e) => {
+                    warn!("{}, comparing voices by spectral shape instead", e);
+                    Box::new(SpectralEmbedder::new(WHISPER_SAMPLE_RATE))
+                }
+            },
+            None => Box::new(SpectralEmbedder::new(WHISPER_SAMPLE_RATE)),
+        };
+        let config = EmbeddingDiarizerConfig::default()
+            .with_sample_rate(WHISPER_SAMPLE_RATE)
+            .with_similarity_threshold(self.similarity_threshold)
+            .with_max_speakers(self.max_speakers as usize);
+        Box::new(EmbeddingDiarizer::with_config(embedder, config))
+    }
+}
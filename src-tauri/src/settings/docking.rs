@@ -0,0 +1,118 @@
+//! Docking Settings
+//!
+//! Rules that swap devices and settings depending on whether the computer
+//! is docked to an external display, e.g. "when docked, use the USB
+//! interface mic and turn audio feedback off".
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// How the computer is currently set up
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum DockState {
+    /// Only the built-in display is in use
+    #[default]
+    Undocked,
+    /// An external display is connected and the lid is open
+    Docked,
+    /// The lid is closed and only external displays are in use
+    Clamshell,
+}
+
+/// Overrides applied while the computer is in a dock state. Unset fields
+/// keep the regular setting.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Type)]
+pub struct DockOverrides {
+    /// Microphone to use, by name; ignored if the device isn't connected
+    #[serde(default)]
+    pub microphone: Option<String>,
+    /// Output device for feedback sounds, by name
+    #[serde(default)]
+    pub output_device: Option<String>,
+    #[serde(default)]
+    pub audio_feedback: Option<bool>,
+}
+
+/// Overrides for one dock state
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct DockRule {
+    pub state: DockState,
+    #[serde(default)]
+    pub overrides: DockOverrides,
+}
+
+/// Settings for dock-dependent overrides
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Type)]
+pub struct DockingSettings {
+    /// Whether dock rules are applied
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// At most one rule per dock state; the first one wins
+    #[serde(default)]
+    pub rules: Vec<DockRule>,
+}
+
+impl DockingSettings {
+    /// Overrides that apply in the given dock state, if any
+    pub fn overrides_for(&self, state: DockState) -> Option<&DockOverrides> {
+        if !self.enabled {
+            return None;
+        }
+        self.rules
+            .iter()
+            .find(|rule| rule.state == state)
+            .map(|rule| &rule.overrides)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(state: DockState, microphone: &str) -> DockRule {
+        DockRule {
+            state,
+            overrides: DockOverrides {
+                microphone: Some(microphone.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_overrides_for_matches_state() {
+        let settings = DockingSettings {
+            enabled: true,
+            rules: vec![
+                rule(DockState::Docked, "USB Interface"),
+                rule(DockState::Docked, "Ignored"),
+                rule(DockState::Clamshell, "Webcam"),
+            ],
+        };
+
+        assert_eq!(
+            settings
+                .overrides_for(DockState::Docked)
+                .and_then(|o| o.microphone.as_deref()),
+            Some("USB Interface")
+        );
+        assert_eq!(
+            settings
+                .overrides_for(DockState::Clamshell)
+                .and_then(|o| o.microphone.as_deref()),
+            Some("Webcam")
+        );
+        assert!(settings.overrides_for(DockState::Undocked).is_none());
+    }
+
+    #[test]
+    fn test_disabled_rules_do_not_apply() {
+        let settings = DockingSettings {
+            enabled: false,
+            rules: vec![rule(DockState::Docked, "USB Interface")],
+        };
+        assert!(settings.overrides_for(DockState::Docked).is_none());
+    }
+}
@@ -0,0 +1,45 @@
+//! Export Settings
+//!
+//! User-editable export templates and the default webhook exports are sent
+//! to. The built-in templates live in [`crate::export::templates`]; only
+//! templates the user created or customized are stored here.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Kind of object an export template renders
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportKind {
+    /// A dictation from the history
+    Transcript,
+    /// An Active Listening meeting summary
+    MeetingSummary,
+    /// Action items from the history
+    ActionItems,
+    /// An Ask AI conversation
+    AskAiConversation,
+}
+
+/// A minijinja template that turns one kind of object into text
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct ExportTemplate {
+    pub id: String,
+    pub name: String,
+    pub kind: ExportKind,
+    /// Template source
+    pub body: String,
+    /// Extension of files exported with this template, without the dot
+    pub file_extension: String,
+}
+
+/// Settings for templated exports
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Type)]
+pub struct ExportSettings {
+    /// Templates created by the user, in addition to the built-in ones
+    #[serde(default)]
+    pub templates: Vec<ExportTemplate>,
+    /// Webhook used when an export to a webhook doesn't name its own URL
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+}
@@ -0,0 +1,133 @@
+//! Formatting Mode Settings
+//!
+//! Output formats a dictation can be shaped into before it's pasted, like an
+//! email, a Markdown list or a git commit message. The selected mode's prompt
+//! post-processes every dictation until another mode is picked from the
+//! tray, the shortcut or the settings.
+
+use super::LLMPrompt;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// A format dictations can be post-processed into
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct FormattingMode {
+    pub id: String,
+    pub name: String,
+    /// Post-process prompt, with `${output}` standing for the dictation
+    pub prompt: String,
+}
+
+impl FormattingMode {
+    /// The mode as a post-process prompt for the selected provider
+    pub fn to_prompt(&self) -> LLMPrompt {
+        LLMPrompt {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            prompt: self.prompt.clone(),
+            provider_id: None,
+            model: None,
+        }
+    }
+}
+
+/// Settings for formatting modes
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct FormattingSettings {
+    /// The mode dictations are formatted with, if any
+    #[serde(default)]
+    pub selected_mode: Option<String>,
+
+    #[serde(default = "default_formatting_modes")]
+    pub modes: Vec<FormattingMode>,
+}
+
+pub fn default_formatting_modes() -> Vec<FormattingMode> {
+    let mode = |id: &str, name: &str, instructions: &str| FormattingMode {
+        id: id.to_string(),
+        name: name.to_string(),
+        prompt: format!(
+            "{} Reply with only the result, without any explanation.\n\nTranscript:\n${{output}}",
+            instructions
+        ),
+    };
+    vec![
+        mode(
+            "email",
+            "Email",
+            "Write the following transcript as a short, well-structured email with a greeting and a sign-off. Keep the meaning and the tone.",
+        ),
+        mode(
+            "bullet_list",
+            "Markdown List",
+            "Turn the following transcript into a Markdown bullet list, one point per item.",
+        ),
+        mode(
+            "commit_message",
+            "Commit Message",
+            "Turn the following transcript into a git commit message: a summary line of at most 72 characters in the imperative mood, a blank line and, if needed, a short body wrapped at 72 characters.",
+        ),
+        mode(
+            "code_comment",
+            "Code Comment",
+            "Turn the following transcript into a concise code comment. Use // line comments unless the transcript names another comment style.",
+        ),
+    ]
+}
+
+impl Default for FormattingSettings {
+    fn default() -> Self {
+        Self {
+            selected_mode: None,
+            modes: default_formatting_modes(),
+        }
+    }
+}
+
+impl FormattingSettings {
+    /// The selected mode, if it still exists
+    pub fn active_mode(&self) -> Option<&FormattingMode> {
+        let selected = self.selected_mode.as_deref()?;
+        self.modes.iter().find(|mode| mode.id == selected)
+    }
+
+    /// The mode after the active one, going back to no formatting after the
+    /// last one
+    pub fn next_mode(&self) -> Option<String> {
+        let next = match self.active_mode() {
+            Some(active) => {
+                let index = self.modes.iter().position(|mode| mode.id == active.id)?;
+                self.modes.get(index + 1)
+            }
+            None => self.modes.first(),
+        };
+        next.map(|mode| mode.id.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_mode_cycles_through_modes_and_off() {
+        let mut settings = FormattingSettings::default();
+        let mut seen = Vec::new();
+        loop {
+            settings.selected_mode = settings.next_mode();
+            match &settings.selected_mode {
+                Some(id) => seen.push(id.clone()),
+                None => break,
+            }
+        }
+        assert_eq!(
+            seen,
+            ["email", "bullet_list", "commit_message", "code_comment"]
+        );
+
+        // A mode that was removed counts as no formatting
+        settings.selected_mode = Some("removed".to_string());
+        assert!(settings.active_mode().is_none());
+        assert_eq!(settings.next_mode().as_deref(), Some("email"));
+    }
+}
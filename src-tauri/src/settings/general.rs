@@ -1,6 +1,15 @@
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+/// Release channel the updater checks
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    Beta,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
 pub struct GeneralSettings {
     pub push_to_talk: bool,
@@ -11,6 +20,17 @@ pub struct GeneralSettings {
     #[serde(default = "default_update_checks_enabled")]
     pub update_checks_enabled: bool,
     #[serde(default)]
+    pub update_channel: UpdateChannel,
+    /// Version the user chose to skip; newer versions are still offered
+    #[serde(default)]
+    pub skipped_update_version: Option<String>,
+    /// Unix timestamp until which automatic update prompts are deferred
+    #[serde(default)]
+    pub update_deferred_until: Option<i64>,
+    /// Stable 0-99 bucket deciding when staged rollouts reach this install
+    #[serde(default)]
+    pub update_rollout_bucket: Option<u8>,
+    #[serde(default)]
     pub mute_while_recording: bool,
     #[serde(default)]
     pub append_trailing_space: bool,
@@ -20,6 +40,65 @@ pub struct GeneralSettings {
     /// Enabled by default for privacy during screen sharing
     #[serde(default = "default_private_overlay")]
     pub private_overlay: bool,
+    /// Show the input level on the tray icon while recording
+    #[serde(default = "default_tray_level_indicator")]
+    pub tray_level_indicator: bool,
+    /// Speech probability above which the VAD keeps a frame
+    #[serde(default = "default_vad_threshold")]
+    pub vad_threshold: f32,
+    /// With the microphone always on, skip processing quiet input while not
+    /// recording
+    #[serde(default = "default_low_power_idle")]
+    pub low_power_idle: bool,
+    /// Input RMS that wakes the full audio pipeline in low-power idle
+    #[serde(default = "default_idle_wake_threshold")]
+    pub idle_wake_threshold: f32,
+    /// In on-demand mode, open the microphone as soon as the first modifier
+    /// of a transcribe shortcut is pressed
+    #[serde(default)]
+    pub prearm_microphone: bool,
+    /// With the microphone always on, start each recording with the audio
+    /// heard just before the shortcut
+    #[serde(default)]
+    pub retroactive_capture: bool,
+    /// How much audio retroactive capture keeps, in seconds
+    #[serde(default = "default_retroactive_capture_seconds")]
+    pub retroactive_capture_seconds: f32,
+    /// Drop audio that doesn't match the enrolled voice before transcribing
+    #[serde(default)]
+    pub primary_speaker_only: bool,
+    /// Voiceprint similarity (-1 to 1) audio needs to count as the user's
+    #[serde(default = "default_primary_speaker_threshold")]
+    pub primary_speaker_threshold: f32,
+    /// Voiceprint bands of the enrolled primary user
+    #[serde(default)]
+    pub voice_profile: Option<Vec<f32>>,
+    /// Remove the app's own feedback sounds from the microphone input
+    #[serde(default = "default_echo_cancellation")]
+    pub echo_cancellation: bool,
+    /// Scale recordings to a steady level before they're transcribed, so
+    /// quiet microphones still get picked up
+    #[serde(default)]
+    pub gain_normalization: bool,
+    /// Level speech is normalized to, in dBFS
+    #[serde(default = "default_gain_target_dbfs")]
+    pub gain_target_dbfs: f32,
+    /// Show live text in the overlay while recording
+    #[serde(default = "default_streaming_preview")]
+    pub streaming_preview: bool,
+    /// Without push-to-talk, end a recording once nothing has been said for
+    /// a while, e.g. after walking away from it
+    #[serde(default)]
+    pub silence_auto_stop: bool,
+    /// How long the VAD has to hear no speech before the recording ends
+    #[serde(default = "default_silence_auto_stop_seconds")]
+    pub silence_auto_stop_seconds: f32,
+    /// Stop and transcribe recordings that run past a length limit, so a
+    /// forgotten recording can't grow for hours
+    #[serde(default)]
+    pub recording_limit: bool,
+    #[serde(default = "default_recording_limit_minutes")]
+    pub recording_limit_minutes: u32,
 }
 
 impl Default for GeneralSettings {
@@ -29,10 +108,32 @@ impl Default for GeneralSettings {
             start_hidden: default_start_hidden(),
             autostart_enabled: default_autostart_enabled(),
             update_checks_enabled: default_update_checks_enabled(),
+            update_channel: UpdateChannel::default(),
+            skipped_update_version: None,
+            update_deferred_until: None,
+            update_rollout_bucket: None,
             mute_while_recording: false,
             append_trailing_space: false,
             app_language: default_app_language(),
             private_overlay: default_private_overlay(),
+            tray_level_indicator: default_tray_level_indicator(),
+            vad_threshold: default_vad_threshold(),
+            low_power_idle: default_low_power_idle(),
+            idle_wake_threshold: default_idle_wake_threshold(),
+            prearm_microphone: false,
+            retroactive_capture: false,
+            retroactive_capture_seconds: default_retroactive_capture_seconds(),
+            primary_speaker_only: false,
+            primary_speaker_threshold: default_primary_speaker_threshold(),
+            voice_profile: None,
+            echo_cancellation: default_echo_cancellation(),
+            gain_normalization: false,
+            gain_target_dbfs: default_gain_target_dbfs(),
+            streaming_preview: default_streaming_preview(),
+            silence_auto_stop: false,
+            silence_auto_stop_seconds: default_silence_auto_stop_seconds(),
+            recording_limit: false,
+            recording_limit_minutes: default_recording_limit_minutes(),
         }
     }
 }
@@ -59,3 +160,48 @@ fn default_private_overlay() -> bool {
     // Enabled by default for privacy during screen sharing
     true
 }
+
+fn default_tray_level_indicator() -> bool {
+    true
+}
+
+fn default_vad_threshold() -> f32 {
+    0.3
+}
+
+fn default_low_power_idle() -> bool {
+    true
+}
+
+fn default_idle_wake_threshold() -> f32 {
+    // About -40 dBFS: above a quiet room, below speech
+    0.01
+}
+
+fn default_retroactive_capture_seconds() -> f32 {
+    2.0
+}
+
+fn default_primary_speaker_threshold() -> f32 {
+    0.85
+}
+
+fn default_echo_cancellation() -> bool {
+    true
+}
+
+fn default_gain_target_dbfs() -> f32 {
+    -20.0
+}
+
+fn default_streaming_preview() -> bool {
+    true
+}
+
+fn default_silence_auto_stop_seconds() -> f32 {
+    10.0
+}
+
+fn default_recording_limit_minutes() -> u32 {
+    10
+}
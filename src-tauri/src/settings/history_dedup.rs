@@ -0,0 +1,51 @@
+//! History Deduplication Settings
+//!
+//! Settings for catching accidental double dictations: a dictation saved
+//! shortly after a nearly identical one is flagged as its duplicate, and
+//! exact repeats can be dropped instead of being saved.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Settings for detecting consecutive duplicate dictations
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Type)]
+pub struct HistoryDedupSettings {
+    /// Whether near-duplicates are flagged in the history
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// How soon after the previous dictation a new one counts as a repeat
+    #[serde(default = "default_window_seconds")]
+    pub window_seconds: u32,
+
+    /// Similarity from 0 to 1 at which two transcriptions are near-duplicates
+    #[serde(default = "default_similarity_threshold")]
+    pub similarity_threshold: f64,
+
+    /// Don't save a dictation that repeats the previous one word for word
+    #[serde(default)]
+    pub discard_exact_duplicates: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_window_seconds() -> u32 {
+    30
+}
+
+fn default_similarity_threshold() -> f64 {
+    0.85
+}
+
+impl Default for HistoryDedupSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window_seconds: default_window_seconds(),
+            similarity_threshold: default_similarity_threshold(),
+            discard_exact_duplicates: false,
+        }
+    }
+}
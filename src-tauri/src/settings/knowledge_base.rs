@@ -31,6 +31,30 @@ pub struct KnowledgeBaseSettings {
     /// Use RAG context in Active Listening prompts
     #[serde(default = "default_use_in_active_listening")]
     pub use_in_active_listening: bool,
+
+    /// Number of chunks sent to Ollama per embed request
+    #[serde(default = "default_embedding_batch_size")]
+    pub embedding_batch_size: usize,
+
+    /// Maximum number of embed requests in flight while indexing a document
+    #[serde(default = "default_embedding_concurrency")]
+    pub embedding_concurrency: usize,
+
+    /// Retries for embed requests that fail with a transient error
+    #[serde(default = "default_embedding_max_retries")]
+    pub embedding_max_retries: u32,
+
+    /// Maximum documents the background indexing queue processes per minute
+    #[serde(default = "default_indexing_max_per_minute")]
+    pub indexing_max_per_minute: u32,
+
+    /// Hold background indexing while an Active Listening session is running
+    #[serde(default = "default_pause_indexing_during_sessions")]
+    pub pause_indexing_during_sessions: bool,
+
+    /// Attempts before a queued document is marked as failed
+    #[serde(default = "default_indexing_max_attempts")]
+    pub indexing_max_attempts: u32,
 }
 
 fn default_enabled() -> bool {
@@ -57,6 +81,30 @@ fn default_use_in_active_listening() -> bool {
     true
 }
 
+fn default_embedding_batch_size() -> usize {
+    16
+}
+
+fn default_embedding_concurrency() -> usize {
+    2
+}
+
+fn default_embedding_max_retries() -> u32 {
+    3
+}
+
+fn default_indexing_max_per_minute() -> u32 {
+    6
+}
+
+fn default_pause_indexing_during_sessions() -> bool {
+    true
+}
+
+fn default_indexing_max_attempts() -> u32 {
+    5
+}
+
 impl Default for KnowledgeBaseSettings {
     fn default() -> Self {
         Self {
@@ -66,6 +114,12 @@ impl Default for KnowledgeBaseSettings {
             top_k: default_top_k(),
             similarity_threshold: default_similarity_threshold(),
             use_in_active_listening: default_use_in_active_listening(),
+            embedding_batch_size: default_embedding_batch_size(),
+            embedding_concurrency: default_embedding_concurrency(),
+            embedding_max_retries: default_embedding_max_retries(),
+            indexing_max_per_minute: default_indexing_max_per_minute(),
+            pause_indexing_during_sessions: default_pause_indexing_during_sessions(),
+            indexing_max_attempts: default_indexing_max_attempts(),
         }
     }
 }
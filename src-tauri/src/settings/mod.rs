@@ -3,27 +3,100 @@ use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use specta::Type;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use tauri::AppHandle;
 use tauri_plugin_store::StoreExt;
 
+pub mod access_policy;
 pub mod active_listening;
+pub mod api_server;
 pub mod ask_ai;
+pub mod automation_hooks;
+pub mod backup;
+pub mod batch_output;
+pub mod browser_bridge;
+pub mod clipboard_watch;
+pub mod code_dictation;
+pub mod config_file;
+pub mod diarization;
+pub mod docking;
+pub mod export;
+pub mod formatting;
 pub mod general;
+pub mod history_dedup;
 pub mod knowledge_base;
+pub mod noise_suppression;
+pub mod notifications;
+pub mod paragraphing;
+pub mod plugins;
+pub mod remote_control;
+pub mod schedule;
+pub mod signals;
 pub mod sound_detection;
 pub mod suggestions;
-
+pub mod sync;
+pub mod transcribe_profiles;
+pub mod transcription;
+pub mod translation;
+pub mod voice_commands;
+pub mod wake_word;
+pub mod webhooks;
+
+pub use access_policy::{AccessPolicyMode, AccessPolicySettings};
 pub use active_listening::{
-    ActiveListeningPrompt, ActiveListeningSettings, AudioSourceType, PromptCategory,
+    ActiveListeningPrompt, ActiveListeningSettings, AudioSourceType, DualLanguageSettings,
+    InsightFilters, PromptCategory, SessionBudget, SpeakerProfile, TranslationMethod,
 };
+pub use api_server::{generate_api_token, ApiServerSettings};
 pub use ask_ai::AskAiSettings;
+pub use automation_hooks::{AutomationHook, AutomationHookSettings, HookStage};
+pub use backup::BackupSettings;
+pub use batch_output::{BatchOutputDestination, BatchOutputSettings};
+pub use browser_bridge::{BrowserBridgeSettings, SiteDelivery, SiteRule};
+pub use clipboard_watch::ClipboardWatchSettings;
+pub use code_dictation::{CodeDictationSettings, IdentifierCase};
+pub use diarization::{DiarizationBackend, DiarizationSettings};
+pub use docking::{DockOverrides, DockRule, DockState, DockingSettings};
+pub use export::{ExportKind, ExportSettings, ExportTemplate};
+pub use formatting::{FormattingMode, FormattingSettings};
+pub use history_dedup::HistoryDedupSettings;
 pub use knowledge_base::KnowledgeBaseSettings;
+pub use noise_suppression::NoiseSuppressionSettings;
+pub use notifications::{NotificationFeature, NotificationSettings, NotificationSeverity};
+pub use paragraphing::ParagraphingSettings;
+pub use plugins::PluginSettings;
+pub use remote_control::{PairedDevice, RemoteControlSettings};
+pub use schedule::{ScheduleOverrides, ScheduleRule, ScheduleSettings};
+pub use signals::{SignalSettings, UnixSignal};
 pub use sound_detection::{SoundCategory, SoundDetectionSettings};
-pub use suggestions::{QuickResponse, SuggestionsSettings, WarningSeverity};
+pub use suggestions::{QuickResponse, SuggestionsSettings, WarningRoute, WarningSeverity};
+pub use sync::{SyncConflictPolicy, SyncSettings};
+pub use transcribe_profiles::{is_profile_binding, profile_binding_id, TranscribeProfile};
+pub use transcription::{
+    RemoteSttApi, RemoteSttConfig, RemoteSttProvider, TranscriptionBackendSettings, LOCAL_BACKEND,
+};
+pub use translation::{TranslationSettings, OLLAMA_TRANSLATION_PROVIDER};
+pub use voice_commands::{VoiceCommand, VoiceCommandAction, VoiceCommandSettings};
+pub use wake_word::{WakeWordAction, WakeWordSettings};
+pub use webhooks::{WebhookEvent, WebhookSettings};
 
 pub const APPLE_INTELLIGENCE_PROVIDER_ID: &str = "apple_intelligence";
 pub const APPLE_INTELLIGENCE_DEFAULT_MODEL_ID: &str = "Apple Intelligence";
 
+/// Top-level settings that hold API keys, tokens, credentials or commands.
+/// Plugin scripts can't read them and folder sync keeps them on this device.
+pub const PRIVATE_SETTINGS: &[&str] = &[
+    "post_process_api_keys",
+    "post_process_providers",
+    "transcription_backend",
+    "api_server",
+    "remote_control",
+    "webhooks",
+    "sync",
+    "automation_hooks",
+    "browser_bridge",
+];
+
 #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "lowercase")]
 pub enum LogLevel {
@@ -105,6 +178,20 @@ pub struct LLMPrompt {
     pub id: String,
     pub name: String,
     pub prompt: String,
+    /// Provider to post-process with instead of the selected one
+    #[serde(default)]
+    pub provider_id: Option<String>,
+    /// Model to use instead of the provider's configured model
+    #[serde(default)]
+    pub model: Option<String>,
+}
+
+/// A spoken phrase that picks the post-process prompt for a dictation
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct PromptRoute {
+    /// Words the dictation starts with, e.g. "formal email"
+    pub phrase: String,
+    pub prompt_id: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
@@ -255,10 +342,18 @@ pub struct AppSettings {
     pub always_on_microphone: bool,
     #[serde(default)]
     pub selected_microphone: Option<String>,
+    /// Stable id of the selected microphone; the name is kept for display
+    /// and as a fallback when the id is unknown
+    #[serde(default)]
+    pub selected_microphone_id: Option<String>,
     #[serde(default)]
     pub clamshell_microphone: Option<String>,
     #[serde(default)]
+    pub clamshell_microphone_id: Option<String>,
+    #[serde(default)]
     pub selected_output_device: Option<String>,
+    #[serde(default)]
+    pub selected_output_device_id: Option<String>,
     #[serde(default = "default_translate_to_english")]
     pub translate_to_english: bool,
     #[serde(default = "default_selected_language")]
@@ -267,8 +362,14 @@ pub struct AppSettings {
     pub overlay_position: OverlayPosition,
     #[serde(default = "default_debug_mode")]
     pub debug_mode: bool,
+    /// Read-only guest mode: nothing is written to history, the knowledge base or disk
+    #[serde(default)]
+    pub guest_mode: bool,
     #[serde(default = "default_log_level")]
     pub log_level: LogLevel,
+    /// File log level overrides by module, see `log_levels`
+    #[serde(default)]
+    pub log_module_levels: HashMap<String, LogLevel>,
     #[serde(default)]
     pub custom_words: Vec<String>,
     #[serde(default)]
@@ -279,6 +380,16 @@ pub struct AppSettings {
     pub history_limit: usize,
     #[serde(default = "default_recording_retention_period")]
     pub recording_retention_period: RecordingRetentionPeriod,
+    /// Cap on the total size of stored recordings in MB; `None` means no cap.
+    /// Enforced on top of the retention period by evicting the oldest unsaved entries.
+    #[serde(default)]
+    pub history_max_size_mb: Option<u64>,
+    /// Days deleted history entries stay in the trash before being purged; 0 deletes immediately
+    #[serde(default = "default_history_trash_retention_days")]
+    pub history_trash_retention_days: u32,
+    /// Detection of accidental double dictations
+    #[serde(default)]
+    pub history_dedup: HistoryDedupSettings,
     #[serde(default)]
     pub paste_method: PasteMethod,
     #[serde(default = "default_paste_delay_ms")]
@@ -299,9 +410,15 @@ pub struct AppSettings {
     pub post_process_prompts: Vec<LLMPrompt>,
     #[serde(default)]
     pub post_process_selected_prompt_id: Option<String>,
+    /// Dictations starting with a route's phrase have it stripped and are
+    /// post-processed with its prompt, even when post-processing is off
+    #[serde(default)]
+    pub post_process_routes: Vec<PromptRoute>,
     #[serde(default)]
     pub active_listening: ActiveListeningSettings,
     #[serde(default)]
+    pub diarization: DiarizationSettings,
+    #[serde(default)]
     pub ask_ai: AskAiSettings,
     #[serde(default)]
     pub knowledge_base: KnowledgeBaseSettings,
@@ -309,6 +426,69 @@ pub struct AppSettings {
     pub suggestions: SuggestionsSettings,
     #[serde(default)]
     pub sound_detection: SoundDetectionSettings,
+    /// Denoise captured audio before it's transcribed
+    #[serde(default)]
+    pub noise_suppression: NoiseSuppressionSettings,
+    #[serde(default)]
+    pub notifications: NotificationSettings,
+    #[serde(default)]
+    pub batch_output: BatchOutputSettings,
+    /// Offer copied audio file paths for batch transcription
+    #[serde(default)]
+    pub clipboard_watch: ClipboardWatchSettings,
+    #[serde(default)]
+    pub export: ExportSettings,
+    #[serde(default)]
+    pub transcription_backend: TranscriptionBackendSettings,
+    #[serde(default)]
+    pub backup: BackupSettings,
+    #[serde(default)]
+    pub sync: SyncSettings,
+    #[serde(default)]
+    pub docking: DockingSettings,
+    /// Overrides for certain times, e.g. quiet hours
+    #[serde(default)]
+    pub schedule: ScheduleSettings,
+    #[serde(default)]
+    pub webhooks: WebhookSettings,
+    /// User programs run on the dictation at set points of the pipeline
+    #[serde(default)]
+    pub automation_hooks: AutomationHookSettings,
+    /// Rhai scripts adding pipeline stages, suggestions and actions
+    #[serde(default)]
+    pub plugins: PluginSettings,
+    #[serde(default)]
+    pub paragraphing: ParagraphingSettings,
+    /// Dictations pasted in another language than they were spoken in
+    #[serde(default)]
+    pub translation: TranslationSettings,
+    #[serde(default)]
+    pub api_server: ApiServerSettings,
+    /// Push-to-talk and live captions from a paired phone or browser
+    #[serde(default)]
+    pub remote_control: RemoteControlSettings,
+    #[serde(default)]
+    pub code_dictation: CodeDictationSettings,
+    /// Spoken commands like "new line" carried out before pasting
+    #[serde(default)]
+    pub voice_commands: VoiceCommandSettings,
+    /// Output format dictations are post-processed into, like an email
+    #[serde(default)]
+    pub formatting: FormattingSettings,
+    /// Dictating into web forms through the companion browser extension
+    #[serde(default)]
+    pub browser_bridge: BrowserBridgeSettings,
+    /// Extra transcribe shortcuts, each with its own language, post-process
+    /// prompt and paste method
+    #[serde(default)]
+    pub transcribe_profiles: Vec<TranscribeProfile>,
+    /// Saying a keyword to start dictating without a shortcut
+    #[serde(default)]
+    pub wake_word: WakeWordSettings,
+    #[serde(default)]
+    pub signals: SignalSettings,
+    #[serde(default)]
+    pub access_policy: AccessPolicySettings,
 }
 
 fn default_model() -> String {
@@ -350,6 +530,10 @@ fn default_history_limit() -> usize {
     5
 }
 
+fn default_history_trash_retention_days() -> u32 {
+    30
+}
+
 fn default_recording_retention_period() -> RecordingRetentionPeriod {
     RecordingRetentionPeriod::PreserveLimit
 }
@@ -471,6 +655,8 @@ fn default_post_process_prompts() -> Vec<LLMPrompt> {
         id: "default_improve_transcriptions".to_string(),
         name: "Improve Transcriptions".to_string(),
         prompt: "Clean this transcript:\n1. Fix spelling, capitalization, and punctuation errors\n2. Convert number words to digits (twenty-five → 25, ten percent → 10%, five dollars → $5)\n3. Replace spoken punctuation with symbols (period → ., comma → ,, question mark → ?)\n4. Remove filler words (um, uh, like as filler)\n5. Keep the language in the original version (if it was french, keep it in french for example)\n\nPreserve exact meaning and word order. Do not paraphrase or reorder content.\n\nReturn only the cleaned transcript.\n\nTranscript:\n${output}".to_string(),
+        provider_id: None,
+        model: None,
     }]
 }
 
@@ -515,6 +701,15 @@ fn ensure_post_process_defaults(settings: &mut AppSettings) -> bool {
 
 pub const SETTINGS_STORE_PATH: &str = "settings_store.json";
 
+/// Path handed to the store plugin. Relative paths resolve against the app
+/// data directory, so only portable mode needs an absolute path.
+pub fn settings_store_path() -> PathBuf {
+    match crate::portable::portable_data_dir() {
+        Some(dir) => dir.join(SETTINGS_STORE_PATH),
+        None => PathBuf::from(SETTINGS_STORE_PATH),
+    }
+}
+
 pub fn get_default_settings() -> AppSettings {
     #[cfg(target_os = "windows")]
     let default_shortcut = "ctrl+space";
@@ -531,6 +726,12 @@ pub fn get_default_settings() -> AppSettings {
     #[cfg(not(target_os = "macos"))]
     let active_listening_shortcut = "ctrl+shift+l";
 
+    // Highlight shortcut
+    #[cfg(target_os = "macos")]
+    let mark_highlight_shortcut = "cmd+shift+b";
+    #[cfg(not(target_os = "macos"))]
+    let mark_highlight_shortcut = "ctrl+shift+b";
+
     // Ask AI shortcut
     #[cfg(target_os = "macos")]
     let ask_ai_shortcut = "cmd+shift+a";
@@ -543,6 +744,18 @@ pub fn get_default_settings() -> AppSettings {
     #[cfg(not(target_os = "macos"))]
     let toggle_overlay_shortcut = "ctrl+shift+h";
 
+    // Microphone mute shortcut
+    #[cfg(target_os = "macos")]
+    let toggle_mic_mute_shortcut = "cmd+alt+m";
+    #[cfg(not(target_os = "macos"))]
+    let toggle_mic_mute_shortcut = "ctrl+alt+m";
+
+    // Formatting mode shortcut
+    #[cfg(target_os = "macos")]
+    let cycle_formatting_mode_shortcut = "cmd+alt+f";
+    #[cfg(not(target_os = "macos"))]
+    let cycle_formatting_mode_shortcut = "ctrl+alt+f";
+
     let mut bindings = HashMap::new();
     bindings.insert(
         "transcribe".to_string(),
@@ -576,6 +789,17 @@ pub fn get_default_settings() -> AppSettings {
             current_binding: active_listening_shortcut.to_string(),
         },
     );
+    bindings.insert(
+        "mark_highlight".to_string(),
+        ShortcutBinding {
+            id: "mark_highlight".to_string(),
+            name: "Mark Highlight".to_string(),
+            description: "Mark the current moment of an active listening session as important."
+                .to_string(),
+            default_binding: mark_highlight_shortcut.to_string(),
+            current_binding: mark_highlight_shortcut.to_string(),
+        },
+    );
     bindings.insert(
         "ask_ai".to_string(),
         ShortcutBinding {
@@ -596,6 +820,26 @@ pub fn get_default_settings() -> AppSettings {
             current_binding: toggle_overlay_shortcut.to_string(),
         },
     );
+    bindings.insert(
+        "toggle_mic_mute".to_string(),
+        ShortcutBinding {
+            id: "toggle_mic_mute".to_string(),
+            name: "Mute Microphone".to_string(),
+            description: "Mute or unmute the microphone system-wide.".to_string(),
+            default_binding: toggle_mic_mute_shortcut.to_string(),
+            current_binding: toggle_mic_mute_shortcut.to_string(),
+        },
+    );
+    bindings.insert(
+        "cycle_formatting_mode".to_string(),
+        ShortcutBinding {
+            id: "cycle_formatting_mode".to_string(),
+            name: "Formatting Mode".to_string(),
+            description: "Switch to the next formatting mode for dictations.".to_string(),
+            default_binding: cycle_formatting_mode_shortcut.to_string(),
+            current_binding: cycle_formatting_mode_shortcut.to_string(),
+        },
+    );
 
     AppSettings {
         general: general::GeneralSettings::default(),
@@ -606,18 +850,26 @@ pub fn get_default_settings() -> AppSettings {
         selected_model: "".to_string(),
         always_on_microphone: false,
         selected_microphone: None,
+        selected_microphone_id: None,
         clamshell_microphone: None,
+        clamshell_microphone_id: None,
         selected_output_device: None,
+        selected_output_device_id: None,
         translate_to_english: false,
         selected_language: "auto".to_string(),
         overlay_position: default_overlay_position(),
         debug_mode: false,
+        guest_mode: false,
         log_level: default_log_level(),
+        log_module_levels: HashMap::new(),
         custom_words: Vec::new(),
         model_unload_timeout: ModelUnloadTimeout::Never,
         word_correction_threshold: default_word_correction_threshold(),
         history_limit: default_history_limit(),
         recording_retention_period: default_recording_retention_period(),
+        history_max_size_mb: None,
+        history_trash_retention_days: default_history_trash_retention_days(),
+        history_dedup: HistoryDedupSettings::default(),
         paste_method: PasteMethod::default(),
         paste_delay_ms: default_paste_delay_ms(),
         clipboard_handling: ClipboardHandling::default(),
@@ -628,21 +880,81 @@ pub fn get_default_settings() -> AppSettings {
         post_process_models: default_post_process_models(),
         post_process_prompts: default_post_process_prompts(),
         post_process_selected_prompt_id: None,
+        post_process_routes: Vec::new(),
         active_listening: ActiveListeningSettings::default(),
+        diarization: DiarizationSettings::default(),
         ask_ai: AskAiSettings::default(),
         knowledge_base: KnowledgeBaseSettings::default(),
         suggestions: SuggestionsSettings::default(),
         sound_detection: SoundDetectionSettings::default(),
+        noise_suppression: NoiseSuppressionSettings::default(),
+        notifications: NotificationSettings::default(),
+        batch_output: BatchOutputSettings::default(),
+        clipboard_watch: ClipboardWatchSettings::default(),
+        export: ExportSettings::default(),
+        transcription_backend: TranscriptionBackendSettings::default(),
+        backup: BackupSettings::default(),
+        sync: SyncSettings::default(),
+        docking: DockingSettings::default(),
+        schedule: ScheduleSettings::default(),
+        webhooks: WebhookSettings::default(),
+        automation_hooks: AutomationHookSettings::default(),
+        plugins: PluginSettings::default(),
+        paragraphing: ParagraphingSettings::default(),
+        translation: TranslationSettings::default(),
+        api_server: ApiServerSettings::default(),
+        remote_control: RemoteControlSettings::default(),
+        code_dictation: CodeDictationSettings::default(),
+        voice_commands: VoiceCommandSettings::default(),
+        formatting: FormattingSettings::default(),
+        browser_bridge: BrowserBridgeSettings::default(),
+        transcribe_profiles: Vec::new(),
+        wake_word: WakeWordSettings::default(),
+        signals: SignalSettings::default(),
+        access_policy: AccessPolicySettings::default(),
     }
 }
 
 impl AppSettings {
+    /// The settings a shortcut's dictations are handled with, with the
+    /// binding's transcribe profile applied when it has one
+    pub fn for_binding(mut self, binding_id: &str) -> Self {
+        let profile = self
+            .transcribe_profiles
+            .iter()
+            .find(|profile| profile_binding_id(&profile.id) == binding_id)
+            .cloned();
+        if let Some(profile) = profile {
+            profile.apply(&mut self);
+        }
+        self
+    }
+
     pub fn active_post_process_provider(&self) -> Option<&PostProcessProvider> {
         self.post_process_providers
             .iter()
             .find(|provider| provider.id == self.post_process_provider_id)
     }
 
+    /// Provider and model a prompt is post-processed with: its own override
+    /// when it has one, otherwise the selected provider and its model
+    pub fn post_process_target(
+        &self,
+        prompt: &LLMPrompt,
+    ) -> Option<(&PostProcessProvider, String)> {
+        let provider = match prompt.provider_id.as_deref() {
+            Some(provider_id) => self.post_process_provider(provider_id)?,
+            None => self.active_post_process_provider()?,
+        };
+        let model = prompt
+            .model
+            .clone()
+            .filter(|model| !model.trim().is_empty())
+            .or_else(|| self.post_process_models.get(&provider.id).cloned())
+            .unwrap_or_default();
+        Some((provider, model))
+    }
+
     pub fn post_process_provider(&self, provider_id: &str) -> Option<&PostProcessProvider> {
         self.post_process_providers
             .iter()
@@ -662,7 +974,7 @@ impl AppSettings {
 pub fn load_or_create_app_settings(app: &AppHandle) -> AppSettings {
     // Initialize store
     let store = app
-        .store(SETTINGS_STORE_PATH)
+        .store(settings_store_path())
         .expect("Failed to initialize store");
 
     let mut settings = if let Some(settings_value) = store.get("settings") {
@@ -717,7 +1029,7 @@ pub fn load_or_create_app_settings(app: &AppHandle) -> AppSettings {
 
 pub fn get_settings(app: &AppHandle) -> AppSettings {
     let store = app
-        .store(SETTINGS_STORE_PATH)
+        .store(settings_store_path())
         .expect("Failed to initialize store");
 
     let mut settings = if let Some(settings_value) = store.get("settings") {
@@ -745,7 +1057,7 @@ pub fn get_settings(app: &AppHandle) -> AppSettings {
 
 pub fn write_settings(app: &AppHandle, settings: AppSettings) {
     let store = app
-        .store(SETTINGS_STORE_PATH)
+        .store(settings_store_path())
         .expect("Failed to initialize store");
 
     store.set("settings", serde_json::to_value(&settings).unwrap());
@@ -765,6 +1077,10 @@ pub fn get_stored_binding(app: &AppHandle, id: &str) -> ShortcutBinding {
     binding
 }
 
+pub fn is_guest_mode(app: &AppHandle) -> bool {
+    get_settings(app).guest_mode
+}
+
 pub fn get_history_limit(app: &AppHandle) -> usize {
     let settings = get_settings(app);
     settings.history_limit
@@ -775,6 +1091,13 @@ pub fn get_recording_retention_period(app: &AppHandle) -> RecordingRetentionPeri
     settings.recording_retention_period
 }
 
+pub fn get_history_max_size_bytes(app: &AppHandle) -> Option<u64> {
+    let settings = get_settings(app);
+    settings
+        .history_max_size_mb
+        .map(|mb| mb.saturating_mul(1024 * 1024))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1180,4 +1503,35 @@ mod tests {
             "max_suggestions should default to 3"
         );
     }
+
+    #[test]
+    fn test_prompt_override_takes_precedence_over_selected_provider() {
+        let mut settings = get_default_settings();
+        settings.post_process_provider_id = "openai".to_string();
+        settings
+            .post_process_models
+            .insert("openai".to_string(), "gpt-4o-mini".to_string());
+        settings
+            .post_process_models
+            .insert("anthropic".to_string(), "claude-haiku".to_string());
+        let mut prompt = settings.post_process_prompts[0].clone();
+
+        let (provider, model) = settings.post_process_target(&prompt).unwrap();
+        assert_eq!(provider.id, "openai");
+        assert_eq!(model, "gpt-4o-mini");
+
+        // The override provider's configured model is used unless the prompt
+        // names one
+        prompt.provider_id = Some("anthropic".to_string());
+        let (provider, model) = settings.post_process_target(&prompt).unwrap();
+        assert_eq!(provider.id, "anthropic");
+        assert_eq!(model, "claude-haiku");
+
+        prompt.model = Some("claude-opus".to_string());
+        let (_, model) = settings.post_process_target(&prompt).unwrap();
+        assert_eq!(model, "claude-opus");
+
+        prompt.provider_id = Some("removed".to_string());
+        assert!(settings.post_process_target(&prompt).is_none());
+    }
 }
@@ -0,0 +1,19 @@
+//! Noise Suppression Settings
+//!
+//! Settings for the RNNoise stage that cleans up captured audio before voice
+//! activity detection and transcription, per audio source.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Which audio sources are denoised
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, Type)]
+pub struct NoiseSuppressionSettings {
+    /// Denoise the microphone
+    #[serde(default)]
+    pub microphone: bool,
+
+    /// Denoise captured system audio
+    #[serde(default)]
+    pub system_audio: bool,
+}
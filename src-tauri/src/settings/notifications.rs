@@ -0,0 +1,250 @@
+//! Notification Settings
+//!
+//! Which features may raise system notifications, how important a
+//! notification must be, quiet hours, and how bursts are batched.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// A feature that raises system notifications
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationFeature {
+    SoundDetection,
+    Suggestions,
+    Warnings,
+    ActionItems,
+    /// Audio files copied to the clipboard
+    Clipboard,
+}
+
+/// How important a notification is
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Type, Default,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationSeverity {
+    #[default]
+    Info,
+    Warning,
+    /// Shown even during quiet hours
+    Critical,
+}
+
+/// Notification settings of one feature
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+pub struct FeatureNotificationSettings {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Notifications less important than this are dropped
+    #[serde(default)]
+    pub min_severity: NotificationSeverity,
+}
+
+impl Default for FeatureNotificationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_severity: NotificationSeverity::Info,
+        }
+    }
+}
+
+/// A daily stretch of time without notifications, in minutes after local
+/// midnight. The end may be earlier than the start to span midnight.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+pub struct QuietHours {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_quiet_start")]
+    pub start_minute: u32,
+    #[serde(default = "default_quiet_end")]
+    pub end_minute: u32,
+}
+
+impl Default for QuietHours {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start_minute: default_quiet_start(),
+            end_minute: default_quiet_end(),
+        }
+    }
+}
+
+impl QuietHours {
+    pub fn contains(&self, minute_of_day: u32) -> bool {
+        if !self.enabled || self.start_minute == self.end_minute {
+            return false;
+        }
+        if self.start_minute < self.end_minute {
+            (self.start_minute..self.end_minute).contains(&minute_of_day)
+        } else {
+            minute_of_day >= self.start_minute || minute_of_day < self.end_minute
+        }
+    }
+}
+
+/// Settings for system notifications
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct NotificationSettings {
+    /// Master switch for every system notification
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    #[serde(default)]
+    pub sound_detection: FeatureNotificationSettings,
+
+    /// Off by default, since suggestions are already shown in the overlay
+    #[serde(default = "default_suggestions")]
+    pub suggestions: FeatureNotificationSettings,
+
+    #[serde(default)]
+    pub warnings: FeatureNotificationSettings,
+
+    #[serde(default)]
+    pub action_items: FeatureNotificationSettings,
+
+    #[serde(default)]
+    pub clipboard: FeatureNotificationSettings,
+
+    #[serde(default)]
+    pub quiet_hours: QuietHours,
+
+    /// After a notification, further ones from the same feature within this
+    /// many seconds are held and shown together. 0 shows each one.
+    #[serde(default = "default_batch_window_seconds")]
+    pub batch_window_seconds: u32,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_suggestions() -> FeatureNotificationSettings {
+    FeatureNotificationSettings {
+        enabled: false,
+        ..Default::default()
+    }
+}
+
+fn default_quiet_start() -> u32 {
+    22 * 60
+}
+
+fn default_quiet_end() -> u32 {
+    7 * 60
+}
+
+fn default_batch_window_seconds() -> u32 {
+    30
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sound_detection: FeatureNotificationSettings::default(),
+            suggestions: default_suggestions(),
+            warnings: FeatureNotificationSettings::default(),
+            action_items: FeatureNotificationSettings::default(),
+            clipboard: FeatureNotificationSettings::default(),
+            quiet_hours: QuietHours::default(),
+            batch_window_seconds: default_batch_window_seconds(),
+        }
+    }
+}
+
+impl NotificationSettings {
+    pub fn feature(&self, feature: NotificationFeature) -> &FeatureNotificationSettings {
+        match feature {
+            NotificationFeature::SoundDetection => &self.sound_detection,
+            NotificationFeature::Suggestions => &self.suggestions,
+            NotificationFeature::Warnings => &self.warnings,
+            NotificationFeature::ActionItems => &self.action_items,
+            NotificationFeature::Clipboard => &self.clipboard,
+        }
+    }
+
+    /// Whether a notification may be shown at the given local time
+    pub fn allows(
+        &self,
+        feature: NotificationFeature,
+        severity: NotificationSeverity,
+        minute_of_day: u32,
+    ) -> bool {
+        let feature = self.feature(feature);
+        self.enabled
+            && feature.enabled
+            && severity >= feature.min_severity
+            && (severity == NotificationSeverity::Critical
+                || !self.quiet_hours.contains(minute_of_day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quiet_hours_span_midnight() {
+        let quiet = QuietHours {
+            enabled: true,
+            ..Default::default()
+        };
+        assert!(quiet.contains(23 * 60));
+        assert!(quiet.contains(3 * 60));
+        assert!(!quiet.contains(7 * 60));
+        assert!(!quiet.contains(12 * 60));
+
+        let lunch = QuietHours {
+            enabled: true,
+            start_minute: 12 * 60,
+            end_minute: 13 * 60,
+        };
+        assert!(lunch.contains(12 * 60 + 30));
+        assert!(!lunch.contains(23 * 60));
+    }
+
+    #[test]
+    fn test_allows_respects_features_severity_and_quiet_hours() {
+        let mut settings = NotificationSettings::default();
+        settings.warnings.min_severity = NotificationSeverity::Warning;
+        settings.quiet_hours.enabled = true;
+        let noon = 12 * 60;
+        let night = 23 * 60;
+
+        assert!(settings.allows(
+            NotificationFeature::ActionItems,
+            NotificationSeverity::Info,
+            noon
+        ));
+        assert!(!settings.allows(
+            NotificationFeature::Suggestions,
+            NotificationSeverity::Info,
+            noon
+        ));
+        assert!(!settings.allows(
+            NotificationFeature::Warnings,
+            NotificationSeverity::Info,
+            noon
+        ));
+        assert!(!settings.allows(
+            NotificationFeature::Warnings,
+            NotificationSeverity::Warning,
+            night
+        ));
+        assert!(settings.allows(
+            NotificationFeature::Warnings,
+            NotificationSeverity::Critical,
+            night
+        ));
+
+        settings.enabled = false;
+        assert!(!settings.allows(
+            NotificationFeature::Warnings,
+            NotificationSeverity::Critical,
+            noon
+        ));
+    }
+}
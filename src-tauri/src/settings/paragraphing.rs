@@ -0,0 +1,45 @@
+//! Paragraphing Settings
+//!
+//! How long a pause in a dictation has to be to end a sentence or start a
+//! new paragraph, and whether speaker turns get "A:", "B:" markers.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Settings for breaking dictations up at pauses
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct ParagraphingSettings {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Pause, in milliseconds, that ends a sentence
+    #[serde(default = "default_sentence_pause_ms")]
+    pub sentence_pause_ms: u32,
+
+    /// Pause, in milliseconds, that starts a new paragraph
+    #[serde(default = "default_paragraph_pause_ms")]
+    pub paragraph_pause_ms: u32,
+
+    /// Start a "A:", "B:" line at each speaker turn, for interviews
+    #[serde(default)]
+    pub speaker_markers: bool,
+}
+
+fn default_sentence_pause_ms() -> u32 {
+    700
+}
+
+fn default_paragraph_pause_ms() -> u32 {
+    2000
+}
+
+impl Default for ParagraphingSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sentence_pause_ms: default_sentence_pause_ms(),
+            paragraph_pause_ms: default_paragraph_pause_ms(),
+            speaker_markers: false,
+        }
+    }
+}
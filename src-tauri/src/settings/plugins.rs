@@ -0,0 +1,26 @@
+//! Plugin Settings
+//!
+//! Settings for the Rhai scripts loaded from the `plugins` folder, which can
+//! add text-processing stages, suggestion sources and shortcut actions.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Whether plugins run, and which ones are turned off
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Type)]
+pub struct PluginSettings {
+    /// Load and run plugins at all
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// File names (without `.rhai`) of plugins that are skipped
+    #[serde(default)]
+    pub disabled: Vec<String>,
+}
+
+impl PluginSettings {
+    /// Whether the plugin with this name should be loaded
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.enabled && !self.disabled.iter().any(|disabled| disabled == name)
+    }
+}
@@ -0,0 +1,57 @@
+//! Remote Control Settings
+//!
+//! The optional server on the local network that lets a paired phone or
+//! browser act as a push-to-talk button and follow live captions and Ask AI
+//! answers. Each paired device has its own token, so one can be revoked
+//! without re-pairing the others.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// A phone or browser paired with a pairing code
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct PairedDevice {
+    pub id: String,
+    pub name: String,
+    /// Bearer token the device sends with every request
+    pub token: String,
+    /// Seconds since the Unix epoch
+    pub paired_at: i64,
+}
+
+/// Settings for the remote control server
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct RemoteControlSettings {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Port the server listens on, on every network interface
+    #[serde(default = "default_port")]
+    pub port: u16,
+
+    /// Announce the server over mDNS so companion apps can find it
+    #[serde(default = "default_advertise")]
+    pub advertise: bool,
+
+    #[serde(default)]
+    pub devices: Vec<PairedDevice>,
+}
+
+fn default_port() -> u16 {
+    48214
+}
+
+fn default_advertise() -> bool {
+    true
+}
+
+impl Default for RemoteControlSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default_port(),
+            advertise: default_advertise(),
+            devices: Vec::new(),
+        }
+    }
+}
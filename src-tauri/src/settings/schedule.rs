@@ -0,0 +1,58 @@
+//! Schedule Settings
+//!
+//! Rules that change settings at certain times of day or days of the week,
+//! e.g. "turn sound detection on at night" or "no Active Listening during
+//! focus hours". When a rule applies is written like a cron expression.
+
+use super::SoundTheme;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Overrides applied while a schedule rule is active. Unset fields keep the
+/// regular setting.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Type)]
+pub struct ScheduleOverrides {
+    #[serde(default)]
+    pub sound_detection: Option<bool>,
+    /// Whether Active Listening sessions can be started
+    #[serde(default)]
+    pub active_listening: Option<bool>,
+    #[serde(default)]
+    pub sound_theme: Option<SoundTheme>,
+}
+
+impl ScheduleOverrides {
+    /// Fill the fields this doesn't set from `other`
+    pub fn or(self, other: &ScheduleOverrides) -> ScheduleOverrides {
+        ScheduleOverrides {
+            sound_detection: self.sound_detection.or(other.sound_detection),
+            active_listening: self.active_listening.or(other.active_listening),
+            sound_theme: self.sound_theme.or(other.sound_theme),
+        }
+    }
+}
+
+/// Overrides for the minutes a cron expression matches
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct ScheduleRule {
+    pub name: String,
+    /// Minute, hour, day of month, month and day of week the rule applies
+    /// in, e.g. `* 22-6 * * *` for every night or `* 9-11 * * 1-5` for
+    /// weekday mornings. Ranges may wrap around.
+    pub when: String,
+    #[serde(default)]
+    pub overrides: ScheduleOverrides,
+}
+
+/// Settings for time-based overrides
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Type)]
+pub struct ScheduleSettings {
+    /// Whether schedule rules are applied
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Rules in order of precedence; for each setting the first active rule
+    /// that overrides it wins
+    #[serde(default)]
+    pub rules: Vec<ScheduleRule>,
+}
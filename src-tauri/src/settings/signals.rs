@@ -0,0 +1,65 @@
+//! Signal Settings
+//!
+//! Which shortcut action each Unix signal triggers, so window managers and
+//! hotkey daemons can drive any action with `pkill -USR1`/`pkill -USR2`.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Signals that can trigger actions
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum UnixSignal {
+    Usr1,
+    Usr2,
+}
+
+impl UnixSignal {
+    pub fn name(self) -> &'static str {
+        match self {
+            UnixSignal::Usr1 => "SIGUSR1",
+            UnixSignal::Usr2 => "SIGUSR2",
+        }
+    }
+}
+
+/// Binding id, from the action map, each signal triggers. Unmapped signals
+/// are ignored.
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct SignalSettings {
+    #[serde(default)]
+    pub usr1: Option<String>,
+
+    #[serde(default = "default_usr2")]
+    pub usr2: Option<String>,
+}
+
+fn default_usr2() -> Option<String> {
+    Some("transcribe".to_string())
+}
+
+impl Default for SignalSettings {
+    fn default() -> Self {
+        Self {
+            usr1: None,
+            usr2: default_usr2(),
+        }
+    }
+}
+
+impl SignalSettings {
+    /// The binding id `signal` triggers, if any
+    pub fn binding_for(&self, signal: UnixSignal) -> Option<&str> {
+        match signal {
+            UnixSignal::Usr1 => self.usr1.as_deref(),
+            UnixSignal::Usr2 => self.usr2.as_deref(),
+        }
+    }
+
+    pub fn set_binding(&mut self, signal: UnixSignal, binding_id: Option<String>) {
+        match signal {
+            UnixSignal::Usr1 => self.usr1 = binding_id,
+            UnixSignal::Usr2 => self.usr2 = binding_id,
+        }
+    }
+}
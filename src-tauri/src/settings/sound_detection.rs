@@ -2,22 +2,11 @@
 //!
 //! Settings for the environmental sound detection feature.
 
+use crate::audio_toolkit::SoundDetector;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
-/// Categories of environmental sounds that can be detected
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
-#[serde(rename_all = "snake_case")]
-pub enum SoundCategory {
-    Doorbell,
-    Alarm,
-    PhoneRing,
-    DogBark,
-    BabyCry,
-    Knocking,
-    Siren,
-    Applause,
-}
+pub use crate::audio_toolkit::sound_detector::SoundCategory;
 
 /// Settings for the Environmental Sound Detection feature
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
@@ -74,3 +63,10 @@ impl Default for SoundDetectionSettings {
         }
     }
 }
+
+impl SoundDetectionSettings {
+    /// Configure a detector to match these settings
+    pub fn apply_to(&self, detector: &mut SoundDetector) {
+        detector.configure(self.enabled, self.threshold, self.categories.clone());
+    }
+}
@@ -13,6 +13,78 @@ pub enum WarningSeverity {
     High,
 }
 
+/// What happens when a warning of a given severity is detected
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+pub struct WarningRoute {
+    /// Show a system notification
+    #[serde(default)]
+    pub notify: bool,
+    /// Play an alert sound
+    #[serde(default)]
+    pub play_sound: bool,
+    /// POST the warning to the configured webhook
+    #[serde(default)]
+    pub webhook: bool,
+}
+
+/// Per-severity routing table for warnings. Warnings are always shown in the
+/// suggestions overlay; routes add system notifications, sound and webhooks.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+pub struct WarningRouting {
+    #[serde(default)]
+    pub low: WarningRoute,
+    #[serde(default = "default_medium_warning_route")]
+    pub medium: WarningRoute,
+    #[serde(default = "default_high_warning_route")]
+    pub high: WarningRoute,
+}
+
+fn default_medium_warning_route() -> WarningRoute {
+    WarningRoute {
+        notify: true,
+        play_sound: false,
+        webhook: false,
+    }
+}
+
+fn default_high_warning_route() -> WarningRoute {
+    WarningRoute {
+        notify: true,
+        play_sound: true,
+        webhook: true,
+    }
+}
+
+impl Default for WarningRouting {
+    fn default() -> Self {
+        Self {
+            low: WarningRoute::default(),
+            medium: default_medium_warning_route(),
+            high: default_high_warning_route(),
+        }
+    }
+}
+
+impl WarningRouting {
+    /// Route for a severity
+    pub fn route(&self, severity: WarningSeverity) -> WarningRoute {
+        match severity {
+            WarningSeverity::Low => self.low,
+            WarningSeverity::Medium => self.medium,
+            WarningSeverity::High => self.high,
+        }
+    }
+
+    /// Mutable route for a severity
+    pub fn route_mut(&mut self, severity: WarningSeverity) -> &mut WarningRoute {
+        match severity {
+            WarningSeverity::Low => &mut self.low,
+            WarningSeverity::Medium => &mut self.medium,
+            WarningSeverity::High => &mut self.high,
+        }
+    }
+}
+
 /// A quick response template that can be triggered by keywords
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
 pub struct QuickResponse {
@@ -22,6 +94,10 @@ pub struct QuickResponse {
     pub name: String,
     /// Keywords that trigger this response (comma-separated phrases)
     pub trigger_phrases: Vec<String>,
+    /// Single words that trigger this response when they appear as whole
+    /// words in the recent transcript (weaker signal than a phrase)
+    #[serde(default)]
+    pub trigger_keywords: Vec<String>,
     /// Category for grouping (e.g., "pricing", "objection", "closing")
     pub category: String,
     /// The response template to suggest
@@ -71,6 +147,40 @@ pub struct SuggestionsSettings {
     /// Suggestion display duration in seconds (0 = until dismissed)
     #[serde(default = "default_display_duration")]
     pub display_duration_seconds: u32,
+
+    /// Word-overlap similarity (0.0 - 1.0) above which a suggestion counts as
+    /// a duplicate of a recently shown one
+    #[serde(default = "default_dedup_similarity_threshold")]
+    pub dedup_similarity_threshold: f32,
+
+    /// How long shown suggestions are remembered for deduplication, in seconds
+    #[serde(default = "default_dedup_window_seconds")]
+    pub dedup_window_seconds: u32,
+
+    /// Minimum seconds between suggestions of the same category (0 = no cooldown)
+    #[serde(default = "default_category_cooldown_seconds")]
+    pub category_cooldown_seconds: u32,
+
+    /// Maximum suggestions shown per minute (0 = unlimited)
+    #[serde(default = "default_max_suggestions_per_minute")]
+    pub max_suggestions_per_minute: u32,
+
+    /// Adjust suggestion ranking based on which suggestions were accepted or dismissed
+    #[serde(default = "default_true")]
+    pub feedback_learning_enabled: bool,
+
+    /// Number of recent transcript segments (including the current one)
+    /// that quick response triggers are matched against
+    #[serde(default = "default_quick_response_context_segments")]
+    pub quick_response_context_segments: usize,
+
+    /// How warnings of each severity are routed beyond the overlay
+    #[serde(default)]
+    pub warning_routing: WarningRouting,
+
+    /// Webhook URL that receives warnings whose route has `webhook` set
+    #[serde(default)]
+    pub warning_webhook_url: Option<String>,
 }
 
 fn default_max_suggestions() -> usize {
@@ -85,6 +195,26 @@ fn default_display_duration() -> u32 {
     0 // Until dismissed
 }
 
+fn default_dedup_similarity_threshold() -> f32 {
+    0.8
+}
+
+fn default_dedup_window_seconds() -> u32 {
+    120
+}
+
+fn default_category_cooldown_seconds() -> u32 {
+    30
+}
+
+fn default_max_suggestions_per_minute() -> u32 {
+    6
+}
+
+fn default_quick_response_context_segments() -> usize {
+    2
+}
+
 fn default_quick_responses() -> Vec<QuickResponse> {
     vec![
         // Pricing objections
@@ -98,6 +228,7 @@ fn default_quick_responses() -> Vec<QuickResponse> {
                 "can't afford".to_string(),
                 "price is high".to_string(),
             ],
+            trigger_keywords: vec![],
             category: "pricing".to_string(),
             response_template: "I understand budget is a concern. Let me share how our solution delivers ROI that typically exceeds the investment within [X] months. Would it help if I walked you through a cost-benefit analysis?".to_string(),
             enabled: true,
@@ -112,6 +243,7 @@ fn default_quick_responses() -> Vec<QuickResponse> {
                 "lower price elsewhere".to_string(),
                 "better deal".to_string(),
             ],
+            trigger_keywords: vec![],
             category: "pricing".to_string(),
             response_template: "That's a fair point. While price is important, let me highlight what's included in our offering that provides additional value: [key differentiators]. Would you like to compare feature-by-feature?".to_string(),
             enabled: true,
@@ -128,6 +260,7 @@ fn default_quick_responses() -> Vec<QuickResponse> {
                 "need to wait".to_string(),
                 "not ready yet".to_string(),
             ],
+            trigger_keywords: vec![],
             category: "timing".to_string(),
             response_template: "I appreciate your honesty. What would need to change for this to become a priority? Understanding your timeline helps me provide relevant information when you're ready.".to_string(),
             enabled: true,
@@ -143,6 +276,7 @@ fn default_quick_responses() -> Vec<QuickResponse> {
                 "have to consider".to_string(),
                 "sleep on it".to_string(),
             ],
+            trigger_keywords: vec![],
             category: "trust".to_string(),
             response_template: "Absolutely, this is an important decision. What specific aspects would you like to think through? I'm happy to provide additional information that might help with your evaluation.".to_string(),
             enabled: true,
@@ -159,6 +293,7 @@ fn default_quick_responses() -> Vec<QuickResponse> {
                 "discuss internally".to_string(),
                 "get buy-in".to_string(),
             ],
+            trigger_keywords: vec![],
             category: "authority".to_string(),
             response_template: "That makes sense - getting team alignment is crucial. Would it help if I prepared a summary document you could share? I could also join a follow-up call with the key stakeholders.".to_string(),
             enabled: true,
@@ -174,6 +309,7 @@ fn default_quick_responses() -> Vec<QuickResponse> {
                 "area to improve".to_string(),
                 "development area".to_string(),
             ],
+            trigger_keywords: vec![],
             category: "interview".to_string(),
             response_template: "A genuine area I've been working on is [specific skill]. I've addressed this by [concrete steps taken], and I've seen improvement in [measurable outcome].".to_string(),
             enabled: true,
@@ -189,6 +325,7 @@ fn default_quick_responses() -> Vec<QuickResponse> {
                 "lowest you can go".to_string(),
                 "any flexibility".to_string(),
             ],
+            trigger_keywords: vec![],
             category: "negotiation".to_string(),
             response_template: "I appreciate you asking directly. Before discussing pricing flexibility, I want to make sure I understand all your requirements. Are there any additional needs we haven't covered that could affect the scope?".to_string(),
             enabled: true,
@@ -208,6 +345,14 @@ impl Default for SuggestionsSettings {
             min_confidence: default_min_confidence(),
             auto_dismiss_on_copy: true,
             display_duration_seconds: default_display_duration(),
+            dedup_similarity_threshold: default_dedup_similarity_threshold(),
+            dedup_window_seconds: default_dedup_window_seconds(),
+            category_cooldown_seconds: default_category_cooldown_seconds(),
+            max_suggestions_per_minute: default_max_suggestions_per_minute(),
+            feedback_learning_enabled: true,
+            quick_response_context_segments: default_quick_response_context_segments(),
+            warning_routing: WarningRouting::default(),
+            warning_webhook_url: None,
         }
     }
 }
@@ -267,6 +412,9 @@ mod tests {
         assert_eq!(settings.min_confidence, 0.5);
         assert!(settings.auto_dismiss_on_copy);
         assert_eq!(settings.display_duration_seconds, 0);
+        assert_eq!(settings.dedup_window_seconds, 120);
+        assert_eq!(settings.category_cooldown_seconds, 30);
+        assert_eq!(settings.max_suggestions_per_minute, 6);
         assert!(!settings.quick_responses.is_empty());
     }
 
@@ -351,6 +499,7 @@ mod tests {
             id: "test".to_string(),
             name: "Test".to_string(),
             trigger_phrases: vec!["trigger".to_string()],
+            trigger_keywords: vec![],
             category: "test".to_string(),
             response_template: "Response".to_string(),
             enabled: true,
@@ -362,6 +511,18 @@ mod tests {
         assert_eq!(qr.trigger_phrases, cloned.trigger_phrases);
     }
 
+    #[test]
+    fn test_default_warning_routing() {
+        let routing = WarningRouting::default();
+
+        assert_eq!(routing.route(WarningSeverity::Low), WarningRoute::default());
+        assert!(routing.route(WarningSeverity::Medium).notify);
+        assert!(!routing.route(WarningSeverity::Medium).webhook);
+
+        let high = routing.route(WarningSeverity::High);
+        assert!(high.notify && high.play_sound && high.webhook);
+    }
+
     #[test]
     fn test_warning_severity_default() {
         let severity = WarningSeverity::default();
@@ -0,0 +1,45 @@
+//! Sync Settings
+//!
+//! Settings for syncing preferences, vocabulary, prompts and quick responses
+//! between devices through a user-provided folder.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// How to resolve an item changed both locally and remotely since the last sync
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncConflictPolicy {
+    PreferLocal,
+    PreferRemote,
+}
+
+/// Settings for folder-based sync
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct SyncSettings {
+    /// Whether sync is enabled
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Folder holding the sync bundle, typically inside Dropbox, iCloud Drive or Syncthing
+    #[serde(default)]
+    pub folder: Option<String>,
+
+    /// Conflict resolution policy
+    #[serde(default = "default_conflict_policy")]
+    pub conflict_policy: SyncConflictPolicy,
+}
+
+fn default_conflict_policy() -> SyncConflictPolicy {
+    SyncConflictPolicy::PreferLocal
+}
+
+impl Default for SyncSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            folder: None,
+            conflict_policy: default_conflict_policy(),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Transcribe Profile Settings
+//!
+//! Extra transcribe shortcuts, each with a profile that overrides how its
+//! dictations are handled: one can paste raw text while another runs an LLM
+//! cleanup prompt. A profile's shortcut is stored with the other bindings
+//! under an id made from the profile's.
+
+use super::{AppSettings, PasteMethod};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Binding ids of profile shortcuts start with this, followed by the
+/// profile's id
+pub const PROFILE_BINDING_PREFIX: &str = "transcribe_profile:";
+
+/// Whether a binding is a profile's transcribe shortcut
+pub fn is_profile_binding(binding_id: &str) -> bool {
+    binding_id.starts_with(PROFILE_BINDING_PREFIX)
+}
+
+/// The binding id of a profile's shortcut
+pub fn profile_binding_id(profile_id: &str) -> String {
+    format!("{}{}", PROFILE_BINDING_PREFIX, profile_id)
+}
+
+/// How dictations from one transcribe shortcut are handled
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct TranscribeProfile {
+    pub id: String,
+    pub name: String,
+
+    /// Language to transcribe in instead of the selected one
+    #[serde(default)]
+    pub language: Option<String>,
+
+    /// Whether dictations are post-processed. When off, neither the
+    /// post-process prompt nor the formatting mode is applied.
+    #[serde(default)]
+    pub post_process: bool,
+
+    /// Prompt to post-process with instead of the selected prompt and
+    /// formatting mode
+    #[serde(default)]
+    pub post_process_prompt_id: Option<String>,
+
+    /// Paste method to use instead of the configured one
+    #[serde(default)]
+    pub paste_method: Option<PasteMethod>,
+}
+
+impl TranscribeProfile {
+    /// Override the settings a dictation is handled with
+    pub fn apply(&self, settings: &mut AppSettings) {
+        if let Some(language) = self.language.as_ref().filter(|l| !l.is_empty()) {
+            settings.selected_language = language.clone();
+        }
+        settings.post_process_enabled = self.post_process;
+        if !self.post_process || self.post_process_prompt_id.is_some() {
+            settings.formatting.selected_mode = None;
+        }
+        if let Some(prompt_id) = &self.post_process_prompt_id {
+            settings.post_process_selected_prompt_id = Some(prompt_id.clone());
+        }
+        if let Some(paste_method) = self.paste_method {
+            settings.paste_method = paste_method;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    fn profile(post_process: bool, prompt: Option<&str>) -> TranscribeProfile {
+        TranscribeProfile {
+            id: "cleanup".to_string(),
+            name: "Cleanup".to_string(),
+            language: Some("de".to_string()),
+            post_process,
+            post_process_prompt_id: prompt.map(String::from),
+            paste_method: Some(PasteMethod::Direct),
+        }
+    }
+
+    #[test]
+    fn test_profile_overrides_language_prompt_and_paste_method() {
+        let mut settings = get_default_settings();
+        settings.formatting.selected_mode = Some("email".to_string());
+        profile(true, Some("cleanup_prompt")).apply(&mut settings);
+        assert_eq!(settings.selected_language, "de");
+        assert!(settings.post_process_enabled);
+        assert_eq!(
+            settings.post_process_selected_prompt_id.as_deref(),
+            Some("cleanup_prompt")
+        );
+        assert_eq!(settings.formatting.selected_mode, None);
+        assert_eq!(settings.paste_method, PasteMethod::Direct);
+    }
+
+    #[test]
+    fn test_raw_profile_turns_off_post_processing_and_formatting() {
+        let mut settings = get_default_settings();
+        settings.post_process_enabled = true;
+        settings.formatting.selected_mode = Some("email".to_string());
+        profile(false, None).apply(&mut settings);
+        assert!(!settings.post_process_enabled);
+        assert_eq!(settings.formatting.selected_mode, None);
+    }
+}
@@ -0,0 +1,228 @@
+//! Transcription Backend Settings
+//!
+//! Which speech-to-text backend transcribes dictations. The local model is
+//! the default; a remote service is only used when it's picked, for every
+//! shortcut or for a single one, and it needs an API key.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+
+/// Backend id of the local model
+pub const LOCAL_BACKEND: &str = "local";
+
+/// API a remote speech-to-text service speaks
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum RemoteSttApi {
+    /// Multipart upload to `/audio/transcriptions`, as OpenAI, Groq and most
+    /// self-hosted Whisper servers accept
+    OpenAi,
+    /// Raw audio posted to `/listen`
+    Deepgram,
+}
+
+/// A remote speech-to-text service
+#[derive(Serialize, Debug, Clone, PartialEq, Type)]
+pub struct RemoteSttProvider {
+    pub id: String,
+    pub label: String,
+    pub api: RemoteSttApi,
+    pub base_url: String,
+    pub default_model: String,
+    /// Whether the user can point it at another server
+    pub allow_base_url_edit: bool,
+}
+
+/// The remote services dictations can be sent to
+pub fn remote_stt_providers() -> Vec<RemoteSttProvider> {
+    let provider =
+        |id: &str, label: &str, api, base_url: &str, model: &str, editable| RemoteSttProvider {
+            id: id.to_string(),
+            label: label.to_string(),
+            api,
+            base_url: base_url.to_string(),
+            default_model: model.to_string(),
+            allow_base_url_edit: editable,
+        };
+    vec![
+        provider(
+            "openai",
+            "OpenAI",
+            RemoteSttApi::OpenAi,
+            "https://api.openai.com/v1",
+            "whisper-1",
+            false,
+        ),
+        provider(
+            "groq",
+            "Groq",
+            RemoteSttApi::OpenAi,
+            "https://api.groq.com/openai/v1",
+            "whisper-large-v3-turbo",
+            false,
+        ),
+        provider(
+            "deepgram",
+            "Deepgram",
+            RemoteSttApi::Deepgram,
+            "https://api.deepgram.com/v1",
+            "nova-2",
+            false,
+        ),
+        provider(
+            "custom",
+            "Custom (OpenAI-compatible)",
+            RemoteSttApi::OpenAi,
+            "http://localhost:8000/v1",
+            "whisper-1",
+            true,
+        ),
+    ]
+}
+
+/// A remote service with the key, model and URL the user set for it
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteSttConfig {
+    pub provider: RemoteSttProvider,
+    pub api_key: String,
+    pub model: String,
+    pub base_url: String,
+}
+
+/// Settings for the speech-to-text backend
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct TranscriptionBackendSettings {
+    /// Backend of shortcuts without their own: `local` or a provider id
+    #[serde(default = "default_backend")]
+    pub default_backend: String,
+    /// Backends picked for single shortcuts, by binding id
+    #[serde(default)]
+    pub binding_backends: HashMap<String, String>,
+    /// API keys by provider id
+    #[serde(default)]
+    pub api_keys: HashMap<String, String>,
+    /// Models by provider id; the provider's default when unset
+    #[serde(default)]
+    pub models: HashMap<String, String>,
+    /// Base URLs of providers that can be pointed at another server
+    #[serde(default)]
+    pub base_urls: HashMap<String, String>,
+    /// Transcribe with the local model when the remote service can't be
+    /// reached, e.g. while offline
+    #[serde(default = "default_fallback_to_local")]
+    pub fallback_to_local: bool,
+    /// Time a remote service gets to answer
+    #[serde(default = "default_timeout_seconds")]
+    pub timeout_seconds: u32,
+}
+
+fn default_backend() -> String {
+    LOCAL_BACKEND.to_string()
+}
+
+fn default_fallback_to_local() -> bool {
+    true
+}
+
+fn default_timeout_seconds() -> u32 {
+    30
+}
+
+impl Default for TranscriptionBackendSettings {
+    fn default() -> Self {
+        Self {
+            default_backend: default_backend(),
+            binding_backends: HashMap::new(),
+            api_keys: HashMap::new(),
+            models: HashMap::new(),
+            base_urls: HashMap::new(),
+            fallback_to_local: default_fallback_to_local(),
+            timeout_seconds: default_timeout_seconds(),
+        }
+    }
+}
+
+impl TranscriptionBackendSettings {
+    /// Backend id a shortcut binding transcribes with
+    pub fn backend_for(&self, binding_id: &str) -> &str {
+        self.binding_backends
+            .get(binding_id)
+            .unwrap_or(&self.default_backend)
+    }
+
+    /// The remote service a shortcut binding transcribes with, or `None` for
+    /// the local model. Unknown provider ids fall back to the local model.
+    pub fn remote_for(&self, binding_id: &str) -> Option<RemoteSttConfig> {
+        let backend = self.backend_for(binding_id);
+        let provider = remote_stt_providers()
+            .into_iter()
+            .find(|provider| provider.id == backend)?;
+        let setting = |values: &HashMap<String, String>| {
+            values
+                .get(&provider.id)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let base_url = setting(&self.base_urls)
+            .filter(|_| provider.allow_base_url_edit)
+            .unwrap_or_else(|| provider.base_url.clone());
+        Some(RemoteSttConfig {
+            api_key: setting(&self.api_keys).unwrap_or_default(),
+            model: setting(&self.models).unwrap_or_else(|| provider.default_model.clone()),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            provider,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bindings_use_their_own_backend_or_the_default() {
+        let mut settings = TranscriptionBackendSettings::default();
+        assert_eq!(settings.backend_for("transcribe"), LOCAL_BACKEND);
+        assert!(settings.remote_for("transcribe").is_none());
+
+        settings.default_backend = "groq".to_string();
+        settings
+            .binding_backends
+            .insert("ask_ai".to_string(), LOCAL_BACKEND.to_string());
+        settings
+            .models
+            .insert("groq".to_string(), " whisper-large-v3 ".to_string());
+        let groq = settings.remote_for("transcribe").unwrap();
+        assert_eq!(groq.provider.id, "groq");
+        assert_eq!(groq.model, "whisper-large-v3");
+        assert!(settings.remote_for("ask_ai").is_none());
+
+        settings.default_backend = "removed_provider".to_string();
+        assert!(settings.remote_for("transcribe").is_none());
+    }
+
+    #[test]
+    fn test_only_editable_providers_take_a_custom_base_url() {
+        let mut settings = TranscriptionBackendSettings {
+            default_backend: "custom".to_string(),
+            ..Default::default()
+        };
+        settings
+            .base_urls
+            .insert("custom".to_string(), "http://stt.lan:9000/v1/".to_string());
+        settings
+            .base_urls
+            .insert("openai".to_string(), "http://example.com".to_string());
+        assert_eq!(
+            settings.remote_for("transcribe").unwrap().base_url,
+            "http://stt.lan:9000/v1"
+        );
+
+        settings.default_backend = "openai".to_string();
+        assert_eq!(
+            settings.remote_for("transcribe").unwrap().base_url,
+            "https://api.openai.com/v1"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! Translation Settings
+//!
+//! The language dictations are translated into before they're pasted, for
+//! every shortcut or per shortcut, and the LLM that translates them.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+
+/// Provider id that translates with the Ollama server and model set up for
+/// Active Listening instead of a post-process provider
+pub const OLLAMA_TRANSLATION_PROVIDER: &str = "ollama";
+
+/// Settings for translating dictations
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct TranslationSettings {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Language dictations are translated into, e.g. "German"; none when
+    /// empty
+    #[serde(default)]
+    pub target_language: String,
+
+    /// Languages picked for single shortcuts, by binding id
+    #[serde(default)]
+    pub binding_languages: HashMap<String, String>,
+
+    /// Post-process provider or `ollama` to translate with; the selected
+    /// post-process provider when unset
+    #[serde(default)]
+    pub provider_id: Option<String>,
+
+    /// Model to use instead of the provider's configured model
+    #[serde(default)]
+    pub model: Option<String>,
+}
+
+impl Default for TranslationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target_language: String::new(),
+            binding_languages: HashMap::new(),
+            provider_id: None,
+            model: None,
+        }
+    }
+}
+
+impl TranslationSettings {
+    /// Language a shortcut binding's dictations are translated into, or
+    /// `None` when they're pasted as spoken
+    pub fn language_for(&self, binding_id: &str) -> Option<&str> {
+        if !self.enabled {
+            return None;
+        }
+        let language = self
+            .binding_languages
+            .get(binding_id)
+            .unwrap_or(&self.target_language)
+            .trim();
+        (!language.is_empty()).then_some(language)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bindings_use_their_own_language_or_the_default() {
+        let mut settings = TranslationSettings {
+            target_language: "German".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(settings.language_for("transcribe"), None);
+
+        settings.enabled = true;
+        settings.binding_languages.insert(
+            "transcribe_with_post_process".to_string(),
+            " Portuguese ".to_string(),
+        );
+        assert_eq!(settings.language_for("transcribe"), Some("German"));
+        assert_eq!(
+            settings.language_for("transcribe_with_post_process"),
+            Some("Portuguese")
+        );
+
+        // Without a default only shortcuts with their own language translate
+        settings.target_language.clear();
+        assert_eq!(settings.language_for("transcribe"), None);
+        assert_eq!(
+            settings.language_for("transcribe_with_post_process"),
+            Some("Portuguese")
+        );
+    }
+}
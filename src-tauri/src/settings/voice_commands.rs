@@ -0,0 +1,57 @@
+//! Voice Command Settings
+//!
+//! The spoken commands, like "new paragraph" or "delete that", that are
+//! carried out in dictations before they're pasted.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+pub use crate::audio_toolkit::{VoiceCommand, VoiceCommandAction};
+
+/// Settings for voice commands in dictations
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct VoiceCommandSettings {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The grammar: each phrase and what it does
+    #[serde(default = "default_voice_commands")]
+    pub commands: Vec<VoiceCommand>,
+}
+
+pub fn default_voice_commands() -> Vec<VoiceCommand> {
+    let insert = |text: &str| VoiceCommandAction::Insert {
+        text: text.to_string(),
+    };
+    [
+        ("new line", VoiceCommandAction::NewLine),
+        ("new paragraph", VoiceCommandAction::NewParagraph),
+        ("comma", insert(",")),
+        ("period", insert(".")),
+        ("full stop", insert(".")),
+        ("question mark", insert("?")),
+        ("exclamation mark", insert("!")),
+        ("colon", insert(":")),
+        ("semicolon", insert(";")),
+        ("delete that", VoiceCommandAction::DeleteLastSentence),
+        ("undo last sentence", VoiceCommandAction::DeleteLastSentence),
+        ("delete last word", VoiceCommandAction::DeleteLastWord),
+        ("capitalize", VoiceCommandAction::CapitalizeNext),
+        ("all caps", VoiceCommandAction::UppercaseNext),
+    ]
+    .into_iter()
+    .map(|(phrase, action)| VoiceCommand {
+        phrase: phrase.to_string(),
+        action,
+    })
+    .collect()
+}
+
+impl Default for VoiceCommandSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            commands: default_voice_commands(),
+        }
+    }
+}
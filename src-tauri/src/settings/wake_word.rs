@@ -0,0 +1,77 @@
+//! Wake Word Settings
+//!
+//! Settings for starting a dictation hands-free by saying a keyword, heard
+//! on the always-on microphone stream.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// What saying the wake word starts
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum WakeWordAction {
+    #[default]
+    Transcribe,
+    AskAi,
+}
+
+impl WakeWordAction {
+    /// The binding whose action is run
+    pub fn binding_id(self) -> &'static str {
+        match self {
+            WakeWordAction::Transcribe => "transcribe",
+            WakeWordAction::AskAi => "ask_ai",
+        }
+    }
+}
+
+/// Settings for the wake word
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct WakeWordSettings {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// openWakeWord keyword model, with `melspectrogram.onnx` and
+    /// `embedding_model.onnx` in the same folder
+    #[serde(default)]
+    pub model_path: Option<String>,
+
+    /// How readily the wake word is recognized (0.0-1.0). Higher catches
+    /// more mumbled wake words but also more false ones.
+    #[serde(default = "default_sensitivity")]
+    pub sensitivity: f32,
+
+    #[serde(default)]
+    pub action: WakeWordAction,
+
+    /// Quiet after speaking that ends the dictation
+    #[serde(default = "default_silence_timeout_ms")]
+    pub silence_timeout_ms: u32,
+}
+
+fn default_sensitivity() -> f32 {
+    0.5
+}
+
+fn default_silence_timeout_ms() -> u32 {
+    1500
+}
+
+impl Default for WakeWordSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            model_path: None,
+            sensitivity: default_sensitivity(),
+            action: WakeWordAction::default(),
+            silence_timeout_ms: default_silence_timeout_ms(),
+        }
+    }
+}
+
+impl WakeWordSettings {
+    /// Keyword model score the wake word has to reach
+    pub fn threshold(&self) -> f32 {
+        (1.0 - self.sensitivity).clamp(0.05, 0.95)
+    }
+}
@@ -0,0 +1,106 @@
+//! Webhook Settings
+//!
+//! Where finished dictations, Active Listening sessions and meeting summaries
+//! are POSTed, and which of them are sent.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Something that happened which a webhook can be told about
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// A dictation was transcribed
+    TranscriptionCompleted,
+    /// An Active Listening session ended
+    SessionEnded,
+    /// A meeting summary was generated
+    SummaryGenerated,
+    /// Sent by the test button, even while webhooks are off
+    Test,
+}
+
+/// Settings for webhook deliveries
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct WebhookSettings {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// http(s) URL payloads are POSTed to
+    #[serde(default)]
+    pub url: Option<String>,
+
+    /// Sent as the `Authorization` header, e.g. `Bearer <token>`
+    #[serde(default)]
+    pub auth_header: Option<String>,
+
+    /// Events that are delivered
+    #[serde(default = "default_events")]
+    pub events: Vec<WebhookEvent>,
+
+    /// Retries after a failed delivery, with a growing delay between them
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+}
+
+fn default_events() -> Vec<WebhookEvent> {
+    vec![
+        WebhookEvent::TranscriptionCompleted,
+        WebhookEvent::SessionEnded,
+        WebhookEvent::SummaryGenerated,
+    ]
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+impl Default for WebhookSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: None,
+            auth_header: None,
+            events: default_events(),
+            max_retries: default_max_retries(),
+        }
+    }
+}
+
+impl WebhookSettings {
+    /// The URL to deliver to, if one is set
+    pub fn target_url(&self) -> Option<&str> {
+        self.url
+            .as_deref()
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+    }
+
+    /// Whether an event should be delivered
+    pub fn sends(&self, event: WebhookEvent) -> bool {
+        self.enabled && self.target_url().is_some() && self.events.contains(&event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sends_only_enabled_events_to_a_set_url() {
+        let mut settings = WebhookSettings {
+            enabled: true,
+            url: Some("  ".to_string()),
+            events: vec![WebhookEvent::SessionEnded],
+            ..Default::default()
+        };
+        assert!(!settings.sends(WebhookEvent::SessionEnded));
+
+        settings.url = Some("https://hooks.example.com/dictum".to_string());
+        assert!(settings.sends(WebhookEvent::SessionEnded));
+        assert!(!settings.sends(WebhookEvent::TranscriptionCompleted));
+
+        settings.enabled = false;
+        assert!(!settings.sends(WebhookEvent::SessionEnded));
+    }
+}
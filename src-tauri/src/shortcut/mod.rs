@@ -1,21 +1,27 @@
+mod prearm;
+
 use log::{error, warn};
 use serde::Serialize;
 use specta::Type;
 use std::sync::Arc;
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::{AppHandle, Manager};
 use tauri_plugin_autostart::ManagerExt;
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 
-use crate::actions::ACTION_MAP;
+use crate::actions::action_for;
+use crate::events::{SettingValue, SettingsChanged};
 use crate::managers::audio::AudioRecordingManager;
+use crate::managers::plugins::{is_plugin_binding, PluginManager};
 use crate::settings::ShortcutBinding;
 use crate::settings::{
-    self, get_settings, ClipboardHandling, LLMPrompt, OverlayPosition, PasteMethod, SoundTheme,
-    APPLE_INTELLIGENCE_DEFAULT_MODEL_ID, APPLE_INTELLIGENCE_PROVIDER_ID,
+    self, get_settings, is_profile_binding, profile_binding_id, ClipboardHandling, LLMPrompt,
+    NoiseSuppressionSettings, OverlayPosition, PasteMethod, PostProcessProvider, PromptRoute,
+    SoundTheme, APPLE_INTELLIGENCE_DEFAULT_MODEL_ID, APPLE_INTELLIGENCE_PROVIDER_ID,
 };
 use crate::tray;
 use crate::utils;
 use crate::ManagedToggleState;
+use tauri_specta::Event;
 
 pub fn init_shortcuts(app: &AppHandle) {
     let default_bindings = settings::get_default_settings().bindings;
@@ -36,6 +42,21 @@ pub fn init_shortcuts(app: &AppHandle) {
             error!("Failed to register shortcut {} during init: {}", id, e);
         }
     }
+    // Transcribe shortcuts of profiles only exist in the user's settings
+    for binding in user_settings.bindings.values() {
+        if !is_profile_binding(&binding.id) {
+            continue;
+        }
+        if let Err(e) = register_shortcut(app, binding.clone()) {
+            error!(
+                "Failed to register shortcut {} during init: {}",
+                binding.id, e
+            );
+        }
+    }
+    if user_settings.general.prearm_microphone {
+        prearm::start(app);
+    }
 }
 
 #[derive(Serialize, Type)]
@@ -233,13 +254,22 @@ pub fn change_debug_mode_setting(app: AppHandle, enabled: bool) -> Result<(), St
     settings::write_settings(&app, settings);
 
     // Emit event to notify frontend of debug mode change
-    let _ = app.emit(
-        "settings-changed",
-        serde_json::json!({
-            "setting": "debug_mode",
-            "value": enabled
-        }),
-    );
+    let _ = SettingsChanged::new("debug_mode", SettingValue::Bool(enabled)).emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_guest_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.guest_mode = enabled;
+    settings::write_settings(&app, settings);
+
+    // Refresh the tray so the guest mode indicator appears or disappears
+    tray::update_tray_menu(&app, &tray::TrayIconState::Idle, None);
+
+    let _ = SettingsChanged::new("guest_mode", SettingValue::Bool(enabled)).emit(&app);
 
     Ok(())
 }
@@ -252,13 +282,7 @@ pub fn change_start_hidden_setting(app: AppHandle, enabled: bool) -> Result<(),
     settings::write_settings(&app, settings);
 
     // Notify frontend
-    let _ = app.emit(
-        "settings-changed",
-        serde_json::json!({
-            "setting": "start_hidden",
-            "value": enabled
-        }),
-    );
+    let _ = SettingsChanged::new("start_hidden", SettingValue::Bool(enabled)).emit(&app);
 
     Ok(())
 }
@@ -279,13 +303,7 @@ pub fn change_autostart_setting(app: AppHandle, enabled: bool) -> Result<(), Str
     }
 
     // Notify frontend
-    let _ = app.emit(
-        "settings-changed",
-        serde_json::json!({
-            "setting": "autostart_enabled",
-            "value": enabled
-        }),
-    );
+    let _ = SettingsChanged::new("autostart_enabled", SettingValue::Bool(enabled)).emit(&app);
 
     Ok(())
 }
@@ -297,13 +315,24 @@ pub fn change_update_checks_setting(app: AppHandle, enabled: bool) -> Result<(),
     settings.general.update_checks_enabled = enabled;
     settings::write_settings(&app, settings);
 
-    let _ = app.emit(
-        "settings-changed",
-        serde_json::json!({
-            "setting": "update_checks_enabled",
-            "value": enabled
-        }),
-    );
+    let _ = SettingsChanged::new("update_checks_enabled", SettingValue::Bool(enabled)).emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_update_channel_setting(
+    app: AppHandle,
+    channel: settings::general::UpdateChannel,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.general.update_channel = channel;
+    // A version skipped on one channel says nothing about the other
+    settings.general.skipped_update_version = None;
+    settings::write_settings(&app, settings);
+
+    let _ = SettingsChanged::new("update_channel", SettingValue::UpdateChannel(channel)).emit(&app);
 
     Ok(())
 }
@@ -476,6 +505,8 @@ pub fn add_post_process_prompt(
         id: id.clone(),
         name,
         prompt,
+        provider_id: None,
+        model: None,
     };
 
     settings.post_process_prompts.push(new_prompt.clone());
@@ -532,29 +563,27 @@ pub fn delete_post_process_prompt(app: AppHandle, id: String) -> Result<(), Stri
             settings.post_process_prompts.first().map(|p| p.id.clone());
     }
 
+    // Routes to the deleted prompt have nowhere to go
+    settings.post_process_routes.retain(|r| r.prompt_id != id);
+
     settings::write_settings(&app, settings);
     Ok(())
 }
 
-#[tauri::command]
-#[specta::specta]
-pub async fn fetch_post_process_models(
-    app: AppHandle,
-    provider_id: String,
-) -> Result<Vec<String>, String> {
-    let settings = settings::get_settings(&app);
-
-    // Find the provider
+/// The provider with the API key it's called with, if it can be used on
+/// this machine
+fn usable_post_process_provider<'a>(
+    settings: &'a settings::AppSettings,
+    provider_id: &str,
+) -> Result<(&'a PostProcessProvider, String), String> {
     let provider = settings
-        .post_process_providers
-        .iter()
-        .find(|p| p.id == provider_id)
+        .post_process_provider(provider_id)
         .ok_or_else(|| format!("Provider '{}' not found", provider_id))?;
 
     if provider.id == APPLE_INTELLIGENCE_PROVIDER_ID {
         #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
         {
-            return Ok(vec![APPLE_INTELLIGENCE_DEFAULT_MODEL_ID.to_string()]);
+            return Ok((provider, String::new()));
         }
 
         #[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
@@ -563,24 +592,76 @@ pub async fn fetch_post_process_models(
         }
     }
 
-    // Get API key
     let api_key = settings
         .post_process_api_keys
-        .get(&provider_id)
+        .get(provider_id)
         .cloned()
         .unwrap_or_default();
 
-    // Skip fetching if no API key for providers that typically need one
+    // Providers other than custom ones typically need an API key
     if api_key.trim().is_empty() && provider.id != "custom" {
         return Err(format!(
-            "API key is required for {}. Please add an API key to list available models.",
+            "API key is required for {}. Please add an API key first.",
             provider.label
         ));
     }
 
+    Ok((provider, api_key))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn fetch_post_process_models(
+    app: AppHandle,
+    provider_id: String,
+) -> Result<Vec<String>, String> {
+    let settings = settings::get_settings(&app);
+    let (provider, api_key) = usable_post_process_provider(&settings, &provider_id)?;
+
+    if provider.id == APPLE_INTELLIGENCE_PROVIDER_ID {
+        return Ok(vec![APPLE_INTELLIGENCE_DEFAULT_MODEL_ID.to_string()]);
+    }
+
     crate::llm_client::fetch_models(provider, api_key).await
 }
 
+/// Set or clear the provider and model a prompt is post-processed with,
+/// instead of the selected provider and its model
+#[tauri::command]
+#[specta::specta]
+pub fn set_post_process_prompt_model(
+    app: AppHandle,
+    id: String,
+    provider_id: Option<String>,
+    model: Option<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+
+    let provider_id = provider_id.filter(|provider_id| !provider_id.is_empty());
+    let model = model
+        .map(|model| model.trim().to_string())
+        .filter(|model| !model.is_empty());
+    match &provider_id {
+        Some(provider_id) => {
+            usable_post_process_provider(&settings, provider_id)?;
+        }
+        None if model.is_some() => {
+            return Err("A model override needs a provider".to_string());
+        }
+        None => {}
+    }
+
+    let prompt = settings
+        .post_process_prompts
+        .iter_mut()
+        .find(|p| p.id == id)
+        .ok_or_else(|| format!("Prompt with id '{}' not found", id))?;
+    prompt.provider_id = provider_id;
+    prompt.model = model;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn set_post_process_selected_prompt(app: AppHandle, id: String) -> Result<(), String> {
@@ -596,6 +677,44 @@ pub fn set_post_process_selected_prompt(app: AppHandle, id: String) -> Result<()
     Ok(())
 }
 
+/// Replace the phrases that route dictations to post-process prompts
+#[tauri::command]
+#[specta::specta]
+pub fn set_post_process_routes(app: AppHandle, routes: Vec<PromptRoute>) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+
+    let mut phrases = std::collections::HashSet::new();
+    for route in &routes {
+        let phrase = route.phrase.trim().to_lowercase();
+        if phrase.is_empty() {
+            return Err("Route phrases can't be empty".to_string());
+        }
+        if !phrases.insert(phrase) {
+            return Err(format!(
+                "Route phrase '{}' is used twice",
+                route.phrase.trim()
+            ));
+        }
+        if !settings
+            .post_process_prompts
+            .iter()
+            .any(|p| p.id == route.prompt_id)
+        {
+            return Err(format!("Prompt with id '{}' not found", route.prompt_id));
+        }
+    }
+
+    settings.post_process_routes = routes
+        .into_iter()
+        .map(|route| PromptRoute {
+            phrase: route.phrase.trim().to_string(),
+            prompt_id: route.prompt_id,
+        })
+        .collect();
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_mute_while_recording_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -641,13 +760,225 @@ pub fn change_private_overlay_setting(app: AppHandle, enabled: bool) -> Result<(
         crate::overlay::set_screen_capture_excluded(&overlay_window, enabled);
     }
 
-    let _ = app.emit(
-        "settings-changed",
-        serde_json::json!({
-            "setting": "private_overlay",
-            "value": enabled
-        }),
-    );
+    let _ = SettingsChanged::new("private_overlay", SettingValue::Bool(enabled)).emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_tray_level_indicator_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.general.tray_level_indicator = enabled;
+    settings::write_settings(&app, settings);
+
+    let _ = SettingsChanged::new("tray_level_indicator", SettingValue::Bool(enabled)).emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_vad_threshold_setting(app: AppHandle, threshold: f32) -> Result<(), String> {
+    if !(0.05..=0.95).contains(&threshold) {
+        return Err("VAD threshold must be between 0.05 and 0.95".to_string());
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.general.vad_threshold = threshold;
+    settings::write_settings(&app, settings);
+
+    // The VAD is built with the recorder, so rebuild it
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    rm.reload_vad()
+        .map_err(|e| format!("Failed to apply VAD threshold: {}", e))?;
+
+    let _ =
+        SettingsChanged::new("vad_threshold", SettingValue::Number(threshold as f64)).emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_low_power_idle_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.general.low_power_idle = enabled;
+    settings::write_settings(&app, settings);
+
+    // The idle gate is built with the recorder, so rebuild it
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    rm.reload_vad()
+        .map_err(|e| format!("Failed to apply low-power idle: {}", e))?;
+
+    let _ = SettingsChanged::new("low_power_idle", SettingValue::Bool(enabled)).emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_idle_wake_threshold_setting(app: AppHandle, threshold: f32) -> Result<(), String> {
+    if !(0.001..=0.5).contains(&threshold) {
+        return Err("Idle wake threshold must be between 0.001 and 0.5".to_string());
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.general.idle_wake_threshold = threshold;
+    settings::write_settings(&app, settings);
+
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    rm.reload_vad()
+        .map_err(|e| format!("Failed to apply idle wake threshold: {}", e))?;
+
+    let _ = SettingsChanged::new(
+        "idle_wake_threshold",
+        SettingValue::Number(threshold as f64),
+    )
+    .emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_prearm_microphone_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.general.prearm_microphone = enabled;
+    settings::write_settings(&app, settings);
+
+    if enabled {
+        prearm::start(&app);
+    } else {
+        prearm::stop();
+    }
+
+    let _ = SettingsChanged::new("prearm_microphone", SettingValue::Bool(enabled)).emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_retroactive_capture_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.general.retroactive_capture = enabled;
+    settings::write_settings(&app, settings);
+
+    // The lookback buffer is built with the recorder, so rebuild it
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    rm.reload_vad()
+        .map_err(|e| format!("Failed to apply retroactive capture: {}", e))?;
+
+    let _ = SettingsChanged::new("retroactive_capture", SettingValue::Bool(enabled)).emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_retroactive_capture_seconds_setting(
+    app: AppHandle,
+    seconds: f32,
+) -> Result<(), String> {
+    if !(0.5..=10.0).contains(&seconds) {
+        return Err("Retroactive capture length must be between 0.5 and 10 seconds".to_string());
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.general.retroactive_capture_seconds = seconds;
+    settings::write_settings(&app, settings);
+
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    rm.reload_vad()
+        .map_err(|e| format!("Failed to apply retroactive capture length: {}", e))?;
+
+    let _ = SettingsChanged::new(
+        "retroactive_capture_seconds",
+        SettingValue::Number(seconds as f64),
+    )
+    .emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_primary_speaker_only_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    if enabled && settings.general.voice_profile.is_none() {
+        return Err("Enroll your voice before filtering other speakers".to_string());
+    }
+    settings.general.primary_speaker_only = enabled;
+    settings::write_settings(&app, settings);
+
+    let _ = SettingsChanged::new("primary_speaker_only", SettingValue::Bool(enabled)).emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_primary_speaker_threshold_setting(
+    app: AppHandle,
+    threshold: f32,
+) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err("Primary speaker threshold must be between 0 and 1".to_string());
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.general.primary_speaker_threshold = threshold;
+    settings::write_settings(&app, settings);
+
+    let _ = SettingsChanged::new(
+        "primary_speaker_threshold",
+        SettingValue::Number(threshold as f64),
+    )
+    .emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_echo_cancellation_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.general.echo_cancellation = enabled;
+    settings::write_settings(&app, settings);
+
+    // The echo canceller is built with the recorder, so rebuild it
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    rm.reload_vad()
+        .map_err(|e| format!("Failed to apply echo cancellation: {}", e))?;
+
+    let _ = SettingsChanged::new("echo_cancellation", SettingValue::Bool(enabled)).emit(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_noise_suppression_setting(
+    app: AppHandle,
+    noise_suppression: NoiseSuppressionSettings,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.noise_suppression = noise_suppression;
+    settings::write_settings(&app, settings);
+
+    // The noise suppressor is built with the recorder, so rebuild it
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    rm.reload_vad()
+        .map_err(|e| format!("Failed to apply noise suppression: {}", e))?;
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_streaming_preview_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.general.streaming_preview = enabled;
+    settings::write_settings(&app, settings);
+
+    let _ = SettingsChanged::new("streaming_preview", SettingValue::Bool(enabled)).emit(&app);
 
     Ok(())
 }
@@ -701,6 +1032,117 @@ pub fn resume_binding(app: AppHandle, id: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Bind a plugin action to a shortcut, or unbind it when `binding` is empty
+#[tauri::command]
+#[specta::specta]
+pub fn change_plugin_action_binding(
+    app: AppHandle,
+    id: String,
+    binding: String,
+) -> Result<Option<ShortcutBinding>, String> {
+    if !is_plugin_binding(&id) {
+        return Err(format!("'{}' is not a plugin action", id));
+    }
+    let label = app
+        .state::<Arc<PluginManager>>()
+        .action_label(&id)
+        .ok_or_else(|| format!("Plugin action '{}' isn't loaded", id))?;
+
+    let mut settings = settings::get_settings(&app);
+    if binding.trim().is_empty() {
+        if let Some(existing) = settings.bindings.remove(&id) {
+            let _ = unregister_shortcut(&app, existing);
+            settings::write_settings(&app, settings);
+        }
+        return Ok(None);
+    }
+    validate_shortcut_string(&binding)?;
+
+    if let Some(existing) = settings.bindings.get(&id).cloned() {
+        let _ = unregister_shortcut(&app, existing);
+    }
+    let plugin_binding = ShortcutBinding {
+        id: id.clone(),
+        name: label,
+        description: "Runs a plugin action.".to_string(),
+        default_binding: String::new(),
+        current_binding: binding,
+    };
+    register_shortcut(&app, plugin_binding.clone())?;
+    settings.bindings.insert(id, plugin_binding.clone());
+    settings::write_settings(&app, settings);
+    Ok(Some(plugin_binding))
+}
+
+/// Bind a transcribe profile to a shortcut, or unbind it when `binding` is
+/// empty
+#[tauri::command]
+#[specta::specta]
+pub fn change_transcribe_profile_binding(
+    app: AppHandle,
+    profile_id: String,
+    binding: String,
+) -> Result<Option<ShortcutBinding>, String> {
+    let mut settings = settings::get_settings(&app);
+    let name = settings
+        .transcribe_profiles
+        .iter()
+        .find(|profile| profile.id == profile_id)
+        .map(|profile| profile.name.clone())
+        .ok_or_else(|| format!("Transcribe profile '{}' not found", profile_id))?;
+    let id = profile_binding_id(&profile_id);
+
+    if binding.trim().is_empty() {
+        if let Some(existing) = settings.bindings.remove(&id) {
+            let _ = unregister_shortcut(&app, existing);
+            settings::write_settings(&app, settings);
+        }
+        return Ok(None);
+    }
+    validate_shortcut_string(&binding)?;
+
+    if let Some(existing) = settings.bindings.get(&id).cloned() {
+        let _ = unregister_shortcut(&app, existing);
+    }
+    let profile_binding = ShortcutBinding {
+        id: id.clone(),
+        name,
+        description: "Transcribes speech with a profile.".to_string(),
+        default_binding: String::new(),
+        current_binding: binding,
+    };
+    register_shortcut(&app, profile_binding.clone())?;
+    settings.bindings.insert(id, profile_binding.clone());
+    settings::write_settings(&app, settings);
+    Ok(Some(profile_binding))
+}
+
+/// Register the saved shortcuts of plugin actions after plugins are
+/// (re)loaded, dropping the ones whose action is gone
+pub fn refresh_plugin_shortcuts(app: &AppHandle) {
+    let plugins = app.state::<Arc<PluginManager>>();
+    let plugin_bindings = get_settings(app)
+        .bindings
+        .into_values()
+        .filter(|binding| is_plugin_binding(&binding.id));
+    for binding in plugin_bindings {
+        let registered = binding
+            .current_binding
+            .parse::<Shortcut>()
+            .is_ok_and(|shortcut| app.global_shortcut().is_registered(shortcut));
+        if registered {
+            let _ = unregister_shortcut(app, binding.clone());
+        }
+        if plugins.action_label(&binding.id).is_none() {
+            continue;
+        }
+        let id = binding.id.clone();
+        if let Err(e) = register_shortcut(app, binding) {
+            error!("Failed to register plugin shortcut {}: {}", id, e);
+        }
+    }
+}
+
 pub fn register_cancel_shortcut(app: &AppHandle) {
     // Cancel shortcut is disabled on Linux due to instability with dynamic shortcut registration
     #[cfg(target_os = "linux")]
@@ -781,13 +1223,17 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
                 let shortcut_string = scut.into_string();
                 let settings = get_settings(ah);
 
-                if let Some(action) = ACTION_MAP.get(&binding_id_for_closure) {
+                if let Some(action) = action_for(&binding_id_for_closure) {
                     if binding_id_for_closure == "cancel" {
                         let audio_manager = ah.state::<Arc<AudioRecordingManager>>();
                         if audio_manager.is_recording() && event.state == ShortcutState::Pressed {
                             action.start(ah, &binding_id_for_closure, &shortcut_string);
                         }
                         return;
+                    } else if is_plugin_binding(&binding_id_for_closure) {
+                        if event.state == ShortcutState::Pressed {
+                            action.start(ah, &binding_id_for_closure, &shortcut_string);
+                        }
                     } else if settings.general.push_to_talk {
                         if event.state == ShortcutState::Pressed {
                             action.start(ah, &binding_id_for_closure, &shortcut_string);
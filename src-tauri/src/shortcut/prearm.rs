@@ -0,0 +1,159 @@
+//! Microphone pre-arming
+//!
+//! In on-demand mode the stream only opens once a shortcut fires, and opening
+//! the device can take long enough to clip the first word. With pre-arming on,
+//! a keyboard listener watches for the first modifier of a transcribe shortcut
+//! (e.g. Option of Option+Space) and opens the stream right away, so it's live
+//! by the time the full chord registers. If the chord isn't completed, the
+//! stream is closed again.
+
+use handy_keys::{KeyboardListener, Modifiers};
+use log::{debug, error, info};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+use crate::managers::audio::AudioRecordingManager;
+use crate::settings::{get_settings, is_profile_binding};
+
+/// Bindings whose shortcut starts a recording
+const RECORDING_BINDINGS: [&str; 2] = ["transcribe", "transcribe_with_post_process"];
+
+/// How long a pre-armed stream stays open without the chord completing
+const ARMED_TIMEOUT: Duration = Duration::from_secs(3);
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Id of the running listener thread, 0 when stopped. A listener exits once
+/// it's no longer the current one, so a quick stop and start can't leave two.
+static LISTENER: AtomicUsize = AtomicUsize::new(0);
+static NEXT_LISTENER: AtomicUsize = AtomicUsize::new(1);
+
+fn parse_modifier(part: &str) -> Option<Modifiers> {
+    match part.trim().to_lowercase().as_str() {
+        "ctrl" | "control" => Some(Modifiers::CTRL),
+        "alt" | "option" => Some(Modifiers::OPT),
+        "shift" => Some(Modifiers::SHIFT),
+        "meta" | "command" | "cmd" | "super" | "win" => Some(Modifiers::CMD),
+        #[cfg(target_os = "macos")]
+        "commandorcontrol" | "cmdorctrl" => Some(Modifiers::CMD),
+        #[cfg(not(target_os = "macos"))]
+        "commandorcontrol" | "cmdorctrl" => Some(Modifiers::CTRL),
+        "fn" => Some(Modifiers::FN),
+        _ => None,
+    }
+}
+
+/// Modifiers of a shortcut string like "option+space"
+fn chord_modifiers(shortcut: &str) -> Modifiers {
+    shortcut
+        .split('+')
+        .filter_map(parse_modifier)
+        .fold(Modifiers::empty(), |all, modifier| all | modifier)
+}
+
+/// Whether the held modifiers are the start of the chord: at least one is
+/// held, and none outside the chord
+fn starts_chord(held: Modifiers, chord: Modifiers) -> bool {
+    !held.is_empty() && chord.contains(held)
+}
+
+fn recording_chords(app: &AppHandle) -> Vec<Modifiers> {
+    let settings = get_settings(app);
+    settings
+        .bindings
+        .values()
+        .filter(|binding| {
+            RECORDING_BINDINGS.contains(&binding.id.as_str()) || is_profile_binding(&binding.id)
+        })
+        .map(|binding| chord_modifiers(&binding.current_binding))
+        .filter(|chord| !chord.is_empty())
+        .collect()
+}
+
+fn listen(app: AppHandle, id: usize) {
+    let listener = match KeyboardListener::new() {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("Failed to create keyboard listener for pre-arming: {}", e);
+            let _ = LISTENER.compare_exchange(id, 0, Ordering::SeqCst, Ordering::SeqCst);
+            return;
+        }
+    };
+    let rm = app.state::<Arc<AudioRecordingManager>>().inner().clone();
+    let mut armed_at: Option<Instant> = None;
+
+    while LISTENER.load(Ordering::SeqCst) == id {
+        let Some(event) = listener.try_recv() else {
+            if armed_at.is_some_and(|at| at.elapsed() >= ARMED_TIMEOUT) {
+                debug!("Shortcut not completed, closing pre-armed stream");
+                rm.disarm_microphone_stream();
+                armed_at = None;
+            }
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        };
+
+        if event.modifiers.is_empty() {
+            // Everything released. If the chord fired, the recording keeps
+            // the stream open.
+            if armed_at.take().is_some() {
+                rm.disarm_microphone_stream();
+            }
+        } else if armed_at.is_none()
+            && event.is_key_down
+            && event.key.is_none()
+            && recording_chords(&app)
+                .into_iter()
+                .any(|chord| starts_chord(event.modifiers, chord))
+        {
+            rm.prearm_microphone_stream();
+            armed_at = Some(Instant::now());
+        }
+    }
+
+    if armed_at.is_some() {
+        rm.disarm_microphone_stream();
+    }
+    debug!("Pre-arm listener stopped");
+}
+
+/// Start watching for shortcut modifiers, if not already
+pub fn start(app: &AppHandle) {
+    let id = NEXT_LISTENER.fetch_add(1, Ordering::SeqCst);
+    if LISTENER
+        .compare_exchange(0, id, Ordering::SeqCst, Ordering::SeqCst)
+        .is_err()
+    {
+        return;
+    }
+    let app = app.clone();
+    thread::spawn(move || listen(app, id));
+    info!("Microphone pre-arming enabled");
+}
+
+pub fn stop() {
+    if LISTENER.swap(0, Ordering::SeqCst) != 0 {
+        info!("Microphone pre-arming disabled");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_modifier_starts_chord() {
+        let chord = chord_modifiers("option+shift+space");
+        assert_eq!(chord, Modifiers::OPT | Modifiers::SHIFT);
+        assert!(starts_chord(Modifiers::OPT, chord));
+        assert!(starts_chord(Modifiers::OPT | Modifiers::SHIFT, chord));
+        assert!(!starts_chord(Modifiers::CTRL, chord));
+        assert!(!starts_chord(Modifiers::OPT | Modifiers::CTRL, chord));
+        assert!(!starts_chord(Modifiers::empty(), chord));
+
+        assert!(chord_modifiers("f13").is_empty());
+    }
+}
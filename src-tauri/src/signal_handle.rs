@@ -1,11 +1,14 @@
 use crate::actions::ACTION_MAP;
+use crate::managers::audio::AudioRecordingManager;
+use crate::settings::{get_settings, UnixSignal};
 use crate::ManagedToggleState;
 use log::{debug, info, warn};
+use std::sync::Arc;
 use std::thread;
 use tauri::{AppHandle, Manager};
 
 #[cfg(unix)]
-use signal_hook::consts::SIGUSR2;
+use signal_hook::consts::{SIGUSR1, SIGUSR2};
 #[cfg(unix)]
 use signal_hook::iterator::Signals;
 
@@ -13,61 +16,76 @@ use signal_hook::iterator::Signals;
 pub fn setup_signal_handler(app_handle: AppHandle, mut signals: Signals) {
     let app_handle_for_signal = app_handle.clone();
 
-    debug!("SIGUSR2 signal handler registered successfully");
+    debug!("SIGUSR1/SIGUSR2 signal handler registered successfully");
     thread::spawn(move || {
-        debug!("SIGUSR2 signal handler thread started");
+        debug!("Signal handler thread started");
         for sig in signals.forever() {
-            match sig {
-                SIGUSR2 => {
-                    debug!("Received SIGUSR2 signal (signal number: {sig})");
+            let signal = match sig {
+                SIGUSR1 => UnixSignal::Usr1,
+                SIGUSR2 => UnixSignal::Usr2,
+                _ => unreachable!(),
+            };
+            debug!("Received {} signal (signal number: {sig})", signal.name());
 
-                    let binding_id = "transcribe";
-                    let shortcut_string = "SIGUSR2";
+            let settings = get_settings(&app_handle_for_signal);
+            match settings.signals.binding_for(signal) {
+                Some(binding_id) => trigger_binding(&app_handle_for_signal, binding_id, signal),
+                None => debug!("{} is not mapped to an action, ignoring", signal.name()),
+            }
+        }
+    });
+}
 
-                    if let Some(action) = ACTION_MAP.get(binding_id) {
-                        // Determine action and update state while holding the lock,
-                        // but RELEASE the lock before calling the action to avoid deadlocks.
-                        // (Actions may need to acquire the lock themselves, e.g., cancel_current_operation)
-                        let should_start: bool;
-                        {
-                            let toggle_state_manager =
-                                app_handle_for_signal.state::<ManagedToggleState>();
+/// Run the action for `binding_id` the way a toggle shortcut would
+#[cfg(unix)]
+fn trigger_binding(app: &AppHandle, binding_id: &str, signal: UnixSignal) {
+    let shortcut_string = signal.name();
 
-                            let mut states = match toggle_state_manager.lock() {
-                                Ok(s) => s,
-                                Err(e) => {
-                                    warn!("Failed to lock toggle state manager: {e}");
-                                    continue;
-                                }
-                            };
+    let Some(action) = ACTION_MAP.get(binding_id) else {
+        warn!("No action defined in ACTION_MAP for binding ID '{binding_id}'");
+        return;
+    };
 
-                            let is_currently_active = states
-                                .active_toggles
-                                .entry(binding_id.to_string())
-                                .or_insert(false);
+    // Cancel only does something while recording
+    if binding_id == "cancel" {
+        if app.state::<Arc<AudioRecordingManager>>().is_recording() {
+            action.start(app, binding_id, shortcut_string);
+        }
+        return;
+    }
 
-                            should_start = !*is_currently_active;
-                            if should_start {
-                                *is_currently_active = true;
-                            }
-                        } // Lock released here
+    // Determine action and update state while holding the lock,
+    // but RELEASE the lock before calling the action to avoid deadlocks.
+    // (Actions may need to acquire the lock themselves, e.g., cancel_current_operation)
+    let should_start: bool;
+    {
+        let toggle_state_manager = app.state::<ManagedToggleState>();
 
-                        // Now call the action without holding the lock
-                        if should_start {
-                            debug!("SIGUSR2: Starting transcription (was inactive)");
-                            action.start(&app_handle_for_signal, binding_id, shortcut_string);
-                            info!("SIGUSR2: Transcription started");
-                        } else {
-                            debug!("SIGUSR2: Stopping transcription (was active)");
-                            action.stop(&app_handle_for_signal, binding_id, shortcut_string);
-                            debug!("SIGUSR2: Transcription stopped");
-                        }
-                    } else {
-                        warn!("No action defined in ACTION_MAP for binding ID '{binding_id}'");
-                    }
-                }
-                _ => unreachable!(),
+        let mut states = match toggle_state_manager.lock() {
+            Ok(s) => s,
+            Err(e) => {
+                warn!("Failed to lock toggle state manager: {e}");
+                return;
             }
-        }
-    });
+        };
+
+        let is_currently_active = states
+            .active_toggles
+            .entry(binding_id.to_string())
+            .or_insert(false);
+
+        should_start = !*is_currently_active;
+        *is_currently_active = should_start;
+    } // Lock released here
+
+    // Now call the action without holding the lock
+    if should_start {
+        debug!("{shortcut_string}: Starting '{binding_id}' (was inactive)");
+        action.start(app, binding_id, shortcut_string);
+        info!("{shortcut_string}: '{binding_id}' started");
+    } else {
+        debug!("{shortcut_string}: Stopping '{binding_id}' (was active)");
+        action.stop(app, binding_id, shortcut_string);
+        debug!("{shortcut_string}: '{binding_id}' stopped");
+    }
 }
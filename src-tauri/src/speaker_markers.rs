@@ -0,0 +1,130 @@
+//! Speaker Markers
+//!
+//! Labels who is talking in a dictation of an interview or conversation.
+//! The energy diarizer runs over the captured recording to find the turns,
+//! each timed segment of the transcript goes to the speaker talking when it
+//! starts, and the text is rebuilt with one "A:", "B:" line per turn. A
+//! dictation with a single speaker, or without segment timing, is left as
+//! it was.
+
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::{DiarizationConfig, EnergyBasedDiarizer, SpeakerDiarizer, SpeakerId};
+use crate::managers::transcription::TranscriptSegment;
+
+/// Samples the diarizer looks at at once (30ms at 16kHz)
+const FRAME_SAMPLES: usize = 480;
+
+/// Where each speaker turn starts, in seconds from the start of the
+/// recording. The first speaker always starts at zero.
+fn speaker_turns(audio: &[f32]) -> Vec<(f32, SpeakerId)> {
+    let mut diarizer = EnergyBasedDiarizer::with_config(
+        DiarizationConfig::default().with_sample_rate(WHISPER_SAMPLE_RATE),
+    );
+    let mut turns = vec![(0.0, diarizer.get_current_speaker())];
+    for frame in audio.chunks(FRAME_SAMPLES) {
+        if let Some(change) = diarizer.process_frame(frame) {
+            let start = change.sample_offset as f32 / WHISPER_SAMPLE_RATE as f32;
+            turns.push((start, change.new_speaker));
+        }
+    }
+    turns
+}
+
+/// "A" for the first speaker, "B" for the second and so on
+fn label(speaker: SpeakerId) -> String {
+    match char::from_u32('A' as u32 + speaker) {
+        Some(letter) if letter <= 'Z' => letter.to_string(),
+        _ => format!("S{}", speaker + 1),
+    }
+}
+
+/// Rebuild the text from its segments with a line per speaker turn, or
+/// `None` when only one speaker said anything
+fn mark_segments(segments: &[TranscriptSegment], turns: &[(f32, SpeakerId)]) -> Option<String> {
+    let speaker_at = |time: f32| {
+        turns
+            .iter()
+            .take_while(|(start, _)| *start <= time)
+            .last()
+            .map_or(0, |&(_, speaker)| speaker)
+    };
+
+    let mut lines: Vec<(SpeakerId, String)> = Vec::new();
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let speaker = speaker_at(segment.start);
+        match lines.last_mut() {
+            Some((current, line)) if *current == speaker => {
+                line.push(' ');
+                line.push_str(text);
+            }
+            _ => lines.push((speaker, text.to_string())),
+        }
+    }
+
+    if lines.iter().all(|(speaker, _)| *speaker == lines[0].0) {
+        return None;
+    }
+    let lines: Vec<String> = lines
+        .into_iter()
+        .map(|(speaker, line)| format!("{}: {}", label(speaker), line))
+        .collect();
+    Some(lines.join("\n"))
+}
+
+/// The dictation's text with a speaker marker at each turn, or `None` when
+/// it has one speaker or its engine didn't report segment timing
+pub fn mark_speakers(audio: &[f32], segments: &[TranscriptSegment]) -> Option<String> {
+    if segments.is_empty() {
+        return None;
+    }
+    mark_segments(segments, &speaker_turns(audio))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start: f32, end: f32, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start,
+            end,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_mark_segments_starts_a_line_per_turn() {
+        let segments = [
+            segment(0.0, 2.0, " So how did you start?"),
+            segment(2.0, 3.5, " Tell me about it."),
+            segment(4.5, 8.0, " I was a teacher first."),
+            segment(9.5, 11.0, " Interesting."),
+        ];
+        let turns = [(0.0, 0), (4.2, 1), (9.2, 0)];
+        assert_eq!(
+            mark_segments(&segments, &turns).as_deref(),
+            Some("A: So how did you start? Tell me about it.\nB: I was a teacher first.\nA: Interesting.")
+        );
+    }
+
+    #[test]
+    fn test_single_speaker_is_left_alone() {
+        let segments = [
+            segment(0.0, 2.0, "Just me."),
+            segment(5.0, 6.0, "Still me."),
+        ];
+        assert_eq!(mark_segments(&segments, &[(0.0, 0), (7.0, 1)]), None);
+        assert_eq!(mark_speakers(&[0.0; 16_000], &[]), None);
+    }
+
+    #[test]
+    fn test_labels() {
+        assert_eq!(label(0), "A");
+        assert_eq!(label(1), "B");
+        assert_eq!(label(30), "S31");
+    }
+}
@@ -0,0 +1,80 @@
+//! Transcription output for other tools
+//!
+//! Every finished dictation is published as a `transcription-finalized`
+//! event carrying the raw and post-processed text, the language, the
+//! recording length and the model's confidence, so other tools can build on
+//! Dictum without reading the history database. Running headless with
+//! `--json`, the same payload is also printed to stdout, one JSON object per
+//! line:
+//!
+//! ```json
+//! {"text":"hello world","post_processed_text":null,"language":"en","duration_seconds":1.5,"confidence":null}
+//! ```
+//!
+//! It's also delivered to the webhook when one is set up.
+
+use crate::events::TranscriptionFinalized;
+use crate::headless;
+use crate::managers::webhook::WebhookManager;
+use crate::settings::WebhookEvent;
+use log::error;
+use std::io::Write;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+/// The payload as one line of JSON, newline included
+fn json_line(transcription: &TranscriptionFinalized) -> serde_json::Result<String> {
+    serde_json::to_string(transcription).map(|json| json + "\n")
+}
+
+pub fn publish(app: &AppHandle, transcription: TranscriptionFinalized) {
+    if headless::is_json_output() {
+        let written = json_line(&transcription)
+            .map_err(|e| e.to_string())
+            .and_then(|line| {
+                let mut stdout = std::io::stdout().lock();
+                stdout
+                    .write_all(line.as_bytes())
+                    .and_then(|_| stdout.flush())
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(e) = written {
+            error!("Failed to print transcription: {}", e);
+        }
+    }
+
+    if let Some(webhooks) = app.try_state::<Arc<WebhookManager>>() {
+        webhooks.notify(WebhookEvent::TranscriptionCompleted, &transcription);
+    }
+
+    if let Err(e) = transcription.emit(app) {
+        error!("Failed to emit transcription-finalized: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_line_is_one_line_with_every_field() {
+        let line = json_line(&TranscriptionFinalized {
+            text: "hello\nworld".to_string(),
+            post_processed_text: Some("Hello, world.".to_string()),
+            language: None,
+            duration_seconds: 1.5,
+            confidence: None,
+        })
+        .unwrap();
+
+        assert_eq!(line.matches('\n').count(), 1);
+        assert!(line.ends_with('\n'));
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["text"], "hello\nworld");
+        assert_eq!(value["post_processed_text"], "Hello, world.");
+        assert!(value["language"].is_null());
+        assert_eq!(value["duration_seconds"], 1.5);
+        assert!(value["confidence"].is_null());
+    }
+}
@@ -0,0 +1,90 @@
+//! Translation
+//!
+//! Translates dictations into another language with an LLM before they're
+//! pasted: one of the post-process providers or the Ollama model set up for
+//! Active Listening. Unlike Whisper's translate mode this works into any
+//! language. This runs after post-processing, so the original stays the
+//! transcription in history and the translation is the text that's pasted.
+
+use crate::llm_client;
+use crate::ollama_client::OllamaClient;
+use crate::settings::{AppSettings, APPLE_INTELLIGENCE_PROVIDER_ID, OLLAMA_TRANSLATION_PROVIDER};
+use log::debug;
+
+/// Prompt asking an LLM to translate a transcript
+pub fn translation_prompt(transcription: &str, language: &str) -> String {
+    format!(
+        "Translate the following transcript into {}. Reply with only the translation, \
+and repeat it unchanged if it's already in {}.\n\n{}",
+        language,
+        language,
+        transcription.trim()
+    )
+}
+
+/// Translate a dictation into `language` with the provider the translation
+/// settings pick
+pub async fn translate(
+    settings: &AppSettings,
+    text: &str,
+    language: &str,
+) -> Result<String, String> {
+    let translation = &settings.translation;
+    let prompt = translation_prompt(text, language);
+    let model_override = translation
+        .model
+        .clone()
+        .filter(|model| !model.trim().is_empty());
+
+    let result = if translation.provider_id.as_deref() == Some(OLLAMA_TRANSLATION_PROVIDER) {
+        let ollama = &settings.active_listening;
+        let model = model_override.unwrap_or_else(|| ollama.ollama_model.clone());
+        if model.is_empty() {
+            return Err("No Ollama model configured".to_string());
+        }
+        debug!(
+            "Translating dictation into {} with Ollama ({})",
+            language, model
+        );
+        OllamaClient::for_active_listening(ollama)?
+            .generate(&model, prompt)
+            .await?
+    } else {
+        let provider = match translation.provider_id.as_deref() {
+            Some(provider_id) => settings.post_process_provider(provider_id),
+            None => settings.active_post_process_provider(),
+        }
+        .ok_or_else(|| "No provider selected for translation".to_string())?;
+        if provider.id == APPLE_INTELLIGENCE_PROVIDER_ID {
+            return Err("Apple Intelligence can't translate dictations".to_string());
+        }
+        let model = model_override
+            .or_else(|| settings.post_process_models.get(&provider.id).cloned())
+            .unwrap_or_default();
+        if model.trim().is_empty() {
+            return Err(format!(
+                "Provider '{}' has no model configured",
+                provider.id
+            ));
+        }
+        let api_key = settings
+            .post_process_api_keys
+            .get(&provider.id)
+            .cloned()
+            .unwrap_or_default();
+        debug!(
+            "Translating dictation into {} with provider '{}' (model: {})",
+            language, provider.id, model
+        );
+        llm_client::send_chat_completion(provider, api_key, &model, prompt)
+            .await?
+            .unwrap_or_default()
+    };
+
+    let result = result.trim();
+    if result.is_empty() {
+        Err("The translation came back empty".to_string())
+    } else {
+        Ok(result.to_string())
+    }
+}
@@ -1,9 +1,15 @@
+use crate::formatting::{TRAY_ITEM_NONE, TRAY_ITEM_PREFIX};
+use crate::helpers::system_mute;
+use crate::managers::audio::AudioRecordingManager;
 use crate::settings;
 use crate::tray_i18n::get_tray_translations;
+use once_cell::sync::Lazy;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tauri::image::Image;
-use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::menu::{CheckMenuItem, IsMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu};
 use tauri::tray::TrayIcon;
-use tauri::{AppHandle, Manager, Theme};
+use tauri::{AppHandle, Manager, Theme, Wry};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum TrayIconState {
@@ -13,6 +19,9 @@ pub enum TrayIconState {
     ActiveListening,
 }
 
+/// State the tray menu was last built for, so it can be rebuilt in place
+static CURRENT_STATE: Lazy<Mutex<TrayIconState>> = Lazy::new(|| Mutex::new(TrayIconState::Idle));
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum AppTheme {
     Dark,
@@ -79,7 +88,137 @@ pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
     update_tray_menu(app, &icon, None);
 }
 
+/// Minimum time between tray icon redraws for the input level
+const LEVEL_UPDATE_INTERVAL: Duration = Duration::from_millis(150);
+
+/// Number of heights the level bar can take; 0 draws no bar
+const LEVEL_STEPS: u8 = 4;
+
+/// Quantize spectrum levels to a bar height so the icon only changes when
+/// the level changes noticeably
+fn level_step(levels: &[f32]) -> u8 {
+    let peak = levels
+        .iter()
+        .copied()
+        .fold(0.0f32, f32::max)
+        .clamp(0.0, 1.0);
+    (peak * LEVEL_STEPS as f32).round() as u8
+}
+
+fn level_bar_color(theme: &AppTheme) -> [u8; 4] {
+    match theme {
+        AppTheme::Dark => [255, 255, 255, 255],
+        AppTheme::Light => [0, 0, 0, 255],
+        AppTheme::Colored => [250, 162, 202, 255],
+    }
+}
+
+/// Draw a level bar up the right edge of an RGBA icon
+fn draw_level_bar(rgba: &mut [u8], width: u32, height: u32, step: u8, color: [u8; 4]) {
+    let bar_width = (width / 8).max(2);
+    let bar_height = height * step.min(LEVEL_STEPS) as u32 / LEVEL_STEPS as u32;
+
+    for y in height - bar_height..height {
+        for x in width - bar_width..width {
+            let offset = ((y * width + x) * 4) as usize;
+            rgba[offset..offset + 4].copy_from_slice(&color);
+        }
+    }
+}
+
+/// Shows the microphone input level on the tray icon while recording
+#[derive(Default)]
+pub struct TrayLevelIndicator {
+    state: Mutex<LevelState>,
+}
+
+#[derive(Default)]
+struct LevelState {
+    last_update: Option<Instant>,
+    last_step: u8,
+    /// Recording icon last drawn on, cached so redraws don't touch the disk
+    base_icon: Option<(&'static str, Arc<Image<'static>>)>,
+}
+
+impl TrayLevelIndicator {
+    /// Feed the latest spectrum levels; cheap enough to call per audio chunk
+    pub fn update(&self, app: &AppHandle, levels: &[f32]) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        let now = Instant::now();
+        if state
+            .last_update
+            .is_some_and(|last| now.duration_since(last) < LEVEL_UPDATE_INTERVAL)
+        {
+            return;
+        }
+        state.last_update = Some(now);
+
+        // Levels also arrive while an always-on microphone idles
+        let recording = app
+            .try_state::<Arc<AudioRecordingManager>>()
+            .is_some_and(|manager| manager.is_recording());
+        if !recording {
+            state.last_step = 0;
+            return;
+        }
+
+        let step = level_step(levels);
+        if step == state.last_step {
+            return;
+        }
+        if !settings::get_settings(app).general.tray_level_indicator {
+            return;
+        }
+        state.last_step = step;
+
+        let theme = get_current_theme(app);
+        let icon_path = get_icon_path(theme.clone(), TrayIconState::Recording);
+        let base = match &state.base_icon {
+            Some((path, icon)) if *path == icon_path => icon.clone(),
+            _ => {
+                let Some(icon) = app
+                    .path()
+                    .resolve(icon_path, tauri::path::BaseDirectory::Resource)
+                    .ok()
+                    .and_then(|path| Image::from_path(path).ok())
+                else {
+                    return;
+                };
+                let icon = Arc::new(icon);
+                state.base_icon = Some((icon_path, icon.clone()));
+                icon
+            }
+        };
+
+        let mut rgba = base.rgba().to_vec();
+        draw_level_bar(
+            &mut rgba,
+            base.width(),
+            base.height(),
+            step,
+            level_bar_color(&theme),
+        );
+        let tray = app.state::<TrayIcon>();
+        let _ = tray.set_icon(Some(Image::new_owned(rgba, base.width(), base.height())));
+    }
+}
+
+/// Rebuild the tray menu for its current state, e.g. after a mute toggle
+pub fn refresh_tray_menu(app: &AppHandle) {
+    let state = CURRENT_STATE
+        .lock()
+        .map(|s| s.clone())
+        .unwrap_or(TrayIconState::Idle);
+    update_tray_menu(app, &state, None);
+}
+
 pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&str>) {
+    if let Ok(mut current) = CURRENT_STATE.lock() {
+        *current = state.clone();
+    }
     let settings = settings::get_settings(app);
 
     let locale = locale.unwrap_or(&settings.general.app_language);
@@ -206,7 +345,116 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&
         }
     };
 
+    // Microphone mute is available whatever the tray state
+    let mic_muted = system_mute::is_microphone_muted_by_app();
+    let (mute_label, mute_fallback) = if mic_muted {
+        (&strings.unmute_microphone, "Unmute Microphone")
+    } else {
+        (&strings.mute_microphone, "Mute Microphone")
+    };
+    let mute_label = if mute_label.is_empty() {
+        mute_fallback
+    } else {
+        mute_label.as_str()
+    };
+    let mute_i = MenuItem::with_id(app, "toggle_mic_mute", mute_label, true, None::<&str>)
+        .expect("failed to create microphone mute item");
+    let _ = menu.insert(&mute_i, 2);
+
+    // The formatting mode can be switched whatever the tray state
+    let formatting = &settings.formatting;
+    if !formatting.modes.is_empty() {
+        let active = formatting.active_mode().map(|mode| mode.id.as_str());
+        let none_label = if strings.no_formatting.is_empty() {
+            "No Formatting"
+        } else {
+            strings.no_formatting.as_str()
+        };
+        let mut items = vec![CheckMenuItem::with_id(
+            app,
+            TRAY_ITEM_NONE,
+            none_label,
+            true,
+            active.is_none(),
+            None::<&str>,
+        )
+        .expect("failed to create formatting item")];
+        for mode in &formatting.modes {
+            let item = CheckMenuItem::with_id(
+                app,
+                format!("{}{}", TRAY_ITEM_PREFIX, mode.id),
+                &mode.name,
+                true,
+                active == Some(mode.id.as_str()),
+                None::<&str>,
+            )
+            .expect("failed to create formatting item");
+            items.push(item);
+        }
+        let formatting_label = if strings.formatting.is_empty() {
+            "Formatting"
+        } else {
+            strings.formatting.as_str()
+        };
+        let item_refs: Vec<&dyn IsMenuItem<Wry>> = items
+            .iter()
+            .map(|item| item as &dyn IsMenuItem<Wry>)
+            .collect();
+        let formatting_i = Submenu::with_items(app, formatting_label, true, &item_refs)
+            .expect("failed to create formatting menu");
+        let _ = menu.insert(&formatting_i, 3);
+    }
+
+    // Keep guest mode visible whatever the tray state
+    if settings.guest_mode {
+        let guest_label = if strings.guest_mode.is_empty() {
+            "Guest mode: nothing is saved"
+        } else {
+            strings.guest_mode.as_str()
+        };
+        let guest_i = MenuItem::with_id(app, "guest_mode", guest_label, false, None::<&str>)
+            .expect("failed to create guest mode item");
+        let _ = menu.insert(&guest_i, 1);
+    }
+
     let tray = app.state::<TrayIcon>();
+    let tooltip = match (settings.guest_mode, mic_muted) {
+        (true, true) => "Dictum (guest mode, microphone muted)",
+        (true, false) => "Dictum (guest mode)",
+        (false, true) => "Dictum (microphone muted)",
+        (false, false) => "Dictum",
+    };
+    let _ = tray.set_tooltip(Some(tooltip));
     let _ = tray.set_menu(Some(menu));
     let _ = tray.set_icon_as_template(true);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_step_uses_peak() {
+        assert_eq!(level_step(&[]), 0);
+        assert_eq!(level_step(&[0.0, 0.05]), 0);
+        assert_eq!(level_step(&[0.1, 0.5, 0.2]), 2);
+        assert_eq!(level_step(&[1.0]), LEVEL_STEPS);
+        assert_eq!(level_step(&[3.0]), LEVEL_STEPS);
+    }
+
+    #[test]
+    fn test_draw_level_bar_fills_bottom_right() {
+        let (width, height) = (16u32, 16u32);
+        let mut rgba = vec![0u8; (width * height * 4) as usize];
+        draw_level_bar(&mut rgba, width, height, 2, [1, 2, 3, 255]);
+
+        let pixel = |x: u32, y: u32| {
+            let offset = ((y * width + x) * 4) as usize;
+            rgba[offset..offset + 4].to_vec()
+        };
+        assert_eq!(pixel(15, 15), vec![1, 2, 3, 255]);
+        assert_eq!(pixel(14, 8), vec![1, 2, 3, 255]);
+        assert_eq!(pixel(15, 7), vec![0, 0, 0, 0]);
+        assert_eq!(pixel(13, 15), vec![0, 0, 0, 0]);
+    }
+}
@@ -1,5 +1,7 @@
 pub mod lock;
 
+use crate::events::MicrophoneMuteChanged;
+use crate::helpers::system_mute;
 use crate::managers::audio::AudioRecordingManager;
 use crate::managers::transcription::TranscriptionManager;
 use crate::shortcut;
@@ -7,6 +9,7 @@ use crate::ManagedToggleState;
 use log::{info, warn};
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
 
 // Re-export all utility modules for easy access
 pub use crate::clipboard::*;
@@ -41,13 +44,41 @@ pub fn cancel_current_operation(app: &AppHandle) {
     change_tray_icon(app, crate::tray::TrayIconState::Idle);
     hide_recording_overlay(app);
 
-    // Unload model if immediate unload is enabled
+    // Stop the live preview and unload model if immediate unload is enabled
     let tm = app.state::<Arc<TranscriptionManager>>();
+    tm.stop_streaming_preview();
     tm.maybe_unload_immediately("cancellation");
 
     info!("Operation cancellation completed - returned to idle state");
 }
 
+/// Set a binding's toggle state; returns whether it changed
+pub fn set_toggle(app: &AppHandle, binding_id: &str, active: bool) -> bool {
+    let toggle_state_manager = app.state::<ManagedToggleState>();
+    let Ok(mut states) = toggle_state_manager.lock() else {
+        warn!("Failed to lock toggle state manager");
+        return false;
+    };
+    let is_active = states
+        .active_toggles
+        .entry(binding_id.to_string())
+        .or_insert(false);
+    let changed = *is_active != active;
+    *is_active = active;
+    changed
+}
+
+/// Toggle the system microphone mute, reflecting the new state in the tray
+/// menu and emitting `microphone-mute-changed`
+pub fn toggle_microphone_mute(app: &AppHandle) -> Result<bool, String> {
+    let muted = system_mute::toggle_microphone_mute()?;
+    info!("Microphone {}", if muted { "muted" } else { "unmuted" });
+
+    refresh_tray_menu(app);
+    let _ = MicrophoneMuteChanged(muted).emit(app);
+    Ok(muted)
+}
+
 /// Check if using the Wayland display server protocol
 #[cfg(target_os = "linux")]
 pub fn is_wayland() -> bool {
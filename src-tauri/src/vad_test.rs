@@ -0,0 +1,261 @@
+//! VAD test
+//!
+//! Opens the selected microphone from the settings window and streams the
+//! input level alongside the VAD's verdict as `vad-test-point` events, so
+//! users tuning the VAD threshold can see where speech is detected and
+//! where it gets cut off. The test runs on its own stream and stops by
+//! itself after [`MAX_TEST_DURATION`].
+
+use crate::audio_toolkit::vad::{VadConfig, VadFrame, VoiceActivityDetector};
+use crate::audio_toolkit::{AudioRecorder, SileroVad};
+use crate::events::VadTestStopped;
+use crate::managers::audio::AudioRecordingManager;
+use crate::settings::get_settings;
+use log::{debug, error, warn};
+use serde::Serialize;
+use specta::Type;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+/// VAD frames (30 ms each) summarized into one point
+const FRAMES_PER_POINT: usize = 3;
+
+/// Longest a test runs before it is stopped automatically
+pub const MAX_TEST_DURATION: Duration = Duration::from_secs(60);
+
+const FRAME_MS: u64 = 30;
+
+/// One downsampled point of a VAD test
+#[derive(Serialize, Clone, Debug, PartialEq, Type, Event)]
+pub struct VadTestPoint {
+    /// Milliseconds since the test started
+    pub offset_ms: u64,
+    /// RMS level of the input
+    pub rms: f32,
+    /// Highest speech probability reported by the model
+    pub probability: f32,
+    /// Whether the VAD kept any of this audio as speech
+    pub is_speech: bool,
+}
+
+/// Folds consecutive VAD frames into points
+#[derive(Default)]
+struct PointAggregator {
+    frames: usize,
+    frames_total: u64,
+    sum_squares: f64,
+    samples: usize,
+    probability: f32,
+    is_speech: bool,
+}
+
+impl PointAggregator {
+    fn push(&mut self, frame: &[f32], probability: f32, is_speech: bool) -> Option<VadTestPoint> {
+        if self.frames == 0 {
+            self.sum_squares = 0.0;
+            self.samples = 0;
+            self.probability = 0.0;
+            self.is_speech = false;
+        }
+        self.frames += 1;
+        self.sum_squares += frame.iter().map(|s| (*s as f64).powi(2)).sum::<f64>();
+        self.samples += frame.len();
+        self.probability = self.probability.max(probability);
+        self.is_speech |= is_speech;
+
+        if self.frames < FRAMES_PER_POINT {
+            return None;
+        }
+
+        let point = VadTestPoint {
+            offset_ms: self.frames_total * FRAME_MS,
+            rms: (self.sum_squares / self.samples.max(1) as f64).sqrt() as f32,
+            probability: self.probability,
+            is_speech: self.is_speech,
+        };
+        self.frames_total += self.frames as u64;
+        self.frames = 0;
+        Some(point)
+    }
+}
+
+/// Passes Silero's decision on while keeping the raw probability of the
+/// last frame
+struct ProbeVad {
+    inner: SileroVad,
+    threshold: f32,
+    last_probability: Arc<Mutex<f32>>,
+}
+
+impl VoiceActivityDetector for ProbeVad {
+    fn push_frame<'a>(&'a mut self, frame: &'a [f32]) -> anyhow::Result<VadFrame<'a>> {
+        let probability = self.inner.speech_probability(frame)?;
+        if let Ok(mut last) = self.last_probability.lock() {
+            *last = probability;
+        }
+        if probability > self.threshold {
+            Ok(VadFrame::Speech(frame))
+        } else {
+            Ok(VadFrame::Noise)
+        }
+    }
+}
+
+struct VadTestSession {
+    id: u64,
+    recorder: AudioRecorder,
+}
+
+/// The running VAD test, if any
+#[derive(Default)]
+pub struct VadTestState {
+    session: Mutex<Option<VadTestSession>>,
+    next_id: AtomicU64,
+}
+
+fn build_recorder(app: &AppHandle) -> Result<AudioRecorder, String> {
+    let vad_path = app
+        .path()
+        .resolve(
+            "resources/models/silero_vad_v4.onnx",
+            tauri::path::BaseDirectory::Resource,
+        )
+        .map_err(|e| format!("Failed to resolve VAD path: {}", e))?;
+    let threshold = get_settings(app).general.vad_threshold.clamp(0.0, 1.0);
+    let silero = SileroVad::new(&vad_path, threshold)
+        .map_err(|e| format!("Failed to create SileroVad: {}", e))?;
+
+    let last_probability = Arc::new(Mutex::new(0.0));
+    let probe = ProbeVad {
+        inner: silero,
+        threshold,
+        last_probability: last_probability.clone(),
+    };
+    // Same smoothing as the recorder, so the result matches real recordings
+    let vad = Mutex::new(VadConfig::default().smooth(Box::new(probe)));
+    let aggregator = Mutex::new(PointAggregator::default());
+
+    let app = app.clone();
+    let recorder = AudioRecorder::new()
+        .map_err(|e| format!("Failed to create AudioRecorder: {}", e))?
+        .with_sample_callback(move |frame| {
+            let is_speech = match vad.lock() {
+                Ok(mut vad) => vad
+                    .push_frame(frame)
+                    .map(|f| f.is_speech())
+                    .unwrap_or(false),
+                Err(_) => return,
+            };
+            let probability = last_probability.lock().map(|p| *p).unwrap_or(0.0);
+            let point = aggregator
+                .lock()
+                .ok()
+                .and_then(|mut a| a.push(frame, probability, is_speech));
+            if let Some(point) = point {
+                let _ = point.emit(&app);
+            }
+        });
+    Ok(recorder)
+}
+
+/// Start a VAD test on the selected microphone, replacing any running test
+pub fn start(app: &AppHandle) -> Result<(), String> {
+    let audio_manager = app.state::<Arc<AudioRecordingManager>>();
+    if audio_manager.is_recording() {
+        return Err("Stop recording before testing the VAD".to_string());
+    }
+    stop(app);
+
+    let mut recorder = build_recorder(app)?;
+    let device = audio_manager.get_effective_microphone_device(&get_settings(app));
+    recorder
+        .open(device)
+        .map_err(|e| format!("Failed to open microphone: {}", e))?;
+    if let Err(e) = recorder.start() {
+        let _ = recorder.close();
+        return Err(format!("Failed to start VAD test: {}", e));
+    }
+
+    let state = app.state::<VadTestState>();
+    let id = state.next_id.fetch_add(1, Ordering::SeqCst);
+    match state.session.lock() {
+        Ok(mut session) => *session = Some(VadTestSession { id, recorder }),
+        Err(e) => {
+            error!("Failed to lock VAD test state: {}", e);
+            let _ = recorder.close();
+            return Err("Failed to start VAD test".to_string());
+        }
+    }
+    debug!("VAD test started");
+
+    let app = app.clone();
+    thread::spawn(move || {
+        thread::sleep(MAX_TEST_DURATION);
+        let state = app.state::<VadTestState>();
+        let is_same_test = state
+            .session
+            .lock()
+            .map(|s| s.as_ref().is_some_and(|s| s.id == id))
+            .unwrap_or(false);
+        if is_same_test {
+            debug!("VAD test reached its time limit");
+            stop(&app);
+        }
+    });
+
+    Ok(())
+}
+
+/// Stop the running VAD test, if any, and emit `vad-test-stopped`
+pub fn stop(app: &AppHandle) {
+    let state = app.state::<VadTestState>();
+    let session = match state.session.lock() {
+        Ok(mut session) => session.take(),
+        Err(e) => {
+            error!("Failed to lock VAD test state: {}", e);
+            return;
+        }
+    };
+
+    if let Some(mut session) = session {
+        let _ = session.recorder.stop();
+        if let Err(e) = session.recorder.close() {
+            warn!("Failed to close VAD test stream: {}", e);
+        }
+        let _ = VadTestStopped.emit(app);
+        debug!("VAD test stopped");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_are_summarized_into_points() {
+        let mut aggregator = PointAggregator::default();
+        let quiet = vec![0.0; 480];
+        let loud = vec![0.5; 480];
+
+        assert!(aggregator.push(&quiet, 0.1, false).is_none());
+        assert!(aggregator.push(&loud, 0.9, true).is_none());
+        let point = aggregator.push(&quiet, 0.2, false).unwrap();
+
+        assert_eq!(point.offset_ms, 0);
+        assert!((point.rms - (0.25f32 / 3.0).sqrt()).abs() < 1e-4);
+        assert_eq!(point.probability, 0.9);
+        assert!(point.is_speech);
+
+        for _ in 0..2 {
+            assert!(aggregator.push(&quiet, 0.0, false).is_none());
+        }
+        let point = aggregator.push(&quiet, 0.0, false).unwrap();
+        assert_eq!(point.offset_ms, 90);
+        assert_eq!(point.rms, 0.0);
+        assert!(!point.is_speech);
+    }
+}
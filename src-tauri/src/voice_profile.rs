@@ -0,0 +1,114 @@
+//! Voice profile
+//!
+//! Enrolls the primary user's voice so other speakers can be filtered out
+//! of push-to-talk recordings and Active Listening segments before they're
+//! transcribed. Enrollment records the user reading aloud on its own stream
+//! and stores the resulting voiceprint in the settings.
+
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::{filter_to_speaker, AudioRecorder, VadConfig, Voiceprint};
+use crate::managers::audio::AudioRecordingManager;
+use crate::settings::{get_settings, write_settings, AppSettings};
+use log::{debug, info};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// How long enrollment records for
+pub const ENROLLMENT_DURATION: Duration = Duration::from_secs(8);
+
+fn record_enrollment(app: &AppHandle) -> Result<Vec<f32>, String> {
+    let vad_path = app
+        .path()
+        .resolve(
+            "resources/models/silero_vad_v4.onnx",
+            tauri::path::BaseDirectory::Resource,
+        )
+        .map_err(|e| format!("Failed to resolve VAD path: {}", e))?;
+    let threshold = get_settings(app).general.vad_threshold.clamp(0.0, 1.0);
+    // Only speech goes into the voiceprint
+    let vad = VadConfig::default()
+        .with_threshold(threshold)
+        .build(&vad_path)
+        .map_err(|e| format!("Failed to create SileroVad: {}", e))?;
+
+    let mut recorder = AudioRecorder::new()
+        .map_err(|e| format!("Failed to create AudioRecorder: {}", e))?
+        .with_vad(Box::new(vad));
+    let audio_manager = app.state::<Arc<AudioRecordingManager>>();
+    let device = audio_manager.get_effective_microphone_device(&get_settings(app));
+    recorder
+        .open(device)
+        .map_err(|e| format!("Failed to open microphone: {}", e))?;
+
+    let samples = recorder
+        .start()
+        .and_then(|_| {
+            thread::sleep(ENROLLMENT_DURATION);
+            recorder.stop()
+        })
+        .map_err(|e| format!("Failed to record voice sample: {}", e));
+    let _ = recorder.close();
+    samples
+}
+
+/// Record the user speaking and store their voiceprint. Blocks for
+/// [`ENROLLMENT_DURATION`].
+pub fn enroll(app: &AppHandle) -> Result<(), String> {
+    let audio_manager = app.state::<Arc<AudioRecordingManager>>();
+    if audio_manager.is_recording() || audio_manager.is_active_listening() {
+        return Err("Stop recording before enrolling your voice".to_string());
+    }
+
+    let samples = record_enrollment(app)?;
+    let voiceprint = Voiceprint::from_samples(&samples, WHISPER_SAMPLE_RATE).ok_or_else(|| {
+        "Not enough speech to build a voice profile. Keep talking for the whole recording."
+            .to_string()
+    })?;
+
+    let mut settings = get_settings(app);
+    settings.general.voice_profile = Some(voiceprint.bands().to_vec());
+    write_settings(app, settings);
+    info!(
+        "Voice profile enrolled from {:.1}s of speech",
+        samples.len() as f32 / WHISPER_SAMPLE_RATE as f32
+    );
+    Ok(())
+}
+
+pub fn clear(app: &AppHandle) {
+    let mut settings = get_settings(app);
+    settings.general.voice_profile = None;
+    write_settings(app, settings);
+}
+
+/// Drop the parts of a recording that weren't spoken by the enrolled user,
+/// when primary-speaker filtering is on
+pub fn filter_to_primary_speaker(settings: &AppSettings, samples: Vec<f32>) -> Vec<f32> {
+    if !settings.general.primary_speaker_only {
+        return samples;
+    }
+    let Some(voiceprint) = settings
+        .general
+        .voice_profile
+        .clone()
+        .and_then(Voiceprint::from_bands)
+    else {
+        return samples;
+    };
+
+    let filtered = filter_to_speaker(
+        &samples,
+        WHISPER_SAMPLE_RATE,
+        &voiceprint,
+        settings.general.primary_speaker_threshold,
+    );
+    if filtered.len() < samples.len() {
+        debug!(
+            "Filtered out {} samples from other speakers",
+            samples.len() - filtered.len()
+        );
+    }
+    filtered
+}
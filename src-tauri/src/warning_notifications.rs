@@ -0,0 +1,125 @@
+//! Routing of conversation warnings beyond the suggestions overlay
+//!
+//! Each warning severity maps to a `WarningRoute` in the suggestions settings,
+//! which decides whether the warning also raises a system notification,
+//! plays an alert sound, or is POSTed to a webhook.
+
+use crate::access_policy;
+use crate::audio_feedback::play_alert_sound;
+use crate::notifications::{notify, Notification};
+use crate::settings::{
+    NotificationFeature, NotificationSeverity, SuggestionsSettings, WarningSeverity,
+};
+use log::{debug, warn};
+use serde::Serialize;
+use std::time::Duration;
+use tauri::AppHandle;
+
+/// Timeout for webhook deliveries
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// JSON body POSTed to the warning webhook
+#[derive(Debug, Serialize)]
+struct WarningWebhookPayload<'a> {
+    session_id: &'a str,
+    severity: WarningSeverity,
+    message: &'a str,
+    timestamp: i64,
+}
+
+fn severity_title(severity: WarningSeverity) -> &'static str {
+    match severity {
+        WarningSeverity::Low => "Conversation note",
+        WarningSeverity::Medium => "Conversation warning",
+        WarningSeverity::High => "Critical conversation warning",
+    }
+}
+
+fn notification_severity(severity: WarningSeverity) -> NotificationSeverity {
+    match severity {
+        WarningSeverity::Low => NotificationSeverity::Info,
+        WarningSeverity::Medium => NotificationSeverity::Warning,
+        WarningSeverity::High => NotificationSeverity::Critical,
+    }
+}
+
+/// Only deliver webhooks to http(s) endpoints
+pub fn is_valid_webhook_url(url: &str) -> bool {
+    let lower = url.trim().to_lowercase();
+    lower.starts_with("https://") || lower.starts_with("http://")
+}
+
+/// Route a warning according to the per-severity routing table
+pub fn route_warning(
+    app: &AppHandle,
+    settings: &SuggestionsSettings,
+    session_id: &str,
+    message: &str,
+    severity: WarningSeverity,
+) {
+    let route = settings.warning_routing.route(severity);
+
+    if route.notify {
+        notify(
+            app,
+            Notification {
+                feature: NotificationFeature::Warnings,
+                severity: notification_severity(severity),
+                title: severity_title(severity).to_string(),
+                body: message.to_string(),
+            },
+        );
+    }
+
+    if route.play_sound {
+        play_alert_sound(app);
+    }
+
+    if route.webhook {
+        match settings.warning_webhook_url.as_deref() {
+            Some(url) if is_valid_webhook_url(url) => {
+                send_webhook(url.trim().to_string(), session_id, message, severity)
+            }
+            Some(_) => warn!("Ignoring warning webhook: only http(s) URLs are supported"),
+            None => debug!("Warning routed to webhook but no webhook URL is configured"),
+        }
+    }
+}
+
+/// Deliver a warning to the webhook in the background
+fn send_webhook(url: String, session_id: &str, message: &str, severity: WarningSeverity) {
+    if let Err(e) = access_policy::check_url(&url) {
+        warn!("Not delivering warning webhook: {}", e);
+        return;
+    }
+
+    let body = match serde_json::to_value(WarningWebhookPayload {
+        session_id,
+        severity,
+        message,
+        timestamp: chrono::Utc::now().timestamp_millis(),
+    }) {
+        Ok(body) => body,
+        Err(e) => {
+            warn!("Failed to serialize warning webhook payload: {}", e);
+            return;
+        }
+    };
+
+    tauri::async_runtime::spawn(async move {
+        let result = reqwest::Client::new()
+            .post(&url)
+            .timeout(WEBHOOK_TIMEOUT)
+            .json(&body)
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if response.status().is_success() => {
+                debug!("Delivered warning to webhook");
+            }
+            Ok(response) => warn!("Warning webhook returned {}", response.status()),
+            Err(e) => warn!("Failed to deliver warning webhook: {}", e),
+        }
+    });
+}
@@ -1,5 +1,6 @@
 import { useEffect, useState, useRef } from "react";
-import { Toaster } from "sonner";
+import { Toaster, toast } from "sonner";
+import { listen } from "@tauri-apps/api/event";
 import { useTranslation } from "react-i18next";
 import { platform } from "@tauri-apps/plugin-os";
 import {
@@ -26,7 +27,7 @@ const renderSettingsContent = (section: SidebarSection) => {
 };
 
 function App() {
-  const { i18n } = useTranslation();
+  const { t, i18n } = useTranslation();
   const [onboardingStep, setOnboardingStep] = useState<OnboardingStep | null>(
     null,
   );
@@ -69,6 +70,37 @@ function App() {
     }
   }, [onboardingStep, refreshAudioDevices, refreshOutputDevices]);
 
+  // Offer to transcribe audio files copied while the clipboard is watched
+  useEffect(() => {
+    const unlisten = listen<{ path: string; file_name: string }>(
+      "clipboard-audio-copied",
+      (event) => {
+        const { path, file_name: fileName } = event.payload;
+        toast(t("batchProcessing.clipboardWatch.offer.title", { fileName }), {
+          action: {
+            label: t("batchProcessing.clipboardWatch.offer.transcribe"),
+            onClick: async () => {
+              const result = await commands.transcribeClipboardFile(path);
+              if (result.status === "error") {
+                toast.error(result.error);
+              } else {
+                toast.success(
+                  t("batchProcessing.clipboardWatch.offer.queued", {
+                    fileName,
+                  }),
+                );
+              }
+            },
+          },
+        });
+      },
+    );
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [t]);
+
   // Handle keyboard shortcuts for debug mode toggle
   useEffect(() => {
     const handleKeyDown = (event: KeyboardEvent) => {
@@ -101,6 +101,14 @@ async changeDebugModeSetting(enabled: boolean) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async changeGuestModeSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_guest_mode_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeWordCorrectionThresholdSetting(threshold: number) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_word_correction_threshold_setting", { threshold }) };
@@ -173,6 +181,18 @@ async fetchPostProcessModels(providerId: string) : Promise<Result<string[], stri
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Set or clear the provider and model a prompt is post-processed with,
+ * instead of the selected provider and its model
+ */
+async setPostProcessPromptModel(id: string, providerId: string | null, model: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_post_process_prompt_model", { id, providerId, model }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async addPostProcessPrompt(name: string, prompt: string) : Promise<Result<LLMPrompt, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("add_post_process_prompt", { name, prompt }) };
@@ -205,6 +225,17 @@ async setPostProcessSelectedPrompt(id: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Replace the phrases that route dictations to post-process prompts
+ */
+async setPostProcessRoutes(routes: PromptRoute[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_post_process_routes", { routes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async updateCustomWords(words: string[]) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_custom_words", { words }) };
@@ -268,6 +299,14 @@ async changeUpdateChecksSetting(enabled: boolean) : Promise<Result<null, string>
     else return { status: "error", error: e  as any };
 }
 },
+async changeUpdateChannelSetting(channel: UpdateChannel) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_update_channel_setting", { channel }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changePrivateOverlaySetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_private_overlay_setting", { enabled }) };
@@ -276,6 +315,102 @@ async changePrivateOverlaySetting(enabled: boolean) : Promise<Result<null, strin
     else return { status: "error", error: e  as any };
 }
 },
+async changeTrayLevelIndicatorSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_tray_level_indicator_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeVadThresholdSetting(threshold: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_vad_threshold_setting", { threshold }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeLowPowerIdleSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_low_power_idle_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeIdleWakeThresholdSetting(threshold: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_idle_wake_threshold_setting", { threshold }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changePrearmMicrophoneSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_prearm_microphone_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeRetroactiveCaptureSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_retroactive_capture_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeRetroactiveCaptureSecondsSetting(seconds: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_retroactive_capture_seconds_setting", { seconds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changePrimarySpeakerOnlySetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_primary_speaker_only_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changePrimarySpeakerThresholdSetting(threshold: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_primary_speaker_threshold_setting", { threshold }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeEchoCancellationSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_echo_cancellation_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeNoiseSuppressionSetting(noiseSuppression: NoiseSuppressionSettings) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_noise_suppression_setting", { noiseSuppression }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeStreamingPreviewSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_streaming_preview_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async triggerUpdateCheck() : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("trigger_update_check") };
@@ -295,6 +430,12 @@ async getAppDirPath() : Promise<Result<string, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Whether app data lives next to the executable instead of the user profile
+ */
+async isPortableMode() : Promise<boolean> {
+    return await TAURI_INVOKE("is_portable_mode");
+},
 async getAppSettings() : Promise<Result<AppSettings, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_app_settings") };
@@ -327,6 +468,35 @@ async setLogLevel(level: LogLevel) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Override the file log level for one module, or remove its override when
+ * `level` is `None`
+ */
+async setLogModuleLevel(module: string, level: LogLevel | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_log_module_level", { module, level }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replace the hosts and folders integrations may access
+ */
+async setAccessPolicy(policy: AccessPolicySettings) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_access_policy", { policy }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Hosts and paths recently reported by the access policy, oldest first
+ */
+async getAccessPolicyViolations() : Promise<AccessViolation[]> {
+    return await TAURI_INVOKE("get_access_policy_violations");
+},
 async openRecordingsFolder() : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("open_recordings_folder") };
@@ -343,6 +513,39 @@ async openLogDir() : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Log entries from the app's log files, newest first
+ */
+async getRecentLogs(filter: LogFilter | null, offset: number | null, limit: number | null) : Promise<Result<LogPage, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_recent_logs", { filter, offset, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Emit new log entries matching the filter as `log-entries` events until
+ * `stop_log_follow` is called
+ */
+async startLogFollow(filter: LogFilter | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_log_follow", { filter }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async stopLogFollow() : Promise<void> {
+    await TAURI_INVOKE("stop_log_follow");
+},
+/**
+ * Recent app events and manager state changes, oldest first. Empty unless
+ * debug mode is on.
+ */
+async getEventTrail() : Promise<TrailEntry[]> {
+    return await TAURI_INVOKE("get_event_trail");
+},
 async openAppDataDir() : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("open_app_data_dir") };
@@ -556,1693 +759,5698 @@ async getClamshellMicrophone() : Promise<Result<string, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async isRecording() : Promise<boolean> {
-    return await TAURI_INVOKE("is_recording");
-},
-async setModelUnloadTimeout(timeout: ModelUnloadTimeout) : Promise<void> {
-    await TAURI_INVOKE("set_model_unload_timeout", { timeout });
+async getDockState() : Promise<DockState> {
+    return await TAURI_INVOKE("get_dock_state");
 },
-async getModelLoadStatus() : Promise<Result<ModelLoadStatus, string>> {
+/**
+ * Replace the dock rules, switching the microphone if the active rule changed it
+ */
+async setDockingSettings(docking: DockingSettings) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_model_load_status") };
+    return { status: "ok", data: await TAURI_INVOKE("set_docking_settings", { docking }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async unloadModelManually() : Promise<Result<null, string>> {
+/**
+ * Replace the schedule rules and apply the ones active right now
+ */
+async setScheduleSettings(schedule: ScheduleSettings) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("unload_model_manually") };
+    return { status: "ok", data: await TAURI_INVOKE("set_schedule_settings", { schedule }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getHistoryEntries() : Promise<Result<HistoryEntry[], string>> {
+/**
+ * Overrides of the schedule rules active right now
+ */
+async getScheduleOverrides() : Promise<ScheduleOverrides> {
+    return await TAURI_INVOKE("get_schedule_overrides");
+},
+/**
+ * Mute or unmute the system microphone, returning the new state
+ */
+async toggleMicrophoneMute() : Promise<Result<boolean, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_history_entries") };
+    return { status: "ok", data: await TAURI_INVOKE("toggle_microphone_mute") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async toggleHistoryEntrySaved(id: number) : Promise<Result<null, string>> {
+async isMicrophoneMuted() : Promise<boolean> {
+    return await TAURI_INVOKE("is_microphone_muted");
+},
+async isRecording() : Promise<boolean> {
+    return await TAURI_INVOKE("is_recording");
+},
+/**
+ * Stream input levels and VAD decisions as `vad-test-point` events until
+ * stopped or the time limit is reached
+ */
+async startVadTest() : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("toggle_history_entry_saved", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("start_vad_test") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAudioFilePath(fileName: string) : Promise<Result<string, string>> {
+async stopVadTest() : Promise<void> {
+    await TAURI_INVOKE("stop_vad_test");
+},
+/**
+ * Record the user reading aloud and store their voiceprint, so other
+ * speakers can be filtered out
+ */
+async enrollVoiceProfile() : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_audio_file_path", { fileName }) };
+    return { status: "ok", data: await TAURI_INVOKE("enroll_voice_profile") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async deleteHistoryEntry(id: number) : Promise<Result<null, string>> {
+async clearVoiceProfile() : Promise<void> {
+    await TAURI_INVOKE("clear_voice_profile");
+},
+/**
+ * Turn gain normalization on or off and set the level, in dBFS, that
+ * recordings are brought to before transcription
+ */
+async changeGainNormalizationSetting(enabled: boolean, targetDbfs: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_history_entry", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_gain_normalization_setting", { enabled, targetDbfs }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateHistoryLimit(limit: number) : Promise<Result<null, string>> {
+/**
+ * Turn the silence auto-stop of toggle-mode recordings on or off and set
+ * how many seconds without speech end a recording
+ */
+async changeSilenceAutoStopSetting(enabled: boolean, seconds: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_history_limit", { limit }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_silence_auto_stop_setting", { enabled, seconds }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateRecordingRetentionPeriod(period: string) : Promise<Result<null, string>> {
+/**
+ * Turn the recording limit on or off and set how many minutes a recording
+ * can run before it's stopped and transcribed. Applies from the next
+ * recording.
+ */
+async changeRecordingLimitSetting(enabled: boolean, minutes: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_recording_retention_period", { period }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_recording_limit_setting", { enabled, minutes }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Start an active listening session
+ * Score every VAD threshold and diarization config and recommend the best.
+ * VAD is scored on the given recording; diarization is scored on built-in
+ * fixtures plus the recording if it has speaker labels.
  */
-async startActiveListeningSession(topic: string | null) : Promise<Result<string, string>> {
+async runTuningBenchmark(sample: TuningSample | null) : Promise<Result<TuningReport, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("start_active_listening_session", { topic }) };
+    return { status: "ok", data: await TAURI_INVOKE("run_tuning_benchmark", { sample }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Stop the current active listening session
- */
-async stopActiveListeningSession() : Promise<Result<ActiveListeningSession | null, string>> {
+async setModelUnloadTimeout(timeout: ModelUnloadTimeout) : Promise<void> {
+    await TAURI_INVOKE("set_model_unload_timeout", { timeout });
+},
+async getModelLoadStatus() : Promise<Result<ModelLoadStatus, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("stop_active_listening_session") };
+    return { status: "ok", data: await TAURI_INVOKE("get_model_load_status") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Get the current active listening state
- */
-async getActiveListeningState() : Promise<ActiveListeningState> {
-    return await TAURI_INVOKE("get_active_listening_state");
+async unloadModelManually() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("unload_model_manually") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 },
 /**
- * Get the current active listening session info
+ * Remote speech-to-text services dictations can be sent to
  */
-async getActiveListeningSession() : Promise<ActiveListeningSession | null> {
-    return await TAURI_INVOKE("get_active_listening_session");
+async getRemoteSttProviders() : Promise<RemoteSttProvider[]> {
+    return await TAURI_INVOKE("get_remote_stt_providers");
 },
 /**
- * Check if Ollama server is available
+ * Pick the transcription backend, `local` or a provider id, for one
+ * shortcut binding or, without a binding, for every shortcut. A binding
+ * without a backend uses the default again.
  */
-async checkOllamaConnection() : Promise<Result<boolean, string>> {
+async setTranscriptionBackend(bindingId: string | null, backend: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("check_ollama_connection") };
+    return { status: "ok", data: await TAURI_INVOKE("set_transcription_backend", { bindingId, backend }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Fetch available models from Ollama
- */
-async fetchOllamaModels() : Promise<Result<OllamaModel[], string>> {
+async changeRemoteSttApiKeySetting(providerId: string, apiKey: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("fetch_ollama_models") };
+    return { status: "ok", data: await TAURI_INVOKE("change_remote_stt_api_key_setting", { providerId, apiKey }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Enable or disable active listening
- */
-async changeActiveListeningEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeRemoteSttModelSetting(providerId: string, model: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_enabled_setting", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_remote_stt_model_setting", { providerId, model }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Change the segment duration
- */
-async changeActiveListeningSegmentDurationSetting(durationSeconds: number) : Promise<Result<null, string>> {
+async changeRemoteSttBaseUrlSetting(providerId: string, baseUrl: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_segment_duration_setting", { durationSeconds }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_remote_stt_base_url_setting", { providerId, baseUrl }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
+async changeRemoteSttFallbackSetting(enabled: boolean) : Promise<void> {
+    await TAURI_INVOKE("change_remote_stt_fallback_setting", { enabled });
+},
+async changeParagraphingEnabledSetting(enabled: boolean) : Promise<void> {
+    await TAURI_INVOKE("change_paragraphing_enabled_setting", { enabled });
+},
 /**
- * Change the Ollama base URL
+ * Set the pauses, in milliseconds, that end a sentence and start a
+ * paragraph
  */
-async changeOllamaBaseUrlSetting(baseUrl: string) : Promise<Result<null, string>> {
+async changeParagraphingPausesSetting(sentencePauseMs: number, paragraphPauseMs: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_ollama_base_url_setting", { baseUrl }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_paragraphing_pauses_setting", { sentencePauseMs, paragraphPauseMs }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
+async changeSpeakerMarkersSetting(enabled: boolean) : Promise<void> {
+    await TAURI_INVOKE("change_speaker_markers_setting", { enabled });
+},
+async changeTranslationEnabledSetting(enabled: boolean) : Promise<void> {
+    await TAURI_INVOKE("change_translation_enabled_setting", { enabled });
+},
 /**
- * Change the Ollama model
+ * Pick the language dictations are translated into for one shortcut
+ * binding or, without a binding, for every shortcut. A binding without a
+ * language uses the default again; without a default, only shortcuts with
+ * their own language are translated.
  */
-async changeOllamaModelSetting(model: string) : Promise<Result<null, string>> {
+async setTranslationLanguage(bindingId: string | null, language: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_ollama_model_setting", { model }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_translation_language", { bindingId, language }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Change the context window size
+ * Pick the post-process provider, or `ollama`, and the model dictations
+ * are translated with. Without a provider the selected post-process
+ * provider is used.
  */
-async changeActiveListeningContextWindowSetting(size: number) : Promise<Result<null, string>> {
+async changeTranslationProviderSetting(providerId: string | null, model: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_context_window_setting", { size }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_translation_provider_setting", { providerId, model }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Change the audio source type for active listening
+ * Format dictations with a mode, or stop formatting them with `None`
  */
-async changeAudioSourceTypeSetting(sourceType: AudioSourceType) : Promise<Result<null, string>> {
+async setFormattingMode(modeId: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_audio_source_type_setting", { sourceType }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_formatting_mode", { modeId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Change the audio mix ratio (0.0 = microphone only, 1.0 = system audio only)
+ * Replace the formatting modes. A selected mode that was removed is
+ * unselected.
  */
-async changeAudioMixRatioSetting(mixRatio: number) : Promise<Result<null, string>> {
+async setFormattingModes(modes: FormattingMode[]) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_audio_mix_ratio_setting", { mixRatio }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_formatting_modes", { modes }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
+async resetFormattingModes() : Promise<void> {
+    await TAURI_INVOKE("reset_formatting_modes");
+},
 /**
- * Get the current audio source type
+ * Replace the transcribe profiles. The shortcuts of removed profiles are
+ * unbound, and renamed profiles' shortcuts take the new name.
  */
-async getAudioSourceType() : Promise<AudioSourceType> {
-    return await TAURI_INVOKE("get_audio_source_type");
+async setTranscribeProfiles(profiles: TranscribeProfile[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_transcribe_profiles", { profiles }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 },
 /**
- * Get the current audio mix ratio
+ * Bind a transcribe profile to a shortcut, or unbind it when `binding` is
+ * empty
  */
-async getAudioMixRatio() : Promise<number> {
-    return await TAURI_INVOKE("get_audio_mix_ratio");
+async changeTranscribeProfileBinding(profileId: string, binding: string) : Promise<Result<ShortcutBinding | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_transcribe_profile_binding", { profileId, binding }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 },
-/**
- * Get the loopback support level for the current platform
- */
-async getLoopbackSupportLevel() : Promise<LoopbackSupportLevel> {
-    return await TAURI_INVOKE("get_loopback_support_level");
+async changeCodeDictationEnabledSetting(enabled: boolean) : Promise<void> {
+    await TAURI_INVOKE("change_code_dictation_enabled_setting", { enabled });
 },
 /**
- * Check if loopback capture is supported on this platform
+ * Set the apps, matched by the start of their name, that count as code
+ * editors or terminals
  */
-async isLoopbackSupported() : Promise<boolean> {
-    return await TAURI_INVOKE("is_loopback_supported");
+async changeCodeDictationAppsSetting(apps: string[]) : Promise<void> {
+    await TAURI_INVOKE("change_code_dictation_apps_setting", { apps });
+},
+async changeCodeDictationIdentifierCaseSetting(case: IdentifierCase) : Promise<void> {
+    await TAURI_INVOKE("change_code_dictation_identifier_case_setting", { case });
+},
+async changeCodeDictationDirectPasteSetting(enabled: boolean) : Promise<void> {
+    await TAURI_INVOKE("change_code_dictation_direct_paste_setting", { enabled });
+},
+async changeVoiceCommandsEnabledSetting(enabled: boolean) : Promise<void> {
+    await TAURI_INVOKE("change_voice_commands_enabled_setting", { enabled });
 },
 /**
- * List available loopback devices
+ * Replace the voice command grammar. Phrases are compared ignoring case,
+ * so each may appear once.
  */
-async listLoopbackDevices() : Promise<Result<LoopbackDeviceInfoDto[], string>> {
+async setVoiceCommands(commands: VoiceCommand[]) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("list_loopback_devices") };
+    return { status: "ok", data: await TAURI_INVOKE("set_voice_commands", { commands }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Add a new active listening prompt
+ * Restore the built-in voice commands
  */
-async addActiveListeningPrompt(name: string, promptTemplate: string) : Promise<Result<ActiveListeningPrompt, string>> {
+async resetVoiceCommands() : Promise<void> {
+    await TAURI_INVOKE("reset_voice_commands");
+},
+async getHistoryEntries() : Promise<Result<HistoryEntry[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("add_active_listening_prompt", { name, promptTemplate }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_history_entries") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Update an existing active listening prompt
- */
-async updateActiveListeningPrompt(id: string, name: string, promptTemplate: string) : Promise<Result<null, string>> {
+async toggleHistoryEntrySaved(id: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_active_listening_prompt", { id, name, promptTemplate }) };
+    return { status: "ok", data: await TAURI_INVOKE("toggle_history_entry_saved", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Delete an active listening prompt
- */
-async deleteActiveListeningPrompt(id: string) : Promise<Result<null, string>> {
+async getAudioFilePath(fileName: string) : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_active_listening_prompt", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_audio_file_path", { fileName }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Set the selected active listening prompt
+ * Word or segment timings of an entry, for a transcript that follows and
+ * seeks the recording's playback. Empty when the engine didn't report any.
  */
-async setActiveListeningSelectedPrompt(id: string) : Promise<Result<null, string>> {
+async getHistoryEntrySegments(id: number) : Promise<Result<TranscriptSegment[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_active_listening_selected_prompt", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_history_entry_segments", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Generate a comprehensive summary from a completed session
- */
-async generateMeetingSummary(session: ActiveListeningSession) : Promise<Result<MeetingSummary, string>> {
+async deleteHistoryEntry(id: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("generate_meeting_summary", { session }) };
+    return { status: "ok", data: await TAURI_INVOKE("delete_history_entry", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Export meeting summary to different formats
- */
-async exportMeetingSummary(summary: MeetingSummary, format: string) : Promise<Result<string, string>> {
+async restoreHistoryEntry(id: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("export_meeting_summary", { summary, format }) };
+    return { status: "ok", data: await TAURI_INVOKE("restore_history_entry", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get the current Ask AI state
- */
-async getAskAiState() : Promise<AskAiState> {
-    return await TAURI_INVOKE("get_ask_ai_state");
-},
-/**
- * Check if an Ask AI session is currently active
- */
-async isAskAiActive() : Promise<boolean> {
-    return await TAURI_INVOKE("is_ask_ai_active");
-},
-/**
- * Get the current question (if any)
- */
-async getAskAiQuestion() : Promise<string | null> {
-    return await TAURI_INVOKE("get_ask_ai_question");
-},
-/**
- * Get the current response text
- */
-async getAskAiResponse() : Promise<string> {
-    return await TAURI_INVOKE("get_ask_ai_response");
-},
-/**
- * Get the current conversation
- */
-async getAskAiConversation() : Promise<AskAiConversation | null> {
-    return await TAURI_INVOKE("get_ask_ai_conversation");
-},
-/**
- * Check if we can start a new recording (idle, complete, or conversation active)
- */
-async canStartAskAiRecording() : Promise<boolean> {
-    return await TAURI_INVOKE("can_start_ask_ai_recording");
-},
-/**
- * Cancel the current Ask AI session
+ * Keep one or both of an entry flagged as a repeat and the one before it
  */
-async cancelAskAiSession() : Promise<Result<null, string>> {
+async resolveDuplicateHistoryEntry(id: number, resolution: DuplicateResolution) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("cancel_ask_ai_session") };
+    return { status: "ok", data: await TAURI_INVOKE("resolve_duplicate_history_entry", { id, resolution }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Reset Ask AI to idle state and clear conversation
+ * Rate how well an entry was transcribed, from 1 to 5, optionally with
+ * what was actually said. Ratings feed the usage report's model stats and
+ * new corrections teach the vocabulary the terms the model missed, which
+ * are returned.
  */
-async resetAskAiSession() : Promise<Result<null, string>> {
+async rateTranscription(historyId: number, rating: number, correction: string | null) : Promise<Result<string[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("reset_ask_ai_session") };
+    return { status: "ok", data: await TAURI_INVOKE("rate_transcription", { historyId, rating, correction }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Dismiss the Ask AI overlay but keep conversation for potential resume
- */
-async dismissAskAiSession() : Promise<Result<null, string>> {
+async getHistoryTrash() : Promise<Result<TrashedHistoryEntry[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("dismiss_ask_ai_session") };
+    return { status: "ok", data: await TAURI_INVOKE("get_history_trash") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Start a new conversation (clears existing conversation)
- */
-async startNewAskAiConversation() : Promise<Result<null, string>> {
+async emptyTrash() : Promise<Result<number, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("start_new_ask_ai_conversation") };
+    return { status: "ok", data: await TAURI_INVOKE("empty_trash") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Enable or disable Ask AI feature
- */
-async changeAskAiEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+async updateHistoryTrashRetentionDays(days: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_ask_ai_enabled_setting", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_history_trash_retention_days", { days }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Change Ask AI Ollama base URL
+ * Set how accidental double dictations are detected
  */
-async changeAskAiOllamaBaseUrlSetting(baseUrl: string) : Promise<Result<null, string>> {
+async updateHistoryDedup(dedup: HistoryDedupSettings) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_ask_ai_ollama_base_url_setting", { baseUrl }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_history_dedup", { dedup }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Change Ask AI Ollama model
- */
-async changeAskAiOllamaModelSetting(model: string) : Promise<Result<null, string>> {
+async updateHistoryLimit(limit: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_ask_ai_ollama_model_setting", { model }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_history_limit", { limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Change Ask AI system prompt
- */
-async changeAskAiSystemPromptSetting(prompt: string) : Promise<Result<null, string>> {
+async updateHistoryMaxSize(maxSizeMb: number | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_ask_ai_system_prompt_setting", { prompt }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_history_max_size", { maxSizeMb }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Get Ask AI settings (for display in UI)
- */
-async getAskAiSettings() : Promise<AskAiSettings> {
-    return await TAURI_INVOKE("get_ask_ai_settings");
-},
-/**
- * Save Ask AI window position and size
- */
-async saveAskAiWindowBounds(bounds: AskAiWindowBounds) : Promise<Result<null, string>> {
+async getHistoryStorageStats() : Promise<Result<HistoryStorageStats, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_ask_ai_window_bounds", { bounds }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_history_storage_stats") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Get Ask AI window position and size
- */
-async getAskAiWindowBounds() : Promise<AskAiWindowBounds> {
-    return await TAURI_INVOKE("get_ask_ai_window_bounds");
-},
-/**
- * Save a conversation to history
- */
-async saveAskAiConversationToHistory(conversation: AskAiConversation) : Promise<Result<null, string>> {
+async updateRecordingRetentionPeriod(period: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_ask_ai_conversation_to_history", { conversation }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_recording_retention_period", { period }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * List recent Ask AI conversations from history
+ * Start an active listening session
  */
-async listAskAiConversations(limit: number) : Promise<Result<AskAiConversation[], string>> {
+async startActiveListeningSession(topic: string | null) : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("list_ask_ai_conversations", { limit }) };
+    return { status: "ok", data: await TAURI_INVOKE("start_active_listening_session", { topic }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get a specific Ask AI conversation from history
+ * Stop the current active listening session
  */
-async getAskAiConversationFromHistory(id: string) : Promise<Result<AskAiConversation | null, string>> {
+async stopActiveListeningSession() : Promise<Result<ActiveListeningSession | null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_ask_ai_conversation_from_history", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("stop_active_listening_session") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Delete an Ask AI conversation from history
+ * Get the current active listening state
  */
-async deleteAskAiConversationFromHistory(id: string) : Promise<Result<null, string>> {
+async getActiveListeningState() : Promise<ActiveListeningState> {
+    return await TAURI_INVOKE("get_active_listening_state");
+},
+/**
+ * Get the current active listening session info
+ */
+async getActiveListeningSession() : Promise<ActiveListeningSession | null> {
+    return await TAURI_INVOKE("get_active_listening_session");
+},
+/**
+ * Mark the current moment of the active session as a highlight
+ */
+async markSessionHighlight() : Promise<Result<SessionHighlight, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_ask_ai_conversation_from_history", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("mark_session_highlight") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Add a document to the knowledge base
+ * Get the highlights of a session, or of the active session when none is
+ * given, with the transcript around each one
  */
-async ragAddDocument(content: string, sourceType: string, sourceId: string | null, title: string | null) : Promise<Result<number, string>> {
+async getSessionHighlights(session: ActiveListeningSession | null) : Promise<Result<HighlightExcerpt[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("rag_add_document", { content, sourceType, sourceId, title }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_session_highlights", { session }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Search the knowledge base for relevant context
+ * Check if Ollama server is available
  */
-async ragSearch(query: string, topK: number | null) : Promise<Result<SearchResult[], string>> {
+async checkOllamaConnection() : Promise<Result<boolean, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("rag_search", { query, topK }) };
+    return { status: "ok", data: await TAURI_INVOKE("check_ollama_connection") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Delete a document from the knowledge base
+ * Fetch available models from Ollama
  */
-async ragDeleteDocument(documentId: number) : Promise<Result<null, string>> {
+async fetchOllamaModels() : Promise<Result<OllamaModel[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("rag_delete_document", { documentId }) };
+    return { status: "ok", data: await TAURI_INVOKE("fetch_ollama_models") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * List all documents in the knowledge base
+ * Enable or disable active listening
  */
-async ragListDocuments() : Promise<Result<StoredDocument[], string>> {
+async changeActiveListeningEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("rag_list_documents") };
+    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_enabled_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get knowledge base statistics
+ * Change the segment duration
  */
-async ragGetStats() : Promise<Result<RagStats, string>> {
+async changeActiveListeningSegmentDurationSetting(durationSeconds: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("rag_get_stats") };
+    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_segment_duration_setting", { durationSeconds }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get the current embedding model
+ * Change the Ollama base URL
  */
-async ragGetEmbeddingModel() : Promise<Result<string, string>> {
+async changeOllamaBaseUrlSetting(baseUrl: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("rag_get_embedding_model") };
+    return { status: "ok", data: await TAURI_INVOKE("change_ollama_base_url_setting", { baseUrl }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Set the embedding model
+ * Change the Ollama model
  */
-async ragSetEmbeddingModel(model: string) : Promise<Result<null, string>> {
+async changeOllamaModelSetting(model: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("rag_set_embedding_model", { model }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_ollama_model_setting", { model }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Clear all documents from the knowledge base
+ * Change how long Ollama keeps the model loaded after a request
  */
-async ragClearAll() : Promise<Result<null, string>> {
+async changeOllamaKeepAliveSetting(keepAlive: ModelUnloadTimeout) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("rag_clear_all") };
+    return { status: "ok", data: await TAURI_INVOKE("change_ollama_keep_alive_setting", { keepAlive }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get knowledge base settings
+ * Change the context window size
  */
-async getKnowledgeBaseSettings() : Promise<Result<KnowledgeBaseSettings, string>> {
+async changeActiveListeningContextWindowSetting(size: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_knowledge_base_settings") };
+    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_context_window_setting", { size }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update knowledge base enabled setting
+ * Set the draft model for low-latency live captions (`None` to disable)
  */
-async changeKnowledgeBaseEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeActiveListeningDraftModelSetting(modelId: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_knowledge_base_enabled_setting", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_draft_model_setting", { modelId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update auto-index transcriptions setting
+ * Set the limits on LLM calls, tokens and latency per session
  */
-async changeAutoIndexTranscriptionsSetting(autoIndex: boolean) : Promise<Result<null, string>> {
+async changeActiveListeningBudgetSetting(budget: SessionBudget) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_auto_index_transcriptions_setting", { autoIndex }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_budget_setting", { budget }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update embedding model setting
+ * Set the checks that drop low-value insights
  */
-async changeKbEmbeddingModelSetting(model: string) : Promise<Result<null, string>> {
+async changeActiveListeningInsightFiltersSetting(filters: InsightFilters) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_kb_embedding_model_setting", { model }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_insight_filters_setting", { filters }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update top_k setting
+ * Set whether segments are stored with a translation, and how it's made
  */
-async changeKbTopKSetting(topK: number) : Promise<Result<null, string>> {
+async changeActiveListeningDualLanguageSetting(dualLanguage: DualLanguageSettings) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_kb_top_k_setting", { topK }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_dual_language_setting", { dualLanguage }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update similarity threshold setting
+ * Set whether sessions are stored with people's names replaced
  */
-async changeKbSimilarityThresholdSetting(threshold: number) : Promise<Result<null, string>> {
+async changeActiveListeningAnonymizeSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_kb_similarity_threshold_setting", { threshold }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_anonymize_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update use in active listening setting
+ * Set the names replaced in anonymized sessions besides the speakers'
  */
-async changeKbUseInActiveListeningSetting(useInAl: boolean) : Promise<Result<null, string>> {
+async changeActiveListeningAnonymizedNamesSetting(names: string[]) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_kb_use_in_active_listening_setting", { useInAl }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_active_listening_anonymized_names_setting", { names }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get the current suggestions settings
+ * Change the audio source type for active listening
  */
-async getSuggestionsSettings() : Promise<Result<SuggestionsSettings, string>> {
+async changeAudioSourceTypeSetting(sourceType: AudioSourceType) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_suggestions_settings") };
+    return { status: "ok", data: await TAURI_INVOKE("change_audio_source_type_setting", { sourceType }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update the suggestions settings
+ * Change the audio mix ratio (0.0 = microphone only, 1.0 = system audio only)
  */
-async updateSuggestionsSettings(settings: SuggestionsSettings) : Promise<Result<null, string>> {
+async changeAudioMixRatioSetting(mixRatio: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_suggestions_settings", { settings }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_audio_mix_ratio_setting", { mixRatio }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Enable or disable the suggestions feature
+ * Get the current audio source type
  */
-async changeSuggestionsEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
-    try {
-    return { status: "ok", data: await TAURI_INVOKE("change_suggestions_enabled_setting", { enabled }) };
-} catch (e) {
-    if(e instanceof Error) throw e;
-    else return { status: "error", error: e  as any };
-}
+async getAudioSourceType() : Promise<AudioSourceType> {
+    return await TAURI_INVOKE("get_audio_source_type");
 },
 /**
- * Get all quick responses
+ * Get the current audio mix ratio
  */
-async getQuickResponses() : Promise<Result<QuickResponse[], string>> {
+async getAudioMixRatio() : Promise<number> {
+    return await TAURI_INVOKE("get_audio_mix_ratio");
+},
+/**
+ * Get the loopback support level for the current platform
+ */
+async getLoopbackSupportLevel() : Promise<LoopbackSupportLevel> {
+    return await TAURI_INVOKE("get_loopback_support_level");
+},
+/**
+ * Check if loopback capture is supported on this platform
+ */
+async isLoopbackSupported() : Promise<boolean> {
+    return await TAURI_INVOKE("is_loopback_supported");
+},
+/**
+ * List available loopback devices
+ */
+async listLoopbackDevices() : Promise<Result<LoopbackDeviceInfoDto[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_quick_responses") };
+    return { status: "ok", data: await TAURI_INVOKE("list_loopback_devices") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get quick responses by category
+ * Add a new active listening prompt
  */
-async getQuickResponsesByCategory(category: string) : Promise<Result<QuickResponse[], string>> {
+async addActiveListeningPrompt(name: string, promptTemplate: string) : Promise<Result<ActiveListeningPrompt, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_quick_responses_by_category", { category }) };
+    return { status: "ok", data: await TAURI_INVOKE("add_active_listening_prompt", { name, promptTemplate }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Add a new quick response
+ * Update an existing active listening prompt
  */
-async addQuickResponse(response: QuickResponse) : Promise<Result<QuickResponse, string>> {
+async updateActiveListeningPrompt(id: string, name: string, promptTemplate: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("add_quick_response", { response }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_active_listening_prompt", { id, name, promptTemplate }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update an existing quick response
+ * Delete an active listening prompt
  */
-async updateQuickResponse(response: QuickResponse) : Promise<Result<QuickResponse, string>> {
+async deleteActiveListeningPrompt(id: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_quick_response", { response }) };
+    return { status: "ok", data: await TAURI_INVOKE("delete_active_listening_prompt", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Delete a quick response
+ * Set the selected active listening prompt
  */
-async deleteQuickResponse(id: string) : Promise<Result<null, string>> {
+async setActiveListeningSelectedPrompt(id: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_quick_response", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_active_listening_selected_prompt", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Toggle a quick response's enabled state
+ * Create a speaker profile
  */
-async toggleQuickResponse(id: string) : Promise<Result<boolean, string>> {
+async addSpeakerProfile(name: string, customWords: string[], defaultSpeakerId: number | null) : Promise<Result<SpeakerProfile, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("toggle_quick_response", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("add_speaker_profile", { name, customWords, defaultSpeakerId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Enable or disable RAG suggestions
+ * Update an existing speaker profile
  */
-async changeRagSuggestionsEnabled(enabled: boolean) : Promise<Result<null, string>> {
+async updateSpeakerProfile(profile: SpeakerProfile) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_rag_suggestions_enabled", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_speaker_profile", { profile }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Enable or disable LLM suggestions
+ * Delete a speaker profile
  */
-async changeLlmSuggestionsEnabled(enabled: boolean) : Promise<Result<null, string>> {
+async deleteSpeakerProfile(id: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_llm_suggestions_enabled", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("delete_speaker_profile", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update max suggestions count
+ * Assign a speaker profile to a diarized speaker of the active session, so
+ * their segments are labeled with the profile's name and corrected with its
+ * vocabulary. `None` clears the assignment.
  */
-async changeMaxSuggestions(maxSuggestions: number) : Promise<Result<null, string>> {
+async assignSpeakerProfile(speakerId: number, profileId: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_max_suggestions", { maxSuggestions }) };
+    return { status: "ok", data: await TAURI_INVOKE("assign_speaker_profile", { speakerId, profileId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update minimum confidence threshold
+ * Name a speaker of the active session. Their segments so far and their
+ * history entries are relabeled; `None` goes back to the default label.
  */
-async changeMinConfidence(minConfidence: number) : Promise<Result<null, string>> {
+async renameSpeaker(speakerId: number, name: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_min_confidence", { minConfidence }) };
+    return { status: "ok", data: await TAURI_INVOKE("rename_speaker", { speakerId, name }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
+async getDiarizationSettings() : Promise<DiarizationSettings> {
+    return await TAURI_INVOKE("get_diarization_settings");
+},
 /**
- * Update auto-dismiss on copy setting
+ * Replace the diarization settings. They apply from the next session.
  */
-async changeAutoDismissOnCopy(autoDismiss: boolean) : Promise<Result<null, string>> {
+async setDiarizationSettings(diarization: DiarizationSettings) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_auto_dismiss_on_copy", { autoDismiss }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_diarization_settings", { diarization }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update display duration setting
+ * Generate a comprehensive summary from a completed session
  */
-async changeDisplayDuration(durationSeconds: number) : Promise<Result<null, string>> {
+async generateMeetingSummary(session: ActiveListeningSession) : Promise<Result<MeetingSummary, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_display_duration", { durationSeconds }) };
+    return { status: "ok", data: await TAURI_INVOKE("generate_meeting_summary", { session }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async addToBatchQueue(paths: string[]) : Promise<Result<BatchQueueStatus, string>> {
+/**
+ * Summary of what has happened so far in the running session. It's cached
+ * and only updated with the segments transcribed since the last request.
+ */
+async getLiveSummary(sessionId: string) : Promise<Result<LiveSummary, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("add_to_batch_queue", { paths }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_live_summary", { sessionId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async startBatchProcessing() : Promise<Result<null, string>> {
+/**
+ * Split a session into titled chapters by topic
+ */
+async detectSessionChapters(session: ActiveListeningSession) : Promise<Result<Chapter[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("start_batch_processing") };
+    return { status: "ok", data: await TAURI_INVOKE("detect_session_chapters", { session }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async cancelBatchProcessing() : Promise<Result<null, string>> {
+/**
+ * Answer a question about one session from its transcript, with the
+ * supporting segments and their timestamps
+ */
+async askMeeting(session: ActiveListeningSession, question: string) : Promise<Result<MeetingAnswer, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("cancel_batch_processing") };
+    return { status: "ok", data: await TAURI_INVOKE("ask_meeting", { session, question }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getBatchStatus() : Promise<Result<BatchQueueStatus, string>> {
+/**
+ * Search all past sessions started between `since` and `until`
+ * (milliseconds) for what was said about something, returning the matching
+ * segments and, with `compose_answer`, an answer written from them
+ */
+async searchMeetingMemory(query: string, since: number | null, until: number | null, composeAnswer: boolean) : Promise<Result<MemorySearch, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_batch_status") };
+    return { status: "ok", data: await TAURI_INVOKE("search_meeting_memory", { query, since, until, composeAnswer }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async removeBatchItem(id: string) : Promise<Result<null, string>> {
+/**
+ * Re-run a finished session, or a recording of a meeting, with a different
+ * prompt or model and summarize the result
+ */
+async replayActiveListeningSession(source: ReplaySource, options: ReplayOptions) : Promise<Result<SessionReplay, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("remove_batch_item", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("replay_active_listening_session", { source, options }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async clearCompletedBatchItems() : Promise<Result<null, string>> {
+/**
+ * Export a meeting summary as Markdown, plain text, HTML, JSON or PDF,
+ * with the session's transcript appended when the session is given.
+ * 
+ * With a path the export is written there and the path is returned;
+ * otherwise the export itself is returned, which PDF doesn't allow.
+ */
+async exportMeetingSummary(summary: MeetingSummary, format: SummaryExportFormat, session: ActiveListeningSession | null, path: string | null) : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("clear_completed_batch_items") };
+    return { status: "ok", data: await TAURI_INVOKE("export_meeting_summary", { summary, format, session, path }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async extractActionItems(entryId: number) : Promise<Result<ActionItem[], string>> {
+/**
+ * List past Active Listening sessions, most recent first
+ */
+async listActiveListeningSessions(limit: number) : Promise<Result<StoredSessionInfo[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("extract_action_items", { entryId }) };
+    return { status: "ok", data: await TAURI_INVOKE("list_active_listening_sessions", { limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getActionItems(entryId: number | null) : Promise<Result<ActionItem[], string>> {
+/**
+ * Find past sessions whose topic, transcript, insights or summary contain the query
+ */
+async searchActiveListeningSessions(query: string, limit: number) : Promise<Result<StoredSessionInfo[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_action_items", { entryId }) };
+    return { status: "ok", data: await TAURI_INVOKE("search_active_listening_sessions", { query, limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async toggleActionItem(id: number, completed: boolean) : Promise<Result<null, string>> {
+/**
+ * Load a past session with its insights and summary
+ */
+async getStoredActiveListeningSession(id: string) : Promise<Result<StoredSession | null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("toggle_action_item", { id, completed }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_stored_active_listening_session", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async deleteActionItem(id: number) : Promise<Result<null, string>> {
+/**
+ * Delete a past session
+ */
+async deleteStoredActiveListeningSession(id: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_action_item", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("delete_stored_active_listening_session", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async exportActionItems(entryId: number | null, format: string) : Promise<Result<string, string>> {
+/**
+ * The names behind the placeholders of a past session that was stored
+ * anonymized, empty for other sessions
+ */
+async getSessionNameMap(id: string) : Promise<Result<NameMapping[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("export_action_items", { entryId, format }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_session_name_map", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getVocabulary() : Promise<Result<VocabularyEntry[], string>> {
+/**
+ * Get the current Ask AI state
+ */
+async getAskAiState() : Promise<AskAiState> {
+    return await TAURI_INVOKE("get_ask_ai_state");
+},
+/**
+ * Check if an Ask AI session is currently active
+ */
+async isAskAiActive() : Promise<boolean> {
+    return await TAURI_INVOKE("is_ask_ai_active");
+},
+/**
+ * Get the current question (if any)
+ */
+async getAskAiQuestion() : Promise<string | null> {
+    return await TAURI_INVOKE("get_ask_ai_question");
+},
+/**
+ * Get the current response text
+ */
+async getAskAiResponse() : Promise<string> {
+    return await TAURI_INVOKE("get_ask_ai_response");
+},
+/**
+ * Get the current conversation
+ */
+async getAskAiConversation() : Promise<AskAiConversation | null> {
+    return await TAURI_INVOKE("get_ask_ai_conversation");
+},
+/**
+ * Check if we can start a new recording (idle, complete, or conversation active)
+ */
+async canStartAskAiRecording() : Promise<boolean> {
+    return await TAURI_INVOKE("can_start_ask_ai_recording");
+},
+/**
+ * Cancel the current Ask AI session
+ */
+async cancelAskAiSession() : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_vocabulary") };
+    return { status: "ok", data: await TAURI_INVOKE("cancel_ask_ai_session") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async addVocabularyTerm(term: string, category: string | null) : Promise<Result<VocabularyEntry, string>> {
+/**
+ * Reset Ask AI to idle state and clear conversation
+ */
+async resetAskAiSession() : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("add_vocabulary_term", { term, category }) };
+    return { status: "ok", data: await TAURI_INVOKE("reset_ask_ai_session") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async removeVocabularyTerm(id: number) : Promise<Result<null, string>> {
+/**
+ * Dismiss the Ask AI overlay but keep conversation for potential resume
+ */
+async dismissAskAiSession() : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("remove_vocabulary_term", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("dismiss_ask_ai_session") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async importVocabulary(json: string) : Promise<Result<number, string>> {
+/**
+ * Start a new conversation (clears existing conversation)
+ */
+async startNewAskAiConversation() : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("import_vocabulary", { json }) };
+    return { status: "ok", data: await TAURI_INVOKE("start_new_ask_ai_conversation") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async exportVocabulary() : Promise<Result<string, string>> {
+/**
+ * Regenerate the last response in the active conversation
+ */
+async regenerateAskAiResponse() : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("export_vocabulary") };
+    return { status: "ok", data: await TAURI_INVOKE("regenerate_ask_ai_response") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get current sound detection settings
+ * Edit an earlier question, forking the conversation at that turn.
+ * Returns the ID of the new branch conversation.
  */
-async getSoundDetectionSettings() : Promise<Result<SoundDetectionSettings, string>> {
+async editAskAiQuestionAndBranch(turnId: string, question: string) : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_sound_detection_settings") };
+    return { status: "ok", data: await TAURI_INVOKE("edit_ask_ai_question_and_branch", { turnId, question }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Enable or disable sound detection
+ * Enable or disable Ask AI feature
  */
-async changeSoundDetectionEnabled(enabled: boolean) : Promise<Result<null, string>> {
+async changeAskAiEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_sound_detection_enabled", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_ask_ai_enabled_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update the detection confidence threshold
+ * Change Ask AI Ollama base URL
  */
-async changeSoundDetectionThreshold(threshold: number) : Promise<Result<null, string>> {
+async changeAskAiOllamaBaseUrlSetting(baseUrl: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_sound_detection_threshold", { threshold }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_ask_ai_ollama_base_url_setting", { baseUrl }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Update which sound categories to detect
+ * Change Ask AI Ollama model
  */
-async changeSoundDetectionCategories(categories: SoundCategory[]) : Promise<Result<null, string>> {
+async changeAskAiOllamaModelSetting(model: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_sound_detection_categories", { categories }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_ask_ai_ollama_model_setting", { model }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Enable or disable sound detection notifications
+ * Change Ask AI system prompt
  */
-async changeSoundDetectionNotification(enabled: boolean) : Promise<Result<null, string>> {
+async changeAskAiSystemPromptSetting(prompt: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_sound_detection_notification", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_ask_ai_system_prompt_setting", { prompt }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Checks if the Mac is a laptop by detecting battery presence
- * 
- * This uses pmset to check for battery information.
- * Returns true if a battery is detected (laptop), false otherwise (desktop)
+ * Enable or disable tool calling for Ask AI
  */
-async isLaptop() : Promise<Result<boolean, string>> {
+async changeAskAiToolsEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("is_laptop") };
+    return { status: "ok", data: await TAURI_INVOKE("change_ask_ai_tools_enabled_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async initializeShortcuts() : Promise<Result<null, string>> {
+/**
+ * Change which tools the Ask AI model is allowed to call
+ */
+async changeAskAiAllowedToolsSetting(tools: string[]) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("initialize_shortcuts") };
+    return { status: "ok", data: await TAURI_INVOKE("change_ask_ai_allowed_tools_setting", { tools }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async openPresentationMode() : Promise<Result<null, string>> {
+/**
+ * List all tools Ask AI can be allowed to call
+ */
+async getAskAiAvailableTools() : Promise<AskAiToolInfo[]> {
+    return await TAURI_INVOKE("get_ask_ai_available_tools");
+},
+/**
+ * Get Ask AI settings (for display in UI)
+ */
+async getAskAiSettings() : Promise<AskAiSettings> {
+    return await TAURI_INVOKE("get_ask_ai_settings");
+},
+/**
+ * Save Ask AI window position and size
+ */
+async saveAskAiWindowBounds(bounds: AskAiWindowBounds) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_presentation_mode") };
+    return { status: "ok", data: await TAURI_INVOKE("save_ask_ai_window_bounds", { bounds }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async stopHandyKeysRecording() : Promise<Result<null, string>> {
+/**
+ * Get Ask AI window position and size
+ */
+async getAskAiWindowBounds() : Promise<AskAiWindowBounds> {
+    return await TAURI_INVOKE("get_ask_ai_window_bounds");
+},
+/**
+ * Save a conversation to history
+ */
+async saveAskAiConversationToHistory(conversation: AskAiConversation) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("stop_handy_keys_recording") };
+    return { status: "ok", data: await TAURI_INVOKE("save_ask_ai_conversation_to_history", { conversation }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async startHandyKeysRecording(bindingId: string) : Promise<Result<null, string>> {
+/**
+ * List recent Ask AI conversations from history
+ */
+async listAskAiConversations(limit: number) : Promise<Result<AskAiConversation[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("start_handy_keys_recording", { bindingId }) };
+    return { status: "ok", data: await TAURI_INVOKE("list_ask_ai_conversations", { limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeKeyboardImplementationSetting(implementation: string) : Promise<Result<{ reset_bindings: string[] }, string>> {
+/**
+ * Get a specific Ask AI conversation from history
+ */
+async getAskAiConversationFromHistory(id: string) : Promise<Result<AskAiConversation | null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_keyboard_implementation_setting", { implementation }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_ask_ai_conversation_from_history", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getExportFilename(entryId: number, format: ExportFormat) : Promise<Result<string, string>> {
+/**
+ * List conversations branched from the given conversation
+ */
+async listAskAiConversationBranches(id: string) : Promise<Result<AskAiConversation[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_export_filename", { entryId, format }) };
+    return { status: "ok", data: await TAURI_INVOKE("list_ask_ai_conversation_branches", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async exportTranscription(entryId: number, format: ExportFormat) : Promise<Result<string, string>> {
+/**
+ * Get the absolute path of a saved Ask AI question recording
+ */
+async getAskAiAudioFilePath(fileName: string) : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("export_transcription", { entryId, format }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_ask_ai_audio_file_path", { fileName }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async exportTranscriptions(entryIds: number[], format: ExportFormat) : Promise<Result<string, string>> {
+/**
+ * Play back a saved Ask AI question recording on the selected output device
+ */
+async playAskAiQuestionAudio(fileName: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("export_transcriptions", { entryIds, format }) };
+    return { status: "ok", data: await TAURI_INVOKE("play_ask_ai_question_audio", { fileName }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getBatchExportFilename(format: ExportFormat) : Promise<string> {
-    return await TAURI_INVOKE("get_batch_export_filename", { format });
-},
-async ragChat(question: string, conversationContext: string | null) : Promise<Result<string, string>> {
+/**
+ * Delete an Ask AI conversation from history
+ */
+async deleteAskAiConversationFromHistory(id: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("rag_chat", { question, conversationContext }) };
+    return { status: "ok", data: await TAURI_INVOKE("delete_ask_ai_conversation_from_history", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
-}
-}
-
-/** user-defined events **/
-
-
-
-/** user-defined constants **/
-
-
-
-/** user-defined types **/
-
-export type ExportFormat = "txt" | "srt" | "vtt" | "json" | "markdown"
-
+},
 /**
- * An action item extracted from a meeting (simple form)
+ * Pin a question/answer turn to the knowledge base so it can be retrieved later.
+ * 
+ * The turn is looked up in the active conversation first, then in history.
+ * Pinning the same turn twice returns the existing document ID.
  */
-export type MeetingActionItem = {
+async pinAskAiTurnToKnowledgeBase(conversationId: string, turnId: string) : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("pin_ask_ai_turn_to_knowledge_base", { conversationId, turnId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Description of the action
+ * Add a document to the knowledge base
  */
-description: string;
+async ragAddDocument(content: string, sourceType: string, sourceId: string | null, title: string | null) : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rag_add_document", { content, sourceType, sourceId, title }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Person responsible (if mentioned)
+ * Search the knowledge base for relevant context
  */
-assignee: string | null;
+async ragSearch(query: string, topK: number | null) : Promise<Result<SearchResult[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rag_search", { query, topK }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Deadline (if mentioned)
+ * Delete a document from the knowledge base
  */
-deadline: string | null }
+async ragDeleteDocument(documentId: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rag_delete_document", { documentId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * An action item stored in history
+ * List all documents in the knowledge base
  */
-export type ActionItem = { id: number; entry_id: number; task: string; assignee: string | null; deadline: string | null; priority: string; completed: boolean; created_at: string }
+async ragListDocuments() : Promise<Result<StoredDocument[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rag_list_documents") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * A prompt template for active listening
+ * Get knowledge base statistics
  */
-export type ActiveListeningPrompt = { 
+async ragGetStats() : Promise<Result<RagStats, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rag_get_stats") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Unique identifier for the prompt
+ * Get the current embedding model
  */
-id: string; 
+async ragGetEmbeddingModel() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rag_get_embedding_model") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Display name for the prompt
+ * Set the embedding model
  */
-name: string; 
+async ragSetEmbeddingModel(model: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rag_set_embedding_model", { model }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Prompt template supporting {{transcription}}, {{previous_context}}, {{session_topic}}
+ * Clear all documents from the knowledge base
  */
-prompt_template: string; 
+async ragClearAll() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rag_clear_all") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * When this prompt was created (Unix timestamp in milliseconds)
+ * Get knowledge base settings
  */
-created_at: number; 
+async getKnowledgeBaseSettings() : Promise<Result<KnowledgeBaseSettings, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_knowledge_base_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Whether this is a built-in default prompt
+ * Update knowledge base enabled setting
  */
-is_default?: boolean; 
+async changeKnowledgeBaseEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_knowledge_base_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Category for grouping prompts in the UI
+ * Update auto-index transcriptions setting
  */
-category?: PromptCategory }
+async changeAutoIndexTranscriptionsSetting(autoIndex: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_auto_index_transcriptions_setting", { autoIndex }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Information about an active listening session
+ * Update embedding model setting
  */
-export type ActiveListeningSession = { 
+async changeKbEmbeddingModelSetting(model: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_kb_embedding_model_setting", { model }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Unique session identifier
+ * Update top_k setting
  */
-id: string; 
+async changeKbTopKSetting(topK: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_kb_top_k_setting", { topK }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Unix timestamp when session started (milliseconds)
+ * Update similarity threshold setting
  */
-started_at: number; 
+async changeKbSimilarityThresholdSetting(threshold: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_kb_similarity_threshold_setting", { threshold }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Unix timestamp when session ended (milliseconds)
+ * Update use in active listening setting
  */
-ended_at: number | null; 
+async changeKbUseInActiveListeningSetting(useInAl: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_kb_use_in_active_listening_setting", { useInAl }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * User-defined topic for this session
+ * Update the number of chunks sent per embed request
  */
-topic: string | null; 
+async changeKbEmbeddingBatchSizeSetting(batchSize: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_kb_embedding_batch_size_setting", { batchSize }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * All insights generated during this session
+ * Update the number of concurrent embed requests while indexing
  */
-insights: SessionInsight[] }
+async changeKbEmbeddingConcurrencySetting(concurrency: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_kb_embedding_concurrency_setting", { concurrency }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Settings for the Active Listening feature
+ * Update the retry count for transient embed request failures
  */
-export type ActiveListeningSettings = { 
+async changeKbEmbeddingMaxRetriesSetting(maxRetries: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_kb_embedding_max_retries_setting", { maxRetries }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Whether active listening is enabled
+ * Update the background indexing rate limit
+ */
+async changeKbIndexingMaxPerMinuteSetting(maxPerMinute: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_kb_indexing_max_per_minute_setting", { maxPerMinute }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update whether background indexing waits for Active Listening sessions to end
+ */
+async changeKbPauseIndexingDuringSessionsSetting(pause: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_kb_pause_indexing_during_sessions_setting", { pause }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update the number of attempts before a queued document is marked as failed
+ */
+async changeKbIndexingMaxAttemptsSetting(maxAttempts: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_kb_indexing_max_attempts_setting", { maxAttempts }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get the status of the background indexing queue
+ */
+async getIndexingStatus() : Promise<Result<IndexingStatus, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_indexing_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Re-queue documents that failed to index, returning how many were re-queued
+ */
+async retryFailedIndexing() : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("retry_failed_indexing") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get the current suggestions settings
+ */
+async getSuggestionsSettings() : Promise<Result<SuggestionsSettings, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_suggestions_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update the suggestions settings
+ */
+async updateSuggestionsSettings(settings: SuggestionsSettings) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_suggestions_settings", { settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Enable or disable the suggestions feature
+ */
+async changeSuggestionsEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_suggestions_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get all quick responses
+ */
+async getQuickResponses() : Promise<Result<QuickResponse[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_quick_responses") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get quick responses by category
+ */
+async getQuickResponsesByCategory(category: string) : Promise<Result<QuickResponse[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_quick_responses_by_category", { category }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Add a new quick response
+ */
+async addQuickResponse(response: QuickResponse) : Promise<Result<QuickResponse, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_quick_response", { response }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update an existing quick response
+ */
+async updateQuickResponse(response: QuickResponse) : Promise<Result<QuickResponse, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_quick_response", { response }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Delete a quick response
+ */
+async deleteQuickResponse(id: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_quick_response", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Toggle a quick response's enabled state
+ */
+async toggleQuickResponse(id: string) : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("toggle_quick_response", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Enable or disable RAG suggestions
+ */
+async changeRagSuggestionsEnabled(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_rag_suggestions_enabled", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Enable or disable LLM suggestions
+ */
+async changeLlmSuggestionsEnabled(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_llm_suggestions_enabled", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update max suggestions count
+ */
+async changeMaxSuggestions(maxSuggestions: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_max_suggestions", { maxSuggestions }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update minimum confidence threshold
+ */
+async changeMinConfidence(minConfidence: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_min_confidence", { minConfidence }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update auto-dismiss on copy setting
+ */
+async changeAutoDismissOnCopy(autoDismiss: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_auto_dismiss_on_copy", { autoDismiss }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update display duration setting
+ */
+async changeDisplayDuration(durationSeconds: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_display_duration", { durationSeconds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update the similarity threshold for treating suggestions as duplicates
+ */
+async changeSuggestionDedupThreshold(threshold: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_suggestion_dedup_threshold", { threshold }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update how long shown suggestions are remembered for deduplication
+ */
+async changeSuggestionDedupWindow(windowSeconds: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_suggestion_dedup_window", { windowSeconds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update the cooldown between suggestions of the same category
+ */
+async changeSuggestionCategoryCooldown(cooldownSeconds: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_suggestion_category_cooldown", { cooldownSeconds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update the maximum number of suggestions shown per minute
+ */
+async changeMaxSuggestionsPerMinute(maxPerMinute: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_max_suggestions_per_minute", { maxPerMinute }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Enable or disable feedback-based suggestion ranking
+ */
+async changeSuggestionFeedbackLearning(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_suggestion_feedback_learning", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Record that a suggestion was accepted (copied/used) or dismissed
+ */
+async recordSuggestionFeedback(sessionId: string | null, suggestion: Suggestion, accepted: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("record_suggestion_feedback", { sessionId, suggestion, accepted }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get accept/reject statistics for suggestions
+ */
+async getSuggestionStats() : Promise<Result<SuggestionStats, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_suggestion_stats") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Clear all suggestion feedback and reset ranking adjustments
+ */
+async resetSuggestionFeedback() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("reset_suggestion_feedback") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update how warnings of a given severity are routed
+ */
+async changeWarningRoute(severity: WarningSeverity, route: WarningRoute) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_warning_route", { severity, route }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update the webhook URL that receives routed warnings
+ */
+async changeWarningWebhookUrl(url: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_warning_webhook_url", { url }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getBackupSettings() : Promise<Result<BackupSettings, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_backup_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeBackupEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_backup_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeBackupIntervalSetting(hours: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_backup_interval_setting", { hours }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeBackupFolderSetting(folder: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_backup_folder_setting", { folder }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeBackupKeepCountSetting(count: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_backup_keep_count_setting", { count }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeBackupIncludeAudioSetting(include: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_backup_include_audio_setting", { include }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Create a backup now
+ */
+async createBackup() : Promise<Result<BackupInfo, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_backup") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listBackups() : Promise<Result<BackupInfo[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_backups") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Restore a backup and restart the app so every manager reloads its data
+ */
+async restoreFromBackup(id: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restore_from_backup", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async addToBatchQueue(paths: string[]) : Promise<Result<BatchQueueStatus, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_to_batch_queue", { paths }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Queue a file offered by the clipboard watcher and start the batch, or
+ * let the running batch pick it up
+ */
+async transcribeClipboardFile(path: string) : Promise<Result<BatchQueueStatus, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("transcribe_clipboard_file", { path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async startBatchProcessing() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_batch_processing") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async cancelBatchProcessing() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cancel_batch_processing") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getBatchStatus() : Promise<Result<BatchQueueStatus, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_batch_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async removeBatchItem(id: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_batch_item", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async clearCompletedBatchItems() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_completed_batch_items") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setBatchItemFormat(id: string, format: ExportFormat | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_batch_item_format", { id, format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Output of the current batch: the formats each item is written in and
+ * where, overriding the batch output settings
+ */
+async getBatchOutputConfig() : Promise<Result<BatchOutputConfig, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_batch_output_config") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the output of the current batch. Items still waiting pick it up too.
+ */
+async configureBatchOutput(config: BatchOutputConfig) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("configure_batch_output", { config }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getBatchOutputSettings() : Promise<Result<BatchOutputSettings, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_batch_output_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeBatchNamingTemplate(template: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_batch_naming_template", { template }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeBatchOutputDestination(destination: BatchOutputDestination, outputRoot: string | null, mirrorSourceRoot: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_batch_output_destination", { destination, outputRoot, mirrorSourceRoot }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeBatchDefaultFormat(format: ExportFormat) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_batch_default_format", { format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set whether copied audio files are offered for transcription
+ */
+async changeClipboardWatchSetting(clipboardWatch: ClipboardWatchSettings) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_clipboard_watch_setting", { clipboardWatch }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getSyncSettings() : Promise<Result<SyncSettings, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_sync_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeSyncEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_sync_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeSyncFolderSetting(folder: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_sync_folder_setting", { folder }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeSyncConflictPolicySetting(policy: SyncConflictPolicy) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_sync_conflict_policy_setting", { policy }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Sync immediately instead of waiting for the next change check
+ */
+async syncNow() : Promise<Result<SyncReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("sync_now") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getSyncStatus() : Promise<Result<SyncStatus, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_sync_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Generate a usage report for a month and write it under the app data
+ * directory. Defaults to the current month. Returns the report's path.
+ */
+async generateMonthlyReport(year: number | null, month: number | null, format: ReportFormat, pricePerMillionTokens: number | null) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("generate_monthly_report", { year, month, format, pricePerMillionTokens }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Check microphone permission and whether any input device is present
+ */
+async checkMicrophoneCapability() : Promise<MicrophoneCheck> {
+    return await TAURI_INVOKE("check_microphone_capability");
+},
+/**
+ * Check accessibility permission, needed to paste transcriptions on macOS
+ */
+async checkAccessibilityCapability() : Promise<PermissionStatus> {
+    return await TAURI_INVOKE("check_accessibility_capability");
+},
+/**
+ * Check whether system notifications may be shown
+ */
+async checkNotificationCapability() : Promise<Result<PermissionStatus, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_notification_capability") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Check whether system audio can be captured for active listening
+ */
+async checkLoopbackCapability() : Promise<LoopbackCheck> {
+    return await TAURI_INVOKE("check_loopback_capability");
+},
+/**
+ * Check free space in the models directory against the model catalogue
+ */
+async checkModelDiskSpace() : Promise<Result<DiskSpaceCheck, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_model_disk_space") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Check whether the configured Ollama server is running
+ */
+async checkOllamaPresence() : Promise<Result<OllamaCheck, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_ollama_presence") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Run every onboarding check and return a combined report
+ */
+async getCapabilityReport() : Promise<Result<CapabilityReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_capability_report") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Open the system settings page where microphone access is granted
+ */
+async openMicrophoneSettings() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_microphone_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Check the configured channel for an update the user should be offered
+ */
+async checkForUpdate(manual: boolean) : Promise<Result<UpdateInfo | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_for_update", { manual }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Changelog of the update found by the last check, if any
+ */
+async getPendingUpdateChangelog() : Promise<Result<UpdateInfo | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_pending_update_changelog") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Never offer this version again; newer versions are still offered
+ */
+async skipUpdateVersion(version: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("skip_update_version", { version }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Stop prompting for updates for the given number of hours
+ */
+async deferUpdate(hours: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("defer_update", { hours }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Download and install the pending update, emitting progress as it downloads
+ */
+async installPendingUpdate() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("install_pending_update") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async extractActionItems(entryId: number) : Promise<Result<ActionItem[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("extract_action_items", { entryId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getActionItems(entryId: number | null) : Promise<Result<ActionItem[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_action_items", { entryId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Link open action items to the parts of a session that discussed them.
+ * `mark_progress` defaults to the Active Listening setting.
+ */
+async linkActionItemMentions(session: ActiveListeningSession, markProgress: boolean | null) : Promise<Result<ActionItemMention[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("link_action_item_mentions", { session, markProgress }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async toggleActionItem(id: number, completed: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("toggle_action_item", { id, completed }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteActionItem(id: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_action_item", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async exportActionItems(entryId: number | null, format: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_action_items", { entryId, format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getVocabulary() : Promise<Result<VocabularyEntry[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_vocabulary") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async addVocabularyTerm(term: string, category: string | null) : Promise<Result<VocabularyEntry, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_vocabulary_term", { term, category }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async removeVocabularyTerm(id: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_vocabulary_term", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async importVocabulary(json: string) : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_vocabulary", { json }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async exportVocabulary() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_vocabulary") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeWebhookEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_webhook_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeWebhookUrlSetting(url: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_webhook_url_setting", { url }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeWebhookAuthHeaderSetting(authHeader: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_webhook_auth_header_setting", { authHeader }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeWebhookEventsSetting(events: WebhookEvent[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_webhook_events_setting", { events }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeWebhookMaxRetriesSetting(retries: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_webhook_max_retries_setting", { retries }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Send a test payload to the configured URL and return the response status
+ */
+async testWebhook() : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("test_webhook") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeAutomationHooksSetting(automationHooks: AutomationHookSettings) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_automation_hooks_setting", { automationHooks }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Run a hook once with `input` and return what it printed
+ */
+async testAutomationHook(hook: AutomationHook, input: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("test_automation_hook", { hook, input }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getPlugins() : Promise<PluginInfo[]> {
+    return await TAURI_INVOKE("get_plugins");
+},
+/**
+ * Load the plugins folder again, picking up new and edited scripts
+ */
+async reloadPlugins() : Promise<Result<PluginInfo[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("reload_plugins") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changePluginSettings(pluginSettings: PluginSettings) : Promise<Result<PluginInfo[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_plugin_settings", { pluginSettings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async runPluginAction(bindingId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("run_plugin_action", { bindingId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async openPluginsFolder() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_plugins_folder") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Bind a plugin action to a shortcut, or unbind it when `binding` is empty
+ */
+async changePluginActionBinding(id: string, binding: string) : Promise<Result<ShortcutBinding | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_plugin_action_binding", { id, binding }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Turn the API server on or off. The first time it's turned on a token is
+ * generated.
+ */
+async changeApiServerEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_api_server_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeApiServerPortSetting(port: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_api_server_port_setting", { port }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replace the API token, so clients using the old one are locked out
+ */
+async regenerateApiServerToken() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("regenerate_api_server_token") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeRemoteControlEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_remote_control_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeRemoteControlPortSetting(port: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_remote_control_port_setting", { port }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Turn the mDNS announcement on or off
+ */
+async changeRemoteControlAdvertiseSetting(advertise: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_remote_control_advertise_setting", { advertise }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Show a new pairing code for a device to enter
+ */
+async startRemotePairing() : Promise<Result<RemotePairing, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_remote_pairing") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async cancelRemotePairing() : Promise<void> {
+    await TAURI_INVOKE("cancel_remote_pairing");
+},
+/**
+ * Forget a paired device, so its token stops working
+ */
+async revokeRemoteDevice(id: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("revoke_remote_device", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Turn the browser bridge on or off. The first time it's turned on a token
+ * is generated.
+ */
+async changeBrowserBridgeEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_browser_bridge_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeBrowserBridgePortSetting(port: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_browser_bridge_port_setting", { port }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the companion extension IDs the native messaging host accepts. The
+ * host has to be installed again for a change to reach the browsers.
+ */
+async setBrowserExtensionIds(ids: string[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_browser_extension_ids", { ids }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setBrowserSiteRules(rules: SiteRule[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_browser_site_rules", { rules }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Install the native messaging host for the installed browsers, returning
+ * where it was installed
+ */
+async installBrowserNativeHost() : Promise<Result<string[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("install_browser_native_host") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getBrowserBridgeStatus() : Promise<BrowserBridgeStatus> {
+    return await TAURI_INVOKE("get_browser_bridge_status");
+},
+/**
+ * Palette items matching `query`, best first. An empty query lists the
+ * actions, models and prompts.
+ */
+async queryPalette(query: string, limit: number | null) : Promise<Result<PaletteItem[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("query_palette", { query, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Execute the action of a palette item returned by `query_palette`
+ */
+async executePaletteItem(action: PaletteAction) : Promise<Result<PaletteOutcome, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("execute_palette_item", { action }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replace the wake word settings and load or unload its model. A model
+ * that fails to load leaves the wake word off.
+ */
+async setWakeWordSettings(wakeWord: WakeWordSettings) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_wake_word_settings", { wakeWord }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Binding ids a signal can be mapped to
+ */
+async getSignalBindingOptions() : Promise<string[]> {
+    return await TAURI_INVOKE("get_signal_binding_options");
+},
+/**
+ * Map `signal` to a binding id, or unmap it with `None`
+ */
+async changeSignalBindingSetting(signal: UnixSignal, bindingId: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_signal_binding_setting", { signal, bindingId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * List the built-in and user export templates, optionally for one kind
+ */
+async getExportTemplates(kind: ExportKind | null) : Promise<ExportTemplate[]> {
+    return await TAURI_INVOKE("get_export_templates", { kind });
+},
+/**
+ * Create a user template, or update it if its id exists. Templates without
+ * an id get a new one.
+ */
+async saveExportTemplate(template: ExportTemplate) : Promise<Result<ExportTemplate, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_export_template", { template }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteExportTemplate(id: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_export_template", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeExportWebhookUrlSetting(url: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_export_webhook_url_setting", { url }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Render an object with a template without writing it anywhere, for previews
+ */
+async renderExport(source: ExportSource, templateId: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("render_export", { source, templateId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Render an object with a template and write it to a file, the clipboard
+ * or a webhook. Returns where the export went.
+ */
+async exportWithTemplate(source: ExportSource, templateId: string, destination: ExportDestination) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_with_template", { source, templateId, destination }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get current sound detection settings
+ */
+async getSoundDetectionSettings() : Promise<Result<SoundDetectionSettings, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_sound_detection_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Enable or disable sound detection
+ */
+async changeSoundDetectionEnabled(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_sound_detection_enabled", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update the detection confidence threshold
+ */
+async changeSoundDetectionThreshold(threshold: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_sound_detection_threshold", { threshold }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update which sound categories to detect
+ */
+async changeSoundDetectionCategories(categories: SoundCategory[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_sound_detection_categories", { categories }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Enable or disable sound detection notifications
+ */
+async changeSoundDetectionNotification(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_sound_detection_notification", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getNotificationSettings() : Promise<NotificationSettings> {
+    return await TAURI_INVOKE("get_notification_settings");
+},
+/**
+ * Replace the notification settings
+ */
+async setNotificationSettings(notifications: NotificationSettings) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_notification_settings", { notifications }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Checks if the Mac is a laptop by detecting battery presence
+ * 
+ * This uses pmset to check for battery information.
+ * Returns true if a battery is detected (laptop), false otherwise (desktop)
+ */
+async isLaptop() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("is_laptop") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async initializeShortcuts() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("initialize_shortcuts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async openPresentationMode() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_presentation_mode") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async stopHandyKeysRecording() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("stop_handy_keys_recording") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async startHandyKeysRecording(bindingId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_handy_keys_recording", { bindingId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeKeyboardImplementationSetting(implementation: string) : Promise<Result<{ reset_bindings: string[] }, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_keyboard_implementation_setting", { implementation }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getExportFilename(entryId: number, format: ExportFormat) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_export_filename", { entryId, format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async exportTranscription(entryId: number, format: ExportFormat) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_transcription", { entryId, format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async exportTranscriptions(entryIds: number[], format: ExportFormat) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_transcriptions", { entryIds, format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getBatchExportFilename(format: ExportFormat) : Promise<string> {
+    return await TAURI_INVOKE("get_batch_export_filename", { format });
+},
+async ragChat(question: string, conversationContext: string | null) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rag_chat", { question, conversationContext }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+}
+}
+
+/** user-defined events **/
+
+
+export const events = __makeEvents__<{
+showOverlay: ShowOverlay,
+hideOverlay: HideOverlay,
+micLevel: MicLevel,
+recordingProgress: RecordingProgress,
+recordingLimitWarning: RecordingLimitWarning,
+microphoneMuteChanged: MicrophoneMuteChanged,
+permissionLost: PermissionLost,
+dockStateChanged: DockStateChanged,
+vadTestPoint: VadTestPoint,
+vadTestStopped: VadTestStopped,
+handyKeysEvent: HandyKeysEvent,
+settingsChanged: SettingsChanged,
+settingsConfigApplied: SettingsConfigApplied,
+settingsSynced: SettingsSynced,
+checkForUpdates: CheckForUpdates,
+updateDownloadProgress: UpdateDownloadProgress,
+modelStateChanged: ModelStateChanged,
+modelDownloadProgress: ModelDownloadProgress,
+modelDownloadComplete: ModelDownloadComplete,
+modelExtractionStarted: ModelExtractionStarted,
+modelExtractionCompleted: ModelExtractionCompleted,
+modelExtractionFailed: ModelExtractionFailed,
+transcriptionFinalized: TranscriptionFinalized,
+transcriptionPartial: TranscriptionPartial,
+transcriptionProgress: TranscriptionProgress,
+promptRouted: PromptRouted,
+formattingModeChanged: FormattingModeChanged,
+historyUpdated: HistoryUpdated,
+backupCompleted: BackupCompleted,
+batchItemStatus: BatchItemStatus,
+batchComplete: BatchComplete,
+clipboardAudioCopied: ClipboardAudioCopied,
+indexingStatusChanged: IndexingStatusChanged,
+ragIndexingProgress: RagIndexingProgress,
+activeListeningStateChanged: ActiveListeningStateChanged,
+activeListeningSegment: ActiveListeningSegment,
+activeListeningInsight: ActiveListeningInsight,
+activeListeningHighlight: ActiveListeningHighlight,
+activeListeningBudgetExceeded: ActiveListeningBudgetExceeded,
+sessionReplayProgress: SessionReplayProgress,
+suggestionPartial: SuggestionPartial,
+suggestions: Suggestions,
+askAiStateChanged: AskAiStateChanged,
+askAiResponse: AskAiResponse,
+askAiToolCall: AskAiToolCall,
+pluginEmitted: PluginEmitted,
+remoteDevicePaired: RemoteDevicePaired,
+logEntries: LogEntries,
+accessPolicyViolation: AccessPolicyViolation
+}>({
+showOverlay: "show-overlay",
+hideOverlay: "hide-overlay",
+micLevel: "mic-level",
+recordingProgress: "recording-progress",
+recordingLimitWarning: "recording-limit-warning",
+microphoneMuteChanged: "microphone-mute-changed",
+permissionLost: "permission-lost",
+dockStateChanged: "dock-state-changed",
+vadTestPoint: "vad-test-point",
+vadTestStopped: "vad-test-stopped",
+handyKeysEvent: "handy-keys-event",
+settingsChanged: "settings-changed",
+settingsConfigApplied: "settings-config-applied",
+settingsSynced: "settings-synced",
+checkForUpdates: "check-for-updates",
+updateDownloadProgress: "update-download-progress",
+modelStateChanged: "model-state-changed",
+modelDownloadProgress: "model-download-progress",
+modelDownloadComplete: "model-download-complete",
+modelExtractionStarted: "model-extraction-started",
+modelExtractionCompleted: "model-extraction-completed",
+modelExtractionFailed: "model-extraction-failed",
+transcriptionFinalized: "transcription-finalized",
+transcriptionPartial: "transcription-partial",
+transcriptionProgress: "transcription-progress",
+promptRouted: "prompt-routed",
+formattingModeChanged: "formatting-mode-changed",
+historyUpdated: "history-updated",
+backupCompleted: "backup-completed",
+batchItemStatus: "batch-item-status",
+batchComplete: "batch-complete",
+clipboardAudioCopied: "clipboard-audio-copied",
+indexingStatusChanged: "indexing-status-changed",
+ragIndexingProgress: "rag-indexing-progress",
+activeListeningStateChanged: "active-listening-state-changed",
+activeListeningSegment: "active-listening-segment",
+activeListeningInsight: "active-listening-insight",
+activeListeningHighlight: "active-listening-highlight",
+activeListeningBudgetExceeded: "active-listening-budget-exceeded",
+sessionReplayProgress: "session-replay-progress",
+suggestionPartial: "suggestion-partial",
+suggestions: "suggestions",
+askAiStateChanged: "ask-ai-state-changed",
+askAiResponse: "ask-ai-response",
+askAiToolCall: "ask-ai-tool-call",
+pluginEmitted: "plugin-emitted",
+remoteDevicePaired: "remote-device-paired",
+logEntries: "log-entries",
+accessPolicyViolation: "access-policy-violation"
+})
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+export type AccessKind = "host" | "path"
+/**
+ * How the access policy treats hosts and paths outside the allowlists
+ */
+export type AccessPolicyMode = 
+/**
+ * No checks
+ */
+"off" | 
+/**
+ * Log and report violations but let the access through
+ */
+"audit" | 
+/**
+ * Block violations
+ */
+"enforce"
+/**
+ * Settings for the access policy
+ */
+export type AccessPolicySettings = { mode?: AccessPolicyMode; 
+/**
+ * Hosts that may be contacted. An entry also allows its subdomains;
+ * loopback addresses are always allowed.
+ */
+allowed_hosts?: string[]; 
+/**
+ * Folders that may be written to, besides the app data dir
+ */
+allowed_paths?: string[] }
+/**
+ * A host or path outside the access policy was accessed
+ */
+export type AccessPolicyViolation = AccessViolation
+export type AccessViolation = { 
+/**
+ * Unix timestamp in milliseconds
+ */
+timestamp_ms: number; kind: AccessKind; 
+/**
+ * The host or path that was not allowed
+ */
+target: string; 
+/**
+ * Whether the access was blocked, or only reported
+ */
+blocked: boolean }
+export type ActionItem = { id: number; entry_id: number; task: string; assignee: string | null; deadline: string | null; priority: string; completed: boolean; created_at: string; 
+/**
+ * Later sessions that discussed this item, oldest first
+ */
+mentions?: ActionItemMention[]; 
+/**
+ * Open and not discussed for `STALE_AFTER_DAYS`
+ */
+stale?: boolean }
+/**
+ * A later session that discussed an action item
+ */
+export type ActionItemMention = { id: number; action_item_id: number; session_id: string; 
+/**
+ * The segment that mentioned the item
+ */
+excerpt: string; 
+/**
+ * How closely the segment matched the item (0-1)
+ */
+similarity: number; progress: MentionProgress; 
+/**
+ * Unix timestamp of the segment in milliseconds
+ */
+mentioned_at: number }
+/**
+ * Event payload for a session budget limit being reached
+ */
+export type ActiveListeningBudgetEvent = { session_id: string; limit: BudgetLimit; 
+/**
+ * LLM calls made in the session so far
+ */
+llm_calls: number; 
+/**
+ * Estimated tokens used in the session so far
+ */
+tokens: number }
+/**
+ * A limit of the session's LLM budget was reached
+ */
+export type ActiveListeningBudgetExceeded = ActiveListeningBudgetEvent
+export type ActiveListeningHighlight = ActiveListeningHighlightEvent
+/**
+ * Event payload for a highlight marked during a session
+ */
+export type ActiveListeningHighlightEvent = { session_id: string; highlight: SessionHighlight }
+export type ActiveListeningInsight = ActiveListeningInsightEvent
+/**
+ * Event payload for active listening insight (streaming)
+ */
+export type ActiveListeningInsightEvent = { session_id: string; chunk: string; done: boolean }
+/**
+ * A prompt template for active listening
+ */
+export type ActiveListeningPrompt = { 
+/**
+ * Unique identifier for the prompt
+ */
+id: string; 
+/**
+ * Display name for the prompt
+ */
+name: string; 
+/**
+ * Prompt template supporting {{transcription}}, {{previous_context}}, {{session_topic}}
+ */
+prompt_template: string; 
+/**
+ * When this prompt was created (Unix timestamp in milliseconds)
+ */
+created_at: number; 
+/**
+ * Whether this is a built-in default prompt
+ */
+is_default?: boolean; 
+/**
+ * Category for grouping prompts in the UI
+ */
+category?: PromptCategory }
+export type ActiveListeningSegment = ActiveListeningSegmentEvent
+/**
+ * Event payload for active listening segment
+ */
+export type ActiveListeningSegmentEvent = { session_id: string; transcription: string; timestamp: number; 
+/**
+ * Speaker ID (0 = primary/you, 1+ = others)
+ */
+speaker_id: number | null; 
+/**
+ * Human-readable speaker label
+ */
+speaker_label: string | null; 
+/**
+ * Whether this is a draft from the draft model. The main model's
+ * transcription follows with the same timestamp and replaces it.
+ */
+draft: boolean }
+/**
+ * Information about an active listening session
+ */
+export type ActiveListeningSession = { 
+/**
+ * Unique session identifier
+ */
+id: string; 
+/**
+ * Unix timestamp when session started (milliseconds)
+ */
+started_at: number; 
+/**
+ * Unix timestamp when session ended (milliseconds)
+ */
+ended_at: number | null; 
+/**
+ * User-defined topic for this session
+ */
+topic: string | null; 
+/**
+ * All insights generated during this session
+ */
+insights: SessionInsight[]; 
+/**
+ * Moments marked with the highlight shortcut
+ */
+highlights?: SessionHighlight[]; 
+/**
+ * Names given to speakers during the session, by speaker ID
+ */
+speaker_names?: Partial<{ [key in number]: string }> }
+/**
+ * Settings for the Active Listening feature
+ */
+export type ActiveListeningSettings = { 
+/**
+ * Whether active listening is enabled
+ */
+enabled?: boolean; 
+/**
+ * Duration of each audio segment in seconds before transcription
+ */
+segment_duration_seconds?: number; 
+/**
+ * Ollama server base URL
+ */
+ollama_base_url?: string; 
+/**
+ * Ollama model to use for generating insights
+ */
+ollama_model?: string; 
+/**
+ * How long Ollama keeps the model loaded after a request. The model is
+ * loaded ahead when a session starts, unless it's unloaded immediately.
+ */
+ollama_keep_alive?: ModelUnloadTimeout; 
+/**
+ * Custom prompts for active listening
+ */
+prompts?: ActiveListeningPrompt[]; 
+/**
+ * Currently selected prompt ID
+ */
+selected_prompt_id?: string | null; 
+/**
+ * Number of previous summaries to keep for context
+ */
+context_window_size?: number; 
+/**
+ * Audio source type for capturing audio
+ */
+audio_source_type?: AudioSourceType; 
+/**
+ * Settings for audio mixing when using Mixed mode
+ */
+audio_mix_settings?: AudioMixSettings; 
+/**
+ * Mark open action items completed when a later session says they're done
+ */
+mark_action_item_progress?: boolean; 
+/**
+ * Known speakers with their own vocabulary
+ */
+speaker_profiles?: SpeakerProfile[]; 
+/**
+ * Small, fast model transcribing each segment first for the live
+ * captions and suggestions. The selected model still transcribes the
+ * segment for the record and replaces the draft. `None` disables drafts.
+ */
+draft_model_id?: string | null; 
+/**
+ * Limits on LLM calls, tokens and latency per session, so cloud
+ * providers can't run up unexpected costs
+ */
+budget?: SessionBudget; 
+/**
+ * Post-filters dropping vacuous or generic insights
+ */
+insight_filters?: InsightFilters; 
+/**
+ * Translation stored and exported alongside each segment
+ */
+dual_language?: DualLanguageSettings; 
+/**
+ * Replace people's names with placeholders in stored sessions, keeping
+ * the names encrypted on this device
+ */
+anonymize_sessions?: boolean; 
+/**
+ * Names replaced in anonymized sessions besides the speakers' and the
+ * speaker profiles', as confirmed by the user
+ */
+anonymized_names?: string[] }
+/**
+ * State of the active listening session
+ */
+export type ActiveListeningState = 
+/**
+ * No active session
+ */
+"idle" | 
+/**
+ * Listening and accumulating audio
+ */
+"listening" | 
+/**
+ * Processing a segment (transcribing + Ollama)
+ */
+"processing" | 
+/**
+ * Error state
+ */
+"error"
+export type ActiveListeningStateChanged = ActiveListeningStateEvent
+/**
+ * Event payload for session state changes
+ */
+export type ActiveListeningStateEvent = { state: ActiveListeningState; session_id: string | null; error: string | null }
+/**
+ * Settings for the local HTTP API
+ */
+export type ApiServerSettings = { enabled?: boolean; 
+/**
+ * Port on 127.0.0.1 the server listens on
+ */
+port?: number; 
+/**
+ * Bearer token requests must carry; generated when the server is first
+ * enabled
+ */
+token?: string | null }
+export type AppSettings = ({ push_to_talk: boolean; start_hidden?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; update_channel?: UpdateChannel; 
+/**
+ * Version the user chose to skip; newer versions are still offered
+ */
+skipped_update_version?: string | null; 
+/**
+ * Unix timestamp until which automatic update prompts are deferred
+ */
+update_deferred_until?: number | null; 
+/**
+ * Stable 0-99 bucket deciding when staged rollouts reach this install
+ */
+update_rollout_bucket?: number | null; mute_while_recording?: boolean; append_trailing_space?: boolean; app_language?: string; 
+/**
+ * Hide overlay from screen capture/sharing (Zoom, Teams, etc.)
+ * Enabled by default for privacy during screen sharing
+ */
+private_overlay?: boolean; 
+/**
+ * Show the input level on the tray icon while recording
+ */
+tray_level_indicator?: boolean; 
+/**
+ * Speech probability above which the VAD keeps a frame
+ */
+vad_threshold?: number; 
+/**
+ * With the microphone always on, skip processing quiet input while not
+ * recording
+ */
+low_power_idle?: boolean; 
+/**
+ * Input RMS that wakes the full audio pipeline in low-power idle
+ */
+idle_wake_threshold?: number; 
+/**
+ * In on-demand mode, open the microphone as soon as the first modifier
+ * of a transcribe shortcut is pressed
+ */
+prearm_microphone?: boolean; 
+/**
+ * With the microphone always on, start each recording with the audio
+ * heard just before the shortcut
+ */
+retroactive_capture?: boolean; 
+/**
+ * How much audio retroactive capture keeps, in seconds
+ */
+retroactive_capture_seconds?: number; 
+/**
+ * Drop audio that doesn't match the enrolled voice before transcribing
+ */
+primary_speaker_only?: boolean; 
+/**
+ * Voiceprint similarity (-1 to 1) audio needs to count as the user's
+ */
+primary_speaker_threshold?: number; 
+/**
+ * Voiceprint bands of the enrolled primary user
+ */
+voice_profile?: number[] | null; 
+/**
+ * Remove the app's own feedback sounds from the microphone input
+ */
+echo_cancellation?: boolean; 
+/**
+ * Scale recordings to a steady level before they're transcribed, so
+ * quiet microphones still get picked up
+ */
+gain_normalization?: boolean; 
+/**
+ * Level speech is normalized to, in dBFS
+ */
+gain_target_dbfs?: number; 
+/**
+ * Show live text in the overlay while recording
+ */
+streaming_preview?: boolean; 
+/**
+ * Without push-to-talk, end a recording once nothing has been said for
+ * a while, e.g. after walking away from it
+ */
+silence_auto_stop?: boolean; 
+/**
+ * How long the VAD has to hear no speech before the recording ends
+ */
+silence_auto_stop_seconds?: number; 
+/**
+ * Stop and transcribe recordings that run past a length limit, so a
+ * forgotten recording can't grow for hours
+ */
+recording_limit?: boolean; recording_limit_minutes?: number }) & { bindings: Partial<{ [key in string]: ShortcutBinding }>; audio_feedback: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; selected_model?: string; always_on_microphone?: boolean; selected_microphone?: string | null; 
+/**
+ * Stable id of the selected microphone; the name is kept for display
+ * and as a fallback when the id is unknown
+ */
+selected_microphone_id?: string | null; clamshell_microphone?: string | null; clamshell_microphone_id?: string | null; selected_output_device?: string | null; selected_output_device_id?: string | null; translate_to_english?: boolean; selected_language?: string; overlay_position?: OverlayPosition; debug_mode?: boolean; 
+/**
+ * Read-only guest mode: nothing is written to history, the knowledge base or disk
+ */
+guest_mode?: boolean; log_level?: LogLevel; 
+/**
+ * File log level overrides by module, see `log_levels`
+ */
+log_module_levels?: Partial<{ [key in string]: LogLevel }>; custom_words?: string[]; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; 
+/**
+ * Cap on the total size of stored recordings in MB; `None` means no cap.
+ * Enforced on top of the retention period by evicting the oldest unsaved entries.
+ */
+history_max_size_mb?: number | null; 
+/**
+ * Days deleted history entries stay in the trash before being purged; 0 deletes immediately
+ */
+history_trash_retention_days?: number; 
+/**
+ * Detection of accidental double dictations
+ */
+history_dedup?: HistoryDedupSettings; paste_method?: PasteMethod; paste_delay_ms?: number; clipboard_handling?: ClipboardHandling; post_process_enabled?: boolean; post_process_provider_id?: string; post_process_providers?: PostProcessProvider[]; post_process_api_keys?: Partial<{ [key in string]: string }>; post_process_models?: Partial<{ [key in string]: string }>; post_process_prompts?: LLMPrompt[]; post_process_selected_prompt_id?: string | null; 
+/**
+ * Dictations starting with a route's phrase have it stripped and are
+ * post-processed with its prompt, even when post-processing is off
+ */
+post_process_routes?: PromptRoute[]; active_listening?: ActiveListeningSettings; diarization?: DiarizationSettings; ask_ai?: AskAiSettings; knowledge_base?: KnowledgeBaseSettings; suggestions?: SuggestionsSettings; sound_detection?: SoundDetectionSettings; experimental_enabled?: boolean; keyboard_implementation?: string; 
+/**
+ * Denoise captured audio before it's transcribed
+ */
+noise_suppression?: NoiseSuppressionSettings; notifications?: NotificationSettings; batch_output?: BatchOutputSettings; 
+/**
+ * Offer copied audio file paths for batch transcription
+ */
+clipboard_watch?: ClipboardWatchSettings; export?: ExportSettings; transcription_backend?: TranscriptionBackendSettings; backup?: BackupSettings; sync?: SyncSettings; docking?: DockingSettings; 
+/**
+ * Overrides for certain times, e.g. quiet hours
+ */
+schedule?: ScheduleSettings; webhooks?: WebhookSettings; 
+/**
+ * User programs run on the dictation at set points of the pipeline
+ */
+automation_hooks?: AutomationHookSettings; 
+/**
+ * Rhai scripts adding pipeline stages, suggestions and actions
+ */
+plugins?: PluginSettings; paragraphing?: ParagraphingSettings; 
+/**
+ * Dictations pasted in another language than they were spoken in
+ */
+translation?: TranslationSettings; api_server?: ApiServerSettings; 
+/**
+ * Push-to-talk and live captions from a paired phone or browser
+ */
+remote_control?: RemoteControlSettings; code_dictation?: CodeDictationSettings; 
+/**
+ * Spoken commands like "new line" carried out before pasting
+ */
+voice_commands?: VoiceCommandSettings; 
+/**
+ * Output format dictations are post-processed into, like an email
+ */
+formatting?: FormattingSettings; 
+/**
+ * Dictating into web forms through the companion browser extension
+ */
+browser_bridge?: BrowserBridgeSettings; 
+/**
+ * Extra transcribe shortcuts, each with its own language, post-process
+ * prompt and paste method
+ */
+transcribe_profiles?: TranscribeProfile[]; 
+/**
+ * Saying a keyword to start dictating without a shortcut
+ */
+wake_word?: WakeWordSettings; signals?: SignalSettings; access_policy?: AccessPolicySettings }
+/**
+ * An Ask AI conversation consisting of multiple turns
+ */
+export type AskAiConversation = { 
+/**
+ * Unique identifier for the conversation
+ */
+id: string; 
+/**
+ * All Q&A turns in this conversation
+ */
+turns: ConversationTurn[]; 
+/**
+ * Unix timestamp when conversation was created
+ */
+created_at: number; 
+/**
+ * Unix timestamp when conversation was last updated
+ */
+updated_at: number; 
+/**
+ * Auto-generated title from first question
+ */
+title: string | null; 
+/**
+ * Conversation this one was branched from, if any
+ */
+parent_id?: string | null; 
+/**
+ * Number of parent turns carried over when branching
+ */
+branch_turn_index?: number | null }
+export type AskAiResponse = AskAiResponseEvent
+/**
+ * Event payload for Ask AI response streaming
+ */
+export type AskAiResponseEvent = { chunk: string; done: boolean }
+/**
+ * Settings for the Ask AI feature
+ */
+export type AskAiSettings = { 
+/**
+ * Whether Ask AI feature is enabled
+ */
+enabled?: boolean; 
+/**
+ * Ollama server base URL
+ */
+ollama_base_url?: string; 
+/**
+ * Ollama model to use for generating responses
+ */
+ollama_model?: string; 
+/**
+ * System prompt for the AI assistant
+ */
+system_prompt?: string; 
+/**
+ * Saved window width for the Ask AI overlay
+ */
+window_width?: number | null; 
+/**
+ * Saved window height for the Ask AI overlay
+ */
+window_height?: number | null; 
+/**
+ * Saved window X position for the Ask AI overlay
+ */
+window_x?: number | null; 
+/**
+ * Saved window Y position for the Ask AI overlay
+ */
+window_y?: number | null; 
+/**
+ * Let the model call whitelisted local tools while answering
+ */
+tools_enabled?: boolean; 
+/**
+ * Names of the tools the model is allowed to call
+ */
+allowed_tools?: string[] }
+/**
+ * State of the Ask AI session
+ */
+export type AskAiState = 
+/**
+ * No active session
+ */
+"idle" | 
+/**
+ * Recording the user's question
+ */
+"recording" | 
+/**
+ * Transcribing the recorded audio
+ */
+"transcribing" | 
+/**
+ * Generating AI response
+ */
+"generating" | 
+/**
+ * Response complete, waiting for follow-up
+ */
+"complete" | 
+/**
+ * Conversation active, waiting for follow-up question
+ */
+"conversation_active" | 
+/**
+ * Error occurred
+ */
+"error"
+export type AskAiStateChanged = AskAiStateEvent
+/**
+ * Event payload for Ask AI state changes
+ */
+export type AskAiStateEvent = { state: AskAiState; question: string | null; error: string | null; 
+/**
+ * Full conversation for UI display
+ */
+conversation: AskAiConversation | null }
+export type AskAiToolCall = AskAiToolCallEvent
+/**
+ * Event payload emitted when the model invokes a tool
+ */
+export type AskAiToolCallEvent = { name: string; arguments: string; result: string }
+/**
+ * Tool description for display in the settings UI
+ */
+export type AskAiToolInfo = { name: string; description: string }
+/**
+ * Window position and size for Ask AI overlay
+ */
+export type AskAiWindowBounds = { width: number | null; height: number | null; x: number | null; y: number | null }
+export type AudioDevice = { index: string; 
+/**
+ * Stable identifier, see `CpalDeviceInfo::id`
+ */
+id: string; name: string; is_default: boolean }
+/**
+ * Settings for audio source mixing when using Mixed mode
+ */
+export type AudioMixSettings = { 
+/**
+ * Mix ratio: 0.0 = microphone only, 1.0 = system audio only, 0.5 = equal mix
+ */
+mix_ratio?: number }
+/**
+ * Audio source type for Active Listening
+ * Determines where audio is captured from for transcription
+ */
+export type AudioSourceType = 
+/**
+ * Microphone input only (current default behavior)
+ */
+"microphone" | 
+/**
+ * System audio output only (loopback capture)
+ */
+"system_audio" | 
+/**
+ * Both microphone and system audio mixed together
+ */
+"mixed"
+/**
+ * A program run at one stage of the pipeline
+ */
+export type AutomationHook = { id: string; name: string; stage: HookStage; 
+/**
+ * Program to run, either a path or a name looked up on `PATH`
+ */
+command: string; 
+/**
+ * Arguments passed as they are, without a shell
+ */
+args?: string[]; enabled?: boolean; 
+/**
+ * How long the program may run before it's killed
+ */
+timeout_ms?: number; 
+/**
+ * Pass Dictum's whole environment on; otherwise the program only sees
+ * the basics like `PATH` and `HOME`, plus `env`
+ */
+inherit_env?: boolean; 
+/**
+ * Extra environment variables
+ */
+env?: Partial<{ [key in string]: string }> }
+/**
+ * Settings for automation hooks
+ */
+export type AutomationHookSettings = { 
+/**
+ * Whether any hooks run
+ */
+enabled?: boolean; 
+/**
+ * Hooks run in this order within a stage, each getting the output of
+ * the one before
+ */
+hooks?: AutomationHook[] }
+export type BackupCompleted = BackupInfo
+/**
+ * A backup available for restore
+ */
+export type BackupInfo = { id: string; created_at: number; app_version: string; files: string[]; includes_audio: boolean; size_bytes: number }
+/**
+ * Settings for automatic backups
+ */
+export type BackupSettings = { 
+/**
+ * Whether scheduled backups are enabled; off until the user opts in
+ */
+enabled?: boolean; 
+/**
+ * Hours between scheduled backups
+ */
+interval_hours?: number; 
+/**
+ * Folder backups are written to; defaults to `backups` in the app data dir
+ */
+folder?: string | null; 
+/**
+ * Number of backups kept before the oldest are rotated out
+ */
+keep_count?: number; 
+/**
+ * Also copy recordings, which can make backups much larger
+ */
+include_audio?: boolean }
+export type BatchComplete = BatchQueueStatus
+export type BatchItem = { id: string; file_name: string; file_path: string; status: JobStatus; progress: number; error: string | null; duration_seconds: number | null; 
+/**
+ * Per-item format override; falls back to the batch output default
+ */
+output_format?: ExportFormat | null; 
+/**
+ * Resolved output file, set once the item completes
+ */
+output_path?: string | null; 
+/**
+ * Every file written for the item, one per format
+ */
+output_paths?: string[]; 
+/**
+ * Output chosen for the batch the item belongs to
+ */
+batch_output?: BatchOutputConfig }
+export type BatchItemStatus = BatchProgressEvent
+/**
+ * Output chosen for one batch, overriding the batch output settings for its
+ * items
+ */
+export type BatchOutputConfig = { 
+/**
+ * Formats each item is written in, one file per format. Empty uses the
+ * default format.
+ */
+formats?: ExportFormat[]; 
+/**
+ * Folder the files are written to instead of the configured destination
+ */
+output_dir?: string | null }
+/**
+ * Where batch output files are placed
+ */
+export type BatchOutputDestination = 
+/**
+ * Next to the source audio file
+ */
+"same_directory" | 
+/**
+ * Directly inside the output root
+ */
+"output_folder" | 
+/**
+ * Inside the output root, recreating the source file's directory tree
+ */
+"mirror_tree"
+/**
+ * Settings for batch transcription output
+ */
+export type BatchOutputSettings = { 
+/**
+ * File name template. Supports {{basename}}, {{model}}, {{date}} and {{ext}};
+ * the format's extension is appended when {{ext}} is not used.
+ */
+naming_template?: string; 
+/**
+ * Where output files are placed
+ */
+destination?: BatchOutputDestination; 
+/**
+ * Output root for the OutputFolder and MirrorTree destinations
+ */
+output_root?: string | null; 
+/**
+ * Directory the mirrored tree is taken relative to. When unset, or when a
+ * source file lies outside it, the file's full directory path is mirrored.
+ */
+mirror_source_root?: string | null; 
+/**
+ * Format used for items without a per-item override
+ */
+default_format?: ExportFormat }
+export type BatchProgressEvent = { item_id: string; status: JobStatus; progress: number; total_items: number; completed_items: number; error: string | null; duration_seconds: number | null }
+export type BatchQueueStatus = { items: BatchItem[]; total: number; completed: number; failed: number; is_processing: boolean }
+export type BindingResponse = { success: boolean; binding: ShortcutBinding | null; error: string | null }
+/**
+ * Settings for the browser extension bridge
+ */
+export type BrowserBridgeSettings = { enabled?: boolean; 
+/**
+ * Port on 127.0.0.1 the native messaging host connects to
+ */
+port?: number; 
+/**
+ * Token the native messaging host authenticates with; generated when
+ * the bridge is first enabled
+ */
+token?: string | null; 
+/**
+ * IDs of the companion extension installs allowed to use the native
+ * messaging host. Chrome IDs are 32 letters, Firefox IDs contain an `@`.
+ */
+extension_ids?: string[]; site_rules?: SiteRule[] }
+/**
+ * Whether any extension is connected and what it has focused
+ */
+export type BrowserBridgeStatus = { listening: boolean; connections: number; focus: BrowserFocus | null }
+/**
+ * The page focused in a browser with the extension installed
+ */
+export type BrowserFocus = { url: string; host: string; title: string; 
+/**
+ * Whether the focused element takes text
+ */
+editable: boolean }
+/**
+ * The limit of a session budget that was reached
+ */
+export type BudgetLimit = "llm_calls" | "tokens" | 
+/**
+ * An insight took longer than allowed for one segment and was dropped.
+ * Later segments still get insights.
+ */
+"segment_latency"
+/**
+ * Everything onboarding needs to know about this machine
+ */
+export type CapabilityReport = { platform: string; microphone: MicrophoneCheck; accessibility: PermissionStatus; notifications: PermissionStatus; loopback: LoopbackCheck; disk_space: DiskSpaceCheck; ollama: OllamaCheck }
+/**
+ * A stretch of a session about one topic
+ */
+export type Chapter = { title: string; 
+/**
+ * Start, in milliseconds from the start of the session
+ */
+start_ms: number; 
+/**
+ * End, in milliseconds from the start of the session
+ */
+end_ms: number; 
+/**
+ * Index of the chapter's first insight in the session
+ */
+first_segment: number; 
+/**
+ * Index of the chapter's last insight in the session
+ */
+last_segment: number }
+export type CheckForUpdates = null
+/**
+ * An audio file was copied while the clipboard is watched
+ */
+export type ClipboardAudioCopied = ClipboardAudioEvent
+/**
+ * An audio file copied to the clipboard, offered for transcription
+ */
+export type ClipboardAudioEvent = { path: string; file_name: string }
+export type ClipboardHandling = "dont_modify" | "copy_to_clipboard"
+/**
+ * Settings for the clipboard watcher
+ */
+export type ClipboardWatchSettings = { 
+/**
+ * Whether the clipboard is watched
+ */
+enabled?: boolean; 
+/**
+ * File extensions, without the dot, of the files that are offered
+ */
+extensions?: string[]; 
+/**
+ * How long the clipboard must hold the same path before it's offered,
+ * so a burst of copies only offers the last one
+ */
+debounce_ms?: number }
+/**
+ * Settings for dictating into code editors and terminals
+ */
+export type CodeDictationSettings = { enabled?: boolean; 
+/**
+ * Apps treated as code editors or terminals, matched against the start
+ * of the focused app's lowercased name, or of the focused site's host
+ * when the browser extension is connected
+ */
+apps?: string[]; 
+/**
+ * Case used for "variable name" phrases
+ */
+identifier_case?: IdentifierCase; 
+/**
+ * Type the text directly instead of using the configured paste method
+ */
+prefer_direct_paste?: boolean }
+/**
+ * A single turn in a conversation (question + response pair)
+ */
+export type ConversationTurn = { 
+/**
+ * Unique identifier for the turn
+ */
+id: string; 
+/**
+ * The transcribed question
+ */
+question: string; 
+/**
+ * The AI response
+ */
+response: string; 
+/**
+ * Unix timestamp when this turn was created
+ */
+timestamp: number; 
+/**
+ * Optional reference to the audio file for this turn
+ */
+audio_file_name: string | null; 
+/**
+ * Earlier responses to this question that were replaced by regeneration
+ */
+alternate_responses?: string[] }
+export type CustomSounds = { start: boolean; stop: boolean }
+/**
+ * How speakers are told apart
+ */
+export type DiarizationBackend = 
+/**
+ * Turn-taking and loudness changes; fine for two people
+ */
+"energy" | 
+/**
+ * Clustering of speaker embeddings; for three or more people
+ */
+"embedding"
+/**
+ * Detection quality for one diarization config
+ */
+export type DiarizationScore = { min_silence_duration_ms: number; energy_change_threshold: number; 
+/**
+ * Share of detected speaker changes that were real
+ */
+precision: number; 
+/**
+ * Share of real speaker changes that were detected
+ */
+recall: number; f1: number }
+/**
+ * Settings for speaker diarization
+ */
+export type DiarizationSettings = { backend?: DiarizationBackend; 
+/**
+ * ONNX speaker embedding model for the embedding backend. Without one,
+ * voices are compared by their spectral shape.
+ */
+model_path?: string | null; 
+/**
+ * How similar two stretches of speech must be to count as the same
+ * speaker (0.0-1.0)
+ */
+similarity_threshold?: number; 
+/**
+ * Most speakers told apart in one session
+ */
+max_speakers?: number }
+export type DiskSpaceCheck = { status: DiskSpaceStatus; free_mb: number | null; smallest_model_mb: number; largest_model_mb: number }
+/**
+ * Whether there is room to download transcription models
+ */
+export type DiskSpaceStatus = 
+/**
+ * Every available model fits
+ */
+"sufficient" | 
+/**
+ * Only some of the smaller models fit
+ */
+"limited_to_small_models" | 
+/**
+ * Not even the smallest model fits
+ */
+"insufficient" | 
+/**
+ * Free space could not be determined
+ */
+"unknown"
+/**
+ * Document metadata for RAG storage
+ */
+export type DocMetadata = { 
+/**
+ * Type of source: "transcription", "upload", "note"
+ */
+source_type: string; 
+/**
+ * Optional reference ID (e.g., history entry ID, file path)
+ */
+source_id: string | null; 
+/**
+ * Document title or description
+ */
+title: string | null }
+/**
+ * Overrides applied while the computer is in a dock state. Unset fields
+ * keep the regular setting.
+ */
+export type DockOverrides = { 
+/**
+ * Microphone to use, by name; ignored if the device isn't connected
+ */
+microphone?: string | null; 
+/**
+ * Output device for feedback sounds, by name
+ */
+output_device?: string | null; audio_feedback?: boolean | null }
+/**
+ * Overrides for one dock state
+ */
+export type DockRule = { state: DockState; overrides?: DockOverrides }
+/**
+ * How the computer is currently set up
+ */
+export type DockState = 
+/**
+ * Only the built-in display is in use
+ */
+"undocked" | 
+/**
+ * An external display is connected and the lid is open
+ */
+"docked" | 
+/**
+ * The lid is closed and only external displays are in use
+ */
+"clamshell"
+export type DockStateChanged = DockState
+/**
+ * Settings for dock-dependent overrides
+ */
+export type DockingSettings = { 
+/**
+ * Whether dock rules are applied
+ */
+enabled?: boolean; 
+/**
+ * At most one rule per dock state; the first one wins
+ */
+rules?: DockRule[] }
+export type DownloadProgress = { model_id: string; downloaded: number; total: number; percentage: number }
+/**
+ * Keep a translation next to the original transcription of each segment,
+ * for meetings held in more than one language
+ */
+export type DualLanguageSettings = { enabled?: boolean; 
+/**
+ * Language the segments are translated into
+ */
+target_language?: string; method?: TranslationMethod }
+/**
+ * What to do with an entry flagged as a duplicate
+ */
+export type DuplicateResolution = 
+/**
+ * Move the duplicate to the trash
+ */
+"keep_original" | 
+/**
+ * Move the original to the trash and keep the duplicate
+ */
+"keep_duplicate" | 
+/**
+ * Keep both entries and drop the flag
+ */
+"keep_both"
+export type EngineType = "Whisper" | "Parakeet" | "Moonshine" | "SenseVoice" | 
+/**
+ * Kaldi models run through Vosk, only available in builds with the
+ * `vosk` feature
+ */
+"Vosk"
+/**
+ * Where a rendered export goes
+ */
+export type ExportDestination = 
+/**
+ * A file, usually picked in a save dialog
+ */
+{ type: "file"; path: string } | { type: "clipboard" } | 
+/**
+ * POSTed as JSON; the webhook from the export settings when no URL is given
+ */
+{ type: "webhook"; url: string | null }
+/**
+ * Supported export formats for transcriptions
+ */
+export type ExportFormat = "txt" | "srt" | "vtt" | "json" | "markdown"
+/**
+ * Kind of object an export template renders
+ */
+export type ExportKind = 
+/**
+ * A dictation from the history
+ */
+"transcript" | 
+/**
+ * An Active Listening meeting summary
+ */
+"meeting_summary" | 
+/**
+ * Action items from the history
+ */
+"action_items" | 
+/**
+ * An Ask AI conversation
+ */
+"ask_ai_conversation"
+/**
+ * Settings for templated exports
+ */
+export type ExportSettings = { 
+/**
+ * Templates created by the user, in addition to the built-in ones
+ */
+templates?: ExportTemplate[]; 
+/**
+ * Webhook used when an export to a webhook doesn't name its own URL
+ */
+webhook_url?: string | null }
+/**
+ * An object to export
+ */
+export type ExportSource = { type: "transcript"; entry_id: number } | 
+/**
+ * Summaries aren't always stored, so the one on screen is passed in
+ */
+{ type: "meeting_summary"; summary: MeetingSummary } | 
+/**
+ * Action items of one history entry, or all of them
+ */
+{ type: "action_items"; entry_id: number | null } | { type: "ask_ai_conversation"; conversation_id: string }
+/**
+ * A minijinja template that turns one kind of object into text
+ */
+export type ExportTemplate = { id: string; name: string; kind: ExportKind; 
+/**
+ * Template source
+ */
+body: string; 
+/**
+ * Extension of files exported with this template, without the dot
+ */
+file_extension: string }
+/**
+ * Notification settings of one feature
+ */
+export type FeatureNotificationSettings = { enabled?: boolean; 
+/**
+ * Notifications less important than this are dropped
+ */
+min_severity?: NotificationSeverity }
+/**
+ * A format dictations can be post-processed into
+ */
+export type FormattingMode = { id: string; name: string; 
+/**
+ * Post-process prompt, with `${output}` standing for the dictation
+ */
+prompt: string }
+/**
+ * A different formatting mode was picked, with its id, or `None` when
+ * formatting was turned off
+ */
+export type FormattingModeChanged = string | null
+/**
+ * Settings for formatting modes
+ */
+export type FormattingSettings = { 
+/**
+ * The mode dictations are formatted with, if any
+ */
+selected_mode?: string | null; modes?: FormattingMode[] }
+/**
+ * Keys pressed while a shortcut is being recorded with HandyKeys
+ */
+export type HandyKeysEvent = { 
+/**
+ * Currently pressed modifier keys
+ */
+modifiers: string[]; 
+/**
+ * The key that was pressed (if any)
+ */
+key: string | null; 
+/**
+ * Whether this is a key down event
+ */
+is_key_down: boolean; 
+/**
+ * The full hotkey string (e.g., "option+space")
+ */
+hotkey_string: string }
+export type HideOverlay = null
+/**
+ * A highlight with the transcript around it
+ */
+export type HighlightExcerpt = { 
+/**
+ * Milliseconds from the start of the session
+ */
+offset_ms: number; transcript: string }
+/**
+ * Settings for detecting consecutive duplicate dictations
+ */
+export type HistoryDedupSettings = { 
+/**
+ * Whether near-duplicates are flagged in the history
+ */
+enabled?: boolean; 
+/**
+ * How soon after the previous dictation a new one counts as a repeat
+ */
+window_seconds?: number; 
+/**
+ * Similarity from 0 to 1 at which two transcriptions are near-duplicates
+ */
+similarity_threshold?: number; 
+/**
+ * Don't save a dictation that repeats the previous one word for word
+ */
+discard_exact_duplicates?: boolean }
+export type HistoryEntry = { id: number; file_name: string; timestamp: number; saved: boolean; title: string; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null; 
+/**
+ * Who said it, for Active Listening segments
+ */
+speaker_label: string | null; 
+/**
+ * How well it was transcribed, from 1 to 5, if the user rated it
+ */
+rating: number | null; 
+/**
+ * What was actually said, if the user corrected the transcription
+ */
+correction: string | null; 
+/**
+ * The entry dictated just before that this one nearly repeats
+ */
+duplicate_of?: number | null; 
+/**
+ * Name of the formatting mode the dictation was formatted with
+ */
+formatting_mode?: string | null }
+/**
+ * Disk usage of stored recordings
+ */
+export type HistoryStorageStats = { entry_count: number; saved_count: number; total_bytes: number; 
+/**
+ * Bytes held by saved entries, which are never evicted by the size cap
+ */
+saved_bytes: number; max_bytes: number | null }
+export type HistoryUpdated = null
+/**
+ * Where in the pipeline a hook runs
+ */
+export type HookStage = 
+/**
+ * Right after transcription, before post-processing; the output
+ * replaces the transcription
+ */
+"post_transcribe" | 
+/**
+ * Just before pasting; the output replaces the text that's pasted
+ */
+"pre_paste" | 
+/**
+ * After pasting; the output is ignored
+ */
+"post_paste"
+/**
+ * How spoken identifiers are written when dictating code
+ */
+export type IdentifierCase = "snake" | "camel"
+/**
+ * Snapshot of the background indexing queue
+ */
+export type IndexingStatus = { 
+/**
+ * Documents waiting to be indexed (including ones scheduled for retry)
+ */
+pending: number; 
+/**
+ * Documents that exhausted their retry attempts
+ */
+failed: number; 
+/**
+ * Whether a document is being indexed right now
+ */
+in_progress: boolean; 
+/**
+ * Whether indexing is held back by interactive LLM work
+ */
+paused: boolean; 
+/**
+ * Error from the most recent failed attempt, cleared on success
+ */
+last_error: string | null }
+export type IndexingStatusChanged = IndexingStatus
+/**
+ * Checks that drop low-value insights before they're shown or stored.
+ * A dropped insight is generated again up to `max_retries` times.
+ */
+export type InsightFilters = { enabled?: boolean; 
+/**
+ * Shortest insight kept, in characters. 0 keeps any length.
+ */
+min_length?: number; 
+/**
+ * Insights containing any of these phrases, ignoring case, are dropped
+ */
+banned_phrases?: string[]; 
+/**
+ * Insights whose embedding is at least this similar to the transcript's
+ * only restate it and are dropped. 0 disables the check.
+ */
+max_transcript_similarity?: number; 
+/**
+ * Ollama model embedding insights and transcripts for the similarity check
+ */
+embedding_model?: string; max_retries?: number }
+export type JobStatus = "Queued" | "Decoding" | "Transcribing" | "Completed" | "Failed" | "Cancelled" | 
+/**
+ * Was in progress when the app exited; picked up again on the next run
+ */
+"Interrupted"
+/**
+ * Settings for the Knowledge Base feature
+ */
+export type KnowledgeBaseSettings = { 
+/**
+ * Whether knowledge base is enabled
+ */
+enabled?: boolean; 
+/**
+ * Automatically index transcriptions from Active Listening
+ */
+auto_index_transcriptions?: boolean; 
+/**
+ * Embedding model to use (Ollama model name)
+ */
+embedding_model?: string; 
+/**
+ * Number of context chunks to retrieve per query
+ */
+top_k?: number; 
+/**
+ * Minimum similarity threshold for including results (0.0-1.0)
+ */
+similarity_threshold?: number; 
+/**
+ * Use RAG context in Active Listening prompts
+ */
+use_in_active_listening?: boolean; 
+/**
+ * Number of chunks sent to Ollama per embed request
+ */
+embedding_batch_size?: number; 
+/**
+ * Maximum number of embed requests in flight while indexing a document
+ */
+embedding_concurrency?: number; 
+/**
+ * Retries for embed requests that fail with a transient error
+ */
+embedding_max_retries?: number; 
+/**
+ * Maximum documents the background indexing queue processes per minute
+ */
+indexing_max_per_minute?: number; 
+/**
+ * Hold background indexing while an Active Listening session is running
+ */
+pause_indexing_during_sessions?: boolean; 
+/**
+ * Attempts before a queued document is marked as failed
+ */
+indexing_max_attempts?: number }
+export type LLMPrompt = { id: string; name: string; prompt: string; 
+/**
+ * Provider to post-process with instead of the selected one
+ */
+provider_id?: string | null; 
+/**
+ * Model to use instead of the provider's configured model
+ */
+model?: string | null }
+/**
+ * What has happened so far in a running session
+ */
+export type LiveSummary = { session_id: string; summary: string; 
+/**
+ * Segments of the session the summary covers
+ */
+segment_count: number; 
+/**
+ * When the summary was last updated (milliseconds)
+ */
+generated_at: number }
+/**
+ * New lines of the log file while following it
+ */
+export type LogEntries = LogEntry[]
+/**
+ * One parsed log record
+ */
+export type LogEntry = { 
+/**
+ * Unix timestamp in milliseconds (log timestamps are UTC)
+ */
+timestamp_ms: number; level: LogLevel; 
+/**
+ * Module path the record was logged from
+ */
+module: string; message: string }
+/**
+ * Filters for [`query`]. Unset fields match everything.
+ */
+export type LogFilter = { 
+/**
+ * Least severe level to include
+ */
+min_level: LogLevel | null; 
+/**
+ * Case-insensitive substring of the module path
+ */
+module: string | null; 
+/**
+ * Case-insensitive substring of the message
+ */
+search: string | null; 
+/**
+ * Earliest timestamp to include, in Unix milliseconds
+ */
+since_ms: number | null; 
+/**
+ * Latest timestamp to include, in Unix milliseconds
+ */
+until_ms: number | null }
+export type LogLevel = "trace" | "debug" | "info" | "warn" | "error"
+/**
+ * A page of log entries, newest first
+ */
+export type LogPage = { entries: LogEntry[]; 
+/**
+ * Number of entries matching the filter across all pages
+ */
+total: number; 
+/**
+ * Offset of the next page, if there is one
+ */
+next_offset: number | null }
+export type LoopbackCheck = { support_level: LoopbackSupportLevel; device_count: number }
+/**
+ * Loopback device information for the frontend
+ */
+export type LoopbackDeviceInfoDto = { id: string; name: string; is_default: boolean }
+/**
+ * Loopback support level for the frontend
+ */
+export type LoopbackSupportLevel = 
+/**
+ * Full native support for loopback capture
+ */
+"native" | 
+/**
+ * Requires additional software/virtual audio device
+ */
+"requires_virtual_device" | 
+/**
+ * Not supported on this platform
+ */
+"not_supported"
+/**
+ * An action item extracted from a meeting
+ */
+export type MeetingActionItem = { 
+/**
+ * Description of the action
+ */
+description: string; 
+/**
+ * Person responsible (if mentioned)
+ */
+assignee: string | null; 
+/**
+ * Deadline (if mentioned)
+ */
+deadline: string | null }
+export type MeetingAnswer = { session_id: string; question: string; answer: string; 
+/**
+ * Supporting segments, in the order they were said
+ */
+sources: MeetingAnswerSource[] }
+/**
+ * A transcript segment the answer is based on
+ */
+export type MeetingAnswerSource = { 
+/**
+ * Index of the segment's insight in the session
+ */
+segment_index: number; 
+/**
+ * Start, in milliseconds from the start of the session
+ */
+start_ms: number; 
+/**
+ * End, in milliseconds from the start of the session
+ */
+end_ms: number; speaker_label: string | null; text: string; 
+/**
+ * Similarity to the question (0-1, higher is better)
+ */
+similarity: number }
+/**
+ * Comprehensive meeting summary generated from a session
+ */
+export type MeetingSummary = { 
+/**
+ * Session ID this summary is for
+ */
+session_id: string; 
+/**
+ * Brief executive summary (2-3 sentences)
+ */
+executive_summary: string; 
+/**
+ * Key decisions made during the meeting
+ */
+decisions: string[]; 
+/**
+ * Action items with optional assignees and deadlines
+ */
+action_items: MeetingActionItem[]; 
+/**
+ * Main topics discussed
+ */
+topics: string[]; 
+/**
+ * Suggested follow-up questions
+ */
+follow_ups: string[]; 
+/**
+ * Total duration in minutes
+ */
+duration_minutes: number; 
+/**
+ * When this summary was generated
+ */
+generated_at: number; 
+/**
+ * Chapters of sessions long enough to be split by topic
+ */
+chapters?: Chapter[]; 
+/**
+ * Highlighted moments with the transcript around them
+ */
+highlights?: HighlightExcerpt[] }
+/**
+ * A segment of a past session that matches the search
+ */
+export type MemoryMatch = { session_id: string; session_topic: string | null; 
+/**
+ * When the session started (milliseconds)
+ */
+session_started_at: number; 
+/**
+ * Index of the segment's insight in the session
+ */
+segment_index: number; 
+/**
+ * Start, in milliseconds from the start of the session
+ */
+start_ms: number; speaker: string | null; text: string; 
+/**
+ * Share of the search's keywords the segment contains (0-1)
+ */
+keyword_score: number; 
+/**
+ * Similarity to the search (0-1), when the knowledge base found it
+ */
+similarity: number | null }
+export type MemorySearch = { query: string; 
+/**
+ * Best matches first
+ */
+matches: MemoryMatch[]; 
+/**
+ * Answer composed from the matches, when asked for
+ */
+answer: string | null }
+/**
+ * What a session said about an action item
+ */
+export type MentionProgress = "mentioned" | "done"
+/**
+ * Spectrum levels of the microphone input
+ */
+export type MicLevel = number[]
+export type MicrophoneCheck = { permission: PermissionStatus; input_device_count: number; has_default_device: boolean }
+export type MicrophoneMuteChanged = boolean
+/**
+ * Id of the downloaded model
+ */
+export type ModelDownloadComplete = string
+export type ModelDownloadProgress = DownloadProgress
+export type ModelExtractionCompleted = string
+export type ModelExtractionFailed = { model_id: string; error: string }
+export type ModelExtractionStarted = string
+export type ModelInfo = { id: string; name: string; description: string; filename: string; url: string | null; size_mb: number; is_downloaded: boolean; is_downloading: boolean; partial_size: number; is_directory: boolean; engine_type: EngineType; accuracy_score: number; speed_score: number; is_custom?: boolean; is_recommended?: boolean; supported_languages?: string[]; supports_translation?: boolean }
+export type ModelLoadStatus = { is_loaded: boolean; current_model: string | null }
+export type ModelStateChanged = ModelStateEvent
+export type ModelStateEvent = { event_type: string; model_id: string | null; model_name: string | null; error: string | null }
+export type ModelUnloadTimeout = "never" | "immediately" | "min_2" | "min_5" | "min_10" | "min_15" | "hour_1" | "sec_5"
+/**
+ * A name in an anonymized session and the placeholder that replaced it
+ */
+export type NameMapping = { name: string; placeholder: string }
+/**
+ * Which audio sources are denoised
+ */
+export type NoiseSuppressionSettings = { 
+/**
+ * Denoise the microphone
+ */
+microphone?: boolean; 
+/**
+ * Denoise captured system audio
+ */
+system_audio?: boolean }
+/**
+ * Settings for system notifications
+ */
+export type NotificationSettings = { 
+/**
+ * Master switch for every system notification
+ */
+enabled?: boolean; sound_detection?: FeatureNotificationSettings; 
+/**
+ * Off by default, since suggestions are already shown in the overlay
+ */
+suggestions?: FeatureNotificationSettings; warnings?: FeatureNotificationSettings; action_items?: FeatureNotificationSettings; clipboard?: FeatureNotificationSettings; quiet_hours?: QuietHours; 
+/**
+ * After a notification, further ones from the same feature within this
+ * many seconds are held and shown together. 0 shows each one.
+ */
+batch_window_seconds?: number }
+/**
+ * How important a notification is
+ */
+export type NotificationSeverity = "info" | "warning" | 
+/**
+ * Shown even during quiet hours
+ */
+"critical"
+export type OllamaCheck = { base_url: string; reachable: boolean; model_count: number }
+/**
+ * Ollama model information
+ */
+export type OllamaModel = { name: string }
+export type OverlayPosition = "none" | "top" | "bottom"
+/**
+ * What the recording overlay shows
+ */
+export type OverlayState = "recording" | "transcribing" | "ask-ai-recording" | "ask-ai-transcribing" | "ask-ai-generating" | "active-listening"
+/**
+ * A phone or browser paired with a pairing code
+ */
+export type PairedDevice = { id: string; name: string; 
+/**
+ * Bearer token the device sends with every request
+ */
+token: string; 
+/**
+ * Seconds since the Unix epoch
+ */
+paired_at: number }
+/**
+ * What executing a palette item does
+ */
+export type PaletteAction = 
+/**
+ * Run a shortcut's action, starting or stopping it like a toggle
+ * shortcut would
+ */
+{ type: "run_binding"; binding_id: string } | 
+/**
+ * Switch to a downloaded model
+ */
+{ type: "switch_model"; model_id: string } | 
+/**
+ * Post-process the latest dictation with a prompt and copy the result
+ */
+{ type: "run_prompt"; prompt_id: string } | 
+/**
+ * Copy a history entry's text
+ */
+{ type: "copy_history_entry"; entry_id: number } | 
+/**
+ * Copy a knowledge base document's content
+ */
+{ type: "copy_document"; document_id: number }
+/**
+ * Kinds of palette items, in the order they rank on equal scores
+ */
+export type PaletteCategory = "action" | "model" | "prompt" | "history" | "document"
+/**
+ * A search result in the palette
+ */
+export type PaletteItem = { title: string; subtitle: string | null; category: PaletteCategory; action: PaletteAction; score: number; 
+/**
+ * Character positions of the title the query matched, for highlighting
+ */
+matches: number[] }
+/**
+ * What happened when an item was executed, for the palette to report
+ */
+export type PaletteOutcome = "done" | "copied"
+/**
+ * Settings for breaking dictations up at pauses
+ */
+export type ParagraphingSettings = { enabled?: boolean; 
+/**
+ * Pause, in milliseconds, that ends a sentence
+ */
+sentence_pause_ms?: number; 
+/**
+ * Pause, in milliseconds, that starts a new paragraph
+ */
+paragraph_pause_ms?: number; 
+/**
+ * Start a "A:", "B:" line at each speaker turn, for interviews
+ */
+speaker_markers?: boolean }
+export type PasteMethod = "ctrl_v" | "direct" | "none" | "shift_insert" | "ctrl_shift_v"
+export type PermissionLossReason = 
+/**
+ * The OS reports the microphone permission as no longer granted
+ */
+"authorization_revoked" | 
+/**
+ * The input stream only delivers digital silence
+ */
+"silent_stream"
+export type PermissionLost = PermissionLostEvent
+/**
+ * Payload of the `permission-lost` event
+ */
+export type PermissionLostEvent = { permission: string; reason: PermissionLossReason; message: string; 
+/**
+ * Link that opens the relevant system settings page, where one exists
+ */
+settings_url: string | null }
+/**
+ * Status of an OS permission the app relies on
+ */
+export type PermissionStatus = "granted" | "denied" | 
+/**
+ * The user has not been asked yet
+ */
+"not_determined" | 
+/**
+ * This platform does not gate the capability behind a permission
+ */
+"not_required"
+export type PluginActionInfo = { 
+/**
+ * Id of the shortcut binding that runs the action
+ */
+binding_id: string; label: string }
+/**
+ * A plugin script called `emit(name, payload)`
+ */
+export type PluginEmitted = PluginMessage
+/**
+ * A plugin file and what it registered
+ */
+export type PluginInfo = { 
+/**
+ * File name without `.rhai`
+ */
+name: string; enabled: boolean; text_stages: string[]; suggestion_sources: string[]; actions: PluginActionInfo[]; 
+/**
+ * Why the plugin failed to load
+ */
+error: string | null }
+/**
+ * An event a plugin sent to the frontend
+ */
+export type PluginMessage = { plugin: string; name: string; 
+/**
+ * The payload as JSON
+ */
+payload: string }
+/**
+ * Whether plugins run, and which ones are turned off
+ */
+export type PluginSettings = { 
+/**
+ * Load and run plugins at all
+ */
+enabled?: boolean; 
+/**
+ * File names (without `.rhai`) of plugins that are skipped
+ */
+disabled?: string[] }
+export type PostProcessProvider = { id: string; label: string; base_url: string; allow_base_url_edit?: boolean; models_endpoint?: string | null }
+/**
+ * Category for grouping prompts
+ */
+export type PromptCategory = 
+/**
+ * Note-taking and summarization prompts
+ */
+"note_taking" | 
+/**
+ * Real-time meeting coach prompts (Perssua-like)
+ */
+"meeting_coach" | 
+/**
+ * User-created custom prompts
+ */
+"custom"
+/**
+ * A spoken phrase that picks the post-process prompt for a dictation
+ */
+export type PromptRoute = { 
+/**
+ * Words the dictation starts with, e.g. "formal email"
+ */
+phrase: string; prompt_id: string }
+/**
+ * A dictation started with a route phrase and is post-processed with the
+ * route's prompt. Emitted before post-processing starts.
+ */
+export type PromptRouted = PromptRoutedEvent
+/**
+ * Event payload for a dictation routed to a prompt
+ */
+export type PromptRoutedEvent = { phrase: string; prompt_id: string; prompt_name: string }
+/**
+ * A quick response template that can be triggered by keywords
+ */
+export type QuickResponse = { 
+/**
+ * Unique identifier for the quick response
+ */
+id: string; 
+/**
+ * Display name for the quick response
+ */
+name: string; 
+/**
+ * Keywords that trigger this response (comma-separated phrases)
+ */
+trigger_phrases: string[]; 
+/**
+ * Single words that trigger this response when they appear as whole
+ * words in the recent transcript (weaker signal than a phrase)
+ */
+trigger_keywords?: string[]; 
+/**
+ * Category for grouping (e.g., "pricing", "objection", "closing")
+ */
+category: string; 
+/**
+ * The response template to suggest
+ */
+response_template: string; 
+/**
+ * Whether this quick response is enabled
  */
 enabled?: boolean; 
 /**
- * Duration of each audio segment in seconds before transcription
+ * When this was created (Unix timestamp in milliseconds)
  */
-segment_duration_seconds?: number; 
+created_at: number }
 /**
- * Ollama server base URL
+ * A daily stretch of time without notifications, in minutes after local
+ * midnight. The end may be earlier than the start to span midnight.
  */
-ollama_base_url?: string; 
+export type QuietHours = { enabled?: boolean; start_minute?: number; end_minute?: number }
 /**
- * Ollama model to use for generating insights
+ * Progress of embedding generation for a single document
  */
-ollama_model?: string; 
+export type RagIndexingProgress = { document_id: number; 
 /**
- * Custom prompts for active listening
+ * Chunks embedded and stored so far
  */
-prompts?: ActiveListeningPrompt[]; 
+completed_chunks: number; total_chunks: number }
+/**
+ * Knowledge base statistics
+ */
+export type RagStats = { document_count: number; embedding_count: number }
+/**
+ * Payload of the `recording-limit-warning` event, sent once a recording has
+ * used up most of the recording limit
+ */
+export type RecordingLimitWarning = { elapsed_ms: number; 
+/**
+ * When the recording is stopped
+ */
+limit_ms: number }
+/**
+ * Payload of the `recording-progress` event
+ */
+export type RecordingProgress = { elapsed_ms: number; 
+/**
+ * Words transcribed so far; only reported while streaming
+ */
+word_count: number | null }
+export type RecordingRetentionPeriod = "never" | "preserve_limit" | "days_3" | "weeks_2" | "months_3"
+/**
+ * Settings for the remote control server
+ */
+export type RemoteControlSettings = { enabled?: boolean; 
+/**
+ * Port the server listens on, on every network interface
+ */
+port?: number; 
+/**
+ * Announce the server over mDNS so companion apps can find it
+ */
+advertise?: boolean; devices?: PairedDevice[] }
+/**
+ * Name of a device that was just paired for remote control
+ */
+export type RemoteDevicePaired = string
+/**
+ * A pairing started in the app, as shown to the user
+ */
+export type RemotePairing = { code: string; expires_in_seconds: number; 
+/**
+ * Addresses to open on the device
+ */
+urls: string[] }
+/**
+ * API a remote speech-to-text service speaks
+ */
+export type RemoteSttApi = 
+/**
+ * Multipart upload to `/audio/transcriptions`, as OpenAI, Groq and most
+ * self-hosted Whisper servers accept
+ */
+"open_ai" | 
+/**
+ * Raw audio posted to `/listen`
+ */
+"deepgram"
+/**
+ * A remote speech-to-text service
+ */
+export type RemoteSttProvider = { id: string; label: string; api: RemoteSttApi; base_url: string; default_model: string; 
+/**
+ * Whether the user can point it at another server
+ */
+allow_base_url_edit: boolean }
+/**
+ * What to change for the replay; unset fields use the current settings
+ */
+export type ReplayOptions = { 
+/**
+ * Active Listening prompt to generate insights with
+ */
+prompt_id: string | null; 
+/**
+ * Ollama model for insights and the summary
+ */
+model: string | null }
+/**
+ * What a replay starts from
+ */
+export type ReplaySource = 
+/**
+ * A session returned by `stop_active_listening_session`
+ */
+{ type: "session"; session: ActiveListeningSession } | 
+/**
+ * A recording of the whole meeting
+ */
+{ type: "audio"; path: string; topic: string | null }
+/**
+ * Output format of the monthly report
+ */
+export type ReportFormat = "markdown" | "html"
+/**
+ * Overrides applied while a schedule rule is active. Unset fields keep the
+ * regular setting.
+ */
+export type ScheduleOverrides = { sound_detection?: boolean | null; 
+/**
+ * Whether Active Listening sessions can be started
+ */
+active_listening?: boolean | null; sound_theme?: SoundTheme | null }
+/**
+ * Overrides for the minutes a cron expression matches
+ */
+export type ScheduleRule = { name: string; 
+/**
+ * Minute, hour, day of month, month and day of week the rule applies
+ * in, e.g. `* 22-6 * * *` for every night or `* 9-11 * * 1-5` for
+ * weekday mornings. Ranges may wrap around.
+ */
+when: string; overrides?: ScheduleOverrides }
+/**
+ * Settings for time-based overrides
+ */
+export type ScheduleSettings = { 
+/**
+ * Whether schedule rules are applied
+ */
+enabled?: boolean; 
+/**
+ * Rules in order of precedence; for each setting the first active rule
+ * that overrides it wins
+ */
+rules?: ScheduleRule[] }
+/**
+ * Search result from RAG query
+ */
+export type SearchResult = { 
+/**
+ * Document ID
+ */
+document_id: number; 
+/**
+ * Chunk text that matched
+ */
+chunk_text: string; 
+/**
+ * Similarity score (0-1, higher is better)
+ */
+similarity: number; 
+/**
+ * Document metadata
+ */
+metadata: DocMetadata; 
+/**
+ * Original document title
+ */
+title: string | null }
+/**
+ * Limits on the LLM work of one Active Listening session. 0 means no limit.
+ */
+export type SessionBudget = { 
+/**
+ * Most insight and LLM suggestion requests per session
+ */
+max_llm_calls?: number; 
+/**
+ * Most estimated prompt and response tokens per session
+ */
+max_tokens?: number; 
+/**
+ * Longest an insight may take for one segment before it's dropped
+ */
+max_segment_latency_seconds?: number }
+/**
+ * A moment marked during a session
+ */
+export type SessionHighlight = { 
+/**
+ * Unix timestamp when the highlight was marked (milliseconds)
+ */
+timestamp: number; 
+/**
+ * Milliseconds from the start of the session
+ */
+offset_ms: number }
+/**
+ * A single insight generated from a segment
+ */
+export type SessionInsight = { 
+/**
+ * Unix timestamp when this insight was generated
+ */
+timestamp: number; 
+/**
+ * The transcribed text for this segment
+ */
+transcription: string; 
+/**
+ * The AI-generated insight
+ */
+insight: string; 
+/**
+ * Duration of the audio segment in milliseconds
+ */
+duration_ms: number; 
+/**
+ * Speaker ID (0 = primary/you, 1+ = others)
+ */
+speaker_id: number | null; 
+/**
+ * Human-readable speaker label (e.g., "You", "Speaker 2", or custom name)
+ */
+speaker_label: string | null; 
+/**
+ * The transcription in the session's second language, when dual
+ * language output is on
+ */
+translation?: string | null }
+export type SessionReplay = { 
+/**
+ * Id of the replayed session, when replaying a session
+ */
+replayed_from: string | null; 
+/**
+ * The replayed session with the new insights
+ */
+session: ActiveListeningSession; summary: MeetingSummary }
+export type SessionReplayProgress = SessionReplayProgressEvent
+/**
+ * Progress of a running replay, emitted after each segment
+ */
+export type SessionReplayProgressEvent = { replay_id: string; completed: number; total: number }
+/**
+ * New value of a setting changed from the backend
+ */
+export type SettingValue = boolean | number | UpdateChannel
+export type SettingsChanged = { setting: string; value: SettingValue }
+/**
+ * Keys changed by an edit to the config file
+ */
+export type SettingsConfigApplied = string[]
+export type SettingsSynced = null
+export type ShortcutBinding = { id: string; name: string; description: string; default_binding: string; current_binding: string }
+export type ShowOverlay = OverlayState
+/**
+ * Binding id, from the action map, each signal triggers. Unmapped signals
+ * are ignored.
+ */
+export type SignalSettings = { usr1?: string | null; usr2?: string | null }
+/**
+ * How a dictation reaches a website's focused field
+ */
+export type SiteDelivery = 
+/**
+ * Send the text to the extension, which inserts it into the field
+ */
+"bridge" | 
+/**
+ * Paste with the configured paste method, for sites whose editors
+ * ignore inserted text
+ */
+"paste"
+/**
+ * How dictations into one website are handled
+ */
+export type SiteRule = { 
+/**
+ * Host the rule applies to, including its subdomains, so "google.com"
+ * also covers "docs.google.com"
+ */
+host: string; delivery?: SiteDelivery; 
+/**
+ * Formatting mode dictations into the site are formatted with
+ */
+formatting_mode?: string | null }
+/**
+ * Categories of environmental sounds that can be detected
+ */
+export type SoundCategory = "doorbell" | "alarm" | "phone_ring" | "dog_bark" | "baby_cry" | "knocking" | "siren" | "applause"
+/**
+ * Settings for the Environmental Sound Detection feature
+ */
+export type SoundDetectionSettings = { 
+/**
+ * Whether sound detection is enabled
+ */
+enabled?: boolean; 
+/**
+ * Which sound categories to detect
+ */
+categories?: SoundCategory[]; 
+/**
+ * Minimum confidence threshold for triggering a detection (0.0-1.0)
+ */
+threshold?: number; 
+/**
+ * Whether to show system notifications on detection
+ */
+notification_enabled?: boolean }
+export type SoundTheme = "marimba" | "pop" | "custom"
+/**
+ * A known speaker whose segments get their own word corrections
+ */
+export type SpeakerProfile = { 
+/**
+ * Unique identifier for the profile
+ */
+id: string; 
+/**
+ * Name shown instead of "Speaker N" for segments of this speaker
+ */
+name: string; 
+/**
+ * Names and terms this speaker uses, corrected in their segments on
+ * top of the global custom words
+ */
+custom_words?: string[]; 
+/**
+ * Diarized speaker this profile is assigned to when a session starts
+ * (0 = you)
+ */
+default_speaker_id?: number | null }
+/**
+ * Stored document representation
+ */
+export type StoredDocument = { id: number; content: string; source_type: string; source_id: string | null; title: string | null; created_at: number }
+/**
+ * A past session with the summary generated for it, if any
+ */
+export type StoredSession = { session: ActiveListeningSession; summary: MeetingSummary | null }
+/**
+ * A past session as listed in the session browser
+ */
+export type StoredSessionInfo = { id: string; started_at: number; ended_at: number | null; topic: string | null; insight_count: number; has_summary: boolean; 
+/**
+ * Transcript around the first match, for sessions found by a search
+ */
+excerpt: string | null }
+/**
+ * A suggestion generated by the engine
+ */
+export type Suggestion = 
+/**
+ * Quick response triggered by keywords
+ */
+{ type: "quick_response"; id: string; text: string; confidence: number; category: string; trigger_phrase: string } | 
+/**
+ * Data point retrieved from knowledge base
+ */
+{ type: "data_point"; fact: string; source: string; relevance: number } | 
+/**
+ * AI-generated talking point
+ */
+{ type: "talking_point"; point: string; rationale: string; confidence: number } | 
+/**
+ * Warning or alert about the conversation
+ */
+{ type: "warning"; message: string; severity: WarningSeverity }
+/**
+ * Accept/reject statistics for one suggestion category
+ */
+export type SuggestionCategoryStats = { category: string; accepted: number; rejected: number; 
+/**
+ * Current ranking adjustment applied to this category's suggestions
+ */
+ranking_adjustment: number }
+export type SuggestionPartial = SuggestionPartialEvent
+/**
+ * Event payload for a single suggestion parsed while the LLM is still generating
+ */
+export type SuggestionPartialEvent = { session_id: string; suggestion: Suggestion; timestamp: number }
+/**
+ * Summary of recorded suggestion feedback
+ */
+export type SuggestionStats = { total_accepted: number; total_rejected: number; categories: SuggestionCategoryStats[] }
+export type Suggestions = SuggestionsEvent
+/**
+ * Event payload for suggestions
+ */
+export type SuggestionsEvent = { session_id: string; suggestions: Suggestion[]; timestamp: number }
 /**
- * Currently selected prompt ID
+ * Settings for the Suggestions feature
  */
-selected_prompt_id?: string | null; 
+export type SuggestionsSettings = { 
 /**
- * Number of previous summaries to keep for context
+ * Whether suggestions are enabled
  */
-context_window_size?: number; 
+enabled?: boolean; 
 /**
- * Audio source type for capturing audio
+ * Quick response templates
  */
-audio_source_type?: AudioSourceType; 
+quick_responses?: QuickResponse[]; 
 /**
- * Settings for audio mixing when using Mixed mode
+ * Whether to use RAG for context-aware suggestions
  */
-audio_mix_settings?: AudioMixSettings }
+rag_suggestions_enabled?: boolean; 
 /**
- * State of the active listening session
+ * Whether to use LLM for generating dynamic suggestions
  */
-export type ActiveListeningState = 
+llm_suggestions_enabled?: boolean; 
 /**
- * No active session
+ * Maximum number of suggestions to show at once
  */
-"idle" | 
+max_suggestions?: number; 
 /**
- * Listening and accumulating audio
+ * Minimum confidence score for showing suggestions (0.0 - 1.0)
  */
-"listening" | 
+min_confidence?: number; 
 /**
- * Processing a segment (transcribing + Ollama)
+ * Whether to auto-dismiss suggestions after copying
  */
-"processing" | 
+auto_dismiss_on_copy?: boolean; 
 /**
- * Error state
+ * Suggestion display duration in seconds (0 = until dismissed)
  */
-"error"
-export type AppSettings = ({ push_to_talk: boolean; start_hidden?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; mute_while_recording?: boolean; append_trailing_space?: boolean; app_language?: string; 
+display_duration_seconds?: number; 
 /**
- * Hide overlay from screen capture/sharing (Zoom, Teams, etc.)
- * Enabled by default for privacy during screen sharing
+ * Word-overlap similarity (0.0 - 1.0) above which a suggestion counts as
+ * a duplicate of a recently shown one
  */
-private_overlay?: boolean }) & { bindings: Partial<{ [key in string]: ShortcutBinding }>; audio_feedback: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; selected_model?: string; always_on_microphone?: boolean; selected_microphone?: string | null; clamshell_microphone?: string | null; selected_output_device?: string | null; translate_to_english?: boolean; selected_language?: string; overlay_position?: OverlayPosition; debug_mode?: boolean; log_level?: LogLevel; custom_words?: string[]; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; paste_method?: PasteMethod; paste_delay_ms?: number; clipboard_handling?: ClipboardHandling; post_process_enabled?: boolean; post_process_provider_id?: string; post_process_providers?: PostProcessProvider[]; post_process_api_keys?: Partial<{ [key in string]: string }>; post_process_models?: Partial<{ [key in string]: string }>; post_process_prompts?: LLMPrompt[]; post_process_selected_prompt_id?: string | null; active_listening?: ActiveListeningSettings; ask_ai?: AskAiSettings; knowledge_base?: KnowledgeBaseSettings; suggestions?: SuggestionsSettings; sound_detection?: SoundDetectionSettings; experimental_enabled?: boolean; keyboard_implementation?: string }
+dedup_similarity_threshold?: number; 
 /**
- * An Ask AI conversation consisting of multiple turns
+ * How long shown suggestions are remembered for deduplication, in seconds
  */
-export type AskAiConversation = { 
+dedup_window_seconds?: number; 
 /**
- * Unique identifier for the conversation
+ * Minimum seconds between suggestions of the same category (0 = no cooldown)
  */
-id: string; 
+category_cooldown_seconds?: number; 
 /**
- * All Q&A turns in this conversation
+ * Maximum suggestions shown per minute (0 = unlimited)
  */
-turns: ConversationTurn[]; 
+max_suggestions_per_minute?: number; 
 /**
- * Unix timestamp when conversation was created
+ * Adjust suggestion ranking based on which suggestions were accepted or dismissed
  */
-created_at: number; 
+feedback_learning_enabled?: boolean; 
 /**
- * Unix timestamp when conversation was last updated
+ * Number of recent transcript segments (including the current one)
+ * that quick response triggers are matched against
  */
-updated_at: number; 
+quick_response_context_segments?: number; 
 /**
- * Auto-generated title from first question
+ * How warnings of each severity are routed beyond the overlay
  */
-title: string | null }
+warning_routing?: WarningRouting; 
 /**
- * Settings for the Ask AI feature
+ * Webhook URL that receives warnings whose route has `webhook` set
  */
-export type AskAiSettings = { 
+warning_webhook_url?: string | null }
 /**
- * Whether Ask AI feature is enabled
+ * Format a meeting summary is exported in
  */
-enabled?: boolean; 
+export type SummaryExportFormat = "markdown" | "text" | "html" | "json" | "pdf"
 /**
- * Ollama server base URL
+ * How to resolve an item changed both locally and remotely since the last sync
  */
-ollama_base_url?: string; 
+export type SyncConflictPolicy = "prefer_local" | "prefer_remote"
 /**
- * Ollama model to use for generating responses
+ * Outcome of a sync
  */
-ollama_model?: string; 
+export type SyncReport = { 
 /**
- * System prompt for the AI assistant
+ * Items updated locally from the remote bundle
  */
-system_prompt?: string; 
+pulled: number; 
 /**
- * Saved window width for the Ask AI overlay
+ * Whether the bundle was rewritten
  */
-window_width?: number | null; 
+pushed: boolean; 
 /**
- * Saved window height for the Ask AI overlay
+ * Items changed on both sides and resolved by the conflict policy
  */
-window_height?: number | null; 
+conflicts: number }
 /**
- * Saved window X position for the Ask AI overlay
+ * Settings for folder-based sync
  */
-window_x?: number | null; 
+export type SyncSettings = { 
 /**
- * Saved window Y position for the Ask AI overlay
+ * Whether sync is enabled
  */
-window_y?: number | null }
+enabled?: boolean; 
 /**
- * State of the Ask AI session
+ * Folder holding the sync bundle, typically inside Dropbox, iCloud Drive or Syncthing
  */
-export type AskAiState = 
+folder?: string | null; 
 /**
- * No active session
+ * Conflict resolution policy
  */
-"idle" | 
+conflict_policy?: SyncConflictPolicy }
 /**
- * Recording the user's question
+ * Current sync state for the settings UI
  */
-"recording" | 
+export type SyncStatus = { enabled: boolean; last_synced_at: number | null; last_error: string | null }
+export type TrailEntry = { 
 /**
- * Transcribing the recorded audio
+ * Unix timestamp in milliseconds
  */
-"transcribing" | 
+timestamp_ms: number; kind: TrailKind; 
 /**
- * Generating AI response
+ * Event name, or the manager whose state changed
  */
-"generating" | 
+name: string; 
 /**
- * Response complete, waiting for follow-up
+ * Payload summary, or `from -> to` for transitions
  */
-"complete" | 
+detail: string }
+export type TrailKind = "event" | "transition"
 /**
- * Conversation active, waiting for follow-up question
+ * How dictations from one transcribe shortcut are handled
  */
-"conversation_active" | 
+export type TranscribeProfile = { id: string; name: string; 
 /**
- * Error occurred
+ * Language to transcribe in instead of the selected one
  */
-"error"
+language?: string | null; 
 /**
- * Window position and size for Ask AI overlay
+ * Whether dictations are post-processed. When off, neither the
+ * post-process prompt nor the formatting mode is applied.
  */
-export type AskAiWindowBounds = { width: number | null; height: number | null; x: number | null; y: number | null }
-export type AudioDevice = { index: string; name: string; is_default: boolean }
+post_process?: boolean; 
 /**
- * Settings for audio source mixing when using Mixed mode
+ * Prompt to post-process with instead of the selected prompt and
+ * formatting mode
  */
-export type AudioMixSettings = { 
+post_process_prompt_id?: string | null; 
 /**
- * Mix ratio: 0.0 = microphone only, 1.0 = system audio only, 0.5 = equal mix
+ * Paste method to use instead of the configured one
  */
-mix_ratio?: number }
+paste_method?: PasteMethod | null }
 /**
- * Audio source type for Active Listening
- * Determines where audio is captured from for transcription
+ * A word or segment of a transcript and when it was said, in seconds from
+ * the start of the recording
  */
-export type AudioSourceType = 
+export type TranscriptSegment = { start: number; end: number; text: string }
 /**
- * Microphone input only (current default behavior)
+ * Settings for the speech-to-text backend
  */
-"microphone" | 
+export type TranscriptionBackendSettings = { 
 /**
- * System audio output only (loopback capture)
+ * Backend of shortcuts without their own: `local` or a provider id
  */
-"system_audio" | 
+default_backend?: string; 
 /**
- * Both microphone and system audio mixed together
+ * Backends picked for single shortcuts, by binding id
  */
-"mixed"
-export type BatchItem = { id: string; file_name: string; file_path: string; status: JobStatus; progress: number; error: string | null; duration_seconds: number | null }
-export type BatchQueueStatus = { items: BatchItem[]; total: number; completed: number; failed: number; is_processing: boolean }
-export type BindingResponse = { success: boolean; binding: ShortcutBinding | null; error: string | null }
-export type ClipboardHandling = "dont_modify" | "copy_to_clipboard"
+binding_backends?: Partial<{ [key in string]: string }>; 
 /**
- * A single turn in a conversation (question + response pair)
+ * API keys by provider id
  */
-export type ConversationTurn = { 
+api_keys?: Partial<{ [key in string]: string }>; 
 /**
- * Unique identifier for the turn
+ * Models by provider id; the provider's default when unset
  */
-id: string; 
+models?: Partial<{ [key in string]: string }>; 
 /**
- * The transcribed question
+ * Base URLs of providers that can be pointed at another server
  */
-question: string; 
+base_urls?: Partial<{ [key in string]: string }>; 
 /**
- * The AI response
+ * Transcribe with the local model when the remote service can't be
+ * reached, e.g. while offline
  */
-response: string; 
+fallback_to_local?: boolean; 
 /**
- * Unix timestamp when this turn was created
+ * Time a remote service gets to answer
  */
-timestamp: number; 
+timeout_seconds?: number }
 /**
- * Optional reference to the audio file for this turn
+ * A dictation finished transcribing. Part of the app's public interface
+ * for other tools (see [`crate::transcription_output`]): fields are only
+ * ever added, never renamed or removed.
  */
-audio_file_name: string | null }
-export type CustomSounds = { start: boolean; stop: boolean }
+export type TranscriptionFinalized = { 
 /**
- * Document metadata for RAG storage
+ * Text as transcribed, before any post-processing
  */
-export type DocMetadata = { 
+text: string; 
 /**
- * Type of source: "transcription", "upload", "note"
+ * Text after Chinese variant conversion or LLM post-processing, if
+ * either ran. This is what was pasted.
  */
-source_type: string; 
+post_processed_text: string | null; 
 /**
- * Optional reference ID (e.g., history entry ID, file path)
+ * Language that was spoken, as detected by the engine or service or as
+ * set in the settings. `None` when auto-detected by an engine that
+ * doesn't report it.
  */
-source_id: string | null; 
+language: string | null; 
 /**
- * Document title or description
+ * Length of the recording in seconds
  */
-title: string | null }
-export type EngineType = "Whisper" | "Parakeet" | "Moonshine" | "SenseVoice"
-export type HistoryEntry = { id: number; file_name: string; timestamp: number; saved: boolean; title: string; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null }
-export type JobStatus = "Queued" | "Decoding" | "Transcribing" | "Completed" | "Failed" | "Cancelled"
+duration_seconds: number; 
 /**
- * Settings for the Knowledge Base feature
+ * Model confidence from 0 to 1, `None` when the engine doesn't report it
  */
-export type KnowledgeBaseSettings = { 
+confidence: number | null }
 /**
- * Whether knowledge base is enabled
+ * Text transcribed so far from the recording in progress. Each event
+ * replaces the previous one.
  */
-enabled?: boolean; 
+export type TranscriptionPartial = string
 /**
- * Automatically index transcriptions from Active Listening
+ * How far the transcription of a long recording, split into chunks, has
+ * got. Emitted after each chunk.
  */
-auto_index_transcriptions?: boolean; 
+export type TranscriptionProgress = { 
 /**
- * Embedding model to use (Ollama model name)
+ * Chunks transcribed so far
  */
-embedding_model?: string; 
+done: number; total: number; 
 /**
- * Number of context chunks to retrieve per query
+ * Share of the recording's audio transcribed, from 0 to 1
  */
-top_k?: number; 
+progress: number }
 /**
- * Minimum similarity threshold for including results (0.0-1.0)
+ * How the translation of a bilingual session is produced
  */
-similarity_threshold?: number; 
+export type TranslationMethod = 
 /**
- * Use RAG context in Active Listening prompts
+ * Ask the insight model to translate the transcription
  */
-use_in_active_listening?: boolean }
-export type LLMPrompt = { id: string; name: string; prompt: string }
-export type LogLevel = "trace" | "debug" | "info" | "warn" | "error"
+"llm" | 
 /**
- * Loopback device information for the frontend
+ * Run the segment through Whisper again in translate mode. Whisper
+ * only translates into English.
  */
-export type LoopbackDeviceInfoDto = { id: string; name: string; is_default: boolean }
+"whisper"
 /**
- * Loopback support level for the frontend
+ * Settings for translating dictations
  */
-export type LoopbackSupportLevel = 
+export type TranslationSettings = { enabled?: boolean; 
 /**
- * Full native support for loopback capture
+ * Language dictations are translated into, e.g. "German"; none when
+ * empty
  */
-"native" | 
+target_language?: string; 
 /**
- * Requires additional software/virtual audio device
+ * Languages picked for single shortcuts, by binding id
  */
-"requires_virtual_device" | 
+binding_languages?: Partial<{ [key in string]: string }>; 
 /**
- * Not supported on this platform
+ * Post-process provider or `ollama` to translate with; the selected
+ * post-process provider when unset
  */
-"not_supported"
+provider_id?: string | null; 
 /**
- * Comprehensive meeting summary generated from a session
+ * Model to use instead of the provider's configured model
  */
-export type MeetingSummary = { 
+model?: string | null }
 /**
- * Session ID this summary is for
+ * A history entry waiting in the trash
  */
-session_id: string; 
+export type TrashedHistoryEntry = { entry: HistoryEntry; deleted_at: number; 
 /**
- * Brief executive summary (2-3 sentences)
+ * When the entry will be purged permanently
  */
-executive_summary: string; 
+purge_at: number }
 /**
- * Key decisions made during the meeting
+ * Scores for every parameter combination plus the recommendation
  */
-decisions: string[]; 
+export type TuningReport = { 
 /**
- * Action items with optional assignees and deadlines
+ * Names of the audio that was scored
  */
-action_items: MeetingActionItem[];
+samples: string[]; vad: VadScore[]; diarization: DiarizationScore[]; recommended_vad: VadScore | null; recommended_diarization: DiarizationScore | null }
 /**
- * Main topics discussed
+ * A recording to tune the VAD with. Without speech labels it is assumed to
+ * follow the guided protocol: [`GUIDED_SILENCE_MS`] of silence, then speech
+ * until the end.
  */
-topics: string[];
+export type TuningSample = { path: string; 
 /**
- * Suggested follow-up questions
+ * `(start, end)` of each speech segment, in milliseconds
  */
-follow_ups: string[]; 
+speech_segments_ms: [number, number][] | null; 
 /**
- * Total duration in minutes
+ * Offsets where the speaker changes, in milliseconds
  */
-duration_minutes: number; 
+speaker_changes_ms: number[] | null }
 /**
- * When this summary was generated
+ * Signals that can trigger actions
  */
-generated_at: number }
-export type ModelInfo = { id: string; name: string; description: string; filename: string; url: string | null; size_mb: number; is_downloaded: boolean; is_downloading: boolean; partial_size: number; is_directory: boolean; engine_type: EngineType; accuracy_score: number; speed_score: number; is_custom?: boolean; is_recommended?: boolean; supported_languages?: string[]; supports_translation?: boolean }
-export type ModelLoadStatus = { is_loaded: boolean; current_model: string | null }
-export type ModelUnloadTimeout = "never" | "immediately" | "min_2" | "min_5" | "min_10" | "min_15" | "hour_1" | "sec_5"
+export type UnixSignal = "usr_1" | "usr_2"
 /**
- * Ollama model information
+ * Release channel the updater checks
  */
-export type OllamaModel = { name: string }
-export type OverlayPosition = "none" | "top" | "bottom"
-export type PasteMethod = "ctrl_v" | "direct" | "none" | "shift_insert" | "ctrl_shift_v"
-export type PostProcessProvider = { id: string; label: string; base_url: string; allow_base_url_edit?: boolean; models_endpoint?: string | null }
+export type UpdateChannel = "stable" | "beta"
 /**
- * Category for grouping prompts
+ * Download progress emitted as `update-download-progress`
  */
-export type PromptCategory = 
+export type UpdateDownloadProgress = { downloaded: number; content_length: number | null }
 /**
- * Note-taking and summarization prompts
+ * An available update as shown in the UI
  */
-"note_taking" | 
+export type UpdateInfo = { version: string; current_version: string; channel: UpdateChannel; 
 /**
- * Real-time meeting coach prompts (Perssua-like)
+ * Release date, if the manifest provides one
  */
-"meeting_coach" | 
+date: string | null; 
 /**
- * User-created custom prompts
+ * Release notes from the update manifest
  */
-"custom"
+changelog: string | null }
 /**
- * A quick response template that can be triggered by keywords
+ * Detection quality for one VAD threshold
  */
-export type QuickResponse = { 
+export type VadScore = { threshold: number; 
 /**
- * Unique identifier for the quick response
+ * Share of kept audio that is speech
  */
-id: string; 
+precision: number; 
 /**
- * Display name for the quick response
+ * Share of speech that was kept (the rest was cut off)
  */
-name: string; 
+recall: number; f1: number; 
 /**
- * Keywords that trigger this response (comma-separated phrases)
+ * Share of non-speech that was kept
  */
-trigger_phrases: string[]; 
+false_positive_rate: number }
 /**
- * Category for grouping (e.g., "pricing", "objection", "closing")
+ * One downsampled point of a VAD test
  */
-category: string; 
+export type VadTestPoint = { 
 /**
- * The response template to suggest
+ * Milliseconds since the test started
  */
-response_template: string; 
+offset_ms: number; 
 /**
- * Whether this quick response is enabled
+ * RMS level of the input
  */
-enabled?: boolean; 
+rms: number; 
 /**
- * When this was created (Unix timestamp in milliseconds)
+ * Highest speech probability reported by the model
  */
-created_at: number }
+probability: number; 
 /**
- * Knowledge base statistics
+ * Whether the VAD kept any of this audio as speech
  */
-export type RagStats = { document_count: number; embedding_count: number }
-export type RecordingRetentionPeriod = "never" | "preserve_limit" | "days_3" | "weeks_2" | "months_3"
+is_speech: boolean }
+export type VadTestStopped = null
+export type VocabularyEntry = { id: number; term: string; frequency: number; source: string; category: string | null; created_at: string }
 /**
- * Search result from RAG query
+ * A spoken phrase and the action it triggers
  */
-export type SearchResult = { 
+export type VoiceCommand = { phrase: string; action: VoiceCommandAction }
 /**
- * Document ID
+ * What a voice command does to the text dictated so far
  */
-document_id: number; 
+export type VoiceCommandAction = { type: "new_line" } | { type: "new_paragraph" } | 
 /**
- * Chunk text that matched
+ * Insert text, e.g. a punctuation mark. Closing punctuation attaches
+ * to the word before it.
  */
-chunk_text: string; 
+{ type: "insert"; text: string } | 
 /**
- * Similarity score (0-1, higher is better)
+ * Remove the sentence dictated last
  */
-similarity: number; 
+{ type: "delete_last_sentence" } | { type: "delete_last_word" } | 
 /**
- * Document metadata
+ * Capitalize the next word
  */
-metadata: DocMetadata; 
+{ type: "capitalize_next" } | 
 /**
- * Original document title
+ * Write the next word in capitals
  */
-title: string | null }
+{ type: "uppercase_next" }
 /**
- * A single insight generated from a segment
+ * Settings for voice commands in dictations
  */
-export type SessionInsight = { 
+export type VoiceCommandSettings = { enabled?: boolean; 
 /**
- * Unix timestamp when this insight was generated
+ * The grammar: each phrase and what it does
  */
-timestamp: number; 
+commands?: VoiceCommand[] }
 /**
- * The transcribed text for this segment
+ * What saying the wake word starts
  */
-transcription: string; 
+export type WakeWordAction = "transcribe" | "ask_ai"
 /**
- * The AI-generated insight
+ * Settings for the wake word
  */
-insight: string; 
+export type WakeWordSettings = { enabled?: boolean; 
 /**
- * Duration of the audio segment in milliseconds
+ * openWakeWord keyword model, with `melspectrogram.onnx` and
+ * `embedding_model.onnx` in the same folder
  */
-duration_ms: number; 
+model_path?: string | null; 
 /**
- * Speaker ID (0 = primary/you, 1+ = others)
+ * How readily the wake word is recognized (0.0-1.0). Higher catches
+ * more mumbled wake words but also more false ones.
  */
-speaker_id: number | null; 
+sensitivity?: number; action?: WakeWordAction; 
 /**
- * Human-readable speaker label (e.g., "You", "Speaker 2", or custom name)
+ * Quiet after speaking that ends the dictation
  */
-speaker_label: string | null }
-export type ShortcutBinding = { id: string; name: string; description: string; default_binding: string; current_binding: string }
+silence_timeout_ms?: number }
 /**
- * Categories of environmental sounds that can be detected
+ * What happens when a warning of a given severity is detected
  */
-export type SoundCategory = "doorbell" | "alarm" | "phone_ring" | "dog_bark" | "baby_cry" | "knocking" | "siren" | "applause"
+export type WarningRoute = { 
 /**
- * Settings for the Environmental Sound Detection feature
+ * Show a system notification
  */
-export type SoundDetectionSettings = { 
+notify?: boolean; 
 /**
- * Whether sound detection is enabled
+ * Play an alert sound
  */
-enabled?: boolean; 
+play_sound?: boolean; 
 /**
- * Which sound categories to detect
+ * POST the warning to the configured webhook
  */
-categories?: SoundCategory[]; 
+webhook?: boolean }
 /**
- * Minimum confidence threshold for triggering a detection (0.0-1.0)
+ * Per-severity routing table for warnings. Warnings are always shown in the
+ * suggestions overlay; routes add system notifications, sound and webhooks.
  */
-threshold?: number; 
+export type WarningRouting = { low?: WarningRoute; medium?: WarningRoute; high?: WarningRoute }
 /**
- * Whether to show system notifications on detection
+ * Severity level for warning suggestions
  */
-notification_enabled?: boolean }
-export type SoundTheme = "marimba" | "pop" | "custom"
+export type WarningSeverity = "low" | "medium" | "high"
 /**
- * Stored document representation
+ * Something that happened which a webhook can be told about
  */
-export type StoredDocument = { id: number; content: string; source_type: string; source_id: string | null; title: string | null; created_at: number }
+export type WebhookEvent = 
 /**
- * Settings for the Suggestions feature
+ * A dictation was transcribed
  */
-export type SuggestionsSettings = { 
+"transcription_completed" | 
 /**
- * Whether suggestions are enabled
+ * An Active Listening session ended
  */
-enabled?: boolean; 
+"session_ended" | 
 /**
- * Quick response templates
+ * A meeting summary was generated
  */
-quick_responses?: QuickResponse[]; 
+"summary_generated" | 
 /**
- * Whether to use RAG for context-aware suggestions
+ * Sent by the test button, even while webhooks are off
  */
-rag_suggestions_enabled?: boolean; 
+"test"
 /**
- * Whether to use LLM for generating dynamic suggestions
+ * Settings for webhook deliveries
  */
-llm_suggestions_enabled?: boolean; 
+export type WebhookSettings = { enabled?: boolean; 
 /**
- * Maximum number of suggestions to show at once
+ * http(s) URL payloads are POSTed to
  */
-max_suggestions?: number; 
+url?: string | null; 
 /**
- * Minimum confidence score for showing suggestions (0.0 - 1.0)
+ * Sent as the `Authorization` header, e.g. `Bearer <token>`
  */
-min_confidence?: number; 
+auth_header?: string | null; 
 /**
- * Whether to auto-dismiss suggestions after copying
+ * Events that are delivered
  */
-auto_dismiss_on_copy?: boolean; 
+events?: WebhookEvent[]; 
 /**
- * Suggestion display duration in seconds (0 = until dismissed)
+ * Retries after a failed delivery, with a growing delay between them
  */
-display_duration_seconds?: number }
-export type VocabularyEntry = { id: number; term: string; frequency: number; source: string; category: string | null; created_at: string }
+max_retries?: number }
 
 /** tauri-specta globals **/
 
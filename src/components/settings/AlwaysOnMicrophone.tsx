@@ -1,5 +1,7 @@
 import React from "react";
 import { useTranslation } from "react-i18next";
+import { commands } from "@/bindings";
+import { Slider } from "../ui/Slider";
 import { ToggleSwitch } from "../ui/ToggleSwitch";
 import { useSettings } from "../../hooks/useSettings";
 
@@ -11,20 +13,97 @@ interface AlwaysOnMicrophoneProps {
 export const AlwaysOnMicrophone: React.FC<AlwaysOnMicrophoneProps> = React.memo(
   ({ descriptionMode = "tooltip", grouped = false }) => {
     const { t } = useTranslation();
-    const { getSetting, updateSetting, isUpdating } = useSettings();
+    const { getSetting, updateSetting, isUpdating, refreshSettings } =
+      useSettings();
 
     const alwaysOnMode = getSetting("always_on_microphone") || false;
+    const lowPowerIdle = getSetting("general")?.low_power_idle ?? true;
+    const prearmMicrophone = getSetting("general")?.prearm_microphone ?? false;
+    const retroactiveCapture =
+      getSetting("general")?.retroactive_capture ?? false;
+    const retroactiveCaptureSeconds =
+      getSetting("general")?.retroactive_capture_seconds ?? 2;
+
+    const handleLowPowerIdleChange = async (enabled: boolean) => {
+      await commands.changeLowPowerIdleSetting(enabled);
+      await refreshSettings();
+    };
+
+    const handleRetroactiveCaptureChange = async (enabled: boolean) => {
+      await commands.changeRetroactiveCaptureSetting(enabled);
+      await refreshSettings();
+    };
+
+    const handleRetroactiveCaptureSecondsChange = async (seconds: number) => {
+      await commands.changeRetroactiveCaptureSecondsSetting(seconds);
+      await refreshSettings();
+    };
+
+    const handlePrearmMicrophoneChange = async (enabled: boolean) => {
+      await commands.changePrearmMicrophoneSetting(enabled);
+      await refreshSettings();
+    };
 
     return (
-      <ToggleSwitch
-        checked={alwaysOnMode}
-        onChange={(enabled) => updateSetting("always_on_microphone", enabled)}
-        isUpdating={isUpdating("always_on_microphone")}
-        label={t("settings.debug.alwaysOnMicrophone.label")}
-        description={t("settings.debug.alwaysOnMicrophone.description")}
-        descriptionMode={descriptionMode}
-        grouped={grouped}
-      />
+      <>
+        <ToggleSwitch
+          checked={alwaysOnMode}
+          onChange={(enabled) =>
+            updateSetting("always_on_microphone", enabled)
+          }
+          isUpdating={isUpdating("always_on_microphone")}
+          label={t("settings.debug.alwaysOnMicrophone.label")}
+          description={t("settings.debug.alwaysOnMicrophone.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {alwaysOnMode && (
+          <ToggleSwitch
+            checked={lowPowerIdle}
+            onChange={handleLowPowerIdleChange}
+            label={t("settings.debug.lowPowerIdle.label")}
+            description={t("settings.debug.lowPowerIdle.description")}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+          />
+        )}
+        {alwaysOnMode && (
+          <ToggleSwitch
+            checked={retroactiveCapture}
+            onChange={handleRetroactiveCaptureChange}
+            label={t("settings.debug.retroactiveCapture.label")}
+            description={t("settings.debug.retroactiveCapture.description")}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+          />
+        )}
+        {alwaysOnMode && retroactiveCapture && (
+          <Slider
+            value={retroactiveCaptureSeconds}
+            onChange={handleRetroactiveCaptureSecondsChange}
+            min={0.5}
+            max={10}
+            step={0.5}
+            label={t("settings.debug.retroactiveCapture.lookback")}
+            description={t(
+              "settings.debug.retroactiveCapture.lookbackDescription",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            formatValue={(v) => `${v.toFixed(1)}s`}
+          />
+        )}
+        {!alwaysOnMode && (
+          <ToggleSwitch
+            checked={prearmMicrophone}
+            onChange={handlePrearmMicrophoneChange}
+            label={t("settings.debug.prearmMicrophone.label")}
+            description={t("settings.debug.prearmMicrophone.description")}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+          />
+        )}
+      </>
     );
   },
 );
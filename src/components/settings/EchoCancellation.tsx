@@ -0,0 +1,37 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { commands } from "@/bindings";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface EchoCancellationProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+  disabled?: boolean;
+}
+
+export const EchoCancellation: React.FC<EchoCancellationProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false, disabled = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+
+    const enabled = getSetting("general")?.echo_cancellation ?? true;
+
+    const handleChange = async (value: boolean) => {
+      await commands.changeEchoCancellationSetting(value);
+      await refreshSettings();
+    };
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={handleChange}
+        disabled={disabled}
+        label={t("settings.sound.echoCancellation.label")}
+        description={t("settings.sound.echoCancellation.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  },
+);
@@ -0,0 +1,63 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
+import { Slider } from "../ui/Slider";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface GainNormalizationProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const GainNormalization: React.FC<GainNormalizationProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+
+    const general = getSetting("general");
+    const enabled = general?.gain_normalization ?? false;
+    const targetDbfs = general?.gain_target_dbfs ?? -20;
+
+    const update = async (enabled: boolean, targetDbfs: number) => {
+      const result = await commands.changeGainNormalizationSetting(
+        enabled,
+        targetDbfs,
+      );
+      if (result.status === "error") {
+        toast.error(result.error);
+      }
+      await refreshSettings();
+    };
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(value) => update(value, targetDbfs)}
+          label={t("settings.sound.gainNormalization.label")}
+          description={t("settings.sound.gainNormalization.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled && (
+          <Slider
+            value={targetDbfs}
+            onChange={(value) => update(enabled, value)}
+            min={-40}
+            max={-6}
+            step={1}
+            label={t("settings.sound.gainNormalization.target.title")}
+            description={t(
+              "settings.sound.gainNormalization.target.description",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            formatValue={(value) => `${value} dBFS`}
+          />
+        )}
+      </>
+    );
+  },
+);
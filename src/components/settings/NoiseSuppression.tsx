@@ -0,0 +1,59 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, NoiseSuppressionSettings } from "@/bindings";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface NoiseSuppressionProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const NoiseSuppression: React.FC<NoiseSuppressionProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+
+    const noiseSuppression = getSetting("noise_suppression") ?? {
+      microphone: false,
+      system_audio: false,
+    };
+
+    const update = async (changes: Partial<NoiseSuppressionSettings>) => {
+      const result = await commands.changeNoiseSuppressionSetting({
+        ...noiseSuppression,
+        ...changes,
+      });
+      if (result.status === "error") {
+        toast.error(result.error);
+      }
+      await refreshSettings();
+    };
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={noiseSuppression.microphone}
+          onChange={(microphone) => update({ microphone })}
+          label={t("settings.sound.noiseSuppression.microphone.label")}
+          description={t(
+            "settings.sound.noiseSuppression.microphone.description",
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        <ToggleSwitch
+          checked={noiseSuppression.system_audio}
+          onChange={(system_audio) => update({ system_audio })}
+          label={t("settings.sound.noiseSuppression.systemAudio.label")}
+          description={t(
+            "settings.sound.noiseSuppression.systemAudio.description",
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+      </>
+    );
+  },
+);
@@ -0,0 +1,120 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
+import { Button } from "../ui/Button";
+import { SettingContainer } from "../ui/SettingContainer";
+import { Slider } from "../ui/Slider";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface PrimarySpeakerFilterProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const PrimarySpeakerFilter: React.FC<PrimarySpeakerFilterProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+    const [enrolling, setEnrolling] = useState(false);
+
+    const general = getSetting("general");
+    const hasProfile = !!general?.voice_profile;
+    const enabled = general?.primary_speaker_only ?? false;
+    const threshold = general?.primary_speaker_threshold ?? 0.85;
+
+    const handleEnroll = async () => {
+      setEnrolling(true);
+      try {
+        const result = await commands.enrollVoiceProfile();
+        if (result.status === "error") {
+          toast.error(result.error);
+        } else {
+          toast.success(t("settings.sound.primarySpeaker.enrolled"));
+        }
+        await refreshSettings();
+      } finally {
+        setEnrolling(false);
+      }
+    };
+
+    const handleClear = async () => {
+      if (enabled) {
+        await commands.changePrimarySpeakerOnlySetting(false);
+      }
+      await commands.clearVoiceProfile();
+      await refreshSettings();
+    };
+
+    const handleToggle = async (value: boolean) => {
+      const result = await commands.changePrimarySpeakerOnlySetting(value);
+      if (result.status === "error") {
+        toast.error(result.error);
+      }
+      await refreshSettings();
+    };
+
+    const handleThresholdChange = async (value: number) => {
+      await commands.changePrimarySpeakerThresholdSetting(value);
+      await refreshSettings();
+    };
+
+    return (
+      <>
+        <SettingContainer
+          title={t("settings.sound.primarySpeaker.profile")}
+          description={t("settings.sound.primarySpeaker.profileDescription")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <div className="flex items-center gap-2">
+            {enrolling && (
+              <span className="text-xs text-mid-gray">
+                {t("settings.sound.primarySpeaker.recording")}
+              </span>
+            )}
+            {hasProfile && !enrolling && (
+              <Button variant="ghost" size="sm" onClick={handleClear}>
+                {t("settings.sound.primarySpeaker.clear")}
+              </Button>
+            )}
+            <Button
+              variant="secondary"
+              size="sm"
+              onClick={handleEnroll}
+              disabled={enrolling}
+            >
+              {hasProfile
+                ? t("settings.sound.primarySpeaker.reenroll")
+                : t("settings.sound.primarySpeaker.enroll")}
+            </Button>
+          </div>
+        </SettingContainer>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={handleToggle}
+          disabled={!hasProfile}
+          label={t("settings.sound.primarySpeaker.label")}
+          description={t("settings.sound.primarySpeaker.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled && (
+          <Slider
+            value={threshold}
+            onChange={handleThresholdChange}
+            min={0.5}
+            max={1}
+            step={0.01}
+            label={t("settings.sound.primarySpeaker.threshold")}
+            description={t(
+              "settings.sound.primarySpeaker.thresholdDescription",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+          />
+        )}
+      </>
+    );
+  });
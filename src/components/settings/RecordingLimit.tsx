@@ -0,0 +1,63 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
+import { Slider } from "../ui/Slider";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface RecordingLimitProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const RecordingLimit: React.FC<RecordingLimitProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+
+    const general = getSetting("general");
+    const enabled = general?.recording_limit ?? false;
+    const minutes = general?.recording_limit_minutes ?? 10;
+
+    const update = async (enabled: boolean, minutes: number) => {
+      const result = await commands.changeRecordingLimitSetting(
+        enabled,
+        minutes,
+      );
+      if (result.status === "error") {
+        toast.error(result.error);
+      }
+      await refreshSettings();
+    };
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(value) => update(value, minutes)}
+          label={t("settings.general.recordingLimit.label")}
+          description={t("settings.general.recordingLimit.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled && (
+          <Slider
+            value={minutes}
+            onChange={(value) => update(enabled, value)}
+            min={1}
+            max={120}
+            step={1}
+            label={t("settings.general.recordingLimit.minutes.title")}
+            description={t(
+              "settings.general.recordingLimit.minutes.description",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            formatValue={(value) => `${value} min`}
+          />
+        )}
+      </>
+    );
+  },
+);
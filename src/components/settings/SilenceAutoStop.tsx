@@ -0,0 +1,69 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
+import { Slider } from "../ui/Slider";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface SilenceAutoStopProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const SilenceAutoStop: React.FC<SilenceAutoStopProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+
+    const general = getSetting("general");
+    const pushToTalk = general?.push_to_talk ?? true;
+    const enabled = general?.silence_auto_stop ?? false;
+    const seconds = general?.silence_auto_stop_seconds ?? 10;
+
+    const update = async (enabled: boolean, seconds: number) => {
+      const result = await commands.changeSilenceAutoStopSetting(
+        enabled,
+        seconds,
+      );
+      if (result.status === "error") {
+        toast.error(result.error);
+      }
+      await refreshSettings();
+    };
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(value) => update(value, seconds)}
+          disabled={pushToTalk}
+          label={t("settings.general.silenceAutoStop.label")}
+          description={
+            pushToTalk
+              ? t("settings.general.silenceAutoStop.needsToggleMode")
+              : t("settings.general.silenceAutoStop.description")
+          }
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled && !pushToTalk && (
+          <Slider
+            value={seconds}
+            onChange={(value) => update(enabled, value)}
+            min={2}
+            max={60}
+            step={1}
+            label={t("settings.general.silenceAutoStop.seconds.title")}
+            description={t(
+              "settings.general.silenceAutoStop.seconds.description",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            formatValue={(value) => `${value}s`}
+          />
+        )}
+      </>
+    );
+  },
+);
@@ -0,0 +1,37 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { commands } from "@/bindings";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface StreamingPreviewProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+  disabled?: boolean;
+}
+
+export const StreamingPreview: React.FC<StreamingPreviewProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false, disabled = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+
+    const enabled = getSetting("general")?.streaming_preview ?? true;
+
+    const handleChange = async (value: boolean) => {
+      await commands.changeStreamingPreviewSetting(value);
+      await refreshSettings();
+    };
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={handleChange}
+        disabled={disabled}
+        label={t("settings.general.streamingPreview.label")}
+        description={t("settings.general.streamingPreview.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  },
+);
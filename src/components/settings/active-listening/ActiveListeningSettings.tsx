@@ -1,7 +1,7 @@
 import React, { useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { RefreshCcw, Wifi, WifiOff, Presentation } from "lucide-react";
-import { commands } from "@/bindings";
+import { commands, type ModelUnloadTimeout } from "@/bindings";
 import { SpeakerAnalytics } from "./SpeakerAnalytics";
 
 import {
@@ -15,9 +15,15 @@ import { Button } from "../../ui/Button";
 import { ResetButton } from "../../ui/ResetButton";
 import { Input } from "../../ui/Input";
 import { useSettings } from "../../../hooks/useSettings";
+import { useModelStore } from "../../../stores/modelStore";
 import { ShortcutInput as DictumShortcut } from "../ShortcutInput";
 import { SessionViewer } from "./SessionViewer";
 import { AudioSourceSettings } from "./AudioSourceSettings";
+import { DiarizationSettings } from "./DiarizationSettings";
+import { BudgetSettings } from "./BudgetSettings";
+import { InsightFilterSettings } from "./InsightFilterSettings";
+import { DualLanguageSettings } from "./DualLanguageSettings";
+import { AnonymizedNames } from "./AnonymizedNames";
 
 const DisabledNotice: React.FC<{ children: React.ReactNode }> = ({
   children,
@@ -88,6 +94,7 @@ const OllamaSettingsComponent: React.FC = () => {
   const activeListening = getSetting("active_listening");
   const baseUrl = activeListening?.ollama_base_url ?? "http://localhost:11434";
   const model = activeListening?.ollama_model ?? "";
+  const keepAlive = activeListening?.ollama_keep_alive ?? "min5";
 
   const fetchModels = async () => {
     setIsFetchingModels(true);
@@ -120,6 +127,25 @@ const OllamaSettingsComponent: React.FC = () => {
     await refreshSettings();
   };
 
+  const handleKeepAliveChange = async (value: string | null) => {
+    if (!value) return;
+    await commands.changeOllamaKeepAliveSetting(value as ModelUnloadTimeout);
+    await refreshSettings();
+  };
+
+  const keepAliveOptions = [
+    "never",
+    "immediately",
+    "min2",
+    "min5",
+    "min10",
+    "min15",
+    "hour1",
+  ].map((value) => ({
+    value,
+    label: t(`settings.advanced.modelUnload.options.${value}`),
+  }));
+
   return (
     <>
       <OllamaConnectionStatus />
@@ -171,6 +197,20 @@ const OllamaSettingsComponent: React.FC = () => {
           </ResetButton>
         </div>
       </SettingContainer>
+
+      <SettingContainer
+        title={t("settings.activeListening.ollama.keepAlive.title")}
+        description={t("settings.activeListening.ollama.keepAlive.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Dropdown
+          selectedValue={keepAlive}
+          options={keepAliveOptions}
+          onSelect={handleKeepAliveChange}
+        />
+      </SettingContainer>
     </>
   );
 };
@@ -231,6 +271,43 @@ const SegmentSettingsComponent: React.FC = () => {
   );
 };
 
+const DraftModelComponent: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const { models, currentModel } = useModelStore();
+
+  const draftModel = getSetting("active_listening")?.draft_model_id ?? "";
+  const options = [
+    { value: "", label: t("settings.activeListening.segments.draftModel.off") },
+    ...models
+      .filter((model) => model.is_downloaded && model.id !== currentModel)
+      .map((model) => ({ value: model.id, label: model.name })),
+  ];
+
+  const handleDraftModelChange = async (value: string | null) => {
+    await commands.changeActiveListeningDraftModelSetting(value || null);
+    await refreshSettings();
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.activeListening.segments.draftModel.title")}
+      description={t(
+        "settings.activeListening.segments.draftModel.description",
+      )}
+      descriptionMode="tooltip"
+      layout="horizontal"
+      grouped={true}
+    >
+      <Dropdown
+        selectedValue={draftModel}
+        options={options}
+        onSelect={handleDraftModelChange}
+      />
+    </SettingContainer>
+  );
+};
+
 // Helper to group prompts by category
 type PromptCategory = "note_taking" | "meeting_coach" | "custom";
 
@@ -570,12 +647,18 @@ export const ActiveListeningSettings: React.FC = () => {
 
   const activeListening = getSetting("active_listening");
   const enabled = activeListening?.enabled ?? false;
+  const anonymize = activeListening?.anonymize_sessions ?? false;
 
   const handleEnableChange = async (value: boolean) => {
     await commands.changeActiveListeningEnabledSetting(value);
     await refreshSettings();
   };
 
+  const handleAnonymizeChange = async (value: boolean) => {
+    await commands.changeActiveListeningAnonymizeSetting(value);
+    await refreshSettings();
+  };
+
   return (
     <div className="max-w-3xl w-full mx-auto space-y-6">
       <SettingsGroup title={t("settings.activeListening.general.title")}>
@@ -587,7 +670,18 @@ export const ActiveListeningSettings: React.FC = () => {
           grouped={true}
         />
         {enabled && (
-          <DictumShortcut shortcutId="active_listening" grouped={true} />
+          <>
+            <DictumShortcut shortcutId="active_listening" grouped={true} />
+            <DictumShortcut shortcutId="mark_highlight" grouped={true} />
+            <ToggleSwitch
+              label={t("settings.activeListening.anonymize.title")}
+              description={t("settings.activeListening.anonymize.description")}
+              checked={anonymize}
+              onChange={handleAnonymizeChange}
+              grouped={true}
+            />
+            {anonymize && <AnonymizedNames />}
+          </>
         )}
       </SettingsGroup>
 
@@ -599,10 +693,19 @@ export const ActiveListeningSettings: React.FC = () => {
 
           <SettingsGroup title={t("settings.activeListening.segments.title")}>
             <SegmentSettingsComponent />
+            <DraftModelComponent />
           </SettingsGroup>
 
           <AudioSourceSettings />
 
+          <DiarizationSettings />
+
+          <BudgetSettings />
+
+          <InsightFilterSettings />
+
+          <DualLanguageSettings />
+
           <SettingsGroup title={t("settings.activeListening.prompts.title")}>
             <PromptsEditorComponent />
           </SettingsGroup>
@@ -0,0 +1,110 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
+
+import { SettingContainer } from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+/**
+ * Names replaced in anonymized sessions besides the speakers' and the
+ * speaker profiles'
+ */
+export const AnonymizedNames: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const [newName, setNewName] = useState("");
+
+  const names = getSetting("active_listening")?.anonymized_names ?? [];
+
+  const update = async (updated: string[]) => {
+    const result =
+      await commands.changeActiveListeningAnonymizedNamesSetting(updated);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const handleAdd = async () => {
+    const name = newName.trim();
+    if (!name || names.includes(name)) {
+      return;
+    }
+    await update([...names, name]);
+    setNewName("");
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === "Enter") {
+      e.preventDefault();
+      handleAdd();
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        title={t("settings.activeListening.anonymizedNames.title")}
+        description={t("settings.activeListening.anonymizedNames.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      >
+        <div className="flex items-center gap-2">
+          <Input
+            type="text"
+            className="max-w-40"
+            value={newName}
+            onChange={(e) => setNewName(e.target.value)}
+            onKeyDown={handleKeyDown}
+            placeholder={t(
+              "settings.activeListening.anonymizedNames.placeholder",
+            )}
+            variant="compact"
+          />
+          <Button
+            onClick={handleAdd}
+            disabled={!newName.trim()}
+            variant="primary"
+            size="md"
+          >
+            {t("settings.activeListening.anonymizedNames.add")}
+          </Button>
+        </div>
+      </SettingContainer>
+      {names.length > 0 && (
+        <div className="px-4 p-2 flex flex-wrap gap-1">
+          {names.map((name) => (
+            <Button
+              key={name}
+              onClick={() => update(names.filter((n) => n !== name))}
+              variant="secondary"
+              size="sm"
+              className="inline-flex items-center gap-1 cursor-pointer"
+              aria-label={t("settings.activeListening.anonymizedNames.remove", {
+                name,
+              })}
+            >
+              <span>{name}</span>
+              <svg
+                className="w-3 h-3"
+                fill="none"
+                stroke="currentColor"
+                viewBox="0 0 24 24"
+              >
+                <path
+                  strokeLinecap="round"
+                  strokeLinejoin="round"
+                  strokeWidth={2}
+                  d="M6 18L18 6M6 6l12 12"
+                />
+              </svg>
+            </Button>
+          ))}
+        </div>
+      )}
+    </>
+  );
+};
@@ -0,0 +1,80 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, SessionBudget } from "@/bindings";
+
+import { SettingsGroup, Slider } from "@/components/ui";
+import { useSettings } from "../../../hooks/useSettings";
+
+const DEFAULT_BUDGET: SessionBudget = {
+  max_llm_calls: 0,
+  max_tokens: 0,
+  max_segment_latency_seconds: 0,
+};
+
+/**
+ * Per-session limits on LLM calls, tokens and latency for Active Listening
+ */
+export const BudgetSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+
+  const budget = getSetting("active_listening")?.budget ?? DEFAULT_BUDGET;
+
+  const update = async (changes: Partial<SessionBudget>) => {
+    const result = await commands.changeActiveListeningBudgetSetting({
+      ...budget,
+      ...changes,
+    });
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const orUnlimited = (format: (value: number) => string) => (value: number) =>
+    value === 0
+      ? t("settings.activeListening.budget.unlimited")
+      : format(value);
+
+  return (
+    <SettingsGroup title={t("settings.activeListening.budget.title")}>
+      <Slider
+        value={budget.max_llm_calls}
+        onChange={(value) => update({ max_llm_calls: value })}
+        min={0}
+        max={500}
+        step={10}
+        label={t("settings.activeListening.budget.calls.title")}
+        description={t("settings.activeListening.budget.calls.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+        formatValue={orUnlimited((value) => value.toString())}
+      />
+      <Slider
+        value={budget.max_tokens}
+        onChange={(value) => update({ max_tokens: value })}
+        min={0}
+        max={200000}
+        step={5000}
+        label={t("settings.activeListening.budget.tokens.title")}
+        description={t("settings.activeListening.budget.tokens.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+        formatValue={orUnlimited((value) => `${value / 1000}k`)}
+      />
+      <Slider
+        value={budget.max_segment_latency_seconds}
+        onChange={(value) => update({ max_segment_latency_seconds: value })}
+        min={0}
+        max={120}
+        step={5}
+        label={t("settings.activeListening.budget.latency.title")}
+        description={t("settings.activeListening.budget.latency.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+        formatValue={orUnlimited((value) => `${value}s`)}
+      />
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,157 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { open } from "@tauri-apps/plugin-dialog";
+import { toast } from "sonner";
+import {
+  commands,
+  DiarizationBackend,
+  DiarizationSettings as DiarizationSettingsType,
+} from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  Slider,
+} from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { useSettings } from "../../../hooks/useSettings";
+
+const DEFAULT_SETTINGS: DiarizationSettingsType = {
+  backend: "energy",
+  model_path: null,
+  similarity_threshold: 0.7,
+  max_speakers: 8,
+};
+
+/**
+ * Speaker detection settings for Active Listening
+ */
+export const DiarizationSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+
+  const diarization = getSetting("diarization") ?? DEFAULT_SETTINGS;
+  const isEmbedding = diarization.backend === "embedding";
+  const modelName = diarization.model_path?.split(/[\\/]/).pop();
+
+  const update = async (changes: Partial<DiarizationSettingsType>) => {
+    const result = await commands.setDiarizationSettings({
+      ...diarization,
+      ...changes,
+    });
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const handleChooseModel = async () => {
+    const selected = await open({
+      multiple: false,
+      filters: [
+        {
+          name: t("settings.activeListening.diarization.model.filter"),
+          extensions: ["onnx"],
+        },
+      ],
+    });
+    if (typeof selected === "string") {
+      await update({ model_path: selected });
+    }
+  };
+
+  return (
+    <SettingsGroup title={t("settings.activeListening.diarization.title")}>
+      <SettingContainer
+        title={t("settings.activeListening.diarization.backend.title")}
+        description={t(
+          "settings.activeListening.diarization.backend.description",
+        )}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Dropdown
+          selectedValue={diarization.backend}
+          options={[
+            {
+              value: "energy",
+              label: t("settings.activeListening.diarization.backend.energy"),
+            },
+            {
+              value: "embedding",
+              label: t(
+                "settings.activeListening.diarization.backend.embedding",
+              ),
+            },
+          ]}
+          onSelect={(value) =>
+            value && update({ backend: value as DiarizationBackend })
+          }
+          className="min-w-[200px]"
+        />
+      </SettingContainer>
+
+      {isEmbedding && (
+        <>
+          <SettingContainer
+            title={t("settings.activeListening.diarization.model.title")}
+            description={t(
+              "settings.activeListening.diarization.model.description",
+            )}
+            descriptionMode="tooltip"
+            layout="horizontal"
+            grouped={true}
+          >
+            <div className="flex items-center gap-2">
+              <span className="text-xs text-mid-gray truncate max-w-[160px]">
+                {modelName ??
+                  t("settings.activeListening.diarization.model.none")}
+              </span>
+              {diarization.model_path && (
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  onClick={() => update({ model_path: null })}
+                >
+                  {t("settings.activeListening.diarization.model.clear")}
+                </Button>
+              )}
+              <Button variant="secondary" size="sm" onClick={handleChooseModel}>
+                {t("settings.activeListening.diarization.model.choose")}
+              </Button>
+            </div>
+          </SettingContainer>
+          <Slider
+            value={diarization.similarity_threshold}
+            onChange={(value) => update({ similarity_threshold: value })}
+            min={0.3}
+            max={0.95}
+            step={0.05}
+            label={t("settings.activeListening.diarization.similarity.title")}
+            description={t(
+              "settings.activeListening.diarization.similarity.description",
+            )}
+            descriptionMode="tooltip"
+            grouped={true}
+          />
+          <Slider
+            value={diarization.max_speakers}
+            onChange={(value) => update({ max_speakers: value })}
+            min={2}
+            max={12}
+            step={1}
+            label={t("settings.activeListening.diarization.maxSpeakers.title")}
+            description={t(
+              "settings.activeListening.diarization.maxSpeakers.description",
+            )}
+            descriptionMode="tooltip"
+            grouped={true}
+            formatValue={(value) => value.toString()}
+          />
+        </>
+      )}
+    </SettingsGroup>
+  );
+};
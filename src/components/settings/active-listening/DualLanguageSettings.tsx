@@ -0,0 +1,116 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import {
+  commands,
+  DualLanguageSettings as DualLanguage,
+  TranslationMethod,
+} from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+const DEFAULT_DUAL_LANGUAGE: DualLanguage = {
+  enabled: false,
+  target_language: "English",
+  method: "llm",
+};
+
+/**
+ * Store and export a translation next to each segment of a bilingual meeting
+ */
+export const DualLanguageSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+
+  const dualLanguage =
+    getSetting("active_listening")?.dual_language ?? DEFAULT_DUAL_LANGUAGE;
+
+  const update = async (changes: Partial<DualLanguage>) => {
+    const result = await commands.changeActiveListeningDualLanguageSetting({
+      ...dualLanguage,
+      ...changes,
+    });
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const handleMethodChange = (value: string) => {
+    const method = value as TranslationMethod;
+    // Whisper only translates into English
+    update(
+      method === "whisper"
+        ? { method, target_language: "English" }
+        : { method },
+    );
+  };
+
+  return (
+    <SettingsGroup title={t("settings.activeListening.dualLanguage.title")}>
+      <ToggleSwitch
+        label={t("settings.activeListening.dualLanguage.enabled.title")}
+        description={t(
+          "settings.activeListening.dualLanguage.enabled.description",
+        )}
+        descriptionMode="tooltip"
+        checked={dualLanguage.enabled}
+        onChange={(enabled) => update({ enabled })}
+        grouped={true}
+      />
+      {dualLanguage.enabled && (
+        <>
+          <SettingContainer
+            title={t("settings.activeListening.dualLanguage.method.title")}
+            description={t(
+              "settings.activeListening.dualLanguage.method.description",
+            )}
+            descriptionMode="tooltip"
+            grouped={true}
+          >
+            <Dropdown
+              selectedValue={dualLanguage.method}
+              options={[
+                {
+                  value: "llm",
+                  label: t("settings.activeListening.dualLanguage.method.llm"),
+                },
+                {
+                  value: "whisper",
+                  label: t(
+                    "settings.activeListening.dualLanguage.method.whisper",
+                  ),
+                },
+              ]}
+              onSelect={handleMethodChange}
+            />
+          </SettingContainer>
+          <SettingContainer
+            title={t("settings.activeListening.dualLanguage.language.title")}
+            description={t(
+              "settings.activeListening.dualLanguage.language.description",
+            )}
+            descriptionMode="tooltip"
+            layout="horizontal"
+            grouped={true}
+          >
+            <Input
+              key={dualLanguage.target_language}
+              defaultValue={dualLanguage.target_language}
+              onBlur={(e) => update({ target_language: e.target.value })}
+              disabled={dualLanguage.method === "whisper"}
+              placeholder="English"
+            />
+          </SettingContainer>
+        </>
+      )}
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,156 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, InsightFilters } from "@/bindings";
+
+import {
+  SettingContainer,
+  SettingsGroup,
+  Slider,
+  Textarea,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+const DEFAULT_FILTERS: InsightFilters = {
+  enabled: false,
+  min_length: 40,
+  banned_phrases: [],
+  max_transcript_similarity: 0,
+  embedding_model: "nomic-embed-text",
+  max_retries: 1,
+};
+
+/**
+ * Checks that drop vacuous insights before they're shown or stored
+ */
+export const InsightFilterSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+
+  const filters =
+    getSetting("active_listening")?.insight_filters ?? DEFAULT_FILTERS;
+
+  const update = async (changes: Partial<InsightFilters>) => {
+    const result = await commands.changeActiveListeningInsightFiltersSetting({
+      ...filters,
+      ...changes,
+    });
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const handlePhrasesChange = (value: string) => {
+    const banned_phrases = value
+      .split("\n")
+      .map((phrase) => phrase.trim())
+      .filter((phrase) => phrase.length > 0);
+    update({ banned_phrases });
+  };
+
+  return (
+    <SettingsGroup title={t("settings.activeListening.insightFilters.title")}>
+      <ToggleSwitch
+        label={t("settings.activeListening.insightFilters.enabled.title")}
+        description={t(
+          "settings.activeListening.insightFilters.enabled.description",
+        )}
+        descriptionMode="tooltip"
+        checked={filters.enabled}
+        onChange={(enabled) => update({ enabled })}
+        grouped={true}
+      />
+      {filters.enabled && (
+        <>
+          <Slider
+            value={filters.min_length}
+            onChange={(value) => update({ min_length: value })}
+            min={0}
+            max={200}
+            step={10}
+            label={t("settings.activeListening.insightFilters.minLength.title")}
+            description={t(
+              "settings.activeListening.insightFilters.minLength.description",
+            )}
+            descriptionMode="tooltip"
+            grouped={true}
+            formatValue={(value) => value.toString()}
+          />
+          <SettingContainer
+            title={t("settings.activeListening.insightFilters.banned.title")}
+            description={t(
+              "settings.activeListening.insightFilters.banned.description",
+            )}
+            descriptionMode="tooltip"
+            layout="stacked"
+            grouped={true}
+          >
+            <Textarea
+              key={filters.banned_phrases.join("\n")}
+              defaultValue={filters.banned_phrases.join("\n")}
+              onBlur={(e) => handlePhrasesChange(e.target.value)}
+              rows={4}
+            />
+          </SettingContainer>
+          <Slider
+            value={filters.max_transcript_similarity}
+            onChange={(value) => update({ max_transcript_similarity: value })}
+            min={0}
+            max={1}
+            step={0.05}
+            label={t(
+              "settings.activeListening.insightFilters.similarity.title",
+            )}
+            description={t(
+              "settings.activeListening.insightFilters.similarity.description",
+            )}
+            descriptionMode="tooltip"
+            grouped={true}
+            formatValue={(value) =>
+              value === 0
+                ? t("settings.activeListening.insightFilters.off")
+                : value.toFixed(2)
+            }
+          />
+          {filters.max_transcript_similarity > 0 && (
+            <SettingContainer
+              title={t(
+                "settings.activeListening.insightFilters.embeddingModel.title",
+              )}
+              description={t(
+                "settings.activeListening.insightFilters.embeddingModel.description",
+              )}
+              descriptionMode="tooltip"
+              layout="horizontal"
+              grouped={true}
+            >
+              <Input
+                key={filters.embedding_model}
+                defaultValue={filters.embedding_model}
+                onBlur={(e) => update({ embedding_model: e.target.value })}
+                placeholder="nomic-embed-text"
+              />
+            </SettingContainer>
+          )}
+          <Slider
+            value={filters.max_retries}
+            onChange={(value) => update({ max_retries: value })}
+            min={0}
+            max={3}
+            step={1}
+            label={t("settings.activeListening.insightFilters.retries.title")}
+            description={t(
+              "settings.activeListening.insightFilters.retries.description",
+            )}
+            descriptionMode="tooltip"
+            grouped={true}
+            formatValue={(value) => value.toString()}
+          />
+        </>
+      )}
+    </SettingsGroup>
+  );
+};
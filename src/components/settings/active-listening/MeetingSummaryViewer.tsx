@@ -1,4 +1,4 @@
-import React, { useState } from "react";
+import React, { useEffect, useRef, useState } from "react";
 import { useTranslation } from "react-i18next";
 import {
   FileText,
@@ -14,6 +14,8 @@ import {
   Loader2,
 } from "lucide-react";
 import { writeText } from "@tauri-apps/plugin-clipboard-manager";
+import { save } from "@tauri-apps/plugin-dialog";
+import { toast } from "sonner";
 import { commands, ActiveListeningSession } from "@/bindings";
 import { Button } from "../../ui/Button";
 
@@ -36,6 +38,17 @@ interface MeetingSummary {
   generated_at: number;
 }
 
+type SummaryExportFormat = "markdown" | "text" | "html" | "json" | "pdf";
+
+// Formats a summary can be saved as, with their file extensions
+const SAVE_FORMATS: { value: SummaryExportFormat; extension: string }[] = [
+  { value: "markdown", extension: "md" },
+  { value: "html", extension: "html" },
+  { value: "pdf", extension: "pdf" },
+  { value: "json", extension: "json" },
+  { value: "text", extension: "txt" },
+];
+
 interface MeetingSummaryViewerProps {
   session: ActiveListeningSession;
   onClose?: () => void;
@@ -50,10 +63,25 @@ export const MeetingSummaryViewer: React.FC<MeetingSummaryViewerProps> = ({
   const [isLoading, setIsLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [copiedToClipboard, setCopiedToClipboard] = useState(false);
+  const [isExportMenuOpen, setIsExportMenuOpen] = useState(false);
+  const exportMenuRef = useRef<HTMLDivElement>(null);
   const [expandedSections, setExpandedSections] = useState<Set<string>>(
     new Set(["executive", "decisions", "actionItems", "topics", "followUps"])
   );
 
+  useEffect(() => {
+    const handleClickOutside = (event: MouseEvent) => {
+      if (
+        exportMenuRef.current &&
+        !exportMenuRef.current.contains(event.target as Node)
+      ) {
+        setIsExportMenuOpen(false);
+      }
+    };
+    document.addEventListener("mousedown", handleClickOutside);
+    return () => document.removeEventListener("mousedown", handleClickOutside);
+  }, []);
+
   const generateSummary = async () => {
     setIsLoading(true);
     setError(null);
@@ -79,29 +107,74 @@ export const MeetingSummaryViewer: React.FC<MeetingSummaryViewerProps> = ({
     }
   };
 
-  const exportSummary = async (format: "markdown" | "text" | "json") => {
+  // Render the summary with the session's transcript, into a file when a
+  // path is given
+  const exportSummary = async (
+    format: SummaryExportFormat,
+    path: string | null
+  ) => {
+    // Use type assertion since commands may not be in bindings yet
+    const commandsAny = commands as unknown as Record<string, unknown>;
+    if (typeof commandsAny.exportMeetingSummary !== "function") {
+      throw new Error("Export feature not available. Please rebuild the application.");
+    }
+    const exportFn = commandsAny.exportMeetingSummary as (
+      summary: MeetingSummary,
+      format: SummaryExportFormat,
+      session: ActiveListeningSession | null,
+      path: string | null
+    ) => Promise<{ status: string; data?: string; error?: string }>;
+    const result = await exportFn(summary!, format, session, path);
+    if (result.status !== "ok" || result.data === undefined) {
+      throw new Error(result.error || "Unknown error");
+    }
+    return result.data;
+  };
+
+  const copySummary = async () => {
     if (!summary) return;
     try {
-      // Use type assertion since commands may not be in bindings yet
-      const commandsAny = commands as unknown as Record<string, unknown>;
-      if (typeof commandsAny.exportMeetingSummary !== "function") {
-        throw new Error("Export feature not available. Please rebuild the application.");
-      }
-      const exportFn = commandsAny.exportMeetingSummary as (
-        summary: MeetingSummary,
-        format: string
-      ) => Promise<{ status: string; data?: string; error?: string }>;
-      const result = await exportFn(summary, format);
-      if (result.status === "ok" && result.data) {
-        await writeText(result.data);
-        setCopiedToClipboard(true);
-        setTimeout(() => setCopiedToClipboard(false), 2000);
-      }
+      await writeText(await exportSummary("text", null));
+      setCopiedToClipboard(true);
+      setTimeout(() => setCopiedToClipboard(false), 2000);
     } catch (err) {
       console.error("Failed to export summary:", err);
     }
   };
 
+  const saveSummary = async (format: SummaryExportFormat) => {
+    setIsExportMenuOpen(false);
+    if (!summary) return;
+    const extension = SAVE_FORMATS.find((f) => f.value === format)!.extension;
+    try {
+      const path = await save({
+        defaultPath: `meeting-summary.${extension}`,
+        filters: [
+          {
+            name: t(`settings.activeListening.summary.export.${format}`),
+            extensions: [extension],
+          },
+        ],
+      });
+      if (!path) return;
+      await exportSummary(format, path);
+      toast.success(
+        t(
+          "settings.activeListening.summary.export.downloadSuccess",
+          "Summary exported successfully"
+        )
+      );
+    } catch (err) {
+      console.error("Failed to export summary:", err);
+      toast.error(
+        t("settings.activeListening.summary.export.failed", {
+          error: String(err),
+          defaultValue: `Failed to export summary: ${err}`,
+        })
+      );
+    }
+  };
+
   const toggleSection = (section: string) => {
     setExpandedSections((prev) => {
       const next = new Set(prev);
@@ -219,21 +292,38 @@ export const MeetingSummaryViewer: React.FC<MeetingSummaryViewerProps> = ({
           </p>
         </div>
         <div className="flex gap-2">
-          <Button
-            variant="ghost"
-            size="sm"
-            onClick={() => exportSummary("markdown")}
-            title={t(
-              "settings.activeListening.summary.export.markdown",
-              "Export as Markdown"
+          <div className="relative" ref={exportMenuRef}>
+            <Button
+              variant="ghost"
+              size="sm"
+              onClick={() => setIsExportMenuOpen(!isExportMenuOpen)}
+              title={t(
+                "settings.activeListening.summary.export.title",
+                "Export Summary"
+              )}
+            >
+              <Download className="h-4 w-4" />
+            </Button>
+            {isExportMenuOpen && (
+              <div className="absolute right-0 top-full mt-1 bg-background border border-mid-gray/20 rounded-lg shadow-lg z-50 min-w-[180px] py-1">
+                {SAVE_FORMATS.map((format) => (
+                  <button
+                    key={format.value}
+                    onClick={() => saveSummary(format.value)}
+                    className="w-full px-3 py-1.5 text-sm text-left hover:bg-primary-light/10 transition-colors cursor-pointer"
+                  >
+                    {t(
+                      `settings.activeListening.summary.export.${format.value}`
+                    )}
+                  </button>
+                ))}
+              </div>
             )}
-          >
-            <Download className="h-4 w-4" />
-          </Button>
+          </div>
           <Button
             variant="ghost"
             size="sm"
-            onClick={() => exportSummary("text")}
+            onClick={copySummary}
             title={t(
               "settings.activeListening.summary.export.copy",
               "Copy to Clipboard"
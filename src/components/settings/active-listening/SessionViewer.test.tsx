@@ -17,6 +17,7 @@ const createMockEntry = (overrides: Partial<{
   transcription_text: string;
   post_processed_text: string | null;
   post_process_prompt: string | null;
+  speaker_label: string | null;
 }> = {}) => ({
   id: 1,
   file_name: 'recording.wav',
@@ -26,6 +27,7 @@ const createMockEntry = (overrides: Partial<{
   transcription_text: 'Test transcription',
   post_processed_text: null,
   post_process_prompt: null,
+  speaker_label: null,
   ...overrides,
 });
 
@@ -70,6 +70,11 @@ const InsightEntry: React.FC<{
             <span className="text-xs text-mid-gray">
               {formatTime(entry.timestamp)}
             </span>
+            {entry.speaker_label && (
+              <span className="text-xs font-medium text-text">
+                {entry.speaker_label}
+              </span>
+            )}
           </div>
           <p className="text-sm text-text line-clamp-2">
             {entry.transcription_text}
@@ -231,7 +236,9 @@ export const SessionViewer: React.FC = () => {
     const markdown = groupEntries
       .map((entry) => {
         const time = formatTime(entry.timestamp);
-        let text = `## ${time}\n\n`;
+        let text = entry.speaker_label
+          ? `## ${time} - ${entry.speaker_label}\n\n`
+          : `## ${time}\n\n`;
         text += `**Transcription:**\n${entry.transcription_text}\n\n`;
         if (entry.post_processed_text) {
           text += `**AI Insight:**\n${entry.post_processed_text}\n\n`;
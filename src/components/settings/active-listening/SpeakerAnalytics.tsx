@@ -1,9 +1,18 @@
 import React, { useMemo, useState, useEffect } from "react";
 import { useTranslation } from "react-i18next";
-import { BarChart3, Users, Clock, MessageSquare, RefreshCcw } from "lucide-react";
+import {
+  BarChart3,
+  Users,
+  Clock,
+  MessageSquare,
+  RefreshCcw,
+  Pencil,
+} from "lucide-react";
+import { toast } from "sonner";
 import { commands, ActiveListeningSession, SessionInsight } from "@/bindings";
 import { SettingsGroup } from "@/components/ui";
 import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
 
 interface SpeakerStats {
   speakerId: number | null;
@@ -109,20 +118,61 @@ function formatDuration(ms: number): string {
   return `${seconds}s`;
 }
 
-const SpeakerBar: React.FC<{ speaker: SpeakerStats; index: number }> = ({
-  speaker,
-  index,
-}) => {
+const SpeakerBar: React.FC<{
+  speaker: SpeakerStats;
+  index: number;
+  onRename?: (name: string | null) => void;
+}> = ({ speaker, index, onRename }) => {
   const { t } = useTranslation();
+  const [isEditing, setIsEditing] = useState(false);
+  const [draftName, setDraftName] = useState(speaker.label);
   const colorClass = SPEAKER_COLORS[index % SPEAKER_COLORS.length];
   const textColorClass = SPEAKER_TEXT_COLORS[index % SPEAKER_TEXT_COLORS.length];
 
+  const startEditing = () => {
+    setDraftName(speaker.label);
+    setIsEditing(true);
+  };
+
+  const finishEditing = () => {
+    setIsEditing(false);
+    const name = draftName.trim();
+    if (name !== speaker.label) {
+      onRename?.(name || null);
+    }
+  };
+
   return (
     <div className="space-y-1.5">
       <div className="flex items-center justify-between text-sm">
         <div className="flex items-center gap-2">
           <div className={`w-3 h-3 rounded-full ${colorClass}`} />
-          <span className={`font-medium ${textColorClass}`}>{speaker.label}</span>
+          {isEditing ? (
+            <Input
+              variant="compact"
+              autoFocus
+              value={draftName}
+              onChange={(e) => setDraftName(e.target.value)}
+              onBlur={finishEditing}
+              onKeyDown={(e) => {
+                if (e.key === "Enter") finishEditing();
+                if (e.key === "Escape") setIsEditing(false);
+              }}
+              placeholder={t("speakerAnalytics.renamePlaceholder", "Name")}
+              className="w-40"
+            />
+          ) : (
+            <span className={`font-medium ${textColorClass}`}>{speaker.label}</span>
+          )}
+          {onRename && !isEditing && (
+            <button
+              onClick={startEditing}
+              className="text-mid-gray hover:text-text"
+              title={t("speakerAnalytics.rename", "Rename speaker")}
+            >
+              <Pencil className="h-3 w-3" />
+            </button>
+          )}
         </div>
         <span className="text-mid-gray">
           {speaker.percentage.toFixed(1)}%
@@ -180,6 +230,14 @@ export const SpeakerAnalytics: React.FC = () => {
     }
   };
 
+  const handleRename = async (speakerId: number, name: string | null) => {
+    const result = await commands.renameSpeaker(speakerId, name);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await loadSession();
+  };
+
   useEffect(() => {
     loadSession();
     // Poll for updates while session is active
@@ -272,6 +330,11 @@ export const SpeakerAnalytics: React.FC = () => {
               key={speaker.speakerId ?? "unknown"}
               speaker={speaker}
               index={index}
+              onRename={
+                speaker.speakerId !== null && session.ended_at === null
+                  ? (name) => handleRename(speaker.speakerId!, name)
+                  : undefined
+              }
             />
           ))}
         </div>
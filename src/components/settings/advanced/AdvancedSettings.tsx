@@ -10,6 +10,23 @@ import { StartHidden } from "../StartHidden";
 import { AutostartToggle } from "../AutostartToggle";
 import { PasteMethodSetting } from "../PasteMethod";
 import { ClipboardHandlingSetting } from "../ClipboardHandling";
+import { ApiServerSettings } from "./ApiServerSettings";
+import { AutomationHookSettings } from "./AutomationHookSettings";
+import { BrowserBridgeSettings } from "./BrowserBridgeSettings";
+import { CodeDictationSettings } from "./CodeDictationSettings";
+import { ExportTemplateSettings } from "./ExportTemplateSettings";
+import { FormattingSettings } from "./FormattingSettings";
+import { ParagraphingSettings } from "./ParagraphingSettings";
+import { PluginSettings } from "./PluginSettings";
+import { RemoteControlSettings } from "./RemoteControlSettings";
+import { ScheduleSettings } from "./ScheduleSettings";
+import { SignalSettings } from "./SignalSettings";
+import { TranscribeProfileSettings } from "./TranscribeProfileSettings";
+import { TranscriptionBackendSettings } from "./TranscriptionBackendSettings";
+import { TranslationSettings } from "./TranslationSettings";
+import { VoiceCommandSettings } from "./VoiceCommandSettings";
+import { WakeWordSettings } from "./WakeWordSettings";
+import { WebhookSettings } from "./WebhookSettings";
 import { useModelStore } from "../../../stores/modelStore";
 
 export const AdvancedSettings: React.FC = () => {
@@ -33,6 +50,23 @@ export const AdvancedSettings: React.FC = () => {
         <ModelUnloadTimeoutSetting descriptionMode="tooltip" grouped={true} />
         <CustomWords descriptionMode="tooltip" grouped />
       </SettingsGroup>
+      <TranscriptionBackendSettings />
+      <ParagraphingSettings />
+      <TranslationSettings />
+      <CodeDictationSettings />
+      <VoiceCommandSettings />
+      <WakeWordSettings />
+      <FormattingSettings />
+      <TranscribeProfileSettings />
+      <ExportTemplateSettings />
+      <WebhookSettings />
+      <AutomationHookSettings />
+      <PluginSettings />
+      <ApiServerSettings />
+      <RemoteControlSettings />
+      <BrowserBridgeSettings />
+      <SignalSettings />
+      <ScheduleSettings />
     </div>
   );
 };
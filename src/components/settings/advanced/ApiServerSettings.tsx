@@ -0,0 +1,121 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { Copy, RefreshCw } from "lucide-react";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
+
+import { SettingContainer, SettingsGroup, ToggleSwitch } from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+/**
+ * Local HTTP API that scripts, window managers and Stream Deck buttons use
+ * to control dictation and Active Listening
+ */
+export const ApiServerSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const apiServer = getSetting("api_server");
+  const [port, setPort] = useState("");
+
+  useEffect(() => {
+    setPort(String(apiServer?.port ?? 48213));
+  }, [apiServer?.port]);
+
+  const handleEnabledChange = async (enabled: boolean) => {
+    const result = await commands.changeApiServerEnabledSetting(enabled);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const savePort = async () => {
+    if (Number(port) === apiServer?.port) return;
+    const result = await commands.changeApiServerPortSetting(Number(port));
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const regenerateToken = async () => {
+    const result = await commands.regenerateApiServerToken();
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const copyToken = async () => {
+    if (!apiServer?.token) return;
+    await navigator.clipboard.writeText(apiServer.token);
+    toast.success(t("settings.apiServer.token.copied"));
+  };
+
+  return (
+    <SettingsGroup title={t("settings.apiServer.title")}>
+      <ToggleSwitch
+        checked={apiServer?.enabled ?? false}
+        onChange={handleEnabledChange}
+        label={t("settings.apiServer.enabled.label")}
+        description={t("settings.apiServer.enabled.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <SettingContainer
+        title={t("settings.apiServer.port.title")}
+        description={t("settings.apiServer.port.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Input
+          type="number"
+          variant="compact"
+          min={1024}
+          max={65535}
+          value={port}
+          onChange={(e) => setPort(e.target.value)}
+          onBlur={savePort}
+        />
+      </SettingContainer>
+      {apiServer?.token && (
+        <SettingContainer
+          title={t("settings.apiServer.token.title")}
+          description={t("settings.apiServer.token.description")}
+          descriptionMode="tooltip"
+          layout="stacked"
+          grouped={true}
+        >
+          <div className="flex items-center gap-2">
+            <Input
+              type="password"
+              variant="compact"
+              value={apiServer.token}
+              readOnly
+              className="flex-1"
+            />
+            <Button
+              onClick={copyToken}
+              variant="secondary"
+              size="sm"
+              title={t("settings.apiServer.token.copy")}
+            >
+              <Copy width={14} height={14} />
+            </Button>
+            <Button
+              onClick={regenerateToken}
+              variant="secondary"
+              size="sm"
+              title={t("settings.apiServer.token.regenerate")}
+            >
+              <RefreshCw width={14} height={14} />
+            </Button>
+          </div>
+        </SettingContainer>
+      )}
+    </SettingsGroup>
+  );
+};
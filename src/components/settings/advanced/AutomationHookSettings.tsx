@@ -0,0 +1,309 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import {
+  AutomationHook,
+  AutomationHookSettings as AutomationHooks,
+  commands,
+  HookStage,
+} from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  Textarea,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+const STAGES: HookStage[] = ["post_transcribe", "pre_paste", "post_paste"];
+
+const emptyHook = (): AutomationHook => ({
+  id: crypto.randomUUID(),
+  name: "",
+  stage: "pre_paste",
+  command: "",
+  args: [],
+  enabled: true,
+  timeout_ms: 5000,
+  inherit_env: false,
+  env: {},
+});
+
+/** One `KEY=value` per line */
+const formatEnv = (env: AutomationHook["env"]) =>
+  Object.entries(env)
+    .map(([name, value]) => `${name}=${value}`)
+    .join("\n");
+
+const parseEnv = (text: string): AutomationHook["env"] =>
+  Object.fromEntries(
+    text
+      .split("\n")
+      .map((line) => line.trim())
+      .filter((line) => line.includes("="))
+      .map((line) => {
+        const split = line.indexOf("=");
+        return [line.slice(0, split).trim(), line.slice(split + 1)];
+      }),
+  );
+
+const withoutBlankArgs = (hook: AutomationHook): AutomationHook => ({
+  ...hook,
+  args: hook.args.filter((arg) => arg.trim() !== ""),
+});
+
+/**
+ * Programs that get the dictation on stdin at set points of the pipeline,
+ * and can rewrite it before it's post-processed or pasted
+ */
+export const AutomationHookSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const [draft, setDraft] = useState<AutomationHook | null>(null);
+  const [testInput, setTestInput] = useState("");
+  const [testOutput, setTestOutput] = useState<string | null>(null);
+  const [isTesting, setIsTesting] = useState(false);
+
+  const automationHooks: AutomationHooks = getSetting("automation_hooks") ?? {
+    enabled: false,
+    hooks: [],
+  };
+  const hooks = automationHooks.hooks;
+
+  const save = async (changes: Partial<AutomationHooks>) => {
+    const result = await commands.changeAutomationHooksSetting({
+      ...automationHooks,
+      ...changes,
+    });
+    await refreshSettings();
+    if (result.status === "error") {
+      toast.error(result.error);
+      return false;
+    }
+    return true;
+  };
+
+  const saveDraft = async () => {
+    if (!draft) return;
+    const hook = withoutBlankArgs(draft);
+    const exists = hooks.some(({ id }) => id === hook.id);
+    await save({
+      hooks: exists
+        ? hooks.map((saved) => (saved.id === hook.id ? hook : saved))
+        : [...hooks, hook],
+    });
+  };
+
+  const deleteDraft = async () => {
+    if (!draft) return;
+    const saved = await save({
+      hooks: hooks.filter((hook) => hook.id !== draft.id),
+    });
+    if (saved) setDraft(null);
+  };
+
+  const selectHook = (id: string | null) => {
+    setDraft(hooks.find((hook) => hook.id === id) ?? null);
+    setTestOutput(null);
+  };
+
+  const runTest = async () => {
+    if (!draft) return;
+    setIsTesting(true);
+    const result = await commands.testAutomationHook(
+      withoutBlankArgs(draft),
+      testInput,
+    );
+    setIsTesting(false);
+    if (result.status === "error") {
+      toast.error(
+        t("settings.automationHooks.test.failed", { error: result.error }),
+      );
+      setTestOutput(null);
+      return;
+    }
+    setTestOutput(result.data);
+  };
+
+  const stageLabel = (stage: HookStage) =>
+    t(`settings.automationHooks.stages.${stage}`);
+
+  return (
+    <SettingsGroup title={t("settings.automationHooks.title")}>
+      <ToggleSwitch
+        checked={automationHooks.enabled}
+        onChange={(enabled) => save({ enabled })}
+        label={t("settings.automationHooks.enabled.label")}
+        description={t("settings.automationHooks.enabled.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+
+      <SettingContainer
+        title={t("settings.automationHooks.hooks.title")}
+        description={t("settings.automationHooks.hooks.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-3">
+          <div className="flex gap-2">
+            <Dropdown
+              selectedValue={draft?.id ?? null}
+              options={hooks.map((hook) => ({
+                value: hook.id,
+                label: `${stageLabel(hook.stage)}: ${hook.name || hook.command}`,
+              }))}
+              onSelect={selectHook}
+              placeholder={t("settings.automationHooks.hooks.select")}
+              className="flex-1"
+            />
+            <Button
+              onClick={() => {
+                setDraft(emptyHook());
+                setTestOutput(null);
+              }}
+              variant="primary"
+              size="md"
+            >
+              {t("settings.automationHooks.hooks.createNew")}
+            </Button>
+          </div>
+
+          {draft && (
+            <div className="space-y-3">
+              <div className="flex gap-2">
+                <Input
+                  type="text"
+                  variant="compact"
+                  value={draft.name}
+                  onChange={(e) =>
+                    setDraft({ ...draft, name: e.target.value })
+                  }
+                  placeholder={t("settings.automationHooks.hooks.name")}
+                  className="flex-1"
+                />
+                <Dropdown
+                  selectedValue={draft.stage}
+                  options={STAGES.map((stage) => ({
+                    value: stage,
+                    label: stageLabel(stage),
+                  }))}
+                  onSelect={(stage) =>
+                    stage && setDraft({ ...draft, stage: stage as HookStage })
+                  }
+                />
+              </div>
+              <Input
+                type="text"
+                variant="compact"
+                value={draft.command}
+                onChange={(e) =>
+                  setDraft({ ...draft, command: e.target.value })
+                }
+                placeholder={t("settings.automationHooks.hooks.command")}
+                className="w-full font-mono"
+              />
+              <Textarea
+                value={draft.args.join("\n")}
+                onChange={(e) =>
+                  setDraft({ ...draft, args: e.target.value.split("\n") })
+                }
+                placeholder={t("settings.automationHooks.hooks.args")}
+                className="font-mono text-xs min-h-[60px]"
+              />
+              <Textarea
+                key={draft.id}
+                defaultValue={formatEnv(draft.env)}
+                onBlur={(e) =>
+                  setDraft({ ...draft, env: parseEnv(e.target.value) })
+                }
+                placeholder={t("settings.automationHooks.hooks.env")}
+                className="font-mono text-xs min-h-[60px]"
+              />
+              <div className="flex items-center gap-2">
+                <span className="text-xs text-mid-gray">
+                  {t("settings.automationHooks.hooks.timeout")}
+                </span>
+                <Input
+                  type="number"
+                  variant="compact"
+                  min={100}
+                  max={60000}
+                  step={100}
+                  value={draft.timeout_ms}
+                  onChange={(e) =>
+                    setDraft({ ...draft, timeout_ms: Number(e.target.value) })
+                  }
+                  className="w-24"
+                />
+              </div>
+              <ToggleSwitch
+                checked={draft.inherit_env}
+                onChange={(inherit_env) => setDraft({ ...draft, inherit_env })}
+                label={t("settings.automationHooks.hooks.inheritEnv.label")}
+                description={t(
+                  "settings.automationHooks.hooks.inheritEnv.description",
+                )}
+                descriptionMode="tooltip"
+              />
+              <ToggleSwitch
+                checked={draft.enabled}
+                onChange={(enabled) => setDraft({ ...draft, enabled })}
+                label={t("settings.automationHooks.hooks.hookEnabled.label")}
+                description={t(
+                  "settings.automationHooks.hooks.hookEnabled.description",
+                )}
+                descriptionMode="tooltip"
+              />
+              <div className="flex gap-2">
+                <Input
+                  type="text"
+                  variant="compact"
+                  value={testInput}
+                  onChange={(e) => setTestInput(e.target.value)}
+                  placeholder={t("settings.automationHooks.test.input")}
+                  className="flex-1"
+                />
+                <Button
+                  onClick={runTest}
+                  variant="secondary"
+                  size="md"
+                  disabled={isTesting || !draft.command.trim()}
+                >
+                  {isTesting
+                    ? t("settings.automationHooks.test.running")
+                    : t("settings.automationHooks.test.button")}
+                </Button>
+              </div>
+              {testOutput !== null && (
+                <pre className="text-xs font-mono whitespace-pre-wrap bg-mid-gray/10 rounded p-2">
+                  {testOutput || t("settings.automationHooks.test.noOutput")}
+                </pre>
+              )}
+              <div className="flex gap-2 pt-2">
+                <Button
+                  onClick={saveDraft}
+                  variant="primary"
+                  size="md"
+                  disabled={!draft.name.trim() || !draft.command.trim()}
+                >
+                  {t("settings.automationHooks.hooks.save")}
+                </Button>
+                {hooks.some((hook) => hook.id === draft.id) && (
+                  <Button onClick={deleteDraft} variant="secondary" size="md">
+                    {t("settings.automationHooks.hooks.delete")}
+                  </Button>
+                )}
+              </div>
+            </div>
+          )}
+        </div>
+      </SettingContainer>
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,243 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import {
+  BrowserBridgeStatus,
+  commands,
+  SiteDelivery,
+  SiteRule,
+} from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+const DELIVERIES: SiteDelivery[] = ["bridge", "paste"];
+const NO_FORMATTING = "";
+const STATUS_POLL_MS = 2000;
+
+/**
+ * Bridge the companion browser extension connects to, so dictations go
+ * straight into web forms, with per-site rules
+ */
+export const BrowserBridgeSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const bridge = getSetting("browser_bridge");
+  const formattingModes = getSetting("formatting")?.modes ?? [];
+  const [port, setPort] = useState("");
+  const [extensionIds, setExtensionIds] = useState("");
+  const [rules, setRules] = useState<SiteRule[]>([]);
+  const [status, setStatus] = useState<BrowserBridgeStatus | null>(null);
+
+  useEffect(() => {
+    setPort(String(bridge?.port ?? 48214));
+    setExtensionIds((bridge?.extension_ids ?? []).join(", "));
+    setRules(bridge?.site_rules ?? []);
+  }, [bridge]);
+
+  useEffect(() => {
+    if (!bridge?.enabled) return;
+    const poll = () => commands.getBrowserBridgeStatus().then(setStatus);
+    poll();
+    const interval = setInterval(poll, STATUS_POLL_MS);
+    return () => clearInterval(interval);
+  }, [bridge?.enabled]);
+
+  const handleEnabledChange = async (enabled: boolean) => {
+    const result = await commands.changeBrowserBridgeEnabledSetting(enabled);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const savePort = async () => {
+    if (Number(port) === bridge?.port) return;
+    const result = await commands.changeBrowserBridgePortSetting(Number(port));
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const saveExtensionIds = async () => {
+    const ids = extensionIds.split(",").map((id) => id.trim());
+    const result = await commands.setBrowserExtensionIds(ids);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const installHost = async () => {
+    const result = await commands.installBrowserNativeHost();
+    if (result.status === "error") {
+      toast.error(result.error);
+      return;
+    }
+    toast.success(
+      t("settings.browserBridge.install.success", {
+        count: result.data.length,
+      }),
+    );
+  };
+
+  const saveRules = async (next: SiteRule[]) => {
+    const result = await commands.setBrowserSiteRules(next);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const edit = (index: number, changes: Partial<SiteRule>) =>
+    rules.map((r, i) => (i === index ? { ...r, ...changes } : r));
+
+  const addRule = () =>
+    setRules([
+      ...rules,
+      { host: "", delivery: "bridge", formatting_mode: null },
+    ]);
+
+  const statusText = () => {
+    if (!status?.listening) return t("settings.browserBridge.status.off");
+    if (status.focus) {
+      return t("settings.browserBridge.status.focused", {
+        host: status.focus.host,
+      });
+    }
+    return t("settings.browserBridge.status.connections", {
+      count: status.connections,
+    });
+  };
+
+  return (
+    <SettingsGroup title={t("settings.browserBridge.title")}>
+      <ToggleSwitch
+        checked={bridge?.enabled ?? false}
+        onChange={handleEnabledChange}
+        label={t("settings.browserBridge.enabled.label")}
+        description={t("settings.browserBridge.enabled.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      {bridge?.enabled && (
+        <SettingContainer
+          title={t("settings.browserBridge.status.title")}
+          description={t("settings.browserBridge.status.description")}
+          descriptionMode="tooltip"
+          layout="horizontal"
+          grouped={true}
+        >
+          <span className="text-sm text-mid-gray">{statusText()}</span>
+        </SettingContainer>
+      )}
+      <SettingContainer
+        title={t("settings.browserBridge.port.title")}
+        description={t("settings.browserBridge.port.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Input
+          type="number"
+          variant="compact"
+          min={1024}
+          max={65535}
+          value={port}
+          onChange={(e) => setPort(e.target.value)}
+          onBlur={savePort}
+        />
+      </SettingContainer>
+      <SettingContainer
+        title={t("settings.browserBridge.extensionIds.title")}
+        description={t("settings.browserBridge.extensionIds.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="flex items-center gap-2">
+          <Input
+            type="text"
+            variant="compact"
+            value={extensionIds}
+            onChange={(e) => setExtensionIds(e.target.value)}
+            onBlur={saveExtensionIds}
+            className="flex-1"
+          />
+          <Button onClick={installHost} variant="secondary" size="sm">
+            {t("settings.browserBridge.install.button")}
+          </Button>
+        </div>
+      </SettingContainer>
+      <SettingContainer
+        title={t("settings.browserBridge.siteRules.title")}
+        description={t("settings.browserBridge.siteRules.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-2">
+          {rules.map((rule, index) => (
+            <div key={index} className="flex items-center gap-2">
+              <Input
+                type="text"
+                variant="compact"
+                value={rule.host}
+                placeholder="docs.google.com"
+                onChange={(e) =>
+                  setRules(edit(index, { host: e.target.value }))
+                }
+                onBlur={() => rule.host.trim() && saveRules(rules)}
+                className="flex-1"
+              />
+              <Dropdown
+                selectedValue={rule.delivery}
+                options={DELIVERIES.map((delivery) => ({
+                  value: delivery,
+                  label: t(`settings.browserBridge.siteRules.${delivery}`),
+                }))}
+                onSelect={(value) =>
+                  saveRules(edit(index, { delivery: value as SiteDelivery }))
+                }
+              />
+              <Dropdown
+                selectedValue={rule.formatting_mode ?? NO_FORMATTING}
+                options={[
+                  {
+                    value: NO_FORMATTING,
+                    label: t("settings.browserBridge.siteRules.noFormatting"),
+                  },
+                  ...formattingModes.map((mode) => ({
+                    value: mode.id,
+                    label: mode.name,
+                  })),
+                ]}
+                onSelect={(value) =>
+                  saveRules(edit(index, { formatting_mode: value || null }))
+                }
+              />
+              <Button
+                onClick={() => saveRules(rules.filter((_, i) => i !== index))}
+                variant="secondary"
+                size="sm"
+              >
+                {t("settings.browserBridge.siteRules.remove")}
+              </Button>
+            </div>
+          ))}
+          <Button onClick={addRule} variant="secondary" size="md">
+            {t("settings.browserBridge.siteRules.add")}
+          </Button>
+        </div>
+      </SettingContainer>
+    </SettingsGroup>
+  );
+};
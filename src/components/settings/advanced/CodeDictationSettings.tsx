@@ -0,0 +1,109 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { commands, IdentifierCase } from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+/**
+ * Code-friendly dictation used when a code editor or terminal has focus
+ */
+export const CodeDictationSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const codeDictation = getSetting("code_dictation");
+  const [apps, setApps] = useState("");
+
+  useEffect(() => {
+    setApps((codeDictation?.apps ?? []).join(", "));
+  }, [codeDictation?.apps]);
+
+  const handleEnabledChange = async (enabled: boolean) => {
+    await commands.changeCodeDictationEnabledSetting(enabled);
+    await refreshSettings();
+  };
+
+  const handleCaseChange = async (value: string) => {
+    await commands.changeCodeDictationIdentifierCaseSetting(
+      value as IdentifierCase,
+    );
+    await refreshSettings();
+  };
+
+  const handleDirectPasteChange = async (enabled: boolean) => {
+    await commands.changeCodeDictationDirectPasteSetting(enabled);
+    await refreshSettings();
+  };
+
+  const saveApps = async () => {
+    await commands.changeCodeDictationAppsSetting(apps.split(","));
+    await refreshSettings();
+  };
+
+  return (
+    <SettingsGroup title={t("settings.codeDictation.title")}>
+      <ToggleSwitch
+        checked={codeDictation?.enabled ?? true}
+        onChange={handleEnabledChange}
+        label={t("settings.codeDictation.enabled.label")}
+        description={t("settings.codeDictation.enabled.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <SettingContainer
+        title={t("settings.codeDictation.apps.title")}
+        description={t("settings.codeDictation.apps.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <Input
+          type="text"
+          variant="compact"
+          value={apps}
+          onChange={(e) => setApps(e.target.value)}
+          onBlur={saveApps}
+          disabled={!codeDictation?.enabled}
+        />
+      </SettingContainer>
+      <SettingContainer
+        title={t("settings.codeDictation.identifierCase.title")}
+        description={t("settings.codeDictation.identifierCase.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Dropdown
+          selectedValue={codeDictation?.identifier_case ?? "snake"}
+          options={[
+            {
+              value: "snake",
+              label: t("settings.codeDictation.identifierCase.snake"),
+            },
+            {
+              value: "camel",
+              label: t("settings.codeDictation.identifierCase.camel"),
+            },
+          ]}
+          onSelect={handleCaseChange}
+          disabled={!codeDictation?.enabled}
+        />
+      </SettingContainer>
+      <ToggleSwitch
+        checked={codeDictation?.prefer_direct_paste ?? true}
+        onChange={handleDirectPasteChange}
+        disabled={!codeDictation?.enabled}
+        label={t("settings.codeDictation.directPaste.label")}
+        description={t("settings.codeDictation.directPaste.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,214 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, ExportKind, ExportTemplate } from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  Textarea,
+} from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+const KINDS: ExportKind[] = [
+  "transcript",
+  "meeting_summary",
+  "action_items",
+  "ask_ai_conversation",
+];
+
+const isBuiltin = (template: ExportTemplate) =>
+  template.id.startsWith("builtin_");
+
+const emptyTemplate = (kind: ExportKind): ExportTemplate => ({
+  id: "",
+  name: "",
+  kind,
+  body: "",
+  file_extension: "md",
+});
+
+/**
+ * Templates used to export transcripts, summaries, action items and Ask AI
+ * conversations, and the webhook exports can be sent to
+ */
+export const ExportTemplateSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const [templates, setTemplates] = useState<ExportTemplate[]>([]);
+  const [draft, setDraft] = useState<ExportTemplate | null>(null);
+  const [webhookUrl, setWebhookUrl] = useState(
+    getSetting("export")?.webhook_url ?? "",
+  );
+
+  const loadTemplates = useCallback(async () => {
+    setTemplates(await commands.getExportTemplates(null));
+  }, []);
+
+  useEffect(() => {
+    loadTemplates();
+  }, [loadTemplates]);
+
+  const saveWebhookUrl = async () => {
+    const result = await commands.changeExportWebhookUrlSetting(
+      webhookUrl.trim() || null,
+    );
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const saveDraft = async () => {
+    if (!draft) return;
+    // Built-in templates are read-only, so edits to them are saved as a copy
+    const template = isBuiltin(draft) ? { ...draft, id: "" } : draft;
+    const result = await commands.saveExportTemplate(template);
+    if (result.status === "error") {
+      toast.error(result.error);
+      return;
+    }
+    setDraft(result.data);
+    await loadTemplates();
+  };
+
+  const deleteDraft = async () => {
+    if (!draft || !draft.id) return;
+    const result = await commands.deleteExportTemplate(draft.id);
+    if (result.status === "error") {
+      toast.error(result.error);
+      return;
+    }
+    setDraft(null);
+    await loadTemplates();
+  };
+
+  const kindLabel = (kind: ExportKind) =>
+    t(`settings.exportTemplates.kinds.${kind}`);
+
+  return (
+    <SettingsGroup title={t("settings.exportTemplates.title")}>
+      <SettingContainer
+        title={t("settings.exportTemplates.webhook.title")}
+        description={t("settings.exportTemplates.webhook.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Input
+          type="url"
+          variant="compact"
+          value={webhookUrl}
+          onChange={(e) => setWebhookUrl(e.target.value)}
+          onBlur={saveWebhookUrl}
+          placeholder="https://"
+          className="min-w-[240px]"
+        />
+      </SettingContainer>
+
+      <SettingContainer
+        title={t("settings.exportTemplates.templates.title")}
+        description={t("settings.exportTemplates.templates.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-3">
+          <div className="flex gap-2">
+            <Dropdown
+              selectedValue={draft?.id || null}
+              options={templates.map((template) => ({
+                value: template.id,
+                label: `${kindLabel(template.kind)}: ${template.name}`,
+              }))}
+              onSelect={(id) =>
+                setDraft(
+                  templates.find((template) => template.id === id) ?? null,
+                )
+              }
+              placeholder={t("settings.exportTemplates.templates.select")}
+              className="flex-1"
+            />
+            <Button
+              onClick={() =>
+                setDraft(emptyTemplate(draft?.kind ?? "transcript"))
+              }
+              variant="primary"
+              size="md"
+            >
+              {t("settings.exportTemplates.templates.createNew")}
+            </Button>
+          </div>
+
+          {draft && (
+            <div className="space-y-3">
+              <div className="flex gap-2">
+                <Input
+                  type="text"
+                  variant="compact"
+                  value={draft.name}
+                  onChange={(e) =>
+                    setDraft({ ...draft, name: e.target.value })
+                  }
+                  placeholder={t("settings.exportTemplates.templates.name")}
+                  className="flex-1"
+                />
+                <Input
+                  type="text"
+                  variant="compact"
+                  value={draft.file_extension}
+                  onChange={(e) =>
+                    setDraft({ ...draft, file_extension: e.target.value })
+                  }
+                  placeholder={t(
+                    "settings.exportTemplates.templates.extension",
+                  )}
+                  className="w-20"
+                />
+                <Dropdown
+                  selectedValue={draft.kind}
+                  options={KINDS.map((kind) => ({
+                    value: kind,
+                    label: kindLabel(kind),
+                  }))}
+                  onSelect={(kind) =>
+                    kind && setDraft({ ...draft, kind: kind as ExportKind })
+                  }
+                  disabled={!!draft.id}
+                />
+              </div>
+              <Textarea
+                value={draft.body}
+                onChange={(e) => setDraft({ ...draft, body: e.target.value })}
+                className="font-mono text-xs min-h-[200px]"
+              />
+              <p className="text-xs text-mid-gray/70">
+                {t(`settings.exportTemplates.variables.${draft.kind}`)}
+              </p>
+              <div className="flex gap-2 pt-2">
+                <Button
+                  onClick={saveDraft}
+                  variant="primary"
+                  size="md"
+                  disabled={!draft.name.trim() || !draft.body.trim()}
+                >
+                  {isBuiltin(draft)
+                    ? t("settings.exportTemplates.templates.saveCopy")
+                    : t("settings.exportTemplates.templates.save")}
+                </Button>
+                {draft.id && !isBuiltin(draft) && (
+                  <Button onClick={deleteDraft} variant="secondary" size="md">
+                    {t("settings.exportTemplates.templates.delete")}
+                  </Button>
+                )}
+              </div>
+            </div>
+          )}
+        </div>
+      </SettingContainer>
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,136 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, FormattingMode } from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  Textarea,
+} from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { ShortcutInput as DictumShortcut } from "../ShortcutInput";
+import { useSettings } from "../../../hooks/useSettings";
+
+const NONE = "";
+
+/**
+ * Output formats, like an email or a Markdown list, that dictations are
+ * post-processed into
+ */
+export const FormattingSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const formatting = getSetting("formatting");
+  const [modes, setModes] = useState<FormattingMode[]>([]);
+
+  useEffect(() => {
+    setModes(formatting?.modes ?? []);
+  }, [formatting]);
+
+  const handleModeChange = async (modeId: string) => {
+    const result = await commands.setFormattingMode(modeId || null);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const save = async (next: FormattingMode[]) => {
+    const result = await commands.setFormattingModes(next);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const edit = (index: number, changes: Partial<FormattingMode>) =>
+    setModes(modes.map((m, i) => (i === index ? { ...m, ...changes } : m)));
+
+  const addMode = () =>
+    save([
+      ...modes,
+      {
+        id: crypto.randomUUID(),
+        name: t("settings.formatting.modes.newName"),
+        prompt: "${output}",
+      },
+    ]);
+
+  const handleReset = async () => {
+    await commands.resetFormattingModes();
+    await refreshSettings();
+  };
+
+  return (
+    <SettingsGroup title={t("settings.formatting.title")}>
+      <SettingContainer
+        title={t("settings.formatting.mode.title")}
+        description={t("settings.formatting.mode.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Dropdown
+          selectedValue={formatting?.selected_mode ?? NONE}
+          options={[
+            { value: NONE, label: t("settings.formatting.mode.none") },
+            ...modes.map((mode) => ({ value: mode.id, label: mode.name })),
+          ]}
+          onSelect={handleModeChange}
+        />
+      </SettingContainer>
+      <DictumShortcut shortcutId="cycle_formatting_mode" grouped={true} />
+      <SettingContainer
+        title={t("settings.formatting.modes.title")}
+        description={t("settings.formatting.modes.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-3">
+          {modes.map((mode, index) => (
+            <div
+              key={mode.id}
+              className="space-y-2 rounded border border-mid-gray/20 p-3"
+            >
+              <div className="flex gap-2">
+                <Input
+                  type="text"
+                  variant="compact"
+                  value={mode.name}
+                  onChange={(e) => edit(index, { name: e.target.value })}
+                  onBlur={() => save(modes)}
+                  className="flex-1"
+                />
+                <Button
+                  onClick={() => save(modes.filter((_, i) => i !== index))}
+                  variant="secondary"
+                  size="sm"
+                >
+                  {t("settings.formatting.modes.remove")}
+                </Button>
+              </div>
+              <Textarea
+                value={mode.prompt}
+                onChange={(e) => edit(index, { prompt: e.target.value })}
+                onBlur={() => save(modes)}
+                className="text-xs min-h-[60px]"
+              />
+            </div>
+          ))}
+          <div className="flex gap-2">
+            <Button onClick={addMode} variant="secondary" size="md">
+              {t("settings.formatting.modes.add")}
+            </Button>
+            <Button onClick={handleReset} variant="secondary" size="md">
+              {t("settings.formatting.modes.reset")}
+            </Button>
+          </div>
+        </div>
+      </SettingContainer>
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,104 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
+
+import { SettingContainer, SettingsGroup, ToggleSwitch } from "@/components/ui";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+/**
+ * Paragraph and sentence breaks inserted where the speaker paused
+ */
+export const ParagraphingSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const paragraphing = getSetting("paragraphing");
+  const [sentencePause, setSentencePause] = useState("");
+  const [paragraphPause, setParagraphPause] = useState("");
+
+  useEffect(() => {
+    setSentencePause(String(paragraphing?.sentence_pause_ms ?? 700));
+    setParagraphPause(String(paragraphing?.paragraph_pause_ms ?? 2000));
+  }, [paragraphing?.sentence_pause_ms, paragraphing?.paragraph_pause_ms]);
+
+  const handleEnabledChange = async (enabled: boolean) => {
+    await commands.changeParagraphingEnabledSetting(enabled);
+    await refreshSettings();
+  };
+
+  const handleSpeakerMarkersChange = async (enabled: boolean) => {
+    await commands.changeSpeakerMarkersSetting(enabled);
+    await refreshSettings();
+  };
+
+  const savePauses = async () => {
+    const result = await commands.changeParagraphingPausesSetting(
+      Number(sentencePause),
+      Number(paragraphPause),
+    );
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  return (
+    <SettingsGroup title={t("settings.paragraphing.title")}>
+      <ToggleSwitch
+        checked={paragraphing?.enabled ?? false}
+        onChange={handleEnabledChange}
+        label={t("settings.paragraphing.enabled.label")}
+        description={t("settings.paragraphing.enabled.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <SettingContainer
+        title={t("settings.paragraphing.sentencePause.title")}
+        description={t("settings.paragraphing.sentencePause.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Input
+          type="number"
+          variant="compact"
+          min={200}
+          max={10000}
+          step={100}
+          value={sentencePause}
+          onChange={(e) => setSentencePause(e.target.value)}
+          onBlur={savePauses}
+          disabled={!paragraphing?.enabled}
+        />
+      </SettingContainer>
+      <SettingContainer
+        title={t("settings.paragraphing.paragraphPause.title")}
+        description={t("settings.paragraphing.paragraphPause.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Input
+          type="number"
+          variant="compact"
+          min={200}
+          max={10000}
+          step={100}
+          value={paragraphPause}
+          onChange={(e) => setParagraphPause(e.target.value)}
+          onBlur={savePauses}
+          disabled={!paragraphing?.enabled}
+        />
+      </SettingContainer>
+      <ToggleSwitch
+        checked={paragraphing?.speaker_markers ?? false}
+        onChange={handleSpeakerMarkersChange}
+        label={t("settings.paragraphing.speakerMarkers.label")}
+        description={t("settings.paragraphing.speakerMarkers.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,193 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import {
+  commands,
+  PluginActionInfo,
+  PluginInfo,
+  PluginSettings as Plugins,
+} from "@/bindings";
+
+import { SettingContainer, SettingsGroup, ToggleSwitch } from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+/**
+ * Rhai scripts from the plugins folder that add text stages, suggestion
+ * sources and shortcut actions
+ */
+export const PluginSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const [plugins, setPlugins] = useState<PluginInfo[]>([]);
+  const [isReloading, setIsReloading] = useState(false);
+
+  const pluginSettings: Plugins = getSetting("plugins") ?? {
+    enabled: false,
+    disabled: [],
+  };
+  const bindings = getSetting("bindings") ?? {};
+
+  useEffect(() => {
+    commands.getPlugins().then(setPlugins);
+  }, []);
+
+  const save = async (changes: Partial<Plugins>) => {
+    const result = await commands.changePluginSettings({
+      ...pluginSettings,
+      ...changes,
+    });
+    await refreshSettings();
+    if (result.status === "error") {
+      toast.error(result.error);
+      return;
+    }
+    setPlugins(result.data);
+  };
+
+  const togglePlugin = (name: string, enabled: boolean) => {
+    const disabled = pluginSettings.disabled.filter((n) => n !== name);
+    save({ disabled: enabled ? disabled : [...disabled, name] });
+  };
+
+  const reload = async () => {
+    setIsReloading(true);
+    const result = await commands.reloadPlugins();
+    setIsReloading(false);
+    if (result.status === "error") {
+      toast.error(result.error);
+      return;
+    }
+    setPlugins(result.data);
+  };
+
+  const openFolder = async () => {
+    const result = await commands.openPluginsFolder();
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+  };
+
+  const runAction = async (action: PluginActionInfo) => {
+    const result = await commands.runPluginAction(action.binding_id);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+  };
+
+  const changeShortcut = async (action: PluginActionInfo, value: string) => {
+    const current = bindings[action.binding_id]?.current_binding ?? "";
+    if (value.trim() === current) return;
+    const result = await commands.changePluginActionBinding(
+      action.binding_id,
+      value.trim(),
+    );
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  return (
+    <SettingsGroup title={t("settings.plugins.title")}>
+      <ToggleSwitch
+        checked={pluginSettings.enabled}
+        onChange={(enabled) => save({ enabled })}
+        label={t("settings.plugins.enabled.label")}
+        description={t("settings.plugins.enabled.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+
+      <SettingContainer
+        title={t("settings.plugins.list.title")}
+        description={t("settings.plugins.list.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-3">
+          <div className="flex gap-2">
+            <Button onClick={openFolder} variant="secondary" size="md">
+              {t("settings.plugins.list.openFolder")}
+            </Button>
+            <Button
+              onClick={reload}
+              variant="secondary"
+              size="md"
+              disabled={isReloading}
+            >
+              {t("settings.plugins.list.reload")}
+            </Button>
+          </div>
+
+          {plugins.length === 0 && (
+            <p className="text-xs text-mid-gray">
+              {t("settings.plugins.list.empty")}
+            </p>
+          )}
+
+          {plugins.map((plugin) => (
+            <div
+              key={plugin.name}
+              className="space-y-2 rounded border border-mid-gray/20 p-3"
+            >
+              <ToggleSwitch
+                checked={plugin.enabled}
+                onChange={(enabled) => togglePlugin(plugin.name, enabled)}
+                label={plugin.name}
+                description={t("settings.plugins.list.pluginEnabled")}
+                descriptionMode="tooltip"
+                disabled={!pluginSettings.enabled}
+              />
+              {plugin.error && (
+                <p className="text-xs text-red-400 font-mono">{plugin.error}</p>
+              )}
+              {plugin.text_stages.length > 0 && (
+                <p className="text-xs text-mid-gray">
+                  {t("settings.plugins.list.textStages", {
+                    names: plugin.text_stages.join(", "),
+                  })}
+                </p>
+              )}
+              {plugin.suggestion_sources.length > 0 && (
+                <p className="text-xs text-mid-gray">
+                  {t("settings.plugins.list.suggestionSources", {
+                    names: plugin.suggestion_sources.join(", "),
+                  })}
+                </p>
+              )}
+              {plugin.actions.map((action) => (
+                <div
+                  key={action.binding_id}
+                  className="flex items-center gap-2"
+                >
+                  <span className="text-sm flex-1">{action.label}</span>
+                  <Input
+                    key={bindings[action.binding_id]?.current_binding}
+                    type="text"
+                    variant="compact"
+                    defaultValue={
+                      bindings[action.binding_id]?.current_binding ?? ""
+                    }
+                    onBlur={(e) => changeShortcut(action, e.target.value)}
+                    placeholder={t("settings.plugins.list.shortcut")}
+                    className="w-40 font-mono"
+                  />
+                  <Button
+                    onClick={() => runAction(action)}
+                    variant="secondary"
+                    size="sm"
+                  >
+                    {t("settings.plugins.list.run")}
+                  </Button>
+                </div>
+              ))}
+            </div>
+          ))}
+        </div>
+      </SettingContainer>
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,179 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { listen } from "@tauri-apps/api/event";
+import { toast } from "sonner";
+import { commands, RemotePairing, Result } from "@/bindings";
+
+import { SettingContainer, SettingsGroup, ToggleSwitch } from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+import { formatDateTime } from "@/utils/dateFormat";
+
+/**
+ * Server on the local network that lets a paired phone or browser act as a
+ * push-to-talk button and follow live captions and Ask AI answers
+ */
+export const RemoteControlSettings: React.FC = () => {
+  const { t, i18n } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const remoteControl = getSetting("remote_control");
+  const [port, setPort] = useState("");
+  const [pairing, setPairing] = useState<RemotePairing | null>(null);
+
+  useEffect(() => {
+    setPort(String(remoteControl?.port ?? 48214));
+  }, [remoteControl?.port]);
+
+  useEffect(() => {
+    const unlistenPromise = listen<string>("remote-device-paired", (event) => {
+      setPairing(null);
+      toast.success(
+        t("settings.remoteControl.pairing.paired", { name: event.payload }),
+      );
+      refreshSettings();
+    });
+    return () => {
+      unlistenPromise.then((unlisten) => unlisten());
+    };
+  }, [refreshSettings, t]);
+
+  const run = async (action: () => Promise<Result<null, string>>) => {
+    const result = await action();
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const savePort = async () => {
+    if (Number(port) === remoteControl?.port) return;
+    await run(() => commands.changeRemoteControlPortSetting(Number(port)));
+  };
+
+  const startPairing = async () => {
+    const result = await commands.startRemotePairing();
+    if (result.status === "error") {
+      toast.error(result.error);
+      return;
+    }
+    setPairing(result.data);
+  };
+
+  const cancelPairing = async () => {
+    await commands.cancelRemotePairing();
+    setPairing(null);
+  };
+
+  return (
+    <SettingsGroup title={t("settings.remoteControl.title")}>
+      <ToggleSwitch
+        checked={remoteControl?.enabled ?? false}
+        onChange={(enabled) => {
+          if (!enabled) setPairing(null);
+          run(() => commands.changeRemoteControlEnabledSetting(enabled));
+        }}
+        label={t("settings.remoteControl.enabled.label")}
+        description={t("settings.remoteControl.enabled.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <SettingContainer
+        title={t("settings.remoteControl.port.title")}
+        description={t("settings.remoteControl.port.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Input
+          type="number"
+          variant="compact"
+          min={1024}
+          max={65535}
+          value={port}
+          onChange={(e) => setPort(e.target.value)}
+          onBlur={savePort}
+        />
+      </SettingContainer>
+      <ToggleSwitch
+        checked={remoteControl?.advertise ?? true}
+        onChange={(advertise) =>
+          run(() => commands.changeRemoteControlAdvertiseSetting(advertise))
+        }
+        label={t("settings.remoteControl.advertise.label")}
+        description={t("settings.remoteControl.advertise.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <SettingContainer
+        title={t("settings.remoteControl.devices.title")}
+        description={t("settings.remoteControl.devices.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-3">
+          {pairing ? (
+            <div className="space-y-2 rounded border border-mid-gray/20 p-3">
+              <p className="text-sm">
+                {t("settings.remoteControl.pairing.instructions", {
+                  minutes: Math.round(pairing.expires_in_seconds / 60),
+                })}
+              </p>
+              {pairing.urls.map((url) => (
+                <p key={url} className="text-sm font-mono select-all">
+                  {url}
+                </p>
+              ))}
+              <p className="text-3xl font-mono tracking-widest">
+                {pairing.code}
+              </p>
+              <Button onClick={cancelPairing} variant="secondary" size="sm">
+                {t("settings.remoteControl.pairing.cancel")}
+              </Button>
+            </div>
+          ) : (
+            <Button
+              onClick={startPairing}
+              variant="primary"
+              size="md"
+              disabled={!remoteControl?.enabled}
+            >
+              {t("settings.remoteControl.pairing.start")}
+            </Button>
+          )}
+
+          {remoteControl?.devices.length === 0 && (
+            <p className="text-xs text-mid-gray">
+              {t("settings.remoteControl.devices.empty")}
+            </p>
+          )}
+          {remoteControl?.devices.map((device) => (
+            <div key={device.id} className="flex items-center gap-2">
+              <div className="flex-1">
+                <p className="text-sm">{device.name}</p>
+                <p className="text-xs text-mid-gray">
+                  {t("settings.remoteControl.devices.pairedAt", {
+                    date: formatDateTime(
+                      String(device.paired_at),
+                      i18n.language,
+                    ),
+                  })}
+                </p>
+              </div>
+              <Button
+                onClick={() =>
+                  run(() => commands.revokeRemoteDevice(device.id))
+                }
+                variant="secondary"
+                size="sm"
+              >
+                {t("settings.remoteControl.devices.revoke")}
+              </Button>
+            </div>
+          ))}
+        </div>
+      </SettingContainer>
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,195 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import {
+  commands,
+  ScheduleOverrides,
+  ScheduleRule,
+  ScheduleSettings as Schedule,
+  SoundTheme,
+} from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+const KEEP = "";
+const SOUND_THEMES: { value: SoundTheme; label: string }[] = [
+  { value: "marimba", label: "Marimba" },
+  { value: "pop", label: "Pop" },
+  { value: "custom", label: "Custom" },
+];
+
+const toOption = (value: boolean | null | undefined) =>
+  value == null ? KEEP : value ? "on" : "off";
+const fromOption = (option: string) =>
+  option === KEEP ? null : option === "on";
+
+/**
+ * Rules that override sound detection, Active Listening and the sound
+ * theme at certain times, written like cron expressions
+ */
+export const ScheduleSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const schedule = getSetting("schedule");
+  const saved: Schedule = schedule ?? { enabled: false, rules: [] };
+  const [rules, setRules] = useState<ScheduleRule[]>([]);
+
+  useEffect(() => {
+    setRules(schedule?.rules ?? []);
+  }, [schedule]);
+
+  const save = async (next: Schedule) => {
+    const result = await commands.setScheduleSettings(next);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const editRule = (index: number, changes: Partial<ScheduleRule>) =>
+    setRules(rules.map((r, i) => (i === index ? { ...r, ...changes } : r)));
+
+  const saveOverride = (
+    index: number,
+    changes: Partial<ScheduleOverrides>,
+  ) => {
+    const next = rules.map((r, i) =>
+      i === index ? { ...r, overrides: { ...r.overrides, ...changes } } : r,
+    );
+    setRules(next);
+    save({ ...saved, rules: next });
+  };
+
+  const addRule = () =>
+    save({
+      ...saved,
+      rules: [
+        ...rules,
+        {
+          name: t("settings.schedule.rules.newName"),
+          when: "* 22-6 * * *",
+          overrides: {
+            sound_detection: null,
+            active_listening: null,
+            sound_theme: null,
+          },
+        },
+      ],
+    });
+
+  const removeRule = (index: number) =>
+    save({ ...saved, rules: rules.filter((_, i) => i !== index) });
+
+  const switchOptions = [
+    { value: KEEP, label: t("settings.schedule.rules.keep") },
+    { value: "on", label: t("settings.schedule.rules.on") },
+    { value: "off", label: t("settings.schedule.rules.off") },
+  ];
+  const themeOptions = [
+    { value: KEEP, label: t("settings.schedule.rules.keep") },
+    ...SOUND_THEMES,
+  ];
+
+  return (
+    <SettingsGroup title={t("settings.schedule.title")}>
+      <ToggleSwitch
+        checked={saved.enabled}
+        onChange={(enabled) => save({ ...saved, enabled })}
+        label={t("settings.schedule.enabled.label")}
+        description={t("settings.schedule.enabled.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <SettingContainer
+        title={t("settings.schedule.rules.title")}
+        description={t("settings.schedule.rules.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-3">
+          {rules.map((rule, index) => (
+            <div
+              key={index}
+              className="space-y-2 rounded border border-mid-gray/20 p-3"
+            >
+              <div className="flex gap-2">
+                <Input
+                  type="text"
+                  variant="compact"
+                  value={rule.name}
+                  onChange={(e) => editRule(index, { name: e.target.value })}
+                  onBlur={() => save({ ...saved, rules })}
+                  className="flex-1"
+                />
+                <Input
+                  type="text"
+                  variant="compact"
+                  value={rule.when}
+                  onChange={(e) => editRule(index, { when: e.target.value })}
+                  onBlur={() => save({ ...saved, rules })}
+                  placeholder="* 22-6 * * *"
+                  className="w-40 font-mono"
+                />
+                <Button
+                  onClick={() => removeRule(index)}
+                  variant="secondary"
+                  size="sm"
+                >
+                  {t("settings.schedule.rules.remove")}
+                </Button>
+              </div>
+              <div className="grid grid-cols-[1fr_auto] items-center gap-2">
+                <span className="text-sm">
+                  {t("settings.schedule.rules.soundDetection")}
+                </span>
+                <Dropdown
+                  selectedValue={toOption(rule.overrides.sound_detection)}
+                  options={switchOptions}
+                  onSelect={(option) =>
+                    saveOverride(index, { sound_detection: fromOption(option) })
+                  }
+                />
+                <span className="text-sm">
+                  {t("settings.schedule.rules.activeListening")}
+                </span>
+                <Dropdown
+                  selectedValue={toOption(rule.overrides.active_listening)}
+                  options={switchOptions}
+                  onSelect={(option) =>
+                    saveOverride(index, {
+                      active_listening: fromOption(option),
+                    })
+                  }
+                />
+                <span className="text-sm">
+                  {t("settings.schedule.rules.soundTheme")}
+                </span>
+                <Dropdown
+                  selectedValue={rule.overrides.sound_theme ?? KEEP}
+                  options={themeOptions}
+                  onSelect={(theme) =>
+                    saveOverride(index, {
+                      sound_theme: (theme || null) as SoundTheme | null,
+                    })
+                  }
+                />
+              </div>
+            </div>
+          ))}
+          <Button onClick={addRule} variant="secondary" size="md">
+            {t("settings.schedule.rules.add")}
+          </Button>
+        </div>
+      </SettingContainer>
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,70 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { type } from "@tauri-apps/plugin-os";
+import { toast } from "sonner";
+import { commands, UnixSignal } from "@/bindings";
+
+import { Dropdown, SettingContainer, SettingsGroup } from "@/components/ui";
+import { useSettings } from "../../../hooks/useSettings";
+
+const SIGNALS: UnixSignal[] = ["usr1", "usr2"];
+const NONE = "none";
+
+/**
+ * Shortcut actions triggered by SIGUSR1 and SIGUSR2, for window managers
+ * and hotkey daemons that keep their own keybindings
+ */
+export const SignalSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const signals = getSetting("signals");
+  const [bindingIds, setBindingIds] = useState<string[]>([]);
+
+  useEffect(() => {
+    commands.getSignalBindingOptions().then(setBindingIds);
+  }, []);
+
+  if (type() === "windows") return null;
+
+  const options = [
+    { value: NONE, label: t("settings.signals.none") },
+    ...bindingIds.map((id) => ({
+      value: id,
+      label: t(`settings.general.shortcut.bindings.${id}.name`, {
+        defaultValue: id,
+      }),
+    })),
+  ];
+
+  const handleSelect = async (signal: UnixSignal, value: string) => {
+    const result = await commands.changeSignalBindingSetting(
+      signal,
+      value === NONE ? null : value,
+    );
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  return (
+    <SettingsGroup title={t("settings.signals.title")}>
+      {SIGNALS.map((signal) => (
+        <SettingContainer
+          key={signal}
+          title={t(`settings.signals.${signal}.title`)}
+          description={t(`settings.signals.${signal}.description`)}
+          descriptionMode="tooltip"
+          layout="horizontal"
+          grouped={true}
+        >
+          <Dropdown
+            selectedValue={signals?.[signal] ?? NONE}
+            options={options}
+            onSelect={(value) => handleSelect(signal, value)}
+          />
+        </SettingContainer>
+      ))}
+    </SettingsGroup>
+  );
+};
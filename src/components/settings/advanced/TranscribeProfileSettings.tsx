@@ -0,0 +1,217 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, PasteMethod, TranscribeProfile } from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+import { useOsType } from "../../../hooks/useOsType";
+import { LANGUAGES } from "../../../lib/constants/languages";
+
+const DEFAULT = "";
+const PASTE_METHODS: PasteMethod[] = [
+  "ctrl_v",
+  "direct",
+  "none",
+  "ctrl_shift_v",
+  "shift_insert",
+];
+const PASTE_METHOD_LABELS: Record<PasteMethod, string> = {
+  ctrl_v: "clipboard",
+  direct: "direct",
+  none: "none",
+  ctrl_shift_v: "clipboardCtrlShiftV",
+  shift_insert: "clipboardShiftInsert",
+};
+
+/**
+ * Extra transcribe shortcuts, each with its own language, post-process
+ * prompt and paste method
+ */
+export const TranscribeProfileSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const osType = useOsType();
+  const bindings = getSetting("bindings") ?? {};
+  const prompts = getSetting("post_process_prompts") ?? [];
+  const savedProfiles = getSetting("transcribe_profiles");
+  const [profiles, setProfiles] = useState<TranscribeProfile[]>([]);
+
+  useEffect(() => {
+    setProfiles(savedProfiles ?? []);
+  }, [savedProfiles]);
+
+  const save = async (next: TranscribeProfile[]) => {
+    const result = await commands.setTranscribeProfiles(next);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const edit = (index: number, changes: Partial<TranscribeProfile>) =>
+    profiles.map((p, i) => (i === index ? { ...p, ...changes } : p));
+
+  const addProfile = () =>
+    save([
+      ...profiles,
+      {
+        id: crypto.randomUUID(),
+        name: t("settings.transcribeProfiles.newName"),
+        language: null,
+        post_process: false,
+        post_process_prompt_id: null,
+        paste_method: null,
+      },
+    ]);
+
+  const shortcutOf = (profile: TranscribeProfile) =>
+    bindings[`transcribe_profile:${profile.id}`]?.current_binding ?? "";
+
+  const changeShortcut = async (profile: TranscribeProfile, value: string) => {
+    if (value.trim() === shortcutOf(profile)) return;
+    const result = await commands.changeTranscribeProfileBinding(
+      profile.id,
+      value.trim(),
+    );
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const modifier = osType === "macos" ? "Cmd" : "Ctrl";
+  const pasteMethodOptions = [
+    { value: DEFAULT, label: t("settings.transcribeProfiles.default") },
+    ...PASTE_METHODS.map((method) => ({
+      value: method,
+      label: t(
+        `settings.advanced.pasteMethod.options.${PASTE_METHOD_LABELS[method]}`,
+        { modifier },
+      ),
+    })),
+  ];
+  const languageOptions = [
+    { value: DEFAULT, label: t("settings.transcribeProfiles.default") },
+    ...LANGUAGES,
+  ];
+  const promptOptions = [
+    { value: DEFAULT, label: t("settings.transcribeProfiles.selectedPrompt") },
+    ...prompts.map((prompt) => ({ value: prompt.id, label: prompt.name })),
+  ];
+
+  return (
+    <SettingsGroup title={t("settings.transcribeProfiles.title")}>
+      <SettingContainer
+        title={t("settings.transcribeProfiles.list.title")}
+        description={t("settings.transcribeProfiles.list.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-3">
+          {profiles.map((profile, index) => (
+            <div
+              key={profile.id}
+              className="space-y-2 rounded border border-mid-gray/20 p-3"
+            >
+              <div className="flex gap-2">
+                <Input
+                  type="text"
+                  variant="compact"
+                  value={profile.name}
+                  onChange={(e) =>
+                    setProfiles(edit(index, { name: e.target.value }))
+                  }
+                  onBlur={() => save(profiles)}
+                  className="flex-1"
+                />
+                <Input
+                  key={shortcutOf(profile)}
+                  type="text"
+                  variant="compact"
+                  defaultValue={shortcutOf(profile)}
+                  onBlur={(e) => changeShortcut(profile, e.target.value)}
+                  placeholder={t("settings.transcribeProfiles.shortcut")}
+                  className="w-40 font-mono"
+                />
+                <Button
+                  onClick={() => save(profiles.filter((_, i) => i !== index))}
+                  variant="secondary"
+                  size="sm"
+                >
+                  {t("settings.transcribeProfiles.remove")}
+                </Button>
+              </div>
+              <div className="flex items-center gap-2">
+                <span className="text-sm flex-1">
+                  {t("settings.transcribeProfiles.language")}
+                </span>
+                <Dropdown
+                  selectedValue={profile.language ?? DEFAULT}
+                  options={languageOptions}
+                  onSelect={(value) =>
+                    save(edit(index, { language: value || null }))
+                  }
+                />
+              </div>
+              <div className="flex items-center gap-2">
+                <span className="text-sm flex-1">
+                  {t("settings.transcribeProfiles.pasteMethod")}
+                </span>
+                <Dropdown
+                  selectedValue={profile.paste_method ?? DEFAULT}
+                  options={pasteMethodOptions}
+                  onSelect={(value) =>
+                    save(
+                      edit(index, {
+                        paste_method: (value || null) as PasteMethod | null,
+                      }),
+                    )
+                  }
+                />
+              </div>
+              <ToggleSwitch
+                checked={profile.post_process}
+                onChange={(postProcess) =>
+                  save(edit(index, { post_process: postProcess }))
+                }
+                label={t("settings.transcribeProfiles.postProcess.label")}
+                description={t(
+                  "settings.transcribeProfiles.postProcess.description",
+                )}
+                descriptionMode="tooltip"
+              />
+              {profile.post_process && (
+                <div className="flex items-center gap-2">
+                  <span className="text-sm flex-1">
+                    {t("settings.transcribeProfiles.prompt")}
+                  </span>
+                  <Dropdown
+                    selectedValue={profile.post_process_prompt_id ?? DEFAULT}
+                    options={promptOptions}
+                    onSelect={(value) =>
+                      save(
+                        edit(index, { post_process_prompt_id: value || null }),
+                      )
+                    }
+                  />
+                </div>
+              )}
+            </div>
+          ))}
+          <Button onClick={addProfile} variant="secondary" size="md">
+            {t("settings.transcribeProfiles.add")}
+          </Button>
+        </div>
+      </SettingContainer>
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,192 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, RemoteSttProvider, Result } from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+const LOCAL_BACKEND = "local";
+const USE_DEFAULT = "";
+
+// Shortcuts that transcribe speech and can pick their own backend
+const DICTATION_BINDINGS = [
+  "transcribe",
+  "transcribe_with_post_process",
+  "ask_ai",
+];
+
+/**
+ * Where dictations are transcribed: the local model or a remote
+ * speech-to-text service, for every shortcut or per shortcut
+ */
+export const TranscriptionBackendSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const [providers, setProviders] = useState<RemoteSttProvider[]>([]);
+  const [editedProviderId, setEditedProviderId] = useState<string | null>(
+    null,
+  );
+  const [apiKey, setApiKey] = useState("");
+  const [model, setModel] = useState("");
+  const [baseUrl, setBaseUrl] = useState("");
+
+  const backendSettings = getSetting("transcription_backend");
+  const bindings = getSetting("bindings") ?? {};
+  const defaultBackend = backendSettings?.default_backend ?? LOCAL_BACKEND;
+
+  // The provider being configured, the default one unless another is picked
+  const providerId =
+    editedProviderId ??
+    (defaultBackend === LOCAL_BACKEND ? providers[0]?.id : defaultBackend);
+  const provider = providers.find((p) => p.id === providerId) ?? null;
+
+  useEffect(() => {
+    commands.getRemoteSttProviders().then(setProviders);
+  }, []);
+
+  useEffect(() => {
+    if (!provider) return;
+    setApiKey(backendSettings?.api_keys[provider.id] ?? "");
+    setModel(backendSettings?.models[provider.id] ?? "");
+    setBaseUrl(backendSettings?.base_urls[provider.id] ?? "");
+  }, [provider?.id, backendSettings]);
+
+  const backendOptions = [
+    { value: LOCAL_BACKEND, label: t("settings.transcriptionBackend.local") },
+    ...providers.map((p) => ({ value: p.id, label: p.label })),
+  ];
+
+  const run = async (action: () => Promise<Result<null, string>>) => {
+    const result = await action();
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const setBackend = (bindingId: string | null, backend: string | null) =>
+    run(() => commands.setTranscriptionBackend(bindingId, backend));
+
+  const saveApiKey = () =>
+    provider &&
+    run(() => commands.changeRemoteSttApiKeySetting(provider.id, apiKey));
+  const saveModel = () =>
+    provider &&
+    run(() => commands.changeRemoteSttModelSetting(provider.id, model));
+  const saveBaseUrl = () =>
+    provider &&
+    run(() => commands.changeRemoteSttBaseUrlSetting(provider.id, baseUrl));
+
+  const handleFallbackChange = async (enabled: boolean) => {
+    await commands.changeRemoteSttFallbackSetting(enabled);
+    await refreshSettings();
+  };
+
+  return (
+    <SettingsGroup title={t("settings.transcriptionBackend.title")}>
+      <SettingContainer
+        title={t("settings.transcriptionBackend.default.title")}
+        description={t("settings.transcriptionBackend.default.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Dropdown
+          selectedValue={defaultBackend}
+          options={backendOptions}
+          onSelect={(backend) => setBackend(null, backend)}
+        />
+      </SettingContainer>
+
+      {DICTATION_BINDINGS.filter((id) => bindings[id]).map((bindingId) => (
+        <SettingContainer
+          key={bindingId}
+          title={bindings[bindingId].name}
+          description={t("settings.transcriptionBackend.binding.description")}
+          descriptionMode="tooltip"
+          layout="horizontal"
+          grouped={true}
+        >
+          <Dropdown
+            selectedValue={
+              backendSettings?.binding_backends[bindingId] ?? USE_DEFAULT
+            }
+            options={[
+              {
+                value: USE_DEFAULT,
+                label: t("settings.transcriptionBackend.binding.useDefault"),
+              },
+              ...backendOptions,
+            ]}
+            onSelect={(backend) => setBackend(bindingId, backend || null)}
+          />
+        </SettingContainer>
+      ))}
+
+      <SettingContainer
+        title={t("settings.transcriptionBackend.provider.title")}
+        description={t("settings.transcriptionBackend.provider.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-3">
+          <Dropdown
+            selectedValue={provider?.id ?? null}
+            options={providers.map((p) => ({ value: p.id, label: p.label }))}
+            onSelect={setEditedProviderId}
+          />
+          {provider && (
+            <div className="space-y-2">
+              <Input
+                type="password"
+                variant="compact"
+                value={apiKey}
+                onChange={(e) => setApiKey(e.target.value)}
+                onBlur={saveApiKey}
+                placeholder={t("settings.transcriptionBackend.provider.apiKey")}
+                className="w-full"
+              />
+              <Input
+                type="text"
+                variant="compact"
+                value={model}
+                onChange={(e) => setModel(e.target.value)}
+                onBlur={saveModel}
+                placeholder={provider.default_model}
+                className="w-full"
+              />
+              {provider.allow_base_url_edit && (
+                <Input
+                  type="url"
+                  variant="compact"
+                  value={baseUrl}
+                  onChange={(e) => setBaseUrl(e.target.value)}
+                  onBlur={saveBaseUrl}
+                  placeholder={provider.base_url}
+                  className="w-full"
+                />
+              )}
+            </div>
+          )}
+        </div>
+      </SettingContainer>
+
+      <ToggleSwitch
+        checked={backendSettings?.fallback_to_local ?? true}
+        onChange={handleFallbackChange}
+        label={t("settings.transcriptionBackend.fallback.label")}
+        description={t("settings.transcriptionBackend.fallback.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,166 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, Result } from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+const OLLAMA_PROVIDER = "ollama";
+const APPLE_INTELLIGENCE_PROVIDER = "apple_intelligence";
+const SELECTED_PROVIDER = "";
+
+// Shortcuts that paste dictations and can translate into their own language
+const DICTATION_BINDINGS = ["transcribe", "transcribe_with_post_process"];
+
+/**
+ * Dictations translated into another language by an LLM before pasting,
+ * with the original kept in history
+ */
+export const TranslationSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const translation = getSetting("translation");
+  const providers = getSetting("post_process_providers") ?? [];
+  const bindings = getSetting("bindings") ?? {};
+  const [language, setLanguage] = useState("");
+  const [bindingLanguages, setBindingLanguages] = useState<
+    Record<string, string>
+  >({});
+  const [model, setModel] = useState("");
+
+  useEffect(() => {
+    setLanguage(translation?.target_language ?? "");
+    setBindingLanguages(
+      Object.fromEntries(
+        Object.entries(translation?.binding_languages ?? {}).map(
+          ([id, value]) => [id, value ?? ""],
+        ),
+      ),
+    );
+    setModel(translation?.model ?? "");
+  }, [translation]);
+
+  const run = async (action: () => Promise<Result<null, string>>) => {
+    const result = await action();
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const handleEnabledChange = async (enabled: boolean) => {
+    await commands.changeTranslationEnabledSetting(enabled);
+    await refreshSettings();
+  };
+
+  const saveLanguage = (bindingId: string | null, value: string) =>
+    run(() => commands.setTranslationLanguage(bindingId, value));
+
+  const saveProvider = (providerId: string | null, value: string) =>
+    run(() =>
+      commands.changeTranslationProviderSetting(providerId, value || null),
+    );
+
+  const providerOptions = [
+    {
+      value: SELECTED_PROVIDER,
+      label: t("settings.translation.provider.selected"),
+    },
+    { value: OLLAMA_PROVIDER, label: "Ollama" },
+    ...providers
+      .filter((p) => p.id !== APPLE_INTELLIGENCE_PROVIDER)
+      .map((p) => ({ value: p.id, label: p.label })),
+  ];
+
+  return (
+    <SettingsGroup title={t("settings.translation.title")}>
+      <ToggleSwitch
+        checked={translation?.enabled ?? false}
+        onChange={handleEnabledChange}
+        label={t("settings.translation.enabled.label")}
+        description={t("settings.translation.enabled.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <SettingContainer
+        title={t("settings.translation.language.title")}
+        description={t("settings.translation.language.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Input
+          type="text"
+          variant="compact"
+          value={language}
+          onChange={(e) => setLanguage(e.target.value)}
+          onBlur={() => saveLanguage(null, language)}
+          placeholder={t("settings.translation.language.placeholder")}
+          disabled={!translation?.enabled}
+        />
+      </SettingContainer>
+
+      {DICTATION_BINDINGS.filter((id) => bindings[id]).map((bindingId) => (
+        <SettingContainer
+          key={bindingId}
+          title={bindings[bindingId].name}
+          description={t("settings.translation.binding.description")}
+          descriptionMode="tooltip"
+          layout="horizontal"
+          grouped={true}
+        >
+          <Input
+            type="text"
+            variant="compact"
+            value={bindingLanguages[bindingId] ?? ""}
+            onChange={(e) =>
+              setBindingLanguages({
+                ...bindingLanguages,
+                [bindingId]: e.target.value,
+              })
+            }
+            onBlur={() =>
+              saveLanguage(bindingId, bindingLanguages[bindingId] ?? "")
+            }
+            placeholder={t("settings.translation.binding.useDefault")}
+            disabled={!translation?.enabled}
+          />
+        </SettingContainer>
+      ))}
+
+      <SettingContainer
+        title={t("settings.translation.provider.title")}
+        description={t("settings.translation.provider.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-2">
+          <Dropdown
+            selectedValue={translation?.provider_id ?? SELECTED_PROVIDER}
+            options={providerOptions}
+            onSelect={(providerId) => saveProvider(providerId || null, model)}
+            disabled={!translation?.enabled}
+          />
+          <Input
+            type="text"
+            variant="compact"
+            value={model}
+            onChange={(e) => setModel(e.target.value)}
+            onBlur={() => saveProvider(translation?.provider_id ?? null, model)}
+            placeholder={t("settings.translation.provider.model")}
+            className="w-full"
+            disabled={!translation?.enabled}
+          />
+        </div>
+      </SettingContainer>
+    </SettingsGroup>
+  );
+};
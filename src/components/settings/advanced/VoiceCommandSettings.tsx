@@ -0,0 +1,170 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, VoiceCommand, VoiceCommandAction } from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+type ActionType = VoiceCommandAction["type"];
+
+const ACTION_TYPES: ActionType[] = [
+  "new_line",
+  "new_paragraph",
+  "insert",
+  "delete_last_sentence",
+  "delete_last_word",
+  "capitalize_next",
+  "uppercase_next",
+];
+
+const toAction = (type: ActionType): VoiceCommandAction =>
+  type === "insert" ? { type, text: "" } : ({ type } as VoiceCommandAction);
+
+/**
+ * Spoken commands like "new paragraph" or "delete that" that edit the
+ * dictation before it's pasted
+ */
+export const VoiceCommandSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const voiceCommands = getSetting("voice_commands");
+  const [grammar, setGrammar] = useState<VoiceCommand[]>([]);
+
+  useEffect(() => {
+    setGrammar(voiceCommands?.commands ?? []);
+  }, [voiceCommands]);
+
+  const handleEnabledChange = async (enabled: boolean) => {
+    await commands.changeVoiceCommandsEnabledSetting(enabled);
+    await refreshSettings();
+  };
+
+  const save = async (next: VoiceCommand[]) => {
+    const result = await commands.setVoiceCommands(next);
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const edit = (index: number, changes: Partial<VoiceCommand>) =>
+    setGrammar(grammar.map((c, i) => (i === index ? { ...c, ...changes } : c)));
+
+  const saveAction = (index: number, action: VoiceCommandAction) => {
+    const next = grammar.map((c, i) => (i === index ? { ...c, action } : c));
+    setGrammar(next);
+    // A new insert command has no text yet, so wait for it to be typed
+    if (action.type !== "insert" || action.text) {
+      save(next);
+    }
+  };
+
+  const handleReset = async () => {
+    await commands.resetVoiceCommands();
+    await refreshSettings();
+  };
+
+  const actionOptions = ACTION_TYPES.map((type) => ({
+    value: type,
+    label: t(`settings.voiceCommands.actions.${type}`),
+  }));
+  const disabled = !voiceCommands?.enabled;
+
+  return (
+    <SettingsGroup title={t("settings.voiceCommands.title")}>
+      <ToggleSwitch
+        checked={voiceCommands?.enabled ?? false}
+        onChange={handleEnabledChange}
+        label={t("settings.voiceCommands.enabled.label")}
+        description={t("settings.voiceCommands.enabled.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <SettingContainer
+        title={t("settings.voiceCommands.grammar.title")}
+        description={t("settings.voiceCommands.grammar.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-2">
+          {grammar.map((command, index) => (
+            <div key={index} className="flex items-center gap-2">
+              <Input
+                type="text"
+                variant="compact"
+                value={command.phrase}
+                onChange={(e) => edit(index, { phrase: e.target.value })}
+                onBlur={() => save(grammar)}
+                disabled={disabled}
+                className="flex-1"
+              />
+              <Dropdown
+                selectedValue={command.action.type}
+                options={actionOptions}
+                onSelect={(type) =>
+                  saveAction(index, toAction(type as ActionType))
+                }
+                disabled={disabled}
+              />
+              {command.action.type === "insert" && (
+                <Input
+                  type="text"
+                  variant="compact"
+                  value={command.action.text}
+                  onChange={(e) =>
+                    edit(index, {
+                      action: { type: "insert", text: e.target.value },
+                    })
+                  }
+                  onBlur={() => save(grammar)}
+                  disabled={disabled}
+                  className="w-16"
+                />
+              )}
+              <Button
+                onClick={() => save(grammar.filter((_, i) => i !== index))}
+                variant="secondary"
+                size="sm"
+                disabled={disabled}
+              >
+                {t("settings.voiceCommands.grammar.remove")}
+              </Button>
+            </div>
+          ))}
+          <div className="flex gap-2">
+            <Button
+              onClick={() =>
+                setGrammar([
+                  ...grammar,
+                  { phrase: "", action: toAction("new_line") },
+                ])
+              }
+              variant="secondary"
+              size="md"
+              disabled={disabled}
+            >
+              {t("settings.voiceCommands.grammar.add")}
+            </Button>
+            <Button
+              onClick={handleReset}
+              variant="secondary"
+              size="md"
+              disabled={disabled}
+            >
+              {t("settings.voiceCommands.grammar.reset")}
+            </Button>
+          </div>
+        </div>
+      </SettingContainer>
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,141 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { open } from "@tauri-apps/plugin-dialog";
+import { toast } from "sonner";
+import {
+  commands,
+  WakeWordAction,
+  WakeWordSettings as WakeWordSettingsType,
+} from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  Slider,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { useSettings } from "../../../hooks/useSettings";
+
+const DEFAULT_SETTINGS: WakeWordSettingsType = {
+  enabled: false,
+  model_path: null,
+  sensitivity: 0.5,
+  action: "transcribe",
+  silence_timeout_ms: 1500,
+};
+const ACTIONS: WakeWordAction[] = ["transcribe", "ask_ai"];
+
+/**
+ * Saying a keyword to start dictating, heard on the always-on microphone
+ */
+export const WakeWordSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+
+  const wakeWord = getSetting("wake_word") ?? DEFAULT_SETTINGS;
+  const alwaysOn = getSetting("always_on_microphone") ?? false;
+  const modelName = wakeWord.model_path?.split(/[\\/]/).pop();
+
+  const update = async (changes: Partial<WakeWordSettingsType>) => {
+    const result = await commands.setWakeWordSettings({
+      ...wakeWord,
+      ...changes,
+    });
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const handleChooseModel = async () => {
+    const selected = await open({
+      multiple: false,
+      filters: [
+        {
+          name: t("settings.wakeWord.model.filter"),
+          extensions: ["onnx"],
+        },
+      ],
+    });
+    if (typeof selected === "string") {
+      await update({ model_path: selected });
+    }
+  };
+
+  return (
+    <SettingsGroup title={t("settings.wakeWord.title")}>
+      <ToggleSwitch
+        checked={wakeWord.enabled}
+        onChange={(enabled) => update({ enabled })}
+        disabled={!wakeWord.enabled && (!alwaysOn || !wakeWord.model_path)}
+        label={t("settings.wakeWord.enabled.label")}
+        description={
+          alwaysOn
+            ? t("settings.wakeWord.enabled.description")
+            : t("settings.wakeWord.enabled.needsAlwaysOn")
+        }
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <SettingContainer
+        title={t("settings.wakeWord.model.title")}
+        description={t("settings.wakeWord.model.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <div className="flex items-center gap-2">
+          <span className="text-xs text-mid-gray truncate max-w-[160px]">
+            {modelName ?? t("settings.wakeWord.model.none")}
+          </span>
+          <Button variant="secondary" size="sm" onClick={handleChooseModel}>
+            {t("settings.wakeWord.model.choose")}
+          </Button>
+        </div>
+      </SettingContainer>
+      <SettingContainer
+        title={t("settings.wakeWord.action.title")}
+        description={t("settings.wakeWord.action.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Dropdown
+          selectedValue={wakeWord.action}
+          options={ACTIONS.map((action) => ({
+            value: action,
+            label: t(`settings.wakeWord.action.${action}`),
+          }))}
+          onSelect={(value) =>
+            value && update({ action: value as WakeWordAction })
+          }
+        />
+      </SettingContainer>
+      <Slider
+        value={wakeWord.sensitivity}
+        onChange={(sensitivity) => update({ sensitivity })}
+        min={0.1}
+        max={0.9}
+        step={0.05}
+        label={t("settings.wakeWord.sensitivity.title")}
+        description={t("settings.wakeWord.sensitivity.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <Slider
+        value={wakeWord.silence_timeout_ms}
+        onChange={(value) => update({ silence_timeout_ms: value })}
+        min={500}
+        max={5000}
+        step={250}
+        label={t("settings.wakeWord.silenceTimeout.title")}
+        description={t("settings.wakeWord.silenceTimeout.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+        formatValue={(value) => `${(value / 1000).toFixed(1)}s`}
+      />
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,161 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, Result, WebhookEvent } from "@/bindings";
+
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+const EVENTS: WebhookEvent[] = [
+  "transcription_completed",
+  "session_ended",
+  "summary_generated",
+];
+
+const RETRY_OPTIONS = [0, 1, 3, 5, 10];
+
+/**
+ * Where finished dictations, Active Listening sessions and meeting
+ * summaries are POSTed as JSON
+ */
+export const WebhookSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const [url, setUrl] = useState("");
+  const [authHeader, setAuthHeader] = useState("");
+  const [isTesting, setIsTesting] = useState(false);
+
+  const webhooks = getSetting("webhooks");
+  const events = webhooks?.events ?? EVENTS;
+
+  useEffect(() => {
+    setUrl(webhooks?.url ?? "");
+    setAuthHeader(webhooks?.auth_header ?? "");
+  }, [webhooks?.url, webhooks?.auth_header]);
+
+  const run = async (action: () => Promise<Result<null, string>>) => {
+    const result = await action();
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const toggleEvent = (event: WebhookEvent, enabled: boolean) =>
+    run(() =>
+      commands.changeWebhookEventsSetting(
+        enabled ? [...events, event] : events.filter((e) => e !== event),
+      ),
+    );
+
+  const sendTest = async () => {
+    setIsTesting(true);
+    const result = await commands.testWebhook();
+    setIsTesting(false);
+    if (result.status === "error") {
+      toast.error(t("settings.webhooks.test.failed", { error: result.error }));
+      return;
+    }
+    toast.success(t("settings.webhooks.test.success", { status: result.data }));
+  };
+
+  return (
+    <SettingsGroup title={t("settings.webhooks.title")}>
+      <ToggleSwitch
+        checked={webhooks?.enabled ?? false}
+        onChange={(enabled) =>
+          run(() => commands.changeWebhookEnabledSetting(enabled))
+        }
+        label={t("settings.webhooks.enabled.label")}
+        description={t("settings.webhooks.enabled.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+
+      <SettingContainer
+        title={t("settings.webhooks.endpoint.title")}
+        description={t("settings.webhooks.endpoint.description")}
+        descriptionMode="tooltip"
+        layout="stacked"
+        grouped={true}
+      >
+        <div className="space-y-2">
+          <Input
+            type="url"
+            variant="compact"
+            value={url}
+            onChange={(e) => setUrl(e.target.value)}
+            onBlur={() =>
+              run(() => commands.changeWebhookUrlSetting(url.trim() || null))
+            }
+            placeholder="https://example.com/hooks/dictum"
+            className="w-full"
+          />
+          <Input
+            type="password"
+            variant="compact"
+            value={authHeader}
+            onChange={(e) => setAuthHeader(e.target.value)}
+            onBlur={() =>
+              run(() =>
+                commands.changeWebhookAuthHeaderSetting(
+                  authHeader.trim() || null,
+                ),
+              )
+            }
+            placeholder={t("settings.webhooks.endpoint.authHeader")}
+            className="w-full"
+          />
+          <Button
+            onClick={sendTest}
+            variant="secondary"
+            size="sm"
+            disabled={isTesting || !webhooks?.url}
+          >
+            {isTesting
+              ? t("settings.webhooks.test.sending")
+              : t("settings.webhooks.test.button")}
+          </Button>
+        </div>
+      </SettingContainer>
+
+      {EVENTS.map((event) => (
+        <ToggleSwitch
+          key={event}
+          checked={events.includes(event)}
+          onChange={(enabled) => toggleEvent(event, enabled)}
+          label={t(`settings.webhooks.events.${event}`)}
+          description={t("settings.webhooks.events.description")}
+          descriptionMode="tooltip"
+          grouped={true}
+        />
+      ))}
+
+      <SettingContainer
+        title={t("settings.webhooks.retries.title")}
+        description={t("settings.webhooks.retries.description")}
+        descriptionMode="tooltip"
+        layout="horizontal"
+        grouped={true}
+      >
+        <Dropdown
+          selectedValue={String(webhooks?.max_retries ?? 3)}
+          options={RETRY_OPTIONS.map((retries) => ({
+            value: String(retries),
+            label: String(retries),
+          }))}
+          onSelect={(retries) =>
+            run(() => commands.changeWebhookMaxRetriesSetting(Number(retries)))
+          }
+        />
+      </SettingContainer>
+    </SettingsGroup>
+  );
+};
@@ -0,0 +1,112 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { open } from "@tauri-apps/plugin-dialog";
+import { invoke } from "@tauri-apps/api/core";
+import { Check, FolderOpen } from "lucide-react";
+import { Button } from "@/components/ui/Button";
+
+type OutputFormat = "txt" | "srt" | "vtt" | "json" | "markdown";
+
+interface BatchOutputConfig {
+  formats: OutputFormat[];
+  output_dir: string | null;
+}
+
+const FORMATS: OutputFormat[] = ["txt", "srt", "vtt", "json", "markdown"];
+
+/**
+ * Formats and folder the current batch is written in. Without a choice the
+ * batch output settings apply.
+ */
+export const BatchOutputOptions: React.FC<{ disabled: boolean }> = ({
+  disabled,
+}) => {
+  const { t } = useTranslation();
+  const [config, setConfig] = useState<BatchOutputConfig>({
+    formats: [],
+    output_dir: null,
+  });
+
+  useEffect(() => {
+    invoke<BatchOutputConfig>("get_batch_output_config")
+      .then(setConfig)
+      .catch((error) =>
+        console.error("Failed to load batch output config:", error),
+      );
+  }, []);
+
+  const save = useCallback(async (next: BatchOutputConfig) => {
+    try {
+      await invoke("configure_batch_output", { config: next });
+      setConfig(next);
+    } catch (error) {
+      console.error("Failed to configure batch output:", error);
+    }
+  }, []);
+
+  const toggleFormat = (format: OutputFormat) => {
+    const formats = config.formats.includes(format)
+      ? config.formats.filter((f) => f !== format)
+      : [...config.formats, format];
+    save({ ...config, formats });
+  };
+
+  const chooseFolder = async () => {
+    const selected = await open({ directory: true });
+    if (typeof selected === "string") {
+      save({ ...config, output_dir: selected });
+    }
+  };
+
+  return (
+    <div className="px-4 pb-3 space-y-2">
+      <div className="flex flex-wrap items-center gap-2">
+        <span className="text-xs text-text-secondary">
+          {t("batchProcessing.output.formats")}
+        </span>
+        {FORMATS.map((format) => {
+          const selected = config.formats.includes(format);
+          return (
+            <button
+              key={format}
+              onClick={() => toggleFormat(format)}
+              disabled={disabled}
+              className={`inline-flex items-center gap-1 rounded-full px-2 py-0.5 text-xs font-medium transition-colors disabled:opacity-50 ${
+                selected
+                  ? "bg-primary-light/20 text-primary-light"
+                  : "bg-mid-gray/20 text-text-secondary hover:bg-mid-gray/30"
+              }`}
+            >
+              {selected && <Check className="h-3 w-3" />}
+              {t(`batchProcessing.output.format.${format}`)}
+            </button>
+          );
+        })}
+      </div>
+      <div className="flex items-center gap-2">
+        <span className="text-xs text-text-secondary truncate">
+          {config.output_dir ?? t("batchProcessing.output.defaultFolder")}
+        </span>
+        <Button
+          onClick={chooseFolder}
+          variant="ghost"
+          size="sm"
+          disabled={disabled}
+        >
+          <FolderOpen className="h-3.5 w-3.5" />
+          {t("batchProcessing.output.chooseFolder")}
+        </Button>
+        {config.output_dir && (
+          <Button
+            onClick={() => save({ ...config, output_dir: null })}
+            variant="ghost"
+            size="sm"
+            disabled={disabled}
+          >
+            {t("batchProcessing.output.resetFolder")}
+          </Button>
+        )}
+      </div>
+    </div>
+  );
+};
@@ -3,6 +3,7 @@ import { useTranslation } from "react-i18next";
 import { open } from "@tauri-apps/plugin-dialog";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
+import { getCurrentWebview } from "@tauri-apps/api/webview";
 import {
   FolderInput,
   Play,
@@ -16,6 +17,8 @@ import {
 } from "lucide-react";
 import { SettingsGroup } from "@/components/ui";
 import { Button } from "@/components/ui/Button";
+import { BatchOutputOptions } from "./BatchOutputOptions";
+import { ClipboardWatchSettings } from "./ClipboardWatchSettings";
 
 interface BatchItem {
   id: string;
@@ -27,21 +30,48 @@ interface BatchItem {
     | "Transcribing"
     | "Completed"
     | "Failed"
-    | "Cancelled";
+    | "Cancelled"
+    | "Interrupted";
   progress: number;
   error: string | null;
   duration_seconds: number | null;
+  output_format: "txt" | "srt" | "vtt" | "json" | "markdown" | null;
+  output_path: string | null;
+  output_paths: string[];
 }
 
 interface BatchItemStatusEvent {
-  id: string;
+  item_id: string;
   status: BatchItem["status"];
   progress: number;
   error: string | null;
   duration_seconds: number | null;
 }
 
-const AUDIO_EXTENSIONS = ["wav", "mp3", "m4a", "aac", "flac", "ogg", "mp4"];
+const MEDIA_EXTENSIONS = [
+  "wav",
+  "mp3",
+  "m4a",
+  "aac",
+  "flac",
+  "ogg",
+  "mp4",
+  "opus",
+  "webm",
+  "mkv",
+  "mov",
+  "m4v",
+  "avi",
+  "wma",
+  "aiff",
+  "aif",
+  "amr",
+];
+
+/** Items report progress from 0 to 1; the bars work in percent */
+function toDisplayItem(item: BatchItem): BatchItem {
+  return { ...item, progress: item.progress * 100 };
+}
 
 function formatDuration(seconds: number): string {
   const mins = Math.floor(seconds / 60);
@@ -61,6 +91,7 @@ const StatusBadge: React.FC<{ status: BatchItem["status"] }> = ({
     Completed: "bg-green-500/20 text-green-400",
     Failed: "bg-red-500/20 text-red-400",
     Cancelled: "bg-mid-gray/20 text-text-secondary",
+    Interrupted: "bg-yellow-500/20 text-yellow-400",
   };
 
   return (
@@ -104,16 +135,24 @@ export const BatchProcessingPanel: React.FC = () => {
   const [isProcessing, setIsProcessing] = useState(false);
 
   useEffect(() => {
+    // Load items persisted from a previous session
+    invoke<{ items: BatchItem[]; is_processing: boolean }>("get_batch_status")
+      .then((status) => {
+        setItems(status.items.map(toDisplayItem));
+        setIsProcessing(status.is_processing);
+      })
+      .catch((error) => console.error("Failed to load batch queue:", error));
+
     const unlistenStatus = listen<BatchItemStatusEvent>(
       "batch-item-status",
       (event) => {
         setItems((prev) =>
           prev.map((item) =>
-            item.id === event.payload.id
+            item.id === event.payload.item_id
               ? {
                   ...item,
                   status: event.payload.status,
-                  progress: event.payload.progress,
+                  progress: event.payload.progress * 100,
                   error: event.payload.error,
                   duration_seconds: event.payload.duration_seconds,
                 }
@@ -123,9 +162,13 @@ export const BatchProcessingPanel: React.FC = () => {
       },
     );
 
-    const unlistenComplete = listen("batch-complete", () => {
-      setIsProcessing(false);
-    });
+    const unlistenComplete = listen<{ items: BatchItem[] }>(
+      "batch-complete",
+      (event) => {
+        setItems(event.payload.items.map(toDisplayItem));
+        setIsProcessing(false);
+      },
+    );
 
     return () => {
       unlistenStatus.then((fn) => fn());
@@ -133,31 +176,47 @@ export const BatchProcessingPanel: React.FC = () => {
     };
   }, []);
 
+  const addFiles = useCallback(async (paths: string[]) => {
+    if (paths.length === 0) return;
+
+    try {
+      const status = await invoke<{ items: BatchItem[] }>(
+        "add_to_batch_queue",
+        { paths },
+      );
+      setItems(status.items.map(toDisplayItem));
+    } catch (error) {
+      console.error("Failed to add files to batch queue:", error);
+    }
+  }, []);
+
+  useEffect(() => {
+    const unlistenDrop = getCurrentWebview().onDragDropEvent((event) => {
+      if (event.payload.type === "drop") {
+        addFiles(event.payload.paths);
+      }
+    });
+
+    return () => {
+      unlistenDrop.then((fn) => fn());
+    };
+  }, [addFiles]);
+
   const handleSelectFiles = useCallback(async () => {
     const selected = await open({
       multiple: true,
       filters: [
         {
           name: t("batchProcessing.audioFiles"),
-          extensions: AUDIO_EXTENSIONS,
+          extensions: MEDIA_EXTENSIONS,
         },
       ],
     });
 
     if (!selected) return;
 
-    const paths = Array.isArray(selected) ? selected : [selected];
-    if (paths.length === 0) return;
-
-    try {
-      const newItems = await invoke<BatchItem[]>("add_to_batch_queue", {
-        paths,
-      });
-      setItems((prev) => [...prev, ...newItems]);
-    } catch (error) {
-      console.error("Failed to add files to batch queue:", error);
-    }
-  }, [t]);
+    await addFiles(Array.isArray(selected) ? selected : [selected]);
+  }, [t, addFiles]);
 
   const handleStart = useCallback(async () => {
     try {
@@ -212,6 +271,7 @@ export const BatchProcessingPanel: React.FC = () => {
   const hasQueuedItems = items.some(
     (item) =>
       item.status === "Queued" ||
+      item.status === "Interrupted" ||
       item.status === "Decoding" ||
       item.status === "Transcribing",
   );
@@ -270,6 +330,8 @@ export const BatchProcessingPanel: React.FC = () => {
           )}
         </div>
 
+        <BatchOutputOptions disabled={isProcessing} />
+
         {/* Overall progress */}
         {totalCount > 0 && (
           <div className="px-4 pb-3 space-y-2">
@@ -346,6 +408,8 @@ export const BatchProcessingPanel: React.FC = () => {
           </div>
         )}
       </SettingsGroup>
+
+      <ClipboardWatchSettings />
     </div>
   );
 };
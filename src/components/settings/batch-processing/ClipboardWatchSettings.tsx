@@ -0,0 +1,103 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, ClipboardWatchSettings as ClipboardWatch } from "@/bindings";
+
+import {
+  SettingContainer,
+  SettingsGroup,
+  Slider,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Input } from "../../ui/Input";
+import { useSettings } from "../../../hooks/useSettings";
+
+const DEFAULT_CLIPBOARD_WATCH: ClipboardWatch = {
+  enabled: false,
+  extensions: [
+    "wav",
+    "mp3",
+    "m4a",
+    "flac",
+    "ogg",
+    "opus",
+    "aac",
+    "webm",
+    "mp4",
+    "mov",
+    "mkv",
+  ],
+  debounce_ms: 1000,
+};
+
+/**
+ * Offer audio files whose path is copied to the clipboard for transcription
+ */
+export const ClipboardWatchSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+
+  const clipboardWatch =
+    getSetting("clipboard_watch") ?? DEFAULT_CLIPBOARD_WATCH;
+
+  const update = async (changes: Partial<ClipboardWatch>) => {
+    const result = await commands.changeClipboardWatchSetting({
+      ...clipboardWatch,
+      ...changes,
+    });
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const handleExtensionsChange = (value: string) => {
+    update({ extensions: value.split(/[\s,]+/) });
+  };
+
+  return (
+    <SettingsGroup title={t("batchProcessing.clipboardWatch.title")}>
+      <ToggleSwitch
+        label={t("batchProcessing.clipboardWatch.enabled.title")}
+        description={t("batchProcessing.clipboardWatch.enabled.description")}
+        descriptionMode="tooltip"
+        checked={clipboardWatch.enabled}
+        onChange={(enabled) => update({ enabled })}
+        grouped={true}
+      />
+      {clipboardWatch.enabled && (
+        <>
+          <SettingContainer
+            title={t("batchProcessing.clipboardWatch.extensions.title")}
+            description={t(
+              "batchProcessing.clipboardWatch.extensions.description",
+            )}
+            descriptionMode="tooltip"
+            layout="horizontal"
+            grouped={true}
+          >
+            <Input
+              key={clipboardWatch.extensions.join(", ")}
+              defaultValue={clipboardWatch.extensions.join(", ")}
+              onBlur={(e) => handleExtensionsChange(e.target.value)}
+            />
+          </SettingContainer>
+          <Slider
+            value={clipboardWatch.debounce_ms}
+            onChange={(value) => update({ debounce_ms: value })}
+            min={0}
+            max={5000}
+            step={250}
+            label={t("batchProcessing.clipboardWatch.debounce.title")}
+            description={t(
+              "batchProcessing.clipboardWatch.debounce.description",
+            )}
+            descriptionMode="tooltip"
+            grouped={true}
+            formatValue={(value) => `${(value / 1000).toFixed(2)}s`}
+          />
+        </>
+      )}
+    </SettingsGroup>
+  );
+};
@@ -6,14 +6,22 @@ import { ShortcutInput as DictumShortcut } from "../ShortcutInput";
 import { SettingsGroup } from "../../ui/SettingsGroup";
 import { OutputDeviceSelector } from "../OutputDeviceSelector";
 import { PushToTalk } from "../PushToTalk";
+import { RecordingLimit } from "../RecordingLimit";
+import { SilenceAutoStop } from "../SilenceAutoStop";
+import { StreamingPreview } from "../StreamingPreview";
 import { AudioFeedback } from "../AudioFeedback";
 import { useSettings } from "../../../hooks/useSettings";
 import { useModelStore } from "../../../stores/modelStore";
 import { VolumeSlider } from "../VolumeSlider";
+import { PrimarySpeakerFilter } from "../PrimarySpeakerFilter";
+import { EchoCancellation } from "../EchoCancellation";
+import { NoiseSuppression } from "../NoiseSuppression";
+import { GainNormalization } from "../GainNormalization";
 import { AskAiToggle } from "../AskAiToggle";
 import { ActiveListeningToggle } from "../ActiveListeningToggle";
 import { KnowledgeBaseToggle } from "../KnowledgeBaseToggle";
 import { SoundDetectionSettings } from "./SoundDetectionSettings";
+import { NotificationSettings } from "./NotificationSettings";
 
 export const GeneralSettings: React.FC = () => {
   const { t } = useTranslation();
@@ -30,6 +38,9 @@ export const GeneralSettings: React.FC = () => {
           <LanguageSelector descriptionMode="tooltip" grouped={true} />
         )}
         <PushToTalk descriptionMode="tooltip" grouped={true} />
+        <SilenceAutoStop descriptionMode="tooltip" grouped={true} />
+        <RecordingLimit descriptionMode="tooltip" grouped={true} />
+        <StreamingPreview descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
 
       <SettingsGroup title={t("settings.advanced.experimental.title")}>
@@ -39,9 +50,14 @@ export const GeneralSettings: React.FC = () => {
         <KnowledgeBaseToggle />
       </SettingsGroup>
       <SoundDetectionSettings />
+      <NotificationSettings />
 
       <SettingsGroup title={t("settings.sound.title")}>
         <MicrophoneSelector descriptionMode="tooltip" grouped={true} />
+        <PrimarySpeakerFilter descriptionMode="tooltip" grouped={true} />
+        <NoiseSuppression descriptionMode="tooltip" grouped={true} />
+        <GainNormalization descriptionMode="tooltip" grouped={true} />
+        <DictumShortcut shortcutId="toggle_mic_mute" grouped={true} />
         <AudioFeedback descriptionMode="tooltip" grouped={true} />
         <OutputDeviceSelector
           descriptionMode="tooltip"
@@ -49,6 +65,11 @@ export const GeneralSettings: React.FC = () => {
           disabled={!audioFeedbackEnabled}
         />
         <VolumeSlider disabled={!audioFeedbackEnabled} />
+        <EchoCancellation
+          descriptionMode="tooltip"
+          grouped={true}
+          disabled={!audioFeedbackEnabled}
+        />
       </SettingsGroup>
     </div>
   );
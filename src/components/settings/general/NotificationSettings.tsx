@@ -0,0 +1,192 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import {
+  commands,
+  FeatureNotificationSettings,
+  NotificationSettings as NotificationSettingsType,
+  NotificationSeverity,
+} from "@/bindings";
+import {
+  Dropdown,
+  SettingContainer,
+  SettingsGroup,
+  Slider,
+} from "@/components/ui";
+import { Input } from "@/components/ui/Input";
+import { ToggleSwitch } from "@/components/ui/ToggleSwitch";
+import { useSettings } from "../../../hooks/useSettings";
+
+const FEATURES = [
+  "sound_detection",
+  "suggestions",
+  "warnings",
+  "action_items",
+  "clipboard",
+] as const;
+
+type Feature = (typeof FEATURES)[number];
+
+const DEFAULT_SETTINGS: NotificationSettingsType = {
+  enabled: true,
+  sound_detection: { enabled: true, min_severity: "info" },
+  suggestions: { enabled: false, min_severity: "info" },
+  warnings: { enabled: true, min_severity: "info" },
+  action_items: { enabled: true, min_severity: "info" },
+  clipboard: { enabled: true, min_severity: "info" },
+  quiet_hours: { enabled: false, start_minute: 22 * 60, end_minute: 7 * 60 },
+  batch_window_seconds: 30,
+};
+
+/** "HH:MM" for minutes after midnight */
+const toTime = (minutes: number) =>
+  `${String(Math.floor(minutes / 60)).padStart(2, "0")}:${String(
+    minutes % 60,
+  ).padStart(2, "0")}`;
+
+const fromTime = (time: string) => {
+  const [hours, minutes] = time.split(":").map(Number);
+  return hours * 60 + minutes;
+};
+
+/**
+ * Which features may raise system notifications, quiet hours and batching
+ */
+export const NotificationSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+
+  const notifications = getSetting("notifications") ?? DEFAULT_SETTINGS;
+
+  const update = async (changes: Partial<NotificationSettingsType>) => {
+    const result = await commands.setNotificationSettings({
+      ...notifications,
+      ...changes,
+    });
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  const featureLevel = (feature: FeatureNotificationSettings) =>
+    feature.enabled ? feature.min_severity : "off";
+
+  const setFeatureLevel = (feature: Feature, level: string) => {
+    const changes: Partial<NotificationSettingsType> = {};
+    changes[feature] =
+      level === "off"
+        ? { ...notifications[feature], enabled: false }
+        : { enabled: true, min_severity: level as NotificationSeverity };
+    return update(changes);
+  };
+
+  const levelOptions = ["off", "info", "warning", "critical"].map((level) => ({
+    value: level,
+    label: t(`settings.notifications.levels.${level}`),
+  }));
+
+  return (
+    <SettingsGroup title={t("settings.notifications.title")}>
+      <ToggleSwitch
+        label={t("settings.notifications.enabled.label")}
+        description={t("settings.notifications.enabled.description")}
+        checked={notifications.enabled}
+        onChange={(enabled) => update({ enabled })}
+        grouped={true}
+      />
+
+      {notifications.enabled && (
+        <>
+          {FEATURES.map((feature) => (
+            <SettingContainer
+              key={feature}
+              title={t(`settings.notifications.features.${feature}.title`)}
+              description={t(
+                `settings.notifications.features.${feature}.description`,
+              )}
+              descriptionMode="tooltip"
+              layout="horizontal"
+              grouped={true}
+            >
+              <Dropdown
+                selectedValue={featureLevel(notifications[feature])}
+                options={levelOptions}
+                onSelect={(value) => value && setFeatureLevel(feature, value)}
+                className="min-w-[200px]"
+              />
+            </SettingContainer>
+          ))}
+
+          <ToggleSwitch
+            label={t("settings.notifications.quietHours.label")}
+            description={t("settings.notifications.quietHours.description")}
+            checked={notifications.quiet_hours.enabled}
+            onChange={(enabled) =>
+              update({
+                quiet_hours: { ...notifications.quiet_hours, enabled },
+              })
+            }
+            grouped={true}
+          />
+          {notifications.quiet_hours.enabled && (
+            <SettingContainer
+              title={t("settings.notifications.quietHours.range")}
+              description={t(
+                "settings.notifications.quietHours.rangeDescription",
+              )}
+              descriptionMode="tooltip"
+              layout="horizontal"
+              grouped={true}
+            >
+              <div className="flex items-center gap-2">
+                <Input
+                  type="time"
+                  variant="compact"
+                  value={toTime(notifications.quiet_hours.start_minute)}
+                  onChange={(e) =>
+                    e.target.value &&
+                    update({
+                      quiet_hours: {
+                        ...notifications.quiet_hours,
+                        start_minute: fromTime(e.target.value),
+                      },
+                    })
+                  }
+                />
+                <span className="text-sm text-mid-gray">–</span>
+                <Input
+                  type="time"
+                  variant="compact"
+                  value={toTime(notifications.quiet_hours.end_minute)}
+                  onChange={(e) =>
+                    e.target.value &&
+                    update({
+                      quiet_hours: {
+                        ...notifications.quiet_hours,
+                        end_minute: fromTime(e.target.value),
+                      },
+                    })
+                  }
+                />
+              </div>
+            </SettingContainer>
+          )}
+
+          <Slider
+            value={notifications.batch_window_seconds}
+            onChange={(value) => update({ batch_window_seconds: value })}
+            min={0}
+            max={300}
+            step={10}
+            label={t("settings.notifications.batchWindow.title")}
+            description={t("settings.notifications.batchWindow.description")}
+            descriptionMode="tooltip"
+            grouped={true}
+            formatValue={(value) => `${value}s`}
+          />
+        </>
+      )}
+    </SettingsGroup>
+  );
+};
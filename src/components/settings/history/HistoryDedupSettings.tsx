@@ -0,0 +1,84 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, HistoryDedupSettings as HistoryDedup } from "@/bindings";
+
+import { SettingsGroup, Slider, ToggleSwitch } from "@/components/ui";
+import { useSettings } from "../../../hooks/useSettings";
+
+const DEFAULT_HISTORY_DEDUP: HistoryDedup = {
+  enabled: true,
+  window_seconds: 30,
+  similarity_threshold: 0.85,
+  discard_exact_duplicates: false,
+};
+
+/**
+ * Flag or discard dictations that repeat the one just before them
+ */
+export const HistoryDedupSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+
+  const dedup = getSetting("history_dedup") ?? DEFAULT_HISTORY_DEDUP;
+
+  const update = async (changes: Partial<HistoryDedup>) => {
+    const result = await commands.updateHistoryDedup({
+      ...dedup,
+      ...changes,
+    });
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  return (
+    <SettingsGroup title={t("settings.history.dedup.title")}>
+      <ToggleSwitch
+        label={t("settings.history.dedup.enabled.title")}
+        description={t("settings.history.dedup.enabled.description")}
+        descriptionMode="tooltip"
+        checked={dedup.enabled}
+        onChange={(enabled) => update({ enabled })}
+        grouped={true}
+      />
+      <ToggleSwitch
+        label={t("settings.history.dedup.discardExact.title")}
+        description={t("settings.history.dedup.discardExact.description")}
+        descriptionMode="tooltip"
+        checked={dedup.discard_exact_duplicates}
+        onChange={(value) => update({ discard_exact_duplicates: value })}
+        grouped={true}
+      />
+      {(dedup.enabled || dedup.discard_exact_duplicates) && (
+        <Slider
+          value={dedup.window_seconds}
+          onChange={(value) => update({ window_seconds: value })}
+          min={5}
+          max={300}
+          step={5}
+          label={t("settings.history.dedup.window.title")}
+          description={t("settings.history.dedup.window.description")}
+          descriptionMode="tooltip"
+          grouped={true}
+          formatValue={(value) => `${value}s`}
+        />
+      )}
+      {dedup.enabled && (
+        <Slider
+          value={dedup.similarity_threshold}
+          onChange={(value) => update({ similarity_threshold: value })}
+          min={0.5}
+          max={1}
+          step={0.05}
+          label={t("settings.history.dedup.threshold.title")}
+          description={t("settings.history.dedup.threshold.description")}
+          descriptionMode="tooltip"
+          grouped={true}
+          formatValue={(value) => `${Math.round(value * 100)}%`}
+        />
+      )}
+    </SettingsGroup>
+  );
+};
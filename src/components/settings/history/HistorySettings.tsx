@@ -7,9 +7,16 @@ import { Copy, Star, Check, Trash2, FolderOpen } from "lucide-react";
 import { convertFileSrc } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { readFile } from "@tauri-apps/plugin-fs";
-import { commands, type HistoryEntry } from "@/bindings";
+import {
+  commands,
+  type DuplicateResolution,
+  type HistoryEntry,
+} from "@/bindings";
 import { formatDateTime } from "@/utils/dateFormat";
 import { useOsType } from "@/hooks/useOsType";
+import { SyncedTranscript } from "./SyncedTranscript";
+import { TranscriptionFeedback } from "./TranscriptionFeedback";
+import { HistoryDedupSettings } from "./HistoryDedupSettings";
 
 interface OpenRecordingsButtonProps {
   onClick: () => void;
@@ -85,6 +92,16 @@ export const HistorySettings: React.FC = () => {
     }
   };
 
+  const resolveDuplicate = async (
+    id: number,
+    resolution: DuplicateResolution,
+  ) => {
+    const result = await commands.resolveDuplicateHistoryEntry(id, resolution);
+    if (result.status === "error") {
+      console.error("Failed to resolve duplicate entry:", result.error);
+    }
+  };
+
   const copyToClipboard = async (text: string) => {
     try {
       await navigator.clipboard.writeText(text);
@@ -179,6 +196,7 @@ export const HistorySettings: React.FC = () => {
             </div>
           </div>
         </div>
+        <HistoryDedupSettings />
       </div>
     );
   }
@@ -207,11 +225,15 @@ export const HistorySettings: React.FC = () => {
                 onCopyText={() => copyToClipboard(entry.transcription_text)}
                 getAudioUrl={getAudioUrl}
                 deleteAudio={deleteAudioEntry}
+                onResolveDuplicate={(resolution) =>
+                  resolveDuplicate(entry.id, resolution)
+                }
               />
             ))}
           </div>
         </div>
       </div>
+      <HistoryDedupSettings />
     </div>
   );
 };
@@ -222,6 +244,7 @@ interface HistoryEntryProps {
   onCopyText: () => void;
   getAudioUrl: (fileName: string) => Promise<string | null>;
   deleteAudio: (id: number) => Promise<void>;
+  onResolveDuplicate: (resolution: DuplicateResolution) => void;
 }
 
 const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
@@ -230,10 +253,15 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
   onCopyText,
   getAudioUrl,
   deleteAudio,
+  onResolveDuplicate,
 }) => {
   const { t, i18n } = useTranslation();
   const [showCopied, setShowCopied] = useState(false);
   const [audioSrc, setAudioSrc] = useState<string | null>(null);
+  const [currentTime, setCurrentTime] = useState(0);
+  const [seekRequest, setSeekRequest] = useState<{ time: number } | null>(
+    null,
+  );
 
   const handleLoadAudio = useCallback(async () => {
     const url = await getAudioUrl(entry.file_name);
@@ -241,6 +269,11 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
     return url;
   }, [getAudioUrl, entry.file_name]);
 
+  const handleSeek = async (time: number) => {
+    if (!audioSrc && !(await handleLoadAudio())) return;
+    setSeekRequest({ time });
+  };
+
   const handleCopyText = () => {
     onCopyText();
     setShowCopied(true);
@@ -302,11 +335,49 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
           </button>
         </div>
       </div>
-      <p className="italic text-text/90 text-sm pb-2 select-text cursor-text">
-        {entry.transcription_text}
-      </p>
+      {entry.formatting_mode && (
+        <span className="self-start px-2 py-0.5 rounded-full bg-mid-gray/15 text-xs text-text/70">
+          {t("settings.history.formattedAs", { mode: entry.formatting_mode })}
+        </span>
+      )}
+      {entry.duplicate_of != null && (
+        <div className="flex flex-wrap items-center gap-2 text-xs">
+          <span className="px-2 py-0.5 rounded-full bg-primary-light/15 text-primary-light">
+            {t("settings.history.duplicate.badge")}
+          </span>
+          <button
+            onClick={() => onResolveDuplicate("keep_original")}
+            className="text-text/60 hover:text-primary-light cursor-pointer"
+          >
+            {t("settings.history.duplicate.keepOriginal")}
+          </button>
+          <button
+            onClick={() => onResolveDuplicate("keep_duplicate")}
+            className="text-text/60 hover:text-primary-light cursor-pointer"
+          >
+            {t("settings.history.duplicate.keepThis")}
+          </button>
+          <button
+            onClick={() => onResolveDuplicate("keep_both")}
+            className="text-text/60 hover:text-primary-light cursor-pointer"
+          >
+            {t("settings.history.duplicate.keepBoth")}
+          </button>
+        </div>
+      )}
+      <SyncedTranscript
+        entry={entry}
+        currentTime={currentTime}
+        onSeek={handleSeek}
+      />
+      <TranscriptionFeedback entry={entry} />
       {audioSrc ? (
-        <WaveformPlayer src={audioSrc} className="w-full" />
+        <WaveformPlayer
+          src={audioSrc}
+          className="w-full"
+          seekRequest={seekRequest}
+          onTimeUpdate={setCurrentTime}
+        />
       ) : (
         <AudioPlayer onLoadRequest={handleLoadAudio} className="w-full" />
       )}
@@ -0,0 +1,65 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import {
+  commands,
+  type HistoryEntry,
+  type TranscriptSegment,
+} from "@/bindings";
+
+interface SyncedTranscriptProps {
+  entry: HistoryEntry;
+  /** Playback position of the entry's recording, in seconds */
+  currentTime: number;
+  onSeek: (time: number) => void;
+}
+
+/**
+ * An entry's transcript split into its timed words or segments. The one
+ * being played is highlighted and clicking one plays the recording from
+ * there. Falls back to plain text when no timings were stored.
+ */
+export const SyncedTranscript: React.FC<SyncedTranscriptProps> = ({
+  entry,
+  currentTime,
+  onSeek,
+}) => {
+  const { t } = useTranslation();
+  const [segments, setSegments] = useState<TranscriptSegment[]>([]);
+
+  useEffect(() => {
+    commands.getHistoryEntrySegments(entry.id).then((result) => {
+      if (result.status === "ok") setSegments(result.data);
+    });
+  }, [entry.id]);
+
+  if (segments.length === 0) {
+    return (
+      <p className="italic text-text/90 text-sm pb-2 select-text cursor-text">
+        {entry.transcription_text}
+      </p>
+    );
+  }
+
+  return (
+    <p className="italic text-text/90 text-sm pb-2 select-text">
+      {segments.map((segment, index) => {
+        const isCurrent =
+          currentTime >= segment.start && currentTime < segment.end;
+        return (
+          <React.Fragment key={index}>
+            {index > 0 && " "}
+            <span
+              onClick={() => onSeek(segment.start)}
+              title={t("settings.history.playFromHere")}
+              className={`cursor-pointer rounded-sm hover:text-primary-light ${
+                isCurrent ? "bg-primary-light/20" : ""
+              }`}
+            >
+              {segment.text}
+            </span>
+          </React.Fragment>
+        );
+      })}
+    </p>
+  );
+};
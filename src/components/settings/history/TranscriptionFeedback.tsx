@@ -0,0 +1,126 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { Pencil } from "lucide-react";
+import { toast } from "sonner";
+import { commands, type HistoryEntry } from "@/bindings";
+import { Textarea } from "@/components/ui";
+import { Button } from "../../ui/Button";
+
+const RATINGS = [1, 2, 3, 4, 5];
+
+interface TranscriptionFeedbackProps {
+  entry: HistoryEntry;
+}
+
+/**
+ * Rating and correction of a transcription. Corrections teach the
+ * vocabulary the terms the model missed.
+ */
+export const TranscriptionFeedback: React.FC<TranscriptionFeedbackProps> = ({
+  entry,
+}) => {
+  const { t } = useTranslation();
+  const [isCorrecting, setIsCorrecting] = useState(false);
+  const [correction, setCorrection] = useState("");
+
+  const rate = async (rating: number, nextCorrection: string | null) => {
+    const result = await commands.rateTranscription(
+      entry.id,
+      rating,
+      nextCorrection,
+    );
+    if (result.status === "error") {
+      toast.error(result.error);
+      return false;
+    }
+    if (result.data.length > 0) {
+      toast.success(
+        t("settings.history.feedback.learned", {
+          terms: result.data.join(", "),
+        }),
+      );
+    }
+    return true;
+  };
+
+  const startCorrecting = () => {
+    setCorrection(entry.correction ?? entry.transcription_text);
+    setIsCorrecting(true);
+  };
+
+  const saveCorrection = async () => {
+    if (entry.rating === null) return;
+    const unchanged = correction.trim() === entry.transcription_text.trim();
+    if (await rate(entry.rating, unchanged ? null : correction)) {
+      setIsCorrecting(false);
+    }
+  };
+
+  return (
+    <div className="flex flex-col gap-2">
+      {entry.correction && !isCorrecting && (
+        <p className="text-xs text-text/70 select-text cursor-text">
+          <span className="font-semibold">
+            {t("settings.history.feedback.corrected")}
+          </span>{" "}
+          {entry.correction}
+        </p>
+      )}
+      <div className="flex items-center gap-1 text-xs text-text/50">
+        <span className="mr-1">{t("settings.history.feedback.rate")}</span>
+        {RATINGS.map((rating) => (
+          <button
+            key={rating}
+            onClick={() => rate(rating, entry.correction)}
+            className={`w-5 h-5 rounded-full border transition-colors cursor-pointer ${
+              entry.rating !== null && rating <= entry.rating
+                ? "bg-primary-light border-primary-light text-background"
+                : "border-mid-gray/40 hover:border-primary-light"
+            }`}
+            title={t("settings.history.feedback.ratingLabel", { rating })}
+          >
+            {rating}
+          </button>
+        ))}
+        <button
+          onClick={startCorrecting}
+          className="ml-2 flex items-center gap-1 hover:text-primary-light transition-colors cursor-pointer"
+        >
+          <Pencil width={12} height={12} />
+          {t("settings.history.feedback.correct")}
+        </button>
+      </div>
+      {isCorrecting && (
+        <div className="flex flex-col gap-2">
+          <Textarea
+            value={correction}
+            onChange={(e) => setCorrection(e.target.value)}
+            placeholder={t("settings.history.feedback.correctionPlaceholder")}
+          />
+          {entry.rating === null && (
+            <p className="text-xs text-mid-gray/70">
+              {t("settings.history.feedback.rateFirst")}
+            </p>
+          )}
+          <div className="flex gap-2">
+            <Button
+              onClick={saveCorrection}
+              variant="primary"
+              size="sm"
+              disabled={entry.rating === null}
+            >
+              {t("settings.history.feedback.save")}
+            </Button>
+            <Button
+              onClick={() => setIsCorrecting(false)}
+              variant="secondary"
+              size="sm"
+            >
+              {t("settings.history.feedback.cancel")}
+            </Button>
+          </div>
+        </div>
+      )}
+    </div>
+  );
+};
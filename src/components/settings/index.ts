@@ -13,6 +13,8 @@ export { VocabularyPanel } from "./vocabulary";
 
 // Individual setting components
 export { MicrophoneSelector } from "./MicrophoneSelector";
+export { PrimarySpeakerFilter } from "./PrimarySpeakerFilter";
+export { EchoCancellation } from "./EchoCancellation";
 export { ClamshellMicrophoneSelector } from "./ClamshellMicrophoneSelector";
 export { OutputDeviceSelector } from "./OutputDeviceSelector";
 export { AlwaysOnMicrophone } from "./AlwaysOnMicrophone";
@@ -1,7 +1,8 @@
 import React, { useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { RefreshCcw } from "lucide-react";
-import { commands } from "@/bindings";
+import { toast } from "sonner";
+import { commands, LLMPrompt, PromptRoute } from "@/bindings";
 
 import { Alert } from "../../ui/Alert";
 import {
@@ -143,6 +144,83 @@ const PostProcessingSettingsApiComponent: React.FC = () => {
   );
 };
 
+const USE_SELECTED_PROVIDER = "";
+
+/**
+ * Provider and model a prompt is post-processed with instead of the
+ * selected ones
+ */
+const PromptModelOverride: React.FC<{ prompt: LLMPrompt }> = ({ prompt }) => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const [model, setModel] = useState(prompt.model ?? "");
+
+  const providers = getSetting("post_process_providers") || [];
+  const providerId = prompt.provider_id ?? USE_SELECTED_PROVIDER;
+
+  useEffect(() => {
+    setModel(prompt.model ?? "");
+  }, [prompt.id, prompt.model]);
+
+  const save = async (
+    nextProviderId: string | null,
+    nextModel: string | null,
+  ) => {
+    const result = await commands.setPostProcessPromptModel(
+      prompt.id,
+      nextProviderId,
+      nextModel,
+    );
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
+    await refreshSettings();
+  };
+
+  return (
+    <div className="space-y-2 flex flex-col">
+      <label className="text-sm font-semibold">
+        {t("settings.postProcessing.prompts.modelOverride.title")}
+      </label>
+      <div className="flex gap-2">
+        <Dropdown
+          selectedValue={providerId}
+          options={[
+            {
+              value: USE_SELECTED_PROVIDER,
+              label: t(
+                "settings.postProcessing.prompts.modelOverride.useSelected",
+              ),
+            },
+            ...providers.map((p) => ({ value: p.id, label: p.label })),
+          ]}
+          // A model belongs to its provider, so switching clears it
+          onSelect={(value) => save(value || null, null)}
+          className="flex-1"
+        />
+        {providerId !== USE_SELECTED_PROVIDER && (
+          <Input
+            type="text"
+            value={model}
+            onChange={(e) => setModel(e.target.value)}
+            onBlur={() =>
+              model !== (prompt.model ?? "") && save(providerId, model || null)
+            }
+            placeholder={t(
+              "settings.postProcessing.prompts.modelOverride.modelPlaceholder",
+            )}
+            variant="compact"
+            className="flex-1"
+          />
+        )}
+      </div>
+      <p className="text-xs text-mid-gray/70">
+        {t("settings.postProcessing.prompts.modelOverride.description")}
+      </p>
+    </div>
+  );
+};
+
 const PostProcessingSettingsPromptsComponent: React.FC = () => {
   const { t } = useTranslation();
   const { getSetting, updateSetting, isUpdating, refreshSettings } =
@@ -322,6 +400,8 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
               />
             </div>
 
+            <PromptModelOverride prompt={selectedPrompt} />
+
             <div className="flex gap-2 pt-2">
               <Button
                 onClick={handleUpdatePrompt}
@@ -413,6 +493,106 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
   );
 };
 
+const PostProcessingSettingsRoutesComponent: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const [phrase, setPhrase] = useState("");
+  const [promptId, setPromptId] = useState<string | null>(null);
+
+  const prompts = getSetting("post_process_prompts") || [];
+  const routes = getSetting("post_process_routes") || [];
+  const promptOptions = prompts.map((p) => ({ value: p.id, label: p.name }));
+
+  const saveRoutes = async (next: PromptRoute[]) => {
+    const result = await commands.setPostProcessRoutes(next);
+    if (result.status === "error") {
+      toast.error(result.error);
+      return false;
+    }
+    await refreshSettings();
+    return true;
+  };
+
+  const handleAddRoute = async () => {
+    if (!phrase.trim() || !promptId) return;
+    const added = await saveRoutes([
+      ...routes,
+      { phrase: phrase.trim(), prompt_id: promptId },
+    ]);
+    if (added) {
+      setPhrase("");
+    }
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.postProcessing.routes.title")}
+      description={t("settings.postProcessing.routes.description")}
+      descriptionMode="tooltip"
+      layout="stacked"
+      grouped={true}
+    >
+      <div className="space-y-3">
+        {routes.length === 0 && (
+          <p className="text-sm text-mid-gray">
+            {t("settings.postProcessing.routes.empty")}
+          </p>
+        )}
+        {routes.map((route, index) => (
+          <div key={route.phrase} className="flex items-center gap-2">
+            <span className="flex-1 text-sm truncate">{route.phrase}</span>
+            <Dropdown
+              selectedValue={route.prompt_id}
+              options={promptOptions}
+              onSelect={(value) =>
+                value &&
+                saveRoutes(
+                  routes.map((r, i) =>
+                    i === index ? { ...r, prompt_id: value } : r,
+                  ),
+                )
+              }
+              className="flex-1"
+            />
+            <Button
+              onClick={() => saveRoutes(routes.filter((_, i) => i !== index))}
+              variant="secondary"
+              size="md"
+            >
+              {t("settings.postProcessing.routes.remove")}
+            </Button>
+          </div>
+        ))}
+        <div className="flex gap-2">
+          <Input
+            type="text"
+            variant="compact"
+            value={phrase}
+            onChange={(e) => setPhrase(e.target.value)}
+            placeholder={t("settings.postProcessing.routes.phrase")}
+            className="flex-1"
+          />
+          <Dropdown
+            selectedValue={promptId}
+            options={promptOptions}
+            onSelect={setPromptId}
+            placeholder={t("settings.postProcessing.routes.prompt")}
+            className="flex-1"
+          />
+          <Button
+            onClick={handleAddRoute}
+            variant="primary"
+            size="md"
+            disabled={!phrase.trim() || !promptId}
+          >
+            {t("settings.postProcessing.routes.add")}
+          </Button>
+        </div>
+      </div>
+    </SettingContainer>
+  );
+};
+
 export const PostProcessingSettingsApi = React.memo(
   PostProcessingSettingsApiComponent,
 );
@@ -423,6 +603,11 @@ export const PostProcessingSettingsPrompts = React.memo(
 );
 PostProcessingSettingsPrompts.displayName = "PostProcessingSettingsPrompts";
 
+export const PostProcessingSettingsRoutes = React.memo(
+  PostProcessingSettingsRoutesComponent,
+);
+PostProcessingSettingsRoutes.displayName = "PostProcessingSettingsRoutes";
+
 export const PostProcessingSettings: React.FC = () => {
   const { t } = useTranslation();
 
@@ -443,6 +628,10 @@ export const PostProcessingSettings: React.FC = () => {
       <SettingsGroup title={t("settings.postProcessing.prompts.title")}>
         <PostProcessingSettingsPrompts />
       </SettingsGroup>
+
+      <SettingsGroup title={t("settings.postProcessing.routes.title")}>
+        <PostProcessingSettingsRoutes />
+      </SettingsGroup>
     </div>
   );
 };
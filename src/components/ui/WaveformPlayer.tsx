@@ -8,6 +8,10 @@ import { SpeedControl } from "./SpeedControl";
 interface WaveformPlayerProps {
   src: string;
   className?: string;
+  /** Jump to this time, in seconds, and play. A new object seeks again. */
+  seekRequest?: { time: number } | null;
+  /** Called with the playback position, in seconds, as it changes */
+  onTimeUpdate?: (time: number) => void;
 }
 
 const SKIP_SHORT = 5;
@@ -23,6 +27,8 @@ function formatTime(seconds: number): string {
 export const WaveformPlayer: React.FC<WaveformPlayerProps> = ({
   src,
   className = "",
+  seekRequest,
+  onTimeUpdate,
 }) => {
   const { t } = useTranslation();
   const containerRef = useRef<HTMLDivElement>(null);
@@ -33,6 +39,8 @@ export const WaveformPlayer: React.FC<WaveformPlayerProps> = ({
   const [currentTime, setCurrentTime] = useState(0);
   const [duration, setDuration] = useState(0);
   const [speed, setSpeed] = useState(1);
+  const onTimeUpdateRef = useRef(onTimeUpdate);
+  onTimeUpdateRef.current = onTimeUpdate;
 
   // Create and manage WaveSurfer instance
   useEffect(() => {
@@ -76,6 +84,7 @@ export const WaveformPlayer: React.FC<WaveformPlayerProps> = ({
 
     ws.on("timeupdate", (time: number) => {
       setCurrentTime(time);
+      onTimeUpdateRef.current?.(time);
     });
 
     ws.on("error", (error: Error) => {
@@ -100,6 +109,14 @@ export const WaveformPlayer: React.FC<WaveformPlayerProps> = ({
     }
   }, [speed, isReady]);
 
+  // Seek when the transcript asks for a position
+  useEffect(() => {
+    const ws = wavesurferRef.current;
+    if (!ws || !isReady || !seekRequest || !duration) return;
+    ws.seekTo(Math.min(seekRequest.time / duration, 1));
+    ws.play();
+  }, [seekRequest, isReady, duration]);
+
   const togglePlayPause = useCallback(() => {
     const ws = wavesurferRef.current;
     if (!ws || !isReady) return;
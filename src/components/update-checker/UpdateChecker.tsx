@@ -1,11 +1,24 @@
 import React, { useState, useEffect, useRef } from "react";
 import { useTranslation } from "react-i18next";
-import { check } from "@tauri-apps/plugin-updater";
+import { invoke } from "@tauri-apps/api/core";
 import { relaunch } from "@tauri-apps/plugin-process";
 import { listen } from "@tauri-apps/api/event";
 import { ProgressBar } from "../shared";
 import { useSettings } from "../../hooks/useSettings";
 
+interface UpdateInfo {
+  version: string;
+  current_version: string;
+  channel: "stable" | "beta";
+  date: string | null;
+  changelog: string | null;
+}
+
+interface UpdateDownloadProgress {
+  downloaded: number;
+  content_length: number | null;
+}
+
 interface UpdateCheckerProps {
   className?: string;
 }
@@ -63,7 +76,11 @@ const UpdateChecker: React.FC<UpdateCheckerProps> = ({ className = "" }) => {
 
     try {
       setIsChecking(true);
-      const update = await check();
+      // The backend applies the update channel, skipped version, deferral
+      // and staged rollout before reporting an update
+      const update = await invoke<UpdateInfo | null>("check_for_update", {
+        manual: isManualCheckRef.current,
+      });
 
       if (update) {
         setUpdateAvailable(true);
@@ -102,32 +119,27 @@ const UpdateChecker: React.FC<UpdateCheckerProps> = ({ className = "" }) => {
       setDownloadProgress(0);
       downloadedBytesRef.current = 0;
       contentLengthRef.current = 0;
-      const update = await check();
 
-      if (!update) {
-        console.log("No update available during install attempt");
-        return;
+      const unlisten = await listen<UpdateDownloadProgress>(
+        "update-download-progress",
+        (event) => {
+          downloadedBytesRef.current = event.payload.downloaded;
+          contentLengthRef.current = event.payload.content_length ?? 0;
+          const progress =
+            contentLengthRef.current > 0
+              ? Math.round(
+                  (downloadedBytesRef.current / contentLengthRef.current) * 100,
+                )
+              : 0;
+          setDownloadProgress(Math.min(progress, 100));
+        },
+      );
+
+      try {
+        await invoke("install_pending_update");
+      } finally {
+        unlisten();
       }
-
-      await update.downloadAndInstall((event) => {
-        switch (event.event) {
-          case "Started":
-            downloadedBytesRef.current = 0;
-            contentLengthRef.current = event.data.contentLength ?? 0;
-            break;
-          case "Progress":
-            downloadedBytesRef.current += event.data.chunkLength;
-            const progress =
-              contentLengthRef.current > 0
-                ? Math.round(
-                    (downloadedBytesRef.current / contentLengthRef.current) *
-                      100,
-                  )
-                : 0;
-            setDownloadProgress(Math.min(progress, 100));
-            break;
-        }
-      });
       await relaunch();
     } catch (error) {
       console.error("Failed to install update:", error);
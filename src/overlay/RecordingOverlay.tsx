@@ -36,6 +36,7 @@ interface ActiveListeningSegmentEvent {
   timestamp: number;
   speaker_id: number | null;
   speaker_label: string | null;
+  draft: boolean;
 }
 
 interface ActiveListeningInsightEvent {
@@ -44,6 +45,13 @@ interface ActiveListeningInsightEvent {
   done: boolean;
 }
 
+interface ActiveListeningBudgetEvent {
+  session_id: string;
+  limit: "llm_calls" | "tokens" | "segment_latency";
+  llm_calls: number;
+  tokens: number;
+}
+
 interface ActiveListeningInsight {
   id: string;
   transcription: string;
@@ -67,6 +75,35 @@ interface AskAiResponseEvent {
   done: boolean;
 }
 
+/** Characters of live text that fit the compact overlay */
+const PARTIAL_TEXT_CHARS = 40;
+
+// Emitted by the backend while recording, so the timer doesn't drift
+interface RecordingProgressEvent {
+  elapsed_ms: number;
+  word_count: number | null;
+}
+
+// Emitted once when a recording nears its maximum length
+interface RecordingLimitWarningEvent {
+  elapsed_ms: number;
+  limit_ms: number;
+}
+
+// Emitted after each chunk while a long recording is transcribed
+interface TranscriptionProgressEvent {
+  done: number;
+  total: number;
+  progress: number;
+}
+
+// Emitted when the dictation's first words picked a post-process prompt
+interface PromptRoutedEvent {
+  phrase: string;
+  prompt_id: string;
+  prompt_name: string;
+}
+
 const RecordingOverlay: React.FC = () => {
   const { t } = useTranslation();
   const [isVisible, setIsVisible] = useState(false);
@@ -89,7 +126,19 @@ const RecordingOverlay: React.FC = () => {
   const [activeListeningSessionId, setActiveListeningSessionId] = useState<string | null>(null);
   const [activeListeningInsights, setActiveListeningInsights] = useState<ActiveListeningInsight[]>([]);
   const [currentTranscription, setCurrentTranscription] = useState<string>("");
-  const [sessionStartTime, setSessionStartTime] = useState<number | null>(null);
+  // Insights stop for the rest of the session once its LLM budget is used up
+  const [budgetReached, setBudgetReached] = useState(false);
+  // Live text of the dictation while recording, if the preview is on
+  const [partialText, setPartialText] = useState("");
+  // Prompt the dictation was routed to, shown while it's post-processed
+  const [routedPrompt, setRoutedPrompt] = useState<string | null>(null);
+  const [progress, setProgress] = useState<RecordingProgressEvent | null>(
+    null,
+  );
+  // Set once the recording nears its maximum length; the timer counts down
+  const [limitMs, setLimitMs] = useState<number | null>(null);
+  // Share of a long recording transcribed so far, from 0 to 1
+  const [transcribed, setTranscribed] = useState<number | null>(null);
   const insightsScrollRef = useRef<HTMLDivElement>(null);
 
   // Clear auto-dismiss timer on unmount or state change
@@ -212,6 +261,16 @@ const RecordingOverlay: React.FC = () => {
         const overlayState = event.payload as OverlayState;
         setState(overlayState);
         setIsVisible(true);
+        if (
+          overlayState === "recording" ||
+          overlayState === "ask-ai-recording"
+        ) {
+          setProgress(null);
+          setLimitMs(null);
+          setTranscribed(null);
+          setPartialText("");
+          setRoutedPrompt(null);
+        }
 
         // Reset Ask AI state when starting a new session
         if (
@@ -257,6 +316,56 @@ const RecordingOverlay: React.FC = () => {
       });
       unlistenFns.push(unlistenLevel);
 
+      // Listen for elapsed time and streamed word count
+      const unlistenProgress = await listen<RecordingProgressEvent>(
+        "recording-progress",
+        (event) => {
+          if (!isMounted) return;
+          setProgress(event.payload);
+        },
+      );
+      unlistenFns.push(unlistenProgress);
+
+      // Listen for the recording nearing its maximum length
+      const unlistenLimit = await listen<RecordingLimitWarningEvent>(
+        "recording-limit-warning",
+        (event) => {
+          if (!isMounted) return;
+          setLimitMs(event.payload.limit_ms);
+        },
+      );
+      unlistenFns.push(unlistenLimit);
+
+      // Listen for progress through a long recording's chunks
+      const unlistenTranscribed = await listen<TranscriptionProgressEvent>(
+        "transcription-progress",
+        (event) => {
+          if (!isMounted) return;
+          setTranscribed(event.payload.progress);
+        },
+      );
+      unlistenFns.push(unlistenTranscribed);
+
+      // Listen for the live transcription preview
+      const unlistenPartial = await listen<string>(
+        "transcription-partial",
+        (event) => {
+          if (!isMounted) return;
+          setPartialText(event.payload);
+        },
+      );
+      unlistenFns.push(unlistenPartial);
+
+      // Listen for dictations routed to a prompt by their first words
+      const unlistenRouted = await listen<PromptRoutedEvent>(
+        "prompt-routed",
+        (event) => {
+          if (!isMounted) return;
+          setRoutedPrompt(event.payload.prompt_name);
+        },
+      );
+      unlistenFns.push(unlistenRouted);
+
       // Listen for active listening state changes
       const unlistenALState = await listen<ActiveListeningStateEvent>(
         "active-listening-state-changed",
@@ -269,9 +378,9 @@ const RecordingOverlay: React.FC = () => {
             // Initialize session state
             if (payload.session_id) {
               setActiveListeningSessionId(payload.session_id);
-              setSessionStartTime(Date.now());
               setActiveListeningInsights([]);
               setCurrentTranscription("");
+              setBudgetReached(false);
             }
           } else if (payload.state === "processing") {
             setState("active-listening-processing");
@@ -279,7 +388,7 @@ const RecordingOverlay: React.FC = () => {
             setIsVisible(false);
             // Clear session state
             setActiveListeningSessionId(null);
-            setSessionStartTime(null);
+            setProgress(null);
             setActiveListeningInsights([]);
             setCurrentTranscription("");
           } else if (payload.state === "error" && payload.error) {
@@ -301,7 +410,15 @@ const RecordingOverlay: React.FC = () => {
           setActiveListeningInsights((prev) => {
             // Check if insight already exists
             if (prev.some((i) => i.id === insightId)) {
-              return prev;
+              if (payload.draft) return prev;
+              // The main model's transcription replaces the draft
+              return payload.transcription.trim()
+                ? prev.map((i) =>
+                    i.id === insightId
+                      ? { ...i, transcription: payload.transcription }
+                      : i
+                  )
+                : prev.filter((i) => i.id !== insightId);
             }
             return [
               ...prev,
@@ -349,6 +466,19 @@ const RecordingOverlay: React.FC = () => {
       );
       unlistenFns.push(unlistenALInsight);
 
+      // Listen for the session's LLM budget running out
+      const unlistenALBudget = await listen<ActiveListeningBudgetEvent>(
+        "active-listening-budget-exceeded",
+        (event) => {
+          if (!isMounted) return;
+          // A slow segment only loses its own insight
+          if (event.payload.limit !== "segment_latency") {
+            setBudgetReached(true);
+          }
+        }
+      );
+      unlistenFns.push(unlistenALBudget);
+
       // Listen for Ask AI state changes
       const unlistenAskAiState = await listen<AskAiStateEvent>(
         "ask-ai-state-changed",
@@ -453,6 +583,7 @@ const RecordingOverlay: React.FC = () => {
     state === "transcribing" ||
     state === "active-listening-processing" ||
     state === "ask-ai-transcribing";
+  const showPartialText = state === "recording" && partialText !== "";
 
   // Generate accessible status text for screen readers
   const getStatusText = () => {
@@ -581,10 +712,10 @@ const RecordingOverlay: React.FC = () => {
     // User can trigger shortcut again manually
   };
 
-  // Format session duration
-  const formatDuration = (startTime: number | null): string => {
-    if (!startTime) return "0:00";
-    const seconds = Math.floor((Date.now() - startTime) / 1000);
+  // Format recording duration
+  const formatDuration = (elapsedMs: number | undefined): string => {
+    if (!elapsedMs) return "0:00";
+    const seconds = Math.floor(elapsedMs / 1000);
     const minutes = Math.floor(seconds / 60);
     const remainingSeconds = seconds % 60;
     return `${minutes}:${remainingSeconds.toString().padStart(2, "0")}`;
@@ -608,13 +739,28 @@ const RecordingOverlay: React.FC = () => {
             <div className="active-listening-header-stats">
               <span className="active-listening-stat">
                 <span className="pulse-dot" aria-hidden="true" />
-                {formatDuration(sessionStartTime)}
+                {formatDuration(progress?.elapsed_ms)}
               </span>
+              {progress?.word_count != null && (
+                <span className="active-listening-stat">
+                  {t("overlay.words", "{{count}} words", {
+                    count: progress.word_count,
+                  })}
+                </span>
+              )}
               <span className="active-listening-stat">
                 {t("activeListening.overlay.insights", "{{count}} insights", {
                   count: activeListeningInsights.filter((i) => i.insight).length,
                 })}
               </span>
+              {budgetReached && (
+                <span className="active-listening-stat">
+                  {t(
+                    "activeListening.overlay.budgetReached",
+                    "Insights paused, budget reached"
+                  )}
+                </span>
+              )}
             </div>
           </div>
 
@@ -922,7 +1068,13 @@ const RecordingOverlay: React.FC = () => {
       </div>
 
       <div className="overlay-middle">
-        {isRecording && (
+        {showPartialText && (
+          <div className="partial-text" aria-hidden="true">
+            {/* Right-to-left clips the start, so the latest words stay visible */}
+            <bdi>{partialText.slice(-PARTIAL_TEXT_CHARS)}</bdi>
+          </div>
+        )}
+        {isRecording && !showPartialText && (
           <div
             className="bars-container"
             role="img"
@@ -941,13 +1093,31 @@ const RecordingOverlay: React.FC = () => {
             ))}
           </div>
         )}
+        {isRecording && progress && limitMs == null && (
+          <span className="recording-timer">
+            {formatDuration(progress.elapsed_ms)}
+          </span>
+        )}
+        {isRecording && progress && limitMs != null && (
+          <span className="recording-timer limit-warning">
+            {t("overlay.limitWarning", {
+              time: formatDuration(Math.max(0, limitMs - progress.elapsed_ms)),
+            })}
+          </span>
+        )}
         {isProcessing && (
           <div className="transcribing-text" aria-hidden="true">
             {state === "active-listening-processing"
               ? t("overlay.processing", "Processing...")
               : state === "ask-ai-transcribing"
                 ? t("overlay.askAiProcessing", "Ask AI...")
-                : t("overlay.transcribing")}
+                : routedPrompt
+                  ? t("overlay.routedTo", { name: routedPrompt })
+                  : transcribed != null
+                    ? t("overlay.transcribingProgress", {
+                        percent: Math.round(transcribed * 100),
+                      })
+                    : t("overlay.transcribing")}
           </div>
         )}
       </div>
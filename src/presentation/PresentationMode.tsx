@@ -11,6 +11,7 @@ interface ActiveListeningSegmentEvent {
   timestamp: number;
   speaker_id: number | null;
   speaker_label: string | null;
+  draft: boolean;
 }
 
 interface ActiveListeningInsightEvent {
@@ -105,7 +106,15 @@ const PresentationMode: React.FC = () => {
           const lineId = `${payload.session_id}-${payload.timestamp}`;
 
           setLines((prev) => {
-            if (prev.some((l) => l.id === lineId)) return prev;
+            if (prev.some((l) => l.id === lineId)) {
+              if (payload.draft) return prev;
+              // The main model's transcription replaces the draft
+              return payload.transcription.trim()
+                ? prev.map((l) =>
+                    l.id === lineId ? { ...l, text: payload.transcription } : l,
+                  )
+                : prev.filter((l) => l.id !== lineId);
+            }
             return [
               ...prev,
               {
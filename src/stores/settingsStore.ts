@@ -2,6 +2,7 @@ import { create } from "zustand";
 import { subscribeWithSelector } from "zustand/middleware";
 import type { AppSettings as Settings, AudioDevice, LogLevel } from "@/bindings";
 import { commands } from "@/bindings";
+import { listen } from "@tauri-apps/api/event";
 
 interface SettingsStore {
   settings: Settings | null;
@@ -510,6 +511,15 @@ export const useSettingsStore = create<SettingsStore>()(
         refreshSettings(),
         checkCustomSounds(),
       ]);
+
+      // The config file can override settings while the app is running
+      listen("settings-config-applied", () => {
+        refreshSettings();
+      });
+      // The formatting mode can be switched from the tray or a shortcut
+      listen("formatting-mode-changed", () => {
+        refreshSettings();
+      });
     },
   })),
 );
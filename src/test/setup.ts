@@ -244,6 +244,7 @@ vi.mock('@/bindings', () => {
       changeOllamaBaseUrlSetting: vi.fn(() => Promise.resolve(mockResult(null))),
       changeOllamaModelSetting: vi.fn(() => Promise.resolve(mockResult(null))),
       changeActiveListeningContextWindowSetting: vi.fn(() => Promise.resolve(mockResult(null))),
+      changeActiveListeningDraftModelSetting: vi.fn(() => Promise.resolve(mockResult(null))),
       addActiveListeningPrompt: vi.fn(() =>
         Promise.resolve(
           mockResult({